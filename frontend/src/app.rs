@@ -10,7 +10,7 @@ use crate::components::command_palette::CommandPalette;
 use crate::components::design_system::ToastContainer;
 use crate::components::layout::main_shell::MainShell;
 use crate::components::library::Library;
-use crate::components::session::Session;
+use crate::components::session::{is_player_window, PlayerView, Session};
 use crate::components::settings::Settings;
 use crate::services::chat_context::provide_chat_context;
 use crate::services::chat_session_service::provide_chat_session_service;
@@ -67,6 +67,11 @@ pub fn App() -> impl IntoView {
         }
     }
 
+    // The player window shows only the players' screen, without the GM shell
+    if is_player_window() {
+        return view! { <PlayerView /> }.into_any();
+    }
+
     view! {
         <Router>
             // Global Command Palette (Ctrl+K)
@@ -97,4 +102,5 @@ pub fn App() -> impl IntoView {
             </MainShell>
         </Router>
     }
+    .into_any()
 }
@@ -1,4 +1,4 @@
-use super::core::{invoke, invoke_no_args, invoke_void};
+use super::core::{invoke, invoke_no_args, invoke_void, invoke_void_no_args};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -38,6 +38,8 @@ pub struct CampaignSettings {
     pub voice_enabled: bool,
     #[serde(default)]
     pub auto_transcribe: bool,
+    #[serde(default)]
+    pub name_culture: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -273,6 +275,209 @@ pub async fn reorder_session(session_id: String, new_order: i32) -> Result<(), S
     .await
 }
 
+pub async fn pause_session(session_id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+    }
+    invoke_void("pause_session", &Args { session_id }).await
+}
+
+pub async fn resume_session(session_id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+    }
+    invoke_void("resume_session", &Args { session_id }).await
+}
+
+// ============================================================================
+// Session Clock Types & Commands
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClockConfig {
+    pub break_reminders: bool,
+    pub break_interval_minutes: u32,
+    pub pacing_nudges: bool,
+    pub nudge_threshold_minutes: u32,
+    pub system_notifications: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClockStatus {
+    pub session_id: String,
+    pub started_at: String,
+    pub elapsed_minutes: i64,
+    pub paused: bool,
+    pub next_break_in_minutes: Option<i64>,
+    pub current_scene: Option<String>,
+    pub planned_minutes: Option<i64>,
+    pub drift_minutes: Option<i64>,
+    pub config: ClockConfig,
+}
+
+/// Payload of the `session:clock_notice` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClockNotice {
+    pub session_id: String,
+    /// "break" or "pacing"
+    pub kind: String,
+    pub title: String,
+    pub message: String,
+    pub notify: bool,
+}
+
+pub async fn get_session_clock(session_id: String) -> Result<ClockStatus, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+    }
+    invoke("get_session_clock", &Args { session_id }).await
+}
+
+pub async fn configure_session_clock(session_id: String, config: ClockConfig) -> Result<ClockStatus, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        config: ClockConfig,
+    }
+    invoke("configure_session_clock", &Args { session_id, config }).await
+}
+
+/// Pace the session against a session plan (as returned by the planner)
+pub async fn set_session_clock_plan(session_id: String, plan: serde_json::Value) -> Result<ClockStatus, String> {
+    invoke("set_session_clock_plan", &json!({ "session_id": session_id, "plan": plan })).await
+}
+
+pub async fn set_session_clock_scene(session_id: String, scene_index: usize) -> Result<ClockStatus, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        scene_index: usize,
+    }
+    invoke("set_session_clock_scene", &Args { session_id, scene_index }).await
+}
+
+// ============================================================================
+// Player Window Types & Commands
+// ============================================================================
+
+/// A combatant as the players see them; exact HP only for player characters
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCombatant {
+    pub id: String,
+    pub name: String,
+    pub combatant_type: String,
+    pub initiative: i32,
+    pub is_current: bool,
+    /// "unhurt", "wounded", "bloodied", "down", "dead", or "unknown"
+    pub health: String,
+    pub current_hp: Option<i32>,
+    pub max_hp: Option<i32>,
+    pub temp_hp: Option<i32>,
+    pub conditions: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCombatView {
+    pub round: u32,
+    pub current: Option<String>,
+    pub combatants: Vec<PlayerCombatant>,
+    pub layout: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerTimelineEvent {
+    pub id: String,
+    pub event_type: TimelineEventType,
+    pub timestamp: String,
+    pub title: String,
+    pub description: String,
+}
+
+/// Payload of the `player:view` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerViewSnapshot {
+    pub session_id: String,
+    pub campaign_id: String,
+    pub combat: Option<PlayerCombatView>,
+    pub timeline: Vec<PlayerTimelineEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayInfo {
+    pub index: usize,
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub primary: bool,
+}
+
+/// What a handout shows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HandoutContent {
+    Image { path: String },
+    PdfPage { path: String, page: u32 },
+    Text { text: String },
+}
+
+/// A revealed handout, as sent with the `handout:revealed` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerHandout {
+    pub id: String,
+    pub campaign_id: String,
+    pub session_id: Option<String>,
+    pub title: String,
+    pub content: HandoutContent,
+    pub caption: String,
+    pub revealed_at: Option<String>,
+}
+
+pub async fn list_displays() -> Result<Vec<DisplayInfo>, String> {
+    invoke_no_args("list_displays").await
+}
+
+/// Open the player window on a session; with a display, fullscreen on it
+pub async fn open_player_window(session_id: String, display: Option<usize>) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        display: Option<usize>,
+    }
+    invoke_void("open_player_window", &Args { session_id, display }).await
+}
+
+pub async fn close_player_window() -> Result<(), String> {
+    invoke_void_no_args("close_player_window").await
+}
+
+pub async fn get_player_view() -> Result<Option<PlayerViewSnapshot>, String> {
+    invoke_no_args("get_player_view").await
+}
+
+pub async fn get_revealed_handouts(
+    campaign_id: String,
+    session_id: Option<String>,
+) -> Result<Vec<PlayerHandout>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        session_id: Option<String>,
+    }
+    invoke("get_revealed_handouts", &Args { campaign_id, session_id }).await
+}
+
+/// A revealed image handout as a data URL
+pub async fn get_player_handout_image(handout_id: String) -> Result<String, String> {
+    #[derive(Serialize)]
+    struct Args {
+        handout_id: String,
+    }
+    invoke("get_player_handout_image", &Args { handout_id }).await
+}
+
 // ============================================================================
 // Timeline Types & Commands
 // ============================================================================
@@ -451,6 +656,41 @@ pub async fn get_timeline_events_by_type(
     .await
 }
 
+/// Which subsystems add events to the session timeline on their own
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineCaptureSettings {
+    pub combat: bool,
+    pub npc_conversations: bool,
+    pub world_events: bool,
+    pub quests: bool,
+    pub dice_rolls: bool,
+}
+
+pub async fn get_timeline_capture() -> Result<TimelineCaptureSettings, String> {
+    invoke_no_args("get_timeline_capture").await
+}
+
+pub async fn set_timeline_capture(settings: TimelineCaptureSettings) -> Result<TimelineCaptureSettings, String> {
+    #[derive(Serialize)]
+    struct Args {
+        settings: TimelineCaptureSettings,
+    }
+    invoke("set_timeline_capture", &Args { settings }).await
+}
+
+/// `category` is "combat", "npc_conversations", "world_events", "quests", or "dice_rolls"
+pub async fn set_timeline_capture_category(
+    category: String,
+    enabled: bool,
+) -> Result<TimelineCaptureSettings, String> {
+    #[derive(Serialize)]
+    struct Args {
+        category: String,
+        enabled: bool,
+    }
+    invoke("set_timeline_capture_category", &Args { category, enabled }).await
+}
+
 pub async fn generate_session_summary(session_id: String) -> Result<String, String> {
     let summary = get_timeline_summary(session_id.clone()).await?;
 
@@ -30,6 +30,152 @@ pub struct Combatant {
     pub combatant_type: String,
     pub conditions: Vec<String>,
     pub is_active: bool,
+    /// Members, when this entry is a group of identical creatures
+    #[serde(default)]
+    pub group: Option<MinionGroup>,
+    /// Stepped out of the initiative order for now
+    #[serde(default)]
+    pub delaying: bool,
+    #[serde(default)]
+    pub readied: Option<ReadiedAction>,
+}
+
+/// An action held until a trigger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadiedAction {
+    pub action: String,
+    pub trigger: String,
+    pub trigger_combatant_id: Option<String>,
+    pub round: u32,
+}
+
+/// A readied action set off by a turn, as sent with `combat:readied`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadiedReminder {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub action: String,
+    pub trigger: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupMember {
+    pub number: u32,
+    pub current_hp: i32,
+    pub down: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinionGroup {
+    /// "pooled" or "per_member"
+    pub hp_mode: String,
+    pub member_max_hp: i32,
+    pub members: Vec<GroupMember>,
+}
+
+impl MinionGroup {
+    pub fn standing(&self) -> usize {
+        self.members.iter().filter(|m| !m.down).count()
+    }
+}
+
+/// Which members of a group a hit lands on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupTargets {
+    /// The first members still standing
+    Count(u32),
+    /// Members by number
+    Members(Vec<u32>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupHits {
+    pub hits: u32,
+    pub hp_lost: i32,
+    pub dropped: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDamageResult {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub hits: GroupHits,
+    pub standing: usize,
+    pub size: usize,
+    pub breakdown: DamageBreakdown,
+}
+
+/// Enemies whose morale is tracked together
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoraleGroup {
+    pub id: String,
+    pub name: String,
+    pub member_ids: Vec<String>,
+    pub leader_id: Option<String>,
+    pub morale: i32,
+    /// "leader_killed" and/or "half_down"
+    pub triggers: Vec<String>,
+    pub broken: bool,
+}
+
+/// A morale roll and the GM's suggestion, as sent with `combat:morale`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoraleCheck {
+    pub group_id: String,
+    pub group_name: String,
+    /// "leader_killed", "half_down", or "called"
+    pub trigger: String,
+    pub roll: Option<i32>,
+    pub target: Option<i32>,
+    /// Whether the group holds; none leaves it to the GM
+    pub holds: Option<bool>,
+    pub suggestion: String,
+}
+
+/// How a hit of damage became lost hit points
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DamageBreakdown {
+    pub raw: i32,
+    pub damage_type: Option<String>,
+    pub effective: i32,
+    pub absorbed: i32,
+    pub hp_before: i32,
+    pub hp_after: i32,
+    pub temp_hp_after: i32,
+}
+
+/// One combatant's part in a finished fight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatantReport {
+    pub combatant_id: String,
+    pub name: String,
+    pub damage_dealt: i32,
+    pub damage_taken: i32,
+    pub healing_received: i32,
+    pub kills: u32,
+    pub defeated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionCount {
+    pub condition: String,
+    pub count: u32,
+}
+
+/// What happened in a finished fight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatReport {
+    pub combat_id: String,
+    pub rounds: u32,
+    pub combatants: Vec<CombatantReport>,
+    pub total_damage: i32,
+    pub total_healing: i32,
+    pub total_kills: u32,
+    pub top_conditions: Vec<ConditionCount>,
+    pub estimated_difficulty: Option<String>,
+    pub actual_difficulty: String,
+    pub calibration: Option<String>,
 }
 
 // ============================================================================
@@ -52,6 +198,15 @@ pub async fn end_combat(session_id: String) -> Result<(), String> {
     invoke_void("end_combat", &Args { session_id }).await
 }
 
+pub async fn get_combat_report(session_id: String, combat_id: Option<String>) -> Result<Option<CombatReport>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combat_id: Option<String>,
+    }
+    invoke("get_combat_report", &Args { session_id, combat_id }).await
+}
+
 pub async fn get_combat(session_id: String) -> Result<Option<CombatState>, String> {
     #[derive(Serialize)]
     struct Args {
@@ -60,6 +215,14 @@ pub async fn get_combat(session_id: String) -> Result<Option<CombatState>, Strin
     invoke("get_combat", &Args { session_id }).await
 }
 
+pub async fn recover_combat(session_id: String) -> Result<Option<CombatState>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+    }
+    invoke("recover_combat", &Args { session_id }).await
+}
+
 pub async fn add_combatant(
     session_id: String,
     name: String,
@@ -136,11 +299,91 @@ pub async fn next_turn(session_id: String) -> Result<Option<Combatant>, String>
     invoke("next_turn", &Args { session_id }).await
 }
 
+/// Move a combatant to a new place in the initiative order (0 is first)
+pub async fn move_in_initiative(
+    session_id: String,
+    combatant_id: String,
+    position: usize,
+) -> Result<CombatState, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+        position: usize,
+    }
+    invoke(
+        "move_in_initiative",
+        &Args {
+            session_id,
+            combatant_id,
+            position,
+        },
+    )
+    .await
+}
+
+/// The current combatant delays; returns whose turn it is now
+pub async fn delay_turn(session_id: String, combatant_id: String) -> Result<Option<Combatant>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+    }
+    invoke("delay_turn", &Args { session_id, combatant_id }).await
+}
+
+/// A delaying combatant comes back in and takes their turn
+pub async fn resume_delayed_turn(session_id: String, combatant_id: String) -> Result<Option<Combatant>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+    }
+    invoke("resume_delayed_turn", &Args { session_id, combatant_id }).await
+}
+
+pub async fn ready_action(
+    session_id: String,
+    combatant_id: String,
+    action: String,
+    trigger: String,
+    trigger_combatant_id: Option<String>,
+) -> Result<ReadiedAction, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+        action: String,
+        trigger: String,
+        trigger_combatant_id: Option<String>,
+    }
+    invoke(
+        "ready_action",
+        &Args {
+            session_id,
+            combatant_id,
+            action,
+            trigger,
+            trigger_combatant_id,
+        },
+    )
+    .await
+}
+
+pub async fn take_readied_action(session_id: String, combatant_id: String) -> Result<ReadiedAction, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+    }
+    invoke("take_readied_action", &Args { session_id, combatant_id }).await
+}
+
 pub async fn damage_combatant(
     session_id: String,
     combatant_id: String,
     amount: i32,
-) -> Result<i32, String> {
+) -> Result<DamageBreakdown, String> {
     #[derive(Serialize)]
     struct Args {
         session_id: String,
@@ -158,6 +401,141 @@ pub async fn damage_combatant(
     .await
 }
 
+/// Add a generated NPC to combat from its stat block
+pub async fn add_npc_to_combat(
+    session_id: String,
+    npc_id: String,
+    count: Option<u32>,
+    hp_method: Option<String>,
+    combatant_type: Option<String>,
+) -> Result<Vec<Combatant>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        npc_id: String,
+        count: Option<u32>,
+        hp_method: Option<String>,
+        combatant_type: Option<String>,
+    }
+    invoke(
+        "add_npc_to_combat",
+        &Args {
+            session_id,
+            npc_id,
+            count,
+            hp_method,
+            combatant_type,
+        },
+    )
+    .await
+}
+
+/// Add a group of identical creatures acting on one initiative
+pub async fn add_combatant_group(
+    session_id: String,
+    name: String,
+    size: u32,
+    member_hp: i32,
+    hp_mode: Option<String>,
+    initiative: Option<i32>,
+) -> Result<Combatant, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        name: String,
+        size: u32,
+        member_hp: i32,
+        hp_mode: Option<String>,
+        initiative: Option<i32>,
+    }
+    invoke(
+        "add_combatant_group",
+        &Args {
+            session_id,
+            name,
+            size,
+            member_hp,
+            hp_mode,
+            initiative,
+        },
+    )
+    .await
+}
+
+pub async fn damage_group(
+    session_id: String,
+    combatant_id: String,
+    amount: i32,
+    targets: GroupTargets,
+) -> Result<GroupDamageResult, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        combatant_id: String,
+        amount: i32,
+        targets: GroupTargets,
+    }
+    invoke(
+        "damage_group",
+        &Args {
+            session_id,
+            combatant_id,
+            amount,
+            targets,
+        },
+    )
+    .await
+}
+
+/// Track morale for a group of enemies, rolled when their leader falls or
+/// half of them are down
+pub async fn add_morale_group(
+    session_id: String,
+    name: String,
+    member_ids: Vec<String>,
+    leader_id: Option<String>,
+    morale: Option<i32>,
+) -> Result<MoraleGroup, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        name: String,
+        member_ids: Vec<String>,
+        leader_id: Option<String>,
+        morale: Option<i32>,
+    }
+    invoke(
+        "add_morale_group",
+        &Args {
+            session_id,
+            name,
+            member_ids,
+            leader_id,
+            morale,
+        },
+    )
+    .await
+}
+
+pub async fn remove_morale_group(session_id: String, group_id: String) -> Result<MoraleGroup, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        group_id: String,
+    }
+    invoke("remove_morale_group", &Args { session_id, group_id }).await
+}
+
+/// Roll morale for a group now
+pub async fn call_morale_check(session_id: String, group_id: String) -> Result<MoraleCheck, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+        group_id: String,
+    }
+    invoke("call_morale_check", &Args { session_id, group_id }).await
+}
+
 pub async fn heal_combatant(
     session_id: String,
     combatant_id: String,
@@ -464,6 +842,14 @@ pub struct Character {
     pub backstory: Option<String>,
     pub notes: String,
     pub portrait_prompt: Option<String>,
+    #[serde(default)]
+    pub derived_stats: Vec<DerivedStat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DerivedStat {
+    pub name: String,
+    pub value: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -554,6 +940,438 @@ pub async fn generate_character_advanced(options: GenerationOptions) -> Result<C
     invoke("generate_character_advanced", &Args { options }).await
 }
 
+// ============================================================================
+// Character Builder (D&D 5e)
+// ============================================================================
+
+/// Ability scores as generated, keyed by ability ("strength", ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum BuildAbilityScores {
+    PointBuy { scores: std::collections::BTreeMap<String, i32> },
+    StandardArray { scores: std::collections::BTreeMap<String, i32> },
+    Rolled { rolls: Vec<i32>, scores: std::collections::BTreeMap<String, i32> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StartingEquipment {
+    ClassPackage,
+    Gold { gp: u32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterBuild {
+    pub name: Option<String>,
+    pub level: u32,
+    pub race: Option<String>,
+    pub lineage: Option<String>,
+    pub class: Option<String>,
+    pub background: Option<String>,
+    pub abilities: Option<BuildAbilityScores>,
+    pub bonus_abilities: Vec<String>,
+    pub skills: Vec<String>,
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+    pub equipment: Option<StartingEquipment>,
+}
+
+impl Default for CharacterBuild {
+    fn default() -> Self {
+        Self {
+            name: None,
+            level: 1,
+            race: None,
+            lineage: None,
+            class: None,
+            background: None,
+            abilities: None,
+            bonus_abilities: vec![],
+            skills: vec![],
+            cantrips: vec![],
+            spells: vec![],
+            equipment: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildLineageOption {
+    pub name: String,
+    pub ability_bonuses: std::collections::BTreeMap<String, i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildRaceOption {
+    pub name: String,
+    pub speed: u32,
+    pub ability_bonuses: std::collections::BTreeMap<String, i32>,
+    pub free_ability_bonuses: u32,
+    pub excluded_from_free_bonuses: Vec<String>,
+    pub skills: Vec<String>,
+    pub free_skills: u32,
+    pub lineages: Vec<BuildLineageOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildClassOption {
+    pub name: String,
+    pub hit_die: u32,
+    pub primary_abilities: Vec<String>,
+    pub saving_throws: Vec<String>,
+    pub skill_count: u32,
+    pub skill_options: Vec<String>,
+    pub spellcasting: Option<serde_json::Value>,
+    pub gold_dice: u32,
+    pub gold_multiplier: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildBackgroundOption {
+    pub name: String,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildSpellOption {
+    pub name: String,
+    pub level: u32,
+    pub classes: Vec<String>,
+    pub document_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildStep {
+    Race,
+    Class,
+    Background,
+    Abilities,
+    Skills,
+    Spells,
+    Equipment,
+    Review,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildIssue {
+    pub step: BuildStep,
+    pub message: String,
+    #[serde(default)]
+    pub warning: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildChoices {
+    pub next_step: BuildStep,
+    pub races: Vec<BuildRaceOption>,
+    pub classes: Vec<BuildClassOption>,
+    pub backgrounds: Vec<BuildBackgroundOption>,
+    pub fixed_skills: Vec<String>,
+    pub skill_options: Vec<String>,
+    pub skills_to_pick: u32,
+    pub free_skills: u32,
+    pub free_ability_bonuses: u32,
+    pub cantrip_options: Vec<BuildSpellOption>,
+    pub cantrips_to_pick: u32,
+    pub spell_options: Vec<BuildSpellOption>,
+    pub spells_to_pick: u32,
+    pub class_equipment: Vec<CharacterEquipment>,
+    pub max_starting_gold: Option<u32>,
+    pub issues: Vec<BuildIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuiltCharacter {
+    pub character: Character,
+    pub max_hp: i32,
+    pub proficiency_bonus: i32,
+    pub speed: u32,
+    pub saving_throws: std::collections::BTreeMap<String, i32>,
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+    #[serde(default)]
+    pub level_history: Vec<LevelGain>,
+}
+
+pub async fn get_character_builder_choices(build: CharacterBuild) -> Result<BuildChoices, String> {
+    #[derive(Serialize)]
+    struct Args {
+        build: CharacterBuild,
+    }
+    invoke("get_character_builder_choices", &Args { build }).await
+}
+
+pub async fn finish_character_build(build: CharacterBuild) -> Result<BuiltCharacter, String> {
+    #[derive(Serialize)]
+    struct Args {
+        build: CharacterBuild,
+    }
+    invoke("finish_character_build", &Args { build }).await
+}
+
+pub async fn roll_ability_scores() -> Result<Vec<i32>, String> {
+    invoke_no_args("roll_ability_scores").await
+}
+
+pub async fn roll_starting_gold(class: String) -> Result<u32, String> {
+    #[derive(Serialize)]
+    struct Args {
+        class: String,
+    }
+    invoke("roll_starting_gold", &Args { class }).await
+}
+
+// ============================================================================
+// Level-Up Assistant (D&D 5e)
+// ============================================================================
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum HitPointGain {
+    #[default]
+    Average,
+    Rolled { roll: i32 },
+}
+
+/// +2 to one ability or +1 to two, keyed by ability ("strength", ...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AbilityImprovement {
+    Scores { increases: std::collections::BTreeMap<String, i32> },
+    Feat { name: String },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LevelUpChoices {
+    pub hit_points: HitPointGain,
+    pub improvement: Option<AbilityImprovement>,
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildFeatOption {
+    pub name: String,
+    pub prerequisite: Option<(String, i32)>,
+    #[serde(default)]
+    pub description: String,
+    pub document_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelUpOptions {
+    pub from_level: u32,
+    pub to_level: u32,
+    pub hit_die: u32,
+    pub average_hp: i32,
+    pub constitution_modifier: i32,
+    pub proficiency_bonus: i32,
+    pub features: Vec<CharacterTrait>,
+    pub ability_score_improvement: bool,
+    pub feats: Vec<BuildFeatOption>,
+    pub cantrips_to_learn: u32,
+    pub cantrip_options: Vec<BuildSpellOption>,
+    pub spells_to_learn: u32,
+    pub spell_options: Vec<BuildSpellOption>,
+    pub spell_slots: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelGain {
+    pub from_level: u32,
+    pub to_level: u32,
+    pub hp_gained: i32,
+    #[serde(default)]
+    pub improvement: Option<AbilityImprovement>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+    pub leveled_at: String,
+}
+
+pub async fn save_built_character(
+    character: BuiltCharacter,
+    campaign_id: Option<String>,
+) -> Result<BuiltCharacter, String> {
+    #[derive(Serialize)]
+    struct Args {
+        character: BuiltCharacter,
+        campaign_id: Option<String>,
+    }
+    invoke("save_built_character", &Args { character, campaign_id }).await
+}
+
+pub async fn get_built_character(character_id: String) -> Result<BuiltCharacter, String> {
+    #[derive(Serialize)]
+    struct Args {
+        character_id: String,
+    }
+    invoke("get_built_character", &Args { character_id }).await
+}
+
+pub async fn get_level_up_options(character_id: String) -> Result<LevelUpOptions, String> {
+    #[derive(Serialize)]
+    struct Args {
+        character_id: String,
+    }
+    invoke("get_level_up_options", &Args { character_id }).await
+}
+
+pub async fn validate_level_up(character_id: String, choices: LevelUpChoices) -> Result<Vec<String>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        character_id: String,
+        choices: LevelUpChoices,
+    }
+    invoke("validate_level_up", &Args { character_id, choices }).await
+}
+
+pub async fn level_up_character(character_id: String, choices: LevelUpChoices) -> Result<LevelGain, String> {
+    #[derive(Serialize)]
+    struct Args {
+        character_id: String,
+        choices: LevelUpChoices,
+    }
+    invoke("level_up_character", &Args { character_id, choices }).await
+}
+
+/// Numbers a character sheet shows that a `Character` doesn't carry
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SheetExtras {
+    #[serde(default)]
+    pub max_hp: Option<i32>,
+    #[serde(default)]
+    pub armor_class: Option<i32>,
+    #[serde(default)]
+    pub speed: Option<u32>,
+    #[serde(default)]
+    pub proficiency_bonus: Option<i32>,
+    #[serde(default)]
+    pub saving_throws: std::collections::BTreeMap<String, i32>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+}
+
+/// Export a character sheet; `format` is "pdf", "foundry", or "roll20"
+pub async fn export_character_sheet(
+    character: Character,
+    format: String,
+    path: String,
+    extras: Option<SheetExtras>,
+) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        character: Character,
+        format: String,
+        path: String,
+        extras: Option<SheetExtras>,
+    }
+    invoke_void("export_character_sheet", &Args { character, format, path, extras }).await
+}
+
+pub async fn export_built_character_sheet(character_id: String, format: String, path: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        character_id: String,
+        format: String,
+        path: String,
+    }
+    invoke_void("export_built_character_sheet", &Args { character_id, format, path }).await
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PassiveStats {
+    pub perception: i32,
+    pub insight: i32,
+    pub investigation: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InventorySummary {
+    #[serde(default)]
+    pub gold: i64,
+    #[serde(default)]
+    pub notable_items: Vec<String>,
+    #[serde(default)]
+    pub attuned_items: Vec<String>,
+}
+
+/// A character read from a D&D Beyond or Pathbuilder export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedCharacter {
+    /// "dnd_beyond" or "pathbuilder"
+    pub source: String,
+    pub character: Character,
+    pub extras: SheetExtras,
+    pub subclass: Option<String>,
+    pub proficiencies: Vec<String>,
+    pub languages: Vec<String>,
+    pub initiative_modifier: i32,
+    pub passives: PassiveStats,
+    pub inventory: InventorySummary,
+}
+
+/// A character on a campaign's party roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerCharacter {
+    pub id: String,
+    pub campaign_id: String,
+    pub name: String,
+    pub player_name: Option<String>,
+    pub class: String,
+    pub subclass: Option<String>,
+    pub ancestry: Option<String>,
+    pub level: u8,
+    pub max_hp: i32,
+    pub armor_class: i32,
+    #[serde(default)]
+    pub initiative_modifier: i32,
+    #[serde(default)]
+    pub passives: PassiveStats,
+    #[serde(default)]
+    pub inventory: InventorySummary,
+    pub active: bool,
+}
+
+/// Read a character export; `source` is "dndbeyond" or "pathbuilder", or
+/// `None` to detect it
+pub async fn import_character(data: String, source: Option<String>) -> Result<ImportedCharacter, String> {
+    #[derive(Serialize)]
+    struct Args {
+        data: String,
+        source: Option<String>,
+    }
+    invoke("import_character", &Args { data, source }).await
+}
+
+pub async fn import_character_to_party(
+    campaign_id: String,
+    data: String,
+    source: Option<String>,
+    player_name: Option<String>,
+) -> Result<PlayerCharacter, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        data: String,
+        source: Option<String>,
+        player_name: Option<String>,
+    }
+    invoke("import_character_to_party", &Args { campaign_id, data, source, player_name }).await
+}
+
 pub async fn get_supported_systems() -> Result<Vec<String>, String> {
     invoke_no_args("get_supported_systems").await
 }
@@ -569,3 +1387,101 @@ pub async fn get_game_system_info(system: String) -> Result<Option<GameSystemInf
     }
     invoke("get_system_info", &Args { system }).await
 }
+
+// ============================================================================
+// Lifepath Generation
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifepathOptions {
+    pub use_ai: bool,
+    pub formative_events: usize,
+    pub contacts: usize,
+    pub enemies: usize,
+    pub tone: Option<String>,
+    pub setting: Option<String>,
+    pub name_culture: Option<String>,
+}
+
+impl Default for LifepathOptions {
+    fn default() -> Self {
+        Self {
+            use_ai: false,
+            formative_events: 2,
+            contacts: 2,
+            enemies: 1,
+            tone: None,
+            setting: None,
+            name_culture: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FamilyBackground {
+    pub origin: String,
+    pub upbringing: String,
+    pub siblings: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifeEvent {
+    /// "family", "formative", "contact", or "enemy"
+    pub category: String,
+    pub age: Option<u32>,
+    pub description: String,
+    pub person: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifepathPerson {
+    pub name: String,
+    /// "family", "friend", "mentor", "romantic", "contact", "rival", or "enemy"
+    pub tie: String,
+    pub role: String,
+    /// "alive", "dead", "missing", "estranged", or "imprisoned"
+    pub status: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifepathHook {
+    pub title: String,
+    pub description: String,
+    pub person: Option<String>,
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lifepath {
+    pub subject_name: String,
+    pub family: FamilyBackground,
+    pub events: Vec<LifeEvent>,
+    pub people: Vec<LifepathPerson>,
+    pub hooks: Vec<LifepathHook>,
+}
+
+/// A lifepath plus the NPCs, relationships, and plot threads it created
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedLifepath {
+    pub lifepath: Lifepath,
+    pub npcs: Vec<serde_json::Value>,
+    pub relationships: Vec<serde_json::Value>,
+    pub plot_threads: Vec<serde_json::Value>,
+}
+
+/// Generate a lifepath for a party member or NPC and add its people and
+/// hooks to the campaign
+pub async fn generate_lifepath(
+    campaign_id: String,
+    character_id: String,
+    options: Option<LifepathOptions>,
+) -> Result<AppliedLifepath, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        character_id: String,
+        options: Option<LifepathOptions>,
+    }
+    invoke("generate_lifepath", &Args { campaign_id, character_id, options }).await
+}
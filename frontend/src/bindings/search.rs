@@ -24,6 +24,471 @@ pub async fn reindex_library(index_name: Option<String>) -> Result<String, Strin
     invoke("reindex_library", &Args { index_name }).await
 }
 
+// ============================================================================
+// Index Maintenance
+// ============================================================================
+
+/// Where an index lives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexBackend {
+    Meilisearch,
+    VectorTable,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSummary {
+    pub name: String,
+    pub backend: IndexBackend,
+    pub documents: u64,
+    /// An interrupted rebuild left a snapshot; rebuilding again restores it
+    pub pending_rebuild: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexStats {
+    pub name: String,
+    pub backend: IndexBackend,
+    pub documents: u64,
+    pub embedded_documents: Option<u64>,
+    pub primary_key: Option<String>,
+    pub table_indexes: Vec<String>,
+    pub pending_rebuild: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceOperation {
+    Rebuild,
+    Optimize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStage {
+    Exporting,
+    Recreating,
+    Importing,
+    Reindexing,
+    Completed,
+    Failed,
+}
+
+/// Payload of the `index-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexProgress {
+    pub index: String,
+    pub backend: IndexBackend,
+    pub operation: MaintenanceOperation,
+    pub stage: MaintenanceStage,
+    pub progress: f32,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceResult {
+    pub index: String,
+    pub backend: IndexBackend,
+    pub operation: MaintenanceOperation,
+    pub documents: u64,
+    pub duration_ms: u64,
+}
+
+/// List Meilisearch indexes and vector tables
+pub async fn list_indexes() -> Result<Vec<IndexSummary>, String> {
+    invoke_no_args("list_indexes").await
+}
+
+pub async fn get_index_stats(name: String, backend: IndexBackend) -> Result<IndexStats, String> {
+    #[derive(Serialize)]
+    struct Args {
+        name: String,
+        backend: IndexBackend,
+    }
+    invoke("get_index_stats", &Args { name, backend }).await
+}
+
+/// Rebuild an index, keeping its documents; emits `index-progress` events
+pub async fn rebuild_index(name: String, backend: IndexBackend) -> Result<MaintenanceResult, String> {
+    #[derive(Serialize)]
+    struct Args {
+        name: String,
+        backend: IndexBackend,
+    }
+    invoke("rebuild_index", &Args { name, backend }).await
+}
+
+/// Optimize an index; emits `index-progress` events
+pub async fn optimize_index(name: String, backend: IndexBackend) -> Result<MaintenanceResult, String> {
+    #[derive(Serialize)]
+    struct Args {
+        name: String,
+        backend: IndexBackend,
+    }
+    invoke("optimize_index", &Args { name, backend }).await
+}
+
+/// Recall and latency with one HNSW search candidate list size
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EfBenchmark {
+    pub search_ef: Option<u32>,
+    pub recall: f32,
+    pub mean_latency_ms: f64,
+    pub p95_latency_ms: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorBenchmarkReport {
+    pub table: String,
+    pub vectors: u64,
+    pub queries: usize,
+    pub k: usize,
+    pub tuning: VectorTuning,
+    pub exact_mean_latency_ms: f64,
+    pub results: Vec<EfBenchmark>,
+    pub recommended_search_ef: Option<u32>,
+}
+
+/// Benchmark vector search recall and latency against exact search
+pub async fn benchmark_vector_search(
+    queries: Option<usize>,
+    k: Option<usize>,
+) -> Result<VectorBenchmarkReport, String> {
+    #[derive(Serialize)]
+    struct Args {
+        queries: Option<usize>,
+        k: Option<usize>,
+    }
+    invoke("benchmark_vector_search", &Args { queries, k }).await
+}
+
+// ============================================================================
+// Vector Store
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingModelStats {
+    pub model: Option<String>,
+    pub dimensions: u64,
+    pub rows: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VectorTableStats {
+    pub table: String,
+    pub rows: u64,
+    pub embedded_rows: u64,
+    pub orphaned_rows: u64,
+    pub estimated_bytes: u64,
+    pub embedding_models: Vec<EmbeddingModelStats>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VectorStoreStats {
+    pub path: String,
+    pub disk_bytes: u64,
+    pub tables: Vec<VectorTableStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamespaceStats {
+    /// "library", "campaign:{id}", "notes", or "npcs"
+    pub namespace: String,
+    pub rows: u64,
+    pub embedded_rows: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrphanCleanupReport {
+    pub orphaned_chunks: u64,
+    pub dangling_references: u64,
+    pub missing_library_items: Vec<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionReport {
+    pub tables: Vec<MaintenanceResult>,
+    pub orphans: OrphanCleanupReport,
+    pub disk_bytes_before: u64,
+    pub disk_bytes_after: u64,
+}
+
+/// Row counts, embedding models, and disk usage of the vector store
+pub async fn get_vector_store_stats() -> Result<VectorStoreStats, String> {
+    invoke_no_args("get_vector_store_stats").await
+}
+
+/// Chunk counts per vector namespace, largest first
+pub async fn list_vector_namespaces() -> Result<Vec<NamespaceStats>, String> {
+    invoke_no_args("list_vector_namespaces").await
+}
+
+/// Remove orphaned chunks and rebuild the vector indexes in place; emits
+/// `index-progress` events
+pub async fn compact_vector_store() -> Result<CompactionReport, String> {
+    invoke_no_args("compact_vector_store").await
+}
+
+/// Remove vectors whose library document was deleted
+pub async fn cleanup_orphan_vectors(dry_run: Option<bool>) -> Result<OrphanCleanupReport, String> {
+    #[derive(Serialize)]
+    struct Args {
+        dry_run: Option<bool>,
+    }
+    invoke("cleanup_orphan_vectors", &Args { dry_run }).await
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingFingerprint {
+    pub models: Vec<EmbeddingModelStats>,
+    pub index_dimensions: Option<u64>,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupTable {
+    pub name: String,
+    pub file: String,
+    pub rows: u64,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorBackupManifest {
+    pub schema_version: u32,
+    pub app_version: String,
+    pub exported_at: String,
+    pub tables: Vec<BackupTable>,
+    pub fingerprint: EmbeddingFingerprint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreMode {
+    Merge,
+    Replace,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestoredTable {
+    pub name: String,
+    pub rows: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestoreReport {
+    pub tables: Vec<RestoredTable>,
+    pub mode: RestoreMode,
+    pub fingerprint: EmbeddingFingerprint,
+    pub exported_at: String,
+}
+
+/// Back up the library and its vectors to a portable archive
+pub async fn export_vector_store(path: String) -> Result<VectorBackupManifest, String> {
+    #[derive(Serialize)]
+    struct Args {
+        path: String,
+    }
+    invoke("export_vector_store", &Args { path }).await
+}
+
+/// Read a vector store backup's manifest
+pub async fn inspect_vector_backup(path: String) -> Result<VectorBackupManifest, String> {
+    #[derive(Serialize)]
+    struct Args {
+        path: String,
+    }
+    invoke("inspect_vector_backup", &Args { path }).await
+}
+
+/// Restore a vector store backup, optionally replacing the stored library
+pub async fn restore_vector_store(path: String, replace: Option<bool>) -> Result<RestoreReport, String> {
+    #[derive(Serialize)]
+    struct Args {
+        path: String,
+        replace: Option<bool>,
+    }
+    invoke("restore_vector_store", &Args { path, replace }).await
+}
+
+// ============================================================================
+// Index Migrations
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationPhase {
+    Pending,
+    Building,
+    Copying,
+    Swapping,
+    CleaningUp,
+    Done,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStatus {
+    InProgress,
+    Completed,
+    PartialSuccess,
+    Failed,
+    /// Failed before the swap; the live index was left as it was
+    RolledBack,
+}
+
+/// One index's move to a new schema version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexMigration {
+    pub id: String,
+    pub index: String,
+    pub staging_index: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub phase: MigrationPhase,
+    pub status: MigrationStatus,
+    pub documents_total: u64,
+    pub documents_copied: u64,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Payload of the `index-migration` event
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexMigrationReport {
+    pub migrations: Vec<IndexMigration>,
+    /// Indexes created at their current version
+    pub created: Vec<String>,
+}
+
+/// Status of the latest index migration run
+pub async fn get_index_migration_status() -> Result<IndexMigrationReport, String> {
+    invoke_no_args("get_index_migration_status").await
+}
+
+/// Migrate any index whose schema is behind; emits `index-migration` events
+pub async fn migrate_indexes() -> Result<IndexMigrationReport, String> {
+    invoke_no_args("migrate_indexes").await
+}
+
+// ============================================================================
+// Similar Content
+// ============================================================================
+
+/// A chunk similar to another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarChunk {
+    pub id: String,
+    pub content: String,
+    /// Cosine similarity (1.0 = identical)
+    pub score: f32,
+    pub source: String,
+    pub page_number: Option<i32>,
+    pub section_path: Option<String>,
+    pub content_type: String,
+}
+
+/// Find chunks similar to `chunk_id` ("more like this"), optionally only
+/// of some content types or from some library items
+pub async fn find_similar(
+    chunk_id: String,
+    limit: Option<usize>,
+    content_types: Option<Vec<String>>,
+    library_items: Option<Vec<String>>,
+) -> Result<Vec<SimilarChunk>, String> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        chunk_id: String,
+        limit: Option<usize>,
+        content_types: Option<Vec<String>>,
+        library_items: Option<Vec<String>>,
+    }
+    invoke(
+        "find_similar",
+        &Args {
+            chunk_id,
+            limit,
+            content_types,
+            library_items,
+        },
+    )
+    .await
+}
+
+// ============================================================================
+// Duplicate Content
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateKind {
+    Note,
+    Chunk,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateSuggestion {
+    Delete,
+    Merge,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateMember {
+    pub id: String,
+    pub kind: DuplicateKind,
+    pub preview: String,
+    pub source: Option<String>,
+    pub page_number: Option<i32>,
+    /// Cosine similarity to the item kept
+    pub similarity: f32,
+}
+
+/// Near-duplicates of one note or chunk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateCluster {
+    pub kind: DuplicateKind,
+    pub keep: DuplicateMember,
+    pub duplicates: Vec<DuplicateMember>,
+    pub suggestion: DuplicateSuggestion,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateReport {
+    pub clusters: Vec<DuplicateCluster>,
+    pub notes_scanned: usize,
+    pub chunks_scanned: usize,
+    pub skipped: usize,
+    pub threshold: f32,
+}
+
+/// Scan a campaign's notes and the ingested chunks for near-duplicates
+pub async fn find_duplicate_content(
+    campaign_id: Option<String>,
+    include_chunks: Option<bool>,
+    threshold: Option<f32>,
+) -> Result<DuplicateReport, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: Option<String>,
+        include_chunks: Option<bool>,
+        threshold: Option<f32>,
+    }
+    invoke(
+        "find_duplicate_content",
+        &Args {
+            campaign_id,
+            include_chunks,
+            threshold,
+        },
+    )
+    .await
+}
+
 // ============================================================================
 // Embedder Configuration
 // ============================================================================
@@ -135,6 +600,9 @@ pub struct SearchOptions {
     pub source_type: Option<String>,
     pub campaign_id: Option<String>,
     pub index: Option<String>,
+    /// Characters per highlighted snippet. None lets the backend apply its default.
+    #[serde(default)]
+    pub snippet_length: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,6 +613,26 @@ pub struct SearchResultPayload {
     pub page_number: Option<u32>,
     pub score: f32,
     pub index: String,
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
+}
+
+/// An excerpt around matched query terms
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snippet {
+    /// Text before the first match, starting with "…" when cut
+    pub pre_context: String,
+    /// From the first match to the last, with matched terms as their own parts
+    pub parts: Vec<SnippetPart>,
+    /// Text after the last match, ending with "…" when cut
+    pub post_context: String,
+}
+
+/// One piece of a snippet's matched span
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
 }
 
 pub async fn search(
@@ -197,6 +685,12 @@ pub struct HybridSearchOptions {
     pub index: Option<String>,
     pub semantic_weight: Option<f32>,
     pub keyword_weight: Option<f32>,
+    /// Characters per highlighted snippet. None lets the backend apply its default.
+    #[serde(default)]
+    pub snippet_length: Option<usize>,
+    /// Selected facet values keyed by facet field, e.g. "game_system"
+    #[serde(default)]
+    pub facets: std::collections::HashMap<String, Vec<String>>,
 }
 
 /// Hybrid search result
@@ -210,6 +704,28 @@ pub struct HybridSearchResultPayload {
     pub index: String,
     pub keyword_rank: Option<usize>,
     pub semantic_rank: Option<usize>,
+    #[serde(default)]
+    pub overlap_count: Option<usize>,
+    #[serde(default)]
+    pub document_id: Option<String>,
+    #[serde(default)]
+    pub score_breakdown: Option<ScoreBreakdown>,
+    /// Cross-encoder relevance, when re-ranking ordered this result
+    #[serde(default)]
+    pub rerank_score: Option<f32>,
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
+}
+
+/// How a hybrid search score was built
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub semantic_score: f32,
+    pub keyword_score: f32,
+    pub attribute_match_bonus: f32,
+    pub antonym_penalty: f32,
+    pub exact_match_boost: f32,
+    pub final_score: f32,
 }
 
 /// Hybrid search response with metadata
@@ -222,6 +738,27 @@ pub struct HybridSearchResponse {
     pub corrected_query: Option<String>,
     pub processing_time_ms: u64,
     pub hints: Vec<String>,
+    #[serde(default)]
+    pub facets: Vec<Facet>,
+    /// Analytics ID for reporting which result was opened
+    #[serde(default)]
+    pub search_id: Option<String>,
+}
+
+/// A facet's values with hit counts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Facet {
+    /// Facet field key, e.g. "game_system" or "challenge_rating"
+    pub field: String,
+    pub label: String,
+    pub values: Vec<FacetValue>,
+}
+
+/// One facet value and how many hits have it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacetValue {
+    pub value: String,
+    pub count: usize,
 }
 
 /// Query expansion result
@@ -273,8 +810,72 @@ pub async fn hybrid_search(
     invoke("hybrid_search", &Args { query, options }).await
 }
 
-/// Get search suggestions for autocomplete
-pub async fn get_search_suggestions(partial: String) -> Result<Vec<String>, String> {
+/// What a scoped search covers
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum SearchScope {
+    #[default]
+    Library,
+    Campaign {
+        campaign_id: String,
+        include_library: bool,
+    },
+    Session {
+        session_id: String,
+        include_library: bool,
+    },
+}
+
+/// Search the library, a campaign's own content, or a session's
+pub async fn scoped_search(
+    query: String,
+    scope: SearchScope,
+    options: Option<HybridSearchOptions>,
+) -> Result<HybridSearchResponse, String> {
+    #[derive(Serialize)]
+    struct Args {
+        query: String,
+        scope: SearchScope,
+        options: Option<HybridSearchOptions>,
+    }
+    invoke("scoped_search", &Args { query, scope, options }).await
+}
+
+/// Where a search suggestion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionKind {
+    History,
+    Npc,
+    Location,
+    Spell,
+    Monster,
+    Term,
+}
+
+impl SuggestionKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SuggestionKind::History => "Recent",
+            SuggestionKind::Npc => "NPC",
+            SuggestionKind::Location => "Location",
+            SuggestionKind::Spell => "Spell",
+            SuggestionKind::Monster => "Monster",
+            SuggestionKind::Term => "",
+        }
+    }
+}
+
+/// A ranked autocomplete suggestion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchSuggestion {
+    pub text: String,
+    pub kind: SuggestionKind,
+    pub score: f32,
+}
+
+/// Get ranked search suggestions for autocomplete
+pub async fn get_search_suggestions(partial: String) -> Result<Vec<SearchSuggestion>, String> {
     #[derive(Serialize)]
     struct Args {
         partial: String,
@@ -282,6 +883,11 @@ pub async fn get_search_suggestions(partial: String) -> Result<Vec<String>, Stri
     invoke("get_search_suggestions", &Args { partial }).await
 }
 
+/// Rebuild the autocomplete index, returning its entry count
+pub async fn rebuild_suggestion_index() -> Result<usize, String> {
+    invoke_no_args("rebuild_suggestion_index").await
+}
+
 /// Get search hints for a query
 pub async fn get_search_hints(query: String) -> Result<Vec<String>, String> {
     #[derive(Serialize)]
@@ -311,6 +917,671 @@ pub async fn correct_query(query: String) -> Result<CorrectionResult, String> {
 
 // copy_to_clipboard moved to core.rs
 
+// ============================================================================
+// Search Settings
+// ============================================================================
+
+/// Cross-encoder used to re-rank search results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RerankModel {
+    #[default]
+    JinaTurbo,
+    BgeBase,
+    JinaMultilingual,
+}
+
+impl RerankModel {
+    pub const ALL: [RerankModel; 3] = [
+        RerankModel::JinaTurbo,
+        RerankModel::BgeBase,
+        RerankModel::JinaMultilingual,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            RerankModel::JinaTurbo => "jina_turbo",
+            RerankModel::BgeBase => "bge_base",
+            RerankModel::JinaMultilingual => "jina_multilingual",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RerankModel::JinaTurbo => "Jina Turbo (small, English)",
+            RerankModel::BgeBase => "BGE Base",
+            RerankModel::JinaMultilingual => "Jina v2 (multilingual)",
+        }
+    }
+}
+
+/// Cross-encoder re-ranking of the top hybrid search results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RerankSettings {
+    pub enabled: bool,
+    pub model: RerankModel,
+    /// Top fused candidates re-scored (10-200)
+    pub candidates: usize,
+}
+
+impl Default for RerankSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: RerankModel::default(),
+            candidates: 50,
+        }
+    }
+}
+
+/// When queries may match words with typos
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TypoTolerance {
+    pub enabled: bool,
+    /// Shortest word that may match with one typo
+    pub one_typo_min_chars: u8,
+    /// Shortest word that may match with two typos
+    pub two_typos_min_chars: u8,
+    pub disable_on_words: Vec<String>,
+    pub disable_on_attributes: Vec<String>,
+}
+
+impl Default for TypoTolerance {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            one_typo_min_chars: 4,
+            two_typos_min_chars: 8,
+            disable_on_words: Vec::new(),
+            disable_on_attributes: Vec::new(),
+        }
+    }
+}
+
+/// A Meilisearch ranking rule, applied in list order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum RankingRule {
+    Words,
+    Typo,
+    Proximity,
+    Attribute,
+    Sort,
+    Exactness,
+    Asc { attribute: String },
+    Desc { attribute: String },
+}
+
+/// Relevancy settings applied to every library index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexTuning {
+    pub typo_tolerance: TypoTolerance,
+    pub ranking_rules: Vec<RankingRule>,
+    pub stop_words: Vec<String>,
+    pub distinct_attribute: Option<String>,
+}
+
+impl Default for IndexTuning {
+    fn default() -> Self {
+        Self {
+            typo_tolerance: TypoTolerance::default(),
+            ranking_rules: vec![
+                RankingRule::Words,
+                RankingRule::Typo,
+                RankingRule::Proximity,
+                RankingRule::Attribute,
+                RankingRule::Sort,
+                RankingRule::Exactness,
+            ],
+            stop_words: Vec::new(),
+            distinct_attribute: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorIndexKind {
+    Hnsw,
+    Mtree,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorPrecision {
+    F64,
+    F32,
+}
+
+/// Vector index structure, precision, and HNSW parameters
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VectorTuning {
+    pub kind: VectorIndexKind,
+    pub precision: VectorPrecision,
+    pub m: u32,
+    pub efc: u32,
+    pub search_ef: u32,
+    pub mtree_capacity: u32,
+}
+
+impl Default for VectorTuning {
+    fn default() -> Self {
+        Self {
+            kind: VectorIndexKind::Hnsw,
+            precision: VectorPrecision::F64,
+            m: 12,
+            efc: 150,
+            search_ef: 100,
+            mtree_capacity: 40,
+        }
+    }
+}
+
+/// Persisted search settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchSettings {
+    pub rerank: RerankSettings,
+    pub index_tuning: IndexTuning,
+    pub vector_tuning: VectorTuning,
+}
+
+/// Get current search settings
+pub async fn get_search_settings() -> Result<SearchSettings, String> {
+    invoke_no_args("get_search_settings").await
+}
+
+/// Save search settings
+pub async fn save_search_settings(settings: SearchSettings) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        settings: SearchSettings,
+    }
+    invoke_void("save_search_settings", &Args { settings }).await
+}
+
+/// Get the library indexes' typo tolerance, ranking rules, stop words, and
+/// distinct attribute
+pub async fn get_index_tuning() -> Result<IndexTuning, String> {
+    invoke_no_args("get_index_tuning").await
+}
+
+/// Save index tuning and apply it to the existing indexes
+pub async fn update_index_tuning(tuning: IndexTuning) -> Result<IndexTuning, String> {
+    #[derive(Serialize)]
+    struct Args {
+        tuning: IndexTuning,
+    }
+    invoke("update_index_tuning", &Args { tuning }).await
+}
+
+/// Restore the default index tuning
+pub async fn reset_index_tuning() -> Result<IndexTuning, String> {
+    invoke_no_args("reset_index_tuning").await
+}
+
+/// Get the vector index tuning
+pub async fn get_vector_tuning() -> Result<VectorTuning, String> {
+    invoke_no_args("get_vector_tuning").await
+}
+
+/// Save vector tuning; structural changes re-index the embeddings
+pub async fn update_vector_tuning(tuning: VectorTuning) -> Result<VectorTuning, String> {
+    #[derive(Serialize)]
+    struct Args {
+        tuning: VectorTuning,
+    }
+    invoke("update_vector_tuning", &Args { tuning }).await
+}
+
+/// Restore the default vector tuning
+pub async fn reset_vector_tuning() -> Result<VectorTuning, String> {
+    invoke_no_args("reset_vector_tuning").await
+}
+
+// ============================================================================
+// Custom Term Sets
+// ============================================================================
+
+/// Whether a term set holds synonyms or antonyms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermSetKind {
+    Synonym,
+    Antonym,
+}
+
+/// Where a term set applies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum TermSetScope {
+    GameSystem { game_system: String },
+    Campaign { campaign_id: String },
+}
+
+/// A user-defined synonym or antonym set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermSet {
+    pub id: String,
+    pub kind: TermSetKind,
+    pub scope: TermSetScope,
+    pub terms: Vec<String>,
+    /// For an antonym set, the terms opposed to `terms`
+    #[serde(default)]
+    pub opposites: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// List custom term sets, optionally of one kind and for a game system or
+/// campaign
+pub async fn list_term_sets(
+    kind: Option<TermSetKind>,
+    game_system: Option<String>,
+    campaign_id: Option<String>,
+) -> Result<Vec<TermSet>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        kind: Option<TermSetKind>,
+        game_system: Option<String>,
+        campaign_id: Option<String>,
+    }
+    invoke("list_term_sets", &Args { kind, game_system, campaign_id }).await
+}
+
+/// Create a synonym or antonym set
+pub async fn create_term_set(
+    kind: TermSetKind,
+    scope: TermSetScope,
+    terms: Vec<String>,
+    opposites: Option<Vec<String>>,
+) -> Result<TermSet, String> {
+    #[derive(Serialize)]
+    struct Args {
+        kind: TermSetKind,
+        scope: TermSetScope,
+        terms: Vec<String>,
+        opposites: Option<Vec<String>>,
+    }
+    invoke("create_term_set", &Args { kind, scope, terms, opposites }).await
+}
+
+/// Replace a set's terms and opposites, optionally moving it to another scope
+pub async fn update_term_set(
+    id: String,
+    terms: Vec<String>,
+    opposites: Option<Vec<String>>,
+    scope: Option<TermSetScope>,
+) -> Result<TermSet, String> {
+    #[derive(Serialize)]
+    struct Args {
+        id: String,
+        terms: Vec<String>,
+        opposites: Option<Vec<String>>,
+        scope: Option<TermSetScope>,
+    }
+    invoke("update_term_set", &Args { id, terms, opposites, scope }).await
+}
+
+/// Delete a term set
+pub async fn delete_term_set(id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        id: String,
+    }
+    invoke_void("delete_term_set", &Args { id }).await
+}
+
+/// Re-apply the synonym sets to the search indexes; returns the number of
+/// terms with synonyms
+pub async fn sync_search_synonyms() -> Result<usize, String> {
+    invoke_no_args("sync_search_synonyms").await
+}
+
+// ============================================================================
+// Bookmarks
+// ============================================================================
+
+/// A search result or chunk to pin
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NewBookmark {
+    pub index: String,
+    pub document_id: Option<String>,
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub label: Option<String>,
+    pub campaign_id: Option<String>,
+    pub session_id: Option<String>,
+}
+
+/// A pinned passage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: String,
+    pub index: String,
+    pub document_id: Option<String>,
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub label: Option<String>,
+    pub campaign_id: Option<String>,
+    pub session_id: Option<String>,
+    pub created_at: String,
+}
+
+/// Bookmarks sharing a campaign and session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkGroup {
+    pub campaign_id: Option<String>,
+    pub session_id: Option<String>,
+    pub bookmarks: Vec<Bookmark>,
+}
+
+/// A bookmark as shown in the in-session sidebar
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickReferenceItem {
+    pub bookmark_id: String,
+    pub title: String,
+    pub excerpt: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    /// Pinned for this session rather than the whole campaign
+    pub session_only: bool,
+}
+
+/// List bookmarks grouped by campaign and session
+pub async fn list_bookmarks(campaign_id: Option<String>) -> Result<Vec<BookmarkGroup>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: Option<String>,
+    }
+    invoke("list_bookmarks", &Args { campaign_id }).await
+}
+
+/// Pin a search result or chunk
+pub async fn create_bookmark(bookmark: NewBookmark) -> Result<Bookmark, String> {
+    #[derive(Serialize)]
+    struct Args {
+        bookmark: NewBookmark,
+    }
+    invoke("create_bookmark", &Args { bookmark }).await
+}
+
+/// Replace a bookmark's label and its campaign and session
+pub async fn update_bookmark(
+    id: String,
+    label: Option<String>,
+    campaign_id: Option<String>,
+    session_id: Option<String>,
+) -> Result<Bookmark, String> {
+    #[derive(Serialize)]
+    struct Args {
+        id: String,
+        label: Option<String>,
+        campaign_id: Option<String>,
+        session_id: Option<String>,
+    }
+    invoke(
+        "update_bookmark",
+        &Args {
+            id,
+            label,
+            campaign_id,
+            session_id,
+        },
+    )
+    .await
+}
+
+/// Delete a bookmark
+pub async fn delete_bookmark(id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        id: String,
+    }
+    invoke_void("delete_bookmark", &Args { id }).await
+}
+
+/// The in-session sidebar's pinned passages
+pub async fn get_session_quick_reference(session_id: String) -> Result<Vec<QuickReferenceItem>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        session_id: String,
+    }
+    invoke("get_session_quick_reference", &Args { session_id }).await
+}
+
+// ============================================================================
+// Quick Lookup
+// ============================================================================
+
+/// How closely a lookup's name matched, best first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchQuality {
+    Exact,
+    Normalized,
+    Prefix,
+    Partial,
+    Fuzzy,
+    /// Found by full-text search rather than by name
+    Search,
+}
+
+/// Another entry that matched the name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LookupCandidate {
+    pub document_id: String,
+    pub name: String,
+    pub quality: MatchQuality,
+}
+
+/// The best entry for a name, parsed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LookupResult<T> {
+    pub entry: T,
+    pub quality: MatchQuality,
+    pub document_id: Option<String>,
+    pub source: String,
+    pub page_number: Option<i32>,
+    pub game_system: Option<String>,
+    pub alternatives: Vec<LookupCandidate>,
+}
+
+/// A spell entry, parsed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellEntry {
+    pub name: String,
+    /// 0 for cantrips
+    pub level: Option<u8>,
+    pub school: Option<String>,
+    pub casting_time: Option<String>,
+    pub range: Option<String>,
+    pub components: Option<String>,
+    pub duration: Option<String>,
+    pub ritual: bool,
+    pub concentration: bool,
+    pub classes: Vec<String>,
+    pub description: String,
+    pub at_higher_levels: Option<String>,
+}
+
+/// A monster's stat block, parsed. Fields the sidebar doesn't show yet
+/// (speeds, ability scores, actions) are kept as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonsterStatBlock {
+    pub name: String,
+    pub size: Option<String>,
+    pub creature_type: Option<String>,
+    pub alignment: Option<String>,
+    pub armor_class: Option<serde_json::Value>,
+    pub hit_points: Option<serde_json::Value>,
+    pub speed: serde_json::Value,
+    pub ability_scores: serde_json::Value,
+    pub challenge_rating: Option<serde_json::Value>,
+    pub traits: Vec<serde_json::Value>,
+    pub actions: Vec<serde_json::Value>,
+    pub reactions: Vec<serde_json::Value>,
+    pub legendary_actions: Vec<serde_json::Value>,
+}
+
+/// Look up a spell by name, exact matches first
+pub async fn lookup_spell(name: String) -> Result<Option<LookupResult<SpellEntry>>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        name: String,
+    }
+    invoke("lookup_spell", &Args { name }).await
+}
+
+/// Look up a monster by name, exact matches first
+pub async fn lookup_monster(name: String) -> Result<Option<LookupResult<MonsterStatBlock>>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        name: String,
+    }
+    invoke("lookup_monster", &Args { name }).await
+}
+
+// ============================================================================
+// Export to Notes and Handouts
+// ============================================================================
+
+/// What exported search results become
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportTarget {
+    Note,
+    Handout,
+}
+
+/// A search result picked for export
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportedResult {
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    /// Heading shown above the passage instead of the source
+    pub label: Option<String>,
+}
+
+/// The campaign note an export created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedNote {
+    pub id: String,
+    pub campaign_id: String,
+    pub content: String,
+    pub tags: Vec<String>,
+    pub session_number: Option<u32>,
+}
+
+/// The handout an export created; it starts hidden
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedHandout {
+    pub id: String,
+    pub campaign_id: String,
+    pub session_id: Option<String>,
+    pub title: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "target", rename_all = "snake_case")]
+pub enum ExportedItem {
+    Note { note: ExportedNote },
+    Handout { handout: ExportedHandout },
+}
+
+/// Save selected search results, with citations, as a campaign note or handout
+pub async fn export_search_results(
+    campaign_id: String,
+    title: String,
+    results: Vec<ExportedResult>,
+    target: ExportTarget,
+    session_id: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<ExportedItem, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        title: String,
+        results: Vec<ExportedResult>,
+        target: ExportTarget,
+        session_id: Option<String>,
+        tags: Option<Vec<String>>,
+    }
+    invoke(
+        "export_search_results",
+        &Args {
+            campaign_id,
+            title,
+            results,
+            target,
+            session_id,
+            tags,
+        },
+    )
+    .await
+}
+
+// ============================================================================
+// Rules Q&A
+// ============================================================================
+
+/// Whether a rules question was answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesAnswerStatus {
+    Answered,
+    Refused,
+}
+
+/// A cited rulebook passage, numbered as it appears in the answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesFootnote {
+    pub number: usize,
+    pub chunk_id: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub quote: Option<String>,
+}
+
+/// A cited rules answer, or a refusal with its reason
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesAnswer {
+    pub question: String,
+    pub game_system: String,
+    pub status: RulesAnswerStatus,
+    /// Answer text with `[n]` footnote markers
+    pub answer: String,
+    pub footnotes: Vec<RulesFootnote>,
+    pub confidence: f32,
+    pub retrieval_score: f32,
+    pub refusal_reason: Option<String>,
+}
+
+/// Answer a rules question from the campaign system's rulebooks, with citations
+pub async fn ask_rules(
+    campaign_id: String,
+    question: String,
+    limit: Option<usize>,
+) -> Result<RulesAnswer, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        question: String,
+        limit: Option<usize>,
+    }
+    invoke("ask_rules", &Args { campaign_id, question, limit }).await
+}
+
 // ============================================================================
 // Search Analytics
 // ============================================================================
@@ -328,6 +1599,46 @@ pub struct SearchAnalyticsSummary {
     pub by_search_type: std::collections::HashMap<String, u32>,
     pub period_start: String,
     pub period_end: String,
+    #[serde(default)]
+    pub trends: SearchTrends,
+}
+
+/// Search volume, zero results, click-through, and latency over time
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SearchTrends {
+    pub bucket_minutes: i64,
+    pub buckets: Vec<AnalyticsBucket>,
+    pub latency_histogram: LatencyHistogram,
+    /// Zero-result queries, most searched first
+    pub missing_content: Vec<MissingContentQuery>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsBucket {
+    pub start: String,
+    pub searches: u32,
+    pub zero_result_searches: u32,
+    pub clicked_searches: u32,
+    pub click_through_rate: f64,
+    pub avg_latency_ms: f64,
+    pub p95_latency_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LatencyHistogram {
+    /// Upper bound of each bucket but the last (ms)
+    pub bounds_ms: Vec<u64>,
+    /// Searches per bucket, one more than there are bounds
+    pub counts: Vec<u32>,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingContentQuery {
+    pub query: String,
+    pub count: u32,
+    pub last_searched: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -349,12 +1660,16 @@ pub struct CacheStats {
     pub top_cached_queries: Vec<(String, u32)>,
 }
 
-pub async fn get_search_analytics(hours: i64) -> Result<SearchAnalyticsSummary, String> {
+pub async fn get_search_analytics(
+    hours: i64,
+    bucket_minutes: Option<i64>,
+) -> Result<SearchAnalyticsSummary, String> {
     #[derive(Serialize)]
     struct Args {
         hours: i64,
+        bucket_minutes: Option<i64>,
     }
-    invoke("get_search_analytics", &Args { hours }).await
+    invoke("get_search_analytics", &Args { hours, bucket_minutes }).await
 }
 
 pub async fn get_popular_queries(limit: usize) -> Result<Vec<PopularQuery>, String> {
@@ -419,12 +1734,16 @@ pub async fn record_search_selection(
 
 // --- Database-Backed Analytics (Persistent) ---
 
-pub async fn get_search_analytics_db(hours: i64) -> Result<SearchAnalyticsSummary, String> {
+pub async fn get_search_analytics_db(
+    hours: i64,
+    bucket_minutes: Option<i64>,
+) -> Result<SearchAnalyticsSummary, String> {
     #[derive(Serialize)]
     struct Args {
         hours: i64,
+        bucket_minutes: Option<i64>,
     }
-    invoke("get_search_analytics_db", &Args { hours }).await
+    invoke("get_search_analytics_db", &Args { hours, bucket_minutes }).await
 }
 
 pub async fn get_popular_queries_db(limit: usize) -> Result<Vec<PopularQuery>, String> {
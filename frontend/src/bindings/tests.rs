@@ -70,6 +70,9 @@ mod tests {
             combatant_type: "npc".to_string(),
             conditions: vec!["prone".to_string()],
             is_active: true,
+            group: None,
+            delaying: false,
+            readied: None,
         };
 
         let state = CombatState {
@@ -116,6 +119,7 @@ mod tests {
             backstory: None,
             notes: "".to_string(),
             portrait_prompt: None,
+            derived_stats: vec![],
         };
 
         let json = serde_json::to_value(&char).unwrap();
@@ -369,6 +369,8 @@ pub struct NpcConversation {
     pub last_message_at: String,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub summarized_count: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -429,6 +431,178 @@ pub async fn mark_npc_read(npc_id: String) -> Result<(), String> {
     invoke_void("mark_npc_read", &Args { npc_id }).await
 }
 
+// ============================================================================
+// NPC Memory
+// ============================================================================
+
+/// A conversation summary or notable fact an NPC remembers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NpcMemory {
+    pub id: String,
+    pub npc_id: String,
+    pub campaign_id: String,
+    /// "summary" or "fact"
+    pub kind: String,
+    pub content: String,
+    /// 1 (trivia) to 5 (life-changing)
+    pub importance: i32,
+    pub source_message_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub async fn list_npc_memories(npc_id: String) -> Result<Vec<NpcMemory>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+    }
+    invoke("list_npc_memories", &Args { npc_id }).await
+}
+
+pub async fn add_npc_memory(
+    npc_id: String,
+    content: String,
+    kind: Option<String>,
+    importance: Option<i32>,
+) -> Result<NpcMemory, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+        content: String,
+        kind: Option<String>,
+        importance: Option<i32>,
+    }
+    invoke(
+        "add_npc_memory",
+        &Args {
+            npc_id,
+            content,
+            kind,
+            importance,
+        },
+    )
+    .await
+}
+
+pub async fn update_npc_memory(
+    memory_id: String,
+    content: Option<String>,
+    importance: Option<i32>,
+) -> Result<NpcMemory, String> {
+    #[derive(Serialize)]
+    struct Args {
+        memory_id: String,
+        content: Option<String>,
+        importance: Option<i32>,
+    }
+    invoke(
+        "update_npc_memory",
+        &Args {
+            memory_id,
+            content,
+            importance,
+        },
+    )
+    .await
+}
+
+pub async fn forget_npc_memory(memory_id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        memory_id: String,
+    }
+    invoke_void("forget_npc_memory", &Args { memory_id }).await
+}
+
+pub async fn forget_all_npc_memories(npc_id: String) -> Result<u64, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+    }
+    invoke("forget_all_npc_memories", &Args { npc_id }).await
+}
+
+/// Summarize the NPC's conversation into memories now
+pub async fn summarize_npc_conversation(npc_id: String) -> Result<Vec<NpcMemory>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+    }
+    invoke("summarize_npc_conversation", &Args { npc_id }).await
+}
+
+// ============================================================================
+// NPC Dispositions
+// ============================================================================
+
+/// How an NPC feels about the party (`target_id` "party") or one PC.
+/// `tier` is "hostile", "unfriendly", "indifferent", "friendly", or "devoted".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyDisposition {
+    pub npc_id: String,
+    pub target_id: String,
+    pub score: i32,
+    pub tier: String,
+    pub familiarity: u8,
+    pub recent_interactions: Vec<InteractionRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DispositionChange {
+    pub npc_id: String,
+    pub target_id: String,
+    pub before: i32,
+    pub after: i32,
+    pub tier_before: String,
+    pub tier_after: String,
+}
+
+pub async fn get_npc_dispositions(campaign_id: String, npc_id: String) -> Result<Vec<PartyDisposition>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        npc_id: String,
+    }
+    invoke("get_npc_dispositions", &Args { campaign_id, npc_id }).await
+}
+
+/// Log an interaction with the party, or with one PC when `pc_id` is set.
+/// `kind` is "helped", "gift", "persuaded", "paid", "insulted",
+/// "threatened", "harmed", "betrayed", or "other".
+pub async fn log_npc_interaction(
+    campaign_id: String,
+    npc_id: String,
+    kind: String,
+    description: String,
+    change: Option<i32>,
+    pc_id: Option<String>,
+    session_number: Option<u32>,
+) -> Result<Vec<DispositionChange>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        npc_id: String,
+        kind: String,
+        description: String,
+        change: Option<i32>,
+        pc_id: Option<String>,
+        session_number: Option<u32>,
+    }
+    invoke(
+        "log_npc_interaction",
+        &Args {
+            campaign_id,
+            npc_id,
+            kind,
+            description,
+            change,
+            pc_id,
+            session_number,
+        },
+    )
+    .await
+}
+
 // ============================================================================
 // NPC Types & Commands
 // ============================================================================
@@ -442,6 +616,8 @@ pub struct NPC {
     pub personality: NPCPersonality,
     pub voice: VoiceDescription,
     pub stats: Option<Character>,
+    #[serde(default)]
+    pub stat_block: Option<serde_json::Value>,
     pub relationships: Vec<NPCRelationship>,
     pub secrets: Vec<String>,
     pub hooks: Vec<PlotHook>,
@@ -481,6 +657,43 @@ pub struct VoiceDescription {
     pub accent: Option<String>,
     pub vocabulary: String,
     pub sample_phrases: Vec<String>,
+    #[serde(default)]
+    pub quirks: Option<SpeechQuirks>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Respelling {
+    pub standard: String,
+    pub written: String,
+    pub spoken: String,
+}
+
+/// Catchphrases, accent respellings, and word choices an NPC keeps to in
+/// chat and when spoken aloud
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeechQuirks {
+    #[serde(default)]
+    pub dialect_id: Option<String>,
+    /// "light", "moderate", or "heavy"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intensity: Option<String>,
+    /// "formal", "casual", or "hostile"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formality: Option<String>,
+    #[serde(default)]
+    pub catchphrases: Vec<String>,
+    #[serde(default)]
+    pub respellings: Vec<Respelling>,
+    #[serde(default)]
+    pub favored_words: Vec<String>,
+    #[serde(default)]
+    pub avoided_words: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeechPreview {
+    pub written: String,
+    pub spoken: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -514,6 +727,21 @@ pub struct NPCGenerationOptions {
     pub personality_depth: String,
     pub include_hooks: bool,
     pub include_secrets: bool,
+    #[serde(default)]
+    pub stat_block: Option<StatBlockTarget>,
+    /// Name bank culture ID; falls back to the campaign default
+    #[serde(default)]
+    pub name_culture: Option<String>,
+}
+
+/// How strong a generated NPC's combat stat block should be
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatBlockTarget {
+    /// D&D 5e challenge rating (1/8 is 0.125)
+    ChallengeRating(f32),
+    /// Pathfinder 2e creature level
+    CreatureLevel(i32),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -595,176 +823,520 @@ pub async fn reply_as_npc(npc_id: String) -> Result<ConversationMessage, String>
     invoke("reply_as_npc", &Args { npc_id }).await
 }
 
-// ============================================================================
-// Entity Relationships & Graphs
-// ============================================================================
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EntityRelationship {
-    pub id: String,
-    pub campaign_id: String,
-    pub source_id: String,
-    pub source_type: String,
-    pub source_name: String,
-    pub target_id: String,
-    pub target_type: String,
-    pub target_name: String,
-    pub relationship_type: String,
-    pub strength: String,
-    pub is_active: bool,
-    pub is_known: bool,
-    pub description: String,
-    pub started_at: Option<String>,
-    pub ended_at: Option<String>,
-    pub tags: Vec<String>,
-    pub metadata: std::collections::HashMap<String, serde_json::Value>,
-    pub created_at: String,
-    pub updated_at: String,
+/// Settings for an NPC roster; `kind` is "thieves_guild", "city_watch",
+/// "cult", "merchant_house", "mercenary_company", "noble_household", or
+/// "generic"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NpcBatchOptions {
+    pub count: usize,
+    pub kind: String,
+    pub group_name: Option<String>,
+    pub system: Option<String>,
+    pub name_culture: Option<String>,
+    pub location: Option<String>,
+    pub dialect_id: Option<String>,
+    pub vocabulary_bank_id: Option<String>,
+}
+
+impl Default for NpcBatchOptions {
+    fn default() -> Self {
+        Self {
+            count: 6,
+            kind: "generic".to_string(),
+            group_name: None,
+            system: None,
+            name_culture: None,
+            location: None,
+            dialect_id: None,
+            vocabulary_bank_id: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RelationshipSummary {
-    pub id: String,
-    pub source_id: String,
-    pub source_name: String,
-    pub source_type: String,
-    pub target_id: String,
-    pub target_name: String,
-    pub target_type: String,
-    pub relationship_type: String,
-    pub strength: String,
-    pub is_active: bool,
+pub struct RosterMember {
+    pub npc: NPC,
+    /// "leader", "lieutenant", or "member"
+    pub rank: String,
+    pub title: String,
+    pub reports_to: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EntityGraph {
-    pub nodes: Vec<GraphNode>,
-    pub edges: Vec<GraphEdge>,
-    pub stats: GraphStats,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedVoice {
+    pub accent: String,
+    pub dialect_id: Option<String>,
+    pub vocabulary_bank_id: Option<String>,
+    pub vocabulary: String,
+    pub phrases: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GraphNode {
-    pub id: String,
-    pub name: String,
-    pub entity_type: String,
-    pub color: String,
-    pub connection_count: usize,
-    pub is_hub: bool,
-    pub data: std::collections::HashMap<String, serde_json::Value>,
+pub struct NpcBatch {
+    pub group_name: String,
+    pub kind: String,
+    pub members: Vec<RosterMember>,
+    pub voice: SharedVoice,
+    pub ties: Vec<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GraphEdge {
-    pub id: String,
-    pub source: String,
-    pub target: String,
-    pub label: String,
-    pub strength: u8,
-    pub bidirectional: bool,
-    pub is_active: bool,
-    pub color: String,
+pub struct GeneratedNpcBatch {
+    pub batch: NpcBatch,
+    pub faction: Option<serde_json::Value>,
+    pub relationships: Vec<EntityRelationship>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct GraphStats {
-    pub node_count: usize,
-    pub edge_count: usize,
-    pub entity_type_counts: std::collections::HashMap<String, usize>,
-    pub relationship_type_counts: std::collections::HashMap<String, usize>,
-    pub most_connected_entities: Vec<(String, usize)>,
-}
-
-pub async fn create_entity_relationship(
+/// Generate a roster of related NPCs and add it to a faction and location
+pub async fn generate_npc_batch(
     campaign_id: String,
-    source_id: String,
-    source_type: String,
-    source_name: String,
-    target_id: String,
-    target_type: String,
-    target_name: String,
-    relationship_type: String,
-    strength: Option<String>,
-    description: Option<String>,
-) -> Result<EntityRelationship, String> {
+    options: Option<NpcBatchOptions>,
+    faction_id: Option<String>,
+    location_id: Option<String>,
+    create_faction: Option<bool>,
+) -> Result<GeneratedNpcBatch, String> {
     #[derive(Serialize)]
     struct Args {
         campaign_id: String,
-        source_id: String,
-        source_type: String,
-        source_name: String,
-        target_id: String,
-        target_type: String,
-        target_name: String,
-        relationship_type: String,
-        strength: Option<String>,
-        description: Option<String>,
+        options: Option<NpcBatchOptions>,
+        faction_id: Option<String>,
+        location_id: Option<String>,
+        create_faction: Option<bool>,
     }
     invoke(
-        "create_entity_relationship",
+        "generate_npc_batch",
         &Args {
             campaign_id,
-            source_id,
-            source_type,
-            source_name,
-            target_id,
-            target_type,
-            target_name,
-            relationship_type,
-            strength,
-            description,
+            options,
+            faction_id,
+            location_id,
+            create_faction,
         },
     )
     .await
 }
 
-pub async fn get_entity_relationship(
-    campaign_id: String,
-    relationship_id: String,
-) -> Result<Option<EntityRelationship>, String> {
+/// Re-theme a stat block ("frost-themed cultist"), keeping its numbers
+pub async fn reskin_stat_block(
+    stat_block: serde_json::Value,
+    theme: String,
+    name: Option<String>,
+    damage_type: Option<String>,
+) -> Result<serde_json::Value, String> {
     #[derive(Serialize)]
     struct Args {
-        campaign_id: String,
-        relationship_id: String,
+        stat_block: serde_json::Value,
+        theme: String,
+        name: Option<String>,
+        damage_type: Option<String>,
     }
     invoke(
-        "get_entity_relationship",
+        "reskin_stat_block",
         &Args {
-            campaign_id,
-            relationship_id,
+            stat_block,
+            theme,
+            name,
+            damage_type,
         },
     )
     .await
 }
 
-pub async fn update_entity_relationship(relationship: EntityRelationship) -> Result<(), String> {
-    #[derive(Serialize)]
-    struct Args {
-        relationship: EntityRelationship,
-    }
-    invoke_void("update_entity_relationship", &Args { relationship }).await
+// ============================================================================
+// Name Banks
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NameBankSummary {
+    pub culture_id: String,
+    pub culture_name: String,
+    pub description: String,
+    /// "builtin", "custom", or "trained"
+    pub kind: String,
+    pub tags: Vec<String>,
 }
 
-pub async fn delete_entity_relationship(
-    campaign_id: String,
-    relationship_id: String,
-) -> Result<(), String> {
+/// Names to train a Markov name bank on
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrainedNameBank {
+    pub culture_id: String,
+    pub culture_name: String,
+    pub description: String,
+    /// Letters of context per step (1-4, default 3); higher stays closer to
+    /// the source names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<usize>,
+    pub names: Vec<String>,
+    pub family_names: Vec<String>,
+}
+
+pub async fn list_name_banks() -> Result<Vec<NameBankSummary>, String> {
+    invoke_no_args("list_name_banks").await
+}
+
+/// Generate names from a culture bank, or the campaign's default culture
+pub async fn generate_names(
+    culture_id: Option<String>,
+    campaign_id: Option<String>,
+    gender: Option<String>,
+    count: Option<usize>,
+) -> Result<Vec<String>, String> {
     #[derive(Serialize)]
     struct Args {
-        campaign_id: String,
-        relationship_id: String,
+        culture_id: Option<String>,
+        campaign_id: Option<String>,
+        gender: Option<String>,
+        count: Option<usize>,
     }
-    invoke_void(
-        "delete_entity_relationship",
+    invoke(
+        "generate_names",
         &Args {
+            culture_id,
             campaign_id,
-            relationship_id,
+            gender,
+            count,
         },
     )
     .await
 }
 
-pub async fn list_entity_relationships(
+pub async fn train_name_bank(bank: TrainedNameBank) -> Result<NameBankSummary, String> {
+    #[derive(Serialize)]
+    struct Args {
+        bank: TrainedNameBank,
+    }
+    invoke("train_name_bank", &Args { bank }).await
+}
+
+pub async fn delete_name_bank(culture_id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        culture_id: String,
+    }
+    invoke_void("delete_name_bank", &Args { culture_id }).await
+}
+
+pub async fn set_campaign_name_culture(
+    campaign_id: String,
+    culture_id: Option<String>,
+) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        culture_id: Option<String>,
+    }
+    invoke_void(
+        "set_campaign_name_culture",
+        &Args {
+            campaign_id,
+            culture_id,
+        },
+    )
+    .await
+}
+
+// ============================================================================
+// Speech Quirks
+// ============================================================================
+
+/// Generate and save an NPC's speech quirks from a dialect definition
+pub async fn generate_npc_speech_quirks(
+    npc_id: String,
+    dialect: Option<serde_json::Value>,
+    intensity: Option<String>,
+) -> Result<SpeechQuirks, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+        dialect: Option<serde_json::Value>,
+        intensity: Option<String>,
+    }
+    invoke(
+        "generate_npc_speech_quirks",
+        &Args {
+            npc_id,
+            dialect,
+            intensity,
+        },
+    )
+    .await
+}
+
+pub async fn set_npc_speech_quirks(npc_id: String, quirks: Option<SpeechQuirks>) -> Result<NPC, String> {
+    #[derive(Serialize)]
+    struct Args {
+        npc_id: String,
+        quirks: Option<SpeechQuirks>,
+    }
+    invoke("set_npc_speech_quirks", &Args { npc_id, quirks }).await
+}
+
+pub async fn preview_npc_speech(quirks: SpeechQuirks, text: String) -> Result<SpeechPreview, String> {
+    #[derive(Serialize)]
+    struct Args {
+        quirks: SpeechQuirks,
+        text: String,
+    }
+    invoke("preview_npc_speech", &Args { quirks, text }).await
+}
+
+// ============================================================================
+// Archetype Packs
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackDependency {
+    pub id: String,
+    /// `*`, `1.2.0`, `>=1.2.0`, `^1.2.0`, or `~1.2.0`
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PackManifest {
+    #[serde(default)]
+    pub format_version: u32,
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub game_system: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_app_version: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<PackDependency>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PackContents {
+    pub archetypes: usize,
+    pub vocabulary_banks: usize,
+    pub name_banks: usize,
+    pub personality_templates: usize,
+    pub has_setting: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchetypePackImportResult {
+    pub pack_id: String,
+    pub version: String,
+    pub version_key: String,
+    pub contents: PackContents,
+    /// Archetypes and vocabulary banks that replaced existing ones
+    pub replaced: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportArchetypePackRequest {
+    pub manifest: PackManifest,
+    pub archetype_ids: Vec<String>,
+    pub vocabulary_bank_ids: Vec<String>,
+    pub name_bank_ids: Vec<String>,
+    pub personality_template_ids: Vec<String>,
+    pub include_setting: bool,
+}
+
+/// Import an archetype pack (JSON file or zip archive)
+pub async fn import_archetype_pack(path: String) -> Result<ArchetypePackImportResult, String> {
+    #[derive(Serialize)]
+    struct Args {
+        path: String,
+    }
+    invoke("import_archetype_pack", &Args { path }).await
+}
+
+/// Export an archetype pack; writes JSON when `path` ends in `.json`,
+/// otherwise a zip archive
+pub async fn export_archetype_pack(
+    request: ExportArchetypePackRequest,
+    path: String,
+) -> Result<PackContents, String> {
+    #[derive(Serialize)]
+    struct Args {
+        request: ExportArchetypePackRequest,
+        path: String,
+    }
+    invoke("export_archetype_pack", &Args { request, path }).await
+}
+
+// ============================================================================
+// Entity Relationships & Graphs
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityRelationship {
+    pub id: String,
+    pub campaign_id: String,
+    pub source_id: String,
+    pub source_type: String,
+    pub source_name: String,
+    pub target_id: String,
+    pub target_type: String,
+    pub target_name: String,
+    pub relationship_type: String,
+    pub strength: String,
+    pub is_active: bool,
+    pub is_known: bool,
+    pub description: String,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata: std::collections::HashMap<String, serde_json::Value>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipSummary {
+    pub id: String,
+    pub source_id: String,
+    pub source_name: String,
+    pub source_type: String,
+    pub target_id: String,
+    pub target_name: String,
+    pub target_type: String,
+    pub relationship_type: String,
+    pub strength: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    pub stats: GraphStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub name: String,
+    pub entity_type: String,
+    pub color: String,
+    pub connection_count: usize,
+    pub is_hub: bool,
+    pub data: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub id: String,
+    pub source: String,
+    pub target: String,
+    pub label: String,
+    pub strength: u8,
+    pub bidirectional: bool,
+    pub is_active: bool,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GraphStats {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub entity_type_counts: std::collections::HashMap<String, usize>,
+    pub relationship_type_counts: std::collections::HashMap<String, usize>,
+    pub most_connected_entities: Vec<(String, usize)>,
+}
+
+pub async fn create_entity_relationship(
+    campaign_id: String,
+    source_id: String,
+    source_type: String,
+    source_name: String,
+    target_id: String,
+    target_type: String,
+    target_name: String,
+    relationship_type: String,
+    strength: Option<String>,
+    description: Option<String>,
+) -> Result<EntityRelationship, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        source_id: String,
+        source_type: String,
+        source_name: String,
+        target_id: String,
+        target_type: String,
+        target_name: String,
+        relationship_type: String,
+        strength: Option<String>,
+        description: Option<String>,
+    }
+    invoke(
+        "create_entity_relationship",
+        &Args {
+            campaign_id,
+            source_id,
+            source_type,
+            source_name,
+            target_id,
+            target_type,
+            target_name,
+            relationship_type,
+            strength,
+            description,
+        },
+    )
+    .await
+}
+
+pub async fn get_entity_relationship(
+    campaign_id: String,
+    relationship_id: String,
+) -> Result<Option<EntityRelationship>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        relationship_id: String,
+    }
+    invoke(
+        "get_entity_relationship",
+        &Args {
+            campaign_id,
+            relationship_id,
+        },
+    )
+    .await
+}
+
+pub async fn update_entity_relationship(relationship: EntityRelationship) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        relationship: EntityRelationship,
+    }
+    invoke_void("update_entity_relationship", &Args { relationship }).await
+}
+
+pub async fn delete_entity_relationship(
+    campaign_id: String,
+    relationship_id: String,
+) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        relationship_id: String,
+    }
+    invoke_void(
+        "delete_entity_relationship",
+        &Args {
+            campaign_id,
+            relationship_id,
+        },
+    )
+    .await
+}
+
+pub async fn list_entity_relationships(
     campaign_id: String,
 ) -> Result<Vec<RelationshipSummary>, String> {
     #[derive(Serialize)]
@@ -1225,3 +1797,799 @@ pub async fn clear_session_personality_context(session_id: String) -> Result<(),
     )
     .await
 }
+
+// ============================================================================
+// Shops
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShopItem {
+    pub name: String,
+    pub document_id: Option<String>,
+    pub category: String,
+    pub rarity: Option<String>,
+    /// Asking price for one, in the system's smallest coin
+    pub price: i64,
+    /// The asking price as change, e.g. "15 gp 5 sp"
+    pub price_text: String,
+    pub quantity: u32,
+    pub max_quantity: u32,
+    #[serde(default)]
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shop {
+    pub id: String,
+    pub campaign_id: String,
+    pub location_id: String,
+    pub name: String,
+    /// "general", "blacksmith", "armorer", "alchemist", "magic", "jeweler",
+    /// "bookseller", or "outfitter"
+    pub shop_type: String,
+    /// "hamlet", "village", "town", "city", or "metropolis"
+    pub settlement_size: String,
+    pub system: String,
+    pub items: Vec<ShopItem>,
+    pub restock_interval_days: u32,
+    pub last_restocked: Option<serde_json::Value>,
+    pub restock_day: Option<i64>,
+    #[serde(default)]
+    pub notes: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestockReport {
+    pub shop_id: String,
+    pub deliveries: u32,
+    pub replenished: Vec<String>,
+    pub removed: Vec<String>,
+    pub added: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShopPurchase {
+    pub shop_id: String,
+    pub item: ShopItem,
+    pub quantity: u32,
+    pub total_price: i64,
+    pub total_text: String,
+}
+
+pub async fn generate_shop(
+    campaign_id: String,
+    location_id: String,
+    shop_type: String,
+    settlement_size: Option<String>,
+    name: Option<String>,
+) -> Result<Shop, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        location_id: String,
+        shop_type: String,
+        settlement_size: Option<String>,
+        name: Option<String>,
+    }
+    invoke(
+        "generate_shop",
+        &Args {
+            campaign_id,
+            location_id,
+            shop_type,
+            settlement_size,
+            name,
+        },
+    )
+    .await
+}
+
+pub async fn list_location_shops(location_id: String) -> Result<Vec<Shop>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        location_id: String,
+    }
+    invoke("list_location_shops", &Args { location_id }).await
+}
+
+pub async fn list_campaign_shops(campaign_id: String) -> Result<Vec<Shop>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+    }
+    invoke("list_campaign_shops", &Args { campaign_id }).await
+}
+
+pub async fn update_shop(shop: Shop) -> Result<Shop, String> {
+    #[derive(Serialize)]
+    struct Args {
+        shop: Shop,
+    }
+    invoke("update_shop", &Args { shop }).await
+}
+
+pub async fn delete_shop(shop_id: String) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Args {
+        shop_id: String,
+    }
+    invoke_void("delete_shop", &Args { shop_id }).await
+}
+
+/// Buy from a shop; with `account` ({"type": "party"} or a character
+/// account), the price is paid from the party treasury, and with
+/// `recipient` the items go into that inventory
+pub async fn buy_from_shop(
+    shop_id: String,
+    item_name: String,
+    quantity: Option<u32>,
+    account: Option<serde_json::Value>,
+    recipient: Option<InventoryOwner>,
+) -> Result<ShopPurchase, String> {
+    #[derive(Serialize)]
+    struct Args {
+        shop_id: String,
+        item_name: String,
+        quantity: Option<u32>,
+        account: Option<serde_json::Value>,
+        recipient: Option<InventoryOwner>,
+    }
+    invoke(
+        "buy_from_shop",
+        &Args {
+            shop_id,
+            item_name,
+            quantity,
+            account,
+            recipient,
+        },
+    )
+    .await
+}
+
+/// Restock every shop in a campaign by the in-game days passed
+pub async fn restock_campaign_shops(campaign_id: String) -> Result<Vec<RestockReport>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+    }
+    invoke("restock_campaign_shops", &Args { campaign_id }).await
+}
+
+// ============================================================================
+// Inventory
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InventoryOwner {
+    Party,
+    Character { character_id: String },
+    Npc { npc_id: String },
+    Combatant { combatant_id: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerSpec {
+    pub capacity: f64,
+    #[serde(default)]
+    pub weightless: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub document_id: Option<String>,
+    #[serde(default)]
+    pub category: String,
+    pub quantity: u32,
+    /// Weight of one, in pounds or Bulk
+    #[serde(default)]
+    pub weight: f64,
+    /// Worth of one, in the system's smallest coin
+    #[serde(default)]
+    pub value: i64,
+    #[serde(default)]
+    pub requires_attunement: bool,
+    #[serde(default)]
+    pub attuned: bool,
+    #[serde(default)]
+    pub equipped: bool,
+    #[serde(default)]
+    pub container: Option<ContainerSpec>,
+    #[serde(default)]
+    pub container_id: Option<String>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    pub campaign_id: String,
+    pub owner: InventoryOwner,
+    pub items: Vec<InventoryItem>,
+    #[serde(default)]
+    pub strength: Option<i32>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncumbranceStatus {
+    pub carried: f64,
+    pub unit: String,
+    /// "unencumbered", "encumbered", "heavily_encumbered", or "over_capacity"
+    pub level: String,
+    pub encumbered_at: f64,
+    pub heavily_encumbered_at: Option<f64>,
+    pub capacity: f64,
+    pub effects: String,
+    pub attuned: usize,
+    pub attunement_limit: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemTransfer {
+    pub from: InventoryOwner,
+    pub to: InventoryOwner,
+    pub items: Vec<InventoryItem>,
+}
+
+pub async fn get_inventory(campaign_id: String, owner: InventoryOwner) -> Result<Inventory, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+    }
+    invoke("get_inventory", &Args { campaign_id, owner }).await
+}
+
+pub async fn add_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item: InventoryItem,
+) -> Result<InventoryItem, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+        item: InventoryItem,
+    }
+    invoke("add_inventory_item", &Args { campaign_id, owner, item }).await
+}
+
+pub async fn remove_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+) -> Result<Vec<InventoryItem>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+        item_id: String,
+        quantity: Option<u32>,
+    }
+    invoke(
+        "remove_inventory_item",
+        &Args {
+            campaign_id,
+            owner,
+            item_id,
+            quantity,
+        },
+    )
+    .await
+}
+
+/// Put an item in a container, or take it out with no `container_id`
+pub async fn stow_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    container_id: Option<String>,
+) -> Result<Inventory, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+        item_id: String,
+        container_id: Option<String>,
+    }
+    invoke(
+        "stow_inventory_item",
+        &Args {
+            campaign_id,
+            owner,
+            item_id,
+            container_id,
+        },
+    )
+    .await
+}
+
+pub async fn set_item_attunement(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    attuned: bool,
+) -> Result<InventoryItem, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+        item_id: String,
+        attuned: bool,
+    }
+    invoke(
+        "set_item_attunement",
+        &Args {
+            campaign_id,
+            owner,
+            item_id,
+            attuned,
+        },
+    )
+    .await
+}
+
+pub async fn get_encumbrance(campaign_id: String, owner: InventoryOwner) -> Result<EncumbranceStatus, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+    }
+    invoke("get_encumbrance", &Args { campaign_id, owner }).await
+}
+
+pub async fn transfer_inventory_item(
+    campaign_id: String,
+    from: InventoryOwner,
+    to: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+    container_id: Option<String>,
+) -> Result<ItemTransfer, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        from: InventoryOwner,
+        to: InventoryOwner,
+        item_id: String,
+        quantity: Option<u32>,
+        container_id: Option<String>,
+    }
+    invoke(
+        "transfer_inventory_item",
+        &Args {
+            campaign_id,
+            from,
+            to,
+            item_id,
+            quantity,
+            container_id,
+        },
+    )
+    .await
+}
+
+/// Take everything a defeated combatant carried (default: into the party stash)
+pub async fn loot_combatant(
+    campaign_id: String,
+    combatant_id: String,
+    to: Option<InventoryOwner>,
+) -> Result<Vec<ItemTransfer>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        combatant_id: String,
+        to: Option<InventoryOwner>,
+    }
+    invoke(
+        "loot_combatant",
+        &Args {
+            campaign_id,
+            combatant_id,
+            to,
+        },
+    )
+    .await
+}
+
+/// Sell items to a shop at its resale price; with `account`, the money goes
+/// into the party treasury
+pub async fn sell_to_shop(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+    shop_id: String,
+    account: Option<serde_json::Value>,
+) -> Result<ShopPurchase, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        owner: InventoryOwner,
+        item_id: String,
+        quantity: Option<u32>,
+        shop_id: String,
+        account: Option<serde_json::Value>,
+    }
+    invoke(
+        "sell_to_shop",
+        &Args {
+            campaign_id,
+            owner,
+            item_id,
+            quantity,
+            shop_id,
+            account,
+        },
+    )
+    .await
+}
+
+// ============================================================================
+// Spellcasting
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Caster {
+    Character { character_id: String },
+    Npc { npc_id: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotPool {
+    pub level: u8,
+    pub total: u32,
+    #[serde(default)]
+    pub expended: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnownSpell {
+    pub id: String,
+    pub name: String,
+    /// 0 for cantrips
+    pub level: u8,
+    #[serde(default)]
+    pub school: Option<String>,
+    #[serde(default)]
+    pub prepared: bool,
+    #[serde(default)]
+    pub always_prepared: bool,
+    #[serde(default)]
+    pub ritual: bool,
+    #[serde(default)]
+    pub concentration: bool,
+    /// Ingested rulebook entry, for `lookup_known_spell`
+    #[serde(default)]
+    pub document_id: Option<String>,
+    #[serde(default)]
+    pub source_document_id: Option<String>,
+    #[serde(default)]
+    pub page_number: Option<i32>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Spellbook {
+    pub campaign_id: String,
+    pub caster: Caster,
+    #[serde(default)]
+    pub ability: Option<String>,
+    #[serde(default)]
+    pub save_dc: Option<i32>,
+    #[serde(default)]
+    pub attack_bonus: Option<i32>,
+    pub slots: Vec<SlotPool>,
+    #[serde(default)]
+    pub pact_slots: Option<SlotPool>,
+    pub spells: Vec<KnownSpell>,
+    #[serde(default)]
+    pub concentrating_on: Option<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CastOutcome {
+    pub spell: KnownSpell,
+    pub slot_level: Option<u8>,
+    pub pact_slot: bool,
+    pub upcast: bool,
+    pub slots_remaining: u32,
+    pub ended_concentration: Option<String>,
+}
+
+pub async fn get_spellbook(campaign_id: String, caster: Caster) -> Result<Spellbook, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+    }
+    invoke("get_spellbook", &Args { campaign_id, caster }).await
+}
+
+/// Fill a player character's slots from their class and level
+pub async fn sync_spell_slots_from_class(campaign_id: String, character_id: String) -> Result<Spellbook, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        character_id: String,
+    }
+    invoke(
+        "sync_spell_slots_from_class",
+        &Args {
+            campaign_id,
+            character_id,
+        },
+    )
+    .await
+}
+
+pub async fn learn_spell(campaign_id: String, caster: Caster, spell: KnownSpell) -> Result<KnownSpell, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+        spell: KnownSpell,
+    }
+    invoke("learn_spell", &Args { campaign_id, caster, spell }).await
+}
+
+pub async fn prepare_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    prepared: bool,
+) -> Result<KnownSpell, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+        spell_id: String,
+        prepared: bool,
+    }
+    invoke(
+        "prepare_spell",
+        &Args {
+            campaign_id,
+            caster,
+            spell_id,
+            prepared,
+        },
+    )
+    .await
+}
+
+pub async fn cast_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    slot_level: Option<u8>,
+    as_ritual: Option<bool>,
+) -> Result<CastOutcome, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+        spell_id: String,
+        slot_level: Option<u8>,
+        as_ritual: Option<bool>,
+    }
+    invoke(
+        "cast_spell",
+        &Args {
+            campaign_id,
+            caster,
+            spell_id,
+            slot_level,
+            as_ritual,
+        },
+    )
+    .await
+}
+
+/// Long rest for one caster, or every caster in the campaign
+pub async fn take_long_rest(campaign_id: String, caster: Option<Caster>) -> Result<Vec<Spellbook>, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Option<Caster>,
+    }
+    invoke("take_long_rest", &Args { campaign_id, caster }).await
+}
+
+pub async fn take_short_rest(campaign_id: String, caster: Caster) -> Result<Spellbook, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+    }
+    invoke("take_short_rest", &Args { campaign_id, caster }).await
+}
+
+/// The spell's rulebook entry, as a TTRPG document record
+pub async fn lookup_known_spell(campaign_id: String, caster: Caster, spell_id: String) -> Result<serde_json::Value, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        caster: Caster,
+        spell_id: String,
+    }
+    invoke(
+        "lookup_known_spell",
+        &Args {
+            campaign_id,
+            caster,
+            spell_id,
+        },
+    )
+    .await
+}
+
+// ============================================================================
+// Site Generators
+// ============================================================================
+
+/// A generated NPC and the location (district, tavern) they are found at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SiteNpc {
+    pub npc: serde_json::Value,
+    pub title: String,
+    pub location_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Government {
+    pub form: String,
+    pub ruler_title: String,
+    pub description: String,
+    pub ruler_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rumor {
+    pub text: String,
+    pub is_true: bool,
+    pub about_npc_id: Option<String>,
+    pub about_location_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedSettlement {
+    pub settlement: serde_json::Value,
+    pub size: String,
+    pub government: Government,
+    pub districts: Vec<serde_json::Value>,
+    pub notable_npcs: Vec<SiteNpc>,
+    pub rumors: Vec<Rumor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MenuItem {
+    pub name: String,
+    /// "food", "drink", or "lodging"
+    pub category: String,
+    pub price_cp: u32,
+    pub price: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TavernHook {
+    pub text: String,
+    pub npc_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedTavern {
+    pub tavern: serde_json::Value,
+    pub quality: String,
+    pub keeper: SiteNpc,
+    pub menu: Vec<MenuItem>,
+    pub patrons: Vec<SiteNpc>,
+    pub hooks: Vec<TavernHook>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DungeonPassage {
+    pub from_id: String,
+    pub to_id: String,
+    pub connection_type: String,
+    pub hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedDungeon {
+    pub dungeon: serde_json::Value,
+    pub rooms: Vec<serde_json::Value>,
+    pub passages: Vec<DungeonPassage>,
+    pub boss_room_id: String,
+}
+
+/// Optional name, theme, system, and name culture for a generated site
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SiteOptions {
+    pub name: Option<String>,
+    pub theme: Option<String>,
+    pub system: Option<String>,
+    pub name_culture: Option<String>,
+}
+
+/// Generate a settlement ("hamlet" to "metropolis") and save its districts,
+/// NPCs, and relationships to the campaign
+pub async fn generate_settlement(
+    campaign_id: String,
+    size: Option<String>,
+    options: Option<SiteOptions>,
+) -> Result<GeneratedSettlement, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        size: Option<String>,
+        options: Option<SiteOptions>,
+    }
+    invoke("generate_settlement", &Args { campaign_id, size, options }).await
+}
+
+/// Generate a tavern ("squalid" to "wealthy"), optionally inside a settlement
+pub async fn generate_tavern(
+    campaign_id: String,
+    quality: Option<String>,
+    parent_location_id: Option<String>,
+    options: Option<SiteOptions>,
+) -> Result<GeneratedTavern, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        quality: Option<String>,
+        parent_location_id: Option<String>,
+        options: Option<SiteOptions>,
+    }
+    invoke(
+        "generate_tavern",
+        &Args {
+            campaign_id,
+            quality,
+            parent_location_id,
+            options,
+        },
+    )
+    .await
+}
+
+/// Generate a dungeon with `room_count` rooms (3 to 30)
+pub async fn generate_dungeon(
+    campaign_id: String,
+    room_count: Option<usize>,
+    danger_level: Option<String>,
+    parent_location_id: Option<String>,
+    options: Option<SiteOptions>,
+) -> Result<GeneratedDungeon, String> {
+    #[derive(Serialize)]
+    struct Args {
+        campaign_id: String,
+        room_count: Option<usize>,
+        danger_level: Option<String>,
+        parent_location_id: Option<String>,
+        options: Option<SiteOptions>,
+    }
+    invoke(
+        "generate_dungeon",
+        &Args {
+            campaign_id,
+            room_count,
+            danger_level,
+            parent_location_id,
+            options,
+        },
+    )
+    .await
+}
@@ -21,6 +21,7 @@ use crate::bindings::{
     CacheStats,
     PopularQuery,
     SearchAnalyticsSummary,
+    SearchTrends,
 };
 use crate::components::design_system::{Card, CardBody, CardHeader, Select};
 
@@ -51,9 +52,9 @@ pub fn SearchAnalyticsDashboard() -> impl IntoView {
 
             // Fetch analytics summary (from database or in-memory)
             let summary_result = if use_db {
-                get_search_analytics_db(hours).await
+                get_search_analytics_db(hours, None).await
             } else {
-                get_search_analytics(hours).await
+                get_search_analytics(hours, None).await
             };
 
             match summary_result {
@@ -229,6 +230,44 @@ pub fn SearchAnalyticsDashboard() -> impl IntoView {
                     </Card>
                 </div>
 
+                // Search Volume Over Time
+                <Card>
+                    <CardHeader>
+                        <h3 class="text-lg font-semibold">"Search Volume"</h3>
+                    </CardHeader>
+                    <CardBody>
+                        {move || {
+                            analytics_summary.get()
+                                .filter(|s| s.total_searches > 0)
+                                .map(|s| view! { <VolumeChart trends=s.trends /> }.into_any())
+                                .unwrap_or_else(|| view! {
+                                    <div class="text-center py-4 text-theme-secondary">
+                                        "No search data for this period"
+                                    </div>
+                                }.into_any())
+                        }}
+                    </CardBody>
+                </Card>
+
+                // Latency Histogram
+                <Card>
+                    <CardHeader>
+                        <h3 class="text-lg font-semibold">"Search Latency"</h3>
+                    </CardHeader>
+                    <CardBody>
+                        {move || {
+                            analytics_summary.get()
+                                .filter(|s| s.total_searches > 0)
+                                .map(|s| view! { <LatencyChart trends=s.trends /> }.into_any())
+                                .unwrap_or_else(|| view! {
+                                    <div class="text-center py-4 text-theme-secondary">
+                                        "No latency data for this period"
+                                    </div>
+                                }.into_any())
+                        }}
+                    </CardBody>
+                </Card>
+
                 // Cache Statistics Card
                 <Card>
                     <CardHeader>
@@ -418,13 +457,24 @@ pub fn SearchAnalyticsDashboard() -> impl IntoView {
                                                 "Consider adding content or improving indexing for these queries:"
                                             </p>
                                             <div class="flex flex-wrap gap-2">
-                                                {summary.failed_queries.iter().map(|query| {
-                                                    view! {
-                                                        <span class="px-3 py-1 bg-red-900/30 border border-red-500/30 text-red-400 rounded-full text-sm">
-                                                            {query.clone()}
-                                                        </span>
-                                                    }
-                                                }).collect_view()}
+                                                {if summary.trends.missing_content.is_empty() {
+                                                    summary.failed_queries.iter().map(|query| {
+                                                        view! {
+                                                            <span class="px-3 py-1 bg-red-900/30 border border-red-500/30 text-red-400 rounded-full text-sm">
+                                                                {query.clone()}
+                                                            </span>
+                                                        }
+                                                    }).collect_view().into_any()
+                                                } else {
+                                                    summary.trends.missing_content.iter().map(|missing| {
+                                                        view! {
+                                                            <span class="px-3 py-1 bg-red-900/30 border border-red-500/30 text-red-400 rounded-full text-sm">
+                                                                {missing.query.clone()}
+                                                                <span class="ml-1 text-xs text-red-300/70">{format!("×{}", missing.count)}</span>
+                                                            </span>
+                                                        }
+                                                    }).collect_view().into_any()
+                                                }}
                                             </div>
                                         </div>
                                     }.into_any()
@@ -554,6 +604,95 @@ pub fn SearchAnalyticsDashboard() -> impl IntoView {
     }
 }
 
+// ============================================================================
+// Trend Charts
+// ============================================================================
+
+/// Searches per time bucket, with the zero-result share in red
+#[component]
+fn VolumeChart(trends: SearchTrends) -> impl IntoView {
+    let max = trends.buckets.iter().map(|b| b.searches).max().unwrap_or(0).max(1);
+    let daily = trends.bucket_minutes >= 24 * 60;
+    let label = move |start: &str| {
+        let range = if daily { 5..10 } else { 11..16 };
+        start.get(range).unwrap_or(start).to_string()
+    };
+    let first = trends.buckets.first().map(|b| label(&b.start)).unwrap_or_default();
+    let last = trends.buckets.last().map(|b| label(&b.start)).unwrap_or_default();
+
+    view! {
+        <div>
+            <div class="flex items-end gap-px h-32">
+                {trends.buckets.into_iter().map(|bucket| {
+                    let height = bucket.searches as f64 / max as f64 * 100.0;
+                    let zero_share = if bucket.searches > 0 {
+                        bucket.zero_result_searches as f64 / bucket.searches as f64 * 100.0
+                    } else {
+                        0.0
+                    };
+                    let title = format!(
+                        "{}: {} searches, {} with no results, {:.0}% clicked, {:.0}ms avg",
+                        label(&bucket.start),
+                        bucket.searches,
+                        bucket.zero_result_searches,
+                        bucket.click_through_rate * 100.0,
+                        bucket.avg_latency_ms,
+                    );
+                    view! {
+                        <div class="flex-1 flex flex-col justify-end h-full" title=title>
+                            <div class="bg-purple-500 rounded-t-sm flex flex-col" style=format!("height: {}%", height)>
+                                <div class="bg-red-500 rounded-t-sm" style=format!("height: {}%", zero_share) />
+                            </div>
+                        </div>
+                    }
+                }).collect_view()}
+            </div>
+            <div class="flex justify-between text-xs text-theme-secondary mt-2">
+                <span>{first}</span>
+                <span>
+                    <span class="inline-block w-2 h-2 bg-purple-500 rounded-sm mr-1" />"Searches"
+                    <span class="inline-block w-2 h-2 bg-red-500 rounded-sm ml-3 mr-1" />"No results"
+                </span>
+                <span>{last}</span>
+            </div>
+        </div>
+    }
+}
+
+/// Searches per latency bucket, with the median and 95th percentile
+#[component]
+fn LatencyChart(trends: SearchTrends) -> impl IntoView {
+    let histogram = trends.latency_histogram;
+    let max = histogram.counts.iter().copied().max().unwrap_or(0).max(1);
+    let labels: Vec<String> = (0..histogram.counts.len())
+        .map(|i| match histogram.bounds_ms.get(i) {
+            Some(bound) => format!("≤{}ms", bound),
+            None => format!(">{}ms", histogram.bounds_ms.last().copied().unwrap_or(0)),
+        })
+        .collect();
+
+    view! {
+        <div class="space-y-2">
+            {histogram.counts.iter().zip(labels).map(|(count, label)| {
+                let percentage = *count as f64 / max as f64 * 100.0;
+                view! {
+                    <div class="flex items-center gap-3">
+                        <span class="text-xs font-mono text-theme-secondary w-16 text-right">{label}</span>
+                        <div class="flex-1 bg-gray-700 rounded-full h-2">
+                            <div class="bg-blue-500 h-2 rounded-full" style=format!("width: {}%", percentage) />
+                        </div>
+                        <span class="text-xs text-theme-secondary w-10">{count.to_string()}</span>
+                    </div>
+                }
+            }).collect_view()}
+            <div class="flex gap-6 text-sm text-theme-secondary pt-2">
+                <span>{format!("Median {}ms", histogram.p50_ms)}</span>
+                <span>{format!("95th percentile {}ms", histogram.p95_ms)}</span>
+            </div>
+        </div>
+    }
+}
+
 // ============================================================================
 // Wrapper Page Component with Navigation
 // ============================================================================
@@ -11,7 +11,7 @@ use leptos::ev;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use super::{use_library_state, SearchResult};
+use super::{use_library_state, MatchSnippet, SearchResult};
 use crate::bindings::{copy_to_clipboard, hybrid_search, HybridSearchOptions};
 use crate::components::design_system::{
     Badge, BadgeVariant, Button, ButtonVariant, Card, CardBody, CardHeader,
@@ -53,6 +53,8 @@ pub fn DocumentDetail() -> impl IntoView {
                         index: None,
                         semantic_weight: Some(0.8),
                         keyword_weight: Some(0.2),
+                        snippet_length: Some(100),
+                        facets: Default::default(),
                     };
 
                     match hybrid_search(source.clone(), Some(options)).await {
@@ -347,7 +349,7 @@ pub fn DocumentDetail() -> impl IntoView {
                                                                                     {result.title.clone()}
                                                                                 </h4>
                                                                                 <p class="text-xs text-[var(--text-muted)] line-clamp-1">
-                                                                                    {result.snippet.clone()}
+                                                                                    <MatchSnippet snippets=result.snippets.clone() fallback=result.snippet.clone() />
                                                                                 </p>
                                                                             </div>
                                                                             <span class="text-xs font-mono text-[var(--accent)] flex-shrink-0">
@@ -11,8 +11,11 @@ use leptos::ev;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use super::{use_library_state, DocumentStatus, SearchResult, SourceDocument, ViewMode};
-use crate::bindings::{ingest_document_two_phase, pick_document_file};
+use super::{
+    use_library_state, DocumentStatus, MatchSnippet, SearchMeta, SearchResult, SourceDocument,
+    ViewMode,
+};
+use crate::bindings::{ingest_document_two_phase, pick_document_file, record_search_selection_db};
 use crate::components::design_system::{Badge, BadgeVariant, LoadingSpinner};
 
 /// Document list/grid component displaying search results or all documents
@@ -181,6 +184,23 @@ pub fn DocumentList() -> impl IntoView {
     }
 }
 
+/// Report an opened search result to search analytics
+fn record_result_opened(search_meta: RwSignal<SearchMeta>, index: usize, result: &SearchResult) {
+    let meta = search_meta.get_untracked();
+    let Some(search_id) = meta.search_id else {
+        return;
+    };
+    let delay_ms = (js_sys::Date::now() - meta.received_at_ms).max(0.0) as u64;
+    let source = result.source.clone();
+    spawn_local(async move {
+        if let Err(e) =
+            record_search_selection_db(search_id, meta.query, index, source, delay_ms, None).await
+        {
+            log::warn!("Failed to record search selection: {}", e);
+        }
+    });
+}
+
 /// Search results in grid layout
 #[component]
 fn SearchResultsGrid(results: Vec<SearchResult>) -> impl IntoView {
@@ -188,7 +208,7 @@ fn SearchResultsGrid(results: Vec<SearchResult>) -> impl IntoView {
 
     view! {
         <div class="p-4 grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
-            {results.into_iter().map(|result| {
+            {results.into_iter().enumerate().map(|(index, result)| {
                 let result_clone = result.clone();
                 let is_selected = {
                     let result_id = result.id.clone();
@@ -211,7 +231,10 @@ fn SearchResultsGrid(results: Vec<SearchResult>) -> impl IntoView {
                         )
                         on:click={
                             let r = result_clone.clone();
-                            move |_| state.selected_document.set(Some(r.clone()))
+                            move |_| {
+                                record_result_opened(state.search_meta, index, &r);
+                                state.selected_document.set(Some(r.clone()));
+                            }
                         }
                     >
                         <div class="flex items-start justify-between mb-2">
@@ -235,7 +258,7 @@ fn SearchResultsGrid(results: Vec<SearchResult>) -> impl IntoView {
                         })}
 
                         <p class="text-sm text-[var(--text-muted)] line-clamp-3">
-                            {result.snippet.clone()}
+                            <MatchSnippet snippets=result.snippets.clone() fallback=result.snippet.clone() />
                         </p>
 
                         <div class="flex gap-2 mt-3 text-xs text-[var(--text-muted)]">
@@ -264,7 +287,7 @@ fn SearchResultsList(results: Vec<SearchResult>) -> impl IntoView {
 
     view! {
         <div class="divide-y divide-[var(--border-subtle)]">
-            {results.into_iter().map(|result| {
+            {results.into_iter().enumerate().map(|(index, result)| {
                 let result_clone = result.clone();
                 let is_selected = {
                     let result_id = result.id.clone();
@@ -287,7 +310,10 @@ fn SearchResultsList(results: Vec<SearchResult>) -> impl IntoView {
                         )
                         on:click={
                             let r = result_clone.clone();
-                            move |_| state.selected_document.set(Some(r.clone()))
+                            move |_| {
+                                record_result_opened(state.search_meta, index, &r);
+                                state.selected_document.set(Some(r.clone()));
+                            }
                         }
                     >
                         <div class="flex items-start gap-4">
@@ -309,7 +335,7 @@ fn SearchResultsList(results: Vec<SearchResult>) -> impl IntoView {
                                     })}
                                 </div>
                                 <p class="text-sm text-[var(--text-muted)] line-clamp-2">
-                                    {result.snippet.clone()}
+                                    <MatchSnippet snippets=result.snippets.clone() fallback=result.snippet.clone() />
                                 </p>
                             </div>
 
@@ -20,6 +20,7 @@ pub use search_panel::SearchPanel;
 pub use source_manager::SourceManager;
 
 use crate::services::notification_service::show_error;
+use std::collections::HashMap;
 use leptos::ev;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
@@ -28,6 +29,7 @@ use wasm_bindgen::prelude::*;
 use crate::bindings::{
     check_meilisearch_health, ingest_document_two_phase, list_library_documents, listen_event,
     pick_document_file, rebuild_library_metadata, HybridSearchResultPayload, LibraryDocument,
+    Facet, Snippet,
 };
 use crate::components::design_system::{
     Badge, BadgeVariant, Button, ButtonVariant, Card, CardBody, CardHeader,
@@ -202,7 +204,8 @@ pub struct SearchResult {
     pub score: f32,
     pub keyword_rank: Option<usize>,
     pub semantic_rank: Option<usize>,
-    pub highlights: Vec<String>,
+    /// Excerpts around the matched query terms, best first
+    pub snippets: Vec<Snippet>,
 }
 
 impl From<HybridSearchResultPayload> for SearchResult {
@@ -214,7 +217,10 @@ impl From<HybridSearchResultPayload> for SearchResult {
         };
 
         Self {
-            id: format!("{}-{:?}", r.source, r.page_number),
+            id: r
+                .document_id
+                .clone()
+                .unwrap_or_else(|| format!("{}-{:?}", r.source, r.page_number)),
             title: format!("{}", r.source),
             content: r.content.clone(),
             snippet,
@@ -224,7 +230,7 @@ impl From<HybridSearchResultPayload> for SearchResult {
             score: r.score,
             keyword_rank: r.keyword_rank,
             semantic_rank: r.semantic_rank,
-            highlights: Vec::new(),
+            snippets: r.snippets,
         }
     }
 }
@@ -237,6 +243,13 @@ pub struct SearchMeta {
     pub expanded_query: Option<String>,
     pub corrected_query: Option<String>,
     pub hints: Vec<String>,
+    /// Facet counts over the current results
+    pub facets: Vec<Facet>,
+    /// Query and analytics ID the results came from, for reporting clicks
+    pub query: String,
+    pub search_id: Option<String>,
+    /// When the results arrived (ms since epoch)
+    pub received_at_ms: f64,
 }
 
 /// View mode for the library
@@ -319,6 +332,14 @@ pub struct LibraryState {
     pub semantic_weight: RwSignal<f32>,
     pub keyword_weight: RwSignal<f32>,
     pub search_hints: RwSignal<Vec<String>>,
+    /// Checked facet values keyed by facet field
+    pub selected_facets: RwSignal<HashMap<String, Vec<String>>>,
+    /// Campaign to search instead of the library, if any
+    pub scope_campaign_id: RwSignal<Option<String>>,
+    /// Session within that campaign, if any
+    pub scope_session_id: RwSignal<Option<String>>,
+    /// Whether a campaign or session search also covers the library
+    pub scope_include_library: RwSignal<bool>,
     pub is_drag_over: RwSignal<bool>,
     pub show_source_manager: RwSignal<bool>,
     pub editing_document: RwSignal<Option<SourceDocument>>,
@@ -346,6 +367,10 @@ impl LibraryState {
             semantic_weight: RwSignal::new(0.5),
             keyword_weight: RwSignal::new(0.5),
             search_hints: RwSignal::new(Vec::new()),
+            selected_facets: RwSignal::new(HashMap::new()),
+            scope_campaign_id: RwSignal::new(None),
+            scope_session_id: RwSignal::new(None),
+            scope_include_library: RwSignal::new(true),
             is_drag_over: RwSignal::new(false),
             show_source_manager: RwSignal::new(false),
             editing_document: RwSignal::new(None),
@@ -762,6 +787,38 @@ pub fn Library() -> impl IntoView {
 // Helper Components
 // ============================================================================
 
+/// A result's first snippet with the matched terms marked, or the plain
+/// opening text when nothing matched
+#[component]
+fn MatchSnippet(snippets: Vec<Snippet>, fallback: String) -> impl IntoView {
+    match snippets.into_iter().next() {
+        Some(snippet) => view! {
+            <span>
+                {snippet.pre_context}
+                {snippet
+                    .parts
+                    .into_iter()
+                    .map(|part| {
+                        if part.highlighted {
+                            view! {
+                                <mark class="bg-[var(--accent)]/20 text-[var(--text-primary)] rounded px-0.5">
+                                    {part.text}
+                                </mark>
+                            }
+                            .into_any()
+                        } else {
+                            view! { <span>{part.text}</span> }.into_any()
+                        }
+                    })
+                    .collect_view()}
+                {snippet.post_context}
+            </span>
+        }
+        .into_any(),
+        None => view! { <span>{fallback}</span> }.into_any(),
+    }
+}
+
 /// Status badge for Meilisearch connection
 #[component]
 fn StatusBadge(status: RwSignal<String>) -> impl IntoView {
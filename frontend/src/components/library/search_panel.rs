@@ -3,6 +3,7 @@
 //! Advanced search interface with:
 //! - Hybrid search input with autocomplete
 //! - Source type filter pills
+//! - Scope selector (library, a campaign, or a session)
 //! - Advanced options (semantic/keyword weights)
 //! - Search suggestions and query hints
 //! - Search history
@@ -12,7 +13,10 @@ use leptos::prelude::*;
 use leptos::task::spawn_local;
 
 use super::{use_library_state, SearchMeta, SearchResult, SourceType};
-use crate::bindings::{get_search_suggestions, hybrid_search, HybridSearchOptions};
+use crate::bindings::{
+    get_search_suggestions, list_campaigns, list_sessions, scoped_search, Campaign,
+    HybridSearchOptions, SearchScope, SearchSuggestion, SessionSummary,
+};
 use crate::components::design_system::{Button, ButtonVariant};
 
 /// Advanced search panel with filters and suggestions
@@ -21,10 +25,30 @@ pub fn SearchPanel() -> impl IntoView {
     let state = use_library_state();
 
     // Local state for suggestions dropdown
-    let suggestions = RwSignal::new(Vec::<String>::new());
+    let suggestions = RwSignal::new(Vec::<SearchSuggestion>::new());
     let show_suggestions = RwSignal::new(false);
     let suggestion_index = RwSignal::new(0_i32);
 
+    // Campaigns and sessions for the scope selector
+    let campaigns = RwSignal::new(Vec::<Campaign>::new());
+    let sessions = RwSignal::new(Vec::<SessionSummary>::new());
+    Effect::new(move |_| {
+        spawn_local(async move {
+            if let Ok(list) = list_campaigns().await {
+                campaigns.set(list);
+            }
+        });
+    });
+    Effect::new(move |_| {
+        let Some(campaign_id) = state.scope_campaign_id.get() else {
+            sessions.set(Vec::new());
+            return;
+        };
+        spawn_local(async move {
+            sessions.set(list_sessions(campaign_id).await.unwrap_or_default());
+        });
+    });
+
     // Debounced search suggestions
     let fetch_suggestions = move |query: String| {
         if query.len() < 2 {
@@ -58,6 +82,10 @@ pub fn SearchPanel() -> impl IntoView {
         let selected_source_type = state.selected_source_type;
         let semantic_weight = state.semantic_weight;
         let keyword_weight = state.keyword_weight;
+        let selected_facets = state.selected_facets;
+        let scope_campaign_id = state.scope_campaign_id;
+        let scope_session_id = state.scope_session_id;
+        let scope_include_library = state.scope_include_library;
 
         move || {
             let query = search_query.get();
@@ -72,6 +100,13 @@ pub fn SearchPanel() -> impl IntoView {
             let source_type = selected_source_type.get();
             let sem_weight = semantic_weight.get();
             let key_weight = keyword_weight.get();
+            let facets = selected_facets.get();
+            let include_library = scope_include_library.get();
+            let scope = match (scope_campaign_id.get(), scope_session_id.get()) {
+                (_, Some(session_id)) => SearchScope::Session { session_id, include_library },
+                (Some(campaign_id), None) => SearchScope::Campaign { campaign_id, include_library },
+                (None, None) => SearchScope::Library,
+            };
 
             spawn_local(async move {
                 let options = HybridSearchOptions {
@@ -85,9 +120,11 @@ pub fn SearchPanel() -> impl IntoView {
                     index: None,
                     semantic_weight: Some(sem_weight),
                     keyword_weight: Some(key_weight),
+                    snippet_length: None,
+                    facets,
                 };
 
-                match hybrid_search(query.clone(), Some(options)).await {
+                match scoped_search(query.clone(), scope, Some(options)).await {
                     Ok(response) => {
                         let results: Vec<SearchResult> = response
                             .results
@@ -103,6 +140,10 @@ pub fn SearchPanel() -> impl IntoView {
                             expanded_query: response.expanded_query,
                             corrected_query: response.corrected_query,
                             hints: response.hints.clone(),
+                            facets: response.facets,
+                            query: query.clone(),
+                            search_id: response.search_id,
+                            received_at_ms: js_sys::Date::now(),
                         });
                         search_hints.set(response.hints);
                         ingestion_status.set(format!(
@@ -139,7 +180,7 @@ pub fn SearchPanel() -> impl IntoView {
                 if show_suggestions.get() && !suggestions.get().is_empty() {
                     let idx = suggestion_index.get() as usize;
                     if let Some(suggestion) = suggestions.get().get(idx) {
-                        search_query.set(suggestion.clone());
+                        search_query.set(suggestion.text.clone());
                     }
                 }
                 show_suggestions.set(false);
@@ -180,10 +221,12 @@ pub fn SearchPanel() -> impl IntoView {
         let search_query = state.search_query;
         let search_results = state.search_results;
         let search_meta = state.search_meta;
+        let selected_facets = state.selected_facets;
         move |_: ev::MouseEvent| {
             search_query.set(String::new());
             search_results.set(Vec::new());
             search_meta.set(SearchMeta::default());
+            selected_facets.set(Default::default());
             show_suggestions.set(false);
         }
     };
@@ -246,7 +289,8 @@ pub fn SearchPanel() -> impl IntoView {
                                     <div class="absolute top-full left-0 right-0 mt-1 bg-[var(--bg-elevated)] border border-[var(--border-subtle)] rounded-lg shadow-lg z-50 overflow-hidden">
                                         {suggs.into_iter().enumerate().map(|(i, suggestion)| {
                                             let is_selected = move || suggestion_index.get() == i as i32;
-                                            let suggestion_clone = suggestion.clone();
+                                            let suggestion_text = suggestion.text.clone();
+                                            let kind_label = suggestion.kind.label();
                                             view! {
                                                 <button
                                                     class=move || format!(
@@ -254,7 +298,7 @@ pub fn SearchPanel() -> impl IntoView {
                                                         if is_selected() { "bg-[var(--accent)]/20 text-[var(--text-primary)]" } else { "text-[var(--text-muted)] hover:bg-[var(--bg-surface)]" }
                                                     )
                                                     on:click={
-                                                        let s = suggestion_clone.clone();
+                                                        let s = suggestion_text.clone();
                                                         let search_query = state.search_query;
                                                         let perform_search = perform_search.clone();
                                                         move |_| {
@@ -268,7 +312,10 @@ pub fn SearchPanel() -> impl IntoView {
                                                         <svg class="w-4 h-4 text-[var(--text-muted)]" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" />
                                                         </svg>
-                                                        {suggestion}
+                                                        <span class="flex-1">{suggestion.text}</span>
+                                                        {(!kind_label.is_empty()).then(|| view! {
+                                                            <span class="text-xs text-[var(--text-muted)]">{kind_label}</span>
+                                                        })}
                                                     </div>
                                                 </button>
                                             }
@@ -333,6 +380,57 @@ pub fn SearchPanel() -> impl IntoView {
                     }).collect_view()}
                 </div>
 
+                // Scope Selector
+                <div class="flex flex-wrap items-center gap-3 text-sm">
+                    <span class="text-[var(--text-muted)]">"Search in:"</span>
+                    <select
+                        class="px-3 py-1.5 rounded-lg bg-[var(--bg-elevated)] border border-[var(--border-subtle)] text-[var(--text-primary)] outline-none focus:border-[var(--accent)]"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            state.scope_session_id.set(None);
+                            state.scope_campaign_id.set((!value.is_empty()).then_some(value));
+                        }
+                    >
+                        <option value="" selected=move || state.scope_campaign_id.get().is_none()>"Library"</option>
+                        {move || campaigns.get().into_iter().map(|campaign| {
+                            let id = campaign.id.clone();
+                            let selected = move || state.scope_campaign_id.get().as_deref() == Some(id.as_str());
+                            view! {
+                                <option value=campaign.id selected=selected>{campaign.name}</option>
+                            }
+                        }).collect_view()}
+                    </select>
+                    {move || state.scope_campaign_id.get().is_some().then(|| view! {
+                        <select
+                            class="px-3 py-1.5 rounded-lg bg-[var(--bg-elevated)] border border-[var(--border-subtle)] text-[var(--text-primary)] outline-none focus:border-[var(--accent)]"
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                state.scope_session_id.set((!value.is_empty()).then_some(value));
+                            }
+                        >
+                            <option value="" selected=move || state.scope_session_id.get().is_none()>"Whole campaign"</option>
+                            {move || sessions.get().into_iter().map(|session| {
+                                let id = session.id.clone();
+                                let selected = move || state.scope_session_id.get().as_deref() == Some(id.as_str());
+                                view! {
+                                    <option value=session.id selected=selected>
+                                        {format!("Session {}", session.session_number)}
+                                    </option>
+                                }
+                            }).collect_view()}
+                        </select>
+                        <label class="flex items-center gap-2 cursor-pointer text-[var(--text-muted)]">
+                            <input
+                                type="checkbox"
+                                class="accent-[var(--accent)]"
+                                prop:checked=move || state.scope_include_library.get()
+                                on:change=move |ev| state.scope_include_library.set(event_target_checked(&ev))
+                            />
+                            "Include library"
+                        </label>
+                    })}
+                </div>
+
                 // Advanced Options Panel
                 {move || {
                     if state.show_advanced_search.get() {
@@ -417,6 +515,61 @@ pub fn SearchPanel() -> impl IntoView {
                     })
                 }}
 
+                // Facet Filters
+                {
+                    let perform_search = perform_search.clone();
+                    move || {
+                        let facets = state.search_meta.get().facets;
+                        if facets.is_empty() {
+                            return None;
+                        }
+                        let perform_search = perform_search.clone();
+                        Some(view! {
+                            <div class="flex flex-wrap gap-x-6 gap-y-3">
+                                {facets.into_iter().map(|facet| {
+                                    let perform_search = perform_search.clone();
+                                    view! {
+                                        <div class="space-y-1">
+                                            <div class="text-xs font-medium text-[var(--text-muted)]">{facet.label}</div>
+                                            {facet.values.into_iter().map(|value| {
+                                                let field = facet.field.clone();
+                                                let checked = state.selected_facets.with(|selected| {
+                                                    selected.get(&field).is_some_and(|v| v.contains(&value.value))
+                                                });
+                                                let perform_search = perform_search.clone();
+                                                let toggle = {
+                                                    let value = value.value.clone();
+                                                    move |_| {
+                                                        state.selected_facets.update(|selected| {
+                                                            let values = selected.entry(field.clone()).or_default();
+                                                            if let Some(i) = values.iter().position(|v| *v == value) {
+                                                                values.remove(i);
+                                                            } else {
+                                                                values.push(value.clone());
+                                                            }
+                                                            if values.is_empty() {
+                                                                selected.remove(&field);
+                                                            }
+                                                        });
+                                                        perform_search();
+                                                    }
+                                                };
+                                                view! {
+                                                    <label class="flex items-center gap-2 text-xs text-[var(--text-primary)] cursor-pointer">
+                                                        <input type="checkbox" prop:checked=checked on:change=toggle />
+                                                        <span>{value.value}</span>
+                                                        <span class="text-[var(--text-muted)]">{format!("({})", value.count)}</span>
+                                                    </label>
+                                                }
+                                            }).collect_view()}
+                                        </div>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        })
+                    }
+                }
+
                 // Query Expansion/Correction Info
                 {move || {
                     let meta = state.search_meta.get();
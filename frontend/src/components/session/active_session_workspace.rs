@@ -4,16 +4,20 @@
 
 use leptos::ev;
 use leptos::prelude::*;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::bindings::{
-    add_combatant, add_condition, damage_combatant, end_combat, end_session, get_combat,
-    heal_combatant, next_turn, remove_combatant, start_combat, CombatState, Combatant, GameSession,
+    add_combatant, add_condition, damage_combatant, delay_turn, end_combat, end_session,
+    get_combat, heal_combatant, listen_event, move_in_initiative, next_turn, open_player_window,
+    recover_combat, remove_combatant, resume_delayed_turn, start_combat, take_readied_action,
+    CombatState, Combatant, GameSession, MoraleCheck, ReadiedReminder,
 };
 use crate::components::design_system::{
     Badge, BadgeVariant, Button, ButtonVariant, Card, CardBody, CardHeader, Input,
 };
 use crate::components::session::SessionChatPanel;
+use crate::services::notification_service::show_info;
 
 /// Active session workspace component
 #[component]
@@ -32,6 +36,8 @@ pub fn ActiveSessionWorkspace(
 
     // Combat state
     let combat = RwSignal::new(Option::<CombatState>::None);
+    // Combatant being dragged to a new place in initiative
+    let dragged = RwSignal::new(Option::<String>::None);
 
     // Combatant form state
     let new_combatant_name = RwSignal::new(String::new());
@@ -43,12 +49,40 @@ pub fn ActiveSessionWorkspace(
     let condition_combatant_id = RwSignal::new(Option::<String>::None);
     let new_condition = RwSignal::new(String::new());
 
-    // Load combat state on mount
+    // Load combat state on mount, bringing back a fight lost to a crash
     Effect::new(move |_| {
         let sid = session_id.get_value();
         spawn_local(async move {
-            if let Ok(Some(c)) = get_combat(sid).await {
-                combat.set(Some(c));
+            match get_combat(sid.clone()).await {
+                Ok(Some(c)) => combat.set(Some(c)),
+                Ok(None) => {
+                    if let Ok(Some(c)) = recover_combat(sid).await {
+                        combat.set(Some(c));
+                        show_info("Combat recovered", Some("The fight was restored from where it left off"));
+                    }
+                }
+                Err(_) => {}
+            }
+        });
+    });
+
+    // Morale suggestions for the GM
+    Effect::new(move |_| {
+        let _ = listen_event("combat:morale", move |event: JsValue| {
+            let check = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                .ok()
+                .and_then(|payload| serde_wasm_bindgen::from_value::<MoraleCheck>(payload).ok());
+            if let Some(check) = check {
+                show_info(&format!("Morale: {}", check.group_name), Some(&check.suggestion));
+            }
+        });
+        let _ = listen_event("combat:readied", move |event: JsValue| {
+            let reminders = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                .ok()
+                .and_then(|payload| serde_wasm_bindgen::from_value::<Vec<ReadiedReminder>>(payload).ok());
+            for reminder in reminders.unwrap_or_default() {
+                let message = format!("Ready to {} when {}", reminder.action, reminder.trigger);
+                show_info(&format!("{} has a readied action", reminder.combatant_name), Some(&message));
             }
         });
     });
@@ -100,6 +134,22 @@ pub fn ActiveSessionWorkspace(
                             <span class="mr-1">"👤"</span>
                             "Quick NPC"
                         </Button>
+                        // Players' screen, for a second monitor or TV
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            class="px-4 py-2 bg-emerald-600/20 text-emerald-300 border border-emerald-600/50 hover:bg-emerald-600/30"
+                            on_click=move |_: ev::MouseEvent| {
+                                let sid = session_id.get_value();
+                                spawn_local(async move {
+                                    if let Err(e) = open_player_window(sid, None).await {
+                                        log::error!("Failed to open player view: {}", e);
+                                    }
+                                });
+                            }
+                        >
+                            <span class="mr-1">"📺"</span>
+                            "Player View"
+                        </Button>
                         <Button
                             variant=ButtonVariant::Destructive
                             class="px-4 py-2 bg-red-600/20 text-red-400 border border-red-600/50"
@@ -196,16 +246,18 @@ pub fn ActiveSessionWorkspace(
                                         .enumerate()
                                         .collect::<Vec<_>>()
                                 }
-                                key=|(_, combatant)| combatant.id.clone()
+                                key=|(idx, combatant)| (*idx, combatant.id.clone())
                                 children=move |(idx, combatant)| {
                                     let current_turn = combat.get().map(|c| c.current_turn).unwrap_or(0);
                                     let combatant_id = combatant.id.clone();
                                     view! {
                                         <CombatantRow
                                             combatant=combatant
+                                            position=idx
                                             is_current_turn=idx == current_turn
                                             session_id=session_id
                                             combat=combat
+                                            dragged=dragged
                                             on_open_condition_modal=Callback::new(move |_| {
                                                 condition_combatant_id.set(Some(combatant_id.clone()));
                                                 condition_modal_open.set(true);
@@ -338,9 +390,13 @@ pub fn ActiveSessionWorkspace(
 #[component]
 fn CombatantRow(
     combatant: Combatant,
+    /// Place in the initiative order
+    position: usize,
     is_current_turn: bool,
     session_id: StoredValue<String>,
     combat: RwSignal<Option<CombatState>>,
+    /// Combatant being dragged to a new place
+    dragged: RwSignal<Option<String>>,
     on_open_condition_modal: Callback<()>,
 ) -> impl IntoView {
     let combatant_id = StoredValue::new(combatant.id.clone());
@@ -351,6 +407,34 @@ fn CombatantRow(
     let hp_max = combatant.hp_max;
     let conditions = StoredValue::new(combatant.conditions.clone());
     let has_conditions = !combatant.conditions.is_empty();
+    let group_status = combatant
+        .group
+        .as_ref()
+        .map(|g| format!("{} of {} standing", g.standing(), g.members.len()));
+    let delaying = combatant.delaying;
+    let readied = combatant
+        .readied
+        .as_ref()
+        .map(|r| format!("Ready: {} when {}", r.action, r.trigger));
+    let has_readied = readied.is_some();
+
+    // Refresh the tracker after a turn-order change
+    let run_turn_change = move |change: &'static str| {
+        let sid = session_id.get_value();
+        let cid = combatant_id.get_value();
+        spawn_local(async move {
+            let result = match change {
+                "delay" => delay_turn(sid.clone(), cid).await.map(|_| ()),
+                "resume" => resume_delayed_turn(sid.clone(), cid).await.map(|_| ()),
+                _ => take_readied_action(sid.clone(), cid).await.map(|_| ()),
+            };
+            if result.is_ok() {
+                if let Ok(Some(c)) = get_combat(sid).await {
+                    combat.set(Some(c));
+                }
+            }
+        });
+    };
 
     let base_class = if is_current_turn {
         "bg-purple-900/20 flex items-center p-3 border-l-4 border-purple-500"
@@ -359,7 +443,25 @@ fn CombatantRow(
     };
 
     view! {
-        <div class=base_class>
+        <div
+            class=base_class
+            draggable="true"
+            on:dragstart=move |_| dragged.set(Some(combatant_id.get_value()))
+            on:dragover=move |ev: ev::DragEvent| ev.prevent_default()
+            on:drop=move |ev: ev::DragEvent| {
+                ev.prevent_default();
+                let Some(moved) = dragged.get_untracked() else {
+                    return;
+                };
+                dragged.set(None);
+                let sid = session_id.get_value();
+                spawn_local(async move {
+                    if let Ok(c) = move_in_initiative(sid, moved, position).await {
+                        combat.set(Some(c));
+                    }
+                });
+            }
+        >
             // Initiative
             <div class="w-12 text-center font-mono text-xl text-zinc-500">
                 {initiative.to_string()}
@@ -369,6 +471,15 @@ fn CombatantRow(
             <div class="flex-1 px-4">
                 <div class="font-bold text-zinc-200">{combatant_name.clone()}</div>
                 <div class="text-xs text-zinc-500 uppercase">{combatant_type}</div>
+                {group_status.map(|status| view! {
+                    <div class="text-xs text-zinc-400">{status}</div>
+                })}
+                {delaying.then(|| view! {
+                    <div class="text-xs text-sky-400">"Delaying"</div>
+                })}
+                {readied.map(|readied| view! {
+                    <div class="text-xs text-amber-400">{readied}</div>
+                })}
                 // Conditions
                 {if has_conditions {
                     Some(view! {
@@ -393,6 +504,35 @@ fn CombatantRow(
                     {format!("{} / {}", hp_current, hp_max)}
                 </div>
 
+                // Turn Order
+                {is_current_turn.then(|| view! {
+                    <button
+                        class="px-2 h-8 rounded bg-sky-900/50 text-sky-400 hover:bg-sky-600 hover:text-white text-xs transition-colors"
+                        aria-label=format!("{} delays their turn", combatant_name)
+                        on:click=move |_| run_turn_change("delay")
+                    >
+                        "Delay"
+                    </button>
+                })}
+                {(delaying && !is_current_turn).then(|| view! {
+                    <button
+                        class="px-2 h-8 rounded bg-sky-900/50 text-sky-400 hover:bg-sky-600 hover:text-white text-xs transition-colors"
+                        aria-label=format!("{} acts now", combatant_name)
+                        on:click=move |_| run_turn_change("resume")
+                    >
+                        "Act Now"
+                    </button>
+                })}
+                {has_readied.then(|| view! {
+                    <button
+                        class="px-2 h-8 rounded bg-amber-900/50 text-amber-400 hover:bg-amber-600 hover:text-white text-xs transition-colors"
+                        aria-label=format!("{} takes their readied action", combatant_name)
+                        on:click=move |_| run_turn_change("take_readied")
+                    >
+                        "Take Readied"
+                    </button>
+                })}
+
                 // Quick Actions
                 <button
                     class="w-8 h-8 rounded bg-red-900/50 text-red-400 hover:bg-red-600 hover:text-white transition-colors"
@@ -78,8 +78,8 @@ pub fn CombatantCard(
         let amount = damage_amount.get();
 
         spawn_local(async move {
-            if let Ok(new_hp) = damage_combatant(sid, cid, amount).await {
-                hp_current.set(new_hp);
+            if let Ok(breakdown) = damage_combatant(sid, cid, amount).await {
+                hp_current.set(breakdown.hp_after);
                 on_update.run(());
             }
         });
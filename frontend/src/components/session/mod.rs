@@ -32,6 +32,9 @@ pub mod cheat_sheet_viewer;
 pub mod session_chat_panel;
 pub mod thread_tabs;
 
+// Player-facing display window
+pub mod player_view;
+
 use leptos::prelude::*;
 use leptos::ev;
 use leptos_router::hooks::use_params;
@@ -87,6 +90,7 @@ pub use cheat_sheet_viewer::{
     CheatSheet, CheatSheetSection, CheatSheetItem, SectionType, TruncationWarning,
 };
 pub use session_chat_panel::SessionChatPanel;
+pub use player_view::{PlayerView, is_player_window};
 
 /// Route params for session page
 #[derive(Params, PartialEq, Clone, Default)]
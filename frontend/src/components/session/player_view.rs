@@ -0,0 +1,233 @@
+//! Player View Component
+//!
+//! The players' screen, shown in the second window opened with
+//! `open_player_window`. It follows the session the GM points it at: the
+//! initiative order, public timeline, and revealed handouts, kept current
+//! through `player:view` and `handout:*` events. Nothing on it is GM-only;
+//! the backend strips that before sending.
+
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::bindings::{
+    get_player_handout_image, get_player_view, get_revealed_handouts, listen_event,
+    HandoutContent, PlayerCombatView, PlayerCombatant, PlayerHandout, PlayerTimelineEvent,
+    PlayerViewSnapshot,
+};
+
+/// Whether this window was opened as the player view
+pub fn is_player_window() -> bool {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .is_some_and(|search| search.contains("view=player"))
+}
+
+fn payload<T: serde::de::DeserializeOwned>(event: &JsValue) -> Option<T> {
+    let payload = js_sys::Reflect::get(event, &JsValue::from_str("payload")).ok()?;
+    serde_wasm_bindgen::from_value(payload).ok()
+}
+
+fn health_label(health: &str) -> (&'static str, &'static str) {
+    match health {
+        "unhurt" => ("Unhurt", "text-green-400"),
+        "wounded" => ("Wounded", "text-yellow-400"),
+        "bloodied" => ("Bloodied", "text-orange-400"),
+        "down" => ("Down", "text-red-400"),
+        "dead" => ("Dead", "text-zinc-500"),
+        _ => ("", "text-zinc-400"),
+    }
+}
+
+/// Full-window player display
+#[component]
+pub fn PlayerView() -> impl IntoView {
+    let snapshot = RwSignal::new(Option::<PlayerViewSnapshot>::None);
+    let handouts = RwSignal::new(Vec::<PlayerHandout>::new());
+    let loaded_campaign = RwSignal::new(Option::<String>::None);
+
+    // Initial state; later changes arrive as events
+    Effect::new(move |_| {
+        spawn_local(async move {
+            if let Ok(view) = get_player_view().await {
+                snapshot.set(view);
+            }
+        });
+    });
+
+    Effect::new(move |_| {
+        let _ = listen_event("player:view", move |event: JsValue| {
+            if let Some(view) = payload::<PlayerViewSnapshot>(&event) {
+                snapshot.set(Some(view));
+            }
+        });
+        let _ = listen_event("handout:revealed", move |event: JsValue| {
+            let Some(handout) = payload::<PlayerHandout>(&event) else {
+                return;
+            };
+            if loaded_campaign.get_untracked().as_deref() != Some(handout.campaign_id.as_str()) {
+                return;
+            }
+            handouts.update(|list| {
+                list.retain(|h| h.id != handout.id);
+                list.insert(0, handout);
+            });
+        });
+        let _ = listen_event("handout:hidden", move |event: JsValue| {
+            if let Some(hidden) = payload::<serde_json::Value>(&event) {
+                let id = hidden.get("handout_id").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+                handouts.update(|list| list.retain(|h| h.id != id));
+            }
+        });
+    });
+
+    // Reload the revealed handouts whenever the followed session changes
+    Effect::new(move |_| {
+        let Some(view) = snapshot.get() else {
+            return;
+        };
+        if loaded_campaign.get_untracked().as_deref() == Some(view.campaign_id.as_str()) {
+            return;
+        }
+        loaded_campaign.set(Some(view.campaign_id.clone()));
+        spawn_local(async move {
+            if let Ok(list) = get_revealed_handouts(view.campaign_id, Some(view.session_id)).await {
+                handouts.set(list);
+            }
+        });
+    });
+
+    view! {
+        <div class="min-h-screen bg-zinc-950 text-zinc-100 p-8 flex gap-8">
+            <Show
+                when=move || snapshot.get().is_some()
+                fallback=|| view! {
+                    <div class="m-auto text-2xl text-zinc-500">"Waiting for the game to begin…"</div>
+                }
+            >
+                <div class="flex-1 space-y-8">
+                    {move || match snapshot.get().and_then(|s| s.combat) {
+                        Some(combat) => view! { <PlayerInitiative combat=combat /> }.into_any(),
+                        None => view! { <PlayerHandouts handouts=handouts /> }.into_any(),
+                    }}
+                </div>
+                <aside class="w-96 space-y-6">
+                    <Show when=move || snapshot.get().is_some_and(|s| s.combat.is_some())>
+                        <PlayerHandouts handouts=handouts />
+                    </Show>
+                    <PlayerTimeline events=Signal::derive(move || {
+                        snapshot.get().map(|s| s.timeline).unwrap_or_default()
+                    }) />
+                </aside>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn PlayerInitiative(combat: PlayerCombatView) -> impl IntoView {
+    view! {
+        <section>
+            <div class="flex items-baseline justify-between mb-4">
+                <h2 class="text-3xl font-bold">"Initiative"</h2>
+                <span class="text-xl text-zinc-400">{format!("Round {}", combat.round)}</span>
+            </div>
+            <ol class="space-y-2">
+                {combat.combatants.into_iter().map(|c| view! { <PlayerCombatantRow combatant=c /> }).collect_view()}
+            </ol>
+        </section>
+    }
+}
+
+#[component]
+fn PlayerCombatantRow(combatant: PlayerCombatant) -> impl IntoView {
+    let (health, health_class) = health_label(&combatant.health);
+    let hp = combatant.current_hp.zip(combatant.max_hp).map(|(current, max)| match combatant.temp_hp {
+        Some(temp) => format!("{}/{} (+{})", current, max, temp),
+        None => format!("{}/{}", current, max),
+    });
+    let row_class = if combatant.is_current {
+        "flex items-center gap-4 p-4 rounded-lg bg-purple-900/40 border border-purple-500"
+    } else {
+        "flex items-center gap-4 p-4 rounded-lg bg-zinc-900 border border-zinc-800"
+    };
+
+    view! {
+        <li class=row_class>
+            <span class="w-10 text-2xl font-mono text-zinc-400">{combatant.initiative}</span>
+            <div class="flex-1">
+                <div class="text-2xl font-semibold">{combatant.name}</div>
+                <div class="flex flex-wrap gap-2 mt-1">
+                    {combatant.conditions.into_iter().map(|name| view! {
+                        <span class="px-2 py-0.5 rounded bg-amber-900/50 text-amber-300 text-sm">{name}</span>
+                    }).collect_view()}
+                </div>
+            </div>
+            {hp.map(|hp| view! { <span class="text-xl font-mono">{hp}</span> })}
+            <span class=format!("text-lg {}", health_class)>{health}</span>
+        </li>
+    }
+}
+
+#[component]
+fn PlayerHandouts(handouts: RwSignal<Vec<PlayerHandout>>) -> impl IntoView {
+    view! {
+        <section class="space-y-4">
+            <For
+                each=move || handouts.get()
+                key=|h| h.id.clone()
+                children=|handout| view! { <PlayerHandoutCard handout=handout /> }
+            />
+        </section>
+    }
+}
+
+#[component]
+fn PlayerHandoutCard(handout: PlayerHandout) -> impl IntoView {
+    let image = RwSignal::new(Option::<String>::None);
+    if matches!(handout.content, HandoutContent::Image { .. }) {
+        let id = handout.id.clone();
+        spawn_local(async move {
+            if let Ok(url) = get_player_handout_image(id).await {
+                image.set(Some(url));
+            }
+        });
+    }
+    let text = match &handout.content {
+        HandoutContent::Text { text } => Some(text.clone()),
+        HandoutContent::PdfPage { page, .. } => Some(format!("Page {}", page)),
+        HandoutContent::Image { .. } => None,
+    };
+
+    view! {
+        <div class="rounded-lg bg-zinc-900 border border-zinc-800 p-4 space-y-3">
+            <h3 class="text-2xl font-semibold">{handout.title}</h3>
+            {move || image.get().map(|src| view! { <img src=src class="w-full rounded" /> })}
+            {text.map(|text| view! { <p class="text-lg whitespace-pre-wrap">{text}</p> })}
+            {(!handout.caption.is_empty()).then(|| view! {
+                <p class="text-zinc-400 italic">{handout.caption}</p>
+            })}
+        </div>
+    }
+}
+
+#[component]
+fn PlayerTimeline(events: Signal<Vec<PlayerTimelineEvent>>) -> impl IntoView {
+    view! {
+        <section>
+            <h2 class="text-xl font-bold mb-3">"Story So Far"</h2>
+            <ul class="space-y-3">
+                <For
+                    each=move || events.get()
+                    key=|e| e.id.clone()
+                    children=|event| view! {
+                        <li>
+                            <div class="font-semibold">{event.title}</div>
+                            <div class="text-sm text-zinc-400">{event.description}</div>
+                        </li>
+                    }
+                />
+            </ul>
+        </section>
+    }
+}
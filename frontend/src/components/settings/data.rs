@@ -1,7 +1,7 @@
 use crate::bindings::{
-    check_ocr_availability, get_extraction_presets, get_extraction_settings, reindex_library,
-    save_extraction_settings, ExtractionPreset, ExtractionSettings, OcrAvailability,
-    TokenReductionLevel,
+    check_ocr_availability, get_extraction_presets, get_extraction_settings, get_search_settings,
+    reindex_library, save_extraction_settings, save_search_settings, ExtractionPreset,
+    ExtractionSettings, OcrAvailability, RerankModel, SearchSettings, TokenReductionLevel,
 };
 use crate::components::design_system::{Button, ButtonVariant, Card};
 use crate::services::notification_service::{show_error, show_success};
@@ -22,6 +22,11 @@ pub fn DataSettingsView() -> impl IntoView {
     let settings_status = RwSignal::new(String::new());
     let has_changes = RwSignal::new(false);
 
+    // Search settings state
+    let search_settings = RwSignal::new(SearchSettings::default());
+    let search_changed = RwSignal::new(false);
+    let is_saving_search = RwSignal::new(false);
+
     // Load settings on mount
     Effect::new(move || {
         spawn_local(async move {
@@ -29,6 +34,10 @@ pub fn DataSettingsView() -> impl IntoView {
             if let Ok(settings) = get_extraction_settings().await {
                 extraction_settings.set(settings);
             }
+            // Load search settings
+            if let Ok(settings) = get_search_settings().await {
+                search_settings.set(settings);
+            }
             // Load presets
             if let Ok(p) = get_extraction_presets().await {
                 presets.set(p);
@@ -78,6 +87,21 @@ pub fn DataSettingsView() -> impl IntoView {
         });
     };
 
+    let handle_save_search = move |_: ev::MouseEvent| {
+        is_saving_search.set(true);
+        let settings = search_settings.get();
+        spawn_local(async move {
+            match save_search_settings(settings).await {
+                Ok(_) => {
+                    search_changed.set(false);
+                    show_success("Search Settings", Some("Settings saved successfully"));
+                }
+                Err(e) => show_error("Save Failed", Some(&e), None),
+            }
+            is_saving_search.set(false);
+        });
+    };
+
     let apply_preset = move |preset_name: String| {
         let current_presets = presets.get();
         if let Some(preset) = current_presets.iter().find(|p| p.name == preset_name) {
@@ -113,6 +137,161 @@ pub fn DataSettingsView() -> impl IntoView {
                 </div>
             </Card>
 
+            // Search Re-ranking Card
+            <Card class="p-6">
+                <div class="space-y-4">
+                    <div>
+                        <h4 class="text-lg font-bold text-theme-primary">"Search Re-ranking"</h4>
+                        <p class="text-sm text-theme-muted">"Re-score the top results with a local cross-encoder for more precise rules lookups. The model downloads on first use."</p>
+                    </div>
+
+                    <label class="flex items-center gap-3 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            class="w-4 h-4 rounded border-theme-subtle text-theme-accent focus:ring-theme-accent"
+                            prop:checked=move || search_settings.get().rerank.enabled
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                search_settings.update(|s| s.rerank.enabled = checked);
+                                search_changed.set(true);
+                            }
+                        />
+                        <span class="text-sm text-theme-secondary">"Enable cross-encoder re-ranking"</span>
+                    </label>
+
+                    <div class="grid grid-cols-2 gap-4">
+                        // Model
+                        <div>
+                            <label class="block text-sm text-theme-muted mb-1">"Model"</label>
+                            <select
+                                class="w-full px-3 py-2 rounded-lg bg-theme-deep border border-theme-subtle text-theme-primary text-sm outline-none focus:border-theme-accent"
+                                style="color-scheme: dark;"
+                                on:change=move |ev| {
+                                    let val = event_target_value(&ev);
+                                    if let Some(model) = RerankModel::ALL.into_iter().find(|m| m.key() == val) {
+                                        search_settings.update(|s| s.rerank.model = model);
+                                        search_changed.set(true);
+                                    }
+                                }
+                            >
+                                {RerankModel::ALL.into_iter().map(|model| view! {
+                                    <option
+                                        value=model.key()
+                                        selected=move || search_settings.get().rerank.model == model
+                                    >
+                                        {model.label()}
+                                    </option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </div>
+
+                        // Candidates
+                        <div>
+                            <label class="block text-sm text-theme-muted mb-1">"Candidates re-scored"</label>
+                            <input
+                                type="number"
+                                min="10"
+                                max="200"
+                                class="w-full px-3 py-2 rounded-lg bg-theme-deep border border-theme-subtle text-theme-primary text-sm outline-none focus:border-theme-accent"
+                                prop:value=move || search_settings.get().rerank.candidates.to_string()
+                                on:input=move |ev| {
+                                    if let Ok(val) = event_target_value(&ev).parse::<usize>() {
+                                        search_settings.update(|s| s.rerank.candidates = val);
+                                        search_changed.set(true);
+                                    }
+                                }
+                            />
+                        </div>
+                    </div>
+
+                    // Typo tolerance
+                    <div class="pt-4 border-t border-theme-subtle space-y-4">
+                        <div>
+                            <h4 class="font-bold">"Typo Tolerance"</h4>
+                            <p class="text-sm text-theme-muted">"Lower word lengths let misspelled names still match. Saving re-indexes the library."</p>
+                        </div>
+
+                        <label class="flex items-center gap-3 cursor-pointer">
+                            <input
+                                type="checkbox"
+                                class="w-4 h-4 rounded border-theme-subtle text-theme-accent focus:ring-theme-accent"
+                                prop:checked=move || search_settings.get().index_tuning.typo_tolerance.enabled
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    search_settings.update(|s| s.index_tuning.typo_tolerance.enabled = checked);
+                                    search_changed.set(true);
+                                }
+                            />
+                            <span class="text-sm text-theme-secondary">"Match words with typos"</span>
+                        </label>
+
+                        <div class="grid grid-cols-2 gap-4">
+                            <div>
+                                <label class="block text-sm text-theme-muted mb-1">"One typo from (letters)"</label>
+                                <input
+                                    type="number"
+                                    min="1"
+                                    max="20"
+                                    class="w-full px-3 py-2 rounded-lg bg-theme-deep border border-theme-subtle text-theme-primary text-sm outline-none focus:border-theme-accent"
+                                    prop:value=move || search_settings.get().index_tuning.typo_tolerance.one_typo_min_chars.to_string()
+                                    on:input=move |ev| {
+                                        if let Ok(val) = event_target_value(&ev).parse::<u8>() {
+                                            search_settings.update(|s| s.index_tuning.typo_tolerance.one_typo_min_chars = val);
+                                            search_changed.set(true);
+                                        }
+                                    }
+                                />
+                            </div>
+                            <div>
+                                <label class="block text-sm text-theme-muted mb-1">"Two typos from (letters)"</label>
+                                <input
+                                    type="number"
+                                    min="1"
+                                    max="20"
+                                    class="w-full px-3 py-2 rounded-lg bg-theme-deep border border-theme-subtle text-theme-primary text-sm outline-none focus:border-theme-accent"
+                                    prop:value=move || search_settings.get().index_tuning.typo_tolerance.two_typos_min_chars.to_string()
+                                    on:input=move |ev| {
+                                        if let Ok(val) = event_target_value(&ev).parse::<u8>() {
+                                            search_settings.update(|s| s.index_tuning.typo_tolerance.two_typos_min_chars = val);
+                                            search_changed.set(true);
+                                        }
+                                    }
+                                />
+                            </div>
+                        </div>
+
+                        <div>
+                            <label class="block text-sm text-theme-muted mb-1">"Stop words (comma separated)"</label>
+                            <input
+                                type="text"
+                                class="w-full px-3 py-2 rounded-lg bg-theme-deep border border-theme-subtle text-theme-primary text-sm outline-none focus:border-theme-accent"
+                                prop:value=move || search_settings.get().index_tuning.stop_words.join(", ")
+                                on:change=move |ev| {
+                                    let words = event_target_value(&ev)
+                                        .split(',')
+                                        .map(|w| w.trim().to_string())
+                                        .filter(|w| !w.is_empty())
+                                        .collect();
+                                    search_settings.update(|s| s.index_tuning.stop_words = words);
+                                    search_changed.set(true);
+                                }
+                            />
+                        </div>
+                    </div>
+
+                    <div class="flex justify-end pt-4 border-t border-theme-subtle">
+                        <Button
+                            variant=ButtonVariant::Primary
+                            on_click=handle_save_search
+                            disabled=Signal::derive(move || is_saving_search.get() || !search_changed.get())
+                            loading=Signal::derive(move || is_saving_search.get())
+                        >
+                            "Save Settings"
+                        </Button>
+                    </div>
+                </div>
+            </Card>
+
             // Extraction Settings Card
             <Card class="p-6">
                 <div class="space-y-6">
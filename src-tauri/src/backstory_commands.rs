@@ -157,6 +157,7 @@ fn convert_to_character(info: &BackstoryCharacterInfo) -> CharGen {
         backstory: None,
         notes: String::new(),
         portrait_prompt: None,
+        derived_stats: Vec::new(),
     }
 }
 
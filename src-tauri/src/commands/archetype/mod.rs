@@ -1,12 +1,13 @@
 //! Archetype Registry Commands
 //!
 //! Commands for managing character archetypes, vocabulary banks,
-//! setting packs, and archetype resolution.
+//! setting packs, archetype packs, and archetype resolution.
 
 pub mod types;
 pub mod crud;
 pub mod vocabulary;
 pub mod setting_packs;
+pub mod packs;
 pub mod resolution;
 
 // Re-export types
@@ -16,4 +17,5 @@ pub use types::*;
 pub use crud::*;
 pub use vocabulary::*;
 pub use setting_packs::*;
+pub use packs::*;
 pub use resolution::*;
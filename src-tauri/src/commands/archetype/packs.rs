@@ -0,0 +1,272 @@
+//! Archetype Pack Commands
+//!
+//! Commands for importing and exporting shareable archetype packs: bundles
+//! of archetypes, vocabulary banks, name banks, personality templates, and a
+//! setting pack. See `core::archetype::archetype_pack` for the file format.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::npc::naming::NameBankState;
+use crate::commands::AppState;
+use crate::core::archetype::{
+    read_pack, write_pack, Archetype, ArchetypePack, InstalledContent, PackContents, PackManifest,
+    VocabularyBank,
+};
+use crate::core::npc_gen::get_names_dir;
+use crate::core::npc_gen::name_banks::{trained_bank_path, BUILTIN_CULTURES};
+use crate::core::personality::TemplateId;
+use super::types::{get_registry, get_vocabulary_manager};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Result of importing an archetype pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchetypePackImportResult {
+    pub pack_id: String,
+    pub version: String,
+    /// Setting pack version key (format: "pack_id@version")
+    pub version_key: String,
+    pub contents: PackContents,
+    /// IDs of archetypes and vocabulary banks that already existed and were
+    /// replaced
+    pub replaced: Vec<String>,
+}
+
+/// What to put in an exported archetype pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportArchetypePackRequest {
+    pub manifest: PackManifest,
+    #[serde(default)]
+    pub archetype_ids: Vec<String>,
+    #[serde(default)]
+    pub vocabulary_bank_ids: Vec<String>,
+    /// Custom or trained name bank culture IDs
+    #[serde(default)]
+    pub name_bank_ids: Vec<String>,
+    #[serde(default)]
+    pub personality_template_ids: Vec<String>,
+    /// Include the loaded setting pack with the manifest's ID and version
+    #[serde(default)]
+    pub include_setting: bool,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Installed packs, archetypes, and vocabulary banks
+async fn installed_content(state: &AppState) -> Result<InstalledContent, String> {
+    let registry = get_registry(state).await?;
+    let vocabulary = get_vocabulary_manager(state).await?;
+    Ok(InstalledContent {
+        packs: state.setting_pack_loader.list_all_versions().await.into_iter().collect(),
+        archetype_ids: registry.list(None).await.into_iter().map(|a| a.id.to_string()).collect(),
+        vocabulary_bank_ids: vocabulary.list_banks(None).await.into_iter().map(|b| b.id).collect(),
+    })
+}
+
+/// Order archetypes so every parent bundled in the pack comes before its
+/// children
+fn parents_first(archetypes: Vec<Archetype>) -> Vec<Archetype> {
+    let bundled: HashSet<String> = archetypes.iter().map(|a| a.id.to_string()).collect();
+    let mut placed: HashSet<String> = HashSet::new();
+    let mut ordered = Vec::with_capacity(archetypes.len());
+    let mut pending = archetypes;
+    while !pending.is_empty() {
+        let (ready, waiting): (Vec<_>, Vec<_>) = pending.into_iter().partition(|a| {
+            a.parent_id
+                .as_ref()
+                .map_or(true, |p| !bundled.contains(p.as_str()) || placed.contains(p.as_str()))
+        });
+        if ready.is_empty() {
+            // Cycle; let the registry report it
+            ordered.extend(waiting);
+            break;
+        }
+        placed.extend(ready.iter().map(|a| a.id.to_string()));
+        ordered.extend(ready);
+        pending = waiting;
+    }
+    ordered
+}
+
+// ============================================================================
+// Archetype Pack Commands
+// ============================================================================
+
+/// Import an archetype pack from a JSON file or zip archive.
+///
+/// The pack's dependencies must already be installed at a matching version,
+/// and everything its archetypes refer to must be bundled or installed.
+/// Archetypes, vocabulary banks, name banks, and templates with the same ID
+/// as existing ones replace them; built-in name banks cannot be replaced.
+///
+/// # Arguments
+/// * `path` - Path to the pack file
+#[tauri::command]
+pub async fn import_archetype_pack(
+    path: String,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<ArchetypePackImportResult, String> {
+    let pack = read_pack(Path::new(&path)).map_err(|e| e.to_string())?;
+    pack.validate(&installed_content(&state).await?, env!("CARGO_PKG_VERSION"))
+        .map_err(|e| e.to_string())?;
+
+    let culture_ids = pack
+        .name_banks
+        .iter()
+        .map(|r| r.culture_id.as_str())
+        .chain(pack.trained_name_banks.iter().map(|b| b.culture_id.as_str()));
+    for id in culture_ids {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-') {
+            return Err(format!("Name bank ID '{}' must use only lowercase letters, digits, '_' and '-'", id));
+        }
+        if BUILTIN_CULTURES.contains(&id) {
+            return Err(format!("'{}' is a built-in name bank", id));
+        }
+    }
+
+    let contents = pack.contents();
+    let setting = pack.setting_pack();
+    let ArchetypePack {
+        manifest,
+        archetypes,
+        vocabulary_banks,
+        name_banks: naming_rules,
+        trained_name_banks,
+        personality_templates,
+        ..
+    } = pack;
+    let mut replaced = Vec::new();
+
+    let vocabulary = get_vocabulary_manager(&state).await?;
+    for definition in vocabulary_banks {
+        match vocabulary.get_bank(&definition.id).await {
+            Ok(existing) => {
+                replaced.push(definition.id.clone());
+                let bank = VocabularyBank { definition, ..existing };
+                vocabulary.update(bank).await.map_err(|e| e.to_string())?;
+            }
+            Err(_) => {
+                vocabulary.register(definition).await.map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    let registry = get_registry(&state).await?;
+    for mut archetype in parents_first(archetypes) {
+        if archetype.setting_pack_id.is_none() {
+            archetype.setting_pack_id = Some(manifest.id.clone());
+        }
+        if registry.exists(archetype.id.as_str()).await {
+            replaced.push(archetype.id.to_string());
+            registry.update(archetype).await.map_err(|e| e.to_string())?;
+        } else {
+            registry.register(archetype).await.map_err(|e| e.to_string())?;
+        }
+    }
+
+    let names_dir = get_names_dir();
+    let names = name_banks.registry();
+    for rules in naming_rules {
+        let path = names_dir.join(format!("{}.yaml", rules.culture_id));
+        let yaml = serde_yaml_ng::to_string(&rules).map_err(|e| e.to_string())?;
+        names.add_rules(rules).map_err(|e| e.to_string())?;
+        tokio::fs::create_dir_all(&names_dir).await.map_err(|e| e.to_string())?;
+        tokio::fs::write(&path, yaml).await.map_err(|e| e.to_string())?;
+    }
+    for bank in trained_name_banks {
+        let path = trained_bank_path(&names_dir, &bank.culture_id);
+        let yaml = serde_yaml_ng::to_string(&bank).map_err(|e| e.to_string())?;
+        names.add_trained(bank).map_err(|e| e.to_string())?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| e.to_string())?;
+        }
+        tokio::fs::write(&path, yaml).await.map_err(|e| e.to_string())?;
+    }
+
+    for template in &personality_templates {
+        state.template_store.save(template).await.map_err(|e| e.to_string())?;
+    }
+
+    let version_key = state.setting_pack_loader.load_pack(setting).await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Imported archetype pack {} from {}", version_key, path);
+    Ok(ArchetypePackImportResult {
+        pack_id: manifest.id,
+        version: manifest.version,
+        version_key,
+        contents,
+        replaced,
+    })
+}
+
+/// Export archetypes, vocabulary banks, name banks, and personality
+/// templates as an archetype pack.
+///
+/// Writes a single JSON file when `path` ends in `.json`, otherwise a zip
+/// archive. The manifest's dependencies must be installed so the pack can be
+/// imported elsewhere.
+///
+/// # Arguments
+/// * `request` - Manifest and the IDs of the content to include
+/// * `path` - Destination file path
+#[tauri::command]
+pub async fn export_archetype_pack(
+    request: ExportArchetypePackRequest,
+    path: String,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<PackContents, String> {
+    let mut pack = ArchetypePack::new(request.manifest);
+
+    let registry = get_registry(&state).await?;
+    for id in &request.archetype_ids {
+        pack.archetypes.push(registry.get(id).await.map_err(|e| e.to_string())?);
+    }
+
+    let vocabulary = get_vocabulary_manager(&state).await?;
+    for id in &request.vocabulary_bank_ids {
+        pack.vocabulary_banks.push(vocabulary.get_bank(id).await.map_err(|e| e.to_string())?.definition);
+    }
+
+    let names = name_banks.registry();
+    for id in &request.name_bank_ids {
+        if let Some(rules) = names.custom_rules(id) {
+            pack.name_banks.push(rules);
+        } else if let Some(bank) = names.trained_bank(id) {
+            pack.trained_name_banks.push(bank);
+        } else {
+            return Err(format!("No custom or trained name bank '{}'", id));
+        }
+    }
+
+    for id in &request.personality_template_ids {
+        let template = state.template_store.get(&TemplateId::new(id)).await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Personality template not found: {}", id))?;
+        pack.personality_templates.push(template);
+    }
+
+    if request.include_setting {
+        let setting = state.setting_pack_loader
+            .get_version(&pack.manifest.id, &pack.manifest.version).await
+            .map_err(|e| e.to_string())?;
+        pack.setting = Some(setting);
+    }
+
+    pack.validate(&installed_content(&state).await?, env!("CARGO_PKG_VERSION"))
+        .map_err(|e| e.to_string())?;
+    write_pack(&pack, Path::new(&path)).map_err(|e| e.to_string())?;
+
+    log::info!("Exported archetype pack {}@{} to {}", pack.manifest.id, pack.manifest.version, path);
+    Ok(pack.contents())
+}
@@ -0,0 +1,270 @@
+//! Campaign Archive Commands
+//!
+//! Commands for exporting a campaign with all of its subsystems to a single
+//! archive file and importing archives (including older versions) back in.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, State};
+use uuid::Uuid;
+
+use crate::commands::{AppState, ReadAloudState, VoiceProfileState};
+use crate::core::campaign::{
+    read_archive, write_archive, ArchiveManifest, ArchivedSession, CampaignArchive,
+};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// What an archive import restored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveImportSummary {
+    pub campaign_id: String,
+    /// Schema version the archive was written with (before migration)
+    pub source_schema_version: u32,
+    pub sessions: usize,
+    pub npcs: usize,
+    pub locations: usize,
+    pub relationships: usize,
+    pub voice_profiles: usize,
+    pub assets: usize,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn get_imported_assets_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("imported_assets").join(Uuid::new_v4().to_string())
+}
+
+/// Gather a campaign and everything attached to it
+pub(crate) async fn collect_archive(
+    campaign_id: &str,
+    state: &AppState,
+    profiles: &VoiceProfileState,
+    read_aloud: &ReadAloudState,
+) -> Result<CampaignArchive, String> {
+    let export = state
+        .campaign_manager
+        .export_campaign(campaign_id)
+        .map_err(|e| e.to_string())?;
+    let mut archive = CampaignArchive::new(export);
+
+    let voice_manager = state.voice_manager.read().await;
+    let read_aloud_store = read_aloud.store.lock().await;
+    for summary in state.session_manager.list_sessions(campaign_id) {
+        let Some(session) = state.session_manager.get_session(&summary.id) else {
+            continue;
+        };
+        let passages = read_aloud_store.load(&session.id).map_err(|e| e.to_string())?;
+        let audio_log = match voice_manager.session_log() {
+            Some(log) => log.load(&session.id).map_err(|e| e.to_string())?,
+            None => Vec::new(),
+        };
+        archive.sessions.push(ArchivedSession {
+            timeline: state.session_manager.get_timeline_events(&session.id),
+            notes: state.session_manager.list_notes_for_session(&session.id),
+            read_aloud: (!passages.passages.is_empty()).then_some(passages),
+            audio_log,
+            session,
+        });
+    }
+    drop(read_aloud_store);
+    drop(voice_manager);
+
+    archive.npcs = state.npc_store.list(Some(campaign_id));
+    archive.locations = state.location_manager.list_locations_for_campaign(campaign_id);
+    archive.relationships = state
+        .relationship_manager
+        .list_relationships(campaign_id)
+        .into_iter()
+        .filter_map(|summary| state.relationship_manager.get_relationship(campaign_id, &summary.id))
+        .collect();
+    archive.world_state = state.world_state_manager.get_state(campaign_id);
+    archive.personality = Some(state.personality_manager.get_context(campaign_id));
+
+    let manager = profiles.manager.read().await;
+    for npc in &archive.npcs {
+        let Some(profile) = manager.get_profile_for_npc(&npc.id) else {
+            continue;
+        };
+        archive.npc_voice_profiles.insert(npc.id.clone(), profile.id.clone());
+        if !profile.is_preset && !archive.voice_profiles.iter().any(|p| p.id == profile.id) {
+            archive.voice_profiles.push(profile.clone());
+        }
+    }
+
+    Ok(archive)
+}
+
+/// Load an archive's contents into the running managers
+async fn restore_archive(
+    archive: CampaignArchive,
+    state: &AppState,
+    profiles: &VoiceProfileState,
+    read_aloud: &ReadAloudState,
+) -> Result<ArchiveImportSummary, String> {
+    let campaign_id = archive.campaign_id().to_string();
+    let mut summary = ArchiveImportSummary {
+        campaign_id: campaign_id.clone(),
+        source_schema_version: 0,
+        sessions: archive.sessions.len(),
+        npcs: archive.npcs.len(),
+        locations: archive.locations.len(),
+        relationships: 0,
+        voice_profiles: 0,
+        assets: 0,
+    };
+
+    state
+        .campaign_manager
+        .import_campaign(archive.campaign, false)
+        .map_err(|e| e.to_string())?;
+
+    let voice_manager = state.voice_manager.read().await;
+    let read_aloud_store = read_aloud.store.lock().await;
+    for archived in archive.sessions {
+        let session_id = archived.session.id.clone();
+        state.session_manager.import_session(archived.session);
+        for event in archived.timeline {
+            state
+                .session_manager
+                .add_timeline_event(&session_id, event)
+                .map_err(|e| e.to_string())?;
+        }
+        for note in archived.notes {
+            state.session_manager.create_note(note).map_err(|e| e.to_string())?;
+        }
+
+        if let Some(mut passages) = archived.read_aloud {
+            for passage in &mut passages.passages {
+                let Some(path) = passage.audio_path.clone().filter(|p| p.exists()) else {
+                    continue;
+                };
+                let stored = read_aloud_store
+                    .store_audio(&session_id, &passage.id, &path)
+                    .map_err(|e| e.to_string())?;
+                passage.audio_path = Some(stored);
+                summary.assets += 1;
+            }
+            read_aloud_store.save(&passages).map_err(|e| e.to_string())?;
+        }
+
+        if let Some(log) = voice_manager.session_log() {
+            for utterance in &archived.audio_log {
+                log.record(&session_id, utterance).map_err(|e| e.to_string())?;
+            }
+            summary.assets += archived.audio_log.iter().filter(|u| u.audio_path.exists()).count();
+        }
+    }
+    drop(read_aloud_store);
+    drop(voice_manager);
+
+    for npc in archive.npcs {
+        state.npc_store.add(npc, Some(&campaign_id));
+    }
+    for location in archive.locations {
+        state.location_manager.save_location(location).map_err(|e| e.to_string())?;
+    }
+    for relationship in archive.relationships {
+        match state.relationship_manager.create_relationship(relationship) {
+            Ok(_) => summary.relationships += 1,
+            Err(e) => log::warn!("Skipping archived relationship: {}", e),
+        }
+    }
+    if let Some(world) = archive.world_state {
+        state.world_state_manager.update_state(world).map_err(|e| e.to_string())?;
+    }
+    if let Some(context) = archive.personality {
+        state.personality_manager.set_context(context);
+    }
+
+    let mut manager = profiles.manager.write().await;
+    for profile in archive.voice_profiles {
+        if manager.get_profile(&profile.id).is_some() {
+            continue;
+        }
+        match manager.create_profile(profile) {
+            Ok(_) => summary.voice_profiles += 1,
+            Err(e) => log::warn!("Skipping archived voice profile: {}", e),
+        }
+    }
+    for (npc_id, profile_id) in archive.npc_voice_profiles {
+        if let Err(e) = manager.link_to_npc(&profile_id, &npc_id) {
+            log::warn!("Could not restore voice for NPC {}: {}", npc_id, e);
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Read an archive file and load it into the running managers
+pub(crate) async fn import_archive_file(
+    source: PathBuf,
+    new_ids: bool,
+    app_handle: &tauri::AppHandle,
+    state: &AppState,
+    profiles: &VoiceProfileState,
+    read_aloud: &ReadAloudState,
+) -> Result<ArchiveImportSummary, String> {
+    let assets_dir = get_imported_assets_dir(app_handle);
+    let (manifest, mut archive) = tokio::task::spawn_blocking(move || read_archive(&source, &assets_dir))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    if new_ids {
+        archive.regenerate_ids();
+    } else if state.campaign_manager.get_campaign(archive.campaign_id()).is_some() {
+        return Err(format!(
+            "Campaign {} already exists; import with new IDs to keep both",
+            archive.campaign_id()
+        ));
+    }
+
+    let mut summary = restore_archive(archive, state, profiles, read_aloud).await?;
+    summary.source_schema_version = manifest.schema_version;
+    Ok(summary)
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Export a campaign and all of its subsystems to a single archive file.
+#[tauri::command]
+pub async fn export_campaign_archive(
+    campaign_id: String,
+    path: String,
+    state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<ArchiveManifest, String> {
+    let archive = collect_archive(&campaign_id, &state, &profiles, &read_aloud).await?;
+    let dest = PathBuf::from(path);
+    tokio::task::spawn_blocking(move || write_archive(&archive, &dest))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Import a campaign archive, migrating older versions.
+///
+/// With `new_ids`, the campaign and everything in it get fresh IDs so the
+/// archive can be imported alongside the original. Without it, importing
+/// over an existing campaign is refused.
+#[tauri::command]
+pub async fn import_campaign_archive(
+    path: String,
+    new_ids: bool,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<ArchiveImportSummary, String> {
+    import_archive_file(PathBuf::from(path), new_ids, &app_handle, &state, &profiles, &read_aloud).await
+}
@@ -0,0 +1,246 @@
+//! Campaign Backup Commands
+//!
+//! Commands for scheduled campaign backups. A background task checks the
+//! schedule once a minute and writes a campaign archive per campaign to the
+//! backup directory, then prunes old backups under the retention policy.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, State};
+use tokio::sync::Mutex as AsyncMutex;
+
+use super::archive::{collect_archive, import_archive_file, ArchiveImportSummary};
+use crate::commands::{AppState, ReadAloudState, VoiceProfileState};
+use crate::core::campaign::backup::{BackupConfig, BackupError, BackupManager, BackupRecord, BackupStore};
+
+/// How often the scheduler checks whether a backup is due
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding backup settings
+pub struct BackupState {
+    pub manager: BackupManager,
+    /// Held while a backup run is in progress so runs never overlap
+    running: AsyncMutex<()>,
+}
+
+impl BackupState {
+    pub fn new(config: BackupConfig) -> Self {
+        Self {
+            manager: BackupManager::new(config),
+            running: AsyncMutex::new(()),
+        }
+    }
+}
+
+/// Backup settings with the next scheduled run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupStatus {
+    pub config: BackupConfig,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_backup_config_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("backup_config.json")
+}
+
+/// Load backup settings from disk
+pub fn load_backup_config_disk(app_handle: &tauri::AppHandle) -> Option<BackupConfig> {
+    let path = get_backup_config_path(app_handle);
+    if !path.exists() {
+        return None;
+    }
+    match BackupConfig::load(&path) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            log::warn!("Failed to parse backup config: {}", e);
+            None
+        }
+    }
+}
+
+fn backup_store(backups: &BackupState) -> Result<BackupStore, String> {
+    backups
+        .manager
+        .config()
+        .directory
+        .map(BackupStore::new)
+        .ok_or_else(|| BackupError::NoDirectory.to_string())
+}
+
+fn status(backups: &BackupState) -> BackupStatus {
+    BackupStatus {
+        config: backups.manager.config(),
+        next_run: backups.manager.next_run(),
+    }
+}
+
+// ============================================================================
+// Backup Runs
+// ============================================================================
+
+/// Back up the given campaigns, or every campaign the settings cover, then
+/// apply the retention policy. Campaigns that fail are logged and skipped.
+async fn run_backups(app_handle: &tauri::AppHandle, campaign_ids: Option<Vec<String>>) -> Result<Vec<BackupRecord>, String> {
+    let backups = app_handle.state::<BackupState>();
+    let state = app_handle.state::<AppState>();
+    let profiles = app_handle.state::<VoiceProfileState>();
+    let read_aloud = app_handle.state::<ReadAloudState>();
+
+    let _running = backups.running.lock().await;
+    let config = backups.manager.config();
+    let offset = config.offset().map_err(|e| e.to_string())?;
+    let dir = config.directory.clone().ok_or_else(|| BackupError::NoDirectory.to_string())?;
+    let campaign_ids = campaign_ids.unwrap_or_else(|| {
+        state
+            .campaign_manager
+            .list_campaigns()
+            .into_iter()
+            .map(|c| c.id)
+            .filter(|id| config.includes(id))
+            .collect()
+    });
+
+    let now = Utc::now();
+    let mut written = Vec::new();
+    for campaign_id in campaign_ids {
+        let archive = match collect_archive(&campaign_id, &state, &profiles, &read_aloud).await {
+            Ok(archive) => archive,
+            Err(e) => {
+                log::warn!("Skipping backup of campaign {}: {}", campaign_id, e);
+                continue;
+            }
+        };
+        let dir = dir.clone();
+        let retention = config.retention.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let store = BackupStore::new(dir);
+            let record = store.write_backup(&archive, now)?;
+            let pruned = store.prune(&record.campaign_id, &retention, offset)?;
+            Ok::<_, BackupError>((record, pruned.len()))
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match result {
+            Ok((record, pruned)) => {
+                log::info!("Backed up campaign {} to {} ({} old backups pruned)", campaign_id, record.file_name, pruned);
+                written.push(record);
+            }
+            Err(e) => log::warn!("Backup of campaign {} failed: {}", campaign_id, e),
+        }
+    }
+
+    backups.manager.mark_run(now);
+    if let Err(e) = backups.manager.config().save(&get_backup_config_path(app_handle)) {
+        log::warn!("Failed to save backup config: {}", e);
+    }
+    Ok(written)
+}
+
+/// Start the background task that runs scheduled backups
+pub fn start_backup_scheduler(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(SCHEDULER_TICK).await;
+            let due = app_handle.state::<BackupState>().manager.is_due(Utc::now());
+            if !due {
+                continue;
+            }
+            match run_backups(&app_handle, None).await {
+                Ok(written) => log::info!("Scheduled backup wrote {} campaign archives", written.len()),
+                Err(e) => log::warn!("Scheduled backup failed: {}", e),
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get the backup settings and when the next scheduled backup will run.
+#[tauri::command]
+pub fn get_backup_config(backups: State<'_, BackupState>) -> Result<BackupStatus, String> {
+    Ok(status(&backups))
+}
+
+/// Replace the backup settings.
+///
+/// `schedule` is a five-field cron expression (minute, hour, day of month,
+/// month, day of week) read in `utc_offset_minutes` local time, or one of
+/// `@hourly`, `@daily`, `@weekly`, `@monthly`.
+#[tauri::command]
+pub fn set_backup_config(
+    config: BackupConfig,
+    app_handle: tauri::AppHandle,
+    backups: State<'_, BackupState>,
+) -> Result<BackupStatus, String> {
+    let config = backups.manager.set_config(config).map_err(|e| e.to_string())?;
+    config
+        .save(&get_backup_config_path(&app_handle))
+        .map_err(|e| e.to_string())?;
+    Ok(status(&backups))
+}
+
+/// Back up now, outside the schedule.
+///
+/// # Arguments
+/// * `campaign_id` - Campaign to back up (default: every campaign the settings cover)
+#[tauri::command]
+pub async fn run_backup_now(campaign_id: Option<String>, app_handle: tauri::AppHandle) -> Result<Vec<BackupRecord>, String> {
+    run_backups(&app_handle, campaign_id.map(|id| vec![id])).await
+}
+
+/// List backups in the backup directory, newest first.
+#[tauri::command]
+pub fn list_backups(campaign_id: Option<String>, backups: State<'_, BackupState>) -> Result<Vec<BackupRecord>, String> {
+    backup_store(&backups)?
+        .list_backups(campaign_id.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+/// Check a backup file against its recorded checksum and that it reads back.
+#[tauri::command]
+pub async fn verify_backup(backup_id: String, backups: State<'_, BackupState>) -> Result<BackupRecord, String> {
+    let store = backup_store(&backups)?;
+    tokio::task::spawn_blocking(move || store.verify_backup(&backup_id))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Restore a campaign from a backup after verifying it.
+///
+/// With `new_ids`, the restored campaign gets fresh IDs so it can sit
+/// alongside the original. Without it, restoring over an existing campaign
+/// is refused.
+#[tauri::command]
+pub async fn restore_backup(
+    backup_id: String,
+    new_ids: bool,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
+    read_aloud: State<'_, ReadAloudState>,
+    backups: State<'_, BackupState>,
+) -> Result<ArchiveImportSummary, String> {
+    let store = backup_store(&backups)?;
+    let path = tokio::task::spawn_blocking(move || store.verify_backup(&backup_id).map(|record| store.path_of(&record)))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    import_archive_file(path, new_ids, &app_handle, &state, &profiles, &read_aloud).await
+}
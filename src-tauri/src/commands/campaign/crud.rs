@@ -4,8 +4,11 @@
 
 use tauri::State;
 
-use crate::commands::AppState;
+use crate::commands::{AppState, BookmarkState, TermSetState};
 use crate::core::models::Campaign;
+use crate::core::storage::delete_campaign_vectors;
+
+use super::encryption::forget_campaign_keys;
 
 // ============================================================================
 // Campaign CRUD Commands
@@ -44,9 +47,30 @@ pub fn update_campaign(
         .map_err(|e| e.to_string())
 }
 
-/// Delete a campaign by ID.
+/// Delete a campaign by ID, along with its custom search term sets,
+/// bookmarks, and search vectors. Vectors are removed in the background.
 #[tauri::command]
-pub fn delete_campaign(id: String, state: State<'_, AppState>) -> Result<(), String> {
+pub fn delete_campaign(
+    id: String,
+    state: State<'_, AppState>,
+    term_sets: State<'_, TermSetState>,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<(), String> {
+    let encrypted = state.campaign_manager.is_encrypted(&id);
     state.campaign_manager.delete_campaign(&id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    if encrypted {
+        forget_campaign_keys(&state.credentials, &id)?;
+    }
+    if let Some(storage) = state.surreal_storage.clone() {
+        let campaign_id = id.clone();
+        tauri::async_runtime::spawn(async move {
+            match delete_campaign_vectors(storage.db(), &campaign_id).await {
+                Ok(deleted) => log::info!("Deleted {} vectors of campaign {}", deleted, campaign_id),
+                Err(e) => log::warn!("Failed to delete vectors of campaign {}: {}", campaign_id, e),
+            }
+        });
+    }
+    term_sets.delete_campaign_sets(&id)?;
+    bookmarks.delete_campaign_bookmarks(&id)
 }
@@ -0,0 +1,169 @@
+//! Encounter Builder Commands
+//!
+//! Commands for working out XP budgets against the party, finding creatures
+//! in the ingested rulebooks, and saving encounters to load into combat.
+
+use tauri::State;
+
+use crate::commands::{AppState, PartyState};
+use crate::core::campaign::encounter_builder::{
+    evaluate_encounter, select_candidates, xp_budget, CandidateQuery, CreatureCandidate, EncounterCreature,
+    EncounterEvaluation, EncounterManager, EncounterRules, SavedEncounter, XpBudget,
+};
+use crate::core::session::plan_types::EncounterDifficulty;
+use crate::database::TtrpgOps;
+
+/// Widest challenge rating range searched when the query leaves it open
+const MAX_CHALLENGE_RATING: f64 = 30.0;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding saved encounters
+#[derive(Default)]
+pub struct EncounterState {
+    pub manager: EncounterManager,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Encounter rules and active party levels for a campaign
+fn campaign_party(
+    campaign_id: &str,
+    state: &AppState,
+    party: &PartyState,
+) -> Result<(EncounterRules, Vec<u8>), String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let levels = party
+        .manager
+        .list_characters(campaign_id, false)
+        .iter()
+        .map(|c| c.level)
+        .collect();
+    Ok((EncounterRules::for_system(&campaign.system), levels))
+}
+
+// ============================================================================
+// Budget Commands
+// ============================================================================
+
+/// Get the XP budget for an encounter of a difficulty against the campaign's
+/// active party.
+///
+/// # Arguments
+/// * `party_levels` - Levels to budget for instead of the roster
+#[tauri::command]
+pub fn get_encounter_budget(
+    campaign_id: String,
+    difficulty: EncounterDifficulty,
+    party_levels: Option<Vec<u8>>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+) -> Result<XpBudget, String> {
+    let (rules, levels) = campaign_party(&campaign_id, &state, &party)?;
+    xp_budget(rules, &party_levels.unwrap_or(levels), &difficulty).map_err(|e| e.to_string())
+}
+
+/// Rate a set of creatures against the campaign's active party.
+#[tauri::command]
+pub fn evaluate_encounter_difficulty(
+    campaign_id: String,
+    creatures: Vec<EncounterCreature>,
+    target_difficulty: Option<EncounterDifficulty>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+) -> Result<EncounterEvaluation, String> {
+    let (rules, levels) = campaign_party(&campaign_id, &state, &party)?;
+    evaluate_encounter(rules, &creatures, &levels, &target_difficulty.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Find ingested creatures for an encounter of a difficulty, strongest first.
+/// Only creatures that fit within the budget on their own are returned.
+#[tauri::command]
+pub async fn find_encounter_candidates(
+    campaign_id: String,
+    difficulty: EncounterDifficulty,
+    query: Option<CandidateQuery>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+) -> Result<Vec<CreatureCandidate>, String> {
+    let (rules, levels) = campaign_party(&campaign_id, &state, &party)?;
+    let budget = xp_budget(rules, &levels, &difficulty).map_err(|e| e.to_string())?;
+    let query = query.unwrap_or_default();
+
+    let records = match rules {
+        EncounterRules::Dnd5e => {
+            let min = query.min_cr.map_or(0.0, f64::from);
+            let max = query.max_cr.map_or(MAX_CHALLENGE_RATING, f64::from);
+            state.database.list_ttrpg_documents_by_cr(min, max).await
+        }
+        EncounterRules::Pathfinder2e => state.database.list_ttrpg_documents_by_type("monster").await,
+    }
+    .map_err(|e| e.to_string())?;
+
+    Ok(select_candidates(&records, &query, &budget))
+}
+
+// ============================================================================
+// Saved Encounter Commands
+// ============================================================================
+
+/// Save an encounter for a campaign. Pass `encounter_id` to replace an
+/// existing one.
+#[tauri::command]
+pub fn save_encounter(
+    campaign_id: String,
+    name: String,
+    creatures: Vec<EncounterCreature>,
+    target_difficulty: Option<EncounterDifficulty>,
+    description: Option<String>,
+    environment: Option<String>,
+    encounter_id: Option<String>,
+    state: State<'_, AppState>,
+    encounters: State<'_, EncounterState>,
+) -> Result<SavedEncounter, String> {
+    let rules = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .map(|c| EncounterRules::for_system(&c.system))
+        .unwrap_or_default();
+    let mut encounter = match encounter_id {
+        Some(id) => encounters.manager.get(&id).map_err(|e| e.to_string())?,
+        None => SavedEncounter::new(&campaign_id, &name, rules, target_difficulty.clone().unwrap_or_default()),
+    };
+    encounter.name = name;
+    encounter.creatures = creatures;
+    if let Some(difficulty) = target_difficulty {
+        encounter.target_difficulty = difficulty;
+    }
+    if let Some(description) = description {
+        encounter.description = description;
+    }
+    encounter.environment = environment.or(encounter.environment);
+    encounters.manager.save(encounter).map_err(|e| e.to_string())
+}
+
+/// Get a saved encounter by ID
+#[tauri::command]
+pub fn get_encounter(encounter_id: String, encounters: State<'_, EncounterState>) -> Result<SavedEncounter, String> {
+    encounters.manager.get(&encounter_id).map_err(|e| e.to_string())
+}
+
+/// List a campaign's saved encounters, most recently updated first
+#[tauri::command]
+pub fn list_encounters(campaign_id: String, encounters: State<'_, EncounterState>) -> Result<Vec<SavedEncounter>, String> {
+    Ok(encounters.manager.list(&campaign_id))
+}
+
+/// Delete a saved encounter
+#[tauri::command]
+pub fn delete_encounter(encounter_id: String, encounters: State<'_, EncounterState>) -> Result<(), String> {
+    encounters.manager.delete(&encounter_id).map_err(|e| e.to_string())
+}
@@ -0,0 +1,130 @@
+//! Campaign Encryption Commands
+//!
+//! Commands for encrypting campaigns at rest. Data keys live in the OS
+//! keyring; locking a campaign drops its plaintext from memory until it is
+//! unlocked with the keyring key or its recovery phrase.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::campaign::encryption::{key_entry, recovery_entry, CampaignKey, CampaignKeys, RecoveryPhrase};
+use crate::core::campaign_manager::LockedCampaign;
+use crate::core::credentials::CredentialManager;
+use crate::core::models::Campaign;
+
+// ============================================================================
+// Keyring Helpers
+// ============================================================================
+
+/// A campaign's data key from the keyring, if one is stored and readable
+pub fn keyring_campaign_key(credentials: &CredentialManager, campaign_id: &str) -> Option<CampaignKey> {
+    let encoded = credentials.get_secret(&key_entry(campaign_id)).ok()?;
+    CampaignKey::from_base64(&encoded).ok()
+}
+
+fn store_campaign_keys(
+    credentials: &CredentialManager,
+    campaign_id: &str,
+    key: &CampaignKey,
+    phrase: &RecoveryPhrase,
+) -> Result<(), String> {
+    credentials
+        .store_secret(&key_entry(campaign_id), &key.to_base64())
+        .and_then(|_| credentials.store_secret(&recovery_entry(campaign_id), phrase.as_str()))
+        .map_err(|e| e.to_string())
+}
+
+pub(crate) fn forget_campaign_keys(credentials: &CredentialManager, campaign_id: &str) -> Result<(), String> {
+    credentials
+        .delete_secret(&key_entry(campaign_id))
+        .and_then(|_| credentials.delete_secret(&recovery_entry(campaign_id)))
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Encryption Commands
+// ============================================================================
+
+/// Encrypt a campaign's storage. Returns the recovery phrase, which can
+/// unlock the campaign if the keyring entry is ever lost.
+#[tauri::command]
+pub async fn enable_campaign_encryption(campaign_id: String, state: State<'_, AppState>) -> Result<String, String> {
+    if state.campaign_manager.get_campaign(&campaign_id).is_none() {
+        return Err(format!("Campaign not found: {}", campaign_id));
+    }
+    let (keys, phrase) = CampaignKeys::generate(&campaign_id).map_err(|e| e.to_string())?;
+    store_campaign_keys(&state.credentials, &campaign_id, &keys.data_key, &phrase)?;
+
+    if let Err(e) = state.campaign_manager.encrypt_campaign(&campaign_id, keys) {
+        let _ = forget_campaign_keys(&state.credentials, &campaign_id);
+        return Err(e.to_string());
+    }
+    Ok(phrase.as_str().to_string())
+}
+
+/// Stop encrypting a campaign and remove its keys from the keyring.
+/// The campaign must be unlocked.
+#[tauri::command]
+pub fn disable_campaign_encryption(campaign_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    state
+        .campaign_manager
+        .decrypt_campaign(&campaign_id)
+        .map_err(|e| e.to_string())?;
+    forget_campaign_keys(&state.credentials, &campaign_id)
+}
+
+/// Lock an encrypted campaign, removing its decrypted data from memory.
+#[tauri::command]
+pub fn lock_campaign(campaign_id: String, state: State<'_, AppState>) -> Result<LockedCampaign, String> {
+    state
+        .campaign_manager
+        .lock_campaign(&campaign_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Unlock an encrypted campaign with the key in the keyring, or with its
+/// recovery phrase. Unlocking by phrase restores the key to the keyring.
+#[tauri::command]
+pub async fn unlock_campaign(
+    campaign_id: String,
+    recovery_phrase: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Campaign, String> {
+    let key = match recovery_phrase {
+        Some(phrase) => {
+            let phrase = RecoveryPhrase::parse(&phrase).map_err(|e| e.to_string())?;
+            let key = state
+                .campaign_manager
+                .read_encrypted(&campaign_id)
+                .and_then(|sealed| sealed.recover_key(&phrase).map_err(Into::into))
+                .map_err(|e| e.to_string())?;
+            store_campaign_keys(&state.credentials, &campaign_id, &key, &phrase)?;
+            key
+        }
+        None => keyring_campaign_key(&state.credentials, &campaign_id)
+            .ok_or_else(|| "No key in the keyring for this campaign; unlock it with its recovery phrase".to_string())?,
+    };
+
+    state
+        .campaign_manager
+        .unlock_campaign(&campaign_id, &key)
+        .map_err(|e| e.to_string())
+}
+
+/// Get an encrypted campaign's recovery phrase from the keyring.
+#[tauri::command]
+pub fn export_recovery_phrase(campaign_id: String, state: State<'_, AppState>) -> Result<String, String> {
+    if !state.campaign_manager.is_encrypted(&campaign_id) {
+        return Err(format!("Campaign is not encrypted: {}", campaign_id));
+    }
+    state
+        .credentials
+        .get_secret(&recovery_entry(&campaign_id))
+        .map_err(|e| e.to_string())
+}
+
+/// List encrypted campaigns that are locked.
+#[tauri::command]
+pub fn list_locked_campaigns(state: State<'_, AppState>) -> Result<Vec<LockedCampaign>, String> {
+    Ok(state.campaign_manager.list_locked_campaigns())
+}
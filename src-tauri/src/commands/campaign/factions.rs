@@ -0,0 +1,256 @@
+//! Faction Commands
+//!
+//! Commands for managing factions and organizations, their influence and
+//! clocks, their relationships to other factions and NPCs, and the faction
+//! web shown in the relationship graph view.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::relationships::crud::{parse_relationship_strength, parse_relationship_type};
+use crate::commands::world::setting::{campaign_factions, WorldSettingState};
+use crate::commands::AppState;
+use crate::core::campaign::factions::{Faction, FactionClock, FactionEventOutcome, FactionManager};
+use crate::core::campaign::relationships::{EntityGraph, EntityRelationship, EntityType};
+use crate::core::campaign::world_state::WorldEventType;
+
+/// Event emitted when a world event changes faction influence or clocks
+pub const FACTION_UPDATE_EVENT: &str = "faction:updated";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign factions
+#[derive(Default)]
+pub struct FactionState {
+    pub manager: FactionManager,
+}
+
+/// Payload for [`FACTION_UPDATE_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionUpdateEvent {
+    pub campaign_id: String,
+    pub outcomes: Vec<FactionEventOutcome>,
+}
+
+// ============================================================================
+// Faction CRUD Commands
+// ============================================================================
+
+/// Create a faction.
+#[tauri::command]
+pub fn create_faction(
+    campaign_id: String,
+    name: String,
+    description: Option<String>,
+    influence: Option<i32>,
+    factions: State<'_, FactionState>,
+) -> Result<Faction, String> {
+    let mut faction = Faction::new(&campaign_id, &name).with_description(description.as_deref().unwrap_or_default());
+    if let Some(influence) = influence {
+        faction.influence = influence;
+    }
+    factions.manager.create_faction(faction).map_err(|e| e.to_string())
+}
+
+/// Get a faction by ID.
+#[tauri::command]
+pub fn get_faction(faction_id: String, factions: State<'_, FactionState>) -> Result<Option<Faction>, String> {
+    Ok(factions.manager.get_faction(&faction_id))
+}
+
+/// Replace a faction, including goals, resources, members, and territory.
+#[tauri::command]
+pub fn update_faction(faction: Faction, factions: State<'_, FactionState>) -> Result<Faction, String> {
+    factions.manager.update_faction(faction).map_err(|e| e.to_string())
+}
+
+/// Delete a faction and any relationships it is part of.
+#[tauri::command]
+pub fn delete_faction(
+    faction_id: String,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<(), String> {
+    let faction = factions
+        .manager
+        .get_faction(&faction_id)
+        .ok_or_else(|| format!("Faction not found: {}", faction_id))?;
+    for relationship in state
+        .relationship_manager
+        .get_entity_relationships(&faction.campaign_id, &faction_id)
+    {
+        state
+            .relationship_manager
+            .delete_relationship(&faction.campaign_id, &relationship.id)
+            .map_err(|e| e.to_string())?;
+    }
+    factions.manager.delete_faction(&faction_id).map_err(|e| e.to_string())
+}
+
+/// List a campaign's factions, including those of its shared setting.
+#[tauri::command]
+pub fn list_factions(
+    campaign_id: String,
+    factions: State<'_, FactionState>,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Vec<Faction>, String> {
+    Ok(campaign_factions(&campaign_id, &factions, &settings))
+}
+
+/// List the factions an NPC belongs to.
+#[tauri::command]
+pub fn get_npc_factions(
+    campaign_id: String,
+    npc_id: String,
+    factions: State<'_, FactionState>,
+) -> Result<Vec<Faction>, String> {
+    Ok(factions.manager.factions_for_npc(&campaign_id, &npc_id))
+}
+
+// ============================================================================
+// Influence & Clock Commands
+// ============================================================================
+
+/// Change a faction's influence by `delta` (clamped to 0-100).
+#[tauri::command]
+pub fn adjust_faction_influence(
+    faction_id: String,
+    delta: i32,
+    factions: State<'_, FactionState>,
+) -> Result<Faction, String> {
+    factions
+        .manager
+        .adjust_influence(&faction_id, delta)
+        .map_err(|e| e.to_string())
+}
+
+/// Add a progress clock to a faction.
+///
+/// # Arguments
+/// * `segments` - Number of segments to fill
+/// * `triggers` - World event types that advance the clock (default: any
+///   event involving the faction)
+#[tauri::command]
+pub fn add_faction_clock(
+    faction_id: String,
+    name: String,
+    segments: u32,
+    triggers: Option<Vec<WorldEventType>>,
+    factions: State<'_, FactionState>,
+) -> Result<Faction, String> {
+    let clock = FactionClock::new(&name, segments).with_triggers(triggers.unwrap_or_default());
+    factions.manager.add_clock(&faction_id, clock).map_err(|e| e.to_string())
+}
+
+/// Advance a faction clock by hand; negative ticks wind it back.
+#[tauri::command]
+pub fn advance_faction_clock(
+    faction_id: String,
+    clock_id: String,
+    ticks: i32,
+    factions: State<'_, FactionState>,
+) -> Result<Faction, String> {
+    factions
+        .manager
+        .advance_clock(&faction_id, &clock_id, ticks)
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a clock from a faction.
+#[tauri::command]
+pub fn remove_faction_clock(
+    faction_id: String,
+    clock_id: String,
+    factions: State<'_, FactionState>,
+) -> Result<Faction, String> {
+    factions
+        .manager
+        .remove_clock(&faction_id, &clock_id)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Relationship Graph Commands
+// ============================================================================
+
+/// Relate a faction to another faction or an NPC.
+///
+/// The relationship is stored with the other entity relationships, so it
+/// shows up in the relationship graph.
+///
+/// # Arguments
+/// * `target_id` - ID of a faction or NPC
+/// * `relationship_type` - e.g. "allied_with", "at_war_with", "enemy", "patron"
+#[tauri::command]
+pub fn relate_faction(
+    faction_id: String,
+    target_id: String,
+    relationship_type: String,
+    strength: Option<String>,
+    description: Option<String>,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<EntityRelationship, String> {
+    let faction = factions
+        .manager
+        .get_faction(&faction_id)
+        .ok_or_else(|| format!("Faction not found: {}", faction_id))?;
+
+    let (target_type, target_name) = if let Some(other) = factions.manager.get_faction(&target_id) {
+        (EntityType::Faction, other.name)
+    } else if let Some(npc) = state.npc_store.get(&target_id) {
+        (EntityType::NPC, npc.name)
+    } else {
+        return Err(format!("No faction or NPC with ID {}", target_id));
+    };
+
+    let mut relationship = EntityRelationship::new(
+        &faction.campaign_id,
+        &faction.id,
+        EntityType::Faction,
+        &faction.name,
+        &target_id,
+        target_type,
+        &target_name,
+        parse_relationship_type(&relationship_type),
+    )
+    .with_strength(strength.map(|s| parse_relationship_strength(&s)).unwrap_or_default());
+    if let Some(description) = description {
+        relationship = relationship.with_description(&description);
+    }
+
+    state
+        .relationship_manager
+        .create_relationship(relationship)
+        .map_err(|e| e.to_string())
+}
+
+/// Get the faction web: faction relationships plus membership and territory,
+/// with influence and clocks on faction nodes.
+#[tauri::command]
+pub fn get_faction_web(
+    campaign_id: String,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<EntityGraph, String> {
+    let mut names: HashMap<String, String> = state
+        .location_manager
+        .list_locations_for_campaign(&campaign_id)
+        .into_iter()
+        .map(|l| (l.id, l.name))
+        .collect();
+    names.extend(
+        state
+            .npc_store
+            .list(Some(&campaign_id))
+            .into_iter()
+            .map(|n| (n.id, n.name)),
+    );
+
+    let graph = state.relationship_manager.get_entity_graph(&campaign_id, false);
+    Ok(factions.manager.faction_web(&campaign_id, graph, &names))
+}
@@ -12,7 +12,7 @@ use tauri::State;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
-use crate::commands::AppState;
+use crate::commands::{AppState, PartyState};
 #[allow(unused_imports)]
 use crate::core::campaign::generation::{
     AcceptanceManager, ArcDraft, ArcGenerationRequest, ArcGenerator, ArcTemplateType,
@@ -28,6 +28,35 @@ use crate::core::campaign::generation::{
 // Helper Functions
 // ============================================================================
 
+/// Fill in party level and size from the campaign roster when not given
+fn with_roster_party(mut request: SessionGenerationRequest, party: &PartyState) -> SessionGenerationRequest {
+    if request.party_level.is_none() || request.party_size.is_none() {
+        let overview = request
+            .campaign_id
+            .as_deref()
+            .and_then(|campaign_id| party.manager.overview(campaign_id));
+        if let Some(overview) = overview {
+            request.party_level = request.party_level.or(Some(overview.average_level));
+            request.party_size = request.party_size.or(Some(overview.size));
+        }
+    }
+    request
+}
+
+/// Use the campaign roster as the party when no members are given
+fn with_roster_members(mut request: PartyAnalysisRequest, party: &PartyState) -> PartyAnalysisRequest {
+    if request.party_details.is_empty() {
+        if let Some(overview) = request
+            .campaign_id
+            .as_deref()
+            .and_then(|campaign_id| party.manager.overview(campaign_id))
+        {
+            request.party_details = overview.members;
+        }
+    }
+    request
+}
+
 /// Convert generation errors to String for Tauri IPC
 fn gen_err_to_string(err: impl std::fmt::Display) -> String {
     let msg = err.to_string();
@@ -172,6 +201,7 @@ pub fn parse_npc_response(response: serde_json::Value) -> Result<NpcDraft, Strin
 /// Generate a session plan.
 ///
 /// Creates a structured session plan with narrative beats, encounters, and pacing.
+/// Party level and size default to the campaign's party roster.
 ///
 /// # Arguments
 /// * `request` - Session generation request with objective, duration, and pacing style
@@ -182,7 +212,9 @@ pub fn parse_npc_response(response: serde_json::Value) -> Result<NpcDraft, Strin
 pub async fn generate_session_plan(
     request: SessionGenerationRequest,
     state: State<'_, AppState>,
+    party: State<'_, PartyState>,
 ) -> Result<GenerationResponse, String> {
+    let request = with_roster_party(request, &party);
     info!(
         objective = %request.objective,
         duration_hours = request.session_duration_hours,
@@ -234,6 +266,34 @@ pub fn calculate_encounter_difficulty(
     SessionGenerator::calculate_encounter_difficulty(party_level, party_size, enemy_cr, enemy_count)
 }
 
+/// Calculate encounter difficulty against a campaign's active party.
+///
+/// # Arguments
+/// * `campaign_id` - Campaign whose party roster is used
+/// * `enemy_cr` - Challenge rating of enemies
+/// * `enemy_count` - Number of enemies
+///
+/// # Returns
+/// Calculated encounter difficulty
+#[tauri::command]
+pub fn calculate_party_encounter_difficulty(
+    campaign_id: String,
+    enemy_cr: f32,
+    enemy_count: u8,
+    party: State<'_, PartyState>,
+) -> Result<EncounterDifficulty, String> {
+    let overview = party
+        .manager
+        .overview(&campaign_id)
+        .ok_or_else(|| "The campaign has no active player characters".to_string())?;
+    Ok(SessionGenerator::calculate_encounter_difficulty(
+        overview.average_level,
+        overview.size,
+        enemy_cr,
+        enemy_count,
+    ))
+}
+
 // ============================================================================
 // Arc Generation Commands
 // ============================================================================
@@ -287,6 +347,7 @@ pub fn parse_arc_response(response: serde_json::Value) -> Result<ArcDraft, Strin
 /// Analyze party composition.
 ///
 /// Identifies gaps, strengths, and provides recommendations for party balance.
+/// Without character details, the campaign's party roster is used.
 ///
 /// # Arguments
 /// * `request` - Party analysis request with character details
@@ -297,7 +358,9 @@ pub fn parse_arc_response(response: serde_json::Value) -> Result<ArcDraft, Strin
 pub async fn analyze_party_composition(
     request: PartyAnalysisRequest,
     state: State<'_, AppState>,
+    party: State<'_, PartyState>,
 ) -> Result<GenerationResponse, String> {
+    let request = with_roster_members(request, &party);
     info!(
         campaign_type = ?request.campaign_type,
         party_size = request.party_details.len(),
@@ -315,7 +378,8 @@ pub async fn analyze_party_composition(
 
 /// Perform static party gap analysis.
 ///
-/// A quick analysis without LLM call, based on role coverage.
+/// A quick analysis without LLM call, based on role coverage. Without
+/// character details, the campaign's party roster is used.
 ///
 /// # Arguments
 /// * `request` - Party analysis request with character details
@@ -323,7 +387,8 @@ pub async fn analyze_party_composition(
 /// # Returns
 /// Gap analysis with strengths, weaknesses, and recommendations
 #[tauri::command]
-pub fn analyze_party_gaps(request: PartyAnalysisRequest) -> GapAnalysis {
+pub fn analyze_party_gaps(request: PartyAnalysisRequest, party: State<'_, PartyState>) -> GapAnalysis {
+    let request = with_roster_members(request, &party);
     debug!(party_size = request.party_details.len(), "Analyzing party gaps");
     PartyAnalyzer::static_analysis(&request.party_details)
 }
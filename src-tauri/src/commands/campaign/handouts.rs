@@ -0,0 +1,173 @@
+//! Handout Commands
+//!
+//! Commands for registering handouts and revealing them to players. Reveals
+//! and hides are broadcast as events so the player-facing window can update
+//! instantly; it loads the current set with `get_revealed_handouts`.
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, State};
+
+use crate::commands::AppState;
+use crate::core::campaign::handouts::{Handout, HandoutContent, HandoutManager, PlayerHandout};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Event emitted when a handout is revealed to players
+pub const HANDOUT_REVEALED_EVENT: &str = "handout:revealed";
+
+/// Event emitted when a revealed handout is hidden again
+pub const HANDOUT_HIDDEN_EVENT: &str = "handout:hidden";
+
+/// Managed state holding campaign handouts
+#[derive(Default)]
+pub struct HandoutState {
+    pub manager: HandoutManager,
+}
+
+/// Payload for [`HANDOUT_HIDDEN_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandoutHiddenEvent {
+    pub campaign_id: String,
+    pub handout_id: String,
+}
+
+// ============================================================================
+// Handout CRUD Commands
+// ============================================================================
+
+/// Register a handout. It starts hidden from players.
+///
+/// # Arguments
+/// * `content` - `{ "type": "image", "path" }`, `{ "type": "pdf_page", "path", "page" }`,
+///   or `{ "type": "text", "text" }`
+/// * `session_id` - Session the handout is prepared for (default: campaign-wide)
+/// * `caption` - Shown to players with the handout
+/// * `gm_notes` - Never shown to players
+#[tauri::command]
+pub fn create_handout(
+    campaign_id: String,
+    title: String,
+    content: HandoutContent,
+    session_id: Option<String>,
+    caption: Option<String>,
+    gm_notes: Option<String>,
+    tags: Option<Vec<String>>,
+    state: State<'_, AppState>,
+    handouts: State<'_, HandoutState>,
+) -> Result<Handout, String> {
+    let mut handout = Handout::new(&campaign_id, &title, content)
+        .with_caption(caption.as_deref().unwrap_or_default());
+    if let Some(session_id) = session_id {
+        let session = state
+            .session_manager
+            .get_session(&session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        if session.campaign_id != campaign_id {
+            return Err(format!("Session {} is not part of this campaign", session_id));
+        }
+        handout = handout.for_session(&session_id);
+    }
+    handout.gm_notes = gm_notes.unwrap_or_default();
+    handout.tags = tags.unwrap_or_default();
+    handouts.manager.create_handout(handout).map_err(|e| e.to_string())
+}
+
+/// Get a handout by ID.
+#[tauri::command]
+pub fn get_handout(handout_id: String, handouts: State<'_, HandoutState>) -> Result<Option<Handout>, String> {
+    Ok(handouts.manager.get_handout(&handout_id))
+}
+
+/// Replace a handout's details. Reveal state and history are kept.
+#[tauri::command]
+pub fn update_handout(handout: Handout, handouts: State<'_, HandoutState>) -> Result<Handout, String> {
+    handouts.manager.update_handout(handout).map_err(|e| e.to_string())
+}
+
+/// Delete a handout, hiding it from players first if it was revealed.
+#[tauri::command]
+pub fn delete_handout(
+    handout_id: String,
+    app_handle: tauri::AppHandle,
+    handouts: State<'_, HandoutState>,
+) -> Result<(), String> {
+    let handout = handouts.manager.delete_handout(&handout_id).map_err(|e| e.to_string())?;
+    if handout.revealed {
+        emit_hidden(&app_handle, &handout);
+    }
+    Ok(())
+}
+
+/// List a campaign's handouts.
+///
+/// # Arguments
+/// * `session_id` - Only this session's handouts and campaign-wide ones
+/// * `revealed` - Only revealed (true) or hidden (false) handouts
+#[tauri::command]
+pub fn list_handouts(
+    campaign_id: String,
+    session_id: Option<String>,
+    revealed: Option<bool>,
+    handouts: State<'_, HandoutState>,
+) -> Result<Vec<Handout>, String> {
+    Ok(handouts.manager.list_handouts(&campaign_id, session_id.as_deref(), revealed))
+}
+
+// ============================================================================
+// Reveal Commands
+// ============================================================================
+
+/// Reveal a handout to players and notify the player window.
+///
+/// # Arguments
+/// * `session_id` - Session the reveal happened in, for the history
+#[tauri::command]
+pub fn reveal_handout(
+    handout_id: String,
+    session_id: Option<String>,
+    app_handle: tauri::AppHandle,
+    handouts: State<'_, HandoutState>,
+) -> Result<Handout, String> {
+    let handout = handouts
+        .manager
+        .reveal(&handout_id, session_id.as_deref())
+        .map_err(|e| e.to_string())?;
+    let _ = app_handle.emit(HANDOUT_REVEALED_EVENT, handout.player_view());
+    Ok(handout)
+}
+
+/// Hide a revealed handout from players and notify the player window.
+#[tauri::command]
+pub fn hide_handout(
+    handout_id: String,
+    session_id: Option<String>,
+    app_handle: tauri::AppHandle,
+    handouts: State<'_, HandoutState>,
+) -> Result<Handout, String> {
+    let handout = handouts
+        .manager
+        .hide(&handout_id, session_id.as_deref())
+        .map_err(|e| e.to_string())?;
+    emit_hidden(&app_handle, &handout);
+    Ok(handout)
+}
+
+/// Handouts players can currently see, most recently revealed first, without
+/// GM notes. Used by the player window on load.
+#[tauri::command]
+pub fn get_revealed_handouts(
+    campaign_id: String,
+    session_id: Option<String>,
+    handouts: State<'_, HandoutState>,
+) -> Result<Vec<PlayerHandout>, String> {
+    Ok(handouts.manager.revealed_for_players(&campaign_id, session_id.as_deref()))
+}
+
+fn emit_hidden(app_handle: &tauri::AppHandle, handout: &Handout) {
+    let _ = app_handle.emit(HANDOUT_HIDDEN_EVENT, HandoutHiddenEvent {
+        campaign_id: handout.campaign_id.clone(),
+        handout_id: handout.id.clone(),
+    });
+}
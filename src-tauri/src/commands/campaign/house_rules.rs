@@ -0,0 +1,81 @@
+//! House Rule Commands
+//!
+//! Commands for managing a campaign's house rules. Rules search shows them
+//! above the rulebook passages they replace when `search` or `hybrid_search`
+//! is given `house_rules_campaign_id`.
+
+use tauri::State;
+
+use crate::core::campaign::house_rules::{HouseRule, HouseRuleManager, HouseRuleMatch, RuleReference};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign house rules
+#[derive(Default)]
+pub struct HouseRuleState {
+    pub manager: HouseRuleManager,
+}
+
+// ============================================================================
+// House Rule CRUD Commands
+// ============================================================================
+
+/// Create a house rule.
+///
+/// # Arguments
+/// * `overrides` - Rulebook passages the rule replaces, as
+///   `{ "book", "page", "section" }`; leave `page` out to cover a whole book
+#[tauri::command]
+pub fn create_house_rule(
+    campaign_id: String,
+    title: String,
+    text: String,
+    overrides: Option<Vec<RuleReference>>,
+    tags: Option<Vec<String>>,
+    house_rules: State<'_, HouseRuleState>,
+) -> Result<HouseRule, String> {
+    let mut rule = HouseRule::new(&campaign_id, &title, &text).with_tags(tags.unwrap_or_default());
+    rule.overrides = overrides.unwrap_or_default();
+    house_rules.manager.create_rule(rule).map_err(|e| e.to_string())
+}
+
+/// Get a house rule by ID.
+#[tauri::command]
+pub fn get_house_rule(rule_id: String, house_rules: State<'_, HouseRuleState>) -> Result<Option<HouseRule>, String> {
+    Ok(house_rules.manager.get_rule(&rule_id))
+}
+
+/// Replace a house rule, including its overrides, tags, and enabled flag.
+#[tauri::command]
+pub fn update_house_rule(rule: HouseRule, house_rules: State<'_, HouseRuleState>) -> Result<HouseRule, String> {
+    house_rules.manager.update_rule(rule).map_err(|e| e.to_string())
+}
+
+/// Delete a house rule.
+#[tauri::command]
+pub fn delete_house_rule(rule_id: String, house_rules: State<'_, HouseRuleState>) -> Result<(), String> {
+    house_rules.manager.delete_rule(&rule_id).map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// List a campaign's house rules by title, optionally only those with a tag.
+#[tauri::command]
+pub fn list_house_rules(
+    campaign_id: String,
+    tag: Option<String>,
+    house_rules: State<'_, HouseRuleState>,
+) -> Result<Vec<HouseRule>, String> {
+    Ok(house_rules.manager.list_rules(&campaign_id, tag.as_deref()))
+}
+
+/// Search only a campaign's house rules.
+#[tauri::command]
+pub fn search_house_rules(
+    campaign_id: String,
+    query: String,
+    limit: Option<usize>,
+    house_rules: State<'_, HouseRuleState>,
+) -> Result<Vec<HouseRuleMatch>, String> {
+    Ok(house_rules.manager.search(&campaign_id, &query, limit.unwrap_or(10)))
+}
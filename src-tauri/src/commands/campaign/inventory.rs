@@ -0,0 +1,328 @@
+//! Inventory Commands
+//!
+//! Commands for itemized inventories of player characters, NPCs,
+//! combatants, and the party stash: adding and stowing items, attunement,
+//! encumbrance, and moving loot between inventories, shops, and the party
+//! treasury.
+
+use tauri::State;
+
+use crate::commands::{AppState, PartyState, ShopState, TreasuryState};
+use crate::core::campaign::inventory::{
+    EncumbranceRules, EncumbranceStatus, Inventory, InventoryError, InventoryItem, InventoryManager, InventoryOwner, ItemTransfer,
+};
+use crate::core::campaign::shops::{ShopItem, ShopPurchase};
+use crate::core::campaign::treasury::{Account, TransactionEntry};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding inventories
+#[derive(Default)]
+pub struct InventoryState {
+    pub manager: InventoryManager,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Encumbrance rules for the campaign's game system
+fn campaign_rules(campaign_id: &str, state: &AppState) -> Result<EncumbranceRules, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+    Ok(EncumbranceRules::for_system(&campaign.system))
+}
+
+/// Keep a player character's roster summary in step with what they are
+/// attuned to
+fn sync_character_summary(inventory: &Inventory, party: &PartyState) {
+    let InventoryOwner::Character { character_id } = &inventory.owner else {
+        return;
+    };
+    if let Some(mut character) = party.manager.get_character(character_id) {
+        character.inventory.attuned_items = inventory.attuned().iter().map(|i| i.name.clone()).collect();
+        if let Err(e) = party.manager.update_character(character) {
+            log::warn!("Failed to update {}'s attuned items: {}", character_id, e);
+        }
+    }
+}
+
+// ============================================================================
+// Inventory Commands
+// ============================================================================
+
+/// Get an owner's inventory (empty if they have none yet)
+#[tauri::command]
+pub fn get_inventory(
+    campaign_id: String,
+    owner: InventoryOwner,
+    inventories: State<'_, InventoryState>,
+) -> Result<Inventory, String> {
+    Ok(inventories.manager.get(&campaign_id, &owner))
+}
+
+/// List every inventory in a campaign
+#[tauri::command]
+pub fn list_campaign_inventories(
+    campaign_id: String,
+    inventories: State<'_, InventoryState>,
+) -> Result<Vec<Inventory>, String> {
+    Ok(inventories.manager.list(&campaign_id))
+}
+
+/// Add items, stacking them with a matching stack. Set `container_id` on
+/// the item to put it inside a container.
+#[tauri::command]
+pub fn add_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item: InventoryItem,
+    inventories: State<'_, InventoryState>,
+) -> Result<InventoryItem, String> {
+    inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| inventory.add(item))
+        .map_err(|e| e.to_string())
+}
+
+/// Save edits to an item: name, weight, value, quantity, notes. Use
+/// `stow_inventory_item` and `set_item_attunement` to move or attune it.
+#[tauri::command]
+pub fn update_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item: InventoryItem,
+    inventories: State<'_, InventoryState>,
+) -> Result<InventoryItem, String> {
+    inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| {
+            let existing = inventory
+                .items
+                .iter_mut()
+                .find(|i| i.id == item.id)
+                .ok_or_else(|| InventoryError::ItemNotFound(item.id.clone()))?;
+            *existing = InventoryItem {
+                container_id: existing.container_id.clone(),
+                attuned: existing.attuned && item.requires_attunement,
+                ..item
+            };
+            Ok(existing.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Remove items; a container is removed with its contents. Returns what was
+/// removed.
+///
+/// # Arguments
+/// * `quantity` - How many to remove (default: the whole stack)
+#[tauri::command]
+pub fn remove_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+    inventories: State<'_, InventoryState>,
+    party: State<'_, PartyState>,
+) -> Result<Vec<InventoryItem>, String> {
+    let removed = inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| inventory.take(&item_id, quantity))
+        .map_err(|e| e.to_string())?;
+    sync_character_summary(&inventories.manager.get(&campaign_id, &owner), &party);
+    Ok(removed)
+}
+
+/// Put an item inside a container, or take it out with no `container_id`
+#[tauri::command]
+pub fn stow_inventory_item(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    container_id: Option<String>,
+    inventories: State<'_, InventoryState>,
+) -> Result<Inventory, String> {
+    inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| {
+            inventory.stow(&item_id, container_id.as_deref())?;
+            Ok(inventory.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Attune to an item, or end attunement, within the game system's limit
+#[tauri::command]
+pub fn set_item_attunement(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    attuned: bool,
+    state: State<'_, AppState>,
+    inventories: State<'_, InventoryState>,
+    party: State<'_, PartyState>,
+) -> Result<InventoryItem, String> {
+    let rules = campaign_rules(&campaign_id, &state)?;
+    let (item, inventory) = inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| {
+            let item = inventory.set_attunement(&item_id, attuned, rules.attunement_limit)?;
+            Ok((item, inventory.clone()))
+        })
+        .map_err(|e| e.to_string())?;
+    sync_character_summary(&inventory, &party);
+    Ok(item)
+}
+
+// ============================================================================
+// Encumbrance Commands
+// ============================================================================
+
+/// Set the Strength score an owner's load is measured against
+#[tauri::command]
+pub fn set_inventory_strength(
+    campaign_id: String,
+    owner: InventoryOwner,
+    strength: Option<i32>,
+    inventories: State<'_, InventoryState>,
+) -> Result<Inventory, String> {
+    inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| {
+            inventory.strength = strength;
+            Ok(inventory.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// How weighed down an owner is under the campaign's game system rules
+#[tauri::command]
+pub fn get_encumbrance(
+    campaign_id: String,
+    owner: InventoryOwner,
+    state: State<'_, AppState>,
+    inventories: State<'_, InventoryState>,
+) -> Result<EncumbranceStatus, String> {
+    let rules = campaign_rules(&campaign_id, &state)?;
+    let inventory = inventories.manager.get(&campaign_id, &owner);
+    Ok(rules.assess(
+        inventory.total_weight(),
+        inventory.strength.unwrap_or(10),
+        inventory.attuned().len(),
+    ))
+}
+
+// ============================================================================
+// Loot Commands
+// ============================================================================
+
+/// Move items from one inventory to another, e.g. a PC handing a potion to
+/// another or stashing loot with the party. Attunement ends on the way.
+///
+/// # Arguments
+/// * `quantity` - How many to move (default: the whole stack)
+/// * `container_id` - Container in the receiving inventory to put them in
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_inventory_item(
+    campaign_id: String,
+    from: InventoryOwner,
+    to: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+    container_id: Option<String>,
+    inventories: State<'_, InventoryState>,
+    party: State<'_, PartyState>,
+) -> Result<ItemTransfer, String> {
+    let transfer = inventories
+        .manager
+        .transfer(&campaign_id, &from, &to, &item_id, quantity, container_id.as_deref())
+        .map_err(|e| e.to_string())?;
+    sync_character_summary(&inventories.manager.get(&campaign_id, &from), &party);
+    Ok(transfer)
+}
+
+/// Take everything a combatant carried, e.g. after the fight
+///
+/// # Arguments
+/// * `to` - Who gets it (default: the party stash)
+#[tauri::command]
+pub fn loot_combatant(
+    campaign_id: String,
+    combatant_id: String,
+    to: Option<InventoryOwner>,
+    inventories: State<'_, InventoryState>,
+) -> Result<Vec<ItemTransfer>, String> {
+    let to = to.unwrap_or(InventoryOwner::Party);
+    inventories
+        .manager
+        .transfer_all(&campaign_id, &InventoryOwner::combatant(&combatant_id), &to)
+        .map_err(|e| e.to_string())
+}
+
+/// Sell items to a shop for its resale price. With `account`, the money is
+/// paid into the party treasury.
+///
+/// # Arguments
+/// * `quantity` - How many to sell (default: the whole stack)
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn sell_to_shop(
+    campaign_id: String,
+    owner: InventoryOwner,
+    item_id: String,
+    quantity: Option<u32>,
+    shop_id: String,
+    account: Option<Account>,
+    state: State<'_, AppState>,
+    inventories: State<'_, InventoryState>,
+    shops: State<'_, ShopState>,
+    treasury: State<'_, TreasuryState>,
+    party: State<'_, PartyState>,
+) -> Result<ShopPurchase, String> {
+    let mut shop = shops.manager.get(&shop_id).map_err(|e| e.to_string())?;
+    let item = inventories
+        .manager
+        .get(&campaign_id, &owner)
+        .item(&item_id)
+        .cloned()
+        .ok_or_else(|| format!("Item not found: {}", item_id))?;
+    if item.container.is_some() && !inventories.manager.get(&campaign_id, &owner).contents(Some(&item_id)).is_empty() {
+        return Err(format!("Empty {} before selling it", item.name));
+    }
+    let quantity = quantity.unwrap_or(item.quantity);
+
+    let offered = ShopItem {
+        name: item.name.clone(),
+        document_id: item.document_id.clone(),
+        category: item.category.clone(),
+        rarity: None,
+        price: item.value,
+        price_text: String::new(),
+        quantity,
+        max_quantity: quantity,
+        weight: Some(item.weight),
+    };
+    let sale = shop.buy_back(offered, quantity).map_err(|e| e.to_string())?;
+    inventories
+        .manager
+        .update(&campaign_id, &owner, |inventory| inventory.take(&item_id, Some(quantity)))
+        .map_err(|e| e.to_string())?;
+
+    if let (Some(account), true) = (account, sale.total_price > 0) {
+        let system = &campaign_rules(&campaign_id, &state)?.system;
+        treasury.manager.open_treasury(&campaign_id, system);
+        let description = format!("Sold {} x{} to {}", item.name, quantity, shop.name);
+        let entry = TransactionEntry::new(account, sale.total_price, &description).in_category("sales");
+        treasury.manager.deposit(&campaign_id, entry).map_err(|e| e.to_string())?;
+    }
+
+    shops.manager.save(shop);
+    sync_character_summary(&inventories.manager.get(&campaign_id, &owner), &party);
+    Ok(sale)
+}
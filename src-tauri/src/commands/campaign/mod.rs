@@ -1,8 +1,13 @@
 //! Campaign Commands Module
 //!
 //! Commands for managing campaigns, including CRUD operations, themes,
-//! snapshots, import/export, notes, stats, versioning, wizard-based creation,
-//! content generation, pipeline management, and quick reference cards.
+//! snapshots, import/export, notes, stats and the dashboard, versioning,
+//! wizard-based creation, content generation, pipeline management, quick reference cards, campaign
+//! templates and cloning, full campaign archives, quest tracking,
+//! factions, the party roster, plot threads, wiki export, handouts,
+//! the party treasury, house rules, XP and milestone progression,
+//! encryption at rest, scheduled backups, the encounter builder, shops,
+//! item inventories, and spellcasting.
 
 pub mod crud;
 pub mod theme;
@@ -17,6 +22,23 @@ pub mod pipeline;
 pub mod quick_reference;
 pub mod random_table;
 pub mod recap;
+pub mod templates;
+pub mod archive;
+pub mod quests;
+pub mod factions;
+pub mod party;
+pub mod plot_threads;
+pub mod wiki;
+pub mod handouts;
+pub mod treasury;
+pub mod house_rules;
+pub mod progression;
+pub mod encryption;
+pub mod backup;
+pub mod encounters;
+pub mod shops;
+pub mod inventory;
+pub mod spellcasting;
 
 // Re-export all commands
 pub use crud::*;
@@ -32,3 +54,20 @@ pub use pipeline::*;
 pub use quick_reference::*;
 pub use random_table::*;
 pub use recap::*;
+pub use templates::*;
+pub use archive::*;
+pub use quests::*;
+pub use factions::*;
+pub use party::*;
+pub use plot_threads::*;
+pub use wiki::*;
+pub use handouts::*;
+pub use treasury::*;
+pub use house_rules::*;
+pub use progression::*;
+pub use encryption::*;
+pub use backup::*;
+pub use encounters::*;
+pub use shops::*;
+pub use inventory::*;
+pub use spellcasting::*;
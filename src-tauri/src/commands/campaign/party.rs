@@ -0,0 +1,116 @@
+//! Party Roster Commands
+//!
+//! Commands for managing a campaign's player characters, leveling them up,
+//! and summarizing the active party.
+
+use tauri::State;
+
+use crate::core::campaign::generation::PartyRole;
+use crate::core::campaign::party::{PartyManager, PartyOverview, PlayerCharacter};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign party rosters
+#[derive(Default)]
+pub struct PartyState {
+    pub manager: PartyManager,
+}
+
+// ============================================================================
+// Character CRUD Commands
+// ============================================================================
+
+/// Add a player character to a campaign's roster.
+///
+/// # Arguments
+/// * `player_name` - The person playing the character
+/// * `level` - Starting level (default: 1)
+#[tauri::command]
+pub fn create_player_character(
+    campaign_id: String,
+    name: String,
+    class: String,
+    level: Option<u8>,
+    player_name: Option<String>,
+    role: Option<PartyRole>,
+    max_hp: Option<i32>,
+    armor_class: Option<i32>,
+    initiative_modifier: Option<i32>,
+    party: State<'_, PartyState>,
+) -> Result<PlayerCharacter, String> {
+    let mut character = PlayerCharacter::new(&campaign_id, &name, &class, level.unwrap_or(1));
+    character.player_name = player_name;
+    character.role = role;
+    character = character.with_combat_stats(
+        max_hp.unwrap_or(character.max_hp),
+        armor_class.unwrap_or(character.armor_class),
+        initiative_modifier.unwrap_or(0),
+    );
+    party.manager.create_character(character).map_err(|e| e.to_string())
+}
+
+/// Get a player character by ID.
+#[tauri::command]
+pub fn get_player_character(
+    character_id: String,
+    party: State<'_, PartyState>,
+) -> Result<Option<PlayerCharacter>, String> {
+    Ok(party.manager.get_character(&character_id))
+}
+
+/// Replace a player character, including passives, inventory, and notes.
+#[tauri::command]
+pub fn update_player_character(
+    character: PlayerCharacter,
+    party: State<'_, PartyState>,
+) -> Result<PlayerCharacter, String> {
+    party.manager.update_character(character).map_err(|e| e.to_string())
+}
+
+/// Remove a player character from the roster.
+#[tauri::command]
+pub fn delete_player_character(character_id: String, party: State<'_, PartyState>) -> Result<(), String> {
+    party.manager.delete_character(&character_id).map_err(|e| e.to_string())
+}
+
+/// List a campaign's player characters (active only unless `include_inactive`).
+#[tauri::command]
+pub fn list_player_characters(
+    campaign_id: String,
+    include_inactive: Option<bool>,
+    party: State<'_, PartyState>,
+) -> Result<Vec<PlayerCharacter>, String> {
+    Ok(party.manager.list_characters(&campaign_id, include_inactive.unwrap_or(false)))
+}
+
+// ============================================================================
+// Progression Commands
+// ============================================================================
+
+/// Raise a player character one level.
+///
+/// # Arguments
+/// * `hp_gained` - Hit points gained (default: the class average)
+#[tauri::command]
+pub fn level_up_player_character(
+    character_id: String,
+    hp_gained: Option<i32>,
+    notes: Option<String>,
+    party: State<'_, PartyState>,
+) -> Result<PlayerCharacter, String> {
+    party
+        .manager
+        .level_up(&character_id, hp_gained, notes.as_deref().unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Get the active party's size, levels, and members.
+#[tauri::command]
+pub fn get_party_overview(
+    campaign_id: String,
+    party: State<'_, PartyState>,
+) -> Result<Option<PartyOverview>, String> {
+    Ok(party.manager.overview(&campaign_id))
+}
@@ -0,0 +1,182 @@
+//! Plot Thread Commands
+//!
+//! Commands for tracking a campaign's open story threads and for finding
+//! the ones that haven't come up in recent sessions.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::campaign::plot_threads::{
+    PlotThread, PlotThreadAnalysis, PlotThreadManager, PlotThreadStatus, SessionActivity, DEFAULT_DANGLING_AFTER,
+};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign plot threads
+#[derive(Default)]
+pub struct PlotThreadState {
+    pub manager: PlotThreadManager,
+}
+
+fn parse_thread_status(status: &str) -> Result<PlotThreadStatus, String> {
+    PlotThreadStatus::parse(status).ok_or_else(|| format!("Unknown plot thread status: {}", status))
+}
+
+// ============================================================================
+// Plot Thread CRUD Commands
+// ============================================================================
+
+/// Create a plot thread.
+///
+/// # Arguments
+/// * `introduced_session_id` - Session the thread was introduced in
+/// * `npc_ids` - NPCs tied to the thread
+/// * `location_ids` - Locations tied to the thread
+/// * `keywords` - Words in session timelines and notes that refer to the
+///   thread (default: the title)
+#[tauri::command]
+pub fn create_plot_thread(
+    campaign_id: String,
+    title: String,
+    description: Option<String>,
+    introduced_session_id: Option<String>,
+    npc_ids: Option<Vec<String>>,
+    location_ids: Option<Vec<String>>,
+    keywords: Option<Vec<String>>,
+    state: State<'_, AppState>,
+    threads: State<'_, PlotThreadState>,
+) -> Result<PlotThread, String> {
+    let mut thread = PlotThread::new(&campaign_id, &title)
+        .with_description(description.as_deref().unwrap_or_default())
+        .with_keywords(keywords.unwrap_or_default());
+    if let Some(session_id) = introduced_session_id {
+        let session = state
+            .session_manager
+            .get_session(&session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        thread = thread.introduced(&session_id, session.session_number);
+    }
+    thread.npc_ids = npc_ids.unwrap_or_default();
+    thread.location_ids = location_ids.unwrap_or_default();
+    threads.manager.create_thread(thread).map_err(|e| e.to_string())
+}
+
+/// Get a plot thread by ID.
+#[tauri::command]
+pub fn get_plot_thread(thread_id: String, threads: State<'_, PlotThreadState>) -> Result<Option<PlotThread>, String> {
+    Ok(threads.manager.get_thread(&thread_id))
+}
+
+/// Replace a plot thread, including its links and keywords.
+#[tauri::command]
+pub fn update_plot_thread(thread: PlotThread, threads: State<'_, PlotThreadState>) -> Result<PlotThread, String> {
+    threads.manager.update_thread(thread).map_err(|e| e.to_string())
+}
+
+/// Delete a plot thread.
+#[tauri::command]
+pub fn delete_plot_thread(thread_id: String, threads: State<'_, PlotThreadState>) -> Result<(), String> {
+    threads.manager.delete_thread(&thread_id).map_err(|e| e.to_string())
+}
+
+/// List a campaign's plot threads, optionally filtered by status.
+#[tauri::command]
+pub fn list_plot_threads(
+    campaign_id: String,
+    status: Option<String>,
+    threads: State<'_, PlotThreadState>,
+) -> Result<Vec<PlotThread>, String> {
+    let status = status.as_deref().map(parse_thread_status).transpose()?;
+    Ok(threads.manager.list_threads(&campaign_id, status))
+}
+
+/// List the plot threads an NPC or location is tied to.
+#[tauri::command]
+pub fn get_entity_plot_threads(
+    campaign_id: String,
+    entity_id: String,
+    threads: State<'_, PlotThreadState>,
+) -> Result<Vec<PlotThread>, String> {
+    Ok(threads.manager.threads_for_entity(&campaign_id, &entity_id))
+}
+
+/// Set a plot thread's status (open, resolved, abandoned).
+#[tauri::command]
+pub fn set_plot_thread_status(
+    thread_id: String,
+    status: String,
+    threads: State<'_, PlotThreadState>,
+) -> Result<PlotThread, String> {
+    threads
+        .manager
+        .set_status(&thread_id, parse_thread_status(&status)?)
+        .map_err(|e| e.to_string())
+}
+
+/// Record that a plot thread came up in a session.
+#[tauri::command]
+pub fn touch_plot_thread(
+    thread_id: String,
+    session_id: String,
+    state: State<'_, AppState>,
+    threads: State<'_, PlotThreadState>,
+) -> Result<PlotThread, String> {
+    let session = state
+        .session_manager
+        .get_session(&session_id)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+    threads
+        .manager
+        .record_touch(&thread_id, &session_id, session.session_number)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Analysis Commands
+// ============================================================================
+
+/// Scan recent sessions for plot thread mentions and report open threads
+/// that have gone untouched.
+///
+/// Mentions found in session timelines and notes are recorded on the
+/// threads, so later scans only need the newest sessions.
+///
+/// # Arguments
+/// * `dangling_after` - Sessions without a mention before a thread is
+///   reported (default: 3)
+/// * `recent_sessions` - Only scan the most recent sessions (default: all)
+#[tauri::command]
+pub fn analyze_plot_threads(
+    campaign_id: String,
+    dangling_after: Option<u32>,
+    recent_sessions: Option<usize>,
+    state: State<'_, AppState>,
+    threads: State<'_, PlotThreadState>,
+) -> Result<PlotThreadAnalysis, String> {
+    let mut sessions = state.session_manager.list_sessions(&campaign_id);
+    sessions.sort_by_key(|s| s.session_number);
+    if let Some(recent) = recent_sessions {
+        let skip = sessions.len().saturating_sub(recent);
+        sessions.drain(..skip);
+    }
+
+    let activity: Vec<SessionActivity> = sessions
+        .iter()
+        .map(|s| {
+            SessionActivity::new(
+                &s.id,
+                s.session_number,
+                &state.session_manager.get_timeline_events(&s.id),
+                &state.session_manager.list_notes_for_session(&s.id),
+            )
+        })
+        .collect();
+
+    Ok(threads.manager.analyze(
+        &campaign_id,
+        &activity,
+        dangling_after.unwrap_or(DEFAULT_DANGLING_AFTER),
+    ))
+}
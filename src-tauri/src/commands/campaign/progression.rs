@@ -0,0 +1,208 @@
+//! Progression Commands
+//!
+//! Commands for awarding experience or milestone checkmarks to the party,
+//! leveling characters who have earned it, and reviewing each character's
+//! progress and the awards given per session.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::{AppState, PartyState};
+use crate::core::campaign::party::PlayerCharacter;
+use crate::core::campaign::progression::{
+    AwardKind, PendingLevelUp, ProgressionAward, ProgressionConfig, ProgressionManager, ProgressionMode,
+    ProgressionReport,
+};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign progression settings and award history
+#[derive(Default)]
+pub struct ProgressionState {
+    pub manager: ProgressionManager,
+}
+
+/// Result of an award
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwardResult {
+    pub awards: Vec<ProgressionAward>,
+    /// Characters leveled up by this award
+    pub leveled_up: Vec<PlayerCharacter>,
+    /// Characters who have earned a level that wasn't applied
+    pub pending: Vec<PendingLevelUp>,
+}
+
+fn campaign_config(campaign_id: &str, state: &AppState, progression: &ProgressionState) -> Result<ProgressionConfig, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+    Ok(progression.manager.config_for(campaign_id, &campaign.system))
+}
+
+// ============================================================================
+// Progression Commands
+// ============================================================================
+
+/// Award experience, or milestone checkmarks when no `amount` is given.
+///
+/// # Arguments
+/// * `character_ids` - Characters receiving the award (default: the active party)
+/// * `amount` - XP per character, or in total when `split` is true
+/// * `split` - Divide `amount` evenly among the characters (default: false)
+/// * `milestone_id` - Arc milestone the checkmarks are for
+/// * `checkmarks` - Checkmarks per character (default: 1)
+/// * `apply_level_ups` - Level up characters who earned it (default: true)
+#[tauri::command]
+pub fn award_xp(
+    campaign_id: String,
+    character_ids: Option<Vec<String>>,
+    amount: Option<u32>,
+    split: Option<bool>,
+    milestone_id: Option<String>,
+    checkmarks: Option<u32>,
+    reason: Option<String>,
+    session_id: Option<String>,
+    apply_level_ups: Option<bool>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+    progression: State<'_, ProgressionState>,
+) -> Result<AwardResult, String> {
+    let config = campaign_config(&campaign_id, &state, &progression)?;
+    let characters: Vec<PlayerCharacter> = match character_ids {
+        Some(ids) => ids
+            .iter()
+            .map(|id| {
+                party
+                    .manager
+                    .get_character(id)
+                    .filter(|c| c.campaign_id == campaign_id)
+                    .ok_or_else(|| format!("Character not found: {}", id))
+            })
+            .collect::<Result<_, _>>()?,
+        None => party.manager.list_characters(&campaign_id, false),
+    };
+    if characters.is_empty() {
+        return Err("No characters to award".to_string());
+    }
+
+    let kind = match amount {
+        Some(amount) if split.unwrap_or(false) => AwardKind::Experience {
+            amount: amount / characters.len() as u32,
+        },
+        Some(amount) => AwardKind::Experience { amount },
+        None => AwardKind::Milestone {
+            milestone_id,
+            checkmarks: checkmarks.unwrap_or(1),
+        },
+    };
+
+    let reason = reason.unwrap_or_default();
+    let mut awards = Vec::with_capacity(characters.len());
+    let mut updated = Vec::with_capacity(characters.len());
+    for character in &characters {
+        let mut award = ProgressionAward::new(&campaign_id, &character.id, kind.clone(), &reason);
+        award.session_id = session_id.clone();
+        awards.push(progression.manager.record_award(award).map_err(|e| e.to_string())?);
+        updated.push(match kind {
+            AwardKind::Experience { amount } => {
+                party.manager.add_experience(&character.id, amount).map_err(|e| e.to_string())?
+            }
+            AwardKind::Milestone { .. } => character.clone(),
+        });
+    }
+
+    let pending = progression.manager.pending_level_ups(&updated, &config);
+    if !apply_level_ups.unwrap_or(true) {
+        return Ok(AwardResult {
+            awards,
+            leveled_up: Vec::new(),
+            pending,
+        });
+    }
+
+    let mut leveled_up = Vec::new();
+    for level_up in pending {
+        let notes = if reason.is_empty() {
+            "Earned through progression".to_string()
+        } else {
+            reason.clone()
+        };
+        let mut character = None;
+        for _ in level_up.current_level..level_up.earned_level {
+            character = Some(party.manager.level_up(&level_up.character_id, None, &notes).map_err(|e| e.to_string())?);
+        }
+        if config.mode == ProgressionMode::Milestone {
+            let levels = level_up.earned_level - level_up.current_level;
+            progression.manager.spend_checkmarks(&level_up.character_id, levels, &config);
+        }
+        leveled_up.extend(character);
+    }
+
+    Ok(AwardResult {
+        awards,
+        leveled_up,
+        pending: Vec::new(),
+    })
+}
+
+/// Get each character's level, XP or checkmarks, pending level-ups, and
+/// the campaign's awards grouped by session.
+///
+/// # Arguments
+/// * `include_inactive` - Include retired or absent characters (default: false)
+#[tauri::command]
+pub fn get_progression(
+    campaign_id: String,
+    include_inactive: Option<bool>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+    progression: State<'_, ProgressionState>,
+) -> Result<ProgressionReport, String> {
+    let config = campaign_config(&campaign_id, &state, &progression)?;
+    let characters = party
+        .manager
+        .list_characters(&campaign_id, include_inactive.unwrap_or(false));
+    Ok(progression.manager.report(&campaign_id, &characters, &config))
+}
+
+/// Switch a campaign between XP and milestone advancement.
+///
+/// # Arguments
+/// * `mode` - "experience" or "milestone"
+/// * `checkmarks_per_level` - Milestone checkmarks needed per level
+/// * `xp_thresholds` - Custom cumulative XP table, starting with 0 for level 1
+#[tauri::command]
+pub fn set_progression_mode(
+    campaign_id: String,
+    mode: String,
+    checkmarks_per_level: Option<u32>,
+    xp_thresholds: Option<Vec<u32>>,
+    state: State<'_, AppState>,
+    progression: State<'_, ProgressionState>,
+) -> Result<ProgressionConfig, String> {
+    let mut config = campaign_config(&campaign_id, &state, &progression)?;
+    config.mode = ProgressionMode::parse(&mode).ok_or_else(|| format!("Unknown progression mode: {}", mode))?;
+    if let Some(checkmarks) = checkmarks_per_level {
+        config.checkmarks_per_level = checkmarks;
+    }
+    if let Some(thresholds) = xp_thresholds {
+        config.xp_thresholds = thresholds;
+    }
+    progression.manager.set_config(&campaign_id, config).map_err(|e| e.to_string())
+}
+
+/// List progression awards, oldest first.
+#[tauri::command]
+pub fn list_progression_awards(
+    campaign_id: String,
+    character_id: Option<String>,
+    session_id: Option<String>,
+    progression: State<'_, ProgressionState>,
+) -> Result<Vec<ProgressionAward>, String> {
+    Ok(progression
+        .manager
+        .list_awards(&campaign_id, character_id.as_deref(), session_id.as_deref()))
+}
@@ -0,0 +1,235 @@
+//! Quest Commands
+//!
+//! Commands for managing campaign quests and their objectives, and for
+//! reviewing quest status suggestions raised by timeline events. Status
+//! changes made during a running session are added to its timeline.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::campaign::quests::{
+    ObjectiveStatus, Quest, QuestError, QuestManager, QuestObjective, QuestReward, QuestStatus,
+    QuestStatusSuggestion,
+};
+use crate::core::session::capture::quest_status_event;
+use crate::core::session_manager::CaptureCategory;
+
+/// Event emitted when a new timeline event produces quest suggestions
+pub const QUEST_SUGGESTIONS_EVENT: &str = "quest:suggestions";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign quests
+#[derive(Default)]
+pub struct QuestState {
+    pub manager: QuestManager,
+}
+
+/// Payload for [`QUEST_SUGGESTIONS_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestSuggestionsEvent {
+    pub campaign_id: String,
+    pub session_id: String,
+    pub suggestions: Vec<QuestStatusSuggestion>,
+}
+
+fn parse_quest_status(status: &str) -> Result<QuestStatus, String> {
+    QuestStatus::parse(status).ok_or_else(|| format!("Unknown quest status: {}", status))
+}
+
+/// Put a quest's status change on the campaign's running session timeline
+fn capture_status_change(state: &AppState, previous: Option<QuestStatus>, quest: &Quest) {
+    if let Some(previous) = previous.filter(|p| *p != quest.status) {
+        state.session_manager.capture_campaign_event(&quest.campaign_id, CaptureCategory::Quests, |session_id| {
+            quest_status_event(session_id, quest, previous)
+        });
+    }
+}
+
+/// Change a quest, then capture any status change it made
+fn change_quest(
+    state: &AppState,
+    quests: &QuestState,
+    quest_id: &str,
+    change: impl FnOnce(&QuestManager) -> std::result::Result<Quest, QuestError>,
+) -> Result<Quest, String> {
+    let previous = quests.manager.get_quest(quest_id).map(|q| q.status);
+    let quest = change(&quests.manager).map_err(|e| e.to_string())?;
+    capture_status_change(state, previous, &quest);
+    Ok(quest)
+}
+
+// ============================================================================
+// Quest CRUD Commands
+// ============================================================================
+
+/// Create a quest.
+///
+/// # Arguments
+/// * `giver_npc_id` - NPC who offers the quest
+/// * `arc_id` - Campaign arc the quest belongs to
+/// * `keywords` - Words in timeline events that refer to the quest
+#[tauri::command]
+pub fn create_quest(
+    campaign_id: String,
+    title: String,
+    description: Option<String>,
+    giver_npc_id: Option<String>,
+    arc_id: Option<String>,
+    keywords: Option<Vec<String>>,
+    rewards: Option<Vec<QuestReward>>,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    let mut quest = Quest::new(&campaign_id, &title)
+        .with_description(description.as_deref().unwrap_or_default())
+        .with_keywords(keywords.unwrap_or_default());
+    quest.giver_npc_id = giver_npc_id;
+    quest.arc_id = arc_id;
+    quest.rewards = rewards.unwrap_or_default();
+    quests.manager.create_quest(quest).map_err(|e| e.to_string())
+}
+
+/// Get a quest by ID.
+#[tauri::command]
+pub fn get_quest(quest_id: String, quests: State<'_, QuestState>) -> Result<Option<Quest>, String> {
+    Ok(quests.manager.get_quest(&quest_id))
+}
+
+/// Replace a quest, including its objectives, rewards, and links.
+#[tauri::command]
+pub fn update_quest(quest: Quest, state: State<'_, AppState>, quests: State<'_, QuestState>) -> Result<Quest, String> {
+    let quest_id = quest.id.clone();
+    change_quest(&state, &quests, &quest_id, |manager| manager.update_quest(quest))
+}
+
+/// Delete a quest.
+#[tauri::command]
+pub fn delete_quest(quest_id: String, quests: State<'_, QuestState>) -> Result<(), String> {
+    quests.manager.delete_quest(&quest_id).map_err(|e| e.to_string())
+}
+
+/// List a campaign's quests, optionally filtered by status.
+#[tauri::command]
+pub fn list_quests(
+    campaign_id: String,
+    status: Option<String>,
+    quests: State<'_, QuestState>,
+) -> Result<Vec<Quest>, String> {
+    let status = status.as_deref().map(parse_quest_status).transpose()?;
+    Ok(quests.manager.list_quests(&campaign_id, status))
+}
+
+/// Search a campaign's quests by title, description, objectives, keywords, and tags.
+#[tauri::command]
+pub fn search_quests(
+    campaign_id: String,
+    query: String,
+    quests: State<'_, QuestState>,
+) -> Result<Vec<Quest>, String> {
+    Ok(quests.manager.search_quests(&campaign_id, &query))
+}
+
+/// List quests linked to an arc or a milestone.
+#[tauri::command]
+pub fn get_linked_quests(
+    campaign_id: String,
+    arc_id: Option<String>,
+    milestone_id: Option<String>,
+    quests: State<'_, QuestState>,
+) -> Result<Vec<Quest>, String> {
+    match (arc_id, milestone_id) {
+        (_, Some(milestone_id)) => Ok(quests.manager.get_quests_for_milestone(&campaign_id, &milestone_id)),
+        (Some(arc_id), None) => Ok(quests.manager.get_quests_for_arc(&campaign_id, &arc_id)),
+        (None, None) => Err("Either arc_id or milestone_id is required".to_string()),
+    }
+}
+
+/// Set a quest's status.
+#[tauri::command]
+pub fn set_quest_status(
+    quest_id: String,
+    status: String,
+    state: State<'_, AppState>,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    let status = parse_quest_status(&status)?;
+    change_quest(&state, &quests, &quest_id, |manager| manager.set_quest_status(&quest_id, status))
+}
+
+// ============================================================================
+// Objective Commands
+// ============================================================================
+
+/// Add an objective to a quest.
+#[tauri::command]
+pub fn add_quest_objective(
+    quest_id: String,
+    description: String,
+    optional: Option<bool>,
+    keywords: Option<Vec<String>>,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    let mut objective = QuestObjective::new(&description).with_keywords(keywords.unwrap_or_default());
+    objective.optional = optional.unwrap_or(false);
+    quests.manager.add_objective(&quest_id, objective).map_err(|e| e.to_string())
+}
+
+/// Remove an objective from a quest.
+#[tauri::command]
+pub fn remove_quest_objective(
+    quest_id: String,
+    objective_id: String,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    quests
+        .manager
+        .remove_objective(&quest_id, &objective_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Set an objective's status (pending, completed, failed).
+#[tauri::command]
+pub fn set_quest_objective_status(
+    quest_id: String,
+    objective_id: String,
+    status: String,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    let status = ObjectiveStatus::parse(&status).ok_or_else(|| format!("Unknown objective status: {}", status))?;
+    quests
+        .manager
+        .set_objective_status(&quest_id, &objective_id, status)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Suggestion Commands
+// ============================================================================
+
+/// Suggest quest status changes from a session's timeline.
+#[tauri::command]
+pub fn suggest_quest_updates(
+    session_id: String,
+    state: State<'_, AppState>,
+    quests: State<'_, QuestState>,
+) -> Result<Vec<QuestStatusSuggestion>, String> {
+    let session = state
+        .session_manager
+        .get_session(&session_id)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+    let events = state.session_manager.get_timeline_events(&session_id);
+    Ok(quests.manager.suggest_status_changes(&session.campaign_id, &events))
+}
+
+/// Apply a quest suggestion the GM accepted.
+#[tauri::command]
+pub fn apply_quest_suggestion(
+    suggestion: QuestStatusSuggestion,
+    state: State<'_, AppState>,
+    quests: State<'_, QuestState>,
+) -> Result<Quest, String> {
+    change_quest(&state, &quests, &suggestion.quest_id, |manager| manager.apply_suggestion(&suggestion))
+}
@@ -26,13 +26,16 @@ use std::sync::Arc;
 use tauri::State;
 use tracing::{info, debug, error};
 
-use crate::commands::AppState;
+use crate::commands::{fire_sfx_event, AppState, SfxTriggerState};
+use crate::core::audio::SfxEvent;
 use crate::core::campaign::{
     RandomTableEngine, RandomTable, TableRollResult,
     CreateTableRequest, TableEntryInput, RollRequest,
     DiceNotation, DiceRoller, RollResult,
     RandomTableError,
 };
+use crate::core::session::capture::dice_roll_event;
+use crate::core::session_manager::CaptureCategory;
 use crate::database::{RollHistoryRecord, RandomTableType};
 
 // ============================================================================
@@ -320,6 +323,9 @@ pub async fn quick_table_roll(
 
 /// Roll dice without a table.
 ///
+/// Rolls for a session, or for a campaign with a running session, are added
+/// to that session's timeline.
+///
 /// # Arguments
 /// * `notation` - Dice notation (e.g., "d20", "2d6+3")
 /// * `session_id` - Optional session for history
@@ -335,14 +341,40 @@ pub async fn roll_dice(
     campaign_id: Option<String>,
     context: Option<String>,
     state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
 ) -> Result<RollResult, String> {
     debug!(notation = %notation, "Rolling dice");
 
     let engine = get_table_engine(&state);
-    engine
+    let result = engine
         .roll_dice(&notation, session_id.as_deref(), campaign_id.as_deref(), context.as_deref())
         .await
-        .map_err(table_err_to_string)
+        .map_err(table_err_to_string)?;
+
+    let critical = if result.is_critical() {
+        Some(true)
+    } else if result.is_critical_fail() {
+        Some(false)
+    } else {
+        None
+    };
+    let event = match critical {
+        Some(true) => SfxEvent::CriticalHit,
+        Some(false) => SfxEvent::CriticalMiss,
+        None => SfxEvent::DiceRoll,
+    };
+    fire_sfx_event(&sfx, event);
+
+    let build = |session_id: &str| dice_roll_event(session_id, &notation, result.total, context.as_deref(), critical);
+    match (&session_id, &campaign_id) {
+        (Some(session_id), _) => state.session_manager.capture_event(session_id, CaptureCategory::DiceRolls, build),
+        (None, Some(campaign_id)) => {
+            state.session_manager.capture_campaign_event(campaign_id, CaptureCategory::DiceRolls, build)
+        }
+        (None, None) => None,
+    };
+
+    Ok(result)
 }
 
 /// Parse and validate dice notation.
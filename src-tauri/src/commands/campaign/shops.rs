@@ -0,0 +1,253 @@
+//! Shop Commands
+//!
+//! Commands for generating merchants at a location, stocked from the
+//! ingested item documents, buying from them, and restocking them as
+//! in-game time passes.
+
+use tauri::State;
+
+use crate::commands::world::calendar::{campaign_calendar, CalendarState};
+use crate::commands::{AppState, InventoryState, TreasuryState};
+use crate::core::campaign::inventory::{InventoryItem, InventoryOwner};
+use crate::core::campaign::shops::{
+    with_basic_stock, ItemCandidate, RestockReport, SettlementSize, Shop, ShopManager, ShopPurchase, ShopType,
+    ITEM_ELEMENT_TYPES,
+};
+use crate::core::campaign::treasury::{Account, CurrencySystem, TransactionEntry};
+use crate::core::campaign::world_state::InGameDate;
+use crate::core::character_gen::GameSystem;
+use crate::database::TtrpgOps;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding generated shops
+#[derive(Default)]
+pub struct ShopState {
+    pub manager: ShopManager,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Items a shop in this game system could stock: ingested items plus the
+/// system's basic equipment
+async fn item_candidates(system: &str, state: &AppState) -> Result<Vec<ItemCandidate>, String> {
+    let currency = CurrencySystem::for_system(system);
+    let game_system = GameSystem::from_str(system);
+    let mut candidates = Vec::new();
+    for element_type in ITEM_ELEMENT_TYPES {
+        let records = state
+            .database
+            .list_ttrpg_documents_by_type(element_type)
+            .await
+            .map_err(|e| e.to_string())?;
+        candidates.extend(
+            records
+                .iter()
+                .filter(|r| GameSystem::from_str(&r.game_system) == game_system)
+                .filter_map(|r| ItemCandidate::from_record(r, &currency)),
+        );
+    }
+    Ok(with_basic_stock(candidates, system))
+}
+
+/// The campaign's in-game date and its calendar day number, if a date is set
+fn today(campaign_id: &str, state: &AppState, calendars: &CalendarState) -> Option<(InGameDate, i64)> {
+    let date = state.world_state_manager.get_current_date(campaign_id).ok()?;
+    let day = campaign_calendar(campaign_id, state, calendars).day_number(&date).ok()?;
+    Some((date, day))
+}
+
+async fn restock(
+    shop_id: &str,
+    state: &AppState,
+    calendars: &CalendarState,
+    shops: &ShopState,
+) -> Result<RestockReport, String> {
+    let shop = shops.manager.get(shop_id).map_err(|e| e.to_string())?;
+    let (date, day) = today(&shop.campaign_id, state, calendars)
+        .ok_or_else(|| "Set the campaign's in-game date to restock shops over time".to_string())?;
+    let candidates = item_candidates(&shop.system, state).await?;
+    shops
+        .manager
+        .update(shop_id, |shop| {
+            let report = shop.restock(day, &candidates, &mut rand::thread_rng());
+            if report.deliveries > 0 || shop.last_restocked.is_none() {
+                shop.last_restocked = Some(date);
+            }
+            Ok(report)
+        })
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Shop Commands
+// ============================================================================
+
+/// Generate a stocked shop at a location.
+///
+/// # Arguments
+/// * `name` - Shop name (default: the location's name)
+/// * `settlement_size` - Decides how many items and how expensive (default: town)
+#[tauri::command]
+pub async fn generate_shop(
+    campaign_id: String,
+    location_id: String,
+    shop_type: ShopType,
+    settlement_size: Option<SettlementSize>,
+    name: Option<String>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    shops: State<'_, ShopState>,
+) -> Result<Shop, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let location = state
+        .location_manager
+        .get_location(&location_id)
+        .ok_or_else(|| format!("Location not found: {}", location_id))?;
+
+    let name = name.filter(|n| !n.trim().is_empty()).unwrap_or(location.name);
+    let mut shop = Shop::new(
+        &campaign_id,
+        &location_id,
+        &name,
+        shop_type,
+        settlement_size.unwrap_or_default(),
+        &campaign.system,
+    );
+    let candidates = item_candidates(&campaign.system, &state).await?;
+    shop.stock(&candidates, &mut rand::thread_rng());
+    if let Some((date, day)) = today(&campaign_id, &state, &calendars) {
+        shop.last_restocked = Some(date);
+        shop.restock_day = Some(day);
+    }
+    Ok(shops.manager.save(shop))
+}
+
+#[tauri::command]
+pub fn get_shop(shop_id: String, shops: State<'_, ShopState>) -> Result<Shop, String> {
+    shops.manager.get(&shop_id).map_err(|e| e.to_string())
+}
+
+/// List the shops at a location
+#[tauri::command]
+pub fn list_location_shops(location_id: String, shops: State<'_, ShopState>) -> Result<Vec<Shop>, String> {
+    Ok(shops.manager.for_location(&location_id))
+}
+
+/// List all of a campaign's shops
+#[tauri::command]
+pub fn list_campaign_shops(campaign_id: String, shops: State<'_, ShopState>) -> Result<Vec<Shop>, String> {
+    Ok(shops.manager.list(&campaign_id))
+}
+
+/// Save hand edits to a shop: names, prices, stock, notes
+#[tauri::command]
+pub fn update_shop(shop: Shop, shops: State<'_, ShopState>) -> Result<Shop, String> {
+    shops.manager.get(&shop.id).map_err(|e| e.to_string())?;
+    Ok(shops.manager.save(shop))
+}
+
+#[tauri::command]
+pub fn delete_shop(shop_id: String, shops: State<'_, ShopState>) -> Result<(), String> {
+    shops.manager.delete(&shop_id).map_err(|e| e.to_string())
+}
+
+/// Throw out a shop's stock and fill the shelves again
+#[tauri::command]
+pub async fn reroll_shop_inventory(
+    shop_id: String,
+    state: State<'_, AppState>,
+    shops: State<'_, ShopState>,
+) -> Result<Shop, String> {
+    let mut shop = shops.manager.get(&shop_id).map_err(|e| e.to_string())?;
+    let candidates = item_candidates(&shop.system, &state).await?;
+    shop.stock(&candidates, &mut rand::thread_rng());
+    Ok(shops.manager.save(shop))
+}
+
+/// Buy from a shop, taking the items off the shelves. With `account`, the
+/// price is paid from the party treasury; with `recipient`, the items go
+/// into that inventory.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn buy_from_shop(
+    shop_id: String,
+    item_name: String,
+    quantity: Option<u32>,
+    account: Option<Account>,
+    recipient: Option<InventoryOwner>,
+    state: State<'_, AppState>,
+    shops: State<'_, ShopState>,
+    treasury: State<'_, TreasuryState>,
+    inventories: State<'_, InventoryState>,
+) -> Result<ShopPurchase, String> {
+    let mut shop = shops.manager.get(&shop_id).map_err(|e| e.to_string())?;
+    let purchase = shop.purchase(&item_name, quantity.unwrap_or(1)).map_err(|e| e.to_string())?;
+
+    if let Some(account) = account {
+        let campaign = state
+            .campaign_manager
+            .get_campaign(&shop.campaign_id)
+            .ok_or_else(|| "Campaign not found".to_string())?;
+        treasury.manager.open_treasury(&shop.campaign_id, &campaign.system);
+        let description = format!("{} x{} from {}", purchase.item.name, purchase.quantity, shop.name);
+        let entry = TransactionEntry::new(account, purchase.total_price, &description).in_category("shopping");
+        treasury.manager.withdraw(&shop.campaign_id, entry).map_err(|e| e.to_string())?;
+    }
+
+    if let Some(owner) = recipient {
+        let item = &purchase.item;
+        let mut bought = InventoryItem::new(&item.name, purchase.quantity, item.weight.unwrap_or(0.0)).worth(item.price);
+        bought.document_id = item.document_id.clone();
+        bought.category = item.category.clone();
+        inventories
+            .manager
+            .update(&shop.campaign_id, &owner, |inventory| inventory.add(bought))
+            .map_err(|e| e.to_string())?;
+    }
+
+    shops.manager.save(shop);
+    Ok(purchase)
+}
+
+// ============================================================================
+// Restock Commands
+// ============================================================================
+
+/// Restock a shop for the deliveries due since its last restock, going by
+/// the campaign's in-game date
+#[tauri::command]
+pub async fn restock_shop(
+    shop_id: String,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    shops: State<'_, ShopState>,
+) -> Result<RestockReport, String> {
+    restock(&shop_id, &state, &calendars, &shops).await
+}
+
+/// Restock every shop in a campaign, e.g. after advancing time. Shops with
+/// no deliveries due are left out of the result.
+#[tauri::command]
+pub async fn restock_campaign_shops(
+    campaign_id: String,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    shops: State<'_, ShopState>,
+) -> Result<Vec<RestockReport>, String> {
+    let mut reports = Vec::new();
+    for shop in shops.manager.list(&campaign_id) {
+        let report = restock(&shop.id, &state, &calendars, &shops).await?;
+        if report.deliveries > 0 {
+            reports.push(report);
+        }
+    }
+    Ok(reports)
+}
@@ -0,0 +1,311 @@
+//! Spellcasting Commands
+//!
+//! Commands for tracking player characters' and NPC casters' spell slots
+//! and spells during play: casting, preparing, resting, and opening a
+//! spell's rulebook entry.
+
+use tauri::State;
+
+use crate::commands::{AppState, PartyState};
+use crate::core::campaign::spellcasting::{
+    CastOutcome, Caster, KnownSpell, SpellcastingError, SpellcastingManager, Spellbook,
+};
+use crate::core::character_gen::builder::{CasterProgression, RulesCatalog};
+use crate::core::character_gen::GameSystem;
+use crate::database::{TTRPGDocumentRecord, TtrpgOps};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding spellbooks
+#[derive(Default)]
+pub struct SpellcastingState {
+    pub manager: SpellcastingManager,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The campaign's rulebook entry for a spell, matched by name
+async fn find_spell_entry(
+    campaign_id: &str,
+    name: &str,
+    state: &AppState,
+) -> Result<Option<TTRPGDocumentRecord>, String> {
+    let system = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .map(|c| GameSystem::from_str(&c.system))
+        .ok_or_else(|| "Campaign not found".to_string())?;
+    let records = state
+        .database
+        .search_ttrpg_documents_by_name(name.trim())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(records.into_iter().find(|r| {
+        r.element_type.eq_ignore_ascii_case("spell")
+            && r.name.trim().eq_ignore_ascii_case(name.trim())
+            && GameSystem::from_str(&r.game_system) == system
+    }))
+}
+
+// ============================================================================
+// Spellbook Commands
+// ============================================================================
+
+/// Get a caster's spellbook (empty if they have none yet)
+#[tauri::command]
+pub fn get_spellbook(
+    campaign_id: String,
+    caster: Caster,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Spellbook, String> {
+    Ok(spellbooks.manager.get(&campaign_id, &caster))
+}
+
+/// List every spellbook in a campaign
+#[tauri::command]
+pub fn list_campaign_spellbooks(
+    campaign_id: String,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Vec<Spellbook>, String> {
+    Ok(spellbooks.manager.list(&campaign_id))
+}
+
+/// Set a caster's slots by hand, indexed from 1st-level slots.
+///
+/// # Arguments
+/// * `pact` - The slots are pact magic, regained on a short rest (default: false)
+#[tauri::command]
+pub fn set_spell_slots(
+    campaign_id: String,
+    caster: Caster,
+    slots: Vec<u32>,
+    pact: Option<bool>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Spellbook, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| {
+            book.set_slots(&slots, pact.unwrap_or(false));
+            Ok(book.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Fill a player character's slots and spellcasting ability from their
+/// class and level
+#[tauri::command]
+pub fn sync_spell_slots_from_class(
+    campaign_id: String,
+    character_id: String,
+    party: State<'_, PartyState>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Spellbook, String> {
+    let character = party
+        .manager
+        .get_character(&character_id)
+        .ok_or_else(|| format!("Character not found: {}", character_id))?;
+    let catalog = RulesCatalog::srd();
+    let casting = catalog
+        .class(&character.class)
+        .and_then(|c| c.spellcasting.as_ref())
+        .ok_or_else(|| format!("{} has no spellcasting table", character.class))?;
+    let slots = casting.spell_slots(u32::from(character.level));
+
+    spellbooks
+        .manager
+        .update(&campaign_id, &Caster::character(&character_id), |book| {
+            book.set_slots(&slots, casting.progression == CasterProgression::Pact);
+            book.ability = Some(casting.ability.name().to_string());
+            Ok(book.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Add a spell to a caster's list, linking it to the campaign's rulebook
+/// entry for it. With a `document_id` already set, the spell's level,
+/// school, and tags are read from that entry.
+#[tauri::command]
+pub async fn learn_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell: KnownSpell,
+    state: State<'_, AppState>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<KnownSpell, String> {
+    let entry = match &spell.document_id {
+        Some(id) => state.database.get_ttrpg_document(id).await.map_err(|e| e.to_string())?,
+        None => find_spell_entry(&campaign_id, &spell.name, &state).await?,
+    };
+    let spell = match entry.as_ref().and_then(KnownSpell::from_record) {
+        Some(from_book) if spell.document_id.is_some() => KnownSpell {
+            id: spell.id,
+            prepared: spell.prepared,
+            always_prepared: spell.always_prepared,
+            notes: spell.notes,
+            ..from_book
+        },
+        _ => {
+            let mut spell = spell;
+            if let Some(entry) = &entry {
+                spell.link(entry);
+            }
+            spell
+        }
+    };
+
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| book.learn(spell))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn forget_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<KnownSpell, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| book.forget(&spell_id))
+        .map_err(|e| e.to_string())
+}
+
+/// Prepare a spell, or unprepare it
+#[tauri::command]
+pub fn prepare_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    prepared: bool,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<KnownSpell, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| book.set_prepared(&spell_id, prepared))
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Casting Commands
+// ============================================================================
+
+/// Cast a spell, spending a slot unless it is a cantrip or a ritual.
+///
+/// # Arguments
+/// * `slot_level` - Slot to cast it with (default: the lowest that can)
+/// * `as_ritual` - Cast it as a ritual, without a slot (default: false)
+#[tauri::command]
+pub fn cast_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    slot_level: Option<u8>,
+    as_ritual: Option<bool>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<CastOutcome, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| {
+            book.cast(&spell_id, slot_level, as_ritual.unwrap_or(false))
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Stop concentrating on a spell
+#[tauri::command]
+pub fn end_concentration(
+    campaign_id: String,
+    caster: Caster,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Option<String>, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| Ok(book.concentrating_on.take()))
+        .map_err(|e| e.to_string())
+}
+
+/// Regain every slot and end concentration.
+///
+/// # Arguments
+/// * `caster` - Who rests (default: every caster in the campaign)
+#[tauri::command]
+pub fn take_long_rest(
+    campaign_id: String,
+    caster: Option<Caster>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Vec<Spellbook>, String> {
+    match caster {
+        Some(caster) => spellbooks
+            .manager
+            .update(&campaign_id, &caster, |book| {
+                book.long_rest();
+                Ok(vec![book.clone()])
+            })
+            .map_err(|e| e.to_string()),
+        None => Ok(spellbooks.manager.long_rest_all(&campaign_id)),
+    }
+}
+
+/// Regain pact magic slots
+#[tauri::command]
+pub fn take_short_rest(
+    campaign_id: String,
+    caster: Caster,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<Spellbook, String> {
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| {
+            book.short_rest();
+            Ok(book.clone())
+        })
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Lookup Commands
+// ============================================================================
+
+/// Open a spell's rulebook entry. A spell without a link is matched to the
+/// campaign's rulebooks by name, and the link is kept for next time.
+#[tauri::command]
+pub async fn lookup_known_spell(
+    campaign_id: String,
+    caster: Caster,
+    spell_id: String,
+    state: State<'_, AppState>,
+    spellbooks: State<'_, SpellcastingState>,
+) -> Result<TTRPGDocumentRecord, String> {
+    let spell = spellbooks
+        .manager
+        .get(&campaign_id, &caster)
+        .spell(&spell_id)
+        .cloned()
+        .ok_or_else(|| SpellcastingError::SpellNotFound(spell_id.clone()).to_string())?;
+
+    if let Some(document_id) = &spell.document_id {
+        if let Some(record) = state.database.get_ttrpg_document(document_id).await.map_err(|e| e.to_string())? {
+            return Ok(record);
+        }
+    }
+
+    let record = find_spell_entry(&campaign_id, &spell.name, &state)
+        .await?
+        .ok_or_else(|| format!("No rulebook entry found for {}", spell.name))?;
+    spellbooks
+        .manager
+        .update(&campaign_id, &caster, |book| {
+            if let Some(known) = book.spells.iter_mut().find(|s| s.id == spell_id) {
+                known.link(&record);
+            }
+            Ok(())
+        })
+        .map_err(|e| e.to_string())?;
+    Ok(record)
+}
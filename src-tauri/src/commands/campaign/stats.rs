@@ -1,10 +1,17 @@
 //! Campaign Stats Commands
 //!
-//! Commands for retrieving campaign statistics.
+//! Commands for retrieving campaign statistics and the campaign dashboard.
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use tauri::State;
 
-use crate::commands::AppState;
+use crate::commands::{AppState, QuestState, UsageTrackerState};
+use crate::core::campaign::dashboard::{
+    active_arcs, npc_activity, CampaignDashboard, CombatStats, SessionCadence, SpendSummary, DEFAULT_RECENT_EVENTS,
+    DEFAULT_TOP_NPCS,
+};
 use crate::core::campaign_manager::CampaignStats;
 use crate::core::session_manager::SessionStatus;
 use crate::database::NpcOps;
@@ -46,3 +53,79 @@ pub async fn get_campaign_stats(
         last_played,
     })
 }
+
+// ============================================================================
+// Dashboard Commands
+// ============================================================================
+
+/// Get everything the campaign dashboard shows in one call: session count
+/// and cadence, active arcs with progress, open quests, recent world events,
+/// NPC interaction frequency, combat stats, and LLM and voice spend.
+///
+/// # Arguments
+/// * `recent_events` - World events to include (default: 10)
+/// * `top_npcs` - NPCs to include, most frequent first (default: 10)
+#[tauri::command]
+pub async fn get_campaign_dashboard(
+    campaign_id: String,
+    recent_events: Option<usize>,
+    top_npcs: Option<usize>,
+    state: State<'_, AppState>,
+    quests: State<'_, QuestState>,
+    usage: State<'_, UsageTrackerState>,
+) -> Result<CampaignDashboard, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+    let now = Utc::now();
+
+    let sessions = state.session_manager.list_sessions(&campaign_id);
+    let timelines: Vec<_> = sessions
+        .iter()
+        .map(|s| (s.session_number, state.session_manager.get_timeline_events(&s.id)))
+        .collect();
+
+    let campaign_quests = quests.manager.list_quests(&campaign_id, None);
+    let arc_names: HashMap<String, String> =
+        sqlx::query_as::<_, (String, String)>("SELECT id, name FROM plot_arcs WHERE campaign_id = ?")
+            .bind(&campaign_id)
+            .fetch_all(state.database.pool())
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .collect();
+
+    let world_events = state.world_state_manager.list_events(&campaign_id, None, None);
+    let npc_names: HashMap<String, String> = state
+        .npc_store
+        .list(Some(&campaign_id))
+        .into_iter()
+        .map(|npc| (npc.id, npc.name))
+        .collect();
+    let mut npcs = npc_activity(&timelines, &world_events, &npc_names);
+    npcs.truncate(top_npcs.unwrap_or(DEFAULT_TOP_NPCS));
+
+    let created_at = DateTime::parse_from_rfc3339(&campaign.created_at)
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let records = usage.tracker.get_records_since(created_at);
+
+    Ok(CampaignDashboard {
+        sessions: SessionCadence::from_sessions(&sessions, now),
+        active_arcs: active_arcs(&campaign_quests, &arc_names),
+        open_quests: campaign_quests
+            .into_iter()
+            .filter(|q| !q.status.is_resolved())
+            .collect(),
+        recent_world_events: world_events
+            .into_iter()
+            .take(recent_events.unwrap_or(DEFAULT_RECENT_EVENTS))
+            .collect(),
+        npc_activity: npcs,
+        combat: CombatStats::tally(&sessions, &timelines),
+        spend: SpendSummary::from_records(&records, &campaign_id, &sessions, now),
+        campaign_id,
+        generated_at: now,
+    })
+}
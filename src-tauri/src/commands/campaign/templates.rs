@@ -0,0 +1,243 @@
+//! Campaign Template Commands
+//!
+//! Commands for creating campaigns from templates, managing user templates,
+//! and cloning a campaign to run the same material with another group.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, State};
+
+use crate::commands::{AppState, FactionState};
+use crate::core::campaign::factions::Faction;
+use crate::core::campaign::templates::{ARCS_FIELD, TEMPLATE_FIELD};
+use crate::core::campaign::{reissue_content_ids, CampaignTemplate, CampaignTemplateStore, LocationState};
+use crate::core::models::Campaign;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// What to leave out when cloning a campaign
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CloneCampaignOptions {
+    /// Name for the copy; defaults to "<name> (Copy)"
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Skip planned/played sessions and world event history
+    #[serde(default)]
+    pub exclude_sessions: bool,
+    /// Skip campaign notes
+    #[serde(default)]
+    pub exclude_notes: bool,
+}
+
+/// Result of cloning a campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneCampaignResult {
+    pub campaign: Campaign,
+    pub locations_copied: usize,
+    pub factions_copied: usize,
+    pub sessions_copied: usize,
+    pub notes_copied: usize,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn get_templates_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("campaign_templates")
+}
+
+/// Create a campaign and seed it with the template's content
+fn apply_template(
+    state: &AppState,
+    factions: &FactionState,
+    template: &CampaignTemplate,
+    name: &str,
+    system: Option<&str>,
+) -> Result<Campaign, String> {
+    let mut campaign = state
+        .campaign_manager
+        .create_campaign(name, system.unwrap_or(&template.system));
+    if !template.description.is_empty() {
+        campaign.description = Some(template.description.clone());
+    }
+    campaign.settings.theme = template.theme.clone();
+    campaign.settings.tags = template.tags.clone();
+    campaign.settings.house_rules = template.house_rules.clone();
+    state
+        .campaign_manager
+        .update_campaign(campaign.clone(), false)
+        .map_err(|e| e.to_string())?;
+
+    // Locations go to the location manager, with matching world state entries
+    let world = &state.world_state_manager;
+    world.get_or_create(&campaign.id);
+    let control = template.location_control();
+    let mut territory: HashMap<String, Vec<String>> = HashMap::new();
+    for location in template.build_locations(&campaign.id) {
+        let mut location_state = LocationState::new(&location.id, &location.name);
+        location_state.controlling_faction = control.get(location.name.as_str()).map(|f| f.to_string());
+        if let Some(faction) = &location_state.controlling_faction {
+            territory.entry(faction.clone()).or_default().push(location.id.clone());
+        }
+        world
+            .set_location_state(&campaign.id, location_state)
+            .map_err(|e| e.to_string())?;
+        state
+            .location_manager
+            .save_location(location)
+            .map_err(|e| e.to_string())?;
+    }
+
+    for built in template.build_factions() {
+        let faction = Faction::from_template(&campaign.id, &built)
+            .with_territory(territory.remove(&built.name).unwrap_or_default());
+        factions.manager.create_faction(faction).map_err(|e| e.to_string())?;
+    }
+
+    let arcs = serde_json::to_value(template.build_arcs(&campaign.id)).map_err(|e| e.to_string())?;
+    for (key, value) in [
+        (ARCS_FIELD, arcs),
+        (TEMPLATE_FIELD, serde_json::Value::String(template.id.clone())),
+    ] {
+        world
+            .set_custom_field(&campaign.id, key, value)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let mut context = state.personality_manager.get_context(&campaign.id);
+    context.settings = template.personality.clone();
+    context.narrator_personality_id = template.narrator_personality_id.clone();
+    context.updated_at = Utc::now().to_rfc3339();
+    state.personality_manager.set_context(context);
+
+    state
+        .campaign_manager
+        .get_campaign(&campaign.id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign.id))
+}
+
+// ============================================================================
+// Template Commands
+// ============================================================================
+
+/// List built-in and user campaign templates.
+#[tauri::command]
+pub fn list_campaign_templates(app_handle: tauri::AppHandle) -> Result<Vec<CampaignTemplate>, String> {
+    Ok(CampaignTemplateStore::new(get_templates_dir(&app_handle)).list())
+}
+
+/// Save a user campaign template, replacing one with the same ID.
+#[tauri::command]
+pub fn save_campaign_template(
+    template: CampaignTemplate,
+    app_handle: tauri::AppHandle,
+) -> Result<CampaignTemplate, String> {
+    CampaignTemplateStore::new(get_templates_dir(&app_handle))
+        .save(template)
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a user campaign template.
+#[tauri::command]
+pub fn delete_campaign_template(template_id: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    CampaignTemplateStore::new(get_templates_dir(&app_handle))
+        .delete(&template_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new campaign from a template.
+///
+/// The template's arcs, factions, locations, house rules, and narrator
+/// personality settings are copied into the new campaign.
+#[tauri::command]
+pub fn create_campaign_from_template(
+    template_id: String,
+    name: String,
+    system: Option<String>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<Campaign, String> {
+    let template = CampaignTemplateStore::new(get_templates_dir(&app_handle))
+        .get(&template_id)
+        .map_err(|e| e.to_string())?;
+    apply_template(&state, &factions, &template, &name, system.as_deref())
+}
+
+// ============================================================================
+// Cloning
+// ============================================================================
+
+/// Deep-copy a campaign with new IDs, e.g. to run the same module for a
+/// second group.
+///
+/// Sessions are copied as planned sessions without their play logs.
+/// Snapshots are never copied.
+#[tauri::command]
+pub fn clone_campaign(
+    campaign_id: String,
+    options: Option<CloneCampaignOptions>,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<CloneCampaignResult, String> {
+    let options = options.unwrap_or_default();
+
+    let campaign = state
+        .campaign_manager
+        .clone_campaign(&campaign_id, options.name.as_deref(), !options.exclude_notes)
+        .map_err(|e| e.to_string())?;
+
+    let location_ids = state
+        .location_manager
+        .copy_campaign_locations(&campaign_id, &campaign.id)
+        .map_err(|e| e.to_string())?;
+
+    if let Some(mut world) = state.world_state_manager.copy_state(
+        &campaign_id,
+        &campaign.id,
+        &location_ids,
+        !options.exclude_sessions,
+    ) {
+        reissue_content_ids(&mut world);
+        state
+            .world_state_manager
+            .update_state(world)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let faction_ids = factions
+        .manager
+        .copy_campaign_factions(&campaign_id, &campaign.id, &location_ids);
+
+    let sessions_copied = if options.exclude_sessions {
+        0
+    } else {
+        state.session_manager.copy_sessions(&campaign_id, &campaign.id).len()
+    };
+
+    let mut context = state.personality_manager.get_context(&campaign_id);
+    context.campaign_id = campaign.id.clone();
+    context.session_id = None;
+    context.location_personalities = context
+        .location_personalities
+        .into_iter()
+        .map(|(id, personality)| (location_ids.get(&id).cloned().unwrap_or(id), personality))
+        .collect();
+    context.updated_at = Utc::now().to_rfc3339();
+    state.personality_manager.set_context(context);
+
+    Ok(CloneCampaignResult {
+        notes_copied: state.campaign_manager.get_notes(&campaign.id).len(),
+        locations_copied: location_ids.len(),
+        factions_copied: faction_ids.len(),
+        sessions_copied,
+        campaign,
+    })
+}
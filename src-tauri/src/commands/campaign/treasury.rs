@@ -0,0 +1,233 @@
+//! Party Treasury Commands
+//!
+//! Commands for tracking party funds and individual shares, splitting loot
+//! rolled from treasure tables, converting currency, and reporting spending.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::campaign::treasury::{
+    parse_loot, Account, CurrencySystem, LootDistribution, ParsedLoot, SpendingReport, Transaction, TransactionEntry,
+    TransactionKind, Treasury, TreasuryManager,
+};
+use crate::core::campaign::{RandomTableEngine, RollRequest};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign treasuries
+#[derive(Default)]
+pub struct TreasuryState {
+    pub manager: TreasuryManager,
+}
+
+/// Result of splitting loot among the party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootSplitResult {
+    /// The loot text that was parsed, e.g. a treasure table result
+    pub text: String,
+    pub loot: ParsedLoot,
+    /// Absent when the loot had no coins
+    pub distribution: Option<LootDistribution>,
+}
+
+/// Open the campaign's treasury in its game system's currency
+fn open_treasury(campaign_id: &str, state: &AppState, treasury: &TreasuryState) -> Result<Treasury, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+    Ok(treasury.manager.open_treasury(campaign_id, &campaign.system))
+}
+
+// ============================================================================
+// Treasury Commands
+// ============================================================================
+
+/// Get a campaign's treasury: party funds, shares, and transaction log.
+#[tauri::command]
+pub fn get_party_treasury(
+    campaign_id: String,
+    state: State<'_, AppState>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<Treasury, String> {
+    open_treasury(&campaign_id, &state, &treasury)
+}
+
+/// Record income, an expense, or a transfer.
+///
+/// # Arguments
+/// * `kind` - "income", "expense", or "transfer"
+/// * `account` - Account credited (income) or debited (expense, transfer)
+/// * `amount` - Amount in `currency`
+/// * `currency` - Denomination code or name (default: the system's main coin)
+/// * `to_account` - Account credited by a transfer
+/// * `category` - Grouping for spending reports (default: "general")
+#[tauri::command]
+pub fn record_treasury_transaction(
+    campaign_id: String,
+    kind: String,
+    account: Account,
+    amount: i64,
+    currency: Option<String>,
+    description: String,
+    to_account: Option<Account>,
+    category: Option<String>,
+    session_id: Option<String>,
+    state: State<'_, AppState>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<Transaction, String> {
+    let kind = TransactionKind::parse(&kind).ok_or_else(|| format!("Unknown transaction kind: {}", kind))?;
+    let current = open_treasury(&campaign_id, &state, &treasury)?;
+    let amount = current
+        .currency
+        .to_base(amount, currency.as_deref())
+        .map_err(|e| e.to_string())?;
+
+    let mut entry = TransactionEntry::new(account, amount, &description);
+    entry.category = category;
+    entry.session_id = session_id;
+    let result = match kind {
+        TransactionKind::Income => treasury.manager.deposit(&campaign_id, entry),
+        TransactionKind::Expense => treasury.manager.withdraw(&campaign_id, entry),
+        TransactionKind::Transfer => {
+            let to = to_account.ok_or_else(|| "A transfer needs a to_account".to_string())?;
+            treasury.manager.transfer(&campaign_id, entry, to)
+        }
+    };
+    result.map_err(|e| e.to_string())
+}
+
+/// List a campaign's transactions, oldest first.
+#[tauri::command]
+pub fn list_treasury_transactions(
+    campaign_id: String,
+    session_id: Option<String>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<Vec<Transaction>, String> {
+    Ok(treasury.manager.list_transactions(&campaign_id, session_id.as_deref()))
+}
+
+// ============================================================================
+// Loot Commands
+// ============================================================================
+
+/// Parse loot text into coins and items without changing the treasury.
+#[tauri::command]
+pub fn parse_loot_text(
+    campaign_id: String,
+    text: String,
+    state: State<'_, AppState>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<ParsedLoot, String> {
+    let current = open_treasury(&campaign_id, &state, &treasury)?;
+    Ok(parse_loot(&text, &current.currency, &mut rand::thread_rng()))
+}
+
+/// Split loot among characters. The loot comes from `text`, or from rolling
+/// on a treasure table when `table_id` is given.
+///
+/// # Arguments
+/// * `character_ids` - Characters receiving an equal share
+/// * `party_share` - Keep one share for the party fund (default: false)
+#[tauri::command]
+pub async fn distribute_loot(
+    campaign_id: String,
+    character_ids: Vec<String>,
+    text: Option<String>,
+    table_id: Option<String>,
+    party_share: Option<bool>,
+    session_id: Option<String>,
+    state: State<'_, AppState>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<LootSplitResult, String> {
+    let current = open_treasury(&campaign_id, &state, &treasury)?;
+    let text = match (text, table_id) {
+        (Some(text), _) => text,
+        (None, Some(table_id)) => {
+            let engine = RandomTableEngine::new(Arc::new(state.database.pool().clone()));
+            engine
+                .roll_on_table(RollRequest {
+                    table_id,
+                    session_id: session_id.clone(),
+                    campaign_id: Some(campaign_id.clone()),
+                    context: Some("loot".to_string()),
+                    forced_roll: None,
+                    max_depth: None,
+                })
+                .await
+                .map_err(|e| e.to_string())?
+                .final_text
+        }
+        (None, None) => return Err("Provide loot text or a treasure table".to_string()),
+    };
+
+    let loot = parse_loot(&text, &current.currency, &mut rand::thread_rng());
+    let distribution = if loot.total > 0 {
+        let description = format!("Loot: {}", text.lines().next().unwrap_or_default());
+        Some(
+            treasury
+                .manager
+                .distribute_loot(
+                    &campaign_id,
+                    loot.total,
+                    &character_ids,
+                    party_share.unwrap_or(false),
+                    &description,
+                    session_id.as_deref(),
+                )
+                .map_err(|e| e.to_string())?,
+        )
+    } else {
+        None
+    };
+
+    Ok(LootSplitResult { text, loot, distribution })
+}
+
+// ============================================================================
+// Currency and Report Commands
+// ============================================================================
+
+/// Get the currency a game system uses.
+#[tauri::command]
+pub fn get_currency_system(system: String) -> Result<CurrencySystem, String> {
+    Ok(CurrencySystem::for_system(&system))
+}
+
+/// Convert an amount between denominations of a game system's currency.
+/// Returns the whole amount and the remainder in the smallest coin.
+#[tauri::command]
+pub fn convert_currency(system: String, amount: i64, from: String, to: String) -> Result<(i64, i64), String> {
+    CurrencySystem::for_system(&system)
+        .convert(amount, &from, &to)
+        .map_err(|e| e.to_string())
+}
+
+/// Summarize a campaign's income and spending by category, account, and
+/// session.
+///
+/// # Arguments
+/// * `session_id` - Only this session's transactions
+/// * `since` - Only transactions at or after this RFC 3339 timestamp
+#[tauri::command]
+pub fn get_spending_report(
+    campaign_id: String,
+    session_id: Option<String>,
+    since: Option<String>,
+    treasury: State<'_, TreasuryState>,
+) -> Result<SpendingReport, String> {
+    let since = since
+        .map(|s| DateTime::parse_from_rfc3339(&s).map(|d| d.with_timezone(&Utc)))
+        .transpose()
+        .map_err(|e| format!("Invalid timestamp: {}", e))?;
+    treasury
+        .manager
+        .spending_report(&campaign_id, session_id.as_deref(), since)
+        .map_err(|e| e.to_string())
+}
@@ -1,13 +1,14 @@
 //! Campaign Versioning Commands
 //!
 //! Commands for managing campaign versions, including creation, comparison,
-//! rollback, tagging, and milestone marking.
+//! rollback, tagging, milestone marking, and snapshot storage upkeep.
 
 use tauri::State;
 
 use crate::commands::AppState;
 use crate::core::campaign::versioning::{
     CampaignVersion, VersionType, CampaignDiff, VersionSummary,
+    GarbageCollectionReport, SnapshotStorageStats,
 };
 use crate::core::models::Campaign;
 
@@ -141,3 +142,21 @@ pub fn mark_version_milestone(
     state.version_manager.mark_as_milestone(&campaign_id, &version_id)
         .map_err(|e| e.to_string())
 }
+
+/// Get version storage usage: checkpoints, deltas, and reclaimable space.
+///
+/// # Arguments
+/// * `campaign_id` - Limit the stats to one campaign (default: all campaigns)
+#[tauri::command]
+pub fn get_snapshot_storage_stats(
+    campaign_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<SnapshotStorageStats, String> {
+    Ok(state.version_manager.storage_stats(campaign_id.as_deref()))
+}
+
+/// Free snapshot data no version uses any more.
+#[tauri::command]
+pub fn collect_snapshot_garbage(state: State<'_, AppState>) -> Result<GarbageCollectionReport, String> {
+    Ok(state.version_manager.collect_garbage())
+}
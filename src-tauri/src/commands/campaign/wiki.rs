@@ -0,0 +1,108 @@
+//! Campaign Wiki Commands
+//!
+//! Commands for exporting a campaign as an interlinked Markdown or static
+//! HTML wiki that can be published to players.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::{AppState, FactionState};
+use crate::core::campaign::wiki::{build_wiki, WikiFormat, WikiRecap, WikiSource};
+use crate::core::session::recap::is_recap_note;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// What a wiki export wrote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiExportSummary {
+    pub campaign_id: String,
+    /// Directory the site was written to
+    pub path: String,
+    pub format: WikiFormat,
+    pub player_safe: bool,
+    /// Index page, relative to `path`
+    pub index: String,
+    pub pages: usize,
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Export a campaign's NPCs, locations, factions, and session recaps as an
+/// interlinked wiki.
+///
+/// # Arguments
+/// * `path` - Directory to write the site into
+/// * `format` - "markdown" (default) or "html"
+/// * `player_safe` - Leave out secrets, hidden features, faction clocks,
+///   GM notes, and GM-only pages (default: true)
+#[tauri::command]
+pub async fn export_campaign_wiki(
+    campaign_id: String,
+    path: String,
+    format: Option<String>,
+    player_safe: Option<bool>,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+) -> Result<WikiExportSummary, String> {
+    let format = format
+        .as_deref()
+        .map(WikiFormat::parse)
+        .transpose()
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    let player_safe = player_safe.unwrap_or(true);
+
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+
+    let recaps = state
+        .session_manager
+        .list_sessions(&campaign_id)
+        .into_iter()
+        .flat_map(|session| {
+            state
+                .session_manager
+                .list_notes_for_session(&session.id)
+                .into_iter()
+                .filter(is_recap_note)
+                .map(move |note| WikiRecap {
+                    session_number: session.session_number,
+                    title: note.title,
+                    content: note.content,
+                    is_private: note.is_private,
+                })
+        })
+        .collect();
+
+    let source = WikiSource {
+        campaign,
+        npcs: state.npc_store.list(Some(&campaign_id)),
+        locations: state.location_manager.list_locations_for_campaign(&campaign_id),
+        factions: factions.manager.list_factions(&campaign_id),
+        recaps,
+    };
+    let site = build_wiki(&source, format, player_safe);
+
+    let dest = PathBuf::from(&path);
+    let written = tokio::task::spawn_blocking(move || site.write_to(&dest))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    Ok(WikiExportSummary {
+        campaign_id,
+        path,
+        format,
+        player_safe,
+        index: format!("index.{}", format.extension()),
+        pages: written.len(),
+    })
+}
@@ -0,0 +1,194 @@
+//! Special Action Commands
+//!
+//! Commands for legendary action pools, lair actions on initiative count 20,
+//! reactions, and limited abilities such as recharge breath weapons.
+//! Spending the last of a pool comes back with a warning; spending from an
+//! empty one is refused.
+
+use serde::Serialize;
+use tauri::{Emitter, State};
+
+use crate::commands::AppState;
+use crate::core::session_manager::{
+    ActionSpend, CombatState, LairActions, TrackedAbility, TurnResult, UsageLimit,
+};
+
+/// Event emitted when a lair acts at the top of its initiative count
+pub const LAIR_ACTION_EVENT: &str = "combat:lair_action";
+
+/// Event emitted with the recharge rolls made at the start of a turn
+pub const RECHARGE_EVENT: &str = "combat:recharge";
+
+/// A lair acting, for the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct LairActionEvent {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub round: u32,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Tell the UI a lair is acting before the new turn
+pub(crate) fn emit_lair_action(result: &TurnResult, combat: Option<&CombatState>, app_handle: &tauri::AppHandle) {
+    let (Some(owner_id), Some(combat)) = (&result.lair_action, combat) else {
+        return;
+    };
+    if let Some(owner) = combat.get_combatant(owner_id) {
+        let _ = app_handle.emit(LAIR_ACTION_EVENT, LairActionEvent {
+            combatant_id: owner.id.clone(),
+            combatant_name: owner.name.clone(),
+            round: combat.round,
+        });
+    }
+}
+
+/// Tell the UI which spent abilities the new combatant rolled to recharge
+pub(crate) fn emit_recharges(result: &TurnResult, app_handle: &tauri::AppHandle) {
+    if !result.recharges.is_empty() {
+        let _ = app_handle.emit(RECHARGE_EVENT, &result.recharges);
+    }
+}
+
+// ============================================================================
+// Legendary Action Commands
+// ============================================================================
+
+/// Give a combatant legendary actions per round (0 removes them)
+#[tauri::command]
+pub fn set_legendary_actions(
+    session_id: String,
+    combatant_id: String,
+    max: u32,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.session_manager.set_legendary_actions(&session_id, &combatant_id, max)
+        .map_err(|e| e.to_string())
+}
+
+/// Spend legendary actions at the end of another combatant's turn
+///
+/// # Arguments
+/// * `cost` - Actions the option costs (default: 1)
+/// * `action` - What the creature does, for the combat log
+#[tauri::command]
+pub fn spend_legendary_action(
+    session_id: String,
+    combatant_id: String,
+    cost: Option<u32>,
+    action: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ActionSpend, String> {
+    state.session_manager
+        .spend_legendary_action(&session_id, &combatant_id, cost.unwrap_or(1), action.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+/// Refill a combatant's legendary actions and reaction before their turn
+#[tauri::command]
+pub fn reset_combatant_actions(
+    session_id: String,
+    combatant_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.session_manager.refresh_actions(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Reaction Commands
+// ============================================================================
+
+/// Spend a combatant's reaction for the round
+///
+/// # Arguments
+/// * `reaction` - What the combatant does, for the combat log
+#[tauri::command]
+pub fn use_reaction(
+    session_id: String,
+    combatant_id: String,
+    reaction: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ActionSpend, String> {
+    state.session_manager.use_reaction(&session_id, &combatant_id, reaction.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Lair Action Commands
+// ============================================================================
+
+/// Set the creature whose lair acts each round, or clear it
+///
+/// # Arguments
+/// * `combatant_id` - The lair's owner (omit to clear lair actions)
+/// * `initiative` - Initiative count the lair acts on, losing ties (default: 20)
+#[tauri::command]
+pub fn set_lair_actions(
+    session_id: String,
+    combatant_id: Option<String>,
+    initiative: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<Option<LairActions>, String> {
+    let lair = combatant_id.map(|id| {
+        let mut lair = LairActions::new(id);
+        if let Some(initiative) = initiative {
+            lair.initiative = initiative;
+        }
+        lair
+    });
+    state.session_manager.set_lair_actions(&session_id, lair.clone())
+        .map_err(|e| e.to_string())?;
+    Ok(lair)
+}
+
+// ============================================================================
+// Limited Ability Commands
+// ============================================================================
+
+/// Use a recharge, per-turn, per-round, or X/Day ability. Spent recharge
+/// abilities are rolled for at the start of the creature's turn.
+#[tauri::command]
+pub fn use_combatant_ability(
+    session_id: String,
+    combatant_id: String,
+    ability: String,
+    state: State<'_, AppState>,
+) -> Result<TrackedAbility, String> {
+    state.session_manager.use_ability(&session_id, &combatant_id, &ability)
+        .map_err(|e| e.to_string())
+}
+
+/// Bring a limited ability back without rolling
+#[tauri::command]
+pub fn restore_combatant_ability(
+    session_id: String,
+    combatant_id: String,
+    ability: String,
+    state: State<'_, AppState>,
+) -> Result<TrackedAbility, String> {
+    state.session_manager.restore_ability(&session_id, &combatant_id, &ability)
+        .map_err(|e| e.to_string())
+}
+
+/// Track a limited ability the stat block didn't mark
+///
+/// # Arguments
+/// * `limit` - A printed limit such as "Recharge 5-6", "3/Day", or "1/Turn"
+#[tauri::command]
+pub fn track_combatant_ability(
+    session_id: String,
+    combatant_id: String,
+    name: String,
+    limit: String,
+    state: State<'_, AppState>,
+) -> Result<TrackedAbility, String> {
+    let limit = UsageLimit::parse(&limit)
+        .ok_or_else(|| format!("Can't read a usage limit from: {}", limit))?;
+    let ability = TrackedAbility::new(name, limit);
+    state.session_manager.track_ability(&session_id, &combatant_id, ability.clone())
+        .map_err(|e| e.to_string())?;
+    Ok(ability)
+}
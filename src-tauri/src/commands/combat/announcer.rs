@@ -0,0 +1,202 @@
+//! Combat Announcer Commands
+//!
+//! Opt-in spoken announcements for combat events. Combat commands report
+//! state changes here; enabled announcements are synthesized in the
+//! announcer's voice and played one at a time on the announcer mixer channel,
+//! separate from the dialogue queue.
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use tauri::{Manager, State};
+use tokio::sync::mpsc;
+use tokio::sync::RwLock as AsyncRwLock;
+
+use crate::commands::AppState;
+use crate::core::audio::{AudioMixer, MixerChannel};
+use crate::core::session_manager::Combatant;
+use crate::core::voice::{
+    AnnouncerConfig, AnnouncerEvent, CombatAnnouncer, OutputFormat, SynthesisRequest, VoiceManager,
+};
+
+/// Announcements still waiting after this long are dropped as out of date
+const STALE_AFTER: Duration = Duration::from_secs(6);
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// A rendered announcement waiting to be spoken
+struct PendingAnnouncement {
+    text: String,
+    voice_id: String,
+    queued_at: Instant,
+}
+
+/// Combat announcer state: templates/settings plus the playback worker
+pub struct CombatAnnouncerState {
+    pub announcer: RwLock<CombatAnnouncer>,
+    tx: mpsc::UnboundedSender<PendingAnnouncement>,
+}
+
+impl CombatAnnouncerState {
+    /// Create the announcer and start its playback worker
+    pub fn new(config: AnnouncerConfig, voice_manager: Arc<AsyncRwLock<VoiceManager>>, mixer: AudioMixer) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tauri::async_runtime::spawn(speak_announcements(rx, voice_manager, mixer));
+        Self {
+            announcer: RwLock::new(CombatAnnouncer::new(config)),
+            tx,
+        }
+    }
+}
+
+/// Speak announcements in order, skipping any that went stale while waiting
+async fn speak_announcements(
+    mut rx: mpsc::UnboundedReceiver<PendingAnnouncement>,
+    voice_manager: Arc<AsyncRwLock<VoiceManager>>,
+    mixer: AudioMixer,
+) {
+    while let Some(pending) = rx.recv().await {
+        if pending.queued_at.elapsed() > STALE_AFTER {
+            log::debug!("Dropping stale announcement: {}", pending.text);
+            continue;
+        }
+
+        let request = SynthesisRequest {
+            text: pending.text,
+            voice_id: pending.voice_id,
+            settings: None,
+            output_format: OutputFormat::Mp3,
+        };
+        let result = {
+            let manager = voice_manager.read().await;
+            manager.synthesize(request).await
+        };
+        let audio = match result {
+            Ok(result) => tokio::fs::read(&result.audio_path).await,
+            Err(e) => {
+                log::warn!("Combat announcement synthesis failed: {}", e);
+                continue;
+            }
+        };
+
+        let track = match audio.map(|data| mixer.play_bytes(MixerChannel::Announcer, data, 1.0)) {
+            Ok(Ok(track)) => track,
+            Ok(Err(e)) => {
+                log::warn!("Combat announcement playback failed: {}", e);
+                continue;
+            }
+            Err(e) => {
+                log::warn!("Could not read combat announcement audio: {}", e);
+                continue;
+            }
+        };
+        let _ = tokio::task::spawn_blocking(move || track.sleep_until_end()).await;
+    }
+}
+
+/// Announce a combat event if the announcer is enabled for it
+pub(crate) fn announce_combat_event(announcer: &CombatAnnouncerState, event: AnnouncerEvent) {
+    let Ok(guard) = announcer.announcer.read() else {
+        return;
+    };
+    let Some(text) = guard.announce(&event) else {
+        return;
+    };
+    let voice_id = guard.config().voice_id.clone().unwrap_or_else(|| "default".to_string());
+    let _ = announcer.tx.send(PendingAnnouncement {
+        text,
+        voice_id,
+        queued_at: Instant::now(),
+    });
+}
+
+/// Look up a combatant in a session's active combat
+pub(crate) fn find_combatant(state: &AppState, session_id: &str, combatant_id: &str) -> Option<Combatant> {
+    state
+        .session_manager
+        .get_combat(session_id)?
+        .combatants
+        .into_iter()
+        .find(|c| c.id == combatant_id)
+}
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_announcer_config_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("combat_announcer.json")
+}
+
+/// Load combat announcer settings from disk
+pub fn load_announcer_config_disk(app_handle: &tauri::AppHandle) -> Option<AnnouncerConfig> {
+    let path = get_announcer_config_path(app_handle);
+    if !path.exists() {
+        return None;
+    }
+    match AnnouncerConfig::load(&path) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            log::warn!("Failed to parse combat announcer config: {}", e);
+            None
+        }
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get the combat announcer settings and custom templates
+#[tauri::command]
+pub fn get_combat_announcer_config(
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<AnnouncerConfig, String> {
+    let announcer = announcer.announcer.read().map_err(|e| e.to_string())?;
+    Ok(announcer.config().clone())
+}
+
+/// Replace the combat announcer settings and custom templates
+#[tauri::command]
+pub fn save_combat_announcer_config(
+    config: AnnouncerConfig,
+    app_handle: tauri::AppHandle,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<(), String> {
+    config.validate()?;
+    config
+        .save(&get_announcer_config_path(&app_handle))
+        .map_err(|e| e.to_string())?;
+    announcer.announcer.write().map_err(|e| e.to_string())?.set_config(config);
+    Ok(())
+}
+
+/// Render the announcement for an event, optionally speaking it
+///
+/// Works while the announcer is disabled so templates can be tried out.
+#[tauri::command]
+pub fn preview_combat_announcement(
+    event: AnnouncerEvent,
+    speak: Option<bool>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<Option<String>, String> {
+    let guard = announcer.announcer.read().map_err(|e| e.to_string())?;
+    let text = guard.render(&event);
+
+    if let (Some(text), true) = (&text, speak.unwrap_or(false)) {
+        let voice_id = guard.config().voice_id.clone().unwrap_or_else(|| "default".to_string());
+        announcer
+            .tx
+            .send(PendingAnnouncement {
+                text: text.clone(),
+                voice_id,
+                queued_at: Instant::now(),
+            })
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(text)
+}
@@ -1,36 +1,84 @@
 //! Combatant Management Commands
 //!
-//! Commands for managing combatants: add, remove, damage, heal, and initiative.
+//! Commands for managing combatants: add, remove, damage (typed, against
+//! resistances, and across several targets), heal, and initiative, plus
+//! minion groups that act on one initiative and shrink as members drop.
+//! Damage rolls morale for enemy groups that lose their leader or half
+//! their number.
 
+use std::collections::HashMap;
 use tauri::State;
-use crate::commands::AppState;
-use crate::core::session_manager::{Combatant, CombatantType};
+use crate::commands::{fire_sfx_event, AppState, PartyState, SfxTriggerState};
+use crate::core::audio::SfxEvent;
+use crate::core::npc_gen::NPCRole;
+use crate::core::session_manager::{
+    AreaDamage, AreaDamageResult, Combatant, CombatantType, CurrentCombatant, Damage, DamageBreakdown,
+    DamageDefenses, GroupDamageResult, GroupHpMode, GroupTargets, HpMethod, TurnResult,
+};
+use crate::ingestion::ttrpg::StatBlockData;
+use crate::core::voice::AnnouncerEvent;
+
+use super::announcer::{announce_combat_event, find_combatant, CombatAnnouncerState};
+use super::actions::{emit_lair_action, emit_recharges};
+use super::conditions::{emit_condition_expiries, emit_condition_reminders};
+use super::death::emit_death_update;
+use super::initiative::{emit_readied, resolve_initiative_modifier};
+use super::morale::emit_morale_checks;
+
+fn parse_combatant_type(combatant_type: &str) -> Result<CombatantType, String> {
+    match combatant_type {
+        "player" => Ok(CombatantType::Player),
+        "npc" => Ok(CombatantType::NPC),
+        "monster" => Ok(CombatantType::Monster),
+        "ally" => Ok(CombatantType::Ally),
+        _ => Err(format!(
+            "Unknown combatant type: '{}'. Valid types: player, npc, monster, ally",
+            combatant_type
+        )),
+    }
+}
 
 /// Add a combatant to the current combat
+///
+/// Without `initiative`, initiative is rolled with the combat's rules. The
+/// modifier comes from `initiative_modifier`, a stat block `dexterity`
+/// score, the party roster (`character_id`), or the NPC's stats (`npc_id`).
 #[tauri::command]
 pub fn add_combatant(
     session_id: String,
     name: String,
-    initiative: i32,
+    initiative: Option<i32>,
     combatant_type: String,
     hp_current: Option<i32>,
     hp_max: Option<i32>,
     armor_class: Option<i32>,
+    initiative_modifier: Option<i32>,
+    dexterity: Option<i32>,
+    character_id: Option<String>,
+    npc_id: Option<String>,
+    surprised: Option<bool>,
     state: State<'_, AppState>,
+    party: State<'_, PartyState>,
 ) -> Result<Combatant, String> {
-    let ctype = match combatant_type.as_str() {
-        "player" => CombatantType::Player,
-        "npc" => CombatantType::NPC,
-        "monster" => CombatantType::Monster,
-        "ally" => CombatantType::Ally,
-        _ => return Err(format!(
-            "Unknown combatant type: '{}'. Valid types: player, npc, monster, ally",
-            combatant_type
-        )),
-    };
+    let ctype = parse_combatant_type(&combatant_type)?;
+
+    let combat = state.session_manager.get_combat(&session_id)
+        .ok_or_else(|| "No active combat".to_string())?;
+    let modifier = resolve_initiative_modifier(
+        initiative_modifier,
+        dexterity,
+        character_id.as_deref(),
+        npc_id.as_deref(),
+        &state,
+        &party,
+    );
 
     // Create full combatant with optional HP/AC
-    let mut combatant = Combatant::new(name.clone(), initiative, ctype);
+    let mut combatant = Combatant::new(name.clone(), initiative.unwrap_or(0), ctype);
+    combatant.initiative_modifier = modifier;
+    if initiative.is_none() {
+        combat.initiative_rules.roll(modifier, &mut rand::thread_rng()).apply(&mut combatant);
+    }
     combatant.current_hp = hp_current.or(hp_max);
     combatant.max_hp = hp_max;
     combatant.armor_class = armor_class;
@@ -38,9 +86,207 @@ pub fn add_combatant(
     state.session_manager.add_combatant(&session_id, combatant.clone())
         .map_err(|e| e.to_string())?;
 
+    if surprised.unwrap_or(false) {
+        let surprised_ids: Vec<String> = combat
+            .combatants
+            .iter()
+            .filter(|c| c.surprised)
+            .map(|c| c.id.clone())
+            .chain(std::iter::once(combatant.id.clone()))
+            .collect();
+        state.session_manager.set_surprised(&session_id, &surprised_ids)
+            .map_err(|e| e.to_string())?;
+        combatant.surprised = true;
+    }
+
     Ok(combatant)
 }
 
+/// Add a creature from a parsed stat block, fully populated with HP, AC,
+/// saves, attacks, and traits. Several copies get numbered names
+/// ("Goblin 1", "Goblin 2", ...) and their own initiative rolls.
+///
+/// # Arguments
+/// * `count` - Number of copies (default: 1)
+/// * `hp_method` - "average" (default) or "rolled" from the hit dice
+/// * `combatant_type` - Defaults to "monster"
+#[tauri::command]
+pub fn add_combatant_from_stat_block(
+    session_id: String,
+    stat_block: StatBlockData,
+    count: Option<u32>,
+    hp_method: Option<HpMethod>,
+    combatant_type: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Combatant>, String> {
+    let ctype = combatant_type
+        .as_deref()
+        .map(parse_combatant_type)
+        .transpose()?
+        .unwrap_or(CombatantType::Monster);
+    state
+        .session_manager
+        .add_stat_block_combatants(&session_id, &stat_block, count.unwrap_or(1), ctype, hp_method.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Add a generated NPC to combat using the stat block built for it at
+/// generation time. Fails when the NPC was generated without one.
+///
+/// # Arguments
+/// * `count` - Number of copies (default: 1)
+/// * `hp_method` - "average" (default) or "rolled" from the hit dice
+/// * `combatant_type` - Defaults from the NPC's role: "monster" for enemies,
+///   bosses, and minions, "ally" for allies, otherwise "npc"
+#[tauri::command]
+pub fn add_npc_to_combat(
+    session_id: String,
+    npc_id: String,
+    count: Option<u32>,
+    hp_method: Option<HpMethod>,
+    combatant_type: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Combatant>, String> {
+    let npc = state
+        .npc_store
+        .get(&npc_id)
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    let stat_block = npc
+        .stat_block
+        .ok_or_else(|| format!("NPC {} has no stat block", npc.name))?;
+    let ctype = match combatant_type.as_deref() {
+        Some(s) => parse_combatant_type(s)?,
+        None if matches!(npc.role, NPCRole::Enemy | NPCRole::Boss | NPCRole::Minion) => {
+            CombatantType::Monster
+        }
+        None if npc.role == NPCRole::Ally => CombatantType::Ally,
+        None => CombatantType::NPC,
+    };
+    state
+        .session_manager
+        .add_stat_block_combatants(&session_id, &stat_block, count.unwrap_or(1), ctype, hp_method.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Add a group of identical creatures acting on one initiative, such as a
+/// dozen skeletons. Without `initiative`, it is rolled with the combat's
+/// rules.
+///
+/// # Arguments
+/// * `size` - Number of creatures in the group
+/// * `member_hp` - Hit points of each creature
+/// * `hp_mode` - "pooled" (default), where damage spills from one member
+///   into the next, or "per_member"
+#[tauri::command]
+pub fn add_combatant_group(
+    session_id: String,
+    name: String,
+    size: u32,
+    member_hp: i32,
+    hp_mode: Option<GroupHpMode>,
+    initiative: Option<i32>,
+    combatant_type: Option<String>,
+    armor_class: Option<i32>,
+    initiative_modifier: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<Combatant, String> {
+    let ctype = combatant_type
+        .as_deref()
+        .map(parse_combatant_type)
+        .transpose()?
+        .unwrap_or(CombatantType::Monster);
+    let combat = state.session_manager.get_combat(&session_id)
+        .ok_or_else(|| "No active combat".to_string())?;
+
+    let mut combatant = Combatant::new(name, initiative.unwrap_or(0), ctype);
+    combatant.initiative_modifier = initiative_modifier.unwrap_or(0);
+    if initiative.is_none() {
+        combat.initiative_rules.roll(combatant.initiative_modifier, &mut rand::thread_rng()).apply(&mut combatant);
+    }
+    combatant.max_hp = Some(member_hp);
+    combatant.armor_class = armor_class;
+
+    state.session_manager
+        .add_combatant_group(&session_id, combatant, size, hp_mode.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Add a group of a stat block's creature acting on one initiative, each
+/// member with the stat block's hit points.
+///
+/// # Arguments
+/// * `hp_method` - "average" (default) or "rolled" from the hit dice
+/// * `hp_mode` - "pooled" (default) or "per_member"
+#[tauri::command]
+pub fn add_group_from_stat_block(
+    session_id: String,
+    stat_block: StatBlockData,
+    size: u32,
+    hp_method: Option<HpMethod>,
+    hp_mode: Option<GroupHpMode>,
+    combatant_type: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Combatant, String> {
+    let ctype = combatant_type
+        .as_deref()
+        .map(parse_combatant_type)
+        .transpose()?
+        .unwrap_or(CombatantType::Monster);
+    state
+        .session_manager
+        .add_stat_block_group(
+            &session_id,
+            &stat_block,
+            size,
+            ctype,
+            hp_method.unwrap_or_default(),
+            hp_mode.unwrap_or_default(),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Damage members of a group. Each member hit takes `amount`, adjusted for
+/// the group's defenses; members whose hit points run out drop, and the
+/// group dies with its last member. Plain `damage_combatant` on a group hits
+/// one member.
+///
+/// # Arguments
+/// * `targets` - `{ "count": n }` for the first n members still standing
+///   (default: one), or `{ "members": [numbers] }`
+#[tauri::command]
+pub fn damage_group(
+    session_id: String,
+    combatant_id: String,
+    amount: i32,
+    targets: Option<GroupTargets>,
+    damage_type: Option<String>,
+    magical: Option<bool>,
+    source_id: Option<String>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<GroupDamageResult, String> {
+    if amount < 0 {
+        return Err("Damage amount cannot be negative. Use heal_combatant for healing.".to_string());
+    }
+    let damage = Damage {
+        amount,
+        damage_type,
+        magical: magical.unwrap_or(false),
+        critical: false,
+        source_id,
+    };
+    let result = state.session_manager
+        .damage_group(&session_id, &combatant_id, &damage, &targets.unwrap_or_default())
+        .map_err(|e| e.to_string())?;
+    let any_dropped = !result.hits.dropped.is_empty();
+    fire_sfx_event(&sfx, if any_dropped { SfxEvent::Death } else { SfxEvent::Damage });
+    resolve_damage(&session_id, &combatant_id, &result.breakdown, &app_handle, &state, &sfx, &announcer)?;
+    emit_morale_checks(&session_id, &app_handle, &state)?;
+    Ok(result)
+}
+
 /// Remove a combatant from combat
 #[tauri::command]
 pub fn remove_combatant(
@@ -52,45 +298,235 @@ pub fn remove_combatant(
         .map_err(|e| e.to_string())
 }
 
-/// Advance to the next turn in initiative order
+/// Advance to the next turn in initiative order. Conditions that expire on
+/// the way are emitted as `combat:conditions_expired`, and reminders for the
+/// new combatant's conditions as `combat:condition_reminders`. A lair acting
+/// before the turn is emitted as `combat:lair_action`, recharge rolls for
+/// the new combatant's spent abilities as `combat:recharge`, and readied
+/// actions their turn sets off as `combat:readied`.
 #[tauri::command]
-pub fn next_turn(session_id: String, state: State<'_, AppState>) -> Result<Option<Combatant>, String> {
-    state.session_manager.next_turn(&session_id)
-        .map_err(|e| e.to_string())
+pub fn next_turn(
+    session_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<Option<Combatant>, String> {
+    let result = state.session_manager.advance_turn(&session_id)
+        .map_err(|e| e.to_string())?;
+    resolve_turn(&session_id, &result, &app_handle, &state, &sfx, &announcer);
+    Ok(result.current_combatant)
+}
+
+/// Expiries, lair actions, recharges, readied actions, and announcements
+/// after the turn passes to a new combatant
+pub(crate) fn resolve_turn(
+    session_id: &str,
+    result: &TurnResult,
+    app_handle: &tauri::AppHandle,
+    state: &AppState,
+    sfx: &SfxTriggerState,
+    announcer: &CombatAnnouncerState,
+) {
+    emit_condition_expiries(&result.expired_conditions, app_handle, sfx, announcer);
+    let combat = state.session_manager.get_combat(session_id);
+    emit_lair_action(result, combat.as_ref(), app_handle);
+    emit_recharges(result, app_handle);
+    emit_readied(result, app_handle);
+    fire_sfx_event(sfx, SfxEvent::TurnStart);
+
+    if let Some(combatant) = &result.current_combatant {
+        let round = combat.as_ref().map(|c| c.round).unwrap_or(1);
+        announce_combat_event(announcer, AnnouncerEvent::TurnStart {
+            name: combatant.name.clone(),
+            round,
+            new_round: result.new_round,
+        });
+    }
+    emit_condition_reminders(&result.reminders, app_handle);
 }
 
-/// Get the current combatant (whose turn it is)
+/// Get the current combatant (whose turn it is), with alerts for limited
+/// abilities they have ready such as "Breath Weapon available!"
 #[tauri::command]
-pub fn get_current_combatant(session_id: String, state: State<'_, AppState>) -> Result<Option<Combatant>, String> {
-    Ok(state.session_manager.get_current_combatant(&session_id))
+pub fn get_current_combatant(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<Option<CurrentCombatant>, String> {
+    Ok(state.session_manager.get_current_combatant(&session_id).map(CurrentCombatant::from))
 }
 
-/// Apply damage to a combatant
+/// Apply damage to a combatant. A concentrating combatant dropped to 0 HP
+/// loses concentration; otherwise a concentration check reminder is emitted.
+/// Dropping to 0 HP applies the combat's death rules, emitted as
+/// `combat:death_update`, and morale checks it calls for are emitted as
+/// `combat:morale`.
+///
+/// Typed damage is adjusted for the combatant's resistances, immunities, and
+/// vulnerabilities before temporary HP absorbs it. The response breaks down
+/// each step from the damage dealt to the hit points lost.
+///
+/// # Arguments
+/// * `damage_type` - Damage type such as "fire" or "slashing"
+/// * `magical` - The damage bypasses resistance to nonmagical attacks (default: false)
+/// * `critical` - The damage came from a critical hit (default: false)
+/// * `source_id` - The combatant dealing the damage, for the combat report
 #[tauri::command]
 pub fn damage_combatant(
     session_id: String,
     combatant_id: String,
     amount: i32,
+    damage_type: Option<String>,
+    magical: Option<bool>,
+    critical: Option<bool>,
+    source_id: Option<String>,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<i32, String> {
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<DamageBreakdown, String> {
     if amount < 0 {
         return Err("Damage amount cannot be negative. Use heal_combatant for healing.".to_string());
     }
-    state.session_manager.damage_combatant(&session_id, &combatant_id, amount)
+    let damage = Damage {
+        amount,
+        damage_type,
+        magical: magical.unwrap_or(false),
+        critical: critical.unwrap_or(false),
+        source_id,
+    };
+    let breakdown = state.session_manager
+        .take_damage(&session_id, &combatant_id, &damage)
+        .map_err(|e| e.to_string())?;
+    fire_sfx_event(&sfx, if breakdown.hp_after == 0 { SfxEvent::Death } else { SfxEvent::Damage });
+    resolve_damage(&session_id, &combatant_id, &breakdown, &app_handle, &state, &sfx, &announcer)?;
+    emit_morale_checks(&session_id, &app_handle, &state)?;
+    Ok(breakdown)
+}
+
+/// Replace a combatant's damage resistances, immunities, and vulnerabilities
+#[tauri::command]
+pub fn set_combatant_damage_defenses(
+    session_id: String,
+    combatant_id: String,
+    defenses: DamageDefenses,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.session_manager.set_damage_defenses(&session_id, &combatant_id, defenses)
         .map_err(|e| e.to_string())
 }
 
-/// Heal a combatant
+/// Apply one damage roll to several combatants, as from a fireball or a
+/// breath weapon. Targets marked as saved in `saves` take half, and each
+/// target's resistances, immunities, and vulnerabilities apply. Undone as a
+/// single action.
+///
+/// # Arguments
+/// * `damage` - Dice notation such as "8d6", or a flat amount
+/// * `saves` - Save results by combatant ID; missing targets failed
+/// * `magical` - The damage bypasses resistance to nonmagical attacks (default: false)
+/// * `source_id` - The combatant whose effect it is, for the combat report
+#[tauri::command]
+pub fn apply_damage_to_many(
+    session_id: String,
+    combatant_ids: Vec<String>,
+    damage: String,
+    damage_type: Option<String>,
+    saves: Option<HashMap<String, bool>>,
+    magical: Option<bool>,
+    source_id: Option<String>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<AreaDamageResult, String> {
+    let area = AreaDamage {
+        expression: damage,
+        damage_type,
+        magical: magical.unwrap_or(false),
+        source_id,
+    };
+    let result = state.session_manager
+        .apply_damage_to_many(&session_id, &combatant_ids, &area, &saves.unwrap_or_default())
+        .map_err(|e| e.to_string())?;
+
+    let any_down = result.targets.iter().any(|t| t.breakdown.hp_after == 0);
+    fire_sfx_event(&sfx, if any_down { SfxEvent::Death } else { SfxEvent::Damage });
+    for target in &result.targets {
+        resolve_damage(&session_id, &target.combatant_id, &target.breakdown, &app_handle, &state, &sfx, &announcer)?;
+    }
+    emit_morale_checks(&session_id, &app_handle, &state)?;
+    Ok(result)
+}
+
+/// Death updates, concentration, and announcements after a combatant takes
+/// damage
+fn resolve_damage(
+    session_id: &str,
+    combatant_id: &str,
+    breakdown: &DamageBreakdown,
+    app_handle: &tauri::AppHandle,
+    state: &AppState,
+    sfx: &SfxTriggerState,
+    announcer: &CombatAnnouncerState,
+) -> Result<(), String> {
+    let new_hp = breakdown.hp_after;
+    let amount = breakdown.effective;
+    emit_death_update(combatant_id, breakdown.death.clone(), app_handle);
+
+    if new_hp == 0 {
+        let expired = state.session_manager.break_concentration(session_id, combatant_id)
+            .map_err(|e| e.to_string())?;
+        emit_condition_expiries(&expired, app_handle, sfx, announcer);
+    } else if amount > 0 {
+        if let Some(check) = state.session_manager.concentration_check(session_id, combatant_id, amount) {
+            emit_condition_reminders(&[check], app_handle);
+        }
+    }
+
+    if let Some(combatant) = find_combatant(state, session_id, combatant_id) {
+        let event = if new_hp == 0 {
+            AnnouncerEvent::Death { name: combatant.name }
+        } else {
+            AnnouncerEvent::Damage {
+                name: combatant.name,
+                amount,
+                hp: combatant.current_hp,
+                max_hp: combatant.max_hp,
+            }
+        };
+        announce_combat_event(announcer, event);
+    }
+    Ok(())
+}
+
+/// Heal a combatant. Healing a dying combatant brings them back; the dead
+/// are not healed.
 #[tauri::command]
 pub fn heal_combatant(
     session_id: String,
     combatant_id: String,
     amount: i32,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
 ) -> Result<i32, String> {
     if amount < 0 {
         return Err("Heal amount cannot be negative. Use damage_combatant for damage.".to_string());
     }
-    state.session_manager.heal_combatant(&session_id, &combatant_id, amount)
-        .map_err(|e| e.to_string())
+    let update = state.session_manager.apply_healing(&session_id, &combatant_id, amount)
+        .map_err(|e| e.to_string())?;
+    let new_hp = update.hp;
+    fire_sfx_event(&sfx, SfxEvent::Healing);
+    emit_death_update(&combatant_id, update.death, &app_handle);
+
+    if let Some(combatant) = find_combatant(&state, &session_id, &combatant_id) {
+        announce_combat_event(&announcer, AnnouncerEvent::Healing {
+            name: combatant.name,
+            amount,
+            hp: combatant.current_hp,
+        });
+    }
+    Ok(new_hp)
 }
@@ -1,13 +1,26 @@
 //! Combat Condition Commands
 //!
-//! Commands for managing conditions on combatants: add, remove, tick, and templates.
+//! Commands for managing conditions on combatants: add, remove, tick,
+//! concentration, and templates. Conditions that expire on their own are
+//! emitted as events for the UI and spoken by the announcer.
 
 use serde::{Deserialize, Serialize};
-use tauri::State;
-use crate::commands::AppState;
+use tauri::{Emitter, State};
+use crate::commands::{fire_sfx_event, AppState, SfxTriggerState};
+use crate::core::audio::SfxEvent;
 use crate::core::session::conditions::{
     AdvancedCondition, ConditionDuration, ConditionTemplates, SaveTiming,
 };
+use crate::core::session_manager::{ConditionExpiry, ConditionReminder};
+use crate::core::voice::AnnouncerEvent;
+
+use super::announcer::{announce_combat_event, find_combatant, CombatAnnouncerState};
+
+/// Event emitted with the conditions that expired on their own
+pub const CONDITIONS_EXPIRED_EVENT: &str = "combat:conditions_expired";
+
+/// Event emitted with reminders for a combatant's conditions
+pub const CONDITION_REMINDERS_EVENT: &str = "combat:condition_reminders";
 
 // ============================================================================
 // Request Types
@@ -25,6 +38,9 @@ pub struct AddConditionRequest {
     pub source_name: Option<String>,
     pub save_type: Option<String>,
     pub save_dc: Option<u32>,
+    /// End the condition when the source stops concentrating
+    #[serde(default)]
+    pub concentration: Option<bool>,
 }
 
 // ============================================================================
@@ -58,6 +74,44 @@ fn parse_condition_duration(
     }
 }
 
+/// Tell the UI and the announcer about conditions that ended on their own
+pub(crate) fn emit_condition_expiries(
+    expired: &[ConditionExpiry],
+    app_handle: &tauri::AppHandle,
+    sfx: &SfxTriggerState,
+    announcer: &CombatAnnouncerState,
+) {
+    if expired.is_empty() {
+        return;
+    }
+    let _ = app_handle.emit(CONDITIONS_EXPIRED_EVENT, expired);
+    fire_sfx_event(sfx, SfxEvent::ConditionRemoved);
+    for expiry in expired {
+        announce_combat_event(announcer, AnnouncerEvent::ConditionRemoved {
+            name: expiry.combatant_name.clone(),
+            condition: expiry.condition_name.clone(),
+        });
+    }
+}
+
+/// Tell the UI about reminders for a combatant's conditions
+pub(crate) fn emit_condition_reminders(reminders: &[ConditionReminder], app_handle: &tauri::AppHandle) {
+    if !reminders.is_empty() {
+        let _ = app_handle.emit(CONDITION_REMINDERS_EVENT, reminders);
+    }
+}
+
+/// The concentration a condition from this source should end with
+fn source_concentration_id(state: &AppState, session_id: &str, source_id: &str) -> Result<String, String> {
+    state.session_manager
+        .get_combatant_conditions(session_id, source_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|c| c.is_concentration())
+        .map(|c| c.id)
+        .ok_or_else(|| "Source is not concentrating".to_string())
+}
+
 // ============================================================================
 // Basic Condition Commands
 // ============================================================================
@@ -69,10 +123,21 @@ pub fn add_condition(
     combatant_id: String,
     condition_name: String,
     state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
 ) -> Result<(), String> {
     state.session_manager
         .add_condition_by_name(&session_id, &combatant_id, &condition_name, None, None, None)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    fire_sfx_event(&sfx, SfxEvent::ConditionApplied);
+
+    if let Some(combatant) = find_combatant(&state, &session_id, &combatant_id) {
+        announce_combat_event(&announcer, AnnouncerEvent::ConditionApplied {
+            name: combatant.name,
+            condition: condition_name,
+        });
+    }
+    Ok(())
 }
 
 /// Remove a condition by name from a combatant
@@ -82,11 +147,22 @@ pub fn remove_condition(
     combatant_id: String,
     condition_name: String,
     state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
 ) -> Result<(), String> {
-    state.session_manager
+    let removed = state.session_manager
         .remove_advanced_condition_by_name(&session_id, &combatant_id, &condition_name)
-        .map(|_| ())
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    if !removed.is_empty() {
+        fire_sfx_event(&sfx, SfxEvent::ConditionRemoved);
+        if let Some(combatant) = find_combatant(&state, &session_id, &combatant_id) {
+            announce_combat_event(&announcer, AnnouncerEvent::ConditionRemoved {
+                name: combatant.name,
+                condition: condition_name,
+            });
+        }
+    }
+    Ok(())
 }
 
 // ============================================================================
@@ -128,6 +204,13 @@ pub fn add_condition_advanced(
         };
     }
 
+    // Link to the source's concentration if requested
+    if request.concentration == Some(true) {
+        let source_id = request.source_id.as_deref()
+            .ok_or_else(|| "A concentration condition needs a source_id".to_string())?;
+        condition = condition.sustained_by(source_concentration_id(&state, &request.session_id, source_id)?);
+    }
+
     // Set source if provided
     if let (Some(src_id), Some(src_name)) = (request.source_id, request.source_name) {
         condition.source_id = Some(src_id);
@@ -217,6 +300,53 @@ pub fn get_combatant_conditions(
         .map_err(|e| e.to_string())
 }
 
+// ============================================================================
+// Concentration Commands
+// ============================================================================
+
+/// Start a combatant concentrating on an effect. Any earlier concentration
+/// breaks, ending the effects it sustained.
+///
+/// # Arguments
+/// * `duration_type` - "rounds", "minutes", "hours", etc. (default: until removed)
+#[tauri::command]
+pub fn start_concentration(
+    session_id: String,
+    combatant_id: String,
+    effect: String,
+    duration_type: Option<String>,
+    duration_value: Option<u32>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<AdvancedCondition, String> {
+    let duration = parse_condition_duration(duration_type, duration_value, None, None)
+        .unwrap_or(ConditionDuration::UntilRemoved);
+    let (condition, expired) = state.session_manager
+        .start_concentration(&session_id, &combatant_id, &effect, duration)
+        .map_err(|e| e.to_string())?;
+    emit_condition_expiries(&expired, &app_handle, &sfx, &announcer);
+    Ok(condition)
+}
+
+/// Break a combatant's concentration, ending every effect it sustains
+#[tauri::command]
+pub fn break_concentration(
+    session_id: String,
+    combatant_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<Vec<ConditionExpiry>, String> {
+    let expired = state.session_manager
+        .break_concentration(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())?;
+    emit_condition_expiries(&expired, &app_handle, &sfx, &announcer);
+    Ok(expired)
+}
+
 // ============================================================================
 // Condition Tick Commands
 // ============================================================================
@@ -0,0 +1,123 @@
+//! Death and Dying Commands
+//!
+//! Commands for death saving throws, recovery checks, stabilization, and
+//! the per-system rules for what happens at 0 HP.
+
+use serde::Serialize;
+use tauri::{Emitter, State};
+
+use crate::commands::{fire_sfx_event, AppState, SfxTriggerState};
+use crate::core::audio::SfxEvent;
+use crate::core::session_manager::{DeathRules, DeathUpdate};
+use crate::core::voice::AnnouncerEvent;
+
+use super::announcer::{announce_combat_event, find_combatant, CombatAnnouncerState};
+
+/// Event emitted when a combatant's death track changes
+pub const DEATH_UPDATE_EVENT: &str = "combat:death_update";
+
+/// A death track change for the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct DeathUpdateEvent {
+    pub combatant_id: String,
+    #[serde(flatten)]
+    pub update: DeathUpdate,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Death rules for the game system of a session's campaign
+pub(crate) fn session_death_rules(state: &AppState, session_id: &str) -> DeathRules {
+    state
+        .session_manager
+        .get_session(session_id)
+        .and_then(|session| state.campaign_manager.get_campaign(&session.campaign_id))
+        .map(|campaign| DeathRules::for_system(&campaign.system))
+        .unwrap_or_default()
+}
+
+/// Tell the UI about a change on a combatant's death track
+pub(crate) fn emit_death_update(combatant_id: &str, update: Option<DeathUpdate>, app_handle: &tauri::AppHandle) {
+    if let Some(update) = update {
+        let _ = app_handle.emit(DEATH_UPDATE_EVENT, DeathUpdateEvent {
+            combatant_id: combatant_id.to_string(),
+            update,
+        });
+    }
+}
+
+// ============================================================================
+// Death Commands
+// ============================================================================
+
+/// Make a death saving throw (D&D 5e) or recovery check (PF2e) for a dying
+/// combatant
+///
+/// # Arguments
+/// * `roll` - The natural d20 result (default: rolled for you)
+#[tauri::command]
+pub fn roll_death_save(
+    session_id: String,
+    combatant_id: String,
+    roll: Option<i32>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<DeathUpdate, String> {
+    let update = state.session_manager.roll_death_save(&session_id, &combatant_id, roll)
+        .map_err(|e| e.to_string())?;
+    if update.died() {
+        fire_sfx_event(&sfx, SfxEvent::Death);
+        if let Some(combatant) = find_combatant(&state, &session_id, &combatant_id) {
+            announce_combat_event(&announcer, AnnouncerEvent::Death { name: combatant.name });
+        }
+    }
+    emit_death_update(&combatant_id, Some(update.clone()), &app_handle);
+    Ok(update)
+}
+
+/// Stabilize a dying combatant, e.g. after a Medicine check or Spare the Dying
+#[tauri::command]
+pub fn stabilize_combatant(
+    session_id: String,
+    combatant_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<DeathUpdate, String> {
+    let update = state.session_manager.stabilize_combatant(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())?;
+    emit_death_update(&combatant_id, Some(update.clone()), &app_handle);
+    Ok(update)
+}
+
+/// Get the death rules the combat uses
+#[tauri::command]
+pub fn get_death_rules(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<DeathRules, String> {
+    state
+        .session_manager
+        .get_combat(&session_id)
+        .map(|c| c.death_rules)
+        .ok_or_else(|| "No active combat".to_string())
+}
+
+/// Change what happens to combatants at 0 HP
+///
+/// # Arguments
+/// * `rules` - `{"type": "death_saves"}`, `{"type": "dying", "max_dying": 4}`,
+///   or `{"type": "manual"}`
+#[tauri::command]
+pub fn set_death_rules(
+    session_id: String,
+    rules: DeathRules,
+    state: State<'_, AppState>,
+) -> Result<DeathRules, String> {
+    state.session_manager.set_death_rules(&session_id, rules)
+        .map_err(|e| e.to_string())?;
+    Ok(rules)
+}
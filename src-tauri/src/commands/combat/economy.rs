@@ -0,0 +1,123 @@
+//! Action Economy Commands
+//!
+//! Commands for the Pathfinder 2e three-action economy: spending actions,
+//! the multiple attack penalty, and hero points.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::session_manager::{Activity, CombatMode, DeathUpdate, TurnActions, TurnSpend};
+
+use super::death::emit_death_update;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Action economy for the game system of a session's campaign
+pub(crate) fn session_combat_mode(state: &AppState, session_id: &str) -> CombatMode {
+    state
+        .session_manager
+        .get_session(session_id)
+        .and_then(|session| state.campaign_manager.get_campaign(&session.campaign_id))
+        .map(|campaign| CombatMode::for_system(&campaign.system))
+        .unwrap_or_default()
+}
+
+// ============================================================================
+// Mode Commands
+// ============================================================================
+
+/// Get the action economy the combat tracks
+#[tauri::command]
+pub fn get_combat_mode(session_id: String, state: State<'_, AppState>) -> Result<CombatMode, String> {
+    state
+        .session_manager
+        .get_combat(&session_id)
+        .map(|c| c.mode)
+        .ok_or_else(|| "No active combat".to_string())
+}
+
+/// Switch between the standard turn and the three-action economy
+#[tauri::command]
+pub fn set_combat_mode(session_id: String, mode: CombatMode, state: State<'_, AppState>) -> Result<CombatMode, String> {
+    state.session_manager.set_combat_mode(&session_id, mode)
+        .map_err(|e| e.to_string())?;
+    Ok(mode)
+}
+
+// ============================================================================
+// Action Commands
+// ============================================================================
+
+/// Spend a combatant's actions this turn. Attacks report the multiple
+/// attack penalty they take and raise it for the next one.
+///
+/// # Arguments
+/// * `activity` - `{"cost": 2}`, or `{"attack": true, "agile": true, "name": "Dagger Strike"}`
+#[tauri::command]
+pub fn spend_actions(
+    session_id: String,
+    combatant_id: String,
+    activity: Activity,
+    state: State<'_, AppState>,
+) -> Result<TurnSpend, String> {
+    state.session_manager.spend_actions(&session_id, &combatant_id, &activity)
+        .map_err(|e| e.to_string())
+}
+
+/// Gain or lose actions this turn, as from quickened, slowed, or stunned
+///
+/// # Arguments
+/// * `delta` - Actions gained (positive) or lost (negative)
+#[tauri::command]
+pub fn adjust_turn_actions(
+    session_id: String,
+    combatant_id: String,
+    delta: i32,
+    state: State<'_, AppState>,
+) -> Result<TurnActions, String> {
+    state.session_manager.adjust_turn_actions(&session_id, &combatant_id, delta)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Hero Point Commands
+// ============================================================================
+
+/// Give a player hero points, returning their new total
+///
+/// # Arguments
+/// * `amount` - Hero points to give (default: 1)
+#[tauri::command]
+pub fn award_hero_points(
+    session_id: String,
+    combatant_id: String,
+    amount: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<u32, String> {
+    state.session_manager.award_hero_points(&session_id, &combatant_id, amount.unwrap_or(1))
+        .map_err(|e| e.to_string())
+}
+
+/// Spend a hero point to reroll a check, returning what is left
+#[tauri::command]
+pub fn spend_hero_point(session_id: String, combatant_id: String, state: State<'_, AppState>) -> Result<u32, String> {
+    state.session_manager.spend_hero_point(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Spend all of a dying combatant's hero points to stabilize at 0 HP
+/// without gaining wounded
+#[tauri::command]
+pub fn heroic_recovery(
+    session_id: String,
+    combatant_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<DeathUpdate, String> {
+    let update = state.session_manager.heroic_recovery(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())?;
+    emit_death_update(&combatant_id, Some(update.clone()), &app_handle);
+    Ok(update)
+}
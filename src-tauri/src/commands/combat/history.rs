@@ -0,0 +1,59 @@
+//! Combat History Commands
+//!
+//! Commands for undoing and redoing combat actions and exporting the combat
+//! log to the session timeline.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::session_manager::{CombatEvent, HistoryStatus, HistoryStep};
+
+use super::positioning::emit_positions;
+
+/// Get the full combat log, including undone actions and the undos
+#[tauri::command]
+pub fn get_combat_log(session_id: String, state: State<'_, AppState>) -> Result<Vec<CombatEvent>, String> {
+    Ok(state.session_manager.get_combat_log(&session_id))
+}
+
+/// Undo the latest damage, heal, condition, move, or turn change. The
+/// combat log keeps the undone entry and records the undo.
+#[tauri::command]
+pub fn undo_combat_action(
+    session_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<HistoryStep, String> {
+    let step = state.session_manager.undo_combat_action(&session_id)
+        .map_err(|e| e.to_string())?;
+    emit_positions(&state, &session_id, &app_handle);
+    Ok(step)
+}
+
+/// Redo the latest undone combat action
+#[tauri::command]
+pub fn redo_combat_action(
+    session_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<HistoryStep, String> {
+    let step = state.session_manager.redo_combat_action(&session_id)
+        .map_err(|e| e.to_string())?;
+    emit_positions(&state, &session_id, &app_handle);
+    Ok(step)
+}
+
+/// Get what undo and redo would do next
+#[tauri::command]
+pub fn get_combat_history(session_id: String, state: State<'_, AppState>) -> Result<HistoryStatus, String> {
+    Ok(state.session_manager.combat_history_status(&session_id))
+}
+
+/// Export the combat log to the session timeline. Entries already exported
+/// are skipped, so this can be called again as the fight goes on. Returns
+/// the number of entries exported.
+#[tauri::command]
+pub fn export_combat_log(session_id: String, state: State<'_, AppState>) -> Result<usize, String> {
+    state.session_manager.export_combat_log(&session_id)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,240 @@
+//! Initiative Commands
+//!
+//! Commands for rolling initiative with the campaign's game-system rules,
+//! re-rolling the whole order, changing tie-break rules, and surprise, plus
+//! delaying, readied actions, and moving combatants in the order by hand.
+
+use tauri::{Emitter, State};
+
+use crate::commands::{AppState, PartyState, SfxTriggerState};
+use crate::core::campaign::dice::DiceNotation;
+use crate::core::session::initiative::{ability_modifier, InitiativeRoll, InitiativeRules, TieBreaker};
+use crate::core::session_manager::{CombatState, Combatant, ReadiedAction, TurnResult};
+
+use super::announcer::CombatAnnouncerState;
+use super::combatants::resolve_turn;
+
+/// Event emitted with readied actions set off by the turn that just began
+pub const READIED_EVENT: &str = "combat:readied";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Initiative rules for the game system of a session's campaign
+pub(crate) fn session_initiative_rules(state: &AppState, session_id: &str) -> InitiativeRules {
+    state
+        .session_manager
+        .get_session(session_id)
+        .and_then(|session| state.campaign_manager.get_campaign(&session.campaign_id))
+        .map(|campaign| InitiativeRules::for_system(&campaign.system))
+        .unwrap_or_default()
+}
+
+/// Tell the UI which readied actions the new combatant's turn sets off
+pub(crate) fn emit_readied(result: &TurnResult, app_handle: &tauri::AppHandle) {
+    if !result.readied.is_empty() {
+        let _ = app_handle.emit(READIED_EVENT, &result.readied);
+    }
+}
+
+/// Initiative modifier from, in order: an explicit value, a stat block's
+/// dexterity score, a party roster character, or an NPC's stat block or
+/// generated stats
+pub(crate) fn resolve_initiative_modifier(
+    initiative_modifier: Option<i32>,
+    dexterity: Option<i32>,
+    character_id: Option<&str>,
+    npc_id: Option<&str>,
+    state: &AppState,
+    party: &PartyState,
+) -> i32 {
+    initiative_modifier
+        .or_else(|| dexterity.map(ability_modifier))
+        .or_else(|| {
+            character_id
+                .and_then(|id| party.manager.get_character(id))
+                .map(|c| c.initiative_modifier)
+        })
+        .or_else(|| {
+            let npc = state.npc_store.get(npc_id?)?;
+            if let Some(dex) = npc.stat_block.as_ref().and_then(|sb| sb.ability_scores.dexterity) {
+                return Some(ability_modifier(dex));
+            }
+            npc.stats?
+                .attributes
+                .iter()
+                .find(|(name, _)| matches!(name.to_lowercase().as_str(), "dexterity" | "dex"))
+                .map(|(_, value)| value.modifier)
+        })
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// Initiative Commands
+// ============================================================================
+
+/// Roll initiative for a combatant using their initiative modifier and the
+/// combat's rules
+#[tauri::command]
+pub fn roll_initiative(
+    session_id: String,
+    combatant_id: String,
+    state: State<'_, AppState>,
+) -> Result<InitiativeRoll, String> {
+    state.session_manager.roll_initiative(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Re-roll initiative for every combatant. The turn order restarts from the
+/// top, so call this between rounds.
+#[tauri::command]
+pub fn reroll_all_initiative(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<CombatState, String> {
+    state.session_manager.reroll_initiative(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Get the initiative rules the combat uses
+#[tauri::command]
+pub fn get_initiative_rules(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<InitiativeRules, String> {
+    state
+        .session_manager
+        .get_combat(&session_id)
+        .map(|c| c.initiative_rules)
+        .ok_or_else(|| "No active combat".to_string())
+}
+
+/// Change how the combat rolls initiative and breaks ties
+///
+/// # Arguments
+/// * `dice` - Dice notation added to the modifier (e.g. "1d20"); omit to use
+///   the modifier alone
+/// * `tie_breakers` - Applied in order: "modifier", "non_players_first",
+///   "players_first", "roll_off"
+#[tauri::command]
+pub fn set_initiative_rules(
+    session_id: String,
+    dice: Option<String>,
+    tie_breakers: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<InitiativeRules, String> {
+    let dice = dice
+        .map(|d| DiceNotation::parse(&d))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let tie_breakers = tie_breakers
+        .iter()
+        .map(|t| TieBreaker::parse(t).ok_or_else(|| format!("Unknown tie-breaker: {}", t)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rules = InitiativeRules { dice, tie_breakers };
+    state.session_manager.set_initiative_rules(&session_id, rules.clone())
+        .map_err(|e| e.to_string())?;
+    Ok(rules)
+}
+
+/// Set which combatants are surprised. Surprised combatants lose their turn
+/// in the first round.
+#[tauri::command]
+pub fn set_surprised_combatants(
+    session_id: String,
+    combatant_ids: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<CombatState, String> {
+    state.session_manager.set_surprised(&session_id, &combatant_ids)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Turn Order Commands
+// ============================================================================
+
+/// Move a combatant to a new place in the initiative order (0 is first).
+/// They take the initiative of the combatant they now go before, the turn
+/// stays where it is, and the move is written to the combat log.
+#[tauri::command]
+pub fn move_in_initiative(
+    session_id: String,
+    combatant_id: String,
+    position: usize,
+    state: State<'_, AppState>,
+) -> Result<CombatState, String> {
+    state.session_manager.move_in_initiative(&session_id, &combatant_id, position)
+        .map_err(|e| e.to_string())?;
+    state.session_manager.get_combat(&session_id)
+        .ok_or_else(|| "No active combat".to_string())
+}
+
+/// The current combatant delays, and the turn passes on as with
+/// `next_turn`. Bring them back with `resume_delayed_turn`; if they don't
+/// come back, they act at their old place next round.
+#[tauri::command]
+pub fn delay_turn(
+    session_id: String,
+    combatant_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<Option<Combatant>, String> {
+    let result = state.session_manager.delay_turn(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())?;
+    resolve_turn(&session_id, &result, &app_handle, &state, &sfx, &announcer);
+    Ok(result.current_combatant)
+}
+
+/// A delaying combatant rejoins the order right after the current combatant,
+/// whose turn ends, and takes their turn at that initiative.
+#[tauri::command]
+pub fn resume_delayed_turn(
+    session_id: String,
+    combatant_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+) -> Result<Option<Combatant>, String> {
+    let result = state.session_manager.resume_delayed_turn(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())?;
+    resolve_turn(&session_id, &result, &app_handle, &state, &sfx, &announcer);
+    Ok(result.current_combatant)
+}
+
+/// Ready an action for a stated trigger. It lasts until the start of the
+/// combatant's next turn.
+///
+/// # Arguments
+/// * `action` - What they'll do, such as "attack with longsword"
+/// * `trigger` - What sets it off, such as "the ogre comes through the door"
+/// * `trigger_combatant_id` - A combatant whose turn brings the trigger up;
+///   the readied action is emitted as `combat:readied` when that turn starts
+#[tauri::command]
+pub fn ready_action(
+    session_id: String,
+    combatant_id: String,
+    action: String,
+    trigger: String,
+    trigger_combatant_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ReadiedAction, String> {
+    state.session_manager
+        .ready_action(&session_id, &combatant_id, &action, &trigger, trigger_combatant_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Take a combatant's readied action, spending their reaction
+#[tauri::command]
+pub fn take_readied_action(
+    session_id: String,
+    combatant_id: String,
+    state: State<'_, AppState>,
+) -> Result<ReadiedAction, String> {
+    state.session_manager.take_readied_action(&session_id, &combatant_id)
+        .map_err(|e| e.to_string())
+}
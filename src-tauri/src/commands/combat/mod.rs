@@ -1,12 +1,33 @@
 //! Combat Commands Module
 //!
-//! Commands for managing combat encounters, combatants, and conditions.
+//! Commands for managing combat encounters, combatants and minion groups,
+//! initiative with delaying, readied actions, and reordering, conditions,
+//! death and dying, enemy morale, legendary, lair, reaction, and recharge
+//! tracking, the PF2e three-action economy and hero points, zone and grid
+//! positioning, and undo and redo with the combat log, plus the spoken
+//! combat announcer.
 
 pub mod state;
 pub mod combatants;
+pub mod initiative;
 pub mod conditions;
+pub mod death;
+pub mod morale;
+pub mod actions;
+pub mod economy;
+pub mod positioning;
+pub mod history;
+pub mod announcer;
 
 // Re-export all commands and types
 pub use state::*;
 pub use combatants::*;
+pub use initiative::*;
 pub use conditions::*;
+pub use death::*;
+pub use morale::*;
+pub use actions::*;
+pub use economy::*;
+pub use positioning::*;
+pub use history::*;
+pub use announcer::*;
@@ -0,0 +1,110 @@
+//! Morale Commands
+//!
+//! Commands for optional morale tracking on enemy groups. Damage that fells
+//! a group's leader or half its members rolls morale under the campaign's
+//! rules, and the suggestion for the GM is emitted as `combat:morale`.
+
+use tauri::{Emitter, State};
+
+use crate::commands::AppState;
+use crate::core::session_manager::{MoraleCheck, MoraleGroup, MoraleRules, MoraleTrigger};
+
+/// Event emitted with each morale check
+pub const MORALE_EVENT: &str = "combat:morale";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Morale rules for the game system of a session's campaign
+pub(crate) fn session_morale_rules(state: &AppState, session_id: &str) -> MoraleRules {
+    state
+        .session_manager
+        .get_session(session_id)
+        .and_then(|session| state.campaign_manager.get_campaign(&session.campaign_id))
+        .map(|campaign| MoraleRules::for_system(&campaign.system))
+        .unwrap_or_default()
+}
+
+/// Roll morale for groups shaken by what just happened and tell the UI
+pub(crate) fn emit_morale_checks(session_id: &str, app_handle: &tauri::AppHandle, state: &AppState) -> Result<(), String> {
+    let checks = state.session_manager.check_morale(session_id)
+        .map_err(|e| e.to_string())?;
+    for check in checks {
+        let _ = app_handle.emit(MORALE_EVENT, check);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Morale Commands
+// ============================================================================
+
+/// Track morale for a group of enemies. Morale is rolled when the leader
+/// falls or half the group is down, each once.
+///
+/// # Arguments
+/// * `name` - Plural name for suggestions, such as "goblins"
+/// * `morale` - Morale score (2-12) under 2d6 rules, or the save bonus
+///   under save rules (default: an average creature's)
+/// * `triggers` - Events that call for a check (default: both)
+#[tauri::command]
+pub fn add_morale_group(
+    session_id: String,
+    name: String,
+    member_ids: Vec<String>,
+    leader_id: Option<String>,
+    morale: Option<i32>,
+    triggers: Option<Vec<MoraleTrigger>>,
+    state: State<'_, AppState>,
+) -> Result<MoraleGroup, String> {
+    let rules = state.session_manager.get_combat(&session_id)
+        .map(|combat| combat.morale_rules)
+        .ok_or_else(|| "No active combat".to_string())?;
+    let mut group = MoraleGroup::new(name, member_ids, morale.unwrap_or_else(|| rules.default_morale()))
+        .map_err(|e| e.to_string())?;
+    if let Some(leader_id) = leader_id {
+        group = group.with_leader(leader_id);
+    }
+    if let Some(triggers) = triggers {
+        group = group.with_triggers(triggers);
+    }
+    state.session_manager.add_morale_group(&session_id, group)
+        .map_err(|e| e.to_string())
+}
+
+/// Stop tracking a group's morale
+#[tauri::command]
+pub fn remove_morale_group(
+    session_id: String,
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<MoraleGroup, String> {
+    state.session_manager.remove_morale_group(&session_id, &group_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Replace the combat's morale rules, which start as the campaign system's
+#[tauri::command]
+pub fn set_morale_rules(
+    session_id: String,
+    rules: MoraleRules,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.session_manager.set_morale_rules(&session_id, rules)
+        .map_err(|e| e.to_string())
+}
+
+/// Roll morale for a group now, whatever has happened to it
+#[tauri::command]
+pub fn call_morale_check(
+    session_id: String,
+    group_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<MoraleCheck, String> {
+    let check = state.session_manager.call_morale_check(&session_id, &group_id)
+        .map_err(|e| e.to_string())?;
+    let _ = app_handle.emit(MORALE_EVENT, check.clone());
+    Ok(check)
+}
@@ -0,0 +1,95 @@
+//! Positioning Commands
+//!
+//! Commands for placing combatants in zones or on a grid, moving them, and
+//! checking attack range. Every change emits the token layout so a map view
+//! and the player window can redraw.
+
+use tauri::{Emitter, State};
+
+use crate::commands::AppState;
+use crate::core::session_manager::{AttackRange, Battlefield, Movement, Position, RangeCheck, TokenLayout};
+
+/// Event emitted with the [`TokenLayout`] whenever positions change
+pub const COMBAT_POSITIONS_EVENT: &str = "combat:positions";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Tell map views the current token layout, if positions are tracked
+pub(crate) fn emit_positions(state: &AppState, session_id: &str, app_handle: &tauri::AppHandle) {
+    if let Ok(Some(layout)) = state.session_manager.token_layout(session_id) {
+        let _ = app_handle.emit(COMBAT_POSITIONS_EVENT, layout);
+    }
+}
+
+// ============================================================================
+// Battlefield Commands
+// ============================================================================
+
+/// Track positions in zones or on a grid, or stop tracking them
+///
+/// # Arguments
+/// * `battlefield` - `{"type": "grid", "width": 20, "height": 15}`,
+///   `{"type": "zones", "zones": [{"id": "hall", "name": "Great Hall", "adjacent": ["stairs"]}]}`,
+///   or null to stop
+#[tauri::command]
+pub fn set_battlefield(
+    session_id: String,
+    battlefield: Option<Battlefield>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<TokenLayout>, String> {
+    state.session_manager.set_battlefield(&session_id, battlefield)
+        .map_err(|e| e.to_string())?;
+    emit_positions(&state, &session_id, &app_handle);
+    state.session_manager.token_layout(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Get the battlefield and every token on it, or null when positions aren't
+/// tracked. Hit points are left out so the layout can be shown to players.
+#[tauri::command]
+pub fn get_token_layout(session_id: String, state: State<'_, AppState>) -> Result<Option<TokenLayout>, String> {
+    state.session_manager.token_layout(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Movement Commands
+// ============================================================================
+
+/// Move a combatant to a zone or grid square
+///
+/// # Arguments
+/// * `position` - `{"type": "square", "x": 3, "y": 4}` or `{"type": "zone", "zone": "hall"}`
+#[tauri::command]
+pub fn move_combatant(
+    session_id: String,
+    combatant_id: String,
+    position: Position,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Movement, String> {
+    let movement = state.session_manager.move_combatant(&session_id, &combatant_id, position)
+        .map_err(|e| e.to_string())?;
+    emit_positions(&state, &session_id, &app_handle);
+    Ok(movement)
+}
+
+/// Check whether an attack from one combatant reaches another
+///
+/// # Arguments
+/// * `range` - `{"normal_feet": 5}` for melee reach, or
+///   `{"normal_feet": 80, "long_feet": 320}` for a ranged attack
+#[tauri::command]
+pub fn check_attack_range(
+    session_id: String,
+    attacker_id: String,
+    target_id: String,
+    range: AttackRange,
+    state: State<'_, AppState>,
+) -> Result<RangeCheck, String> {
+    state.session_manager.check_attack_range(&session_id, &attacker_id, &target_id, range)
+        .map_err(|e| e.to_string())
+}
@@ -1,23 +1,175 @@
 //! Combat State Commands
 //!
-//! Commands for managing combat lifecycle: start, end, and query state.
+//! Commands for managing combat lifecycle: start, end, and query state,
+//! recovery of a fight lost to a crash, plus the report each fight leaves
+//! behind.
 
 use tauri::State;
-use crate::commands::AppState;
-use crate::core::session_manager::CombatState;
+use crate::commands::{fire_sfx_event, AppState, EncounterState, PartyState, SfxTriggerState};
+use crate::core::audio::SfxEvent;
+use crate::core::campaign::encounter_builder::{evaluate_encounter, EncounterCreature, EncounterRules, SavedEncounter};
+use crate::core::campaign::party::PlayerCharacter;
+use crate::core::session_manager::{
+    CombatReport, Combatant, CombatantType, CombatState, EncounterDifficulty, InitiativeRules,
+};
+use crate::core::voice::AnnouncerEvent;
+
+use super::announcer::{announce_combat_event, CombatAnnouncerState};
+use super::death::session_death_rules;
+use super::economy::session_combat_mode;
+use super::initiative::session_initiative_rules;
+use super::morale::session_morale_rules;
+
+/// A party member as a combatant, with initiative rolled
+fn party_combatant(character: &PlayerCharacter, rules: &InitiativeRules) -> Combatant {
+    let mut combatant = Combatant::new(character.name.clone(), 0, CombatantType::Player);
+    rules
+        .roll(character.initiative_modifier, &mut rand::thread_rng())
+        .apply(&mut combatant);
+    combatant.current_hp = Some(character.max_hp);
+    combatant.max_hp = Some(character.max_hp);
+    combatant.armor_class = Some(character.armor_class);
+    combatant
+}
+
+/// Combatants for a saved encounter's creatures, numbered when there are
+/// several of one kind, each with initiative rolled
+fn encounter_combatants(creature: &EncounterCreature, rules: &InitiativeRules) -> Vec<Combatant> {
+    SavedEncounter::combatant_names(creature)
+        .into_iter()
+        .map(|name| {
+            let mut combatant = Combatant::new(name, 0, CombatantType::Monster);
+            rules
+                .roll(creature.initiative_modifier, &mut rand::thread_rng())
+                .apply(&mut combatant);
+            combatant.current_hp = creature.max_hp;
+            combatant.max_hp = creature.max_hp;
+            combatant.armor_class = creature.armor_class;
+            combatant
+        })
+        .collect()
+}
+
+/// Rate creatures against the campaign's active party, or `None` when none
+/// of them has a challenge rating or level
+fn estimate_difficulty(
+    state: &AppState,
+    party: &PartyState,
+    session_id: &str,
+    creatures: &[EncounterCreature],
+) -> Option<EncounterDifficulty> {
+    let session = state.session_manager.get_session(session_id)?;
+    let campaign = state.campaign_manager.get_campaign(&session.campaign_id)?;
+    let levels: Vec<u8> = party
+        .manager
+        .list_characters(&session.campaign_id, false)
+        .iter()
+        .map(|c| c.level)
+        .collect();
+    let evaluation = evaluate_encounter(
+        EncounterRules::for_system(&campaign.system),
+        creatures,
+        &levels,
+        &EncounterDifficulty::default(),
+    )
+    .ok()?;
+    (evaluation.base_xp > 0).then_some(evaluation.rating)
+}
+
+/// Creatures in a fight that came from stat blocks with a challenge rating
+fn combat_creatures(combat: &CombatState) -> Vec<EncounterCreature> {
+    combat
+        .combatants
+        .iter()
+        .filter(|c| c.combatant_type != CombatantType::Player)
+        .filter_map(|c| {
+            let cr = c.stats.as_ref()?.challenge_rating?;
+            Some(EncounterCreature::new(c.name.clone(), 1).with_cr(cr))
+        })
+        .collect()
+}
 
 /// Initialize combat for a session
+///
+/// Initiative is rolled, ties broken, dying handled, actions tracked, and
+/// morale rolled by the rules of the campaign's game system. The campaign's
+/// active player characters join automatically with rolled initiative,
+/// unless `include_party` is false.
+/// Pass `encounter_id` to add a saved encounter's creatures as well.
 #[tauri::command]
-pub fn start_combat(session_id: String, state: State<'_, AppState>) -> Result<CombatState, String> {
-    state.session_manager.start_combat(&session_id)
-        .map_err(|e| e.to_string())
+pub fn start_combat(
+    session_id: String,
+    include_party: Option<bool>,
+    encounter_id: Option<String>,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+    party: State<'_, PartyState>,
+    encounters: State<'_, EncounterState>,
+) -> Result<CombatState, String> {
+    let encounter = encounter_id
+        .map(|id| encounters.manager.get(&id))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let mut combat = state.session_manager.start_combat(&session_id)
+        .map_err(|e| e.to_string())?;
+    let rules = session_initiative_rules(&state, &session_id);
+    state.session_manager.set_initiative_rules(&session_id, rules.clone())
+        .map_err(|e| e.to_string())?;
+    state.session_manager.set_death_rules(&session_id, session_death_rules(&state, &session_id))
+        .map_err(|e| e.to_string())?;
+    state.session_manager.set_combat_mode(&session_id, session_combat_mode(&state, &session_id))
+        .map_err(|e| e.to_string())?;
+    state.session_manager.set_morale_rules(&session_id, session_morale_rules(&state, &session_id))
+        .map_err(|e| e.to_string())?;
+    if include_party.unwrap_or(true) {
+        if let Some(session) = state.session_manager.get_session(&session_id) {
+            for character in party.manager.list_characters(&session.campaign_id, false) {
+                state.session_manager.add_combatant(&session_id, party_combatant(&character, &rules))
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+    for creature in encounter.iter().flat_map(|e| &e.creatures) {
+        for combatant in encounter_combatants(creature, &rules) {
+            state.session_manager.add_combatant(&session_id, combatant)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    if let Some(encounter) = &encounter {
+        let estimate = estimate_difficulty(&state, &party, &session_id, &encounter.creatures);
+        state.session_manager.set_estimated_difficulty(&session_id, estimate)
+            .map_err(|e| e.to_string())?;
+    }
+    combat = state.session_manager.get_combat(&session_id).unwrap_or(combat);
+    fire_sfx_event(&sfx, SfxEvent::CombatStart);
+    announce_combat_event(&announcer, AnnouncerEvent::CombatStart);
+    Ok(combat)
 }
 
-/// End combat for a session
+/// End combat for a session. A report on the fight is stored with the
+/// session for `get_combat_report`, rating the creatures against the party
+/// when the fight didn't start from a saved encounter.
 #[tauri::command]
-pub fn end_combat(session_id: String, state: State<'_, AppState>) -> Result<(), String> {
+pub fn end_combat(
+    session_id: String,
+    state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
+    announcer: State<'_, CombatAnnouncerState>,
+    party: State<'_, PartyState>,
+) -> Result<(), String> {
+    let combat = state.session_manager.get_combat(&session_id);
+    let rounds = combat.as_ref().map(|c| c.round);
+    if let Some(combat) = combat.filter(|c| c.estimated_difficulty.is_none()) {
+        let estimate = estimate_difficulty(&state, &party, &session_id, &combat_creatures(&combat));
+        state.session_manager.set_estimated_difficulty(&session_id, estimate)
+            .map_err(|e| e.to_string())?;
+    }
     state.session_manager.end_combat(&session_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    fire_sfx_event(&sfx, SfxEvent::CombatEnd);
+    announce_combat_event(&announcer, AnnouncerEvent::CombatEnd { rounds: rounds.unwrap_or(1) });
+    Ok(())
 }
 
 /// Get current combat state for a session
@@ -25,3 +177,28 @@ pub fn end_combat(session_id: String, state: State<'_, AppState>) -> Result<(),
 pub fn get_combat(session_id: String, state: State<'_, AppState>) -> Result<Option<CombatState>, String> {
     Ok(state.session_manager.get_combat(&session_id))
 }
+
+/// Restore a session's fight from the combat journal after a crash, such
+/// as when the session is reopened. A fight already running is returned as
+/// it is; `None` means there was nothing to recover.
+#[tauri::command]
+pub fn recover_combat(session_id: String, state: State<'_, AppState>) -> Result<Option<CombatState>, String> {
+    state.session_manager.recover_combat(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Get the report on a finished fight: rounds, damage, healing, and kills
+/// per combatant, the conditions used most, and the estimated difficulty
+/// against how hard it played
+///
+/// # Arguments
+/// * `combat_id` - The fight to report on (default: the session's latest)
+#[tauri::command]
+pub fn get_combat_report(
+    session_id: String,
+    combat_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Option<CombatReport>, String> {
+    state.session_manager.get_combat_report(&session_id, combat_id.as_deref())
+        .map_err(|e| e.to_string())
+}
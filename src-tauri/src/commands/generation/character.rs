@@ -1,8 +1,67 @@
 //! Character Generation Commands
 //!
-//! Commands for procedural character generation across different TTRPG systems.
+//! Commands for procedural character generation across different TTRPG systems,
+//! for building D&D 5e characters step by step against the rules, for
+//! leveling up built characters kept in the database, and for exporting
+//! character sheets and importing characters from D&D Beyond and Pathbuilder.
 
+use tauri::State;
+
+use crate::commands::{AppState, PartyState};
 use crate::core::character_gen::{CharacterGenerator, GenerationOptions, Character, SystemInfo};
+use crate::core::character_gen::builder::{
+    self, BuildChoices, BuiltCharacter, CharacterBuild, CharacterBuilder, RulesCatalog,
+};
+use crate::core::character_gen::export::{self, SheetExtras, SheetFormat};
+use crate::core::character_gen::import::{self, ImportSource, ImportedCharacter};
+use crate::core::character_gen::level_up::{LevelGain, LevelUpAssistant, LevelUpChoices, LevelUpOptions};
+use crate::core::campaign::party::PlayerCharacter;
+use crate::database::{CharacterOps, CharacterRecord, TtrpgOps};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The SRD rules plus every spell and feat in the ingested rulebooks
+async fn rules_catalog(state: &AppState) -> Result<RulesCatalog, String> {
+    let spells = state.database.list_ttrpg_documents_by_type("spell").await
+        .map_err(|e| e.to_string())?;
+    let feats = state.database.list_ttrpg_documents_by_type("feat").await
+        .map_err(|e| e.to_string())?;
+    Ok(RulesCatalog::srd().with_ingested_spells(&spells).with_ingested_feats(&feats))
+}
+
+/// A built character stored in the database, with its record
+async fn load_built_character(state: &AppState, character_id: &str) -> Result<(CharacterRecord, BuiltCharacter), String> {
+    let record = state.database.get_character(character_id).await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Character not found: {}", character_id))?;
+    let built = serde_json::from_str(&record.data_json)
+        .map_err(|_| format!("{} wasn't made with the character builder", record.name))?;
+    Ok((record, built))
+}
+
+/// Store a built character, keeping the record's campaign and creation time
+async fn store_built_character(
+    state: &AppState,
+    built: &BuiltCharacter,
+    existing: Option<CharacterRecord>,
+    campaign_id: Option<String>,
+) -> Result<(), String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let record = CharacterRecord {
+        id: built.character.id.clone(),
+        campaign_id: campaign_id.or_else(|| existing.as_ref().and_then(|r| r.campaign_id.clone())),
+        name: built.character.name.clone(),
+        system: "dnd5e".to_string(),
+        character_type: existing.as_ref().map_or_else(|| "player".to_string(), |r| r.character_type.clone()),
+        level: Some(built.character.level as i32),
+        data_json: serde_json::to_string(built).map_err(|e| e.to_string())?,
+        created_at: existing.map_or_else(|| now.clone(), |r| r.created_at),
+        updated_at: now,
+    };
+    state.database.save_character(&record).await.map_err(|e| e.to_string())
+}
 
 // ============================================================================
 // Character Generation Commands
@@ -48,3 +107,180 @@ pub fn get_system_info(system: String) -> Option<SystemInfo> {
 pub fn generate_character_advanced(options: GenerationOptions) -> Result<Character, String> {
     CharacterGenerator::generate(&options).map_err(|e| e.to_string())
 }
+
+// ============================================================================
+// Character Builder Commands
+// ============================================================================
+
+/// Options open to a D&D 5e build, the step it's on, and anything wrong
+/// with its choices so far
+#[tauri::command]
+pub async fn get_character_builder_choices(
+    build: CharacterBuild,
+    state: State<'_, AppState>,
+) -> Result<BuildChoices, String> {
+    let catalog = rules_catalog(&state).await?;
+    Ok(CharacterBuilder::new(&catalog).choices(&build))
+}
+
+/// Turn a finished build into a character. Fails while any choice is
+/// missing or breaks the rules.
+#[tauri::command]
+pub async fn finish_character_build(
+    build: CharacterBuild,
+    state: State<'_, AppState>,
+) -> Result<BuiltCharacter, String> {
+    let catalog = rules_catalog(&state).await?;
+    CharacterBuilder::new(&catalog).finish(&build).map_err(|e| e.to_string())
+}
+
+/// Roll six ability scores, each the best three of 4d6
+#[tauri::command]
+pub fn roll_ability_scores() -> Vec<i32> {
+    builder::roll_ability_scores(&mut rand::thread_rng())
+}
+
+/// Roll a class's starting gold, taken instead of its equipment
+#[tauri::command]
+pub fn roll_starting_gold(class: String) -> Result<u32, String> {
+    let catalog = RulesCatalog::srd();
+    let class = catalog.class(&class).ok_or_else(|| format!("Unknown class: {}", class))?;
+    Ok(builder::roll_starting_gold(class, &mut rand::thread_rng()))
+}
+
+// ============================================================================
+// Level-Up Commands
+// ============================================================================
+
+/// Store a built character so it can be leveled up over the campaign
+#[tauri::command]
+pub async fn save_built_character(
+    character: BuiltCharacter,
+    campaign_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<BuiltCharacter, String> {
+    let existing = state.database.get_character(&character.character.id).await
+        .map_err(|e| e.to_string())?;
+    store_built_character(&state, &character, existing, campaign_id).await?;
+    Ok(character)
+}
+
+/// Get a stored built character, with its level history
+#[tauri::command]
+pub async fn get_built_character(
+    character_id: String,
+    state: State<'_, AppState>,
+) -> Result<BuiltCharacter, String> {
+    load_built_character(&state, &character_id).await.map(|(_, built)| built)
+}
+
+/// What a stored character's next level brings and the options to choose from
+#[tauri::command]
+pub async fn get_level_up_options(
+    character_id: String,
+    state: State<'_, AppState>,
+) -> Result<LevelUpOptions, String> {
+    let (_, built) = load_built_character(&state, &character_id).await?;
+    let catalog = rules_catalog(&state).await?;
+    LevelUpAssistant::new(&catalog).options(&built).map_err(|e| e.to_string())
+}
+
+/// Check choices for a stored character's next level without applying them
+#[tauri::command]
+pub async fn validate_level_up(
+    character_id: String,
+    choices: LevelUpChoices,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let (_, built) = load_built_character(&state, &character_id).await?;
+    let catalog = rules_catalog(&state).await?;
+    Ok(LevelUpAssistant::new(&catalog).validate(&built, &choices))
+}
+
+/// Raise a stored character one level and save it. The gain is added to
+/// the character's level history.
+#[tauri::command]
+pub async fn level_up_character(
+    character_id: String,
+    choices: LevelUpChoices,
+    state: State<'_, AppState>,
+) -> Result<LevelGain, String> {
+    let (record, mut built) = load_built_character(&state, &character_id).await?;
+    let catalog = rules_catalog(&state).await?;
+    let gain = LevelUpAssistant::new(&catalog).level_up(&mut built, &choices)
+        .map_err(|e| e.to_string())?;
+    store_built_character(&state, &built, Some(record), None).await?;
+    Ok(gain)
+}
+
+// ============================================================================
+// Character Sheet Export
+// ============================================================================
+
+/// Write a character sheet to `path` as a PDF, a Foundry VTT Actor, or a
+/// Roll20 character
+#[tauri::command]
+pub async fn export_character_sheet(
+    character: Character,
+    format: String,
+    path: String,
+    extras: Option<SheetExtras>,
+) -> Result<(), String> {
+    let format = SheetFormat::parse(&format).map_err(|e| e.to_string())?;
+    write_sheet(character, extras.unwrap_or_default(), format, path).await
+}
+
+/// Write a stored built character's sheet, with its hit points, saves, and
+/// spells filled in
+#[tauri::command]
+pub async fn export_built_character_sheet(
+    character_id: String,
+    format: String,
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let format = SheetFormat::parse(&format).map_err(|e| e.to_string())?;
+    let (_, built) = load_built_character(&state, &character_id).await?;
+    let extras = SheetExtras::from(&built);
+    write_sheet(built.character, extras, format, path).await
+}
+
+async fn write_sheet(character: Character, extras: SheetExtras, format: SheetFormat, path: String) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        let bytes = export::export_character(&character, &extras, format).map_err(|e| e.to_string())?;
+        std::fs::write(&path, bytes).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// ============================================================================
+// Character Import
+// ============================================================================
+
+/// Read a D&D Beyond or Pathbuilder 2e character export. The source is
+/// detected from the JSON unless `source` names it.
+#[tauri::command]
+pub fn import_character(data: String, source: Option<String>) -> Result<ImportedCharacter, String> {
+    let source = source
+        .as_deref()
+        .map(ImportSource::parse)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    import::import_character(&data, source).map_err(|e| e.to_string())
+}
+
+/// Import a character export onto a campaign's party roster
+#[tauri::command]
+pub fn import_character_to_party(
+    campaign_id: String,
+    data: String,
+    source: Option<String>,
+    player_name: Option<String>,
+    party: State<'_, PartyState>,
+) -> Result<PlayerCharacter, String> {
+    let imported = import_character(data, source)?;
+    let mut character = imported.to_player_character(&campaign_id);
+    character.player_name = player_name;
+    party.manager.create_character(character).map_err(|e| e.to_string())
+}
@@ -0,0 +1,181 @@
+//! Lifepath Commands
+//!
+//! Commands for rolling or generating the lifepath of a party member or NPC.
+//! The people in the lifepath are added to the campaign as NPCs related to
+//! the character, and its hooks are opened as plot threads.
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::commands::npc::generation::save_npc;
+use crate::commands::npc::naming::{campaign_name_culture, NameBankState};
+use crate::commands::{AppState, PartyState, PlotThreadState};
+use crate::core::campaign::plot_threads::PlotThread;
+use crate::core::campaign::relationships::{EntityRelationship, EntityType};
+use crate::core::character_gen::lifepath::{
+    Lifepath, LifepathGenerator, LifepathOptions, LifepathSubject, PersonStatus,
+};
+use crate::core::npc_gen::{NPCGenerationOptions, NPCGenerator, NPC};
+use crate::database::NpcOps;
+
+/// A lifepath and everything it added to the campaign
+#[derive(Debug, Clone, Serialize)]
+pub struct AppliedLifepath {
+    pub lifepath: Lifepath,
+    pub npcs: Vec<NPC>,
+    pub relationships: Vec<EntityRelationship>,
+    pub plot_threads: Vec<PlotThread>,
+}
+
+// ============================================================================
+// Lifepath Commands
+// ============================================================================
+
+/// Generate a lifepath for a party member or NPC, add the people in it to
+/// the campaign as related NPCs, open its hooks as plot threads, and append
+/// it to the character's notes.
+///
+/// # Arguments
+/// * `character_id` - Party member or NPC ID
+/// * `options` - Event counts, tone, and whether to use the LLM (default:
+///   rolled on the tables)
+#[tauri::command]
+pub async fn generate_lifepath(
+    campaign_id: String,
+    character_id: String,
+    options: Option<LifepathOptions>,
+    state: State<'_, AppState>,
+    party: State<'_, PartyState>,
+    threads: State<'_, PlotThreadState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<AppliedLifepath, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+
+    let pc = party.manager.get_character(&character_id);
+    let (subject_type, subject, npc_record) = match &pc {
+        Some(pc) => {
+            let subject = LifepathSubject {
+                name: pc.name.clone(),
+                ancestry: pc.ancestry.clone(),
+                occupation: Some(pc.class.clone()),
+            };
+            (EntityType::PC, subject, None)
+        }
+        None => {
+            let record = state
+                .database
+                .get_npc(&character_id)
+                .await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("Character not found: {}", character_id))?;
+            let subject = LifepathSubject {
+                name: record.name.clone(),
+                ancestry: None,
+                occupation: Some(record.role.clone()),
+            };
+            (EntityType::NPC, subject, Some(record))
+        }
+    };
+
+    let mut options = options.unwrap_or_default();
+    if options.name_culture.is_none() {
+        options.name_culture = campaign_name_culture(&state, Some(&campaign_id));
+    }
+    if options.setting.is_none() {
+        options.setting = campaign.description.clone();
+    }
+
+    let generator = LifepathGenerator::new().with_name_banks(name_banks.registry());
+    let llm_config = state.llm_config.read().map_err(|e| e.to_string())?.clone();
+    let lifepath = match llm_config.filter(|_| options.use_ai) {
+        Some(config) => generator
+            .with_llm(config)
+            .generate_detailed(&subject, &options)
+            .await
+            .map_err(|e| e.to_string())?,
+        None => generator.roll(&subject, &options, &mut rand::thread_rng()),
+    };
+
+    // The people become NPCs related to the character
+    let npc_generator = NPCGenerator::new().with_name_banks(name_banks.registry());
+    let mut npcs = Vec::new();
+    let mut relationships = Vec::new();
+    for person in &lifepath.people {
+        let mut npc = npc_generator.generate_quick(&NPCGenerationOptions {
+            system: Some(campaign.system.clone()),
+            name: Some(person.name.clone()),
+            role: Some(person.tie.npc_role().to_string()),
+            occupation: Some(person.role.clone()),
+            ..Default::default()
+        });
+        npc.notes = format!("{}'s {}. {}", subject.name, person.role, person.description).trim().to_string();
+        if person.status != PersonStatus::Alive {
+            let status = format!("{:?}", person.status).to_lowercase();
+            npc.notes.push_str(&format!(" Status: {}.", status));
+        }
+        save_npc(&state, &npc, Some(campaign_id.clone()), None).await?;
+
+        let mut relationship = EntityRelationship::new(
+            &campaign_id,
+            &character_id,
+            subject_type.clone(),
+            &subject.name,
+            &npc.id,
+            EntityType::NPC,
+            &npc.name,
+            person.tie.relationship_type(),
+        )
+        .with_description(&person.role);
+        relationship.is_active = person.status != PersonStatus::Dead;
+        relationships.push(
+            state
+                .relationship_manager
+                .create_relationship(relationship)
+                .map_err(|e| e.to_string())?,
+        );
+        npcs.push(npc);
+    }
+
+    // The hooks become open plot threads tied to the people involved
+    let mut plot_threads = Vec::new();
+    for hook in &lifepath.hooks {
+        let mut thread = PlotThread::new(&campaign_id, &hook.title)
+            .with_description(&hook.description)
+            .with_keywords(hook.keywords.clone());
+        if subject_type == EntityType::NPC {
+            thread.npc_ids.push(character_id.clone());
+        }
+        if let Some(name) = &hook.person {
+            thread.npc_ids.extend(npcs.iter().filter(|n| n.name.eq_ignore_ascii_case(name)).map(|n| n.id.clone()));
+        }
+        plot_threads.push(threads.manager.create_thread(thread).map_err(|e| e.to_string())?);
+    }
+
+    // Keep the lifepath with the character
+    let summary = lifepath.summary();
+    if let Some(mut pc) = pc {
+        pc.notes = append_note(&pc.notes, &summary);
+        party.manager.update_character(pc).map_err(|e| e.to_string())?;
+    } else if let Some(mut record) = npc_record {
+        record.notes = Some(append_note(record.notes.as_deref().unwrap_or_default(), &summary));
+        state.database.save_npc(&record).await.map_err(|e| e.to_string())?;
+    }
+
+    Ok(AppliedLifepath {
+        lifepath,
+        npcs,
+        relationships,
+        plot_threads,
+    })
+}
+
+fn append_note(notes: &str, addition: &str) -> String {
+    if notes.trim().is_empty() {
+        addition.to_string()
+    } else {
+        format!("{}\n\n{}", notes.trim_end(), addition)
+    }
+}
@@ -1,11 +1,15 @@
 //! Generation Commands Module
 //!
-//! Commands for procedural generation of characters, locations, and other
-//! TTRPG content.
+//! Commands for procedural generation of characters, locations, whole
+//! sites, and other TTRPG content.
 
 pub mod character;
+pub mod lifepath;
 pub mod location;
+pub mod sites;
 
 // Re-export all commands using glob to include Tauri __cmd__ macros
 pub use character::*;
+pub use lifepath::*;
 pub use location::*;
+pub use sites::*;
@@ -0,0 +1,194 @@
+//! Site Generation Commands
+//!
+//! Commands for generating whole settlements, taverns, and dungeons. The
+//! generated locations, NPCs, and relationships are saved to the campaign,
+//! so the result is browsable in the location, NPC, and relationship views.
+
+use tauri::State;
+
+use crate::commands::npc::generation::save_npc;
+use crate::commands::npc::naming::{campaign_name_culture, NameBankState};
+use crate::commands::AppState;
+use crate::core::campaign::relationships::{EntityRelationship, EntityType, RelationshipType};
+use crate::core::campaign::shops::SettlementSize;
+use crate::core::location_gen::sites::{
+    GeneratedDungeon, GeneratedSettlement, GeneratedTavern, SiteGenerator, SiteNpc, SiteOptions, TavernQuality,
+};
+use crate::core::location_gen::{ConnectionType, Difficulty, Location, LocationConnection};
+use crate::core::npc_gen::NPCGenerator;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Fill in the campaign ID, its game system, and its default name culture
+fn site_options(campaign_id: &str, options: Option<SiteOptions>, state: &AppState) -> Result<SiteOptions, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let mut options = options.unwrap_or_default();
+    options.campaign_id = Some(campaign_id.to_string());
+    if options.system.is_none() {
+        options.system = Some(campaign.system);
+    }
+    if options.name_culture.is_none() {
+        options.name_culture = campaign_name_culture(state, Some(campaign_id));
+    }
+    Ok(options)
+}
+
+fn site_generator(name_banks: &NameBankState) -> SiteGenerator {
+    SiteGenerator::new().with_npc_generator(NPCGenerator::new().with_name_banks(name_banks.registry()))
+}
+
+/// Put a generated site inside an existing location: connect the two and
+/// record the site as located there
+fn place_in_parent(
+    campaign_id: &str,
+    site: &mut Location,
+    parent_id: &str,
+    state: &AppState,
+) -> Result<EntityRelationship, String> {
+    let parent = state
+        .location_manager
+        .get_location(parent_id)
+        .ok_or_else(|| format!("Location not found: {}", parent_id))?;
+    site.connected_locations.push(LocationConnection {
+        target_id: Some(parent.id.clone()),
+        target_name: parent.name.clone(),
+        connection_type: ConnectionType::Path,
+        description: None,
+        travel_time: None,
+        hazards: vec![],
+    });
+    state
+        .location_manager
+        .add_connection(
+            &parent.id,
+            LocationConnection {
+                target_id: Some(site.id.clone()),
+                target_name: site.name.clone(),
+                connection_type: ConnectionType::Path,
+                description: None,
+                travel_time: None,
+                hazards: vec![],
+            },
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(EntityRelationship::new(
+        campaign_id,
+        &site.id,
+        EntityType::Location,
+        &site.name,
+        &parent.id,
+        EntityType::Location,
+        &parent.name,
+        RelationshipType::LocatedAt,
+    ))
+}
+
+/// Save a generated site's locations, NPCs, and relationships
+async fn save_site(
+    campaign_id: &str,
+    locations: Vec<&Location>,
+    npcs: Vec<&SiteNpc>,
+    relationships: Vec<EntityRelationship>,
+    state: &AppState,
+) -> Result<(), String> {
+    for location in locations {
+        state.location_manager.save_location(location.clone()).map_err(|e| e.to_string())?;
+    }
+    for site_npc in npcs {
+        save_npc(state, &site_npc.npc, Some(campaign_id.to_string()), Some(site_npc.location_id.clone())).await?;
+    }
+    for relationship in relationships {
+        state.relationship_manager.create_relationship(relationship).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Site Generation Commands
+// ============================================================================
+
+/// Generate a settlement with its government, districts, notable NPCs, and
+/// rumors, and save it to the campaign
+///
+/// # Arguments
+/// * `size` - Decides how many districts, NPCs, and rumors (default: town)
+#[tauri::command]
+pub async fn generate_settlement(
+    campaign_id: String,
+    size: Option<SettlementSize>,
+    options: Option<SiteOptions>,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<GeneratedSettlement, String> {
+    let options = site_options(&campaign_id, options, &state)?;
+    let site = site_generator(&name_banks).settlement(&options, size.unwrap_or_default(), &mut rand::thread_rng());
+
+    save_site(
+        &campaign_id,
+        site.locations(),
+        site.notable_npcs.iter().collect(),
+        site.relationships(&campaign_id),
+        &state,
+    )
+    .await?;
+    Ok(site)
+}
+
+/// Generate a tavern with its keeper, menu, patrons, and hooks, and save it
+/// to the campaign
+///
+/// # Arguments
+/// * `quality` - Scales the menu prices (default: modest)
+/// * `parent_location_id` - Settlement or district the tavern stands in
+#[tauri::command]
+pub async fn generate_tavern(
+    campaign_id: String,
+    quality: Option<TavernQuality>,
+    parent_location_id: Option<String>,
+    options: Option<SiteOptions>,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<GeneratedTavern, String> {
+    let options = site_options(&campaign_id, options, &state)?;
+    let mut site = site_generator(&name_banks).tavern(&options, quality.unwrap_or_default(), &mut rand::thread_rng());
+
+    let mut relationships = site.relationships(&campaign_id);
+    if let Some(parent_id) = parent_location_id {
+        relationships.push(place_in_parent(&campaign_id, &mut site.tavern, &parent_id, &state)?);
+    }
+    save_site(&campaign_id, vec![&site.tavern], site.npcs(), relationships, &state).await?;
+    Ok(site)
+}
+
+/// Generate a dungeon as a graph of rooms with encounters and treasure, and
+/// save it to the campaign
+///
+/// # Arguments
+/// * `room_count` - Number of rooms, 3 to 30 (default: 8)
+/// * `danger_level` - easy, medium, hard, very_hard, nearly_impossible
+/// * `parent_location_id` - Region or settlement the dungeon is found in
+#[tauri::command]
+pub async fn generate_dungeon(
+    campaign_id: String,
+    room_count: Option<usize>,
+    danger_level: Option<String>,
+    parent_location_id: Option<String>,
+    options: Option<SiteOptions>,
+    state: State<'_, AppState>,
+) -> Result<GeneratedDungeon, String> {
+    let options = site_options(&campaign_id, options, &state)?;
+    let danger = danger_level.as_deref().map(Difficulty::from_str).unwrap_or(Difficulty::Medium);
+    let mut site = SiteGenerator::new().dungeon(&options, room_count.unwrap_or(8), danger, &mut rand::thread_rng());
+
+    let mut relationships = site.relationships(&campaign_id);
+    if let Some(parent_id) = parent_location_id {
+        relationships.push(place_in_parent(&campaign_id, &mut site.dungeon, &parent_id, &state)?);
+    }
+    save_site(&campaign_id, site.locations(), vec![], relationships, &state).await?;
+    Ok(site)
+}
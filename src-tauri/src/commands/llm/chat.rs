@@ -5,6 +5,7 @@
 use tauri::State;
 
 use crate::commands::state::AppState;
+use crate::commands::{fire_narration_sfx, SfxTriggerState};
 use crate::core::llm::{ChatMessage, MessageRole};
 
 use super::types::{ChatRequestPayload, ChatResponsePayload};
@@ -18,6 +19,7 @@ use super::types::{ChatRequestPayload, ChatResponsePayload};
 pub async fn chat(
     payload: ChatRequestPayload,
     state: State<'_, AppState>,
+    sfx: State<'_, SfxTriggerState>,
 ) -> Result<ChatResponsePayload, String> {
     // Get configuration
     let config = state.llm_config.read()
@@ -93,6 +95,8 @@ pub async fn chat(
     let content = manager_guard.chat(messages, &model).await
         .map_err(|e| format!("Chat failed: {}", e))?;
 
+    fire_narration_sfx(&sfx, &content);
+
     Ok(ChatResponsePayload {
         content,
         model,
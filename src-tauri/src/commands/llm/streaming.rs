@@ -10,8 +10,11 @@
 
 use tauri::State;
 use tauri::Emitter;
+use tauri::Manager;
 
 use crate::commands::state::AppState;
+use crate::commands::{fire_narration_sfx, SfxTriggerState};
+use crate::core::audio::NarrationScanner;
 use crate::core::llm::{ChatMessage, ChatChunk};
 
 // ============================================================================
@@ -94,6 +97,8 @@ pub async fn stream_chat(
         log::info!("[stream_chat:{}] Receiver task started", stream_id_clone);
         let mut chunk_count = 0;
         let mut total_bytes = 0;
+        // Narration is scanned for SFX trigger words one sentence at a time
+        let mut narration = NarrationScanner::new();
 
         // Process chunks and emit events
         while let Some(chunk_result) = rx.recv().await {
@@ -108,6 +113,12 @@ pub async fn stream_chat(
                     chunk_count += 1;
                     total_bytes += content.len();
 
+                    if let Some(segment) = narration.push(&content) {
+                        if let Some(sfx) = app_handle.try_state::<SfxTriggerState>() {
+                            fire_narration_sfx(&sfx, &segment);
+                        }
+                    }
+
                     let chunk = ChatChunk {
                         stream_id: stream_id_clone.clone(),
                         content,
@@ -147,6 +158,12 @@ pub async fn stream_chat(
         }
         log::info!("[stream_chat:{}] Receiver task exiting", stream_id_clone);
 
+        if let Some(segment) = narration.finish() {
+            if let Some(sfx) = app_handle.try_state::<SfxTriggerState>() {
+                fire_narration_sfx(&sfx, &segment);
+            }
+        }
+
         // Emit final chunk to signal completion
         let final_chunk = ChatChunk {
             stream_id: stream_id_clone.clone(),
@@ -5,6 +5,7 @@
 use tauri::State;
 
 use crate::core::location_gen::Location;
+use crate::commands::world::setting::{campaign_locations, WorldSettingState};
 use crate::commands::AppState;
 
 // ============================================================================
@@ -35,13 +36,14 @@ pub fn get_location(
     Ok(state.location_manager.get_location(&location_id))
 }
 
-/// List all locations for a campaign
+/// List all locations for a campaign, including those of its shared setting
 #[tauri::command]
 pub fn list_campaign_locations(
     campaign_id: String,
     state: State<'_, AppState>,
+    settings: State<'_, WorldSettingState>,
 ) -> Result<Vec<Location>, String> {
-    Ok(state.location_manager.list_locations_for_campaign(&campaign_id))
+    Ok(campaign_locations(&campaign_id, &state, &settings))
 }
 
 /// Delete a location
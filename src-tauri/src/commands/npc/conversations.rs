@@ -7,13 +7,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use once_cell::sync::Lazy;
 use tauri::State;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 
-use crate::commands::AppState;
+use crate::commands::{AppState, PartyState};
 use crate::database::{NpcConversation, NpcRecord, ConversationMessage, NpcOps};
 use crate::core::llm::ChatChunk;
+use crate::core::npc_gen::memory::context_window;
+use crate::core::session::capture::npc_dialogue_event;
+use crate::core::session_manager::CaptureCategory;
 
 // ============================================================================
 // Per-NPC Chat Lock
@@ -33,6 +36,25 @@ async fn get_npc_lock(npc_id: &str) -> Arc<Mutex<()>> {
         .clone()
 }
 
+/// Fold older messages into the NPC's memories in the background once
+/// enough have piled up
+fn spawn_remember(app_handle: &tauri::AppHandle, npc: NpcRecord) {
+    let app_handle = app_handle.clone();
+    tokio::spawn(async move {
+        let state = app_handle.state::<AppState>();
+        if let Err(e) = super::memory::remember_conversation(&state, &npc, false).await {
+            log::warn!("Failed to summarize conversation for NPC {}: {}", npc.id, e);
+        }
+    });
+}
+
+/// Put an NPC's reply on the campaign's running session timeline
+fn capture_npc_reply(state: &AppState, campaign_id: &str, npc: &NpcRecord, content: &str) {
+    state.session_manager.capture_campaign_event(campaign_id, CaptureCategory::NpcConversations, |session_id| {
+        npc_dialogue_event(session_id, &npc.id, &npc.name, content)
+    });
+}
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -175,8 +197,10 @@ pub async fn list_npc_summaries(
 /// Generate an LLM reply as an NPC
 #[tauri::command]
 pub async fn reply_as_npc(
+    app_handle: tauri::AppHandle,
     npc_id: String,
     state: State<'_, AppState>,
+    party: State<'_, PartyState>,
 ) -> Result<ConversationMessage, String> {
     // 1. Load NPC
     let npc = state.database.get_npc(&npc_id).await.map_err(|e| e.to_string())?
@@ -201,8 +225,27 @@ pub async fn reply_as_npc(
          .ok_or_else(|| "Conversation not found".to_string())?;
     let history: Vec<ConversationMessage> = serde_json::from_str(&conv.messages_json).unwrap_or_default();
 
+    // Remind the NPC of earlier conversations relevant to the latest message
+    let query = history.iter().rev().find(|m| m.role == "user").map(|m| m.content.as_str()).unwrap_or_default();
+    let system_prompt = match super::memory::memory_context(&state, &npc.id, query).await {
+        Some(memories) => format!("{}\n\n{}", system_prompt, memories),
+        None => system_prompt,
+    };
+    // Speak to the party according to how the NPC feels about them
+    let system_prompt = match super::dispositions::disposition_context(&state, &party, &npc) {
+        Some(attitude) => format!("{}\n\n{}", system_prompt, attitude),
+        None => system_prompt,
+    };
+    // Keep the NPC's catchphrases, accent, and word choices
+    let quirks = super::dialects::npc_quirks(&npc);
+    let system_prompt = match quirks.as_ref().and_then(|q| q.prompt_section()) {
+        Some(speech) => format!("{}\n\n{}", system_prompt, speech),
+        None => system_prompt,
+    };
+
     // 4. Construct LLM Request
-    let llm_messages: Vec<crate::core::llm::ChatMessage> = history.iter().map(|m| crate::core::llm::ChatMessage {
+    let window = context_window(&history, conv.summarized_count as usize);
+    let llm_messages: Vec<crate::core::llm::ChatMessage> = window.iter().map(|m| crate::core::llm::ChatMessage {
         role: if m.role == "user" { crate::core::llm::MessageRole::User } else { crate::core::llm::MessageRole::Assistant },
         content: m.content.clone(),
         images: None,
@@ -238,7 +281,11 @@ pub async fn reply_as_npc(
     let message = ConversationMessage {
         id: uuid::Uuid::new_v4().to_string(),
         role: "assistant".to_string(), // standard role
-        content: resp.content,
+        // Respell anything the model wrote in standard spelling
+        content: match &quirks {
+            Some(quirks) => quirks.write(&resp.content),
+            None => resp.content,
+        },
         parent_message_id: history.last().map(|m| m.id.clone()),
         created_at: chrono::Utc::now().to_rfc3339(),
     };
@@ -251,6 +298,8 @@ pub async fn reply_as_npc(
     conv_update.unread_count += 1;
 
     state.database.save_npc_conversation(&conv_update).await.map_err(|e| e.to_string())?;
+    capture_npc_reply(&state, &conv_update.campaign_id, &npc, &message.content);
+    spawn_remember(&app_handle, npc);
 
     Ok(message)
 }
@@ -277,6 +326,7 @@ pub async fn stream_npc_chat(
     mode: Option<String>,
     provided_stream_id: Option<String>,
     state: State<'_, AppState>,
+    party: State<'_, PartyState>,
 ) -> Result<String, String> {
     let chat_mode = mode.map(|m| NpcChatMode::from_str(&m)).unwrap_or(NpcChatMode::Voice);
     log::info!("[stream_npc_chat] Starting for NPC {} with message: {}",
@@ -300,7 +350,21 @@ pub async fn stream_npc_chat(
         .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
 
     // 2. Build system prompt from personality (using selected mode)
-    let system_prompt = build_npc_system_prompt_with_mode(&npc, &state, chat_mode).await?;
+    let mut system_prompt = build_npc_system_prompt_with_mode(&npc, &state, chat_mode).await?;
+    if chat_mode == NpcChatMode::Voice {
+        if let Some(memories) = super::memory::memory_context(&state, &npc.id, &user_message).await {
+            system_prompt.push_str("\n\n");
+            system_prompt.push_str(&memories);
+        }
+        if let Some(attitude) = super::dispositions::disposition_context(&state, &party, &npc) {
+            system_prompt.push_str("\n\n");
+            system_prompt.push_str(&attitude);
+        }
+        if let Some(speech) = super::dialects::npc_quirks(&npc).and_then(|q| q.prompt_section()) {
+            system_prompt.push_str("\n\n");
+            system_prompt.push_str(&speech);
+        }
+    }
 
     // 3. Load conversation history
     let conv = state.database.get_npc_conversation(&npc.id).await.map_err(|e| e.to_string())?;
@@ -330,6 +394,7 @@ pub async fn stream_npc_chat(
             unread_count: 0,
             created_at: chrono::Utc::now().to_rfc3339(),
             updated_at: chrono::Utc::now().to_rfc3339(),
+            summarized_count: 0,
         }
     });
 
@@ -344,8 +409,8 @@ pub async fn stream_npc_chat(
         crate::core::llm::ChatMessage::system(system_prompt),
     ];
 
-    // Add conversation history
-    for m in &all_messages {
+    // Add conversation history; summarized messages are covered by memories
+    for m in context_window(&all_messages, conv_to_update.summarized_count as usize) {
         llm_messages.push(crate::core::llm::ChatMessage {
             role: if m.role == "user" {
                 crate::core::llm::MessageRole::User
@@ -389,6 +454,7 @@ pub async fn stream_npc_chat(
 
     // 9. Clone what we need for the spawned task
     let npc_id_for_task = npc.id.clone();
+    let npc_for_task = npc.clone();
     let database = state.database.clone();
 
     // 10. Spawn streaming task
@@ -461,6 +527,12 @@ pub async fn stream_npc_chat(
                         conv.unread_count += 1;
                         if let Err(e) = database.save_npc_conversation(&conv).await {
                             log::error!("[stream_npc_chat:{}] Failed to save response: {}", stream_id_clone, e);
+                        } else {
+                            if chat_mode == NpcChatMode::Voice {
+                                let state = app_handle.state::<AppState>();
+                                capture_npc_reply(&state, &conv.campaign_id, &npc_for_task, &assistant_msg.content);
+                            }
+                            spawn_remember(&app_handle, npc_for_task.clone());
                         }
                     }
                     Err(e) => {
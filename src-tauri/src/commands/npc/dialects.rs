@@ -1,13 +1,26 @@
 //! NPC Dialect Commands
 //!
-//! Commands for loading and applying dialect transformations.
+//! Commands for loading and applying dialect transformations, and for the
+//! per-NPC speech quirks built from them.
 
 use std::path::PathBuf;
 
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::AppState;
 use crate::core::npc_gen::{
-    DialectDefinition, DialectTransformer, DialectTransformResult, Intensity,
+    DialectDefinition, DialectTransformer, DialectTransformResult, Intensity, SpeechQuirks, NPC,
     load_yaml_file, get_dialects_dir,
 };
+use crate::database::{NpcOps, NpcRecord};
+
+/// An NPC's line as written in chat and as read aloud
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeechPreview {
+    pub written: String,
+    pub spoken: String,
+}
 
 // ============================================================================
 // Path Validation
@@ -43,6 +56,32 @@ fn validate_dialect_path(path: &str) -> Result<PathBuf, String> {
     Ok(canonical_path)
 }
 
+// ============================================================================
+// Speech Quirk Helpers
+// ============================================================================
+
+/// The speech quirks stored with an NPC's record, if any
+pub(crate) fn npc_quirks(record: &NpcRecord) -> Option<SpeechQuirks> {
+    let npc: NPC = serde_json::from_str(record.data_json.as_deref()?).ok()?;
+    npc.voice.quirks.filter(|q| !q.is_empty())
+}
+
+/// Store speech quirks on an NPC, keeping the rest of their record as is
+async fn save_quirks(npc_id: &str, quirks: Option<SpeechQuirks>, state: &AppState) -> Result<NPC, String> {
+    let mut record = state.database.get_npc(npc_id).await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    let json = record.data_json.as_deref()
+        .ok_or_else(|| format!("NPC {} has no stored data", npc_id))?;
+    let mut npc: NPC = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+    npc.voice.quirks = quirks;
+    record.data_json = Some(serde_json::to_string(&npc).map_err(|e| e.to_string())?);
+    state.database.save_npc(&record).await.map_err(|e| e.to_string())?;
+    state.npc_store.update(npc.clone());
+    Ok(npc)
+}
+
 // ============================================================================
 // NPC Dialect Commands
 // ============================================================================
@@ -76,3 +115,53 @@ pub fn apply_dialect(
     let transformer = DialectTransformer::new(dialect).with_intensity(intensity);
     Ok(transformer.transform(&text, &mut rng))
 }
+
+// ============================================================================
+// Speech Quirk Commands
+// ============================================================================
+
+/// Generate and save an NPC's speech quirks: catchphrases, accent
+/// respellings, and word choices. The same NPC and dialect always give the
+/// same quirks.
+///
+/// # Arguments
+/// * `dialect` - Dialect to build the accent from (default: none, so only
+///   catchphrases and word choices)
+/// * `intensity` - light, moderate, or heavy (default: the dialect's)
+#[tauri::command]
+pub async fn generate_npc_speech_quirks(
+    npc_id: String,
+    dialect: Option<DialectDefinition>,
+    intensity: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<SpeechQuirks, String> {
+    let record = state.database.get_npc(&npc_id).await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    let json = record.data_json
+        .ok_or_else(|| format!("NPC {} has no stored data", npc_id))?;
+    let npc: NPC = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+    let intensity = intensity.as_deref().map(Intensity::from_str);
+    let quirks = SpeechQuirks::generate(&npc, dialect.as_ref(), intensity);
+    save_quirks(&npc_id, Some(quirks.clone()), &state).await?;
+    Ok(quirks)
+}
+
+/// Save hand-edited speech quirks on an NPC, or clear them with none
+#[tauri::command]
+pub async fn set_npc_speech_quirks(
+    npc_id: String,
+    quirks: Option<SpeechQuirks>,
+    state: State<'_, AppState>,
+) -> Result<NPC, String> {
+    save_quirks(&npc_id, quirks, &state).await
+}
+
+/// Show a line as an NPC with these quirks would write and say it
+#[tauri::command]
+pub fn preview_npc_speech(quirks: SpeechQuirks, text: String) -> Result<SpeechPreview, String> {
+    let written = quirks.write(&text);
+    let spoken = quirks.speak(&written);
+    Ok(SpeechPreview { written, spoken })
+}
@@ -0,0 +1,110 @@
+//! NPC Disposition Commands
+//!
+//! Commands for viewing and adjusting how NPCs feel about the party and
+//! individual player characters, plus the helper conversation commands use
+//! to set the NPC's attitude before replying.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, State};
+
+use crate::commands::{AppState, PartyState};
+use crate::core::campaign::dispositions::{
+    disposition_prompt_section, log_interaction, party_dispositions, DispositionChange, InteractionKind,
+    PartyDisposition,
+};
+use crate::database::NpcRecord;
+
+/// Event emitted when an NPC's attitude toward the party moves into another
+/// tier
+pub const DISPOSITION_UPDATE_EVENT: &str = "npc:disposition_changed";
+
+/// Payload for [`DISPOSITION_UPDATE_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispositionUpdateEvent {
+    pub campaign_id: String,
+    pub changes: Vec<DispositionChange>,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// System prompt section with the NPC's attitude toward the party and any PC
+/// they see differently, if anything has happened between them
+pub(crate) fn disposition_context(state: &AppState, party: &PartyState, npc: &NpcRecord) -> Option<String> {
+    let campaign_id = npc.campaign_id.as_deref()?;
+    let dispositions = party_dispositions(&state.world_state_manager, campaign_id, &npc.id);
+    let pc_names: HashMap<String, String> = party
+        .manager
+        .list_characters(campaign_id, true)
+        .into_iter()
+        .map(|pc| (pc.id, pc.name))
+        .collect();
+    disposition_prompt_section(&dispositions, &pc_names)
+}
+
+/// Emit [`DISPOSITION_UPDATE_EVENT`] for the changes that crossed a tier
+pub(crate) fn emit_tier_changes(app_handle: &tauri::AppHandle, campaign_id: &str, changes: &[DispositionChange]) {
+    let changes: Vec<DispositionChange> = changes.iter().filter(|c| c.tier_changed()).cloned().collect();
+    if !changes.is_empty() {
+        let _ = app_handle.emit(DISPOSITION_UPDATE_EVENT, DispositionUpdateEvent {
+            campaign_id: campaign_id.to_string(),
+            changes,
+        });
+    }
+}
+
+// ============================================================================
+// Disposition Commands
+// ============================================================================
+
+/// Get an NPC's dispositions toward the party and individual PCs, party first
+#[tauri::command]
+pub fn get_npc_dispositions(
+    campaign_id: String,
+    npc_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<PartyDisposition>, String> {
+    Ok(party_dispositions(&state.world_state_manager, &campaign_id, &npc_id))
+}
+
+/// Log an interaction between an NPC and the party, or one PC.
+///
+/// A change toward one PC also moves the NPC's view of the party by half as
+/// much. Changes that cross a tier are emitted as `npc:disposition_changed`;
+/// the NPC's next conversation picks up the new attitude.
+///
+/// # Arguments
+/// * `kind` - helped, gift, persuaded, paid, insulted, threatened, harmed,
+///   betrayed, or other
+/// * `change` - Disposition change, overriding the kind's default
+/// * `pc_id` - Player character the interaction was with (default: the
+///   whole party)
+#[tauri::command]
+pub fn log_npc_interaction(
+    campaign_id: String,
+    npc_id: String,
+    kind: InteractionKind,
+    description: String,
+    change: Option<i32>,
+    pc_id: Option<String>,
+    session_number: Option<u32>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<DispositionChange>, String> {
+    let change = change.unwrap_or_else(|| kind.default_change());
+    let changes = log_interaction(
+        &state.world_state_manager,
+        &campaign_id,
+        &npc_id,
+        pc_id.as_deref(),
+        change,
+        &description,
+        session_number,
+    )
+    .map_err(|e| e.to_string())?;
+    emit_tier_changes(&app_handle, &campaign_id, &changes);
+    Ok(changes)
+}
@@ -1,11 +1,19 @@
 //! NPC Generation Commands
 //!
-//! Commands for generating new NPCs.
+//! Commands for generating new NPCs, one at a time or as a roster for a
+//! faction or location.
 
+use serde::Serialize;
 use tauri::State;
 
-use crate::commands::AppState;
-use crate::core::npc_gen::{NPCGenerator, NPCGenerationOptions, NPC};
+use crate::commands::{AppState, FactionState};
+use super::naming::{campaign_name_culture, NameBankState};
+use crate::core::campaign::factions::Faction;
+use crate::core::campaign::relationships::EntityRelationship;
+use crate::core::location_gen::{Disposition, Inhabitant};
+use crate::core::npc_gen::reskin;
+use crate::core::npc_gen::{NPCGenerator, NPCGenerationOptions, NpcBatch, NpcBatchGenerator, NpcBatchOptions, ReskinTheme, NPC};
+use crate::ingestion::ttrpg::StatBlockData;
 use crate::database::NpcOps;
 
 // Helper function for enum serialization
@@ -15,20 +23,13 @@ fn serialize_enum_to_string<T: serde::Serialize>(value: &T) -> String {
         .unwrap_or_default()
 }
 
-// ============================================================================
-// NPC Generation Commands
-// ============================================================================
-
-/// Generate a new NPC and save to store and database
-#[tauri::command]
-pub async fn generate_npc(
-    options: NPCGenerationOptions,
+/// Save a generated NPC to the in-memory store and the database
+pub(crate) async fn save_npc(
+    state: &AppState,
+    npc: &NPC,
     campaign_id: Option<String>,
-    state: State<'_, AppState>,
-) -> Result<NPC, String> {
-    let generator = NPCGenerator::new();
-    let npc = generator.generate_quick(&options);
-
+    location_id: Option<String>,
+) -> Result<(), String> {
     // Save to memory store
     state.npc_store.add(npc.clone(), campaign_id.as_deref());
 
@@ -36,11 +37,12 @@ pub async fn generate_npc(
     let personality_json = serde_json::to_string(&npc.personality).map_err(|e| e.to_string())?;
     let stats_json = npc.stats.as_ref().map(|s| serde_json::to_string(s).unwrap_or_default());
     let role_str = serialize_enum_to_string(&npc.role);
-    let data_json = serde_json::to_string(&npc).map_err(|e| e.to_string())?;
+    let data_json = serde_json::to_string(npc).map_err(|e| e.to_string())?;
+    let quest_hooks: Vec<&str> = npc.hooks.iter().map(|h| h.description.as_str()).collect();
 
     let record = crate::database::NpcRecord {
         id: npc.id.clone(),
-        campaign_id: campaign_id.clone(),
+        campaign_id,
         name: npc.name.clone(),
         role: role_str,
         personality_id: None,
@@ -48,13 +50,172 @@ pub async fn generate_npc(
         data_json: Some(data_json),
         stats_json,
         notes: Some(npc.notes.clone()),
-        location_id: None,
+        location_id,
         voice_profile_id: None,
-        quest_hooks: None,
+        quest_hooks: (!quest_hooks.is_empty()).then(|| serde_json::to_string(&quest_hooks).unwrap_or_default()),
         created_at: chrono::Utc::now().to_rfc3339(),
     };
 
-    state.database.save_npc(&record).await.map_err(|e| e.to_string())?;
+    state.database.save_npc(&record).await.map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// NPC Generation Commands
+// ============================================================================
+
+/// Generate a new NPC and save to store and database
+#[tauri::command]
+pub async fn generate_npc(
+    mut options: NPCGenerationOptions,
+    campaign_id: Option<String>,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<NPC, String> {
+    if options.name_culture.is_none() {
+        options.name_culture = campaign_name_culture(&state, campaign_id.as_deref());
+    }
+    let generator = NPCGenerator::new().with_name_banks(name_banks.registry());
+    let npc = generator.generate_quick(&options);
+
+    save_npc(&state, &npc, campaign_id, None).await?;
 
     Ok(npc)
 }
+
+/// A generated roster and what it was added to
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedNpcBatch {
+    pub batch: NpcBatch,
+    /// The faction the roster joined, if any
+    pub faction: Option<Faction>,
+    pub relationships: Vec<EntityRelationship>,
+}
+
+/// Generate a roster of related NPCs (e.g. a thieves' guild) with a leader
+/// hierarchy and a shared voice, and save them with their relationships
+///
+/// # Arguments
+/// * `faction_id` - Existing faction the roster joins; its name becomes the
+///   group name unless one is given
+/// * `location_id` - Location the roster operates from
+/// * `create_faction` - Create a faction for the roster when no
+///   `faction_id` is given (default: false)
+#[tauri::command]
+pub async fn generate_npc_batch(
+    campaign_id: String,
+    options: Option<NpcBatchOptions>,
+    faction_id: Option<String>,
+    location_id: Option<String>,
+    create_faction: Option<bool>,
+    state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<GeneratedNpcBatch, String> {
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let existing_faction = match &faction_id {
+        Some(id) => Some(factions.manager.get_faction(id).ok_or_else(|| format!("Faction not found: {}", id))?),
+        None => None,
+    };
+    let location = match &location_id {
+        Some(id) => Some(state.location_manager.get_location(id).ok_or_else(|| format!("Location not found: {}", id))?),
+        None => None,
+    };
+
+    let mut options = options.unwrap_or_default();
+    if options.system.is_none() {
+        options.system = Some(campaign.system.clone());
+    }
+    if options.name_culture.is_none() {
+        options.name_culture = campaign_name_culture(&state, Some(&campaign_id));
+    }
+    if options.group_name.is_none() {
+        options.group_name = existing_faction.as_ref().map(|f| f.name.clone());
+    }
+    if let Some(location) = &location {
+        options.location = Some(location.name.clone());
+    }
+
+    let generator = NpcBatchGenerator::new().with_npc_generator(NPCGenerator::new().with_name_banks(name_banks.registry()));
+    let batch = generator.generate(&options, &mut rand::thread_rng());
+
+    for member in &batch.members {
+        save_npc(&state, &member.npc, Some(campaign_id.clone()), location_id.clone()).await?;
+        if let Some(location) = &location {
+            let inhabitant = Inhabitant {
+                name: member.npc.name.clone(),
+                role: format!("{}, {}", member.title, batch.group_name),
+                description: member.npc.appearance.demeanor.clone(),
+                disposition: Disposition::Neutral,
+                secrets: member.npc.secrets.clone(),
+                services: vec![],
+            };
+            state.location_manager.add_inhabitant(&location.id, inhabitant).map_err(|e| e.to_string())?;
+        }
+    }
+
+    // Join the given faction, or found a new one for the roster
+    let faction = match existing_faction {
+        Some(mut faction) => {
+            faction.members.extend(batch.faction_members());
+            Some(factions.manager.update_faction(faction).map_err(|e| e.to_string())?)
+        }
+        None if create_faction.unwrap_or(false) => {
+            let mut faction = Faction::new(&campaign_id, &batch.group_name)
+                .with_territory(location.iter().map(|l| l.id.clone()).collect());
+            faction.members = batch.faction_members();
+            Some(factions.manager.create_faction(faction).map_err(|e| e.to_string())?)
+        }
+        None => None,
+    };
+
+    let mut relationships = Vec::new();
+    for relationship in batch.relationships(&campaign_id, faction.as_ref(), location.as_ref()) {
+        relationships.push(
+            state
+                .relationship_manager
+                .create_relationship(relationship)
+                .map_err(|e| e.to_string())?,
+        );
+    }
+
+    Ok(GeneratedNpcBatch {
+        batch,
+        faction,
+        relationships,
+    })
+}
+
+// ============================================================================
+// Stat Block Commands
+// ============================================================================
+
+/// Re-theme a creature's stat block, e.g. "make this bandit captain a
+/// frost-themed cultist". Names, damage types, and flavor change; armor
+/// class, hit points, attacks, and challenge rating stay as they were.
+///
+/// # Arguments
+/// * `theme` - Description of the new look ("frost-themed cultist")
+/// * `name` - New name, instead of one read from the theme
+/// * `damage_type` - Damage type to deal, instead of one read from the theme
+#[tauri::command]
+pub fn reskin_stat_block(
+    stat_block: StatBlockData,
+    theme: String,
+    name: Option<String>,
+    damage_type: Option<String>,
+) -> Result<StatBlockData, String> {
+    if theme.trim().is_empty() && name.is_none() && damage_type.is_none() {
+        return Err("Describe the new theme".to_string());
+    }
+    let mut reskin_theme = ReskinTheme::parse(&theme);
+    if let Some(name) = name.filter(|n| !n.trim().is_empty()) {
+        reskin_theme.name = Some(name.trim().to_string());
+    }
+    if let Some(damage_type) = damage_type {
+        reskin_theme = ReskinTheme { adjective: None, flavor: None, ..reskin_theme }.with_damage_type(&damage_type);
+    }
+    Ok(reskin::reskin_stat_block(&stat_block, &reskin_theme))
+}
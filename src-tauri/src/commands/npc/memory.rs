@@ -0,0 +1,191 @@
+//! NPC Memory Commands
+//!
+//! Commands for viewing, editing, and forgetting what an NPC remembers from
+//! earlier conversations, plus the helpers conversation commands use to fold
+//! old messages into memories and remind the NPC of them before replying.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::llm::{ChatMessage, ChatRequest, LLMClient};
+use crate::core::npc_gen::memory::{
+    memory_prompt_section, parse_extraction, pending_messages, select_relevant,
+    summarization_prompt, MemoryKind, DEFAULT_IMPORTANCE, MEMORIES_PER_PROMPT,
+};
+use crate::database::{ConversationMessage, NpcMemoryRecord, NpcOps, NpcRecord};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// System prompt section with the memories most relevant to `query`, if the
+/// NPC remembers anything
+pub(crate) async fn memory_context(state: &AppState, npc_id: &str, query: &str) -> Option<String> {
+    let memories = state.database.list_npc_memories(npc_id).await
+        .map_err(|e| log::warn!("Failed to load memories for NPC {}: {}", npc_id, e))
+        .ok()?;
+    memory_prompt_section(&select_relevant(&memories, query, MEMORIES_PER_PROMPT))
+}
+
+/// Fold the conversation's unsummarized messages into a summary and notable
+/// facts. Without `force`, nothing happens until enough messages pile up.
+pub(crate) async fn remember_conversation(
+    state: &AppState,
+    npc: &NpcRecord,
+    force: bool,
+) -> Result<Vec<NpcMemoryRecord>, String> {
+    let Some(conv) = state.database.get_npc_conversation(&npc.id).await.map_err(|e| e.to_string())? else {
+        return Ok(Vec::new());
+    };
+    let messages: Vec<ConversationMessage> = serde_json::from_str(&conv.messages_json).unwrap_or_default();
+    let Some(pending) = pending_messages(&messages, conv.summarized_count as usize, force) else {
+        return Ok(Vec::new());
+    };
+    let summarized_count = messages.len();
+    let last_message_id = pending.last().map(|m| m.id.clone());
+
+    let config = state.llm_config.read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .ok_or("LLM not configured")?;
+    let client = LLMClient::new(config);
+    let req = ChatRequest {
+        messages: vec![ChatMessage::user(summarization_prompt(&npc.name, pending))],
+        system_prompt: Some("You summarize tabletop roleplaying conversations. Respond only with JSON.".to_string()),
+        temperature: Some(0.3),
+        max_tokens: Some(600),
+        provider: None,
+        tools: None,
+        tool_choice: None,
+    };
+    let resp = client.chat(req).await.map_err(|e| e.to_string())?;
+    let extraction = parse_extraction(&resp.content)
+        .ok_or_else(|| "Could not parse conversation summary".to_string())?;
+
+    let campaign_id = conv.campaign_id.clone();
+    let mut memories = Vec::new();
+    if !extraction.summary.is_empty() {
+        memories.push(NpcMemoryRecord::new(
+            npc.id.clone(),
+            campaign_id.clone(),
+            MemoryKind::Summary.as_str(),
+            extraction.summary,
+            DEFAULT_IMPORTANCE,
+        ));
+    }
+    for fact in extraction.facts {
+        memories.push(NpcMemoryRecord::new(
+            npc.id.clone(),
+            campaign_id.clone(),
+            MemoryKind::Fact.as_str(),
+            fact.fact,
+            fact.importance,
+        ));
+    }
+    for memory in &mut memories {
+        memory.source_message_id = last_message_id.clone();
+        state.database.save_npc_memory(memory).await.map_err(|e| e.to_string())?;
+    }
+
+    // Re-read so messages added while the LLM was summarizing are kept
+    if let Some(mut latest) = state.database.get_npc_conversation(&npc.id).await.map_err(|e| e.to_string())? {
+        latest.summarized_count = latest.summarized_count.max(summarized_count as u32);
+        latest.updated_at = chrono::Utc::now().to_rfc3339();
+        state.database.save_npc_conversation(&latest).await.map_err(|e| e.to_string())?;
+    }
+
+    Ok(memories)
+}
+
+// ============================================================================
+// NPC Memory Commands
+// ============================================================================
+
+/// List everything an NPC remembers, oldest first
+#[tauri::command]
+pub async fn list_npc_memories(
+    npc_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<NpcMemoryRecord>, String> {
+    state.database.list_npc_memories(&npc_id).await.map_err(|e| e.to_string())
+}
+
+/// Give an NPC a memory by hand
+///
+/// # Arguments
+/// * `kind` - "fact" (default) or "summary"
+/// * `importance` - 1 (trivia) to 5 (life-changing), default 3
+#[tauri::command]
+pub async fn add_npc_memory(
+    npc_id: String,
+    content: String,
+    kind: Option<String>,
+    importance: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<NpcMemoryRecord, String> {
+    let npc = state.database.get_npc(&npc_id).await.map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    let kind = kind.as_deref().map(MemoryKind::parse).unwrap_or(MemoryKind::Fact);
+    let memory = NpcMemoryRecord::new(
+        npc.id,
+        npc.campaign_id.unwrap_or_default(),
+        kind.as_str(),
+        content,
+        importance.unwrap_or(DEFAULT_IMPORTANCE),
+    );
+    state.database.save_npc_memory(&memory).await.map_err(|e| e.to_string())?;
+    Ok(memory)
+}
+
+/// Edit the wording or importance of a memory
+#[tauri::command]
+pub async fn update_npc_memory(
+    memory_id: String,
+    content: Option<String>,
+    importance: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<NpcMemoryRecord, String> {
+    let mut memory = state.database.get_npc_memory(&memory_id).await.map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Memory not found: {}", memory_id))?;
+    if let Some(content) = content {
+        memory.content = content;
+    }
+    if let Some(importance) = importance {
+        memory.importance = importance.clamp(1, 5);
+    }
+    memory.updated_at = chrono::Utc::now().to_rfc3339();
+    state.database.save_npc_memory(&memory).await.map_err(|e| e.to_string())?;
+    Ok(memory)
+}
+
+/// Make an NPC forget a single memory
+#[tauri::command]
+pub async fn forget_npc_memory(
+    memory_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.database.delete_npc_memory(&memory_id).await.map_err(|e| e.to_string())
+}
+
+/// Make an NPC forget everything it remembers. Already summarized messages
+/// stay summarized, so old conversations are not remembered again.
+/// Returns how many memories were removed.
+#[tauri::command]
+pub async fn forget_all_npc_memories(
+    npc_id: String,
+    state: State<'_, AppState>,
+) -> Result<u64, String> {
+    state.database.clear_npc_memories(&npc_id).await.map_err(|e| e.to_string())
+}
+
+/// Summarize the NPC's conversation into memories now, instead of waiting
+/// for enough messages to pile up
+#[tauri::command]
+pub async fn summarize_npc_conversation(
+    npc_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<NpcMemoryRecord>, String> {
+    let npc = state.database.get_npc(&npc_id).await.map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    remember_conversation(&state, &npc, true).await
+}
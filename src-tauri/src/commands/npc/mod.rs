@@ -1,10 +1,13 @@
 //! NPC Commands Module
 //!
-//! Commands for NPC management, conversations, vocabulary, naming, dialects, and indexing.
+//! Commands for NPC management, conversations, memories, dispositions, vocabulary,
+//! naming, dialects, and indexing.
 
 pub mod generation;
 pub mod crud;
 pub mod conversations;
+pub mod memory;
+pub mod dispositions;
 pub mod vocabulary;
 pub mod naming;
 pub mod dialects;
@@ -14,6 +17,8 @@ pub mod indexes;
 pub use generation::*;
 pub use crud::*;
 pub use conversations::*;
+pub use memory::*;
+pub use dispositions::*;
 pub use vocabulary::*;
 pub use naming::*;
 pub use dialects::*;
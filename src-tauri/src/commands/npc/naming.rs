@@ -1,14 +1,51 @@
 //! NPC Naming Commands
 //!
-//! Commands for loading and using cultural naming rules.
+//! Commands for loading and using cultural naming rules, generating names
+//! from culture name banks, and training new banks from name lists.
 
 use std::path::PathBuf;
+use std::sync::{Arc, Once};
 
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::npc_gen::name_banks::{trained_bank_path, BUILTIN_CULTURES};
 use crate::core::npc_gen::{
-    CulturalNamingRules, NameStructure,
-    load_yaml_file, get_names_dir,
+    CulturalNamingRules, Gender, NameBankKind, NameBankRegistry, NameBankSummary, NameStructure,
+    TrainedNameBank, load_yaml_file, get_names_dir,
 };
 
+/// Most names one `generate_names` call returns
+const MAX_NAMES_PER_CALL: usize = 100;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding the name banks
+#[derive(Default)]
+pub struct NameBankState {
+    registry: Arc<NameBankRegistry>,
+    loaded: Once,
+}
+
+impl NameBankState {
+    /// The registry, with custom and trained banks from the names directory
+    /// loaded on first use
+    pub fn registry(&self) -> Arc<NameBankRegistry> {
+        self.loaded.call_once(|| {
+            let count = self.registry.load_dir(&get_names_dir());
+            log::info!("Loaded {} custom name banks", count);
+        });
+        self.registry.clone()
+    }
+}
+
+/// The campaign's default name culture, if it has one
+pub(crate) fn campaign_name_culture(state: &AppState, campaign_id: Option<&str>) -> Option<String> {
+    state.campaign_manager.get_campaign(campaign_id?)?.settings.name_culture
+}
+
 // ============================================================================
 // Path Validation
 // ============================================================================
@@ -80,3 +117,117 @@ pub fn validate_naming_rules(
 ) -> Result<(), String> {
     rules.validate().map_err(|e| e.to_string())
 }
+
+/// List the built-in, custom, and trained name banks
+#[tauri::command]
+pub fn list_name_banks(name_banks: State<'_, NameBankState>) -> Vec<NameBankSummary> {
+    name_banks.registry().list()
+}
+
+/// Generate names from a culture's bank
+///
+/// # Arguments
+/// * `culture_id` - Bank to draw from; defaults to the campaign's name culture
+/// * `gender` - "male", "female", or "neutral"; mixed when omitted
+/// * `count` - Number of names (default 10, at most 100)
+#[tauri::command]
+pub fn generate_names(
+    culture_id: Option<String>,
+    campaign_id: Option<String>,
+    gender: Option<String>,
+    count: Option<usize>,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<Vec<String>, String> {
+    let culture_id = culture_id
+        .or_else(|| campaign_name_culture(&state, campaign_id.as_deref()))
+        .ok_or("No culture given and the campaign has no default name culture")?;
+    let gender = gender.as_deref().map(Gender::from_str);
+    let registry = name_banks.registry();
+    let mut rng = rand::thread_rng();
+
+    (0..count.unwrap_or(10).clamp(1, MAX_NAMES_PER_CALL))
+        .map(|_| {
+            let gender = gender.unwrap_or_else(|| {
+                if rand::Rng::gen_bool(&mut rng, 0.5) { Gender::Male } else { Gender::Female }
+            });
+            registry.generate(&culture_id, gender, &mut rng).map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Train a Markov name bank from a list of names and save it to the names
+/// directory. Retraining an existing trained bank replaces it.
+#[tauri::command]
+pub async fn train_name_bank(
+    bank: TrainedNameBank,
+    name_banks: State<'_, NameBankState>,
+) -> Result<NameBankSummary, String> {
+    let culture_id = bank.culture_id.trim().to_string();
+    if culture_id.is_empty()
+        || !culture_id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    {
+        return Err("Culture ID must use only lowercase letters, digits, '_' and '-'".to_string());
+    }
+    if BUILTIN_CULTURES.contains(&culture_id.as_str()) {
+        return Err(format!("'{}' is a built-in name bank", culture_id));
+    }
+    let bank = TrainedNameBank { culture_id, ..bank };
+
+    let summary = name_banks.registry().add_trained(bank.clone()).map_err(|e| e.to_string())?;
+
+    let path = trained_bank_path(&get_names_dir(), &bank.culture_id);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| e.to_string())?;
+    }
+    let yaml = serde_yaml_ng::to_string(&bank).map_err(|e| e.to_string())?;
+    tokio::fs::write(&path, yaml).await.map_err(|e| e.to_string())?;
+
+    Ok(summary)
+}
+
+/// Delete a trained name bank. Built-in banks cannot be deleted, and custom
+/// banks are removed by deleting their file from the names directory.
+#[tauri::command]
+pub async fn delete_name_bank(
+    culture_id: String,
+    name_banks: State<'_, NameBankState>,
+) -> Result<(), String> {
+    let registry = name_banks.registry();
+    let kind = registry.list().into_iter()
+        .find(|b| b.culture_id == culture_id)
+        .map(|b| b.kind)
+        .ok_or_else(|| format!("Name bank not found: {}", culture_id))?;
+    if kind == NameBankKind::Custom {
+        return Err(format!(
+            "Name bank '{}' comes from a naming rules file; remove it from the names directory instead",
+            culture_id
+        ));
+    }
+    registry.remove(&culture_id).map_err(|e| e.to_string())?;
+
+    let path = trained_bank_path(&get_names_dir(), &culture_id);
+    if path.exists() {
+        tokio::fs::remove_file(&path).await.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Set or clear the name culture a campaign's generated NPCs default to
+#[tauri::command]
+pub fn set_campaign_name_culture(
+    campaign_id: String,
+    culture_id: Option<String>,
+    state: State<'_, AppState>,
+    name_banks: State<'_, NameBankState>,
+) -> Result<(), String> {
+    if let Some(culture_id) = &culture_id {
+        if !name_banks.registry().contains(culture_id) {
+            return Err(format!("Name bank not found: {}", culture_id));
+        }
+    }
+    let mut campaign = state.campaign_manager.get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    campaign.settings.name_culture = culture_id;
+    state.campaign_manager.update_campaign(campaign, false).map_err(|e| e.to_string())
+}
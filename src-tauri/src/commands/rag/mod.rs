@@ -9,6 +9,7 @@
 //! - Configuration of LLM providers (OpenAI, Anthropic, Azure, Mistral, vLLM)
 //! - Non-streaming RAG queries with source citations
 //! - Streaming RAG queries with real-time chunk emission
+//! - Cited rules answers scoped to the campaign's rulebooks (`ask_rules`)
 //!
 //! # Architecture
 //!
@@ -29,7 +30,9 @@
 //! ```
 
 pub mod commands;
+pub mod rules;
 pub mod types;
 
 pub use commands::*;
+pub use rules::*;
 pub use types::*;
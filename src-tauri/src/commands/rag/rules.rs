@@ -0,0 +1,134 @@
+//! Rules Q&A Commands
+//!
+//! Answers rules questions from the campaign system's rulebooks only, with
+//! every claim footnoted to a retrieved passage. Unlike general chat, a
+//! question the rulebooks don't clearly answer is refused.
+
+use tauri::State;
+
+use crate::commands::search::{
+    load_library_documents, retrieve_passages, source_filter, SearchSettingsState,
+};
+use crate::commands::AppState;
+use crate::core::character_gen::GameSystem;
+use crate::core::llm::router::{ChatMessage, ChatRequest};
+use crate::core::rag::rules_qa::{
+    build_rules_prompt, is_rulebook, parse_rules_answer, retrieval_refusal, RulesAnswer,
+    RulesPassage, DEFAULT_RULES_PASSAGES, RULES_QA_SYSTEM_PROMPT,
+};
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Answer a rules question from the campaign system's rulebooks.
+///
+/// Retrieval covers library documents that hold rules and are tagged with
+/// the campaign's game system. The answer cites passages by footnote with
+/// source and page; weak matches, an unsure model, or uncited answers come
+/// back as a refusal with its reason instead. Passages are re-ranked by the
+/// cross-encoder when that is enabled in the search settings.
+///
+/// # Arguments
+/// * `campaign_id` - Campaign whose game system scopes the rulebooks
+/// * `question` - The rules question
+/// * `limit` - Passages given to the model (default 6)
+#[tauri::command]
+pub async fn ask_rules(
+    campaign_id: String,
+    question: String,
+    limit: Option<usize>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    search_settings: State<'_, SearchSettingsState>,
+) -> Result<RulesAnswer, String> {
+    let question = question.trim().to_string();
+    if question.is_empty() {
+        return Err("Question cannot be empty".to_string());
+    }
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let system = GameSystem::from_str(&campaign.system);
+    let system_name = system.display_name().to_string();
+
+    let meili = state.embedded_search.clone_inner();
+    let rulebooks: Vec<_> = load_library_documents(meili.clone())
+        .await?
+        .into_iter()
+        .filter(|doc| {
+            is_rulebook(doc) && doc.game_system.as_deref().is_some_and(|s| GameSystem::from_str(s) == system)
+        })
+        .collect();
+    if rulebooks.is_empty() {
+        return Ok(RulesAnswer::refused(
+            &question,
+            &system_name,
+            format!("No {} rulebooks are in the library; tag rulebooks with their game system", system_name),
+            0.0,
+        ));
+    }
+
+    let mut indexes: Vec<String> = rulebooks.iter().map(|doc| doc.content_index.clone()).collect();
+    indexes.sort();
+    indexes.dedup();
+    let sources: Vec<String> = rulebooks
+        .iter()
+        .map(|doc| doc.file_path.clone().unwrap_or_else(|| doc.name.clone()))
+        .collect();
+    let from_rulebook = |source: &str, chunk_id: Option<&str>| {
+        rulebooks.iter().any(|doc| {
+            doc.file_path.as_deref() == Some(source)
+                || doc.name == source
+                || chunk_id.is_some_and(|id| id.starts_with(&doc.id))
+        })
+    };
+
+    let limit = limit.unwrap_or(DEFAULT_RULES_PASSAGES).max(1);
+    let (hits, retrieval_score) = retrieve_passages(
+        meili,
+        indexes,
+        &question,
+        source_filter(&sources),
+        from_rulebook,
+        limit,
+        search_settings.active_reranker(&app_handle, limit),
+    )
+    .await?;
+    let passages: Vec<RulesPassage> = hits
+        .into_iter()
+        .enumerate()
+        .map(|(i, hit)| RulesPassage {
+            chunk_id: hit.document_id.unwrap_or_else(|| format!("passage-{}", i + 1)),
+            source: hit.source,
+            page_number: hit.page_number,
+            content: hit.content,
+        })
+        .collect();
+    if let Some(reason) = retrieval_refusal(&passages, retrieval_score) {
+        return Ok(RulesAnswer::refused(&question, &system_name, reason, retrieval_score));
+    }
+
+    let request = ChatRequest::new(vec![ChatMessage::user(build_rules_prompt(
+        &question,
+        &system_name,
+        &passages,
+    ))])
+    .with_system(RULES_QA_SYSTEM_PROMPT.to_string())
+    .with_temperature(0.1)
+    .with_max_tokens(1000);
+    let response = {
+        let router = state.llm_router.read().await;
+        router.chat(request).await.map_err(|e| e.to_string())?
+    };
+
+    let answer = parse_rules_answer(&response.content, &question, &system_name, &passages, retrieval_score);
+    log::info!(
+        "[ask_rules] {:?} with {} footnote(s) from {} passage(s)",
+        answer.status,
+        answer.footnotes.len(),
+        passages.len()
+    );
+    Ok(answer)
+}
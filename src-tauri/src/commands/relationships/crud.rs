@@ -112,7 +112,7 @@ fn parse_entity_type(s: &str) -> EntityType {
     }
 }
 
-fn parse_relationship_type(s: &str) -> RelationshipType {
+pub(crate) fn parse_relationship_type(s: &str) -> RelationshipType {
     match s.to_lowercase().as_str() {
         "ally" => RelationshipType::Ally,
         "enemy" => RelationshipType::Enemy,
@@ -148,7 +148,7 @@ fn parse_relationship_type(s: &str) -> RelationshipType {
     }
 }
 
-fn parse_relationship_strength(s: &str) -> RelationshipStrength {
+pub(crate) fn parse_relationship_strength(s: &str) -> RelationshipStrength {
     match s.to_lowercase().as_str() {
         "weak" => RelationshipStrength::Weak,
         "moderate" => RelationshipStrength::Moderate,
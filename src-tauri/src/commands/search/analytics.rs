@@ -6,6 +6,7 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::commands::AppState;
+use crate::database::Database;
 use crate::core::search_analytics::{
     SearchAnalytics, AnalyticsSummary, PopularQuery, CacheStats,
     ResultSelection, SearchRecord, DbSearchAnalytics,
@@ -21,14 +22,40 @@ pub struct SearchAnalyticsState {
     pub analytics: SearchAnalytics,
 }
 
+/// Record a search run by a search command, in memory now and in the
+/// database in the background. Returns the ID result clicks are reported
+/// against.
+pub(crate) fn record_search(
+    analytics: &SearchAnalyticsState,
+    database: &Database,
+    record: SearchRecord,
+) -> String {
+    let search_id = record.id.clone();
+    analytics.analytics.record(record.clone());
+
+    let db_analytics = DbSearchAnalytics::new(Arc::new(database.clone()));
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = db_analytics.record(record).await {
+            log::warn!("{}", e);
+        }
+    });
+    search_id
+}
+
 // ============================================================================
 // In-Memory Analytics (Fast, Session-Only)
 // ============================================================================
 
-/// Get search analytics summary for a time period (in-memory)
+/// Get search analytics summary for a time period (in-memory), with volume,
+/// zero results, click-through, and latency in buckets of `bucket_minutes`
+/// (default: hourly up to two days, daily beyond)
 #[tauri::command]
-pub fn get_search_analytics(hours: i64, state: State<'_, SearchAnalyticsState>) -> AnalyticsSummary {
-    state.analytics.get_summary(hours)
+pub fn get_search_analytics(
+    hours: i64,
+    bucket_minutes: Option<i64>,
+    state: State<'_, SearchAnalyticsState>,
+) -> AnalyticsSummary {
+    state.analytics.get_summary_with_buckets(hours, bucket_minutes)
 }
 
 /// Get popular queries with detailed stats (in-memory)
@@ -86,15 +113,17 @@ pub fn record_search_selection(
 // Database-Backed Analytics (Persistent, Full History)
 // ============================================================================
 
-/// Get search analytics summary from database
+/// Get search analytics summary from database, with trends bucketed as for
+/// `get_search_analytics`
 #[tauri::command]
 pub async fn get_search_analytics_db(
     hours: i64,
+    bucket_minutes: Option<i64>,
     app_state: State<'_, AppState>,
 ) -> Result<AnalyticsSummary, String> {
     let db = Arc::new(app_state.database.clone());
     let db_analytics = DbSearchAnalytics::new(db);
-    db_analytics.get_summary(hours).await
+    db_analytics.get_summary_with_buckets(hours, bucket_minutes).await
 }
 
 /// Get popular queries from database
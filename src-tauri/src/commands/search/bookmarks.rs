@@ -0,0 +1,173 @@
+//! Bookmark Commands
+//!
+//! Pin search results and chunks, optionally to a campaign or session, and
+//! serve the compact quick reference the in-session sidebar shows.
+
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use tauri::{Manager, State};
+
+use crate::commands::AppState;
+use crate::core::search::bookmarks::{Bookmark, BookmarkGroup, BookmarkLibrary, NewBookmark, QuickReferenceItem};
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_bookmarks_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    app_handle
+        .path()
+        .app_data_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("bookmarks.json")
+}
+
+// ============================================================================
+// Bookmark State
+// ============================================================================
+
+/// Managed state holding the bookmarks
+pub struct BookmarkState {
+    library: RwLock<BookmarkLibrary>,
+    path: PathBuf,
+}
+
+impl BookmarkState {
+    /// State with the bookmarks saved on disk, or none
+    pub fn load(app_handle: &tauri::AppHandle) -> Self {
+        let path = get_bookmarks_path(app_handle);
+        let library = BookmarkLibrary::load(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load bookmarks: {}", e);
+            BookmarkLibrary::default()
+        });
+        Self { library: RwLock::new(library), path }
+    }
+
+    /// Apply a change to the library and save it
+    fn update<T>(
+        &self,
+        change: impl FnOnce(&mut BookmarkLibrary) -> crate::core::search::bookmarks::Result<T>,
+    ) -> Result<T, String> {
+        let mut library = self.library.write().unwrap_or_else(|e| e.into_inner());
+        let value = change(&mut library).map_err(|e| e.to_string())?;
+        library.save(&self.path).map_err(|e| e.to_string())?;
+        Ok(value)
+    }
+
+    /// Remove a deleted campaign's bookmarks
+    pub fn delete_campaign_bookmarks(&self, campaign_id: &str) -> Result<(), String> {
+        self.update(|library| {
+            library.delete_campaign_bookmarks(campaign_id);
+            Ok(())
+        })
+    }
+}
+
+// ============================================================================
+// Bookmark Commands
+// ============================================================================
+
+/// List bookmarks grouped by campaign and session.
+///
+/// # Arguments
+/// * `campaign_id` - Only this campaign's bookmarks (default: all)
+#[tauri::command]
+pub async fn list_bookmarks(
+    campaign_id: Option<String>,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<Vec<BookmarkGroup>, String> {
+    Ok(bookmarks
+        .library
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .grouped(campaign_id.as_deref()))
+}
+
+/// Pin a search result or chunk.
+///
+/// A session bookmark is filed under the session's campaign. Pinning a
+/// passage already pinned in the same place returns that bookmark.
+#[tauri::command]
+pub async fn create_bookmark(
+    mut bookmark: NewBookmark,
+    state: State<'_, AppState>,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<Bookmark, String> {
+    let (campaign_id, session_id) = resolve_association(bookmark.campaign_id, bookmark.session_id, &state)?;
+    bookmark.campaign_id = campaign_id;
+    bookmark.session_id = session_id;
+    let bookmark = Bookmark::new(bookmark).map_err(|e| e.to_string())?;
+    bookmarks.update(|library| Ok(library.create(bookmark)))
+}
+
+/// Replace a bookmark's label and its campaign and session
+#[tauri::command]
+pub async fn update_bookmark(
+    id: String,
+    label: Option<String>,
+    campaign_id: Option<String>,
+    session_id: Option<String>,
+    state: State<'_, AppState>,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<Bookmark, String> {
+    let (campaign_id, session_id) = resolve_association(campaign_id, session_id, &state)?;
+    bookmarks.update(|library| library.update(&id, label, campaign_id, session_id))
+}
+
+/// Delete a bookmark
+#[tauri::command]
+pub async fn delete_bookmark(
+    id: String,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<(), String> {
+    bookmarks.update(|library| library.delete(&id)).map(|_| ())
+}
+
+/// The in-session sidebar: the session's bookmarks, then its campaign's
+#[tauri::command]
+pub async fn get_session_quick_reference(
+    session_id: String,
+    state: State<'_, AppState>,
+    bookmarks: State<'_, BookmarkState>,
+) -> Result<Vec<QuickReferenceItem>, String> {
+    let session = state
+        .session_manager
+        .get_session(&session_id)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+    Ok(bookmarks
+        .library
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .quick_reference(&session.campaign_id, &session_id))
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Check the campaign and session exist, taking the campaign from the
+/// session when only the session is given
+fn resolve_association(
+    campaign_id: Option<String>,
+    session_id: Option<String>,
+    state: &AppState,
+) -> Result<(Option<String>, Option<String>), String> {
+    if let Some(session_id) = session_id {
+        let session = state
+            .session_manager
+            .get_session(&session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        if campaign_id.as_ref().is_some_and(|id| *id != session.campaign_id) {
+            return Err(format!("Session {} is not in campaign {}", session_id, campaign_id.unwrap_or_default()));
+        }
+        return Ok((Some(session.campaign_id), Some(session_id)));
+    }
+    if let Some(campaign_id) = &campaign_id {
+        state
+            .campaign_manager
+            .get_campaign(campaign_id)
+            .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    }
+    Ok((campaign_id, None))
+}
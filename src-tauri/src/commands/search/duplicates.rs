@@ -0,0 +1,104 @@
+//! Duplicate Content Commands
+//!
+//! Maintenance scan for near-duplicate campaign notes and ingested chunks.
+//! Chunks are compared by their stored embeddings; notes are embedded for
+//! the scan. The report only suggests merges and deletions, it changes
+//! nothing.
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::search::duplicates::{
+    check_threshold, find_duplicate_clusters, DuplicateCandidate, DuplicateKind, DuplicateReport,
+};
+use crate::core::search::{create_provider, EmbeddingConfig};
+use crate::core::storage::list_embedded_chunks;
+
+/// Most chunks compared in one scan; every pair is compared, so this keeps
+/// a scan to a few seconds
+const MAX_SCANNED_CHUNKS: usize = 2000;
+
+// ============================================================================
+// Duplicate Scan Commands
+// ============================================================================
+
+/// Find near-duplicate campaign notes and ingested chunks.
+///
+/// # Arguments
+/// * `campaign_id` - Campaign whose notes to scan (default: no notes)
+/// * `include_chunks` - Also scan ingested chunks (default: true)
+/// * `threshold` - Cosine similarity counted as a duplicate, 0.5 to 1.0 (default: 0.92)
+/// * `embedding_config` - Provider used to embed the notes (default: local Ollama)
+#[tauri::command]
+pub async fn find_duplicate_content(
+    campaign_id: Option<String>,
+    include_chunks: Option<bool>,
+    threshold: Option<f32>,
+    embedding_config: Option<EmbeddingConfig>,
+    state: State<'_, AppState>,
+) -> Result<DuplicateReport, String> {
+    let threshold = check_threshold(threshold).map_err(|e| e.to_string())?;
+    let mut report = DuplicateReport { threshold, ..Default::default() };
+    let mut candidates = Vec::new();
+
+    if let Some(campaign_id) = campaign_id {
+        let notes = state.campaign_manager.get_notes(&campaign_id);
+        let notes: Vec<_> = notes.into_iter().filter(|n| !n.content.trim().is_empty()).collect();
+        if !notes.is_empty() {
+            let provider = create_provider(&embedding_config.unwrap_or_default()).map_err(|e| e.to_string())?;
+            let texts: Vec<&str> = notes.iter().map(|n| n.content.as_str()).collect();
+            let embeddings = provider
+                .embed_batch(&texts)
+                .await
+                .map_err(|e| format!("Failed to embed campaign notes: {}", e))?;
+            report.notes_scanned = notes.len();
+            candidates.extend(notes.into_iter().zip(embeddings).map(|(note, embedding)| DuplicateCandidate {
+                id: note.id,
+                kind: DuplicateKind::Note,
+                content: note.content,
+                source: None,
+                page_number: None,
+                embedding,
+            }));
+        }
+    }
+
+    if include_chunks.unwrap_or(true) {
+        match state.surreal_storage.as_ref() {
+            Some(storage) => {
+                let chunks = list_embedded_chunks(storage.db(), MAX_SCANNED_CHUNKS, None)
+                    .await
+                    .map_err(|e| format!("Failed to read chunk embeddings: {}", e))?;
+                for chunk in chunks {
+                    let Some(embedding) = chunk.embedding else {
+                        report.skipped += 1;
+                        continue;
+                    };
+                    report.chunks_scanned += 1;
+                    candidates.push(DuplicateCandidate {
+                        id: chunk.id,
+                        kind: DuplicateKind::Chunk,
+                        content: chunk.content,
+                        source: Some(chunk.source).filter(|s| !s.is_empty()),
+                        page_number: chunk.page_number,
+                        embedding,
+                    });
+                }
+            }
+            None => log::warn!("[find_duplicate_content] SurrealDB storage not initialized; skipping chunks"),
+        }
+    }
+
+    report.clusters = tokio::task::spawn_blocking(move || find_duplicate_clusters(&candidates, threshold))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+    log::info!(
+        "[find_duplicate_content] {} notes and {} chunks scanned, {} duplicates in {} clusters",
+        report.notes_scanned,
+        report.chunks_scanned,
+        report.duplicate_count(),
+        report.clusters.len()
+    );
+    Ok(report)
+}
@@ -0,0 +1,94 @@
+//! Search Result Export Commands
+//!
+//! Capture prep research in one step: the search results the GM picked are
+//! saved as a campaign note or a text handout, quoted with their sources.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::{AppState, HandoutState};
+use crate::core::campaign::handouts::{Handout, HandoutContent};
+use crate::core::campaign_manager::SessionNote;
+use crate::core::search::export::{export_tags, format_export, ExportTarget, ExportedResult};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// The note or handout an export created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "target", rename_all = "snake_case")]
+pub enum ExportedItem {
+    Note { note: SessionNote },
+    Handout { handout: Handout },
+}
+
+// ============================================================================
+// Export Commands
+// ============================================================================
+
+/// Save selected search results as a campaign note or handout.
+///
+/// Each passage is quoted under its source and page, numbered, and followed
+/// by the list of sources. Handouts start hidden from players.
+///
+/// # Arguments
+/// * `results` - The selected results, in the order to show them
+/// * `target` - `"note"` or `"handout"`
+/// * `session_id` - Session the research is for (default: campaign-wide)
+/// * `tags` - Added to the `research` tag
+#[tauri::command]
+pub fn export_search_results(
+    campaign_id: String,
+    title: String,
+    results: Vec<ExportedResult>,
+    target: ExportTarget,
+    session_id: Option<String>,
+    tags: Option<Vec<String>>,
+    state: State<'_, AppState>,
+    handouts: State<'_, HandoutState>,
+) -> Result<ExportedItem, String> {
+    state
+        .campaign_manager
+        .get_campaign(&campaign_id)
+        .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+    let session = match session_id {
+        Some(session_id) => {
+            let session = state
+                .session_manager
+                .get_session(&session_id)
+                .ok_or_else(|| format!("Session not found: {}", session_id))?;
+            if session.campaign_id != campaign_id {
+                return Err(format!("Session {} is not part of this campaign", session_id));
+            }
+            Some(session)
+        }
+        None => None,
+    };
+
+    let document = format_export(&title, &results).map_err(|e| e.to_string())?;
+    let tags = export_tags(tags.unwrap_or_default());
+
+    match target {
+        ExportTarget::Note => {
+            let note = state.campaign_manager.add_note(
+                &campaign_id,
+                &document.body,
+                tags,
+                session.map(|s| s.session_number),
+            );
+            Ok(ExportedItem::Note { note })
+        }
+        ExportTarget::Handout => {
+            let mut handout =
+                Handout::new(&campaign_id, &document.title, HandoutContent::Text { text: document.body });
+            if let Some(session) = session {
+                handout = handout.for_session(&session.id);
+            }
+            handout.gm_notes = format!("Sources: {}", document.citations.join("; "));
+            handout.tags = tags;
+            let handout = handouts.manager.create_handout(handout).map_err(|e| e.to_string())?;
+            Ok(ExportedItem::Handout { handout })
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Index Migration Commands
+//!
+//! Bring the versioned Meilisearch indexes up to the schemas this app
+//! version defines, without taking them offline. Migrations run in the
+//! background at startup and can be re-run on demand; their status is kept
+//! in managed state and emitted as `index-migration` events.
+
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use meilisearch_lib::{MeilisearchLib, Settings, Unchecked};
+use tauri::{Emitter, Manager, State};
+
+use crate::commands::AppState;
+use crate::core::campaign::get_index_configs;
+use crate::core::search::index_migration::{
+    run_index_migrations, IndexDefinition, IndexMigrationBackend, IndexMigrationReport, IndexVersions,
+};
+use crate::core::search::{TASK_TIMEOUT_LONG_SECS, TASK_TIMEOUT_SHORT_SECS};
+
+/// Event carrying the `IndexMigrationReport` after each step
+const INDEX_MIGRATION_EVENT: &str = "index-migration";
+
+// ============================================================================
+// Migration State
+// ============================================================================
+
+/// Managed state holding the latest migration run's report
+#[derive(Default)]
+pub struct IndexMigrationState {
+    report: RwLock<IndexMigrationReport>,
+}
+
+impl IndexMigrationState {
+    fn report(&self) -> IndexMigrationReport {
+        self.report.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set_report(&self, report: &IndexMigrationReport) {
+        *self.report.write().unwrap_or_else(|e| e.into_inner()) = report.clone();
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn get_versions_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    app_handle
+        .path()
+        .app_data_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("index_versions.json")
+}
+
+/// The versioned indexes this app version defines
+fn index_definitions() -> Result<Vec<IndexDefinition>, String> {
+    get_index_configs()
+        .into_iter()
+        .map(|config| {
+            Ok(IndexDefinition {
+                uid: config.name.to_string(),
+                primary_key: Some(config.primary_key.to_string()),
+                version: config.version,
+                settings: serde_json::to_value(&config.settings)
+                    .map_err(|e| format!("Failed to serialize settings of '{}': {}", config.name, e))?,
+            })
+        })
+        .collect()
+}
+
+/// Migrate every versioned index, publishing the report as it changes
+fn migrate_all(meili: &MeilisearchLib, app_handle: &tauri::AppHandle) -> Result<IndexMigrationReport, String> {
+    let path = get_versions_path(app_handle);
+    let mut versions = IndexVersions::load(&path).map_err(|e| e.to_string())?;
+    let definitions = index_definitions()?;
+    let state = app_handle.state::<IndexMigrationState>();
+
+    let report = run_index_migrations(&MeiliBackend(meili), &definitions, &mut versions, |report| {
+        state.set_report(report);
+        let _ = app_handle.emit(INDEX_MIGRATION_EVENT, report);
+    });
+    versions.save(&path).map_err(|e| e.to_string())?;
+    log::info!("{}", report.summary());
+    Ok(report)
+}
+
+/// Run the migrations in the background; searches keep using the current
+/// indexes until each new one is swapped in
+pub fn start_index_migrations(app_handle: &tauri::AppHandle) {
+    let app_handle = app_handle.clone();
+    let meili = app_handle.state::<AppState>().embedded_search.clone_inner();
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = migrate_all(&meili, &app_handle) {
+            log::error!("Index migrations failed: {}", e);
+        }
+    });
+}
+
+// ============================================================================
+// Meilisearch Backend
+// ============================================================================
+
+/// Migration operations on the embedded Meilisearch, each waited on
+struct MeiliBackend<'a>(&'a MeilisearchLib);
+
+impl MeiliBackend<'_> {
+    fn wait(&self, task_uid: u32, timeout_secs: u64, what: &str) -> Result<(), String> {
+        self.0
+            .wait_for_task(task_uid, Some(Duration::from_secs(timeout_secs)))
+            .map(|_| ())
+            .map_err(|e| format!("{} failed: {}", what, e))
+    }
+}
+
+impl IndexMigrationBackend for MeiliBackend<'_> {
+    fn index_exists(&self, uid: &str) -> Result<bool, String> {
+        self.0.index_exists(uid).map_err(|e| e.to_string())
+    }
+
+    fn create_index(&self, uid: &str, primary_key: Option<String>) -> Result<(), String> {
+        let task = self
+            .0
+            .create_index(uid, primary_key)
+            .map_err(|e| format!("Failed to create '{}': {}", uid, e))?;
+        self.wait(task.uid, TASK_TIMEOUT_SHORT_SECS, "Creating the index")
+    }
+
+    fn update_settings(&self, uid: &str, settings: &serde_json::Value) -> Result<(), String> {
+        let settings: Settings<Unchecked> =
+            serde_json::from_value(settings.clone()).map_err(|e| format!("Invalid settings: {}", e))?;
+        let task = self
+            .0
+            .update_settings(uid, settings)
+            .map_err(|e| format!("Failed to update settings of '{}': {}", uid, e))?;
+        self.wait(task.uid, TASK_TIMEOUT_LONG_SECS, "Applying settings")
+    }
+
+    fn document_count(&self, uid: &str) -> Result<u64, String> {
+        self.0
+            .index_stats(uid)
+            .map(|stats| stats.number_of_documents)
+            .map_err(|e| format!("Failed to get stats for '{}': {}", uid, e))
+    }
+
+    fn read_documents(&self, uid: &str, offset: usize, limit: usize) -> Result<(u64, Vec<serde_json::Value>), String> {
+        self.0
+            .get_documents(uid, offset, limit)
+            .map_err(|e| format!("Failed to read documents of '{}': {}", uid, e))
+    }
+
+    fn add_documents(
+        &self,
+        uid: &str,
+        documents: Vec<serde_json::Value>,
+        primary_key: Option<String>,
+    ) -> Result<(), String> {
+        let task = self
+            .0
+            .add_documents(uid, documents, primary_key)
+            .map_err(|e| format!("Failed to add documents to '{}': {}", uid, e))?;
+        self.wait(task.uid, TASK_TIMEOUT_LONG_SECS, "Adding documents")
+    }
+
+    fn swap_indexes(&self, a: &str, b: &str) -> Result<(), String> {
+        let task = self
+            .0
+            .swap_indexes(vec![(a.to_string(), b.to_string())])
+            .map_err(|e| format!("Failed to swap '{}' and '{}': {}", a, b, e))?;
+        self.wait(task.uid, TASK_TIMEOUT_SHORT_SECS, "Swapping the indexes")
+    }
+
+    fn delete_index(&self, uid: &str) -> Result<(), String> {
+        match self.0.delete_index(uid) {
+            Ok(task) => self.wait(task.uid, TASK_TIMEOUT_SHORT_SECS, "Deleting the index"),
+            Err(meilisearch_lib::Error::IndexNotFound(_)) => Ok(()),
+            Err(e) => Err(format!("Failed to delete '{}': {}", uid, e)),
+        }
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Status of the latest index migration run
+#[tauri::command]
+pub fn get_index_migration_status(
+    migrations: State<'_, IndexMigrationState>,
+) -> Result<IndexMigrationReport, String> {
+    Ok(migrations.report())
+}
+
+/// Migrate any index whose schema is behind, e.g. after one failed at
+/// startup. Emits `index-migration` events as it goes.
+#[tauri::command]
+pub async fn migrate_indexes(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    migrations: State<'_, IndexMigrationState>,
+) -> Result<IndexMigrationReport, String> {
+    if migrations.report().in_progress() {
+        return Err("Index migrations are already running".to_string());
+    }
+    let meili = state.embedded_search.clone_inner();
+    tokio::task::spawn_blocking(move || migrate_all(&meili, &app_handle))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
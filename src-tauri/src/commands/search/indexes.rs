@@ -0,0 +1,654 @@
+//! Index Maintenance Commands
+//!
+//! List, inspect, rebuild, and optimize the search indexes, covering both the
+//! embedded Meilisearch indexes and the SurrealDB tables with vector indexes.
+//! Rebuilds keep every document, so a corrupted index can be recovered
+//! without deleting the data directory. Progress is emitted as
+//! `index-progress` events. Vector search can also be benchmarked against
+//! exact search to pick the vector index tuning.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use meilisearch_lib::{MeilisearchLib, Settings, Unchecked};
+use tauri::{Emitter, Manager, State};
+
+use crate::commands::AppState;
+use crate::core::search::maintenance::{
+    IndexBackend, IndexProgress, IndexStats, IndexSummary, MaintenanceOperation, MaintenanceResult,
+    MaintenanceStage, RebuildSnapshot, EXPORT_PAGE_SIZE, IMPORT_BATCH_SIZE,
+};
+use crate::core::search::vector_tuning::{
+    benchmark_ef_values, latency_summary, recall, recommend_search_ef, EfBenchmark, VectorBenchmarkReport,
+    VectorTuning,
+};
+use crate::core::search::{TASK_TIMEOUT_LONG_SECS, TASK_TIMEOUT_SHORT_SECS};
+use crate::core::storage::schema::{index_definitions, vector_tables, IndexDefinition};
+use crate::core::storage::{
+    exact_vector_search, sample_chunk_embeddings, vector_search_with_ef, SearchResult, SurrealStorage,
+};
+
+use super::settings::SearchSettingsState;
+
+/// Event carrying `IndexProgress`
+const INDEX_PROGRESS_EVENT: &str = "index-progress";
+
+/// Most Meilisearch indexes listed
+const MAX_LISTED_INDEXES: usize = 500;
+
+/// Vector table the search benchmark runs on
+const BENCHMARK_TABLE: &str = "chunk";
+
+/// Default and most queries per benchmark setting
+const DEFAULT_BENCHMARK_QUERIES: usize = 20;
+const MAX_BENCHMARK_QUERIES: usize = 200;
+
+// ============================================================================
+// Maintenance State
+// ============================================================================
+
+/// Managed state tracking indexes with a rebuild or optimize under way
+#[derive(Default)]
+pub struct IndexMaintenanceState {
+    running: Mutex<HashSet<(IndexBackend, String)>>,
+}
+
+/// Marks an index busy until dropped
+struct MaintenanceGuard<'a> {
+    state: &'a IndexMaintenanceState,
+    key: (IndexBackend, String),
+}
+
+impl IndexMaintenanceState {
+    fn start(&self, backend: IndexBackend, name: &str) -> Result<MaintenanceGuard<'_>, String> {
+        let key = (backend, name.to_string());
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        if !running.insert(key.clone()) {
+            return Err(format!("Index '{}' is already being rebuilt or optimized", name));
+        }
+        Ok(MaintenanceGuard { state: self, key })
+    }
+}
+
+impl Drop for MaintenanceGuard<'_> {
+    fn drop(&mut self) {
+        self.state.running.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.key);
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Where rebuild snapshots are kept until an index is refilled
+fn snapshot_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    app_handle
+        .path()
+        .app_data_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("index_rebuilds")
+}
+
+pub(super) fn get_storage(state: &AppState) -> Result<Arc<SurrealStorage>, String> {
+    state
+        .surreal_storage
+        .as_ref()
+        .cloned()
+        .ok_or_else(|| "SurrealDB storage not initialized".to_string())
+}
+
+fn check_vector_table(table: &str) -> Result<(), String> {
+    if vector_tables().iter().any(|t| t == table) {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a vector table. Vector tables: {}", table, vector_tables().join(", ")))
+    }
+}
+
+/// Emits progress events for one operation on one index
+#[derive(Clone)]
+struct ProgressReporter {
+    app_handle: tauri::AppHandle,
+    index: String,
+    backend: IndexBackend,
+    operation: MaintenanceOperation,
+}
+
+impl ProgressReporter {
+    fn report(&self, stage: MaintenanceStage, fraction: f32, message: impl Into<String>) {
+        let _ = self.app_handle.emit(
+            INDEX_PROGRESS_EVENT,
+            IndexProgress {
+                index: self.index.clone(),
+                backend: self.backend,
+                operation: self.operation,
+                stage,
+                progress: stage.progress(fraction),
+                message: message.into(),
+            },
+        );
+    }
+}
+
+fn wait(meili: &MeilisearchLib, task_uid: u32, timeout_secs: u64, what: &str) -> Result<(), String> {
+    meili
+        .wait_for_task(task_uid, Some(Duration::from_secs(timeout_secs)))
+        .map(|_| ())
+        .map_err(|e| format!("{} failed: {}", what, e))
+}
+
+// ============================================================================
+// Meilisearch
+// ============================================================================
+
+/// Read an index's documents, settings, and primary key into a snapshot
+fn export_meili_index(
+    meili: &MeilisearchLib,
+    uid: &str,
+    primary_key: Option<String>,
+    progress: &ProgressReporter,
+) -> Result<RebuildSnapshot, String> {
+    let settings = meili
+        .get_settings(uid)
+        .map_err(|e| format!("Failed to read settings of '{}': {}", uid, e))?;
+    let settings = serde_json::to_value(&settings).map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+    let mut documents = Vec::new();
+    loop {
+        let (total, page) = meili
+            .get_documents(uid, documents.len(), EXPORT_PAGE_SIZE)
+            .map_err(|e| format!("Failed to read documents of '{}': {}", uid, e))?;
+        let page_len = page.len();
+        documents.extend(page);
+        progress.report(
+            MaintenanceStage::Exporting,
+            documents.len() as f32 / total.max(1) as f32,
+            format!("Exported {} of {} documents", documents.len(), total),
+        );
+        if page_len < EXPORT_PAGE_SIZE || documents.len() >= total as usize {
+            break;
+        }
+    }
+    Ok(RebuildSnapshot::new(uid, primary_key, settings, documents))
+}
+
+/// Drop and recreate an index from a snapshot, adding its documents back in
+/// batches. Returns the number of documents.
+fn restore_meili_index(
+    meili: &MeilisearchLib,
+    snapshot: &RebuildSnapshot,
+    progress: &ProgressReporter,
+) -> Result<u64, String> {
+    let uid = snapshot.index.as_str();
+    progress.report(MaintenanceStage::Recreating, 0.0, format!("Recreating '{}'", uid));
+    if meili.index_exists(uid).map_err(|e| e.to_string())? {
+        let task = meili.delete_index(uid).map_err(|e| format!("Failed to delete '{}': {}", uid, e))?;
+        wait(meili, task.uid, TASK_TIMEOUT_SHORT_SECS, "Deleting the index")?;
+    }
+    let task = meili
+        .create_index(uid, snapshot.primary_key.clone())
+        .map_err(|e| format!("Failed to create '{}': {}", uid, e))?;
+    wait(meili, task.uid, TASK_TIMEOUT_SHORT_SECS, "Creating the index")?;
+
+    let settings: Settings<Unchecked> = serde_json::from_value(snapshot.settings.clone())
+        .map_err(|e| format!("Failed to read saved settings: {}", e))?;
+    let task = meili
+        .update_settings(uid, settings)
+        .map_err(|e| format!("Failed to restore settings of '{}': {}", uid, e))?;
+    wait(meili, task.uid, TASK_TIMEOUT_LONG_SECS, "Restoring settings")?;
+    progress.report(MaintenanceStage::Recreating, 1.0, format!("Recreated '{}'", uid));
+
+    let total = snapshot.documents.len();
+    let mut added = 0;
+    for batch in snapshot.documents.chunks(IMPORT_BATCH_SIZE) {
+        let task = meili
+            .add_documents(uid, batch.to_vec(), snapshot.primary_key.clone())
+            .map_err(|e| format!("Failed to add documents to '{}': {}", uid, e))?;
+        wait(meili, task.uid, TASK_TIMEOUT_LONG_SECS, "Adding documents")?;
+        added += batch.len();
+        progress.report(
+            MaintenanceStage::Importing,
+            added as f32 / total.max(1) as f32,
+            format!("Indexed {} of {} documents", added, total),
+        );
+    }
+    Ok(total as u64)
+}
+
+/// Rebuild a Meilisearch index through a snapshot file, restoring from a
+/// snapshot an interrupted rebuild left behind when there is one
+fn rebuild_meili_index(
+    meili: &MeilisearchLib,
+    uid: &str,
+    snapshot_dir: PathBuf,
+    progress: &ProgressReporter,
+) -> Result<u64, String> {
+    let path = RebuildSnapshot::path(&snapshot_dir, uid);
+    let snapshot = match RebuildSnapshot::load(&path).map_err(|e| e.to_string())? {
+        Some(snapshot) => {
+            log::warn!("Resuming rebuild of '{}' from snapshot taken {}", uid, snapshot.created_at);
+            progress.report(MaintenanceStage::Exporting, 1.0, "Resuming from an earlier rebuild's snapshot");
+            snapshot
+        }
+        None => {
+            let primary_key = meili_primary_key(meili, uid)?;
+            let snapshot = export_meili_index(meili, uid, primary_key, progress)?;
+            snapshot.save(&path).map_err(|e| e.to_string())?;
+            snapshot
+        }
+    };
+
+    let documents = restore_meili_index(meili, &snapshot, progress)?;
+    RebuildSnapshot::remove(&path).map_err(|e| e.to_string())?;
+    Ok(documents)
+}
+
+/// Primary key of an existing index
+fn meili_primary_key(meili: &MeilisearchLib, uid: &str) -> Result<Option<String>, String> {
+    let (_, indexes) = meili
+        .list_indexes(0, MAX_LISTED_INDEXES)
+        .map_err(|e| format!("Failed to list indexes: {}", e))?;
+    indexes
+        .into_iter()
+        .find(|index| index.uid == uid)
+        .map(|index| index.primary_key)
+        .ok_or_else(|| format!("Index not found: {}", uid))
+}
+
+// ============================================================================
+// Vector Tables
+// ============================================================================
+
+/// Row and embedding counts of a vector table
+async fn vector_table_counts(storage: &SurrealStorage, table: &str) -> Result<(u64, u64), String> {
+    let rows: Vec<serde_json::Value> = storage
+        .db()
+        .query(format!(
+            "SELECT count() AS total, count(embedding != NONE) AS embedded FROM {} GROUP ALL",
+            table
+        ))
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| format!("Failed to count '{}': {}", table, e))?;
+    let count = |field: &str| {
+        rows.first()
+            .and_then(|row| row.get(field))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+    Ok((count("total"), count("embedded")))
+}
+
+/// Rebuild each index on a vector table. A rebuild drops and redefines the
+/// indexes from the schema and vector tuning, repairing a broken definition; an optimize
+/// rebuilds them in place, compacting the HNSW graph after deletions.
+async fn reindex_vector_table(
+    storage: &SurrealStorage,
+    table: &str,
+    operation: MaintenanceOperation,
+    tuning: &VectorTuning,
+    progress: &ProgressReporter,
+) -> Result<u64, String> {
+    let definitions: Vec<_> = index_definitions().into_iter().filter(|i| i.table == table).collect();
+    for (done, definition) in definitions.iter().enumerate() {
+        progress.report(
+            MaintenanceStage::Reindexing,
+            done as f32 / definitions.len() as f32,
+            format!("Rebuilding index '{}'", definition.name),
+        );
+        let statement = match operation {
+            MaintenanceOperation::Rebuild => redefine_index_statement(definition, tuning)?,
+            MaintenanceOperation::Optimize => {
+                format!("REBUILD INDEX IF EXISTS {} ON {};", definition.name, table)
+            }
+        };
+        storage
+            .db()
+            .query(statement)
+            .await
+            .and_then(|r| r.check())
+            .map_err(|e| format!("Failed to rebuild index '{}': {}", definition.name, e))?;
+    }
+    Ok(vector_table_counts(storage, table).await?.0)
+}
+
+/// Statement dropping an index and defining it again, vector indexes with
+/// the tuning applied
+fn redefine_index_statement(definition: &IndexDefinition, tuning: &VectorTuning) -> Result<String, String> {
+    let statement = if definition.vector {
+        tuning.index_statement(&definition.statement).map_err(|e| e.to_string())?
+    } else {
+        definition.statement.clone()
+    };
+    Ok(format!(
+        "REMOVE INDEX IF EXISTS {name} ON {table}; {statement};",
+        name = definition.name,
+        table = definition.table,
+        statement = statement
+    ))
+}
+
+/// Redefine every vector index with the tuning, which re-indexes their
+/// rows. Returns the number of indexes redefined.
+pub(crate) async fn retune_vector_indexes(storage: &SurrealStorage, tuning: &VectorTuning) -> Result<usize, String> {
+    let definitions: Vec<_> = index_definitions().into_iter().filter(|i| i.vector).collect();
+    for definition in &definitions {
+        storage
+            .db()
+            .query(redefine_index_statement(definition, tuning)?)
+            .await
+            .and_then(|r| r.check())
+            .map_err(|e| format!("Failed to redefine index '{}': {}", definition.name, e))?;
+    }
+    Ok(definitions.len())
+}
+
+/// IDs of the results, leaving out the query's own row
+fn neighbour_ids(results: Vec<SearchResult>, query_id: &str, k: usize) -> Vec<String> {
+    results.into_iter().map(|r| r.id).filter(|id| id != query_id).take(k).collect()
+}
+
+/// Time vector searches with each candidate list size against exact search
+async fn benchmark_vector_search_on(
+    storage: &SurrealStorage,
+    queries: usize,
+    k: usize,
+    tuning: VectorTuning,
+) -> Result<VectorBenchmarkReport, String> {
+    let db = storage.db();
+    let (_, vectors) = vector_table_counts(storage, BENCHMARK_TABLE).await?;
+    let samples = sample_chunk_embeddings(db, queries)
+        .await
+        .map_err(|e| format!("Failed to pick benchmark queries: {}", e))?;
+    if samples.is_empty() {
+        return Err("No embedded chunks to benchmark; ingest and embed documents first".to_string());
+    }
+
+    // One extra neighbour, since each query's own row is its nearest
+    let mut exact = Vec::with_capacity(samples.len());
+    let mut exact_latencies = Vec::with_capacity(samples.len());
+    for (id, embedding) in &samples {
+        let start = Instant::now();
+        let results = exact_vector_search(db, embedding.clone(), k + 1, None)
+            .await
+            .map_err(|e| format!("Exact search failed: {}", e))?;
+        exact_latencies.push(start.elapsed().as_secs_f64() * 1000.0);
+        exact.push(neighbour_ids(results, id, k));
+    }
+
+    let ef_values: Vec<Option<u32>> = match tuning.search_ef() {
+        Some(current) => benchmark_ef_values(Some(current)).into_iter().map(Some).collect(),
+        None => vec![None],
+    };
+    let mut results = Vec::with_capacity(ef_values.len());
+    for ef in ef_values {
+        let mut latencies = Vec::with_capacity(samples.len());
+        let mut total_recall = 0.0;
+        for ((id, embedding), truth) in samples.iter().zip(&exact) {
+            let start = Instant::now();
+            let found = vector_search_with_ef(db, embedding.clone(), k + 1, ef, None)
+                .await
+                .map_err(|e| format!("Vector search failed: {}", e))?;
+            latencies.push(start.elapsed().as_secs_f64() * 1000.0);
+            total_recall += recall(truth, &neighbour_ids(found, id, k));
+        }
+        let (mean_latency_ms, p95_latency_ms) = latency_summary(&latencies);
+        results.push(EfBenchmark {
+            search_ef: ef,
+            recall: total_recall / samples.len() as f32,
+            mean_latency_ms,
+            p95_latency_ms,
+        });
+    }
+
+    Ok(VectorBenchmarkReport {
+        table: BENCHMARK_TABLE.to_string(),
+        vectors,
+        queries: samples.len(),
+        k,
+        tuning,
+        exact_mean_latency_ms: latency_summary(&exact_latencies).0,
+        recommended_search_ef: recommend_search_ef(&results),
+        results,
+    })
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// List the Meilisearch indexes and vector tables with their document counts
+#[tauri::command]
+pub async fn list_indexes(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<IndexSummary>, String> {
+    let meili = state.embedded_search.clone_inner();
+    let dir = snapshot_dir(&app_handle);
+    let snapshots = dir.clone();
+    let mut summaries = tokio::task::spawn_blocking(move || -> Result<Vec<IndexSummary>, String> {
+        let (_, indexes) = meili
+            .list_indexes(0, MAX_LISTED_INDEXES)
+            .map_err(|e| format!("Failed to list indexes: {}", e))?;
+        Ok(indexes
+            .into_iter()
+            .map(|index| IndexSummary {
+                documents: meili.index_stats(&index.uid).map(|s| s.number_of_documents).unwrap_or(0),
+                pending_rebuild: RebuildSnapshot::path(&snapshots, &index.uid).exists(),
+                name: index.uid,
+                backend: IndexBackend::Meilisearch,
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))??;
+
+    // Indexes whose rebuild was interrupted after the drop only have a snapshot
+    if let Ok(entries) = std::fs::read_dir(&dir) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension().is_some_and(|ext| ext == "json") {
+                if let Ok(Some(snapshot)) = RebuildSnapshot::load(&path) {
+                    if !summaries.iter().any(|s| s.name == snapshot.index) {
+                        summaries.push(IndexSummary {
+                            documents: snapshot.documents.len() as u64,
+                            name: snapshot.index,
+                            backend: IndexBackend::Meilisearch,
+                            pending_rebuild: true,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    if let Ok(storage) = get_storage(&state) {
+        for table in vector_tables() {
+            let (documents, _) = vector_table_counts(&storage, &table).await?;
+            summaries.push(IndexSummary {
+                name: table,
+                backend: IndexBackend::VectorTable,
+                documents,
+                pending_rebuild: false,
+            });
+        }
+    }
+
+    summaries.sort_by(|a, b| (a.backend as u8, &a.name).cmp(&(b.backend as u8, &b.name)));
+    Ok(summaries)
+}
+
+/// Document counts and structure of one index or vector table
+#[tauri::command]
+pub async fn get_index_stats(
+    name: String,
+    backend: IndexBackend,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<IndexStats, String> {
+    match backend {
+        IndexBackend::Meilisearch => {
+            let meili = state.embedded_search.clone_inner();
+            let pending_rebuild = RebuildSnapshot::path(&snapshot_dir(&app_handle), &name).exists();
+            tokio::task::spawn_blocking(move || -> Result<IndexStats, String> {
+                let primary_key = meili_primary_key(&meili, &name)?;
+                let stats = meili
+                    .index_stats(&name)
+                    .map_err(|e| format!("Failed to get stats for '{}': {}", name, e))?;
+                Ok(IndexStats {
+                    name,
+                    backend,
+                    documents: stats.number_of_documents,
+                    embedded_documents: None,
+                    primary_key,
+                    table_indexes: Vec::new(),
+                    pending_rebuild,
+                })
+            })
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+        }
+        IndexBackend::VectorTable => {
+            check_vector_table(&name)?;
+            let storage = get_storage(&state)?;
+            let (documents, embedded) = vector_table_counts(&storage, &name).await?;
+            Ok(IndexStats {
+                table_indexes: index_definitions()
+                    .into_iter()
+                    .filter(|i| i.table == name)
+                    .map(|i| i.name)
+                    .collect(),
+                name,
+                backend,
+                documents,
+                embedded_documents: Some(embedded),
+                primary_key: None,
+                pending_rebuild: false,
+            })
+        }
+    }
+}
+
+/// Rebuild an index without losing its documents.
+///
+/// A Meilisearch index is exported to a snapshot in the app data directory,
+/// dropped, recreated with the same primary key and settings, and refilled;
+/// the snapshot is removed once it is back. Rebuilding an index whose
+/// earlier rebuild was interrupted restores the earlier snapshot. A vector
+/// table's indexes are dropped and redefined from the schema, which
+/// re-indexes every row. Emits `index-progress` events throughout.
+#[tauri::command]
+pub async fn rebuild_index(
+    name: String,
+    backend: IndexBackend,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    maintenance: State<'_, IndexMaintenanceState>,
+    settings: State<'_, SearchSettingsState>,
+) -> Result<MaintenanceResult, String> {
+    let tuning = settings.settings().vector_tuning;
+    run_maintenance(name, backend, MaintenanceOperation::Rebuild, app_handle, &state, &maintenance, &tuning).await
+}
+
+/// Optimize an index.
+///
+/// Meilisearch only gives back space held by deleted and replaced documents
+/// when an index is written afresh, so optimizing one rebuilds it as
+/// `rebuild_index` does. A vector table's indexes are rebuilt in place,
+/// which compacts the HNSW graph after many deletions.
+#[tauri::command]
+pub async fn optimize_index(
+    name: String,
+    backend: IndexBackend,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    maintenance: State<'_, IndexMaintenanceState>,
+    settings: State<'_, SearchSettingsState>,
+) -> Result<MaintenanceResult, String> {
+    let tuning = settings.settings().vector_tuning;
+    run_maintenance(name, backend, MaintenanceOperation::Optimize, app_handle, &state, &maintenance, &tuning).await
+}
+
+pub(super) async fn run_maintenance(
+    name: String,
+    backend: IndexBackend,
+    operation: MaintenanceOperation,
+    app_handle: tauri::AppHandle,
+    state: &AppState,
+    maintenance: &IndexMaintenanceState,
+    tuning: &VectorTuning,
+) -> Result<MaintenanceResult, String> {
+    let storage = match backend {
+        IndexBackend::Meilisearch => None,
+        IndexBackend::VectorTable => {
+            check_vector_table(&name)?;
+            Some(get_storage(state)?)
+        }
+    };
+    let _guard = maintenance.start(backend, &name)?;
+    let start = Instant::now();
+    let progress = ProgressReporter { app_handle: app_handle.clone(), index: name.clone(), backend, operation };
+    log::info!("{:?} of {:?} index '{}' started", operation, backend, name);
+
+    let result = match storage {
+        Some(storage) => reindex_vector_table(&storage, &name, operation, tuning, &progress).await,
+        None => {
+            let meili = state.embedded_search.clone_inner();
+            let dir = snapshot_dir(&app_handle);
+            let (uid, reporter) = (name.clone(), progress.clone());
+            tokio::task::spawn_blocking(move || rebuild_meili_index(&meili, &uid, dir, &reporter))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))
+                .and_then(|result| result)
+        }
+    };
+
+    match result {
+        Ok(documents) => {
+            progress.report(MaintenanceStage::Completed, 1.0, format!("{} documents indexed", documents));
+            log::info!("{:?} of '{}' finished with {} documents", operation, name, documents);
+            Ok(MaintenanceResult {
+                index: name,
+                backend,
+                operation,
+                documents,
+                duration_ms: start.elapsed().as_millis() as u64,
+            })
+        }
+        Err(e) => {
+            progress.report(MaintenanceStage::Failed, 0.0, e.clone());
+            log::error!("{:?} of '{}' failed: {}", operation, name, e);
+            Err(e)
+        }
+    }
+}
+
+/// Benchmark vector search on the library.
+///
+/// Random embedded chunks are used as queries. Each HNSW search candidate
+/// list size is timed and its results compared with exact search, giving
+/// the recall and latency tradeoff on this machine and this data, and the
+/// smallest size that still finds 95% of the true nearest neighbours.
+///
+/// # Arguments
+/// * `queries` - Queries per setting (default: 20, at most 200)
+/// * `k` - Neighbours compared per query (default: 10)
+#[tauri::command]
+pub async fn benchmark_vector_search(
+    queries: Option<usize>,
+    k: Option<usize>,
+    state: State<'_, AppState>,
+    settings: State<'_, SearchSettingsState>,
+) -> Result<VectorBenchmarkReport, String> {
+    let storage = get_storage(&state)?;
+    let queries = queries.unwrap_or(DEFAULT_BENCHMARK_QUERIES).clamp(1, MAX_BENCHMARK_QUERIES);
+    let k = k.unwrap_or(10).clamp(1, 100);
+    let report = benchmark_vector_search_on(&storage, queries, k, settings.settings().vector_tuning).await?;
+    log::info!(
+        "Vector search benchmark on {} vectors: recommended search EF {:?}",
+        report.vectors,
+        report.recommended_search_ef
+    );
+    Ok(report)
+}
@@ -2,8 +2,10 @@
 //!
 //! Commands for listing, deleting, updating, and managing library documents.
 
+use std::sync::Arc;
 use std::time::Duration;
 
+use meilisearch_lib::MeilisearchLib;
 use tauri::State;
 
 use crate::commands::AppState;
@@ -21,8 +23,13 @@ use super::types::{UpdateLibraryDocumentRequest, IngestResult, IngestProgress};
 pub async fn list_library_documents(
     state: State<'_, AppState>,
 ) -> Result<Vec<LibraryDocumentMetadata>, String> {
-    let meili = state.embedded_search.clone_inner();
+    load_library_documents(state.embedded_search.clone_inner()).await
+}
 
+/// Read every library document's metadata from the embedded index
+pub(crate) async fn load_library_documents(
+    meili: Arc<MeilisearchLib>,
+) -> Result<Vec<LibraryDocumentMetadata>, String> {
     tokio::task::spawn_blocking(move || {
         // First check if the index exists
         let index_exists = meili
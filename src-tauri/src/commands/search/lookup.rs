@@ -0,0 +1,158 @@
+//! Quick Lookup Commands
+//!
+//! Spell and monster lookups by name for the chat agent's tool calls and
+//! the keyboard lookup. Ingested entries are matched by name first; only
+//! when none is close enough are the rulebooks searched.
+
+use std::collections::HashSet;
+
+use meilisearch_lib::{MeilisearchLib, SearchQuery};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::search::lookup::{
+    find_entry_in_text, parse_stat_block, rank_by_name, stat_block_from_record, LookupKind, LookupResult,
+    MatchQuality, SpellEntry,
+};
+use crate::database::{TTRPGDocumentRecord, TtrpgOps};
+use crate::ingestion::ttrpg::StatBlockData;
+
+use super::library::load_library_documents;
+
+/// Search hits read per rulebook when falling back to search
+const SEARCH_FALLBACK_HITS: usize = 10;
+
+// ============================================================================
+// Lookup Commands
+// ============================================================================
+
+/// Look up a spell by name.
+///
+/// Returns the parsed spell with how well its name matched and any other
+/// close matches, or `None` when nothing matches.
+#[tauri::command]
+pub async fn lookup_spell(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<Option<LookupResult<SpellEntry>>, String> {
+    lookup(&name, LookupKind::Spell, &state, SpellEntry::from_record, SpellEntry::parse).await
+}
+
+/// Look up a monster by name.
+///
+/// Returns the parsed stat block with how well its name matched and any
+/// other close matches, or `None` when nothing matches.
+#[tauri::command]
+pub async fn lookup_monster(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<Option<LookupResult<StatBlockData>>, String> {
+    lookup(&name, LookupKind::Monster, &state, stat_block_from_record, parse_stat_block).await
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Best ingested entry for `name`, else the first rulebook passage headed
+/// with the name
+async fn lookup<T>(
+    name: &str,
+    kind: LookupKind,
+    state: &AppState,
+    from_record: fn(&TTRPGDocumentRecord) -> T,
+    from_text: fn(&str, &str) -> T,
+) -> Result<Option<LookupResult<T>>, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Name is required".to_string());
+    }
+
+    let records = candidate_records(name, kind, state).await?;
+    let ranked = rank_by_name(name, kind, &records);
+    if let Some(result) = LookupResult::from_ranked(&ranked, from_record) {
+        return Ok(Some(result));
+    }
+
+    let meili = state.embedded_search.clone_inner();
+    let indexes: Vec<String> = load_library_documents(meili.clone())
+        .await?
+        .into_iter()
+        .map(|doc| doc.content_index)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let query = name.to_string();
+    let hit = tokio::task::spawn_blocking(move || search_entry(&meili, &indexes, &query, kind))
+        .await
+        .map_err(|e| format!("Lookup search task failed: {}", e))?;
+
+    Ok(hit.map(|(heading, text, source, page_number)| {
+        LookupResult::from_search(from_text(&heading, &text), source, page_number)
+    }))
+}
+
+/// Ingested entries of `kind` whose names contain `name`, widened to every
+/// entry of the kind when none of those is an exact or normalized match
+async fn candidate_records(name: &str, kind: LookupKind, state: &AppState) -> Result<Vec<TTRPGDocumentRecord>, String> {
+    let db = &state.database;
+    let mut records = db.search_ttrpg_documents_by_name(name).await.map_err(|e| e.to_string())?;
+    records.retain(|r| kind.holds(r));
+    let near_exact = rank_by_name(name, kind, &records)
+        .first()
+        .is_some_and(|(quality, _)| *quality <= MatchQuality::Normalized);
+    if near_exact {
+        return Ok(records);
+    }
+
+    let mut seen: HashSet<String> = records.iter().map(|r| r.id.clone()).collect();
+    for element_type in kind.element_types() {
+        for record in db.list_ttrpg_documents_by_type(element_type).await.map_err(|e| e.to_string())? {
+            if seen.insert(record.id.clone()) {
+                records.push(record);
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// The first keyword hit that reads like an entry of `kind` and has a
+/// heading naming it: the heading, the text from there, its source and page
+fn search_entry(
+    meili: &MeilisearchLib,
+    indexes: &[String],
+    query: &str,
+    kind: LookupKind,
+) -> Option<(String, String, String, Option<i32>)> {
+    for index_uid in indexes {
+        let search_query = SearchQuery::new(query).with_pagination(0, SEARCH_FALLBACK_HITS);
+        let results = match meili.search(index_uid, search_query) {
+            Ok(results) => results,
+            Err(e) => {
+                log::debug!("Lookup search skipped index '{}': {}", index_uid, e);
+                continue;
+            }
+        };
+        for hit in &results.hits {
+            let doc = &hit.document;
+            let Some(content) = doc.get("content").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some((heading, text)) = find_entry_in_text(query, content) else {
+                continue;
+            };
+            if !kind.looks_like(text) {
+                continue;
+            }
+            let source = doc
+                .get("source")
+                .or_else(|| doc.get("book_title"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown")
+                .to_string();
+            let page_number = doc.get("page_number").and_then(|v| v.as_i64()).map(|p| p as i32);
+            return Some((heading.to_string(), text.to_string(), source, page_number));
+        }
+    }
+    None
+}
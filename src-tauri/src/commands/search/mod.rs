@@ -2,7 +2,10 @@
 //!
 //! Commands for search, document ingestion, library management,
 //! TTRPG document queries, search analytics, embeddings configuration,
-//! and extraction settings.
+//! extraction settings, search settings, custom synonym and antonym sets,
+//! index maintenance, bookmarks, exporting results to campaign notes
+//! and handouts, near-duplicate content detection, and vector store
+//! statistics and cleanup.
 //!
 //! ## SurrealDB Migration
 //!
@@ -17,14 +20,24 @@
 //! from AppState to preprocess queries before searching.
 
 pub mod query;
+pub mod scoped;
 pub mod suggestions;
 pub mod library;
 pub mod ingestion;
 pub mod extraction;
+pub mod settings;
+pub mod term_sets;
+pub mod bookmarks;
+pub mod duplicates;
+pub mod export;
 pub mod ttrpg_docs;
+pub mod lookup;
 pub mod embeddings;
 pub mod analytics;
 pub mod meilisearch;
+pub mod indexes;
+pub mod index_migrations;
+pub mod vector_store;
 pub mod types;
 
 // SurrealDB migration modules (Tasks 6.1.1-6.1.3, 4.2.3)
@@ -36,14 +49,24 @@ pub mod preprocessing;
 
 // Re-export all commands using glob to include Tauri __cmd__ macros
 pub use query::*;
+pub use scoped::*;
 pub use suggestions::*;
 pub use library::*;
 pub use ingestion::*;
 pub use extraction::*;
+pub use settings::*;
+pub use term_sets::*;
+pub use bookmarks::*;
+pub use duplicates::*;
+pub use export::*;
 pub use ttrpg_docs::*;
+pub use lookup::*;
 pub use embeddings::*;
 pub use analytics::*;
 pub use meilisearch::*;
+pub use indexes::*;
+pub use index_migrations::*;
+pub use vector_store::*;
 pub use types::*;
 
 // Re-export SurrealDB commands
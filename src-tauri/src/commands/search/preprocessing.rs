@@ -36,7 +36,7 @@ use crate::commands::state::AppState;
 use crate::core::preprocess::{Correction, DictionaryGenerator, QueryPipeline};
 use crate::core::storage::{
     hybrid_search_with_preprocessing, HybridSearchConfig, SearchFilter, SearchResult,
-    SurrealStorage,
+    SurrealStorage, VectorNamespace,
 };
 
 // ============================================================================
@@ -150,6 +150,18 @@ pub struct PreprocessingSearchOptions {
     pub page_min: Option<i32>,
     /// Maximum page number
     pub page_max: Option<i32>,
+    /// Filter by the source document's game system ID (e.g. "dnd5e")
+    #[serde(default)]
+    pub game_system: Option<String>,
+    /// Filter by semantic chunk type (stat_block, spell, table, narrative)
+    #[serde(default)]
+    pub element_type: Option<String>,
+    /// Filter to chunks belonging to a campaign
+    #[serde(default)]
+    pub campaign_id: Option<String>,
+    /// Search only these namespaces ("library", "campaign:{id}", "notes", "npcs")
+    #[serde(default)]
+    pub namespaces: Option<Vec<VectorNamespace>>,
 }
 
 fn default_limit() -> usize {
@@ -166,6 +178,10 @@ impl Default for PreprocessingSearchOptions {
             library_item: None,
             page_min: None,
             page_max: None,
+            game_system: None,
+            element_type: None,
+            campaign_id: None,
+            namespaces: None,
         }
     }
 }
@@ -221,6 +237,26 @@ fn build_filter(opts: &PreprocessingSearchOptions) -> Option<SearchFilter> {
         has_filter = true;
     }
 
+    if let Some(ref gs) = opts.game_system {
+        filter = filter.game_system(gs);
+        has_filter = true;
+    }
+
+    if let Some(ref et) = opts.element_type {
+        filter = filter.element_type(et);
+        has_filter = true;
+    }
+
+    if let Some(ref campaign_id) = opts.campaign_id {
+        filter = filter.campaign(campaign_id);
+        has_filter = true;
+    }
+
+    if let Some(ref namespaces) = opts.namespaces {
+        filter = filter.namespaces(namespaces.iter().cloned());
+        has_filter = true;
+    }
+
     if opts.page_min.is_some() || opts.page_max.is_some() {
         filter = filter.page_range(opts.page_min, opts.page_max);
         has_filter = true;
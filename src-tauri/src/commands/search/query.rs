@@ -1,17 +1,33 @@
 //! Search Query Commands
 //!
-//! Core search functionality including basic search and hybrid search.
+//! Core search functionality including basic search and hybrid search
+//! (keyword and vector queries fused with Reciprocal Rank Fusion).
 //! Uses embedded MeilisearchLib for direct Rust integration without HTTP.
 
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 
-use meilisearch_lib::{HybridQuery, SearchQuery};
+use meilisearch_lib::{HybridQuery, MeilisearchLib, SearchQuery};
 use tauri::State;
 
-use crate::commands::AppState;
+use crate::commands::{AppState, HouseRuleState, SearchAnalyticsState};
+use crate::core::campaign::house_rules::{HouseRuleMatch, HOUSE_RULE_BADGE};
 // Re-exported from core::search::config - config module is private but items are pub
-use crate::core::search::{all_indexes, select_index_for_source_type};
+use crate::core::search::rerank::{reorder_by_scores, score_passages};
+use crate::core::search::{
+    all_indexes, extract_snippets, query_terms, select_index_for_source_type, snippet_length,
+    CrossEncoder, HybridConfig,
+};
+use crate::core::search_analytics::SearchRecord;
+use crate::core::ttrpg_search::{
+    matches_selection, AntonymMapper, AttributeFilter, Facet, FacetCounter, FacetField,
+    QueryConstraints, QueryParser, RankingConfig, ResultRanker, SearchCandidate,
+};
 
+use super::analytics::record_search;
+use super::settings::SearchSettingsState;
+use super::term_sets::TermSetState;
 use super::types::{
     HybridSearchOptions, HybridSearchResponsePayload, HybridSearchResultPayload, SearchOptions,
     SearchResultPayload,
@@ -32,18 +48,23 @@ use super::types::{
 /// * `state` - Application state containing embedded search engine
 ///
 /// # Returns
-/// Vector of search results with content, source, and relevance scores
+/// Vector of search results with content, source, and relevance scores.
+/// With `house_rules_campaign_id`, the campaign's matching house rules come
+/// first, badged, and the passages they replace are marked.
 #[tauri::command]
 pub async fn search(
     query: String,
     options: Option<SearchOptions>,
     state: State<'_, AppState>,
+    house_rules: State<'_, HouseRuleState>,
 ) -> Result<Vec<SearchResultPayload>, String> {
     let opts = options.unwrap_or_default();
     let meili = state.embedded_search.clone_inner();
     let query_clone = query.clone();
+    let house_rules_campaign_id = opts.house_rules_campaign_id.clone();
+    let limit = opts.limit;
 
-    tokio::task::spawn_blocking(move || {
+    let mut results = tokio::task::spawn_blocking(move || {
         let start = Instant::now();
 
         // Determine which index(es) to search
@@ -94,6 +115,12 @@ pub async fn search(
         all_results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
         all_results.truncate(opts.limit);
 
+        let terms = query_terms(&query_clone);
+        let length = snippet_length(opts.snippet_length);
+        for result in &mut all_results {
+            result.snippets = extract_snippets(&result.content, &terms, length);
+        }
+
         log::debug!(
             "Search for '{}' returned {} results in {:?}",
             query_clone,
@@ -101,150 +128,531 @@ pub async fn search(
             start.elapsed()
         );
 
-        Ok(all_results)
+        Ok::<_, String>(all_results)
     })
     .await
-    .map_err(|e| format!("Search task failed: {}", e))?
+    .map_err(|e| format!("Search task failed: {}", e))??;
+
+    if let Some(campaign_id) = house_rules_campaign_id {
+        let passages: Vec<_> = results
+            .iter()
+            .map(|r| (r.source.as_str(), r.page_number, r.score))
+            .collect();
+        let (rules, overridden_by) = house_rules.manager.surface(&campaign_id, &query, limit, &passages);
+        for (result, rule_id) in results.iter_mut().zip(overridden_by) {
+            result.overridden_by = rule_id;
+        }
+        results.splice(0..0, rules.iter().map(house_rule_payload));
+    }
+
+    Ok(results)
 }
 
 // ============================================================================
 // Hybrid Search
 // ============================================================================
 
+/// Attribute fields that feed the ranker's attribute bonus and antonym veto
+const ATTRIBUTE_FIELDS: [&str; 7] = [
+    "damage_types", "creature_types", "conditions", "alignments",
+    "rarities", "sizes", "spell_schools",
+];
+
+/// Candidates fetched from each method, as a multiple of the requested limit
+const CANDIDATE_MULTIPLIER: usize = 3;
+
+/// A hit from one search method, with the fields fusion needs
+struct LegHit {
+    /// `index:document_id`, unique across indexes
+    key: String,
+    document_id: Option<String>,
+    index: String,
+    content: String,
+    source: String,
+    source_type: String,
+    page_number: Option<u32>,
+    attributes: Vec<String>,
+    facet_values: Vec<(FacetField, String)>,
+    score: f32,
+}
+
+/// Everything one search method found across the indexes
+#[derive(Default)]
+struct SearchLeg {
+    hits: Vec<LegHit>,
+    hints: Vec<String>,
+}
+
 /// Perform hybrid search combining keyword and semantic matching.
 ///
-/// Uses Meilisearch's native hybrid search which combines BM25 keyword matching
-/// with vector similarity search. Results are fused using the configured
-/// semantic ratio (0.0 = pure keyword, 1.0 = pure semantic).
+/// Runs a keyword (BM25) query and a pure vector query in parallel, then
+/// fuses the two rankings with Reciprocal Rank Fusion through the
+/// `ResultRanker`. Attribute constraints parsed from the query ("fire",
+/// "not undead", "CR 1-5") become Meilisearch filters where the index
+/// supports them, and also drive the ranker's attribute bonus and antonym
+/// veto, which also uses the custom antonym sets for the campaign and its
+/// game system. When re-ranking is enabled in the search settings, a
+/// cross-encoder re-orders the top fused candidates before the limit applies.
 ///
 /// # Arguments
 /// * `query` - The search query string
-/// * `options` - Optional search configuration (limit, filters, semantic_weight)
+/// * `options` - Optional search configuration (limit, filters, weights)
 /// * `state` - Application state containing embedded search engine
 ///
 /// # Returns
-/// Search results with fused scores, timing, and query metadata
+/// Structured results with the document, page, keyword and semantic ranks,
+/// score breakdown, and highlighted snippets, plus timing and query metadata. With
+/// `house_rules_campaign_id`, the campaign's matching house rules come first,
+/// badged, and the passages they replace are marked. The search is recorded
+/// in search analytics under the returned `search_id`.
 #[tauri::command]
 pub async fn hybrid_search(
     query: String,
     options: Option<HybridSearchOptions>,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
+    house_rules: State<'_, HouseRuleState>,
+    search_settings: State<'_, SearchSettingsState>,
+    analytics: State<'_, SearchAnalyticsState>,
+    term_sets: State<'_, TermSetState>,
 ) -> Result<HybridSearchResponsePayload, String> {
     let opts = options.unwrap_or_default();
+    let antonyms = term_sets.antonym_mapper(&state, opts.campaign_id.as_deref());
     let meili = state.embedded_search.clone_inner();
-    let query_clone = query.clone();
-
-    tokio::task::spawn_blocking(move || {
-        let start = Instant::now();
-
-        // Determine semantic ratio from options
-        // Default to balanced (0.5) if not specified, clamp to [0.0, 1.0] and handle NaN
-        let raw_semantic_ratio = opts.semantic_weight.unwrap_or(0.5);
-        let semantic_ratio = if raw_semantic_ratio.is_nan() {
-            0.5
-        } else if raw_semantic_ratio < 0.0 {
-            0.0
-        } else if raw_semantic_ratio > 1.0 {
-            1.0
-        } else {
-            raw_semantic_ratio
-        };
+    let house_rules_campaign_id = opts.house_rules_campaign_id.clone();
+    let limit = opts.limit;
+    let start = Instant::now();
+
+    let constraints = QueryParser::new().parse(&query);
+    let base_filter = build_hybrid_filter_expression(&opts)
+        .and_then(|f| f.as_str().map(str::to_string));
+    let indexes: Vec<String> = if let Some(ref index) = opts.index {
+        vec![index.clone()]
+    } else if let Some(ref source_type) = opts.source_type {
+        vec![select_index_for_source_type(source_type).to_string()]
+    } else {
+        all_indexes().into_iter().map(str::to_string).collect()
+    };
+
+    let (mut results, facets, mut hints) =
+        fused_search(meili, indexes, &query, base_filter, &opts, &constraints, antonyms).await?;
+    let total_hits = results.len();
+    results = apply_reranking(results, &query, limit, &mut hints, &search_settings, &app_handle).await;
+    results.truncate(limit);
+
+    let processing_time_ms = start.elapsed().as_millis() as u64;
+    let within_target = processing_time_ms < 500; // Performance target: <500ms
+
+    log::debug!(
+        "Hybrid search for '{}' returned {} results in {}ms (target: {})",
+        query,
+        results.len(),
+        processing_time_ms,
+        if within_target { "met" } else { "missed" }
+    );
+
+    let mut response = HybridSearchResponsePayload {
+        results,
+        total_hits,
+        original_query: query.clone(),
+        expanded_query: Some(constraints.expanded_query.clone())
+            .filter(|expanded| *expanded != query),
+        corrected_query: None, // Typo tolerance handled by MeilisearchLib
+        processing_time_ms,
+        hints,
+        within_target,
+        facets,
+        search_id: None,
+    };
+
+    if let Some(campaign_id) = house_rules_campaign_id {
+        surface_house_rules(&mut response, &campaign_id, &query, limit, &house_rules);
+    }
 
-        // Determine which index(es) to search
-        let indexes_to_search = if let Some(ref index) = opts.index {
-            vec![index.as_str()]
-        } else if let Some(ref source_type) = opts.source_type {
-            vec![select_index_for_source_type(source_type)]
-        } else {
-            // Search all content indexes for federated hybrid search
-            all_indexes()
-        };
+    let mut record = SearchRecord::new(query, response.results.len(), processing_time_ms, "hybrid".to_string())
+        .with_source_filter(opts.source_type.clone());
+    record.campaign_id = opts.campaign_id.clone();
+    response.search_id = Some(record_search(&analytics, &state.database, record));
 
-        // Build filter expression
-        let filter = build_hybrid_filter_expression(&opts);
+    Ok(response)
+}
 
-        let mut all_results = Vec::new();
-        let mut total_hits: usize = 0;
-        let mut hints = Vec::new();
+/// Keyword and semantic search over `indexes`, fused with the options'
+/// weights and facet selection, penalizing opposites with `antonyms`.
+/// Returns every fused result, the facet counts, and hints about filters
+/// and unavailable indexes.
+pub(crate) async fn fused_search(
+    meili: Arc<MeilisearchLib>,
+    indexes: Vec<String>,
+    query: &str,
+    base_filter: Option<String>,
+    opts: &HybridSearchOptions,
+    constraints: &QueryConstraints,
+    antonyms: AntonymMapper,
+) -> Result<(Vec<HybridSearchResultPayload>, Vec<Facet>, Vec<String>), String> {
+    // Default to balanced (0.5) if not specified, clamp to [0.0, 1.0] and handle NaN
+    let raw_semantic_ratio = opts.semantic_weight.unwrap_or(0.5);
+    let semantic_ratio = if raw_semantic_ratio.is_nan() {
+        0.5
+    } else {
+        raw_semantic_ratio.clamp(0.0, 1.0)
+    };
+    let (keyword_weight, semantic_weight) = HybridConfig {
+        semantic_weight: semantic_ratio,
+        keyword_weight: opts.keyword_weight.unwrap_or(1.0 - semantic_ratio),
+        fusion_strategy: opts.fusion_strategy.clone(),
+        ..Default::default()
+    }
+    .effective_weights();
+
+    // Constraints from the query and selected facets; dropped for indexes
+    // that can't filter on them, where the ranker and facet check apply
+    let attribute_filter = Some(AttributeFilter::combine_and(&[
+        AttributeFilter::build_filter_string(constraints),
+        AttributeFilter::build_facet_filter(&opts.facets),
+    ]))
+    .filter(|f| !f.is_empty());
+
+    let fetch = opts.limit.max(1) * CANDIDATE_MULTIPLIER;
+
+    let (keyword, semantic) = search_legs(
+        meili,
+        indexes,
+        query,
+        base_filter,
+        attribute_filter.clone(),
+        fetch,
+    )
+    .await?;
+
+    let mut hints = keyword.hints;
+    for hint in semantic.hints {
+        if !hints.contains(&hint) {
+            hints.push(hint);
+        }
+    }
+    if let Some(ref filter) = attribute_filter {
+        hints.push(format!("Filtered by: {}", filter));
+    }
 
-        for index_uid in indexes_to_search {
-            // Build hybrid search query
-            let hybrid_config = HybridQuery::new(semantic_ratio);
-            let mut search_query = SearchQuery::new(&query_clone)
-                .with_hybrid(hybrid_config)
-                .with_pagination(0, opts.limit);
+    let (results, facets) = fuse_legs(
+        keyword.hits,
+        semantic.hits,
+        &opts.facets,
+        constraints,
+        RankingConfig {
+            semantic_weight,
+            keyword_weight,
+            ..Default::default()
+        },
+        antonyms,
+        snippet_length(opts.snippet_length),
+    );
+    Ok((results, facets, hints))
+}
 
-            // Apply filter if present
-            if let Some(ref filter_value) = filter {
-                search_query = search_query.with_filter(filter_value.clone());
+/// Re-order the top results with the cross-encoder when re-ranking is on,
+/// noting in `hints` when it was skipped
+pub(crate) async fn apply_reranking(
+    results: Vec<HybridSearchResultPayload>,
+    query: &str,
+    limit: usize,
+    hints: &mut Vec<String>,
+    search_settings: &SearchSettingsState,
+    app_handle: &tauri::AppHandle,
+) -> Vec<HybridSearchResultPayload> {
+    match search_settings.active_reranker(app_handle, limit) {
+        Some((reranker, candidates)) => {
+            let (reranked, hint) = rerank_results(reranker, query, results, candidates).await;
+            hints.extend(hint);
+            reranked
+        }
+        None => {
+            if search_settings.settings().rerank.enabled {
+                hints.push(if search_settings.is_loading() {
+                    "Re-ranking model is loading; results are in fused order".to_string()
+                } else {
+                    "Re-ranking model unavailable; results are in fused order".to_string()
+                });
             }
+            results
+        }
+    }
+}
 
-            // Enable ranking scores
-            search_query.show_ranking_score = true;
-
-            // Execute hybrid search
-            match meili.search(index_uid, search_query) {
-                Ok(result) => {
-                    if let Some(estimated) = result.estimated_total_hits {
-                        total_hits += estimated as usize;
-                    }
+/// Put the campaign's matching house rules first, badged, and mark the
+/// results they replace
+pub(crate) fn surface_house_rules(
+    response: &mut HybridSearchResponsePayload,
+    campaign_id: &str,
+    query: &str,
+    limit: usize,
+    house_rules: &HouseRuleState,
+) {
+    let passages: Vec<_> = response
+        .results
+        .iter()
+        .map(|r| (r.source.as_str(), r.page_number, r.score))
+        .collect();
+    let (rules, overridden_by) = house_rules.manager.surface(campaign_id, query, limit, &passages);
+    for (result, rule_id) in response.results.iter_mut().zip(overridden_by) {
+        result.overridden_by = rule_id;
+    }
+    if !rules.is_empty() {
+        response.hints.push(format!("{} house rule(s) apply", rules.len()));
+    }
+    response.results.splice(0..0, rules.iter().map(house_rule_hybrid_payload));
+}
 
-                    // Track if semantic search found results
-                    if let Some(semantic_count) = result.semantic_hit_count {
-                        if semantic_count > 0 {
-                            hints.push(format!(
-                                "Found {} semantic matches in {}",
-                                semantic_count, index_uid
-                            ));
-                        }
-                    }
+/// Passages for a grounded answer: keyword and semantic hits over `indexes`,
+/// kept to those `keep` accepts by source and document ID, fused and cut to
+/// `limit`. `filter` narrows the search where the indexes support it.
+///
+/// Also returns the best raw ranking score among the kept hits, so callers
+/// can tell weak retrieval from a real match. With a `reranker` and its
+/// candidate count, the fused passages are re-ordered before the cut.
+pub(crate) async fn retrieve_passages(
+    meili: Arc<MeilisearchLib>,
+    indexes: Vec<String>,
+    query: &str,
+    filter: Option<String>,
+    keep: impl Fn(&str, Option<&str>) -> bool,
+    limit: usize,
+    reranker: Option<(Arc<CrossEncoder>, usize)>,
+) -> Result<(Vec<HybridSearchResultPayload>, f32), String> {
+    let (mut keyword, mut semantic) =
+        search_legs(meili, indexes, query, None, filter, limit.max(1) * CANDIDATE_MULTIPLIER).await?;
+    keyword.hits.retain(|h| keep(&h.source, h.document_id.as_deref()));
+    semantic.hits.retain(|h| keep(&h.source, h.document_id.as_deref()));
+
+    let best_score = keyword
+        .hits
+        .iter()
+        .chain(&semantic.hits)
+        .map(|h| h.score)
+        .fold(0.0, f32::max);
+    let (mut passages, _) = fuse_legs(
+        keyword.hits,
+        semantic.hits,
+        &HashMap::new(),
+        &QueryParser::new().parse(query),
+        RankingConfig::default(),
+        AntonymMapper::new(),
+        snippet_length(None),
+    );
+    if let Some((reranker, candidates)) = reranker {
+        passages = rerank_results(reranker, query, passages, candidates).await.0;
+    }
+    passages.truncate(limit);
+    Ok((passages, best_score))
+}
 
-                    for (rank, hit) in result.hits.iter().enumerate() {
-                        if let Some(payload) = convert_hit_to_hybrid_payload(&hit, index_uid, rank) {
-                            all_results.push(payload);
-                        }
-                    }
-                }
-                Err(e) => {
-                    // Log error but continue with other indexes
-                    log::warn!("Hybrid search error in index '{}': {}", index_uid, e);
-                    hints.push(format!("Search unavailable for {}: {}", index_uid, e));
-                }
+/// Re-order the top `candidates` fused results with the cross-encoder,
+/// scoring off the async runtime. If scoring fails the fused order stands
+/// and the error comes back as a hint.
+async fn rerank_results(
+    reranker: Arc<CrossEncoder>,
+    query: &str,
+    mut results: Vec<HybridSearchResultPayload>,
+    candidates: usize,
+) -> (Vec<HybridSearchResultPayload>, Option<String>) {
+    let start = Instant::now();
+    let query = query.to_string();
+    let passages: Vec<String> = results.iter().take(candidates).map(|r| r.content.clone()).collect();
+    let scored = tokio::task::spawn_blocking(move || {
+        let passages: Vec<&str> = passages.iter().map(String::as_str).collect();
+        score_passages(reranker.as_ref(), &query, &passages).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("Re-ranking task failed: {}", e))
+    .and_then(|scored| scored);
+
+    match scored {
+        Ok(scores) => {
+            let sorted = reorder_by_scores(&mut results, &scores);
+            for (result, score) in results.iter_mut().zip(sorted) {
+                result.rerank_score = Some(score);
             }
+            log::debug!("Re-ranked {} candidates in {:?}", scores.len(), start.elapsed());
+            (results, None)
         }
+        Err(e) => {
+            log::warn!("{}", e);
+            (results, Some(format!("Re-ranking skipped: {}", e)))
+        }
+    }
+}
 
-        // Sort by score descending and limit total results
-        all_results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        all_results.truncate(opts.limit);
+/// Filter matching chunks from any of the given sources
+pub(crate) fn source_filter(sources: &[String]) -> Option<String> {
+    if sources.is_empty() {
+        return None;
+    }
+    let quoted: Vec<String> = sources
+        .iter()
+        .map(|s| format!("\"{}\"", escape_filter_value(s)))
+        .collect();
+    Some(format!("source IN [{}]", quoted.join(", ")))
+}
 
-        let processing_time_ms = start.elapsed().as_millis() as u64;
-        let within_target = processing_time_ms < 500; // Performance target: <500ms
+/// Run the keyword and semantic legs in parallel
+async fn search_legs(
+    meili: Arc<MeilisearchLib>,
+    indexes: Vec<String>,
+    query: &str,
+    base_filter: Option<String>,
+    attribute_filter: Option<String>,
+    fetch: usize,
+) -> Result<(SearchLeg, SearchLeg), String> {
+    let leg = |semantic: bool| {
+        let meili = meili.clone();
+        let indexes = indexes.clone();
+        let query = query.to_string();
+        let base_filter = base_filter.clone();
+        let attribute_filter = attribute_filter.clone();
+        tokio::task::spawn_blocking(move || {
+            run_search_leg(&meili, &indexes, &query, semantic, base_filter, attribute_filter, fetch)
+        })
+    };
+    let (keyword, semantic) = tokio::join!(leg(false), leg(true));
+    let keyword = keyword.map_err(|e| format!("Keyword search task failed: {}", e))?;
+    let semantic = semantic.map_err(|e| format!("Semantic search task failed: {}", e))?;
+    Ok((keyword, semantic))
+}
 
-        log::debug!(
-            "Hybrid search for '{}' returned {} results in {}ms (target: {})",
-            query_clone,
-            all_results.len(),
-            processing_time_ms,
-            if within_target { "met" } else { "missed" }
-        );
+/// Run one search method over every index, best hits first.
+///
+/// When an index rejects the attribute filter (its fields aren't filterable
+/// there), the query is retried with only the campaign and source filters;
+/// the ranker still scores attribute matches afterwards.
+fn run_search_leg(
+    meili: &meilisearch_lib::MeilisearchLib,
+    indexes: &[String],
+    query: &str,
+    semantic: bool,
+    base_filter: Option<String>,
+    attribute_filter: Option<String>,
+    fetch: usize,
+) -> SearchLeg {
+    let method = if semantic { "Semantic" } else { "Keyword" };
+    let combined = match (&base_filter, &attribute_filter) {
+        (Some(base), Some(attrs)) => Some(format!("{} AND {}", base, attrs)),
+        (None, Some(attrs)) => Some(attrs.clone()),
+        (base, None) => base.clone(),
+    };
+
+    let run = |index_uid: &str, filter: &Option<String>| {
+        let mut search_query = SearchQuery::new(query).with_pagination(0, fetch);
+        if semantic {
+            search_query = search_query.with_hybrid(HybridQuery::new(1.0));
+        }
+        if let Some(filter) = filter {
+            search_query = search_query.with_filter(serde_json::Value::String(filter.clone()));
+        }
+        search_query.show_ranking_score = true;
+        meili.search(index_uid, search_query)
+    };
+
+    let mut leg = SearchLeg::default();
+    for index_uid in indexes {
+        let result = match run(index_uid, &combined) {
+            Err(e) if attribute_filter.is_some() => {
+                log::debug!("Attribute filter rejected by '{}': {}", index_uid, e);
+                run(index_uid, &base_filter)
+            }
+            other => other,
+        };
+        match result {
+            Ok(result) => leg.hits.extend(result.hits.iter().map(|hit| extract_leg_hit(hit, index_uid))),
+            Err(e) => {
+                // Log error but continue with other indexes
+                log::warn!("{} search error in index '{}': {}", method, index_uid, e);
+                leg.hints.push(format!("Search unavailable for {}: {}", index_uid, e));
+            }
+        }
+    }
+
+    leg.hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    leg
+}
 
-        Ok(HybridSearchResponsePayload {
-            results: all_results,
-            total_hits,
-            original_query: query_clone,
-            expanded_query: None, // Query expansion handled by MeilisearchLib synonyms
-            corrected_query: None, // Typo tolerance handled by MeilisearchLib
-            processing_time_ms,
-            hints,
-            within_target,
+/// Fuse keyword and semantic hits with RRF, dropping vetoed results and
+/// hits outside the selected facets, and count facets over what remains
+fn fuse_legs(
+    keyword: Vec<LegHit>,
+    semantic: Vec<LegHit>,
+    selected_facets: &HashMap<String, Vec<String>>,
+    constraints: &QueryConstraints,
+    config: RankingConfig,
+    antonyms: AntonymMapper,
+    snippet_length: usize,
+) -> (Vec<HybridSearchResultPayload>, Vec<Facet>) {
+    let in_selection = |h: &LegHit| matches_selection(&h.facet_values, selected_facets);
+    let keyword: Vec<LegHit> = keyword.into_iter().filter(in_selection).collect();
+    let semantic: Vec<LegHit> = semantic.into_iter().filter(in_selection).collect();
+
+    let candidates = |hits: &[LegHit]| -> Vec<SearchCandidate> {
+        hits.iter()
+            .map(|h| SearchCandidate {
+                id: h.key.clone(),
+                score: h.score,
+                content: h.content.clone(),
+            })
+            .collect()
+    };
+    let keyword_candidates = candidates(&keyword);
+    let semantic_candidates = candidates(&semantic);
+
+    // Position of each hit within its method, best first
+    let ranks = |hits: &[LegHit]| -> HashMap<String, usize> {
+        hits.iter().enumerate().rev().map(|(i, h)| (h.key.clone(), i)).collect()
+    };
+    let keyword_ranks = ranks(&keyword);
+    let semantic_ranks = ranks(&semantic);
+
+    let mut hits: HashMap<String, LegHit> = HashMap::new();
+    for hit in semantic.into_iter().chain(keyword) {
+        hits.entry(hit.key.clone()).or_insert(hit);
+    }
+    let doc_attributes: HashMap<String, Vec<String>> = hits
+        .iter()
+        .map(|(key, hit)| (key.clone(), hit.attributes.clone()))
+        .collect();
+
+    let terms = highlight_terms(constraints);
+    let mut facets = FacetCounter::new();
+    let results = ResultRanker::with_config(config)
+        .with_antonym_mapper(antonyms)
+        .rank(&semantic_candidates, &keyword_candidates, constraints, &doc_attributes)
+        .into_iter()
+        .filter(|ranked| !ranked.vetoed)
+        .filter_map(|ranked| {
+            let hit = hits.remove(&ranked.id)?;
+            facets.add(&hit.facet_values);
+            let keyword_rank = keyword_ranks.get(&ranked.id).copied();
+            let semantic_rank = semantic_ranks.get(&ranked.id).copied();
+            Some(HybridSearchResultPayload {
+                snippets: extract_snippets(&hit.content, &terms, snippet_length),
+                content: hit.content,
+                source: hit.source,
+                source_type: hit.source_type,
+                page_number: hit.page_number,
+                score: ranked.breakdown.final_score,
+                index: hit.index,
+                keyword_rank,
+                semantic_rank,
+                overlap_count: Some(keyword_rank.is_some() as usize + semantic_rank.is_some() as usize),
+                badge: None,
+                house_rule_id: None,
+                overridden_by: None,
+                document_id: hit.document_id,
+                score_breakdown: Some(ranked.breakdown),
+                rerank_score: None,
+            })
         })
-    })
-    .await
-    .map_err(|e| format!("Hybrid search task failed: {}", e))?
+        .collect();
+
+    (results, facets.facets())
 }
 
 // ============================================================================
@@ -254,7 +662,7 @@ pub async fn hybrid_search(
 /// Escape a value for use in Meilisearch filter expressions.
 ///
 /// Prevents filter injection by escaping `\` and `"` characters.
-fn escape_filter_value(value: &str) -> String {
+pub(crate) fn escape_filter_value(value: &str) -> String {
     value.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
@@ -278,7 +686,7 @@ fn build_filter_expression(opts: &SearchOptions) -> Option<serde_json::Value> {
 }
 
 /// Build filter expression from HybridSearchOptions
-fn build_hybrid_filter_expression(opts: &HybridSearchOptions) -> Option<serde_json::Value> {
+pub(crate) fn build_hybrid_filter_expression(opts: &HybridSearchOptions) -> Option<serde_json::Value> {
     let mut filters = Vec::new();
 
     if let Some(ref campaign_id) = opts.campaign_id {
@@ -346,15 +754,15 @@ fn convert_hit_to_payload(
         page_number,
         score,
         index: index.to_string(),
+        badge: None,
+        house_rule_id: None,
+        overridden_by: None,
+        snippets: Vec::new(),
     })
 }
 
-/// Convert a MeilisearchLib SearchHit to frontend HybridSearchResultPayload
-fn convert_hit_to_hybrid_payload(
-    hit: &meilisearch_lib::SearchHit,
-    index: &str,
-    _rank: usize,
-) -> Option<HybridSearchResultPayload> {
+/// Convert a MeilisearchLib SearchHit into a hit for fusion
+fn extract_leg_hit(hit: &meilisearch_lib::SearchHit, index: &str) -> LegHit {
     let doc = &hit.document;
 
     // Extract content
@@ -390,20 +798,161 @@ fn convert_hit_to_hybrid_payload(
         .and_then(|v| v.as_u64())
         .map(|n| n as u32);
 
-    // Get ranking score
-    let score = hit.ranking_score.unwrap_or(0.0) as f32;
-
-    Some(HybridSearchResultPayload {
+    // Extract document ID, which may be a string or a number
+    let document_id = doc.get("id").and_then(|v| match v {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    });
+
+    // Without an ID, fall back to what identifies the passage
+    let key = match document_id {
+        Some(ref id) => format!("{}:{}", index, id),
+        None => format!("{}:{}:{:?}:{}", index, source, page_number, content),
+    };
+
+    // Collect TTRPG attributes for ranking
+    let attributes = ATTRIBUTE_FIELDS
+        .iter()
+        .filter_map(|field| doc.get(*field).and_then(|v| v.as_array()))
+        .flatten()
+        .filter_map(|v| v.as_str().map(str::to_string))
+        .collect();
+
+    LegHit {
+        key,
+        document_id,
+        index: index.to_string(),
         content,
         source,
         source_type,
         page_number,
-        score,
-        index: index.to_string(),
-        // Hybrid search returns a fused ranking; keyword/semantic ranks are not separable.
+        attributes,
+        facet_values: FacetField::values_from(|field| doc.get(field)),
+        score: hit.ranking_score.unwrap_or(0.0) as f32,
+    }
+}
+
+/// Exact-match entities followed by the query's own words
+fn highlight_terms(constraints: &QueryConstraints) -> Vec<String> {
+    let mut terms: Vec<String> = constraints
+        .exact_match_entities
+        .iter()
+        .map(|e| e.to_lowercase())
+        .collect();
+    for term in query_terms(&constraints.original_query) {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Present a house rule as a search result
+fn house_rule_payload(found: &HouseRuleMatch) -> SearchResultPayload {
+    SearchResultPayload {
+        content: format!("{}\n\n{}", found.rule.title, found.rule.text),
+        source: "House rules".to_string(),
+        source_type: "house_rule".to_string(),
+        page_number: None,
+        score: found.score,
+        index: "house_rules".to_string(),
+        badge: Some(HOUSE_RULE_BADGE.to_string()),
+        house_rule_id: Some(found.rule.id.clone()),
+        overridden_by: None,
+        snippets: Vec::new(),
+    }
+}
+
+/// Present a house rule as a hybrid search result
+fn house_rule_hybrid_payload(found: &HouseRuleMatch) -> HybridSearchResultPayload {
+    let payload = house_rule_payload(found);
+    HybridSearchResultPayload {
+        content: payload.content,
+        source: payload.source,
+        source_type: payload.source_type,
+        page_number: payload.page_number,
+        score: payload.score,
+        index: payload.index,
         keyword_rank: None,
         semantic_rank: None,
-        // Native hybrid doesn't provide per-source overlap information.
         overlap_count: None,
-    })
+        badge: payload.badge,
+        house_rule_id: payload.house_rule_id,
+        overridden_by: None,
+        document_id: None,
+        score_breakdown: None,
+        rerank_score: None,
+        snippets: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(key: &str, content: &str, score: f32) -> LegHit {
+        LegHit {
+            key: format!("rules:{}", key),
+            document_id: Some(key.to_string()),
+            index: "rules".to_string(),
+            content: content.to_string(),
+            source: "Player's Handbook".to_string(),
+            source_type: "rules".to_string(),
+            page_number: Some(1),
+            attributes: Vec::new(),
+            facet_values: vec![(FacetField::GameSystem, "dnd5e".to_string())],
+            score,
+        }
+    }
+
+    #[test]
+    fn test_fuse_legs_ranks_shared_hits_first() {
+        let constraints = QueryParser::new().parse("grapple rules");
+        let keyword = vec![hit("a", "Grappling a creature", 0.9), hit("b", "Shoving a creature", 0.5)];
+        let semantic = vec![hit("c", "Restrained condition", 0.8), hit("b", "Shoving a creature", 0.7)];
+
+        let (results, facets) =
+            fuse_legs(keyword, semantic, &HashMap::new(), &constraints, RankingConfig::default(), AntonymMapper::new(), 200);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].document_id.as_deref(), Some("b"));
+        assert_eq!(results[0].keyword_rank, Some(1));
+        assert_eq!(results[0].semantic_rank, Some(1));
+        assert_eq!(results[0].overlap_count, Some(2));
+        let breakdown = results[0].score_breakdown.as_ref().unwrap();
+        assert!(breakdown.keyword_score > 0.0 && breakdown.semantic_score > 0.0);
+        assert_eq!(results[0].score, breakdown.final_score);
+        assert!(results.iter().skip(1).all(|r| r.overlap_count == Some(1)));
+        assert_eq!(facets[0].values[0].count, 3);
+    }
+
+    #[test]
+    fn test_fuse_legs_keeps_selected_facets() {
+        let constraints = QueryParser::new().parse("grapple");
+        let mut other_system = hit("b", "Grapple in Pathfinder", 0.5);
+        other_system.facet_values = vec![(FacetField::GameSystem, "pf2e".to_string())];
+        let keyword = vec![hit("a", "Grapple in D&D", 0.9), other_system];
+        let selected: HashMap<String, Vec<String>> =
+            [("game_system".to_string(), vec!["pf2e".to_string()])].into();
+
+        let (results, facets) =
+            fuse_legs(keyword, Vec::new(), &selected, &constraints, RankingConfig::default(), AntonymMapper::new(), 200);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document_id.as_deref(), Some("b"));
+        assert_eq!(facets[0].values[0].value, "pf2e");
+    }
+
+    #[test]
+    fn test_fuse_legs_highlights_query_terms() {
+        let constraints = QueryParser::new().parse("grapple");
+        let keyword = vec![hit("a", "You can Grapple a creature no more than one size larger.", 0.9)];
+
+        let (results, _) =
+            fuse_legs(keyword, Vec::new(), &HashMap::new(), &constraints, RankingConfig::default(), AntonymMapper::new(), 200);
+
+        assert_eq!(results[0].snippets.len(), 1);
+        assert_eq!(results[0].snippets[0].matched_terms(), vec!["Grapple"]);
+    }
 }
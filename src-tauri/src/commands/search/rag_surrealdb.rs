@@ -37,7 +37,7 @@ use crate::commands::state::AppState;
 use crate::core::llm::router::{ChatMessage, ChatRequest};
 use crate::core::storage::{
     prepare_rag_context, retrieve_rag_context, RagConfig, RagContext, RagSource, SearchFilter,
-    SurrealStorage,
+    SurrealStorage, VectorNamespace,
 };
 
 // ============================================================================
@@ -58,6 +58,18 @@ pub struct SurrealRagOptions {
     pub content_type: Option<String>,
     /// Filter by library item slug
     pub library_item: Option<String>,
+    /// Filter by the source document's game system ID (e.g. "dnd5e")
+    #[serde(default)]
+    pub game_system: Option<String>,
+    /// Filter by semantic chunk type (stat_block, spell, table, narrative)
+    #[serde(default)]
+    pub element_type: Option<String>,
+    /// Filter to chunks belonging to a campaign
+    #[serde(default)]
+    pub campaign_id: Option<String>,
+    /// Search only these namespaces ("library", "campaign:{id}", "notes", "npcs")
+    #[serde(default)]
+    pub namespaces: Option<Vec<VectorNamespace>>,
     /// Custom system prompt template (use {{context}} placeholder)
     pub system_template: Option<String>,
     /// Include source citations in response
@@ -77,6 +89,10 @@ impl Default for SurrealRagOptions {
             semantic_ratio: None,
             content_type: None,
             library_item: None,
+            game_system: None,
+            element_type: None,
+            campaign_id: None,
+            namespaces: None,
             system_template: None,
             include_sources: default_include_sources(),
         }
@@ -199,6 +215,26 @@ fn build_filter(opts: &SurrealRagOptions) -> Option<SearchFilter> {
         has_filter = true;
     }
 
+    if let Some(ref gs) = opts.game_system {
+        filter = filter.game_system(gs);
+        has_filter = true;
+    }
+
+    if let Some(ref et) = opts.element_type {
+        filter = filter.element_type(et);
+        has_filter = true;
+    }
+
+    if let Some(ref campaign_id) = opts.campaign_id {
+        filter = filter.campaign(campaign_id);
+        has_filter = true;
+    }
+
+    if let Some(ref namespaces) = opts.namespaces {
+        filter = filter.namespaces(namespaces.iter().cloned());
+        has_filter = true;
+    }
+
     if has_filter {
         Some(filter)
     } else {
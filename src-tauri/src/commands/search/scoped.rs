@@ -0,0 +1,317 @@
+//! Scoped Search Commands
+//!
+//! One search command whose scope selector picks what it covers: the shared
+//! library, a campaign's own content, or a single session's, each optionally
+//! combined with the library. Index hits and matching campaign notes, NPCs,
+//! locations, and handouts come back as one ranked list.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use futures::future::try_join_all;
+use tauri::State;
+
+use crate::commands::{AppState, HandoutState, HouseRuleState, SearchAnalyticsState};
+use crate::core::campaign::handouts::HandoutContent;
+use crate::core::search::scope::{
+    merge_ranked, rank_scoped_items, ScopedItem, ScopedKind, SearchScope, CAMPAIGN_INDEXES,
+    LIBRARY_INDEXES,
+};
+use crate::core::search::{extract_snippets, query_terms, snippet_length};
+use crate::core::search_analytics::SearchRecord;
+use crate::core::ttrpg_search::{FacetCounter, QueryParser};
+
+use super::analytics::record_search;
+use super::query::{
+    apply_reranking, build_hybrid_filter_expression, escape_filter_value, fused_search,
+    surface_house_rules,
+};
+use super::settings::SearchSettingsState;
+use super::term_sets::TermSetState;
+use super::types::{HybridSearchOptions, HybridSearchResponsePayload, HybridSearchResultPayload};
+
+/// Index name reported on campaign content results
+const CAMPAIGN_CONTENT_INDEX: &str = "campaign";
+
+/// Longest note title taken from a note's first line
+const NOTE_TITLE_CHARS: usize = 80;
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Search the library, a campaign, or a session.
+///
+/// The library scope searches rulebooks, fiction, and documents. A campaign
+/// scope searches the campaign's chat and documents plus its notes, NPCs,
+/// locations, and handouts; a session scope narrows that to the session's
+/// chat, notes, and handouts. Either can include the library too. All hits
+/// are merged with Reciprocal Rank Fusion and cut to the limit.
+///
+/// # Arguments
+/// * `query` - The search query string
+/// * `scope` - What to search (default: library)
+/// * `options` - As for hybrid search; source type and campaign filters
+///   apply to the library part
+///
+/// The search is recorded in search analytics under the returned `search_id`.
+#[tauri::command]
+pub async fn scoped_search(
+    query: String,
+    scope: Option<SearchScope>,
+    options: Option<HybridSearchOptions>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    handouts: State<'_, HandoutState>,
+    house_rules: State<'_, HouseRuleState>,
+    search_settings: State<'_, SearchSettingsState>,
+    analytics: State<'_, SearchAnalyticsState>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<HybridSearchResponsePayload, String> {
+    let scope = scope.unwrap_or_default();
+    let opts = options.unwrap_or_default();
+    let limit = opts.limit;
+    let start = Instant::now();
+
+    // Campaign and session the scope resolves to
+    let (campaign_id, session) = match &scope {
+        SearchScope::Library => (None, None),
+        SearchScope::Campaign { campaign_id, .. } => {
+            state
+                .campaign_manager
+                .get_campaign(campaign_id)
+                .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+            (Some(campaign_id.clone()), None)
+        }
+        SearchScope::Session { session_id, .. } => {
+            let session = state
+                .session_manager
+                .get_session(session_id)
+                .ok_or_else(|| format!("Session not found: {}", session_id))?;
+            (Some(session.campaign_id.clone()), Some((session.id.clone(), session.session_number)))
+        }
+    };
+
+    let mut targets: Vec<(Vec<String>, Option<String>)> = Vec::new();
+    if scope.includes_library() {
+        // Source type and campaign options narrow the library part only
+        let filter = build_hybrid_filter_expression(&opts).and_then(|f| f.as_str().map(str::to_string));
+        targets.push((LIBRARY_INDEXES.iter().map(|i| i.to_string()).collect(), filter));
+    }
+    if let Some(ref campaign_id) = campaign_id {
+        let filter = match session {
+            Some((ref session_id, _)) => format!("session_id = \"{}\"", escape_filter_value(session_id)),
+            None => format!("campaign_id = \"{}\"", escape_filter_value(campaign_id)),
+        };
+        targets.push((CAMPAIGN_INDEXES.iter().map(|i| i.to_string()).collect(), Some(filter)));
+    }
+
+    let constraints = QueryParser::new().parse(&query);
+    let antonyms = term_sets.antonym_mapper(&state, campaign_id.as_deref().or(opts.campaign_id.as_deref()));
+    let meili = state.embedded_search.clone_inner();
+    let searches = targets.into_iter().map(|(indexes, filter)| {
+        fused_search(meili.clone(), indexes, &query, filter, &opts, &constraints, antonyms.clone())
+    });
+    let mut lists: Vec<Vec<HybridSearchResultPayload>> = Vec::new();
+    let mut hints: Vec<String> = Vec::new();
+    let mut facets = FacetCounter::new();
+    for (results, target_facets, target_hints) in try_join_all(searches).await? {
+        lists.push(results);
+        facets.add_facets(&target_facets);
+        for hint in target_hints {
+            if !hints.contains(&hint) {
+                hints.push(hint);
+            }
+        }
+    }
+
+    if let Some(ref campaign_id) = campaign_id {
+        let items = campaign_items(
+            &state,
+            &handouts,
+            campaign_id,
+            session.as_ref().map(|(id, number)| (id.as_str(), *number)),
+        );
+        let terms = query_terms(&query);
+        let length = snippet_length(opts.snippet_length);
+        lists.push(
+            rank_scoped_items(&query, items)
+                .into_iter()
+                .enumerate()
+                .map(|(rank, (item, score))| scoped_item_payload(item, score, rank, &terms, length))
+                .collect(),
+        );
+    }
+
+    let mut results = merge_results(lists);
+    let total_hits = results.len();
+    results = apply_reranking(results, &query, limit, &mut hints, &search_settings, &app_handle).await;
+    results.truncate(limit);
+    hints.push(format!("Scope: {}", scope.label()));
+
+    let processing_time_ms = start.elapsed().as_millis() as u64;
+    log::debug!(
+        "Scoped search ({}) for '{}' returned {} results in {}ms",
+        scope.label(),
+        query,
+        results.len(),
+        processing_time_ms
+    );
+
+    let mut response = HybridSearchResponsePayload {
+        results,
+        total_hits,
+        original_query: query.clone(),
+        expanded_query: Some(constraints.expanded_query.clone())
+            .filter(|expanded| *expanded != query),
+        corrected_query: None,
+        processing_time_ms,
+        hints,
+        within_target: processing_time_ms < 500,
+        facets: facets.facets(),
+        search_id: None,
+    };
+
+    if let Some(ref house_rules_campaign_id) = opts.house_rules_campaign_id {
+        surface_house_rules(&mut response, house_rules_campaign_id, &query, limit, &house_rules);
+    }
+
+    let mut record = SearchRecord::new(query, response.results.len(), processing_time_ms, "scoped".to_string())
+        .with_source_filter(opts.source_type.clone());
+    record.campaign_id = campaign_id.or(opts.campaign_id);
+    response.search_id = Some(record_search(&analytics, &state.database, record));
+
+    Ok(response)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// The campaign's notes, NPCs, locations, and handouts, or with a session
+/// (ID and number) only that session's notes and handouts
+fn campaign_items(
+    state: &AppState,
+    handouts: &HandoutState,
+    campaign_id: &str,
+    session: Option<(&str, u32)>,
+) -> Vec<ScopedItem> {
+    let mut items = Vec::new();
+
+    for note in state.campaign_manager.search_notes(campaign_id, "", None) {
+        if session.is_some_and(|(_, number)| note.session_number != Some(number)) {
+            continue;
+        }
+        let first_line = note.content.lines().next().unwrap_or_default();
+        let title: String = first_line.chars().take(NOTE_TITLE_CHARS).collect();
+        let tags = note.tags.join(", ");
+        items.push(ScopedItem::new(ScopedKind::CampaignNote, &note.id, &title, &[&note.content, &tags]));
+    }
+
+    for note in state.session_manager.search_notes("", session.map(|(id, _)| id)) {
+        if note.campaign_id != campaign_id {
+            continue;
+        }
+        let tags = note.tags.join(", ");
+        items.push(ScopedItem::new(ScopedKind::SessionNote, &note.id, &note.title, &[&note.content, &tags]));
+    }
+
+    for handout in handouts.manager.list_handouts(campaign_id, session.map(|(id, _)| id), None) {
+        // Campaign-wide handouts aren't part of a session
+        if session.is_some_and(|(id, _)| handout.session_id.as_deref() != Some(id)) {
+            continue;
+        }
+        let text = match handout.content {
+            HandoutContent::Text { ref text } => text.as_str(),
+            _ => "",
+        };
+        items.push(ScopedItem::new(
+            ScopedKind::Handout,
+            &handout.id,
+            &handout.title,
+            &[&handout.caption, text, &handout.gm_notes, &handout.tags.join(", ")],
+        ));
+    }
+
+    if session.is_none() {
+        for npc in state.npc_store.list(Some(campaign_id)) {
+            let role = format!("{:?}", npc.role);
+            items.push(ScopedItem::new(
+                ScopedKind::Npc,
+                &npc.id,
+                &npc.name,
+                &[&role, &npc.notes, &npc.appearance.distinguishing_features.join(", "), &npc.tags.join(", ")],
+            ));
+        }
+        for location in state.location_manager.list_locations_for_campaign(campaign_id) {
+            items.push(ScopedItem::new(
+                ScopedKind::Location,
+                &location.id,
+                &location.name,
+                &[&location.description, &location.notes, &location.tags.join(", ")],
+            ));
+        }
+    }
+
+    items
+}
+
+fn scoped_item_payload(
+    item: ScopedItem,
+    score: f32,
+    rank: usize,
+    terms: &[String],
+    snippet_length: usize,
+) -> HybridSearchResultPayload {
+    HybridSearchResultPayload {
+        snippets: extract_snippets(&item.body, terms, snippet_length),
+        content: item.body,
+        source: item.title,
+        source_type: item.kind.source_type().to_string(),
+        page_number: None,
+        score,
+        index: CAMPAIGN_CONTENT_INDEX.to_string(),
+        keyword_rank: Some(rank),
+        semantic_rank: None,
+        overlap_count: Some(1),
+        badge: None,
+        house_rule_id: None,
+        overridden_by: None,
+        document_id: Some(item.id),
+        score_breakdown: None,
+        rerank_score: None,
+    }
+}
+
+/// Merge ranked result lists with RRF. A result in several lists is kept
+/// once, and every result's score becomes its fused score.
+fn merge_results(lists: Vec<Vec<HybridSearchResultPayload>>) -> Vec<HybridSearchResultPayload> {
+    let mut by_key: HashMap<String, HybridSearchResultPayload> = HashMap::new();
+    let keys: Vec<Vec<String>> = lists
+        .into_iter()
+        .enumerate()
+        .map(|(list, results)| {
+            results
+                .into_iter()
+                .enumerate()
+                .map(|(position, result)| {
+                    let key = match result.document_id {
+                        Some(ref id) => format!("{}:{}:{}", result.index, result.source_type, id),
+                        None => format!("{}:{}:{}", result.index, list, position),
+                    };
+                    by_key.entry(key.clone()).or_insert(result);
+                    key
+                })
+                .collect()
+        })
+        .collect();
+
+    merge_ranked(&keys)
+        .into_iter()
+        .filter_map(|(key, score)| {
+            let mut result = by_key.remove(&key)?;
+            result.score = score;
+            Some(result)
+        })
+        .collect()
+}
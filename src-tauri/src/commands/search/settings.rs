@@ -0,0 +1,358 @@
+//! Search Settings Commands
+//!
+//! Persisted search settings: the optional cross-encoder re-ranking stage,
+//! the Meilisearch relevancy tuning of the library indexes, and the vector
+//! index tuning, with the managed state that holds the loaded model.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use meilisearch_lib::MeilisearchLib;
+use tauri::{Manager, State};
+
+use crate::commands::AppState;
+use crate::core::search::index_tuning::IndexTuning;
+use crate::core::search::vector_tuning::VectorTuning;
+use crate::core::search::rerank::{CrossEncoder, RerankModel, RERANK_CANDIDATE_RANGE};
+use crate::core::search::{all_indexes, TASK_TIMEOUT_LONG_SECS};
+use crate::core::storage::set_vector_search_ef;
+use super::indexes::retune_vector_indexes;
+use super::library::load_library_documents;
+use super::types::SearchSettings;
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_search_config_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    if !dir.exists() {
+        let _ = std::fs::create_dir_all(&dir);
+    }
+    dir.join("search_settings.json")
+}
+
+/// Load search settings from disk
+fn load_search_config_disk(app_handle: &tauri::AppHandle) -> Option<SearchSettings> {
+    let path = get_search_config_path(app_handle);
+    if path.exists() {
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&content) {
+                Ok(settings) => return Some(settings),
+                Err(e) => log::warn!("Failed to parse search settings: {}", e),
+            }
+        }
+    }
+    None
+}
+
+/// Save search settings to disk
+fn save_search_config_disk(app_handle: &tauri::AppHandle, settings: &SearchSettings) -> Result<(), String> {
+    let path = get_search_config_path(app_handle);
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&path, json)
+        .map_err(|e| format!("Failed to write settings to {}: {}", path.display(), e))?;
+    log::info!("Search settings saved to disk");
+    Ok(())
+}
+
+/// Where downloaded re-ranking models are cached
+fn rerank_model_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    app_handle
+        .path()
+        .app_data_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("models")
+        .join("rerank")
+}
+
+// ============================================================================
+// Search Settings State
+// ============================================================================
+
+/// Managed state holding search settings and the loaded cross-encoder
+#[derive(Default)]
+pub struct SearchSettingsState {
+    settings: RwLock<SearchSettings>,
+    reranker: RwLock<Option<Arc<CrossEncoder>>>,
+    loading: AtomicBool,
+}
+
+impl SearchSettingsState {
+    /// State with the settings saved on disk, or defaults
+    pub fn load(app_handle: &tauri::AppHandle) -> Self {
+        let state = Self::default();
+        if let Some(settings) = load_search_config_disk(app_handle) {
+            set_vector_search_ef(settings.vector_tuning.search_ef());
+            *state.settings.write().unwrap_or_else(|e| e.into_inner()) = settings;
+        }
+        state
+    }
+
+    pub fn settings(&self) -> SearchSettings {
+        self.settings.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// The cross-encoder for the configured model, if loaded.
+    ///
+    /// When it isn't, loading starts in the background and `None` comes
+    /// back, so a first download never holds up a search.
+    pub fn reranker(&self, app_handle: &tauri::AppHandle) -> Option<Arc<CrossEncoder>> {
+        let model = self.settings().rerank.model;
+        let current = self.reranker.read().unwrap_or_else(|e| e.into_inner()).clone();
+        match current {
+            Some(reranker) if reranker.model() == model => Some(reranker),
+            _ => {
+                self.start_loading(app_handle.clone(), model);
+                None
+            }
+        }
+    }
+
+    /// The cross-encoder and how many candidates it re-scores for a search
+    /// of `limit` results, when re-ranking is on and the model is loaded
+    pub fn active_reranker(&self, app_handle: &tauri::AppHandle, limit: usize) -> Option<(Arc<CrossEncoder>, usize)> {
+        let rerank = self.settings().rerank;
+        if !rerank.enabled {
+            return None;
+        }
+        self.reranker(app_handle).map(|reranker| (reranker, rerank.candidate_count(limit)))
+    }
+
+    fn start_loading(&self, app_handle: tauri::AppHandle, model: RerankModel) {
+        if !CrossEncoder::is_supported() || self.loading.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        tauri::async_runtime::spawn_blocking(move || {
+            let state = app_handle.state::<SearchSettingsState>();
+            match CrossEncoder::load(model, &rerank_model_dir(&app_handle)) {
+                Ok(reranker) => {
+                    log::info!("Loaded re-ranking model {:?}", model);
+                    *state.reranker.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(reranker));
+                }
+                Err(e) => log::warn!("{}", e),
+            }
+            state.loading.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// Whether a model load is in progress
+    pub fn is_loading(&self) -> bool {
+        self.loading.load(Ordering::SeqCst)
+    }
+}
+
+// ============================================================================
+// Search Settings Commands
+// ============================================================================
+
+/// Get current search settings
+#[tauri::command]
+pub async fn get_search_settings(
+    settings: State<'_, SearchSettingsState>,
+) -> Result<SearchSettings, String> {
+    Ok(settings.settings())
+}
+
+/// Save search settings.
+///
+/// Enabling re-ranking starts loading the model in the background; the
+/// first use downloads it into the app data directory. Changed index
+/// tuning is applied to the existing indexes before this returns.
+#[tauri::command]
+pub async fn save_search_settings(
+    mut settings: SearchSettings,
+    state: State<'_, SearchSettingsState>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    if settings.rerank.enabled && !CrossEncoder::is_supported() {
+        return Err("Re-ranking is not available in this build".to_string());
+    }
+    let (min, max) = RERANK_CANDIDATE_RANGE;
+    if !(min..=max).contains(&settings.rerank.candidates) {
+        return Err(format!("Re-ranking candidates must be between {} and {}", min, max));
+    }
+    settings.index_tuning = settings.index_tuning.validated().map_err(|e| e.to_string())?;
+    settings.vector_tuning = settings.vector_tuning.validated().map_err(|e| e.to_string())?;
+    let tuning_changed = settings.index_tuning != state.settings().index_tuning;
+    use_vector_tuning(&app_state, &state.settings().vector_tuning, &settings.vector_tuning).await?;
+
+    *state.settings.write().unwrap_or_else(|e| e.into_inner()) = settings.clone();
+    save_search_config_disk(&app_handle, &settings)?;
+
+    if tuning_changed {
+        apply_index_tuning(&app_state, settings.index_tuning).await?;
+    }
+    if settings.rerank.enabled {
+        state.reranker(&app_handle);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Index Tuning Commands
+// ============================================================================
+
+/// Get the typo tolerance, ranking rules, stop words, and distinct
+/// attribute used for the library indexes
+#[tauri::command]
+pub async fn get_index_tuning(
+    settings: State<'_, SearchSettingsState>,
+) -> Result<IndexTuning, String> {
+    Ok(settings.settings().index_tuning)
+}
+
+/// Save new index tuning and apply it to the existing indexes; indexes
+/// created later get it as well.
+///
+/// Returns the tuning as saved, with word lists normalized.
+#[tauri::command]
+pub async fn update_index_tuning(
+    tuning: IndexTuning,
+    state: State<'_, SearchSettingsState>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<IndexTuning, String> {
+    let tuning = tuning.validated().map_err(|e| e.to_string())?;
+    save_index_tuning(&state, &app_handle, tuning.clone())?;
+    apply_index_tuning(&app_state, tuning.clone()).await?;
+    Ok(tuning)
+}
+
+/// Restore the default index tuning and apply it to the existing indexes
+#[tauri::command]
+pub async fn reset_index_tuning(
+    state: State<'_, SearchSettingsState>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<IndexTuning, String> {
+    let tuning = IndexTuning::default();
+    save_index_tuning(&state, &app_handle, tuning.clone())?;
+    apply_index_tuning(&app_state, tuning.clone()).await?;
+    Ok(tuning)
+}
+
+// ============================================================================
+// Vector Tuning Commands
+// ============================================================================
+
+/// Get the vector index structure, precision, and HNSW parameters
+#[tauri::command]
+pub async fn get_vector_tuning(
+    state: State<'_, SearchSettingsState>,
+) -> Result<VectorTuning, String> {
+    Ok(state.settings().vector_tuning)
+}
+
+/// Save new vector tuning. Search parameters apply at once; a new index
+/// structure, precision, or graph parameters redefine the vector indexes,
+/// which re-indexes every embedding.
+#[tauri::command]
+pub async fn update_vector_tuning(
+    tuning: VectorTuning,
+    state: State<'_, SearchSettingsState>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<VectorTuning, String> {
+    let tuning = tuning.validated().map_err(|e| e.to_string())?;
+    apply_vector_tuning(&state, &app_state, &app_handle, tuning.clone()).await?;
+    Ok(tuning)
+}
+
+/// Restore the default vector tuning
+#[tauri::command]
+pub async fn reset_vector_tuning(
+    state: State<'_, SearchSettingsState>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<VectorTuning, String> {
+    let tuning = VectorTuning::default();
+    apply_vector_tuning(&state, &app_state, &app_handle, tuning.clone()).await?;
+    Ok(tuning)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Save the vector tuning once it is in use
+async fn apply_vector_tuning(
+    state: &SearchSettingsState,
+    app_state: &AppState,
+    app_handle: &tauri::AppHandle,
+    tuning: VectorTuning,
+) -> Result<(), String> {
+    use_vector_tuning(app_state, &state.settings().vector_tuning, &tuning).await?;
+    let mut settings = state.settings.write().unwrap_or_else(|e| e.into_inner());
+    settings.vector_tuning = tuning;
+    save_search_config_disk(app_handle, &settings)
+}
+
+/// Redefine the vector indexes if the tuning changes them, and search with
+/// its parameters
+async fn use_vector_tuning(app_state: &AppState, current: &VectorTuning, tuning: &VectorTuning) -> Result<(), String> {
+    if tuning.needs_reindex(current) {
+        let storage = app_state
+            .surreal_storage
+            .as_ref()
+            .ok_or_else(|| "SurrealDB storage not initialized".to_string())?;
+        let count = retune_vector_indexes(storage, tuning).await?;
+        log::info!("Redefined {} vector indexes with new tuning", count);
+    }
+    set_vector_search_ef(tuning.search_ef());
+    Ok(())
+}
+
+fn save_index_tuning(
+    state: &SearchSettingsState,
+    app_handle: &tauri::AppHandle,
+    tuning: IndexTuning,
+) -> Result<(), String> {
+    let mut settings = state.settings.write().unwrap_or_else(|e| e.into_inner());
+    settings.index_tuning = tuning;
+    save_search_config_disk(app_handle, &settings)
+}
+
+/// Give new chunk indexes the tuning, and update every existing content
+/// and chunk index. Returns the number of indexes updated.
+async fn apply_index_tuning(state: &AppState, tuning: IndexTuning) -> Result<usize, String> {
+    state.ingestion_pipeline.set_index_tuning(tuning.clone());
+
+    let meili = state.embedded_search.clone_inner();
+    let mut indexes: Vec<String> = all_indexes().into_iter().map(str::to_string).collect();
+    for doc in load_library_documents(meili.clone()).await? {
+        if !indexes.contains(&doc.content_index) {
+            indexes.push(doc.content_index);
+        }
+    }
+
+    let count = tokio::task::spawn_blocking(move || update_index_settings(&meili, &indexes, &tuning))
+        .await
+        .map_err(|e| format!("Index settings task failed: {}", e))??;
+    log::info!("Applied index tuning to {} search indexes", count);
+    Ok(count)
+}
+
+fn update_index_settings(meili: &MeilisearchLib, indexes: &[String], tuning: &IndexTuning) -> Result<usize, String> {
+    let mut updated = 0;
+    for index in indexes {
+        // Indexes are created on first ingestion and get the tuning then
+        if !meili.index_exists(index).map_err(|e| e.to_string())? {
+            continue;
+        }
+        let settings = tuning.meili_settings().map_err(|e| e.to_string())?;
+        let task = meili
+            .update_settings(index, settings)
+            .map_err(|e| format!("Failed to update settings for '{}': {}", index, e))?;
+        // Changing ranking or typo settings reindexes the documents
+        meili
+            .wait_for_task(task.uid, Some(Duration::from_secs(TASK_TIMEOUT_LONG_SECS)))
+            .map_err(|e| format!("Settings update for '{}' failed: {}", index, e))?;
+        updated += 1;
+    }
+    Ok(updated)
+}
@@ -2,42 +2,175 @@
 //!
 //! Commands for autocomplete, query hints, query expansion, and spell correction.
 //!
+//! Autocomplete is served from an in-memory suggestion index. Query hints
+//! are still stubbed out.
+//!
 //! TODO: Phase 3 Migration - HybridSearchEngine needs to be updated to work with
-//! EmbeddedSearch/MeilisearchLib. Currently stubbed out.
+//! EmbeddedSearch/MeilisearchLib before hints can return.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
-use tauri::State;
+use tauri::{Manager, State};
 
-use crate::commands::AppState;
+use crate::commands::{AppState, SearchAnalyticsState};
+use crate::core::preprocess::get_corpus_dictionary_path;
+use crate::core::search::autocomplete::{
+    Suggestion, SuggestionIndex, SuggestionKind, DEFAULT_SUGGESTION_LIMIT, MAX_CORPUS_TERMS,
+};
+use crate::database::{SearchAnalyticsOps, TtrpgOps};
 // TODO: Re-enable when HybridSearchEngine is migrated to EmbeddedSearch
 // use crate::core::search::HybridSearchEngine;
 
+/// The suggestion index is rebuilt in the background once older than this
+const SUGGESTION_INDEX_MAX_AGE: Duration = Duration::from_secs(300);
+
+/// Past searches included in the suggestion index
+const MAX_HISTORY_QUERIES: usize = 500;
+
+/// Ingested element types suggested by name
+const NAMED_ELEMENT_TYPES: [(&str, SuggestionKind); 3] = [
+    ("spell", SuggestionKind::Spell),
+    ("monster", SuggestionKind::Monster),
+    ("stat_block", SuggestionKind::Monster),
+];
+
+// ============================================================================
+// Suggestion Index State
+// ============================================================================
+
+/// Managed state holding the autocomplete index
+#[derive(Default)]
+pub struct SuggestionState {
+    index: RwLock<Option<(Arc<SuggestionIndex>, Instant)>>,
+    rebuilding: AtomicBool,
+}
+
+impl SuggestionState {
+    /// The current index and whether it is due for a rebuild
+    fn current(&self) -> Option<(Arc<SuggestionIndex>, bool)> {
+        let guard = self.index.read().unwrap_or_else(|e| e.into_inner());
+        guard
+            .as_ref()
+            .map(|(index, built_at)| (index.clone(), built_at.elapsed() > SUGGESTION_INDEX_MAX_AGE))
+    }
+
+    fn replace(&self, index: SuggestionIndex) -> Arc<SuggestionIndex> {
+        let index = Arc::new(index);
+        *self.index.write().unwrap_or_else(|e| e.into_inner()) = Some((index.clone(), Instant::now()));
+        index
+    }
+}
+
+/// Build the index from the corpus dictionary, campaign NPCs and locations,
+/// ingested spells and monsters, and past searches
+async fn build_suggestion_index(state: &AppState, analytics: &SearchAnalyticsState) -> SuggestionIndex {
+    let start = Instant::now();
+    let mut index = SuggestionIndex::new();
+
+    if let Some(path) = get_corpus_dictionary_path() {
+        if let Err(e) = index.add_dictionary_file(&path, MAX_CORPUS_TERMS) {
+            log::warn!("Could not read corpus dictionary {}: {}", path.display(), e);
+        }
+    }
+
+    for npc in state.npc_store.list(None) {
+        index.add(&npc.name, SuggestionKind::Npc, 1);
+    }
+    for location in state.location_manager.list_all() {
+        index.add(&location.name, SuggestionKind::Location, 1);
+    }
+    for (element_type, kind) in NAMED_ELEMENT_TYPES {
+        match state.database.list_ttrpg_documents_by_type(element_type).await {
+            Ok(records) => records.iter().for_each(|r| index.add(&r.name, kind, 1)),
+            Err(e) => log::warn!("Could not load {} names for suggestions: {}", element_type, e),
+        }
+    }
+
+    // Past searches from earlier sessions and this one
+    let mut history: HashMap<String, u64> = HashMap::new();
+    match state.database.get_popular_queries(MAX_HISTORY_QUERIES).await {
+        Ok(records) => {
+            for record in records {
+                history.insert(record.query, record.count.max(0) as u64);
+            }
+        }
+        Err(e) => log::warn!("Could not load search history for suggestions: {}", e),
+    }
+    for (query, count) in analytics.analytics.get_popular_queries(MAX_HISTORY_QUERIES) {
+        let known = history.entry(query).or_insert(0);
+        *known = (*known).max(count as u64);
+    }
+    for (query, count) in history {
+        index.add(&query, SuggestionKind::History, count);
+    }
+
+    index.finish();
+    log::debug!("Built suggestion index with {} entries in {:?}", index.len(), start.elapsed());
+    index
+}
+
+/// Rebuild the index off the request path, unless a rebuild is running
+fn refresh_in_background(app_handle: tauri::AppHandle) {
+    let suggestions = app_handle.state::<SuggestionState>();
+    if suggestions.rebuilding.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        let state = app_handle.state::<AppState>();
+        let analytics = app_handle.state::<SearchAnalyticsState>();
+        let suggestions = app_handle.state::<SuggestionState>();
+        suggestions.replace(build_suggestion_index(&state, &analytics).await);
+        suggestions.rebuilding.store(false, Ordering::SeqCst);
+    });
+}
+
 // ============================================================================
 // Search Suggestions and Hints
 // ============================================================================
 
-/// Get search suggestions for autocomplete
+/// Get ranked completions for as-you-type search.
 ///
-/// TODO: Phase 3 Migration - Update HybridSearchEngine to work with EmbeddedSearch
+/// Draws on corpus terms, NPC and location names, ingested spells and
+/// monsters, and past searches. The index is built on first use and
+/// refreshed in the background when it goes stale, so lookups stay fast.
+///
+/// # Arguments
+/// * `partial` - What has been typed so far
+/// * `limit` - Most completions to return (default 8)
 #[tauri::command]
-#[allow(unused_variables)]
-pub fn get_search_suggestions(
+pub async fn get_search_suggestions(
     partial: String,
+    limit: Option<usize>,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<Vec<String>, String> {
-    // TODO: Migrate to embedded MeilisearchLib
-    // HybridSearchEngine::with_defaults expects Arc<SearchClient> (HTTP SDK).
-    // Need to update HybridSearchEngine to work with EmbeddedSearch/MeilisearchLib.
-    //
-    // Access via: state.embedded_search.inner()
-    let _meili = state.embedded_search.inner();
-
-    log::warn!(
-        "get_search_suggestions() called but not yet migrated to embedded MeilisearchLib. Partial: {}",
-        partial
-    );
+    analytics: State<'_, SearchAnalyticsState>,
+    suggestions: State<'_, SuggestionState>,
+) -> Result<Vec<Suggestion>, String> {
+    let index = match suggestions.current() {
+        Some((index, stale)) => {
+            if stale {
+                refresh_in_background(app_handle);
+            }
+            index
+        }
+        None => suggestions.replace(build_suggestion_index(&state, &analytics).await),
+    };
+    Ok(index.suggest(&partial, limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT)))
+}
 
-    // Return empty suggestions with explicit Ok - migration in Phase 3 Task 6
-    Ok(Vec::new())
+/// Rebuild the suggestion index now, e.g. after ingesting documents.
+/// Returns the number of entries indexed.
+#[tauri::command]
+pub async fn rebuild_suggestion_index(
+    state: State<'_, AppState>,
+    analytics: State<'_, SearchAnalyticsState>,
+    suggestions: State<'_, SuggestionState>,
+) -> Result<usize, String> {
+    let index = suggestions.replace(build_suggestion_index(&state, &analytics).await);
+    Ok(index.len())
 }
 
 /// Get search hints for a query
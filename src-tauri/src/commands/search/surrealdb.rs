@@ -26,8 +26,8 @@ use tauri::State;
 
 use crate::commands::state::AppState;
 use crate::core::storage::{
-    fulltext_search, hybrid_search, vector_search, HybridSearchConfig, SearchFilter, SearchResult,
-    SurrealStorage,
+    find_similar_chunks, fulltext_search, hybrid_search, vector_search, HybridSearchConfig,
+    SearchFilter, SearchResult, StorageError, SurrealStorage, VectorNamespace,
 };
 
 // ============================================================================
@@ -109,6 +109,18 @@ pub struct SurrealSearchOptions {
     pub page_min: Option<i32>,
     /// Maximum page number
     pub page_max: Option<i32>,
+    /// Filter by the source document's game system ID (e.g. "dnd5e")
+    #[serde(default)]
+    pub game_system: Option<String>,
+    /// Filter by semantic chunk type (stat_block, spell, table, narrative)
+    #[serde(default)]
+    pub element_type: Option<String>,
+    /// Filter to chunks belonging to a campaign
+    #[serde(default)]
+    pub campaign_id: Option<String>,
+    /// Search only these namespaces ("library", "campaign:{id}", "notes", "npcs")
+    #[serde(default)]
+    pub namespaces: Option<Vec<VectorNamespace>>,
 }
 
 fn default_limit() -> usize {
@@ -124,6 +136,10 @@ impl Default for SurrealSearchOptions {
             library_item: None,
             page_min: None,
             page_max: None,
+            game_system: None,
+            element_type: None,
+            campaign_id: None,
+            namespaces: None,
         }
     }
 }
@@ -143,6 +159,19 @@ fn get_storage(state: &AppState) -> Result<Arc<SurrealStorage>, String> {
         .ok_or_else(|| "SurrealDB storage not initialized".to_string())
 }
 
+/// Check a content type or library slug is safe to put in a filter
+fn check_filter_value(value: &str) -> Result<(), String> {
+    let valid = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid filter value: '{}'", value))
+    }
+}
+
 /// Build SearchFilter from options.
 fn build_filter(opts: &SurrealSearchOptions) -> Option<SearchFilter> {
     let mut filter = SearchFilter::new();
@@ -158,6 +187,26 @@ fn build_filter(opts: &SurrealSearchOptions) -> Option<SearchFilter> {
         has_filter = true;
     }
 
+    if let Some(ref gs) = opts.game_system {
+        filter = filter.game_system(gs);
+        has_filter = true;
+    }
+
+    if let Some(ref et) = opts.element_type {
+        filter = filter.element_type(et);
+        has_filter = true;
+    }
+
+    if let Some(ref campaign_id) = opts.campaign_id {
+        filter = filter.campaign(campaign_id);
+        has_filter = true;
+    }
+
+    if let Some(ref namespaces) = opts.namespaces {
+        filter = filter.namespaces(namespaces.iter().cloned());
+        has_filter = true;
+    }
+
     if opts.page_min.is_some() || opts.page_max.is_some() {
         filter = filter.page_range(opts.page_min, opts.page_max);
         has_filter = true;
@@ -421,6 +470,72 @@ pub async fn keyword_search_surrealdb(
     })
 }
 
+// ============================================================================
+// SIMILAR CONTENT
+// ============================================================================
+
+/// Find content similar to a chunk ("more like this").
+///
+/// Uses the chunk's stored embedding, so it works offline and costs no
+/// embedding calls. Useful for finding related lore or alternative monsters
+/// from a result the GM already has open.
+///
+/// # Arguments
+///
+/// * `chunk_id` - ID of the chunk to match (with or without the `chunk:` prefix)
+/// * `limit` - Maximum results to return (default: 10)
+/// * `content_types` - Only chunks of these content types (default: all)
+/// * `library_items` - Only chunks from these library item slugs (default: all)
+/// * `state` - Application state with SurrealDB connection
+///
+/// # Returns
+///
+/// Nearest chunks, most similar first, with `score` as cosine similarity
+/// (1.0 = identical). The source chunk is not included.
+#[tauri::command]
+pub async fn find_similar(
+    chunk_id: String,
+    limit: Option<usize>,
+    content_types: Option<Vec<String>>,
+    library_items: Option<Vec<String>>,
+    state: State<'_, AppState>,
+) -> Result<Vec<SurrealSearchHit>, String> {
+    let chunk_id = chunk_id.trim().trim_start_matches("chunk:").to_string();
+    if chunk_id.is_empty() {
+        return Err("Chunk ID cannot be empty".to_string());
+    }
+    let limit = limit.unwrap_or_else(default_limit).clamp(1, 100);
+
+    let content_types = content_types.unwrap_or_default();
+    let library_items = library_items.unwrap_or_default();
+    for value in content_types.iter().chain(&library_items) {
+        check_filter_value(value)?;
+    }
+    let filter = SearchFilter::new()
+        .content_types(content_types)
+        .library_items(library_items)
+        .to_surql();
+
+    let storage = get_storage(&state)?;
+    let results = find_similar_chunks(storage.db(), &chunk_id, limit, filter.as_deref())
+        .await
+        .map_err(|e| match e {
+            StorageError::NotFound(_) => format!("Chunk not found: {}", chunk_id),
+            StorageError::Embedding(_) => format!("Chunk {} has not been embedded yet", chunk_id),
+            e => format!("Similar content search failed: {}", e),
+        })?;
+
+    log::debug!("[find_similar] {} results for chunk:{}", results.len(), chunk_id);
+
+    Ok(results
+        .into_iter()
+        .map(|r| SurrealSearchHit {
+            score: (1.0 - r.score).clamp(0.0, 1.0),
+            ..SurrealSearchHit::from(r)
+        })
+        .collect())
+}
+
 // ============================================================================
 // SUGGESTIONS (Task 6.1.2)
 // ============================================================================
@@ -626,6 +741,37 @@ mod tests {
         assert!(surql.unwrap().contains("content_type"));
     }
 
+    #[test]
+    fn test_build_filter_with_metadata() {
+        let opts = SurrealSearchOptions {
+            game_system: Some("dnd5e".to_string()),
+            element_type: Some("spell".to_string()),
+            campaign_id: Some("c-1".to_string()),
+            ..Default::default()
+        };
+        let surql = build_filter(&opts).unwrap().to_surql().unwrap();
+        assert!(surql.contains("library_item.game_system_id = 'dnd5e'"));
+        assert!(surql.contains("chunk_type = 'spell'"));
+        assert!(surql.contains("metadata.campaign_id = 'c-1'"));
+    }
+
+    #[test]
+    fn test_build_filter_with_namespaces() {
+        let opts: SurrealSearchOptions =
+            serde_json::from_str(r#"{"limit": 5, "namespaces": ["notes", "campaign:c-1"]}"#).unwrap();
+        let surql = build_filter(&opts).unwrap().to_surql().unwrap();
+        assert!(surql.contains("namespace IN ['notes', 'campaign:c-1']"));
+        assert!(serde_json::from_str::<SurrealSearchOptions>(r#"{"namespaces": ["rules"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_check_filter_value() {
+        assert!(check_filter_value("phb-2024").is_ok());
+        assert!(check_filter_value("session_notes").is_ok());
+        assert!(check_filter_value("").is_err());
+        assert!(check_filter_value("rules' OR true OR '").is_err());
+    }
+
     #[test]
     fn test_build_filter_with_page_range() {
         let opts = SurrealSearchOptions {
@@ -0,0 +1,243 @@
+//! Custom Term Set Commands
+//!
+//! CRUD for user-defined synonym and antonym sets, scoped to a game system
+//! or a campaign. Synonym sets are pushed into the search indexes' synonym
+//! settings whenever they change; antonym sets feed the result ranker's
+//! antonym penalty for searches in the matching campaign or system.
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use meilisearch_lib::{MeilisearchLib, Setting, Settings, Unchecked};
+use tauri::{Manager, State};
+
+use crate::commands::AppState;
+use crate::core::character_gen::GameSystem;
+use crate::core::search::all_indexes;
+use crate::core::search::term_sets::{TermSet, TermSetKind, TermSetLibrary, TermSetScope};
+use crate::core::ttrpg_search::AntonymMapper;
+
+/// How long to wait for an index to apply new synonyms
+const SYNONYM_TASK_TIMEOUT: Duration = Duration::from_secs(30);
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_term_sets_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    app_handle
+        .path()
+        .app_data_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("term_sets.json")
+}
+
+// ============================================================================
+// Term Set State
+// ============================================================================
+
+/// Managed state holding the custom term sets
+pub struct TermSetState {
+    library: RwLock<TermSetLibrary>,
+    path: PathBuf,
+}
+
+impl TermSetState {
+    /// State with the sets saved on disk, or none
+    pub fn load(app_handle: &tauri::AppHandle) -> Self {
+        let path = get_term_sets_path(app_handle);
+        let library = TermSetLibrary::load(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load term sets: {}", e);
+            TermSetLibrary::default()
+        });
+        Self { library: RwLock::new(library), path }
+    }
+
+    /// Apply a change to the library and save it
+    fn update<T>(
+        &self,
+        change: impl FnOnce(&mut TermSetLibrary) -> crate::core::search::term_sets::Result<T>,
+    ) -> Result<T, String> {
+        let mut library = self.library.write().unwrap_or_else(|e| e.into_inner());
+        let value = change(&mut library).map_err(|e| e.to_string())?;
+        library.save(&self.path).map_err(|e| e.to_string())?;
+        Ok(value)
+    }
+
+    /// The antonym mapper for a search in `campaign_id`, with the sets for
+    /// the campaign and its game system; only the built-in pairs without one
+    pub fn antonym_mapper(&self, state: &AppState, campaign_id: Option<&str>) -> AntonymMapper {
+        let game_system = campaign_id
+            .and_then(|id| state.campaign_manager.get_campaign(id))
+            .map(|campaign| GameSystem::from_str(&campaign.system).id().to_string());
+        self.library
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .antonym_mapper(game_system.as_deref(), campaign_id)
+    }
+
+    /// Remove a deleted campaign's sets
+    pub fn delete_campaign_sets(&self, campaign_id: &str) -> Result<(), String> {
+        self.update(|library| {
+            library.delete_campaign_sets(campaign_id);
+            Ok(())
+        })
+    }
+}
+
+// ============================================================================
+// Term Set Commands
+// ============================================================================
+
+/// List custom term sets.
+///
+/// # Arguments
+/// * `kind` - Only synonym or only antonym sets (default: both)
+/// * `game_system` - Only sets for this game system...
+/// * `campaign_id` - ...or this campaign
+#[tauri::command]
+pub async fn list_term_sets(
+    kind: Option<TermSetKind>,
+    game_system: Option<String>,
+    campaign_id: Option<String>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<Vec<TermSet>, String> {
+    let game_system = game_system.map(|s| GameSystem::from_str(&s).id().to_string());
+    Ok(term_sets
+        .library
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .list(kind, game_system.as_deref(), campaign_id.as_deref()))
+}
+
+/// Create a synonym or antonym set.
+///
+/// # Arguments
+/// * `kind` - Synonym or antonym
+/// * `scope` - The game system or campaign it applies to
+/// * `terms` - The synonyms, or the terms opposed to `opposites`
+/// * `opposites` - For an antonym set, the opposing terms
+///
+/// A synonym set is applied to the search indexes before this returns.
+#[tauri::command]
+pub async fn create_term_set(
+    kind: TermSetKind,
+    scope: TermSetScope,
+    terms: Vec<String>,
+    opposites: Option<Vec<String>>,
+    state: State<'_, AppState>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<TermSet, String> {
+    let scope = validate_scope(scope, &state)?;
+    let set = TermSet::new(kind, scope, &terms, &opposites.unwrap_or_default()).map_err(|e| e.to_string())?;
+    let set = term_sets.update(|library| Ok(library.create(set)))?;
+    if kind == TermSetKind::Synonym {
+        sync_synonyms(&state, &term_sets).await?;
+    }
+    Ok(set)
+}
+
+/// Replace a set's terms and opposites, and optionally move it to another
+/// scope
+#[tauri::command]
+pub async fn update_term_set(
+    id: String,
+    terms: Vec<String>,
+    opposites: Option<Vec<String>>,
+    scope: Option<TermSetScope>,
+    state: State<'_, AppState>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<TermSet, String> {
+    let scope = scope.map(|scope| validate_scope(scope, &state)).transpose()?;
+    let opposites = opposites.unwrap_or_default();
+    let set = term_sets.update(|library| library.update(&id, &terms, &opposites, scope))?;
+    if set.kind == TermSetKind::Synonym {
+        sync_synonyms(&state, &term_sets).await?;
+    }
+    Ok(set)
+}
+
+/// Delete a set
+#[tauri::command]
+pub async fn delete_term_set(
+    id: String,
+    state: State<'_, AppState>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<(), String> {
+    let set = term_sets.update(|library| library.delete(&id))?;
+    if set.kind == TermSetKind::Synonym {
+        sync_synonyms(&state, &term_sets).await?;
+    }
+    Ok(())
+}
+
+/// Push the synonym sets into the search indexes again, as after
+/// recreating an index. Returns the number of terms with synonyms.
+#[tauri::command]
+pub async fn sync_search_synonyms(
+    state: State<'_, AppState>,
+    term_sets: State<'_, TermSetState>,
+) -> Result<usize, String> {
+    sync_synonyms(&state, &term_sets).await
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Normalize a game system scope to its system ID, and check a campaign
+/// scope names an existing campaign
+fn validate_scope(scope: TermSetScope, state: &AppState) -> Result<TermSetScope, String> {
+    match scope {
+        TermSetScope::GameSystem { game_system } => {
+            if game_system.trim().is_empty() {
+                return Err("Game system cannot be empty".to_string());
+            }
+            Ok(TermSetScope::GameSystem { game_system: GameSystem::from_str(&game_system).id().to_string() })
+        }
+        TermSetScope::Campaign { campaign_id } => {
+            state
+                .campaign_manager
+                .get_campaign(&campaign_id)
+                .ok_or_else(|| format!("Campaign not found: {}", campaign_id))?;
+            Ok(TermSetScope::Campaign { campaign_id })
+        }
+    }
+}
+
+/// Set every content index's synonyms to the library's synonym sets
+async fn sync_synonyms(state: &AppState, term_sets: &TermSetState) -> Result<usize, String> {
+    let synonyms = term_sets.library.read().unwrap_or_else(|e| e.into_inner()).index_synonyms();
+    let count = synonyms.len();
+    let meili = state.embedded_search.clone_inner();
+    tokio::task::spawn_blocking(move || apply_synonyms(&meili, synonyms))
+        .await
+        .map_err(|e| format!("Synonym update task failed: {}", e))??;
+    log::info!("Applied {} custom synonym terms to the search indexes", count);
+    Ok(count)
+}
+
+fn apply_synonyms(
+    meili: &Arc<MeilisearchLib>,
+    synonyms: std::collections::BTreeMap<String, Vec<String>>,
+) -> Result<(), String> {
+    for index in all_indexes() {
+        // Indexes are created on first ingestion; a missing one gets the
+        // synonyms on the next sync
+        if !meili.index_exists(index).map_err(|e| e.to_string())? {
+            continue;
+        }
+        let settings: Settings<Unchecked> = Settings {
+            synonyms: Setting::Set(synonyms.clone()),
+            ..Default::default()
+        };
+        let task = meili
+            .update_settings(index, settings)
+            .map_err(|e| format!("Failed to update synonyms for '{}': {}", index, e))?;
+        meili
+            .wait_for_task(task.uid, Some(SYNONYM_TASK_TIMEOUT))
+            .map_err(|e| format!("Synonym update for '{}' failed: {}", index, e))?;
+    }
+    Ok(())
+}
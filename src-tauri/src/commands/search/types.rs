@@ -2,8 +2,13 @@
 //!
 //! Contains types used across search command modules.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::core::search::{IndexTuning, RerankSettings, Snippet, VectorTuning};
+use crate::core::ttrpg_search::{Facet, ScoreBreakdown};
+
 // ============================================================================
 // Search Options and Results
 // ============================================================================
@@ -19,6 +24,12 @@ pub struct SearchOptions {
     pub campaign_id: Option<String>,
     /// Search specific index only
     pub index: Option<String>,
+    /// Campaign whose house rules are shown above the passages they replace
+    #[serde(default)]
+    pub house_rules_campaign_id: Option<String>,
+    /// Characters per highlighted snippet (default 200)
+    #[serde(default)]
+    pub snippet_length: Option<usize>,
 }
 
 fn default_limit() -> usize {
@@ -32,6 +43,8 @@ impl Default for SearchOptions {
             source_type: None,
             campaign_id: None,
             index: None,
+            house_rules_campaign_id: None,
+            snippet_length: None,
         }
     }
 }
@@ -44,6 +57,18 @@ pub struct SearchResultPayload {
     pub page_number: Option<u32>,
     pub score: f32,
     pub index: String,
+    /// "house rule" for campaign house rules
+    #[serde(default)]
+    pub badge: Option<String>,
+    /// Set on house rules
+    #[serde(default)]
+    pub house_rule_id: Option<String>,
+    /// House rule that replaces this passage
+    #[serde(default)]
+    pub overridden_by: Option<String>,
+    /// Excerpts around the matched query terms
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
 }
 
 // ============================================================================
@@ -72,6 +97,15 @@ pub struct HybridSearchOptions {
     pub query_expansion: Option<bool>,
     /// Enable/disable spell correction (default: true)
     pub spell_correction: Option<bool>,
+    /// Campaign whose house rules are shown above the passages they replace
+    #[serde(default)]
+    pub house_rules_campaign_id: Option<String>,
+    /// Characters per highlighted snippet (default 200)
+    #[serde(default)]
+    pub snippet_length: Option<usize>,
+    /// Selected facet values by facet key, e.g. `{"game_system": ["dnd5e"]}`
+    #[serde(default)]
+    pub facets: HashMap<String, Vec<String>>,
 }
 
 /// Hybrid search result for frontend
@@ -87,6 +121,27 @@ pub struct HybridSearchResultPayload {
     pub semantic_rank: Option<usize>,
     /// Number of search methods that found this result (1 = single, 2 = both)
     pub overlap_count: Option<usize>,
+    /// "house rule" for campaign house rules
+    #[serde(default)]
+    pub badge: Option<String>,
+    /// Set on house rules
+    #[serde(default)]
+    pub house_rule_id: Option<String>,
+    /// House rule that replaces this passage
+    #[serde(default)]
+    pub overridden_by: Option<String>,
+    /// Document ID within its index
+    #[serde(default)]
+    pub document_id: Option<String>,
+    /// How the fused score was built
+    #[serde(default)]
+    pub score_breakdown: Option<ScoreBreakdown>,
+    /// Cross-encoder relevance, when re-ranking ordered this result
+    #[serde(default)]
+    pub rerank_score: Option<f32>,
+    /// Excerpts around the matched query terms
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
 }
 
 /// Hybrid search response for frontend
@@ -101,6 +156,29 @@ pub struct HybridSearchResponsePayload {
     pub hints: Vec<String>,
     /// Whether performance target was met (<500ms)
     pub within_target: bool,
+    /// Facet value counts over all matches, before the limit
+    #[serde(default)]
+    pub facets: Vec<Facet>,
+    /// ID the search was recorded under in search analytics, for reporting
+    /// which result was opened
+    #[serde(default)]
+    pub search_id: Option<String>,
+}
+
+// ============================================================================
+// Search Settings
+// ============================================================================
+
+/// Persisted search settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchSettings {
+    /// Cross-encoder re-ranking of the top hybrid results
+    pub rerank: RerankSettings,
+    /// Typo tolerance, ranking rules, stop words, and distinct attribute
+    /// for the library indexes
+    pub index_tuning: IndexTuning,    /// Structure, precision, and search parameters of the vector indexes
+    pub vector_tuning: VectorTuning,
 }
 
 // ============================================================================
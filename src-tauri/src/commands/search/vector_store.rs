@@ -0,0 +1,217 @@
+//! Vector Store Commands
+//!
+//! Statistics, compaction, and orphan cleanup for the SurrealDB vector
+//! store: how many rows each vector table holds, which embedding models
+//! produced them, and how much disk the store uses. The store can also be
+//! backed up to a portable archive and restored on another machine, so an
+//! ingested library moves without being embedded again. Chunk counts are
+//! also listed per namespace (library, campaign, notes, NPCs).
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::search::maintenance::{IndexBackend, MaintenanceOperation, MaintenanceResult};
+use crate::core::storage::schema::vector_tables;
+use crate::core::storage::{
+    cleanup_orphaned_chunks, directory_size, export_vector_backup, namespace_stats, read_backup_manifest,
+    restore_vector_backup, vector_table_stats, NamespaceStats, OrphanCleanupReport, RestoreMode, RestoreReport,
+    SurrealStorage, VectorBackupManifest, VectorStoreStats,
+};
+
+use super::indexes::{get_storage, run_maintenance, IndexMaintenanceState};
+use super::settings::SearchSettingsState;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Outcome of compacting the vector store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// One optimize per vector table
+    pub tables: Vec<MaintenanceResult>,
+    /// Orphaned chunks removed first
+    pub orphans: OrphanCleanupReport,
+    pub disk_bytes_before: u64,
+    /// Storage compaction runs in the background, so this can keep
+    /// dropping after the command returns
+    pub disk_bytes_after: u64,
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+async fn disk_usage(storage: &SurrealStorage) -> Result<u64, String> {
+    let path = storage.path().to_path_buf();
+    tokio::task::spawn_blocking(move || directory_size(&path))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+}
+
+// ============================================================================
+// Vector Store Commands
+// ============================================================================
+
+/// Row counts, embedding models and dimensions, and disk usage of the
+/// vector store
+#[tauri::command]
+pub async fn get_vector_store_stats(state: State<'_, AppState>) -> Result<VectorStoreStats, String> {
+    let storage = get_storage(&state)?;
+    let mut tables = Vec::new();
+    for table in vector_tables() {
+        tables.push(
+            vector_table_stats(storage.db(), &table)
+                .await
+                .map_err(|e| e.to_string())?,
+        );
+    }
+    Ok(VectorStoreStats {
+        path: storage.path().display().to_string(),
+        disk_bytes: disk_usage(&storage).await?,
+        tables,
+    })
+}
+
+/// Chunk counts per vector namespace, largest first
+#[tauri::command]
+pub async fn list_vector_namespaces(state: State<'_, AppState>) -> Result<Vec<NamespaceStats>, String> {
+    let storage = get_storage(&state)?;
+    namespace_stats(storage.db()).await.map_err(|e| e.to_string())
+}
+
+/// Compact the vector store.
+///
+/// Orphaned chunks are removed, then every vector table's indexes are
+/// rebuilt in place as `optimize_index` does, dropping the entries that
+/// deleted rows left in the HNSW graph. Emits `index-progress` events.
+#[tauri::command]
+pub async fn compact_vector_store(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    maintenance: State<'_, IndexMaintenanceState>,
+    settings: State<'_, SearchSettingsState>,
+) -> Result<CompactionReport, String> {
+    let storage = get_storage(&state)?;
+    let tuning = settings.settings().vector_tuning;
+    let disk_bytes_before = disk_usage(&storage).await?;
+
+    let orphans = cleanup_orphaned_chunks(storage.db(), false)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut tables = Vec::new();
+    for table in vector_tables() {
+        tables.push(
+            run_maintenance(
+                table,
+                IndexBackend::VectorTable,
+                MaintenanceOperation::Optimize,
+                app_handle.clone(),
+                &state,
+                &maintenance,
+                &tuning,
+            )
+            .await?,
+        );
+    }
+
+    let report = CompactionReport {
+        tables,
+        orphans,
+        disk_bytes_before,
+        disk_bytes_after: disk_usage(&storage).await?,
+    };
+    log::info!(
+        "Vector store compacted: {} orphaned chunks removed, {} -> {} bytes on disk",
+        report.orphans.orphaned_chunks,
+        report.disk_bytes_before,
+        report.disk_bytes_after
+    );
+    Ok(report)
+}
+
+/// Remove vectors whose source document was deleted from the library.
+///
+/// Finds chunks whose library item no longer exists and deletes them, with
+/// any chunk references left pointing at missing chunks.
+///
+/// # Arguments
+/// * `dry_run` - Only count what would be removed (default: false)
+#[tauri::command]
+pub async fn cleanup_orphan_vectors(
+    dry_run: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<OrphanCleanupReport, String> {
+    let storage = get_storage(&state)?;
+    let report = cleanup_orphaned_chunks(storage.db(), dry_run.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())?;
+    if !report.dry_run {
+        log::info!(
+            "Removed {} orphaned chunks from {} deleted library items",
+            report.orphaned_chunks,
+            report.missing_library_items.len()
+        );
+    }
+    Ok(report)
+}
+
+// ============================================================================
+// Backup Commands
+// ============================================================================
+
+/// Back up the library items and vector tables, with their embedding-model
+/// fingerprint, to a portable archive at `path`
+#[tauri::command]
+pub async fn export_vector_store(path: String, state: State<'_, AppState>) -> Result<VectorBackupManifest, String> {
+    let storage = get_storage(&state)?;
+    let manifest = export_vector_backup(storage.db(), &PathBuf::from(&path))
+        .await
+        .map_err(|e| e.to_string())?;
+    log::info!(
+        "Vector store backed up to {}: {} rows",
+        path,
+        manifest.tables.iter().map(|t| t.rows).sum::<u64>()
+    );
+    Ok(manifest)
+}
+
+/// Read a vector store backup's manifest, to show what a restore would add
+#[tauri::command]
+pub async fn inspect_vector_backup(path: String) -> Result<VectorBackupManifest, String> {
+    tokio::task::spawn_blocking(move || read_backup_manifest(&PathBuf::from(path)))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
+/// Restore a vector store backup.
+///
+/// The archive is verified, and its embeddings must fit the vector index
+/// and, unless replacing, come from the same models as the stored ones.
+///
+/// # Arguments
+/// * `path` - Backup written by `export_vector_store`
+/// * `replace` - Delete the stored library items and vectors first (default: false)
+#[tauri::command]
+pub async fn restore_vector_store(
+    path: String,
+    replace: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<RestoreReport, String> {
+    let storage = get_storage(&state)?;
+    let mode = if replace.unwrap_or(false) { RestoreMode::Replace } else { RestoreMode::Merge };
+    let report = restore_vector_backup(storage.db(), &PathBuf::from(&path), mode)
+        .await
+        .map_err(|e| e.to_string())?;
+    log::info!(
+        "Vector store restored from {} ({:?}): {} rows",
+        path,
+        mode,
+        report.tables.iter().map(|t| t.rows).sum::<u64>()
+    );
+    Ok(report)
+}
@@ -0,0 +1,96 @@
+//! Session Clock Commands
+//!
+//! Commands for the real-time session clock started with each session. A
+//! background task checks the running clocks every half minute and delivers
+//! break reminders and pacing nudges as `session:clock_notice` events and,
+//! unless turned off, system notifications.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use tauri::{Emitter, Manager, State};
+
+use crate::commands::AppState;
+use crate::core::session_manager::{ClockConfig, ClockNotice, ClockStatus, SceneEstimate, SessionPlan};
+
+/// How often the ticker checks the running clocks
+const CLOCK_TICK: Duration = Duration::from_secs(30);
+
+/// Event emitted with each [`ClockNotice`]
+pub const SESSION_CLOCK_NOTICE_EVENT: &str = "session:clock_notice";
+
+// ============================================================================
+// Ticker
+// ============================================================================
+
+fn deliver(app_handle: &tauri::AppHandle, notice: &ClockNotice) {
+    use tauri_plugin_notification::NotificationExt;
+
+    let _ = app_handle.emit(SESSION_CLOCK_NOTICE_EVENT, notice);
+    if notice.notify {
+        if let Err(e) = app_handle.notification().builder().title(&notice.title).body(&notice.message).show() {
+            log::warn!("Couldn't show session clock notification: {}", e);
+        }
+    }
+}
+
+/// Start the background task that raises break reminders and pacing nudges
+pub fn start_session_clock_ticker(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CLOCK_TICK).await;
+            let notices = app_handle.state::<AppState>().session_manager.check_session_clocks(Utc::now());
+            for notice in &notices {
+                deliver(&app_handle, notice);
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get how long a session has been running, when the next break reminder
+/// is due, and how it stands against its plan.
+#[tauri::command]
+pub fn get_session_clock(session_id: String, state: State<'_, AppState>) -> Result<ClockStatus, String> {
+    state.session_manager.session_clock(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Turn break reminders and pacing nudges on or off and set their timing.
+/// The next break reminder is counted from now.
+#[tauri::command]
+pub fn configure_session_clock(
+    session_id: String,
+    config: ClockConfig,
+    state: State<'_, AppState>,
+) -> Result<ClockStatus, String> {
+    state.session_manager.configure_session_clock(&session_id, config)
+        .map_err(|e| e.to_string())
+}
+
+/// Pace a session against a session plan's pacing beats, starting with the
+/// first. Pacing nudges still need to be turned on.
+#[tauri::command]
+pub fn set_session_clock_plan(
+    session_id: String,
+    plan: SessionPlan,
+    state: State<'_, AppState>,
+) -> Result<ClockStatus, String> {
+    state.session_manager.set_clock_plan(&session_id, SceneEstimate::from_plan(&plan))
+        .map_err(|e| e.to_string())
+}
+
+/// Mark the table as having reached a planned scene, by its position in
+/// the plan (0 for the first).
+#[tauri::command]
+pub fn set_session_clock_scene(
+    session_id: String,
+    scene_index: usize,
+    state: State<'_, AppState>,
+) -> Result<ClockStatus, String> {
+    state.session_manager.set_clock_scene(&session_id, scene_index)
+        .map_err(|e| e.to_string())
+}
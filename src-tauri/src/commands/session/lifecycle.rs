@@ -1,11 +1,12 @@
 //! Session Lifecycle Commands
 //!
-//! Commands for managing session lifecycle: start, get, list, end,
-//! planned sessions, and session reordering.
+//! Commands for managing session lifecycle: start, get, list, pause,
+//! resume, end, planned sessions, and session reordering.
 
-use tauri::State;
+use tauri::{Manager, State};
 
-use crate::commands::AppState;
+use crate::commands::world::updates::{emit_changeset_proposed, propose_for_session};
+use crate::commands::{AppState, WorldUpdateState};
 use crate::core::session_manager::{GameSession, SessionSummary};
 
 // ============================================================================
@@ -67,8 +68,12 @@ pub fn list_sessions(campaign_id: String, state: State<'_, AppState>) -> Result<
 
 /// End an active session.
 ///
+/// Unless disabled, world-state changes are then proposed from the session
+/// in the background and announced with `world:changeset_proposed`.
+///
 /// # Arguments
 /// * `session_id` - The session ID to end
+/// * `propose_world_updates` - Propose world-state changes (default: true)
 ///
 /// # Returns
 /// Summary of the ended session.
@@ -76,8 +81,53 @@ pub fn list_sessions(campaign_id: String, state: State<'_, AppState>) -> Result<
 /// # Errors
 /// If the session is not found or already ended.
 #[tauri::command]
-pub fn end_session(session_id: String, state: State<'_, AppState>) -> Result<SessionSummary, String> {
-    state.session_manager.end_session(&session_id)
+pub fn end_session(
+    session_id: String,
+    propose_world_updates: Option<bool>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<SessionSummary, String> {
+    let summary = state.session_manager.end_session(&session_id)
+        .map_err(|e| e.to_string())?;
+
+    if propose_world_updates.unwrap_or(true) {
+        tauri::async_runtime::spawn(async move {
+            let state = app_handle.state::<AppState>();
+            let updates = app_handle.state::<WorldUpdateState>();
+            match propose_for_session(&session_id, &state, &updates).await {
+                Ok(changeset) => emit_changeset_proposed(&app_handle, &changeset),
+                Err(e) => log::warn!("World update proposal failed for session {}: {}", session_id, e),
+            }
+        });
+    }
+
+    Ok(summary)
+}
+
+/// Pause an active session. Its clock stops until the session resumes.
+///
+/// # Arguments
+/// * `session_id` - The session to pause
+///
+/// # Errors
+/// If the session is not found.
+#[tauri::command]
+pub fn pause_session(session_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    state.session_manager.pause_session(&session_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Resume a paused session. The pause counts as a break, so the next break
+/// reminder is a full interval away.
+///
+/// # Arguments
+/// * `session_id` - The session to resume
+///
+/// # Errors
+/// If the session is not found.
+#[tauri::command]
+pub fn resume_session(session_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    state.session_manager.resume_session(&session_id)
         .map_err(|e| e.to_string())
 }
 
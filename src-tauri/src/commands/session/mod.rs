@@ -1,15 +1,22 @@
 //! Session Commands Module
 //!
 //! Commands for managing game sessions, including lifecycle management,
-//! chat sessions, and notes.
+//! the session clock with break reminders and pacing nudges, the player
+//! display window, chat sessions, notes, and LLM-written recaps.
 //!
 //! Note: Timeline commands are in the separate `timeline` module.
 
 pub mod lifecycle;
+pub mod clock;
+pub mod player_window;
 pub mod chat;
 pub mod notes;
+pub mod recap;
 
 // Re-export all commands
 pub use lifecycle::*;
+pub use clock::*;
+pub use player_window::*;
 pub use chat::*;
 pub use notes::*;
+pub use recap::*;
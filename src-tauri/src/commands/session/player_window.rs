@@ -0,0 +1,219 @@
+//! Player Window Commands
+//!
+//! A second window for the players' screen, meant to be dragged to another
+//! monitor or a TV (or opened fullscreen on one). It follows one session: a
+//! background task pushes the initiative order, token layout, and public
+//! timeline to it as `player:view` events whenever they change, with GM-only
+//! details removed. Revealed handouts reach it through the `handout:*`
+//! events; their images are read through `get_player_handout_image`, which
+//! only serves handouts that are currently revealed.
+
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+
+use crate::commands::{AppState, HandoutState};
+use crate::core::campaign::handouts::HandoutContent;
+use crate::core::session_manager::PlayerViewSnapshot;
+
+/// Label of the player window
+pub const PLAYER_WINDOW_LABEL: &str = "player";
+
+/// Event emitted to the player window with each new [`PlayerViewSnapshot`]
+pub const PLAYER_VIEW_EVENT: &str = "player:view";
+
+/// How often the relay looks for changes to send
+const RELAY_TICK: Duration = Duration::from_millis(500);
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state for the player window
+#[derive(Default)]
+pub struct PlayerWindowState {
+    /// Session the player window follows
+    session_id: RwLock<Option<String>>,
+    /// Last snapshot sent, serialized, so unchanged views aren't resent
+    last_sent: Mutex<Option<String>>,
+}
+
+impl PlayerWindowState {
+    fn follow(&self, session_id: Option<String>) {
+        *self.session_id.write().unwrap() = session_id;
+        *self.last_sent.lock().unwrap() = None;
+    }
+
+    fn followed(&self) -> Option<String> {
+        self.session_id.read().unwrap().clone()
+    }
+}
+
+/// A display the player window can be put on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayInfo {
+    pub index: usize,
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub primary: bool,
+}
+
+// ============================================================================
+// Relay
+// ============================================================================
+
+/// Send the player window the followed session's view if it has changed
+fn relay_changes(app_handle: &tauri::AppHandle) {
+    if app_handle.get_webview_window(PLAYER_WINDOW_LABEL).is_none() {
+        return;
+    }
+    let window_state = app_handle.state::<PlayerWindowState>();
+    let Some(session_id) = window_state.followed() else {
+        return;
+    };
+    let Ok(snapshot) = app_handle.state::<AppState>().session_manager.player_view(&session_id) else {
+        return;
+    };
+    let Ok(serialized) = serde_json::to_string(&snapshot) else {
+        return;
+    };
+    let mut last_sent = window_state.last_sent.lock().unwrap();
+    if last_sent.as_deref() != Some(serialized.as_str()) {
+        let _ = app_handle.emit_to(PLAYER_WINDOW_LABEL, PLAYER_VIEW_EVENT, &snapshot);
+        *last_sent = Some(serialized);
+    }
+}
+
+/// Start the background task that keeps the player window up to date
+pub fn start_player_view_relay(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(RELAY_TICK).await;
+            relay_changes(&app_handle);
+        }
+    });
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// List the displays the player window can be opened on.
+#[tauri::command]
+pub fn list_displays(app_handle: tauri::AppHandle) -> Result<Vec<DisplayInfo>, String> {
+    let primary = app_handle
+        .primary_monitor()
+        .map_err(|e| e.to_string())?
+        .map(|m| *m.position());
+    let monitors = app_handle.available_monitors().map_err(|e| e.to_string())?;
+    Ok(monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| DisplayInfo {
+            index,
+            name: monitor.name().cloned(),
+            width: monitor.size().width,
+            height: monitor.size().height,
+            primary: primary == Some(*monitor.position()),
+        })
+        .collect())
+}
+
+/// Open the player window following a session, or point the open one at it.
+///
+/// # Arguments
+/// * `display` - Index from `list_displays` to open it fullscreen on;
+///   otherwise it opens as a normal window that can be dragged anywhere
+#[tauri::command]
+pub fn open_player_window(
+    session_id: String,
+    display: Option<usize>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    player_window: State<'_, PlayerWindowState>,
+) -> Result<(), String> {
+    // Fail early on an unknown session rather than showing an empty screen
+    state.session_manager.player_view(&session_id)
+        .map_err(|e| e.to_string())?;
+    player_window.follow(Some(session_id));
+
+    let window = match app_handle.get_webview_window(PLAYER_WINDOW_LABEL) {
+        Some(window) => window,
+        None => WebviewWindowBuilder::new(
+            &app_handle,
+            PLAYER_WINDOW_LABEL,
+            WebviewUrl::App("index.html?view=player".into()),
+        )
+        .title("Player View")
+        .inner_size(1280.0, 720.0)
+        .build()
+        .map_err(|e| e.to_string())?,
+    };
+
+    if let Some(index) = display {
+        let monitors = app_handle.available_monitors().map_err(|e| e.to_string())?;
+        let monitor = monitors.get(index).ok_or_else(|| format!("No display {}", index))?;
+        window.set_position(*monitor.position()).map_err(|e| e.to_string())?;
+        window.set_fullscreen(true).map_err(|e| e.to_string())?;
+    }
+    window.set_focus().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Close the player window.
+#[tauri::command]
+pub fn close_player_window(
+    app_handle: tauri::AppHandle,
+    player_window: State<'_, PlayerWindowState>,
+) -> Result<(), String> {
+    player_window.follow(None);
+    if let Some(window) = app_handle.get_webview_window(PLAYER_WINDOW_LABEL) {
+        window.close().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// The players' view of the followed session, or null when the player
+/// window isn't following one. Used by the player window on load.
+#[tauri::command]
+pub fn get_player_view(
+    state: State<'_, AppState>,
+    player_window: State<'_, PlayerWindowState>,
+) -> Result<Option<PlayerViewSnapshot>, String> {
+    let Some(session_id) = player_window.followed() else {
+        return Ok(None);
+    };
+    state.session_manager.player_view(&session_id)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+/// A revealed image handout as a data URL the player window can show.
+#[tauri::command]
+pub fn get_player_handout_image(
+    handout_id: String,
+    handouts: State<'_, HandoutState>,
+) -> Result<String, String> {
+    use base64::Engine;
+
+    let handout = handouts
+        .manager
+        .get_handout(&handout_id)
+        .filter(|h| h.revealed)
+        .ok_or_else(|| format!("No revealed handout {}", handout_id))?;
+    let HandoutContent::Image { path } = &handout.content else {
+        return Err(format!("{} isn't an image", handout.title));
+    };
+    let mime = match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        _ => "image/png",
+    };
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    Ok(format!("data:{};base64,{}", mime, base64::engine::general_purpose::STANDARD.encode(bytes)))
+}
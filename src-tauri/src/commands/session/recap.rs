@@ -0,0 +1,167 @@
+//! Session Recap Commands
+//!
+//! Commands for writing an LLM recap of a live session, saving it as a
+//! session note, and optionally voicing a "previously on…" intro.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::llm::router::{ChatMessage, ChatRequest};
+use crate::core::personality::ContentType;
+use crate::core::session::recap::{
+    build_recap_prompt, parse_recap_response, ChatExcerpt, RecapInputs, RECAP_SYSTEM_PROMPT,
+};
+use crate::core::session::SessionNote;
+use crate::core::session_manager::LogEntryType;
+use crate::core::voice::{OutputFormat, SynthesisRequest};
+use crate::database::ChatOps;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A generated session recap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecapResult {
+    /// The recap note saved to the session
+    pub note: SessionNote,
+    /// Intro text for the start of the next session
+    pub previously_on: String,
+    /// Synthesized intro audio, when requested
+    pub intro_audio_path: Option<String>,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Collect the session's narration and table chat, oldest first
+pub(crate) async fn gather_chat_excerpts(session_id: &str, state: &AppState) -> Vec<ChatExcerpt> {
+    let mut excerpts: Vec<ChatExcerpt> = state
+        .session_manager
+        .get_session(session_id)
+        .map(|s| s.notes)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| {
+            matches!(
+                entry.entry_type,
+                LogEntryType::Narrative | LogEntryType::NPCAction | LogEntryType::PlayerAction
+            )
+        })
+        .map(|entry| ChatExcerpt {
+            speaker: entry.actor.unwrap_or_else(|| "Narrator".to_string()),
+            text: entry.content,
+        })
+        .collect();
+
+    match state.database.get_chat_sessions_by_game_session(session_id).await {
+        Ok(chats) => {
+            for chat in chats {
+                let messages = match state.database.get_chat_messages(&chat.id, 200).await {
+                    Ok(messages) => messages,
+                    Err(e) => {
+                        log::warn!("Skipping chat {} in recap: {}", chat.id, e);
+                        continue;
+                    }
+                };
+                excerpts.extend(messages.into_iter().filter(|m| m.role != "system").map(|m| ChatExcerpt {
+                    speaker: if m.role == "user" { "GM".to_string() } else { "Assistant".to_string() },
+                    text: m.content,
+                }));
+            }
+        }
+        Err(e) => log::warn!("Could not load chat history for session {}: {}", session_id, e),
+    }
+
+    excerpts
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Generate an LLM recap of a session and save it as a session note.
+///
+/// The session's timeline, combat log, chat excerpts, and notes are
+/// summarized in the campaign's narrator personality. With `speak_intro`,
+/// the "previously on…" intro is also synthesized.
+///
+/// # Arguments
+/// * `session_id` - The session to recap
+/// * `speak_intro` - Synthesize the intro (default: false)
+/// * `voice_id` - Voice for the intro (defaults to the configured default voice)
+#[tauri::command]
+pub async fn generate_session_recap(
+    session_id: String,
+    speak_intro: Option<bool>,
+    voice_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<SessionRecapResult, String> {
+    let session = state
+        .session_manager
+        .get_session(&session_id)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+    let inputs = RecapInputs::new(session.session_number, session.title.clone())
+        .with_timeline(state.session_manager.get_timeline_events(&session_id))
+        .with_combat_log(state.session_manager.get_combat_log(&session_id))
+        .with_chat(gather_chat_excerpts(&session_id, &state).await)
+        .with_notes(state.session_manager.list_notes_for_session(&session_id));
+    if inputs.is_empty() {
+        return Err("Nothing has been recorded for this session yet".to_string());
+    }
+
+    let personality = state
+        .personality_manager
+        .get_session_system_prompt(&session_id, &session.campaign_id, ContentType::Narration)
+        .unwrap_or_else(|e| {
+            log::warn!("Recap personality unavailable for {}: {}", session.campaign_id, e);
+            String::new()
+        });
+    let system_prompt = if personality.is_empty() {
+        RECAP_SYSTEM_PROMPT.to_string()
+    } else {
+        format!("{}\n\n{}", RECAP_SYSTEM_PROMPT, personality)
+    };
+
+    let request = ChatRequest::new(vec![ChatMessage::user(build_recap_prompt(&inputs))])
+        .with_system(system_prompt)
+        .with_temperature(0.6)
+        .with_max_tokens(1500);
+    let response = {
+        let router = state.llm_router.read().await;
+        router.chat(request).await.map_err(|e| e.to_string())?
+    };
+
+    let recap = parse_recap_response(&response.content);
+    let note = recap.to_note(&session_id, &session.campaign_id, &inputs);
+    state.session_manager.create_note(note.clone()).map_err(|e| e.to_string())?;
+
+    let intro_audio_path = if speak_intro.unwrap_or(false) && !recap.previously_on.is_empty() {
+        let voice_manager = state.voice_manager.read().await;
+        let request = SynthesisRequest {
+            text: recap.previously_on.clone(),
+            voice_id: voice_id
+                .or_else(|| voice_manager.get_config().default_voice_id.clone())
+                .unwrap_or_else(|| "default".to_string()),
+            settings: None,
+            output_format: OutputFormat::Mp3,
+        };
+        let tags = vec![format!("session:{}", session_id), "recap".to_string()];
+        let result = voice_manager
+            .synthesize_with_tags(request, &tags)
+            .await
+            .map_err(|e| e.to_string())?;
+        Some(result.audio_path.to_string_lossy().to_string())
+    } else {
+        None
+    };
+
+    Ok(SessionRecapResult {
+        note,
+        previously_on: recap.previously_on,
+        intro_audio_path,
+    })
+}
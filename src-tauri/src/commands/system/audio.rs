@@ -2,16 +2,18 @@
 //!
 //! Commands for audio volume settings and SFX categories.
 
+use tauri::State;
+
 use crate::core::audio::AudioVolumes;
 
-/// Get current audio volume settings
+use super::mixer::MixerState;
+
+/// Get current audio volume settings (muted channels report 0.0)
 ///
-/// Note: Audio playback uses rodio which requires the OutputStream to stay
-/// on the same thread. For Tauri, we handle this by creating the audio player
-/// on-demand in the main thread context.
+/// See `get_mixer_state` for the full mixer view.
 #[tauri::command]
-pub fn get_audio_volumes() -> AudioVolumes {
-    AudioVolumes::default()
+pub fn get_audio_volumes(mixer: State<'_, MixerState>) -> AudioVolumes {
+    AudioVolumes::from(&mixer.mixer.settings())
 }
 
 /// Get available SFX categories
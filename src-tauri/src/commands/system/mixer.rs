@@ -0,0 +1,149 @@
+//! Audio Mixer Commands
+//!
+//! Commands for the shared channel mixer: per-channel volume and mute, the
+//! master bus, voice ducking, and per-channel output devices. Changes are
+//! persisted to `mixer_settings.json` and restored on startup.
+
+use std::path::PathBuf;
+
+use tauri::{Manager, State};
+
+use crate::core::audio::{
+    list_output_devices, AudioMixer, DuckingSettings, MixerChannel, MixerSettings, MixerStatus,
+    OutputDeviceInfo,
+};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Shared audio mixer state
+pub struct MixerState {
+    pub mixer: AudioMixer,
+}
+
+impl MixerState {
+    pub fn new(mixer: AudioMixer) -> Self {
+        Self { mixer }
+    }
+}
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_mixer_settings_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("mixer_settings.json")
+}
+
+/// Load mixer settings from disk
+pub fn load_mixer_settings_disk(app_handle: &tauri::AppHandle) -> Option<MixerSettings> {
+    let path = get_mixer_settings_path(app_handle);
+    if !path.exists() {
+        return None;
+    }
+    match MixerSettings::load(&path) {
+        Ok(settings) => Some(settings),
+        Err(e) => {
+            log::warn!("Failed to parse mixer settings: {}", e);
+            None
+        }
+    }
+}
+
+/// Persist the mixer's current settings and return its status
+fn save_and_report(app_handle: &tauri::AppHandle, mixer: &AudioMixer) -> Result<MixerStatus, String> {
+    mixer
+        .settings()
+        .save(&get_mixer_settings_path(app_handle))
+        .map_err(|e| e.to_string())?;
+    Ok(mixer.status())
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get channel volumes, mute state, ducking, and what is playing
+#[tauri::command]
+pub fn get_mixer_state(mixer: State<'_, MixerState>) -> MixerStatus {
+    mixer.mixer.status()
+}
+
+/// Set a channel's volume (0.0 - 1.0)
+#[tauri::command]
+pub fn set_channel_volume(
+    channel: MixerChannel,
+    volume: f32,
+    app_handle: tauri::AppHandle,
+    mixer: State<'_, MixerState>,
+) -> Result<MixerStatus, String> {
+    mixer.mixer.set_channel_volume(channel, volume);
+    save_and_report(&app_handle, &mixer.mixer)
+}
+
+/// Mute or unmute a channel
+#[tauri::command]
+pub fn set_channel_muted(
+    channel: MixerChannel,
+    muted: bool,
+    app_handle: tauri::AppHandle,
+    mixer: State<'_, MixerState>,
+) -> Result<MixerStatus, String> {
+    mixer.mixer.set_channel_muted(channel, muted);
+    save_and_report(&app_handle, &mixer.mixer)
+}
+
+/// Set the master volume (0.0 - 1.0) and optionally mute everything
+#[tauri::command]
+pub fn set_master_volume(
+    volume: f32,
+    muted: Option<bool>,
+    app_handle: tauri::AppHandle,
+    mixer: State<'_, MixerState>,
+) -> Result<MixerStatus, String> {
+    mixer.mixer.set_master_volume(volume);
+    if let Some(muted) = muted {
+        mixer.mixer.set_master_muted(muted);
+    }
+    save_and_report(&app_handle, &mixer.mixer)
+}
+
+/// Configure automatic ducking of music and ambience while voice plays
+#[tauri::command]
+pub fn set_ducking(
+    ducking: DuckingSettings,
+    app_handle: tauri::AppHandle,
+    mixer: State<'_, MixerState>,
+) -> Result<MixerStatus, String> {
+    mixer.mixer.set_ducking(ducking);
+    save_and_report(&app_handle, &mixer.mixer)
+}
+
+/// List available audio output devices
+#[tauri::command]
+pub fn list_audio_output_devices() -> Result<Vec<OutputDeviceInfo>, String> {
+    list_output_devices().map_err(|e| e.to_string())
+}
+
+/// Route a channel to an output device, or back to the system default with `None`
+///
+/// If the device is later unplugged, the channel falls back to the default
+/// device and returns to the chosen one when it reappears.
+#[tauri::command]
+pub fn set_channel_output_device(
+    channel: MixerChannel,
+    device: Option<String>,
+    app_handle: tauri::AppHandle,
+    mixer: State<'_, MixerState>,
+) -> Result<MixerStatus, String> {
+    if let Some(name) = &device {
+        let devices = list_output_devices().map_err(|e| e.to_string())?;
+        if !devices.iter().any(|d| &d.name == name) {
+            return Err(format!("Audio output device not found: {}", name));
+        }
+    }
+    mixer.mixer.set_channel_device(channel, device);
+    save_and_report(&app_handle, &mixer.mixer)
+}
@@ -1,12 +1,19 @@
 //! System Commands Module
 //!
-//! Commands for system information, audio volumes, and browser operations.
+//! Commands for system information, audio volumes, the channel mixer,
+//! soundscapes, sound effect triggers, and browser operations.
 
 pub mod info;
 pub mod audio;
+pub mod mixer;
+pub mod soundscape;
+pub mod sfx_triggers;
 pub mod browser;
 
 // Re-export all commands using glob to include Tauri __cmd__ macros
 pub use info::*;
 pub use audio::*;
+pub use mixer::*;
+pub use soundscape::*;
+pub use sfx_triggers::*;
 pub use browser::*;
@@ -0,0 +1,138 @@
+//! Sound Effect Trigger Commands
+//!
+//! Commands for managing keyword/event sound effect mappings, plus helpers
+//! used by combat, dice, and chat commands to fire effects automatically.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use tauri::{Manager, State};
+
+use crate::core::audio::sfx_triggers::default_sfx_library_dir;
+use crate::core::audio::{
+    AudioMixer, SfxEvent, SfxPlayer, SfxTrigger, SfxTriggerConfig, SfxTriggerEngine,
+};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Sound effect trigger state: matching engine plus the SFX playback channel
+pub struct SfxTriggerState {
+    pub engine: Mutex<SfxTriggerEngine>,
+    pub player: SfxPlayer,
+}
+
+impl SfxTriggerState {
+    pub fn new(config: SfxTriggerConfig, mixer: AudioMixer) -> Self {
+        Self {
+            engine: Mutex::new(SfxTriggerEngine::new(config)),
+            player: SfxPlayer::new(mixer, default_sfx_library_dir()),
+        }
+    }
+
+    fn play_all(&self, triggers: &[SfxTrigger]) {
+        for trigger in triggers {
+            if let Err(e) = self.player.play(&trigger.sound, trigger.volume) {
+                log::debug!("SFX trigger '{}' not played: {}", trigger.id, e);
+            }
+        }
+    }
+}
+
+/// Fire any effects mapped to a game event
+pub(crate) fn fire_sfx_event(sfx: &SfxTriggerState, event: SfxEvent) {
+    let fired = match sfx.engine.lock() {
+        Ok(mut engine) => engine.on_event(event, Instant::now()),
+        Err(_) => return,
+    };
+    sfx.play_all(&fired);
+}
+
+/// Fire any effects whose trigger words appear in a narration segment
+pub(crate) fn fire_narration_sfx(sfx: &SfxTriggerState, text: &str) -> Vec<SfxTrigger> {
+    let fired = match sfx.engine.lock() {
+        Ok(mut engine) => engine.on_narration(text, Instant::now()),
+        Err(_) => return Vec::new(),
+    };
+    sfx.play_all(&fired);
+    fired
+}
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_sfx_triggers_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("sfx_triggers.json")
+}
+
+/// Load SFX trigger config from disk
+pub fn load_sfx_triggers_disk(app_handle: &tauri::AppHandle) -> Option<SfxTriggerConfig> {
+    let path = get_sfx_triggers_path(app_handle);
+    if !path.exists() {
+        return None;
+    }
+    match SfxTriggerConfig::load(&path) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            log::warn!("Failed to parse SFX trigger config: {}", e);
+            None
+        }
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get the current trigger mappings and debounce settings
+#[tauri::command]
+pub fn get_sfx_triggers(sfx: State<'_, SfxTriggerState>) -> Result<SfxTriggerConfig, String> {
+    let engine = sfx.engine.lock().map_err(|e| e.to_string())?;
+    Ok(engine.config().clone())
+}
+
+/// Replace the trigger mappings and debounce settings
+#[tauri::command]
+pub fn save_sfx_triggers(
+    config: SfxTriggerConfig,
+    app_handle: tauri::AppHandle,
+    sfx: State<'_, SfxTriggerState>,
+) -> Result<(), String> {
+    config
+        .save(&get_sfx_triggers_path(&app_handle))
+        .map_err(|e| e.to_string())?;
+    sfx.engine.lock().map_err(|e| e.to_string())?.set_config(config);
+    Ok(())
+}
+
+/// Play a sound effect by path or SFX library key
+#[tauri::command]
+pub fn play_sfx(
+    sound: String,
+    volume: Option<f32>,
+    sfx: State<'_, SfxTriggerState>,
+) -> Result<String, String> {
+    sfx.player
+        .play(&sound, volume.unwrap_or(1.0))
+        .map(|p| p.display().to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Fire the effects mapped to a game event (subject to debounce rules)
+#[tauri::command]
+pub fn trigger_sfx_event(event: SfxEvent, sfx: State<'_, SfxTriggerState>) {
+    fire_sfx_event(&sfx, event);
+}
+
+/// Scan text for trigger words and play matching effects
+///
+/// Streamed chat narration is scanned automatically; this is for text that
+/// arrives another way (e.g., pasted read-aloud boxes).
+#[tauri::command]
+pub fn scan_text_for_sfx(text: String, sfx: State<'_, SfxTriggerState>) -> Vec<SfxTrigger> {
+    fire_narration_sfx(&sfx, &text)
+}
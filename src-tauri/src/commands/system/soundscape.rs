@@ -0,0 +1,162 @@
+//! Soundscape Commands
+//!
+//! Commands for layered ambient soundscapes: playing and crossfading scenes,
+//! adjusting layer volumes, and managing per-campaign scene presets.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tauri::{Manager, State};
+
+use crate::core::audio::soundscape::{default_library_dir, DEFAULT_CROSSFADE_MS, DEFAULT_FADE_MS};
+use crate::core::audio::{AudioMixer, SoundscapeEngine, SoundscapePresets, SoundscapeScene, SoundscapeStatus};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Soundscape engine state
+pub struct SoundscapeState {
+    pub engine: SoundscapeEngine,
+}
+
+impl SoundscapeState {
+    pub fn new(mixer: AudioMixer) -> Self {
+        Self {
+            engine: SoundscapeEngine::new(mixer, default_library_dir()),
+        }
+    }
+}
+
+// ============================================================================
+// Persistence Helpers
+// ============================================================================
+
+fn get_presets_path(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("soundscape_presets.json")
+}
+
+fn load_presets(app_handle: &tauri::AppHandle) -> Result<SoundscapePresets, String> {
+    SoundscapePresets::load(&get_presets_path(app_handle)).map_err(|e| e.to_string())
+}
+
+fn find_scene(
+    app_handle: &tauri::AppHandle,
+    scene_id: &str,
+    campaign_id: Option<&str>,
+) -> Result<SoundscapeScene, String> {
+    load_presets(app_handle)?
+        .find(campaign_id, scene_id)
+        .ok_or_else(|| format!("Soundscape scene not found: {}", scene_id))
+}
+
+// ============================================================================
+// Playback Commands
+// ============================================================================
+
+/// Play a soundscape scene (built-in or a campaign preset), replacing the current one
+#[tauri::command]
+pub fn play_soundscape(
+    scene_id: String,
+    campaign_id: Option<String>,
+    fade_ms: Option<u64>,
+    app_handle: tauri::AppHandle,
+    soundscape: State<'_, SoundscapeState>,
+) -> Result<SoundscapeStatus, String> {
+    let scene = find_scene(&app_handle, &scene_id, campaign_id.as_deref())?;
+    let fade = Duration::from_millis(fade_ms.unwrap_or(DEFAULT_FADE_MS));
+    soundscape.engine.crossfade(scene, fade).map_err(|e| e.to_string())
+}
+
+/// Crossfade from the current scene to another over `duration_ms`
+#[tauri::command]
+pub fn crossfade_soundscape(
+    scene_id: String,
+    campaign_id: Option<String>,
+    duration_ms: Option<u64>,
+    app_handle: tauri::AppHandle,
+    soundscape: State<'_, SoundscapeState>,
+) -> Result<SoundscapeStatus, String> {
+    let scene = find_scene(&app_handle, &scene_id, campaign_id.as_deref())?;
+    let duration = Duration::from_millis(duration_ms.unwrap_or(DEFAULT_CROSSFADE_MS));
+    soundscape.engine.crossfade(scene, duration).map_err(|e| e.to_string())
+}
+
+/// Fade out and stop the current soundscape
+#[tauri::command]
+pub fn stop_soundscape(
+    fade_ms: Option<u64>,
+    soundscape: State<'_, SoundscapeState>,
+) -> Result<(), String> {
+    let fade = Duration::from_millis(fade_ms.unwrap_or(DEFAULT_FADE_MS));
+    soundscape.engine.stop(fade).map_err(|e| e.to_string())
+}
+
+/// Set the volume of a single layer in the playing scene
+#[tauri::command]
+pub fn set_soundscape_layer_volume(
+    layer_id: String,
+    volume: f32,
+    soundscape: State<'_, SoundscapeState>,
+) -> Result<(), String> {
+    soundscape.engine.set_layer_volume(&layer_id, volume).map_err(|e| e.to_string())
+}
+
+/// Set the overall soundscape volume
+#[tauri::command]
+pub fn set_soundscape_volume(
+    volume: f32,
+    soundscape: State<'_, SoundscapeState>,
+) -> Result<(), String> {
+    soundscape.engine.set_master_volume(volume).map_err(|e| e.to_string())
+}
+
+/// Get what the soundscape engine is currently playing
+#[tauri::command]
+pub fn get_soundscape_status(soundscape: State<'_, SoundscapeState>) -> SoundscapeStatus {
+    soundscape.engine.status()
+}
+
+// ============================================================================
+// Preset Commands
+// ============================================================================
+
+/// List built-in scenes plus the campaign's saved presets
+#[tauri::command]
+pub fn list_soundscape_scenes(
+    campaign_id: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<SoundscapeScene>, String> {
+    Ok(load_presets(&app_handle)?.scenes_for(campaign_id.as_deref()))
+}
+
+/// Save a scene preset for a campaign (replaces a preset with the same ID)
+#[tauri::command]
+pub fn save_soundscape_preset(
+    campaign_id: String,
+    scene: SoundscapeScene,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    if scene.id.trim().is_empty() {
+        return Err("Scene ID cannot be empty".to_string());
+    }
+    let mut presets = load_presets(&app_handle)?;
+    presets.upsert(&campaign_id, scene);
+    presets.save(&get_presets_path(&app_handle)).map_err(|e| e.to_string())
+}
+
+/// Delete a campaign scene preset
+#[tauri::command]
+pub fn delete_soundscape_preset(
+    campaign_id: String,
+    scene_id: String,
+    app_handle: tauri::AppHandle,
+) -> Result<bool, String> {
+    let mut presets = load_presets(&app_handle)?;
+    let removed = presets.remove(&campaign_id, &scene_id);
+    if removed {
+        presets.save(&get_presets_path(&app_handle)).map_err(|e| e.to_string())?;
+    }
+    Ok(removed)
+}
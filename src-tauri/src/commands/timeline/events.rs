@@ -1,21 +1,26 @@
 //! Timeline Event Commands
 //!
 //! Commands for managing session timeline events, tracking notable
-//! occurrences during gameplay sessions.
+//! occurrences during gameplay sessions, and for choosing which subsystems
+//! add events on their own.
 
 use std::collections::HashMap;
-use tauri::State;
+use tauri::{Emitter, State};
 
-use crate::commands::AppState;
+use crate::commands::{AppState, QuestState, QuestSuggestionsEvent, QUEST_SUGGESTIONS_EVENT};
 use crate::core::session::timeline::{
     TimelineEvent, TimelineEventType, EventSeverity, EntityRef, TimelineSummary,
 };
+use crate::core::session_manager::{CaptureCategory, CaptureSettings};
 
 // ============================================================================
 // Session Timeline Commands
 // ============================================================================
 
 /// Add a timeline event to a session
+///
+/// If the event mentions any of the campaign's open quests, the resulting
+/// status suggestions are emitted as a `quest:suggestions` event.
 #[tauri::command]
 pub fn add_timeline_event(
     session_id: String,
@@ -26,7 +31,9 @@ pub fn add_timeline_event(
     entity_refs: Option<Vec<EntityRef>>,
     tags: Option<Vec<String>>,
     metadata: Option<HashMap<String, serde_json::Value>>,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
+    quests: State<'_, QuestState>,
 ) -> Result<TimelineEvent, String> {
     let etype = match event_type.as_str() {
         "session_start" => TimelineEventType::SessionStart,
@@ -78,6 +85,17 @@ pub fn add_timeline_event(
     state.session_manager.add_timeline_event(&session_id, event.clone())
         .map_err(|e| e.to_string())?;
 
+    if let Some(session) = state.session_manager.get_session(&session_id) {
+        let suggestions = quests.manager.suggest_status_changes(&session.campaign_id, std::slice::from_ref(&event));
+        if !suggestions.is_empty() {
+            let _ = app_handle.emit(QUEST_SUGGESTIONS_EVENT, QuestSuggestionsEvent {
+                campaign_id: session.campaign_id,
+                session_id: session_id.clone(),
+                suggestions,
+            });
+        }
+    }
+
     Ok(event)
 }
 
@@ -120,3 +138,34 @@ pub fn get_timeline_events_by_type(
 
     Ok(state.session_manager.get_timeline_events_by_type(&session_id, &etype))
 }
+
+// ============================================================================
+// Capture Settings Commands
+// ============================================================================
+
+/// Get which subsystems add events to the timeline on their own
+#[tauri::command]
+pub fn get_timeline_capture(state: State<'_, AppState>) -> Result<CaptureSettings, String> {
+    Ok(state.session_manager.capture_settings())
+}
+
+/// Replace the timeline capture settings
+#[tauri::command]
+pub fn set_timeline_capture(settings: CaptureSettings, state: State<'_, AppState>) -> Result<CaptureSettings, String> {
+    state.session_manager.set_capture_settings(settings);
+    Ok(state.session_manager.capture_settings())
+}
+
+/// Turn automatic capture for one category on or off
+///
+/// # Arguments
+/// * `category` - "combat", "npc_conversations", "world_events", "quests",
+///   or "dice_rolls"
+#[tauri::command]
+pub fn set_timeline_capture_category(
+    category: CaptureCategory,
+    enabled: bool,
+    state: State<'_, AppState>,
+) -> Result<CaptureSettings, String> {
+    Ok(state.session_manager.set_capture_category(category, enabled))
+}
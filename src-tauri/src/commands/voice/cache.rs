@@ -6,6 +6,7 @@ use tauri::State;
 
 use crate::core::voice::{CacheStats, CacheEntry};
 use crate::commands::AppState;
+use crate::commands::voice::config::save_masked_voice_config_disk;
 
 // ============================================================================
 // Audio Cache Commands
@@ -20,6 +21,15 @@ pub struct AudioCacheSizeInfo {
     pub usage_percent: f64,
 }
 
+/// Voice cache statistics with on-disk location and usage
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VoiceCacheStats {
+    #[serde(flatten)]
+    pub stats: CacheStats,
+    pub cache_dir: String,
+    pub usage_percent: f64,
+}
+
 /// Get audio cache statistics
 ///
 /// Returns comprehensive cache statistics including:
@@ -115,3 +125,74 @@ pub async fn get_audio_cache_size(state: State<'_, AppState>) -> Result<AudioCac
         },
     })
 }
+
+// ============================================================================
+// Voice Cache Management Commands
+// ============================================================================
+
+/// Get voice cache statistics
+///
+/// Includes hit rate, size against the configured limit, and bytes used per
+/// provider and per voice.
+#[tauri::command]
+pub async fn get_voice_cache_stats(state: State<'_, AppState>) -> Result<VoiceCacheStats, String> {
+    let voice_manager = state.voice_manager.read().await;
+    let stats = voice_manager.get_cache_stats().await.map_err(|e| e.to_string())?;
+    let usage_percent = if stats.max_size_bytes > 0 {
+        (stats.current_size_bytes as f64 / stats.max_size_bytes as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    Ok(VoiceCacheStats {
+        stats,
+        cache_dir: voice_manager.cache_dir().display().to_string(),
+        usage_percent,
+    })
+}
+
+/// Clear cached voice audio
+///
+/// # Arguments
+/// * `provider` - Only clear audio from this provider (e.g., "openai")
+/// * `voice_id` - Only clear audio for this provider voice ID
+///
+/// With neither filter, the whole cache is cleared.
+///
+/// # Returns
+/// The number of entries removed
+#[tauri::command]
+pub async fn clear_voice_cache(
+    provider: Option<String>,
+    voice_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    let voice_manager = state.voice_manager.read().await;
+    voice_manager
+        .clear_cache_matching(provider.as_deref(), voice_id.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Set the voice cache size limit
+///
+/// Least recently used audio is evicted immediately if the cache is over the
+/// new limit. The limit is saved with the voice configuration.
+///
+/// # Returns
+/// The number of entries evicted
+#[tauri::command]
+pub async fn set_voice_cache_max_size(
+    max_size_mb: u64,
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    if max_size_mb == 0 {
+        return Err("Cache size limit must be at least 1 MB".to_string());
+    }
+
+    let mut voice_manager = state.voice_manager.write().await;
+    let evicted = voice_manager.set_cache_max_size(max_size_mb).await.map_err(|e| e.to_string())?;
+    save_masked_voice_config_disk(&app_handle, voice_manager.get_config());
+    Ok(evicted)
+}
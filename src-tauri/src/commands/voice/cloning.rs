@@ -0,0 +1,228 @@
+//! Voice Cloning Commands
+//!
+//! Guided voice cloning: validate reference samples, create the clone with
+//! the provider (XTTS-v2 speaker reference or ElevenLabs instant clone),
+//! register it as a voice profile, and delete it again.
+
+use std::path::PathBuf;
+
+use tauri::{Manager, State};
+
+use crate::commands::{AppState, VoiceProfileState};
+use crate::core::voice::cloning::{best_reference, validate_samples};
+use crate::core::voice::providers::elevenlabs::ElevenLabsProvider;
+use crate::core::voice::{
+    ClonedVoice, ClonedVoiceStore, CloneRequirements, CloneValidation, ProfileMetadata,
+    SampleAnalysis, VoiceProfile, VoiceProviderType,
+};
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Registry of cloned voices and their reference audio
+pub struct VoiceCloneState {
+    pub store: ClonedVoiceStore,
+}
+
+impl VoiceCloneState {
+    pub fn new(root: PathBuf) -> Self {
+        Self { store: ClonedVoiceStore::new(root) }
+    }
+
+    /// Voice profiles for all stored clones (registered at startup)
+    pub fn profiles(&self) -> Vec<VoiceProfile> {
+        match self.store.list() {
+            Ok(clones) => clones.iter().map(ClonedVoice::to_profile).collect(),
+            Err(e) => {
+                log::warn!("Failed to load cloned voices: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Directory for cloned voice reference audio
+pub fn voice_clones_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("voice_clones")
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn parse_clone_provider(provider: &str) -> Result<(VoiceProviderType, CloneRequirements), String> {
+    let provider_type = match provider {
+        "xtts_v2" => VoiceProviderType::XttsV2,
+        "elevenlabs" => VoiceProviderType::ElevenLabs,
+        _ => return Err(format!("Provider {} does not support voice cloning", provider)),
+    };
+    let requirements = CloneRequirements::for_provider(&provider_type)
+        .ok_or_else(|| format!("Provider {} does not support voice cloning", provider))?;
+    Ok((provider_type, requirements))
+}
+
+/// Decode and measure samples off the async runtime
+async fn analyze_samples(paths: Vec<PathBuf>) -> Result<Vec<SampleAnalysis>, String> {
+    tokio::task::spawn_blocking(move || {
+        paths
+            .iter()
+            .map(|p| SampleAnalysis::from_file(p).map_err(|e| e.to_string()))
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+async fn elevenlabs_provider(state: &State<'_, AppState>) -> Result<ElevenLabsProvider, String> {
+    let manager = state.voice_manager.read().await;
+    let config = manager
+        .get_config()
+        .elevenlabs
+        .clone()
+        .filter(|c| !c.api_key.is_empty())
+        .ok_or("ElevenLabs is not configured")?;
+    Ok(ElevenLabsProvider::new(config))
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Get a provider's reference audio requirements
+#[tauri::command]
+pub fn get_voice_clone_requirements(provider: String) -> Result<CloneRequirements, String> {
+    parse_clone_provider(&provider).map(|(_, requirements)| requirements)
+}
+
+/// Check reference samples (length, sample rate, silence, clipping) before cloning
+#[tauri::command]
+pub async fn validate_voice_clone_samples(
+    provider: String,
+    sample_paths: Vec<String>,
+) -> Result<CloneValidation, String> {
+    let (_, requirements) = parse_clone_provider(&provider)?;
+    let samples = analyze_samples(sample_paths.into_iter().map(PathBuf::from).collect()).await?;
+    Ok(validate_samples(&requirements, samples))
+}
+
+/// Create a cloned voice and register it as a voice profile
+///
+/// Samples are copied into app storage first, so the originals can be moved
+/// or deleted afterwards.
+#[tauri::command]
+pub async fn create_voice_clone(
+    name: String,
+    provider: String,
+    sample_paths: Vec<String>,
+    description: Option<String>,
+    metadata: Option<ProfileMetadata>,
+    state: State<'_, AppState>,
+    clones: State<'_, VoiceCloneState>,
+    profiles: State<'_, VoiceProfileState>,
+) -> Result<ClonedVoice, String> {
+    let (provider_type, requirements) = parse_clone_provider(&provider)?;
+    let clone_id = uuid::Uuid::new_v4().to_string();
+
+    let sources: Vec<PathBuf> = sample_paths.into_iter().map(PathBuf::from).collect();
+    let files = clones.store.import_samples(&clone_id, &sources).map_err(|e| e.to_string())?;
+
+    let result = async {
+        let validation = validate_samples(&requirements, analyze_samples(files.clone()).await?);
+        if !validation.valid {
+            return Err(validation.errors.join("; "));
+        }
+
+        let provider_voice_id = match provider_type {
+            VoiceProviderType::ElevenLabs => {
+                elevenlabs_provider(&state)
+                    .await?
+                    .create_instant_clone(&name, description.as_deref(), &files)
+                    .await
+                    .map_err(|e| e.to_string())?
+            }
+            _ => best_reference(&validation.samples)
+                .map(|s| s.path.display().to_string())
+                .ok_or("No usable reference sample")?,
+        };
+        Ok((provider_voice_id, validation.total_duration_secs))
+    }
+    .await;
+
+    let (provider_voice_id, total_duration_secs) = match result {
+        Ok(ok) => ok,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(clones.store.clone_dir(&clone_id));
+            return Err(e);
+        }
+    };
+
+    let mut clone = ClonedVoice {
+        id: clone_id,
+        name,
+        provider: provider_type,
+        provider_voice_id,
+        profile_id: None,
+        description,
+        metadata: metadata.unwrap_or_default(),
+        reference_files: files,
+        total_duration_secs,
+        created_at: chrono::Utc::now(),
+    };
+
+    let profile_id = profiles
+        .manager
+        .write()
+        .await
+        .create_profile(clone.to_profile())
+        .map_err(|e| e.to_string())?;
+    clone.profile_id = Some(profile_id);
+
+    clones.store.upsert(clone.clone()).map_err(|e| e.to_string())?;
+    log::info!("Created {:?} voice clone '{}'", clone.provider, clone.name);
+    Ok(clone)
+}
+
+/// List cloned voices
+#[tauri::command]
+pub fn list_voice_clones(clones: State<'_, VoiceCloneState>) -> Result<Vec<ClonedVoice>, String> {
+    clones.store.list().map_err(|e| e.to_string())
+}
+
+/// Delete a cloned voice, its voice profile, and its reference audio
+///
+/// ElevenLabs clones are also removed from the account.
+#[tauri::command]
+pub async fn delete_voice_clone(
+    clone_id: String,
+    state: State<'_, AppState>,
+    clones: State<'_, VoiceCloneState>,
+    profiles: State<'_, VoiceProfileState>,
+) -> Result<(), String> {
+    let clone = clones
+        .store
+        .get(&clone_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Voice clone not found: {}", clone_id))?;
+
+    if clone.provider == VoiceProviderType::ElevenLabs {
+        // The voice may already have been removed from the account; keep going
+        // so the local clone can always be cleaned up
+        match elevenlabs_provider(&state).await {
+            Ok(provider) => {
+                if let Err(e) = provider.delete_voice(&clone.provider_voice_id).await {
+                    log::warn!("Failed to delete ElevenLabs voice {}: {}", clone.provider_voice_id, e);
+                }
+            }
+            Err(e) => log::warn!("Cannot delete ElevenLabs voice {}: {}", clone.provider_voice_id, e),
+        }
+    }
+
+    if let Some(profile_id) = &clone.profile_id {
+        let _ = profiles.manager.write().await.delete_profile(profile_id);
+    }
+
+    clones.store.remove(&clone_id).map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -21,6 +21,17 @@ fn save_voice_config_disk(app_handle: &tauri::AppHandle, config: &VoiceConfig) {
     }
 }
 
+/// Save voice config to disk with MASKED secrets (never write plaintext secrets)
+pub(crate) fn save_masked_voice_config_disk(app_handle: &tauri::AppHandle, config: &VoiceConfig) {
+    let mut config_for_disk = config.clone();
+    if let Some(ref mut elevenlabs) = config_for_disk.elevenlabs {
+        if !elevenlabs.api_key.is_empty() {
+            elevenlabs.api_key = String::new(); // Mask for disk storage
+        }
+    }
+    save_voice_config_disk(app_handle, &config_for_disk);
+}
+
 // ============================================================================
 // Voice Configuration Commands
 // ============================================================================
@@ -50,19 +61,15 @@ pub async fn configure_voice(
         }
     }
 
-    // Save config to disk with MASKED secrets (never write plaintext secrets)
-    let mut config_for_disk = effective_config.clone();
-    if let Some(ref mut elevenlabs) = config_for_disk.elevenlabs {
-        if !elevenlabs.api_key.is_empty() {
-            elevenlabs.api_key = String::new(); // Mask for disk storage
-        }
-    }
-    save_voice_config_disk(&app_handle, &config_for_disk);
+    save_masked_voice_config_disk(&app_handle, &effective_config);
 
-    let new_manager = VoiceManager::new(effective_config);
+    let mut new_manager = VoiceManager::new(effective_config);
 
-    // Update state
+    // Update state, keeping the session log and fallback wiring
     let mut manager = state.voice_manager.write().await;
+    new_manager.set_session_log(manager.session_log().cloned());
+    new_manager.set_voice_profiles(manager.voice_profiles().cloned());
+    new_manager.set_app_handle(manager.app_handle().cloned());
     *manager = new_manager;
     Ok("Voice configuration updated successfully".to_string())
 }
@@ -1,7 +1,8 @@
 //! Voice Commands Module
 //!
 //! Commands for voice synthesis, provider management, voice presets,
-//! voice profiles, queue management, and audio cache.
+//! voice profiles, voice cloning, queue management, audio cache, push-to-talk
+//! input, read-aloud pre-generation, and session audio export.
 
 pub mod config;
 pub mod providers;
@@ -12,6 +13,11 @@ pub mod profiles;
 pub mod cache;
 pub mod synthesis_queue;
 pub mod speech;
+pub mod npc_voice;
+pub mod push_to_talk;
+pub mod read_aloud;
+pub mod cloning;
+pub mod session_audio;
 
 // Re-export all commands using glob to include Tauri __cmd__ macros
 // Note: config module name conflicts with llm::config at top-level, but
@@ -25,3 +31,8 @@ pub use profiles::*;
 pub use cache::*;
 pub use synthesis_queue::*;
 pub use speech::*;
+pub use npc_voice::*;
+pub use push_to_talk::*;
+pub use read_aloud::*;
+pub use cloning::*;
+pub use session_audio::*;
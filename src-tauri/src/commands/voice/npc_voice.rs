@@ -0,0 +1,217 @@
+//! NPC Voice Commands
+//!
+//! Commands for speaking dialogue in an NPC's assigned voice. NPCs without a
+//! linked voice profile are auto-assigned one based on age, gender, and
+//! personality so each character keeps a consistent voice.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::{AppState, MixerState};
+use crate::core::npc_gen::NPC;
+use crate::core::voice::{
+    apply_pronunciations, auto_assign_profile, qualified_voice_id, FallbackPolicy, NpcVoiceHints, VoiceProfile,
+    types::{QueuedVoice, VoicePriority},
+};
+use crate::database::NpcOps;
+
+use super::profiles::VoiceProfileState;
+use super::queue::enqueue_voice;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Result of resolving an NPC's voice profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcVoiceAssignment {
+    pub npc_id: String,
+    pub profile: VoiceProfile,
+    /// True if the profile was chosen automatically during this call
+    pub auto_assigned: bool,
+}
+
+/// Response for `speak_as_npc`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcSpeechResponse {
+    pub assignment: NpcVoiceAssignment,
+    pub queued: QueuedVoice,
+    /// Text after the NPC's accent respellings and the profile's
+    /// pronunciation overrides were applied
+    pub spoken_text: String,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Load an NPC from the in-memory store, falling back to the database
+async fn load_npc(npc_id: &str, state: &AppState) -> Result<NPC, String> {
+    if let Some(npc) = state.npc_store.get(npc_id) {
+        return Ok(npc);
+    }
+
+    let record = state.database.get_npc(npc_id).await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("NPC not found: {}", npc_id))?;
+    let json = record.data_json
+        .ok_or_else(|| format!("NPC {} has no stored data", npc_id))?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+/// Persist the profile link on the NPC's database record (if the NPC is persisted)
+async fn persist_npc_profile(npc_id: &str, profile_id: &str, state: &AppState) -> Result<(), String> {
+    if let Some(mut record) = state.database.get_npc(npc_id).await.map_err(|e| e.to_string())? {
+        record.voice_profile_id = Some(profile_id.to_string());
+        if let Some(json) = &record.data_json {
+            let mut npc: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+            npc["voice_profile_id"] = serde_json::json!(profile_id);
+            record.data_json = Some(serde_json::to_string(&npc).map_err(|e| e.to_string())?);
+        }
+        state.database.save_npc(&record).await.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Resolve the voice profile for an NPC, auto-assigning one if needed.
+///
+/// Resolution order: in-memory link, persisted `voice_profile_id`, then
+/// automatic assignment from all known profiles and presets.
+pub(crate) async fn resolve_npc_voice(
+    npc_id: &str,
+    state: &AppState,
+    profiles: &VoiceProfileState,
+) -> Result<NpcVoiceAssignment, String> {
+    if let Some(profile) = profiles.manager.read().await.get_profile_for_npc(npc_id) {
+        return Ok(NpcVoiceAssignment {
+            npc_id: npc_id.to_string(),
+            profile: profile.clone(),
+            auto_assigned: false,
+        });
+    }
+
+    // Restore a link persisted in a previous run
+    let persisted = state.database.get_npc(npc_id).await
+        .map_err(|e| e.to_string())?
+        .and_then(|r| r.voice_profile_id);
+    if let Some(profile_id) = persisted {
+        let mut manager = profiles.manager.write().await;
+        if manager.get_profile(&profile_id).is_some() {
+            manager.link_to_npc(&profile_id, npc_id).map_err(|e| e.to_string())?;
+            let profile = manager.get_profile(&profile_id).cloned()
+                .ok_or_else(|| format!("Profile not found: {}", profile_id))?;
+            return Ok(NpcVoiceAssignment {
+                npc_id: npc_id.to_string(),
+                profile,
+                auto_assigned: false,
+            });
+        }
+        log::warn!("NPC {} references unknown voice profile {}, reassigning", npc_id, profile_id);
+    }
+
+    let npc = load_npc(npc_id, state).await?;
+    let hints = NpcVoiceHints::from_npc(&npc);
+
+    let profile = {
+        let mut manager = profiles.manager.write().await;
+        let usage = manager.usage_counts();
+        let candidates = manager.list_all();
+        let chosen = auto_assign_profile(npc_id, &hints, &candidates, &usage)
+            .cloned()
+            .ok_or_else(|| "No voice profiles available for assignment".to_string())?;
+        manager.link_to_npc(&chosen.id, npc_id).map_err(|e| e.to_string())?;
+        chosen
+    };
+
+    persist_npc_profile(npc_id, &profile.id, state).await?;
+    log::info!("Auto-assigned voice profile '{}' to NPC {}", profile.name, npc_id);
+
+    Ok(NpcVoiceAssignment {
+        npc_id: npc_id.to_string(),
+        profile,
+        auto_assigned: true,
+    })
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Resolve (and auto-assign if needed) the voice profile for an NPC
+#[tauri::command]
+pub async fn assign_npc_voice(
+    npc_id: String,
+    state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
+) -> Result<NpcVoiceAssignment, String> {
+    resolve_npc_voice(&npc_id, &state, &profiles).await
+}
+
+/// Speak a line of dialogue in an NPC's voice
+///
+/// Resolves the NPC's voice profile (auto-assigning one if none is linked),
+/// turns the NPC's accent respellings back into speakable words, applies the
+/// profile's pronunciation overrides, and queues synthesis with the
+/// profile's voice settings at the given playback priority.
+#[tauri::command]
+pub async fn speak_as_npc(
+    npc_id: String,
+    text: String,
+    priority: Option<VoicePriority>,
+    session_id: Option<String>,
+    state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
+    mixer: State<'_, MixerState>,
+) -> Result<NpcSpeechResponse, String> {
+    if text.trim().is_empty() {
+        return Err("Cannot speak empty text".to_string());
+    }
+
+    let assignment = resolve_npc_voice(&npc_id, &state, &profiles).await?;
+    let quirks = load_npc(&npc_id, &state).await.ok().and_then(|npc| npc.voice.quirks);
+    let spoken_text = match &quirks {
+        Some(quirks) => quirks.speak(&text),
+        None => text,
+    };
+    let spoken_text = apply_pronunciations(&spoken_text, &assignment.profile.metadata.pronunciations);
+    let voice_id = qualified_voice_id(&assignment.profile);
+
+    let queued = enqueue_voice(
+        spoken_text.clone(),
+        voice_id,
+        Some(assignment.profile.settings.clone()),
+        priority.unwrap_or_default(),
+        session_id,
+        FallbackPolicy::Default,
+        state,
+        &mixer.mixer,
+    ).await?;
+
+    Ok(NpcSpeechResponse {
+        assignment,
+        queued,
+        spoken_text,
+    })
+}
+
+/// Set a pronunciation override on a user voice profile
+#[tauri::command]
+pub async fn set_voice_pronunciation(
+    profile_id: String,
+    word: String,
+    spoken_as: Option<String>,
+    profiles: State<'_, VoiceProfileState>,
+) -> Result<VoiceProfile, String> {
+    let mut manager = profiles.manager.write().await;
+    let profile = manager.get_profile_mut(&profile_id).map_err(|e| e.to_string())?;
+    match spoken_as {
+        Some(spoken) => {
+            profile.metadata.pronunciations.insert(word, spoken);
+        }
+        None => {
+            profile.metadata.pronunciations.remove(&word);
+        }
+    }
+    profile.updated_at = chrono::Utc::now();
+    Ok(profile.clone())
+}
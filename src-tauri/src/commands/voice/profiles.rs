@@ -2,15 +2,43 @@
 //!
 //! Commands for managing voice profiles and linking them to NPCs.
 
+use std::sync::Arc;
+
 use tauri::State;
+use tokio::sync::RwLock;
 
 use crate::core::voice::{
-    VoiceProfile, VoiceProviderType, ProfileMetadata,
+    VoiceProfile, VoiceProfileManager, VoiceProviderType, ProfileMetadata,
     Gender, AgeRange, get_dm_presets,
 };
 use crate::commands::AppState;
 use crate::database::NpcOps;
 
+// ============================================================================
+// State Types
+// ============================================================================
+
+/// State wrapper for the voice profile manager
+///
+/// Shared with the VoiceManager, which maps voices onto fallback providers.
+#[derive(Default)]
+pub struct VoiceProfileState {
+    pub manager: Arc<RwLock<VoiceProfileManager>>,
+}
+
+impl VoiceProfileState {
+    /// Create with user profiles restored from disk (e.g., cloned voices)
+    pub fn with_profiles(profiles: Vec<VoiceProfile>) -> Self {
+        let mut manager = VoiceProfileManager::new();
+        for profile in profiles {
+            if let Err(e) = manager.create_profile(profile) {
+                log::warn!("Skipping voice profile: {}", e);
+            }
+        }
+        Self { manager: Arc::new(RwLock::new(manager)) }
+    }
+}
+
 // ============================================================================
 // Voice Profile Commands
 // ============================================================================
@@ -22,7 +50,7 @@ pub async fn create_voice_profile(
     provider: String,
     voice_id: String,
     metadata: Option<ProfileMetadata>,
-    _state: State<'_, AppState>,
+    profiles: State<'_, VoiceProfileState>,
 ) -> Result<String, String> {
     let provider_type = match provider.as_str() {
         "elevenlabs" => VoiceProviderType::ElevenLabs,
@@ -35,6 +63,7 @@ pub async fn create_voice_profile(
         "xtts_v2" => VoiceProviderType::XttsV2,
         "fish_speech" => VoiceProviderType::FishSpeech,
         "dia" => VoiceProviderType::Dia,
+        "coqui" => VoiceProviderType::Coqui,
         _ => return Err(format!("Unknown provider: {}", provider)),
     };
 
@@ -43,7 +72,7 @@ pub async fn create_voice_profile(
         profile = profile.with_metadata(meta);
     }
 
-    Ok(profile.id)
+    profiles.manager.write().await.create_profile(profile).map_err(|e| e.to_string())
 }
 
 /// Link a voice profile to an NPC
@@ -1,14 +1,32 @@
 //! Voice Provider Installation Commands
 //!
-//! Commands for installing, checking, and managing voice providers (Piper, Coqui, etc.).
+//! Commands for installing, checking, and managing voice providers (Piper, Coqui, etc.),
+//! and for browsing, previewing, and downloading Piper voices.
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 
+use serde::Serialize;
+use tauri::{Emitter, State};
+
+use crate::commands::MixerState;
+use crate::core::audio::MixerChannel;
 use crate::core::voice::{
-    VoiceProviderType, ProviderInstaller, InstallStatus,
-    AvailablePiperVoice, get_recommended_piper_voices,
+    VoiceProviderType, ProviderInstaller, InstallStatus, VoiceDownloader,
+    AvailablePiperVoice, PiperVoiceFilter, get_recommended_piper_voices,
 };
 
+/// Event emitted while a Piper voice model downloads
+pub const PIPER_DOWNLOAD_PROGRESS_EVENT: &str = "piper-download:progress";
+
+/// Progress of a Piper voice download
+#[derive(Debug, Clone, Serialize)]
+pub struct PiperDownloadProgress {
+    pub voice_key: String,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+}
+
 /// Get the voice models directory path.
 /// Fallback chain: data_local_dir -> data_dir -> temp_dir (last resort, non-persistent)
 /// Note: temp_dir fallback may result in models being lost on system restart.
@@ -66,12 +84,75 @@ pub fn get_popular_piper_voices() -> Vec<(String, String, String)> {
         .collect()
 }
 
+/// Browse the full Piper voice catalog from Hugging Face
+///
+/// The catalog is cached for a day; `refresh` forces a refetch.
+#[tauri::command]
+pub async fn list_piper_voice_catalog(
+    filter: Option<PiperVoiceFilter>,
+    refresh: Option<bool>,
+) -> Result<Vec<AvailablePiperVoice>, String> {
+    let downloader = VoiceDownloader::new(get_models_dir());
+    let voices = downloader
+        .catalog(refresh.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let filter = filter.unwrap_or_default();
+    Ok(voices.into_iter().filter(|v| filter.matches(v)).collect())
+}
+
+/// Play a Piper voice's published sample before downloading it
+#[tauri::command]
+pub async fn preview_piper_voice(
+    voice_key: String,
+    speaker_id: Option<u32>,
+    mixer: State<'_, MixerState>,
+) -> Result<String, String> {
+    let downloader = VoiceDownloader::new(get_models_dir());
+    let path = downloader
+        .fetch_sample(&voice_key, speaker_id.unwrap_or(0))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let audio = tokio::fs::read(&path).await.map_err(|e| e.to_string())?;
+    mixer
+        .mixer
+        .play_bytes(MixerChannel::Voice, audio, 1.0)
+        .map_err(|e| e.to_string())?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
 /// Download a Piper voice from Hugging Face
+///
+/// Interrupted downloads resume on the next attempt, and files are checked
+/// against the catalog's MD5 digests. Progress is reported on the
+/// `piper-download:progress` event channel.
 #[tauri::command]
-pub async fn download_piper_voice(voice_key: String, quality: Option<String>) -> Result<String, String> {
-    let installer = ProviderInstaller::new(get_models_dir());
-    let path = installer
-        .download_piper_voice(&voice_key, quality.as_deref())
+pub async fn download_piper_voice(
+    voice_key: String,
+    quality: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    let downloader = VoiceDownloader::new(get_models_dir());
+
+    // Report roughly every percent rather than on every chunk
+    let last_percent = AtomicU64::new(u64::MAX);
+    let key = voice_key.clone();
+    let progress = Box::new(move |downloaded: u64, total: u64| {
+        let percent = (downloaded * 100).checked_div(total).unwrap_or(0);
+        if last_percent.swap(percent, Ordering::Relaxed) != percent {
+            let _ = app_handle.emit(PIPER_DOWNLOAD_PROGRESS_EVENT, PiperDownloadProgress {
+                voice_key: key.clone(),
+                downloaded_bytes: downloaded,
+                total_bytes: total,
+            });
+        }
+    });
+
+    let path = downloader
+        .download_voice(&voice_key, quality.as_deref(), Some(progress))
         .await
         .map_err(|e| e.to_string())?;
 
@@ -0,0 +1,271 @@
+//! Push-to-Talk Commands
+//!
+//! Record from an input device while the push-to-talk key is held, then
+//! transcribe and route the text: recognized combat actions are applied to
+//! the session's combat tracker, dice rolls are rolled, and everything else
+//! is handed to the frontend as chat input.
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, State};
+
+use crate::commands::AppState;
+use crate::core::campaign::dice::{DiceRoller, RollResult};
+use crate::core::session_manager::CombatState;
+use crate::core::speech_input::{
+    list_input_devices, parse_intent, InputDeviceInfo, PushToTalkRecorder, VoiceIntent,
+    WHISPER_SAMPLE_RATE,
+};
+use crate::core::transcription::TranscriptionProviderType;
+
+use super::speech::build_transcription_manager;
+
+/// Event emitted with every push-to-talk transcript
+pub const STT_TRANSCRIPT_EVENT: &str = "stt:transcript";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Push-to-talk recorder state
+#[derive(Default)]
+pub struct SpeechInputState {
+    pub recorder: tokio::sync::Mutex<PushToTalkRecorder>,
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Where a transcript should be routed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechRoute {
+    /// Apply recognized game actions, send everything else to chat
+    #[default]
+    Auto,
+    /// Always send to chat
+    Chat,
+    /// Only accept game actions; unrecognized speech is an error
+    Combat,
+}
+
+/// What happened to a push-to-talk transcript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushToTalkResult {
+    pub transcript: String,
+    pub intent: VoiceIntent,
+    /// "chat", "combat", "dice", or "none" (silence)
+    pub routed_to: String,
+    /// Human-readable outcome of an applied action
+    pub message: Option<String>,
+    pub roll: Option<RollResult>,
+    pub provider: Option<String>,
+    pub duration_secs: f32,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Find a combatant by spoken name: exact match first, then a unique partial match
+fn find_combatant_id(combat: &CombatState, spoken: &str) -> Result<String, String> {
+    let spoken = spoken.to_lowercase();
+
+    if let Some(c) = combat.combatants.iter().find(|c| c.name.to_lowercase() == spoken) {
+        return Ok(c.id.clone());
+    }
+
+    let partial: Vec<_> = combat
+        .combatants
+        .iter()
+        .filter(|c| c.name.to_lowercase().contains(&spoken))
+        .collect();
+    match partial.as_slice() {
+        [only] => Ok(only.id.clone()),
+        [] => Err(format!("No combatant named '{}'", spoken)),
+        many => Err(format!(
+            "'{}' is ambiguous: {}",
+            spoken,
+            many.iter().map(|c| c.name.as_str()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Apply a combat intent to the session's combat tracker
+fn apply_combat_intent(
+    intent: &VoiceIntent,
+    session_id: &str,
+    state: &AppState,
+) -> Result<String, String> {
+    let sessions = &state.session_manager;
+    let combat = sessions
+        .get_combat(session_id)
+        .ok_or_else(|| "No active combat".to_string())?;
+
+    match intent {
+        VoiceIntent::Damage { target, amount } => {
+            let id = find_combatant_id(&combat, target)?;
+            let hp = sessions.damage_combatant(session_id, &id, *amount).map_err(|e| e.to_string())?;
+            Ok(format!("{} takes {} damage ({} HP left)", target, amount, hp))
+        }
+        VoiceIntent::Heal { target, amount } => {
+            let id = find_combatant_id(&combat, target)?;
+            let hp = sessions.heal_combatant(session_id, &id, *amount).map_err(|e| e.to_string())?;
+            Ok(format!("{} heals {} HP ({} HP now)", target, amount, hp))
+        }
+        VoiceIntent::AddCondition { target, condition } => {
+            let id = find_combatant_id(&combat, target)?;
+            sessions
+                .add_condition_by_name(session_id, &id, condition, None, None, None)
+                .map_err(|e| e.to_string())?;
+            Ok(format!("{} is {}", target, condition))
+        }
+        VoiceIntent::RemoveCondition { target, condition } => {
+            let id = find_combatant_id(&combat, target)?;
+            let removed = sessions
+                .remove_advanced_condition_by_name(session_id, &id, condition)
+                .map_err(|e| e.to_string())?;
+            if removed.is_empty() {
+                Ok(format!("{} was not {}", target, condition))
+            } else {
+                Ok(format!("{} is no longer {}", target, condition))
+            }
+        }
+        VoiceIntent::NextTurn => {
+            let current = sessions.next_turn(session_id).map_err(|e| e.to_string())?;
+            Ok(match current {
+                Some(c) => format!("{}'s turn", c.name),
+                None => "Next turn".to_string(),
+            })
+        }
+        VoiceIntent::PreviousTurn => {
+            let current = sessions.previous_turn(session_id).map_err(|e| e.to_string())?;
+            Ok(match current {
+                Some(c) => format!("Back to {}'s turn", c.name),
+                None => "Previous turn".to_string(),
+            })
+        }
+        VoiceIntent::RollDice { .. } | VoiceIntent::Chat { .. } => {
+            Err("Not a combat action".to_string())
+        }
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// List audio input devices available for push-to-talk
+#[tauri::command]
+pub fn list_audio_input_devices() -> Result<Vec<InputDeviceInfo>, String> {
+    list_input_devices().map_err(|e| e.to_string())
+}
+
+/// Start recording from the given input device (or the system default)
+#[tauri::command]
+pub async fn start_push_to_talk(
+    device_name: Option<String>,
+    ptt: State<'_, SpeechInputState>,
+) -> Result<(), String> {
+    let mut recorder = ptt.recorder.lock().await;
+    recorder.start(device_name.as_deref()).map_err(|e| e.to_string())
+}
+
+/// Discard the current recording without transcribing
+#[tauri::command]
+pub async fn cancel_push_to_talk(ptt: State<'_, SpeechInputState>) -> Result<(), String> {
+    ptt.recorder.lock().await.cancel();
+    Ok(())
+}
+
+/// Stop recording, transcribe, and route the result
+///
+/// Transcription prefers local whisper.cpp and falls back to cloud Whisper.
+/// With `route = auto`, recognized combat actions are applied when
+/// `session_id` has active combat; everything else is emitted on
+/// `stt:transcript` for the chat input.
+#[tauri::command]
+pub async fn stop_push_to_talk(
+    session_id: Option<String>,
+    route: Option<SpeechRoute>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    ptt: State<'_, SpeechInputState>,
+) -> Result<PushToTalkResult, String> {
+    let route = route.unwrap_or_default();
+    let recording = ptt.recorder.lock().await.stop().map_err(|e| e.to_string())?;
+    let duration_secs = recording.duration_secs();
+
+    if recording.is_silent() {
+        return Ok(PushToTalkResult {
+            transcript: String::new(),
+            intent: VoiceIntent::Chat { text: String::new() },
+            routed_to: "none".to_string(),
+            message: Some("No speech detected".to_string()),
+            roll: None,
+            provider: None,
+            duration_secs,
+        });
+    }
+
+    let wav = recording.to_wav(WHISPER_SAMPLE_RATE);
+    let file = tempfile::Builder::new()
+        .prefix("ptt-")
+        .suffix(".wav")
+        .tempfile()
+        .map_err(|e| e.to_string())?;
+    tokio::fs::write(file.path(), &wav).await.map_err(|e| e.to_string())?;
+
+    let manager = build_transcription_manager(&state, TranscriptionProviderType::Local);
+    let transcription = manager.transcribe(file.path()).await.map_err(|e| e.to_string())?;
+    let transcript = transcription.text.trim().to_string();
+
+    let intent = match route {
+        SpeechRoute::Chat => VoiceIntent::Chat { text: transcript.clone() },
+        _ => parse_intent(&transcript),
+    };
+
+    let mut result = PushToTalkResult {
+        transcript: transcript.clone(),
+        intent: intent.clone(),
+        routed_to: "chat".to_string(),
+        message: None,
+        roll: None,
+        provider: Some(transcription.provider),
+        duration_secs,
+    };
+
+    match &intent {
+        VoiceIntent::RollDice { expression } => {
+            let roll = DiceRoller::new().quick_roll(expression).map_err(|e| e.to_string())?;
+            result.message = Some(format!("{} = {}", expression, roll.total));
+            result.roll = Some(roll);
+            result.routed_to = "dice".to_string();
+        }
+        intent if intent.is_combat() => {
+            let combat_session = session_id
+                .as_deref()
+                .filter(|id| state.session_manager.get_combat(id).is_some());
+            match (combat_session, route) {
+                (Some(id), _) => {
+                    result.message = Some(apply_combat_intent(intent, id, &state)?);
+                    result.routed_to = "combat".to_string();
+                }
+                (None, SpeechRoute::Combat) => {
+                    return Err("No active combat for this session".to_string());
+                }
+                (None, _) => {
+                    result.intent = VoiceIntent::Chat { text: transcript.clone() };
+                }
+            }
+        }
+        _ => {
+            if route == SpeechRoute::Combat {
+                return Err(format!("Not a recognized game action: \"{}\"", transcript));
+            }
+        }
+    }
+
+    let _ = app_handle.emit(STT_TRANSCRIPT_EVENT, &result);
+    Ok(result)
+}
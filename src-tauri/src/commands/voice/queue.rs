@@ -1,15 +1,22 @@
 //! Voice Queue Commands
 //!
-//! Commands for managing the voice synthesis queue.
+//! Commands for managing the voice playback queue: priorities, interrupting
+//! the current utterance, pausing the whole queue, and replaying the last line.
 
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
 use tauri::State;
+use tokio::sync::RwLock as AsyncRwLock;
 
+use crate::core::audio::{AudioMixer, MixerChannel};
 use crate::core::voice::{
-    SynthesisRequest, OutputFormat,
-    types::{QueuedVoice, VoiceStatus},
+    FallbackPolicy, SynthesisRequest, OutputFormat, VoiceManager, VoiceSettings,
+    types::{QueuedVoice, VoicePriority, VoiceStatus},
 };
-use crate::commands::AppState;
+use crate::commands::{AppState, MixerState};
 
 /// Global flag to prevent multiple concurrent queue processors.
 /// NOTE: Intentional singleton pattern - the app has a single voice queue shared
@@ -26,33 +33,87 @@ impl Drop for ProcessingGuard {
     }
 }
 
+/// Result of replaying the last utterance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayVoiceResponse {
+    pub queued: QueuedVoice,
+    /// Cached audio file, for saving a copy
+    pub audio_path: String,
+}
+
 // ============================================================================
 // Voice Queue Commands
 // ============================================================================
 
+/// Queue text for speech
+///
+/// Higher priorities are spoken first. A `combat` line cuts off `flavor` text
+/// that is playing; an `urgent` line cuts off anything. Lines with a
+/// `session_id` are logged for that session's audio recap. `fallback`
+/// controls whether another provider may speak the line if the voice's own
+/// provider fails.
 #[tauri::command]
 pub async fn queue_voice(
     text: String,
     voice_id: Option<String>,
+    priority: Option<VoicePriority>,
+    session_id: Option<String>,
+    fallback: Option<FallbackPolicy>,
     state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
 ) -> Result<QueuedVoice, String> {
-    // 1. Determine Voice ID
+    // Determine Voice ID
     let vid = voice_id.unwrap_or_else(|| "default".to_string());
 
-    // 2. Add to Queue
+    enqueue_voice(
+        text,
+        vid,
+        None,
+        priority.unwrap_or_default(),
+        session_id,
+        fallback.unwrap_or_default(),
+        state,
+        &mixer.mixer,
+    ).await
+}
+
+/// Add an utterance to the voice queue and make sure the queue processor is running.
+///
+/// Shared by `queue_voice` and other commands that speak on behalf of a
+/// character (e.g., `speak_as_npc`).
+pub(crate) async fn enqueue_voice(
+    text: String,
+    voice_id: String,
+    settings: Option<VoiceSettings>,
+    priority: VoicePriority,
+    session_id: Option<String>,
+    fallback: FallbackPolicy,
+    state: State<'_, AppState>,
+    mixer: &AudioMixer,
+) -> Result<QueuedVoice, String> {
+    // 1. Add to Queue, cutting off lower-priority speech if this preempts it
     let item = {
         let mut manager = state.voice_manager.write().await;
-        manager.add_to_queue(text, vid)
+        let mut item = manager.add_to_queue_with_priority(text, voice_id, settings, priority);
+        if let Some(session_id) = session_id {
+            manager.set_session_id(&item.id, session_id.clone());
+            item.session_id = Some(session_id);
+        }
+        if fallback != FallbackPolicy::Default {
+            manager.set_fallback_policy(&item.id, fallback.clone());
+            item.fallback = fallback;
+        }
+        let preempted = manager
+            .playing_item()
+            .is_some_and(|playing| priority.preempts(playing.priority));
+        if preempted {
+            manager.interrupt_current();
+        }
+        item
     };
 
-    // 3. Trigger Processing (Background) - Only spawn if not already processing
-    // Use atomic compare_exchange to prevent multiple concurrent processors
-    // Note: process_voice_queue spawns a detached task internally via tauri::async_runtime::spawn.
-    // The spawned task has a ProcessingGuard that resets IS_QUEUE_PROCESSING on exit.
-    if IS_QUEUE_PROCESSING.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
-        // Spawn always succeeds - the guard inside the task handles cleanup
-        let _ = process_voice_queue(state).await;
-    }
+    // 2. Trigger Processing (Background)
+    start_queue_processor(&state, mixer);
 
     Ok(item)
 }
@@ -63,29 +124,127 @@ pub async fn get_voice_queue(state: State<'_, AppState>) -> Result<Vec<QueuedVoi
     Ok(manager.get_queue())
 }
 
+/// Remove an item from the queue, stopping it if it is playing
 #[tauri::command]
 pub async fn cancel_voice(queue_id: String, state: State<'_, AppState>) -> Result<(), String> {
     let mut manager = state.voice_manager.write().await;
+    if manager.playing_item().is_some_and(|item| item.id == queue_id) {
+        manager.interrupt_current();
+    }
     manager.remove_from_queue(&queue_id);
     Ok(())
 }
 
-/// Internal helper to process the queue
-async fn process_voice_queue(state: State<'_, AppState>) -> Result<(), String> {
-    let vm_clone = state.voice_manager.clone();
+/// Stop the utterance that is playing and move on to the next one
+#[tauri::command]
+pub async fn interrupt_voice(state: State<'_, AppState>) -> Result<Option<QueuedVoice>, String> {
+    let mut manager = state.voice_manager.write().await;
+    Ok(manager.interrupt_current())
+}
+
+/// Pause the whole voice queue, including the utterance that is playing
+#[tauri::command]
+pub async fn pause_voice_queue(state: State<'_, AppState>) -> Result<(), String> {
+    state.voice_manager.write().await.pause_queue();
+    Ok(())
+}
+
+/// Resume the voice queue
+#[tauri::command]
+pub async fn resume_voice_queue(
+    state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
+) -> Result<(), String> {
+    state.voice_manager.write().await.resume_queue();
+    start_queue_processor(&state, &mixer.mixer);
+    Ok(())
+}
+
+/// Speak the last utterance again from the voice cache
+///
+/// Returns the cached audio path so the UI can offer to save it.
+#[tauri::command]
+pub async fn replay_last_voice(
+    state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
+) -> Result<ReplayVoiceResponse, String> {
+    let queued = {
+        let mut manager = state.voice_manager.write().await;
+        let last = manager.last_played().cloned().ok_or("Nothing has been spoken yet")?;
+        let audio_path = last
+            .audio_path
+            .clone()
+            .filter(|p| Path::new(p).exists())
+            .ok_or("Audio for the last utterance is no longer cached")?;
+
+        let item = manager.add_to_queue_with_priority(
+            last.text,
+            last.voice_id,
+            last.settings,
+            VoicePriority::Combat,
+        );
+        manager.set_audio_path(&item.id, audio_path);
+        manager.get_queue().into_iter().find(|i| i.id == item.id).unwrap_or(item)
+    };
+
+    start_queue_processor(&state, &mixer.mixer);
 
+    let audio_path = queued.audio_path.clone().unwrap_or_default();
+    Ok(ReplayVoiceResponse { queued, audio_path })
+}
+
+// ============================================================================
+// Queue Processing
+// ============================================================================
+
+/// Spawn the queue processor unless one is already running
+pub(crate) fn start_queue_processor(state: &State<'_, AppState>, mixer: &AudioMixer) {
+    // Use atomic compare_exchange to prevent multiple concurrent processors.
+    // The spawned task has a ProcessingGuard that resets IS_QUEUE_PROCESSING on exit.
+    if IS_QUEUE_PROCESSING.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+        process_voice_queue(state.voice_manager.clone(), mixer.clone());
+    }
+}
+
+/// Synthesize an item, or reuse its cached audio (replays)
+async fn resolve_audio(
+    vm: &Arc<AsyncRwLock<VoiceManager>>,
+    item: &QueuedVoice,
+) -> Result<String, String> {
+    if let Some(path) = item.audio_path.as_ref().filter(|p| Path::new(p).exists()) {
+        return Ok(path.clone());
+    }
+
+    let req = SynthesisRequest {
+        text: item.text.clone(),
+        voice_id: item.voice_id.clone(),
+        settings: item.settings.clone(),
+        output_format: OutputFormat::Mp3, // Default
+    };
+
+    // Perform synthesis without holding the write lock
+    let manager = vm.read().await;
+    let result = manager
+        .synthesize_with_policy(req, &[], &item.fallback)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(result.audio_path.to_string_lossy().into_owned())
+}
+
+/// Internal helper to process the queue
+fn process_voice_queue(vm_clone: Arc<AsyncRwLock<VoiceManager>>, mixer: AudioMixer) {
     // Spawn a detached task
     tauri::async_runtime::spawn(async move {
         // Guard ensures IS_QUEUE_PROCESSING is reset even if task panics
         let _guard = ProcessingGuard;
 
-        // We loop until queue is empty or processing fails
+        // We loop until the queue is empty or paused
         loop {
             // 1. Get next pending AND mark as Processing atomically (single write lock)
             // This prevents TOCTOU race condition between selection and claim
             let next_item = {
                 let mut manager = vm_clone.write().await;
-                if manager.is_playing {
+                if manager.is_playing || manager.paused {
                     None
                 } else if let Some(item) = manager.get_next_pending() {
                     // Atomically claim the item by updating its status
@@ -96,75 +255,51 @@ async fn process_voice_queue(state: State<'_, AppState>) -> Result<(), String> {
                 }
             };
 
-            if let Some(item) = next_item {
-
-                // 3. Synthesize
-                let req = SynthesisRequest {
-                    text: item.text.clone(),
-                    voice_id: item.voice_id.clone(),
-                    settings: None,
-                    output_format: OutputFormat::Mp3, // Default
-                };
-
-                // Perform synthesis without holding lock
-                let result = {
-                    let manager = vm_clone.read().await;
-                    manager.synthesize(req).await
-                };
-
-                match result {
-                    Ok(res) => {
-                        // 4. Synthesized. Now Play.
-                        // Read file
-                        if let Ok(audio_data) = tokio::fs::read(&res.audio_path).await {
-                             // Mark Playing
-                            {
-                                let mut manager = vm_clone.write().await;
-                                manager.update_status(&item.id, VoiceStatus::Playing);
-                                manager.is_playing = true;
-                            }
-
-                            // Play (Blocking for now, inside spawn)
-                            let vm_for_clos = vm_clone.clone();
-                            let play_result = tokio::task::spawn_blocking(move || {
-                                let manager = vm_for_clos.blocking_read();
-                                manager.play_audio(audio_data)
-                            }).await;
-
-                            let play_result = match play_result {
-                                Ok(inner) => inner.map_err(|e| e.to_string()),
-                                Err(e) => Err(e.to_string()),
-                            };
-
-                            // Mark Completed
-                            {
-                                let mut manager = vm_clone.write().await;
-                                manager.is_playing = false;
-                                manager.update_status(&item.id, if play_result.is_ok() {
-                                    VoiceStatus::Completed
-                                } else {
-                                    VoiceStatus::Failed("Playback failed".into())
-                                });
-                            }
-                        } else {
-                             // File read failed
-                            let mut manager = vm_clone.write().await;
-                            manager.update_status(&item.id, VoiceStatus::Failed("Could not read audio file".into()));
-                        }
-                    }
-                    Err(e) => {
-                        // Synthesis Failed
-                        let mut manager = vm_clone.write().await;
-                        manager.update_status(&item.id, VoiceStatus::Failed(e.to_string()));
-                    }
-                }
-            } else {
-                // No more items - guard will reset flag on drop
+            let Some(item) = next_item else {
+                // Nothing to do - guard will reset flag on drop
                 break;
+            };
+
+            // 2. Synthesize (or reuse cached audio)
+            let audio_path = match resolve_audio(&vm_clone, &item).await {
+                Ok(path) => path,
+                Err(e) => {
+                    let mut manager = vm_clone.write().await;
+                    manager.update_status(&item.id, VoiceStatus::Failed(e));
+                    continue;
+                }
+            };
+
+            let Ok(audio_data) = tokio::fs::read(&audio_path).await else {
+                let mut manager = vm_clone.write().await;
+                manager.update_status(&item.id, VoiceStatus::Failed("Could not read audio file".into()));
+                continue;
+            };
+
+            // 3. Play on the voice channel; the manager holds the track so the
+            // utterance can be paused or interrupted
+            let track = match mixer.play_bytes(MixerChannel::Voice, audio_data, 1.0) {
+                Ok(track) => track,
+                Err(e) => {
+                    log::warn!("Voice playback failed: {}", e);
+                    let mut manager = vm_clone.write().await;
+                    manager.update_status(&item.id, VoiceStatus::Failed("Playback failed".into()));
+                    continue;
+                }
+            };
+
+            {
+                let mut manager = vm_clone.write().await;
+                manager.set_audio_path(&item.id, audio_path);
+                manager.begin_playback(&item.id, track.clone());
             }
+
+            let play_result = tokio::task::spawn_blocking(move || track.sleep_until_end()).await;
+
+            // 4. Mark Completed (unless it was interrupted meanwhile)
+            let mut manager = vm_clone.write().await;
+            manager.finish_playback(&item.id, play_result.err().map(|e| e.to_string()));
         }
         // ProcessingGuard drops here, resetting IS_QUEUE_PROCESSING
     });
-
-    Ok(())
 }
@@ -0,0 +1,268 @@
+//! Read-Aloud Pre-Generation Commands
+//!
+//! Synthesize a session's read-aloud passages ahead of time (e.g., overnight)
+//! and play them back instantly from session storage during the game.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager, State};
+use tokio::sync::{Mutex as AsyncMutex, RwLock as AsyncRwLock};
+
+use crate::commands::{AppState, MixerState};
+use crate::core::voice::{
+    types::{QueuedVoice, VoicePriority},
+    OutputFormat, ReadAloudRequest, ReadAloudStatus, ReadAloudStore, SessionReadAloud,
+    SynthesisRequest, VoiceManager,
+};
+
+use super::queue::start_queue_processor;
+
+/// Event emitted as each passage finishes (or fails)
+pub const READ_ALOUD_PROGRESS_EVENT: &str = "read-aloud:progress";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Read-aloud store; the lock serializes manifest load-modify-save cycles
+pub struct ReadAloudState {
+    pub store: Arc<AsyncMutex<ReadAloudStore>>,
+}
+
+impl ReadAloudState {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            store: Arc::new(AsyncMutex::new(ReadAloudStore::new(root))),
+        }
+    }
+}
+
+/// Directory for pre-generated session audio
+pub fn read_aloud_dir(app_handle: &tauri::AppHandle) -> PathBuf {
+    let dir = app_handle.path().app_data_dir().unwrap_or_else(|_| PathBuf::from("."));
+    dir.join("read_aloud")
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Progress of a pre-generation batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadAloudProgressEvent {
+    pub session_id: String,
+    pub passage_id: String,
+    pub status: ReadAloudStatus,
+    /// Passages finished in this batch so far
+    pub completed: usize,
+    /// Passages in this batch
+    pub total: usize,
+}
+
+// ============================================================================
+// Background Generation
+// ============================================================================
+
+/// Synthesize passages one at a time, copying each into session storage
+async fn generate_passages(
+    session_id: String,
+    passage_ids: Vec<String>,
+    voice_manager: Arc<AsyncRwLock<VoiceManager>>,
+    store: Arc<AsyncMutex<ReadAloudStore>>,
+    app_handle: tauri::AppHandle,
+) {
+    let total = passage_ids.len();
+    let tags = vec![format!("session:{}", session_id), "read_aloud".to_string()];
+
+    for (index, passage_id) in passage_ids.into_iter().enumerate() {
+        let passage = {
+            let store = store.lock().await;
+            match store.load(&session_id) {
+                Ok(manifest) => manifest.get(&passage_id).cloned(),
+                Err(e) => {
+                    log::warn!("Read-aloud manifest for {} unreadable: {}", session_id, e);
+                    return;
+                }
+            }
+        };
+        // Deleted while the batch was running
+        let Some(passage) = passage else { continue };
+
+        let request = SynthesisRequest {
+            text: passage.text.clone(),
+            voice_id: passage.voice_id.clone(),
+            settings: None,
+            output_format: OutputFormat::Mp3,
+        };
+        let result = voice_manager
+            .read()
+            .await
+            .synthesize_with_tags(request, &tags)
+            .await
+            .map_err(|e| e.to_string());
+
+        let status = {
+            let store = store.lock().await;
+            let mut manifest = match store.load(&session_id) {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    log::warn!("Read-aloud manifest for {} unreadable: {}", session_id, e);
+                    return;
+                }
+            };
+
+            match result.and_then(|r| {
+                store
+                    .store_audio(&session_id, &passage_id, &r.audio_path)
+                    .map_err(|e| e.to_string())
+            }) {
+                Ok(path) => manifest.mark_ready(&passage_id, path),
+                Err(e) => {
+                    log::warn!("Read-aloud passage {} failed: {}", passage_id, e);
+                    manifest.mark_failed(&passage_id, e);
+                }
+            }
+
+            if let Err(e) = store.save(&manifest) {
+                log::warn!("Failed to save read-aloud manifest for {}: {}", session_id, e);
+            }
+            manifest.get(&passage_id).map(|p| p.status.clone()).unwrap_or_default()
+        };
+
+        let _ = app_handle.emit(READ_ALOUD_PROGRESS_EVENT, ReadAloudProgressEvent {
+            session_id: session_id.clone(),
+            passage_id,
+            status,
+            completed: index + 1,
+            total,
+        });
+    }
+
+    log::info!("Read-aloud pre-generation finished for session {} ({} passages)", session_id, total);
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Pre-generate audio for a session's read-aloud passages
+///
+/// Passages are added to the session and synthesized in the background;
+/// progress is reported through `read-aloud:progress` events. Passages that
+/// already have audio are skipped.
+///
+/// # Arguments
+/// * `passages` - Text to read, each with an optional title and voice
+/// * `default_voice_id` - Voice for passages without one (defaults to the
+///   configured default voice)
+#[tauri::command]
+pub async fn pregenerate_read_aloud(
+    session_id: String,
+    passages: Vec<ReadAloudRequest>,
+    default_voice_id: Option<String>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<SessionReadAloud, String> {
+    let default_voice_id = match default_voice_id {
+        Some(id) => id,
+        None => state
+            .voice_manager
+            .read()
+            .await
+            .get_config()
+            .default_voice_id
+            .clone()
+            .unwrap_or_else(|| "default".to_string()),
+    };
+
+    let (manifest, to_generate) = {
+        let store = read_aloud.store.lock().await;
+        let mut manifest = store.load(&session_id).map_err(|e| e.to_string())?;
+        let to_generate = manifest.add_passages(passages, &default_voice_id);
+        store.save(&manifest).map_err(|e| e.to_string())?;
+        (manifest, to_generate)
+    };
+
+    if !to_generate.is_empty() {
+        tauri::async_runtime::spawn(generate_passages(
+            session_id,
+            to_generate,
+            state.voice_manager.clone(),
+            read_aloud.store.clone(),
+            app_handle,
+        ));
+    }
+
+    Ok(manifest)
+}
+
+/// Get a session's read-aloud passages and their generation status
+#[tauri::command]
+pub async fn get_session_read_aloud(
+    session_id: String,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<SessionReadAloud, String> {
+    read_aloud.store.lock().await.load(&session_id).map_err(|e| e.to_string())
+}
+
+/// Play a pre-generated passage through the voice queue
+#[tauri::command]
+pub async fn play_read_aloud(
+    session_id: String,
+    passage_id: String,
+    state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<QueuedVoice, String> {
+    let passage = {
+        let store = read_aloud.store.lock().await;
+        let manifest = store.load(&session_id).map_err(|e| e.to_string())?;
+        manifest
+            .get(&passage_id)
+            .cloned()
+            .ok_or_else(|| format!("Read-aloud passage not found: {}", passage_id))?
+    };
+
+    if !passage.is_playable() {
+        return Err("Audio for this passage has not been generated yet".to_string());
+    }
+    let audio_path = passage.audio_path.unwrap_or_default().to_string_lossy().into_owned();
+
+    let queued = {
+        let mut manager = state.voice_manager.write().await;
+        let item = manager.add_to_queue_with_priority(passage.text, passage.voice_id, None, VoicePriority::Normal);
+        manager.set_audio_path(&item.id, audio_path);
+        manager.set_session_id(&item.id, session_id);
+        manager.get_queue().into_iter().find(|i| i.id == item.id).unwrap_or(item)
+    };
+
+    start_queue_processor(&state, &mixer.mixer);
+    Ok(queued)
+}
+
+/// Remove one passage and its audio from a session
+#[tauri::command]
+pub async fn remove_read_aloud_passage(
+    session_id: String,
+    passage_id: String,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<(), String> {
+    let store = read_aloud.store.lock().await;
+    let mut manifest = store.load(&session_id).map_err(|e| e.to_string())?;
+    if let Some(path) = manifest.remove(&passage_id).and_then(|p| p.audio_path) {
+        let _ = std::fs::remove_file(path);
+    }
+    store.save(&manifest).map_err(|e| e.to_string())
+}
+
+/// Delete all pre-generated audio for a session
+#[tauri::command]
+pub async fn delete_session_read_aloud(
+    session_id: String,
+    read_aloud: State<'_, ReadAloudState>,
+) -> Result<(), String> {
+    read_aloud.store.lock().await.delete(&session_id).map_err(|e| e.to_string())
+}
@@ -0,0 +1,66 @@
+//! Session Audio Export Commands
+//!
+//! Stitch the lines spoken during a game session into one audio file, for
+//! players who want a "radio drama" recap.
+
+use std::path::PathBuf;
+
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::core::voice::session_audio::stitch_audio;
+use crate::core::voice::{SessionExportOptions, SessionExportSummary};
+
+/// Export a session's spoken lines as a single MP3/OGG file
+///
+/// Lines are joined in the order they were spoken, with `gap_ms` of silence
+/// between them and an optional looping music bed. Lines whose audio has
+/// since been evicted from the voice cache are skipped and counted.
+#[tauri::command]
+pub async fn export_session_audio(
+    session_id: String,
+    output_path: String,
+    options: Option<SessionExportOptions>,
+    state: State<'_, AppState>,
+) -> Result<SessionExportSummary, String> {
+    let options = options.unwrap_or_default();
+
+    let utterances = {
+        let manager = state.voice_manager.read().await;
+        let log = manager.session_log().ok_or("Session audio logging is not enabled")?;
+        log.load(&session_id).map_err(|e| e.to_string())?
+    };
+
+    let (clips, missing): (Vec<PathBuf>, Vec<PathBuf>) = utterances
+        .into_iter()
+        .map(|u| u.audio_path)
+        .partition(|path| path.exists());
+    if clips.is_empty() {
+        return Err(if missing.is_empty() {
+            format!("Nothing was spoken in session {}", session_id)
+        } else {
+            "Audio for this session is no longer cached".to_string()
+        });
+    }
+
+    let mut output_path = PathBuf::from(output_path);
+    if output_path.extension().is_none() {
+        output_path.set_extension(options.format.extension());
+    }
+
+    stitch_audio(&clips, &options, &output_path).await.map_err(|e| e.to_string())?;
+    log::info!(
+        "Exported {} lines of session {} to {}",
+        clips.len(),
+        session_id,
+        output_path.display()
+    );
+
+    Ok(SessionExportSummary {
+        session_id,
+        output_path,
+        format: options.format,
+        utterance_count: clips.len(),
+        skipped_count: missing.len(),
+    })
+}
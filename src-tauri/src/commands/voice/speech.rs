@@ -8,6 +8,10 @@ use tauri::State;
 
 use crate::commands::AppState;
 use crate::core::llm::LLMConfig;
+use crate::core::transcription::{
+    LocalWhisperTranscriptionProvider, TranscriptionManager, TranscriptionManagerBuilder,
+    TranscriptionProviderType, TranscriptionResult,
+};
 use crate::core::voice::{
     VoiceProviderType, SynthesisRequest, OutputFormat,
 };
@@ -84,17 +88,14 @@ pub async fn speak(
     }
 }
 
-/// Transcribe audio file using available transcription provider
+/// Build a transcription manager from stored credentials and the LLM config
 ///
-/// Supports OpenAI Whisper and Groq Whisper. Will use the first available provider
-/// based on configured API keys.
-#[tauri::command]
-pub async fn transcribe_audio(
-    path: String,
-    state: State<'_, AppState>,
-) -> Result<crate::core::transcription::TranscriptionResult, String> {
-    use crate::core::transcription::{TranscriptionManagerBuilder, TranscriptionProviderType};
-
+/// Registers the local whisper.cpp provider plus OpenAI/Groq when API keys are
+/// available. Providers that aren't usable are skipped at transcription time.
+pub(crate) fn build_transcription_manager(
+    state: &AppState,
+    default_provider: TranscriptionProviderType,
+) -> TranscriptionManager {
     // Try to get API keys from credentials store
     let openai_key = state.credentials.get_secret("openai_api_key").ok();
     let groq_key = state.credentials.get_secret("groq_api_key").ok();
@@ -121,7 +122,8 @@ pub async fn transcribe_audio(
     });
 
     // Build transcription manager with available providers
-    let mut builder = TranscriptionManagerBuilder::new();
+    let mut builder = TranscriptionManagerBuilder::new()
+        .with_local(LocalWhisperTranscriptionProvider::new());
 
     if let Some(key) = openai_key {
         builder = builder.with_openai(key);
@@ -130,13 +132,22 @@ pub async fn transcribe_audio(
         builder = builder.with_groq(key);
     }
 
-    // Default to OpenAI if available, otherwise Groq
-    builder = builder.default_provider(TranscriptionProviderType::OpenAI);
+    builder.default_provider(default_provider).build()
+}
 
-    let manager = builder.build();
+/// Transcribe audio file using available transcription provider
+///
+/// Supports OpenAI Whisper, Groq Whisper, and local whisper.cpp. Will use
+/// OpenAI if available, otherwise the first available provider.
+#[tauri::command]
+pub async fn transcribe_audio(
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<TranscriptionResult, String> {
+    let manager = build_transcription_manager(&state, TranscriptionProviderType::OpenAI);
 
     if manager.available_providers().is_empty() {
-        return Err("No transcription providers available. Configure OpenAI or Groq API keys.".to_string());
+        return Err("No transcription providers available. Configure OpenAI or Groq API keys, or install whisper.cpp with a local model.".to_string());
     }
 
     manager.transcribe(Path::new(&path))
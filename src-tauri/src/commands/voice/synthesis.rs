@@ -2,12 +2,28 @@
 //!
 //! Commands for synthesizing speech from text.
 
-use tauri::State;
+use serde::Serialize;
+use tauri::{Emitter, State};
 
 use crate::core::voice::{
-    SynthesisRequest, OutputFormat, Voice,
+    SynthesisRequest, OutputFormat, Voice, StreamingPlayer, StreamPlaybackSummary, AudioChunk,
+    ProviderDegradation,
 };
-use crate::commands::AppState;
+use crate::commands::{AppState, MixerState};
+use crate::core::audio::MixerChannel;
+
+/// Event emitted while a streaming TTS playback progresses
+#[derive(Debug, Clone, Serialize)]
+pub struct TtsStreamEvent {
+    pub stream_id: String,
+    /// "started", "first_audio", "finished", or "error"
+    pub stage: String,
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Event channel for streaming TTS progress
+pub const TTS_STREAM_EVENT: &str = "voice:tts-stream";
 
 // ============================================================================
 // Voice Synthesis Commands
@@ -19,6 +35,7 @@ pub async fn play_tts(
     text: String,
     voice_id: String,
     state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
 ) -> Result<(), String> {
     // Synthesize audio first, keeping the lock scope minimal.
     let audio_path = {
@@ -39,24 +56,114 @@ pub async fn play_tts(
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())?;
 
-    // Play audio in a blocking task to avoid blocking async runtime
+    // Play on the voice channel (ducking music/ambience) in a blocking task
+    // to avoid blocking async runtime
+    let mixer = mixer.mixer.clone();
     tokio::task::spawn_blocking(move || {
-        use rodio::{Decoder, OutputStream, Sink};
-        use std::io::Cursor;
-
-        let (_stream, stream_handle) = OutputStream::try_default().map_err(|e| e.to_string())?;
-        let sink = Sink::try_new(&stream_handle).map_err(|e| e.to_string())?;
-        let cursor = Cursor::new(audio_data);
-        let source = Decoder::new(cursor).map_err(|e| e.to_string())?;
-
-        sink.append(source);
-        sink.sleep_until_end();
+        let track = mixer
+            .play_bytes(MixerChannel::Voice, audio_data, 1.0)
+            .map_err(|e| e.to_string())?;
+        track.sleep_until_end();
         Ok::<(), String>(())
     }).await.map_err(|e| e.to_string())??;
 
     Ok(())
 }
 
+/// Play text-to-speech audio while it is still being synthesized
+///
+/// Unlike `play_tts`, playback starts as soon as the first chunk of audio is
+/// available. Providers with native streaming (ElevenLabs, OpenAI) stream raw
+/// PCM; other providers are synthesized sentence-by-sentence. Progress is
+/// reported on the `voice:tts-stream` event channel.
+#[tauri::command]
+pub async fn play_tts_streaming(
+    app_handle: tauri::AppHandle,
+    text: String,
+    voice_id: String,
+    state: State<'_, AppState>,
+    mixer: State<'_, MixerState>,
+) -> Result<StreamPlaybackSummary, String> {
+    let started = std::time::Instant::now();
+    let stream_id = uuid::Uuid::new_v4().to_string();
+    let emit = |stage: &str, elapsed_ms: u64, error: Option<String>| {
+        let _ = app_handle.emit(TTS_STREAM_EVENT, TtsStreamEvent {
+            stream_id: stream_id.clone(),
+            stage: stage.to_string(),
+            elapsed_ms,
+            error,
+        });
+    };
+
+    let player = StreamingPlayer::start(&mixer.mixer);
+    emit("started", 0, None);
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<AudioChunk>(32);
+    let request = SynthesisRequest {
+        text,
+        voice_id,
+        settings: None,
+        output_format: OutputFormat::Wav,
+    };
+
+    let voice_manager = state.voice_manager.clone();
+    let synthesis = tokio::spawn(async move {
+        let manager = voice_manager.read().await;
+        manager.synthesize_streaming(request, &tx).await
+    });
+
+    // Forward chunks to the player as they arrive
+    let mut first_audio_reported = false;
+    while let Some(chunk) = rx.recv().await {
+        player.push(chunk).map_err(|e| e.to_string())?;
+        if !first_audio_reported {
+            first_audio_reported = true;
+            emit("first_audio", player.elapsed_ms(), None);
+        }
+    }
+
+    let native_streaming = match synthesis.await.map_err(|e| e.to_string())? {
+        Ok(native) => native,
+        Err(e) => {
+            emit("error", player.elapsed_ms(), Some(e.to_string()));
+            // Let whatever was already queued finish playing before reporting
+            let _ = tokio::task::spawn_blocking(move || player.finish_blocking()).await;
+            return Err(e.to_string());
+        }
+    };
+
+    let first_audio_ms = player.first_audio_ms();
+    let chunks = tokio::task::spawn_blocking(move || player.finish_blocking())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    let total_ms = started.elapsed().as_millis() as u64;
+
+    emit("finished", total_ms, None);
+
+    Ok(StreamPlaybackSummary {
+        stream_id,
+        chunks,
+        first_audio_ms,
+        total_ms,
+        native_streaming,
+    })
+}
+
+/// Show how text will be spoken after dice and abbreviation expansion
+#[tauri::command]
+pub async fn preview_tts_text(text: String, state: State<'_, AppState>) -> Result<String, String> {
+    let manager = state.voice_manager.read().await;
+    Ok(manager.normalize_text(&text))
+}
+
+/// Providers the fallback chain is currently skipping after a failure
+#[tauri::command]
+pub async fn get_voice_fallback_status(state: State<'_, AppState>) -> Result<Vec<ProviderDegradation>, String> {
+    let manager = state.voice_manager.read().await;
+    Ok(manager.degraded_providers())
+}
+
 /// List OpenAI TTS voices (static list)
 #[tauri::command]
 pub fn list_openai_voices() -> Vec<Voice> {
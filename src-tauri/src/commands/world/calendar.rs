@@ -1,11 +1,48 @@
 //! Calendar Commands
 //!
-//! Commands for managing in-game calendar and date tracking.
+//! Commands for managing in-game calendar and date tracking, custom fantasy
+//! calendars, recurring events, NPC schedules, and advancing time.
 
-use tauri::State;
+use std::collections::BTreeSet;
 
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager, State};
+
+use crate::core::campaign::calendar::{
+    CalendarDay, CalendarDefinition, CalendarManager, NpcScheduleEntry, Recurrence, RecurringEvent, TimeAdvance,
+    TimeWindow,
+};
 use crate::core::campaign::world_state::{InGameDate, CalendarConfig};
-use crate::commands::AppState;
+use crate::commands::world::events::{parse_event_impact, parse_world_event_type};
+use crate::commands::world::presence::{parse_period, record_event_presence, PresenceState};
+use crate::commands::world::weather::{severe_weather_events, WeatherState};
+use crate::commands::{AppState, FactionState, FactionUpdateEvent, FACTION_UPDATE_EVENT};
+
+/// Event emitted after time advances
+pub const CALENDAR_ADVANCED_EVENT: &str = "calendar:advanced";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding campaign calendars, recurring events, and NPC schedules
+#[derive(Default)]
+pub struct CalendarState {
+    pub manager: CalendarManager,
+}
+
+/// Payload for [`CALENDAR_ADVANCED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarAdvancedEvent {
+    pub campaign_id: String,
+    pub advance: TimeAdvance,
+}
+
+/// The campaign's calendar, falling back to its simple calendar config
+pub(crate) fn campaign_calendar(campaign_id: &str, state: &AppState, calendars: &CalendarState) -> CalendarDefinition {
+    let config = state.world_state_manager.get_calendar_config(campaign_id);
+    calendars.manager.calendar_for(campaign_id, config.as_ref())
+}
 
 // ============================================================================
 // In-Game Calendar Commands
@@ -23,14 +60,25 @@ pub fn set_in_game_date(
 }
 
 /// Advance in-game date by days
+///
+/// Uses the campaign's calendar for month lengths and leap years. Holidays,
+/// recurring events, and NPC schedules are not triggered; use
+/// `advance_time` for that.
 #[tauri::command]
 pub fn advance_in_game_date(
     campaign_id: String,
     days: i32,
     state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
 ) -> Result<InGameDate, String> {
-    state.world_state_manager.advance_date(&campaign_id, days)
-        .map_err(|e| e.to_string())
+    let calendar = campaign_calendar(&campaign_id, &state, &calendars);
+    let current = state.world_state_manager.get_current_date(&campaign_id)
+        .map_err(|e| e.to_string())?;
+    let date = calendar.add_days(&current, days as i64)
+        .map_err(|e| e.to_string())?;
+    state.world_state_manager.set_current_date(&campaign_id, date.clone())
+        .map_err(|e| e.to_string())?;
+    Ok(date)
 }
 
 /// Get current in-game date
@@ -62,3 +110,333 @@ pub fn get_calendar_config(
 ) -> Result<Option<CalendarConfig>, String> {
     Ok(state.world_state_manager.get_calendar_config(&campaign_id))
 }
+
+// ============================================================================
+// Fantasy Calendar Commands
+// ============================================================================
+
+/// List the built-in calendar definitions (Standard, Harptos, Golarion)
+#[tauri::command]
+pub fn list_builtin_calendars() -> Result<Vec<CalendarDefinition>, String> {
+    CalendarDefinition::builtin_names()
+        .into_iter()
+        .map(|name| CalendarDefinition::builtin(name).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Set a campaign's calendar, either a custom definition or a built-in by name
+#[tauri::command]
+pub fn set_campaign_calendar(
+    campaign_id: String,
+    calendar: Option<CalendarDefinition>,
+    builtin: Option<String>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<CalendarDefinition, String> {
+    let calendar = match (calendar, builtin) {
+        (Some(calendar), _) => calendar,
+        (None, Some(name)) => CalendarDefinition::builtin(&name).map_err(|e| e.to_string())?,
+        (None, None) => return Err("Either calendar or builtin is required".to_string()),
+    };
+    let calendar = calendars.manager.set_calendar(&campaign_id, calendar)
+        .map_err(|e| e.to_string())?;
+
+    // Keep the current date's calendar name in step
+    let mut world = state.world_state_manager.get_or_create(&campaign_id);
+    world.current_date.calendar = calendar.name.clone();
+    state.world_state_manager.set_current_date(&campaign_id, world.current_date)
+        .map_err(|e| e.to_string())?;
+
+    Ok(calendar)
+}
+
+/// Get a campaign's calendar
+#[tauri::command]
+pub fn get_campaign_calendar(
+    campaign_id: String,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<CalendarDefinition, String> {
+    Ok(campaign_calendar(&campaign_id, &state, &calendars))
+}
+
+/// Describe a date (default: the current date): weekday, holidays, and moon phases
+#[tauri::command]
+pub fn describe_in_game_date(
+    campaign_id: String,
+    date: Option<InGameDate>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<CalendarDay, String> {
+    let date = match date {
+        Some(date) => date,
+        None => state.world_state_manager.get_current_date(&campaign_id)
+            .map_err(|e| e.to_string())?,
+    };
+    campaign_calendar(&campaign_id, &state, &calendars)
+        .describe(&date)
+        .map_err(|e| e.to_string())
+}
+
+/// Add days to a date using the campaign's calendar, without changing the current date
+#[tauri::command]
+pub fn add_days_to_date(
+    campaign_id: String,
+    date: InGameDate,
+    days: i64,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<InGameDate, String> {
+    campaign_calendar(&campaign_id, &state, &calendars)
+        .add_days(&date, days)
+        .map_err(|e| e.to_string())
+}
+
+/// Count the days between two dates (negative when `to` is earlier)
+#[tauri::command]
+pub fn days_between_dates(
+    campaign_id: String,
+    from: InGameDate,
+    to: InGameDate,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<i64, String> {
+    campaign_calendar(&campaign_id, &state, &calendars)
+        .days_between(&from, &to)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Recurring Event Commands
+// ============================================================================
+
+/// Add an event that is recorded on the world timeline each time it recurs
+///
+/// # Arguments
+/// * `recurrence` - e.g. `{"type": "weekly", "weekday": "Starday"}`
+/// * `starts_on` - First date the event can occur (default: current date)
+#[tauri::command]
+pub fn add_recurring_event(
+    campaign_id: String,
+    title: String,
+    description: Option<String>,
+    recurrence: Recurrence,
+    event_type: Option<String>,
+    impact: Option<String>,
+    starts_on: Option<InGameDate>,
+    location_ids: Option<Vec<String>>,
+    npc_ids: Option<Vec<String>>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<RecurringEvent, String> {
+    let starts_on = starts_on.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let mut event = RecurringEvent::new(&campaign_id, &title, recurrence, starts_on)
+        .with_description(description.as_deref().unwrap_or_default());
+    if let Some(event_type) = event_type {
+        event = event.with_type(parse_world_event_type(&event_type));
+    }
+    if let Some(impact) = impact {
+        event.impact = parse_event_impact(&impact);
+    }
+    event.location_ids = location_ids.unwrap_or_default();
+    event.npc_ids = npc_ids.unwrap_or_default();
+    Ok(calendars.manager.add_recurring_event(event))
+}
+
+/// Replace a recurring event (e.g. to pause it or change its dates)
+#[tauri::command]
+pub fn update_recurring_event(
+    event: RecurringEvent,
+    calendars: State<'_, CalendarState>,
+) -> Result<RecurringEvent, String> {
+    calendars.manager.update_recurring_event(event)
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a recurring event
+#[tauri::command]
+pub fn remove_recurring_event(
+    event_id: String,
+    calendars: State<'_, CalendarState>,
+) -> Result<(), String> {
+    calendars.manager.remove_recurring_event(&event_id)
+        .map_err(|e| e.to_string())
+}
+
+/// List a campaign's recurring events
+#[tauri::command]
+pub fn list_recurring_events(
+    campaign_id: String,
+    calendars: State<'_, CalendarState>,
+) -> Result<Vec<RecurringEvent>, String> {
+    Ok(calendars.manager.list_recurring_events(&campaign_id))
+}
+
+// ============================================================================
+// NPC Schedule Commands
+// ============================================================================
+
+/// Add a recurring entry to an NPC's schedule
+///
+/// # Arguments
+/// * `window` - Minutes since midnight the entry covers (default: all day)
+/// * `period` - Named part of the day, used when `window` is not given:
+///   "morning", "afternoon", "evening", "night", or "tonight"
+#[tauri::command]
+pub fn add_npc_schedule_entry(
+    campaign_id: String,
+    npc_id: String,
+    activity: String,
+    recurrence: Recurrence,
+    location_id: Option<String>,
+    starts_on: Option<InGameDate>,
+    window: Option<TimeWindow>,
+    period: Option<String>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<NpcScheduleEntry, String> {
+    let window = match (window, period) {
+        (Some(window), _) => Some(window),
+        (None, Some(period)) => Some(parse_period(&period)?),
+        (None, None) => None,
+    };
+    if let Some(window) = &window {
+        window.validate().map_err(|e| e.to_string())?;
+    }
+    let starts_on = starts_on.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let mut entry = NpcScheduleEntry::new(&campaign_id, &npc_id, &activity, recurrence, starts_on);
+    entry.location_id = location_id;
+    entry.window = window;
+    Ok(calendars.manager.add_schedule_entry(entry))
+}
+
+/// Remove an NPC schedule entry
+#[tauri::command]
+pub fn remove_npc_schedule_entry(
+    entry_id: String,
+    calendars: State<'_, CalendarState>,
+) -> Result<(), String> {
+    calendars.manager.remove_schedule_entry(&entry_id)
+        .map_err(|e| e.to_string())
+}
+
+/// List schedule entries for a campaign, optionally for one NPC
+#[tauri::command]
+pub fn list_npc_schedule(
+    campaign_id: String,
+    npc_id: Option<String>,
+    calendars: State<'_, CalendarState>,
+) -> Result<Vec<NpcScheduleEntry>, String> {
+    Ok(calendars.manager.list_schedule(&campaign_id, npc_id.as_deref()))
+}
+
+// ============================================================================
+// Advancing Time
+// ============================================================================
+
+/// Advance the campaign's current date and time.
+///
+/// Every day passed is checked for holidays, recurring events, and severe
+/// weather at climate-tracked locations, which are recorded as world events
+/// (advancing faction clocks as usual). NPCs with schedule entries, or
+/// placed by those events, are moved to wherever they are at the new time.
+/// The result is emitted as a
+/// `calendar:advanced` event.
+#[tauri::command]
+pub fn advance_time(
+    campaign_id: String,
+    days: Option<u32>,
+    hours: Option<u32>,
+    minutes: Option<u32>,
+    app_handle: tauri::AppHandle,
+) -> Result<TimeAdvance, String> {
+    let days = days.unwrap_or(0) as i64;
+    let minutes = (hours.is_some() || minutes.is_some())
+        .then(|| hours.unwrap_or(0) as i64 * 60 + minutes.unwrap_or(0) as i64);
+    advance_campaign_time(&app_handle, &campaign_id, days, minutes)
+}
+
+/// Advance the campaign clock by whole days, or by days plus minutes when
+/// `minutes` is given, triggering calendar events as [`advance_time`] does.
+pub(crate) fn advance_campaign_time(
+    app_handle: &tauri::AppHandle,
+    campaign_id: &str,
+    days: i64,
+    minutes: Option<i64>,
+) -> Result<TimeAdvance, String> {
+    let state = app_handle.state::<AppState>();
+    let calendars = app_handle.state::<CalendarState>();
+    let factions = app_handle.state::<FactionState>();
+    let weather = app_handle.state::<WeatherState>();
+    let presence = app_handle.state::<PresenceState>();
+    let calendar = campaign_calendar(campaign_id, &state, &calendars);
+    let current = state.world_state_manager.get_or_create(campaign_id).current_date;
+
+    let target = match minutes {
+        Some(minutes) => calendar.add_minutes(&current, days * 24 * 60 + minutes),
+        None => calendar.add_days(&current, days),
+    }
+    .map_err(|e| e.to_string())?;
+    let days_passed = calendar.days_between(&current, &target).map_err(|e| e.to_string())?;
+
+    let mut advance = calendars
+        .manager
+        .advance(campaign_id, &calendar, &current, days_passed.max(0) as u32)
+        .map_err(|e| e.to_string())?;
+    advance.to = target.clone();
+    advance.events.extend(severe_weather_events(campaign_id, &calendar, &current, days_passed, &state, &weather));
+    advance.events.sort_by_key(|e| calendar.day_number(&e.in_game_date).unwrap_or_default());
+    state.world_state_manager.set_current_date(campaign_id, target.clone())
+        .map_err(|e| e.to_string())?;
+
+    let mut outcomes = Vec::new();
+    let mut recorded = Vec::with_capacity(advance.events.len());
+    for event in advance.events.drain(..) {
+        let event = state.world_state_manager.add_event(campaign_id, event)
+            .map_err(|e| e.to_string())?;
+        outcomes.extend(factions.manager.apply_world_event(&event));
+        record_event_presence(&event, &presence);
+        recorded.push(event);
+    }
+    advance.events = recorded;
+    if let Err(e) = presence.manager.prune_ended(campaign_id, &calendar, &target) {
+        log::warn!("Failed to prune presence overrides: {}", e);
+    }
+
+    let schedule = calendars.manager.list_schedule(campaign_id, None);
+    let npc_ids: BTreeSet<&str> = schedule
+        .iter()
+        .map(|e| e.npc_id.as_str())
+        .chain(advance.events.iter().flat_map(|e| e.npc_ids.iter().map(String::as_str)))
+        .collect();
+    for npc_id in npc_ids {
+        let whereabouts = presence
+            .manager
+            .where_is(campaign_id, npc_id, &calendar, &schedule, &target)
+            .map_err(|e| e.to_string())?;
+        let Some(location_id) = whereabouts.and_then(|p| p.location_id) else {
+            continue;
+        };
+        let name = state
+            .location_manager
+            .get_location(&location_id)
+            .map(|l| l.name)
+            .unwrap_or_else(|| location_id.clone());
+        state.world_state_manager
+            .move_npc(campaign_id, npc_id, &location_id, &name, &target)
+            .map_err(|e| e.to_string())?;
+    }
+
+    if !outcomes.is_empty() {
+        let _ = app_handle.emit(FACTION_UPDATE_EVENT, FactionUpdateEvent {
+            campaign_id: campaign_id.to_string(),
+            outcomes,
+        });
+    }
+    let _ = app_handle.emit(CALENDAR_ADVANCED_EVENT, CalendarAdvancedEvent {
+        campaign_id: campaign_id.to_string(),
+        advance: advance.clone(),
+    });
+
+    Ok(advance)
+}
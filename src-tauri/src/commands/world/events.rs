@@ -3,18 +3,38 @@
 //! Commands for managing world events that track significant occurrences
 //! in the campaign world.
 
-use tauri::State;
+use std::collections::HashMap;
+use tauri::{Emitter, State};
 
+use crate::core::campaign::dispositions::{self, DISPOSITION_FIELD};
+use crate::core::campaign::factions::{FACTION_IDS_FIELD, INFLUENCE_FIELD};
+use crate::core::campaign::presence::PRESENCE_UNTIL_FIELD;
 use crate::core::campaign::world_state::{
     WorldEvent, WorldEventType, EventImpact, InGameDate,
 };
-use crate::commands::AppState;
+use crate::core::session::capture::world_event_event;
+use crate::core::session_manager::CaptureCategory;
+use crate::commands::npc::dispositions::emit_tier_changes;
+use crate::commands::world::presence::{record_event_presence, PresenceState};
+use crate::commands::world::setting::{propagate_world_event, WorldSettingState};
+use crate::commands::{AppState, FactionState, FactionUpdateEvent, FACTION_UPDATE_EVENT};
 
 // ============================================================================
 // World Event Commands
 // ============================================================================
 
 /// Add a world event
+///
+/// Factions involved in the event (listed in `faction_ids`, named in the
+/// text, or tied to its locations or NPCs) have their clocks advanced and
+/// any `faction_influence` changes applied; the results are emitted as a
+/// `faction:updated` event. In a shared setting, events its propagation
+/// rule lets through are also copied to the setting's other campaigns.
+/// An event at a single location places its `npc_ids` there for the rest
+/// of the event's day, or until `presence_until`. NPCs listed in
+/// `npc_disposition` change their disposition toward the event's `pc_ids`
+/// (or the whole party) by the given amount. During a running session the
+/// event is also added to the session timeline.
 #[tauri::command]
 pub fn add_world_event(
     campaign_id: String,
@@ -23,17 +43,63 @@ pub fn add_world_event(
     date: InGameDate,
     event_type: String,
     impact: String,
+    faction_ids: Option<Vec<String>>,
+    faction_influence: Option<HashMap<String, i32>>,
+    npc_ids: Option<Vec<String>>,
+    location_ids: Option<Vec<String>>,
+    presence_until: Option<InGameDate>,
+    pc_ids: Option<Vec<String>>,
+    npc_disposition: Option<HashMap<String, i32>>,
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
+    factions: State<'_, FactionState>,
+    settings: State<'_, WorldSettingState>,
+    presence: State<'_, PresenceState>,
 ) -> Result<WorldEvent, String> {
     let etype = parse_world_event_type(&event_type);
     let eimpact = parse_event_impact(&impact);
 
-    let event = WorldEvent::new(&campaign_id, &title, &description, date)
+    let mut event = WorldEvent::new(&campaign_id, &title, &description, date)
         .with_type(etype)
-        .with_impact(eimpact);
+        .with_impact(eimpact)
+        .at_locations(location_ids.unwrap_or_default())
+        .involving_npcs(npc_ids.unwrap_or_default());
+    if let Some(ids) = faction_ids {
+        event.metadata.insert(FACTION_IDS_FIELD.to_string(), serde_json::json!(ids));
+    }
+    if let Some(influence) = faction_influence {
+        event.metadata.insert(INFLUENCE_FIELD.to_string(), serde_json::json!(influence));
+    }
+    if let Some(until) = presence_until {
+        event.metadata.insert(PRESENCE_UNTIL_FIELD.to_string(), serde_json::json!(until));
+    }
+    if let Some(disposition) = npc_disposition {
+        event.metadata.insert(DISPOSITION_FIELD.to_string(), serde_json::json!(disposition));
+    }
+    event.pc_ids = pc_ids.unwrap_or_default();
 
-    state.world_state_manager.add_event(&campaign_id, event)
-        .map_err(|e| e.to_string())
+    let event = state.world_state_manager.add_event(&campaign_id, event)
+        .map_err(|e| e.to_string())?;
+
+    record_event_presence(&event, &presence);
+    state.session_manager.capture_campaign_event(&campaign_id, CaptureCategory::WorldEvents, |session_id| {
+        world_event_event(session_id, &event)
+    });
+
+    let changes = dispositions::apply_world_event(&state.world_state_manager, &event)
+        .map_err(|e| e.to_string())?;
+    emit_tier_changes(&app_handle, &campaign_id, &changes);
+
+    let outcomes = factions.manager.apply_world_event(&event);
+    if !outcomes.is_empty() {
+        let _ = app_handle.emit(FACTION_UPDATE_EVENT, FactionUpdateEvent {
+            campaign_id,
+            outcomes,
+        });
+    }
+    propagate_world_event(&event, &app_handle, &state, &factions, &settings);
+
+    Ok(event)
 }
 
 /// List world events (alias for get_world_events)
@@ -86,7 +152,7 @@ pub fn delete_world_event(
 // Helper Functions
 // ============================================================================
 
-fn parse_world_event_type(s: &str) -> WorldEventType {
+pub(crate) fn parse_world_event_type(s: &str) -> WorldEventType {
     match s.to_lowercase().as_str() {
         "combat" => WorldEventType::Combat,
         "political" => WorldEventType::Political,
@@ -102,7 +168,7 @@ fn parse_world_event_type(s: &str) -> WorldEventType {
     }
 }
 
-fn parse_event_impact(s: &str) -> EventImpact {
+pub(crate) fn parse_event_impact(s: &str) -> EventImpact {
     match s.to_lowercase().as_str() {
         "personal" => EventImpact::Personal,
         "local" => EventImpact::Local,
@@ -4,12 +4,27 @@
 //! - World state CRUD operations
 //! - In-game calendar and date management
 //! - World events tracking
+//! - Weather and travel conditions
+//! - Post-session world update proposals
+//! - Travel routes and journeys
+//! - Settings shared by several campaigns
+//! - NPC whereabouts and who is at a location
 
 pub mod state;
 pub mod calendar;
 pub mod events;
+pub mod weather;
+pub mod updates;
+pub mod travel;
+pub mod setting;
+pub mod presence;
 
 // Re-export all commands using glob to include Tauri __cmd__ macros
 pub use state::*;
 pub use calendar::*;
 pub use events::*;
+pub use weather::*;
+pub use updates::*;
+pub use travel::*;
+pub use setting::*;
+pub use presence::*;
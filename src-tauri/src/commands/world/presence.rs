@@ -0,0 +1,147 @@
+//! NPC Presence Commands
+//!
+//! Commands for asking where NPCs are and who is at a location, resolved
+//! from NPC schedules and the presence overrides that travel, world events,
+//! and the GM record on top of them.
+
+use crate::commands::world::calendar::{campaign_calendar, CalendarState};
+use crate::commands::AppState;
+use crate::core::campaign::calendar::{minute_of_day, TimeWindow};
+use crate::core::campaign::presence::{
+    overrides_from_event, NpcPresence, PresenceManager, PresenceOverride, PresenceSource,
+};
+use crate::core::campaign::world_state::{InGameDate, WorldEvent};
+use tauri::State;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding NPC presence overrides
+#[derive(Default)]
+pub struct PresenceState {
+    pub manager: PresenceManager,
+}
+
+/// Record where a world event puts its NPCs
+pub(crate) fn record_event_presence(event: &WorldEvent, presence: &PresenceState) {
+    for o in overrides_from_event(event) {
+        presence.manager.add_override(o);
+    }
+}
+
+/// Parse a named part of the day
+pub(crate) fn parse_period(period: &str) -> Result<TimeWindow, String> {
+    TimeWindow::named(period).ok_or_else(|| {
+        format!(
+            "Unknown time of day '{}'; expected morning, afternoon, evening, night, tonight, or all day",
+            period
+        )
+    })
+}
+
+// ============================================================================
+// Presence Commands
+// ============================================================================
+
+/// Where an NPC is at a moment
+///
+/// # Arguments
+/// * `at` - Date and time to check (default: the campaign's current date;
+///   a date without a time is read as midday)
+#[tauri::command]
+pub fn where_is_npc(
+    campaign_id: String,
+    npc_id: String,
+    at: Option<InGameDate>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    presence: State<'_, PresenceState>,
+) -> Result<Option<NpcPresence>, String> {
+    let at = at.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let calendar = campaign_calendar(&campaign_id, &state, &calendars);
+    let schedule = calendars.manager.list_schedule(&campaign_id, Some(&npc_id));
+    presence
+        .manager
+        .where_is(&campaign_id, &npc_id, &calendar, &schedule, &at)
+        .map_err(|e| e.to_string())
+}
+
+/// NPCs at a location, with when each arrives and leaves
+///
+/// # Arguments
+/// * `date` - Day to check (default: the campaign's current date)
+/// * `period` - "morning", "afternoon", "evening", "night", "tonight", or
+///   "all day". Without it, the moment given by `date` (or now) is checked.
+#[tauri::command]
+pub fn who_is_at_location(
+    campaign_id: String,
+    location_id: String,
+    date: Option<InGameDate>,
+    period: Option<String>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    presence: State<'_, PresenceState>,
+) -> Result<Vec<NpcPresence>, String> {
+    let date = date.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let window = match period {
+        Some(period) => parse_period(&period)?,
+        None => match minute_of_day(&date) {
+            Some(minute) => TimeWindow { start: minute, end: minute + 1 },
+            None => TimeWindow::ALL_DAY,
+        },
+    };
+    let calendar = campaign_calendar(&campaign_id, &state, &calendars);
+    let schedule = calendars.manager.list_schedule(&campaign_id, None);
+    presence
+        .manager
+        .who_is_at(&campaign_id, &location_id, &calendar, &schedule, &date, window)
+        .map_err(|e| e.to_string())
+}
+
+/// Place an NPC somewhere regardless of their schedule
+///
+/// # Arguments
+/// * `location_id` - Where the NPC is (omit when they are away somewhere untracked)
+/// * `starts_on` - When the override starts (default: the campaign's current date)
+/// * `ends_on` - Last day, or end time when it has one (default: until replaced)
+#[tauri::command]
+pub fn set_npc_presence(
+    campaign_id: String,
+    npc_id: String,
+    location_id: Option<String>,
+    activity: Option<String>,
+    starts_on: Option<InGameDate>,
+    ends_on: Option<InGameDate>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    presence: State<'_, PresenceState>,
+) -> Result<PresenceOverride, String> {
+    let starts_on = starts_on.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let mut o = PresenceOverride::new(&campaign_id, &npc_id, location_id.as_deref(), PresenceSource::Manual, starts_on)
+        .with_activity(activity.as_deref().unwrap_or_default());
+    o.ends_on = ends_on;
+    o.validate(&campaign_calendar(&campaign_id, &state, &calendars))
+        .map_err(|e| e.to_string())?;
+    Ok(presence.manager.add_override(o))
+}
+
+/// Remove a presence override, returning the NPC to their schedule
+#[tauri::command]
+pub fn remove_npc_presence(
+    override_id: String,
+    presence: State<'_, PresenceState>,
+) -> Result<(), String> {
+    presence.manager.remove_override(&override_id)
+        .map_err(|e| e.to_string())
+}
+
+/// List presence overrides for a campaign, optionally for one NPC
+#[tauri::command]
+pub fn list_npc_presence(
+    campaign_id: String,
+    npc_id: Option<String>,
+    presence: State<'_, PresenceState>,
+) -> Result<Vec<PresenceOverride>, String> {
+    Ok(presence.manager.list_overrides(&campaign_id, npc_id.as_deref()))
+}
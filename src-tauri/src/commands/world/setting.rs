@@ -0,0 +1,437 @@
+//! Shared Setting Commands
+//!
+//! Commands for worlds shared by several campaigns: creating settings,
+//! moving campaigns in and out of them, sharing locations, factions,
+//! deities, and a calendar, per-campaign overrides, and spreading world
+//! events between a setting's campaigns.
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, State};
+
+use crate::commands::world::calendar::{campaign_calendar, CalendarState};
+use crate::commands::{AppState, FactionState, FactionUpdateEvent, FACTION_UPDATE_EVENT};
+use crate::core::campaign::calendar::CalendarDefinition;
+use crate::core::campaign::factions::Faction;
+use crate::core::campaign::world_setting::{
+    propagated_event, Deity, EntityOverride, PropagationRule, SettingMembership, SharedEntityKind, WorldSetting,
+    WorldSettingManager,
+};
+use crate::core::campaign::world_state::WorldEvent;
+use crate::core::location_gen::Location;
+
+/// Event emitted when a world event spreads to other campaigns in its setting
+pub const SETTING_EVENT_PROPAGATED_EVENT: &str = "setting:event_propagated";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding shared settings, their campaigns, and overrides
+#[derive(Default)]
+pub struct WorldSettingState {
+    pub manager: WorldSettingManager,
+}
+
+/// Payload for [`SETTING_EVENT_PROPAGATED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPropagatedEvent {
+    pub setting_id: String,
+    pub source_event_id: String,
+    /// The copies added to other campaigns
+    pub events: Vec<WorldEvent>,
+}
+
+/// Everything a campaign sees of its world, overrides applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignWorld {
+    pub setting: Option<WorldSetting>,
+    pub membership: Option<SettingMembership>,
+    pub locations: Vec<Location>,
+    pub factions: Vec<Faction>,
+    pub deities: Vec<Deity>,
+    pub calendar: CalendarDefinition,
+}
+
+fn parse_entity_kind(kind: &str) -> Result<SharedEntityKind, String> {
+    SharedEntityKind::parse(kind).ok_or_else(|| format!("Unknown shared entity kind: {}", kind))
+}
+
+fn require_setting(campaign_id: &str, settings: &WorldSettingState) -> Result<WorldSetting, String> {
+    settings
+        .manager
+        .setting_for(campaign_id)
+        .ok_or_else(|| format!("Campaign {} doesn't belong to a setting", campaign_id))
+}
+
+/// A campaign's own locations plus its setting's, overrides applied
+pub(crate) fn campaign_locations(campaign_id: &str, state: &AppState, settings: &WorldSettingState) -> Vec<Location> {
+    let mut locations = state.location_manager.list_locations_for_campaign(campaign_id);
+    if let Some(setting) = settings.manager.setting_for(campaign_id) {
+        let shared = state.location_manager.list_locations_for_campaign(&setting.id);
+        locations.extend(
+            settings
+                .manager
+                .apply_overrides(campaign_id, SharedEntityKind::Location, shared, |l| &l.id),
+        );
+    }
+    locations
+}
+
+/// A campaign's own factions plus its setting's, by name, overrides applied
+pub(crate) fn campaign_factions(campaign_id: &str, factions: &FactionState, settings: &WorldSettingState) -> Vec<Faction> {
+    let mut list = factions.manager.list_factions(campaign_id);
+    if let Some(setting) = settings.manager.setting_for(campaign_id) {
+        let shared = factions.manager.list_factions(&setting.id);
+        list.extend(
+            settings
+                .manager
+                .apply_overrides(campaign_id, SharedEntityKind::Faction, shared, |f| &f.id),
+        );
+        list.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    list
+}
+
+/// Spread a world event just recorded in its campaign through the
+/// campaign's setting: the setting's factions react to it, and campaigns
+/// that take in events get a copy dated to their own current date.
+pub(crate) fn propagate_world_event(
+    event: &WorldEvent,
+    app_handle: &tauri::AppHandle,
+    state: &AppState,
+    factions: &FactionState,
+    settings: &WorldSettingState,
+) -> Vec<WorldEvent> {
+    let Some(setting) = settings.manager.spreading_setting(event) else {
+        return Vec::new();
+    };
+
+    let mut shared = event.clone();
+    shared.campaign_id = setting.id.clone();
+    let outcomes = factions.manager.apply_world_event(&shared);
+    if !outcomes.is_empty() {
+        let _ = app_handle.emit(FACTION_UPDATE_EVENT, FactionUpdateEvent {
+            campaign_id: setting.id.clone(),
+            outcomes,
+        });
+    }
+
+    let mut copies = Vec::new();
+    for campaign_id in settings.manager.propagation_targets(event) {
+        let date = state.world_state_manager.get_or_create(&campaign_id).current_date;
+        let Ok(copy) = state
+            .world_state_manager
+            .add_event(&campaign_id, propagated_event(event, &campaign_id, Some(date)))
+        else {
+            continue;
+        };
+        let outcomes = factions.manager.apply_world_event(&copy);
+        if !outcomes.is_empty() {
+            let _ = app_handle.emit(FACTION_UPDATE_EVENT, FactionUpdateEvent {
+                campaign_id: campaign_id.clone(),
+                outcomes,
+            });
+        }
+        copies.push(copy);
+    }
+
+    if !copies.is_empty() {
+        let _ = app_handle.emit(SETTING_EVENT_PROPAGATED_EVENT, EventPropagatedEvent {
+            setting_id: setting.id,
+            source_event_id: event.id.clone(),
+            events: copies.clone(),
+        });
+    }
+    copies
+}
+
+// ============================================================================
+// Setting CRUD Commands
+// ============================================================================
+
+/// Create a shared setting.
+///
+/// # Arguments
+/// * `calendar` - Built-in calendar the setting's campaigns use, e.g. "harptos"
+#[tauri::command]
+pub fn create_world_setting(
+    name: String,
+    description: Option<String>,
+    calendar: Option<String>,
+    settings: State<'_, WorldSettingState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<WorldSetting, String> {
+    let setting = WorldSetting::new(&name).with_description(description.as_deref().unwrap_or_default());
+    let calendar = calendar
+        .map(|name| CalendarDefinition::builtin(&name).map_err(|e| e.to_string()))
+        .transpose()?;
+    let setting = settings.manager.create_setting(setting).map_err(|e| e.to_string())?;
+    if let Some(calendar) = calendar {
+        calendars.manager.set_calendar(&setting.id, calendar).map_err(|e| e.to_string())?;
+    }
+    Ok(setting)
+}
+
+/// Get a setting by ID.
+#[tauri::command]
+pub fn get_world_setting(setting_id: String, settings: State<'_, WorldSettingState>) -> Result<Option<WorldSetting>, String> {
+    Ok(settings.manager.get_setting(&setting_id))
+}
+
+/// Replace a setting's name, description, deities, and propagation rule.
+#[tauri::command]
+pub fn update_world_setting(setting: WorldSetting, settings: State<'_, WorldSettingState>) -> Result<WorldSetting, String> {
+    settings.manager.update_setting(setting).map_err(|e| e.to_string())
+}
+
+/// Delete a setting along with the locations, factions, and calendar it
+/// owns. Its campaigns keep their own content and leave the setting.
+#[tauri::command]
+pub fn delete_world_setting(
+    setting_id: String,
+    state: State<'_, AppState>,
+    settings: State<'_, WorldSettingState>,
+    factions: State<'_, FactionState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<Vec<String>, String> {
+    let released = settings.manager.delete_setting(&setting_id).map_err(|e| e.to_string())?;
+    for campaign_id in &released {
+        calendars.manager.unshare_calendar(campaign_id);
+    }
+    for location in state.location_manager.list_locations_for_campaign(&setting_id) {
+        let _ = state.location_manager.delete_location(&location.id);
+    }
+    factions.manager.delete_campaign_factions(&setting_id);
+    calendars.manager.delete_campaign_calendar(&setting_id);
+    Ok(released)
+}
+
+/// List all settings by name.
+#[tauri::command]
+pub fn list_world_settings(settings: State<'_, WorldSettingState>) -> Result<Vec<WorldSetting>, String> {
+    Ok(settings.manager.list_settings())
+}
+
+/// Set how world events spread between a setting's campaigns.
+#[tauri::command]
+pub fn set_setting_propagation(
+    setting_id: String,
+    rule: PropagationRule,
+    settings: State<'_, WorldSettingState>,
+) -> Result<WorldSetting, String> {
+    let mut setting = settings
+        .manager
+        .get_setting(&setting_id)
+        .ok_or_else(|| format!("Setting not found: {}", setting_id))?;
+    setting.propagation = rule;
+    settings.manager.update_setting(setting).map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Membership Commands
+// ============================================================================
+
+/// Make a campaign part of a setting. The campaign uses the setting's
+/// calendar unless it has set its own.
+#[tauri::command]
+pub fn join_world_setting(
+    campaign_id: String,
+    setting_id: String,
+    state: State<'_, AppState>,
+    settings: State<'_, WorldSettingState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<SettingMembership, String> {
+    if state.campaign_manager.get_campaign(&campaign_id).is_none() {
+        return Err("Campaign not found".to_string());
+    }
+    let membership = settings.manager.join(&campaign_id, &setting_id).map_err(|e| e.to_string())?;
+    calendars.manager.share_calendar(&campaign_id, &setting_id);
+    Ok(membership)
+}
+
+/// Take a campaign out of its setting, dropping its overrides.
+#[tauri::command]
+pub fn leave_world_setting(
+    campaign_id: String,
+    settings: State<'_, WorldSettingState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<SettingMembership, String> {
+    let membership = settings.manager.leave(&campaign_id).map_err(|e| e.to_string())?;
+    calendars.manager.unshare_calendar(&campaign_id);
+    Ok(membership)
+}
+
+/// Choose whether a campaign spreads its world events to the rest of its
+/// setting and takes in theirs.
+#[tauri::command]
+pub fn set_setting_event_sharing(
+    campaign_id: String,
+    share_events: bool,
+    receive_events: bool,
+    settings: State<'_, WorldSettingState>,
+) -> Result<SettingMembership, String> {
+    settings
+        .manager
+        .set_event_sharing(&campaign_id, share_events, receive_events)
+        .map_err(|e| e.to_string())
+}
+
+/// List a setting's campaigns.
+#[tauri::command]
+pub fn list_setting_campaigns(
+    setting_id: String,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Vec<SettingMembership>, String> {
+    Ok(settings.manager.members(&setting_id))
+}
+
+// ============================================================================
+// Shared Content Commands
+// ============================================================================
+
+/// Move one of a campaign's locations or factions into its setting so the
+/// setting's other campaigns share it.
+///
+/// # Arguments
+/// * `kind` - "location" or "faction"
+#[tauri::command]
+pub fn share_with_setting(
+    campaign_id: String,
+    kind: String,
+    entity_id: String,
+    state: State<'_, AppState>,
+    settings: State<'_, WorldSettingState>,
+    factions: State<'_, FactionState>,
+) -> Result<(), String> {
+    let setting = require_setting(&campaign_id, &settings)?;
+    match parse_entity_kind(&kind)? {
+        SharedEntityKind::Location => {
+            let mut location = state
+                .location_manager
+                .get_location(&entity_id)
+                .filter(|l| l.campaign_id.as_deref() == Some(campaign_id.as_str()))
+                .ok_or_else(|| format!("Location not found in campaign: {}", entity_id))?;
+            location.campaign_id = Some(setting.id);
+            state.location_manager.update_location(location).map_err(|e| e.to_string())
+        }
+        SharedEntityKind::Faction => {
+            let mut faction = factions
+                .manager
+                .get_faction(&entity_id)
+                .filter(|f| f.campaign_id == campaign_id)
+                .ok_or_else(|| format!("Faction not found in campaign: {}", entity_id))?;
+            faction.campaign_id = setting.id;
+            factions.manager.update_faction(faction).map(|_| ()).map_err(|e| e.to_string())
+        }
+        SharedEntityKind::Deity => Err("Deities already belong to the setting".to_string()),
+    }
+}
+
+/// Add a deity to a setting, or replace the one with the same ID.
+#[tauri::command]
+pub fn save_setting_deity(
+    setting_id: String,
+    deity: Deity,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Deity, String> {
+    settings.manager.save_deity(&setting_id, deity).map_err(|e| e.to_string())
+}
+
+/// Remove a deity from a setting.
+#[tauri::command]
+pub fn remove_setting_deity(
+    setting_id: String,
+    deity_id: String,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Deity, String> {
+    settings.manager.remove_deity(&setting_id, &deity_id).map_err(|e| e.to_string())
+}
+
+/// Set the calendar a setting's campaigns use, either a custom definition
+/// or a built-in by name.
+#[tauri::command]
+pub fn set_setting_calendar(
+    setting_id: String,
+    calendar: Option<CalendarDefinition>,
+    builtin: Option<String>,
+    settings: State<'_, WorldSettingState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<CalendarDefinition, String> {
+    if settings.manager.get_setting(&setting_id).is_none() {
+        return Err(format!("Setting not found: {}", setting_id));
+    }
+    let calendar = match (calendar, builtin) {
+        (Some(calendar), _) => calendar,
+        (None, Some(name)) => CalendarDefinition::builtin(&name).map_err(|e| e.to_string())?,
+        (None, None) => return Err("Either calendar or builtin is required".to_string()),
+    };
+    calendars.manager.set_calendar(&setting_id, calendar).map_err(|e| e.to_string())
+}
+
+/// Get a campaign's whole world: its own and shared locations and
+/// factions, the setting's deities, and the calendar it uses.
+#[tauri::command]
+pub fn get_campaign_world(
+    campaign_id: String,
+    state: State<'_, AppState>,
+    settings: State<'_, WorldSettingState>,
+    factions: State<'_, FactionState>,
+    calendars: State<'_, CalendarState>,
+) -> Result<CampaignWorld, String> {
+    Ok(CampaignWorld {
+        setting: settings.manager.setting_for(&campaign_id),
+        membership: settings.manager.membership(&campaign_id),
+        locations: campaign_locations(&campaign_id, &state, &settings),
+        factions: campaign_factions(&campaign_id, &factions, &settings),
+        deities: settings.manager.deities_for(&campaign_id),
+        calendar: campaign_calendar(&campaign_id, &state, &calendars),
+    })
+}
+
+// ============================================================================
+// Override Commands
+// ============================================================================
+
+/// Hide or change a shared location, faction, or deity for one campaign.
+///
+/// # Arguments
+/// * `kind` - "location", "faction", or "deity"
+/// * `hidden` - Leave the entity out of this campaign (default: false)
+/// * `patch` - Fields to replace, e.g. `{ "description": "Burned down" }`;
+///   a `null` value removes the field
+#[tauri::command]
+pub fn set_setting_override(
+    campaign_id: String,
+    kind: String,
+    entity_id: String,
+    hidden: Option<bool>,
+    patch: Option<serde_json::Value>,
+    settings: State<'_, WorldSettingState>,
+) -> Result<EntityOverride, String> {
+    let mut entity_override = EntityOverride::new(&campaign_id, parse_entity_kind(&kind)?, &entity_id);
+    entity_override.hidden = hidden.unwrap_or(false);
+    entity_override.patch = patch.unwrap_or_default();
+    settings.manager.set_override(entity_override).map_err(|e| e.to_string())
+}
+
+/// Go back to the shared version of an entity.
+#[tauri::command]
+pub fn clear_setting_override(
+    campaign_id: String,
+    kind: String,
+    entity_id: String,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Option<EntityOverride>, String> {
+    Ok(settings
+        .manager
+        .clear_override(&campaign_id, parse_entity_kind(&kind)?, &entity_id))
+}
+
+/// List a campaign's overrides.
+#[tauri::command]
+pub fn list_setting_overrides(
+    campaign_id: String,
+    settings: State<'_, WorldSettingState>,
+) -> Result<Vec<EntityOverride>, String> {
+    Ok(settings.manager.list_overrides(&campaign_id))
+}
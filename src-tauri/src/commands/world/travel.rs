@@ -0,0 +1,250 @@
+//! Travel Commands
+//!
+//! Commands for setting distances along location connections, planning
+//! routes, and making journeys that advance the calendar and roll random
+//! encounters on the way.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::world::calendar::advance_campaign_time;
+use crate::commands::world::presence::PresenceState;
+use crate::commands::AppState;
+use crate::core::campaign::calendar::TimeAdvance;
+use crate::core::campaign::presence::travel_overrides;
+use crate::core::campaign::travel::{
+    EncounterCheck, RouteSegment, Terrain, TravelManager, TravelPace, TravelPlan,
+};
+use crate::core::campaign::{RandomTableEngine, RollRequest, TableRollResult};
+use crate::core::location_gen::Location;
+
+/// World-state custom field holding the party's current location ID
+pub const PARTY_LOCATION_FIELD: &str = "party_location";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding route segments between locations
+#[derive(Default)]
+pub struct TravelState {
+    pub manager: TravelManager,
+}
+
+/// An encounter check made on a journey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteEncounter {
+    pub check: EncounterCheck,
+    /// The table result, for checks that triggered
+    pub result: Option<TableRollResult>,
+}
+
+/// What happened on a journey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JourneyResult {
+    pub plan: TravelPlan,
+    pub advance: TimeAdvance,
+    pub encounters: Vec<RouteEncounter>,
+}
+
+fn parse_pace(pace: Option<&str>) -> Result<TravelPace, String> {
+    pace.map(|p| TravelPace::parse(p).ok_or_else(|| format!("Unknown travel pace: {}", p)))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+fn campaign_location(campaign_id: &str, location_id: &str, state: &AppState) -> Result<Location, String> {
+    state
+        .location_manager
+        .get_location(location_id)
+        .filter(|l| l.campaign_id.as_deref() == Some(campaign_id))
+        .ok_or_else(|| format!("Location not found in campaign: {}", location_id))
+}
+
+// ============================================================================
+// Route Segment Commands
+// ============================================================================
+
+/// Set the distance and terrain between two connected locations, replacing
+/// any existing segment between them.
+///
+/// # Arguments
+/// * `terrain` - e.g. "road", "forest", "mountains" (default: guessed from
+///   the connection type)
+/// * `bidirectional` - Usable in both directions (default: true)
+/// * `encounter_table_id` - Random table rolled for encounters on this segment
+/// * `encounter_chance` - Chance of an encounter per travel day, 0-1
+#[tauri::command]
+pub fn set_route_segment(
+    campaign_id: String,
+    from_location_id: String,
+    to_location_id: String,
+    distance_miles: f64,
+    terrain: Option<String>,
+    bidirectional: Option<bool>,
+    encounter_table_id: Option<String>,
+    encounter_chance: Option<f32>,
+    state: State<'_, AppState>,
+    travel: State<'_, TravelState>,
+) -> Result<RouteSegment, String> {
+    let from = campaign_location(&campaign_id, &from_location_id, &state)?;
+    let to = campaign_location(&campaign_id, &to_location_id, &state)?;
+    let connection = from
+        .connected_locations
+        .iter()
+        .find(|c| c.target_id.as_deref() == Some(to.id.as_str()))
+        .or_else(|| {
+            to.connected_locations
+                .iter()
+                .find(|c| c.target_id.as_deref() == Some(from.id.as_str()))
+        })
+        .ok_or_else(|| format!("{} and {} are not connected", from.name, to.name))?;
+
+    let terrain = match terrain {
+        Some(t) => Terrain::parse(&t).ok_or_else(|| format!("Unknown terrain: {}", t))?,
+        None => Terrain::for_connection(&connection.connection_type),
+    };
+    let mut segment = RouteSegment::new(&campaign_id, &from.id, &to.id, distance_miles, terrain);
+    segment.bidirectional = bidirectional.unwrap_or(true);
+    if let Some(table_id) = encounter_table_id {
+        segment = segment.with_encounters(&table_id, encounter_chance.unwrap_or(0.0));
+    }
+    travel.manager.set_segment(segment).map_err(|e| e.to_string())
+}
+
+/// List a campaign's route segments, optionally only those touching a location.
+#[tauri::command]
+pub fn list_route_segments(
+    campaign_id: String,
+    location_id: Option<String>,
+    travel: State<'_, TravelState>,
+) -> Result<Vec<RouteSegment>, String> {
+    Ok(travel.manager.list_segments(&campaign_id, location_id.as_deref()))
+}
+
+/// Delete a route segment.
+#[tauri::command]
+pub fn delete_route_segment(segment_id: String, travel: State<'_, TravelState>) -> Result<(), String> {
+    travel.manager.delete_segment(&segment_id).map(|_| ()).map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Travel Commands
+// ============================================================================
+
+/// Find the quickest route between two locations and how long it takes.
+///
+/// # Arguments
+/// * `pace` - "slow", "normal" (default), or "fast"
+/// * `hours_per_day` - Hours travelled each day (default: 8)
+#[tauri::command]
+pub fn plan_travel(
+    campaign_id: String,
+    from_location_id: String,
+    to_location_id: String,
+    pace: Option<String>,
+    hours_per_day: Option<u32>,
+    travel: State<'_, TravelState>,
+) -> Result<TravelPlan, String> {
+    travel
+        .manager
+        .plan_route(
+            &campaign_id,
+            &from_location_id,
+            &to_location_id,
+            parse_pace(pace.as_deref())?,
+            hours_per_day.unwrap_or(8),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Travel between two locations: advance the calendar by the journey's
+/// length, roll encounter checks along the route, and record the party's
+/// new location. NPCs in `companion_npc_ids` are on the road for the
+/// journey and at the destination afterwards, in place of their schedules.
+///
+/// # Arguments
+/// * `pace` - "slow", "normal" (default), or "fast"
+/// * `hours_per_day` - Hours travelled each day (default: 8)
+/// * `roll_encounters` - Roll encounter checks on segments with a table
+///   (default: true)
+/// * `session_id` - Session to log encounter rolls against
+#[tauri::command]
+pub async fn travel_to_location(
+    campaign_id: String,
+    from_location_id: String,
+    to_location_id: String,
+    pace: Option<String>,
+    hours_per_day: Option<u32>,
+    roll_encounters: Option<bool>,
+    session_id: Option<String>,
+    companion_npc_ids: Option<Vec<String>>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    travel: State<'_, TravelState>,
+    presence: State<'_, PresenceState>,
+) -> Result<JourneyResult, String> {
+    let plan = travel
+        .manager
+        .plan_route(
+            &campaign_id,
+            &from_location_id,
+            &to_location_id,
+            parse_pace(pace.as_deref())?,
+            hours_per_day.unwrap_or(8),
+        )
+        .map_err(|e| e.to_string())?;
+    let checks = if roll_encounters.unwrap_or(true) {
+        plan.roll_encounter_checks(&mut rand::thread_rng())
+    } else {
+        Vec::new()
+    };
+
+    let departed = state.world_state_manager.get_or_create(&campaign_id).current_date;
+    let advance = advance_campaign_time(&app_handle, &campaign_id, 0, Some(plan.elapsed_minutes))?;
+    state
+        .world_state_manager
+        .set_custom_field(&campaign_id, PARTY_LOCATION_FIELD, serde_json::json!(to_location_id))
+        .map_err(|e| e.to_string())?;
+
+    let companions = companion_npc_ids.unwrap_or_default();
+    if !companions.is_empty() {
+        let name = state
+            .location_manager
+            .get_location(&to_location_id)
+            .map(|l| l.name)
+            .unwrap_or_else(|| to_location_id.clone());
+        for o in travel_overrides(&campaign_id, &companions, &to_location_id, &name, &departed, &advance.to) {
+            presence.manager.add_override(o);
+        }
+        for npc_id in &companions {
+            state
+                .world_state_manager
+                .move_npc(&campaign_id, npc_id, &to_location_id, &name, &advance.to)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    let engine = RandomTableEngine::new(Arc::new(state.database.pool().clone()));
+    let mut encounters = Vec::with_capacity(checks.len());
+    for check in checks {
+        let result = if check.triggered {
+            let request = RollRequest {
+                table_id: check.table_id.clone(),
+                session_id: session_id.clone(),
+                campaign_id: Some(campaign_id.clone()),
+                context: Some(format!("Travel encounter, day {}", check.day)),
+                forced_roll: None,
+                max_depth: None,
+            };
+            Some(engine.roll_on_table(request).await.map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        encounters.push(RouteEncounter { check, result });
+    }
+
+    Ok(JourneyResult { plan, advance, encounters })
+}
@@ -0,0 +1,244 @@
+//! World Update Commands
+//!
+//! Commands for proposing world-state changes from a finished session and
+//! reviewing them. Approved changes are applied to the world state, and a
+//! campaign version is created so the update can be rolled back.
+
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, State};
+
+use crate::commands::session::recap::gather_chat_excerpts;
+use crate::commands::{AppState, FactionState, FactionUpdateEvent, FACTION_UPDATE_EVENT};
+use crate::core::campaign::versioning::VersionType;
+use crate::core::campaign::world_updates::{
+    build_world_update_prompt, parse_world_update_response, ChangeDecision, ChangesetApplication,
+    ChangesetManager, ChangesetStatus, KnownEntity, WorldStateChangeset, WORLD_UPDATE_SYSTEM_PROMPT,
+};
+use crate::core::llm::router::{ChatMessage, ChatRequest};
+use crate::core::session::recap::{session_transcript, RecapInputs};
+
+/// Event emitted when a changeset has been proposed for review
+pub const WORLD_CHANGESET_EVENT: &str = "world:changeset_proposed";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding world-state changesets awaiting review
+#[derive(Default)]
+pub struct WorldUpdateState {
+    pub manager: ChangesetManager,
+}
+
+/// Payload for [`WORLD_CHANGESET_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldChangesetEvent {
+    pub campaign_id: String,
+    pub session_id: String,
+    pub changeset_id: String,
+    pub change_count: usize,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Run a session's record through the extraction prompt and store the
+/// proposed changes as a pending changeset.
+pub(crate) async fn propose_for_session(
+    session_id: &str,
+    state: &AppState,
+    updates: &WorldUpdateState,
+) -> Result<WorldStateChangeset, String> {
+    let session = state
+        .session_manager
+        .get_session(session_id)
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+    let inputs = RecapInputs::new(session.session_number, session.title.clone())
+        .with_timeline(state.session_manager.get_timeline_events(session_id))
+        .with_combat_log(state.session_manager.get_combat_log(session_id))
+        .with_chat(gather_chat_excerpts(session_id, state).await)
+        .with_notes(state.session_manager.list_notes_for_session(session_id));
+    if inputs.is_empty() {
+        return Err("Nothing has been recorded for this session yet".to_string());
+    }
+
+    let npcs: Vec<KnownEntity> = state
+        .npc_store
+        .list(Some(&session.campaign_id))
+        .into_iter()
+        .map(|n| KnownEntity { id: n.id, name: n.name })
+        .collect();
+    let locations: Vec<KnownEntity> = state
+        .location_manager
+        .list_locations_for_campaign(&session.campaign_id)
+        .into_iter()
+        .map(|l| KnownEntity { id: l.id, name: l.name })
+        .collect();
+
+    let prompt = build_world_update_prompt(&session_transcript(&inputs), &npcs, &locations);
+    let request = ChatRequest::new(vec![ChatMessage::user(prompt)])
+        .with_system(WORLD_UPDATE_SYSTEM_PROMPT.to_string())
+        .with_temperature(0.2)
+        .with_max_tokens(1500);
+    let response = {
+        let router = state.llm_router.read().await;
+        router.chat(request).await.map_err(|e| e.to_string())?
+    };
+
+    let changes = parse_world_update_response(&response.content, &npcs, &locations);
+    Ok(updates.manager.add_changeset(WorldStateChangeset::new(
+        &session.campaign_id,
+        session_id,
+        session.session_number,
+        changes,
+    )))
+}
+
+/// Emit [`WORLD_CHANGESET_EVENT`] for a new changeset
+pub(crate) fn emit_changeset_proposed(app_handle: &tauri::AppHandle, changeset: &WorldStateChangeset) {
+    let _ = app_handle.emit(WORLD_CHANGESET_EVENT, WorldChangesetEvent {
+        campaign_id: changeset.campaign_id.clone(),
+        session_id: changeset.session_id.clone(),
+        changeset_id: changeset.id.clone(),
+        change_count: changeset.changes.len(),
+    });
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Propose world-state changes from a session's timeline, combat log, chat,
+/// and notes. Nothing is applied until the changeset is reviewed.
+///
+/// # Arguments
+/// * `session_id` - The session to extract changes from
+#[tauri::command]
+pub async fn propose_world_updates(
+    session_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    updates: State<'_, WorldUpdateState>,
+) -> Result<WorldStateChangeset, String> {
+    let changeset = propose_for_session(&session_id, &state, &updates).await?;
+    emit_changeset_proposed(&app_handle, &changeset);
+    Ok(changeset)
+}
+
+/// List a campaign's changesets, newest first.
+///
+/// # Arguments
+/// * `status` - Optional filter: "pending", "applied", or "discarded"
+#[tauri::command]
+pub fn list_world_changesets(
+    campaign_id: String,
+    status: Option<String>,
+    updates: State<'_, WorldUpdateState>,
+) -> Result<Vec<WorldStateChangeset>, String> {
+    let status = status
+        .map(|s| ChangesetStatus::parse(&s).ok_or_else(|| format!("Unknown changeset status: {}", s)))
+        .transpose()?;
+    Ok(updates.manager.list_changesets(&campaign_id, status))
+}
+
+/// Get a changeset by ID.
+#[tauri::command]
+pub fn get_world_changeset(
+    changeset_id: String,
+    updates: State<'_, WorldUpdateState>,
+) -> Result<Option<WorldStateChangeset>, String> {
+    Ok(updates.manager.get_changeset(&changeset_id))
+}
+
+/// Approve or reject one proposed change.
+#[tauri::command]
+pub fn decide_world_change(
+    changeset_id: String,
+    change_id: String,
+    approved: bool,
+    updates: State<'_, WorldUpdateState>,
+) -> Result<WorldStateChangeset, String> {
+    let decision = if approved { ChangeDecision::Approved } else { ChangeDecision::Rejected };
+    updates
+        .manager
+        .decide(&changeset_id, &change_id, decision)
+        .map_err(|e| e.to_string())
+}
+
+/// Discard a changeset without applying any of it.
+#[tauri::command]
+pub fn discard_world_changeset(
+    changeset_id: String,
+    updates: State<'_, WorldUpdateState>,
+) -> Result<WorldStateChangeset, String> {
+    updates.manager.discard(&changeset_id).map_err(|e| e.to_string())
+}
+
+/// Apply a changeset's approved changes to the world state.
+///
+/// Each applied change is recorded as a world event (which also feeds
+/// faction clocks), and a campaign version is created afterwards.
+/// Changes left pending are not applied.
+#[tauri::command]
+pub fn apply_world_changeset(
+    changeset_id: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    updates: State<'_, WorldUpdateState>,
+    factions: State<'_, FactionState>,
+) -> Result<ChangesetApplication, String> {
+    let changeset = updates
+        .manager
+        .get_changeset(&changeset_id)
+        .ok_or_else(|| format!("Changeset not found: {}", changeset_id))?;
+    let campaign = state
+        .campaign_manager
+        .get_campaign(&changeset.campaign_id)
+        .ok_or_else(|| "Campaign not found".to_string())?;
+
+    let date = state
+        .world_state_manager
+        .get_or_create(&changeset.campaign_id)
+        .current_date;
+    let mut application = updates
+        .manager
+        .apply(&changeset_id, &state.world_state_manager, &date)
+        .map_err(|e| e.to_string())?;
+
+    let outcomes: Vec<_> = application
+        .events
+        .iter()
+        .flat_map(|event| factions.manager.apply_world_event(event))
+        .collect();
+    if !outcomes.is_empty() {
+        let _ = app_handle.emit(FACTION_UPDATE_EVENT, FactionUpdateEvent {
+            campaign_id: changeset.campaign_id.clone(),
+            outcomes,
+        });
+    }
+
+    let data_snapshot = serde_json::to_string(&campaign)
+        .map_err(|e| format!("Failed to serialize campaign: {}", e))?;
+    let version = state
+        .version_manager
+        .create_version(
+            &changeset.campaign_id,
+            &format!(
+                "World updates from session {} ({} applied)",
+                changeset.session_number,
+                application.events.len()
+            ),
+            VersionType::Auto,
+            &data_snapshot,
+        )
+        .map_err(|e| e.to_string())?;
+    updates
+        .manager
+        .set_version(&changeset_id, &version.id)
+        .map_err(|e| e.to_string())?;
+    application.changeset.version_id = Some(version.id);
+
+    Ok(application)
+}
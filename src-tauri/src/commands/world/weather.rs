@@ -0,0 +1,170 @@
+//! Weather Commands
+//!
+//! Commands for setting location climates and reading the procedurally
+//! generated weather, forecasts, and travel conditions.
+
+use tauri::State;
+
+use crate::commands::world::calendar::{campaign_calendar, CalendarState};
+use crate::commands::AppState;
+use crate::core::campaign::calendar::CalendarDefinition;
+use crate::core::campaign::weather::{ClimateProfile, ClimateZone, WeatherManager, WeatherReport};
+use crate::core::campaign::world_state::{InGameDate, WorldEvent};
+
+/// Forecasts longer than this are cut short
+const MAX_FORECAST_DAYS: u32 = 30;
+
+/// When time jumps ahead further than this, only the latest days are checked
+/// for severe weather
+const MAX_SEVERE_WEATHER_DAYS: i64 = 31;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Managed state holding location climates and generated weather
+#[derive(Default)]
+pub struct WeatherState {
+    pub manager: WeatherManager,
+}
+
+/// A location's climate: the one set for it, else a guess from its type
+fn resolve_climate(campaign_id: &str, location_id: &str, state: &AppState, weather: &WeatherState) -> ClimateProfile {
+    weather.manager.get_climate(campaign_id, location_id).unwrap_or_else(|| {
+        state
+            .location_manager
+            .get_location(location_id)
+            .map(|l| ClimateProfile::for_zone(ClimateZone::for_location_type(&l.location_type)))
+            .unwrap_or_default()
+    })
+}
+
+/// Severe weather at climate-tracked locations on the days after `from`,
+/// as world events ready to be recorded
+pub(crate) fn severe_weather_events(
+    campaign_id: &str,
+    calendar: &CalendarDefinition,
+    from: &InGameDate,
+    days_passed: i64,
+    state: &AppState,
+    weather: &WeatherState,
+) -> Vec<WorldEvent> {
+    let mut events = Vec::new();
+    for location_id in weather.manager.climate_locations(campaign_id) {
+        let climate = resolve_climate(campaign_id, &location_id, state, weather);
+        let name = state
+            .location_manager
+            .get_location(&location_id)
+            .map(|l| l.name)
+            .unwrap_or_else(|| location_id.clone());
+
+        for offset in (days_passed - MAX_SEVERE_WEATHER_DAYS).max(0) + 1..=days_passed {
+            let report = calendar
+                .add_days(from, offset)
+                .map_err(|e| e.to_string())
+                .and_then(|date| {
+                    weather
+                        .manager
+                        .weather_for(campaign_id, &location_id, calendar, &date, &climate)
+                        .map_err(|e| e.to_string())
+                });
+            match report {
+                Ok(report) => events.extend(report.to_world_event(campaign_id, &name)),
+                Err(e) => {
+                    log::warn!("Skipping weather for {} in {}: {}", location_id, campaign_id, e);
+                    break;
+                }
+            }
+        }
+    }
+    events
+}
+
+// ============================================================================
+// Climate Commands
+// ============================================================================
+
+/// Set a location's climate.
+///
+/// Locations with a climate set have their weather generated as time
+/// advances, with severe weather recorded as world events.
+///
+/// # Arguments
+/// * `zone` - arctic, temperate, arid, tropical, coastal, mountain, or swamp
+/// * `profile` - Custom climate values overriding the zone's defaults
+#[tauri::command]
+pub fn set_location_climate(
+    campaign_id: String,
+    location_id: String,
+    zone: String,
+    profile: Option<ClimateProfile>,
+    weather: State<'_, WeatherState>,
+) -> Result<ClimateProfile, String> {
+    let zone = ClimateZone::parse(&zone).ok_or_else(|| format!("Unknown climate zone: {}", zone))?;
+    let climate = profile.unwrap_or_else(|| ClimateProfile::for_zone(zone));
+    weather.manager.set_climate(&campaign_id, &location_id, climate.clone());
+    Ok(climate)
+}
+
+/// Get a location's climate (guessed from the location type if none is set)
+#[tauri::command]
+pub fn get_location_climate(
+    campaign_id: String,
+    location_id: String,
+    state: State<'_, AppState>,
+    weather: State<'_, WeatherState>,
+) -> Result<ClimateProfile, String> {
+    Ok(resolve_climate(&campaign_id, &location_id, &state, &weather))
+}
+
+// ============================================================================
+// Weather Commands
+// ============================================================================
+
+/// Get the weather at a location (default: on the current in-game date)
+#[tauri::command]
+pub fn get_weather(
+    campaign_id: String,
+    location_id: String,
+    date: Option<InGameDate>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    weather: State<'_, WeatherState>,
+) -> Result<WeatherReport, String> {
+    let calendar = campaign_calendar(&campaign_id, &state, &calendars);
+    let date = date.unwrap_or_else(|| state.world_state_manager.get_or_create(&campaign_id).current_date);
+    let climate = resolve_climate(&campaign_id, &location_id, &state, &weather);
+    weather
+        .manager
+        .weather_for(&campaign_id, &location_id, &calendar, &date, &climate)
+        .map_err(|e| e.to_string())
+}
+
+/// Get a location's forecast starting today, for the GM dashboard
+///
+/// # Arguments
+/// * `days` - Number of days (default: 7, max: 30)
+#[tauri::command]
+pub fn get_forecast(
+    campaign_id: String,
+    location_id: String,
+    days: Option<u32>,
+    state: State<'_, AppState>,
+    calendars: State<'_, CalendarState>,
+    weather: State<'_, WeatherState>,
+) -> Result<Vec<WeatherReport>, String> {
+    let calendar = campaign_calendar(&campaign_id, &state, &calendars);
+    let today = state.world_state_manager.get_or_create(&campaign_id).current_date;
+    let climate = resolve_climate(&campaign_id, &location_id, &state, &weather);
+    weather
+        .manager
+        .forecast(
+            &campaign_id,
+            &location_id,
+            &calendar,
+            &today,
+            days.unwrap_or(7).min(MAX_FORECAST_DAYS),
+            &climate,
+        )
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,585 @@
+//! Archetype Packs
+//!
+//! Shareable bundles of setting content: archetypes, vocabulary banks, name
+//! banks, personality templates, and an optional setting pack, described by
+//! a manifest with a version and the packs it depends on.
+//!
+//! # File Format
+//!
+//! A pack is either a single JSON file:
+//!
+//! ```json
+//! {
+//!   "manifest": {
+//!     "format_version": 1,
+//!     "id": "sunken_isles",
+//!     "name": "The Sunken Isles",
+//!     "version": "1.2.0",
+//!     "game_system": "dnd5e",
+//!     "author": "...",
+//!     "min_app_version": "0.9.0",
+//!     "dependencies": [{ "id": "core_seafarers", "version": "^1.0.0" }]
+//!   },
+//!   "archetypes": [ ... ],
+//!   "vocabulary_banks": [ ... ],
+//!   "name_banks": [ ... ],
+//!   "trained_name_banks": [ ... ],
+//!   "personality_templates": [ ... ],
+//!   "setting": { ... }
+//! }
+//! ```
+//!
+//! or a zip archive with one file per item, each JSON or YAML:
+//!
+//! ```text
+//! pack.json                    manifest
+//! setting.json                 setting pack (optional)
+//! archetypes/*.json            one archetype per file
+//! vocabulary/*.json            one vocabulary bank per file
+//! names/*.json                 one set of cultural naming rules per file
+//! names/trained/*.json         one trained name bank per file
+//! personalities/*.json         one personality template per file
+//! ```
+//!
+//! Dependency versions accept `*`, an exact version (`1.2.0`), `>=1.2.0`,
+//! `^1.2.0` (same major version), or `~1.2.0` (same major and minor).
+//! See `docs/ARCHETYPE_PACKS.md` for the full field reference.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::error::{ArchetypeError, Result};
+use super::setting_pack::{compare_semver, is_valid_semver, parse_semver, SettingPack, VocabularyBankDefinition};
+use super::types::Archetype;
+use crate::core::npc_gen::{CulturalNamingRules, TrainedNameBank};
+use crate::core::personality::SettingTemplate;
+
+/// Newest pack format this version of the app reads
+pub const PACK_FORMAT_VERSION: u32 = 1;
+
+/// Manifest file name inside a zip pack
+pub const PACK_MANIFEST_FILE: &str = "pack.json";
+
+const SETTING_STEM: &str = "setting";
+const ARCHETYPES_DIR: &str = "archetypes/";
+const VOCABULARY_DIR: &str = "vocabulary/";
+const NAMES_DIR: &str = "names/";
+const TRAINED_NAMES_DIR: &str = "names/trained/";
+const PERSONALITIES_DIR: &str = "personalities/";
+const ZIP_MAGIC: &[u8; 4] = b"PK\x03\x04";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Pack metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackManifest {
+    #[serde(default = "default_format_version")]
+    pub format_version: u32,
+    pub id: String,
+    pub name: String,
+    /// Semantic version (MAJOR.MINOR.PATCH)
+    pub version: String,
+    pub game_system: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Oldest app version the pack works with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_app_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<PackDependency>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+fn default_format_version() -> u32 {
+    PACK_FORMAT_VERSION
+}
+
+/// Another pack that must be installed first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackDependency {
+    pub id: String,
+    /// Version requirement, e.g. `^1.0.0` (default: any version)
+    #[serde(default = "any_version")]
+    pub version: String,
+}
+
+fn any_version() -> String {
+    "*".to_string()
+}
+
+/// A shareable bundle of setting content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchetypePack {
+    pub manifest: PackManifest,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archetypes: Vec<Archetype>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vocabulary_banks: Vec<VocabularyBankDefinition>,
+    /// Rule-based name banks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub name_banks: Vec<CulturalNamingRules>,
+    /// Name banks trained from name lists
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trained_name_banks: Vec<TrainedNameBank>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub personality_templates: Vec<SettingTemplate>,
+    /// Setting pack with overrides applied when a campaign activates it;
+    /// must share the manifest's ID and version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setting: Option<SettingPack>,
+}
+
+/// What is already installed, for validating a pack's dependencies and
+/// references
+#[derive(Debug, Clone, Default)]
+pub struct InstalledContent {
+    /// Pack ID -> installed versions
+    pub packs: HashMap<String, Vec<String>>,
+    pub archetype_ids: HashSet<String>,
+    pub vocabulary_bank_ids: HashSet<String>,
+}
+
+/// Item counts for a pack
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackContents {
+    pub archetypes: usize,
+    pub vocabulary_banks: usize,
+    pub name_banks: usize,
+    pub personality_templates: usize,
+    pub has_setting: bool,
+}
+
+impl ArchetypePack {
+    pub fn new(manifest: PackManifest) -> Self {
+        Self {
+            manifest,
+            archetypes: Vec::new(),
+            vocabulary_banks: Vec::new(),
+            name_banks: Vec::new(),
+            trained_name_banks: Vec::new(),
+            personality_templates: Vec::new(),
+            setting: None,
+        }
+    }
+
+    pub fn contents(&self) -> PackContents {
+        PackContents {
+            archetypes: self.archetypes.len(),
+            vocabulary_banks: self.vocabulary_banks.len(),
+            name_banks: self.name_banks.len() + self.trained_name_banks.len(),
+            personality_templates: self.personality_templates.len(),
+            has_setting: self.setting.is_some(),
+        }
+    }
+
+    /// The setting pack registered for this pack: the bundled one, or an
+    /// empty one carrying the manifest's metadata so the pack can be listed
+    /// and depended on
+    pub fn setting_pack(&self) -> SettingPack {
+        if let Some(setting) = &self.setting {
+            return setting.clone();
+        }
+        let manifest = &self.manifest;
+        let mut setting =
+            SettingPack::new(&manifest.id, &manifest.name, &manifest.game_system, &manifest.version);
+        setting.description = manifest.description.clone();
+        setting.author = manifest.author.clone();
+        setting.url = manifest.url.clone();
+        setting.tags = manifest.tags.clone();
+        setting
+    }
+
+    /// Validate the manifest, the app and dependency versions, and that
+    /// everything the content refers to is either in the pack or installed
+    pub fn validate(&self, installed: &InstalledContent, app_version: &str) -> Result<()> {
+        let manifest = &self.manifest;
+        let invalid = |reason: String| ArchetypeError::SettingPackInvalid {
+            pack_id: manifest.id.clone(),
+            reason,
+        };
+
+        if manifest.format_version == 0 || manifest.format_version > PACK_FORMAT_VERSION {
+            return Err(invalid(format!(
+                "Pack format {} is not supported (this app reads up to format {})",
+                manifest.format_version, PACK_FORMAT_VERSION
+            )));
+        }
+        if manifest.id.trim().is_empty() || manifest.name.trim().is_empty() || manifest.game_system.trim().is_empty() {
+            return Err(invalid("Pack ID, name, and game system are required".to_string()));
+        }
+        if !is_valid_semver(&manifest.version) {
+            return Err(invalid(format!(
+                "Invalid version format: '{}' (expecting MAJOR.MINOR.PATCH)",
+                manifest.version
+            )));
+        }
+        if let Some(min) = &manifest.min_app_version {
+            if compare_semver(app_version, min) == Some(std::cmp::Ordering::Less) {
+                return Err(invalid(format!("Requires app version {} or newer (running {})", min, app_version)));
+            }
+        }
+
+        for dependency in &manifest.dependencies {
+            if dependency.id == manifest.id {
+                return Err(invalid("A pack cannot depend on itself".to_string()));
+            }
+            let versions = installed.packs.get(&dependency.id).map(Vec::as_slice).unwrap_or_default();
+            let satisfied = versions
+                .iter()
+                .any(|v| version_satisfies(v, &dependency.version).unwrap_or(false));
+            if !satisfied {
+                return Err(ArchetypeError::PackDependencyMissing {
+                    pack_id: manifest.id.clone(),
+                    dependency: dependency.id.clone(),
+                    requirement: dependency.version.clone(),
+                    installed: versions.to_vec(),
+                });
+            }
+        }
+
+        if let Some(setting) = &self.setting {
+            if setting.id != manifest.id || setting.version != manifest.version {
+                return Err(invalid(format!(
+                    "Setting pack {}@{} does not match the manifest",
+                    setting.id, setting.version
+                )));
+            }
+            setting.validate()?;
+        }
+        for archetype in &self.archetypes {
+            archetype.validate().map_err(|e| invalid(format!("Invalid archetype '{}': {}", archetype.id, e)))?;
+        }
+        for rules in &self.name_banks {
+            rules
+                .validate()
+                .map_err(|e| invalid(format!("Invalid name bank '{}': {}", rules.culture_id, e)))?;
+        }
+        for template in &self.personality_templates {
+            template
+                .validate()
+                .map_err(|e| invalid(format!("Invalid personality template '{}': {}", template.name, e)))?;
+        }
+
+        let (mut missing_archetypes, missing_banks) = self.missing_references(installed);
+        if let Some(bank) = missing_banks.into_iter().next() {
+            return Err(ArchetypeError::VocabularyBankNotFound(bank));
+        }
+        missing_archetypes.sort();
+        missing_archetypes.dedup();
+        if !missing_archetypes.is_empty() {
+            return Err(ArchetypeError::SettingPackReferenceError {
+                pack_id: manifest.id.clone(),
+                missing_ids: missing_archetypes,
+            });
+        }
+        Ok(())
+    }
+
+    /// Archetypes (parents and overrides) and vocabulary banks the pack
+    /// refers to but neither bundles nor finds installed
+    fn missing_references(&self, installed: &InstalledContent) -> (Vec<String>, Vec<String>) {
+        let archetypes: HashSet<&str> = self
+            .archetypes
+            .iter()
+            .map(|a| a.id.as_str())
+            .chain(self.setting.iter().flat_map(|s| s.custom_archetypes.iter().map(|a| a.id.as_str())))
+            .chain(installed.archetype_ids.iter().map(String::as_str))
+            .collect();
+        let banks: HashSet<&str> = self
+            .vocabulary_banks
+            .iter()
+            .map(|b| b.id.as_str())
+            .chain(self.setting.iter().flat_map(|s| s.vocabulary_banks.iter().map(|b| b.id.as_str())))
+            .chain(installed.vocabulary_bank_ids.iter().map(String::as_str))
+            .collect();
+
+        let mut missing_archetypes = Vec::new();
+        let mut missing_banks = Vec::new();
+        for archetype in &self.archetypes {
+            if let Some(parent) = &archetype.parent_id {
+                if !archetypes.contains(parent.as_str()) {
+                    missing_archetypes.push(parent.to_string());
+                }
+            }
+            if let Some(bank) = &archetype.vocabulary_bank_id {
+                if !banks.contains(bank.as_str()) {
+                    missing_banks.push(bank.clone());
+                }
+            }
+        }
+        if let Some(setting) = &self.setting {
+            missing_archetypes.extend(
+                setting.archetype_overrides.keys().filter(|id| !archetypes.contains(id.as_str())).cloned(),
+            );
+        }
+        (missing_archetypes, missing_banks)
+    }
+}
+
+// ============================================================================
+// Versions
+// ============================================================================
+
+/// Whether `version` meets `requirement` (`*`, `1.2.0`, `>=1.2.0`,
+/// `^1.2.0`, or `~1.2.0`). `None` if either cannot be parsed.
+pub fn version_satisfies(version: &str, requirement: &str) -> Option<bool> {
+    let requirement = requirement.trim();
+    if requirement.is_empty() || requirement == "*" {
+        return Some(parse_semver(version).is_some());
+    }
+    let (op, required) = ["^", "~", ">=", "="]
+        .iter()
+        .find_map(|op| requirement.strip_prefix(op).map(|rest| (*op, rest.trim())))
+        .unwrap_or(("=", requirement));
+
+    let (major, minor, _) = parse_semver(version)?;
+    let (req_major, req_minor, _) = parse_semver(required)?;
+    let at_least = compare_semver(version, required)? != std::cmp::Ordering::Less;
+    Some(match op {
+        "^" => at_least && major == req_major,
+        "~" => at_least && major == req_major && minor == req_minor,
+        ">=" => at_least,
+        _ => compare_semver(version, required)? == std::cmp::Ordering::Equal,
+    })
+}
+
+// ============================================================================
+// Reading and Writing
+// ============================================================================
+
+/// Read a pack from a JSON file or zip archive
+pub fn read_pack(path: &Path) -> Result<ArchetypePack> {
+    let mut file = File::open(path)?;
+    let mut magic = [0u8; 4];
+    let is_zip = file.read_exact(&mut magic).is_ok() && &magic == ZIP_MAGIC;
+    file.seek(SeekFrom::Start(0))?;
+
+    if !is_zip {
+        return Ok(serde_json::from_reader(file)?);
+    }
+    let mut zip = ZipArchive::new(file).map_err(archive_error)?;
+    read_zip_pack(&mut zip)
+}
+
+/// Write a pack as a zip archive, or as a single JSON file when `dest` ends
+/// in `.json`
+pub fn write_pack(pack: &ArchetypePack, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if dest.extension().is_some_and(|e| e.eq_ignore_ascii_case("json")) {
+        std::fs::write(dest, serde_json::to_vec_pretty(pack)?)?;
+        return Ok(());
+    }
+
+    let mut zip = ZipWriter::new(File::create(dest)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut add = |name: String, data: Vec<u8>| -> Result<()> {
+        zip.start_file(name, options).map_err(archive_error)?;
+        zip.write_all(&data)?;
+        Ok(())
+    };
+
+    add(PACK_MANIFEST_FILE.to_string(), serde_json::to_vec_pretty(&pack.manifest)?)?;
+    if let Some(setting) = &pack.setting {
+        add(format!("{}.json", SETTING_STEM), serde_json::to_vec_pretty(setting)?)?;
+    }
+    for archetype in &pack.archetypes {
+        add(entry_name(ARCHETYPES_DIR, archetype.id.as_str()), serde_json::to_vec_pretty(archetype)?)?;
+    }
+    for bank in &pack.vocabulary_banks {
+        add(entry_name(VOCABULARY_DIR, &bank.id), serde_json::to_vec_pretty(bank)?)?;
+    }
+    for rules in &pack.name_banks {
+        add(entry_name(NAMES_DIR, &rules.culture_id), serde_json::to_vec_pretty(rules)?)?;
+    }
+    for bank in &pack.trained_name_banks {
+        add(entry_name(TRAINED_NAMES_DIR, &bank.culture_id), serde_json::to_vec_pretty(bank)?)?;
+    }
+    for template in &pack.personality_templates {
+        add(entry_name(PERSONALITIES_DIR, template.id.as_str()), serde_json::to_vec_pretty(template)?)?;
+    }
+    zip.finish().map_err(archive_error)?;
+    Ok(())
+}
+
+fn read_zip_pack<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Result<ArchetypePack> {
+    let manifest: PackManifest = serde_json::from_reader(zip.by_name(PACK_MANIFEST_FILE).map_err(archive_error)?)?;
+    let mut pack = ArchetypePack::new(manifest);
+
+    for index in 0..zip.len() {
+        let mut entry = zip.by_index(index).map_err(archive_error)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let name = entry.name().to_string();
+        let mut content = String::new();
+        entry.read_to_string(&mut content)?;
+
+        let Some((dir, stem, is_yaml)) = split_entry(&name) else {
+            continue;
+        };
+        match dir {
+            "" if stem == SETTING_STEM => pack.setting = Some(parse_entry(&name, &content, is_yaml)?),
+            ARCHETYPES_DIR => pack.archetypes.push(parse_entry(&name, &content, is_yaml)?),
+            VOCABULARY_DIR => pack.vocabulary_banks.push(parse_entry(&name, &content, is_yaml)?),
+            NAMES_DIR => pack.name_banks.push(parse_entry(&name, &content, is_yaml)?),
+            TRAINED_NAMES_DIR => pack.trained_name_banks.push(parse_entry(&name, &content, is_yaml)?),
+            PERSONALITIES_DIR => pack.personality_templates.push(parse_entry(&name, &content, is_yaml)?),
+            _ => log::debug!("Ignoring unrecognized pack entry {}", name),
+        }
+    }
+    Ok(pack)
+}
+
+/// Split `dir/stem.ext` into its directory (with trailing slash), stem, and
+/// whether it is YAML; `None` for files that are neither JSON nor YAML
+fn split_entry(name: &str) -> Option<(&str, &str, bool)> {
+    let (dir, file) = match name.rfind('/') {
+        Some(i) => name.split_at(i + 1),
+        None => ("", name),
+    };
+    let (stem, extension) = file.rsplit_once('.')?;
+    match extension.to_ascii_lowercase().as_str() {
+        "json" => Some((dir, stem, false)),
+        "yaml" | "yml" => Some((dir, stem, true)),
+        _ => None,
+    }
+}
+
+fn parse_entry<T: DeserializeOwned>(name: &str, content: &str, is_yaml: bool) -> Result<T> {
+    let parsed = if is_yaml {
+        serde_yaml_ng::from_str(content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(content).map_err(|e| e.to_string())
+    };
+    parsed.map_err(|e| ArchetypeError::PackArchive(format!("{}: {}", name, e)))
+}
+
+/// Zip entry name for an item, keeping only filename-safe characters
+fn entry_name(dir: &str, id: &str) -> String {
+    let stem: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect();
+    format!("{}{}.json", dir, stem)
+}
+
+fn archive_error(e: zip::result::ZipError) -> ArchetypeError {
+    ArchetypeError::PackArchive(e.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::archetype::types::ArchetypeCategory;
+
+    fn manifest(id: &str, version: &str) -> PackManifest {
+        PackManifest {
+            format_version: PACK_FORMAT_VERSION,
+            id: id.to_string(),
+            name: "Sunken Isles".to_string(),
+            version: version.to_string(),
+            game_system: "dnd5e".to_string(),
+            description: None,
+            author: None,
+            license: None,
+            url: None,
+            min_app_version: None,
+            dependencies: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn test_version_requirements() {
+        assert_eq!(version_satisfies("1.4.2", "*"), Some(true));
+        assert_eq!(version_satisfies("1.4.2", "^1.2.0"), Some(true));
+        assert_eq!(version_satisfies("2.0.0", "^1.2.0"), Some(false));
+        assert_eq!(version_satisfies("1.2.9", "~1.2.0"), Some(true));
+        assert_eq!(version_satisfies("1.3.0", "~1.2.0"), Some(false));
+        assert_eq!(version_satisfies("3.0.0", ">=1.2.0"), Some(true));
+        assert_eq!(version_satisfies("1.2.0", "1.2.0"), Some(true));
+        assert_eq!(version_satisfies("1.2.1", "=1.2.0"), Some(false));
+        assert_eq!(version_satisfies("1.2", "^1.0.0"), None);
+    }
+
+    #[test]
+    fn test_validate_dependencies_and_references() {
+        let mut pack = ArchetypePack::new(manifest("sunken_isles", "1.0.0"));
+        pack.manifest.dependencies.push(PackDependency {
+            id: "core_seafarers".to_string(),
+            version: "^1.0.0".to_string(),
+        });
+        let mut installed = InstalledContent::default();
+        assert!(matches!(
+            pack.validate(&installed, "1.0.0"),
+            Err(ArchetypeError::PackDependencyMissing { .. })
+        ));
+
+        installed.packs.insert("core_seafarers".to_string(), vec!["0.9.0".to_string(), "1.1.0".to_string()]);
+        assert!(pack.validate(&installed, "1.0.0").is_ok());
+
+        pack.archetypes.push(
+            Archetype::new("pearl_diver", "Pearl Diver", ArchetypeCategory::Role).with_parent("sailor"),
+        );
+        assert!(matches!(
+            pack.validate(&installed, "1.0.0"),
+            Err(ArchetypeError::SettingPackReferenceError { ref missing_ids, .. }) if missing_ids == &["sailor"]
+        ));
+        installed.archetype_ids.insert("sailor".to_string());
+        assert!(pack.validate(&installed, "1.0.0").is_ok());
+
+        pack.manifest.min_app_version = Some("2.0.0".to_string());
+        assert!(pack.validate(&installed, "1.5.0").is_err());
+        pack.manifest.min_app_version = None;
+        pack.manifest.format_version = PACK_FORMAT_VERSION + 1;
+        assert!(pack.validate(&installed, "1.0.0").is_err());
+    }
+
+    #[test]
+    fn test_zip_and_json_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut pack = ArchetypePack::new(manifest("sunken_isles", "1.2.0"));
+        pack.archetypes.push(Archetype::new("pearl_diver", "Pearl Diver", ArchetypeCategory::Role));
+        pack.trained_name_banks.push(TrainedNameBank {
+            culture_id: "islander".to_string(),
+            culture_name: "Islander".to_string(),
+            description: String::new(),
+            order: 2,
+            names: vec!["Kaia".to_string(), "Moana".to_string()],
+            family_names: vec![],
+        });
+        pack.setting = Some(pack.setting_pack());
+
+        for file in ["pack.zip", "pack.json"] {
+            let path = dir.path().join(file);
+            write_pack(&pack, &path).unwrap();
+            let read = read_pack(&path).unwrap();
+            assert_eq!(read.manifest.id, "sunken_isles");
+            assert_eq!(read.archetypes[0].id.as_str(), "pearl_diver");
+            assert_eq!(read.trained_name_banks[0].names.len(), 2);
+            assert_eq!(read.setting.as_ref().map(|s| s.version.as_str()), Some("1.2.0"));
+        }
+    }
+}
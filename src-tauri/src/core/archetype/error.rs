@@ -122,6 +122,24 @@ pub enum ArchetypeError {
         missing_ids: Vec<String>,
     },
 
+    /// Archetype pack depends on a pack that is not installed at a matching
+    /// version.
+    #[error("Pack '{pack_id}' requires {dependency} {requirement} (installed: {installed:?})")]
+    PackDependencyMissing {
+        /// The pack being imported
+        pack_id: String,
+        /// The required pack ID
+        dependency: String,
+        /// The version requirement
+        requirement: String,
+        /// Installed versions of the required pack
+        installed: Vec<String>,
+    },
+
+    /// Archetype pack archive could not be read or written.
+    #[error("Pack archive error: {0}")]
+    PackArchive(String),
+
     // =========================================================================
     // Vocabulary Bank Errors
     // =========================================================================
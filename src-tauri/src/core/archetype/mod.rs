@@ -102,6 +102,7 @@
 //! - [`error`]: Error types for all archetype operations
 //! - [`types`]: Core data models (Archetype, ArchetypeCategory, etc.)
 //! - [`setting_pack`]: Setting pack types for content customization
+//! - [`archetype_pack`]: Shareable pack format with import/export
 //! - [`resolution`]: Query and result types for archetype resolution
 //! - [`meilisearch`]: Meilisearch index configuration and management
 
@@ -109,6 +110,7 @@
 // Module Declarations
 // ============================================================================
 
+pub mod archetype_pack;
 pub mod cache;
 pub mod error;
 pub mod integration;
@@ -187,6 +189,15 @@ pub use setting_pack::{
     parse_semver,
 };
 
+// ============================================================================
+// Re-exports: Archetype Packs
+// ============================================================================
+
+pub use archetype_pack::{
+    read_pack, version_satisfies, write_pack, ArchetypePack, InstalledContent, PackContents, PackDependency,
+    PackManifest, PACK_FORMAT_VERSION, PACK_MANIFEST_FILE,
+};
+
 // ============================================================================
 // Re-exports: Resolution Types
 // ============================================================================
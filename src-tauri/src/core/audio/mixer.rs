@@ -0,0 +1,928 @@
+//! Multi-Channel Audio Mixer
+//!
+//! Routes all playback through a single output device and four named
+//! channels (voice, music, ambience, sfx), each with its own volume and mute.
+//! Music and ambience are ducked automatically while the voice channel is
+//! speaking.
+//!
+//! rodio's `OutputStream` is not `Send`, so it is kept alive on a dedicated
+//! thread; the thread-safe `OutputStreamHandle` is shared and every track
+//! gets its own `Sink` on it. The mixer rescales a track's sink whenever
+//! channel settings or the duck level change.
+//!
+//! Each channel can be routed to its own output device. One stream is opened
+//! per device in use. A device watcher thread notices when a device
+//! disappears (or the system default changes): tracks on the lost stream are
+//! stopped so nothing waits on it forever, and the next playback reopens on
+//! the default device.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::Duration;
+
+use rodio::cpal::traits::{DeviceTrait, HostTrait};
+use rodio::cpal::FromSample;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sample, Sink, Source};
+use serde::{Deserialize, Serialize};
+
+use super::{AudioError, AudioVolumes, Result, TrackType};
+
+/// Ducking update interval while voice is playing or the duck is ramping
+const DUCK_TICK: Duration = Duration::from_millis(30);
+
+/// Ducking update interval while idle
+const IDLE_TICK: Duration = Duration::from_millis(200);
+
+/// How often open output devices are checked for removal
+const DEVICE_POLL: Duration = Duration::from_secs(2);
+
+// ============================================================================
+// Channels & Settings
+// ============================================================================
+
+/// A named mixer channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MixerChannel {
+    Voice,
+    Music,
+    Ambience,
+    Sfx,
+    /// Combat announcements, kept separate from dialogue
+    Announcer,
+}
+
+impl MixerChannel {
+    pub const ALL: [MixerChannel; 5] = [
+        MixerChannel::Voice,
+        MixerChannel::Music,
+        MixerChannel::Ambience,
+        MixerChannel::Sfx,
+        MixerChannel::Announcer,
+    ];
+
+    /// Whether this channel is lowered while voice plays
+    pub fn is_duckable(self) -> bool {
+        matches!(self, MixerChannel::Music | MixerChannel::Ambience)
+    }
+
+    /// Whether speech on this channel ducks the duckable channels
+    pub fn is_speech(self) -> bool {
+        matches!(self, MixerChannel::Voice | MixerChannel::Announcer)
+    }
+}
+
+impl From<TrackType> for MixerChannel {
+    fn from(track_type: TrackType) -> Self {
+        match track_type {
+            TrackType::Voice => MixerChannel::Voice,
+            TrackType::Music => MixerChannel::Music,
+            TrackType::Ambience => MixerChannel::Ambience,
+            TrackType::SoundEffect => MixerChannel::Sfx,
+        }
+    }
+}
+
+/// Volume and mute for one channel (or the master bus)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ChannelSettings {
+    /// Volume (0.0 - 1.0)
+    pub volume: f32,
+    pub muted: bool,
+}
+
+impl ChannelSettings {
+    pub fn new(volume: f32) -> Self {
+        Self { volume: volume.clamp(0.0, 1.0), muted: false }
+    }
+
+    fn gain(&self) -> f32 {
+        if self.muted { 0.0 } else { self.volume }
+    }
+}
+
+impl Default for ChannelSettings {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Automatic ducking of music and ambience under voice
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DuckingSettings {
+    pub enabled: bool,
+    /// Gain applied to ducked channels while voice plays (0.0 - 1.0)
+    pub level: f32,
+    /// Time to ramp down when voice starts
+    pub attack_ms: u64,
+    /// Time to ramp back up after voice stops
+    pub release_ms: u64,
+}
+
+impl Default for DuckingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            level: 0.35,
+            attack_ms: 150,
+            release_ms: 600,
+        }
+    }
+}
+
+/// Persisted mixer configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MixerSettings {
+    pub master: ChannelSettings,
+    pub voice: ChannelSettings,
+    pub music: ChannelSettings,
+    pub ambience: ChannelSettings,
+    pub sfx: ChannelSettings,
+    pub announcer: ChannelSettings,
+    pub ducking: DuckingSettings,
+    /// Output device name per channel; channels not listed use the default device
+    pub output_devices: HashMap<MixerChannel, String>,
+}
+
+impl Default for MixerSettings {
+    fn default() -> Self {
+        let volumes = AudioVolumes::default();
+        Self {
+            master: ChannelSettings::new(volumes.master),
+            voice: ChannelSettings::new(volumes.voice),
+            music: ChannelSettings::new(volumes.music),
+            ambience: ChannelSettings::new(volumes.ambience),
+            sfx: ChannelSettings::new(volumes.sfx),
+            announcer: ChannelSettings::new(volumes.voice),
+            ducking: DuckingSettings::default(),
+            output_devices: HashMap::new(),
+        }
+    }
+}
+
+impl MixerSettings {
+    pub fn channel(&self, channel: MixerChannel) -> &ChannelSettings {
+        match channel {
+            MixerChannel::Voice => &self.voice,
+            MixerChannel::Music => &self.music,
+            MixerChannel::Ambience => &self.ambience,
+            MixerChannel::Sfx => &self.sfx,
+            MixerChannel::Announcer => &self.announcer,
+        }
+    }
+
+    pub fn channel_mut(&mut self, channel: MixerChannel) -> &mut ChannelSettings {
+        match channel {
+            MixerChannel::Voice => &mut self.voice,
+            MixerChannel::Music => &mut self.music,
+            MixerChannel::Ambience => &mut self.ambience,
+            MixerChannel::Sfx => &mut self.sfx,
+            MixerChannel::Announcer => &mut self.announcer,
+        }
+    }
+
+    /// Output device selected for a channel (`None` = system default)
+    pub fn output_device(&self, channel: MixerChannel) -> Option<&str> {
+        self.output_devices.get(&channel).map(String::as_str)
+    }
+
+    /// Output gain for a channel given the current duck level (1.0 = not ducked)
+    pub fn channel_gain(&self, channel: MixerChannel, duck: f32) -> f32 {
+        let duck = if channel.is_duckable() { duck } else { 1.0 };
+        self.master.gain() * self.channel(channel).gain() * duck
+    }
+
+    /// Load from a JSON file; a missing file yields the defaults
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| AudioError::DecodeError(e.to_string()))
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+impl From<&MixerSettings> for AudioVolumes {
+    fn from(settings: &MixerSettings) -> Self {
+        Self {
+            master: settings.master.gain(),
+            voice: settings.voice.gain(),
+            music: settings.music.gain(),
+            ambience: settings.ambience.gain(),
+            sfx: settings.sfx.gain(),
+        }
+    }
+}
+
+/// Move the duck gain one tick toward its target.
+///
+/// Ramps cover the full 0..1 range in `ramp`, so a partial duck reaches its
+/// level proportionally sooner.
+fn step_duck(current: f32, target: f32, dt: Duration, ramp: Duration) -> f32 {
+    if ramp.is_zero() {
+        return target;
+    }
+    let step = dt.as_secs_f32() / ramp.as_secs_f32();
+    if current > target {
+        (current - step).max(target)
+    } else {
+        (current + step).min(target)
+    }
+}
+
+// ============================================================================
+// Status Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelStatus {
+    pub channel: MixerChannel,
+    pub volume: f32,
+    pub muted: bool,
+    /// Gain after master, mute, and ducking
+    pub effective_volume: f32,
+    pub active_tracks: usize,
+    /// Selected output device (`None` = system default)
+    pub device: Option<String>,
+    /// Device the channel is actually playing on, if its output is open
+    pub active_device: Option<String>,
+    /// The selected device was unavailable and the default is used instead
+    pub device_fallback: bool,
+}
+
+/// An available output device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+}
+
+/// List the output devices on the default audio host
+pub fn list_output_devices() -> Result<Vec<OutputDeviceInfo>> {
+    let host = rodio::cpal::default_host();
+    let default_name = host.default_output_device().and_then(|d| d.name().ok());
+    let devices = host
+        .output_devices()
+        .map_err(|e| AudioError::OutputError(e.to_string()))?;
+
+    Ok(devices
+        .filter_map(|d| d.name().ok())
+        .map(|name| OutputDeviceInfo {
+            is_default: default_name.as_deref() == Some(name.as_str()),
+            name,
+        })
+        .collect())
+}
+
+/// Snapshot of the mixer for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MixerStatus {
+    pub master_volume: f32,
+    pub master_muted: bool,
+    pub channels: Vec<ChannelStatus>,
+    pub ducking: DuckingSettings,
+    /// Current duck gain applied to music and ambience (1.0 = not ducked)
+    pub duck_gain: f32,
+    pub voice_active: bool,
+    pub output_open: bool,
+}
+
+// ============================================================================
+// Tracks
+// ============================================================================
+
+struct TrackShared {
+    channel: MixerChannel,
+    /// Key of the output this track's sink is bound to
+    output: Option<String>,
+    sink: Sink,
+    /// Per-track gain (f32 bits), e.g. a soundscape layer's fade position
+    gain: AtomicU32,
+}
+
+impl TrackShared {
+    fn gain(&self) -> f32 {
+        f32::from_bits(self.gain.load(Ordering::Relaxed))
+    }
+
+    fn apply(&self, channel_gain: f32) {
+        self.sink.set_volume(self.gain() * channel_gain);
+    }
+}
+
+/// A playing sound on one mixer channel.
+///
+/// Dropping the handle does not stop playback; the mixer keeps the track
+/// until it finishes. Call `stop` to cut it off.
+#[derive(Clone)]
+pub struct MixerTrack {
+    shared: Arc<TrackShared>,
+    mixer: Arc<MixerInner>,
+}
+
+impl MixerTrack {
+    pub fn channel(&self) -> MixerChannel {
+        self.shared.channel
+    }
+
+    /// Queue a source on this track
+    pub fn append<S>(&self, source: S)
+    where
+        S: Source + Send + 'static,
+        f32: FromSample<S::Item>,
+        S::Item: Sample + Send,
+    {
+        self.shared.sink.append(source);
+    }
+
+    /// Set the track's own gain (0.0 - 1.0), applied on top of its channel
+    pub fn set_gain(&self, gain: f32) {
+        self.shared.gain.store(gain.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        self.shared.apply(self.mixer.channel_gain(self.shared.channel));
+    }
+
+    pub fn gain(&self) -> f32 {
+        self.shared.gain()
+    }
+
+    pub fn pause(&self) {
+        self.shared.sink.pause();
+    }
+
+    pub fn resume(&self) {
+        self.shared.sink.play();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.shared.sink.is_paused()
+    }
+
+    pub fn stop(&self) {
+        self.shared.sink.stop();
+    }
+
+    /// True when nothing is queued
+    pub fn is_finished(&self) -> bool {
+        self.shared.sink.empty()
+    }
+
+    /// Block until everything queued has played. Call from a blocking context.
+    pub fn sleep_until_end(&self) {
+        self.shared.sink.sleep_until_end();
+    }
+}
+
+// ============================================================================
+// Mixer
+// ============================================================================
+
+/// Handle to an open output device; dropping `_keepalive` closes the stream
+struct Output {
+    handle: OutputStreamHandle,
+    _keepalive: mpsc::Sender<()>,
+    /// Name of the device actually opened
+    device_name: Option<String>,
+    /// Opened on the system default device (requested or as a fallback)
+    is_default: bool,
+    /// The requested device was missing and the default was opened instead
+    fallback: bool,
+}
+
+impl Output {
+    /// Open a device by name (`None` = default) on a keepalive thread.
+    ///
+    /// Falls back to the default device when the named one is not present.
+    fn open(device: Option<String>) -> Result<Self> {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let (keepalive_tx, keepalive_rx) = mpsc::channel::<()>();
+        std::thread::Builder::new()
+            .name("audio-output".to_string())
+            .spawn(move || {
+                let host = rodio::cpal::default_host();
+                let requested = device.as_deref().and_then(|name| {
+                    host.output_devices()
+                        .ok()?
+                        .find(|d| d.name().ok().as_deref() == Some(name))
+                });
+                let fallback = device.is_some() && requested.is_none();
+                let is_default = requested.is_none();
+                let Some(selected) = requested.or_else(|| host.default_output_device()) else {
+                    let _ = ready_tx.send(Err("No audio output device available".to_string()));
+                    return;
+                };
+                let device_name = selected.name().ok();
+
+                match OutputStream::try_from_device(&selected) {
+                    Ok((_stream, handle)) => {
+                        let _ = ready_tx.send(Ok((handle, device_name, is_default, fallback)));
+                        // Hold the stream open until the mixer lets go of the sender
+                        let _ = keepalive_rx.recv();
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e.to_string()));
+                    }
+                }
+            })
+            .map_err(|e| AudioError::OutputError(e.to_string()))?;
+
+        let (handle, device_name, is_default, fallback) = ready_rx
+            .recv()
+            .map_err(|_| AudioError::OutputError("Audio output thread exited".to_string()))?
+            .map_err(AudioError::OutputError)?;
+
+        if fallback {
+            log::warn!(
+                "Audio output device not found; using default device {:?}",
+                device_name
+            );
+        }
+
+        Ok(Self { handle, _keepalive: keepalive_tx, device_name, is_default, fallback })
+    }
+}
+
+struct MixerInner {
+    settings: RwLock<MixerSettings>,
+    /// Open outputs keyed by requested device name (`None` = default)
+    outputs: Mutex<HashMap<Option<String>, Output>>,
+    tracks: Mutex<Vec<Arc<TrackShared>>>,
+    /// Current duck gain (f32 bits)
+    duck: AtomicU32,
+}
+
+impl MixerInner {
+    fn duck(&self) -> f32 {
+        f32::from_bits(self.duck.load(Ordering::Relaxed))
+    }
+
+    fn channel_gain(&self, channel: MixerChannel) -> f32 {
+        self.settings
+            .read()
+            .map(|s| s.channel_gain(channel, self.duck()))
+            .unwrap_or(1.0)
+    }
+
+    /// Get the output handle for a device, opening it on first use
+    fn output_handle(&self, device: Option<&str>) -> Result<OutputStreamHandle> {
+        let mut outputs = self.outputs.lock().map_err(|e| AudioError::OutputError(e.to_string()))?;
+        let key = device.map(str::to_string);
+        if let Some(output) = outputs.get(&key) {
+            return Ok(output.handle.clone());
+        }
+
+        let output = Output::open(key.clone())?;
+        let handle = output.handle.clone();
+        outputs.insert(key, output);
+        Ok(handle)
+    }
+
+    /// Close outputs whose device went away, or whose preferred device is back.
+    ///
+    /// Tracks on a closed output are stopped so nothing blocks on a dead
+    /// stream; the next playback on the channel reopens a device.
+    fn check_devices(&self) {
+        let selected: Vec<Option<String>> = match self.settings.read() {
+            Ok(settings) => settings.output_devices.values().cloned().map(Some).collect(),
+            Err(_) => return,
+        };
+
+        let Ok(mut outputs) = self.outputs.lock() else {
+            return;
+        };
+        if outputs.is_empty() {
+            return;
+        }
+
+        let host = rodio::cpal::default_host();
+        let available: Vec<String> = match host.output_devices() {
+            Ok(devices) => devices.filter_map(|d| d.name().ok()).collect(),
+            Err(e) => {
+                log::debug!("Could not enumerate audio devices: {}", e);
+                return;
+            }
+        };
+        let default_name = host.default_output_device().and_then(|d| d.name().ok());
+
+        let Ok(tracks) = self.tracks.lock() else {
+            return;
+        };
+        let in_use = |key: &Option<String>| tracks.iter().any(|t| &t.output == key && !t.sink.empty());
+        let stale: Vec<Option<String>> = outputs
+            .iter()
+            .filter(|(key, output)| {
+                let lost = output
+                    .device_name
+                    .as_ref()
+                    .is_some_and(|name| !available.contains(name));
+                let default_moved = output.is_default && output.device_name != default_name;
+                let preferred_back = output.fallback
+                    && key.as_ref().is_some_and(|k| available.contains(k))
+                    && !in_use(key);
+                // A device no channel is routed to any more is closed once idle
+                let unused = key.is_some() && !selected.contains(key) && !in_use(key);
+                lost || default_moved || preferred_back || unused
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in stale {
+            if let Some(output) = outputs.remove(&key) {
+                log::info!("Closing audio output {:?}", output.device_name);
+            }
+            for track in tracks.iter().filter(|t| t.output == key) {
+                track.sink.stop();
+            }
+        }
+    }
+
+    /// Name of the device a channel's output is actually using, if open
+    fn active_device(&self, device: Option<&str>) -> (Option<String>, bool) {
+        self.outputs
+            .lock()
+            .ok()
+            .and_then(|outputs| {
+                outputs
+                    .get(&device.map(str::to_string))
+                    .map(|o| (o.device_name.clone(), o.fallback))
+            })
+            .unwrap_or((None, false))
+    }
+
+    fn apply_all(&self) {
+        let Ok(settings) = self.settings.read() else {
+            return;
+        };
+        let duck = self.duck();
+        if let Ok(tracks) = self.tracks.lock() {
+            for track in tracks.iter() {
+                track.apply(settings.channel_gain(track.channel, duck));
+            }
+        }
+    }
+
+    /// Drop finished tracks nobody holds, and advance ducking.
+    ///
+    /// Returns true while voice is playing or the duck is still ramping.
+    fn tick(&self, dt: Duration) -> bool {
+        let voice_active = {
+            let Ok(mut tracks) = self.tracks.lock() else {
+                return false;
+            };
+            tracks.retain(|t| Arc::strong_count(t) > 1 || !t.sink.empty());
+            tracks
+                .iter()
+                .any(|t| t.channel.is_speech() && !t.sink.empty() && !t.sink.is_paused())
+        };
+
+        let ducking = self.settings.read().map(|s| s.ducking).unwrap_or_default();
+        let target = if ducking.enabled && voice_active { ducking.level.clamp(0.0, 1.0) } else { 1.0 };
+        let current = self.duck();
+        if current == target {
+            return voice_active;
+        }
+
+        let ramp = if target < current { ducking.attack_ms } else { ducking.release_ms };
+        let next = step_duck(current, target, dt, Duration::from_millis(ramp));
+        self.duck.store(next.to_bits(), Ordering::Relaxed);
+        self.apply_all();
+        true
+    }
+}
+
+/// Shared multi-channel mixer. Cloning yields another handle to the same mixer.
+#[derive(Clone)]
+pub struct AudioMixer {
+    inner: Arc<MixerInner>,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self::new(MixerSettings::default())
+    }
+}
+
+impl AudioMixer {
+    /// Create the mixer and its ducking thread. The output device is opened on
+    /// first playback.
+    pub fn new(settings: MixerSettings) -> Self {
+        let inner = Arc::new(MixerInner {
+            settings: RwLock::new(settings),
+            outputs: Mutex::new(HashMap::new()),
+            tracks: Mutex::new(Vec::new()),
+            duck: AtomicU32::new(1.0f32.to_bits()),
+        });
+
+        let weak: Weak<MixerInner> = Arc::downgrade(&inner);
+        std::thread::Builder::new()
+            .name("audio-ducking".to_string())
+            .spawn(move || {
+                let mut interval = IDLE_TICK;
+                loop {
+                    std::thread::sleep(interval);
+                    let Some(inner) = weak.upgrade() else {
+                        break;
+                    };
+                    interval = if inner.tick(interval) { DUCK_TICK } else { IDLE_TICK };
+                }
+            })
+            .expect("failed to spawn audio ducking thread");
+
+        let weak: Weak<MixerInner> = Arc::downgrade(&inner);
+        std::thread::Builder::new()
+            .name("audio-devices".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(DEVICE_POLL);
+                let Some(inner) = weak.upgrade() else {
+                    break;
+                };
+                inner.check_devices();
+            })
+            .expect("failed to spawn audio device watcher thread");
+
+        Self { inner }
+    }
+
+    // ========================================================================
+    // Playback
+    // ========================================================================
+
+    /// Create an empty track on a channel; append sources to it as they arrive
+    pub fn track(&self, channel: MixerChannel, gain: f32) -> Result<MixerTrack> {
+        let device = self.settings().output_device(channel).map(str::to_string);
+        let handle = self.inner.output_handle(device.as_deref())?;
+        let sink = Sink::try_new(&handle).map_err(|e| AudioError::PlaybackError(e.to_string()))?;
+
+        let shared = Arc::new(TrackShared {
+            channel,
+            output: device,
+            sink,
+            gain: AtomicU32::new(gain.clamp(0.0, 1.0).to_bits()),
+        });
+        shared.apply(self.inner.channel_gain(channel));
+
+        self.inner
+            .tracks
+            .lock()
+            .map_err(|e| AudioError::PlaybackError(e.to_string()))?
+            .push(shared.clone());
+
+        Ok(MixerTrack { shared, mixer: self.inner.clone() })
+    }
+
+    /// Play a source on a channel
+    pub fn play<S>(&self, channel: MixerChannel, source: S, gain: f32) -> Result<MixerTrack>
+    where
+        S: Source + Send + 'static,
+        f32: FromSample<S::Item>,
+        S::Item: Sample + Send,
+    {
+        let track = self.track(channel, gain)?;
+        track.append(source);
+        Ok(track)
+    }
+
+    /// Decode an in-memory file (wav, mp3, ogg, ...) and play it on a channel
+    pub fn play_bytes(&self, channel: MixerChannel, bytes: Vec<u8>, gain: f32) -> Result<MixerTrack> {
+        let source = Decoder::new(Cursor::new(bytes))
+            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
+        self.play(channel, source, gain)
+    }
+
+    /// Stop everything playing on a channel
+    pub fn stop_channel(&self, channel: MixerChannel) {
+        if let Ok(tracks) = self.inner.tracks.lock() {
+            for track in tracks.iter().filter(|t| t.channel == channel) {
+                track.sink.stop();
+            }
+        }
+    }
+
+    /// Stop all channels
+    pub fn stop_all(&self) {
+        for channel in MixerChannel::ALL {
+            self.stop_channel(channel);
+        }
+    }
+
+    // ========================================================================
+    // Settings
+    // ========================================================================
+
+    pub fn settings(&self) -> MixerSettings {
+        self.inner.settings.read().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Replace all settings and rescale playing tracks
+    pub fn set_settings(&self, settings: MixerSettings) {
+        self.update(|s| *s = settings);
+    }
+
+    /// Set a channel's volume (0.0 - 1.0)
+    pub fn set_channel_volume(&self, channel: MixerChannel, volume: f32) {
+        self.update(|s| s.channel_mut(channel).volume = volume.clamp(0.0, 1.0));
+    }
+
+    pub fn set_channel_muted(&self, channel: MixerChannel, muted: bool) {
+        self.update(|s| s.channel_mut(channel).muted = muted);
+    }
+
+    /// Set the master volume (0.0 - 1.0)
+    pub fn set_master_volume(&self, volume: f32) {
+        self.update(|s| s.master.volume = volume.clamp(0.0, 1.0));
+    }
+
+    pub fn set_master_muted(&self, muted: bool) {
+        self.update(|s| s.master.muted = muted);
+    }
+
+    pub fn set_ducking(&self, ducking: DuckingSettings) {
+        self.update(|s| s.ducking = ducking);
+    }
+
+    /// Route a channel to an output device (`None` = system default).
+    ///
+    /// Sounds already playing stay on their current device; new playback on
+    /// the channel uses the new one.
+    pub fn set_channel_device(&self, channel: MixerChannel, device: Option<String>) {
+        self.update(|s| match device {
+            Some(name) => {
+                s.output_devices.insert(channel, name);
+            }
+            None => {
+                s.output_devices.remove(&channel);
+            }
+        });
+    }
+
+    fn update(&self, f: impl FnOnce(&mut MixerSettings)) {
+        if let Ok(mut settings) = self.inner.settings.write() {
+            f(&mut settings);
+        }
+        self.inner.apply_all();
+    }
+
+    // ========================================================================
+    // Status
+    // ========================================================================
+
+    /// Whether anything is currently playing on the voice channel
+    pub fn is_voice_active(&self) -> bool {
+        self.inner
+            .tracks
+            .lock()
+            .map(|tracks| {
+                tracks
+                    .iter()
+                    .any(|t| t.channel == MixerChannel::Voice && !t.sink.empty())
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn status(&self) -> MixerStatus {
+        let settings = self.settings();
+        let duck = self.inner.duck();
+        let counts = self
+            .inner
+            .tracks
+            .lock()
+            .map(|tracks| {
+                MixerChannel::ALL.map(|c| {
+                    tracks.iter().filter(|t| t.channel == c && !t.sink.empty()).count()
+                })
+            })
+            .unwrap_or_default();
+
+        let channels = MixerChannel::ALL
+            .iter()
+            .zip(counts)
+            .map(|(&channel, active_tracks)| {
+                let ch = settings.channel(channel);
+                let device = settings.output_device(channel);
+                let (active_device, device_fallback) = self.inner.active_device(device);
+                ChannelStatus {
+                    channel,
+                    volume: ch.volume,
+                    muted: ch.muted,
+                    effective_volume: settings.channel_gain(channel, duck),
+                    active_tracks,
+                    device: device.map(str::to_string),
+                    active_device,
+                    device_fallback,
+                }
+            })
+            .collect();
+
+        MixerStatus {
+            master_volume: settings.master.volume,
+            master_muted: settings.master.muted,
+            channels,
+            ducking: settings.ducking,
+            duck_gain: duck,
+            voice_active: self.is_voice_active(),
+            output_open: self.inner.outputs.lock().map(|o| !o.is_empty()).unwrap_or(false),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_match_legacy_volumes() {
+        let settings = MixerSettings::default();
+        let volumes = AudioVolumes::from(&settings);
+        assert_eq!(volumes.music, AudioVolumes::default().music);
+        assert_eq!(volumes.sfx, AudioVolumes::default().sfx);
+        assert!(settings.ducking.enabled);
+    }
+
+    #[test]
+    fn test_channel_gain_applies_master_mute_and_duck() {
+        let mut settings = MixerSettings::default();
+        settings.master.volume = 0.5;
+        settings.music.volume = 0.8;
+
+        assert!((settings.channel_gain(MixerChannel::Music, 1.0) - 0.4).abs() < 1e-6);
+        assert!((settings.channel_gain(MixerChannel::Music, 0.5) - 0.2).abs() < 1e-6);
+        // Voice and SFX are never ducked
+        assert!((settings.channel_gain(MixerChannel::Voice, 0.1) - 0.5).abs() < 1e-6);
+
+        settings.music.muted = true;
+        assert_eq!(settings.channel_gain(MixerChannel::Music, 1.0), 0.0);
+        settings.music.muted = false;
+        settings.master.muted = true;
+        assert_eq!(settings.channel_gain(MixerChannel::Sfx, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_step_duck_ramps_and_clamps() {
+        let ramp = Duration::from_millis(300);
+        let tick = Duration::from_millis(30);
+        let next = step_duck(1.0, 0.35, tick, ramp);
+        assert!((next - 0.9).abs() < 1e-6);
+        assert_eq!(step_duck(0.36, 0.35, tick, ramp), 0.35);
+        assert_eq!(step_duck(0.99, 1.0, tick, ramp), 1.0);
+        assert_eq!(step_duck(0.35, 1.0, tick, Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn test_settings_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mixer_settings.json");
+        assert_eq!(MixerSettings::load(&path).unwrap(), MixerSettings::default());
+
+        let mut settings = MixerSettings::default();
+        settings.channel_mut(MixerChannel::Ambience).volume = 0.1;
+        settings.voice.muted = true;
+        settings.save(&path).unwrap();
+        assert_eq!(MixerSettings::load(&path).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_partial_settings_fill_defaults() {
+        let settings: MixerSettings =
+            serde_json::from_str(r#"{"music":{"volume":0.2},"ducking":{"enabled":false}}"#).unwrap();
+        assert_eq!(settings.music.volume, 0.2);
+        assert!(!settings.music.muted);
+        assert!(!settings.ducking.enabled);
+        assert_eq!(settings.ducking.level, DuckingSettings::default().level);
+        assert_eq!(settings.sfx, MixerSettings::default().sfx);
+        assert!(settings.output_devices.is_empty());
+    }
+
+    #[test]
+    fn test_output_devices_roundtrip_by_channel_name() {
+        let mut settings = MixerSettings::default();
+        settings.output_devices.insert(MixerChannel::Music, "USB Speakers".to_string());
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""output_devices":{"music":"USB Speakers"}"#));
+
+        let loaded: MixerSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.output_device(MixerChannel::Music), Some("USB Speakers"));
+        assert_eq!(loaded.output_device(MixerChannel::Voice), None);
+    }
+}
@@ -1,13 +1,35 @@
 //! Audio Playback Module
 //!
 //! Handles audio playback for voice synthesis and sound effects using rodio.
+//! All output goes through the channel mixer in `mixer`. Layered ambient
+//! scenes live in the `soundscape` submodule; keyword and event-driven sound
+//! effects live in `sfx_triggers`.
+
+pub mod mixer;
+pub mod sfx_triggers;
+pub mod soundscape;
+
+pub use mixer::{
+    list_output_devices, AudioMixer, ChannelSettings, ChannelStatus, DuckingSettings,
+    MixerChannel, MixerSettings, MixerStatus, MixerTrack, OutputDeviceInfo,
+};
+
+pub use sfx_triggers::{
+    NarrationScanner, SfxEvent, SfxPlayer, SfxTrigger, SfxTriggerConfig, SfxTriggerEngine,
+    TriggerSource,
+};
+
+pub use soundscape::{
+    builtin_scenes, SoundscapeEngine, SoundscapeLayer, SoundscapePresets, SoundscapeScene,
+    SoundscapeStatus,
+};
 
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::collections::HashMap;
-use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use rodio::{Decoder, Source};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -86,16 +108,16 @@ impl Default for PlaybackState {
 // Audio Player
 // ============================================================================
 
-/// Main audio player for voice and sound effects
+/// Convenience player for voice, music, ambience, and sound effects.
+///
+/// Keeps one current voice/music/ambience track; everything is routed through
+/// the shared [`AudioMixer`], so channel volumes and ducking apply.
 pub struct AudioPlayer {
-    _stream: OutputStream,
-    stream_handle: OutputStreamHandle,
-    voice_sink: Arc<RwLock<Option<Sink>>>,
-    music_sink: Arc<RwLock<Option<Sink>>>,
-    ambience_sink: Arc<RwLock<Option<Sink>>>,
-    sfx_sinks: Arc<RwLock<Vec<Sink>>>,
-    current_track: Arc<RwLock<Option<String>>>,
-    volumes: Arc<RwLock<AudioVolumes>>,
+    mixer: AudioMixer,
+    voice: Mutex<Option<MixerTrack>>,
+    music: Mutex<Option<MixerTrack>>,
+    ambience: Mutex<Option<MixerTrack>>,
+    current_track: RwLock<Option<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -119,21 +141,35 @@ impl Default for AudioVolumes {
     }
 }
 
+/// Open and decode an audio file
+fn decode_file(path: &Path) -> Result<Decoder<BufReader<File>>> {
+    let file = File::open(path)
+        .map_err(|_| AudioError::FileNotFound(path.display().to_string()))?;
+    Decoder::new(BufReader::new(file))
+        .map_err(|e| AudioError::DecodeError(e.to_string()))
+}
+
 impl AudioPlayer {
-    pub fn new() -> Result<Self> {
-        let (stream, stream_handle) = OutputStream::try_default()
-            .map_err(|e| AudioError::OutputError(e.to_string()))?;
-
-        Ok(Self {
-            _stream: stream,
-            stream_handle,
-            voice_sink: Arc::new(RwLock::new(None)),
-            music_sink: Arc::new(RwLock::new(None)),
-            ambience_sink: Arc::new(RwLock::new(None)),
-            sfx_sinks: Arc::new(RwLock::new(Vec::new())),
-            current_track: Arc::new(RwLock::new(None)),
-            volumes: Arc::new(RwLock::new(AudioVolumes::default())),
-        })
+    pub fn new(mixer: AudioMixer) -> Self {
+        Self {
+            mixer,
+            voice: Mutex::new(None),
+            music: Mutex::new(None),
+            ambience: Mutex::new(None),
+            current_track: RwLock::new(None),
+        }
+    }
+
+    pub fn mixer(&self) -> &AudioMixer {
+        &self.mixer
+    }
+
+    /// Replace the track in `slot`, stopping the previous one
+    fn replace(slot: &Mutex<Option<MixerTrack>>, track: Option<MixerTrack>) {
+        let previous = std::mem::replace(&mut *slot.lock().unwrap(), track);
+        if let Some(previous) = previous {
+            previous.stop();
+        }
     }
 
     // ========================================================================
@@ -142,26 +178,13 @@ impl AudioPlayer {
 
     /// Play voice audio (NPC speech)
     pub fn play_voice(&self, path: impl AsRef<Path>) -> Result<()> {
-        let file = File::open(path.as_ref())
-            .map_err(|_| AudioError::FileNotFound(path.as_ref().display().to_string()))?;
-
-        let source = Decoder::new(BufReader::new(file))
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
+        let source = decode_file(path.as_ref())?;
 
         // Stop any existing voice
         self.stop_voice();
 
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.voice;
-        drop(volumes);
-
-        let sink = Sink::try_new(&self.stream_handle)
-            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
-
-        sink.set_volume(volume);
-        sink.append(source);
-
-        *self.voice_sink.write().unwrap() = Some(sink);
+        let track = self.mixer.play(MixerChannel::Voice, source, 1.0)?;
+        Self::replace(&self.voice, Some(track));
         *self.current_track.write().unwrap() = Some(path.as_ref().display().to_string());
 
         Ok(())
@@ -169,34 +192,21 @@ impl AudioPlayer {
 
     /// Stop voice playback
     pub fn stop_voice(&self) {
-        if let Some(sink) = self.voice_sink.write().unwrap().take() {
-            sink.stop();
-        }
+        Self::replace(&self.voice, None);
         *self.current_track.write().unwrap() = None;
     }
 
     /// Check if voice is currently playing
     pub fn is_voice_playing(&self) -> bool {
-        self.voice_sink.read().unwrap()
+        self.voice.lock().unwrap()
             .as_ref()
-            .map(|s| !s.empty())
+            .map(|t| !t.is_finished())
             .unwrap_or(false)
     }
 
     /// Set voice volume (0.0 - 1.0)
     pub fn set_voice_volume(&self, volume: f32) {
-        self.volumes.write().unwrap().voice = volume.clamp(0.0, 1.0);
-        self.update_voice_volume();
-    }
-
-    fn update_voice_volume(&self) {
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.voice;
-        drop(volumes);
-
-        if let Some(sink) = self.voice_sink.read().unwrap().as_ref() {
-            sink.set_volume(volume);
-        }
+        self.mixer.set_channel_volume(MixerChannel::Voice, volume);
     }
 
     // ========================================================================
@@ -205,66 +215,34 @@ impl AudioPlayer {
 
     /// Play background music (loops)
     pub fn play_music(&self, path: impl AsRef<Path>) -> Result<()> {
-        let file = File::open(path.as_ref())
-            .map_err(|_| AudioError::FileNotFound(path.as_ref().display().to_string()))?;
-
-        let source = Decoder::new(BufReader::new(file))
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?
-            .repeat_infinite();
-
-        // Stop existing music
-        self.stop_music();
-
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.music;
-        drop(volumes);
-
-        let sink = Sink::try_new(&self.stream_handle)
-            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
-
-        sink.set_volume(volume);
-        sink.append(source);
-
-        *self.music_sink.write().unwrap() = Some(sink);
-
+        let source = decode_file(path.as_ref())?.repeat_infinite();
+        let track = self.mixer.play(MixerChannel::Music, source, 1.0)?;
+        Self::replace(&self.music, Some(track));
         Ok(())
     }
 
     /// Stop music playback
     pub fn stop_music(&self) {
-        if let Some(sink) = self.music_sink.write().unwrap().take() {
-            sink.stop();
-        }
+        Self::replace(&self.music, None);
     }
 
     /// Pause music
     pub fn pause_music(&self) {
-        if let Some(sink) = self.music_sink.read().unwrap().as_ref() {
-            sink.pause();
+        if let Some(track) = self.music.lock().unwrap().as_ref() {
+            track.pause();
         }
     }
 
     /// Resume music
     pub fn resume_music(&self) {
-        if let Some(sink) = self.music_sink.read().unwrap().as_ref() {
-            sink.play();
+        if let Some(track) = self.music.lock().unwrap().as_ref() {
+            track.resume();
         }
     }
 
     /// Set music volume (0.0 - 1.0)
     pub fn set_music_volume(&self, volume: f32) {
-        self.volumes.write().unwrap().music = volume.clamp(0.0, 1.0);
-        self.update_music_volume();
-    }
-
-    fn update_music_volume(&self) {
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.music;
-        drop(volumes);
-
-        if let Some(sink) = self.music_sink.read().unwrap().as_ref() {
-            sink.set_volume(volume);
-        }
+        self.mixer.set_channel_volume(MixerChannel::Music, volume);
     }
 
     // ========================================================================
@@ -273,52 +251,20 @@ impl AudioPlayer {
 
     /// Play ambient sounds (loops)
     pub fn play_ambience(&self, path: impl AsRef<Path>) -> Result<()> {
-        let file = File::open(path.as_ref())
-            .map_err(|_| AudioError::FileNotFound(path.as_ref().display().to_string()))?;
-
-        let source = Decoder::new(BufReader::new(file))
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?
-            .repeat_infinite();
-
-        // Stop existing ambience
-        self.stop_ambience();
-
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.ambience;
-        drop(volumes);
-
-        let sink = Sink::try_new(&self.stream_handle)
-            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
-
-        sink.set_volume(volume);
-        sink.append(source);
-
-        *self.ambience_sink.write().unwrap() = Some(sink);
-
+        let source = decode_file(path.as_ref())?.repeat_infinite();
+        let track = self.mixer.play(MixerChannel::Ambience, source, 1.0)?;
+        Self::replace(&self.ambience, Some(track));
         Ok(())
     }
 
     /// Stop ambience playback
     pub fn stop_ambience(&self) {
-        if let Some(sink) = self.ambience_sink.write().unwrap().take() {
-            sink.stop();
-        }
+        Self::replace(&self.ambience, None);
     }
 
     /// Set ambience volume (0.0 - 1.0)
     pub fn set_ambience_volume(&self, volume: f32) {
-        self.volumes.write().unwrap().ambience = volume.clamp(0.0, 1.0);
-        self.update_ambience_volume();
-    }
-
-    fn update_ambience_volume(&self) {
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.ambience;
-        drop(volumes);
-
-        if let Some(sink) = self.ambience_sink.read().unwrap().as_ref() {
-            sink.set_volume(volume);
-        }
+        self.mixer.set_channel_volume(MixerChannel::Ambience, volume);
     }
 
     // ========================================================================
@@ -327,35 +273,14 @@ impl AudioPlayer {
 
     /// Play a sound effect (fire and forget)
     pub fn play_sfx(&self, path: impl AsRef<Path>) -> Result<()> {
-        let file = File::open(path.as_ref())
-            .map_err(|_| AudioError::FileNotFound(path.as_ref().display().to_string()))?;
-
-        let source = Decoder::new(BufReader::new(file))
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
-
-        let volumes = self.volumes.read().unwrap();
-        let volume = volumes.master * volumes.sfx;
-        drop(volumes);
-
-        let sink = Sink::try_new(&self.stream_handle)
-            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
-
-        sink.set_volume(volume);
-        sink.append(source);
-
-        // Clean up finished sinks
-        {
-            let mut sinks = self.sfx_sinks.write().unwrap();
-            sinks.retain(|s| !s.empty());
-            sinks.push(sink);
-        }
-
+        let source = decode_file(path.as_ref())?;
+        self.mixer.play(MixerChannel::Sfx, source, 1.0)?;
         Ok(())
     }
 
     /// Set SFX volume (0.0 - 1.0)
     pub fn set_sfx_volume(&self, volume: f32) {
-        self.volumes.write().unwrap().sfx = volume.clamp(0.0, 1.0);
+        self.mixer.set_channel_volume(MixerChannel::Sfx, volume);
     }
 
     // ========================================================================
@@ -364,19 +289,12 @@ impl AudioPlayer {
 
     /// Set master volume (0.0 - 1.0)
     pub fn set_master_volume(&self, volume: f32) {
-        self.volumes.write().unwrap().master = volume.clamp(0.0, 1.0);
-        self.update_all_volumes();
-    }
-
-    fn update_all_volumes(&self) {
-        self.update_voice_volume();
-        self.update_music_volume();
-        self.update_ambience_volume();
+        self.mixer.set_master_volume(volume);
     }
 
     /// Get current volume settings
     pub fn get_volumes(&self) -> AudioVolumes {
-        self.volumes.read().unwrap().clone()
+        AudioVolumes::from(&self.mixer.settings())
     }
 
     /// Stop all audio
@@ -384,42 +302,30 @@ impl AudioPlayer {
         self.stop_voice();
         self.stop_music();
         self.stop_ambience();
-
-        let mut sinks = self.sfx_sinks.write().unwrap();
-        for sink in sinks.drain(..) {
-            sink.stop();
-        }
+        self.mixer.stop_all();
     }
 
     /// Mute all audio
     pub fn mute_all(&self) {
-        if let Some(sink) = self.voice_sink.read().unwrap().as_ref() {
-            sink.set_volume(0.0);
-        }
-        if let Some(sink) = self.music_sink.read().unwrap().as_ref() {
-            sink.set_volume(0.0);
-        }
-        if let Some(sink) = self.ambience_sink.read().unwrap().as_ref() {
-            sink.set_volume(0.0);
-        }
+        self.mixer.set_master_muted(true);
     }
 
     /// Unmute all audio
     pub fn unmute_all(&self) {
-        self.update_all_volumes();
+        self.mixer.set_master_muted(false);
     }
 
     /// Get current playback state
     pub fn get_state(&self) -> PlaybackState {
         let track_id = self.current_track.read().unwrap().clone();
         let is_playing = self.is_voice_playing();
-        let volumes = self.volumes.read().unwrap();
+        let settings = self.mixer.settings();
 
         PlaybackState {
             track_id,
             is_playing,
             is_paused: false,
-            volume: volumes.master,
+            volume: settings.master.volume,
             position_ms: 0, // Would need additional tracking
             duration_ms: None,
         }
@@ -528,6 +434,31 @@ pub fn get_sfx_categories() -> Vec<String> {
     ]
 }
 
+// ============================================================================
+// Sound Library
+// ============================================================================
+
+/// Extensions tried when a sound is referenced by library key rather than path
+const AUDIO_EXTENSIONS: &[&str] = &["ogg", "mp3", "wav", "flac"];
+
+/// Resolve a sound reference to a file: an existing path, or `<library>/<key>.<ext>`
+pub fn resolve_library_file(source: &str, library_dir: &Path) -> Option<PathBuf> {
+    let direct = PathBuf::from(source);
+    if direct.is_file() {
+        return Some(direct);
+    }
+
+    let in_library = library_dir.join(source);
+    if in_library.is_file() {
+        return Some(in_library);
+    }
+
+    AUDIO_EXTENSIONS
+        .iter()
+        .map(|ext| library_dir.join(format!("{}.{}", source, ext)))
+        .find(|p| p.is_file())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -559,6 +490,18 @@ mod tests {
         assert!(categories.contains(&"spell_cast".to_string()));
     }
 
+    #[test]
+    fn test_resolve_library_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ambient_rain.ogg"), b"x").unwrap();
+
+        assert_eq!(
+            resolve_library_file("ambient_rain", dir.path()),
+            Some(dir.path().join("ambient_rain.ogg"))
+        );
+        assert_eq!(resolve_library_file("ambient_missing", dir.path()), None);
+    }
+
     // Note: Actual audio playback tests require audio hardware
     // and are best done as integration tests
 }
@@ -0,0 +1,502 @@
+//! Keyword and Event Sound Effect Triggers
+//!
+//! Maps trigger words in narration ("fireball", "the door creaks") and game
+//! events (critical hit, combatant down) to sound effects. Debounce rules keep
+//! effects from stacking: each trigger has its own cooldown, there is a global
+//! minimum gap between any two effects, and a single narration segment can
+//! fire only a limited number of effects.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use rodio::Decoder;
+use serde::{Deserialize, Serialize};
+
+use super::{resolve_library_file, AudioError, AudioMixer, MixerChannel, Result};
+
+// ============================================================================
+// Trigger Types
+// ============================================================================
+
+/// Game events that can trigger a sound effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SfxEvent {
+    CombatStart,
+    CombatEnd,
+    TurnStart,
+    Damage,
+    Healing,
+    /// A combatant dropped to 0 HP
+    Death,
+    ConditionApplied,
+    ConditionRemoved,
+    /// Natural 20 on a d20
+    CriticalHit,
+    /// Natural 1 on a d20
+    CriticalMiss,
+    DiceRoll,
+}
+
+/// What fires a trigger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TriggerSource {
+    /// Whole-word, case-insensitive phrase in narration text
+    Keyword { phrase: String },
+    /// A game event
+    Event { event: SfxEvent },
+}
+
+/// A mapping from a keyword or event to a sound effect
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SfxTrigger {
+    pub id: String,
+    pub source: TriggerSource,
+    /// File path, or a key resolved against the SFX library directory
+    pub sound: String,
+    #[serde(default = "default_volume")]
+    pub volume: f32,
+    /// Minimum time between two firings of this trigger
+    #[serde(default = "default_cooldown_ms")]
+    pub cooldown_ms: u64,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+fn default_cooldown_ms() -> u64 {
+    2000
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl SfxTrigger {
+    pub fn keyword(id: &str, phrase: &str, sound: &str) -> Self {
+        Self::new(id, TriggerSource::Keyword { phrase: phrase.to_string() }, sound)
+    }
+
+    pub fn event(id: &str, event: SfxEvent, sound: &str) -> Self {
+        Self::new(id, TriggerSource::Event { event }, sound)
+    }
+
+    fn new(id: &str, source: TriggerSource, sound: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            source,
+            sound: sound.to_string(),
+            volume: default_volume(),
+            cooldown_ms: default_cooldown_ms(),
+            enabled: true,
+        }
+    }
+}
+
+/// Trigger mappings and debounce settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SfxTriggerConfig {
+    pub triggers: Vec<SfxTrigger>,
+    /// Minimum gap between any two effects
+    #[serde(default = "default_global_cooldown_ms")]
+    pub global_cooldown_ms: u64,
+    /// Maximum effects fired from a single narration segment
+    #[serde(default = "default_max_per_segment")]
+    pub max_per_segment: usize,
+    #[serde(default = "default_true")]
+    pub narration_enabled: bool,
+    #[serde(default = "default_true")]
+    pub events_enabled: bool,
+}
+
+fn default_global_cooldown_ms() -> u64 {
+    300
+}
+
+fn default_max_per_segment() -> usize {
+    2
+}
+
+impl Default for SfxTriggerConfig {
+    fn default() -> Self {
+        Self {
+            triggers: default_triggers(),
+            global_cooldown_ms: default_global_cooldown_ms(),
+            max_per_segment: default_max_per_segment(),
+            narration_enabled: true,
+            events_enabled: true,
+        }
+    }
+}
+
+impl SfxTriggerConfig {
+    /// Load from a JSON file; a missing file yields the defaults
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| AudioError::DecodeError(e.to_string()))
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Starter mappings using the standard SFX categories
+pub fn default_triggers() -> Vec<SfxTrigger> {
+    vec![
+        SfxTrigger::event("evt-critical-hit", SfxEvent::CriticalHit, "combat_critical"),
+        SfxTrigger::event("evt-critical-miss", SfxEvent::CriticalMiss, "combat_miss"),
+        SfxTrigger::event("evt-damage", SfxEvent::Damage, "combat_hit"),
+        SfxTrigger::event("evt-healing", SfxEvent::Healing, "spell_heal"),
+        SfxTrigger::event("evt-death", SfxEvent::Death, "monster_death"),
+        SfxTrigger::event("evt-combat-end", SfxEvent::CombatEnd, "fanfare_victory"),
+        SfxTrigger::keyword("kw-critical-hit", "critical hit", "combat_critical"),
+        SfxTrigger::keyword("kw-fireball", "fireball", "spell_fire"),
+        SfxTrigger::keyword("kw-lightning", "lightning bolt", "spell_lightning"),
+        SfxTrigger::keyword("kw-door-creak", "door creaks", "door_open"),
+        SfxTrigger::keyword("kw-chest", "chest creaks open", "chest_open"),
+        SfxTrigger::keyword("kw-roar", "roars", "monster_roar"),
+    ]
+}
+
+/// Default SFX library directory
+pub fn default_sfx_library_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("ttrpg-assistant/audio/sfx")
+}
+
+// ============================================================================
+// Matching and Debounce
+// ============================================================================
+
+/// Matches narration and events against triggers, applying debounce rules
+pub struct SfxTriggerEngine {
+    config: SfxTriggerConfig,
+    /// Compiled keyword patterns, indexed like `config.triggers`
+    patterns: Vec<Option<Regex>>,
+    last_fired: HashMap<String, Instant>,
+    last_any: Option<Instant>,
+}
+
+impl Default for SfxTriggerEngine {
+    fn default() -> Self {
+        Self::new(SfxTriggerConfig::default())
+    }
+}
+
+impl SfxTriggerEngine {
+    pub fn new(config: SfxTriggerConfig) -> Self {
+        let patterns = compile_patterns(&config.triggers);
+        Self {
+            config,
+            patterns,
+            last_fired: HashMap::new(),
+            last_any: None,
+        }
+    }
+
+    pub fn config(&self) -> &SfxTriggerConfig {
+        &self.config
+    }
+
+    /// Replace the configuration (cooldown history is kept)
+    pub fn set_config(&mut self, config: SfxTriggerConfig) {
+        self.patterns = compile_patterns(&config.triggers);
+        self.config = config;
+    }
+
+    /// Triggers to fire for a game event
+    pub fn on_event(&mut self, event: SfxEvent, now: Instant) -> Vec<SfxTrigger> {
+        if !self.config.events_enabled {
+            return Vec::new();
+        }
+        let candidates: Vec<usize> = self
+            .config
+            .triggers
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.enabled && t.source == TriggerSource::Event { event })
+            .map(|(i, _)| i)
+            .collect();
+        self.fire(candidates, 1, now)
+    }
+
+    /// Triggers to fire for a segment of narration, in order of appearance
+    pub fn on_narration(&mut self, text: &str, now: Instant) -> Vec<SfxTrigger> {
+        if !self.config.narration_enabled || text.trim().is_empty() {
+            return Vec::new();
+        }
+
+        let mut hits: Vec<(usize, usize)> = self
+            .patterns
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.config.triggers[*i].enabled)
+            .filter_map(|(i, re)| re.as_ref()?.find(text).map(|m| (m.start(), i)))
+            .collect();
+        hits.sort();
+
+        let candidates = hits.into_iter().map(|(_, i)| i).collect();
+        self.fire(candidates, self.config.max_per_segment, now)
+    }
+
+    /// Apply cooldowns to candidate triggers and record the ones that fire
+    fn fire(&mut self, candidates: Vec<usize>, limit: usize, now: Instant) -> Vec<SfxTrigger> {
+        let global = Duration::from_millis(self.config.global_cooldown_ms);
+        let mut fired: Vec<SfxTrigger> = Vec::new();
+
+        for idx in candidates {
+            if fired.len() >= limit {
+                break;
+            }
+            // Only the first effect in a batch is subject to the global gap
+            if fired.is_empty() {
+                if let Some(last) = self.last_any {
+                    if now.saturating_duration_since(last) < global {
+                        break;
+                    }
+                }
+            }
+
+            let trigger = &self.config.triggers[idx];
+            let cooldown = Duration::from_millis(trigger.cooldown_ms);
+            let cooling = self
+                .last_fired
+                .get(&trigger.id)
+                .map(|last| now.saturating_duration_since(*last) < cooldown)
+                .unwrap_or(false);
+            // Two triggers mapped to the same sound don't double up
+            let duplicate_sound = fired.iter().any(|f| f.sound == trigger.sound);
+            if cooling || duplicate_sound {
+                continue;
+            }
+
+            self.last_fired.insert(trigger.id.clone(), now);
+            fired.push(trigger.clone());
+        }
+
+        if !fired.is_empty() {
+            self.last_any = Some(now);
+        }
+        fired
+    }
+}
+
+fn compile_patterns(triggers: &[SfxTrigger]) -> Vec<Option<Regex>> {
+    triggers
+        .iter()
+        .map(|t| match &t.source {
+            TriggerSource::Keyword { phrase } if !phrase.trim().is_empty() => {
+                let words: Vec<String> = phrase.split_whitespace().map(regex::escape).collect();
+                Regex::new(&format!(r"(?i)\b{}\b", words.join(r"\s+"))).ok()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// ============================================================================
+// Narration Segmentation
+// ============================================================================
+
+/// Collects streamed narration and releases it a sentence at a time, so
+/// trigger words split across chunks are still matched and effects land
+/// close to the text that caused them.
+#[derive(Debug, Default)]
+pub struct NarrationScanner {
+    pending: String,
+}
+
+impl NarrationScanner {
+    /// Upper bound on buffered text before it is released without a boundary
+    const MAX_PENDING: usize = 400;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk; returns completed text up to the last sentence boundary
+    pub fn push(&mut self, chunk: &str) -> Option<String> {
+        self.pending.push_str(chunk);
+
+        let boundary = self
+            .pending
+            .rfind(['.', '!', '?', '\n'])
+            .map(|i| i + 1)
+            .or_else(|| {
+                (self.pending.len() > Self::MAX_PENDING)
+                    .then(|| self.pending.rfind(' '))
+                    .flatten()
+            })?;
+
+        let rest = self.pending.split_off(boundary);
+        let ready = std::mem::replace(&mut self.pending, rest);
+        Some(ready)
+    }
+
+    /// Release whatever is left
+    pub fn finish(&mut self) -> Option<String> {
+        let rest = std::mem::take(&mut self.pending);
+        (!rest.trim().is_empty()).then_some(rest)
+    }
+}
+
+// ============================================================================
+// SFX Playback
+// ============================================================================
+
+/// Fire-and-forget sound effect playback on the mixer's SFX channel
+pub struct SfxPlayer {
+    mixer: AudioMixer,
+    library_dir: PathBuf,
+}
+
+impl SfxPlayer {
+    pub fn new(mixer: AudioMixer, library_dir: PathBuf) -> Self {
+        Self { mixer, library_dir }
+    }
+
+    pub fn library_dir(&self) -> &Path {
+        &self.library_dir
+    }
+
+    /// Play a sound by path or library key
+    pub fn play(&self, sound: &str, volume: f32) -> Result<PathBuf> {
+        let path = resolve_library_file(sound, &self.library_dir)
+            .ok_or_else(|| AudioError::FileNotFound(sound.to_string()))?;
+        let file = File::open(&path)?;
+        let source = Decoder::new(BufReader::new(file))
+            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
+        self.mixer.play(MixerChannel::Sfx, source, volume)?;
+        Ok(path)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine(triggers: Vec<SfxTrigger>) -> SfxTriggerEngine {
+        SfxTriggerEngine::new(SfxTriggerConfig {
+            triggers,
+            global_cooldown_ms: 300,
+            max_per_segment: 2,
+            narration_enabled: true,
+            events_enabled: true,
+        })
+    }
+
+    #[test]
+    fn test_keyword_whole_word_case_insensitive() {
+        let mut e = engine(vec![SfxTrigger::keyword("fb", "fireball", "spell_fire")]);
+        let now = Instant::now();
+        assert_eq!(e.on_narration("The wizard hurls a FIREBALL!", now).len(), 1);
+
+        let mut e = engine(vec![SfxTrigger::keyword("fb", "fire", "spell_fire")]);
+        assert!(e.on_narration("A fireball explodes", now).is_empty());
+    }
+
+    #[test]
+    fn test_multi_word_phrase_spans_whitespace() {
+        let mut e = engine(vec![SfxTrigger::keyword("door", "door creaks", "door_open")]);
+        assert_eq!(e.on_narration("The door\ncreaks open.", Instant::now()).len(), 1);
+    }
+
+    #[test]
+    fn test_per_trigger_cooldown() {
+        let mut e = engine(vec![SfxTrigger::event("hit", SfxEvent::Damage, "combat_hit")]);
+        let t0 = Instant::now();
+        assert_eq!(e.on_event(SfxEvent::Damage, t0).len(), 1);
+        assert!(e.on_event(SfxEvent::Damage, t0 + Duration::from_millis(500)).is_empty());
+        assert_eq!(e.on_event(SfxEvent::Damage, t0 + Duration::from_millis(2500)).len(), 1);
+    }
+
+    #[test]
+    fn test_global_cooldown() {
+        let mut e = engine(vec![
+            SfxTrigger::event("hit", SfxEvent::Damage, "combat_hit"),
+            SfxTrigger::event("heal", SfxEvent::Healing, "spell_heal"),
+        ]);
+        let t0 = Instant::now();
+        assert_eq!(e.on_event(SfxEvent::Damage, t0).len(), 1);
+        assert!(e.on_event(SfxEvent::Healing, t0 + Duration::from_millis(100)).is_empty());
+        assert_eq!(e.on_event(SfxEvent::Healing, t0 + Duration::from_millis(400)).len(), 1);
+    }
+
+    #[test]
+    fn test_segment_limit_and_order() {
+        let mut e = engine(vec![
+            SfxTrigger::keyword("a", "roars", "monster_roar"),
+            SfxTrigger::keyword("b", "fireball", "spell_fire"),
+            SfxTrigger::keyword("c", "door creaks", "door_open"),
+        ]);
+        let fired = e.on_narration(
+            "A fireball lights the hall, the door creaks, and the beast roars.",
+            Instant::now(),
+        );
+        let ids: Vec<_> = fired.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_disabled_triggers_and_switches() {
+        let mut trigger = SfxTrigger::keyword("fb", "fireball", "spell_fire");
+        trigger.enabled = false;
+        let mut e = engine(vec![trigger]);
+        assert!(e.on_narration("fireball", Instant::now()).is_empty());
+
+        let config = SfxTriggerConfig {
+            events_enabled: false,
+            ..Default::default()
+        };
+        let mut e = SfxTriggerEngine::new(config);
+        assert!(e.on_event(SfxEvent::CriticalHit, Instant::now()).is_empty());
+    }
+
+    #[test]
+    fn test_narration_scanner() {
+        let mut scanner = NarrationScanner::new();
+        assert_eq!(scanner.push("The fire"), None);
+        assert_eq!(scanner.push("ball hits. The orc"), Some("The fireball hits.".to_string()));
+        assert_eq!(scanner.finish(), Some(" The orc".to_string()));
+        assert_eq!(scanner.finish(), None);
+    }
+
+    #[test]
+    fn test_config_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sfx.json");
+        let config = SfxTriggerConfig {
+            max_per_segment: 5,
+            ..Default::default()
+        };
+        config.save(&path).unwrap();
+
+        let loaded = SfxTriggerConfig::load(&path).unwrap();
+        assert_eq!(loaded.max_per_segment, 5);
+        assert_eq!(loaded.triggers, config.triggers);
+    }
+}
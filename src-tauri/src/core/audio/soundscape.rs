@@ -0,0 +1,634 @@
+//! Ambient Soundscape Engine
+//!
+//! Plays layered ambient loops (e.g., tavern chatter + crackling fire) with
+//! per-layer volume and timed crossfades between scenes. Layers play on the
+//! mixer's ambience channel; fades are driven by a dedicated engine thread,
+//! and the engine handle only sends commands and reads a shared status
+//! snapshot.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use rodio::{Decoder, Source};
+use serde::{Deserialize, Serialize};
+
+use super::{resolve_library_file, AudioError, AudioMixer, MixerChannel, MixerTrack, Result};
+
+/// Default fade used by `play` when switching scenes
+pub const DEFAULT_FADE_MS: u64 = 500;
+
+/// Default crossfade duration between scenes
+pub const DEFAULT_CROSSFADE_MS: u64 = 3000;
+
+/// How often fades are advanced on the audio thread
+const FADE_TICK: Duration = Duration::from_millis(25);
+
+// ============================================================================
+// Scene Types
+// ============================================================================
+
+/// A single looping layer in a scene
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SoundscapeLayer {
+    pub id: String,
+    pub name: String,
+    /// File path, or a key resolved against the ambience library directory
+    pub source: String,
+    /// Layer volume (0.0 - 1.0)
+    pub volume: f32,
+}
+
+impl SoundscapeLayer {
+    pub fn new(id: &str, name: &str, source: &str, volume: f32) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            source: source.to_string(),
+            volume: volume.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// A named set of layers played together
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SoundscapeScene {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub layers: Vec<SoundscapeLayer>,
+    /// Scene volume applied on top of each layer's volume
+    #[serde(default = "default_scene_volume")]
+    pub volume: f32,
+    /// True for scenes shipped with the app
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+fn default_scene_volume() -> f32 {
+    1.0
+}
+
+/// Built-in scenes. Layer sources are keys in the ambience library directory.
+pub fn builtin_scenes() -> Vec<SoundscapeScene> {
+    let scene = |id: &str, name: &str, description: &str, layers: Vec<SoundscapeLayer>| {
+        SoundscapeScene {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            layers,
+            volume: 1.0,
+            builtin: true,
+        }
+    };
+
+    vec![
+        scene("tavern", "Tavern", "Busy common room with a roaring hearth", vec![
+            SoundscapeLayer::new("crowd", "Crowd Chatter", "ambient_tavern", 0.7),
+            SoundscapeLayer::new("fire", "Hearth Fire", "ambient_fire", 0.4),
+            SoundscapeLayer::new("mugs", "Clinking Mugs", "ambient_mugs", 0.25),
+        ]),
+        scene("forest", "Forest", "Daytime woodland with birdsong and wind", vec![
+            SoundscapeLayer::new("birds", "Birdsong", "ambient_forest", 0.6),
+            SoundscapeLayer::new("wind", "Wind in Leaves", "ambient_wind", 0.35),
+            SoundscapeLayer::new("stream", "Distant Stream", "ambient_stream", 0.2),
+        ]),
+        scene("dungeon", "Dungeon", "Damp stone corridors and distant echoes", vec![
+            SoundscapeLayer::new("drone", "Low Drone", "ambient_dungeon", 0.5),
+            SoundscapeLayer::new("drips", "Water Drips", "ambient_drips", 0.3),
+            SoundscapeLayer::new("torches", "Torches", "ambient_fire", 0.15),
+        ]),
+        scene("storm", "Storm", "Heavy rain with rolling thunder", vec![
+            SoundscapeLayer::new("rain", "Heavy Rain", "ambient_rain", 0.7),
+            SoundscapeLayer::new("wind", "Howling Wind", "ambient_wind", 0.45),
+            SoundscapeLayer::new("thunder", "Thunder", "ambient_thunder", 0.5),
+        ]),
+    ]
+}
+
+/// Default ambience library directory
+pub fn default_library_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("ttrpg-assistant/audio/ambience")
+}
+
+// ============================================================================
+// Per-Campaign Presets
+// ============================================================================
+
+/// Scene presets saved per campaign
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SoundscapePresets {
+    #[serde(default)]
+    pub campaigns: HashMap<String, Vec<SoundscapeScene>>,
+}
+
+impl SoundscapePresets {
+    /// Load presets from a JSON file; a missing file yields empty presets
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| AudioError::DecodeError(e.to_string()))
+    }
+
+    /// Save presets to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| AudioError::PlaybackError(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Built-in scenes followed by the campaign's saved scenes.
+    /// A saved scene with a built-in's ID overrides it.
+    pub fn scenes_for(&self, campaign_id: Option<&str>) -> Vec<SoundscapeScene> {
+        let saved = campaign_id
+            .and_then(|id| self.campaigns.get(id))
+            .cloned()
+            .unwrap_or_default();
+
+        let mut scenes: Vec<SoundscapeScene> = builtin_scenes()
+            .into_iter()
+            .filter(|b| !saved.iter().any(|s| s.id == b.id))
+            .collect();
+        scenes.extend(saved);
+        scenes
+    }
+
+    /// Find a scene by ID for a campaign (saved presets first, then built-ins)
+    pub fn find(&self, campaign_id: Option<&str>, scene_id: &str) -> Option<SoundscapeScene> {
+        self.scenes_for(campaign_id).into_iter().find(|s| s.id == scene_id)
+    }
+
+    /// Insert or replace a campaign scene preset
+    pub fn upsert(&mut self, campaign_id: &str, mut scene: SoundscapeScene) {
+        scene.builtin = false;
+        let scenes = self.campaigns.entry(campaign_id.to_string()).or_default();
+        match scenes.iter_mut().find(|s| s.id == scene.id) {
+            Some(existing) => *existing = scene,
+            None => scenes.push(scene),
+        }
+    }
+
+    /// Remove a campaign scene preset; returns true if one was removed
+    pub fn remove(&mut self, campaign_id: &str, scene_id: &str) -> bool {
+        let Some(scenes) = self.campaigns.get_mut(campaign_id) else {
+            return false;
+        };
+        let before = scenes.len();
+        scenes.retain(|s| s.id != scene_id);
+        before != scenes.len()
+    }
+}
+
+// ============================================================================
+// Status
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerStatus {
+    pub id: String,
+    pub name: String,
+    pub volume: f32,
+}
+
+/// Snapshot of what the soundscape engine is playing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundscapeStatus {
+    pub scene_id: Option<String>,
+    pub scene_name: Option<String>,
+    pub layers: Vec<LayerStatus>,
+    /// Layers from the scene file that could not be found
+    pub missing_layers: Vec<String>,
+    pub crossfading: bool,
+    pub master_volume: f32,
+}
+
+impl Default for SoundscapeStatus {
+    fn default() -> Self {
+        Self {
+            scene_id: None,
+            scene_name: None,
+            layers: Vec::new(),
+            missing_layers: Vec::new(),
+            crossfading: false,
+            master_volume: 1.0,
+        }
+    }
+}
+
+// ============================================================================
+// Fades
+// ============================================================================
+
+/// A linear gain ramp
+#[derive(Debug, Clone, Copy)]
+struct Fade {
+    from: f32,
+    to: f32,
+    started: Instant,
+    duration: Duration,
+}
+
+impl Fade {
+    fn new(from: f32, to: f32, duration: Duration) -> Self {
+        Self { from, to, started: Instant::now(), duration }
+    }
+
+    fn gain(&self) -> f32 {
+        fade_gain(self.from, self.to, self.started.elapsed(), self.duration)
+    }
+
+    fn is_done(&self) -> bool {
+        self.started.elapsed() >= self.duration
+    }
+}
+
+/// Gain at `elapsed` along a linear ramp from `from` to `to`
+fn fade_gain(from: f32, to: f32, elapsed: Duration, duration: Duration) -> f32 {
+    if duration.is_zero() || elapsed >= duration {
+        return to;
+    }
+    let t = elapsed.as_secs_f32() / duration.as_secs_f32();
+    from + (to - from) * t
+}
+
+// ============================================================================
+// Audio Thread
+// ============================================================================
+
+/// A layer with its file already resolved
+#[derive(Debug, Clone)]
+struct ResolvedLayer {
+    layer: SoundscapeLayer,
+    path: PathBuf,
+}
+
+enum EngineCommand {
+    Crossfade { scene: SoundscapeScene, layers: Vec<ResolvedLayer>, duration: Duration },
+    SetLayerVolume { layer_id: String, volume: f32 },
+    SetMasterVolume(f32),
+    Stop { duration: Duration },
+}
+
+/// A playing layer
+struct Voice {
+    layer: SoundscapeLayer,
+    track: MixerTrack,
+    gain: f32,
+    fade: Option<Fade>,
+}
+
+impl Voice {
+    fn apply_volume(&self, scene_volume: f32, master: f32) {
+        self.track.set_gain(self.layer.volume * scene_volume * master * self.gain);
+    }
+}
+
+struct EngineThread {
+    rx: mpsc::Receiver<EngineCommand>,
+    status: Arc<RwLock<SoundscapeStatus>>,
+    mixer: AudioMixer,
+    scene: Option<SoundscapeScene>,
+    active: Vec<Voice>,
+    /// Voices from the previous scene fading out, with that scene's volume
+    outgoing: Vec<(Voice, f32)>,
+    master: f32,
+}
+
+impl EngineThread {
+    fn run(mut self) {
+        loop {
+            let timeout = if self.is_fading() { FADE_TICK } else { Duration::from_secs(3600) };
+            match self.rx.recv_timeout(timeout) {
+                Ok(command) => self.handle(command),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            self.tick();
+        }
+
+        for voice in self.active.drain(..) {
+            voice.track.stop();
+        }
+        for (voice, _) in self.outgoing.drain(..) {
+            voice.track.stop();
+        }
+    }
+
+    fn is_fading(&self) -> bool {
+        !self.outgoing.is_empty() || self.active.iter().any(|v| v.fade.is_some())
+    }
+
+    fn handle(&mut self, command: EngineCommand) {
+        match command {
+            EngineCommand::Crossfade { scene, layers, duration } => {
+                self.fade_out_current(duration);
+                self.start_scene(scene, layers, duration);
+            }
+            EngineCommand::SetLayerVolume { layer_id, volume } => {
+                let scene_volume = self.scene_volume();
+                for voice in self.active.iter_mut().filter(|v| v.layer.id == layer_id) {
+                    voice.layer.volume = volume.clamp(0.0, 1.0);
+                    voice.apply_volume(scene_volume, self.master);
+                }
+                if let Some(scene) = self.scene.as_mut() {
+                    for layer in scene.layers.iter_mut().filter(|l| l.id == layer_id) {
+                        layer.volume = volume.clamp(0.0, 1.0);
+                    }
+                }
+            }
+            EngineCommand::SetMasterVolume(volume) => {
+                self.master = volume.clamp(0.0, 1.0);
+                let scene_volume = self.scene_volume();
+                for voice in &self.active {
+                    voice.apply_volume(scene_volume, self.master);
+                }
+            }
+            EngineCommand::Stop { duration } => {
+                self.fade_out_current(duration);
+                self.scene = None;
+            }
+        }
+        self.publish_status();
+    }
+
+    fn scene_volume(&self) -> f32 {
+        self.scene.as_ref().map(|s| s.volume).unwrap_or(1.0)
+    }
+
+    fn fade_out_current(&mut self, duration: Duration) {
+        let scene_volume = self.scene_volume();
+        for mut voice in self.active.drain(..) {
+            voice.fade = Some(Fade::new(voice.gain, 0.0, duration));
+            self.outgoing.push((voice, scene_volume));
+        }
+    }
+
+    fn start_scene(&mut self, scene: SoundscapeScene, layers: Vec<ResolvedLayer>, duration: Duration) {
+        let mut voices = Vec::new();
+        for resolved in layers {
+            let source = File::open(&resolved.path)
+                .map_err(|e| e.to_string())
+                .and_then(|f| Decoder::new(BufReader::new(f)).map_err(|e| e.to_string()));
+            let track = self
+                .mixer
+                .track(MixerChannel::Ambience, 0.0)
+                .map_err(|e| e.to_string());
+
+            match (source, track) {
+                (Ok(source), Ok(track)) => {
+                    let voice = Voice {
+                        layer: resolved.layer,
+                        track,
+                        gain: 0.0,
+                        fade: Some(Fade::new(0.0, 1.0, duration)),
+                    };
+                    voice.apply_volume(scene.volume, self.master);
+                    voice.track.append(source.repeat_infinite());
+                    voices.push(voice);
+                }
+                (Err(e), _) | (_, Err(e)) => {
+                    log::warn!("Skipping soundscape layer '{}': {}", resolved.layer.name, e);
+                }
+            }
+        }
+
+        self.active = voices;
+        self.scene = Some(scene);
+    }
+
+    /// Advance fades and drop finished outgoing voices
+    fn tick(&mut self) {
+        if !self.is_fading() {
+            return;
+        }
+
+        let scene_volume = self.scene_volume();
+        for voice in &mut self.active {
+            if let Some(fade) = voice.fade {
+                voice.gain = fade.gain();
+                if fade.is_done() {
+                    voice.fade = None;
+                }
+            }
+            voice.apply_volume(scene_volume, self.master);
+        }
+
+        let master = self.master;
+        self.outgoing.retain_mut(|(voice, volume)| {
+            let done = voice.fade.map(|f| f.is_done()).unwrap_or(true);
+            voice.gain = voice.fade.map(|f| f.gain()).unwrap_or(0.0);
+            voice.apply_volume(*volume, master);
+            if done {
+                voice.track.stop();
+            }
+            !done
+        });
+
+        if !self.is_fading() {
+            self.publish_status();
+        }
+    }
+
+    fn publish_status(&self) {
+        let Ok(mut status) = self.status.write() else {
+            return;
+        };
+        status.scene_id = self.scene.as_ref().map(|s| s.id.clone());
+        status.scene_name = self.scene.as_ref().map(|s| s.name.clone());
+        status.layers = self
+            .active
+            .iter()
+            .map(|v| LayerStatus {
+                id: v.layer.id.clone(),
+                name: v.layer.name.clone(),
+                volume: v.layer.volume,
+            })
+            .collect();
+        status.crossfading = self.is_fading();
+        status.master_volume = self.master;
+    }
+}
+
+// ============================================================================
+// Engine Handle
+// ============================================================================
+
+/// Handle to the soundscape audio thread
+pub struct SoundscapeEngine {
+    tx: mpsc::Sender<EngineCommand>,
+    status: Arc<RwLock<SoundscapeStatus>>,
+    library_dir: PathBuf,
+}
+
+impl SoundscapeEngine {
+    /// Spawn the engine thread; layers play on the mixer's ambience channel
+    pub fn new(mixer: AudioMixer, library_dir: PathBuf) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let status = Arc::new(RwLock::new(SoundscapeStatus::default()));
+
+        let thread = EngineThread {
+            rx,
+            status: status.clone(),
+            mixer,
+            scene: None,
+            active: Vec::new(),
+            outgoing: Vec::new(),
+            master: 1.0,
+        };
+        std::thread::Builder::new()
+            .name("soundscape".to_string())
+            .spawn(move || thread.run())
+            .expect("failed to spawn soundscape thread");
+
+        Self { tx, status, library_dir }
+    }
+
+    pub fn library_dir(&self) -> &Path {
+        &self.library_dir
+    }
+
+    /// Switch to a scene with a short fade
+    pub fn play(&self, scene: SoundscapeScene) -> Result<SoundscapeStatus> {
+        self.crossfade(scene, Duration::from_millis(DEFAULT_FADE_MS))
+    }
+
+    /// Crossfade from the current scene to a new one over `duration`
+    pub fn crossfade(&self, scene: SoundscapeScene, duration: Duration) -> Result<SoundscapeStatus> {
+        let mut layers = Vec::new();
+        let mut missing = Vec::new();
+        for layer in &scene.layers {
+            match resolve_library_file(&layer.source, &self.library_dir) {
+                Some(path) => layers.push(ResolvedLayer { layer: layer.clone(), path }),
+                None => missing.push(layer.source.clone()),
+            }
+        }
+
+        if layers.is_empty() {
+            return Err(AudioError::FileNotFound(format!(
+                "No audio found for scene '{}' (looked for: {}) in {}",
+                scene.name,
+                missing.join(", "),
+                self.library_dir.display()
+            )));
+        }
+        if !missing.is_empty() {
+            log::warn!("Soundscape '{}' missing layers: {}", scene.name, missing.join(", "));
+        }
+
+        if let Ok(mut status) = self.status.write() {
+            status.missing_layers = missing;
+        }
+        self.send(EngineCommand::Crossfade { scene, layers, duration })?;
+        Ok(self.status())
+    }
+
+    /// Fade out and stop the current scene
+    pub fn stop(&self, fade: Duration) -> Result<()> {
+        self.send(EngineCommand::Stop { duration: fade })
+    }
+
+    /// Set the volume of one layer in the current scene
+    pub fn set_layer_volume(&self, layer_id: &str, volume: f32) -> Result<()> {
+        self.send(EngineCommand::SetLayerVolume {
+            layer_id: layer_id.to_string(),
+            volume,
+        })
+    }
+
+    /// Set the overall soundscape volume
+    pub fn set_master_volume(&self, volume: f32) -> Result<()> {
+        self.send(EngineCommand::SetMasterVolume(volume))
+    }
+
+    /// Current playback snapshot
+    pub fn status(&self) -> SoundscapeStatus {
+        self.status.read().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    fn send(&self, command: EngineCommand) -> Result<()> {
+        self.tx
+            .send(command)
+            .map_err(|_| AudioError::PlaybackError("Soundscape thread has stopped".to_string()))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_gain() {
+        let d = Duration::from_millis(1000);
+        assert_eq!(fade_gain(0.0, 1.0, Duration::ZERO, d), 0.0);
+        assert!((fade_gain(0.0, 1.0, Duration::from_millis(500), d) - 0.5).abs() < 1e-6);
+        assert_eq!(fade_gain(1.0, 0.0, Duration::from_millis(1500), d), 0.0);
+        assert_eq!(fade_gain(0.3, 1.0, Duration::ZERO, Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn test_builtin_scenes() {
+        let scenes = builtin_scenes();
+        for id in ["tavern", "forest", "dungeon", "storm"] {
+            let scene = scenes.iter().find(|s| s.id == id).expect(id);
+            assert!(scene.builtin);
+            assert!(!scene.layers.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_presets_override_and_remove() {
+        let mut presets = SoundscapePresets::default();
+        let mut tavern = builtin_scenes().into_iter().find(|s| s.id == "tavern").unwrap();
+        tavern.volume = 0.5;
+        presets.upsert("camp-1", tavern);
+
+        let found = presets.find(Some("camp-1"), "tavern").unwrap();
+        assert_eq!(found.volume, 0.5);
+        assert!(!found.builtin);
+        assert_eq!(presets.scenes_for(Some("camp-1")).iter().filter(|s| s.id == "tavern").count(), 1);
+
+        // Other campaigns still see the built-in
+        assert!(presets.find(Some("camp-2"), "tavern").unwrap().builtin);
+
+        assert!(presets.remove("camp-1", "tavern"));
+        assert!(presets.find(Some("camp-1"), "tavern").unwrap().builtin);
+    }
+
+    #[test]
+    fn test_presets_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("soundscapes.json");
+
+        let mut presets = SoundscapePresets::default();
+        presets.upsert("camp-1", SoundscapeScene {
+            id: "crypt".to_string(),
+            name: "Crypt".to_string(),
+            description: None,
+            layers: vec![SoundscapeLayer::new("drone", "Drone", "ambient_dungeon", 0.4)],
+            volume: 1.0,
+            builtin: false,
+        });
+        presets.save(&path).unwrap();
+
+        let loaded = SoundscapePresets::load(&path).unwrap();
+        assert_eq!(loaded.find(Some("camp-1"), "crypt").unwrap().layers.len(), 1);
+        assert!(SoundscapePresets::load(&dir.path().join("missing.json")).unwrap().campaigns.is_empty());
+    }
+}
@@ -0,0 +1,733 @@
+//! Campaign Archive
+//!
+//! Single-file backup of a campaign and everything attached to it: the
+//! campaign with its snapshots and notes, sessions (timelines, session notes,
+//! read-aloud passages, spoken line log), NPCs, locations, relationships,
+//! world state, personality context, and linked voice profiles.
+//!
+//! An archive is a zip file with a `manifest.json` carrying the schema
+//! version, one `<section>.json` file per subsystem, and referenced audio
+//! under `assets/`. Older archives are migrated when read; schema version 1
+//! is the bare campaign JSON written by `export_campaign`.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::relationships::EntityRelationship;
+use super::templates::reissue_content_ids;
+use super::world_state::WorldState;
+use crate::core::campaign_manager::CampaignExport;
+use crate::core::location_gen::Location;
+use crate::core::npc_gen::NPC;
+use crate::core::personality::ActivePersonalityContext;
+use crate::core::session::{SessionNote, TimelineEvent};
+use crate::core::session_manager::GameSession;
+use crate::core::voice::{SessionReadAloud, SessionUtterance, VoiceProfile};
+
+/// Current archive schema version
+pub const ARCHIVE_SCHEMA_VERSION: u32 = 2;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const ASSETS_PREFIX: &str = "assets/";
+const ZIP_MAGIC: &[u8; 4] = b"PK\x03\x04";
+
+/// Section files every current archive contains
+const SECTIONS: [&str; 9] = [
+    "campaign",
+    "sessions",
+    "npcs",
+    "locations",
+    "relationships",
+    "world_state",
+    "personality",
+    "voice_profiles",
+    "npc_voice_profiles",
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Unsupported archive schema version {0} (this build reads up to {ARCHIVE_SCHEMA_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Archive is missing section: {0}")]
+    MissingSection(String),
+
+    #[error("Asset failed verification: {0}")]
+    AssetMismatch(String),
+
+    #[error("Invalid archive: {0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, ArchiveError>;
+
+// ============================================================================
+// Archive Types
+// ============================================================================
+
+/// Archive metadata stored in `manifest.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub schema_version: u32,
+    /// App version that wrote the archive
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub campaign_id: String,
+    pub campaign_name: String,
+    /// Section names; each is stored as `<name>.json`
+    pub sections: Vec<String>,
+    pub assets: Vec<ArchiveAsset>,
+}
+
+/// A bundled file referenced from the archive data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveAsset {
+    /// Path inside the archive, under `assets/`
+    pub path: String,
+    pub size: u64,
+    /// Hex-encoded SHA-256 of the file contents
+    pub sha256: String,
+}
+
+/// A session with everything recorded against it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedSession {
+    pub session: GameSession,
+    #[serde(default)]
+    pub timeline: Vec<TimelineEvent>,
+    #[serde(default)]
+    pub notes: Vec<SessionNote>,
+    #[serde(default)]
+    pub read_aloud: Option<SessionReadAloud>,
+    #[serde(default)]
+    pub audio_log: Vec<SessionUtterance>,
+}
+
+/// All data for one campaign; each field is one archive section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignArchive {
+    /// Campaign with its snapshots and notes
+    pub campaign: CampaignExport,
+    pub sessions: Vec<ArchivedSession>,
+    pub npcs: Vec<NPC>,
+    pub locations: Vec<Location>,
+    pub relationships: Vec<EntityRelationship>,
+    pub world_state: Option<WorldState>,
+    pub personality: Option<ActivePersonalityContext>,
+    /// User voice profiles linked to the campaign's NPCs
+    pub voice_profiles: Vec<VoiceProfile>,
+    /// NPC ID -> voice profile ID (user profiles or presets)
+    pub npc_voice_profiles: HashMap<String, String>,
+}
+
+impl CampaignArchive {
+    /// Start an archive holding only the campaign export
+    pub fn new(campaign: CampaignExport) -> Self {
+        Self {
+            campaign,
+            sessions: Vec::new(),
+            npcs: Vec::new(),
+            locations: Vec::new(),
+            relationships: Vec::new(),
+            world_state: None,
+            personality: None,
+            voice_profiles: Vec::new(),
+            npc_voice_profiles: HashMap::new(),
+        }
+    }
+
+    pub fn campaign_id(&self) -> &str {
+        &self.campaign.campaign.id
+    }
+
+    /// Visit every file path referenced by the archive data
+    fn for_each_asset_path(&mut self, mut f: impl FnMut(&mut PathBuf)) {
+        for session in &mut self.sessions {
+            if let Some(read_aloud) = &mut session.read_aloud {
+                for passage in &mut read_aloud.passages {
+                    if let Some(path) = &mut passage.audio_path {
+                        f(path);
+                    }
+                }
+            }
+            for utterance in &mut session.audio_log {
+                f(&mut utterance.audio_path);
+            }
+        }
+    }
+
+    /// Point referenced files at archive asset paths
+    ///
+    /// Returns (archive path, local file) for each distinct file that exists.
+    fn bundle_assets(&mut self) -> Vec<(String, PathBuf)> {
+        let mut bundled: HashMap<PathBuf, String> = HashMap::new();
+        let mut assets = Vec::new();
+
+        self.for_each_asset_path(|path| {
+            if !path.is_file() {
+                return;
+            }
+            let name = bundled.entry(path.clone()).or_insert_with(|| {
+                let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("bin");
+                let name = format!("{}{}.{}", ASSETS_PREFIX, Uuid::new_v4(), extension);
+                assets.push((name.clone(), path.clone()));
+                name
+            });
+            *path = PathBuf::from(name.as_str());
+        });
+
+        assets
+    }
+
+    /// Point archive asset paths at files extracted into `dir`
+    fn resolve_assets(&mut self, dir: &Path) {
+        self.for_each_asset_path(|path| {
+            if let Some(name) = path.to_str().and_then(|p| p.strip_prefix(ASSETS_PREFIX)) {
+                *path = dir.join(name);
+            }
+        });
+    }
+
+    /// Replace the campaign's ID and every ID owned by it with fresh ones
+    ///
+    /// References between sections (relationships, world state, personality
+    /// and voice links, session notes and timelines) are rewritten to match.
+    /// Voice profile IDs are kept since profiles are shared across campaigns.
+    pub fn regenerate_ids(&mut self) {
+        let mut ids: HashMap<String, String> = HashMap::new();
+        let owned = std::iter::once(self.campaign_id().to_string())
+            .chain(self.sessions.iter().map(|s| s.session.id.clone()))
+            .chain(self.npcs.iter().map(|n| n.id.clone()))
+            .chain(self.locations.iter().map(|l| l.id.clone()));
+        for id in owned {
+            ids.entry(id).or_insert_with(|| Uuid::new_v4().to_string());
+        }
+        let remap = |id: &str| ids.get(id).cloned().unwrap_or_else(|| id.to_string());
+        let new_id = || Uuid::new_v4().to_string();
+
+        let campaign_id = remap(self.campaign_id());
+        let export = &mut self.campaign;
+        export.campaign.id = campaign_id.clone();
+        for snapshot in &mut export.snapshots {
+            snapshot.id = new_id();
+            snapshot.campaign_id = campaign_id.clone();
+            snapshot.data.id = campaign_id.clone();
+        }
+        for note in &mut export.notes {
+            note.id = new_id();
+            note.campaign_id = campaign_id.clone();
+        }
+
+        for archived in &mut self.sessions {
+            let session_id = remap(&archived.session.id);
+            archived.session.id = session_id.clone();
+            archived.session.campaign_id = campaign_id.clone();
+            for event in &mut archived.timeline {
+                event.id = new_id();
+                event.session_id = session_id.clone();
+                for entity in &mut event.entity_refs {
+                    entity.entity_id = remap(&entity.entity_id);
+                }
+            }
+            for note in &mut archived.notes {
+                note.id = new_id();
+                note.session_id = session_id.clone();
+                note.campaign_id = campaign_id.clone();
+                for link in &mut note.entity_links {
+                    link.entity_id = remap(&link.entity_id);
+                }
+            }
+            if let Some(read_aloud) = &mut archived.read_aloud {
+                read_aloud.session_id = session_id.clone();
+            }
+        }
+
+        for npc in &mut self.npcs {
+            npc.id = remap(&npc.id);
+            for relationship in &mut npc.relationships {
+                if let Some(target) = &mut relationship.target_id {
+                    *target = remap(target);
+                }
+            }
+        }
+
+        for location in &mut self.locations {
+            location.id = remap(&location.id);
+            location.campaign_id = Some(campaign_id.clone());
+            for connection in &mut location.connected_locations {
+                if let Some(target) = &mut connection.target_id {
+                    *target = remap(target);
+                }
+            }
+        }
+
+        for relationship in &mut self.relationships {
+            relationship.id = new_id();
+            relationship.campaign_id = campaign_id.clone();
+            relationship.source_id = remap(&relationship.source_id);
+            relationship.target_id = remap(&relationship.target_id);
+        }
+
+        if let Some(world) = &mut self.world_state {
+            world.campaign_id = campaign_id.clone();
+            world.locations = std::mem::take(&mut world.locations)
+                .into_values()
+                .map(|mut location| {
+                    location.location_id = remap(&location.location_id);
+                    (location.location_id.clone(), location)
+                })
+                .collect();
+            for event in &mut world.events {
+                event.id = new_id();
+                event.campaign_id = campaign_id.clone();
+                event.location_ids = event.location_ids.iter().map(|id| remap(id)).collect();
+                event.npc_ids = event.npc_ids.iter().map(|id| remap(id)).collect();
+            }
+            for relationship in &mut world.npc_relationships {
+                relationship.npc_id = remap(&relationship.npc_id);
+                relationship.target_id = remap(&relationship.target_id);
+            }
+            reissue_content_ids(world);
+        }
+
+        if let Some(context) = &mut self.personality {
+            context.campaign_id = campaign_id.clone();
+            context.session_id = context.session_id.as_deref().map(remap);
+            context.npc_personalities = std::mem::take(&mut context.npc_personalities)
+                .into_iter()
+                .map(|(npc, personality)| (remap(&npc), personality))
+                .collect();
+            context.location_personalities = std::mem::take(&mut context.location_personalities)
+                .into_iter()
+                .map(|(location, personality)| (remap(&location), personality))
+                .collect();
+        }
+
+        for profile in &mut self.voice_profiles {
+            for npc_id in &mut profile.metadata.linked_npc_ids {
+                *npc_id = remap(npc_id);
+            }
+        }
+        self.npc_voice_profiles = std::mem::take(&mut self.npc_voice_profiles)
+            .into_iter()
+            .map(|(npc, profile)| (remap(&npc), profile))
+            .collect();
+    }
+
+    /// Check that every section belongs to this campaign and IDs are unique
+    pub fn validate(&self) -> Result<()> {
+        let campaign_id = self.campaign_id();
+        let invalid = |msg: String| Err(ArchiveError::Invalid(msg));
+
+        let mut sessions = HashSet::new();
+        for archived in &self.sessions {
+            let session = &archived.session;
+            if session.campaign_id != campaign_id {
+                return invalid(format!("session {} belongs to campaign {}", session.id, session.campaign_id));
+            }
+            if !sessions.insert(session.id.as_str()) {
+                return invalid(format!("duplicate session {}", session.id));
+            }
+            let foreign_event = archived.timeline.iter().any(|e| e.session_id != session.id);
+            let foreign_note = archived.notes.iter().any(|n| n.session_id != session.id);
+            if foreign_event || foreign_note {
+                return invalid(format!("session {} contains records from another session", session.id));
+            }
+        }
+
+        if let Some(duplicate) = first_duplicate(self.npcs.iter().map(|n| n.id.as_str())) {
+            return invalid(format!("duplicate NPC {}", duplicate));
+        }
+        if let Some(duplicate) = first_duplicate(self.locations.iter().map(|l| l.id.as_str())) {
+            return invalid(format!("duplicate location {}", duplicate));
+        }
+        if let Some(location) = self
+            .locations
+            .iter()
+            .find(|l| l.campaign_id.as_deref().is_some_and(|id| id != campaign_id))
+        {
+            return invalid(format!("location {} belongs to another campaign", location.id));
+        }
+        if let Some(relationship) = self.relationships.iter().find(|r| r.campaign_id != campaign_id) {
+            return invalid(format!("relationship {} belongs to another campaign", relationship.id));
+        }
+        if self.world_state.as_ref().is_some_and(|w| w.campaign_id != campaign_id) {
+            return invalid("world state belongs to another campaign".to_string());
+        }
+        if self.personality.as_ref().is_some_and(|p| p.campaign_id != campaign_id) {
+            return invalid("personality context belongs to another campaign".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn first_duplicate<'a>(mut ids: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let mut seen = HashSet::new();
+    ids.find(|id| !seen.insert(*id))
+}
+
+// ============================================================================
+// Writing
+// ============================================================================
+
+/// Write a campaign archive to `dest`
+///
+/// Referenced audio files that exist on disk are bundled as assets; missing
+/// ones are left as plain paths.
+pub fn write_archive(archive: &CampaignArchive, dest: &Path) -> Result<ArchiveManifest> {
+    let mut archive = archive.clone();
+    let assets = archive.bundle_assets();
+
+    let sections = match serde_json::to_value(&archive)? {
+        Value::Object(map) => map,
+        _ => return Err(ArchiveError::Invalid("archive did not serialize to an object".to_string())),
+    };
+
+    let mut manifest = ArchiveManifest {
+        schema_version: ARCHIVE_SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        campaign_id: archive.campaign_id().to_string(),
+        campaign_name: archive.campaign.campaign.name.clone(),
+        sections: Vec::new(),
+        assets: Vec::new(),
+    };
+
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut zip = ZipWriter::new(File::create(dest)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for (name, value) in sections {
+        zip.start_file(format!("{}.json", name), options)?;
+        zip.write_all(&serde_json::to_vec_pretty(&value)?)?;
+        manifest.sections.push(name);
+    }
+
+    for (path, local) in assets {
+        let data = std::fs::read(&local)?;
+        zip.start_file(path.as_str(), options)?;
+        zip.write_all(&data)?;
+        manifest.assets.push(ArchiveAsset {
+            path,
+            size: data.len() as u64,
+            sha256: hex::encode(Sha256::digest(&data)),
+        });
+    }
+
+    zip.start_file(MANIFEST_FILE, options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+    zip.finish()?;
+
+    Ok(manifest)
+}
+
+// ============================================================================
+// Reading
+// ============================================================================
+
+/// Read, migrate, and validate an archive, extracting assets into `assets_dir`
+///
+/// Accepts current zip archives and legacy campaign JSON exports. The
+/// returned manifest reports the version the archive was written with.
+pub fn read_archive(path: &Path, assets_dir: &Path) -> Result<(ArchiveManifest, CampaignArchive)> {
+    let mut file = File::open(path)?;
+    if !is_zip_file(&mut file)? {
+        return read_legacy_export(file);
+    }
+
+    let mut zip = ZipArchive::new(file)?;
+    let (manifest, mut archive) = read_zip_sections(&mut zip)?;
+    extract_assets(&mut zip, &manifest.assets, assets_dir)?;
+    archive.resolve_assets(assets_dir);
+    Ok((manifest, archive))
+}
+
+/// Check that an archive reads back cleanly, including asset checksums,
+/// without extracting anything
+pub fn verify_archive(path: &Path) -> Result<ArchiveManifest> {
+    let mut file = File::open(path)?;
+    if !is_zip_file(&mut file)? {
+        return read_legacy_export(file).map(|(manifest, _)| manifest);
+    }
+
+    let mut zip = ZipArchive::new(file)?;
+    let (manifest, _) = read_zip_sections(&mut zip)?;
+    verify_assets(&mut zip, &manifest.assets)?;
+    Ok(manifest)
+}
+
+fn is_zip_file(file: &mut File) -> Result<bool> {
+    let mut magic = [0u8; 4];
+    let is_zip = file.read_exact(&mut magic).is_ok() && &magic == ZIP_MAGIC;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(is_zip)
+}
+
+/// Read the manifest and sections of a zip archive
+fn read_zip_sections<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Result<(ArchiveManifest, CampaignArchive)> {
+    let manifest: ArchiveManifest = serde_json::from_reader(zip.by_name(MANIFEST_FILE)?)?;
+    if manifest.schema_version == 0 || manifest.schema_version > ARCHIVE_SCHEMA_VERSION {
+        return Err(ArchiveError::UnsupportedVersion(manifest.schema_version));
+    }
+
+    let mut sections = Map::new();
+    for name in &manifest.sections {
+        let entry = zip
+            .by_name(&format!("{}.json", name))
+            .map_err(|_| ArchiveError::MissingSection(name.clone()))?;
+        sections.insert(name.clone(), serde_json::from_reader(entry)?);
+    }
+
+    let archive = load_sections(sections, manifest.schema_version)?;
+    if manifest.campaign_id != archive.campaign_id() {
+        return Err(ArchiveError::Invalid(format!(
+            "manifest is for campaign {} but data is for {}",
+            manifest.campaign_id,
+            archive.campaign_id()
+        )));
+    }
+    Ok((manifest, archive))
+}
+
+/// Read a bare campaign JSON export as a version 1 archive
+fn read_legacy_export(file: File) -> Result<(ArchiveManifest, CampaignArchive)> {
+    let export: Value = serde_json::from_reader(std::io::BufReader::new(file))?;
+    let mut sections = Map::new();
+    sections.insert("campaign".to_string(), export);
+
+    let archive = load_sections(sections, 1)?;
+    let manifest = ArchiveManifest {
+        schema_version: 1,
+        app_version: String::new(),
+        exported_at: archive.campaign.exported_at,
+        campaign_id: archive.campaign_id().to_string(),
+        campaign_name: archive.campaign.campaign.name.clone(),
+        sections: vec!["campaign".to_string()],
+        assets: Vec::new(),
+    };
+    Ok((manifest, archive))
+}
+
+/// Migrate raw sections to the current schema and deserialize them
+fn load_sections(mut sections: Map<String, Value>, schema_version: u32) -> Result<CampaignArchive> {
+    migrate(&mut sections, schema_version)?;
+    if let Some(missing) = SECTIONS.iter().find(|s| !sections.contains_key(**s)) {
+        return Err(ArchiveError::MissingSection(missing.to_string()));
+    }
+
+    let archive: CampaignArchive = serde_json::from_value(Value::Object(sections))?;
+    archive.validate()?;
+    Ok(archive)
+}
+
+/// Upgrade sections written with an older schema, one version at a time
+fn migrate(sections: &mut Map<String, Value>, from_version: u32) -> Result<()> {
+    if from_version == 0 || from_version > ARCHIVE_SCHEMA_VERSION {
+        return Err(ArchiveError::UnsupportedVersion(from_version));
+    }
+    for version in from_version..ARCHIVE_SCHEMA_VERSION {
+        match version {
+            1 => migrate_v1(sections),
+            _ => return Err(ArchiveError::UnsupportedVersion(version)),
+        }
+    }
+    Ok(())
+}
+
+/// Version 1 held only the campaign export; other subsystems start empty
+fn migrate_v1(sections: &mut Map<String, Value>) {
+    for (name, empty) in [
+        ("sessions", Value::Array(Vec::new())),
+        ("npcs", Value::Array(Vec::new())),
+        ("locations", Value::Array(Vec::new())),
+        ("relationships", Value::Array(Vec::new())),
+        ("world_state", Value::Null),
+        ("personality", Value::Null),
+        ("voice_profiles", Value::Array(Vec::new())),
+        ("npc_voice_profiles", Value::Object(Map::new())),
+    ] {
+        sections.entry(name).or_insert(empty);
+    }
+}
+
+/// Verify and extract bundled assets
+fn extract_assets<R: Read + Seek>(zip: &mut ZipArchive<R>, assets: &[ArchiveAsset], dir: &Path) -> Result<()> {
+    let verified = verify_assets(zip, assets)?;
+    if !verified.is_empty() {
+        std::fs::create_dir_all(dir)?;
+    }
+    for (name, data) in verified {
+        std::fs::write(dir.join(name), data)?;
+    }
+    Ok(())
+}
+
+/// Check bundled assets against their manifest entries, returning each
+/// asset's file name and contents
+fn verify_assets<'a, R: Read + Seek>(
+    zip: &mut ZipArchive<R>,
+    assets: &'a [ArchiveAsset],
+) -> Result<Vec<(&'a str, Vec<u8>)>> {
+    let mut verified = Vec::with_capacity(assets.len());
+    for asset in assets {
+        let name = asset
+            .path
+            .strip_prefix(ASSETS_PREFIX)
+            .filter(|n| !n.is_empty() && !n.contains(['/', '\\']) && !n.starts_with('.'))
+            .ok_or_else(|| ArchiveError::Invalid(format!("bad asset path {}", asset.path)))?;
+
+        let mut data = Vec::new();
+        zip.by_name(&asset.path)?.read_to_end(&mut data)?;
+        if data.len() as u64 != asset.size || hex::encode(Sha256::digest(&data)) != asset.sha256 {
+            return Err(ArchiveError::AssetMismatch(asset.path.clone()));
+        }
+        verified.push((name, data));
+    }
+    Ok(verified)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign::relationships::{EntityType, RelationshipType};
+    use crate::core::campaign_manager::CampaignManager;
+    use crate::core::session_manager::SessionManager;
+    use crate::core::voice::ReadAloudRequest;
+
+    fn sample_archive(audio: &Path) -> CampaignArchive {
+        let campaigns = CampaignManager::new();
+        let campaign = campaigns.create_campaign("Archive Test", "D&D 5e");
+        campaigns.add_note(&campaign.id, "Remember the bridge", vec![], None);
+        let mut archive = CampaignArchive::new(campaigns.export_campaign(&campaign.id).unwrap());
+
+        let sessions = SessionManager::new();
+        let session = sessions.start_session(&campaign.id, 1);
+        let mut read_aloud = SessionReadAloud::new(&session.id);
+        let ids = read_aloud.add_passages(
+            vec![ReadAloudRequest { title: None, text: "The door creaks.".to_string(), voice_id: None }],
+            "narrator",
+        );
+        read_aloud.mark_ready(&ids[0], audio.to_path_buf());
+        archive.sessions.push(ArchivedSession {
+            session,
+            timeline: vec![],
+            notes: vec![],
+            read_aloud: Some(read_aloud),
+            audio_log: vec![],
+        });
+
+        archive.world_state = Some(WorldState::new(&campaign.id));
+        archive.relationships.push(EntityRelationship::new(
+            &campaign.id,
+            "npc-1",
+            EntityType::NPC,
+            "Mira",
+            "npc-2",
+            EntityType::NPC,
+            "Tobin",
+            RelationshipType::Ally,
+        ));
+        archive
+    }
+
+    #[test]
+    fn test_round_trip_with_assets() {
+        let temp = tempfile::tempdir().unwrap();
+        let audio = temp.path().join("line.mp3");
+        std::fs::write(&audio, b"fake audio").unwrap();
+        let archive = sample_archive(&audio);
+
+        let path = temp.path().join("campaign.zip");
+        let manifest = write_archive(&archive, &path).unwrap();
+        assert_eq!(manifest.schema_version, ARCHIVE_SCHEMA_VERSION);
+        assert_eq!(manifest.assets.len(), 1);
+        assert_eq!(manifest.sections.len(), SECTIONS.len());
+
+        let assets_dir = temp.path().join("assets");
+        let (read_manifest, restored) = read_archive(&path, &assets_dir).unwrap();
+        assert_eq!(read_manifest.campaign_id, archive.campaign_id());
+        assert_eq!(restored.campaign.notes.len(), 1);
+        assert_eq!(restored.relationships.len(), 1);
+
+        let passage = &restored.sessions[0].read_aloud.as_ref().unwrap().passages[0];
+        let restored_audio = passage.audio_path.as_ref().unwrap();
+        assert!(restored_audio.starts_with(&assets_dir));
+        assert_eq!(std::fs::read(restored_audio).unwrap(), b"fake audio");
+    }
+
+    #[test]
+    fn test_reads_legacy_json_export() {
+        let temp = tempfile::tempdir().unwrap();
+        let campaigns = CampaignManager::new();
+        let campaign = campaigns.create_campaign("Old Export", "GURPS");
+        let path = temp.path().join("old.json");
+        std::fs::write(&path, campaigns.export_to_json(&campaign.id).unwrap()).unwrap();
+
+        let (manifest, archive) = read_archive(&path, temp.path()).unwrap();
+        assert_eq!(manifest.schema_version, 1);
+        assert_eq!(archive.campaign.campaign.name, "Old Export");
+        assert!(archive.sessions.is_empty());
+        assert!(archive.world_state.is_none());
+    }
+
+    #[test]
+    fn test_rejects_newer_schema() {
+        let mut sections = Map::new();
+        sections.insert("campaign".to_string(), Value::Null);
+        assert!(matches!(
+            migrate(&mut sections, ARCHIVE_SCHEMA_VERSION + 1),
+            Err(ArchiveError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_regenerate_ids_keeps_references() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut archive = sample_archive(&temp.path().join("missing.mp3"));
+        let old_campaign = archive.campaign_id().to_string();
+        let old_session = archive.sessions[0].session.id.clone();
+
+        archive.regenerate_ids();
+        let campaign_id = archive.campaign_id().to_string();
+        assert_ne!(campaign_id, old_campaign);
+        assert_ne!(archive.sessions[0].session.id, old_session);
+        assert_eq!(archive.sessions[0].session.campaign_id, campaign_id);
+        assert_eq!(
+            archive.sessions[0].read_aloud.as_ref().unwrap().session_id,
+            archive.sessions[0].session.id
+        );
+        assert_eq!(archive.relationships[0].campaign_id, campaign_id);
+        assert_eq!(archive.world_state.as_ref().unwrap().campaign_id, campaign_id);
+        archive.validate().unwrap();
+    }
+}
@@ -0,0 +1,680 @@
+//! Campaign Backup Module
+//!
+//! Scheduled campaign backups. Backups are ordinary campaign archives
+//! written to a user-chosen directory (which may be a cloud-synced folder)
+//! on a cron-style schedule. Each directory keeps a `backups.json` index
+//! with every backup's SHA-256 so backups can be verified before they are
+//! restored, and a retention policy prunes old backups per campaign.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::archive::{verify_archive, write_archive, ArchiveError, CampaignArchive};
+
+/// Index file kept in each backup directory
+pub const BACKUP_INDEX_FILE: &str = "backups.json";
+
+/// Default schedule: every day at 03:00
+pub const DEFAULT_BACKUP_SCHEDULE: &str = "0 3 * * *";
+
+/// How far ahead to look for the next scheduled time; covers Feb 29 schedules
+const MAX_SCHEDULE_DAYS: u32 = 366 * 4 + 1;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum BackupError {
+    #[error("Invalid schedule '{0}': {1}")]
+    InvalidSchedule(String, String),
+
+    #[error("Retention policy must keep at least one backup")]
+    InvalidRetention,
+
+    #[error("UTC offset must be within ±14 hours")]
+    InvalidOffset,
+
+    #[error("No backup directory configured")]
+    NoDirectory,
+
+    #[error("Backup not found: {0}")]
+    NotFound(String),
+
+    #[error("Backup failed verification: {0}")]
+    ChecksumMismatch(String),
+
+    #[error("Archive error: {0}")]
+    Archive(#[from] ArchiveError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, BackupError>;
+
+// ============================================================================
+// Schedule
+// ============================================================================
+
+/// A five-field cron expression: minute, hour, day of month, month, and day
+/// of week (0 or 7 is Sunday). Fields accept `*`, numbers, ranges, lists, and
+/// `/` steps; `@hourly`, `@daily`, `@weekly`, and `@monthly` are shorthands.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct BackupSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl BackupSchedule {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let expression = expression.trim();
+        let expanded = match expression {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let invalid = |reason: &str| BackupError::InvalidSchedule(expression.to_string(), reason.to_string());
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(invalid("expected 5 fields"));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7).map_err(|e| invalid(&e))?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            expression: expression.to_string(),
+            minutes: parse_field(minute, 0, 59).map_err(|e| invalid(&e))?,
+            hours: parse_field(hour, 0, 23).map_err(|e| invalid(&e))?,
+            days: parse_field(day, 1, 31).map_err(|e| invalid(&e))?,
+            months: parse_field(month, 1, 12).map_err(|e| invalid(&e))?,
+            weekdays,
+            any_day: day == "*",
+            any_weekday: weekday == "*",
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+
+    /// First scheduled time strictly after `after`, with the schedule read
+    /// in the given local offset
+    pub fn next_after(&self, after: DateTime<Utc>, offset: FixedOffset) -> Option<DateTime<Utc>> {
+        let start = after.with_timezone(&offset).naive_local() + Duration::minutes(1);
+        let mut date = start.date();
+        for day in 0..MAX_SCHEDULE_DAYS {
+            if self.matches_date(date) {
+                let first_hour = if day == 0 { start.hour() } else { 0 };
+                for hour in (first_hour..24).filter(|h| bit(self.hours, *h)) {
+                    let first_minute = if day == 0 && hour == first_hour { start.minute() } else { 0 };
+                    if let Some(minute) = (first_minute..60).find(|m| bit(self.minutes, *m)) {
+                        let local = date.and_hms_opt(hour, minute, 0)?;
+                        return offset.from_local_datetime(&local).single().map(|d| d.with_timezone(&Utc));
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// Cron semantics: when both day fields are restricted, either may match
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !bit(self.months, date.month()) {
+            return false;
+        }
+        let day = bit(self.days, date.day());
+        let weekday = bit(self.weekdays, date.weekday().num_days_from_sunday());
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        }
+    }
+}
+
+impl Default for BackupSchedule {
+    fn default() -> Self {
+        Self::parse(DEFAULT_BACKUP_SCHEDULE).expect("default schedule is valid")
+    }
+}
+
+impl TryFrom<String> for BackupSchedule {
+    type Error = BackupError;
+
+    fn try_from(expression: String) -> Result<Self> {
+        Self::parse(&expression)
+    }
+}
+
+impl From<BackupSchedule> for String {
+    fn from(schedule: BackupSchedule) -> Self {
+        schedule.expression
+    }
+}
+
+fn bit(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+/// Parse one cron field into a bitset of allowed values
+fn parse_field(field: &str, min: u32, max: u32) -> std::result::Result<u64, String> {
+    let number = |s: &str| -> std::result::Result<u32, String> {
+        let n: u32 = s.parse().map_err(|_| format!("'{}' is not a number", s))?;
+        if n < min || n > max {
+            return Err(format!("{} is outside {}-{}", n, min, max));
+        }
+        Ok(n)
+    };
+
+    let mut set = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0).ok_or("bad step")?),
+            None => (part, 1),
+        };
+        let (from, to) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((from, to)) => (number(from)?, number(to)?),
+                None if step > 1 => (number(range)?, max),
+                None => (number(range)?, number(range)?),
+            },
+        };
+        if from > to {
+            return Err(format!("range {} is backwards", range));
+        }
+        for value in (from..=to).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+    Ok(set)
+}
+
+// ============================================================================
+// Retention
+// ============================================================================
+
+/// Which backups to keep per campaign. A backup is kept if any rule keeps it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Most recent backups to keep regardless of age
+    pub keep_last: usize,
+    /// Days to keep the newest backup of
+    pub keep_daily: usize,
+    /// Weeks to keep the newest backup of
+    pub keep_weekly: usize,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 3,
+            keep_daily: 7,
+            keep_weekly: 4,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    pub fn validate(&self) -> Result<()> {
+        if self.keep_last == 0 && self.keep_daily == 0 && self.keep_weekly == 0 {
+            return Err(BackupError::InvalidRetention);
+        }
+        Ok(())
+    }
+
+    /// Backups the policy no longer keeps, from one campaign's backups.
+    /// Days and weeks are counted in the given local offset.
+    pub fn expired<'a>(&self, backups: &'a [BackupRecord], offset: FixedOffset) -> Vec<&'a BackupRecord> {
+        let mut newest_first: Vec<&BackupRecord> = backups.iter().collect();
+        newest_first.sort_by_key(|b| Reverse(b.created_at));
+
+        let mut keep: HashSet<&str> = newest_first.iter().take(self.keep_last).map(|b| b.id.as_str()).collect();
+        let mut days = HashSet::new();
+        let mut weeks = HashSet::new();
+        for backup in &newest_first {
+            let local = backup.created_at.with_timezone(&offset).date_naive();
+            if days.len() < self.keep_daily && days.insert(local) {
+                keep.insert(&backup.id);
+            }
+            let week = local.iso_week();
+            if weeks.len() < self.keep_weekly && weeks.insert((week.year(), week.week())) {
+                keep.insert(&backup.id);
+            }
+        }
+
+        newest_first.into_iter().filter(|b| !keep.contains(b.id.as_str())).collect()
+    }
+}
+
+// ============================================================================
+// Backup Configuration
+// ============================================================================
+
+/// Scheduled backup settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    pub enabled: bool,
+    pub directory: Option<PathBuf>,
+    pub schedule: BackupSchedule,
+    /// Local offset the schedule and retention days are read in
+    pub utc_offset_minutes: i32,
+    /// Campaigns to back up; empty backs up every campaign
+    pub campaign_ids: Vec<String>,
+    pub retention: RetentionPolicy,
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+impl BackupConfig {
+    pub fn validate(&self) -> Result<()> {
+        self.retention.validate()?;
+        if self.enabled && self.directory.is_none() {
+            return Err(BackupError::NoDirectory);
+        }
+        self.offset().map(|_| ())
+    }
+
+    pub fn offset(&self) -> Result<FixedOffset> {
+        FixedOffset::east_opt(self.utc_offset_minutes * 60)
+            .filter(|_| self.utc_offset_minutes.abs() <= 14 * 60)
+            .ok_or(BackupError::InvalidOffset)
+    }
+
+    /// Whether a campaign is covered by scheduled backups
+    pub fn includes(&self, campaign_id: &str) -> bool {
+        self.campaign_ids.is_empty() || self.campaign_ids.iter().any(|id| id == campaign_id)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Backup Store
+// ============================================================================
+
+/// One backup file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupRecord {
+    pub id: String,
+    pub campaign_id: String,
+    pub campaign_name: String,
+    /// File name within the backup directory
+    pub file_name: String,
+    pub created_at: DateTime<Utc>,
+    pub size: u64,
+    /// Hex-encoded SHA-256 of the archive file
+    pub sha256: String,
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+/// A backup directory and its index
+pub struct BackupStore {
+    dir: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path_of(&self, backup: &BackupRecord) -> PathBuf {
+        self.dir.join(&backup.file_name)
+    }
+
+    fn load_index(&self) -> Result<Vec<BackupRecord>> {
+        let path = self.dir.join(BACKUP_INDEX_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    fn save_index(&self, records: &[BackupRecord]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(BACKUP_INDEX_FILE);
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(records)?)?;
+        std::fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    /// Write an archive as a new backup and check it reads back cleanly
+    pub fn write_backup(&self, archive: &CampaignArchive, now: DateTime<Utc>) -> Result<BackupRecord> {
+        let id = Uuid::new_v4().to_string();
+        let file_name = format!("{}-{}-{}.zip", archive.campaign_id(), now.format("%Y%m%d-%H%M%S"), &id[..8]);
+        let path = self.dir.join(&file_name);
+        let manifest = write_archive(archive, &path)?;
+        verify_archive(&path)?;
+
+        let data = std::fs::read(&path)?;
+        let record = BackupRecord {
+            id,
+            campaign_id: manifest.campaign_id,
+            campaign_name: manifest.campaign_name,
+            file_name,
+            created_at: now,
+            size: data.len() as u64,
+            sha256: hex::encode(Sha256::digest(&data)),
+            verified_at: Some(now),
+        };
+
+        let mut index = self.load_index()?;
+        index.push(record.clone());
+        self.save_index(&index)?;
+        Ok(record)
+    }
+
+    /// Backups, newest first, optionally for one campaign
+    pub fn list_backups(&self, campaign_id: Option<&str>) -> Result<Vec<BackupRecord>> {
+        let mut backups: Vec<BackupRecord> = self
+            .load_index()?
+            .into_iter()
+            .filter(|b| campaign_id.is_none_or(|id| b.campaign_id == id))
+            .collect();
+        backups.sort_by_key(|b| Reverse(b.created_at));
+        Ok(backups)
+    }
+
+    pub fn get_backup(&self, backup_id: &str) -> Result<BackupRecord> {
+        self.load_index()?
+            .into_iter()
+            .find(|b| b.id == backup_id)
+            .ok_or_else(|| BackupError::NotFound(backup_id.to_string()))
+    }
+
+    /// Check a backup's file against its recorded checksum and that the
+    /// archive inside reads back for the same campaign
+    pub fn verify_backup(&self, backup_id: &str) -> Result<BackupRecord> {
+        let mut index = self.load_index()?;
+        let record = index
+            .iter_mut()
+            .find(|b| b.id == backup_id)
+            .ok_or_else(|| BackupError::NotFound(backup_id.to_string()))?;
+
+        let path = self.dir.join(&record.file_name);
+        let data = std::fs::read(&path)?;
+        if data.len() as u64 != record.size || hex::encode(Sha256::digest(&data)) != record.sha256 {
+            return Err(BackupError::ChecksumMismatch(record.file_name.clone()));
+        }
+        if verify_archive(&path)?.campaign_id != record.campaign_id {
+            return Err(BackupError::ChecksumMismatch(record.file_name.clone()));
+        }
+
+        record.verified_at = Some(Utc::now());
+        let verified = record.clone();
+        self.save_index(&index)?;
+        Ok(verified)
+    }
+
+    /// Delete a campaign's backups that the policy no longer keeps
+    pub fn prune(&self, campaign_id: &str, policy: &RetentionPolicy, offset: FixedOffset) -> Result<Vec<BackupRecord>> {
+        let index = self.load_index()?;
+        let campaign: Vec<BackupRecord> = index.iter().filter(|b| b.campaign_id == campaign_id).cloned().collect();
+        let expired: Vec<BackupRecord> = policy.expired(&campaign, offset).into_iter().cloned().collect();
+        if expired.is_empty() {
+            return Ok(expired);
+        }
+
+        for backup in &expired {
+            match std::fs::remove_file(self.path_of(backup)) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let removed: HashSet<&str> = expired.iter().map(|b| b.id.as_str()).collect();
+        let remaining: Vec<BackupRecord> = index.into_iter().filter(|b| !removed.contains(b.id.as_str())).collect();
+        self.save_index(&remaining)?;
+        Ok(expired)
+    }
+}
+
+// ============================================================================
+// Backup Manager
+// ============================================================================
+
+/// Holds the backup settings and decides when the next run is due
+pub struct BackupManager {
+    config: RwLock<BackupConfig>,
+    /// When the current settings took effect; the schedule counts from here
+    /// until the first run
+    since: RwLock<DateTime<Utc>>,
+}
+
+impl Default for BackupManager {
+    fn default() -> Self {
+        Self::new(BackupConfig::default())
+    }
+}
+
+impl BackupManager {
+    pub fn new(config: BackupConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            since: RwLock::new(Utc::now()),
+        }
+    }
+
+    pub fn config(&self) -> BackupConfig {
+        self.config.read().unwrap().clone()
+    }
+
+    /// Replace the settings, keeping the last run time
+    pub fn set_config(&self, mut config: BackupConfig) -> Result<BackupConfig> {
+        config.validate()?;
+        let mut current = self.config.write().unwrap();
+        config.last_run = current.last_run;
+        *current = config.clone();
+        *self.since.write().unwrap() = Utc::now();
+        Ok(config)
+    }
+
+    /// Next scheduled run; `None` while backups are disabled
+    pub fn next_run(&self) -> Option<DateTime<Utc>> {
+        let config = self.config.read().unwrap();
+        if !config.enabled || config.directory.is_none() {
+            return None;
+        }
+        let from = config.last_run.unwrap_or(*self.since.read().unwrap());
+        config.schedule.next_after(from, config.offset().ok()?)
+    }
+
+    /// Whether a scheduled run is due. A run missed while the app was closed
+    /// is due as soon as it starts.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_run().is_some_and(|next| next <= now)
+    }
+
+    pub fn mark_run(&self, at: DateTime<Utc>) {
+        self.config.write().unwrap().last_run = Some(at);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign_manager::CampaignManager;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn archive(name: &str) -> CampaignArchive {
+        let campaigns = CampaignManager::new();
+        let campaign = campaigns.create_campaign(name, "dnd5e");
+        CampaignArchive::new(campaigns.export_campaign(&campaign.id).unwrap())
+    }
+
+    #[test]
+    fn test_schedule_next_run() {
+        let utc0 = FixedOffset::east_opt(0).unwrap();
+        let daily = BackupSchedule::parse("0 3 * * *").unwrap();
+        assert_eq!(daily.next_after(utc("2026-03-01T02:59:30Z"), utc0), Some(utc("2026-03-01T03:00:00Z")));
+        assert_eq!(daily.next_after(utc("2026-03-01T03:00:00Z"), utc0), Some(utc("2026-03-02T03:00:00Z")));
+
+        // 2026-03-04 is a Wednesday
+        let weekly = BackupSchedule::parse("30 22 * * 0,7").unwrap();
+        assert_eq!(weekly.next_after(utc("2026-03-04T12:00:00Z"), utc0), Some(utc("2026-03-08T22:30:00Z")));
+
+        let every_six = BackupSchedule::parse("*/15 */6 * * *").unwrap();
+        assert_eq!(every_six.next_after(utc("2026-03-01T06:20:00Z"), utc0), Some(utc("2026-03-01T06:30:00Z")));
+
+        // Local 03:00 at UTC-5 is 08:00 UTC
+        let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(daily.next_after(utc("2026-03-01T12:00:00Z"), eastern), Some(utc("2026-03-02T08:00:00Z")));
+    }
+
+    #[test]
+    fn test_schedule_parsing() {
+        assert_eq!(BackupSchedule::parse("@daily").unwrap().as_str(), "@daily");
+        for bad in ["0 3 * *", "60 * * * *", "0 3 * * 8", "0 5-2 * * *", "*/0 * * * *", "x * * * *"] {
+            assert!(BackupSchedule::parse(bad).is_err(), "{} should be rejected", bad);
+        }
+
+        let config: BackupConfig = serde_json::from_str(r#"{"schedule": "0 4 * * 1"}"#).unwrap();
+        assert_eq!(config.schedule.as_str(), "0 4 * * 1");
+        assert!(serde_json::from_str::<BackupConfig>(r#"{"schedule": "nope"}"#).is_err());
+    }
+
+    #[test]
+    fn test_retention_keeps_daily_and_weekly() {
+        let record = |id: &str, at: &str| BackupRecord {
+            id: id.to_string(),
+            campaign_id: "camp-1".to_string(),
+            campaign_name: "Test".to_string(),
+            file_name: format!("{}.zip", id),
+            created_at: utc(at),
+            size: 0,
+            sha256: String::new(),
+            verified_at: None,
+        };
+        let backups = vec![
+            record("mon-1", "2026-03-02T03:00:00Z"),
+            record("mon-2", "2026-03-02T15:00:00Z"),
+            record("tue", "2026-03-03T03:00:00Z"),
+            record("prev-week", "2026-02-25T03:00:00Z"),
+            record("old-week", "2026-02-18T03:00:00Z"),
+        ];
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: 2,
+            keep_weekly: 2,
+        };
+        let expired: Vec<&str> =
+            policy.expired(&backups, FixedOffset::east_opt(0).unwrap()).iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(expired, vec!["mon-1", "old-week"]);
+
+        let none = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: 0,
+            keep_weekly: 0,
+        };
+        assert!(matches!(none.validate(), Err(BackupError::InvalidRetention)));
+    }
+
+    #[test]
+    fn test_write_verify_and_prune_backups() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BackupStore::new(dir.path());
+        let archive = archive("Rime of the Frostmaiden");
+        let first = store.write_backup(&archive, utc("2026-03-01T03:00:00Z")).unwrap();
+        let second = store.write_backup(&archive, utc("2026-03-02T03:00:00Z")).unwrap();
+        store.write_backup(&self::archive("Other Campaign"), utc("2026-03-02T03:00:00Z")).unwrap();
+
+        let listed = store.list_backups(Some(archive.campaign_id())).unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].id, second.id);
+        assert!(store.verify_backup(&first.id).is_ok());
+
+        // Tampering with the file is caught
+        std::fs::write(store.path_of(&first), b"not a backup").unwrap();
+        assert!(matches!(store.verify_backup(&first.id), Err(BackupError::ChecksumMismatch(_))));
+
+        let keep_one = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: 0,
+            keep_weekly: 0,
+        };
+        let pruned = store.prune(archive.campaign_id(), &keep_one, FixedOffset::east_opt(0).unwrap()).unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert!(!store.path_of(&first).exists());
+        assert_eq!(store.list_backups(None).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_manager_due_and_catches_up() {
+        let config = BackupConfig {
+            enabled: true,
+            directory: Some(PathBuf::from("/backups")),
+            last_run: Some(utc("2026-03-01T03:00:00Z")),
+            ..BackupConfig::default()
+        };
+        let manager = BackupManager::new(config);
+        assert_eq!(manager.next_run(), Some(utc("2026-03-02T03:00:00Z")));
+        assert!(!manager.is_due(utc("2026-03-02T02:59:00Z")));
+        // Closed for several days: one run is due on start
+        assert!(manager.is_due(utc("2026-03-05T12:00:00Z")));
+        manager.mark_run(utc("2026-03-05T12:00:00Z"));
+        assert!(!manager.is_due(utc("2026-03-05T12:01:00Z")));
+
+        let mut disabled = manager.config();
+        disabled.enabled = false;
+        manager.set_config(disabled).unwrap();
+        assert_eq!(manager.next_run(), None);
+        assert_eq!(manager.config().last_run, Some(utc("2026-03-05T12:00:00Z")));
+
+        let no_dir = BackupConfig {
+            enabled: true,
+            ..BackupConfig::default()
+        };
+        assert!(matches!(manager.set_config(no_dir), Err(BackupError::NoDirectory)));
+    }
+}
@@ -0,0 +1,1267 @@
+//! Fantasy Calendar Module
+//!
+//! Configurable calendar definitions (months, leap rules, weeks, moons, and
+//! holidays) with date arithmetic on top of [`InGameDate`]. Built-in
+//! definitions cover the Calendar of Harptos (Forgotten Realms) and the
+//! Absalom Reckoning (Golarion). Recurring events and NPC schedule entries
+//! fire as time advances, producing world events and NPC whereabouts.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::world_state::{CalendarConfig, EventImpact, InGameDate, InGameTime, WorldEvent, WorldEventType};
+
+/// World event metadata key set on events generated from holidays
+pub const HOLIDAY_FIELD: &str = "holiday";
+
+/// World event metadata key set on events generated from recurring events
+pub const RECURRING_EVENT_FIELD: &str = "recurring_event_id";
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum CalendarError {
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("Invalid calendar: {0}")]
+    InvalidCalendar(String),
+
+    #[error("Unknown built-in calendar: {0}")]
+    UnknownCalendar(String),
+
+    #[error("Recurring event not found: {0}")]
+    RecurringEventNotFound(String),
+
+    #[error("Schedule entry not found: {0}")]
+    ScheduleEntryNotFound(String),
+}
+
+pub type Result<T> = std::result::Result<T, CalendarError>;
+
+// ============================================================================
+// Calendar Definition
+// ============================================================================
+
+/// A month, or an intercalary festival day that sits between months
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarMonth {
+    pub name: String,
+    /// Days in a common year (may be 0 for leap-only months)
+    pub days: u16,
+    /// Extra days added in leap years
+    #[serde(default)]
+    pub leap_days: u16,
+    /// Festival days outside the regular weeks (e.g. Midwinter)
+    #[serde(default)]
+    pub intercalary: bool,
+}
+
+impl CalendarMonth {
+    pub fn new(name: &str, days: u16) -> Self {
+        Self {
+            name: name.to_string(),
+            days,
+            leap_days: 0,
+            intercalary: false,
+        }
+    }
+
+    pub fn festival(name: &str) -> Self {
+        Self {
+            intercalary: true,
+            ..Self::new(name, 1)
+        }
+    }
+
+    pub fn with_leap_days(mut self, leap_days: u16) -> Self {
+        self.leap_days = leap_days;
+        self
+    }
+}
+
+/// Leap year rule: every `interval` years, except every `except_every`
+/// years, unless also every `unless_every` years (Gregorian: 4/100/400)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeapRule {
+    pub interval: u32,
+    #[serde(default)]
+    pub except_every: Option<u32>,
+    #[serde(default)]
+    pub unless_every: Option<u32>,
+}
+
+impl LeapRule {
+    pub fn every(interval: u32) -> Self {
+        Self {
+            interval,
+            except_every: None,
+            unless_every: None,
+        }
+    }
+
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        let divisible = |n: Option<u32>| n.is_some_and(|n| n > 0 && year.rem_euclid(n as i32) == 0);
+        if divisible(self.unless_every) {
+            return true;
+        }
+        divisible(Some(self.interval)) && !divisible(self.except_every)
+    }
+
+    /// Number of leap years in `[1, year)`; negative for years before 1
+    fn leap_years_before(&self, year: i32) -> i64 {
+        let multiples = |n: Option<u32>| match n {
+            Some(n) if n > 0 => (year as i64 - 1).div_euclid(n as i64),
+            _ => 0,
+        };
+        let leaps = multiples(Some(self.interval)) - multiples(self.except_every);
+        match (self.except_every, self.unless_every) {
+            (Some(_), Some(_)) => leaps + multiples(self.unless_every),
+            _ => leaps,
+        }
+    }
+}
+
+/// A moon with a fixed orbital cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Moon {
+    pub name: String,
+    /// Days from one new moon to the next
+    pub cycle_days: f64,
+    /// Days into the cycle on day 0 (1st day of year 1)
+    #[serde(default)]
+    pub offset_days: f64,
+}
+
+impl Moon {
+    pub fn new(name: &str, cycle_days: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            cycle_days,
+            offset_days: 0.0,
+        }
+    }
+
+    pub fn phase_on(&self, day_number: i64) -> MoonPhase {
+        if self.cycle_days <= 0.0 {
+            return MoonPhase::New;
+        }
+        let age = (day_number as f64 + self.offset_days).rem_euclid(self.cycle_days);
+        let index = ((age / self.cycle_days) * 8.0).round() as usize % 8;
+        MoonPhase::ALL[index]
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    const ALL: [MoonPhase; 8] = [
+        MoonPhase::New,
+        MoonPhase::WaxingCrescent,
+        MoonPhase::FirstQuarter,
+        MoonPhase::WaxingGibbous,
+        MoonPhase::Full,
+        MoonPhase::WaningGibbous,
+        MoonPhase::LastQuarter,
+        MoonPhase::WaningCrescent,
+    ];
+}
+
+/// A named holiday on a fixed month and day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    pub name: String,
+    pub month: u8,
+    pub day: u8,
+    #[serde(default)]
+    pub description: String,
+    /// World event type recorded when the holiday comes around
+    #[serde(default = "default_holiday_type")]
+    pub event_type: WorldEventType,
+}
+
+fn default_holiday_type() -> WorldEventType {
+    WorldEventType::Social
+}
+
+impl Holiday {
+    pub fn new(name: &str, month: u8, day: u8) -> Self {
+        Self {
+            name: name.to_string(),
+            month,
+            day,
+            description: String::new(),
+            event_type: default_holiday_type(),
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn with_type(mut self, event_type: WorldEventType) -> Self {
+        self.event_type = event_type;
+        self
+    }
+}
+
+/// A full calendar definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarDefinition {
+    pub name: String,
+    pub months: Vec<CalendarMonth>,
+    pub week_days: Vec<String>,
+    /// Weeks restart on the first of each month (Harptos tendays)
+    #[serde(default)]
+    pub week_resets_monthly: bool,
+    /// Weekday index of day 0 (1st day of year 1)
+    #[serde(default)]
+    pub first_weekday: usize,
+    #[serde(default)]
+    pub leap_rule: Option<LeapRule>,
+    #[serde(default)]
+    pub moons: Vec<Moon>,
+    #[serde(default)]
+    pub holidays: Vec<Holiday>,
+    /// Era suffix used when formatting dates (e.g. "DR", "AR")
+    #[serde(default)]
+    pub era: Option<String>,
+}
+
+/// Everything the calendar knows about a single day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarDay {
+    pub date: InGameDate,
+    pub display: String,
+    pub month_name: String,
+    pub weekday: Option<String>,
+    pub holidays: Vec<Holiday>,
+    pub moons: Vec<MoonState>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoonState {
+    pub name: String,
+    pub phase: MoonPhase,
+}
+
+impl CalendarDefinition {
+    pub fn new(name: &str, months: Vec<CalendarMonth>, week_days: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            months,
+            week_days,
+            week_resets_monthly: false,
+            first_weekday: 0,
+            leap_rule: None,
+            moons: vec![],
+            holidays: vec![],
+            era: None,
+        }
+    }
+
+    /// Build a definition from the simple per-campaign calendar config
+    pub fn from_config(config: &CalendarConfig) -> Self {
+        let months = config
+            .month_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| CalendarMonth::new(name, config.days_per_month.get(i).copied().unwrap_or(30) as u16))
+            .collect();
+        let mut definition = Self::new(&config.name, months, config.week_days.clone());
+        definition.era = config.eras.first().cloned();
+        definition
+    }
+
+    /// The Calendar of Harptos used in the Forgotten Realms
+    pub fn harptos() -> Self {
+        let month = |name| CalendarMonth::new(name, 30);
+        let months = vec![
+            month("Hammer"),
+            CalendarMonth::festival("Midwinter"),
+            month("Alturiak"),
+            month("Ches"),
+            month("Tarsakh"),
+            CalendarMonth::festival("Greengrass"),
+            month("Mirtul"),
+            month("Kythorn"),
+            month("Flamerule"),
+            CalendarMonth::festival("Midsummer"),
+            CalendarMonth {
+                days: 0,
+                ..CalendarMonth::festival("Shieldmeet")
+            }
+            .with_leap_days(1),
+            month("Eleasis"),
+            month("Eleint"),
+            CalendarMonth::festival("Highharvestide"),
+            month("Marpenoth"),
+            month("Uktar"),
+            CalendarMonth::festival("Feast of the Moon"),
+            month("Nightal"),
+        ];
+        let week_days = (1..=10).map(|n| format!("{} Day", ordinal(n))).collect();
+
+        let mut definition = Self::new("Harptos", months, week_days);
+        definition.week_resets_monthly = true;
+        definition.leap_rule = Some(LeapRule::every(4));
+        definition.moons = vec![Moon::new("Selûne", 30.4375)];
+        definition.era = Some("DR".to_string());
+        definition.holidays = vec![
+            Holiday::new("Midwinter", 2, 1).with_description("Deadwinter Day; nobles renew alliances"),
+            Holiday::new("Spring Equinox", 4, 19),
+            Holiday::new("Greengrass", 6, 1).with_description("Flowers are offered to the gods of spring"),
+            Holiday::new("Summer Solstice", 8, 20),
+            Holiday::new("Midsummer", 10, 1).with_description("A night of feasting, music, and love"),
+            Holiday::new("Shieldmeet", 11, 1)
+                .with_description("Once every four years; pacts and oaths are renewed")
+                .with_type(WorldEventType::Political),
+            Holiday::new("Autumn Equinox", 13, 21),
+            Holiday::new("Highharvestide", 14, 1).with_description("Harvest feast before the winter"),
+            Holiday::new("Feast of the Moon", 17, 1)
+                .with_description("The dead are honored")
+                .with_type(WorldEventType::Religious),
+            Holiday::new("Winter Solstice", 18, 20),
+        ];
+        definition
+    }
+
+    /// The Absalom Reckoning used on Golarion
+    pub fn golarion() -> Self {
+        let months = vec![
+            CalendarMonth::new("Abadius", 31),
+            CalendarMonth::new("Calistril", 28).with_leap_days(1),
+            CalendarMonth::new("Pharast", 31),
+            CalendarMonth::new("Gozran", 30),
+            CalendarMonth::new("Desnus", 31),
+            CalendarMonth::new("Sarenith", 30),
+            CalendarMonth::new("Erastus", 31),
+            CalendarMonth::new("Arodus", 31),
+            CalendarMonth::new("Rova", 30),
+            CalendarMonth::new("Lamashan", 31),
+            CalendarMonth::new("Neth", 30),
+            CalendarMonth::new("Kuthona", 31),
+        ];
+        let week_days = ["Moonday", "Toilday", "Wealday", "Oathday", "Fireday", "Starday", "Sunday"]
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+
+        let mut definition = Self::new("Golarion", months, week_days);
+        definition.leap_rule = Some(LeapRule::every(8));
+        definition.moons = vec![Moon::new("Somal", 29.5)];
+        definition.era = Some("AR".to_string());
+        definition.holidays = vec![
+            Holiday::new("Foundation Day", 1, 1).with_description("Founding of Absalom and the new year"),
+            Holiday::new("Day of Bones", 3, 5)
+                .with_description("Pharasmins parade the dead")
+                .with_type(WorldEventType::Religious),
+            Holiday::new("Armasse", 8, 16).with_description("Week-long festival of Aroden and law"),
+            Holiday::new("Crystalhue", 12, 21).with_description("Winter solstice festival of Shelyn"),
+        ];
+        definition
+    }
+
+    /// Names of the built-in calendars
+    pub fn builtin_names() -> Vec<&'static str> {
+        vec!["Standard", "Harptos", "Golarion"]
+    }
+
+    /// Look up a built-in calendar by name
+    pub fn builtin(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "standard" => Ok(Self::from_config(&CalendarConfig::default())),
+            "harptos" | "forgotten realms" => Ok(Self::harptos()),
+            "golarion" | "absalom reckoning" | "pathfinder" => Ok(Self::golarion()),
+            _ => Err(CalendarError::UnknownCalendar(name.to_string())),
+        }
+    }
+
+    /// Check the definition is usable for date arithmetic
+    pub fn validate(&self) -> Result<()> {
+        if self.months.is_empty() {
+            return Err(CalendarError::InvalidCalendar("a calendar needs at least one month".to_string()));
+        }
+        if self.months.len() > u8::MAX as usize {
+            return Err(CalendarError::InvalidCalendar("too many months".to_string()));
+        }
+        if self.months.iter().all(|m| m.days == 0) {
+            return Err(CalendarError::InvalidCalendar("a common year has no days".to_string()));
+        }
+        if self.months.iter().any(|m| m.days + m.leap_days > u8::MAX as u16) {
+            return Err(CalendarError::InvalidCalendar("a month is longer than 255 days".to_string()));
+        }
+        if self.leap_rule.as_ref().is_some_and(|r| r.interval == 0) {
+            return Err(CalendarError::InvalidCalendar("leap interval must be positive".to_string()));
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Year & Month Lengths
+    // ========================================================================
+
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        self.leap_rule.as_ref().is_some_and(|r| r.is_leap_year(year))
+    }
+
+    /// Length of a month (1-based) in the given year
+    pub fn month_length(&self, year: i32, month: u8) -> u16 {
+        match self.months.get((month as usize).wrapping_sub(1)) {
+            Some(m) if self.is_leap_year(year) => m.days + m.leap_days,
+            Some(m) => m.days,
+            None => 0,
+        }
+    }
+
+    fn common_year_length(&self) -> i64 {
+        self.months.iter().map(|m| m.days as i64).sum()
+    }
+
+    fn leap_extra(&self) -> i64 {
+        self.months.iter().map(|m| m.leap_days as i64).sum()
+    }
+
+    pub fn year_length(&self, year: i32) -> i64 {
+        if self.is_leap_year(year) {
+            self.common_year_length() + self.leap_extra()
+        } else {
+            self.common_year_length()
+        }
+    }
+
+    fn days_before_year(&self, year: i32) -> i64 {
+        let leaps = self.leap_rule.as_ref().map(|r| r.leap_years_before(year)).unwrap_or(0);
+        (year as i64 - 1) * self.common_year_length() + leaps * self.leap_extra()
+    }
+
+    // ========================================================================
+    // Date Arithmetic
+    // ========================================================================
+
+    /// Check a date exists in this calendar
+    pub fn check_date(&self, date: &InGameDate) -> Result<()> {
+        let length = self.month_length(date.year, date.month);
+        if date.month == 0 || date.month as usize > self.months.len() {
+            return Err(CalendarError::InvalidDate(format!(
+                "{} has no month {}",
+                self.name, date.month
+            )));
+        }
+        if date.day == 0 || date.day as u16 > length {
+            return Err(CalendarError::InvalidDate(format!(
+                "{} has {} days in year {}, not {}",
+                self.months[date.month as usize - 1].name,
+                length,
+                date.year,
+                date.day
+            )));
+        }
+        Ok(())
+    }
+
+    /// Days since the 1st day of year 1
+    pub fn day_number(&self, date: &InGameDate) -> Result<i64> {
+        self.check_date(date)?;
+        let before_month: i64 = (1..date.month).map(|m| self.month_length(date.year, m) as i64).sum();
+        Ok(self.days_before_year(date.year) + before_month + date.day as i64 - 1)
+    }
+
+    /// The date for a day number, keeping era and time from `template`
+    pub fn date_from_day_number(&self, day_number: i64, template: &InGameDate) -> InGameDate {
+        let common = self.common_year_length().max(1);
+        let mut year = (1 + day_number.div_euclid(common)).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        while self.days_before_year(year) > day_number {
+            year -= 1;
+        }
+        while self.days_before_year(year + 1) <= day_number {
+            year += 1;
+        }
+
+        let mut remaining = day_number - self.days_before_year(year);
+        let mut month = 1u8;
+        for m in 1..=self.months.len() as u8 {
+            let length = self.month_length(year, m) as i64;
+            if remaining < length {
+                month = m;
+                break;
+            }
+            remaining -= length;
+        }
+
+        InGameDate {
+            year,
+            month,
+            day: (remaining + 1) as u8,
+            era: template.era.clone(),
+            calendar: self.name.clone(),
+            time: template.time.clone(),
+        }
+    }
+
+    /// Zero-based day within the date's year
+    pub fn day_of_year(&self, date: &InGameDate) -> Result<i64> {
+        Ok(self.day_number(date)? - self.days_before_year(date.year))
+    }
+
+    /// Add (or with a negative count, subtract) days
+    pub fn add_days(&self, date: &InGameDate, days: i64) -> Result<InGameDate> {
+        Ok(self.date_from_day_number(self.day_number(date)? + days, date))
+    }
+
+    /// Add minutes, rolling over into following days. A date without a time
+    /// is treated as midnight.
+    pub fn add_minutes(&self, date: &InGameDate, minutes: i64) -> Result<InGameDate> {
+        let current = date.time.as_ref().map(|t| t.hour as i64 * 60 + t.minute as i64).unwrap_or(0);
+        let total = current + minutes;
+        let mut result = self.add_days(date, total.div_euclid(MINUTES_PER_DAY))?;
+        let of_day = total.rem_euclid(MINUTES_PER_DAY);
+        result.time = Some(InGameTime {
+            hour: (of_day / 60) as u8,
+            minute: (of_day % 60) as u8,
+            period: None,
+        });
+        Ok(result)
+    }
+
+    /// Days from `from` to `to` (negative when `to` is earlier)
+    pub fn days_between(&self, from: &InGameDate, to: &InGameDate) -> Result<i64> {
+        Ok(self.day_number(to)? - self.day_number(from)?)
+    }
+
+    // ========================================================================
+    // Day Details
+    // ========================================================================
+
+    /// Weekday of a date; festival days fall outside the week
+    pub fn weekday(&self, date: &InGameDate) -> Result<Option<String>> {
+        let day_number = self.day_number(date)?;
+        if self.week_days.is_empty() || self.months[date.month as usize - 1].intercalary {
+            return Ok(None);
+        }
+        let index = if self.week_resets_monthly {
+            (date.day as usize - 1) % self.week_days.len()
+        } else {
+            (day_number + self.first_weekday as i64).rem_euclid(self.week_days.len() as i64) as usize
+        };
+        Ok(Some(self.week_days[index].clone()))
+    }
+
+    pub fn holidays_on(&self, date: &InGameDate) -> Vec<Holiday> {
+        self.holidays
+            .iter()
+            .filter(|h| h.month == date.month && h.day == date.day)
+            .cloned()
+            .collect()
+    }
+
+    pub fn moon_phases(&self, date: &InGameDate) -> Result<Vec<MoonState>> {
+        let day_number = self.day_number(date)?;
+        Ok(self
+            .moons
+            .iter()
+            .map(|m| MoonState {
+                name: m.name.clone(),
+                phase: m.phase_on(day_number),
+            })
+            .collect())
+    }
+
+    /// Format a date as e.g. "15 Mirtul 1492 DR" or "Midsummer 1492 DR"
+    pub fn format_date(&self, date: &InGameDate) -> String {
+        let era = date.era.as_ref().or(self.era.as_ref());
+        let month = self.months.get((date.month as usize).wrapping_sub(1));
+        let base = match month {
+            Some(m) if m.intercalary && self.month_length(date.year, date.month) == 1 => {
+                format!("{} {}", m.name, date.year)
+            }
+            Some(m) => format!("{} {} {}", date.day, m.name, date.year),
+            None => date.display(),
+        };
+        match era {
+            Some(era) => format!("{} {}", base, era),
+            None => base,
+        }
+    }
+
+    pub fn describe(&self, date: &InGameDate) -> Result<CalendarDay> {
+        self.check_date(date)?;
+        Ok(CalendarDay {
+            date: date.clone(),
+            display: self.format_date(date),
+            month_name: self.months[date.month as usize - 1].name.clone(),
+            weekday: self.weekday(date)?,
+            holidays: self.holidays_on(date),
+            moons: self.moon_phases(date)?,
+        })
+    }
+}
+
+fn ordinal(n: usize) -> &'static str {
+    [
+        "First", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Eighth", "Ninth", "Tenth",
+    ][n - 1]
+}
+
+// ============================================================================
+// Recurrence
+// ============================================================================
+
+/// How often a recurring event or schedule entry comes around
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Recurrence {
+    Daily,
+    Weekly { weekday: String },
+    Monthly { day: u8 },
+    Yearly { month: u8, day: u8 },
+    /// Every `interval` days, counted from the start date
+    EveryDays { interval: u32 },
+}
+
+impl Recurrence {
+    fn occurs_on(
+        &self,
+        calendar: &CalendarDefinition,
+        date: &InGameDate,
+        day_number: i64,
+        start_day_number: i64,
+    ) -> bool {
+        if day_number < start_day_number {
+            return false;
+        }
+        match self {
+            Self::Daily => true,
+            Self::Weekly { weekday } => calendar
+                .weekday(date)
+                .ok()
+                .flatten()
+                .is_some_and(|d| d.eq_ignore_ascii_case(weekday)),
+            Self::Monthly { day } => date.day == *day,
+            Self::Yearly { month, day } => date.month == *month && date.day == *day,
+            Self::EveryDays { interval } => {
+                *interval > 0 && (day_number - start_day_number) % *interval as i64 == 0
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Time Windows
+// ============================================================================
+
+/// A span of the day in minutes since midnight. A window whose end is at or
+/// before its start runs past midnight into the next day.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl TimeWindow {
+    pub const ALL_DAY: Self = Self { start: 0, end: MINUTES_PER_DAY as u16 };
+
+    /// A window between whole hours, e.g. `hours(18, 2)` for 18:00–02:00
+    pub fn hours(start: u8, end: u8) -> Self {
+        Self {
+            start: start.min(24) as u16 * 60,
+            end: end.min(24) as u16 * 60,
+        }
+    }
+
+    /// Named part of the day: morning, afternoon, evening, night, tonight, or all day
+    pub fn named(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(['-', '_'], " ").as_str() {
+            "dawn" => Some(Self::hours(5, 7)),
+            "morning" => Some(Self::hours(6, 12)),
+            "midday" | "noon" => Some(Self::hours(11, 14)),
+            "afternoon" => Some(Self::hours(12, 18)),
+            "evening" => Some(Self::hours(18, 22)),
+            "night" => Some(Self::hours(22, 6)),
+            "tonight" => Some(Self::hours(18, 6)),
+            "all day" | "day" | "today" => Some(Self::ALL_DAY),
+            _ => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.start >= MINUTES_PER_DAY as u16 || self.end > MINUTES_PER_DAY as u16 {
+            return Err(CalendarError::InvalidDate(format!(
+                "time window {}-{} is outside the day",
+                self.start, self.end
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the window runs past midnight
+    pub fn wraps(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Length in minutes
+    pub fn minutes(&self) -> i64 {
+        let (start, end) = self.span(0);
+        end - start
+    }
+
+    /// Absolute `[start, end)` minutes for the window beginning on `day_number`
+    pub fn span(&self, day_number: i64) -> (i64, i64) {
+        let base = day_number * MINUTES_PER_DAY;
+        let end = if self.wraps() {
+            self.end as i64 + MINUTES_PER_DAY
+        } else {
+            self.end as i64
+        };
+        (base + self.start as i64, base + end)
+    }
+}
+
+/// Minutes since midnight of a date's time of day, if it has one
+pub fn minute_of_day(date: &InGameDate) -> Option<u16> {
+    date.time.as_ref().map(|t| (t.hour as u16 * 60 + t.minute as u16).min(MINUTES_PER_DAY as u16 - 1))
+}
+
+// ============================================================================
+// Recurring Events & NPC Schedules
+// ============================================================================
+
+/// An event that is recorded on the world timeline each time it comes around
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringEvent {
+    pub id: String,
+    pub campaign_id: String,
+    pub title: String,
+    pub description: String,
+    pub recurrence: Recurrence,
+    pub starts_on: InGameDate,
+    #[serde(default)]
+    pub ends_on: Option<InGameDate>,
+    pub event_type: WorldEventType,
+    pub impact: EventImpact,
+    #[serde(default)]
+    pub location_ids: Vec<String>,
+    #[serde(default)]
+    pub npc_ids: Vec<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl RecurringEvent {
+    pub fn new(campaign_id: &str, title: &str, recurrence: Recurrence, starts_on: InGameDate) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            recurrence,
+            starts_on,
+            ends_on: None,
+            event_type: WorldEventType::default(),
+            impact: EventImpact::default(),
+            location_ids: vec![],
+            npc_ids: vec![],
+            enabled: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn with_type(mut self, event_type: WorldEventType) -> Self {
+        self.event_type = event_type;
+        self
+    }
+
+    fn occurs_on(&self, calendar: &CalendarDefinition, date: &InGameDate, day_number: i64) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let Ok(start) = calendar.day_number(&self.starts_on) else {
+            return false;
+        };
+        let ended = self
+            .ends_on
+            .as_ref()
+            .and_then(|end| calendar.day_number(end).ok())
+            .is_some_and(|end| day_number > end);
+        !ended && self.recurrence.occurs_on(calendar, date, day_number, start)
+    }
+
+    fn to_world_event(&self, date: &InGameDate) -> WorldEvent {
+        let mut event = WorldEvent::new(&self.campaign_id, &self.title, &self.description, date.clone())
+            .with_type(self.event_type.clone())
+            .with_impact(self.impact.clone())
+            .at_locations(self.location_ids.clone())
+            .involving_npcs(self.npc_ids.clone());
+        event
+            .metadata
+            .insert(RECURRING_EVENT_FIELD.to_string(), serde_json::json!(self.id));
+        event
+    }
+}
+
+/// Where an NPC goes, and what they do there, on a recurring basis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcScheduleEntry {
+    pub id: String,
+    pub campaign_id: String,
+    pub npc_id: String,
+    pub location_id: Option<String>,
+    pub activity: String,
+    pub recurrence: Recurrence,
+    pub starts_on: InGameDate,
+    /// Part of the day the entry covers; `None` means all day
+    #[serde(default)]
+    pub window: Option<TimeWindow>,
+}
+
+impl NpcScheduleEntry {
+    pub fn new(campaign_id: &str, npc_id: &str, activity: &str, recurrence: Recurrence, starts_on: InGameDate) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            npc_id: npc_id.to_string(),
+            location_id: None,
+            activity: activity.to_string(),
+            recurrence,
+            starts_on,
+            window: None,
+        }
+    }
+
+    pub fn at_location(mut self, location_id: &str) -> Self {
+        self.location_id = Some(location_id.to_string());
+        self
+    }
+
+    pub fn during(mut self, window: TimeWindow) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Whether the entry comes around on a day
+    pub fn occurs_on(&self, calendar: &CalendarDefinition, day_number: i64) -> bool {
+        let Ok(start) = calendar.day_number(&self.starts_on) else {
+            return false;
+        };
+        let date = calendar.date_from_day_number(day_number, &self.starts_on);
+        self.recurrence.occurs_on(calendar, &date, day_number, start)
+    }
+
+    /// Absolute `[start, end)` minutes the entry covers at `minute`, counting
+    /// a window that started the previous evening
+    pub fn span_at(&self, calendar: &CalendarDefinition, minute: i64) -> Option<(i64, i64)> {
+        let window = self.window.unwrap_or(TimeWindow::ALL_DAY);
+        let day_number = minute.div_euclid(MINUTES_PER_DAY);
+        [day_number, day_number - 1]
+            .into_iter()
+            .filter(|d| self.occurs_on(calendar, *d))
+            .map(|d| window.span(d))
+            .find(|(start, end)| (*start..*end).contains(&minute))
+    }
+}
+
+/// An NPC's whereabouts after time advances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleChange {
+    pub npc_id: String,
+    pub location_id: Option<String>,
+    pub activity: String,
+    pub date: InGameDate,
+    pub entry_id: String,
+}
+
+/// Everything that happened while time advanced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeAdvance {
+    pub from: InGameDate,
+    pub to: InGameDate,
+    /// Holiday and recurring events, oldest first (not yet on the timeline)
+    pub events: Vec<WorldEvent>,
+    /// Latest schedule entry per NPC within the advanced span
+    pub schedule_changes: Vec<ScheduleChange>,
+}
+
+// ============================================================================
+// Calendar Manager
+// ============================================================================
+
+/// Holds per-campaign calendars, recurring events, and NPC schedules
+pub struct CalendarManager {
+    /// Campaign ID -> calendar
+    calendars: RwLock<HashMap<String, CalendarDefinition>>,
+    /// Campaign ID -> ID whose calendar it uses when it has none of its own
+    shared: RwLock<HashMap<String, String>>,
+    recurring_events: RwLock<HashMap<String, RecurringEvent>>,
+    schedule: RwLock<HashMap<String, NpcScheduleEntry>>,
+}
+
+impl Default for CalendarManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarManager {
+    pub fn new() -> Self {
+        Self {
+            calendars: RwLock::new(HashMap::new()),
+            shared: RwLock::new(HashMap::new()),
+            recurring_events: RwLock::new(HashMap::new()),
+            schedule: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // ========================================================================
+    // Calendars
+    // ========================================================================
+
+    pub fn set_calendar(&self, campaign_id: &str, calendar: CalendarDefinition) -> Result<CalendarDefinition> {
+        calendar.validate()?;
+        self.calendars
+            .write()
+            .unwrap()
+            .insert(campaign_id.to_string(), calendar.clone());
+        Ok(calendar)
+    }
+
+    pub fn get_calendar(&self, campaign_id: &str) -> Option<CalendarDefinition> {
+        self.calendars.read().unwrap().get(campaign_id).cloned()
+    }
+
+    /// Use `owner_id`'s calendar (e.g. a shared setting's) for a campaign
+    /// that hasn't set its own
+    pub fn share_calendar(&self, campaign_id: &str, owner_id: &str) {
+        self.shared
+            .write()
+            .unwrap()
+            .insert(campaign_id.to_string(), owner_id.to_string());
+    }
+
+    pub fn unshare_calendar(&self, campaign_id: &str) {
+        self.shared.write().unwrap().remove(campaign_id);
+    }
+
+    /// The campaign's calendar, falling back to a shared calendar and then
+    /// to its simple calendar config
+    pub fn calendar_for(&self, campaign_id: &str, fallback: Option<&CalendarConfig>) -> CalendarDefinition {
+        let shared = || {
+            let owner = self.shared.read().unwrap().get(campaign_id).cloned()?;
+            self.get_calendar(&owner)
+        };
+        self.get_calendar(campaign_id).or_else(shared).unwrap_or_else(|| match fallback {
+            Some(config) => CalendarDefinition::from_config(config),
+            None => CalendarDefinition::from_config(&CalendarConfig::default()),
+        })
+    }
+
+    // ========================================================================
+    // Recurring Events
+    // ========================================================================
+
+    pub fn add_recurring_event(&self, event: RecurringEvent) -> RecurringEvent {
+        self.recurring_events
+            .write()
+            .unwrap()
+            .insert(event.id.clone(), event.clone());
+        event
+    }
+
+    pub fn update_recurring_event(&self, event: RecurringEvent) -> Result<RecurringEvent> {
+        let mut events = self.recurring_events.write().unwrap();
+        if !events.contains_key(&event.id) {
+            return Err(CalendarError::RecurringEventNotFound(event.id));
+        }
+        events.insert(event.id.clone(), event.clone());
+        Ok(event)
+    }
+
+    pub fn remove_recurring_event(&self, event_id: &str) -> Result<()> {
+        self.recurring_events
+            .write()
+            .unwrap()
+            .remove(event_id)
+            .map(|_| ())
+            .ok_or_else(|| CalendarError::RecurringEventNotFound(event_id.to_string()))
+    }
+
+    pub fn list_recurring_events(&self, campaign_id: &str) -> Vec<RecurringEvent> {
+        let mut events: Vec<RecurringEvent> = self
+            .recurring_events
+            .read()
+            .unwrap()
+            .values()
+            .filter(|e| e.campaign_id == campaign_id)
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| e.created_at);
+        events
+    }
+
+    // ========================================================================
+    // NPC Schedules
+    // ========================================================================
+
+    pub fn add_schedule_entry(&self, entry: NpcScheduleEntry) -> NpcScheduleEntry {
+        self.schedule.write().unwrap().insert(entry.id.clone(), entry.clone());
+        entry
+    }
+
+    pub fn remove_schedule_entry(&self, entry_id: &str) -> Result<()> {
+        self.schedule
+            .write()
+            .unwrap()
+            .remove(entry_id)
+            .map(|_| ())
+            .ok_or_else(|| CalendarError::ScheduleEntryNotFound(entry_id.to_string()))
+    }
+
+    /// List schedule entries for a campaign, optionally for one NPC
+    pub fn list_schedule(&self, campaign_id: &str, npc_id: Option<&str>) -> Vec<NpcScheduleEntry> {
+        let mut entries: Vec<NpcScheduleEntry> = self
+            .schedule
+            .read()
+            .unwrap()
+            .values()
+            .filter(|e| e.campaign_id == campaign_id && npc_id.is_none_or(|id| e.npc_id == id))
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| a.npc_id.cmp(&b.npc_id).then_with(|| a.activity.cmp(&b.activity)));
+        entries
+    }
+
+    /// Drop everything stored for a campaign
+    pub fn delete_campaign_calendar(&self, campaign_id: &str) {
+        self.calendars.write().unwrap().remove(campaign_id);
+        self.shared.write().unwrap().remove(campaign_id);
+        self.recurring_events
+            .write()
+            .unwrap()
+            .retain(|_, e| e.campaign_id != campaign_id);
+        self.schedule.write().unwrap().retain(|_, e| e.campaign_id != campaign_id);
+    }
+
+    // ========================================================================
+    // Advancing Time
+    // ========================================================================
+
+    /// Walk each day after `from` up to `days` later, collecting holiday and
+    /// recurring world events and where scheduled NPCs end up.
+    pub fn advance(
+        &self,
+        campaign_id: &str,
+        calendar: &CalendarDefinition,
+        from: &InGameDate,
+        days: u32,
+    ) -> Result<TimeAdvance> {
+        let start = calendar.day_number(from)?;
+        let recurring = self.list_recurring_events(campaign_id);
+        let schedule = self.list_schedule(campaign_id, None);
+
+        let mut events = Vec::new();
+        let mut whereabouts: HashMap<String, ScheduleChange> = HashMap::new();
+        for offset in 1..=days as i64 {
+            let day_number = start + offset;
+            let date = calendar.date_from_day_number(day_number, from);
+
+            for holiday in calendar.holidays_on(&date) {
+                let mut event = WorldEvent::new(campaign_id, &holiday.name, &holiday.description, date.clone())
+                    .with_type(holiday.event_type.clone())
+                    .with_impact(EventImpact::Regional);
+                event.metadata.insert(HOLIDAY_FIELD.to_string(), serde_json::json!(true));
+                events.push(event);
+            }
+            events.extend(
+                recurring
+                    .iter()
+                    .filter(|r| r.occurs_on(calendar, &date, day_number))
+                    .map(|r| r.to_world_event(&date)),
+            );
+
+            for entry in &schedule {
+                let Ok(entry_start) = calendar.day_number(&entry.starts_on) else {
+                    continue;
+                };
+                if entry.recurrence.occurs_on(calendar, &date, day_number, entry_start) {
+                    whereabouts.insert(
+                        entry.npc_id.clone(),
+                        ScheduleChange {
+                            npc_id: entry.npc_id.clone(),
+                            location_id: entry.location_id.clone(),
+                            activity: entry.activity.clone(),
+                            date: date.clone(),
+                            entry_id: entry.id.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut schedule_changes: Vec<ScheduleChange> = whereabouts.into_values().collect();
+        schedule_changes.sort_by(|a, b| a.npc_id.cmp(&b.npc_id));
+
+        Ok(TimeAdvance {
+            from: from.clone(),
+            to: calendar.date_from_day_number(start + days as i64, from),
+            events,
+            schedule_changes,
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_harptos_leap_years_and_shieldmeet() {
+        let harptos = CalendarDefinition::harptos();
+        assert!(harptos.is_leap_year(1372));
+        assert!(!harptos.is_leap_year(1373));
+        assert_eq!(harptos.year_length(1372), 366);
+        assert_eq!(harptos.year_length(1373), 365);
+
+        // Midsummer is followed by Shieldmeet only in leap years
+        let midsummer = InGameDate::new(1372, 10, 1);
+        let shieldmeet = harptos.add_days(&midsummer, 1).unwrap();
+        assert_eq!((shieldmeet.month, shieldmeet.day, shieldmeet.calendar.as_str()), (11, 1, "Harptos"));
+        let midsummer = InGameDate::new(1373, 10, 1);
+        assert_eq!(harptos.add_days(&midsummer, 1).unwrap().month, 12);
+        assert!(harptos.check_date(&InGameDate::new(1373, 11, 1)).is_err());
+
+        assert_eq!(harptos.format_date(&InGameDate::new(1372, 10, 1)), "Midsummer 1372 DR");
+        assert_eq!(harptos.format_date(&InGameDate::new(1372, 7, 15)), "15 Mirtul 1372 DR");
+        assert_eq!(harptos.weekday(&InGameDate::new(1372, 7, 15)).unwrap().as_deref(), Some("Fifth Day"));
+        assert_eq!(harptos.weekday(&InGameDate::new(1372, 2, 1)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_day_number_round_trip() {
+        let mut gregorian = CalendarDefinition::from_config(&CalendarConfig::default());
+        gregorian.months = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+            .iter()
+            .zip(&CalendarConfig::default().month_names)
+            .map(|(days, name)| CalendarMonth::new(name, *days))
+            .collect();
+        gregorian.months[1].leap_days = 1;
+        gregorian.leap_rule = Some(LeapRule {
+            interval: 4,
+            except_every: Some(100),
+            unless_every: Some(400),
+        });
+        assert!(gregorian.is_leap_year(2000));
+        assert!(!gregorian.is_leap_year(1900));
+
+        for calendar in [gregorian, CalendarDefinition::harptos(), CalendarDefinition::golarion()] {
+            for day_number in [-800, -1, 0, 1, 365, 366, 1460, 2921, 700_000] {
+                let date = calendar.date_from_day_number(day_number, &InGameDate::default());
+                assert_eq!(calendar.day_number(&date).unwrap(), day_number, "{}", calendar.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_arithmetic_across_years_and_time() {
+        let golarion = CalendarDefinition::golarion();
+        let date = InGameDate::new(4723, 12, 30);
+        let next = golarion.add_days(&date, 3).unwrap();
+        assert_eq!((next.year, next.month, next.day), (4724, 1, 2));
+        assert_eq!(golarion.days_between(&date, &next).unwrap(), 3);
+        assert_eq!(golarion.month_length(4720, 2), 29);
+        assert_eq!(golarion.month_length(4721, 2), 28);
+
+        let late = golarion.add_minutes(&date, 23 * 60 + 90).unwrap();
+        assert_eq!((late.month, late.day), (12, 31));
+        assert_eq!(late.time.as_ref().map(|t| (t.hour, t.minute)), Some((0, 30)));
+
+        assert!(golarion.add_days(&InGameDate::new(4723, 2, 30), 1).is_err());
+    }
+
+    #[test]
+    fn test_moon_phases() {
+        let moon = Moon::new("Test", 8.0);
+        assert_eq!(moon.phase_on(0), MoonPhase::New);
+        assert_eq!(moon.phase_on(4), MoonPhase::Full);
+        assert_eq!(moon.phase_on(-4), MoonPhase::Full);
+        assert_eq!(moon.phase_on(6), MoonPhase::LastQuarter);
+    }
+
+    #[test]
+    fn test_advance_collects_holidays_recurring_events_and_schedules() {
+        let manager = CalendarManager::new();
+        let harptos = CalendarDefinition::harptos();
+        let start = InGameDate::new(1372, 9, 28);
+
+        manager.add_recurring_event(
+            RecurringEvent::new("camp-1", "Market day", Recurrence::Weekly { weekday: "Tenth Day".to_string() }, start.clone())
+                .with_type(WorldEventType::Economic),
+        );
+        manager.add_recurring_event(RecurringEvent::new("camp-2", "Elsewhere", Recurrence::Daily, start.clone()));
+        manager.add_schedule_entry(
+            NpcScheduleEntry::new("camp-1", "npc-priest", "Leads prayers", Recurrence::Daily, start.clone())
+                .at_location("loc-temple"),
+        );
+        manager.add_schedule_entry(
+            NpcScheduleEntry::new("camp-1", "npc-priest", "Midsummer rites", Recurrence::Yearly { month: 10, day: 1 }, start.clone())
+                .at_location("loc-grove"),
+        );
+
+        let advance = manager.advance("camp-1", &harptos, &start, 4).unwrap();
+        assert_eq!((advance.to.month, advance.to.day), (11, 1));
+        let titles: Vec<&str> = advance.events.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["Market day", "Midsummer", "Shieldmeet"]);
+        assert!(advance.events[1].metadata.contains_key(HOLIDAY_FIELD));
+
+        // Shieldmeet is the last day walked; daily prayers win over Midsummer rites
+        assert_eq!(advance.schedule_changes.len(), 1);
+        assert_eq!(advance.schedule_changes[0].location_id.as_deref(), Some("loc-temple"));
+    }
+
+    #[test]
+    fn test_shared_calendar_fallback() {
+        let manager = CalendarManager::new();
+        manager.set_calendar("setting-1", CalendarDefinition::harptos()).unwrap();
+        manager.share_calendar("camp-1", "setting-1");
+
+        assert_eq!(manager.calendar_for("camp-1", None).name, "Harptos");
+        manager.set_calendar("camp-1", CalendarDefinition::golarion()).unwrap();
+        assert_eq!(manager.calendar_for("camp-1", None).name, CalendarDefinition::golarion().name);
+
+        manager.unshare_calendar("camp-2");
+        manager.delete_campaign_calendar("camp-1");
+        assert_ne!(manager.calendar_for("camp-1", None).name, "Harptos");
+    }
+}
@@ -0,0 +1,526 @@
+//! Campaign Dashboard Module
+//!
+//! Rolls session history, quests, timelines, world events, and usage records
+//! up into the figures shown on the campaign dashboard: how often the group
+//! meets, how far each active arc has come, which NPCs keep turning up, how
+//! much fighting there has been, and what the campaign has cost in LLM and
+//! voice calls.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::core::campaign::quests::Quest;
+use crate::core::campaign::world_state::WorldEvent;
+use crate::core::session::timeline::{TimelineEvent, TimelineEventType};
+use crate::core::session_manager::{SessionStatus, SessionSummary};
+use crate::core::usage::UsageRecord;
+
+/// World events shown when the caller doesn't ask for a number
+pub const DEFAULT_RECENT_EVENTS: usize = 10;
+
+/// NPCs shown when the caller doesn't ask for a number
+pub const DEFAULT_TOP_NPCS: usize = 10;
+
+/// Usage providers that bill for speech rather than text
+const VOICE_PROVIDERS: &[&str] = &[
+    "elevenlabs", "fish_audio", "fishaudio", "piper", "chatterbox", "gpt_sovits", "xtts_v2", "fish_speech",
+    "dia", "coqui",
+];
+
+// ============================================================================
+// Session Cadence
+// ============================================================================
+
+/// How much and how often a campaign has been played
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SessionCadence {
+    pub session_count: usize,
+    /// Sessions that have been started
+    pub played_sessions: usize,
+    pub planned_sessions: usize,
+    pub total_playtime_minutes: i64,
+    pub average_session_minutes: Option<f64>,
+    /// Average real-world days between session starts
+    pub average_days_between: Option<f64>,
+    pub first_played: Option<DateTime<Utc>>,
+    pub last_played: Option<DateTime<Utc>>,
+    pub days_since_last: Option<i64>,
+}
+
+impl SessionCadence {
+    pub fn from_sessions(sessions: &[SessionSummary], now: DateTime<Utc>) -> Self {
+        let mut starts: Vec<DateTime<Utc>> = sessions
+            .iter()
+            .filter(|s| s.status != SessionStatus::Planned)
+            .map(|s| s.started_at)
+            .collect();
+        starts.sort();
+
+        let durations: Vec<i64> = sessions.iter().filter_map(|s| s.duration_minutes).collect();
+        let total_playtime_minutes = durations.iter().sum();
+        let first_played = starts.first().copied();
+        let last_played = starts.last().copied();
+        let average_days_between = match (first_played, last_played) {
+            (Some(first), Some(last)) if starts.len() > 1 => {
+                Some((last - first).num_minutes() as f64 / (60.0 * 24.0) / (starts.len() - 1) as f64)
+            }
+            _ => None,
+        };
+
+        Self {
+            session_count: sessions.len(),
+            played_sessions: starts.len(),
+            planned_sessions: sessions.len() - starts.len(),
+            total_playtime_minutes,
+            average_session_minutes: (!durations.is_empty())
+                .then(|| total_playtime_minutes as f64 / durations.len() as f64),
+            average_days_between,
+            first_played,
+            last_played,
+            days_since_last: last_played.map(|last| (now - last).num_days()),
+        }
+    }
+}
+
+// ============================================================================
+// Arc Progress
+// ============================================================================
+
+/// Progress of an arc with unresolved quests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArcOverview {
+    pub arc_id: String,
+    pub name: Option<String>,
+    pub quest_count: usize,
+    pub resolved_quests: usize,
+    pub objectives_completed: usize,
+    pub objectives_total: usize,
+    /// Average quest completion, 0-100; resolved quests count as finished
+    pub percent_complete: f64,
+}
+
+/// Arcs that still have unresolved quests, most complete first.
+///
+/// `names` maps arc IDs to display names where they are known.
+pub fn active_arcs(quests: &[Quest], names: &HashMap<String, String>) -> Vec<ArcOverview> {
+    let mut by_arc: HashMap<&str, Vec<&Quest>> = HashMap::new();
+    for quest in quests {
+        if let Some(arc_id) = quest.arc_id.as_deref() {
+            by_arc.entry(arc_id).or_default().push(quest);
+        }
+    }
+
+    let mut arcs: Vec<ArcOverview> = by_arc
+        .into_iter()
+        .filter(|(_, quests)| quests.iter().any(|q| !q.status.is_resolved()))
+        .map(|(arc_id, quests)| {
+            let mut overview = ArcOverview {
+                arc_id: arc_id.to_string(),
+                name: names.get(arc_id).cloned(),
+                quest_count: quests.len(),
+                resolved_quests: 0,
+                objectives_completed: 0,
+                objectives_total: 0,
+                percent_complete: 0.0,
+            };
+            let mut completion = 0.0;
+            for quest in &quests {
+                let (done, total) = quest.progress();
+                overview.objectives_completed += done;
+                overview.objectives_total += total;
+                if quest.status.is_resolved() {
+                    overview.resolved_quests += 1;
+                    completion += 1.0;
+                } else if total > 0 {
+                    completion += done as f64 / total as f64;
+                }
+            }
+            overview.percent_complete = (completion / quests.len() as f64 * 100.0).clamp(0.0, 100.0);
+            overview
+        })
+        .collect();
+
+    arcs.sort_by(|a, b| {
+        b.percent_complete
+            .total_cmp(&a.percent_complete)
+            .then_with(|| a.arc_id.cmp(&b.arc_id))
+    });
+    arcs
+}
+
+// ============================================================================
+// NPC Activity
+// ============================================================================
+
+/// How often an NPC has come up in play
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcActivity {
+    pub npc_id: String,
+    pub name: String,
+    /// Timeline and world events the NPC was part of
+    pub interactions: usize,
+    /// Sessions the NPC appeared in
+    pub sessions: usize,
+    pub last_session: Option<u32>,
+}
+
+/// Count NPC appearances across session timelines (as session number and
+/// events) and world events, most frequent first.
+///
+/// `names` fills in NPCs whose events don't carry a display name.
+pub fn npc_activity(
+    timelines: &[(u32, Vec<TimelineEvent>)],
+    world_events: &[WorldEvent],
+    names: &HashMap<String, String>,
+) -> Vec<NpcActivity> {
+    struct Tally {
+        name: Option<String>,
+        interactions: usize,
+        sessions: HashSet<u32>,
+    }
+
+    let mut tallies: HashMap<String, Tally> = HashMap::new();
+    let mut count = |npc_id: &str, name: Option<&str>, session: Option<u32>| {
+        let tally = tallies.entry(npc_id.to_string()).or_insert_with(|| Tally {
+            name: None,
+            interactions: 0,
+            sessions: HashSet::new(),
+        });
+        tally.interactions += 1;
+        if tally.name.is_none() {
+            tally.name = name.filter(|n| !n.is_empty()).map(str::to_string);
+        }
+        tally.sessions.extend(session);
+    };
+
+    for (session_number, events) in timelines {
+        for event in events {
+            let mut seen = HashSet::new();
+            for entity in &event.entity_refs {
+                if entity.entity_type.eq_ignore_ascii_case("npc") && seen.insert(entity.entity_id.as_str()) {
+                    count(&entity.entity_id, Some(&entity.name), Some(*session_number));
+                }
+            }
+        }
+    }
+    for event in world_events {
+        for npc_id in &event.npc_ids {
+            count(npc_id, None, event.session_number);
+        }
+    }
+
+    let mut activity: Vec<NpcActivity> = tallies
+        .into_iter()
+        .map(|(npc_id, tally)| NpcActivity {
+            name: tally
+                .name
+                .or_else(|| names.get(&npc_id).cloned())
+                .unwrap_or_else(|| npc_id.clone()),
+            interactions: tally.interactions,
+            sessions: tally.sessions.len(),
+            last_session: tally.sessions.into_iter().max(),
+            npc_id,
+        })
+        .collect();
+    activity.sort_by(|a, b| {
+        b.interactions
+            .cmp(&a.interactions)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    activity
+}
+
+// ============================================================================
+// Combat Stats
+// ============================================================================
+
+/// Combat totals across a campaign's sessions
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CombatStats {
+    pub sessions_with_combat: usize,
+    pub encounters: usize,
+    /// Rounds fought in encounters that have ended
+    pub rounds: u32,
+    pub average_rounds: Option<f64>,
+    pub deaths: usize,
+    pub damage_events: usize,
+    pub healing_events: usize,
+}
+
+impl CombatStats {
+    /// Tally combat from session summaries and their timelines
+    pub fn tally(sessions: &[SessionSummary], timelines: &[(u32, Vec<TimelineEvent>)]) -> Self {
+        let mut stats = Self {
+            sessions_with_combat: sessions.iter().filter(|s| s.had_combat).count(),
+            ..Default::default()
+        };
+        let mut ended = 0;
+        for event in timelines.iter().flat_map(|(_, events)| events) {
+            match event.event_type {
+                TimelineEventType::CombatStart => stats.encounters += 1,
+                TimelineEventType::CombatEnd => {
+                    if let Some(rounds) = event.metadata.get("rounds").and_then(|r| r.as_u64()) {
+                        stats.rounds += rounds as u32;
+                        ended += 1;
+                    }
+                }
+                TimelineEventType::CombatDeath => stats.deaths += 1,
+                TimelineEventType::CombatDamage => stats.damage_events += 1,
+                TimelineEventType::CombatHealing => stats.healing_events += 1,
+                _ => {}
+            }
+        }
+        stats.average_rounds = (ended > 0).then(|| stats.rounds as f64 / ended as f64);
+        stats
+    }
+}
+
+// ============================================================================
+// Spend
+// ============================================================================
+
+/// What a campaign has cost in provider calls
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SpendSummary {
+    pub llm_cost_usd: f64,
+    pub voice_cost_usd: f64,
+    pub total_cost_usd: f64,
+    pub llm_requests: u32,
+    pub voice_requests: u32,
+    pub by_provider: HashMap<String, f64>,
+}
+
+/// Whether a usage record is for speech synthesis
+pub fn is_voice_usage(record: &UsageRecord) -> bool {
+    let provider = record.provider.to_lowercase().replace(['-', ' '], "_");
+    VOICE_PROVIDERS.contains(&provider.as_str()) || record.model.to_lowercase().contains("tts")
+}
+
+impl SpendSummary {
+    /// Total the records that belong to a campaign.
+    ///
+    /// A record belongs to the campaign when its context names the campaign
+    /// or one of its sessions. Records without a context count when they
+    /// were made while one of the campaign's sessions was running.
+    pub fn from_records(
+        records: &[UsageRecord],
+        campaign_id: &str,
+        sessions: &[SessionSummary],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let session_ids: HashSet<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+        let windows: Vec<(DateTime<Utc>, DateTime<Utc>)> = sessions
+            .iter()
+            .filter(|s| s.status != SessionStatus::Planned)
+            .map(|s| (s.started_at, s.ended_at.unwrap_or(now)))
+            .collect();
+
+        let mut spend = Self::default();
+        for record in records {
+            let belongs = match record.context.as_deref() {
+                Some(context) => context == campaign_id || session_ids.contains(context),
+                None => windows
+                    .iter()
+                    .any(|(start, end)| record.timestamp >= *start && record.timestamp <= *end),
+            };
+            if !belongs {
+                continue;
+            }
+            if is_voice_usage(record) {
+                spend.voice_cost_usd += record.cost_usd;
+                spend.voice_requests += 1;
+            } else {
+                spend.llm_cost_usd += record.cost_usd;
+                spend.llm_requests += 1;
+            }
+            spend.total_cost_usd += record.cost_usd;
+            *spend.by_provider.entry(record.provider.clone()).or_default() += record.cost_usd;
+        }
+        spend
+    }
+}
+
+// ============================================================================
+// Dashboard
+// ============================================================================
+
+/// Everything the campaign dashboard shows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignDashboard {
+    pub campaign_id: String,
+    pub generated_at: DateTime<Utc>,
+    pub sessions: SessionCadence,
+    pub active_arcs: Vec<ArcOverview>,
+    /// Available and active quests, oldest first
+    pub open_quests: Vec<Quest>,
+    /// Most recent in-game first
+    pub recent_world_events: Vec<WorldEvent>,
+    /// Most frequent first
+    pub npc_activity: Vec<NpcActivity>,
+    pub combat: CombatStats,
+    pub spend: SpendSummary,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign::quests::{ObjectiveStatus, QuestObjective, QuestStatus};
+    use chrono::Duration;
+
+    fn session(id: &str, number: u32, started_at: DateTime<Utc>, minutes: Option<i64>) -> SessionSummary {
+        SessionSummary {
+            id: id.to_string(),
+            campaign_id: "camp".to_string(),
+            session_number: number,
+            started_at,
+            ended_at: minutes.map(|m| started_at + Duration::minutes(m)),
+            duration_minutes: minutes,
+            status: if minutes.is_some() { SessionStatus::Ended } else { SessionStatus::Planned },
+            note_count: 0,
+            had_combat: number == 2,
+            order_index: number as i32,
+        }
+    }
+
+    #[test]
+    fn test_session_cadence() {
+        let start = Utc::now() - Duration::days(30);
+        let sessions = vec![
+            session("s1", 1, start, Some(180)),
+            session("s2", 2, start + Duration::days(7), Some(240)),
+            session("s3", 3, start + Duration::days(14), Some(180)),
+            session("s4", 4, start + Duration::days(21), None),
+        ];
+        let cadence = SessionCadence::from_sessions(&sessions, start + Duration::days(20));
+
+        assert_eq!(cadence.session_count, 4);
+        assert_eq!(cadence.played_sessions, 3);
+        assert_eq!(cadence.planned_sessions, 1);
+        assert_eq!(cadence.total_playtime_minutes, 600);
+        assert_eq!(cadence.average_session_minutes, Some(200.0));
+        assert_eq!(cadence.average_days_between, Some(7.0));
+        assert_eq!(cadence.days_since_last, Some(6));
+
+        let empty = SessionCadence::from_sessions(&[], Utc::now());
+        assert!(empty.average_days_between.is_none());
+        assert!(empty.last_played.is_none());
+    }
+
+    #[test]
+    fn test_active_arcs_progress() {
+        let mut done = QuestObjective::new("Find the map");
+        done.status = ObjectiveStatus::Completed;
+        let mut half = Quest::new("camp", "The Lost Map")
+            .with_objective(done)
+            .with_objective(QuestObjective::new("Reach the tomb"));
+        half.arc_id = Some("arc-1".to_string());
+        let mut finished = Quest::new("camp", "Hire a guide");
+        finished.arc_id = Some("arc-1".to_string());
+        finished.status = QuestStatus::Completed;
+        let mut closed = Quest::new("camp", "Old business");
+        closed.arc_id = Some("arc-2".to_string());
+        closed.status = QuestStatus::Failed;
+        let loose = Quest::new("camp", "No arc");
+
+        let names = HashMap::from([("arc-1".to_string(), "The Tomb".to_string())]);
+        let arcs = active_arcs(&[half, finished, closed, loose], &names);
+
+        assert_eq!(arcs.len(), 1);
+        assert_eq!(arcs[0].name.as_deref(), Some("The Tomb"));
+        assert_eq!(arcs[0].quest_count, 2);
+        assert_eq!(arcs[0].resolved_quests, 1);
+        assert_eq!((arcs[0].objectives_completed, arcs[0].objectives_total), (1, 2));
+        assert_eq!(arcs[0].percent_complete, 75.0);
+    }
+
+    #[test]
+    fn test_npc_activity_ranking() {
+        let timelines = vec![
+            (
+                1,
+                vec![
+                    TimelineEvent::new("s1", TimelineEventType::NPCDialogue, "Haggling", "")
+                        .with_entity("npc", "npc-1", "Mara")
+                        .with_entity("npc", "npc-1", "Mara"),
+                    TimelineEvent::new("s1", TimelineEventType::NPCInteraction, "Warning", "")
+                        .with_entity("npc", "npc-2", ""),
+                ],
+            ),
+            (
+                2,
+                vec![TimelineEvent::new("s2", TimelineEventType::NPCInteraction, "Return", "")
+                    .with_entity("NPC", "npc-1", "Mara")
+                    .with_entity("location", "loc-1", "Docks")],
+            ),
+        ];
+        let names = HashMap::from([("npc-2".to_string(), "Old Tom".to_string())]);
+        let activity = npc_activity(&timelines, &[], &names);
+
+        assert_eq!(activity.len(), 2);
+        assert_eq!(activity[0].npc_id, "npc-1");
+        assert_eq!(activity[0].interactions, 2);
+        assert_eq!(activity[0].sessions, 2);
+        assert_eq!(activity[0].last_session, Some(2));
+        assert_eq!(activity[1].name, "Old Tom");
+    }
+
+    #[test]
+    fn test_combat_stats_tally() {
+        let start = Utc::now();
+        let sessions = vec![session("s1", 1, start, Some(60)), session("s2", 2, start, Some(60))];
+        let timelines = vec![(
+            2,
+            vec![
+                TimelineEvent::new("s2", TimelineEventType::CombatStart, "Combat Initiated", ""),
+                TimelineEvent::new("s2", TimelineEventType::CombatDamage, "Hit", ""),
+                TimelineEvent::new("s2", TimelineEventType::CombatDeath, "Goblin falls", ""),
+                TimelineEvent::new("s2", TimelineEventType::CombatEnd, "Combat Concluded", "").with_meta("rounds", 4),
+                TimelineEvent::new("s2", TimelineEventType::CombatStart, "Combat Initiated", ""),
+                TimelineEvent::new("s2", TimelineEventType::CombatEnd, "Combat Concluded", "").with_meta("rounds", 2),
+            ],
+        )];
+        let stats = CombatStats::tally(&sessions, &timelines);
+
+        assert_eq!(stats.sessions_with_combat, 1);
+        assert_eq!(stats.encounters, 2);
+        assert_eq!(stats.rounds, 6);
+        assert_eq!(stats.average_rounds, Some(3.0));
+        assert_eq!(stats.deaths, 1);
+        assert_eq!(stats.damage_events, 1);
+    }
+
+    #[test]
+    fn test_spend_attribution() {
+        let start = Utc::now() - Duration::hours(5);
+        let sessions = vec![session("s1", 1, start, Some(120))];
+        let record = |provider: &str, model: &str, cost: f64, offset: i64, context: Option<&str>| {
+            let mut r = UsageRecord::new(provider.to_string(), model.to_string(), 0, 0);
+            r.cost_usd = cost;
+            r.timestamp = start + Duration::minutes(offset);
+            r.context = context.map(str::to_string);
+            r
+        };
+        let records = vec![
+            record("claude", "claude-3-5-sonnet", 0.50, 30, None),
+            record("elevenlabs", "eleven_multilingual_v2", 0.20, 60, None),
+            record("openai", "tts-1", 0.05, 400, Some("s1")),
+            record("openai", "gpt-4o", 1.00, 200, None),
+            record("claude", "claude-3-5-sonnet", 2.00, 60, Some("other-campaign")),
+            record("gemini", "gemini-pro", 0.25, 500, Some("camp")),
+        ];
+        let spend = SpendSummary::from_records(&records, "camp", &sessions, Utc::now());
+
+        assert_eq!(spend.llm_requests, 2);
+        assert_eq!(spend.voice_requests, 2);
+        assert!((spend.llm_cost_usd - 0.75).abs() < 1e-9);
+        assert!((spend.voice_cost_usd - 0.25).abs() < 1e-9);
+        assert!((spend.total_cost_usd - 1.00).abs() < 1e-9);
+        assert!((spend.by_provider["gemini"] - 0.25).abs() < 1e-9);
+        assert!((spend.by_provider["claude"] - 0.50).abs() < 1e-9);
+    }
+}
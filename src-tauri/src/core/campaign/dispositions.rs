@@ -0,0 +1,410 @@
+//! NPC Dispositions Toward the Party
+//!
+//! How each NPC feels about the party as a whole and about individual player
+//! characters. Scores live with the other NPC relationships in the world
+//! state (target type `Party` or `Player`), move with logged interactions
+//! and world events, and fall into tiers that tell the NPC's conversations
+//! how warmly or coldly to speak.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::world_state::{
+    Disposition, InteractionRecord, NpcRelationshipState, Result, WorldEvent, WorldStateManager,
+};
+
+/// Relationship target ID used for the party as a whole
+pub const PARTY_TARGET_ID: &str = "party";
+
+/// Relationship target type for the party as a whole
+pub const PARTY_TARGET_TYPE: &str = "Party";
+
+/// Relationship target type for a single player character
+pub const PC_TARGET_TYPE: &str = "Player";
+
+/// World event metadata key mapping NPC IDs to disposition changes toward
+/// the PCs in the event (or the whole party when it names none)
+pub const DISPOSITION_FIELD: &str = "npc_disposition";
+
+/// Share of a change toward one PC that also applies toward the party
+const PARTY_SHARE_PERCENT: i32 = 50;
+
+/// Familiarity gained per logged interaction
+const FAMILIARITY_PER_INTERACTION: u8 = 5;
+
+// ============================================================================
+// Tiers
+// ============================================================================
+
+/// Disposition band that decides how the NPC treats the party
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DispositionTier {
+    Hostile,
+    Unfriendly,
+    Indifferent,
+    Friendly,
+    Devoted,
+}
+
+impl DispositionTier {
+    /// Tier for a score from -100 to 100
+    pub fn from_score(score: Disposition) -> Self {
+        match score {
+            i32::MIN..=-60 => Self::Hostile,
+            -59..=-20 => Self::Unfriendly,
+            -19..=19 => Self::Indifferent,
+            20..=59 => Self::Friendly,
+            _ => Self::Devoted,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Hostile => "hostile",
+            Self::Unfriendly => "unfriendly",
+            Self::Indifferent => "indifferent",
+            Self::Friendly => "friendly",
+            Self::Devoted => "devoted",
+        }
+    }
+
+    /// How an NPC in this tier speaks to the party
+    pub fn guidance(&self) -> &'static str {
+        match self {
+            Self::Hostile => "Openly hostile: refuse help, threaten or stonewall, and never volunteer information.",
+            Self::Unfriendly => "Cold and suspicious: keep answers short, ask for something in return, hold back details.",
+            Self::Indifferent => "Guarded but civil: answer direct questions and treat them like any stranger.",
+            Self::Friendly => "Warm and helpful: offer advice and small favors, share rumors freely.",
+            Self::Devoted => "Loyal: take risks on their behalf, confide secrets, and speak with open affection.",
+        }
+    }
+}
+
+// ============================================================================
+// Interactions
+// ============================================================================
+
+/// Common kinds of interaction, each with a default disposition change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionKind {
+    Helped,
+    Gift,
+    Persuaded,
+    Paid,
+    Insulted,
+    Threatened,
+    Harmed,
+    Betrayed,
+    /// No default; the change must be given
+    Other,
+}
+
+impl InteractionKind {
+    pub fn default_change(&self) -> i32 {
+        match self {
+            Self::Helped => 15,
+            Self::Gift => 10,
+            Self::Persuaded => 5,
+            Self::Paid => 5,
+            Self::Insulted => -10,
+            Self::Threatened => -20,
+            Self::Harmed => -30,
+            Self::Betrayed => -50,
+            Self::Other => 0,
+        }
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// An NPC's disposition toward the party or one player character
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyDisposition {
+    pub npc_id: String,
+    /// [`PARTY_TARGET_ID`] or a player character ID
+    pub target_id: String,
+    pub score: Disposition,
+    pub tier: DispositionTier,
+    /// How well the NPC knows them (0-100)
+    pub familiarity: u8,
+    pub recent_interactions: Vec<InteractionRecord>,
+}
+
+impl PartyDisposition {
+    pub fn is_party(&self) -> bool {
+        self.target_id == PARTY_TARGET_ID
+    }
+
+    fn from_state(relationship: &NpcRelationshipState) -> Self {
+        Self {
+            npc_id: relationship.npc_id.clone(),
+            target_id: relationship.target_id.clone(),
+            score: relationship.disposition,
+            tier: DispositionTier::from_score(relationship.disposition),
+            familiarity: relationship.familiarity,
+            recent_interactions: relationship.recent_interactions.clone(),
+        }
+    }
+}
+
+/// A change to one disposition score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispositionChange {
+    pub npc_id: String,
+    pub target_id: String,
+    pub before: Disposition,
+    pub after: Disposition,
+    pub tier_before: DispositionTier,
+    pub tier_after: DispositionTier,
+}
+
+impl DispositionChange {
+    /// Whether the change moved the NPC into another tier, which changes
+    /// how they speak
+    pub fn tier_changed(&self) -> bool {
+        self.tier_before != self.tier_after
+    }
+}
+
+// ============================================================================
+// Operations
+// ============================================================================
+
+/// Dispositions of an NPC toward the party and individual PCs, party first
+pub fn party_dispositions(world: &WorldStateManager, campaign_id: &str, npc_id: &str) -> Vec<PartyDisposition> {
+    let mut dispositions: Vec<PartyDisposition> = world
+        .get_npc_relationships(campaign_id, npc_id)
+        .iter()
+        .filter(|r| r.target_type == PARTY_TARGET_TYPE || r.target_type == PC_TARGET_TYPE)
+        .map(PartyDisposition::from_state)
+        .collect();
+    dispositions.sort_by_key(|d| !d.is_party());
+    dispositions
+}
+
+/// Log an interaction with the party, or with one PC when `pc_id` is given.
+/// A change toward one PC also moves the NPC's view of the whole party by
+/// half as much. Returns the changed scores.
+pub fn log_interaction(
+    world: &WorldStateManager,
+    campaign_id: &str,
+    npc_id: &str,
+    pc_id: Option<&str>,
+    change: i32,
+    description: &str,
+    session_number: Option<u32>,
+) -> Result<Vec<DispositionChange>> {
+    let mut changes = Vec::new();
+    if let Some(pc_id) = pc_id {
+        changes.push(adjust(world, campaign_id, npc_id, pc_id, change, description, session_number)?);
+    }
+    let party_change = match pc_id {
+        Some(_) => change * PARTY_SHARE_PERCENT / 100,
+        None => change,
+    };
+    if party_change != 0 || pc_id.is_none() {
+        changes.push(adjust(world, campaign_id, npc_id, PARTY_TARGET_ID, party_change, description, session_number)?);
+    }
+    Ok(changes)
+}
+
+/// Apply the disposition changes a world event lists under
+/// [`DISPOSITION_FIELD`], toward each PC in the event or toward the party
+pub fn apply_world_event(world: &WorldStateManager, event: &WorldEvent) -> Result<Vec<DispositionChange>> {
+    let deltas: HashMap<String, i64> = event
+        .metadata
+        .get(DISPOSITION_FIELD)
+        .and_then(|v| v.as_object())
+        .map(|m| m.iter().filter_map(|(id, d)| d.as_i64().map(|d| (id.clone(), d))).collect())
+        .unwrap_or_default();
+
+    let mut npc_ids: Vec<&String> = deltas.keys().collect();
+    npc_ids.sort();
+    let mut changes = Vec::new();
+    for npc_id in npc_ids {
+        let change = deltas[npc_id] as i32;
+        if event.pc_ids.is_empty() {
+            changes.extend(log_interaction(
+                world,
+                &event.campaign_id,
+                npc_id,
+                None,
+                change,
+                &event.title,
+                event.session_number,
+            )?);
+        } else {
+            for pc_id in &event.pc_ids {
+                changes.extend(log_interaction(
+                    world,
+                    &event.campaign_id,
+                    npc_id,
+                    Some(pc_id),
+                    change,
+                    &event.title,
+                    event.session_number,
+                )?);
+            }
+        }
+    }
+    Ok(changes)
+}
+
+/// System prompt section telling the NPC how they feel about the party and
+/// about individual PCs whose standing differs from the party's.
+/// `pc_names` maps PC IDs to names.
+pub fn disposition_prompt_section(
+    dispositions: &[PartyDisposition],
+    pc_names: &HashMap<String, String>,
+) -> Option<String> {
+    if dispositions.is_empty() {
+        return None;
+    }
+    let party_tier = dispositions.iter().find(|d| d.is_party()).map(|d| d.tier);
+
+    let mut section = String::from("### ATTITUDE BEGIN ###\n");
+    if let Some(tier) = party_tier {
+        section.push_str(&format!("Toward the party you are {}. {}\n", tier.label(), tier.guidance()));
+    }
+    for disposition in dispositions.iter().filter(|d| !d.is_party() && Some(d.tier) != party_tier) {
+        let Some(name) = pc_names.get(&disposition.target_id) else {
+            continue;
+        };
+        section.push_str(&format!(
+            "Toward {} you are {}. {}\n",
+            name,
+            disposition.tier.label(),
+            disposition.tier.guidance()
+        ));
+    }
+    section.push_str("### ATTITUDE END ###\n");
+    Some(section)
+}
+
+fn adjust(
+    world: &WorldStateManager,
+    campaign_id: &str,
+    npc_id: &str,
+    target_id: &str,
+    change: i32,
+    description: &str,
+    session_number: Option<u32>,
+) -> Result<DispositionChange> {
+    let current_date = world.get_or_create(campaign_id).current_date;
+    let interaction = InteractionRecord {
+        in_game_date: current_date,
+        description: description.to_string(),
+        disposition_change: change,
+        session_number,
+    };
+
+    let existing = world
+        .get_npc_relationships(campaign_id, npc_id)
+        .into_iter()
+        .find(|r| r.target_id == target_id);
+    let mut relationship = existing.unwrap_or_else(|| NpcRelationshipState {
+        npc_id: npc_id.to_string(),
+        target_id: target_id.to_string(),
+        target_type: if target_id == PARTY_TARGET_ID { PARTY_TARGET_TYPE } else { PC_TARGET_TYPE }.to_string(),
+        disposition: 0,
+        relationship_type: "acquaintance".to_string(),
+        familiarity: 0,
+        recent_interactions: Vec::new(),
+        notes: String::new(),
+    });
+
+    let before = relationship.disposition;
+    relationship.disposition = (before + change).clamp(-100, 100);
+    relationship.familiarity = relationship.familiarity.saturating_add(FAMILIARITY_PER_INTERACTION).min(100);
+    relationship.recent_interactions.push(interaction);
+    // Keep only last 10 interactions, as the world state does
+    if relationship.recent_interactions.len() > 10 {
+        relationship.recent_interactions.remove(0);
+    }
+    let after = relationship.disposition;
+    world.set_npc_relationship(campaign_id, relationship)?;
+
+    Ok(DispositionChange {
+        npc_id: npc_id.to_string(),
+        target_id: target_id.to_string(),
+        before,
+        after,
+        tier_before: DispositionTier::from_score(before),
+        tier_after: DispositionTier::from_score(after),
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign::world_state::InGameDate;
+
+    fn world() -> WorldStateManager {
+        let world = WorldStateManager::new();
+        world.initialize("camp-1");
+        world
+    }
+
+    #[test]
+    fn test_interactions_move_pc_and_party() {
+        let world = world();
+        let changes = log_interaction(&world, "camp-1", "npc-1", Some("pc-1"), -40, "Punched the barkeep", None).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].after, -40);
+        assert!(changes[0].tier_changed());
+        assert_eq!(changes[1].target_id, PARTY_TARGET_ID);
+        assert_eq!(changes[1].after, -20);
+
+        log_interaction(&world, "camp-1", "npc-1", None, -200, "Burned the tavern", None).unwrap();
+        let dispositions = party_dispositions(&world, "camp-1", "npc-1");
+        assert!(dispositions[0].is_party());
+        assert_eq!(dispositions[0].score, -100);
+        assert_eq!(dispositions[0].tier, DispositionTier::Hostile);
+        assert_eq!(dispositions[0].recent_interactions.len(), 2);
+        assert_eq!(dispositions[1].tier, DispositionTier::Unfriendly);
+    }
+
+    #[test]
+    fn test_world_event_dispositions() {
+        let world = world();
+        let mut event = WorldEvent::new("camp-1", "Saved the mill", "", InGameDate::default());
+        event.metadata.insert(DISPOSITION_FIELD.to_string(), serde_json::json!({ "npc-1": 25, "npc-2": -10 }));
+
+        let changes = apply_world_event(&world, &event).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(party_dispositions(&world, "camp-1", "npc-1")[0].tier, DispositionTier::Friendly);
+
+        event.pc_ids = vec!["pc-1".to_string(), "pc-2".to_string()];
+        let changes = apply_world_event(&world, &event).unwrap();
+        assert_eq!(changes.iter().filter(|c| c.npc_id == "npc-1").count(), 4);
+        assert!(apply_world_event(&world, &WorldEvent::new("camp-1", "Rain", "", InGameDate::default()))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_prompt_section_names_differing_pcs() {
+        let world = world();
+        log_interaction(&world, "camp-1", "npc-1", None, 30, "Returned the ring", None).unwrap();
+        log_interaction(&world, "camp-1", "npc-1", Some("pc-1"), -70, "Stole from the shrine", None).unwrap();
+        log_interaction(&world, "camp-1", "npc-1", Some("pc-2"), 0, "Said hello", None).unwrap();
+
+        let names = HashMap::from([("pc-1".to_string(), "Vex".to_string()), ("pc-2".to_string(), "Ida".to_string())]);
+        let dispositions = party_dispositions(&world, "camp-1", "npc-1");
+        let section = disposition_prompt_section(&dispositions, &names).unwrap();
+        assert!(section.contains("Toward the party you are indifferent"));
+        assert!(section.contains("Toward Vex you are hostile"));
+        // Ida is seen the same way as the rest of the party
+        assert!(!section.contains("Ida"));
+        assert!(disposition_prompt_section(&[], &names).is_none());
+    }
+}
@@ -0,0 +1,756 @@
+//! Encounter Builder Module
+//!
+//! XP budget math for building combat encounters against the party roster:
+//! D&D 5e thresholds and group multipliers from the DMG, and Pathfinder 2e
+//! encounter budgets by creature level. Picks candidate creatures from the
+//! ingested TTRPG documents and keeps saved encounters ready to load into
+//! combat.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::character_gen::GameSystem;
+use crate::core::session::plan_types::EncounterDifficulty;
+use crate::database::TTRPGDocumentRecord;
+
+/// XP for each D&D 5e challenge rating from 1 to 30
+const DND5E_CR_XP: [u32; 30] = [
+    200, 450, 700, 1_100, 1_800, 2_300, 2_900, 3_900, 5_000, 5_900, 7_200, 8_400, 10_000, 11_500, 13_000, 15_000,
+    18_000, 20_000, 22_000, 25_000, 33_000, 41_000, 50_000, 62_000, 75_000, 90_000, 105_000, 120_000, 135_000,
+    155_000,
+];
+
+/// D&D 5e easy, medium, hard, and deadly XP thresholds per character, by level
+const DND5E_THRESHOLDS: [[u32; 4]; 20] = [
+    [25, 50, 75, 100],
+    [50, 100, 150, 200],
+    [75, 150, 225, 400],
+    [125, 250, 375, 500],
+    [250, 500, 750, 1_100],
+    [300, 600, 900, 1_400],
+    [350, 750, 1_100, 1_700],
+    [450, 900, 1_400, 2_100],
+    [550, 1_100, 1_600, 2_400],
+    [600, 1_200, 1_900, 2_800],
+    [800, 1_600, 2_400, 3_600],
+    [1_000, 2_000, 3_000, 4_500],
+    [1_100, 2_200, 3_400, 5_100],
+    [1_250, 2_500, 3_800, 5_700],
+    [1_400, 2_800, 4_300, 6_400],
+    [1_600, 3_200, 4_800, 7_200],
+    [2_000, 3_900, 5_900, 8_800],
+    [2_100, 4_200, 6_300, 9_500],
+    [2_400, 4_900, 7_300, 10_900],
+    [2_800, 5_700, 8_500, 12_700],
+];
+
+/// D&D 5e encounter multipliers, stepped by the number of monsters
+const DND5E_MULTIPLIERS: [f32; 8] = [0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0];
+
+/// PF2e creature XP for a level difference of -4 through +4
+const PF2E_CREATURE_XP: [u32; 9] = [10, 15, 20, 30, 40, 60, 80, 120, 160];
+
+/// PF2e party size the encounter budgets are written for
+const PF2E_BASE_PARTY: i64 = 4;
+
+/// Element types the ingestion pipeline gives creatures
+const CREATURE_ELEMENT_TYPES: &[&str] = &["monster", "creature", "stat_block", "npc"];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum EncounterError {
+    #[error("The party has no active characters")]
+    EmptyParty,
+
+    #[error("Encounter not found: {0}")]
+    NotFound(String),
+
+    #[error("Encounter needs a name")]
+    MissingName,
+
+    #[error("Creature '{0}' needs a count of at least 1")]
+    InvalidCount(String),
+}
+
+pub type Result<T> = std::result::Result<T, EncounterError>;
+
+// ============================================================================
+// Encounter Rules
+// ============================================================================
+
+/// Which budget math an encounter uses
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterRules {
+    /// DMG thresholds per character with group multipliers
+    #[default]
+    Dnd5e,
+    /// GM Core budgets by creature level relative to the party
+    Pathfinder2e,
+}
+
+impl EncounterRules {
+    /// Budget math for a game system, using D&D 5e outside Pathfinder
+    pub fn for_system(system: &str) -> Self {
+        match GameSystem::from_str(system) {
+            GameSystem::Pathfinder2e => Self::Pathfinder2e,
+            _ => Self::Dnd5e,
+        }
+    }
+}
+
+/// XP at which a difficulty starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DifficultyThreshold {
+    pub difficulty: EncounterDifficulty,
+    pub xp: u32,
+}
+
+/// How much XP an encounter of a difficulty may spend on this party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XpBudget {
+    pub rules: EncounterRules,
+    pub difficulty: EncounterDifficulty,
+    pub party_size: usize,
+    /// Average level, rounded
+    pub party_level: u8,
+    pub budget: u32,
+    /// Where each difficulty starts, easiest first
+    pub thresholds: Vec<DifficultyThreshold>,
+}
+
+/// Party level used for PF2e creature XP: the rounded average
+pub fn party_level(levels: &[u8]) -> u8 {
+    if levels.is_empty() {
+        return 1;
+    }
+    let total: u32 = levels.iter().map(|&l| u32::from(l)).sum();
+    ((total as f32 / levels.len() as f32).round() as u8).max(1)
+}
+
+/// Thresholds for Trivial through Deadly. Trivial starts at half of Easy in
+/// D&D 5e.
+fn thresholds(rules: EncounterRules, levels: &[u8]) -> Vec<DifficultyThreshold> {
+    let xp: [u32; 5] = match rules {
+        EncounterRules::Dnd5e => {
+            let mut sums = [0u32; 4];
+            for &level in levels {
+                let row = DND5E_THRESHOLDS[usize::from(level.clamp(1, 20)) - 1];
+                for (sum, xp) in sums.iter_mut().zip(row) {
+                    *sum += xp;
+                }
+            }
+            [sums[0] / 2, sums[0], sums[1], sums[2], sums[3]]
+        }
+        EncounterRules::Pathfinder2e => {
+            let extra = levels.len() as i64 - PF2E_BASE_PARTY;
+            let budget = |base: i64, adjustment: i64| (base + extra * adjustment).max(0) as u32;
+            [budget(40, 10), budget(60, 15), budget(80, 20), budget(120, 30), budget(160, 40)]
+        }
+    };
+    [
+        EncounterDifficulty::Trivial,
+        EncounterDifficulty::Easy,
+        EncounterDifficulty::Medium,
+        EncounterDifficulty::Hard,
+        EncounterDifficulty::Deadly,
+    ]
+    .into_iter()
+    .zip(xp)
+    .map(|(difficulty, xp)| DifficultyThreshold { difficulty, xp })
+    .collect()
+}
+
+/// The XP budget for an encounter of `difficulty` against a party of these
+/// levels. Boss encounters get half again the Deadly (Extreme) budget.
+pub fn xp_budget(rules: EncounterRules, levels: &[u8], difficulty: &EncounterDifficulty) -> Result<XpBudget> {
+    if levels.is_empty() {
+        return Err(EncounterError::EmptyParty);
+    }
+    let thresholds = thresholds(rules, levels);
+    let budget = match difficulty {
+        EncounterDifficulty::Boss => thresholds[4].xp * 3 / 2,
+        other => thresholds.iter().find(|t| &t.difficulty == other).map(|t| t.xp).unwrap_or(0),
+    };
+    Ok(XpBudget {
+        rules,
+        difficulty: difficulty.clone(),
+        party_size: levels.len(),
+        party_level: party_level(levels),
+        budget,
+        thresholds,
+    })
+}
+
+/// XP for a D&D 5e challenge rating
+pub fn cr_to_xp(cr: f32) -> Option<u32> {
+    match cr {
+        cr if cr < 0.0 => None,
+        cr if cr < 0.125 => Some(10),
+        cr if cr < 0.25 => Some(25),
+        cr if cr < 0.5 => Some(50),
+        cr if cr < 1.0 => Some(100),
+        cr => DND5E_CR_XP.get(cr as usize - 1).copied(),
+    }
+}
+
+/// Parse a challenge rating such as "1/4" or "5"
+pub fn parse_cr(s: &str) -> Option<f32> {
+    let s = s.trim();
+    match s.split_once('/') {
+        Some((n, d)) => {
+            let (n, d) = (n.trim().parse::<f32>().ok()?, d.trim().parse::<f32>().ok()?);
+            (d > 0.0).then(|| n / d)
+        }
+        None => s.parse().ok(),
+    }
+}
+
+/// XP for a PF2e creature of `level` against a party of `party_level`.
+/// Creatures more than four levels below the party are worth nothing;
+/// more than four above are off the chart.
+pub fn pf2e_creature_xp(level: i32, party_level: u8) -> Option<u32> {
+    let diff = level - i32::from(party_level);
+    match diff {
+        d if d < -4 => Some(0),
+        d => PF2E_CREATURE_XP.get((d + 4) as usize).copied(),
+    }
+}
+
+/// The D&D 5e multiplier for a number of monsters against a party size
+pub fn encounter_multiplier(monsters: u32, party_size: usize) -> f32 {
+    let step: usize = match monsters {
+        0 | 1 => 1,
+        2 => 2,
+        3..=6 => 3,
+        7..=10 => 4,
+        11..=14 => 5,
+        _ => 6,
+    };
+    let step = match party_size {
+        0..=2 => step + 1,
+        6.. => step - 1,
+        _ => step,
+    };
+    DND5E_MULTIPLIERS[step]
+}
+
+// ============================================================================
+// Encounter Creatures
+// ============================================================================
+
+/// A group of identical creatures in an encounter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterCreature {
+    pub name: String,
+    pub count: u32,
+    /// D&D 5e challenge rating
+    pub challenge_rating: Option<f32>,
+    /// PF2e creature level
+    pub level: Option<i32>,
+    /// Ingested TTRPG document the creature came from
+    pub document_id: Option<String>,
+    pub creature_type: Option<String>,
+    pub max_hp: Option<i32>,
+    pub armor_class: Option<i32>,
+    #[serde(default)]
+    pub initiative_modifier: i32,
+}
+
+impl EncounterCreature {
+    pub fn new(name: impl Into<String>, count: u32) -> Self {
+        Self {
+            name: name.into(),
+            count,
+            challenge_rating: None,
+            level: None,
+            document_id: None,
+            creature_type: None,
+            max_hp: None,
+            armor_class: None,
+            initiative_modifier: 0,
+        }
+    }
+
+    /// Builder: set challenge rating
+    pub fn with_cr(mut self, cr: f32) -> Self {
+        self.challenge_rating = Some(cr);
+        self
+    }
+
+    /// Builder: set creature level
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Builder: set hit points and armor class
+    pub fn with_stats(mut self, max_hp: i32, armor_class: i32) -> Self {
+        self.max_hp = Some(max_hp);
+        self.armor_class = Some(armor_class);
+        self
+    }
+
+    /// XP for one of these creatures, when its CR or level is known
+    pub fn xp_each(&self, rules: EncounterRules, party_level: u8) -> Option<u32> {
+        match rules {
+            EncounterRules::Dnd5e => cr_to_xp(self.challenge_rating?),
+            EncounterRules::Pathfinder2e => pf2e_creature_xp(self.level?, party_level),
+        }
+    }
+}
+
+/// How an encounter measures up against the party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterEvaluation {
+    /// XP the creatures are worth
+    pub base_xp: u32,
+    /// XP after the group multiplier, compared against the thresholds
+    pub adjusted_xp: u32,
+    pub multiplier: f32,
+    /// Hardest difficulty the adjusted XP reaches
+    pub rating: EncounterDifficulty,
+    pub budget: XpBudget,
+    /// Budget left before the target difficulty is exceeded (negative when over)
+    pub remaining_budget: i64,
+    /// Creatures without a CR or level, left out of the totals
+    pub unrated: Vec<String>,
+}
+
+/// Rate creatures against a party for a target difficulty
+pub fn evaluate_encounter(
+    rules: EncounterRules,
+    creatures: &[EncounterCreature],
+    levels: &[u8],
+    target: &EncounterDifficulty,
+) -> Result<EncounterEvaluation> {
+    let budget = xp_budget(rules, levels, target)?;
+    let mut base_xp = 0;
+    let mut unrated = Vec::new();
+    for creature in creatures {
+        match creature.xp_each(rules, budget.party_level) {
+            Some(xp) => base_xp += xp * creature.count,
+            None => unrated.push(creature.name.clone()),
+        }
+    }
+
+    let multiplier = match rules {
+        EncounterRules::Dnd5e => {
+            // Monsters too weak to matter don't count toward the multiplier
+            let weakest_counted = creatures
+                .iter()
+                .filter_map(|c| c.xp_each(rules, budget.party_level))
+                .max()
+                .unwrap_or(0)
+                / 10;
+            let monsters = creatures
+                .iter()
+                .filter(|c| c.xp_each(rules, budget.party_level).is_some_and(|xp| xp > weakest_counted))
+                .map(|c| c.count)
+                .sum();
+            encounter_multiplier(monsters, levels.len())
+        }
+        EncounterRules::Pathfinder2e => 1.0,
+    };
+    let adjusted_xp = (base_xp as f32 * multiplier).round() as u32;
+    let rating = budget
+        .thresholds
+        .iter()
+        .rev()
+        .find(|t| adjusted_xp >= t.xp)
+        .map(|t| t.difficulty.clone())
+        .unwrap_or(EncounterDifficulty::Trivial);
+
+    Ok(EncounterEvaluation {
+        base_xp,
+        adjusted_xp,
+        multiplier,
+        rating,
+        remaining_budget: i64::from(budget.budget) - i64::from(adjusted_xp),
+        budget,
+        unrated,
+    })
+}
+
+// ============================================================================
+// Creature Candidates
+// ============================================================================
+
+/// A creature from the ingested documents that could join an encounter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatureCandidate {
+    pub document_id: String,
+    pub name: String,
+    pub game_system: String,
+    pub challenge_rating: Option<f32>,
+    pub level: Option<i32>,
+    pub creature_type: Option<String>,
+    pub environments: Vec<String>,
+    pub max_hp: Option<i32>,
+    pub armor_class: Option<i32>,
+    pub initiative_modifier: i32,
+    /// XP for one against the party
+    pub xp: Option<u32>,
+}
+
+impl CreatureCandidate {
+    /// Read a creature from an ingested document, if it is one
+    pub fn from_record(record: &TTRPGDocumentRecord) -> Option<Self> {
+        if !CREATURE_ELEMENT_TYPES.contains(&record.element_type.to_lowercase().as_str()) {
+            return None;
+        }
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let number = |value: Option<&serde_json::Value>, field: &str| {
+            value.and_then(|v| v.as_i64().or_else(|| v.get(field)?.as_i64())).map(|n| n as i32)
+        };
+        let environments = match attributes.get("environments").or_else(|| attributes.get("environment")) {
+            Some(serde_json::Value::Array(values)) => {
+                values.iter().filter_map(|v| v.as_str()).map(str::to_lowercase).collect()
+            }
+            Some(serde_json::Value::String(s)) => s.split(',').map(|e| e.trim().to_lowercase()).collect(),
+            _ => Vec::new(),
+        };
+        let dexterity = attributes
+            .get("ability_scores")
+            .and_then(|a| a.get("dexterity"))
+            .and_then(|v| v.as_i64());
+
+        Some(Self {
+            document_id: record.id.clone(),
+            name: record.name.clone(),
+            game_system: record.game_system.clone(),
+            challenge_rating: record.challenge_rating.map(|cr| cr as f32),
+            level: record.level,
+            creature_type: attributes
+                .get("creature_type")
+                .or_else(|| attributes.get("type"))
+                .and_then(|v| v.as_str())
+                .map(str::to_lowercase),
+            environments,
+            max_hp: number(attributes.get("hit_points").or_else(|| attributes.get("hp")), "average"),
+            armor_class: number(attributes.get("armor_class").or_else(|| attributes.get("ac")), "value"),
+            initiative_modifier: dexterity.map(|d| (d as i32 - 10).div_euclid(2)).unwrap_or(0),
+            xp: None,
+        })
+    }
+
+    /// This creature as an encounter group
+    pub fn to_creature(&self, count: u32) -> EncounterCreature {
+        EncounterCreature {
+            name: self.name.clone(),
+            count,
+            challenge_rating: self.challenge_rating,
+            level: self.level,
+            document_id: Some(self.document_id.clone()),
+            creature_type: self.creature_type.clone(),
+            max_hp: self.max_hp,
+            armor_class: self.armor_class,
+            initiative_modifier: self.initiative_modifier,
+        }
+    }
+}
+
+/// What to look for among the ingested creatures
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CandidateQuery {
+    pub min_cr: Option<f32>,
+    pub max_cr: Option<f32>,
+    pub creature_type: Option<String>,
+    pub environment: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// Creatures from the records that match the query and fit in the budget on
+/// their own, strongest first. Without an environment tag, a creature
+/// matches an environment its text mentions.
+pub fn select_candidates(
+    records: &[TTRPGDocumentRecord],
+    query: &CandidateQuery,
+    budget: &XpBudget,
+) -> Vec<CreatureCandidate> {
+    let creature_type = query.creature_type.as_deref().map(str::to_lowercase);
+    let environment = query.environment.as_deref().map(str::to_lowercase);
+    let mut candidates: Vec<CreatureCandidate> = records
+        .iter()
+        .filter_map(|record| {
+            let mut candidate = CreatureCandidate::from_record(record)?;
+            let rating = match budget.rules {
+                EncounterRules::Dnd5e => candidate.challenge_rating,
+                EncounterRules::Pathfinder2e => candidate.level.map(|l| l as f32),
+            };
+            if query.min_cr.is_some_and(|min| rating.is_none_or(|r| r < min))
+                || query.max_cr.is_some_and(|max| rating.is_none_or(|r| r > max))
+            {
+                return None;
+            }
+            if let Some(wanted) = &creature_type {
+                if !candidate.creature_type.as_deref().is_some_and(|t| t.contains(wanted.as_str())) {
+                    return None;
+                }
+            }
+            if let Some(wanted) = &environment {
+                let tagged = candidate.environments.iter().any(|e| e == wanted);
+                let mentioned = candidate.environments.is_empty() && record.content.to_lowercase().contains(wanted.as_str());
+                if !tagged && !mentioned {
+                    return None;
+                }
+            }
+            candidate.xp = candidate.to_creature(1).xp_each(budget.rules, budget.party_level);
+            candidate.xp.filter(|&xp| xp > 0 && xp <= budget.budget)?;
+            Some(candidate)
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.xp.cmp(&a.xp).then_with(|| a.name.cmp(&b.name)));
+    candidates.dedup_by(|a, b| a.name.eq_ignore_ascii_case(&b.name));
+    if let Some(limit) = query.limit {
+        candidates.truncate(limit);
+    }
+    candidates
+}
+
+// ============================================================================
+// Saved Encounters
+// ============================================================================
+
+/// An encounter saved for a campaign, ready to load into combat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedEncounter {
+    pub id: String,
+    pub campaign_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub rules: EncounterRules,
+    pub target_difficulty: EncounterDifficulty,
+    pub creatures: Vec<EncounterCreature>,
+    pub environment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SavedEncounter {
+    pub fn new(campaign_id: &str, name: &str, rules: EncounterRules, target_difficulty: EncounterDifficulty) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            rules,
+            target_difficulty,
+            creatures: Vec::new(),
+            environment: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Builder: add a creature group
+    pub fn with_creature(mut self, creature: EncounterCreature) -> Self {
+        self.creatures.push(creature);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(EncounterError::MissingName);
+        }
+        if let Some(c) = self.creatures.iter().find(|c| c.count == 0) {
+            return Err(EncounterError::InvalidCount(c.name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Combatant names for each creature, numbered when there are several
+    pub fn combatant_names(creature: &EncounterCreature) -> Vec<String> {
+        match creature.count {
+            1 => vec![creature.name.clone()],
+            n => (1..=n).map(|i| format!("{} {}", creature.name, i)).collect(),
+        }
+    }
+}
+
+pub struct EncounterManager {
+    encounters: RwLock<HashMap<String, SavedEncounter>>,
+}
+
+impl Default for EncounterManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EncounterManager {
+    pub fn new() -> Self {
+        Self {
+            encounters: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Save a new encounter or replace an existing one
+    pub fn save(&self, mut encounter: SavedEncounter) -> Result<SavedEncounter> {
+        encounter.validate()?;
+        encounter.updated_at = Utc::now();
+        self.encounters
+            .write()
+            .unwrap()
+            .insert(encounter.id.clone(), encounter.clone());
+        Ok(encounter)
+    }
+
+    pub fn get(&self, encounter_id: &str) -> Result<SavedEncounter> {
+        self.encounters
+            .read()
+            .unwrap()
+            .get(encounter_id)
+            .cloned()
+            .ok_or_else(|| EncounterError::NotFound(encounter_id.to_string()))
+    }
+
+    /// A campaign's encounters, most recently updated first
+    pub fn list(&self, campaign_id: &str) -> Vec<SavedEncounter> {
+        let mut encounters: Vec<SavedEncounter> = self
+            .encounters
+            .read()
+            .unwrap()
+            .values()
+            .filter(|e| e.campaign_id == campaign_id)
+            .cloned()
+            .collect();
+        encounters.sort_by_key(|e| std::cmp::Reverse(e.updated_at));
+        encounters
+    }
+
+    pub fn delete(&self, encounter_id: &str) -> Result<()> {
+        self.encounters
+            .write()
+            .unwrap()
+            .remove(encounter_id)
+            .map(|_| ())
+            .ok_or_else(|| EncounterError::NotFound(encounter_id.to_string()))
+    }
+
+    pub fn delete_campaign_encounters(&self, campaign_id: &str) {
+        self.encounters.write().unwrap().retain(|_, e| e.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, cr: f64, attributes: serde_json::Value) -> TTRPGDocumentRecord {
+        TTRPGDocumentRecord::new(
+            format!("doc-{}", name),
+            "mm".to_string(),
+            name.to_string(),
+            "monster".to_string(),
+            "dnd5e".to_string(),
+            format!("{} lurks in the forest.", name),
+            0.9,
+        )
+        .with_cr(cr)
+        .with_attributes(attributes)
+    }
+
+    #[test]
+    fn test_dnd5e_budget() {
+        let budget = xp_budget(EncounterRules::Dnd5e, &[3, 3, 3, 3], &EncounterDifficulty::Medium).unwrap();
+        assert_eq!(budget.budget, 600);
+        assert_eq!(budget.thresholds[4].xp, 1_600);
+        assert_eq!(budget.thresholds[0].xp, 150);
+
+        let boss = xp_budget(EncounterRules::Dnd5e, &[3, 3, 3, 3], &EncounterDifficulty::Boss).unwrap();
+        assert_eq!(boss.budget, 2_400);
+        assert!(xp_budget(EncounterRules::Dnd5e, &[], &EncounterDifficulty::Easy).is_err());
+    }
+
+    #[test]
+    fn test_cr_and_multipliers() {
+        assert_eq!(cr_to_xp(0.25), Some(50));
+        assert_eq!(cr_to_xp(0.0), Some(10));
+        assert_eq!(cr_to_xp(5.0), Some(1_800));
+        assert_eq!(cr_to_xp(31.0), None);
+        assert_eq!(parse_cr("1/8"), Some(0.125));
+        assert_eq!(parse_cr(" 7 "), Some(7.0));
+
+        assert_eq!(encounter_multiplier(1, 4), 1.0);
+        assert_eq!(encounter_multiplier(4, 4), 2.0);
+        assert_eq!(encounter_multiplier(4, 2), 2.5);
+        assert_eq!(encounter_multiplier(1, 6), 0.5);
+        assert_eq!(encounter_multiplier(20, 4), 4.0);
+    }
+
+    #[test]
+    fn test_evaluate_encounter() {
+        let creatures = vec![
+            EncounterCreature::new("Goblin", 4).with_cr(0.25),
+            EncounterCreature::new("Bugbear", 1).with_cr(1.0),
+            EncounterCreature::new("Mystery", 1),
+        ];
+        let eval = evaluate_encounter(EncounterRules::Dnd5e, &creatures, &[3, 3, 3, 3], &EncounterDifficulty::Medium).unwrap();
+        assert_eq!(eval.base_xp, 400);
+        assert_eq!(eval.multiplier, 2.0);
+        assert_eq!(eval.adjusted_xp, 800);
+        assert_eq!(eval.rating, EncounterDifficulty::Medium);
+        assert_eq!(eval.remaining_budget, -200);
+        assert_eq!(eval.unrated, vec!["Mystery".to_string()]);
+    }
+
+    #[test]
+    fn test_pf2e_budget_and_creature_xp() {
+        let levels = [5, 5, 5, 5, 5];
+        let budget = xp_budget(EncounterRules::Pathfinder2e, &levels, &EncounterDifficulty::Medium).unwrap();
+        assert_eq!(budget.budget, 100);
+        assert_eq!(pf2e_creature_xp(7, 5), Some(80));
+        assert_eq!(pf2e_creature_xp(0, 5), Some(0));
+        assert_eq!(pf2e_creature_xp(10, 5), None);
+
+        let creatures = vec![EncounterCreature::new("Troll", 1).with_level(7), EncounterCreature::new("Wolf", 1).with_level(4)];
+        let eval = evaluate_encounter(EncounterRules::Pathfinder2e, &creatures, &levels, &EncounterDifficulty::Medium).unwrap();
+        assert_eq!(eval.adjusted_xp, 110);
+        assert_eq!(eval.rating, EncounterDifficulty::Medium);
+    }
+
+    #[test]
+    fn test_select_candidates() {
+        let records = vec![
+            record("Owlbear", 3.0, serde_json::json!({"creature_type": "Monstrosity", "environments": ["forest"],
+                "hit_points": {"average": 59}, "armor_class": {"value": 13}, "ability_scores": {"dexterity": 12}})),
+            record("Wolf", 0.25, serde_json::json!({"creature_type": "beast"})),
+            record("Adult Red Dragon", 17.0, serde_json::json!({"creature_type": "dragon"})),
+            record("Kraken", 23.0, serde_json::json!({"creature_type": "monstrosity", "environments": ["underwater"]})),
+        ];
+        let budget = xp_budget(EncounterRules::Dnd5e, &[3, 3, 3, 3], &EncounterDifficulty::Hard).unwrap();
+        let found = select_candidates(&records, &CandidateQuery { environment: Some("Forest".into()), ..Default::default() }, &budget);
+        let names: Vec<_> = found.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Owlbear", "Wolf"]);
+
+        let owlbear = found[0].to_creature(1);
+        assert_eq!((owlbear.max_hp, owlbear.armor_class, owlbear.initiative_modifier), (Some(59), Some(13), 1));
+
+        let beasts = select_candidates(&records, &CandidateQuery { creature_type: Some("beast".into()), ..Default::default() }, &budget);
+        assert_eq!(beasts.len(), 1);
+
+        let manager = EncounterManager::new();
+        let saved = manager
+            .save(SavedEncounter::new("camp-1", "Forest ambush", EncounterRules::Dnd5e, EncounterDifficulty::Hard).with_creature(owlbear))
+            .unwrap();
+        assert_eq!(manager.list("camp-1").len(), 1);
+        assert!(manager.save(SavedEncounter::new("camp-1", " ", EncounterRules::Dnd5e, EncounterDifficulty::Easy)).is_err());
+        manager.delete(&saved.id).unwrap();
+        assert!(manager.get(&saved.id).is_err());
+    }
+}
@@ -0,0 +1,397 @@
+//! Campaign Encryption Module
+//!
+//! Encryption at rest for campaigns holding sensitive notes. Each encrypted
+//! campaign has its own AES-256-GCM data key, kept in the OS keyring. The
+//! data key is also wrapped with a key derived from a recovery phrase and
+//! stored alongside the ciphertext, so the campaign can still be opened if
+//! the keyring entry is lost.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::core::campaign_manager::CampaignExport;
+
+/// Current on-disk format version
+pub const ENCRYPTION_FORMAT_VERSION: u32 = 1;
+
+/// File extension for encrypted campaign files
+pub const ENCRYPTED_CAMPAIGN_EXTENSION: &str = "campaign.enc";
+
+/// Words in a generated recovery phrase (96 bits of entropy)
+pub const RECOVERY_PHRASE_WORDS: usize = 12;
+
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+
+/// Recovery phrase vocabulary; one byte of entropy per word
+const WORDLIST: [&str; 256] = [
+    "amber", "anvil", "arrow", "ash", "aspen", "autumn", "axe", "badger", "banner", "barrel", "basil", "beacon",
+    "bear", "bell", "birch", "blade", "bloom", "boar", "bone", "bow", "bramble", "brass", "bread", "bridge", "brook",
+    "candle", "canyon", "cape", "castle", "cedar", "chalk", "chapel", "cherry", "chest", "cider", "clay", "cliff",
+    "cloak", "clover", "coal", "cobalt", "comet", "copper", "coral", "crab", "crane", "crest", "crow", "crown",
+    "crystal", "cup", "dagger", "dawn", "deer", "dew", "dove", "dragon", "drum", "dune", "dusk", "eagle", "ember",
+    "falcon", "fang", "feather", "fern", "fiddle", "fig", "flame", "flint", "fog", "forest", "forge", "fox", "frost",
+    "gale", "garnet", "gate", "gem", "ghost", "giant", "glade", "glass", "glove", "goat", "gold", "goose", "grain",
+    "granite", "grape", "griffin", "grove", "gull", "hammer", "harbor", "harp", "hawk", "hazel", "heath", "helm",
+    "hen", "herb", "heron", "hill", "hive", "holly", "honey", "horn", "horse", "hound", "ice", "inn", "iris", "iron",
+    "ivory", "ivy", "jade", "jasper", "jewel", "kettle", "key", "king", "kite", "knight", "lake", "lamp", "lance",
+    "lantern", "lark", "leaf", "ledge", "lemon", "lily", "lion", "lizard", "loom", "lotus", "lute", "lynx", "maple",
+    "marble", "marsh", "mast", "meadow", "mill", "mint", "mist", "moat", "monk", "moon", "moss", "moth", "mountain",
+    "mule", "oak", "oar", "oasis", "ocean", "olive", "onyx", "opal", "orchid", "otter", "owl", "ox", "pearl",
+    "pepper", "pine", "plum", "pond", "pony", "poppy", "quartz", "quill", "rabbit", "raven", "reed", "ridge", "river",
+    "robe", "robin", "rose", "ruby", "rune", "rye", "saddle", "sage", "sail", "salt", "sand", "scroll", "sea", "seal",
+    "shadow", "shell", "shield", "ship", "silk", "silver", "sky", "slate", "sleet", "snow", "sparrow", "spear",
+    "spice", "spire", "spring", "spruce", "staff", "star", "stone", "storm", "stream", "sun", "swan", "sword", "tale",
+    "thistle", "thorn", "thunder", "tide", "tiger", "timber", "toad", "tomb", "torch", "tower", "trail", "tree",
+    "troll", "tulip", "tundra", "valley", "vault", "velvet", "vine", "violet", "wagon", "wand", "wasp", "wave",
+    "well", "whale", "wheat", "willow", "wind", "wing", "winter", "wolf", "wren", "yarrow", "yew", "zephyr",
+    "cobble", "elm",
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum EncryptionError {
+    #[error("Invalid encryption key")]
+    InvalidKey,
+
+    #[error("Recovery phrase must be {RECOVERY_PHRASE_WORDS} words from the recovery word list")]
+    InvalidPhrase,
+
+    #[error("Decryption failed: wrong key or corrupted data")]
+    DecryptionFailed,
+
+    #[error("Key derivation failed: {0}")]
+    KeyDerivation(String),
+
+    #[error("Unsupported encrypted campaign version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, EncryptionError>;
+
+// ============================================================================
+// Keys and Recovery Phrases
+// ============================================================================
+
+/// Keyring entry holding a campaign's data key
+pub fn key_entry(campaign_id: &str) -> String {
+    format!("campaign_key_{}", campaign_id)
+}
+
+/// Keyring entry holding a campaign's recovery phrase
+pub fn recovery_entry(campaign_id: &str) -> String {
+    format!("campaign_recovery_{}", campaign_id)
+}
+
+/// A campaign's AES-256 data key
+#[derive(Clone, PartialEq, Eq)]
+pub struct CampaignKey([u8; KEY_LEN]);
+
+impl CampaignKey {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; KEY_LEN];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Encode for storage in the keyring
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.0)
+    }
+
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = STANDARD.decode(encoded.trim()).map_err(|_| EncryptionError::InvalidKey)?;
+        let bytes: [u8; KEY_LEN] = bytes.try_into().map_err(|_| EncryptionError::InvalidKey)?;
+        Ok(Self(bytes))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0))
+    }
+}
+
+impl fmt::Debug for CampaignKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CampaignKey(..)")
+    }
+}
+
+/// Words that can re-derive a campaign's data key
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecoveryPhrase(String);
+
+impl RecoveryPhrase {
+    pub fn generate() -> Self {
+        let mut rng = OsRng;
+        let words: Vec<&str> = (0..RECOVERY_PHRASE_WORDS)
+            .map(|_| *WORDLIST.choose(&mut rng).expect("wordlist is not empty"))
+            .collect();
+        Self(words.join(" "))
+    }
+
+    /// Parse a phrase as typed, ignoring case and extra whitespace
+    pub fn parse(phrase: &str) -> Result<Self> {
+        let words: Vec<String> = phrase.split_whitespace().map(|w| w.to_lowercase()).collect();
+        if words.len() != RECOVERY_PHRASE_WORDS || !words.iter().all(|w| WORDLIST.contains(&w.as_str())) {
+            return Err(EncryptionError::InvalidPhrase);
+        }
+        Ok(Self(words.join(" ")))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn derive_key(&self, salt: &[u8]) -> Result<CampaignKey> {
+        let mut bytes = [0u8; KEY_LEN];
+        Argon2::default()
+            .hash_password_into(self.0.as_bytes(), salt, &mut bytes)
+            .map_err(|e| EncryptionError::KeyDerivation(e.to_string()))?;
+        Ok(CampaignKey(bytes))
+    }
+}
+
+impl fmt::Debug for RecoveryPhrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RecoveryPhrase(..)")
+    }
+}
+
+// ============================================================================
+// Sealed Data
+// ============================================================================
+
+/// AES-GCM ciphertext with its nonce, base64 encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedBox {
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl SealedBox {
+    /// Encrypt, binding the ciphertext to `context` so it can't be moved to another campaign
+    fn seal(key: &CampaignKey, plaintext: &[u8], context: &str) -> Result<Self> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = key
+            .cipher()
+            .encrypt(&nonce, Payload { msg: plaintext, aad: context.as_bytes() })
+            .map_err(|_| EncryptionError::InvalidKey)?;
+        Ok(Self {
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    fn open(&self, key: &CampaignKey, context: &str) -> Result<Vec<u8>> {
+        let nonce = STANDARD.decode(&self.nonce).map_err(|_| EncryptionError::DecryptionFailed)?;
+        if nonce.len() != 12 {
+            return Err(EncryptionError::DecryptionFailed);
+        }
+        let ciphertext = STANDARD
+            .decode(&self.ciphertext)
+            .map_err(|_| EncryptionError::DecryptionFailed)?;
+        key.cipher()
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: context.as_bytes() })
+            .map_err(|_| EncryptionError::DecryptionFailed)
+    }
+}
+
+/// A data key wrapped with a recovery phrase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WrappedKey {
+    pub salt: String,
+    pub key: SealedBox,
+}
+
+impl WrappedKey {
+    pub fn wrap(key: &CampaignKey, phrase: &RecoveryPhrase, campaign_id: &str) -> Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let wrapping_key = phrase.derive_key(&salt)?;
+        Ok(Self {
+            salt: STANDARD.encode(salt),
+            key: SealedBox::seal(&wrapping_key, &key.0, campaign_id)?,
+        })
+    }
+
+    pub fn unwrap_key(&self, phrase: &RecoveryPhrase, campaign_id: &str) -> Result<CampaignKey> {
+        let salt = STANDARD.decode(&self.salt).map_err(|_| EncryptionError::DecryptionFailed)?;
+        let wrapping_key = phrase.derive_key(&salt)?;
+        let bytes = self.key.open(&wrapping_key, campaign_id)?;
+        let bytes: [u8; KEY_LEN] = bytes.try_into().map_err(|_| EncryptionError::InvalidKey)?;
+        Ok(CampaignKey(bytes))
+    }
+}
+
+/// Everything needed to re-encrypt an unlocked campaign
+#[derive(Debug, Clone)]
+pub struct CampaignKeys {
+    pub data_key: CampaignKey,
+    pub recovery: WrappedKey,
+}
+
+impl CampaignKeys {
+    /// Fresh keys for a campaign, returning the phrase to show the GM once
+    pub fn generate(campaign_id: &str) -> Result<(Self, RecoveryPhrase)> {
+        let data_key = CampaignKey::generate();
+        let phrase = RecoveryPhrase::generate();
+        let recovery = WrappedKey::wrap(&data_key, &phrase, campaign_id)?;
+        Ok((Self { data_key, recovery }, phrase))
+    }
+}
+
+// ============================================================================
+// Encrypted Campaign File
+// ============================================================================
+
+/// An encrypted campaign as stored on disk. Only the ID and name are in the clear.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedCampaign {
+    pub version: u32,
+    pub campaign_id: String,
+    pub name: String,
+    pub encrypted_at: DateTime<Utc>,
+    pub recovery: WrappedKey,
+    pub payload: SealedBox,
+}
+
+impl EncryptedCampaign {
+    pub fn seal(export: &CampaignExport, keys: &CampaignKeys) -> Result<Self> {
+        let campaign_id = &export.campaign.id;
+        let plaintext = serde_json::to_vec(export)?;
+        Ok(Self {
+            version: ENCRYPTION_FORMAT_VERSION,
+            campaign_id: campaign_id.clone(),
+            name: export.campaign.name.clone(),
+            encrypted_at: Utc::now(),
+            recovery: keys.recovery.clone(),
+            payload: SealedBox::seal(&keys.data_key, &plaintext, campaign_id)?,
+        })
+    }
+
+    pub fn open(&self, key: &CampaignKey) -> Result<CampaignExport> {
+        if self.version != ENCRYPTION_FORMAT_VERSION {
+            return Err(EncryptionError::UnsupportedVersion(self.version));
+        }
+        let plaintext = self.payload.open(key, &self.campaign_id)?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    /// Recover the data key from the recovery phrase
+    pub fn recover_key(&self, phrase: &RecoveryPhrase) -> Result<CampaignKey> {
+        self.recovery.unwrap_key(phrase, &self.campaign_id)
+    }
+
+    pub fn keys(&self, data_key: CampaignKey) -> CampaignKeys {
+        CampaignKeys {
+            data_key,
+            recovery: self.recovery.clone(),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Write via a temp file so a crash never leaves a half-written campaign
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign_manager::CampaignManager;
+
+    fn export() -> CampaignExport {
+        let manager = CampaignManager::new();
+        let campaign = manager.create_campaign("Curse of Strahd", "dnd5e");
+        manager.add_note(&campaign.id, "Ireena's player is moving away in March", vec![], None);
+        manager.export_campaign(&campaign.id).unwrap()
+    }
+
+    #[test]
+    fn test_wordlist_is_unique() {
+        let mut words = WORDLIST.to_vec();
+        words.sort_unstable();
+        words.dedup();
+        assert_eq!(words.len(), WORDLIST.len());
+    }
+
+    #[test]
+    fn test_seal_and_open_round_trip() {
+        let export = export();
+        let (keys, _) = CampaignKeys::generate(&export.campaign.id).unwrap();
+        let sealed = EncryptedCampaign::seal(&export, &keys).unwrap();
+        assert!(!sealed.payload.ciphertext.contains("Ireena"));
+
+        let opened = sealed.open(&keys.data_key).unwrap();
+        assert_eq!(opened.campaign.name, "Curse of Strahd");
+        assert_eq!(opened.notes[0].content, "Ireena's player is moving away in March");
+
+        assert!(matches!(sealed.open(&CampaignKey::generate()), Err(EncryptionError::DecryptionFailed)));
+    }
+
+    #[test]
+    fn test_recovery_phrase_recovers_key() {
+        let export = export();
+        let (keys, phrase) = CampaignKeys::generate(&export.campaign.id).unwrap();
+        let sealed = EncryptedCampaign::seal(&export, &keys).unwrap();
+
+        let typed = format!("  {}  ", phrase.as_str().to_uppercase());
+        let recovered = sealed.recover_key(&RecoveryPhrase::parse(&typed).unwrap()).unwrap();
+        assert_eq!(recovered, keys.data_key);
+
+        let wrong = RecoveryPhrase::parse(&["amber"; RECOVERY_PHRASE_WORDS].join(" ")).unwrap();
+        assert!(sealed.recover_key(&wrong).is_err());
+    }
+
+    #[test]
+    fn test_phrase_and_key_validation() {
+        assert!(matches!(RecoveryPhrase::parse("amber anvil"), Err(EncryptionError::InvalidPhrase)));
+        let unknown = ["xylophone"; RECOVERY_PHRASE_WORDS].join(" ");
+        assert!(RecoveryPhrase::parse(&unknown).is_err());
+
+        let key = CampaignKey::generate();
+        assert_eq!(CampaignKey::from_base64(&key.to_base64()).unwrap(), key);
+        assert!(CampaignKey::from_base64("c2hvcnQ=").is_err());
+        assert_eq!(format!("{:?}", key), "CampaignKey(..)");
+    }
+
+    #[test]
+    fn test_ciphertext_bound_to_campaign() {
+        let export = export();
+        let (keys, _) = CampaignKeys::generate(&export.campaign.id).unwrap();
+        let mut sealed = EncryptedCampaign::seal(&export, &keys).unwrap();
+        sealed.campaign_id = "another-campaign".to_string();
+        assert!(sealed.open(&keys.data_key).is_err());
+    }
+}
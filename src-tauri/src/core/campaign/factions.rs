@@ -0,0 +1,745 @@
+//! Factions Module
+//!
+//! Factions and organizations with goals, resources, members, and territory.
+//! Each faction has an influence score and progress clocks that advance as
+//! world events involving the faction are recorded. Faction webs are built on
+//! top of the entity relationship graph.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::relationships::{EntityGraph, EntityType, GraphEdge, GraphNode, RelationshipType};
+use super::templates::CampaignFaction;
+use super::world_state::{EventImpact, WorldEvent, WorldEventType};
+
+/// World event metadata key listing the IDs of factions involved
+pub const FACTION_IDS_FIELD: &str = "faction_ids";
+
+/// World event metadata key mapping faction IDs to influence changes
+pub const INFLUENCE_FIELD: &str = "faction_influence";
+
+/// Influence range
+pub const MIN_INFLUENCE: i32 = 0;
+pub const MAX_INFLUENCE: i32 = 100;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum FactionError {
+    #[error("Faction not found: {0}")]
+    FactionNotFound(String),
+
+    #[error("Clock not found: {0}")]
+    ClockNotFound(String),
+
+    #[error("Faction name cannot be empty")]
+    EmptyName,
+
+    #[error("Clock needs at least one segment")]
+    InvalidClock,
+}
+
+pub type Result<T> = std::result::Result<T, FactionError>;
+
+// ============================================================================
+// Faction Types
+// ============================================================================
+
+/// Something a faction is working toward
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionGoal {
+    pub description: String,
+    #[serde(default)]
+    pub achieved: bool,
+}
+
+/// A resource a faction commands (coin, soldiers, spies, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionResource {
+    pub name: String,
+    pub amount: i32,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// An NPC belonging to a faction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionMember {
+    pub npc_id: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub is_leader: bool,
+}
+
+/// A progress clock for a faction project or threat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionClock {
+    pub id: String,
+    pub name: String,
+    pub segments: u32,
+    pub filled: u32,
+    /// World event types that advance the clock; empty means any event
+    /// involving the faction
+    #[serde(default)]
+    pub triggers: Vec<WorldEventType>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl FactionClock {
+    pub fn new(name: &str, segments: u32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            segments,
+            filled: 0,
+            triggers: Vec::new(),
+            completed_at: None,
+        }
+    }
+
+    /// Builder: only advance on these event types
+    pub fn with_triggers(mut self, triggers: Vec<WorldEventType>) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.filled >= self.segments
+    }
+
+    /// Fill segments (negative to empty them); returns true if this filled
+    /// the clock
+    pub fn advance(&mut self, ticks: i32) -> bool {
+        let was_complete = self.is_complete();
+        self.filled = (self.filled as i32 + ticks).clamp(0, self.segments as i32) as u32;
+        if self.is_complete() && !was_complete {
+            self.completed_at = Some(Utc::now());
+            true
+        } else {
+            if !self.is_complete() {
+                self.completed_at = None;
+            }
+            false
+        }
+    }
+
+    fn triggered_by(&self, event_type: &WorldEventType) -> bool {
+        self.triggers.is_empty() || self.triggers.contains(event_type)
+    }
+}
+
+/// A faction or organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Faction {
+    pub id: String,
+    pub campaign_id: String,
+    pub name: String,
+    pub description: String,
+    pub goals: Vec<FactionGoal>,
+    pub resources: Vec<FactionResource>,
+    pub members: Vec<FactionMember>,
+    /// Location IDs the faction controls
+    pub territory: Vec<String>,
+    /// Standing in the world, 0-100
+    pub influence: i32,
+    pub clocks: Vec<FactionClock>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Faction {
+    pub fn new(campaign_id: &str, name: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            goals: Vec::new(),
+            resources: Vec::new(),
+            members: Vec::new(),
+            territory: Vec::new(),
+            influence: 50,
+            clocks: Vec::new(),
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Create a faction from a campaign template entry, keeping its ID
+    pub fn from_template(campaign_id: &str, faction: &CampaignFaction) -> Self {
+        let mut built = Self::new(campaign_id, &faction.name);
+        built.id = faction.id.clone();
+        built.description = faction.description.clone();
+        built.goals = faction
+            .goals
+            .iter()
+            .map(|g| FactionGoal {
+                description: g.clone(),
+                achieved: false,
+            })
+            .collect();
+        built
+    }
+
+    /// Builder: set description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Builder: add a member NPC
+    pub fn with_member(mut self, npc_id: &str, role: Option<&str>, is_leader: bool) -> Self {
+        self.members.push(FactionMember {
+            npc_id: npc_id.to_string(),
+            role: role.map(|r| r.to_string()),
+            is_leader,
+        });
+        self
+    }
+
+    /// Builder: add controlled locations
+    pub fn with_territory(mut self, location_ids: Vec<String>) -> Self {
+        self.territory = location_ids;
+        self
+    }
+
+    /// Builder: add a clock
+    pub fn with_clock(mut self, clock: FactionClock) -> Self {
+        self.clocks.push(clock);
+        self
+    }
+
+    /// Whether a world event involves this faction: it is named in the
+    /// event's metadata or text, takes place in its territory, or involves
+    /// one of its members
+    pub fn involved_in(&self, event: &WorldEvent) -> bool {
+        let listed = event
+            .metadata
+            .get(FACTION_IDS_FIELD)
+            .and_then(|v| v.as_array())
+            .is_some_and(|ids| ids.iter().any(|id| id.as_str() == Some(self.id.as_str())));
+        let name = self.name.to_lowercase();
+        let mentioned = event.title.to_lowercase().contains(&name) || event.description.to_lowercase().contains(&name);
+
+        listed
+            || mentioned
+            || event.location_ids.iter().any(|l| self.territory.contains(l))
+            || event.npc_ids.iter().any(|n| self.members.iter().any(|m| &m.npc_id == n))
+    }
+}
+
+/// Clock ticks for an event of a given impact
+fn impact_ticks(impact: &EventImpact) -> i32 {
+    match impact {
+        EventImpact::Personal | EventImpact::Local => 1,
+        EventImpact::Regional => 2,
+        EventImpact::National | EventImpact::Global | EventImpact::Cosmic => 3,
+    }
+}
+
+// ============================================================================
+// Event Outcomes
+// ============================================================================
+
+/// A clock moved by a world event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockAdvance {
+    pub clock_id: String,
+    pub name: String,
+    pub filled: u32,
+    pub segments: u32,
+    /// This event filled the clock
+    pub completed: bool,
+}
+
+/// How a world event changed one faction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionEventOutcome {
+    pub faction_id: String,
+    pub faction_name: String,
+    pub event_id: String,
+    pub influence_before: i32,
+    pub influence_after: i32,
+    pub clocks: Vec<ClockAdvance>,
+}
+
+// ============================================================================
+// Faction Manager
+// ============================================================================
+
+pub struct FactionManager {
+    factions: RwLock<HashMap<String, Faction>>,
+}
+
+impl Default for FactionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FactionManager {
+    pub fn new() -> Self {
+        Self {
+            factions: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_faction(&self, mut faction: Faction) -> Result<Faction> {
+        if faction.name.trim().is_empty() {
+            return Err(FactionError::EmptyName);
+        }
+        if faction.clocks.iter().any(|c| c.segments == 0) {
+            return Err(FactionError::InvalidClock);
+        }
+        faction.influence = faction.influence.clamp(MIN_INFLUENCE, MAX_INFLUENCE);
+        self.factions.write().unwrap().insert(faction.id.clone(), faction.clone());
+        Ok(faction)
+    }
+
+    pub fn get_faction(&self, faction_id: &str) -> Option<Faction> {
+        self.factions.read().unwrap().get(faction_id).cloned()
+    }
+
+    pub fn update_faction(&self, mut faction: Faction) -> Result<Faction> {
+        if faction.name.trim().is_empty() {
+            return Err(FactionError::EmptyName);
+        }
+        if faction.clocks.iter().any(|c| c.segments == 0) {
+            return Err(FactionError::InvalidClock);
+        }
+        let mut factions = self.factions.write().unwrap();
+        let existing = factions
+            .get(&faction.id)
+            .ok_or_else(|| FactionError::FactionNotFound(faction.id.clone()))?;
+        faction.created_at = existing.created_at;
+        faction.updated_at = Utc::now();
+        faction.influence = faction.influence.clamp(MIN_INFLUENCE, MAX_INFLUENCE);
+        factions.insert(faction.id.clone(), faction.clone());
+        Ok(faction)
+    }
+
+    pub fn delete_faction(&self, faction_id: &str) -> Result<()> {
+        self.factions
+            .write()
+            .unwrap()
+            .remove(faction_id)
+            .map(|_| ())
+            .ok_or_else(|| FactionError::FactionNotFound(faction_id.to_string()))
+    }
+
+    /// List a campaign's factions by name
+    pub fn list_factions(&self, campaign_id: &str) -> Vec<Faction> {
+        let mut factions: Vec<Faction> = self
+            .factions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|f| f.campaign_id == campaign_id)
+            .cloned()
+            .collect();
+        factions.sort_by(|a, b| a.name.cmp(&b.name));
+        factions
+    }
+
+    /// Factions that count an NPC as a member
+    pub fn factions_for_npc(&self, campaign_id: &str, npc_id: &str) -> Vec<Faction> {
+        self.list_factions(campaign_id)
+            .into_iter()
+            .filter(|f| f.members.iter().any(|m| m.npc_id == npc_id))
+            .collect()
+    }
+
+    /// Faction controlling a location, if any
+    pub fn controller_of(&self, campaign_id: &str, location_id: &str) -> Option<Faction> {
+        self.list_factions(campaign_id)
+            .into_iter()
+            .find(|f| f.territory.iter().any(|l| l == location_id))
+    }
+
+    /// Change a faction's influence by `delta`, clamped to 0-100
+    pub fn adjust_influence(&self, faction_id: &str, delta: i32) -> Result<Faction> {
+        self.with_faction_mut(faction_id, |faction| {
+            faction.influence = (faction.influence + delta).clamp(MIN_INFLUENCE, MAX_INFLUENCE);
+            Ok(())
+        })
+    }
+
+    pub fn add_clock(&self, faction_id: &str, clock: FactionClock) -> Result<Faction> {
+        if clock.segments == 0 {
+            return Err(FactionError::InvalidClock);
+        }
+        self.with_faction_mut(faction_id, |faction| {
+            faction.clocks.push(clock);
+            Ok(())
+        })
+    }
+
+    /// Advance (or with negative ticks, wind back) a clock
+    pub fn advance_clock(&self, faction_id: &str, clock_id: &str, ticks: i32) -> Result<Faction> {
+        self.with_faction_mut(faction_id, |faction| {
+            let clock = faction
+                .clocks
+                .iter_mut()
+                .find(|c| c.id == clock_id)
+                .ok_or_else(|| FactionError::ClockNotFound(clock_id.to_string()))?;
+            clock.advance(ticks);
+            Ok(())
+        })
+    }
+
+    pub fn remove_clock(&self, faction_id: &str, clock_id: &str) -> Result<Faction> {
+        self.with_faction_mut(faction_id, |faction| {
+            let before = faction.clocks.len();
+            faction.clocks.retain(|c| c.id != clock_id);
+            if faction.clocks.len() == before {
+                return Err(FactionError::ClockNotFound(clock_id.to_string()));
+            }
+            Ok(())
+        })
+    }
+
+    /// Update factions involved in a world event.
+    ///
+    /// Open clocks whose triggers match the event type advance by the event's
+    /// impact. Influence changes only when the event lists them under
+    /// [`INFLUENCE_FIELD`].
+    pub fn apply_world_event(&self, event: &WorldEvent) -> Vec<FactionEventOutcome> {
+        let influence: HashMap<String, i64> = event
+            .metadata
+            .get(INFLUENCE_FIELD)
+            .and_then(|v| v.as_object())
+            .map(|m| m.iter().filter_map(|(id, d)| d.as_i64().map(|d| (id.clone(), d))).collect())
+            .unwrap_or_default();
+        let ticks = impact_ticks(&event.impact);
+
+        let mut factions = self.factions.write().unwrap();
+        let mut outcomes = Vec::new();
+        for faction in factions.values_mut().filter(|f| f.campaign_id == event.campaign_id) {
+            if !faction.involved_in(event) && !influence.contains_key(&faction.id) {
+                continue;
+            }
+
+            let influence_before = faction.influence;
+            if let Some(delta) = influence.get(&faction.id) {
+                faction.influence = (faction.influence + *delta as i32).clamp(MIN_INFLUENCE, MAX_INFLUENCE);
+            }
+
+            let clocks: Vec<ClockAdvance> = faction
+                .clocks
+                .iter_mut()
+                .filter(|c| !c.is_complete() && c.triggered_by(&event.event_type))
+                .map(|clock| {
+                    let completed = clock.advance(ticks);
+                    ClockAdvance {
+                        clock_id: clock.id.clone(),
+                        name: clock.name.clone(),
+                        filled: clock.filled,
+                        segments: clock.segments,
+                        completed,
+                    }
+                })
+                .collect();
+
+            faction.updated_at = Utc::now();
+            outcomes.push(FactionEventOutcome {
+                faction_id: faction.id.clone(),
+                faction_name: faction.name.clone(),
+                event_id: event.id.clone(),
+                influence_before,
+                influence_after: faction.influence,
+                clocks,
+            });
+        }
+        outcomes.sort_by(|a, b| a.faction_name.cmp(&b.faction_name));
+        outcomes
+    }
+
+    /// Copy a campaign's factions to another campaign with new IDs, remapping
+    /// territory through `location_ids` (old ID -> new ID)
+    pub fn copy_campaign_factions(
+        &self,
+        from_campaign_id: &str,
+        to_campaign_id: &str,
+        location_ids: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let mut id_map = HashMap::new();
+        let now = Utc::now();
+        for mut faction in self.list_factions(from_campaign_id) {
+            let new_id = Uuid::new_v4().to_string();
+            id_map.insert(faction.id.clone(), new_id.clone());
+            faction.id = new_id;
+            faction.campaign_id = to_campaign_id.to_string();
+            faction.territory = faction
+                .territory
+                .iter()
+                .map(|l| location_ids.get(l).cloned().unwrap_or_else(|| l.clone()))
+                .collect();
+            faction.created_at = now;
+            faction.updated_at = now;
+            self.factions.write().unwrap().insert(faction.id.clone(), faction);
+        }
+        id_map
+    }
+
+    /// Remove all factions for a campaign
+    pub fn delete_campaign_factions(&self, campaign_id: &str) {
+        self.factions.write().unwrap().retain(|_, f| f.campaign_id != campaign_id);
+    }
+
+    /// Build the faction web from a campaign's relationship graph.
+    ///
+    /// Keeps relationships touching a faction, adds membership and territory
+    /// edges that aren't already in the graph, and annotates faction nodes
+    /// with influence and clock progress. `names` supplies display names for
+    /// member NPCs and territory locations.
+    pub fn faction_web(&self, campaign_id: &str, graph: EntityGraph, names: &HashMap<String, String>) -> EntityGraph {
+        let factions = self.list_factions(campaign_id);
+        let is_faction = |id: &str| factions.iter().any(|f| f.id == id);
+
+        let mut edges: Vec<GraphEdge> = graph
+            .edges
+            .into_iter()
+            .filter(|e| is_faction(&e.source) || is_faction(&e.target))
+            .collect();
+        let mut nodes: HashMap<String, GraphNode> = graph
+            .nodes
+            .into_iter()
+            .filter(|n| is_faction(&n.id) || edges.iter().any(|e| e.source == n.id || e.target == n.id))
+            .map(|n| (n.id.clone(), n))
+            .collect();
+
+        let mut ensure_node = |id: &str, name: &str, entity_type: EntityType, color: &str| {
+            nodes.entry(id.to_string()).or_insert_with(|| GraphNode {
+                id: id.to_string(),
+                name: name.to_string(),
+                entity_type,
+                color: color.to_string(),
+                connection_count: 0,
+                is_hub: false,
+                data: HashMap::new(),
+            });
+        };
+
+        for faction in &factions {
+            ensure_node(&faction.id, &faction.name, EntityType::Faction, "#9b59b6");
+
+            let derived = faction
+                .members
+                .iter()
+                .map(|m| (m.npc_id.as_str(), EntityType::NPC, RelationshipType::MemberOf, "#3498db"))
+                .chain(
+                    faction
+                        .territory
+                        .iter()
+                        .map(|l| (l.as_str(), EntityType::Location, RelationshipType::Controls, "#27ae60")),
+                );
+            for (other_id, other_type, rel_type, color) in derived {
+                // Members point at the faction; the faction points at its territory
+                let (source, target) = match rel_type {
+                    RelationshipType::MemberOf => (other_id, faction.id.as_str()),
+                    _ => (faction.id.as_str(), other_id),
+                };
+                let label = rel_type.to_string();
+                if edges.iter().any(|e| e.source == source && e.target == target && e.label == label) {
+                    continue;
+                }
+                let name = names.get(other_id).map(|n| n.as_str()).unwrap_or(other_id);
+                ensure_node(other_id, name, other_type, color);
+                edges.push(GraphEdge {
+                    id: format!("{}:{}:{}", source, label, target),
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    label,
+                    strength: 50,
+                    bidirectional: false,
+                    is_active: true,
+                    color: color.to_string(),
+                });
+            }
+        }
+
+        let mut nodes: Vec<GraphNode> = nodes
+            .into_values()
+            .map(|mut node| {
+                node.connection_count = edges.iter().filter(|e| e.source == node.id || e.target == node.id).count();
+                if let Some(faction) = factions.iter().find(|f| f.id == node.id) {
+                    node.data.insert("influence".to_string(), serde_json::json!(faction.influence));
+                    node.data.insert(
+                        "clocks".to_string(),
+                        serde_json::json!(faction
+                            .clocks
+                            .iter()
+                            .map(|c| serde_json::json!({ "name": c.name, "filled": c.filled, "segments": c.segments }))
+                            .collect::<Vec<_>>()),
+                    );
+                }
+                node
+            })
+            .collect();
+        nodes.sort_by(|a, b| b.connection_count.cmp(&a.connection_count).then_with(|| a.name.cmp(&b.name)));
+
+        let mut stats = graph.stats;
+        stats.node_count = nodes.len();
+        stats.edge_count = edges.len();
+        stats.entity_type_counts = HashMap::new();
+        for node in &nodes {
+            *stats.entity_type_counts.entry(node.entity_type.to_string()).or_insert(0) += 1;
+        }
+        stats.relationship_type_counts = HashMap::new();
+        for edge in &edges {
+            *stats.relationship_type_counts.entry(edge.label.clone()).or_insert(0) += 1;
+        }
+        stats.most_connected_entities = nodes.iter().take(5).map(|n| (n.name.clone(), n.connection_count)).collect();
+
+        EntityGraph { nodes, edges, stats }
+    }
+
+    fn with_faction_mut(&self, faction_id: &str, f: impl FnOnce(&mut Faction) -> Result<()>) -> Result<Faction> {
+        let mut factions = self.factions.write().unwrap();
+        let faction = factions
+            .get_mut(faction_id)
+            .ok_or_else(|| FactionError::FactionNotFound(faction_id.to_string()))?;
+        f(faction)?;
+        faction.updated_at = Utc::now();
+        Ok(faction.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign::relationships::{EntityRelationship, RelationshipManager};
+    use crate::core::campaign::world_state::InGameDate;
+
+    fn setup() -> (FactionManager, Faction, Faction) {
+        let manager = FactionManager::new();
+        let guild = manager
+            .create_faction(
+                Faction::new("camp-1", "Thieves' Guild")
+                    .with_member("npc-fence", Some("Fence"), true)
+                    .with_territory(vec!["loc-docks".to_string()])
+                    .with_clock(FactionClock::new("Take the Docks", 4).with_triggers(vec![WorldEventType::Combat])),
+            )
+            .unwrap();
+        let watch = manager
+            .create_faction(Faction::new("camp-1", "City Watch").with_clock(FactionClock::new("Crackdown", 6)))
+            .unwrap();
+        (manager, guild, watch)
+    }
+
+    #[test]
+    fn test_clock_advance_and_completion() {
+        let mut clock = FactionClock::new("Ritual", 3);
+        assert!(!clock.advance(2));
+        assert!(clock.advance(5));
+        assert_eq!(clock.filled, 3);
+        assert!(clock.completed_at.is_some());
+        clock.advance(-1);
+        assert!(!clock.is_complete());
+        assert!(clock.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_world_event_advances_involved_factions() {
+        let (manager, guild, watch) = setup();
+
+        let mut event = WorldEvent::new("camp-1", "Brawl at the docks", "Smugglers clash", InGameDate::default())
+            .with_type(WorldEventType::Combat)
+            .with_impact(EventImpact::Regional)
+            .at_locations(vec!["loc-docks".to_string()]);
+        event
+            .metadata
+            .insert(INFLUENCE_FIELD.to_string(), serde_json::json!({ guild.id.clone(): 10, watch.id.clone(): -5 }));
+
+        let outcomes = manager.apply_world_event(&event);
+        assert_eq!(outcomes.len(), 2);
+
+        let guild_outcome = outcomes.iter().find(|o| o.faction_id == guild.id).unwrap();
+        assert_eq!(guild_outcome.influence_after, 60);
+        assert_eq!(guild_outcome.clocks[0].filled, 2);
+
+        // Listing the watch for influence involves it, so its any-event clock moves too
+        let watch_after = manager.get_faction(&watch.id).unwrap();
+        assert_eq!(watch_after.influence, 45);
+        assert_eq!(watch_after.clocks[0].filled, 2);
+    }
+
+    #[test]
+    fn test_unrelated_events_and_triggers() {
+        let (manager, guild, _) = setup();
+
+        let festival = WorldEvent::new("camp-1", "Harvest festival", "", InGameDate::default())
+            .with_type(WorldEventType::Social);
+        assert!(manager.apply_world_event(&festival).is_empty());
+
+        let named = WorldEvent::new("camp-1", "Thieves' Guild bribes a judge", "", InGameDate::default())
+            .with_type(WorldEventType::Political);
+        let outcomes = manager.apply_world_event(&named);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].faction_id, guild.id);
+        // Political events don't trigger the combat-only clock
+        assert!(outcomes[0].clocks.is_empty());
+    }
+
+    #[test]
+    fn test_faction_web_includes_derived_edges() {
+        let (manager, guild, watch) = setup();
+        let relationships = RelationshipManager::default();
+        relationships
+            .create_relationship(EntityRelationship::new(
+                "camp-1",
+                &guild.id,
+                EntityType::Faction,
+                &guild.name,
+                &watch.id,
+                EntityType::Faction,
+                &watch.name,
+                RelationshipType::Enemy,
+            ))
+            .unwrap();
+        relationships
+            .create_relationship(EntityRelationship::new(
+                "camp-1",
+                "npc-a",
+                EntityType::NPC,
+                "Alice",
+                "npc-b",
+                EntityType::NPC,
+                "Bob",
+                RelationshipType::Ally,
+            ))
+            .unwrap();
+
+        let names = HashMap::from([("loc-docks".to_string(), "The Docks".to_string())]);
+        let web = manager.faction_web("camp-1", relationships.get_entity_graph("camp-1", false), &names);
+
+        assert_eq!(web.edges.len(), 3);
+        assert!(web.nodes.iter().all(|n| n.id != "npc-a"));
+        assert!(web.nodes.iter().any(|n| n.name == "The Docks"));
+        let guild_node = web.nodes.iter().find(|n| n.id == guild.id).unwrap();
+        assert_eq!(guild_node.data["influence"], serde_json::json!(50));
+    }
+
+    #[test]
+    fn test_copy_campaign_factions() {
+        let (manager, guild, _) = setup();
+        let locations = HashMap::from([("loc-docks".to_string(), "loc-docks-2".to_string())]);
+
+        let ids = manager.copy_campaign_factions("camp-1", "camp-2", &locations);
+        let copy = manager.get_faction(&ids[&guild.id]).unwrap();
+
+        assert_eq!(manager.list_factions("camp-2").len(), 2);
+        assert_eq!(copy.territory, vec!["loc-docks-2"]);
+        assert_eq!(manager.controller_of("camp-2", "loc-docks-2").unwrap().id, copy.id);
+    }
+}
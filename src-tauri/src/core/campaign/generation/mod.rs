@@ -108,6 +108,7 @@ pub use session_gen::{
 };
 pub use party_gen::{
     PartyAnalyzer, PartyAnalysisRequest, PartySuggestion, GapAnalysis,
+    PartyMember, PartyRole,
 };
 pub use arc_gen::{
     ArcGenerator, ArcGenerationRequest, ArcDraft, TensionCurve,
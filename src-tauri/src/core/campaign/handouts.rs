@@ -0,0 +1,389 @@
+//! Handout Management Module
+//!
+//! Images, PDF pages, and text snippets the GM shows to players. Handouts
+//! belong to a campaign and optionally a session, start hidden, and keep a
+//! history of when they were revealed or hidden again.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum HandoutError {
+    #[error("Handout not found: {0}")]
+    HandoutNotFound(String),
+
+    #[error("Handout title cannot be empty")]
+    EmptyTitle,
+
+    #[error("Handout file not found: {0}")]
+    FileNotFound(String),
+
+    #[error("PDF page numbers start at 1")]
+    InvalidPage,
+}
+
+pub type Result<T> = std::result::Result<T, HandoutError>;
+
+// ============================================================================
+// Handout Types
+// ============================================================================
+
+/// What a handout shows
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HandoutContent {
+    Image { path: PathBuf },
+    PdfPage { path: PathBuf, page: u32 },
+    Text { text: String },
+}
+
+impl HandoutContent {
+    fn validate(&self) -> Result<()> {
+        match self {
+            Self::Image { path } | Self::PdfPage { path, .. } if !path.exists() => {
+                Err(HandoutError::FileNotFound(path.display().to_string()))
+            }
+            Self::PdfPage { page: 0, .. } => Err(HandoutError::InvalidPage),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A reveal or hide, for the handout's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevealRecord {
+    pub revealed: bool,
+    pub session_id: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// Something the GM can show to players
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Handout {
+    pub id: String,
+    pub campaign_id: String,
+    /// Session the handout was prepared for, if any
+    pub session_id: Option<String>,
+    pub title: String,
+    pub content: HandoutContent,
+    /// Shown to players alongside the handout
+    #[serde(default)]
+    pub caption: String,
+    /// Never shown to players
+    #[serde(default)]
+    pub gm_notes: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub revealed: bool,
+    pub revealed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub history: Vec<RevealRecord>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Handout {
+    pub fn new(campaign_id: &str, title: &str, content: HandoutContent) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            session_id: None,
+            title: title.to_string(),
+            content,
+            caption: String::new(),
+            gm_notes: String::new(),
+            tags: Vec::new(),
+            revealed: false,
+            revealed_at: None,
+            history: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Builder: attach to a session
+    pub fn for_session(mut self, session_id: &str) -> Self {
+        self.session_id = Some(session_id.to_string());
+        self
+    }
+
+    /// Builder: set caption
+    pub fn with_caption(mut self, caption: &str) -> Self {
+        self.caption = caption.to_string();
+        self
+    }
+
+    /// The handout as players see it
+    pub fn player_view(&self) -> PlayerHandout {
+        PlayerHandout {
+            id: self.id.clone(),
+            campaign_id: self.campaign_id.clone(),
+            session_id: self.session_id.clone(),
+            title: self.title.clone(),
+            content: self.content.clone(),
+            caption: self.caption.clone(),
+            revealed_at: self.revealed_at,
+        }
+    }
+
+    fn set_revealed(&mut self, revealed: bool, session_id: Option<&str>) {
+        if self.revealed == revealed {
+            return;
+        }
+        let now = Utc::now();
+        self.revealed = revealed;
+        self.revealed_at = revealed.then_some(now);
+        self.history.push(RevealRecord {
+            revealed,
+            session_id: session_id.map(str::to_string),
+            at: now,
+        });
+        self.updated_at = now;
+    }
+}
+
+/// A revealed handout without GM-only fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerHandout {
+    pub id: String,
+    pub campaign_id: String,
+    pub session_id: Option<String>,
+    pub title: String,
+    pub content: HandoutContent,
+    pub caption: String,
+    pub revealed_at: Option<DateTime<Utc>>,
+}
+
+// ============================================================================
+// Handout Manager
+// ============================================================================
+
+pub struct HandoutManager {
+    handouts: RwLock<HashMap<String, Handout>>,
+}
+
+impl Default for HandoutManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandoutManager {
+    pub fn new() -> Self {
+        Self {
+            handouts: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_handout(&self, handout: Handout) -> Result<Handout> {
+        if handout.title.trim().is_empty() {
+            return Err(HandoutError::EmptyTitle);
+        }
+        handout.content.validate()?;
+        self.handouts
+            .write()
+            .unwrap()
+            .insert(handout.id.clone(), handout.clone());
+        Ok(handout)
+    }
+
+    pub fn get_handout(&self, handout_id: &str) -> Option<Handout> {
+        self.handouts.read().unwrap().get(handout_id).cloned()
+    }
+
+    /// Replace a handout's details; reveal state and history are kept
+    pub fn update_handout(&self, mut handout: Handout) -> Result<Handout> {
+        if handout.title.trim().is_empty() {
+            return Err(HandoutError::EmptyTitle);
+        }
+        handout.content.validate()?;
+        let mut handouts = self.handouts.write().unwrap();
+        let existing = handouts
+            .get(&handout.id)
+            .ok_or_else(|| HandoutError::HandoutNotFound(handout.id.clone()))?;
+        handout.revealed = existing.revealed;
+        handout.revealed_at = existing.revealed_at;
+        handout.history = existing.history.clone();
+        handout.created_at = existing.created_at;
+        handout.updated_at = Utc::now();
+        handouts.insert(handout.id.clone(), handout.clone());
+        Ok(handout)
+    }
+
+    pub fn delete_handout(&self, handout_id: &str) -> Result<Handout> {
+        self.handouts
+            .write()
+            .unwrap()
+            .remove(handout_id)
+            .ok_or_else(|| HandoutError::HandoutNotFound(handout_id.to_string()))
+    }
+
+    /// List a campaign's handouts, oldest first. With a session, only that
+    /// session's handouts and campaign-wide ones are listed.
+    pub fn list_handouts(&self, campaign_id: &str, session_id: Option<&str>, revealed: Option<bool>) -> Vec<Handout> {
+        let mut handouts: Vec<Handout> = self
+            .handouts
+            .read()
+            .unwrap()
+            .values()
+            .filter(|h| h.campaign_id == campaign_id)
+            .filter(|h| session_id.is_none_or(|s| h.session_id.as_deref().is_none_or(|hs| hs == s)))
+            .filter(|h| revealed.is_none_or(|r| h.revealed == r))
+            .cloned()
+            .collect();
+        handouts.sort_by_key(|h| h.created_at);
+        handouts
+    }
+
+    /// What players can currently see, most recently revealed first
+    pub fn revealed_for_players(&self, campaign_id: &str, session_id: Option<&str>) -> Vec<PlayerHandout> {
+        let mut revealed: Vec<PlayerHandout> = self
+            .list_handouts(campaign_id, session_id, Some(true))
+            .iter()
+            .map(Handout::player_view)
+            .collect();
+        revealed.sort_by_key(|h| std::cmp::Reverse(h.revealed_at));
+        revealed
+    }
+
+    /// Reveal a handout to players
+    pub fn reveal(&self, handout_id: &str, session_id: Option<&str>) -> Result<Handout> {
+        self.with_handout_mut(handout_id, |h| h.set_revealed(true, session_id))
+    }
+
+    /// Hide a handout from players again
+    pub fn hide(&self, handout_id: &str, session_id: Option<&str>) -> Result<Handout> {
+        self.with_handout_mut(handout_id, |h| h.set_revealed(false, session_id))
+    }
+
+    /// Remove all handouts for a campaign
+    pub fn delete_campaign_handouts(&self, campaign_id: &str) {
+        self.handouts.write().unwrap().retain(|_, h| h.campaign_id != campaign_id);
+    }
+
+    fn with_handout_mut(&self, handout_id: &str, f: impl FnOnce(&mut Handout)) -> Result<Handout> {
+        let mut handouts = self.handouts.write().unwrap();
+        let handout = handouts
+            .get_mut(handout_id)
+            .ok_or_else(|| HandoutError::HandoutNotFound(handout_id.to_string()))?;
+        f(handout);
+        Ok(handout.clone())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter(campaign_id: &str) -> Handout {
+        Handout::new(
+            campaign_id,
+            "Letter from the Duke",
+            HandoutContent::Text {
+                text: "Meet me at midnight.".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_create_validates_content() {
+        let manager = HandoutManager::new();
+        assert!(manager.create_handout(letter("camp-1")).is_ok());
+        assert!(matches!(
+            manager.create_handout(Handout::new(
+                "camp-1",
+                "Map",
+                HandoutContent::Image {
+                    path: PathBuf::from("/nonexistent/map.png")
+                }
+            )),
+            Err(HandoutError::FileNotFound(_))
+        ));
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let page_zero = Handout::new(
+            "camp-1",
+            "Ledger",
+            HandoutContent::PdfPage {
+                path: file.path().to_path_buf(),
+                page: 0,
+            },
+        );
+        assert!(matches!(manager.create_handout(page_zero), Err(HandoutError::InvalidPage)));
+    }
+
+    #[test]
+    fn test_reveal_and_hide_track_history() {
+        let manager = HandoutManager::new();
+        let handout = manager.create_handout(letter("camp-1")).unwrap();
+        assert!(!handout.revealed);
+
+        let revealed = manager.reveal(&handout.id, Some("session-1")).unwrap();
+        assert!(revealed.revealed);
+        assert!(revealed.revealed_at.is_some());
+        // Revealing twice doesn't add history
+        manager.reveal(&handout.id, Some("session-1")).unwrap();
+
+        let hidden = manager.hide(&handout.id, None).unwrap();
+        assert!(!hidden.revealed);
+        assert!(hidden.revealed_at.is_none());
+        assert_eq!(hidden.history.len(), 2);
+        assert_eq!(hidden.history[0].session_id.as_deref(), Some("session-1"));
+    }
+
+    #[test]
+    fn test_session_filter_includes_campaign_handouts() {
+        let manager = HandoutManager::new();
+        manager.create_handout(letter("camp-1")).unwrap();
+        manager.create_handout(letter("camp-1").for_session("session-1")).unwrap();
+        manager.create_handout(letter("camp-1").for_session("session-2")).unwrap();
+        manager.create_handout(letter("camp-2")).unwrap();
+
+        assert_eq!(manager.list_handouts("camp-1", None, None).len(), 3);
+        assert_eq!(manager.list_handouts("camp-1", Some("session-1"), None).len(), 2);
+        assert_eq!(manager.list_handouts("camp-1", None, Some(true)).len(), 0);
+    }
+
+    #[test]
+    fn test_player_view_hides_gm_notes() {
+        let manager = HandoutManager::new();
+        let mut handout = letter("camp-1").with_caption("Found on the courier");
+        handout.gm_notes = "The duke is lying".to_string();
+        let handout = manager.create_handout(handout).unwrap();
+        assert!(manager.revealed_for_players("camp-1", None).is_empty());
+
+        manager.reveal(&handout.id, None).unwrap();
+        let visible = manager.revealed_for_players("camp-1", None);
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].caption, "Found on the courier");
+        assert!(!serde_json::to_string(&visible[0]).unwrap().contains("lying"));
+    }
+
+    #[test]
+    fn test_update_keeps_reveal_state() {
+        let manager = HandoutManager::new();
+        let handout = manager.create_handout(letter("camp-1")).unwrap();
+        manager.reveal(&handout.id, None).unwrap();
+
+        let mut edited = handout.clone();
+        edited.title = "Letter from the Duchess".to_string();
+        let updated = manager.update_handout(edited).unwrap();
+        assert!(updated.revealed);
+        assert_eq!(updated.history.len(), 1);
+        assert_eq!(updated.title, "Letter from the Duchess");
+    }
+}
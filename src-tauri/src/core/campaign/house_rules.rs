@@ -0,0 +1,444 @@
+//! House Rules Module
+//!
+//! Campaign-specific rule changes, each optionally pointing at the book and
+//! page it overrides. Rules are kept in a small keyword index per campaign
+//! so rules search can show the table's house rule above the rules-as-written
+//! passage it replaces.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Badge shown on house rules in search results
+pub const HOUSE_RULE_BADGE: &str = "house rule";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum HouseRuleError {
+    #[error("House rule not found: {0}")]
+    RuleNotFound(String),
+
+    #[error("House rule title cannot be empty")]
+    EmptyTitle,
+
+    #[error("House rule text cannot be empty")]
+    EmptyText,
+}
+
+pub type Result<T> = std::result::Result<T, HouseRuleError>;
+
+// ============================================================================
+// House Rule Types
+// ============================================================================
+
+/// A rulebook passage a house rule replaces
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuleReference {
+    /// Book title or file name, as it appears as a search result's source
+    pub book: String,
+    /// Page the passage is on; `None` covers the whole book
+    pub page: Option<u32>,
+    pub section: Option<String>,
+}
+
+impl RuleReference {
+    pub fn new(book: &str, page: Option<u32>) -> Self {
+        Self {
+            book: book.to_string(),
+            page,
+            section: None,
+        }
+    }
+
+    /// Whether a search result from `source` on `page` is the referenced passage
+    pub fn matches(&self, source: &str, page: Option<u32>) -> bool {
+        let book = normalize_book(&self.book);
+        let source = normalize_book(source);
+        let same_book = !book.is_empty() && (source == book || source.contains(&book) || book.contains(&source));
+        same_book && self.page.is_none_or(|p| page == Some(p))
+    }
+}
+
+/// Lowercase a book name and drop its file extension
+fn normalize_book(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if ext.len() <= 4 && !stem.is_empty() => stem.to_string(),
+        _ => name,
+    }
+}
+
+/// A campaign's change to the rules as written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseRule {
+    pub id: String,
+    pub campaign_id: String,
+    pub title: String,
+    pub text: String,
+    /// Passages this rule replaces
+    #[serde(default)]
+    pub overrides: Vec<RuleReference>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Disabled rules are kept but not shown in search
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HouseRule {
+    pub fn new(campaign_id: &str, title: &str, text: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            title: title.to_string(),
+            text: text.to_string(),
+            overrides: Vec::new(),
+            tags: Vec::new(),
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Builder: add an overridden passage
+    pub fn overriding(mut self, reference: RuleReference) -> Self {
+        self.overrides.push(reference);
+        self
+    }
+
+    /// Builder: set tags
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Whether this rule replaces a search result from `source` on `page`
+    pub fn overrides_passage(&self, source: &str, page: Option<u32>) -> bool {
+        self.overrides.iter().any(|r| r.matches(source, page))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.title.trim().is_empty() {
+            return Err(HouseRuleError::EmptyTitle);
+        }
+        if self.text.trim().is_empty() {
+            return Err(HouseRuleError::EmptyText);
+        }
+        Ok(())
+    }
+
+    /// Indexed terms with their weight: title and tags count double
+    fn weighted_terms(&self) -> HashMap<String, f32> {
+        let mut terms = HashMap::new();
+        for term in tokenize(&self.text) {
+            terms.entry(term).or_insert(1.0);
+        }
+        for reference in &self.overrides {
+            for term in tokenize(reference.section.as_deref().unwrap_or_default()) {
+                terms.entry(term).or_insert(1.0);
+            }
+        }
+        for term in tokenize(&self.title).into_iter().chain(self.tags.iter().flat_map(|t| tokenize(t))) {
+            terms.insert(term, 2.0);
+        }
+        terms
+    }
+}
+
+/// A house rule found by search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseRuleMatch {
+    pub rule: HouseRule,
+    /// 0-1, higher for more query terms matched in the title or tags
+    pub score: f32,
+}
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "for", "from", "how", "in", "is", "it", "of", "on",
+    "or", "the", "to", "what", "when", "with",
+];
+
+/// Lowercase words of two or more characters, without stopwords
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|w| w.len() >= 2 && !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+// ============================================================================
+// House Rule Manager
+// ============================================================================
+
+pub struct HouseRuleManager {
+    rules: RwLock<HashMap<String, HouseRule>>,
+    /// Rule ID -> weighted terms
+    index: RwLock<HashMap<String, HashMap<String, f32>>>,
+}
+
+impl Default for HouseRuleManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HouseRuleManager {
+    pub fn new() -> Self {
+        Self {
+            rules: RwLock::new(HashMap::new()),
+            index: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_rule(&self, rule: HouseRule) -> Result<HouseRule> {
+        rule.validate()?;
+        self.store(rule.clone());
+        Ok(rule)
+    }
+
+    pub fn get_rule(&self, rule_id: &str) -> Option<HouseRule> {
+        self.rules.read().unwrap().get(rule_id).cloned()
+    }
+
+    pub fn update_rule(&self, mut rule: HouseRule) -> Result<HouseRule> {
+        rule.validate()?;
+        let existing = self
+            .get_rule(&rule.id)
+            .ok_or_else(|| HouseRuleError::RuleNotFound(rule.id.clone()))?;
+        rule.created_at = existing.created_at;
+        rule.updated_at = Utc::now();
+        self.store(rule.clone());
+        Ok(rule)
+    }
+
+    pub fn delete_rule(&self, rule_id: &str) -> Result<HouseRule> {
+        self.index.write().unwrap().remove(rule_id);
+        self.rules
+            .write()
+            .unwrap()
+            .remove(rule_id)
+            .ok_or_else(|| HouseRuleError::RuleNotFound(rule_id.to_string()))
+    }
+
+    /// List a campaign's rules by title, optionally only those with a tag
+    pub fn list_rules(&self, campaign_id: &str, tag: Option<&str>) -> Vec<HouseRule> {
+        let mut rules: Vec<HouseRule> = self
+            .rules
+            .read()
+            .unwrap()
+            .values()
+            .filter(|r| r.campaign_id == campaign_id)
+            .filter(|r| tag.is_none_or(|t| r.tags.iter().any(|rt| rt.eq_ignore_ascii_case(t))))
+            .cloned()
+            .collect();
+        rules.sort_by_key(|r| r.title.to_lowercase());
+        rules
+    }
+
+    /// Search a campaign's enabled rules, best match first
+    pub fn search(&self, campaign_id: &str, query: &str, limit: usize) -> Vec<HouseRuleMatch> {
+        let query_terms: HashSet<String> = tokenize(query).into_iter().collect();
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+        let rules = self.rules.read().unwrap();
+        let index = self.index.read().unwrap();
+
+        let mut matches: Vec<HouseRuleMatch> = rules
+            .values()
+            .filter(|r| r.campaign_id == campaign_id && r.enabled)
+            .filter_map(|rule| {
+                let terms = index.get(&rule.id)?;
+                let weight: f32 = query_terms.iter().filter_map(|t| terms.get(t)).sum();
+                (weight > 0.0).then(|| HouseRuleMatch {
+                    rule: rule.clone(),
+                    score: weight / (2.0 * query_terms.len() as f32),
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.rule.title.cmp(&b.rule.title)));
+        matches.truncate(limit);
+        matches
+    }
+
+    /// The campaign's enabled rule replacing a passage, if any
+    pub fn find_override(&self, campaign_id: &str, source: &str, page: Option<u32>) -> Option<HouseRule> {
+        self.rules
+            .read()
+            .unwrap()
+            .values()
+            .filter(|r| r.campaign_id == campaign_id && r.enabled)
+            .filter(|r| r.overrides_passage(source, page))
+            // Page-specific overrides win over whole-book ones
+            .max_by_key(|r| (r.overrides.iter().any(|o| o.page == page && o.page.is_some()), r.updated_at))
+            .cloned()
+    }
+
+    /// House rules to show alongside rulebook search results: rules
+    /// matching the query, plus any rule overriding one of the `passages`
+    /// (source, page, score). Also returns, per passage, the ID of the rule
+    /// overriding it.
+    pub fn surface(
+        &self,
+        campaign_id: &str,
+        query: &str,
+        limit: usize,
+        passages: &[(&str, Option<u32>, f32)],
+    ) -> (Vec<HouseRuleMatch>, Vec<Option<String>>) {
+        let mut matches = self.search(campaign_id, query, limit);
+        let mut overridden_by = Vec::with_capacity(passages.len());
+        for &(source, page, score) in passages {
+            let rule = self.find_override(campaign_id, source, page);
+            if let Some(rule) = &rule {
+                match matches.iter_mut().find(|m| m.rule.id == rule.id) {
+                    Some(existing) => existing.score = existing.score.max(score),
+                    None => matches.push(HouseRuleMatch {
+                        rule: rule.clone(),
+                        score,
+                    }),
+                }
+            }
+            overridden_by.push(rule.map(|r| r.id));
+        }
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.rule.title.cmp(&b.rule.title)));
+        (matches, overridden_by)
+    }
+
+    /// Remove all house rules for a campaign
+    pub fn delete_campaign_rules(&self, campaign_id: &str) {
+        let mut rules = self.rules.write().unwrap();
+        let mut index = self.index.write().unwrap();
+        rules.retain(|id, r| {
+            let keep = r.campaign_id != campaign_id;
+            if !keep {
+                index.remove(id);
+            }
+            keep
+        });
+    }
+
+    fn store(&self, rule: HouseRule) {
+        self.index.write().unwrap().insert(rule.id.clone(), rule.weighted_terms());
+        self.rules.write().unwrap().insert(rule.id.clone(), rule);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flanking() -> HouseRule {
+        HouseRule::new(
+            "camp-1",
+            "Flanking",
+            "Flanking grants +2 to attack rolls instead of advantage.",
+        )
+        .overriding(RuleReference::new("Dungeon Master's Guide", Some(251)))
+        .with_tags(vec!["combat".to_string()])
+    }
+
+    #[test]
+    fn test_search_ranks_title_matches_higher() {
+        let manager = HouseRuleManager::new();
+        manager.create_rule(flanking()).unwrap();
+        manager
+            .create_rule(HouseRule::new(
+                "camp-1",
+                "Potions",
+                "Drinking a potion is a bonus action; flanking enemies get no reaction.",
+            ))
+            .unwrap();
+
+        let results = manager.search("camp-1", "flanking rules", 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].rule.title, "Flanking");
+        assert!(results[0].score > results[1].score);
+        assert!(manager.search("camp-1", "the a of", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_is_campaign_scoped_and_skips_disabled() {
+        let manager = HouseRuleManager::new();
+        let rule = manager.create_rule(flanking()).unwrap();
+        assert!(manager.search("camp-2", "flanking", 10).is_empty());
+
+        let mut disabled = rule.clone();
+        disabled.enabled = false;
+        manager.update_rule(disabled).unwrap();
+        assert!(manager.search("camp-1", "flanking", 10).is_empty());
+        assert_eq!(manager.list_rules("camp-1", Some("COMBAT")).len(), 1);
+    }
+
+    #[test]
+    fn test_update_reindexes_rule() {
+        let manager = HouseRuleManager::new();
+        let rule = manager.create_rule(flanking()).unwrap();
+        let mut edited = rule.clone();
+        edited.title = "Surrounding".to_string();
+        edited.text = "Surrounded creatures take a -2 penalty to AC.".to_string();
+        manager.update_rule(edited).unwrap();
+
+        assert!(manager.search("camp-1", "flanking", 10).is_empty());
+        assert_eq!(manager.search("camp-1", "surrounded", 10).len(), 1);
+        assert!(manager.delete_rule(&rule.id).is_ok());
+        assert!(manager.search("camp-1", "surrounded", 10).is_empty());
+    }
+
+    #[test]
+    fn test_rule_reference_matches_passages() {
+        let reference = RuleReference::new("Player's Handbook", Some(195));
+        assert!(reference.matches("player's handbook.pdf", Some(195)));
+        assert!(!reference.matches("Player's Handbook", Some(196)));
+        assert!(!reference.matches("Monster Manual", Some(195)));
+
+        let whole_book = RuleReference::new("PHB", None);
+        assert!(whole_book.matches("phb.pdf", Some(12)));
+        assert!(whole_book.matches("PHB", None));
+    }
+
+    #[test]
+    fn test_find_override_prefers_page_specific_rule() {
+        let manager = HouseRuleManager::new();
+        let page_rule = manager.create_rule(flanking()).unwrap();
+        manager
+            .create_rule(
+                HouseRule::new("camp-1", "Optional rules", "We use every optional rule in the DMG.")
+                    .overriding(RuleReference::new("Dungeon Master's Guide", None)),
+            )
+            .unwrap();
+
+        let found = manager.find_override("camp-1", "Dungeon Master's Guide", Some(251)).unwrap();
+        assert_eq!(found.id, page_rule.id);
+        let found = manager.find_override("camp-1", "Dungeon Master's Guide", Some(12)).unwrap();
+        assert_eq!(found.title, "Optional rules");
+        assert!(manager.find_override("camp-2", "Dungeon Master's Guide", Some(251)).is_none());
+    }
+
+    #[test]
+    fn test_surface_includes_overriding_rules() {
+        let manager = HouseRuleManager::new();
+        let rule = manager.create_rule(flanking()).unwrap();
+        let passages = [
+            ("Dungeon Master's Guide", Some(251), 0.9),
+            ("Player's Handbook", Some(195), 0.8),
+        ];
+
+        // The query doesn't mention flanking, but a result is the overridden page
+        let (rules, overridden_by) = manager.surface("camp-1", "surrounded attack bonus", 5, &passages);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].rule.id, rule.id);
+        assert_eq!(overridden_by, vec![Some(rule.id.clone()), None]);
+    }
+}
@@ -0,0 +1,743 @@
+//! Inventory Module
+//!
+//! Itemized inventories for player characters, NPCs, combatants, and the
+//! party stash: quantities, weights, containers, and attunement. Each game
+//! system's encumbrance rules turn what a creature carries into a load
+//! level. Items move between inventories as loot, and coins stay in the
+//! party treasury.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::character_gen::GameSystem;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum InventoryError {
+    #[error("Item not found: {0}")]
+    ItemNotFound(String),
+
+    #[error("Quantity must be at least 1")]
+    InvalidQuantity,
+
+    #[error("Only {available} {item} to move, {requested} requested")]
+    NotEnough { item: String, available: u32, requested: u32 },
+
+    #[error("{0} is not a container")]
+    NotAContainer(String),
+
+    #[error("{item} cannot go inside itself")]
+    ContainerCycle { item: String },
+
+    #[error("{container} can hold {capacity} more, {requested} requested")]
+    ContainerFull { container: String, capacity: f64, requested: f64 },
+
+    #[error("{0} does not require attunement")]
+    AttunementNotRequired(String),
+
+    #[error("Already attuned to {limit} items")]
+    AttunementLimit { limit: u8 },
+}
+
+pub type Result<T> = std::result::Result<T, InventoryError>;
+
+// ============================================================================
+// Inventory Types
+// ============================================================================
+
+/// Who an inventory belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InventoryOwner {
+    /// The shared party stash, alongside the treasury's party fund
+    Party,
+    Character { character_id: String },
+    Npc { npc_id: String },
+    Combatant { combatant_id: String },
+}
+
+impl InventoryOwner {
+    pub fn character(character_id: &str) -> Self {
+        Self::Character {
+            character_id: character_id.to_string(),
+        }
+    }
+
+    pub fn npc(npc_id: &str) -> Self {
+        Self::Npc {
+            npc_id: npc_id.to_string(),
+        }
+    }
+
+    pub fn combatant(combatant_id: &str) -> Self {
+        Self::Combatant {
+            combatant_id: combatant_id.to_string(),
+        }
+    }
+
+    fn key(&self, campaign_id: &str) -> String {
+        match self {
+            Self::Party => format!("{}:party", campaign_id),
+            Self::Character { character_id } => format!("{}:character:{}", campaign_id, character_id),
+            Self::Npc { npc_id } => format!("{}:npc:{}", campaign_id, npc_id),
+            Self::Combatant { combatant_id } => format!("{}:combatant:{}", campaign_id, combatant_id),
+        }
+    }
+}
+
+/// What a container holds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ContainerSpec {
+    /// Most weight the container holds
+    pub capacity: f64,
+    /// Contents don't count toward the carrier's load (bag of holding)
+    #[serde(default)]
+    pub weightless: bool,
+}
+
+/// A stack of items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub id: String,
+    pub name: String,
+    /// Ingested document the item came from
+    #[serde(default)]
+    pub document_id: Option<String>,
+    #[serde(default)]
+    pub category: String,
+    pub quantity: u32,
+    /// Weight of one, in the system's unit (pounds, or Bulk with light
+    /// items as 0.1)
+    #[serde(default)]
+    pub weight: f64,
+    /// Worth of one, in the system's smallest coin
+    #[serde(default)]
+    pub value: i64,
+    #[serde(default)]
+    pub requires_attunement: bool,
+    #[serde(default)]
+    pub attuned: bool,
+    #[serde(default)]
+    pub equipped: bool,
+    /// Set when the item can hold others
+    #[serde(default)]
+    pub container: Option<ContainerSpec>,
+    /// The container item this is inside
+    #[serde(default)]
+    pub container_id: Option<String>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl InventoryItem {
+    pub fn new(name: &str, quantity: u32, weight: f64) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            document_id: None,
+            category: String::new(),
+            quantity,
+            weight,
+            value: 0,
+            requires_attunement: false,
+            attuned: false,
+            equipped: false,
+            container: None,
+            container_id: None,
+            notes: String::new(),
+        }
+    }
+
+    /// Builder: make the item a container
+    pub fn as_container(mut self, capacity: f64, weightless: bool) -> Self {
+        self.container = Some(ContainerSpec { capacity, weightless });
+        self
+    }
+
+    /// Builder: the item needs attuning before it works
+    pub fn needing_attunement(mut self) -> Self {
+        self.requires_attunement = true;
+        self
+    }
+
+    /// Builder: set the worth of one
+    pub fn worth(mut self, value: i64) -> Self {
+        self.value = value;
+        self
+    }
+
+    fn stacks_with(&self, other: &InventoryItem) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+            && self.container.is_none()
+            && other.container.is_none()
+            && !self.attuned
+            && !other.attuned
+            && self.container_id == other.container_id
+            && self.document_id == other.document_id
+            && self.weight == other.weight
+            && self.value == other.value
+    }
+}
+
+/// Everything one owner carries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    pub campaign_id: String,
+    pub owner: InventoryOwner,
+    pub items: Vec<InventoryItem>,
+    /// Strength score the load is measured against (default: 10)
+    #[serde(default)]
+    pub strength: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Inventory {
+    pub fn new(campaign_id: &str, owner: InventoryOwner) -> Self {
+        Self {
+            campaign_id: campaign_id.to_string(),
+            owner,
+            items: Vec::new(),
+            strength: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn item(&self, item_id: &str) -> Option<&InventoryItem> {
+        self.items.iter().find(|i| i.id == item_id)
+    }
+
+    fn item_index(&self, item_id: &str) -> Result<usize> {
+        self.items
+            .iter()
+            .position(|i| i.id == item_id)
+            .ok_or_else(|| InventoryError::ItemNotFound(item_id.to_string()))
+    }
+
+    /// Items directly inside a container, or loose when `None`
+    pub fn contents(&self, container_id: Option<&str>) -> Vec<&InventoryItem> {
+        self.items.iter().filter(|i| i.container_id.as_deref() == container_id).collect()
+    }
+
+    /// A container and everything nested inside it
+    fn with_contents(&self, item_id: &str) -> HashSet<String> {
+        let mut ids = HashSet::from([item_id.to_string()]);
+        loop {
+            let nested: Vec<String> = self
+                .items
+                .iter()
+                .filter(|i| i.container_id.as_ref().is_some_and(|c| ids.contains(c)) && !ids.contains(&i.id))
+                .map(|i| i.id.clone())
+                .collect();
+            if nested.is_empty() {
+                return ids;
+            }
+            ids.extend(nested);
+        }
+    }
+
+    /// Weight of an item stack plus whatever it holds, as felt by whoever
+    /// carries it
+    fn carried_weight(&self, item: &InventoryItem) -> f64 {
+        let own = item.weight * item.quantity as f64;
+        match item.container {
+            Some(spec) if spec.weightless => own,
+            Some(_) => own + self.contents_weight(&item.id),
+            None => own,
+        }
+    }
+
+    /// Weight inside a container
+    fn contents_weight(&self, container_id: &str) -> f64 {
+        self.contents(Some(container_id)).into_iter().map(|i| self.carried_weight(i)).sum()
+    }
+
+    /// Everything the owner carries, with weightless containers counting
+    /// only their own weight
+    pub fn total_weight(&self) -> f64 {
+        self.contents(None).into_iter().map(|i| self.carried_weight(i)).sum()
+    }
+
+    /// Worth of everything, in the smallest coin
+    pub fn total_value(&self) -> i64 {
+        self.items.iter().map(|i| i.value * i.quantity as i64).sum()
+    }
+
+    pub fn attuned(&self) -> Vec<&InventoryItem> {
+        self.items.iter().filter(|i| i.attuned).collect()
+    }
+
+    /// Add items, loose or into a container, stacking with a matching stack
+    pub fn add(&mut self, mut item: InventoryItem) -> Result<InventoryItem> {
+        if item.quantity == 0 {
+            return Err(InventoryError::InvalidQuantity);
+        }
+        if let Some(container_id) = item.container_id.clone() {
+            self.check_room(&container_id, item.weight * item.quantity as f64)?;
+        }
+        if let Some(stack) = self.items.iter_mut().find(|i| i.stacks_with(&item)) {
+            stack.quantity += item.quantity;
+            self.updated_at = Utc::now();
+            return Ok(stack.clone());
+        }
+        if item.id.is_empty() || self.item(&item.id).is_some() {
+            item.id = Uuid::new_v4().to_string();
+        }
+        self.items.push(item.clone());
+        self.updated_at = Utc::now();
+        Ok(item)
+    }
+
+    fn check_room(&self, container_id: &str, weight: f64) -> Result<()> {
+        let container = &self.items[self.item_index(container_id)?];
+        let spec = container
+            .container
+            .ok_or_else(|| InventoryError::NotAContainer(container.name.clone()))?;
+        let free = spec.capacity - self.contents_weight(container_id);
+        if weight > free + f64::EPSILON {
+            return Err(InventoryError::ContainerFull {
+                container: container.name.clone(),
+                capacity: free.max(0.0),
+                requested: weight,
+            });
+        }
+        Ok(())
+    }
+
+    /// Take items out. A container comes out with everything inside it;
+    /// the removed items are returned, the taken stack first.
+    pub fn take(&mut self, item_id: &str, quantity: Option<u32>) -> Result<Vec<InventoryItem>> {
+        let index = self.item_index(item_id)?;
+        let available = self.items[index].quantity;
+        let quantity = quantity.unwrap_or(available);
+        if quantity == 0 {
+            return Err(InventoryError::InvalidQuantity);
+        }
+        if quantity > available {
+            return Err(InventoryError::NotEnough {
+                item: self.items[index].name.clone(),
+                available,
+                requested: quantity,
+            });
+        }
+        self.updated_at = Utc::now();
+
+        if quantity < available && self.items[index].container.is_none() {
+            self.items[index].quantity -= quantity;
+            let mut split = self.items[index].clone();
+            split.id = Uuid::new_v4().to_string();
+            split.quantity = quantity;
+            split.attuned = false;
+            split.equipped = false;
+            return Ok(vec![split]);
+        }
+
+        let ids = self.with_contents(item_id);
+        let (mut taken, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.items).into_iter().partition(|i| ids.contains(&i.id));
+        self.items = kept;
+        taken.sort_by_key(|i| i.id != item_id);
+        Ok(taken)
+    }
+
+    /// Put taken items in, keeping nested contents inside their container.
+    /// The first item goes loose, or into `container_id`.
+    pub fn put(&mut self, mut items: Vec<InventoryItem>, container_id: Option<&str>) -> Result<InventoryItem> {
+        if items.is_empty() {
+            return Err(InventoryError::InvalidQuantity);
+        }
+        let mut first = items.remove(0);
+        first.container_id = container_id.map(str::to_string);
+        first.attuned = false;
+        first.equipped = false;
+        if items.is_empty() {
+            return self.add(first);
+        }
+
+        if let Some(container_id) = container_id {
+            let nested: f64 = items.iter().map(|i| i.weight * i.quantity as f64).sum();
+            let load = if first.container.is_some_and(|c| c.weightless) { 0.0 } else { nested };
+            self.check_room(container_id, first.weight * first.quantity as f64 + load)?;
+        }
+        self.updated_at = Utc::now();
+        self.items.push(first.clone());
+        for mut item in items {
+            item.attuned = false;
+            item.equipped = false;
+            self.items.push(item);
+        }
+        Ok(first)
+    }
+
+    /// Move an item into a container, or loose with `None`
+    pub fn stow(&mut self, item_id: &str, container_id: Option<&str>) -> Result<()> {
+        let index = self.item_index(item_id)?;
+        if let Some(container_id) = container_id {
+            if self.with_contents(item_id).contains(container_id) {
+                return Err(InventoryError::ContainerCycle {
+                    item: self.items[index].name.clone(),
+                });
+            }
+            let weight = self.carried_weight(&self.items[index]);
+            self.check_room(container_id, weight)?;
+        }
+        self.items[index].container_id = container_id.map(str::to_string);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Attune to or end attunement with an item, within the system's limit
+    pub fn set_attunement(&mut self, item_id: &str, attuned: bool, limit: Option<u8>) -> Result<InventoryItem> {
+        let index = self.item_index(item_id)?;
+        let item = &self.items[index];
+        if attuned && !item.requires_attunement {
+            return Err(InventoryError::AttunementNotRequired(item.name.clone()));
+        }
+        if attuned && !item.attuned {
+            if let Some(limit) = limit {
+                if self.attuned().len() >= limit as usize {
+                    return Err(InventoryError::AttunementLimit { limit });
+                }
+            }
+        }
+        self.items[index].attuned = attuned;
+        self.updated_at = Utc::now();
+        Ok(self.items[index].clone())
+    }
+}
+
+// ============================================================================
+// Encumbrance
+// ============================================================================
+
+/// How weighed down a creature is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadLevel {
+    Unencumbered,
+    Encumbered,
+    HeavilyEncumbered,
+    /// Past the most the creature can carry at all
+    OverCapacity,
+}
+
+/// A game system's carrying rules
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EncumbranceRules {
+    pub system: String,
+    /// "lb" or "bulk"
+    pub unit: String,
+    /// Most items a creature can be attuned to (or invested in)
+    pub attunement_limit: Option<u8>,
+}
+
+/// A creature's load against its system's thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncumbranceStatus {
+    pub carried: f64,
+    pub unit: String,
+    pub level: LoadLevel,
+    /// Load at which the creature becomes encumbered
+    pub encumbered_at: f64,
+    /// Load at which the creature becomes heavily encumbered, where the
+    /// system has that step
+    pub heavily_encumbered_at: Option<f64>,
+    /// Most the creature can carry
+    pub capacity: f64,
+    /// What the load level does, for the GM
+    pub effects: String,
+    pub attuned: usize,
+    pub attunement_limit: Option<u8>,
+}
+
+impl EncumbranceRules {
+    /// Rules for a game system name, as accepted by [`GameSystem::from_str`]
+    pub fn for_system(system: &str) -> Self {
+        let game_system = GameSystem::from_str(system);
+        let (unit, attunement_limit) = match game_system {
+            GameSystem::DnD5e => ("lb", Some(3)),
+            GameSystem::Pathfinder2e => ("bulk", Some(10)),
+            _ => ("lb", None),
+        };
+        Self {
+            system: game_system.id().to_string(),
+            unit: unit.to_string(),
+            attunement_limit,
+        }
+    }
+
+    /// Measure a load against a creature's Strength score
+    pub fn assess(&self, carried: f64, strength: i32, attuned: usize) -> EncumbranceStatus {
+        let strength = strength.max(1);
+        let (encumbered_at, heavily_encumbered_at, capacity) = if self.unit == "bulk" {
+            // Bulk limits go by the Strength modifier
+            let modifier = (strength - 10).div_euclid(2) as f64;
+            (5.0 + modifier, None, 10.0 + modifier)
+        } else {
+            let strength = strength as f64;
+            (strength * 5.0, Some(strength * 10.0), strength * 15.0)
+        };
+
+        let level = if carried > capacity {
+            LoadLevel::OverCapacity
+        } else if heavily_encumbered_at.is_some_and(|at| carried > at) {
+            LoadLevel::HeavilyEncumbered
+        } else if carried > encumbered_at {
+            LoadLevel::Encumbered
+        } else {
+            LoadLevel::Unencumbered
+        };
+        let effects = match (self.unit.as_str(), level) {
+            (_, LoadLevel::Unencumbered) => "No penalty",
+            ("bulk", LoadLevel::Encumbered) => "Clumsy 1 and -10 ft. to all Speeds",
+            ("bulk", _) => "Cannot hold or carry more Bulk",
+            (_, LoadLevel::Encumbered) => "Speed -10 ft.",
+            (_, LoadLevel::HeavilyEncumbered) => {
+                "Speed -20 ft.; disadvantage on Strength, Dexterity, and Constitution ability checks, attack rolls, and saving throws"
+            }
+            (_, LoadLevel::OverCapacity) => "Can only push, drag, or lift; speed 5 ft.",
+        };
+
+        EncumbranceStatus {
+            carried,
+            unit: self.unit.clone(),
+            level,
+            encumbered_at,
+            heavily_encumbered_at,
+            capacity,
+            effects: effects.to_string(),
+            attuned,
+            attunement_limit: self.attunement_limit,
+        }
+    }
+}
+
+// ============================================================================
+// Inventory Manager
+// ============================================================================
+
+/// Items moved from one inventory to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemTransfer {
+    pub from: InventoryOwner,
+    pub to: InventoryOwner,
+    /// The moved stack as it landed, first, then anything that was inside it
+    pub items: Vec<InventoryItem>,
+}
+
+/// Holds every inventory, keyed by campaign and owner
+pub struct InventoryManager {
+    inventories: RwLock<HashMap<String, Inventory>>,
+}
+
+impl Default for InventoryManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InventoryManager {
+    pub fn new() -> Self {
+        Self {
+            inventories: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// An owner's inventory, empty if they have none yet
+    pub fn get(&self, campaign_id: &str, owner: &InventoryOwner) -> Inventory {
+        self.inventories
+            .read()
+            .unwrap()
+            .get(&owner.key(campaign_id))
+            .cloned()
+            .unwrap_or_else(|| Inventory::new(campaign_id, owner.clone()))
+    }
+
+    /// Change an owner's inventory in place, creating it if needed
+    pub fn update<T>(
+        &self,
+        campaign_id: &str,
+        owner: &InventoryOwner,
+        f: impl FnOnce(&mut Inventory) -> Result<T>,
+    ) -> Result<T> {
+        let mut inventories = self.inventories.write().unwrap();
+        let inventory = inventories
+            .entry(owner.key(campaign_id))
+            .or_insert_with(|| Inventory::new(campaign_id, owner.clone()));
+        f(inventory)
+    }
+
+    /// Move items between inventories: all of a stack without `quantity`,
+    /// and a container with its contents. Attunement ends on the way.
+    pub fn transfer(
+        &self,
+        campaign_id: &str,
+        from: &InventoryOwner,
+        to: &InventoryOwner,
+        item_id: &str,
+        quantity: Option<u32>,
+        container_id: Option<&str>,
+    ) -> Result<ItemTransfer> {
+        let mut inventories = self.inventories.write().unwrap();
+        let mut staged = inventories
+            .get(&from.key(campaign_id))
+            .cloned()
+            .ok_or_else(|| InventoryError::ItemNotFound(item_id.to_string()))?;
+        let taken = staged.take(item_id, quantity)?;
+        let nested: Vec<InventoryItem> = taken.iter().skip(1).cloned().collect();
+
+        let first = if from == to {
+            staged.put(taken, container_id)?
+        } else {
+            let mut landed = inventories
+                .get(&to.key(campaign_id))
+                .cloned()
+                .unwrap_or_else(|| Inventory::new(campaign_id, to.clone()));
+            let first = landed.put(taken, container_id)?;
+            inventories.insert(to.key(campaign_id), landed);
+            first
+        };
+        inventories.insert(from.key(campaign_id), staged);
+
+        Ok(ItemTransfer {
+            from: from.clone(),
+            to: to.clone(),
+            items: std::iter::once(first).chain(nested).collect(),
+        })
+    }
+
+    /// Move everything one owner carries to another, e.g. looting a fallen
+    /// combatant
+    pub fn transfer_all(&self, campaign_id: &str, from: &InventoryOwner, to: &InventoryOwner) -> Result<Vec<ItemTransfer>> {
+        let loose: Vec<String> = self.get(campaign_id, from).contents(None).iter().map(|i| i.id.clone()).collect();
+        loose
+            .iter()
+            .map(|item_id| self.transfer(campaign_id, from, to, item_id, None, None))
+            .collect()
+    }
+
+    /// Drop an owner's inventory, e.g. when a combatant leaves combat
+    pub fn remove(&self, campaign_id: &str, owner: &InventoryOwner) -> Option<Inventory> {
+        self.inventories.write().unwrap().remove(&owner.key(campaign_id))
+    }
+
+    /// A campaign's inventories
+    pub fn list(&self, campaign_id: &str) -> Vec<Inventory> {
+        self.inventories
+            .read()
+            .unwrap()
+            .values()
+            .filter(|i| i.campaign_id == campaign_id)
+            .cloned()
+            .collect()
+    }
+
+    pub fn delete_campaign_inventories(&self, campaign_id: &str) {
+        self.inventories.write().unwrap().retain(|_, i| i.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_containers_and_weight() {
+        let mut inventory = Inventory::new("camp-1", InventoryOwner::character("pc-1"));
+        let backpack = inventory.add(InventoryItem::new("Backpack", 1, 5.0).as_container(30.0, false)).unwrap();
+        let bag = inventory.add(InventoryItem::new("Bag of Holding", 1, 15.0).as_container(500.0, true)).unwrap();
+        let mut rope = InventoryItem::new("Hempen Rope", 1, 10.0);
+        rope.container_id = Some(backpack.id.clone());
+        inventory.add(rope).unwrap();
+        let mut gold_bars = InventoryItem::new("Gold Bar", 20, 10.0);
+        gold_bars.container_id = Some(bag.id.clone());
+        inventory.add(gold_bars).unwrap();
+
+        // Backpack 5 + rope 10, bag of holding counts only itself
+        assert_eq!(inventory.total_weight(), 30.0);
+
+        let mut anvil = InventoryItem::new("Anvil", 1, 100.0);
+        anvil.container_id = Some(backpack.id.clone());
+        assert!(matches!(inventory.add(anvil), Err(InventoryError::ContainerFull { .. })));
+        assert!(matches!(
+            inventory.stow(&bag.id, Some(&bag.id)),
+            Err(InventoryError::ContainerCycle { .. })
+        ));
+
+        let torches = inventory.add(InventoryItem::new("Torch", 5, 1.0)).unwrap();
+        inventory.add(InventoryItem::new("torch", 3, 1.0)).unwrap();
+        assert_eq!(inventory.item(&torches.id).unwrap().quantity, 8);
+    }
+
+    #[test]
+    fn test_attunement_limit_and_encumbrance() {
+        let rules = EncumbranceRules::for_system("dnd5e");
+        let mut inventory = Inventory::new("camp-1", InventoryOwner::character("pc-1"));
+        let mut ids = Vec::new();
+        for name in ["Ring of Protection", "Cloak of Elvenkind", "Boots of Speed", "Staff of Power"] {
+            ids.push(inventory.add(InventoryItem::new(name, 1, 1.0).needing_attunement()).unwrap().id);
+        }
+        let torch = inventory.add(InventoryItem::new("Torch", 1, 1.0)).unwrap();
+        for id in &ids[..3] {
+            inventory.set_attunement(id, true, rules.attunement_limit).unwrap();
+        }
+        assert!(matches!(
+            inventory.set_attunement(&ids[3], true, rules.attunement_limit),
+            Err(InventoryError::AttunementLimit { limit: 3 })
+        ));
+        assert!(inventory.set_attunement(&torch.id, true, None).is_err());
+
+        let status = rules.assess(60.0, 10, 3);
+        assert_eq!(status.level, LoadLevel::Encumbered);
+        assert_eq!(rules.assess(120.0, 10, 0).level, LoadLevel::HeavilyEncumbered);
+        assert_eq!(rules.assess(151.0, 10, 0).level, LoadLevel::OverCapacity);
+
+        let pf2e = EncumbranceRules::for_system("pf2e");
+        assert_eq!(pf2e.assess(7.0, 14, 0).level, LoadLevel::Unencumbered);
+        assert_eq!(pf2e.assess(8.0, 14, 0).level, LoadLevel::Encumbered);
+    }
+
+    #[test]
+    fn test_transfer_moves_containers_with_contents() {
+        let manager = InventoryManager::new();
+        let goblin = InventoryOwner::combatant("goblin-1");
+        let fighter = InventoryOwner::character("pc-1");
+        let pouch = manager
+            .update("camp-1", &goblin, |inv| inv.add(InventoryItem::new("Pouch", 1, 1.0).as_container(6.0, false)))
+            .unwrap();
+        manager
+            .update("camp-1", &goblin, |inv| {
+                let mut gem = InventoryItem::new("Garnet", 3, 0.0).worth(10_000);
+                gem.container_id = Some(pouch.id.clone());
+                inv.add(gem)?;
+                inv.add(InventoryItem::new("Scimitar", 1, 3.0))
+            })
+            .unwrap();
+        let arrows = manager
+            .update("camp-1", &fighter, |inv| inv.add(InventoryItem::new("Arrow", 20, 0.05)))
+            .unwrap();
+
+        let transfer = manager.transfer("camp-1", &goblin, &fighter, &pouch.id, None, None).unwrap();
+        assert_eq!(transfer.items.len(), 2);
+        assert_eq!(transfer.items[1].container_id.as_deref(), Some(pouch.id.as_str()));
+        assert_eq!(manager.get("camp-1", &fighter).total_value(), 30_000);
+
+        manager.transfer("camp-1", &fighter, &InventoryOwner::Party, &arrows.id, Some(5), None).unwrap();
+        assert_eq!(manager.get("camp-1", &fighter).item(&arrows.id).unwrap().quantity, 15);
+        assert_eq!(manager.get("camp-1", &InventoryOwner::Party).items[0].quantity, 5);
+
+        let looted = manager.transfer_all("camp-1", &goblin, &fighter).unwrap();
+        assert_eq!(looted.len(), 1);
+        assert!(manager.get("camp-1", &goblin).items.is_empty());
+        assert!(manager.transfer("camp-1", &fighter, &goblin, &arrows.id, Some(50), None).is_err());
+    }
+}
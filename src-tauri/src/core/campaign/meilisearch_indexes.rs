@@ -44,6 +44,12 @@ pub trait IndexConfig {
     /// Get the sortable attributes
     fn sortable_attributes() -> Vec<&'static str>;
 
+    /// Schema version; bump it when the settings or primary key change so
+    /// existing indexes are migrated
+    fn schema_version() -> u32 {
+        1
+    }
+
     /// Build meilisearch-lib Settings from configuration
     fn build_settings() -> Settings<Unchecked> {
         let searchable: Vec<String> = Self::searchable_attributes()
@@ -210,6 +216,8 @@ pub struct IndexInitConfig {
     pub name: &'static str,
     /// Primary key field
     pub primary_key: &'static str,
+    /// Schema version of the settings
+    pub version: u32,
     /// Settings to apply (meilisearch-lib format)
     pub settings: Settings<Unchecked>,
 }
@@ -220,16 +228,19 @@ pub fn get_index_configs() -> Vec<IndexInitConfig> {
         IndexInitConfig {
             name: CampaignArcsIndexConfig::index_name(),
             primary_key: CampaignArcsIndexConfig::primary_key(),
+            version: CampaignArcsIndexConfig::schema_version(),
             settings: CampaignArcsIndexConfig::build_settings(),
         },
         IndexInitConfig {
             name: SessionPlansIndexConfig::index_name(),
             primary_key: SessionPlansIndexConfig::primary_key(),
+            version: SessionPlansIndexConfig::schema_version(),
             settings: SessionPlansIndexConfig::build_settings(),
         },
         IndexInitConfig {
             name: PlotPointsIndexConfig::index_name(),
             primary_key: PlotPointsIndexConfig::primary_key(),
+            version: PlotPointsIndexConfig::schema_version(),
             settings: PlotPointsIndexConfig::build_settings(),
         },
     ]
@@ -37,9 +37,85 @@ pub mod dice;
 pub mod random_table;
 pub mod recap;
 
+// Campaign Templates & Cloning
+pub mod templates;
+
+// Full campaign archive export/import
+pub mod archive;
+
+// Quest and objective tracking
+pub mod quests;
+
+// Factions, influence, and clocks
+pub mod factions;
+
+// Fantasy calendars, recurring events, and NPC schedules
+pub mod calendar;
+
+// Procedural weather and travel conditions
+pub mod weather;
+
+// Party and player character roster
+pub mod party;
+
+// Post-session world-state change proposals
+pub mod world_updates;
+
+// Plot threads and dangling-thread detection
+pub mod plot_threads;
+
+// Interlinked Markdown/HTML campaign wiki
+pub mod wiki;
+
+// Player handouts and reveal tracking
+pub mod handouts;
+
+// Party treasury, loot splitting, and currency
+pub mod treasury;
+
+// Route distances, travel time, and journey encounters
+pub mod travel;
+
+// Campaign house rules and their search index
+pub mod house_rules;
+
+// XP and milestone progression for the party roster
+pub mod progression;
+
+// Campaign dashboard figures
+pub mod dashboard;
+
+// Settings shared by several campaigns
+pub mod world_setting;
+
+// Encryption at rest for campaign storage
+pub mod encryption;
+
+// Scheduled campaign backups with retention
+pub mod backup;
+
+// NPC whereabouts from schedules, travel, and world events
+pub mod presence;
+
+// Encounter XP budgets and saved encounters
+pub mod encounter_builder;
+
+// Merchant inventories stocked from ingested items
+pub mod shops;
+
+// NPC dispositions toward the party and individual PCs
+pub mod dispositions;
+
+// Itemized inventories, containers, attunement, and encumbrance
+pub mod inventory;
+
+// Spell slots, prepared spells, and rulebook links for casters
+pub mod spellcasting;
+
 // Re-exports for convenience
 pub use versioning::{
     CampaignVersion, VersionType, CampaignDiff, DiffEntry, DiffOperation, VersionManager,
+    SnapshotContent, SnapshotStorageStats, GarbageCollectionReport,
 };
 pub use world_state::{
     WorldState, WorldEvent, WorldEventType, LocationState, NpcRelationshipState,
@@ -173,3 +249,149 @@ pub use recap::{
     GenerateRecapRequest, GenerateArcRecapRequest,
     EntityReference, CharacterArcSummary, PCKnowledgeFilter,
 };
+
+// Campaign Templates re-exports
+pub use templates::{
+    CampaignTemplate, CampaignTemplateStore, CampaignTemplateError, CampaignFaction,
+    TemplateArc, TemplateFaction, TemplateLocation, builtin_templates, reissue_content_ids,
+};
+
+// Campaign Archive re-exports
+pub use archive::{
+    CampaignArchive, ArchivedSession, ArchiveManifest, ArchiveAsset, ArchiveError,
+    ARCHIVE_SCHEMA_VERSION, read_archive, write_archive, verify_archive,
+};
+
+// Quest re-exports
+pub use quests::{
+    Quest, QuestObjective, QuestReward, QuestStatus, ObjectiveStatus, RewardKind,
+    QuestManager, QuestError, QuestStatusSuggestion, QuestSuggestionKind,
+};
+
+// Faction re-exports
+pub use factions::{
+    Faction, FactionGoal, FactionResource, FactionMember, FactionClock,
+    FactionManager, FactionError, FactionEventOutcome, ClockAdvance,
+};
+
+// Calendar re-exports
+pub use calendar::{
+    CalendarDefinition, CalendarMonth, LeapRule, Moon, MoonPhase, Holiday, CalendarDay,
+    Recurrence, RecurringEvent, NpcScheduleEntry, ScheduleChange, TimeAdvance, TimeWindow,
+    CalendarManager, CalendarError,
+};
+
+// Weather re-exports
+pub use weather::{
+    ClimateZone, ClimateProfile, Season, WeatherFront, FrontKind, WeatherCondition,
+    WindLevel, Visibility, TravelConditions, WeatherReport, WeatherManager, WeatherError,
+};
+
+// Party roster re-exports
+pub use party::{
+    PlayerCharacter, PassiveStats, InventorySummary, LevelUpRecord, PartyOverview,
+    PartyManager, PartyError,
+};
+
+// World update re-exports
+pub use world_updates::{
+    WorldChange, ProposedChange, ChangeDecision, WorldStateChangeset, ChangesetStatus,
+    ChangesetApplication, KnownEntity, ChangesetManager, ChangesetError,
+};
+
+// Plot thread re-exports
+pub use plot_threads::{
+    PlotThread, PlotThreadStatus, ThreadTouch, SessionActivity, DanglingThread, PlotThreadAnalysis,
+    PlotThreadManager, PlotThreadError,
+};
+
+// Wiki export re-exports
+pub use wiki::{WikiFormat, WikiRecap, WikiSource, WikiPage, WikiSite, WikiError, build_wiki};
+
+// Handout re-exports
+pub use handouts::{
+    Handout, HandoutContent, RevealRecord, PlayerHandout, HandoutManager, HandoutError,
+};
+
+// Treasury re-exports
+pub use treasury::{
+    Treasury, TreasuryManager, TreasuryError, CurrencySystem, Denomination, Account, Transaction,
+    TransactionKind, TransactionEntry, ParsedLoot, LootCoins, LootDistribution, SpendingReport,
+    SpendingTotal, parse_loot,
+};
+
+// Travel re-exports
+pub use travel::{
+    TravelPace, Terrain, RouteSegment, TravelLeg, TravelPlan, EncounterCheck, TravelManager, TravelError,
+};
+
+// House rule re-exports
+pub use house_rules::{
+    HouseRule, RuleReference, HouseRuleMatch, HouseRuleManager, HouseRuleError, HOUSE_RULE_BADGE,
+};
+
+// Progression re-exports
+pub use progression::{
+    ProgressionMode, ProgressionConfig, AwardKind, ProgressionAward, PendingLevelUp, CharacterProgression,
+    SessionAwards, ProgressionReport, ProgressionManager, ProgressionError,
+};
+
+// Dashboard re-exports
+pub use dashboard::{
+    CampaignDashboard, SessionCadence, ArcOverview, NpcActivity, CombatStats, SpendSummary, active_arcs,
+    npc_activity, is_voice_usage,
+};
+
+// Shared setting re-exports
+pub use world_setting::{
+    WorldSetting, WorldSettingManager, WorldSettingError, SettingMembership, SharedEntityKind, Deity,
+    PropagationRule, EntityOverride, propagated_event, PROPAGATED_FROM_FIELD,
+};
+
+// Encryption re-exports
+pub use encryption::{
+    CampaignKey, CampaignKeys, RecoveryPhrase, EncryptedCampaign, EncryptionError, WrappedKey, SealedBox,
+    key_entry, recovery_entry, RECOVERY_PHRASE_WORDS,
+};
+
+// Backup re-exports
+pub use backup::{
+    BackupSchedule, RetentionPolicy, BackupConfig, BackupRecord, BackupStore, BackupManager, BackupError,
+    DEFAULT_BACKUP_SCHEDULE,
+};
+
+// Presence re-exports
+pub use presence::{
+    PresenceManager, PresenceOverride, PresenceSource, NpcPresence, PresenceError, overrides_from_event,
+    travel_overrides, PRESENCE_UNTIL_FIELD,
+};
+
+// Encounter builder re-exports
+pub use encounter_builder::{
+    EncounterManager, EncounterError, EncounterRules, XpBudget, DifficultyThreshold, EncounterCreature,
+    EncounterEvaluation, CreatureCandidate, CandidateQuery, SavedEncounter, xp_budget, evaluate_encounter, select_candidates,
+    cr_to_xp, parse_cr, pf2e_creature_xp, encounter_multiplier,
+};
+
+// Shop re-exports
+pub use shops::{
+    ShopManager, ShopError, Shop, ShopItem, ShopType, SettlementSize, ItemCandidate, RestockReport, ShopPurchase,
+    basic_stock, with_basic_stock, parse_price, RESALE_PERCENT,
+};
+
+// Disposition re-exports
+pub use dispositions::{
+    DispositionTier, InteractionKind, PartyDisposition, DispositionChange, party_dispositions, log_interaction,
+    disposition_prompt_section, DISPOSITION_FIELD, PARTY_TARGET_ID,
+};
+
+// Inventory re-exports
+pub use inventory::{
+    InventoryManager, InventoryError, Inventory, InventoryItem, InventoryOwner, ContainerSpec, EncumbranceRules,
+    EncumbranceStatus, LoadLevel, ItemTransfer,
+};
+
+// Spellcasting re-exports
+pub use spellcasting::{
+    SpellcastingManager, SpellcastingError, Spellbook, KnownSpell, Caster, SlotPool, CastOutcome, MAX_SPELL_LEVEL,
+};
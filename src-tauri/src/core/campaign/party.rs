@@ -0,0 +1,420 @@
+//! Party Roster Module
+//!
+//! First-class player characters per campaign: who plays them, class and
+//! level, passive scores, and a short inventory summary. The roster feeds
+//! party composition into the combat tracker, encounter difficulty, and
+//! party gap analysis.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::generation::{PartyMember, PartyRole};
+
+/// Highest level a character can reach
+pub const MAX_LEVEL: u8 = 20;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum PartyError {
+    #[error("Character not found: {0}")]
+    CharacterNotFound(String),
+
+    #[error("Character name cannot be empty")]
+    EmptyName,
+
+    #[error("{0} is already at the maximum level")]
+    MaxLevel(String),
+}
+
+pub type Result<T> = std::result::Result<T, PartyError>;
+
+// ============================================================================
+// Character Types
+// ============================================================================
+
+/// Passive scores the GM checks without asking for a roll
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PassiveStats {
+    pub perception: i32,
+    pub insight: i32,
+    pub investigation: i32,
+}
+
+impl Default for PassiveStats {
+    fn default() -> Self {
+        Self {
+            perception: 10,
+            insight: 10,
+            investigation: 10,
+        }
+    }
+}
+
+/// What the character is carrying, at a glance
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InventorySummary {
+    /// Wealth in gold pieces
+    #[serde(default)]
+    pub gold: i64,
+    #[serde(default)]
+    pub notable_items: Vec<String>,
+    #[serde(default)]
+    pub attuned_items: Vec<String>,
+}
+
+/// A level gained
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelUpRecord {
+    pub from_level: u8,
+    pub to_level: u8,
+    pub hp_gained: i32,
+    #[serde(default)]
+    pub notes: String,
+    pub leveled_at: DateTime<Utc>,
+}
+
+/// A player character on a campaign's party roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerCharacter {
+    pub id: String,
+    pub campaign_id: String,
+    pub name: String,
+    /// The person playing the character
+    pub player_name: Option<String>,
+    pub class: String,
+    pub subclass: Option<String>,
+    pub ancestry: Option<String>,
+    pub level: u8,
+    #[serde(default)]
+    pub experience: u32,
+    pub role: Option<PartyRole>,
+    pub max_hp: i32,
+    pub armor_class: i32,
+    #[serde(default)]
+    pub initiative_modifier: i32,
+    #[serde(default)]
+    pub passives: PassiveStats,
+    #[serde(default)]
+    pub inventory: InventorySummary,
+    /// Inactive characters (retired, absent, dead) stay on the roster but are
+    /// left out of the party
+    pub active: bool,
+    #[serde(default)]
+    pub level_history: Vec<LevelUpRecord>,
+    #[serde(default)]
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PlayerCharacter {
+    pub fn new(campaign_id: &str, name: &str, class: &str, level: u8) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            name: name.to_string(),
+            player_name: None,
+            class: class.to_string(),
+            subclass: None,
+            ancestry: None,
+            level: level.clamp(1, MAX_LEVEL),
+            experience: 0,
+            role: None,
+            max_hp: 10,
+            armor_class: 10,
+            initiative_modifier: 0,
+            passives: PassiveStats::default(),
+            inventory: InventorySummary::default(),
+            active: true,
+            level_history: vec![],
+            notes: String::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn played_by(mut self, player_name: &str) -> Self {
+        self.player_name = Some(player_name.to_string());
+        self
+    }
+
+    pub fn with_combat_stats(mut self, max_hp: i32, armor_class: i32, initiative_modifier: i32) -> Self {
+        self.max_hp = max_hp;
+        self.armor_class = armor_class;
+        self.initiative_modifier = initiative_modifier;
+        self
+    }
+
+    /// The character as a party member for composition analysis
+    pub fn to_party_member(&self) -> PartyMember {
+        PartyMember {
+            name: Some(self.name.clone()),
+            class: self.class.clone(),
+            subclass: self.subclass.clone(),
+            level: self.level,
+            role: self.role,
+        }
+    }
+}
+
+/// Average hit points gained per level for a class (D&D 5e hit dice,
+/// without the Constitution modifier)
+pub fn average_hp_per_level(class: &str) -> i32 {
+    match class.to_lowercase().as_str() {
+        "barbarian" => 7,
+        "fighter" | "paladin" | "ranger" => 6,
+        "sorcerer" | "wizard" => 4,
+        _ => 5,
+    }
+}
+
+/// The active party at a glance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyOverview {
+    pub campaign_id: String,
+    pub size: u8,
+    /// Average level, rounded
+    pub average_level: u8,
+    pub min_level: u8,
+    pub max_level: u8,
+    /// Lowest passive perception in the party (who gets surprised)
+    pub lowest_passive_perception: Option<i32>,
+    pub total_gold: i64,
+    pub members: Vec<PartyMember>,
+}
+
+// ============================================================================
+// Party Manager
+// ============================================================================
+
+/// Holds every campaign's party roster
+pub struct PartyManager {
+    characters: RwLock<HashMap<String, PlayerCharacter>>,
+}
+
+impl Default for PartyManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartyManager {
+    pub fn new() -> Self {
+        Self {
+            characters: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_character(&self, character: PlayerCharacter) -> Result<PlayerCharacter> {
+        if character.name.trim().is_empty() {
+            return Err(PartyError::EmptyName);
+        }
+        self.characters
+            .write()
+            .unwrap()
+            .insert(character.id.clone(), character.clone());
+        Ok(character)
+    }
+
+    pub fn get_character(&self, character_id: &str) -> Option<PlayerCharacter> {
+        self.characters.read().unwrap().get(character_id).cloned()
+    }
+
+    pub fn update_character(&self, mut character: PlayerCharacter) -> Result<PlayerCharacter> {
+        if character.name.trim().is_empty() {
+            return Err(PartyError::EmptyName);
+        }
+        let mut characters = self.characters.write().unwrap();
+        if !characters.contains_key(&character.id) {
+            return Err(PartyError::CharacterNotFound(character.id));
+        }
+        character.level = character.level.clamp(1, MAX_LEVEL);
+        character.updated_at = Utc::now();
+        characters.insert(character.id.clone(), character.clone());
+        Ok(character)
+    }
+
+    pub fn delete_character(&self, character_id: &str) -> Result<()> {
+        self.characters
+            .write()
+            .unwrap()
+            .remove(character_id)
+            .map(|_| ())
+            .ok_or_else(|| PartyError::CharacterNotFound(character_id.to_string()))
+    }
+
+    /// List a campaign's characters by name, optionally including inactive ones
+    pub fn list_characters(&self, campaign_id: &str, include_inactive: bool) -> Vec<PlayerCharacter> {
+        let mut characters: Vec<PlayerCharacter> = self
+            .characters
+            .read()
+            .unwrap()
+            .values()
+            .filter(|c| c.campaign_id == campaign_id && (include_inactive || c.active))
+            .cloned()
+            .collect();
+        characters.sort_by(|a, b| a.name.cmp(&b.name));
+        characters
+    }
+
+    /// Raise a character one level. Without `hp_gained`, the class average
+    /// is used.
+    pub fn level_up(&self, character_id: &str, hp_gained: Option<i32>, notes: &str) -> Result<PlayerCharacter> {
+        let mut characters = self.characters.write().unwrap();
+        let character = characters
+            .get_mut(character_id)
+            .ok_or_else(|| PartyError::CharacterNotFound(character_id.to_string()))?;
+        if character.level >= MAX_LEVEL {
+            return Err(PartyError::MaxLevel(character.name.clone()));
+        }
+
+        let hp_gained = hp_gained.unwrap_or_else(|| average_hp_per_level(&character.class));
+        character.level_history.push(LevelUpRecord {
+            from_level: character.level,
+            to_level: character.level + 1,
+            hp_gained,
+            notes: notes.to_string(),
+            leveled_at: Utc::now(),
+        });
+        character.level += 1;
+        character.max_hp += hp_gained;
+        character.updated_at = Utc::now();
+        Ok(character.clone())
+    }
+
+    /// Add experience points to a character without changing their level
+    pub fn add_experience(&self, character_id: &str, amount: u32) -> Result<PlayerCharacter> {
+        let mut characters = self.characters.write().unwrap();
+        let character = characters
+            .get_mut(character_id)
+            .ok_or_else(|| PartyError::CharacterNotFound(character_id.to_string()))?;
+        character.experience = character.experience.saturating_add(amount);
+        character.updated_at = Utc::now();
+        Ok(character.clone())
+    }
+
+    /// Summarize the active party; `None` when no one is on the roster
+    pub fn overview(&self, campaign_id: &str) -> Option<PartyOverview> {
+        let party = self.list_characters(campaign_id, false);
+        if party.is_empty() {
+            return None;
+        }
+
+        let levels: Vec<u32> = party.iter().map(|c| c.level as u32).collect();
+        let average_level = (levels.iter().sum::<u32>() as f32 / levels.len() as f32).round() as u8;
+        Some(PartyOverview {
+            campaign_id: campaign_id.to_string(),
+            size: party.len().min(u8::MAX as usize) as u8,
+            average_level,
+            min_level: levels.iter().copied().min().unwrap_or(1) as u8,
+            max_level: levels.iter().copied().max().unwrap_or(1) as u8,
+            lowest_passive_perception: party.iter().map(|c| c.passives.perception).min(),
+            total_gold: party.iter().map(|c| c.inventory.gold).sum(),
+            members: party.iter().map(PlayerCharacter::to_party_member).collect(),
+        })
+    }
+
+    pub fn delete_campaign_party(&self, campaign_id: &str) {
+        self.characters
+            .write()
+            .unwrap()
+            .retain(|_, c| c.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (PartyManager, PlayerCharacter, PlayerCharacter) {
+        let manager = PartyManager::new();
+        let fighter = manager
+            .create_character(PlayerCharacter::new("camp-1", "Bruenor", "Fighter", 5).played_by("Sam"))
+            .unwrap();
+        let mut wizard = PlayerCharacter::new("camp-1", "Elminster", "Wizard", 4);
+        wizard.passives.perception = 14;
+        wizard.inventory.gold = 120;
+        let wizard = manager.create_character(wizard).unwrap();
+        (manager, fighter, wizard)
+    }
+
+    #[test]
+    fn test_create_and_list_characters() {
+        let (manager, fighter, _) = setup();
+        manager
+            .create_character(PlayerCharacter::new("camp-2", "Drizzt", "Ranger", 7))
+            .unwrap();
+        assert!(manager
+            .create_character(PlayerCharacter::new("camp-1", " ", "Rogue", 1))
+            .is_err());
+
+        let names: Vec<String> = manager.list_characters("camp-1", false).into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["Bruenor", "Elminster"]);
+
+        let mut retired = fighter.clone();
+        retired.active = false;
+        manager.update_character(retired).unwrap();
+        assert_eq!(manager.list_characters("camp-1", false).len(), 1);
+        assert_eq!(manager.list_characters("camp-1", true).len(), 2);
+    }
+
+    #[test]
+    fn test_level_up_records_history() {
+        let (manager, fighter, _) = setup();
+        let leveled = manager.level_up(&fighter.id, None, "Defeated the dragon").unwrap();
+        assert_eq!(leveled.level, 6);
+        assert_eq!(leveled.max_hp, fighter.max_hp + 6);
+        assert_eq!(leveled.level_history.len(), 1);
+        assert_eq!(leveled.level_history[0].from_level, 5);
+
+        let leveled = manager.level_up(&fighter.id, Some(9), "").unwrap();
+        assert_eq!(leveled.max_hp, fighter.max_hp + 15);
+    }
+
+    #[test]
+    fn test_level_cap() {
+        let manager = PartyManager::new();
+        let character = manager
+            .create_character(PlayerCharacter::new("camp-1", "Demigod", "Cleric", 25))
+            .unwrap();
+        assert_eq!(character.level, MAX_LEVEL);
+        assert!(matches!(
+            manager.level_up(&character.id, None, ""),
+            Err(PartyError::MaxLevel(_))
+        ));
+    }
+
+    #[test]
+    fn test_party_overview() {
+        let (manager, _, _) = setup();
+        assert!(manager.overview("camp-empty").is_none());
+
+        let overview = manager.overview("camp-1").unwrap();
+        assert_eq!(overview.size, 2);
+        assert_eq!(overview.average_level, 5);
+        assert_eq!((overview.min_level, overview.max_level), (4, 5));
+        assert_eq!(overview.lowest_passive_perception, Some(10));
+        assert_eq!(overview.total_gold, 120);
+        assert_eq!(overview.members[0].class, "Fighter");
+    }
+
+    #[test]
+    fn test_delete_campaign_party() {
+        let (manager, fighter, _) = setup();
+        manager.delete_campaign_party("camp-1");
+        assert!(manager.get_character(&fighter.id).is_none());
+        assert!(manager.delete_character(&fighter.id).is_err());
+    }
+}
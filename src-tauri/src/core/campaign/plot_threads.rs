@@ -0,0 +1,511 @@
+//! Plot Thread Tracking Module
+//!
+//! Story threads the GM has opened (a missing caravan, a stolen relic, a
+//! rival's grudge) with the session they were introduced in and the NPCs
+//! and locations tied to them. Scanning session timelines and notes shows
+//! which open threads have gone untouched for too long.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::session::notes::SessionNote;
+use crate::core::session::timeline::TimelineEvent;
+
+/// Sessions without a mention before an open thread counts as dangling
+pub const DEFAULT_DANGLING_AFTER: u32 = 3;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum PlotThreadError {
+    #[error("Plot thread not found: {0}")]
+    ThreadNotFound(String),
+
+    #[error("Plot thread title cannot be empty")]
+    EmptyTitle,
+}
+
+pub type Result<T> = std::result::Result<T, PlotThreadError>;
+
+// ============================================================================
+// Plot Thread Types
+// ============================================================================
+
+/// Where a plot thread stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PlotThreadStatus {
+    /// Introduced and waiting for a payoff
+    #[default]
+    Open,
+    /// Paid off in play
+    Resolved,
+    /// Deliberately dropped by the GM
+    Abandoned,
+}
+
+impl PlotThreadStatus {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "open" => Some(Self::Open),
+            "resolved" => Some(Self::Resolved),
+            "abandoned" => Some(Self::Abandoned),
+            _ => None,
+        }
+    }
+}
+
+/// A session in which a thread came up
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadTouch {
+    pub session_id: String,
+    pub session_number: u32,
+    /// Keyword that matched, or "manual" when recorded by hand
+    pub matched: String,
+}
+
+/// A story thread waiting for a payoff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlotThread {
+    pub id: String,
+    pub campaign_id: String,
+    pub title: String,
+    pub description: String,
+    pub status: PlotThreadStatus,
+    /// Session the thread was introduced in
+    pub introduced_session_id: Option<String>,
+    pub introduced_in: Option<u32>,
+    pub npc_ids: Vec<String>,
+    pub location_ids: Vec<String>,
+    /// Words in session timelines and notes that point at this thread
+    pub keywords: Vec<String>,
+    /// Sessions the thread came up in, oldest first
+    pub touches: Vec<ThreadTouch>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl PlotThread {
+    pub fn new(campaign_id: &str, title: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            status: PlotThreadStatus::Open,
+            introduced_session_id: None,
+            introduced_in: None,
+            npc_ids: Vec::new(),
+            location_ids: Vec::new(),
+            keywords: Vec::new(),
+            touches: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            resolved_at: None,
+        }
+    }
+
+    /// Builder: set description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Builder: set the session the thread was introduced in
+    pub fn introduced(mut self, session_id: &str, session_number: u32) -> Self {
+        self.introduced_session_id = Some(session_id.to_string());
+        self.introduced_in = Some(session_number);
+        self
+    }
+
+    /// Builder: set keywords
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// The last session the thread came up in, or its introduction
+    pub fn last_touched(&self) -> Option<u32> {
+        self.touches
+            .iter()
+            .map(|t| t.session_number)
+            .chain(self.introduced_in)
+            .max()
+    }
+
+    fn set_status(&mut self, status: PlotThreadStatus) {
+        self.status = status;
+        self.resolved_at = (status != PlotThreadStatus::Open).then(Utc::now);
+        self.updated_at = Utc::now();
+    }
+
+    /// Thread keywords, falling back to the title when none are set
+    fn match_terms(&self) -> Vec<String> {
+        if self.keywords.is_empty() {
+            vec![self.title.to_lowercase()]
+        } else {
+            self.keywords.iter().map(|k| k.to_lowercase()).collect()
+        }
+    }
+
+    fn add_touch(&mut self, touch: ThreadTouch) -> bool {
+        if self.touches.iter().any(|t| t.session_id == touch.session_id) {
+            return false;
+        }
+        self.touches.push(touch);
+        self.touches.sort_by_key(|t| t.session_number);
+        self.updated_at = Utc::now();
+        true
+    }
+}
+
+// ============================================================================
+// Session Scanning
+// ============================================================================
+
+/// What happened in one session, as searchable text
+#[derive(Debug, Clone)]
+pub struct SessionActivity {
+    pub session_id: String,
+    pub session_number: u32,
+    text: String,
+}
+
+impl SessionActivity {
+    /// Gather a session's timeline events and notes
+    pub fn new(session_id: &str, session_number: u32, events: &[TimelineEvent], notes: &[SessionNote]) -> Self {
+        let mut parts: Vec<String> = events
+            .iter()
+            .map(|e| format!("{} {} {}", e.title, e.description, e.tags.join(" ")))
+            .collect();
+        parts.extend(
+            notes
+                .iter()
+                .map(|n| format!("{} {} {}", n.title, n.content, n.tags.join(" "))),
+        );
+        Self {
+            session_id: session_id.to_string(),
+            session_number,
+            text: parts.join("\n").to_lowercase(),
+        }
+    }
+
+    fn find_term<'a>(&self, terms: &'a [String]) -> Option<&'a String> {
+        terms.iter().find(|t| !t.is_empty() && self.text.contains(t.as_str()))
+    }
+}
+
+/// An open thread that hasn't come up in a while
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanglingThread {
+    pub thread_id: String,
+    pub title: String,
+    pub introduced_in: Option<u32>,
+    pub last_touched: Option<u32>,
+    /// Scanned sessions played since the thread last came up
+    pub sessions_untouched: u32,
+    pub npc_ids: Vec<String>,
+    pub location_ids: Vec<String>,
+    /// Reminder text for the GM
+    pub reminder: String,
+}
+
+/// Result of scanning sessions for plot thread activity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlotThreadAnalysis {
+    pub campaign_id: String,
+    pub sessions_scanned: usize,
+    /// Touches found during this scan
+    pub new_touches: usize,
+    /// Dangling threads, longest untouched first
+    pub dangling: Vec<DanglingThread>,
+}
+
+fn reminder_for(thread: &PlotThread, untouched: u32) -> String {
+    match thread.last_touched() {
+        Some(session) => format!(
+            "You never resolved \"{}\" - it hasn't come up since session {} ({} sessions ago).",
+            thread.title, session, untouched
+        ),
+        None => format!(
+            "You never resolved \"{}\" - it hasn't come up in the last {} sessions.",
+            thread.title, untouched
+        ),
+    }
+}
+
+// ============================================================================
+// Plot Thread Manager
+// ============================================================================
+
+pub struct PlotThreadManager {
+    threads: RwLock<HashMap<String, PlotThread>>,
+}
+
+impl Default for PlotThreadManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlotThreadManager {
+    pub fn new() -> Self {
+        Self {
+            threads: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_thread(&self, thread: PlotThread) -> Result<PlotThread> {
+        if thread.title.trim().is_empty() {
+            return Err(PlotThreadError::EmptyTitle);
+        }
+        self.threads.write().unwrap().insert(thread.id.clone(), thread.clone());
+        Ok(thread)
+    }
+
+    pub fn get_thread(&self, thread_id: &str) -> Option<PlotThread> {
+        self.threads.read().unwrap().get(thread_id).cloned()
+    }
+
+    pub fn update_thread(&self, mut thread: PlotThread) -> Result<PlotThread> {
+        if thread.title.trim().is_empty() {
+            return Err(PlotThreadError::EmptyTitle);
+        }
+        let mut threads = self.threads.write().unwrap();
+        let existing = threads
+            .get(&thread.id)
+            .ok_or_else(|| PlotThreadError::ThreadNotFound(thread.id.clone()))?;
+        if existing.status != thread.status {
+            thread.resolved_at = (thread.status != PlotThreadStatus::Open).then(Utc::now);
+        }
+        thread.updated_at = Utc::now();
+        threads.insert(thread.id.clone(), thread.clone());
+        Ok(thread)
+    }
+
+    pub fn delete_thread(&self, thread_id: &str) -> Result<()> {
+        self.threads
+            .write()
+            .unwrap()
+            .remove(thread_id)
+            .map(|_| ())
+            .ok_or_else(|| PlotThreadError::ThreadNotFound(thread_id.to_string()))
+    }
+
+    /// List a campaign's threads, oldest first
+    pub fn list_threads(&self, campaign_id: &str, status: Option<PlotThreadStatus>) -> Vec<PlotThread> {
+        let mut threads: Vec<PlotThread> = self
+            .threads
+            .read()
+            .unwrap()
+            .values()
+            .filter(|t| t.campaign_id == campaign_id && status.is_none_or(|s| t.status == s))
+            .cloned()
+            .collect();
+        threads.sort_by_key(|t| t.created_at);
+        threads
+    }
+
+    /// Threads involving an NPC or location
+    pub fn threads_for_entity(&self, campaign_id: &str, entity_id: &str) -> Vec<PlotThread> {
+        self.list_threads(campaign_id, None)
+            .into_iter()
+            .filter(|t| t.npc_ids.iter().chain(&t.location_ids).any(|id| id == entity_id))
+            .collect()
+    }
+
+    pub fn set_status(&self, thread_id: &str, status: PlotThreadStatus) -> Result<PlotThread> {
+        self.with_thread_mut(thread_id, |thread| thread.set_status(status))
+    }
+
+    /// Record by hand that a thread came up in a session
+    pub fn record_touch(&self, thread_id: &str, session_id: &str, session_number: u32) -> Result<PlotThread> {
+        self.with_thread_mut(thread_id, |thread| {
+            thread.add_touch(ThreadTouch {
+                session_id: session_id.to_string(),
+                session_number,
+                matched: "manual".to_string(),
+            });
+        })
+    }
+
+    /// Record touches for every open thread mentioned in the sessions, then
+    /// report threads untouched for at least `dangling_after` of them.
+    pub fn analyze(&self, campaign_id: &str, sessions: &[SessionActivity], dangling_after: u32) -> PlotThreadAnalysis {
+        let mut new_touches = 0;
+        let mut dangling = Vec::new();
+
+        let mut threads = self.threads.write().unwrap();
+        for thread in threads
+            .values_mut()
+            .filter(|t| t.campaign_id == campaign_id && t.status == PlotThreadStatus::Open)
+        {
+            let terms = thread.match_terms();
+            for session in sessions {
+                if thread.introduced_in.is_some_and(|n| session.session_number < n) {
+                    continue;
+                }
+                if let Some(term) = session.find_term(&terms) {
+                    let touch = ThreadTouch {
+                        session_id: session.session_id.clone(),
+                        session_number: session.session_number,
+                        matched: term.clone(),
+                    };
+                    if thread.add_touch(touch) {
+                        new_touches += 1;
+                    }
+                }
+            }
+
+            let since = thread.last_touched();
+            let untouched = sessions
+                .iter()
+                .filter(|s| since.is_none_or(|n| s.session_number > n))
+                .count() as u32;
+            if untouched >= dangling_after.max(1) {
+                dangling.push(DanglingThread {
+                    thread_id: thread.id.clone(),
+                    title: thread.title.clone(),
+                    introduced_in: thread.introduced_in,
+                    last_touched: since,
+                    sessions_untouched: untouched,
+                    npc_ids: thread.npc_ids.clone(),
+                    location_ids: thread.location_ids.clone(),
+                    reminder: reminder_for(thread, untouched),
+                });
+            }
+        }
+        dangling.sort_by_key(|d| std::cmp::Reverse(d.sessions_untouched));
+
+        PlotThreadAnalysis {
+            campaign_id: campaign_id.to_string(),
+            sessions_scanned: sessions.len(),
+            new_touches,
+            dangling,
+        }
+    }
+
+    /// Remove all plot threads for a campaign
+    pub fn delete_campaign_threads(&self, campaign_id: &str) {
+        self.threads.write().unwrap().retain(|_, t| t.campaign_id != campaign_id);
+    }
+
+    fn with_thread_mut(&self, thread_id: &str, f: impl FnOnce(&mut PlotThread)) -> Result<PlotThread> {
+        let mut threads = self.threads.write().unwrap();
+        let thread = threads
+            .get_mut(thread_id)
+            .ok_or_else(|| PlotThreadError::ThreadNotFound(thread_id.to_string()))?;
+        f(thread);
+        thread.updated_at = Utc::now();
+        Ok(thread.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::timeline::TimelineEventType;
+
+    fn session(number: u32, description: &str) -> SessionActivity {
+        let event = TimelineEvent::new(
+            format!("session-{}", number),
+            TimelineEventType::PlayerAction,
+            "Scene",
+            description,
+        );
+        SessionActivity::new(&format!("session-{}", number), number, &[event], &[])
+    }
+
+    fn caravan(manager: &PlotThreadManager) -> PlotThread {
+        let mut thread = PlotThread::new("campaign-1", "The Missing Caravan")
+            .introduced("session-1", 1)
+            .with_keywords(vec!["caravan".to_string(), "Merchant Tolvo".to_string()]);
+        thread.npc_ids = vec!["npc-tolvo".to_string()];
+        manager.create_thread(thread).unwrap()
+    }
+
+    #[test]
+    fn test_thread_crud_and_entities() {
+        let manager = PlotThreadManager::new();
+        let thread = caravan(&manager);
+
+        assert!(manager.create_thread(PlotThread::new("campaign-1", " ")).is_err());
+        assert_eq!(manager.threads_for_entity("campaign-1", "npc-tolvo").len(), 1);
+        assert!(manager.list_threads("campaign-2", None).is_empty());
+
+        let resolved = manager.set_status(&thread.id, PlotThreadStatus::Resolved).unwrap();
+        assert!(resolved.resolved_at.is_some());
+        assert!(manager.list_threads("campaign-1", Some(PlotThreadStatus::Open)).is_empty());
+
+        manager.delete_thread(&thread.id).unwrap();
+        assert!(manager.get_thread(&thread.id).is_none());
+    }
+
+    #[test]
+    fn test_untouched_thread_is_dangling() {
+        let manager = PlotThreadManager::new();
+        let thread = caravan(&manager);
+        let sessions = [
+            session(1, "A merchant begs the party to find his caravan"),
+            session(2, "Tavern brawl"),
+            session(3, "Into the crypt"),
+            session(4, "The lich awakens"),
+        ];
+
+        let analysis = manager.analyze("campaign-1", &sessions, 3);
+        assert_eq!(analysis.new_touches, 1);
+        assert_eq!(analysis.dangling.len(), 1);
+        assert_eq!(analysis.dangling[0].thread_id, thread.id);
+        assert_eq!(analysis.dangling[0].last_touched, Some(1));
+        assert_eq!(analysis.dangling[0].sessions_untouched, 3);
+        assert!(analysis.dangling[0].reminder.contains("The Missing Caravan"));
+    }
+
+    #[test]
+    fn test_recent_mention_keeps_thread_alive() {
+        let manager = PlotThreadManager::new();
+        caravan(&manager);
+        let sessions = [
+            session(2, "Tavern brawl"),
+            session(3, "Merchant Tolvo sends another letter"),
+            session(4, "The lich awakens"),
+        ];
+
+        let analysis = manager.analyze("campaign-1", &sessions, 3);
+        assert!(analysis.dangling.is_empty());
+        // Scanning again doesn't record the same session twice
+        assert_eq!(manager.analyze("campaign-1", &sessions, 3).new_touches, 0);
+    }
+
+    #[test]
+    fn test_mentions_before_introduction_are_ignored() {
+        let manager = PlotThreadManager::new();
+        let thread = manager
+            .create_thread(PlotThread::new("campaign-1", "Cult of the Eye").introduced("session-5", 5))
+            .unwrap();
+
+        manager.analyze("campaign-1", &[session(3, "Whispers of the cult of the eye")], 3);
+        assert!(manager.get_thread(&thread.id).unwrap().touches.is_empty());
+
+        manager.record_touch(&thread.id, "session-6", 6).unwrap();
+        assert_eq!(manager.get_thread(&thread.id).unwrap().last_touched(), Some(6));
+    }
+
+    #[test]
+    fn test_resolved_threads_are_not_dangling() {
+        let manager = PlotThreadManager::new();
+        let thread = caravan(&manager);
+        manager.set_status(&thread.id, PlotThreadStatus::Abandoned).unwrap();
+
+        let sessions: Vec<_> = (2..8).map(|n| session(n, "Nothing of note")).collect();
+        assert!(manager.analyze("campaign-1", &sessions, 3).dangling.is_empty());
+    }
+}
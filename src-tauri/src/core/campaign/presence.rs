@@ -0,0 +1,601 @@
+//! NPC Presence Module
+//!
+//! Resolves where NPCs are at a given in-game moment. Recurring schedule
+//! entries (see [`super::calendar`]) give each NPC's routine; presence
+//! overrides recorded by travel, world events, or the GM take precedence
+//! while they last. Answers questions like "where is the smith this evening"
+//! and "who is at the Rusty Flagon tonight".
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::calendar::{minute_of_day, CalendarDefinition, CalendarError, NpcScheduleEntry, Recurrence, TimeWindow};
+use super::world_state::{InGameDate, InGameTime, WorldEvent};
+
+/// World event metadata key holding the date an event keeps its NPCs at its
+/// location until (default: the end of the event's day)
+pub const PRESENCE_UNTIL_FIELD: &str = "presence_until";
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// Step used when scanning a span of time for who is present
+const SLOT_MINUTES: i64 = 15;
+
+/// Time of day assumed when a date has none
+const DEFAULT_MINUTE: u16 = 12 * 60;
+
+/// An override with the absolute minutes it covers
+type SpannedOverride = (PresenceOverride, (i64, Option<i64>));
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum PresenceError {
+    #[error("Presence override not found: {0}")]
+    OverrideNotFound(String),
+
+    #[error("Invalid presence: {0}")]
+    InvalidPresence(String),
+
+    #[error("Calendar error: {0}")]
+    Calendar(#[from] CalendarError),
+}
+
+pub type Result<T> = std::result::Result<T, PresenceError>;
+
+// ============================================================================
+// Presence Overrides
+// ============================================================================
+
+/// What put an NPC where they are
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceSource {
+    Schedule,
+    Travel,
+    Event,
+    Manual,
+}
+
+impl PresenceSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Schedule => "schedule",
+            Self::Travel => "travel",
+            Self::Event => "event",
+            Self::Manual => "manual",
+        }
+    }
+}
+
+/// Places an NPC somewhere (or nowhere known, with no location) for a span
+/// of time, regardless of their schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceOverride {
+    pub id: String,
+    pub campaign_id: String,
+    pub npc_id: String,
+    pub location_id: Option<String>,
+    pub activity: String,
+    pub source: PresenceSource,
+    /// Journey or world event that recorded the override
+    #[serde(default)]
+    pub source_id: Option<String>,
+    /// Start of the override; a date without a time starts at midnight
+    pub starts_on: InGameDate,
+    /// Last day of the override, or the moment it ends when the date has a
+    /// time; `None` lasts until something replaces it
+    #[serde(default)]
+    pub ends_on: Option<InGameDate>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PresenceOverride {
+    pub fn new(
+        campaign_id: &str,
+        npc_id: &str,
+        location_id: Option<&str>,
+        source: PresenceSource,
+        starts_on: InGameDate,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            npc_id: npc_id.to_string(),
+            location_id: location_id.map(str::to_string),
+            activity: String::new(),
+            source,
+            source_id: None,
+            starts_on,
+            ends_on: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn with_activity(mut self, activity: &str) -> Self {
+        self.activity = activity.to_string();
+        self
+    }
+
+    pub fn until(mut self, ends_on: InGameDate) -> Self {
+        self.ends_on = Some(ends_on);
+        self
+    }
+
+    pub fn from_source(mut self, source_id: &str) -> Self {
+        self.source_id = Some(source_id.to_string());
+        self
+    }
+
+    /// Absolute `[start, end)` minutes the override covers
+    fn span(&self, calendar: &CalendarDefinition) -> Result<(i64, Option<i64>)> {
+        let start = calendar.day_number(&self.starts_on)? * MINUTES_PER_DAY
+            + minute_of_day(&self.starts_on).unwrap_or(0) as i64;
+        let end = match &self.ends_on {
+            Some(end) => Some(
+                calendar.day_number(end)? * MINUTES_PER_DAY
+                    + minute_of_day(end).map(i64::from).unwrap_or(MINUTES_PER_DAY),
+            ),
+            None => None,
+        };
+        Ok((start, end))
+    }
+
+    pub fn validate(&self, calendar: &CalendarDefinition) -> Result<()> {
+        let (start, end) = self.span(calendar)?;
+        if end.is_some_and(|end| end <= start) {
+            return Err(PresenceError::InvalidPresence("override ends before it starts".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Overrides placing a world event's NPCs at its location. Events at more
+/// than one location, or without NPCs, place nobody.
+pub fn overrides_from_event(event: &WorldEvent) -> Vec<PresenceOverride> {
+    let [location_id] = event.location_ids.as_slice() else {
+        return Vec::new();
+    };
+    let until = event
+        .metadata
+        .get(PRESENCE_UNTIL_FIELD)
+        .and_then(|v| serde_json::from_value::<InGameDate>(v.clone()).ok())
+        .unwrap_or_else(|| InGameDate { time: None, ..event.in_game_date.clone() });
+
+    event
+        .npc_ids
+        .iter()
+        .map(|npc_id| {
+            PresenceOverride::new(&event.campaign_id, npc_id, Some(location_id), PresenceSource::Event, event.in_game_date.clone())
+                .with_activity(&event.title)
+                .until(until.clone())
+                .from_source(&event.id)
+        })
+        .collect()
+}
+
+/// Overrides for NPCs travelling with the party: on the road from
+/// `departed` until `arrived`, then at the destination until something else
+/// moves them.
+pub fn travel_overrides(
+    campaign_id: &str,
+    npc_ids: &[String],
+    to_location_id: &str,
+    to_location_name: &str,
+    departed: &InGameDate,
+    arrived: &InGameDate,
+) -> Vec<PresenceOverride> {
+    let journey_id = Uuid::new_v4().to_string();
+    npc_ids
+        .iter()
+        .flat_map(|npc_id| {
+            [
+                PresenceOverride::new(campaign_id, npc_id, None, PresenceSource::Travel, departed.clone())
+                    .with_activity(&format!("Travelling to {}", to_location_name))
+                    .until(arrived.clone())
+                    .from_source(&journey_id),
+                PresenceOverride::new(campaign_id, npc_id, Some(to_location_id), PresenceSource::Travel, arrived.clone())
+                    .with_activity("Travelling with the party")
+                    .from_source(&journey_id),
+            ]
+        })
+        .collect()
+}
+
+// ============================================================================
+// Resolved Presence
+// ============================================================================
+
+/// Where an NPC is, and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcPresence {
+    pub npc_id: String,
+    /// `None` when the NPC is away somewhere untracked
+    pub location_id: Option<String>,
+    pub activity: String,
+    pub source: PresenceSource,
+    /// Schedule entry or override that placed the NPC
+    pub source_id: String,
+    /// When the NPC arrives, if known
+    pub from: Option<InGameDate>,
+    /// When the NPC leaves, if known
+    pub until: Option<InGameDate>,
+}
+
+/// The date and time at an absolute minute
+fn date_at(calendar: &CalendarDefinition, minute: i64, template: &InGameDate) -> InGameDate {
+    let of_day = minute.rem_euclid(MINUTES_PER_DAY);
+    let mut date = calendar.date_from_day_number(minute.div_euclid(MINUTES_PER_DAY), template);
+    date.time = Some(InGameTime {
+        hour: (of_day / 60) as u8,
+        minute: (of_day % 60) as u8,
+        period: None,
+    });
+    date
+}
+
+/// Narrower, rarer entries win over broad routines
+fn specificity(entry: &NpcScheduleEntry) -> (bool, Reverse<i64>, u8) {
+    let rank = match entry.recurrence {
+        Recurrence::Daily => 0,
+        Recurrence::EveryDays { .. } => 1,
+        Recurrence::Weekly { .. } => 2,
+        Recurrence::Monthly { .. } => 3,
+        Recurrence::Yearly { .. } => 4,
+    };
+    let minutes = entry.window.unwrap_or(TimeWindow::ALL_DAY).minutes();
+    (entry.window.is_some(), Reverse(minutes), rank)
+}
+
+/// Resolve one NPC's presence at an absolute minute
+fn resolve(
+    calendar: &CalendarDefinition,
+    overrides: &[SpannedOverride],
+    schedule: &[&NpcScheduleEntry],
+    minute: i64,
+    template: &InGameDate,
+) -> Option<NpcPresence> {
+    let active = overrides
+        .iter()
+        .filter(|(_, (start, end))| *start <= minute && end.is_none_or(|end| minute < end))
+        .max_by_key(|(o, (start, _))| (*start, o.created_at));
+    if let Some((o, _)) = active {
+        return Some(NpcPresence {
+            npc_id: o.npc_id.clone(),
+            location_id: o.location_id.clone(),
+            activity: o.activity.clone(),
+            source: o.source,
+            source_id: o.id.clone(),
+            from: Some(o.starts_on.clone()),
+            until: o.ends_on.clone(),
+        });
+    }
+
+    schedule
+        .iter()
+        .filter_map(|entry| entry.span_at(calendar, minute).map(|span| (entry, span)))
+        .max_by_key(|(entry, _)| specificity(entry))
+        .map(|(entry, (start, end))| NpcPresence {
+            npc_id: entry.npc_id.clone(),
+            location_id: entry.location_id.clone(),
+            activity: entry.activity.clone(),
+            source: PresenceSource::Schedule,
+            source_id: entry.id.clone(),
+            from: Some(date_at(calendar, start, template)),
+            until: Some(date_at(calendar, end, template)),
+        })
+}
+
+// ============================================================================
+// Presence Manager
+// ============================================================================
+
+/// Holds presence overrides and resolves NPC whereabouts against schedules
+#[derive(Default)]
+pub struct PresenceManager {
+    overrides: RwLock<HashMap<String, PresenceOverride>>,
+}
+
+impl PresenceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_override(&self, presence: PresenceOverride) -> PresenceOverride {
+        self.overrides
+            .write()
+            .unwrap()
+            .insert(presence.id.clone(), presence.clone());
+        presence
+    }
+
+    pub fn remove_override(&self, override_id: &str) -> Result<()> {
+        self.overrides
+            .write()
+            .unwrap()
+            .remove(override_id)
+            .map(|_| ())
+            .ok_or_else(|| PresenceError::OverrideNotFound(override_id.to_string()))
+    }
+
+    /// Overrides for a campaign, optionally for one NPC, oldest first
+    pub fn list_overrides(&self, campaign_id: &str, npc_id: Option<&str>) -> Vec<PresenceOverride> {
+        let mut overrides: Vec<PresenceOverride> = self
+            .overrides
+            .read()
+            .unwrap()
+            .values()
+            .filter(|o| o.campaign_id == campaign_id && npc_id.is_none_or(|id| o.npc_id == id))
+            .cloned()
+            .collect();
+        overrides.sort_by_key(|o| o.created_at);
+        overrides
+    }
+
+    /// Drop overrides that ended before `date`. Returns how many were removed.
+    pub fn prune_ended(&self, campaign_id: &str, calendar: &CalendarDefinition, date: &InGameDate) -> Result<usize> {
+        let now = calendar.day_number(date)? * MINUTES_PER_DAY + minute_of_day(date).unwrap_or(0) as i64;
+        let mut overrides = self.overrides.write().unwrap();
+        let before = overrides.len();
+        overrides.retain(|_, o| {
+            o.campaign_id != campaign_id || !matches!(o.span(calendar), Ok((_, Some(end))) if end <= now)
+        });
+        Ok(before - overrides.len())
+    }
+
+    pub fn delete_campaign_presence(&self, campaign_id: &str) {
+        self.overrides
+            .write()
+            .unwrap()
+            .retain(|_, o| o.campaign_id != campaign_id);
+    }
+
+    /// Overrides with their spans, grouped by NPC
+    fn overrides_by_npc(
+        &self,
+        campaign_id: &str,
+        calendar: &CalendarDefinition,
+    ) -> HashMap<String, Vec<SpannedOverride>> {
+        let mut grouped: HashMap<String, Vec<SpannedOverride>> = HashMap::new();
+        for o in self.list_overrides(campaign_id, None) {
+            if let Ok(span) = o.span(calendar) {
+                grouped.entry(o.npc_id.clone()).or_default().push((o, span));
+            }
+        }
+        grouped
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Where an NPC is at a moment. A date without a time is read as midday.
+    /// `schedule` holds the campaign's schedule entries.
+    pub fn where_is(
+        &self,
+        campaign_id: &str,
+        npc_id: &str,
+        calendar: &CalendarDefinition,
+        schedule: &[NpcScheduleEntry],
+        at: &InGameDate,
+    ) -> Result<Option<NpcPresence>> {
+        let minute = calendar.day_number(at)? * MINUTES_PER_DAY + minute_of_day(at).unwrap_or(DEFAULT_MINUTE) as i64;
+        let overrides = self.overrides_by_npc(campaign_id, calendar).remove(npc_id).unwrap_or_default();
+        let entries: Vec<&NpcScheduleEntry> = schedule.iter().filter(|e| e.npc_id == npc_id).collect();
+        Ok(resolve(calendar, &overrides, &entries, minute, at))
+    }
+
+    /// NPCs at a location at any point during `window` on `date`, with when
+    /// they are first and last there. `schedule` holds the campaign's
+    /// schedule entries.
+    pub fn who_is_at(
+        &self,
+        campaign_id: &str,
+        location_id: &str,
+        calendar: &CalendarDefinition,
+        schedule: &[NpcScheduleEntry],
+        date: &InGameDate,
+        window: TimeWindow,
+    ) -> Result<Vec<NpcPresence>> {
+        window.validate()?;
+        let (start, end) = window.span(calendar.day_number(date)?);
+        let mut overrides = self.overrides_by_npc(campaign_id, calendar);
+        let npc_ids: BTreeSet<String> = schedule
+            .iter()
+            .map(|e| e.npc_id.clone())
+            .chain(overrides.keys().cloned())
+            .collect();
+
+        let mut present = Vec::new();
+        for npc_id in npc_ids {
+            let npc_overrides = overrides.remove(&npc_id).unwrap_or_default();
+            let entries: Vec<&NpcScheduleEntry> = schedule.iter().filter(|e| e.npc_id == npc_id).collect();
+
+            let mut found: Option<(NpcPresence, i64, i64)> = None;
+            for minute in (start..end).step_by(SLOT_MINUTES as usize) {
+                let Some(presence) = resolve(calendar, &npc_overrides, &entries, minute, date) else {
+                    continue;
+                };
+                if presence.location_id.as_deref() != Some(location_id) {
+                    continue;
+                }
+                let slot_end = (minute + SLOT_MINUTES).min(end);
+                match &mut found {
+                    Some((_, _, last)) => *last = slot_end,
+                    None => found = Some((presence, minute, slot_end)),
+                }
+            }
+
+            if let Some((mut presence, first, last)) = found {
+                presence.from = Some(date_at(calendar, first, date));
+                presence.until = Some(date_at(calendar, last, date));
+                present.push(presence);
+            }
+        }
+        present.sort_by_key(|p| p.from.as_ref().and_then(|d| calendar.day_number(d).ok().zip(minute_of_day(d))));
+        Ok(present)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: &InGameDate, hour: u8) -> InGameDate {
+        InGameDate {
+            time: Some(InGameTime { hour, minute: 0, period: None }),
+            ..date.clone()
+        }
+    }
+
+    /// Harptos with a smith at the forge daily and at the tavern on one
+    /// weekday evening, and a bard playing the tavern every night
+    fn setup() -> (CalendarDefinition, InGameDate, String, Vec<NpcScheduleEntry>) {
+        let harptos = CalendarDefinition::harptos();
+        let start = InGameDate::new(1372, 3, 1);
+        let weekday = harptos.weekday(&start).unwrap().unwrap();
+        let schedule = vec![
+            NpcScheduleEntry::new("camp-1", "npc-smith", "Working the forge", Recurrence::Daily, start.clone())
+                .at_location("loc-forge"),
+            NpcScheduleEntry::new("camp-1", "npc-smith", "Drinking", Recurrence::Weekly { weekday: weekday.clone() }, start.clone())
+                .at_location("loc-flagon")
+                .during(TimeWindow::hours(19, 23)),
+            NpcScheduleEntry::new("camp-1", "npc-bard", "Playing", Recurrence::Daily, start.clone())
+                .at_location("loc-flagon")
+                .during(TimeWindow::hours(20, 2)),
+        ];
+        (harptos, start, weekday, schedule)
+    }
+
+    #[test]
+    fn test_time_windows() {
+        assert_eq!(TimeWindow::named("Tonight"), Some(TimeWindow::hours(18, 6)));
+        assert_eq!(TimeWindow::named("all-day"), Some(TimeWindow::ALL_DAY));
+        assert!(TimeWindow::named("teatime").is_none());
+
+        let night = TimeWindow::hours(22, 6);
+        assert!(night.wraps());
+        assert_eq!(night.minutes(), 8 * 60);
+        assert_eq!(night.span(1), (MINUTES_PER_DAY + 22 * 60, 2 * MINUTES_PER_DAY + 6 * 60));
+        assert!(!TimeWindow::ALL_DAY.wraps());
+        assert_eq!(TimeWindow::ALL_DAY.minutes(), MINUTES_PER_DAY);
+        assert!(TimeWindow { start: 1440, end: 60 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_where_is_prefers_specific_entries() {
+        let (harptos, start, _, schedule) = setup();
+        let manager = PresenceManager::new();
+
+        let evening = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &at(&start, 20)).unwrap().unwrap();
+        assert_eq!(evening.location_id.as_deref(), Some("loc-flagon"));
+        assert_eq!(evening.until.unwrap().time.unwrap().hour, 23);
+
+        let midday = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &start).unwrap().unwrap();
+        assert_eq!(midday.location_id.as_deref(), Some("loc-forge"));
+
+        // The tavern night is weekly, so the next evening is spent at the forge
+        let next = harptos.add_days(&start, 1).unwrap();
+        let next_evening = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &at(&next, 20)).unwrap().unwrap();
+        assert_eq!(next_evening.location_id.as_deref(), Some("loc-forge"));
+
+        // The bard's set runs past midnight into the next day
+        let late = manager.where_is("camp-1", "npc-bard", &harptos, &schedule, &at(&next, 1)).unwrap().unwrap();
+        assert_eq!(late.location_id.as_deref(), Some("loc-flagon"));
+        assert!(manager.where_is("camp-1", "npc-bard", &harptos, &schedule, &next).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_who_is_at_location_tonight() {
+        let (harptos, start, _, schedule) = setup();
+        let manager = PresenceManager::new();
+        let tonight = TimeWindow::named("tonight").unwrap();
+
+        let present = manager.who_is_at("camp-1", "loc-flagon", &harptos, &schedule, &start, tonight).unwrap();
+        let ids: Vec<&str> = present.iter().map(|p| p.npc_id.as_str()).collect();
+        assert_eq!(ids, vec!["npc-smith", "npc-bard"]);
+        let bard_until = present[1].until.clone().unwrap();
+        assert_eq!((bard_until.day, bard_until.time.unwrap().hour), (2, 2));
+
+        let next = harptos.add_days(&start, 1).unwrap();
+        let present = manager.who_is_at("camp-1", "loc-flagon", &harptos, &schedule, &next, tonight).unwrap();
+        assert_eq!(present.len(), 1);
+        assert_eq!(present[0].npc_id, "npc-bard");
+
+        let morning = TimeWindow::named("morning").unwrap();
+        let forge = manager.who_is_at("camp-1", "loc-forge", &harptos, &schedule, &start, morning).unwrap();
+        assert_eq!(forge.len(), 1);
+        assert!(manager.who_is_at("camp-1", "loc-flagon", &harptos, &schedule, &start, morning).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_event_overrides_schedule() {
+        let (harptos, start, _, schedule) = setup();
+        let manager = PresenceManager::new();
+
+        let mut event = WorldEvent::new("camp-1", "Guild meeting", "", at(&start, 9))
+            .at_locations(vec!["loc-hall".to_string()])
+            .involving_npcs(vec!["npc-smith".to_string()]);
+        let overrides = overrides_from_event(&event);
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].source_id.as_deref(), Some(event.id.as_str()));
+        for o in overrides {
+            o.validate(&harptos).unwrap();
+            manager.add_override(o);
+        }
+
+        let before = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &at(&start, 8)).unwrap().unwrap();
+        assert_eq!(before.source, PresenceSource::Schedule);
+        let during = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &at(&start, 20)).unwrap().unwrap();
+        assert_eq!(during.location_id.as_deref(), Some("loc-hall"));
+        assert_eq!(during.activity, "Guild meeting");
+
+        // The override covers the event's day only
+        let next = harptos.add_days(&start, 1).unwrap();
+        let after = manager.where_is("camp-1", "npc-smith", &harptos, &schedule, &next).unwrap().unwrap();
+        assert_eq!(after.location_id.as_deref(), Some("loc-forge"));
+        assert_eq!(manager.prune_ended("camp-1", &harptos, &next).unwrap(), 1);
+
+        event.location_ids.push("loc-gate".to_string());
+        assert!(overrides_from_event(&event).is_empty());
+    }
+
+    #[test]
+    fn test_travel_overrides() {
+        let (harptos, start, _, schedule) = setup();
+        let manager = PresenceManager::new();
+        let arrived = harptos.add_minutes(&at(&start, 8), 30 * 60).unwrap();
+
+        for o in travel_overrides("camp-1", &["npc-bard".to_string()], "loc-keep", "the Keep", &at(&start, 8), &arrived) {
+            manager.add_override(o);
+        }
+        assert_eq!(manager.list_overrides("camp-1", Some("npc-bard")).len(), 2);
+
+        let on_road = manager.where_is("camp-1", "npc-bard", &harptos, &schedule, &at(&start, 21)).unwrap().unwrap();
+        assert_eq!(on_road.source, PresenceSource::Travel);
+        assert!(on_road.location_id.is_none());
+        assert_eq!(on_road.activity, "Travelling to the Keep");
+
+        let later = harptos.add_days(&start, 5).unwrap();
+        let there = manager.where_is("camp-1", "npc-bard", &harptos, &schedule, &at(&later, 21)).unwrap().unwrap();
+        assert_eq!(there.location_id.as_deref(), Some("loc-keep"));
+
+        // The companion no longer plays the tavern while away
+        let tonight = TimeWindow::named("tonight").unwrap();
+        assert!(manager.who_is_at("camp-1", "loc-flagon", &harptos, &schedule, &later, tonight).unwrap().is_empty());
+
+        manager.delete_campaign_presence("camp-1");
+        assert!(manager.list_overrides("camp-1", None).is_empty());
+    }
+}
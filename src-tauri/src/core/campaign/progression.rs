@@ -0,0 +1,506 @@
+//! Character Progression Module
+//!
+//! Experience and milestone advancement for the party roster. Campaigns
+//! level either by XP against their game system's table or by milestone
+//! checkmarks, optionally tied to achieved arc milestones. Every award is
+//! logged against its session, and characters who have earned a level are
+//! flagged so the roster can level them up.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::party::{PlayerCharacter, MAX_LEVEL};
+use crate::core::character_gen::GameSystem;
+
+/// Cumulative XP needed to reach each level in D&D 5e, starting at level 1
+const DND5E_XP_THRESHOLDS: [u32; 20] = [
+    0, 300, 900, 2_700, 6_500, 14_000, 23_000, 34_000, 48_000, 64_000, 85_000, 100_000, 120_000, 140_000, 165_000,
+    195_000, 225_000, 265_000, 305_000, 355_000,
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ProgressionError {
+    #[error("Award must grant experience or at least one checkmark")]
+    EmptyAward,
+
+    #[error("Checkmarks per level must be at least 1")]
+    InvalidCheckmarks,
+
+    #[error("XP thresholds must start at 0 and increase")]
+    InvalidThresholds,
+}
+
+pub type Result<T> = std::result::Result<T, ProgressionError>;
+
+// ============================================================================
+// Progression Settings
+// ============================================================================
+
+/// How characters advance
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressionMode {
+    #[default]
+    Experience,
+    Milestone,
+}
+
+impl ProgressionMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "experience" | "xp" => Some(Self::Experience),
+            "milestone" | "milestones" => Some(Self::Milestone),
+            _ => None,
+        }
+    }
+}
+
+/// A campaign's advancement rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressionConfig {
+    pub mode: ProgressionMode,
+    /// Cumulative XP needed for each level, starting with level 1 at 0
+    pub xp_thresholds: Vec<u32>,
+    /// Milestone checkmarks needed per level
+    pub checkmarks_per_level: u32,
+}
+
+impl ProgressionConfig {
+    /// XP table for a game system name, as accepted by [`GameSystem::from_str`].
+    /// Systems without an XP table level every 1,000 XP, as in Pathfinder 2e.
+    pub fn for_system(system: &str) -> Self {
+        let xp_thresholds = match GameSystem::from_str(system) {
+            GameSystem::DnD5e => DND5E_XP_THRESHOLDS.to_vec(),
+            _ => (0..u32::from(MAX_LEVEL)).map(|level| level * 1_000).collect(),
+        };
+        Self {
+            mode: ProgressionMode::Experience,
+            xp_thresholds,
+            checkmarks_per_level: 1,
+        }
+    }
+
+    /// Level a character with this much XP has earned
+    pub fn level_for_xp(&self, experience: u32) -> u8 {
+        let reached = self.xp_thresholds.iter().take_while(|&&t| experience >= t).count();
+        (reached as u8).clamp(1, MAX_LEVEL)
+    }
+
+    /// XP needed to reach the level after `level`; `None` at the cap
+    pub fn xp_for_next_level(&self, level: u8) -> Option<u32> {
+        if level >= MAX_LEVEL {
+            return None;
+        }
+        self.xp_thresholds.get(level as usize).copied()
+    }
+}
+
+// ============================================================================
+// Award Types
+// ============================================================================
+
+/// What an award grants
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AwardKind {
+    Experience { amount: u32 },
+    Milestone {
+        /// Arc milestone the checkmarks were earned for
+        milestone_id: Option<String>,
+        checkmarks: u32,
+    },
+}
+
+/// One award to one character
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressionAward {
+    pub id: String,
+    pub campaign_id: String,
+    pub character_id: String,
+    pub kind: AwardKind,
+    #[serde(default)]
+    pub reason: String,
+    pub session_id: Option<String>,
+    pub awarded_at: DateTime<Utc>,
+}
+
+impl ProgressionAward {
+    pub fn new(campaign_id: &str, character_id: &str, kind: AwardKind, reason: &str) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            character_id: character_id.to_string(),
+            kind,
+            reason: reason.to_string(),
+            session_id: None,
+            awarded_at: Utc::now(),
+        }
+    }
+
+    /// Builder: link to a session
+    pub fn in_session(mut self, session_id: &str) -> Self {
+        self.session_id = Some(session_id.to_string());
+        self
+    }
+
+    fn experience(&self) -> u32 {
+        match self.kind {
+            AwardKind::Experience { amount } => amount,
+            AwardKind::Milestone { .. } => 0,
+        }
+    }
+
+    fn checkmarks(&self) -> u32 {
+        match self.kind {
+            AwardKind::Milestone { checkmarks, .. } => checkmarks,
+            AwardKind::Experience { .. } => 0,
+        }
+    }
+}
+
+/// A character who has earned more levels than they have
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingLevelUp {
+    pub character_id: String,
+    pub name: String,
+    pub current_level: u8,
+    pub earned_level: u8,
+}
+
+/// Where a character stands
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterProgression {
+    pub character_id: String,
+    pub name: String,
+    pub level: u8,
+    pub experience: u32,
+    /// XP total needed for the next level
+    pub next_level_at: Option<u32>,
+    pub xp_to_next_level: Option<u32>,
+    /// Unspent milestone checkmarks
+    pub checkmarks: u32,
+    pub earned_level: u8,
+    pub level_up_pending: bool,
+    pub awards: usize,
+}
+
+/// Awards given during one session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionAwards {
+    /// `None` for awards not tied to a session
+    pub session_id: Option<String>,
+    pub total_experience: u32,
+    pub total_checkmarks: u32,
+    pub awards: Vec<ProgressionAward>,
+}
+
+/// A campaign's progression at a glance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressionReport {
+    pub campaign_id: String,
+    pub config: ProgressionConfig,
+    pub characters: Vec<CharacterProgression>,
+    pub sessions: Vec<SessionAwards>,
+}
+
+// ============================================================================
+// Progression Manager
+// ============================================================================
+
+pub struct ProgressionManager {
+    /// Campaign ID -> advancement rules
+    configs: RwLock<HashMap<String, ProgressionConfig>>,
+    awards: RwLock<Vec<ProgressionAward>>,
+    /// Character ID -> unspent checkmarks
+    checkmarks: RwLock<HashMap<String, u32>>,
+}
+
+impl Default for ProgressionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressionManager {
+    pub fn new() -> Self {
+        Self {
+            configs: RwLock::new(HashMap::new()),
+            awards: RwLock::new(Vec::new()),
+            checkmarks: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// A campaign's advancement rules, defaulting to its game system's XP table
+    pub fn config_for(&self, campaign_id: &str, system: &str) -> ProgressionConfig {
+        self.configs
+            .write()
+            .unwrap()
+            .entry(campaign_id.to_string())
+            .or_insert_with(|| ProgressionConfig::for_system(system))
+            .clone()
+    }
+
+    pub fn set_config(&self, campaign_id: &str, config: ProgressionConfig) -> Result<ProgressionConfig> {
+        if config.checkmarks_per_level == 0 {
+            return Err(ProgressionError::InvalidCheckmarks);
+        }
+        if config.xp_thresholds.first() != Some(&0) || !config.xp_thresholds.windows(2).all(|w| w[0] < w[1]) {
+            return Err(ProgressionError::InvalidThresholds);
+        }
+        self.configs
+            .write()
+            .unwrap()
+            .insert(campaign_id.to_string(), config.clone());
+        Ok(config)
+    }
+
+    /// Log an award; milestone checkmarks are banked toward the next level.
+    /// XP is added to the character on the roster by the caller.
+    pub fn record_award(&self, award: ProgressionAward) -> Result<ProgressionAward> {
+        if award.experience() == 0 && award.checkmarks() == 0 {
+            return Err(ProgressionError::EmptyAward);
+        }
+        if award.checkmarks() > 0 {
+            *self
+                .checkmarks
+                .write()
+                .unwrap()
+                .entry(award.character_id.clone())
+                .or_insert(0) += award.checkmarks();
+        }
+        self.awards.write().unwrap().push(award.clone());
+        Ok(award)
+    }
+
+    pub fn checkmarks(&self, character_id: &str) -> u32 {
+        self.checkmarks.read().unwrap().get(character_id).copied().unwrap_or(0)
+    }
+
+    /// Spend the checkmarks for levels a character has just gained
+    pub fn spend_checkmarks(&self, character_id: &str, levels: u8, config: &ProgressionConfig) {
+        if let Some(banked) = self.checkmarks.write().unwrap().get_mut(character_id) {
+            *banked = banked.saturating_sub(u32::from(levels) * config.checkmarks_per_level);
+        }
+    }
+
+    /// Level a character has earned under the campaign's rules
+    pub fn earned_level(&self, character: &PlayerCharacter, config: &ProgressionConfig) -> u8 {
+        match config.mode {
+            ProgressionMode::Experience => config.level_for_xp(character.experience).max(character.level),
+            ProgressionMode::Milestone => {
+                let gained = self.checkmarks(&character.id) / config.checkmarks_per_level;
+                (u32::from(character.level) + gained).min(u32::from(MAX_LEVEL)) as u8
+            }
+        }
+    }
+
+    /// Characters who have earned a level they haven't taken yet
+    pub fn pending_level_ups(&self, characters: &[PlayerCharacter], config: &ProgressionConfig) -> Vec<PendingLevelUp> {
+        characters
+            .iter()
+            .filter_map(|character| {
+                let earned_level = self.earned_level(character, config);
+                (earned_level > character.level).then(|| PendingLevelUp {
+                    character_id: character.id.clone(),
+                    name: character.name.clone(),
+                    current_level: character.level,
+                    earned_level,
+                })
+            })
+            .collect()
+    }
+
+    /// A campaign's awards, oldest first, optionally for one character or session
+    pub fn list_awards(&self, campaign_id: &str, character_id: Option<&str>, session_id: Option<&str>) -> Vec<ProgressionAward> {
+        self.awards
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|a| a.campaign_id == campaign_id)
+            .filter(|a| character_id.is_none_or(|c| a.character_id == c))
+            .filter(|a| session_id.is_none_or(|s| a.session_id.as_deref() == Some(s)))
+            .cloned()
+            .collect()
+    }
+
+    /// Progression for each character, plus awards grouped by session
+    pub fn report(&self, campaign_id: &str, characters: &[PlayerCharacter], config: &ProgressionConfig) -> ProgressionReport {
+        let awards = self.list_awards(campaign_id, None, None);
+        let characters = characters
+            .iter()
+            .map(|character| {
+                let next_level_at = match config.mode {
+                    ProgressionMode::Experience => config.xp_for_next_level(character.level),
+                    ProgressionMode::Milestone => None,
+                };
+                let earned_level = self.earned_level(character, config);
+                CharacterProgression {
+                    character_id: character.id.clone(),
+                    name: character.name.clone(),
+                    level: character.level,
+                    experience: character.experience,
+                    next_level_at,
+                    xp_to_next_level: next_level_at.map(|at| at.saturating_sub(character.experience)),
+                    checkmarks: self.checkmarks(&character.id),
+                    earned_level,
+                    level_up_pending: earned_level > character.level,
+                    awards: awards.iter().filter(|a| a.character_id == character.id).count(),
+                }
+            })
+            .collect();
+
+        let mut by_session: BTreeMap<Option<String>, SessionAwards> = BTreeMap::new();
+        for award in awards {
+            let session = by_session.entry(award.session_id.clone()).or_insert_with(|| SessionAwards {
+                session_id: award.session_id.clone(),
+                total_experience: 0,
+                total_checkmarks: 0,
+                awards: Vec::new(),
+            });
+            session.total_experience += award.experience();
+            session.total_checkmarks += award.checkmarks();
+            session.awards.push(award);
+        }
+        let mut sessions: Vec<SessionAwards> = by_session.into_values().collect();
+        sessions.sort_by_key(|s| s.awards.first().map(|a| a.awarded_at));
+
+        ProgressionReport {
+            campaign_id: campaign_id.to_string(),
+            config: config.clone(),
+            characters,
+            sessions,
+        }
+    }
+
+    /// Remove a campaign's settings and awards
+    pub fn delete_campaign_progression(&self, campaign_id: &str) {
+        self.configs.write().unwrap().remove(campaign_id);
+        let mut awards = self.awards.write().unwrap();
+        let mut checkmarks = self.checkmarks.write().unwrap();
+        for award in awards.iter().filter(|a| a.campaign_id == campaign_id) {
+            checkmarks.remove(&award.character_id);
+        }
+        awards.retain(|a| a.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fighter(level: u8, experience: u32) -> PlayerCharacter {
+        let mut character = PlayerCharacter::new("camp-1", "Brom", "Fighter", level);
+        character.experience = experience;
+        character
+    }
+
+    #[test]
+    fn test_xp_tables_by_system() {
+        let dnd = ProgressionConfig::for_system("dnd5e");
+        assert_eq!(dnd.level_for_xp(0), 1);
+        assert_eq!(dnd.level_for_xp(299), 1);
+        assert_eq!(dnd.level_for_xp(300), 2);
+        assert_eq!(dnd.level_for_xp(7_000), 5);
+        assert_eq!(dnd.level_for_xp(1_000_000), 20);
+        assert_eq!(dnd.xp_for_next_level(4), Some(6_500));
+        assert_eq!(dnd.xp_for_next_level(20), None);
+
+        let pf2e = ProgressionConfig::for_system("pf2e");
+        assert_eq!(pf2e.level_for_xp(2_500), 3);
+    }
+
+    #[test]
+    fn test_xp_detects_pending_level_up() {
+        let manager = ProgressionManager::new();
+        let config = ProgressionConfig::for_system("dnd5e");
+        let ready = fighter(2, 2_800);
+        let not_ready = fighter(4, 2_800);
+
+        let pending = manager.pending_level_ups(&[ready.clone(), not_ready], &config);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].character_id, ready.id);
+        assert_eq!(pending[0].earned_level, 4);
+    }
+
+    #[test]
+    fn test_milestone_checkmarks_bank_and_spend() {
+        let manager = ProgressionManager::new();
+        let config = ProgressionConfig {
+            mode: ProgressionMode::Milestone,
+            checkmarks_per_level: 2,
+            ..ProgressionConfig::for_system("dnd5e")
+        };
+        let character = fighter(3, 0);
+        let checkmark = |milestone: &str| AwardKind::Milestone {
+            milestone_id: Some(milestone.to_string()),
+            checkmarks: 1,
+        };
+
+        manager
+            .record_award(ProgressionAward::new("camp-1", &character.id, checkmark("m-1"), "Found the map"))
+            .unwrap();
+        assert_eq!(manager.earned_level(&character, &config), 3);
+        manager
+            .record_award(ProgressionAward::new("camp-1", &character.id, checkmark("m-2"), "Slew the wyrm"))
+            .unwrap();
+        assert_eq!(manager.earned_level(&character, &config), 4);
+
+        manager.spend_checkmarks(&character.id, 1, &config);
+        assert_eq!(manager.checkmarks(&character.id), 0);
+    }
+
+    #[test]
+    fn test_report_groups_awards_by_session() {
+        let manager = ProgressionManager::new();
+        let config = ProgressionConfig::for_system("dnd5e");
+        let character = fighter(1, 450);
+        for (amount, session) in [(300, "session-1"), (150, "session-1"), (100, "session-2")] {
+            manager
+                .record_award(
+                    ProgressionAward::new("camp-1", &character.id, AwardKind::Experience { amount }, "Combat")
+                        .in_session(session),
+                )
+                .unwrap();
+        }
+
+        let report = manager.report("camp-1", std::slice::from_ref(&character), &config);
+        assert_eq!(report.sessions.len(), 2);
+        assert_eq!(report.sessions[0].session_id.as_deref(), Some("session-1"));
+        assert_eq!(report.sessions[0].total_experience, 450);
+        let progression = &report.characters[0];
+        assert_eq!(progression.awards, 3);
+        assert_eq!(progression.next_level_at, Some(300));
+        assert!(progression.level_up_pending);
+    }
+
+    #[test]
+    fn test_empty_awards_and_configs_rejected() {
+        let manager = ProgressionManager::new();
+        let empty = ProgressionAward::new("camp-1", "pc-1", AwardKind::Experience { amount: 0 }, "Nothing");
+        assert!(matches!(manager.record_award(empty), Err(ProgressionError::EmptyAward)));
+
+        let config = ProgressionConfig {
+            checkmarks_per_level: 0,
+            ..ProgressionConfig::for_system("dnd5e")
+        };
+        assert!(manager.set_config("camp-1", config).is_err());
+        let config = ProgressionConfig {
+            xp_thresholds: vec![0, 500, 400],
+            ..ProgressionConfig::for_system("dnd5e")
+        };
+        assert!(matches!(manager.set_config("camp-1", config), Err(ProgressionError::InvalidThresholds)));
+        assert_eq!(manager.config_for("camp-1", "pf2e").xp_thresholds[1], 1_000);
+    }
+}
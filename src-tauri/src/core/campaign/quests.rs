@@ -0,0 +1,704 @@
+//! Quest Tracking Module
+//!
+//! Quests with objectives, rewards, a quest-giver NPC, and links to campaign
+//! arcs and milestones. Timeline events that mention a quest's keywords
+//! produce status suggestions for the GM to accept or ignore.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::session::timeline::TimelineEvent;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum QuestError {
+    #[error("Quest not found: {0}")]
+    QuestNotFound(String),
+
+    #[error("Objective not found: {0}")]
+    ObjectiveNotFound(String),
+
+    #[error("Quest title cannot be empty")]
+    EmptyTitle,
+}
+
+pub type Result<T> = std::result::Result<T, QuestError>;
+
+// ============================================================================
+// Status Types
+// ============================================================================
+
+/// Where a quest stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestStatus {
+    /// Known to the GM but not yet offered to the party
+    #[default]
+    Available,
+    /// Accepted and in progress
+    Active,
+    /// Finished successfully
+    Completed,
+    /// Can no longer be finished
+    Failed,
+    /// Dropped by the party
+    Abandoned,
+}
+
+impl QuestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Available => "available",
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Abandoned => "abandoned",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "available" => Some(Self::Available),
+            "active" => Some(Self::Active),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            "abandoned" => Some(Self::Abandoned),
+            _ => None,
+        }
+    }
+
+    /// Completed, failed, or abandoned
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Abandoned)
+    }
+}
+
+/// Where a single objective stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectiveStatus {
+    #[default]
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl ObjectiveStatus {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "pending" => Some(Self::Pending),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Quest Types
+// ============================================================================
+
+/// A step toward finishing a quest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestObjective {
+    pub id: String,
+    pub description: String,
+    pub status: ObjectiveStatus,
+    /// Optional objectives don't block quest completion
+    #[serde(default)]
+    pub optional: bool,
+    /// Words in timeline events that point at this objective
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl QuestObjective {
+    pub fn new(description: &str) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            description: description.to_string(),
+            status: ObjectiveStatus::Pending,
+            optional: false,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Builder: mark as optional
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Builder: set keywords
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+}
+
+/// Kind of quest reward
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RewardKind {
+    Experience,
+    Currency,
+    Item,
+    Reputation,
+    Other,
+}
+
+/// Something the party earns by finishing a quest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestReward {
+    pub kind: RewardKind,
+    pub description: String,
+    /// XP, coins, or reputation points, where that applies
+    #[serde(default)]
+    pub amount: Option<i64>,
+}
+
+/// A campaign quest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quest {
+    pub id: String,
+    pub campaign_id: String,
+    pub title: String,
+    pub description: String,
+    pub status: QuestStatus,
+    /// NPC who gave the quest
+    pub giver_npc_id: Option<String>,
+    pub objectives: Vec<QuestObjective>,
+    pub rewards: Vec<QuestReward>,
+    /// Campaign arc this quest belongs to
+    pub arc_id: Option<String>,
+    /// Milestones this quest advances
+    pub milestone_ids: Vec<String>,
+    /// Words in timeline events that point at this quest
+    pub keywords: Vec<String>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// When the quest was completed, failed, or abandoned
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl Quest {
+    pub fn new(campaign_id: &str, title: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            status: QuestStatus::Available,
+            giver_npc_id: None,
+            objectives: Vec::new(),
+            rewards: Vec::new(),
+            arc_id: None,
+            milestone_ids: Vec::new(),
+            keywords: Vec::new(),
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            resolved_at: None,
+        }
+    }
+
+    /// Builder: set description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Builder: set quest giver
+    pub fn with_giver(mut self, npc_id: &str) -> Self {
+        self.giver_npc_id = Some(npc_id.to_string());
+        self
+    }
+
+    /// Builder: add an objective
+    pub fn with_objective(mut self, objective: QuestObjective) -> Self {
+        self.objectives.push(objective);
+        self
+    }
+
+    /// Builder: set keywords
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Whether every required objective is complete
+    pub fn required_objectives_complete(&self) -> bool {
+        self.objectives
+            .iter()
+            .filter(|o| !o.optional)
+            .all(|o| o.status == ObjectiveStatus::Completed)
+    }
+
+    /// Completed objectives over total objectives
+    pub fn progress(&self) -> (usize, usize) {
+        let done = self
+            .objectives
+            .iter()
+            .filter(|o| o.status == ObjectiveStatus::Completed)
+            .count();
+        (done, self.objectives.len())
+    }
+
+    fn set_status(&mut self, status: QuestStatus) {
+        self.status = status;
+        self.resolved_at = status.is_resolved().then(Utc::now);
+        self.updated_at = Utc::now();
+    }
+
+    /// Quest keywords, falling back to the title when none are set
+    fn match_terms(&self) -> Vec<String> {
+        if self.keywords.is_empty() {
+            vec![self.title.to_lowercase()]
+        } else {
+            self.keywords.iter().map(|k| k.to_lowercase()).collect()
+        }
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        self.title.to_lowercase().contains(query)
+            || self.description.to_lowercase().contains(query)
+            || self.keywords.iter().chain(&self.tags).any(|k| k.to_lowercase().contains(query))
+            || self.objectives.iter().any(|o| o.description.to_lowercase().contains(query))
+    }
+}
+
+// ============================================================================
+// Status Suggestions
+// ============================================================================
+
+/// Words in an event that suggest something was finished
+const SUCCESS_CUES: &[&str] = &[
+    "completed", "complete", "finished", "defeated", "rescued", "recovered",
+    "delivered", "returned", "found", "retrieved", "slain", "solved",
+];
+
+/// Words in an event that suggest something went wrong for good
+const FAILURE_CUES: &[&str] = &[
+    "failed", "lost", "destroyed", "died", "killed", "betrayed", "too late",
+];
+
+/// Suggested change to a quest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuestSuggestionKind {
+    /// The party has picked up an available quest
+    StartQuest,
+    /// An objective looks done
+    CompleteObjective { objective_id: String },
+    /// All required objectives look done
+    CompleteQuest,
+    /// The quest looks failed
+    FailQuest,
+}
+
+/// A status change suggested by a timeline event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestStatusSuggestion {
+    pub quest_id: String,
+    pub quest_title: String,
+    pub kind: QuestSuggestionKind,
+    /// Timeline event that triggered the suggestion
+    pub event_id: String,
+    /// Keyword that matched
+    pub matched_keyword: String,
+}
+
+fn event_text(event: &TimelineEvent) -> String {
+    format!("{} {} {}", event.title, event.description, event.tags.join(" ")).to_lowercase()
+}
+
+fn find_term<'a>(text: &str, terms: &'a [String]) -> Option<&'a String> {
+    terms.iter().find(|t| !t.is_empty() && text.contains(t.as_str()))
+}
+
+/// Whether any cue appears as whole words in the text
+fn has_cue(text: &str, cues: &[&str]) -> bool {
+    let words: Vec<&str> = text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    cues.iter().any(|cue| {
+        let cue_words: Vec<&str> = cue.split(' ').collect();
+        words.windows(cue_words.len()).any(|w| w == cue_words.as_slice())
+    })
+}
+
+/// Suggest status changes for one quest from one timeline event
+fn suggest_for_event(quest: &Quest, event: &TimelineEvent) -> Vec<QuestStatusSuggestion> {
+    let text = event_text(event);
+    let suggestion = |kind, keyword: &str| QuestStatusSuggestion {
+        quest_id: quest.id.clone(),
+        quest_title: quest.title.clone(),
+        kind,
+        event_id: event.id.clone(),
+        matched_keyword: keyword.to_string(),
+    };
+
+    let quest_term = find_term(&text, &quest.match_terms()).cloned();
+    let succeeded = has_cue(&text, SUCCESS_CUES);
+    let mut suggestions = Vec::new();
+
+    // Objectives can match on their own keywords even when the quest's don't
+    let mut newly_completed = Vec::new();
+    if succeeded {
+        for objective in quest.objectives.iter().filter(|o| o.status == ObjectiveStatus::Pending) {
+            let terms: Vec<String> = objective.keywords.iter().map(|k| k.to_lowercase()).collect();
+            if let Some(term) = find_term(&text, &terms) {
+                newly_completed.push(objective.id.clone());
+                suggestions.push(suggestion(
+                    QuestSuggestionKind::CompleteObjective { objective_id: objective.id.clone() },
+                    term,
+                ));
+            }
+        }
+    }
+
+    let keyword = match quest_term.or_else(|| suggestions.first().map(|s| s.matched_keyword.clone())) {
+        Some(keyword) => keyword,
+        None => return suggestions,
+    };
+
+    if quest.status == QuestStatus::Available {
+        suggestions.insert(0, suggestion(QuestSuggestionKind::StartQuest, &keyword));
+    }
+
+    if has_cue(&text, FAILURE_CUES) {
+        suggestions.push(suggestion(QuestSuggestionKind::FailQuest, &keyword));
+    } else if succeeded {
+        let all_done = quest
+            .objectives
+            .iter()
+            .filter(|o| !o.optional)
+            .all(|o| o.status == ObjectiveStatus::Completed || newly_completed.contains(&o.id));
+        if all_done {
+            suggestions.push(suggestion(QuestSuggestionKind::CompleteQuest, &keyword));
+        }
+    }
+
+    suggestions
+}
+
+// ============================================================================
+// Quest Manager
+// ============================================================================
+
+pub struct QuestManager {
+    quests: RwLock<HashMap<String, Quest>>,
+}
+
+impl Default for QuestManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuestManager {
+    pub fn new() -> Self {
+        Self {
+            quests: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn create_quest(&self, quest: Quest) -> Result<Quest> {
+        if quest.title.trim().is_empty() {
+            return Err(QuestError::EmptyTitle);
+        }
+        self.quests.write().unwrap().insert(quest.id.clone(), quest.clone());
+        Ok(quest)
+    }
+
+    pub fn get_quest(&self, quest_id: &str) -> Option<Quest> {
+        self.quests.read().unwrap().get(quest_id).cloned()
+    }
+
+    pub fn update_quest(&self, mut quest: Quest) -> Result<Quest> {
+        if quest.title.trim().is_empty() {
+            return Err(QuestError::EmptyTitle);
+        }
+        let mut quests = self.quests.write().unwrap();
+        let existing = quests
+            .get(&quest.id)
+            .ok_or_else(|| QuestError::QuestNotFound(quest.id.clone()))?;
+        if existing.status != quest.status {
+            let status = quest.status;
+            quest.set_status(status);
+        }
+        quest.created_at = existing.created_at;
+        quest.updated_at = Utc::now();
+        quests.insert(quest.id.clone(), quest.clone());
+        Ok(quest)
+    }
+
+    pub fn delete_quest(&self, quest_id: &str) -> Result<()> {
+        self.quests
+            .write()
+            .unwrap()
+            .remove(quest_id)
+            .map(|_| ())
+            .ok_or_else(|| QuestError::QuestNotFound(quest_id.to_string()))
+    }
+
+    /// List a campaign's quests, optionally only those with one status
+    pub fn list_quests(&self, campaign_id: &str, status: Option<QuestStatus>) -> Vec<Quest> {
+        let mut quests: Vec<Quest> = self
+            .quests
+            .read()
+            .unwrap()
+            .values()
+            .filter(|q| q.campaign_id == campaign_id)
+            .filter(|q| status.is_none_or(|s| q.status == s))
+            .cloned()
+            .collect();
+        quests.sort_by_key(|q| q.created_at);
+        quests
+    }
+
+    /// Search titles, descriptions, objectives, keywords, and tags
+    pub fn search_quests(&self, campaign_id: &str, query: &str) -> Vec<Quest> {
+        let query = query.trim().to_lowercase();
+        self.list_quests(campaign_id, None)
+            .into_iter()
+            .filter(|q| query.is_empty() || q.matches(&query))
+            .collect()
+    }
+
+    pub fn get_quests_for_arc(&self, campaign_id: &str, arc_id: &str) -> Vec<Quest> {
+        self.list_quests(campaign_id, None)
+            .into_iter()
+            .filter(|q| q.arc_id.as_deref() == Some(arc_id))
+            .collect()
+    }
+
+    pub fn get_quests_for_milestone(&self, campaign_id: &str, milestone_id: &str) -> Vec<Quest> {
+        self.list_quests(campaign_id, None)
+            .into_iter()
+            .filter(|q| q.milestone_ids.iter().any(|m| m == milestone_id))
+            .collect()
+    }
+
+    pub fn get_quests_from_giver(&self, campaign_id: &str, npc_id: &str) -> Vec<Quest> {
+        self.list_quests(campaign_id, None)
+            .into_iter()
+            .filter(|q| q.giver_npc_id.as_deref() == Some(npc_id))
+            .collect()
+    }
+
+    pub fn set_quest_status(&self, quest_id: &str, status: QuestStatus) -> Result<Quest> {
+        self.with_quest_mut(quest_id, |quest| {
+            quest.set_status(status);
+            Ok(())
+        })
+    }
+
+    pub fn add_objective(&self, quest_id: &str, objective: QuestObjective) -> Result<Quest> {
+        self.with_quest_mut(quest_id, |quest| {
+            quest.objectives.push(objective);
+            Ok(())
+        })
+    }
+
+    pub fn remove_objective(&self, quest_id: &str, objective_id: &str) -> Result<Quest> {
+        self.with_quest_mut(quest_id, |quest| {
+            let before = quest.objectives.len();
+            quest.objectives.retain(|o| o.id != objective_id);
+            if quest.objectives.len() == before {
+                return Err(QuestError::ObjectiveNotFound(objective_id.to_string()));
+            }
+            Ok(())
+        })
+    }
+
+    pub fn set_objective_status(
+        &self,
+        quest_id: &str,
+        objective_id: &str,
+        status: ObjectiveStatus,
+    ) -> Result<Quest> {
+        self.with_quest_mut(quest_id, |quest| {
+            let objective = quest
+                .objectives
+                .iter_mut()
+                .find(|o| o.id == objective_id)
+                .ok_or_else(|| QuestError::ObjectiveNotFound(objective_id.to_string()))?;
+            objective.status = status;
+            Ok(())
+        })
+    }
+
+    /// Suggest quest status changes from timeline events.
+    ///
+    /// Resolved quests are skipped. Nothing is changed; the caller decides
+    /// which suggestions to apply.
+    pub fn suggest_status_changes(&self, campaign_id: &str, events: &[TimelineEvent]) -> Vec<QuestStatusSuggestion> {
+        let quests = self.list_quests(campaign_id, None);
+        let mut suggestions: Vec<QuestStatusSuggestion> = Vec::new();
+        for event in events {
+            for quest in quests.iter().filter(|q| !q.status.is_resolved()) {
+                for suggestion in suggest_for_event(quest, event) {
+                    let duplicate = suggestions
+                        .iter()
+                        .any(|s| s.quest_id == suggestion.quest_id && s.kind == suggestion.kind);
+                    if !duplicate {
+                        suggestions.push(suggestion);
+                    }
+                }
+            }
+        }
+        suggestions
+    }
+
+    /// Apply a suggestion the GM accepted
+    pub fn apply_suggestion(&self, suggestion: &QuestStatusSuggestion) -> Result<Quest> {
+        match &suggestion.kind {
+            QuestSuggestionKind::StartQuest => self.set_quest_status(&suggestion.quest_id, QuestStatus::Active),
+            QuestSuggestionKind::CompleteObjective { objective_id } => {
+                self.set_objective_status(&suggestion.quest_id, objective_id, ObjectiveStatus::Completed)
+            }
+            QuestSuggestionKind::CompleteQuest => self.set_quest_status(&suggestion.quest_id, QuestStatus::Completed),
+            QuestSuggestionKind::FailQuest => self.set_quest_status(&suggestion.quest_id, QuestStatus::Failed),
+        }
+    }
+
+    /// Remove all quests for a campaign
+    pub fn delete_campaign_quests(&self, campaign_id: &str) {
+        self.quests.write().unwrap().retain(|_, q| q.campaign_id != campaign_id);
+    }
+
+    fn with_quest_mut(&self, quest_id: &str, f: impl FnOnce(&mut Quest) -> Result<()>) -> Result<Quest> {
+        let mut quests = self.quests.write().unwrap();
+        let quest = quests
+            .get_mut(quest_id)
+            .ok_or_else(|| QuestError::QuestNotFound(quest_id.to_string()))?;
+        f(quest)?;
+        quest.updated_at = Utc::now();
+        Ok(quest.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::timeline::TimelineEventType;
+
+    fn event(title: &str, description: &str) -> TimelineEvent {
+        TimelineEvent::new("session-1", TimelineEventType::PlayerAction, title, description)
+    }
+
+    fn sample_quest(manager: &QuestManager) -> Quest {
+        let quest = Quest::new("campaign-1", "The Missing Heir")
+            .with_giver("npc-duke")
+            .with_keywords(vec!["heir".to_string(), "Lady Wren".to_string()])
+            .with_objective(QuestObjective::new("Find the heir's trail").with_keywords(vec!["trail".to_string()]))
+            .with_objective(QuestObjective::new("Return Lady Wren to the keep").with_keywords(vec!["keep".to_string()]))
+            .with_objective(QuestObjective::new("Recover the signet ring").optional());
+        manager.create_quest(quest).unwrap()
+    }
+
+    #[test]
+    fn test_quest_crud_and_search() {
+        let manager = QuestManager::new();
+        let quest = sample_quest(&manager);
+
+        assert!(manager.create_quest(Quest::new("campaign-1", "  ")).is_err());
+        assert_eq!(manager.search_quests("campaign-1", "signet").len(), 1);
+        assert_eq!(manager.get_quests_from_giver("campaign-1", "npc-duke").len(), 1);
+        assert!(manager.search_quests("campaign-2", "heir").is_empty());
+
+        let mut updated = quest.clone();
+        updated.arc_id = Some("arc-1".to_string());
+        updated.status = QuestStatus::Completed;
+        let updated = manager.update_quest(updated).unwrap();
+        assert!(updated.resolved_at.is_some());
+        assert_eq!(manager.get_quests_for_arc("campaign-1", "arc-1").len(), 1);
+        assert_eq!(manager.list_quests("campaign-1", Some(QuestStatus::Active)).len(), 0);
+
+        manager.delete_quest(&quest.id).unwrap();
+        assert!(manager.get_quest(&quest.id).is_none());
+    }
+
+    #[test]
+    fn test_objective_progress() {
+        let manager = QuestManager::new();
+        let quest = sample_quest(&manager);
+        for objective in quest.objectives.iter().filter(|o| !o.optional) {
+            manager
+                .set_objective_status(&quest.id, &objective.id, ObjectiveStatus::Completed)
+                .unwrap();
+        }
+
+        let quest = manager.get_quest(&quest.id).unwrap();
+        assert!(quest.required_objectives_complete());
+        assert_eq!(quest.progress(), (2, 3));
+        assert!(manager
+            .set_objective_status(&quest.id, "missing", ObjectiveStatus::Failed)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mention_suggests_starting_quest() {
+        let manager = QuestManager::new();
+        let quest = sample_quest(&manager);
+
+        let suggestions = manager.suggest_status_changes("campaign-1", &[event("Tavern rumor", "Talk of the missing heir")]);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].quest_id, quest.id);
+        assert_eq!(suggestions[0].kind, QuestSuggestionKind::StartQuest);
+        assert_eq!(suggestions[0].matched_keyword, "heir");
+    }
+
+    #[test]
+    fn test_success_cues_complete_objectives_and_quest() {
+        let manager = QuestManager::new();
+        let quest = sample_quest(&manager);
+        manager.set_quest_status(&quest.id, QuestStatus::Active).unwrap();
+
+        let events = [
+            event("Tracking", "The ranger found the trail north"),
+            event("Homecoming", "Lady Wren returned to the keep"),
+        ];
+        let suggestions = manager.suggest_status_changes("campaign-1", &events);
+        let kinds: Vec<_> = suggestions.iter().map(|s| s.kind.clone()).collect();
+
+        assert!(kinds.contains(&QuestSuggestionKind::CompleteObjective {
+            objective_id: quest.objectives[0].id.clone()
+        }));
+        assert!(kinds.contains(&QuestSuggestionKind::CompleteObjective {
+            objective_id: quest.objectives[1].id.clone()
+        }));
+        // Neither event alone finishes both required objectives
+        assert!(!kinds.contains(&QuestSuggestionKind::CompleteQuest));
+
+        for suggestion in &suggestions {
+            manager.apply_suggestion(suggestion).unwrap();
+        }
+        let suggestions =
+            manager.suggest_status_changes("campaign-1", &[event("Reward", "The duke thanks them: the heir quest is completed")]);
+        assert!(suggestions.iter().any(|s| s.kind == QuestSuggestionKind::CompleteQuest));
+    }
+
+    #[test]
+    fn test_failure_and_resolved_quests() {
+        let manager = QuestManager::new();
+        let quest = sample_quest(&manager);
+        manager.set_quest_status(&quest.id, QuestStatus::Active).unwrap();
+
+        let suggestions = manager.suggest_status_changes("campaign-1", &[event("Tragedy", "Lady Wren died in the fire")]);
+        assert!(suggestions.iter().any(|s| s.kind == QuestSuggestionKind::FailQuest));
+
+        manager.set_quest_status(&quest.id, QuestStatus::Failed).unwrap();
+        assert!(manager
+            .suggest_status_changes("campaign-1", &[event("Rumor", "The heir was seen")])
+            .is_empty());
+    }
+}
@@ -0,0 +1,867 @@
+//! Shop Generator Module
+//!
+//! Generates stocked merchant inventories for a location from the items in
+//! the ingested TTRPG documents, with a basic equipment list to fall back on.
+//! Settlement size decides how much is on the shelves and how expensive it
+//! can get; shops sell out as the party buys and restock as in-game days
+//! pass.
+
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{OnceLock, RwLock};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::treasury::CurrencySystem;
+use super::world_state::InGameDate;
+use crate::core::character_gen::GameSystem;
+use crate::database::TTRPGDocumentRecord;
+
+/// Document element types that hold items
+pub const ITEM_ELEMENT_TYPES: &[&str] = &["item", "magic_item", "equipment", "weapon", "armor"];
+
+/// D&D 5e magic item prices in gp by rarity, when the source gives none
+const DND5E_RARITY_PRICES: &[(&str, i64)] = &[
+    ("common", 100),
+    ("uncommon", 400),
+    ("rare", 4_000),
+    ("very rare", 40_000),
+    ("legendary", 200_000),
+];
+
+/// Pathfinder 2e permanent item prices in gp for levels 0 to 20, when the
+/// source gives none
+const PF2E_LEVEL_PRICES: [i64; 21] = [
+    5, 20, 35, 60, 100, 160, 250, 360, 500, 700, 900, 1_300, 1_800, 2_700, 4_000, 6_500, 10_000, 15_000,
+    24_000, 40_000, 70_000,
+];
+
+/// Share of an item's worth a shop pays when buying it from the party
+pub const RESALE_PERCENT: i64 = 50;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ShopError {
+    #[error("Shop not found: {0}")]
+    NotFound(String),
+
+    #[error("{shop} doesn't sell {item}")]
+    ItemNotFound { shop: String, item: String },
+
+    #[error("Only {available} {item} in stock, {requested} requested")]
+    OutOfStock { item: String, available: u32, requested: u32 },
+
+    #[error("Quantity must be at least 1")]
+    InvalidQuantity,
+}
+
+pub type Result<T> = std::result::Result<T, ShopError>;
+
+// ============================================================================
+// Shop Types
+// ============================================================================
+
+/// How big the settlement around the shop is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementSize {
+    Hamlet,
+    Village,
+    #[default]
+    Town,
+    City,
+    Metropolis,
+}
+
+impl SettlementSize {
+    /// Fewest and most different items on the shelves
+    pub fn item_range(&self) -> (usize, usize) {
+        match self {
+            Self::Hamlet => (4, 8),
+            Self::Village => (6, 12),
+            Self::Town => (10, 18),
+            Self::City => (15, 25),
+            Self::Metropolis => (20, 35),
+        }
+    }
+
+    /// Most a single item can cost, in the system's main coin
+    pub fn price_cap(&self) -> Option<i64> {
+        match self {
+            Self::Hamlet => Some(50),
+            Self::Village => Some(250),
+            Self::Town => Some(2_500),
+            Self::City => Some(25_000),
+            Self::Metropolis => None,
+        }
+    }
+
+    /// Price adjustment in percent: remote places charge more
+    pub fn markup_percent(&self) -> i64 {
+        match self {
+            Self::Hamlet => 120,
+            Self::Village => 110,
+            Self::Town | Self::City => 100,
+            Self::Metropolis => 95,
+        }
+    }
+
+    /// How many of a cheap item a shop keeps, relative to a village
+    pub fn stock_multiplier(&self) -> u32 {
+        match self {
+            Self::Hamlet | Self::Village => 1,
+            Self::Town => 2,
+            Self::City => 3,
+            Self::Metropolis => 4,
+        }
+    }
+
+    /// Days between deliveries
+    pub fn restock_interval_days(&self) -> u32 {
+        match self {
+            Self::Hamlet => 30,
+            Self::Village => 14,
+            Self::Town => 7,
+            Self::City => 3,
+            Self::Metropolis => 1,
+        }
+    }
+}
+
+/// What a shop deals in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShopType {
+    #[default]
+    General,
+    Blacksmith,
+    Armorer,
+    Alchemist,
+    Magic,
+    Jeweler,
+    Bookseller,
+    Outfitter,
+}
+
+impl ShopType {
+    /// Words in an item's category or name that belong in this shop
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::General => &[],
+            Self::Blacksmith => &[
+                "weapon", "sword", "axe", "hammer", "mace", "spear", "dagger", "pick", "flail", "glaive", "halberd",
+                "lance", "trident", "scimitar", "rapier", "sickle", "tool", "horseshoe",
+            ],
+            Self::Armorer => &["armor", "armour", "shield", "mail", "plate", "breastplate", "helm", "gauntlet"],
+            Self::Alchemist => &[
+                "potion", "elixir", "oil", "poison", "alchemical", "alchemist", "antitoxin", "acid", "herb", "salve",
+            ],
+            Self::Magic => &[],
+            Self::Jeweler => &["ring", "amulet", "necklace", "gem", "jewel", "brooch", "circlet", "pendant", "crown"],
+            Self::Bookseller => &["book", "scroll", "tome", "map", "ink", "paper", "parchment", "spellbook"],
+            Self::Outfitter => &[
+                "pack", "rope", "tent", "lantern", "torch", "bedroll", "clothes", "boots", "cloak", "ration", "kit",
+                "gear", "waterskin", "blanket", "mount", "saddle",
+            ],
+        }
+    }
+
+    /// Whether the shop would carry an item
+    pub fn stocks(&self, item: &ItemCandidate) -> bool {
+        let text = format!("{} {}", item.category, item.name).to_lowercase();
+        let matches = self.keywords().iter().any(|k| text.contains(k));
+        match self {
+            Self::General => !item.magic,
+            Self::Magic => item.magic,
+            Self::Alchemist => matches,
+            _ => matches && !item.magic,
+        }
+    }
+}
+
+// ============================================================================
+// Item Candidates
+// ============================================================================
+
+/// An item that could be stocked, from the ingested documents or the basic
+/// equipment list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemCandidate {
+    pub name: String,
+    /// Ingested document the item came from
+    pub document_id: Option<String>,
+    /// Weapon, armor, potion, adventuring gear...
+    pub category: String,
+    pub rarity: Option<String>,
+    pub magic: bool,
+    /// In the system's smallest coin
+    pub price: i64,
+    /// Weight of one, when the source gives it
+    #[serde(default)]
+    pub weight: Option<f64>,
+}
+
+impl ItemCandidate {
+    /// Read an item from an ingested document, if it is one with a price or
+    /// a rarity or level to price it by
+    pub fn from_record(record: &TTRPGDocumentRecord, currency: &CurrencySystem) -> Option<Self> {
+        let element_type = record.element_type.to_lowercase();
+        if !ITEM_ELEMENT_TYPES.contains(&element_type.as_str()) {
+            return None;
+        }
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let text = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|k| attributes.get(*k).and_then(|v| v.as_str()))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+        let game_system = GameSystem::from_str(&record.game_system);
+        let rarity = text(&["rarity"]).map(|r| r.to_lowercase());
+        let has_magic_trait = matches!(
+            attributes.get("traits"),
+            Some(serde_json::Value::Array(traits)) if traits.iter().any(|t| t.as_str().is_some_and(|t| t.eq_ignore_ascii_case("magical")))
+        );
+        let magic = attributes.get("magic").and_then(|v| v.as_bool()).unwrap_or(false)
+            || element_type == "magic_item"
+            || has_magic_trait
+            || (game_system != GameSystem::Pathfinder2e && rarity.is_some());
+
+        let listed_price = ["cost", "price", "value"].iter().find_map(|k| match attributes.get(*k)? {
+            serde_json::Value::Number(n) => n.as_f64().and_then(|n| price_in(currency, n, None)),
+            serde_json::Value::String(s) => parse_price(s, currency),
+            _ => None,
+        });
+        let price = listed_price.or_else(|| {
+            let main_coins = match game_system {
+                GameSystem::Pathfinder2e => {
+                    let level = record.level.or_else(|| attributes.get("level")?.as_i64().map(|l| l as i32))?;
+                    PF2E_LEVEL_PRICES[level.clamp(0, 20) as usize]
+                }
+                _ => DND5E_RARITY_PRICES.iter().find(|(r, _)| Some(*r) == rarity.as_deref())?.1,
+            };
+            currency.to_base(main_coins, None).ok()
+        })?;
+
+        Some(Self {
+            name: record.name.clone(),
+            document_id: Some(record.id.clone()),
+            category: text(&["category", "item_type", "type"]).unwrap_or_else(|| element_type.clone()),
+            rarity,
+            magic,
+            price,
+            weight: attributes.get("weight").and_then(parse_weight),
+        })
+    }
+
+    fn basic(name: &str, category: &str, price: &str, currency: &CurrencySystem) -> Option<Self> {
+        Some(Self {
+            name: name.to_string(),
+            document_id: None,
+            category: category.to_string(),
+            rarity: None,
+            magic: category == "potion",
+            price: parse_price(price, currency)?,
+            weight: None,
+        })
+    }
+}
+
+fn price_in(currency: &CurrencySystem, amount: f64, code: Option<&str>) -> Option<i64> {
+    let unit = currency.to_base(1, code).ok()?;
+    Some((amount * unit as f64).round() as i64)
+}
+
+/// Read a price like "15 gp", "1,500gp", or "2 sp" into the smallest coin.
+/// A bare number is in the system's main coin.
+pub fn parse_price(text: &str, currency: &CurrencySystem) -> Option<i64> {
+    static PRICE: OnceLock<Regex> = OnceLock::new();
+    let re = PRICE.get_or_init(|| Regex::new(r"(?i)(\d[\d,]*(?:\.\d+)?)\s*([a-z$]+)?").unwrap());
+    let caps = re.captures(text)?;
+    let amount: f64 = caps[1].replace(',', "").parse().ok()?;
+    price_in(currency, amount, caps.get(2).map(|m| m.as_str()))
+}
+
+/// Read a weight like 3, "3 lb.", "1/2 lb", "L" (light Bulk), or "2 Bulk"
+fn parse_weight(value: &serde_json::Value) -> Option<f64> {
+    if let Some(n) = value.as_f64() {
+        return Some(n);
+    }
+    let text = value.as_str()?.trim();
+    if text.eq_ignore_ascii_case("l") {
+        return Some(0.1);
+    }
+    if text == "-" || text == "—" {
+        return Some(0.0);
+    }
+    static WEIGHT: OnceLock<Regex> = OnceLock::new();
+    let re = WEIGHT.get_or_init(|| Regex::new(r"(\d+(?:\.\d+)?)(?:\s*/\s*(\d+))?").unwrap());
+    let caps = re.captures(text)?;
+    let amount: f64 = caps[1].parse().ok()?;
+    match caps.get(2).and_then(|d| d.as_str().parse::<f64>().ok()) {
+        Some(denominator) if denominator > 0.0 => Some(amount / denominator),
+        _ => Some(amount),
+    }
+}
+
+/// The basic equipment list for systems that have one, so a shop has
+/// something to sell before any sourcebooks are ingested
+pub fn basic_stock(system: &str) -> Vec<ItemCandidate> {
+    let currency = CurrencySystem::for_system(system);
+    let items: &[(&str, &str, &str)] = match GameSystem::from_str(system) {
+        GameSystem::DnD5e => &[
+            ("Backpack", "adventuring gear", "2 gp"),
+            ("Bedroll", "adventuring gear", "1 gp"),
+            ("Blanket", "adventuring gear", "5 sp"),
+            ("Candle", "adventuring gear", "1 cp"),
+            ("Crowbar", "tool", "2 gp"),
+            ("Hempen Rope (50 feet)", "adventuring gear", "1 gp"),
+            ("Lantern, Hooded", "adventuring gear", "5 gp"),
+            ("Oil (flask)", "adventuring gear", "1 sp"),
+            ("Rations (1 day)", "adventuring gear", "5 sp"),
+            ("Tinderbox", "adventuring gear", "5 sp"),
+            ("Torch", "adventuring gear", "1 cp"),
+            ("Waterskin", "adventuring gear", "2 sp"),
+            ("Tent, Two-Person", "adventuring gear", "2 gp"),
+            ("Traveler's Clothes", "clothing", "2 gp"),
+            ("Ink (1 ounce bottle)", "adventuring gear", "10 gp"),
+            ("Paper (one sheet)", "adventuring gear", "2 sp"),
+            ("Book", "adventuring gear", "25 gp"),
+            ("Healer's Kit", "adventuring gear", "5 gp"),
+            ("Antitoxin (vial)", "adventuring gear", "50 gp"),
+            ("Alchemist's Fire (flask)", "adventuring gear", "50 gp"),
+            ("Acid (vial)", "adventuring gear", "25 gp"),
+            ("Dagger", "simple weapon", "2 gp"),
+            ("Handaxe", "simple weapon", "5 gp"),
+            ("Mace", "simple weapon", "5 gp"),
+            ("Spear", "simple weapon", "1 gp"),
+            ("Light Crossbow", "simple weapon", "25 gp"),
+            ("Shortbow", "simple weapon", "25 gp"),
+            ("Battleaxe", "martial weapon", "10 gp"),
+            ("Longsword", "martial weapon", "15 gp"),
+            ("Rapier", "martial weapon", "25 gp"),
+            ("Warhammer", "martial weapon", "15 gp"),
+            ("Longbow", "martial weapon", "50 gp"),
+            ("Arrows (20)", "ammunition", "1 gp"),
+            ("Leather Armor", "light armor", "10 gp"),
+            ("Studded Leather Armor", "light armor", "45 gp"),
+            ("Scale Mail", "medium armor", "50 gp"),
+            ("Breastplate", "medium armor", "400 gp"),
+            ("Chain Mail", "heavy armor", "75 gp"),
+            ("Plate Armor", "heavy armor", "1,500 gp"),
+            ("Shield", "shield", "10 gp"),
+            ("Potion of Healing", "potion", "50 gp"),
+            ("Signet Ring", "adventuring gear", "5 gp"),
+        ],
+        GameSystem::Pathfinder2e => &[
+            ("Backpack", "adventuring gear", "1 sp"),
+            ("Bedroll", "adventuring gear", "2 cp"),
+            ("Rope (50 feet)", "adventuring gear", "5 sp"),
+            ("Rations (1 week)", "adventuring gear", "4 sp"),
+            ("Torch", "adventuring gear", "1 cp"),
+            ("Waterskin", "adventuring gear", "5 cp"),
+            ("Healer's Tools", "tool", "5 gp"),
+            ("Writing Set", "adventuring gear", "1 gp"),
+            ("Dagger", "simple weapon", "2 sp"),
+            ("Spear", "simple weapon", "1 sp"),
+            ("Longsword", "martial weapon", "1 gp"),
+            ("Shortbow", "martial weapon", "3 gp"),
+            ("Arrows (10)", "ammunition", "1 sp"),
+            ("Leather Armor", "light armor", "2 gp"),
+            ("Chain Mail", "medium armor", "6 gp"),
+            ("Full Plate", "heavy armor", "30 gp"),
+            ("Wooden Shield", "shield", "1 gp"),
+            ("Minor Healing Potion", "potion", "4 gp"),
+            ("Antidote (Lesser)", "alchemical elixir", "3 gp"),
+        ],
+        _ => &[],
+    };
+    items.iter().filter_map(|(name, category, price)| ItemCandidate::basic(name, category, price, &currency)).collect()
+}
+
+/// Ingested items plus the basic equipment list, without duplicate names.
+/// Ingested items win over basic ones of the same name.
+pub fn with_basic_stock(mut candidates: Vec<ItemCandidate>, system: &str) -> Vec<ItemCandidate> {
+    candidates.extend(basic_stock(system));
+    let mut seen = HashSet::new();
+    candidates.retain(|c| seen.insert(c.name.to_lowercase()));
+    candidates
+}
+
+// ============================================================================
+// Shops
+// ============================================================================
+
+/// An item on a shop's shelves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShopItem {
+    pub name: String,
+    pub document_id: Option<String>,
+    pub category: String,
+    pub rarity: Option<String>,
+    /// Asking price for one, in the system's smallest coin
+    pub price: i64,
+    /// The asking price as change, e.g. "15 gp 5 sp"
+    pub price_text: String,
+    pub quantity: u32,
+    /// How many the shop holds when fully stocked
+    pub max_quantity: u32,
+    /// Weight of one, when known
+    #[serde(default)]
+    pub weight: Option<f64>,
+}
+
+/// A merchant and its stock, kept at a location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Shop {
+    pub id: String,
+    pub campaign_id: String,
+    pub location_id: String,
+    pub name: String,
+    pub shop_type: ShopType,
+    pub settlement_size: SettlementSize,
+    /// Game system the prices are in
+    pub system: String,
+    pub items: Vec<ShopItem>,
+    pub restock_interval_days: u32,
+    /// In-game date of the last restock
+    pub last_restocked: Option<InGameDate>,
+    /// Calendar day number of the last restock
+    pub restock_day: Option<i64>,
+    #[serde(default)]
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What changed when a shop restocked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RestockReport {
+    pub shop_id: String,
+    /// Deliveries since the last restock; nothing changes when zero
+    pub deliveries: u32,
+    /// Items topped back up
+    pub replenished: Vec<String>,
+    /// Sold-out items dropped from the shelves
+    pub removed: Vec<String>,
+    /// New items put on the shelves
+    pub added: Vec<String>,
+}
+
+/// A purchase from a shop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShopPurchase {
+    pub shop_id: String,
+    pub item: ShopItem,
+    pub quantity: u32,
+    /// In the system's smallest coin
+    pub total_price: i64,
+    pub total_text: String,
+}
+
+impl Shop {
+    pub fn new(
+        campaign_id: &str,
+        location_id: &str,
+        name: &str,
+        shop_type: ShopType,
+        settlement_size: SettlementSize,
+        system: &str,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            location_id: location_id.to_string(),
+            name: name.to_string(),
+            shop_type,
+            settlement_size,
+            system: system.to_string(),
+            items: Vec::new(),
+            restock_interval_days: settlement_size.restock_interval_days(),
+            last_restocked: None,
+            restock_day: None,
+            notes: String::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Items from the candidates this shop would carry at this settlement size
+    fn eligible<'a>(&self, candidates: &'a [ItemCandidate], currency: &CurrencySystem) -> Vec<&'a ItemCandidate> {
+        let cap = self.settlement_size.price_cap().and_then(|gp| currency.to_base(gp, None).ok());
+        candidates
+            .iter()
+            .filter(|c| self.shop_type.stocks(c))
+            .filter(|c| cap.is_none_or(|cap| c.price <= cap))
+            .collect()
+    }
+
+    /// Put an item on the shelves at this shop's prices
+    fn shelve(&self, candidate: &ItemCandidate, currency: &CurrencySystem, rng: &mut impl Rng) -> ShopItem {
+        let haggle = rng.gen_range(90..=110);
+        let price = (candidate.price * self.settlement_size.markup_percent() * haggle / 10_000).max(1);
+        let main_coin = currency.to_base(1, None).unwrap_or(1);
+        let max_quantity = if price < main_coin {
+            rng.gen_range(5..=20) * self.settlement_size.stock_multiplier()
+        } else if price < 50 * main_coin {
+            rng.gen_range(1..=6) * self.settlement_size.stock_multiplier()
+        } else {
+            1
+        };
+        ShopItem {
+            name: candidate.name.clone(),
+            document_id: candidate.document_id.clone(),
+            category: candidate.category.clone(),
+            rarity: candidate.rarity.clone(),
+            price,
+            price_text: currency.format(price),
+            quantity: max_quantity,
+            max_quantity,
+            weight: candidate.weight,
+        }
+    }
+
+    /// Fill the shelves from scratch
+    pub fn stock(&mut self, candidates: &[ItemCandidate], rng: &mut impl Rng) {
+        let currency = CurrencySystem::for_system(&self.system);
+        let eligible = self.eligible(candidates, &currency);
+        let (min, max) = self.settlement_size.item_range();
+        let count = rng.gen_range(min..=max);
+        let mut items: Vec<ShopItem> = eligible
+            .choose_multiple(rng, count)
+            .map(|c| self.shelve(c, &currency, rng))
+            .collect();
+        items.sort_by(|a, b| a.category.cmp(&b.category).then_with(|| a.name.cmp(&b.name)));
+        self.items = items;
+        self.updated_at = Utc::now();
+    }
+
+    /// Restock for the deliveries due by calendar day `today`. Each delivery
+    /// tops items back up and may swap sold-out items for new ones. A shop
+    /// that has never been restocked starts counting from today.
+    pub fn restock(&mut self, today: i64, candidates: &[ItemCandidate], rng: &mut impl Rng) -> RestockReport {
+        let mut report = RestockReport { shop_id: self.id.clone(), ..Default::default() };
+        let Some(last) = self.restock_day else {
+            self.restock_day = Some(today);
+            return report;
+        };
+        let interval = self.restock_interval_days.max(1) as i64;
+        let deliveries = (today - last).max(0) / interval;
+        if deliveries == 0 {
+            return report;
+        }
+        self.restock_day = Some(last + deliveries * interval);
+        report.deliveries = deliveries as u32;
+
+        let currency = CurrencySystem::for_system(&self.system);
+        for _ in 0..deliveries.min(10) {
+            for item in &mut self.items {
+                if item.quantity < item.max_quantity {
+                    item.quantity = (item.quantity + item.max_quantity.div_ceil(2)).min(item.max_quantity);
+                    if !report.replenished.contains(&item.name) {
+                        report.replenished.push(item.name.clone());
+                    }
+                }
+            }
+
+            let sold_out: Vec<usize> = (0..self.items.len())
+                .filter(|&i| self.items[i].quantity == 0 && rng.gen_bool(0.5))
+                .collect();
+            for index in sold_out.into_iter().rev() {
+                report.removed.push(self.items.remove(index).name);
+            }
+            let (_, max) = self.settlement_size.item_range();
+            let stocked: HashSet<String> = self.items.iter().map(|i| i.name.to_lowercase()).collect();
+            let fresh: Vec<&ItemCandidate> = self
+                .eligible(candidates, &currency)
+                .into_iter()
+                .filter(|c| !stocked.contains(&c.name.to_lowercase()))
+                .collect();
+            let room = max.saturating_sub(self.items.len()).min(rng.gen_range(0..=2));
+            for candidate in fresh.choose_multiple(rng, room).copied().collect::<Vec<_>>() {
+                report.added.push(candidate.name.clone());
+                let item = self.shelve(candidate, &currency, rng);
+                self.items.push(item);
+            }
+        }
+        self.updated_at = Utc::now();
+        report
+    }
+
+    /// Sell items to the party, taking them off the shelves
+    pub fn purchase(&mut self, item_name: &str, quantity: u32) -> Result<ShopPurchase> {
+        if quantity == 0 {
+            return Err(ShopError::InvalidQuantity);
+        }
+        let item = self
+            .items
+            .iter_mut()
+            .find(|i| i.name.eq_ignore_ascii_case(item_name.trim()))
+            .ok_or_else(|| ShopError::ItemNotFound { shop: self.name.clone(), item: item_name.to_string() })?;
+        if item.quantity < quantity {
+            return Err(ShopError::OutOfStock { item: item.name.clone(), available: item.quantity, requested: quantity });
+        }
+        item.quantity -= quantity;
+        let total_price = item.price * quantity as i64;
+        let purchase = ShopPurchase {
+            shop_id: self.id.clone(),
+            item: item.clone(),
+            quantity,
+            total_price,
+            total_text: CurrencySystem::for_system(&self.system).format(total_price),
+        };
+        self.updated_at = Utc::now();
+        Ok(purchase)
+    }
+
+    /// Buy items from the party for [`RESALE_PERCENT`] of their worth,
+    /// putting them on the shelves. `item.price` is the worth of one; when
+    /// it's zero, the shop's own asking price for the item is used.
+    pub fn buy_back(&mut self, mut item: ShopItem, quantity: u32) -> Result<ShopPurchase> {
+        if quantity == 0 {
+            return Err(ShopError::InvalidQuantity);
+        }
+        let currency = CurrencySystem::for_system(&self.system);
+        let shelved = self.items.iter_mut().find(|i| i.name.eq_ignore_ascii_case(item.name.trim()));
+        if item.price <= 0 {
+            item.price = shelved.as_ref().map(|i| i.price).unwrap_or(0);
+        }
+        let offer = item.price * RESALE_PERCENT / 100;
+        match shelved {
+            Some(existing) => {
+                existing.quantity += quantity;
+                existing.max_quantity = existing.max_quantity.max(existing.quantity);
+            }
+            None => {
+                item.price_text = currency.format(item.price);
+                item.quantity = quantity;
+                item.max_quantity = quantity;
+                self.items.push(item.clone());
+            }
+        }
+        let total_price = offer * quantity as i64;
+        self.updated_at = Utc::now();
+        Ok(ShopPurchase {
+            shop_id: self.id.clone(),
+            item,
+            quantity,
+            total_price,
+            total_text: currency.format(total_price),
+        })
+    }
+}
+
+// ============================================================================
+// Shop Manager
+// ============================================================================
+
+pub struct ShopManager {
+    shops: RwLock<HashMap<String, Shop>>,
+}
+
+impl Default for ShopManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShopManager {
+    pub fn new() -> Self {
+        Self {
+            shops: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Save a new shop or replace an existing one
+    pub fn save(&self, mut shop: Shop) -> Shop {
+        shop.updated_at = Utc::now();
+        self.shops.write().unwrap().insert(shop.id.clone(), shop.clone());
+        shop
+    }
+
+    pub fn get(&self, shop_id: &str) -> Result<Shop> {
+        self.shops
+            .read()
+            .unwrap()
+            .get(shop_id)
+            .cloned()
+            .ok_or_else(|| ShopError::NotFound(shop_id.to_string()))
+    }
+
+    /// Change a shop in place
+    pub fn update<T>(&self, shop_id: &str, f: impl FnOnce(&mut Shop) -> Result<T>) -> Result<T> {
+        let mut shops = self.shops.write().unwrap();
+        let shop = shops.get_mut(shop_id).ok_or_else(|| ShopError::NotFound(shop_id.to_string()))?;
+        f(shop)
+    }
+
+    /// Shops at a location, by name
+    pub fn for_location(&self, location_id: &str) -> Vec<Shop> {
+        let mut shops: Vec<Shop> = self
+            .shops
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.location_id == location_id)
+            .cloned()
+            .collect();
+        shops.sort_by(|a, b| a.name.cmp(&b.name));
+        shops
+    }
+
+    /// A campaign's shops, by name
+    pub fn list(&self, campaign_id: &str) -> Vec<Shop> {
+        let mut shops: Vec<Shop> = self
+            .shops
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.campaign_id == campaign_id)
+            .cloned()
+            .collect();
+        shops.sort_by(|a, b| a.name.cmp(&b.name));
+        shops
+    }
+
+    pub fn delete(&self, shop_id: &str) -> Result<()> {
+        self.shops
+            .write()
+            .unwrap()
+            .remove(shop_id)
+            .map(|_| ())
+            .ok_or_else(|| ShopError::NotFound(shop_id.to_string()))
+    }
+
+    pub fn delete_location_shops(&self, location_id: &str) {
+        self.shops.write().unwrap().retain(|_, s| s.location_id != location_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn record(name: &str, element_type: &str, system: &str, attributes: serde_json::Value) -> TTRPGDocumentRecord {
+        TTRPGDocumentRecord::new(
+            format!("doc-{}", name),
+            "dmg".to_string(),
+            name.to_string(),
+            element_type.to_string(),
+            system.to_string(),
+            format!("{} is an item.", name),
+            0.9,
+        )
+        .with_attributes(attributes)
+    }
+
+    #[test]
+    fn test_item_candidates_from_records() {
+        let currency = CurrencySystem::for_system("dnd5e");
+        assert_eq!(parse_price("1,500 gp", &currency), Some(150_000));
+        assert_eq!(parse_price("2sp", &currency), Some(20));
+        assert_eq!(parse_price("7", &currency), Some(700));
+        assert_eq!(parse_price("priceless", &currency), None);
+
+        let rope = record("Silk Rope", "item", "dnd5e", serde_json::json!({"cost": "10 gp", "category": "gear"}));
+        let rope = ItemCandidate::from_record(&rope, &currency).unwrap();
+        assert_eq!(rope.price, 1_000);
+        assert!(!rope.magic);
+
+        let cloak = record("Cloak of Elvenkind", "item", "dnd5e", serde_json::json!({"rarity": "Uncommon"}));
+        let cloak = ItemCandidate::from_record(&cloak, &currency).unwrap();
+        assert!(cloak.magic);
+        assert_eq!(cloak.price, 40_000);
+
+        let unpriced = record("Mystery Box", "item", "dnd5e", serde_json::json!({}));
+        assert!(ItemCandidate::from_record(&unpriced, &currency).is_none());
+        let monster = record("Goblin", "monster", "dnd5e", serde_json::json!({"cost": "1 gp"}));
+        assert!(ItemCandidate::from_record(&monster, &currency).is_none());
+
+        let pf2e = CurrencySystem::for_system("pf2e");
+        let wand = record("Wand of Sparks", "item", "pf2e", serde_json::json!({"level": 3, "traits": ["Magical"]}));
+        let wand = ItemCandidate::from_record(&wand, &pf2e).unwrap();
+        assert!(wand.magic);
+        assert_eq!(wand.price, 6_000);
+    }
+
+    #[test]
+    fn test_stock_follows_shop_type_and_settlement() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let candidates = with_basic_stock(Vec::new(), "dnd5e");
+
+        let mut smithy = Shop::new("camp-1", "loc-1", "Anvil & Ember", ShopType::Armorer, SettlementSize::Hamlet, "dnd5e");
+        smithy.stock(&candidates, &mut rng);
+        assert!(!smithy.items.is_empty());
+        assert!(smithy.items.iter().all(|i| i.price <= 5_000 * 120 * 110 / 10_000));
+        assert!(!smithy.items.iter().any(|i| i.name == "Plate Armor" || i.name == "Longsword"));
+
+        let mut store = Shop::new("camp-1", "loc-1", "Sundries", ShopType::General, SettlementSize::City, "dnd5e");
+        store.stock(&candidates, &mut rng);
+        let (min, _) = SettlementSize::City.item_range();
+        assert!(store.items.len() >= min);
+        assert!(!store.items.iter().any(|i| i.name == "Potion of Healing"));
+    }
+
+    #[test]
+    fn test_purchase_and_restock_over_time() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let candidates = with_basic_stock(Vec::new(), "dnd5e");
+        let mut shop = Shop::new("camp-1", "loc-1", "Sundries", ShopType::General, SettlementSize::Town, "dnd5e");
+        shop.stock(&candidates, &mut rng);
+
+        let first = shop.items[0].clone();
+        let purchase = shop.purchase(&first.name.to_uppercase(), first.quantity).unwrap();
+        assert_eq!(purchase.total_price, first.price * first.quantity as i64);
+        assert_eq!(shop.items[0].quantity, 0);
+        assert!(matches!(shop.purchase(&first.name, 1), Err(ShopError::OutOfStock { .. })));
+        assert!(matches!(shop.purchase("Dragon Egg", 1), Err(ShopError::ItemNotFound { .. })));
+
+        assert_eq!(shop.restock(100, &candidates, &mut rng).deliveries, 0);
+        assert_eq!(shop.restock(106, &candidates, &mut rng).deliveries, 0);
+        let report = shop.restock(110, &candidates, &mut rng);
+        assert_eq!(report.deliveries, 1);
+        assert_eq!(shop.restock_day, Some(107));
+        assert!(report.replenished.contains(&first.name) || report.removed.contains(&first.name));
+        if let Some(item) = shop.items.iter().find(|i| i.name == first.name) {
+            assert!(item.quantity > 0);
+        }
+    }
+
+    #[test]
+    fn test_buy_back_pays_resale_price() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let candidates = with_basic_stock(Vec::new(), "dnd5e");
+        let mut shop = Shop::new("camp-1", "loc-1", "Sundries", ShopType::General, SettlementSize::Town, "dnd5e");
+        shop.stock(&candidates, &mut rng);
+
+        let mut shelved = shop.items[0].clone();
+        shelved.price = 0;
+        let sale = shop.buy_back(shelved.clone(), 2).unwrap();
+        assert_eq!(sale.total_price, shop.items[0].price * RESALE_PERCENT / 100 * 2);
+        assert_eq!(shop.items[0].quantity, shelved.quantity + 2);
+
+        let mut idol = shelved;
+        idol.name = "Jade Idol".to_string();
+        idol.price = 25_000;
+        assert_eq!(shop.buy_back(idol, 1).unwrap().total_price, 12_500);
+        assert!(shop.items.iter().any(|i| i.name == "Jade Idol" && i.quantity == 1));
+
+        assert_eq!(parse_weight(&serde_json::json!("1/2 lb.")), Some(0.5));
+        assert_eq!(parse_weight(&serde_json::json!("L")), Some(0.1));
+        assert_eq!(parse_weight(&serde_json::json!(6)), Some(6.0));
+    }
+}
@@ -0,0 +1,595 @@
+//! Spellcasting Module
+//!
+//! Spell slots and known or prepared spells for player characters and NPC
+//! casters. Casting a spell spends a slot, a long rest restores them, and
+//! each spell links to its entry in an ingested rulebook so the GM can look
+//! it up mid-session.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::database::TTRPGDocumentRecord;
+
+/// Highest spell level (Pathfinder 2e spell ranks reach 10)
+pub const MAX_SPELL_LEVEL: u8 = 10;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum SpellcastingError {
+    #[error("Spell not found: {0}")]
+    SpellNotFound(String),
+
+    #[error("Spell level must be 0 to {MAX_SPELL_LEVEL}, got {0}")]
+    InvalidSpellLevel(u8),
+
+    #[error("{0} is not prepared")]
+    NotPrepared(String),
+
+    #[error("{0} can't be cast as a ritual")]
+    NotARitual(String),
+
+    #[error("{spell} is level {spell_level} and can't be cast with a level {slot_level} slot")]
+    SlotTooLow { spell: String, spell_level: u8, slot_level: u8 },
+
+    #[error("No spell slots of level {0} or higher left")]
+    NoSlotsLeft(u8),
+}
+
+pub type Result<T> = std::result::Result<T, SpellcastingError>;
+
+// ============================================================================
+// Spell Types
+// ============================================================================
+
+/// Who a spellbook belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Caster {
+    Character { character_id: String },
+    Npc { npc_id: String },
+}
+
+impl Caster {
+    pub fn character(character_id: &str) -> Self {
+        Self::Character {
+            character_id: character_id.to_string(),
+        }
+    }
+
+    pub fn npc(npc_id: &str) -> Self {
+        Self::Npc {
+            npc_id: npc_id.to_string(),
+        }
+    }
+
+    fn key(&self, campaign_id: &str) -> String {
+        match self {
+            Self::Character { character_id } => format!("{}:character:{}", campaign_id, character_id),
+            Self::Npc { npc_id } => format!("{}:npc:{}", campaign_id, npc_id),
+        }
+    }
+}
+
+/// The slots of one spell level
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SlotPool {
+    pub level: u8,
+    pub total: u32,
+    #[serde(default)]
+    pub expended: u32,
+}
+
+impl SlotPool {
+    pub fn remaining(&self) -> u32 {
+        self.total.saturating_sub(self.expended)
+    }
+}
+
+/// A spell a caster knows, with its rulebook entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownSpell {
+    pub id: String,
+    pub name: String,
+    /// 0 for cantrips
+    pub level: u8,
+    #[serde(default)]
+    pub school: Option<String>,
+    #[serde(default)]
+    pub prepared: bool,
+    /// Prepared without counting against the limit, e.g. domain spells
+    #[serde(default)]
+    pub always_prepared: bool,
+    #[serde(default)]
+    pub ritual: bool,
+    #[serde(default)]
+    pub concentration: bool,
+    /// Ingested rulebook entry for the spell
+    #[serde(default)]
+    pub document_id: Option<String>,
+    /// The rulebook the entry came from
+    #[serde(default)]
+    pub source_document_id: Option<String>,
+    #[serde(default)]
+    pub page_number: Option<i32>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl KnownSpell {
+    pub fn new(name: &str, level: u8) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.trim().to_string(),
+            level,
+            school: None,
+            prepared: false,
+            always_prepared: false,
+            ritual: false,
+            concentration: false,
+            document_id: None,
+            source_document_id: None,
+            page_number: None,
+            notes: String::new(),
+        }
+    }
+
+    /// Read a spell from an ingested rulebook entry, if it is one
+    pub fn from_record(record: &TTRPGDocumentRecord) -> Option<Self> {
+        if !record.element_type.eq_ignore_ascii_case("spell") {
+            return None;
+        }
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let level = record.level.map(|l| l.clamp(0, MAX_SPELL_LEVEL as i32) as u8).or_else(|| {
+            match attributes.get("level").or_else(|| attributes.get("rank"))? {
+                serde_json::Value::Number(n) => n.as_u64().map(|n| n.min(MAX_SPELL_LEVEL as u64) as u8),
+                serde_json::Value::String(s) if s.eq_ignore_ascii_case("cantrip") => Some(0),
+                serde_json::Value::String(s) => s
+                    .trim_end_matches(|c: char| !c.is_ascii_digit())
+                    .parse::<u8>()
+                    .ok()
+                    .map(|l| l.min(MAX_SPELL_LEVEL)),
+                _ => None,
+            }
+        })?;
+
+        let mut spell = Self::new(&record.name, level);
+        spell.link(record);
+        spell.school = attributes
+            .get("school")
+            .and_then(|v| v.as_str())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        spell.ritual = attributes.get("ritual").and_then(|v| v.as_bool()).unwrap_or(false);
+        spell.concentration = attributes.get("concentration").and_then(|v| v.as_bool()).unwrap_or_else(|| {
+            attributes
+                .get("duration")
+                .and_then(|v| v.as_str())
+                .is_some_and(|d| d.to_lowercase().contains("concentration"))
+        });
+        Some(spell)
+    }
+
+    /// Point the spell at its rulebook entry
+    pub fn link(&mut self, record: &TTRPGDocumentRecord) {
+        self.document_id = Some(record.id.clone());
+        self.source_document_id = Some(record.source_document_id.clone());
+        self.page_number = record.page_number;
+    }
+
+    pub fn is_cantrip(&self) -> bool {
+        self.level == 0
+    }
+
+    /// Castable without preparing it first
+    pub fn is_ready(&self) -> bool {
+        self.is_cantrip() || self.prepared || self.always_prepared
+    }
+}
+
+/// What casting a spell used up
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CastOutcome {
+    pub spell: KnownSpell,
+    /// Level of the slot spent; none for cantrips and rituals
+    pub slot_level: Option<u8>,
+    /// The slot came from pact magic
+    pub pact_slot: bool,
+    /// Cast with a slot above the spell's level
+    pub upcast: bool,
+    /// Slots of that level left afterwards
+    pub slots_remaining: u32,
+    /// Spell whose concentration this one broke
+    pub ended_concentration: Option<String>,
+}
+
+// ============================================================================
+// Spellbook
+// ============================================================================
+
+/// A caster's spell slots and spells
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Spellbook {
+    pub campaign_id: String,
+    pub caster: Caster,
+    /// Spellcasting ability, e.g. "Intelligence"
+    #[serde(default)]
+    pub ability: Option<String>,
+    #[serde(default)]
+    pub save_dc: Option<i32>,
+    #[serde(default)]
+    pub attack_bonus: Option<i32>,
+    /// One pool per spell level, lowest first
+    pub slots: Vec<SlotPool>,
+    /// Pact magic slots, which come back on a short rest
+    #[serde(default)]
+    pub pact_slots: Option<SlotPool>,
+    pub spells: Vec<KnownSpell>,
+    /// Spell the caster is concentrating on
+    #[serde(default)]
+    pub concentrating_on: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Spellbook {
+    pub fn new(campaign_id: &str, caster: Caster) -> Self {
+        Self {
+            campaign_id: campaign_id.to_string(),
+            caster,
+            ability: None,
+            save_dc: None,
+            attack_bonus: None,
+            slots: Vec::new(),
+            pact_slots: None,
+            spells: Vec::new(),
+            concentrating_on: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn spell(&self, spell_id: &str) -> Option<&KnownSpell> {
+        self.spells.iter().find(|s| s.id == spell_id)
+    }
+
+    /// Set slots from a class table indexed from 1st-level slots. With
+    /// `pact`, the highest level's slots are pact magic. Slots already spent
+    /// stay spent.
+    pub fn set_slots(&mut self, per_level: &[u32], pact: bool) {
+        let expended = |pools: &[SlotPool], level: u8| pools.iter().find(|p| p.level == level).map_or(0, |p| p.expended);
+        let previous = std::mem::take(&mut self.slots);
+        let previous_pact = self.pact_slots.take();
+
+        let pools = per_level.iter().enumerate().filter(|(_, total)| **total > 0).map(|(i, total)| {
+            let level = (i + 1).min(MAX_SPELL_LEVEL as usize) as u8;
+            SlotPool {
+                level,
+                total: *total,
+                expended: 0,
+            }
+        });
+        for mut pool in pools {
+            if pact {
+                pool.expended = previous_pact.filter(|p| p.level == pool.level).map_or(0, |p| p.expended).min(pool.total);
+                self.pact_slots = Some(pool);
+            } else {
+                pool.expended = expended(&previous, pool.level).min(pool.total);
+                self.slots.push(pool);
+            }
+        }
+        if pact {
+            self.slots = previous;
+        } else {
+            self.pact_slots = previous_pact;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Add a spell. A spell with the same name is updated with any rulebook
+    /// link it lacked instead of added twice.
+    pub fn learn(&mut self, spell: KnownSpell) -> Result<KnownSpell> {
+        if spell.level > MAX_SPELL_LEVEL {
+            return Err(SpellcastingError::InvalidSpellLevel(spell.level));
+        }
+        self.updated_at = Utc::now();
+        if let Some(existing) = self.spells.iter_mut().find(|s| s.name.eq_ignore_ascii_case(spell.name.trim())) {
+            if existing.document_id.is_none() {
+                existing.document_id = spell.document_id;
+                existing.source_document_id = spell.source_document_id;
+                existing.page_number = spell.page_number;
+            }
+            return Ok(existing.clone());
+        }
+        self.spells.push(spell.clone());
+        self.spells.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.name.cmp(&b.name)));
+        Ok(spell)
+    }
+
+    pub fn forget(&mut self, spell_id: &str) -> Result<KnownSpell> {
+        let index = self
+            .spells
+            .iter()
+            .position(|s| s.id == spell_id)
+            .ok_or_else(|| SpellcastingError::SpellNotFound(spell_id.to_string()))?;
+        self.updated_at = Utc::now();
+        Ok(self.spells.remove(index))
+    }
+
+    pub fn set_prepared(&mut self, spell_id: &str, prepared: bool) -> Result<KnownSpell> {
+        let spell = self
+            .spells
+            .iter_mut()
+            .find(|s| s.id == spell_id)
+            .ok_or_else(|| SpellcastingError::SpellNotFound(spell_id.to_string()))?;
+        spell.prepared = prepared;
+        self.updated_at = Utc::now();
+        Ok(spell.clone())
+    }
+
+    /// Prepared spells, not counting cantrips or always-prepared spells
+    pub fn prepared_count(&self) -> usize {
+        self.spells.iter().filter(|s| s.prepared && !s.always_prepared && !s.is_cantrip()).count()
+    }
+
+    /// Cast a spell, spending a slot unless it is a cantrip or cast as a
+    /// ritual. Without `slot_level`, the lowest slot that can cast it is
+    /// used, pact magic winning ties.
+    pub fn cast(&mut self, spell_id: &str, slot_level: Option<u8>, as_ritual: bool) -> Result<CastOutcome> {
+        let spell = self
+            .spell(spell_id)
+            .cloned()
+            .ok_or_else(|| SpellcastingError::SpellNotFound(spell_id.to_string()))?;
+        if as_ritual && !spell.ritual {
+            return Err(SpellcastingError::NotARitual(spell.name));
+        }
+        if !spell.is_ready() {
+            return Err(SpellcastingError::NotPrepared(spell.name));
+        }
+
+        let mut outcome = CastOutcome {
+            spell: spell.clone(),
+            slot_level: None,
+            pact_slot: false,
+            upcast: false,
+            slots_remaining: 0,
+            ended_concentration: None,
+        };
+
+        if !spell.is_cantrip() && !as_ritual {
+            if let Some(level) = slot_level.filter(|l| *l < spell.level) {
+                return Err(SpellcastingError::SlotTooLow {
+                    spell: spell.name,
+                    spell_level: spell.level,
+                    slot_level: level,
+                });
+            }
+            let usable = |pool: &SlotPool| {
+                pool.remaining() > 0 && slot_level.map_or(pool.level >= spell.level, |l| pool.level == l)
+            };
+            let pact = self.pact_slots.as_mut().filter(|p| usable(p));
+            let regular = self.slots.iter_mut().filter(|p| usable(p)).min_by_key(|p| p.level);
+            let (pool, pact_slot) = match (regular, pact) {
+                (Some(regular), Some(pact)) if pact.level <= regular.level => (pact, true),
+                (Some(regular), _) => (regular, false),
+                (None, Some(pact)) => (pact, true),
+                (None, None) => return Err(SpellcastingError::NoSlotsLeft(slot_level.unwrap_or(spell.level))),
+            };
+            pool.expended += 1;
+            outcome.slot_level = Some(pool.level);
+            outcome.pact_slot = pact_slot;
+            outcome.upcast = pool.level > spell.level;
+            outcome.slots_remaining = pool.remaining();
+        }
+
+        if spell.concentration {
+            outcome.ended_concentration = self.concentrating_on.replace(spell.name);
+        }
+        self.updated_at = Utc::now();
+        Ok(outcome)
+    }
+
+    /// Regain every slot and drop concentration
+    pub fn long_rest(&mut self) {
+        for pool in self.slots.iter_mut().chain(self.pact_slots.iter_mut()) {
+            pool.expended = 0;
+        }
+        self.concentrating_on = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Regain pact magic slots
+    pub fn short_rest(&mut self) {
+        if let Some(pool) = self.pact_slots.as_mut() {
+            pool.expended = 0;
+        }
+        self.updated_at = Utc::now();
+    }
+}
+
+// ============================================================================
+// Spellcasting Manager
+// ============================================================================
+
+/// Holds every spellbook, keyed by campaign and caster
+pub struct SpellcastingManager {
+    spellbooks: RwLock<HashMap<String, Spellbook>>,
+}
+
+impl Default for SpellcastingManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpellcastingManager {
+    pub fn new() -> Self {
+        Self {
+            spellbooks: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// A caster's spellbook, empty if they have none yet
+    pub fn get(&self, campaign_id: &str, caster: &Caster) -> Spellbook {
+        self.spellbooks
+            .read()
+            .unwrap()
+            .get(&caster.key(campaign_id))
+            .cloned()
+            .unwrap_or_else(|| Spellbook::new(campaign_id, caster.clone()))
+    }
+
+    /// Change a caster's spellbook in place, creating it if needed
+    pub fn update<T>(&self, campaign_id: &str, caster: &Caster, f: impl FnOnce(&mut Spellbook) -> Result<T>) -> Result<T> {
+        let mut spellbooks = self.spellbooks.write().unwrap();
+        let spellbook = spellbooks
+            .entry(caster.key(campaign_id))
+            .or_insert_with(|| Spellbook::new(campaign_id, caster.clone()));
+        f(spellbook)
+    }
+
+    /// Long rest for every caster in a campaign
+    pub fn long_rest_all(&self, campaign_id: &str) -> Vec<Spellbook> {
+        let mut spellbooks = self.spellbooks.write().unwrap();
+        spellbooks
+            .values_mut()
+            .filter(|s| s.campaign_id == campaign_id)
+            .map(|s| {
+                s.long_rest();
+                s.clone()
+            })
+            .collect()
+    }
+
+    /// A campaign's spellbooks
+    pub fn list(&self, campaign_id: &str) -> Vec<Spellbook> {
+        self.spellbooks
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.campaign_id == campaign_id)
+            .cloned()
+            .collect()
+    }
+
+    pub fn remove(&self, campaign_id: &str, caster: &Caster) -> Option<Spellbook> {
+        self.spellbooks.write().unwrap().remove(&caster.key(campaign_id))
+    }
+
+    pub fn delete_campaign_spellbooks(&self, campaign_id: &str) {
+        self.spellbooks.write().unwrap().retain(|_, s| s.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wizard() -> Spellbook {
+        let mut book = Spellbook::new("camp-1", Caster::character("pc-1"));
+        book.set_slots(&[4, 3, 2], false);
+        book
+    }
+
+    #[test]
+    fn test_cast_spends_lowest_slot_and_long_rest_restores() {
+        let mut book = wizard();
+        let mut shield = KnownSpell::new("Shield", 1);
+        shield.prepared = true;
+        let shield = book.learn(shield).unwrap();
+        let bolt = book.learn(KnownSpell::new("Fire Bolt", 0)).unwrap();
+        let web = book.learn(KnownSpell::new("Web", 2)).unwrap();
+
+        let cast = book.cast(&shield.id, None, false).unwrap();
+        assert_eq!(cast.slot_level, Some(1));
+        assert_eq!(cast.slots_remaining, 3);
+        assert!(!cast.upcast);
+
+        let upcast = book.cast(&shield.id, Some(3), false).unwrap();
+        assert!(upcast.upcast);
+        assert_eq!(book.slots[2].remaining(), 1);
+
+        assert_eq!(book.cast(&bolt.id, None, false).unwrap().slot_level, None);
+        assert!(matches!(book.cast(&web.id, None, false), Err(SpellcastingError::NotPrepared(_))));
+        assert!(matches!(
+            book.cast(&shield.id, Some(0), false),
+            Err(SpellcastingError::SlotTooLow { .. })
+        ));
+
+        for _ in 0..3 {
+            book.cast(&shield.id, Some(1), false).unwrap();
+        }
+        assert!(matches!(book.cast(&shield.id, Some(1), false), Err(SpellcastingError::NoSlotsLeft(1))));
+
+        book.long_rest();
+        assert!(book.slots.iter().all(|p| p.expended == 0));
+    }
+
+    #[test]
+    fn test_pact_slots_rituals_and_concentration() {
+        let mut book = Spellbook::new("camp-1", Caster::npc("npc-1"));
+        book.set_slots(&[0, 2], true);
+        assert_eq!(book.pact_slots.map(|p| p.level), Some(2));
+        assert!(book.slots.is_empty());
+
+        let mut hex = KnownSpell::new("Hex", 1);
+        hex.prepared = true;
+        hex.concentration = true;
+        let hex = book.learn(hex).unwrap();
+        let mut detect = KnownSpell::new("Detect Magic", 1);
+        detect.ritual = true;
+        detect.concentration = true;
+        detect.always_prepared = true;
+        let detect = book.learn(detect).unwrap();
+
+        let cast = book.cast(&hex.id, None, false).unwrap();
+        assert!(cast.pact_slot && cast.upcast);
+        assert_eq!(cast.slots_remaining, 1);
+
+        let ritual = book.cast(&detect.id, None, true).unwrap();
+        assert_eq!(ritual.slot_level, None);
+        assert_eq!(ritual.ended_concentration.as_deref(), Some("Hex"));
+        assert!(matches!(book.cast(&hex.id, None, true), Err(SpellcastingError::NotARitual(_))));
+
+        book.short_rest();
+        assert_eq!(book.pact_slots.unwrap().remaining(), 2);
+        assert_eq!(book.concentrating_on.as_deref(), Some("Detect Magic"));
+    }
+
+    #[test]
+    fn test_spell_from_record_links_rulebook_entry() {
+        let mut record = TTRPGDocumentRecord::new(
+            "doc-fireball".to_string(),
+            "phb".to_string(),
+            "Fireball".to_string(),
+            "spell".to_string(),
+            "dnd5e".to_string(),
+            "A bright streak flashes...".to_string(),
+            0.9,
+        )
+        .with_attributes(serde_json::json!({ "level": "3rd", "school": "Evocation", "duration": "Instantaneous" }));
+        record.page_number = Some(241);
+
+        let spell = KnownSpell::from_record(&record).unwrap();
+        assert_eq!(spell.level, 3);
+        assert_eq!(spell.school.as_deref(), Some("Evocation"));
+        assert!(!spell.concentration);
+        assert_eq!(spell.document_id.as_deref(), Some("doc-fireball"));
+        assert_eq!(spell.page_number, Some(241));
+
+        let mut book = wizard();
+        let first = book.learn(KnownSpell::new("fireball", 3)).unwrap();
+        let again = book.learn(spell).unwrap();
+        assert_eq!(first.id, again.id);
+        assert_eq!(again.document_id.as_deref(), Some("doc-fireball"));
+        assert_eq!(book.spells.len(), 1);
+    }
+}
@@ -0,0 +1,576 @@
+//! Campaign Templates
+//!
+//! Reusable starting points for new campaigns. A template bundles default
+//! arcs, factions, locations, house rules, and narrator personality settings.
+//! Built-in templates ship with the app; user templates are stored as one
+//! JSON file each in the templates directory.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::arc_types::{ArcType, CampaignArc};
+use super::world_state::WorldState;
+use crate::core::location_gen::{Atmosphere, ConnectionType, Location, LocationConnection, LocationType};
+use crate::core::personality::{GenreConvention, NarrativeTone, PersonalitySettings, VocabularyLevel};
+
+/// World state custom field holding a campaign's arcs
+pub const ARCS_FIELD: &str = "arcs";
+/// World state custom field holding a campaign's factions
+pub const FACTIONS_FIELD: &str = "factions";
+/// World state custom field recording the template a campaign was created from
+pub const TEMPLATE_FIELD: &str = "template_id";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum CampaignTemplateError {
+    #[error("Template not found: {0}")]
+    NotFound(String),
+
+    #[error("Built-in template cannot be modified: {0}")]
+    BuiltIn(String),
+
+    #[error("Invalid template: {0}")]
+    Invalid(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, CampaignTemplateError>;
+
+// ============================================================================
+// Template Types
+// ============================================================================
+
+/// A story arc created with the campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateArc {
+    pub name: String,
+    #[serde(default)]
+    pub arc_type: ArcType,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub premise: String,
+    #[serde(default)]
+    pub is_main_arc: bool,
+}
+
+/// A faction created with the campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateFaction {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub goals: Vec<String>,
+    /// Names of template locations this faction controls
+    #[serde(default)]
+    pub controls: Vec<String>,
+}
+
+/// A location created with the campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateLocation {
+    pub name: String,
+    pub location_type: LocationType,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Names of other template locations reachable from here
+    #[serde(default)]
+    pub connections: Vec<String>,
+}
+
+/// A faction as stored on a campaign
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CampaignFaction {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub goals: Vec<String>,
+}
+
+/// Starting content for a new campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignTemplate {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub system: String,
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub arcs: Vec<TemplateArc>,
+    #[serde(default)]
+    pub factions: Vec<TemplateFaction>,
+    #[serde(default)]
+    pub locations: Vec<TemplateLocation>,
+    #[serde(default)]
+    pub house_rules: Vec<String>,
+    /// Narrator personality settings applied to the new campaign
+    #[serde(default)]
+    pub personality: PersonalitySettings,
+    #[serde(default)]
+    pub narrator_personality_id: Option<String>,
+    /// Set on templates that ship with the app
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+fn default_theme() -> String {
+    "fantasy".to_string()
+}
+
+impl CampaignTemplate {
+    /// Check names are present and location references resolve
+    pub fn validate(&self) -> Result<()> {
+        if self.id.trim().is_empty() {
+            return Err(CampaignTemplateError::Invalid("id is required".to_string()));
+        }
+        if self.name.trim().is_empty() {
+            return Err(CampaignTemplateError::Invalid("name is required".to_string()));
+        }
+
+        let mut names = HashSet::new();
+        for location in &self.locations {
+            if !names.insert(location.name.as_str()) {
+                return Err(CampaignTemplateError::Invalid(format!(
+                    "duplicate location '{}'",
+                    location.name
+                )));
+            }
+        }
+
+        let connections = self
+            .locations
+            .iter()
+            .flat_map(|l| l.connections.iter());
+        let controls = self.factions.iter().flat_map(|f| f.controls.iter());
+        if let Some(missing) = connections.chain(controls).find(|n| !names.contains(n.as_str())) {
+            return Err(CampaignTemplateError::Invalid(format!(
+                "unknown location '{}'",
+                missing
+            )));
+        }
+        Ok(())
+    }
+
+    /// Build the template's arcs for a campaign, in template order
+    pub fn build_arcs(&self, campaign_id: &str) -> Vec<CampaignArc> {
+        self.arcs
+            .iter()
+            .enumerate()
+            .map(|(i, arc)| {
+                let mut built = CampaignArc::new(campaign_id, &arc.name, arc.arc_type.clone());
+                built.description = arc.description.clone();
+                built.premise = arc.premise.clone();
+                built.is_main_arc = arc.is_main_arc;
+                built.display_order = i as i32;
+                built
+            })
+            .collect()
+    }
+
+    /// Build the template's factions with fresh ids
+    pub fn build_factions(&self) -> Vec<CampaignFaction> {
+        self.factions
+            .iter()
+            .map(|f| CampaignFaction {
+                id: Uuid::new_v4().to_string(),
+                name: f.name.clone(),
+                description: f.description.clone(),
+                goals: f.goals.clone(),
+            })
+            .collect()
+    }
+
+    /// Build the template's locations for a campaign, linking connections by id
+    pub fn build_locations(&self, campaign_id: &str) -> Vec<Location> {
+        let now = Utc::now();
+        let ids: HashMap<&str, String> = self
+            .locations
+            .iter()
+            .map(|l| (l.name.as_str(), Uuid::new_v4().to_string()))
+            .collect();
+
+        self.locations
+            .iter()
+            .map(|l| Location {
+                id: ids[l.name.as_str()].clone(),
+                campaign_id: Some(campaign_id.to_string()),
+                name: l.name.clone(),
+                location_type: l.location_type.clone(),
+                description: l.description.clone(),
+                atmosphere: Atmosphere::default(),
+                notable_features: vec![],
+                inhabitants: vec![],
+                secrets: vec![],
+                encounters: vec![],
+                connected_locations: l
+                    .connections
+                    .iter()
+                    .map(|target| LocationConnection {
+                        target_id: ids.get(target.as_str()).cloned(),
+                        target_name: target.clone(),
+                        connection_type: ConnectionType::Path,
+                        description: None,
+                        travel_time: None,
+                        hazards: vec![],
+                    })
+                    .collect(),
+                loot_potential: None,
+                map_reference: None,
+                tags: l.tags.clone(),
+                notes: String::new(),
+                created_at: now,
+                updated_at: now,
+            })
+            .collect()
+    }
+
+    /// Faction name controlling each location, keyed by location name
+    pub fn location_control(&self) -> HashMap<&str, &str> {
+        self.factions
+            .iter()
+            .flat_map(|f| f.controls.iter().map(move |l| (l.as_str(), f.name.as_str())))
+            .collect()
+    }
+}
+
+/// Give a copied world state's arcs and factions fresh ids
+///
+/// Arcs are also re-pointed at the world state's campaign.
+pub fn reissue_content_ids(state: &mut WorldState) {
+    if let Some(value) = state.custom_fields.get_mut(ARCS_FIELD) {
+        if let Ok(mut arcs) = serde_json::from_value::<Vec<CampaignArc>>(value.clone()) {
+            for arc in &mut arcs {
+                arc.id = Uuid::new_v4().to_string();
+                arc.campaign_id = state.campaign_id.clone();
+            }
+            if let Ok(updated) = serde_json::to_value(arcs) {
+                *value = updated;
+            }
+        }
+    }
+
+    if let Some(value) = state.custom_fields.get_mut(FACTIONS_FIELD) {
+        if let Ok(mut factions) = serde_json::from_value::<Vec<CampaignFaction>>(value.clone()) {
+            for faction in &mut factions {
+                faction.id = Uuid::new_v4().to_string();
+            }
+            if let Ok(updated) = serde_json::to_value(factions) {
+                *value = updated;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Template Store
+// ============================================================================
+
+/// Built-in templates plus user templates saved on disk
+pub struct CampaignTemplateStore {
+    dir: PathBuf,
+}
+
+impl CampaignTemplateStore {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn template_path(&self, id: &str) -> PathBuf {
+        let safe: String = id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}.json", safe))
+    }
+
+    /// List built-in templates followed by user templates sorted by name
+    pub fn list(&self) -> Vec<CampaignTemplate> {
+        let mut user: Vec<CampaignTemplate> = std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|e| match std::fs::read_to_string(e.path()) {
+                Ok(content) => serde_json::from_str::<CampaignTemplate>(&content)
+                    .map_err(|err| log::warn!("Skipping campaign template {:?}: {}", e.path(), err))
+                    .ok(),
+                Err(_) => None,
+            })
+            .map(|mut t| {
+                t.builtin = false;
+                t
+            })
+            .collect();
+        user.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut templates = builtin_templates();
+        templates.extend(user);
+        templates
+    }
+
+    /// Get a template by id
+    pub fn get(&self, id: &str) -> Result<CampaignTemplate> {
+        self.list()
+            .into_iter()
+            .find(|t| t.id == id)
+            .ok_or_else(|| CampaignTemplateError::NotFound(id.to_string()))
+    }
+
+    /// Save a user template, replacing any with the same id
+    pub fn save(&self, mut template: CampaignTemplate) -> Result<CampaignTemplate> {
+        if is_builtin(&template.id) {
+            return Err(CampaignTemplateError::BuiltIn(template.id));
+        }
+        template.builtin = false;
+        template.validate()?;
+
+        std::fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_string_pretty(&template)?;
+        std::fs::write(self.template_path(&template.id), json)?;
+        Ok(template)
+    }
+
+    /// Delete a user template
+    pub fn delete(&self, id: &str) -> Result<()> {
+        if is_builtin(id) {
+            return Err(CampaignTemplateError::BuiltIn(id.to_string()));
+        }
+        let path = self.template_path(id);
+        if !path.exists() {
+            return Err(CampaignTemplateError::NotFound(id.to_string()));
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
+
+fn is_builtin(id: &str) -> bool {
+    builtin_templates().iter().any(|t| t.id == id)
+}
+
+// ============================================================================
+// Built-in Templates
+// ============================================================================
+
+fn location(name: &str, location_type: LocationType, description: &str, connections: &[&str]) -> TemplateLocation {
+    TemplateLocation {
+        name: name.to_string(),
+        location_type,
+        description: description.to_string(),
+        tags: vec![],
+        connections: connections.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+fn faction(name: &str, description: &str, goals: &[&str], controls: &[&str]) -> TemplateFaction {
+    TemplateFaction {
+        name: name.to_string(),
+        description: description.to_string(),
+        goals: goals.iter().map(|g| g.to_string()).collect(),
+        controls: controls.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+fn arc(name: &str, arc_type: ArcType, premise: &str, is_main_arc: bool) -> TemplateArc {
+    TemplateArc {
+        name: name.to_string(),
+        arc_type,
+        description: String::new(),
+        premise: premise.to_string(),
+        is_main_arc,
+    }
+}
+
+/// Templates that ship with the app
+pub fn builtin_templates() -> Vec<CampaignTemplate> {
+    vec![
+        CampaignTemplate {
+            id: "frontier-dungeon-crawl".to_string(),
+            name: "Frontier Dungeon Crawl".to_string(),
+            description: "A border town on the edge of the wilds with a ruin beneath the hills.".to_string(),
+            system: "D&D 5e".to_string(),
+            theme: "fantasy".to_string(),
+            tags: vec!["dungeon".to_string(), "exploration".to_string()],
+            arcs: vec![
+                arc("The Sunken Halls", ArcType::Linear, "Something stirs in the ruin below the hills.", true),
+                arc("Trouble in Town", ArcType::Sandbox, "Local rivalries flare as adventurers bring in coin.", false),
+            ],
+            factions: vec![
+                faction("Town Watch", "Underpaid and overstretched.", &["Keep the peace"], &["Millbrook"]),
+                faction("The Hollow Court", "Goblin tribes united under a new chief.", &["Drive out the settlers"], &["Sunken Halls"]),
+            ],
+            locations: vec![
+                location("Millbrook", LocationType::Village, "A frontier village of farmers and prospectors.", &["The Crooked Tankard", "Sunken Halls"]),
+                location("The Crooked Tankard", LocationType::Tavern, "The only inn for a day's ride.", &["Millbrook"]),
+                location("Sunken Halls", LocationType::Dungeon, "Collapsed dwarven halls under the hills.", &["Millbrook"]),
+            ],
+            house_rules: vec!["Potions can be drunk as a bonus action.".to_string()],
+            personality: PersonalitySettings {
+                tone: NarrativeTone::Epic,
+                genre: GenreConvention::HighFantasy,
+                ..Default::default()
+            },
+            narrator_personality_id: None,
+            builtin: true,
+        },
+        CampaignTemplate {
+            id: "gothic-horror".to_string(),
+            name: "Gothic Horror".to_string(),
+            description: "A fog-bound valley ruled by an ancient, hungry lord.".to_string(),
+            system: "D&D 5e".to_string(),
+            theme: "horror".to_string(),
+            tags: vec!["horror".to_string(), "mystery".to_string()],
+            arcs: vec![
+                arc("The Lord of the Valley", ArcType::Linear, "Escape the valley or destroy its master.", true),
+                arc("Whispers in the Village", ArcType::Mystery, "Villagers vanish on moonless nights.", false),
+            ],
+            factions: vec![
+                faction("The Castle", "The lord and his servants.", &["Keep the valley in fear"], &["Castle Ravenmoor"]),
+                faction("The Vigil", "Villagers sworn to resist.", &["Survive until dawn"], &["Barovar"]),
+            ],
+            locations: vec![
+                location("Barovar", LocationType::Village, "Shuttered houses and empty streets.", &["Castle Ravenmoor"]),
+                location("Castle Ravenmoor", LocationType::Castle, "A crumbling fortress above the valley.", &["Barovar"]),
+            ],
+            house_rules: vec!["Long rests only in sanctified ground.".to_string()],
+            personality: PersonalitySettings {
+                tone: NarrativeTone::Horror,
+                vocabulary: VocabularyLevel::Archaic,
+                genre: GenreConvention::Horror,
+                ..Default::default()
+            },
+            narrator_personality_id: None,
+            builtin: true,
+        },
+        CampaignTemplate {
+            id: "city-intrigue".to_string(),
+            name: "City Intrigue".to_string(),
+            description: "Guilds, nobles, and thieves compete for control of a great city.".to_string(),
+            system: "D&D 5e".to_string(),
+            theme: "noir".to_string(),
+            tags: vec!["urban".to_string(), "intrigue".to_string()],
+            arcs: vec![
+                arc("The Empty Throne", ArcType::Branching, "The city's ruler is dead and every faction wants the seat.", true),
+                arc("The Vault Job", ArcType::Heist, "A noble's vault holds proof of the murder.", false),
+            ],
+            factions: vec![
+                faction("The Merchant Guild", "Wealth buys influence.", &["Control the council"], &["The Grand Market"]),
+                faction("The Masks", "Thieves who trade in secrets.", &["Profit from chaos"], &["The Underways"]),
+            ],
+            locations: vec![
+                location("The Grand Market", LocationType::Market, "The beating heart of city trade.", &["The Underways", "High Hall"]),
+                location("The Underways", LocationType::Custom("Sewer".to_string()), "Tunnels beneath every district.", &["The Grand Market"]),
+                location("High Hall", LocationType::Manor, "Seat of the city council.", &["The Grand Market"]),
+            ],
+            house_rules: vec![],
+            personality: PersonalitySettings {
+                tone: NarrativeTone::Mysterious,
+                genre: GenreConvention::Noir,
+                ..Default::default()
+            },
+            narrator_personality_id: None,
+            builtin: true,
+        },
+    ]
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_templates_are_valid() {
+        for template in builtin_templates() {
+            template.validate().unwrap();
+            assert!(template.builtin);
+        }
+    }
+
+    #[test]
+    fn test_build_locations_links_connections() {
+        let template = builtin_templates().remove(0);
+        let locations = template.build_locations("camp-1");
+        assert_eq!(locations.len(), template.locations.len());
+
+        let ids: HashSet<&str> = locations.iter().map(|l| l.id.as_str()).collect();
+        for location in &locations {
+            assert_eq!(location.campaign_id.as_deref(), Some("camp-1"));
+            for connection in &location.connected_locations {
+                assert!(ids.contains(connection.target_id.as_deref().unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_location() {
+        let mut template = builtin_templates().remove(0);
+        template.factions[0].controls.push("Nowhere".to_string());
+        assert!(matches!(template.validate(), Err(CampaignTemplateError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_reissue_content_ids() {
+        let template = builtin_templates().remove(2);
+        let arcs = template.build_arcs("camp-1");
+        let factions = template.build_factions();
+
+        let mut state = WorldState::new("camp-2");
+        state.custom_fields.insert(ARCS_FIELD.to_string(), serde_json::to_value(&arcs).unwrap());
+        state.custom_fields.insert(FACTIONS_FIELD.to_string(), serde_json::to_value(&factions).unwrap());
+        reissue_content_ids(&mut state);
+
+        let copied: Vec<CampaignArc> = serde_json::from_value(state.custom_fields[ARCS_FIELD].clone()).unwrap();
+        assert_eq!(copied.len(), arcs.len());
+        assert!(copied.iter().all(|a| a.campaign_id == "camp-2"));
+        assert_ne!(copied[0].id, arcs[0].id);
+
+        let copied: Vec<CampaignFaction> = serde_json::from_value(state.custom_fields[FACTIONS_FIELD].clone()).unwrap();
+        assert_eq!(copied[0].name, factions[0].name);
+        assert_ne!(copied[0].id, factions[0].id);
+    }
+
+    #[test]
+    fn test_store_save_and_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CampaignTemplateStore::new(dir.path());
+
+        let mut template = builtin_templates().remove(1);
+        assert!(matches!(store.save(template.clone()), Err(CampaignTemplateError::BuiltIn(_))));
+
+        template.id = "my-horror".to_string();
+        template.name = "My Horror".to_string();
+        store.save(template).unwrap();
+        assert!(!store.get("my-horror").unwrap().builtin);
+        assert_eq!(store.list().len(), builtin_templates().len() + 1);
+
+        store.delete("my-horror").unwrap();
+        assert!(store.get("my-horror").is_err());
+        assert!(store.delete("gothic-horror").is_err());
+    }
+}
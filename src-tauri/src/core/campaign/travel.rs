@@ -0,0 +1,540 @@
+//! Travel Planning Module
+//!
+//! Distances and terrain for the connections between a campaign's
+//! locations, route finding, and travel time by pace. A journey can be
+//! checked for random encounters along each leg, one check per travel day,
+//! rolled on the leg's encounter table.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::location_gen::ConnectionType;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum TravelError {
+    #[error("Route segment not found: {0}")]
+    SegmentNotFound(String),
+
+    #[error("Distance must be positive")]
+    InvalidDistance,
+
+    #[error("Encounter chance must be between 0 and 1")]
+    InvalidChance,
+
+    #[error("Travel day must be between 1 and 24 hours")]
+    InvalidTravelDay,
+
+    #[error("No known route from {from} to {to}")]
+    NoRoute { from: String, to: String },
+}
+
+pub type Result<T> = std::result::Result<T, TravelError>;
+
+// ============================================================================
+// Pace and Terrain
+// ============================================================================
+
+/// How fast the party moves overland
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TravelPace {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl TravelPace {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "slow" | "stealthy" | "cautious" => Some(Self::Slow),
+            "normal" | "steady" => Some(Self::Normal),
+            "fast" | "forced" | "hurried" => Some(Self::Fast),
+            _ => None,
+        }
+    }
+
+    /// Miles per hour on open ground
+    pub fn miles_per_hour(&self) -> f64 {
+        match self {
+            Self::Slow => 2.0,
+            Self::Normal => 3.0,
+            Self::Fast => 4.0,
+        }
+    }
+}
+
+/// The ground a route segment crosses
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Terrain {
+    Road,
+    #[default]
+    Plains,
+    Forest,
+    Hills,
+    Mountains,
+    Swamp,
+    Desert,
+    Arctic,
+    Underground,
+    /// Travel by boat
+    Water,
+}
+
+impl Terrain {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "road" | "trail" => Some(Self::Road),
+            "plains" | "grassland" | "open" => Some(Self::Plains),
+            "forest" | "jungle" | "woods" => Some(Self::Forest),
+            "hills" => Some(Self::Hills),
+            "mountains" | "mountain" => Some(Self::Mountains),
+            "swamp" | "marsh" => Some(Self::Swamp),
+            "desert" => Some(Self::Desert),
+            "arctic" | "tundra" | "snow" => Some(Self::Arctic),
+            "underground" | "underdark" | "caves" => Some(Self::Underground),
+            "water" | "river" | "sea" | "boat" => Some(Self::Water),
+            _ => None,
+        }
+    }
+
+    /// Best guess from how two locations are connected
+    pub fn for_connection(connection_type: &ConnectionType) -> Self {
+        match connection_type {
+            ConnectionType::Road | ConnectionType::Door | ConnectionType::Stairs | ConnectionType::Portal => Self::Road,
+            ConnectionType::Water => Self::Water,
+            ConnectionType::Climb | ConnectionType::Ladder => Self::Mountains,
+            ConnectionType::Path | ConnectionType::Secret | ConnectionType::Flight => Self::Plains,
+        }
+    }
+
+    /// Multiplier on pace; difficult terrain halves it
+    pub fn speed_multiplier(&self) -> f64 {
+        match self {
+            Self::Road => 1.0,
+            Self::Plains => 1.0,
+            Self::Desert | Self::Underground => 0.75,
+            Self::Forest | Self::Hills | Self::Swamp | Self::Arctic => 0.5,
+            Self::Mountains => 1.0 / 3.0,
+            Self::Water => 1.0,
+        }
+    }
+}
+
+// ============================================================================
+// Route Segments
+// ============================================================================
+
+/// The distance and terrain between two connected locations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteSegment {
+    pub id: String,
+    pub campaign_id: String,
+    pub from_location_id: String,
+    pub to_location_id: String,
+    pub distance_miles: f64,
+    pub terrain: Terrain,
+    /// Also usable from `to_location_id` to `from_location_id`
+    pub bidirectional: bool,
+    /// Random table rolled when an encounter check succeeds
+    pub encounter_table_id: Option<String>,
+    /// Chance of an encounter per travel day on this segment (0-1)
+    pub encounter_chance: f32,
+}
+
+impl RouteSegment {
+    pub fn new(campaign_id: &str, from: &str, to: &str, distance_miles: f64, terrain: Terrain) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            from_location_id: from.to_string(),
+            to_location_id: to.to_string(),
+            distance_miles,
+            terrain,
+            bidirectional: true,
+            encounter_table_id: None,
+            encounter_chance: 0.0,
+        }
+    }
+
+    /// Builder: roll on a table for encounters
+    pub fn with_encounters(mut self, table_id: &str, chance: f32) -> Self {
+        self.encounter_table_id = Some(table_id.to_string());
+        self.encounter_chance = chance;
+        self
+    }
+
+    /// Builder: one-way only
+    pub fn one_way(mut self) -> Self {
+        self.bidirectional = false;
+        self
+    }
+
+    /// Whether this segment connects the two locations, in either usable direction
+    pub fn connects(&self, a: &str, b: &str) -> bool {
+        (self.from_location_id == a && self.to_location_id == b)
+            || (self.bidirectional && self.from_location_id == b && self.to_location_id == a)
+    }
+
+    /// Minutes to cover this segment at a pace
+    pub fn travel_minutes(&self, pace: TravelPace) -> i64 {
+        let hours = self.distance_miles / (pace.miles_per_hour() * self.terrain.speed_multiplier());
+        (hours * 60.0).ceil() as i64
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !self.distance_miles.is_finite() || self.distance_miles <= 0.0 {
+            return Err(TravelError::InvalidDistance);
+        }
+        if !(0.0..=1.0).contains(&self.encounter_chance) {
+            return Err(TravelError::InvalidChance);
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Travel Plans
+// ============================================================================
+
+/// One leg of a planned journey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TravelLeg {
+    pub segment_id: String,
+    pub from_location_id: String,
+    pub to_location_id: String,
+    pub distance_miles: f64,
+    pub terrain: Terrain,
+    pub minutes: i64,
+    pub encounter_table_id: Option<String>,
+    pub encounter_chance: f32,
+}
+
+/// A route between two locations with its travel time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TravelPlan {
+    pub campaign_id: String,
+    pub from_location_id: String,
+    pub to_location_id: String,
+    pub pace: TravelPace,
+    /// Hours of travel per day before the party makes camp
+    pub hours_per_day: u32,
+    pub legs: Vec<TravelLeg>,
+    pub total_miles: f64,
+    /// Time spent moving
+    pub travel_minutes: i64,
+    /// Calendar time the journey takes, counting nights spent camping
+    pub elapsed_minutes: i64,
+    /// Travel days, counting a partial last day
+    pub days: u32,
+}
+
+/// One encounter check along a journey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterCheck {
+    /// Index into the plan's legs
+    pub leg: usize,
+    /// Journey day the check happens on, starting at 1
+    pub day: u32,
+    pub table_id: String,
+    pub triggered: bool,
+}
+
+impl TravelPlan {
+    fn new(campaign_id: &str, from: &str, to: &str, pace: TravelPace, hours_per_day: u32, legs: Vec<TravelLeg>) -> Self {
+        let travel_minutes: i64 = legs.iter().map(|l| l.minutes).sum();
+        let day_minutes = i64::from(hours_per_day) * 60;
+        let full_days = travel_minutes / day_minutes;
+        let remainder = travel_minutes % day_minutes;
+        // Whole travel days also use up the hours spent camping overnight
+        let elapsed_minutes = if remainder == 0 && full_days > 0 {
+            (full_days - 1) * 24 * 60 + day_minutes
+        } else {
+            full_days * 24 * 60 + remainder
+        };
+        Self {
+            campaign_id: campaign_id.to_string(),
+            from_location_id: from.to_string(),
+            to_location_id: to.to_string(),
+            pace,
+            hours_per_day,
+            total_miles: legs.iter().map(|l| l.distance_miles).sum(),
+            legs,
+            travel_minutes,
+            elapsed_minutes,
+            days: ((travel_minutes + day_minutes - 1) / day_minutes) as u32,
+        }
+    }
+
+    /// Roll for encounters: one check per travel day spent on each leg with
+    /// an encounter table
+    pub fn roll_encounter_checks<R: Rng>(&self, rng: &mut R) -> Vec<EncounterCheck> {
+        let day_minutes = i64::from(self.hours_per_day) * 60;
+        let mut elapsed = 0;
+        let mut checks = Vec::new();
+        for (index, leg) in self.legs.iter().enumerate() {
+            if let Some(table_id) = &leg.encounter_table_id {
+                let first_day = elapsed / day_minutes;
+                let last_day = ((elapsed + leg.minutes - 1) / day_minutes).max(first_day);
+                for day in first_day..=last_day {
+                    checks.push(EncounterCheck {
+                        leg: index,
+                        day: day as u32 + 1,
+                        table_id: table_id.clone(),
+                        triggered: rng.gen::<f32>() < leg.encounter_chance,
+                    });
+                }
+            }
+            elapsed += leg.minutes;
+        }
+        checks
+    }
+}
+
+// ============================================================================
+// Travel Manager
+// ============================================================================
+
+pub struct TravelManager {
+    /// Segment ID -> segment
+    segments: RwLock<HashMap<String, RouteSegment>>,
+}
+
+impl Default for TravelManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TravelManager {
+    pub fn new() -> Self {
+        Self {
+            segments: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Add a segment, replacing any existing segment between the same
+    /// locations
+    pub fn set_segment(&self, segment: RouteSegment) -> Result<RouteSegment> {
+        segment.validate()?;
+        let mut segments = self.segments.write().unwrap();
+        segments.retain(|_, s| {
+            s.campaign_id != segment.campaign_id
+                || !(s.connects(&segment.from_location_id, &segment.to_location_id)
+                    || segment.connects(&s.from_location_id, &s.to_location_id))
+        });
+        segments.insert(segment.id.clone(), segment.clone());
+        Ok(segment)
+    }
+
+    pub fn get_segment(&self, segment_id: &str) -> Option<RouteSegment> {
+        self.segments.read().unwrap().get(segment_id).cloned()
+    }
+
+    pub fn delete_segment(&self, segment_id: &str) -> Result<RouteSegment> {
+        self.segments
+            .write()
+            .unwrap()
+            .remove(segment_id)
+            .ok_or_else(|| TravelError::SegmentNotFound(segment_id.to_string()))
+    }
+
+    /// A campaign's segments, optionally only those touching a location
+    pub fn list_segments(&self, campaign_id: &str, location_id: Option<&str>) -> Vec<RouteSegment> {
+        let mut segments: Vec<RouteSegment> = self
+            .segments
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.campaign_id == campaign_id)
+            .filter(|s| location_id.is_none_or(|l| s.from_location_id == l || s.to_location_id == l))
+            .cloned()
+            .collect();
+        segments.sort_by(|a, b| (&a.from_location_id, &a.to_location_id).cmp(&(&b.from_location_id, &b.to_location_id)));
+        segments
+    }
+
+    /// Find the quickest route between two locations at a pace
+    pub fn plan_route(
+        &self,
+        campaign_id: &str,
+        from: &str,
+        to: &str,
+        pace: TravelPace,
+        hours_per_day: u32,
+    ) -> Result<TravelPlan> {
+        if !(1..=24).contains(&hours_per_day) {
+            return Err(TravelError::InvalidTravelDay);
+        }
+        let segments = self.list_segments(campaign_id, None);
+
+        // Dijkstra over travel minutes
+        let mut best: HashMap<&str, i64> = HashMap::from([(from, 0)]);
+        let mut previous: HashMap<&str, (&str, &RouteSegment)> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, from))]);
+        while let Some(Reverse((minutes, location))) = queue.pop() {
+            if location == to {
+                break;
+            }
+            if best.get(location).is_some_and(|&b| minutes > b) {
+                continue;
+            }
+            for segment in &segments {
+                let next = if segment.from_location_id == location {
+                    segment.to_location_id.as_str()
+                } else if segment.bidirectional && segment.to_location_id == location {
+                    segment.from_location_id.as_str()
+                } else {
+                    continue;
+                };
+                let cost = minutes + segment.travel_minutes(pace);
+                if best.get(next).is_none_or(|&b| cost < b) {
+                    best.insert(next, cost);
+                    previous.insert(next, (location, segment));
+                    queue.push(Reverse((cost, next)));
+                }
+            }
+        }
+
+        if from != to && !previous.contains_key(to) {
+            return Err(TravelError::NoRoute {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+        let mut legs = Vec::new();
+        let mut current = to;
+        while let Some((prior, segment)) = previous.get(current) {
+            legs.push(TravelLeg {
+                segment_id: segment.id.clone(),
+                from_location_id: prior.to_string(),
+                to_location_id: current.to_string(),
+                distance_miles: segment.distance_miles,
+                terrain: segment.terrain,
+                minutes: segment.travel_minutes(pace),
+                encounter_table_id: segment.encounter_table_id.clone(),
+                encounter_chance: segment.encounter_chance,
+            });
+            current = prior;
+        }
+        legs.reverse();
+        Ok(TravelPlan::new(campaign_id, from, to, pace, hours_per_day, legs))
+    }
+
+    /// Remove all segments for a campaign
+    pub fn delete_campaign_segments(&self, campaign_id: &str) {
+        self.segments.write().unwrap().retain(|_, s| s.campaign_id != campaign_id);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn network() -> TravelManager {
+        let manager = TravelManager::new();
+        manager
+            .set_segment(RouteSegment::new("camp-1", "town", "fort", 24.0, Terrain::Road))
+            .unwrap();
+        manager
+            .set_segment(RouteSegment::new("camp-1", "fort", "keep", 12.0, Terrain::Forest).with_encounters("wolves", 1.0))
+            .unwrap();
+        // Direct but slow
+        manager
+            .set_segment(RouteSegment::new("camp-1", "town", "keep", 20.0, Terrain::Mountains))
+            .unwrap();
+        manager
+    }
+
+    #[test]
+    fn test_travel_minutes_by_pace_and_terrain() {
+        let road = RouteSegment::new("camp-1", "a", "b", 24.0, Terrain::Road);
+        assert_eq!(road.travel_minutes(TravelPace::Normal), 8 * 60);
+        assert_eq!(road.travel_minutes(TravelPace::Fast), 6 * 60);
+        let forest = RouteSegment::new("camp-1", "a", "b", 12.0, Terrain::Forest);
+        assert_eq!(forest.travel_minutes(TravelPace::Normal), 8 * 60);
+        assert_eq!(Terrain::for_connection(&ConnectionType::Water), Terrain::Water);
+    }
+
+    #[test]
+    fn test_plan_route_takes_quickest_path() {
+        let manager = network();
+        let plan = manager
+            .plan_route("camp-1", "town", "keep", TravelPace::Normal, 8)
+            .unwrap();
+        // Road + forest is 16 hours; the mountain pass is 20
+        assert_eq!(plan.legs.len(), 2);
+        assert_eq!(plan.legs[1].from_location_id, "fort");
+        assert_eq!(plan.travel_minutes, 16 * 60);
+        assert_eq!(plan.days, 2);
+        // Two full travel days: a day and a night, then another 8 hours
+        assert_eq!(plan.elapsed_minutes, 32 * 60);
+
+        // Segments work in reverse
+        let back = manager.plan_route("camp-1", "keep", "town", TravelPace::Normal, 8).unwrap();
+        assert_eq!(back.legs[0].from_location_id, "keep");
+        assert!(matches!(
+            manager.plan_route("camp-1", "town", "nowhere", TravelPace::Normal, 8),
+            Err(TravelError::NoRoute { .. })
+        ));
+    }
+
+    #[test]
+    fn test_partial_days_add_no_camping_time() {
+        let manager = network();
+        let plan = manager.plan_route("camp-1", "town", "fort", TravelPace::Normal, 6).unwrap();
+        assert_eq!(plan.days, 2);
+        // 6 hours, a night, then the remaining 2 hours
+        assert_eq!(plan.elapsed_minutes, 24 * 60 + 2 * 60);
+    }
+
+    #[test]
+    fn test_encounter_checks_per_travel_day() {
+        let manager = network();
+        let plan = manager
+            .plan_route("camp-1", "town", "keep", TravelPace::Normal, 8)
+            .unwrap();
+        let checks = plan.roll_encounter_checks(&mut StdRng::seed_from_u64(1));
+        // Only the forest leg has a table; it takes all of day 2
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].day, 2);
+        assert_eq!(checks[0].table_id, "wolves");
+        assert!(checks[0].triggered);
+    }
+
+    #[test]
+    fn test_set_segment_replaces_and_validates() {
+        let manager = network();
+        manager
+            .set_segment(RouteSegment::new("camp-1", "fort", "town", 30.0, Terrain::Road))
+            .unwrap();
+        let segments = manager.list_segments("camp-1", Some("town"));
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().any(|s| s.distance_miles == 30.0));
+
+        assert!(matches!(
+            manager.set_segment(RouteSegment::new("camp-1", "a", "b", 0.0, Terrain::Road)),
+            Err(TravelError::InvalidDistance)
+        ));
+        assert!(matches!(
+            manager.plan_route("camp-1", "town", "fort", TravelPace::Normal, 0),
+            Err(TravelError::InvalidTravelDay)
+        ));
+    }
+}
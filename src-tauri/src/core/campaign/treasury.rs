@@ -0,0 +1,803 @@
+//! Party Treasury Module
+//!
+//! Tracks a campaign's money: the shared party fund, each character's
+//! share, and a timestamped transaction log linked to sessions. Amounts are
+//! stored in the game system's smallest coin so splits and conversions
+//! never lose value.
+//!
+//! Loot text rolled from ingested treasure tables ("4d6 x 100 gp, a silver
+//! chalice") can be parsed into coins and items and split among the party.
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{OnceLock, RwLock};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::dice::{DiceNotation, DiceRoller};
+use crate::core::character_gen::GameSystem;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum TreasuryError {
+    #[error("Treasury not found for campaign: {0}")]
+    TreasuryNotFound(String),
+
+    #[error("Unknown currency: {0}")]
+    UnknownCurrency(String),
+
+    #[error("Amount must be positive")]
+    InvalidAmount,
+
+    #[error("Insufficient funds: {available} available, {requested} requested")]
+    InsufficientFunds { available: String, requested: String },
+
+    #[error("Loot needs at least one recipient")]
+    NoRecipients,
+}
+
+pub type Result<T> = std::result::Result<T, TreasuryError>;
+
+// ============================================================================
+// Currency
+// ============================================================================
+
+/// A coin or unit of money
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Denomination {
+    /// Short form used in loot text, e.g. "gp"
+    pub code: String,
+    pub name: String,
+    /// Worth in the system's smallest unit
+    pub value: i64,
+    /// Used when formatting amounts as change (electrum isn't)
+    pub standard: bool,
+}
+
+impl Denomination {
+    fn new(code: &str, name: &str, value: i64) -> Self {
+        Self {
+            code: code.to_string(),
+            name: name.to_string(),
+            value,
+            standard: true,
+        }
+    }
+
+    fn nonstandard(mut self) -> Self {
+        self.standard = false;
+        self
+    }
+}
+
+/// The denominations a game system counts money in
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CurrencySystem {
+    pub system: String,
+    /// Smallest first
+    pub denominations: Vec<Denomination>,
+    /// Code amounts are entered in when none is given
+    pub primary: String,
+}
+
+impl CurrencySystem {
+    /// Currency for a game system name, as accepted by [`GameSystem::from_str`]
+    pub fn for_system(system: &str) -> Self {
+        let game_system = GameSystem::from_str(system);
+        let (denominations, primary) = match game_system {
+            GameSystem::DnD5e => (
+                vec![
+                    Denomination::new("cp", "copper piece", 1),
+                    Denomination::new("sp", "silver piece", 10),
+                    Denomination::new("ep", "electrum piece", 50).nonstandard(),
+                    Denomination::new("gp", "gold piece", 100),
+                    Denomination::new("pp", "platinum piece", 1000),
+                ],
+                "gp",
+            ),
+            GameSystem::Pathfinder2e => (
+                vec![
+                    Denomination::new("cp", "copper piece", 1),
+                    Denomination::new("sp", "silver piece", 10),
+                    Denomination::new("gp", "gold piece", 100),
+                    Denomination::new("pp", "platinum piece", 1000),
+                ],
+                "gp",
+            ),
+            GameSystem::Warhammer => (
+                vec![
+                    Denomination::new("d", "brass penny", 1),
+                    Denomination::new("ss", "silver shilling", 12),
+                    Denomination::new("gc", "gold crown", 240),
+                ],
+                "ss",
+            ),
+            GameSystem::CallOfCthulhu => (
+                vec![Denomination::new("c", "cent", 1), Denomination::new("$", "dollar", 100)],
+                "$",
+            ),
+            GameSystem::Shadowrun => (vec![Denomination::new("nuyen", "nuyen", 1)], "nuyen"),
+            GameSystem::Cyberpunk => (vec![Denomination::new("eb", "eurobuck", 1)], "eb"),
+            GameSystem::GURPS | GameSystem::WorldOfDarkness | GameSystem::SavageWorlds => {
+                (vec![Denomination::new("$", "dollar", 1)], "$")
+            }
+            GameSystem::FateCore | GameSystem::DungeonWorld | GameSystem::Custom(_) => {
+                (vec![Denomination::new("coin", "coin", 1)], "coin")
+            }
+        };
+        Self {
+            system: game_system.id().to_string(),
+            denominations,
+            primary: primary.to_string(),
+        }
+    }
+
+    /// Find a denomination by code or name, ignoring case and plurals
+    pub fn denomination(&self, code: &str) -> Option<&Denomination> {
+        let code = code.trim().to_lowercase();
+        let singular = code.strip_suffix('s').unwrap_or(&code);
+        self.denominations.iter().find(|d| {
+            let name = d.name.to_lowercase();
+            d.code == code || name == code || name == singular || name.rsplit(' ').next() == Some(singular)
+        })
+    }
+
+    /// Convert an amount in the given denomination to the smallest unit
+    pub fn to_base(&self, amount: i64, code: Option<&str>) -> Result<i64> {
+        let code = code.unwrap_or(&self.primary);
+        let denomination = self
+            .denomination(code)
+            .ok_or_else(|| TreasuryError::UnknownCurrency(code.to_string()))?;
+        Ok(amount * denomination.value)
+    }
+
+    /// Convert between denominations, returning the whole amount and what's
+    /// left over in the smallest unit
+    pub fn convert(&self, amount: i64, from: &str, to: &str) -> Result<(i64, i64)> {
+        let base = self.to_base(amount, Some(from))?;
+        let target = self
+            .denomination(to)
+            .ok_or_else(|| TreasuryError::UnknownCurrency(to.to_string()))?;
+        Ok((base / target.value, base % target.value))
+    }
+
+    /// Format an amount in the smallest unit as change, largest coins first
+    pub fn format(&self, base: i64) -> String {
+        if base == 0 {
+            return format!("0 {}", self.primary);
+        }
+        let sign = if base < 0 { "-" } else { "" };
+        let mut remaining = base.abs();
+        let mut parts = Vec::new();
+        for denomination in self.denominations.iter().rev().filter(|d| d.standard) {
+            let count = remaining / denomination.value;
+            if count > 0 {
+                parts.push(format!("{} {}", count, denomination.code));
+                remaining %= denomination.value;
+            }
+        }
+        format!("{}{}", sign, parts.join(" "))
+    }
+}
+
+// ============================================================================
+// Treasury Types
+// ============================================================================
+
+/// Where money is held
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Account {
+    Party,
+    Character { character_id: String },
+}
+
+impl Account {
+    pub fn character(character_id: &str) -> Self {
+        Self::Character {
+            character_id: character_id.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionKind {
+    Income,
+    Expense,
+    Transfer,
+}
+
+impl TransactionKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "income" | "deposit" => Some(Self::Income),
+            "expense" | "withdrawal" | "spend" => Some(Self::Expense),
+            "transfer" => Some(Self::Transfer),
+            _ => None,
+        }
+    }
+}
+
+/// One entry in the treasury log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transaction {
+    pub id: String,
+    pub campaign_id: String,
+    pub kind: TransactionKind,
+    /// Account credited (income), debited (expense), or debited (transfer)
+    pub account: Account,
+    /// Account credited by a transfer
+    pub to_account: Option<Account>,
+    /// In the smallest unit
+    pub amount: i64,
+    pub description: String,
+    pub category: String,
+    pub session_id: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A campaign's money
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Treasury {
+    pub campaign_id: String,
+    pub currency: CurrencySystem,
+    pub party_funds: i64,
+    /// Character ID -> personal share
+    pub shares: HashMap<String, i64>,
+    pub transactions: Vec<Transaction>,
+}
+
+impl Treasury {
+    pub fn new(campaign_id: &str, currency: CurrencySystem) -> Self {
+        Self {
+            campaign_id: campaign_id.to_string(),
+            currency,
+            party_funds: 0,
+            shares: HashMap::new(),
+            transactions: Vec::new(),
+        }
+    }
+
+    pub fn balance(&self, account: &Account) -> i64 {
+        match account {
+            Account::Party => self.party_funds,
+            Account::Character { character_id } => self.shares.get(character_id).copied().unwrap_or(0),
+        }
+    }
+
+    fn balance_mut(&mut self, account: &Account) -> &mut i64 {
+        match account {
+            Account::Party => &mut self.party_funds,
+            Account::Character { character_id } => self.shares.entry(character_id.clone()).or_insert(0),
+        }
+    }
+
+    fn debit(&mut self, account: &Account, amount: i64) -> Result<()> {
+        let available = self.balance(account);
+        if available < amount {
+            return Err(TreasuryError::InsufficientFunds {
+                available: self.currency.format(available),
+                requested: self.currency.format(amount),
+            });
+        }
+        *self.balance_mut(account) -= amount;
+        Ok(())
+    }
+
+    fn record(&mut self, entry: TransactionEntry, kind: TransactionKind, to_account: Option<Account>) -> Transaction {
+        let transaction = Transaction {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: self.campaign_id.clone(),
+            kind,
+            account: entry.account,
+            to_account,
+            amount: entry.amount,
+            description: entry.description,
+            category: entry.category.unwrap_or_else(|| "general".to_string()),
+            session_id: entry.session_id,
+            timestamp: Utc::now(),
+        };
+        self.transactions.push(transaction.clone());
+        transaction
+    }
+}
+
+/// Details of a deposit, expense, or transfer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionEntry {
+    pub account: Account,
+    /// In the smallest unit
+    pub amount: i64,
+    pub description: String,
+    pub category: Option<String>,
+    pub session_id: Option<String>,
+}
+
+impl TransactionEntry {
+    pub fn new(account: Account, amount: i64, description: &str) -> Self {
+        Self {
+            account,
+            amount,
+            description: description.to_string(),
+            category: None,
+            session_id: None,
+        }
+    }
+
+    /// Builder: set category
+    pub fn in_category(mut self, category: &str) -> Self {
+        self.category = Some(category.to_string());
+        self
+    }
+
+    /// Builder: link to a session
+    pub fn in_session(mut self, session_id: &str) -> Self {
+        self.session_id = Some(session_id.to_string());
+        self
+    }
+}
+
+// ============================================================================
+// Loot
+// ============================================================================
+
+/// Coins found in loot text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootCoins {
+    /// The text the amount came from, e.g. "4d6 x 100 gp"
+    pub source: String,
+    pub denomination: String,
+    pub count: i64,
+    /// In the smallest unit
+    pub value: i64,
+}
+
+/// Loot text split into coins and items
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParsedLoot {
+    pub coins: Vec<LootCoins>,
+    pub items: Vec<String>,
+    /// Total coin value in the smallest unit
+    pub total: i64,
+}
+
+fn coin_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)^(?:(\d+d\d+(?:\s*[+-]\s*\d+)?)(?:\s*[x×*]\s*([\d,]+))?|([\d,]+))\s*([^\d\s]+(?:\s+[a-z]+)?)\.?$")
+            .expect("coin pattern is valid")
+    })
+}
+
+fn parse_number(s: &str) -> Option<i64> {
+    s.replace(',', "").parse().ok()
+}
+
+/// Split loot text, such as a treasure table result, into coins and items.
+/// Dice in coin amounts ("3d6 x 10 sp") are rolled with `rng`.
+pub fn parse_loot<R: Rng>(text: &str, currency: &CurrencySystem, rng: &mut R) -> ParsedLoot {
+    static SEPARATOR: OnceLock<Regex> = OnceLock::new();
+    let separator = SEPARATOR.get_or_init(|| Regex::new(r"(?i)[;\n]|,\s+|\s+and\s+|\s+plus\s+").expect("separator is valid"));
+    let roller = DiceRoller::new();
+    let mut loot = ParsedLoot::default();
+
+    for segment in separator.split(text).map(str::trim).filter(|s| !s.is_empty()) {
+        let coins = coin_pattern().captures(segment).and_then(|caps| {
+            let denomination = currency.denomination(&caps[4])?;
+            let count = match (caps.get(1), caps.get(3)) {
+                (Some(dice), _) => {
+                    let notation = DiceNotation::parse(&dice.as_str().replace(' ', "")).ok()?;
+                    let multiplier = caps.get(2).and_then(|m| parse_number(m.as_str())).unwrap_or(1);
+                    i64::from(roller.roll_with_rng(&notation, rng).total.max(0)) * multiplier
+                }
+                (None, Some(flat)) => parse_number(flat.as_str())?,
+                _ => return None,
+            };
+            Some(LootCoins {
+                source: segment.to_string(),
+                denomination: denomination.code.clone(),
+                count,
+                value: count * denomination.value,
+            })
+        });
+        match coins {
+            Some(coins) => {
+                loot.total += coins.value;
+                loot.coins.push(coins);
+            }
+            None => loot.items.push(segment.to_string()),
+        }
+    }
+    loot
+}
+
+/// How a sum was split among the party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootDistribution {
+    pub total: i64,
+    /// Each character's cut, in the smallest unit
+    pub per_share: i64,
+    /// Party fund share plus the indivisible remainder
+    pub to_party: i64,
+    pub transactions: Vec<Transaction>,
+}
+
+// ============================================================================
+// Reports
+// ============================================================================
+
+/// Income and expenses under one heading
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpendingTotal {
+    pub key: String,
+    pub income: i64,
+    pub expenses: i64,
+}
+
+/// Where a campaign's money came from and went
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpendingReport {
+    pub campaign_id: String,
+    pub session_id: Option<String>,
+    pub total_income: i64,
+    pub total_expenses: i64,
+    pub net: i64,
+    /// `net` formatted as change, e.g. "12 gp 5 sp"
+    pub net_formatted: String,
+    pub by_category: Vec<SpendingTotal>,
+    /// Keyed by "party" or character ID
+    pub by_account: Vec<SpendingTotal>,
+    /// Keyed by session ID, or "unassigned"
+    pub by_session: Vec<SpendingTotal>,
+    pub party_funds: i64,
+    pub shares: HashMap<String, i64>,
+}
+
+fn add_to(totals: &mut BTreeMap<String, SpendingTotal>, key: &str, transaction: &Transaction) {
+    let total = totals.entry(key.to_string()).or_insert_with(|| SpendingTotal {
+        key: key.to_string(),
+        ..Default::default()
+    });
+    match transaction.kind {
+        TransactionKind::Income => total.income += transaction.amount,
+        TransactionKind::Expense => total.expenses += transaction.amount,
+        TransactionKind::Transfer => {}
+    }
+}
+
+// ============================================================================
+// Treasury Manager
+// ============================================================================
+
+pub struct TreasuryManager {
+    treasuries: RwLock<HashMap<String, Treasury>>,
+}
+
+impl Default for TreasuryManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreasuryManager {
+    pub fn new() -> Self {
+        Self {
+            treasuries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get a campaign's treasury, opening an empty one in the given game
+    /// system's currency if it has none
+    pub fn open_treasury(&self, campaign_id: &str, system: &str) -> Treasury {
+        self.treasuries
+            .write()
+            .unwrap()
+            .entry(campaign_id.to_string())
+            .or_insert_with(|| Treasury::new(campaign_id, CurrencySystem::for_system(system)))
+            .clone()
+    }
+
+    pub fn get_treasury(&self, campaign_id: &str) -> Option<Treasury> {
+        self.treasuries.read().unwrap().get(campaign_id).cloned()
+    }
+
+    /// Add money to an account
+    pub fn deposit(&self, campaign_id: &str, entry: TransactionEntry) -> Result<Transaction> {
+        Self::check_amount(entry.amount)?;
+        self.with_treasury_mut(campaign_id, |treasury| {
+            *treasury.balance_mut(&entry.account) += entry.amount;
+            Ok(treasury.record(entry, TransactionKind::Income, None))
+        })
+    }
+
+    /// Spend money from an account
+    pub fn withdraw(&self, campaign_id: &str, entry: TransactionEntry) -> Result<Transaction> {
+        Self::check_amount(entry.amount)?;
+        self.with_treasury_mut(campaign_id, |treasury| {
+            treasury.debit(&entry.account, entry.amount)?;
+            Ok(treasury.record(entry, TransactionKind::Expense, None))
+        })
+    }
+
+    /// Move money between accounts, e.g. a character paying into the party fund
+    pub fn transfer(&self, campaign_id: &str, entry: TransactionEntry, to: Account) -> Result<Transaction> {
+        Self::check_amount(entry.amount)?;
+        self.with_treasury_mut(campaign_id, |treasury| {
+            treasury.debit(&entry.account, entry.amount)?;
+            *treasury.balance_mut(&to) += entry.amount;
+            Ok(treasury.record(entry, TransactionKind::Transfer, Some(to)))
+        })
+    }
+
+    /// Split a sum evenly among characters, optionally keeping one share for
+    /// the party fund. Whatever doesn't divide evenly goes to the party fund.
+    pub fn distribute_loot(
+        &self,
+        campaign_id: &str,
+        total: i64,
+        character_ids: &[String],
+        party_share: bool,
+        description: &str,
+        session_id: Option<&str>,
+    ) -> Result<LootDistribution> {
+        Self::check_amount(total)?;
+        if character_ids.is_empty() {
+            return Err(TreasuryError::NoRecipients);
+        }
+        let shares = character_ids.len() as i64 + i64::from(party_share);
+        let per_share = total / shares;
+        let to_party = total - per_share * character_ids.len() as i64;
+
+        self.with_treasury_mut(campaign_id, |treasury| {
+            let recipients = character_ids
+                .iter()
+                .map(|id| (Account::character(id), per_share))
+                .chain(std::iter::once((Account::Party, to_party)))
+                .filter(|(_, amount)| *amount > 0);
+            let mut transactions = Vec::new();
+            for (account, amount) in recipients {
+                *treasury.balance_mut(&account) += amount;
+                let mut entry = TransactionEntry::new(account, amount, description).in_category("loot");
+                entry.session_id = session_id.map(str::to_string);
+                transactions.push(treasury.record(entry, TransactionKind::Income, None));
+            }
+            Ok(LootDistribution {
+                total,
+                per_share,
+                to_party,
+                transactions,
+            })
+        })
+    }
+
+    /// A campaign's transactions, oldest first, optionally for one session
+    pub fn list_transactions(&self, campaign_id: &str, session_id: Option<&str>) -> Vec<Transaction> {
+        self.get_treasury(campaign_id)
+            .map(|t| {
+                t.transactions
+                    .into_iter()
+                    .filter(|tx| session_id.is_none_or(|s| tx.session_id.as_deref() == Some(s)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Summarize income and spending, optionally for one session or since a
+    /// point in time
+    pub fn spending_report(
+        &self,
+        campaign_id: &str,
+        session_id: Option<&str>,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<SpendingReport> {
+        let treasury = self
+            .get_treasury(campaign_id)
+            .ok_or_else(|| TreasuryError::TreasuryNotFound(campaign_id.to_string()))?;
+
+        let mut by_category = BTreeMap::new();
+        let mut by_account = BTreeMap::new();
+        let mut by_session = BTreeMap::new();
+        let (mut income, mut expenses) = (0, 0);
+        let matching = treasury
+            .transactions
+            .iter()
+            .filter(|tx| session_id.is_none_or(|s| tx.session_id.as_deref() == Some(s)))
+            .filter(|tx| since.is_none_or(|since| tx.timestamp >= since));
+        for tx in matching {
+            match tx.kind {
+                TransactionKind::Income => income += tx.amount,
+                TransactionKind::Expense => expenses += tx.amount,
+                TransactionKind::Transfer => continue,
+            }
+            let account = match &tx.account {
+                Account::Party => "party",
+                Account::Character { character_id } => character_id.as_str(),
+            };
+            add_to(&mut by_category, &tx.category, tx);
+            add_to(&mut by_account, account, tx);
+            add_to(&mut by_session, tx.session_id.as_deref().unwrap_or("unassigned"), tx);
+        }
+
+        Ok(SpendingReport {
+            campaign_id: campaign_id.to_string(),
+            session_id: session_id.map(str::to_string),
+            total_income: income,
+            total_expenses: expenses,
+            net: income - expenses,
+            net_formatted: treasury.currency.format(income - expenses),
+            by_category: by_category.into_values().collect(),
+            by_account: by_account.into_values().collect(),
+            by_session: by_session.into_values().collect(),
+            party_funds: treasury.party_funds,
+            shares: treasury.shares,
+        })
+    }
+
+    /// Remove a campaign's treasury
+    pub fn delete_campaign_treasury(&self, campaign_id: &str) {
+        self.treasuries.write().unwrap().remove(campaign_id);
+    }
+
+    fn check_amount(amount: i64) -> Result<()> {
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn with_treasury_mut<T>(&self, campaign_id: &str, f: impl FnOnce(&mut Treasury) -> Result<T>) -> Result<T> {
+        let mut treasuries = self.treasuries.write().unwrap();
+        let treasury = treasuries
+            .get_mut(campaign_id)
+            .ok_or_else(|| TreasuryError::TreasuryNotFound(campaign_id.to_string()))?;
+        f(treasury)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn dnd() -> CurrencySystem {
+        CurrencySystem::for_system("dnd5e")
+    }
+
+    #[test]
+    fn test_currency_conversion_and_format() {
+        let currency = dnd();
+        assert_eq!(currency.to_base(3, None).unwrap(), 300);
+        assert_eq!(currency.convert(25, "sp", "gp").unwrap(), (2, 50));
+        assert_eq!(currency.convert(1, "gold pieces", "cp").unwrap(), (100, 0));
+        assert_eq!(currency.format(1255), "1 pp 2 gp 5 sp 5 cp");
+        assert_eq!(currency.format(-50), "-5 sp");
+        assert!(currency.convert(1, "gp", "zorkmid").is_err());
+
+        let warhammer = CurrencySystem::for_system("wfrp");
+        assert_eq!(warhammer.convert(1, "gc", "d").unwrap(), (240, 0));
+    }
+
+    #[test]
+    fn test_parse_loot_rolls_coins_and_keeps_items() {
+        let currency = dnd();
+        let mut rng = StdRng::seed_from_u64(7);
+        let loot = parse_loot(
+            "2d6 x 100 cp, 1,500 gp; a silver chalice and 3 gems (50 gp each)",
+            &currency,
+            &mut rng,
+        );
+
+        assert_eq!(loot.coins.len(), 2);
+        let copper = &loot.coins[0];
+        assert_eq!(copper.denomination, "cp");
+        assert!((200..=1200).contains(&copper.count));
+        assert_eq!(copper.count % 100, 0);
+        assert_eq!(loot.coins[1].value, 150_000);
+        assert_eq!(loot.total, copper.value + 150_000);
+        assert_eq!(loot.items, vec!["a silver chalice", "3 gems (50 gp each)"]);
+    }
+
+    #[test]
+    fn test_distribute_loot_gives_remainder_to_party() {
+        let manager = TreasuryManager::new();
+        manager.open_treasury("camp-1", "dnd5e");
+        let ids = vec!["pc-1".to_string(), "pc-2".to_string(), "pc-3".to_string()];
+
+        let split = manager
+            .distribute_loot("camp-1", 1000, &ids, false, "Dragon hoard", Some("session-1"))
+            .unwrap();
+        assert_eq!(split.per_share, 333);
+        assert_eq!(split.to_party, 1);
+        assert_eq!(split.transactions.len(), 4);
+
+        let split = manager
+            .distribute_loot("camp-1", 1000, &ids, true, "Bandit camp", None)
+            .unwrap();
+        assert_eq!(split.per_share, 250);
+        assert_eq!(split.to_party, 250);
+
+        let treasury = manager.get_treasury("camp-1").unwrap();
+        assert_eq!(treasury.party_funds, 251);
+        assert_eq!(treasury.balance(&Account::character("pc-1")), 583);
+        assert!(matches!(
+            manager.distribute_loot("camp-1", 10, &[], true, "Nothing", None),
+            Err(TreasuryError::NoRecipients)
+        ));
+    }
+
+    #[test]
+    fn test_withdraw_and_transfer_check_funds() {
+        let manager = TreasuryManager::new();
+        manager.open_treasury("camp-1", "dnd5e");
+        manager
+            .deposit("camp-1", TransactionEntry::new(Account::character("pc-1"), 500, "Reward"))
+            .unwrap();
+
+        let err = manager
+            .withdraw("camp-1", TransactionEntry::new(Account::Party, 100, "Rations"))
+            .unwrap_err();
+        assert!(matches!(err, TreasuryError::InsufficientFunds { .. }));
+
+        manager
+            .transfer(
+                "camp-1",
+                TransactionEntry::new(Account::character("pc-1"), 300, "Pooling funds"),
+                Account::Party,
+            )
+            .unwrap();
+        manager
+            .withdraw("camp-1", TransactionEntry::new(Account::Party, 100, "Rations").in_category("supplies"))
+            .unwrap();
+
+        let treasury = manager.get_treasury("camp-1").unwrap();
+        assert_eq!(treasury.party_funds, 200);
+        assert_eq!(treasury.balance(&Account::character("pc-1")), 200);
+        assert!(manager
+            .deposit("camp-1", TransactionEntry::new(Account::Party, 0, "Nothing"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_spending_report_groups_transactions() {
+        let manager = TreasuryManager::new();
+        manager.open_treasury("camp-1", "dnd5e");
+        manager
+            .deposit(
+                "camp-1",
+                TransactionEntry::new(Account::Party, 1000, "Quest reward").in_session("session-1"),
+            )
+            .unwrap();
+        manager
+            .withdraw(
+                "camp-1",
+                TransactionEntry::new(Account::Party, 250, "Inn")
+                    .in_category("lodging")
+                    .in_session("session-2"),
+            )
+            .unwrap();
+
+        let report = manager.spending_report("camp-1", None, None).unwrap();
+        assert_eq!(report.total_income, 1000);
+        assert_eq!(report.total_expenses, 250);
+        assert_eq!(report.net_formatted, "7 gp 5 sp");
+        assert_eq!(report.by_category.len(), 2);
+        assert_eq!(report.by_session.len(), 2);
+
+        let session = manager.spending_report("camp-1", Some("session-2"), None).unwrap();
+        assert_eq!(session.total_income, 0);
+        assert_eq!(session.total_expenses, 250);
+        assert!(manager.spending_report("camp-2", None, None).is_err());
+    }
+}
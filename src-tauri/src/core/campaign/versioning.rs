@@ -1,7 +1,8 @@
 //! Campaign Versioning Module (TASK-006)
 //!
 //! Provides granular versioning for campaign data with diff tracking,
-//! rollback capabilities, and version history management.
+//! rollback capabilities, and version history management. Snapshots are
+//! stored as content-addressed deltas between periodic full checkpoints.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -320,6 +321,332 @@ impl CampaignDiff {
     }
 }
 
+// ============================================================================
+// Snapshot Storage
+// ============================================================================
+
+/// How a version's data is stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SnapshotContent {
+    /// A complete copy of the data (a checkpoint)
+    Full { blob: String },
+    /// A patch against another version's data
+    Delta { base_version_id: String, blob: String },
+}
+
+impl SnapshotContent {
+    fn blob(&self) -> &str {
+        match self {
+            Self::Full { blob } | Self::Delta { blob, .. } => blob,
+        }
+    }
+}
+
+/// One structural change in a delta
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum PatchOp {
+    /// Replace or insert the value at a path (an empty path is the root)
+    Set { path: Vec<String>, value: serde_json::Value },
+    /// Remove an object key
+    Remove { path: Vec<String> },
+    /// Extend an array
+    Append { path: Vec<String>, values: Vec<serde_json::Value> },
+}
+
+/// Collect the patch that turns `from` into `to`
+fn compute_patch(from: &serde_json::Value, to: &serde_json::Value, path: &mut Vec<String>, ops: &mut Vec<PatchOp>) {
+    use serde_json::Value;
+
+    match (from, to) {
+        (Value::Object(from_map), Value::Object(to_map)) => {
+            for (key, from_val) in from_map {
+                path.push(key.clone());
+                match to_map.get(key) {
+                    Some(to_val) if to_val != from_val => compute_patch(from_val, to_val, path, ops),
+                    Some(_) => {}
+                    None => ops.push(PatchOp::Remove { path: path.clone() }),
+                }
+                path.pop();
+            }
+            for (key, to_val) in to_map {
+                if !from_map.contains_key(key) {
+                    path.push(key.clone());
+                    ops.push(PatchOp::Set {
+                        path: path.clone(),
+                        value: to_val.clone(),
+                    });
+                    path.pop();
+                }
+            }
+        }
+        // Appending is the common case for notes, events, and logs
+        (Value::Array(from_arr), Value::Array(to_arr))
+            if to_arr.len() > from_arr.len() && to_arr[..from_arr.len()] == from_arr[..] =>
+        {
+            ops.push(PatchOp::Append {
+                path: path.clone(),
+                values: to_arr[from_arr.len()..].to_vec(),
+            });
+        }
+        _ => {
+            if from != to {
+                ops.push(PatchOp::Set {
+                    path: path.clone(),
+                    value: to.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Walk to the value at `path`
+fn value_at<'a>(root: &'a mut serde_json::Value, path: &[String]) -> Option<&'a mut serde_json::Value> {
+    path.iter().try_fold(root, |value, key| value.as_object_mut()?.get_mut(key))
+}
+
+fn apply_patch(root: &mut serde_json::Value, ops: &[PatchOp]) -> Result<()> {
+    let broken = |path: &[String]| VersionError::SerializationError(format!("Patch path not found: {}", path.join(".")));
+
+    for op in ops {
+        match op {
+            PatchOp::Set { path, value } => match path.split_last() {
+                None => *root = value.clone(),
+                Some((key, parent)) => {
+                    value_at(root, parent)
+                        .and_then(|v| v.as_object_mut())
+                        .ok_or_else(|| broken(path))?
+                        .insert(key.clone(), value.clone());
+                }
+            },
+            PatchOp::Remove { path } => {
+                let (key, parent) = path.split_last().ok_or_else(|| broken(path))?;
+                value_at(root, parent)
+                    .and_then(|v| v.as_object_mut())
+                    .ok_or_else(|| broken(path))?
+                    .remove(key);
+            }
+            PatchOp::Append { path, values } => {
+                value_at(root, path)
+                    .and_then(|v| v.as_array_mut())
+                    .ok_or_else(|| broken(path))?
+                    .extend(values.iter().cloned());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Snapshots are stored as compact JSON so deltas rebuild them exactly;
+/// anything that isn't JSON is kept as-is
+fn canonicalize(data: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(data)
+        .ok()
+        .and_then(|value| serde_json::to_string(&value).ok())
+        .unwrap_or_else(|| data.to_string())
+}
+
+/// Content address of a blob
+fn blob_key(data: &str) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(data.as_bytes()))
+}
+
+/// Storage usage for versions
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SnapshotStorageStats {
+    /// Campaign the stats cover, or `None` for all campaigns
+    pub campaign_id: Option<String>,
+    pub version_count: usize,
+    pub full_checkpoints: usize,
+    pub deltas: usize,
+    /// Size of the versions if each were stored as a full copy
+    pub logical_bytes: usize,
+    /// Size of the blobs the versions use (shared blobs counted once)
+    pub stored_bytes: usize,
+    /// Blobs no version uses any more
+    pub garbage_blobs: usize,
+    pub garbage_bytes: usize,
+    /// Most deltas applied to rebuild any one version
+    pub longest_chain: usize,
+}
+
+/// What a garbage collection pass removed
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GarbageCollectionReport {
+    pub blobs_removed: usize,
+    pub bytes_freed: usize,
+}
+
+/// Version metadata plus where its data lives
+#[derive(Debug, Clone)]
+struct StoredVersion {
+    /// Metadata; `data_snapshot` is left empty
+    version: CampaignVersion,
+    content: SnapshotContent,
+}
+
+#[derive(Debug, Default)]
+struct VersionStore {
+    /// Campaign ID -> versions, ordered by version_number
+    versions: HashMap<String, Vec<StoredVersion>>,
+    /// Content-addressed snapshot and patch blobs
+    blobs: HashMap<String, String>,
+}
+
+impl VersionStore {
+    fn find(&self, campaign_id: &str, version_id: &str) -> Option<&StoredVersion> {
+        self.versions
+            .get(campaign_id)
+            .and_then(|versions| versions.iter().find(|v| v.version.id == version_id))
+    }
+
+    fn put_blob(&mut self, data: String) -> String {
+        let key = blob_key(&data);
+        self.blobs.entry(key.clone()).or_insert(data);
+        key
+    }
+
+    fn blob(&self, key: &str) -> Result<&String> {
+        self.blobs
+            .get(key)
+            .ok_or_else(|| VersionError::SerializationError(format!("Missing snapshot blob {}", key)))
+    }
+
+    /// Number of deltas between a version and its checkpoint
+    fn chain_length(&self, campaign_id: &str, version_id: &str) -> usize {
+        let mut length = 0;
+        let mut current = self.find(campaign_id, version_id);
+        while let Some(SnapshotContent::Delta { base_version_id, .. }) = current.map(|v| &v.content) {
+            length += 1;
+            current = self.find(campaign_id, base_version_id);
+        }
+        length
+    }
+
+    /// Rebuild a version's data from its checkpoint and deltas
+    fn materialize(&self, campaign_id: &str, version_id: &str) -> Result<String> {
+        let mut patches: Vec<&str> = Vec::new();
+        let mut current = self
+            .find(campaign_id, version_id)
+            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
+        loop {
+            match &current.content {
+                SnapshotContent::Full { blob } => {
+                    let base = self.blob(blob)?;
+                    if patches.is_empty() {
+                        return Ok(base.clone());
+                    }
+                    let mut value: serde_json::Value = serde_json::from_str(base)
+                        .map_err(|e| VersionError::SerializationError(e.to_string()))?;
+                    for blob in patches.iter().rev() {
+                        let ops: Vec<PatchOp> = serde_json::from_str(self.blob(blob)?)
+                            .map_err(|e| VersionError::SerializationError(e.to_string()))?;
+                        apply_patch(&mut value, &ops)?;
+                    }
+                    return serde_json::to_string(&value).map_err(|e| VersionError::SerializationError(e.to_string()));
+                }
+                SnapshotContent::Delta { base_version_id, blob } => {
+                    patches.push(blob);
+                    current = self
+                        .find(campaign_id, base_version_id)
+                        .ok_or_else(|| VersionError::NotFound(base_version_id.clone()))?;
+                }
+            }
+        }
+    }
+
+    /// Store data as a delta against `base`, or as a full checkpoint when
+    /// the chain is long, the delta wouldn't be smaller, or the data isn't
+    /// JSON
+    fn encode(&mut self, campaign_id: &str, data: &str, base: Option<&str>, checkpoint_interval: usize) -> SnapshotContent {
+        let delta = base
+            .filter(|base| self.chain_length(campaign_id, base) + 1 < checkpoint_interval.max(1))
+            .and_then(|base| {
+                let base_data = self.materialize(campaign_id, base).ok()?;
+                let from: serde_json::Value = serde_json::from_str(&base_data).ok()?;
+                let to: serde_json::Value = serde_json::from_str(data).ok()?;
+                let mut ops = Vec::new();
+                compute_patch(&from, &to, &mut Vec::new(), &mut ops);
+
+                // Only keep deltas that rebuild the data exactly
+                let mut rebuilt = from;
+                apply_patch(&mut rebuilt, &ops).ok()?;
+                if serde_json::to_string(&rebuilt).ok()? != data {
+                    return None;
+                }
+                let patch = serde_json::to_string(&ops).ok()?;
+                (patch.len() < data.len()).then(|| (base.to_string(), patch))
+            });
+
+        match delta {
+            Some((base_version_id, patch)) => SnapshotContent::Delta {
+                base_version_id,
+                blob: self.put_blob(patch),
+            },
+            None => SnapshotContent::Full {
+                blob: self.put_blob(data.to_string()),
+            },
+        }
+    }
+
+    /// Remove a version, re-encoding the versions built on it against its
+    /// own base so their chains stay intact
+    fn remove(&mut self, campaign_id: &str, version_id: &str, checkpoint_interval: usize) -> Result<()> {
+        let removed = self
+            .find(campaign_id, version_id)
+            .cloned()
+            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
+        let new_base = match &removed.content {
+            SnapshotContent::Delta { base_version_id, .. } => Some(base_version_id.clone()),
+            SnapshotContent::Full { .. } => None,
+        };
+
+        let dependents: Vec<(String, String)> = self
+            .versions
+            .get(campaign_id)
+            .into_iter()
+            .flatten()
+            .filter(|v| matches!(&v.content, SnapshotContent::Delta { base_version_id, .. } if base_version_id == version_id))
+            .map(|v| Ok((v.version.id.clone(), self.materialize(campaign_id, &v.version.id)?)))
+            .collect::<Result<_>>()?;
+
+        if let Some(versions) = self.versions.get_mut(campaign_id) {
+            versions.retain(|v| v.version.id != version_id);
+        }
+        for (dependent_id, data) in dependents {
+            let content = self.encode(campaign_id, &data, new_base.as_deref(), checkpoint_interval);
+            if let Some(stored) = self
+                .versions
+                .get_mut(campaign_id)
+                .and_then(|versions| versions.iter_mut().find(|v| v.version.id == dependent_id))
+            {
+                stored.content = content;
+            }
+        }
+        Ok(())
+    }
+
+    fn with_metadata_mut(
+        &mut self,
+        campaign_id: &str,
+        version_id: &str,
+        f: impl FnOnce(&mut CampaignVersion),
+    ) -> Result<()> {
+        let campaign_versions = self
+            .versions
+            .get_mut(campaign_id)
+            .ok_or_else(|| VersionError::CampaignNotFound(campaign_id.to_string()))?;
+        let stored = campaign_versions
+            .iter_mut()
+            .find(|v| v.version.id == version_id)
+            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
+        f(&mut stored.version);
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Version Manager
 // ============================================================================
@@ -333,6 +660,9 @@ pub struct VersionManagerConfig {
     pub max_auto_versions: usize,
     /// Whether to compress version data
     pub compress_data: bool,
+    /// Store a full checkpoint at least every this many versions; the
+    /// versions in between are stored as deltas
+    pub checkpoint_interval: usize,
 }
 
 impl Default for VersionManagerConfig {
@@ -341,14 +671,19 @@ impl Default for VersionManagerConfig {
             max_versions_per_campaign: 100,
             max_auto_versions: 20,
             compress_data: false,
+            checkpoint_interval: 10,
         }
     }
 }
 
-/// Manages campaign versions with CRUD operations
+/// Manages campaign versions with CRUD operations.
+///
+/// Version data is stored content-addressed: periodic full checkpoints with
+/// structural deltas in between. Blobs left behind by deleted versions are
+/// reclaimed by [`VersionManager::collect_garbage`].
 pub struct VersionManager {
-    /// Campaign ID -> Vec<Version> (ordered by version_number)
-    versions: RwLock<HashMap<String, Vec<CampaignVersion>>>,
+    /// Version metadata and snapshot blobs
+    store: RwLock<VersionStore>,
     /// Campaign ID -> current version number counter
     version_counters: RwLock<HashMap<String, u64>>,
     /// Configuration
@@ -365,12 +700,26 @@ impl VersionManager {
     /// Create a new version manager
     pub fn new(config: VersionManagerConfig) -> Self {
         Self {
-            versions: RwLock::new(HashMap::new()),
+            store: RwLock::new(VersionStore::default()),
             version_counters: RwLock::new(HashMap::new()),
             config,
         }
     }
 
+    /// Attach the rebuilt data to a stored version
+    fn materialize(store: &VersionStore, stored: &StoredVersion) -> Option<CampaignVersion> {
+        match store.materialize(&stored.version.campaign_id, &stored.version.id) {
+            Ok(data) => Some(CampaignVersion {
+                data_snapshot: data,
+                ..stored.version.clone()
+            }),
+            Err(e) => {
+                log::warn!("Could not rebuild version {}: {}", stored.version.id, e);
+                None
+            }
+        }
+    }
+
     // ========================================================================
     // Version CRUD
     // ========================================================================
@@ -383,6 +732,33 @@ impl VersionManager {
         version_type: VersionType,
         data_snapshot: &str,
     ) -> Result<CampaignVersion> {
+        let data_snapshot = canonicalize(data_snapshot);
+        let mut store = self.store.write().unwrap();
+
+        // Check max versions
+        let existing = store.versions.get(campaign_id).map(|v| v.len()).unwrap_or(0);
+        if self.config.max_versions_per_campaign > 0 && existing >= self.config.max_versions_per_campaign {
+            return Err(VersionError::MaxVersionsReached);
+        }
+
+        // Prune old auto-versions if needed
+        if version_type == VersionType::Auto {
+            let autos: Vec<String> = store
+                .versions
+                .get(campaign_id)
+                .into_iter()
+                .flatten()
+                .filter(|v| v.version.version_type == VersionType::Auto)
+                .map(|v| v.version.id.clone())
+                .collect();
+            if autos.len() >= self.config.max_auto_versions {
+                // Remove oldest auto-version
+                if let Some(oldest) = autos.first() {
+                    store.remove(campaign_id, oldest, self.config.checkpoint_interval)?;
+                }
+            }
+        }
+
         // Get next version number
         let version_number = {
             let mut counters = self.version_counters.write().unwrap();
@@ -392,119 +768,170 @@ impl VersionManager {
         };
 
         // Get parent version
-        let parent_version_id = self
-            .get_latest_version(campaign_id)
-            .map(|v| v.id.clone());
+        let parent_version_id = store
+            .versions
+            .get(campaign_id)
+            .and_then(|versions| versions.last())
+            .map(|v| v.version.id.clone());
 
         let version = CampaignVersion::new(
             campaign_id,
             version_number,
             description,
-            version_type.clone(),
-            data_snapshot,
-            parent_version_id,
+            version_type,
+            &data_snapshot,
+            parent_version_id.clone(),
         );
 
-        // Store version
-        {
-            let mut versions = self.versions.write().unwrap();
-            let campaign_versions = versions.entry(campaign_id.to_string()).or_default();
-
-            // Check max versions
-            if self.config.max_versions_per_campaign > 0
-                && campaign_versions.len() >= self.config.max_versions_per_campaign
-            {
-                return Err(VersionError::MaxVersionsReached);
-            }
-
-            // Prune old auto-versions if needed
-            if version_type == VersionType::Auto {
-                let auto_count = campaign_versions
-                    .iter()
-                    .filter(|v| v.version_type == VersionType::Auto)
-                    .count();
-
-                if auto_count >= self.config.max_auto_versions {
-                    // Remove oldest auto-version
-                    if let Some(pos) = campaign_versions
-                        .iter()
-                        .position(|v| v.version_type == VersionType::Auto)
-                    {
-                        campaign_versions.remove(pos);
-                    }
-                }
-            }
-
-            campaign_versions.push(version.clone());
-        }
+        let content = store.encode(
+            campaign_id,
+            &data_snapshot,
+            parent_version_id.as_deref(),
+            self.config.checkpoint_interval,
+        );
+        store
+            .versions
+            .entry(campaign_id.to_string())
+            .or_default()
+            .push(StoredVersion {
+                version: CampaignVersion {
+                    data_snapshot: String::new(),
+                    ..version.clone()
+                },
+                content,
+            });
 
         Ok(version)
     }
 
     /// Get a specific version by ID
     pub fn get_version(&self, campaign_id: &str, version_id: &str) -> Option<CampaignVersion> {
-        self.versions
-            .read()
-            .unwrap()
-            .get(campaign_id)
-            .and_then(|versions| versions.iter().find(|v| v.id == version_id).cloned())
+        let store = self.store.read().unwrap();
+        let stored = store.find(campaign_id, version_id)?;
+        Self::materialize(&store, stored)
     }
 
     /// Get the latest version for a campaign
     pub fn get_latest_version(&self, campaign_id: &str) -> Option<CampaignVersion> {
-        self.versions
-            .read()
-            .unwrap()
-            .get(campaign_id)
-            .and_then(|versions| versions.last().cloned())
+        let store = self.store.read().unwrap();
+        let stored = store.versions.get(campaign_id)?.last()?;
+        Self::materialize(&store, stored)
     }
 
     /// Get a version by version number
     pub fn get_version_by_number(&self, campaign_id: &str, version_number: u64) -> Option<CampaignVersion> {
-        self.versions
-            .read()
-            .unwrap()
-            .get(campaign_id)
-            .and_then(|versions| {
-                versions
-                    .iter()
-                    .find(|v| v.version_number == version_number)
-                    .cloned()
-            })
+        let store = self.store.read().unwrap();
+        let stored = store
+            .versions
+            .get(campaign_id)?
+            .iter()
+            .find(|v| v.version.version_number == version_number)?;
+        Self::materialize(&store, stored)
     }
 
     /// List all versions for a campaign (returns summaries)
     pub fn list_versions(&self, campaign_id: &str) -> Vec<VersionSummary> {
-        self.versions
+        self.store
             .read()
             .unwrap()
+            .versions
             .get(campaign_id)
-            .map(|versions| versions.iter().map(VersionSummary::from).collect())
+            .map(|versions| versions.iter().map(|v| VersionSummary::from(&v.version)).collect())
             .unwrap_or_default()
     }
 
     /// Delete a specific version
     pub fn delete_version(&self, campaign_id: &str, version_id: &str) -> Result<()> {
-        let mut versions = self.versions.write().unwrap();
-        let campaign_versions = versions
-            .get_mut(campaign_id)
-            .ok_or_else(|| VersionError::CampaignNotFound(campaign_id.to_string()))?;
-
-        let pos = campaign_versions
-            .iter()
-            .position(|v| v.id == version_id)
-            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
-
-        campaign_versions.remove(pos);
-        Ok(())
+        let mut store = self.store.write().unwrap();
+        if !store.versions.contains_key(campaign_id) {
+            return Err(VersionError::CampaignNotFound(campaign_id.to_string()));
+        }
+        store.remove(campaign_id, version_id, self.config.checkpoint_interval)
     }
 
     /// Delete all versions for a campaign
     pub fn delete_all_versions(&self, campaign_id: &str) {
-        self.versions.write().unwrap().remove(campaign_id);
+        self.store.write().unwrap().versions.remove(campaign_id);
         self.version_counters.write().unwrap().remove(campaign_id);
     }
 
+    // ========================================================================
+    // Storage
+    // ========================================================================
+
+    /// How a version's data is stored
+    pub fn get_snapshot_content(&self, campaign_id: &str, version_id: &str) -> Option<SnapshotContent> {
+        self.store
+            .read()
+            .unwrap()
+            .find(campaign_id, version_id)
+            .map(|v| v.content.clone())
+    }
+
+    /// Storage usage for one campaign, or all campaigns
+    pub fn storage_stats(&self, campaign_id: Option<&str>) -> SnapshotStorageStats {
+        let store = self.store.read().unwrap();
+        let mut stats = SnapshotStorageStats {
+            campaign_id: campaign_id.map(str::to_string),
+            ..Default::default()
+        };
+
+        let mut used_blobs = std::collections::HashSet::new();
+        let mut all_used = std::collections::HashSet::new();
+        for (id, versions) in &store.versions {
+            let included = campaign_id.is_none_or(|c| c == id.as_str());
+            for stored in versions {
+                all_used.insert(stored.content.blob());
+                if !included {
+                    continue;
+                }
+                used_blobs.insert(stored.content.blob());
+                stats.version_count += 1;
+                stats.logical_bytes += stored.version.size_bytes;
+                match stored.content {
+                    SnapshotContent::Full { .. } => stats.full_checkpoints += 1,
+                    SnapshotContent::Delta { .. } => stats.deltas += 1,
+                }
+                stats.longest_chain = stats.longest_chain.max(store.chain_length(id, &stored.version.id));
+            }
+        }
+
+        stats.stored_bytes = used_blobs
+            .iter()
+            .filter_map(|key| store.blobs.get(*key))
+            .map(|blob| blob.len())
+            .sum();
+        for (key, blob) in &store.blobs {
+            if !all_used.contains(key.as_str()) {
+                stats.garbage_blobs += 1;
+                stats.garbage_bytes += blob.len();
+            }
+        }
+        stats
+    }
+
+    /// Drop blobs that no version uses any more
+    pub fn collect_garbage(&self) -> GarbageCollectionReport {
+        let mut store = self.store.write().unwrap();
+        let used: std::collections::HashSet<String> = store
+            .versions
+            .values()
+            .flatten()
+            .map(|v| v.content.blob().to_string())
+            .collect();
+
+        let mut report = GarbageCollectionReport::default();
+        store.blobs.retain(|key, blob| {
+            let keep = used.contains(key);
+            if !keep {
+                report.blobs_removed += 1;
+                report.bytes_freed += blob.len();
+            }
+            keep
+        });
+        report
+    }
+
     // ========================================================================
     // Comparison and Diff
     // ========================================================================
@@ -580,9 +1007,10 @@ impl VersionManager {
 
     /// Get the version count for a campaign
     pub fn version_count(&self, campaign_id: &str) -> usize {
-        self.versions
+        self.store
             .read()
             .unwrap()
+            .versions
             .get(campaign_id)
             .map(|v| v.len())
             .unwrap_or(0)
@@ -594,53 +1022,32 @@ impl VersionManager {
 
     /// Add a tag to a version
     pub fn add_tag(&self, campaign_id: &str, version_id: &str, tag: &str) -> Result<()> {
-        let mut versions = self.versions.write().unwrap();
-        let campaign_versions = versions
-            .get_mut(campaign_id)
-            .ok_or_else(|| VersionError::CampaignNotFound(campaign_id.to_string()))?;
-
-        let version = campaign_versions
-            .iter_mut()
-            .find(|v| v.id == version_id)
-            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
-
-        if !version.tags.contains(&tag.to_string()) {
-            version.tags.push(tag.to_string());
-        }
-
-        Ok(())
+        self.store
+            .write()
+            .unwrap()
+            .with_metadata_mut(campaign_id, version_id, |version| {
+                if !version.tags.contains(&tag.to_string()) {
+                    version.tags.push(tag.to_string());
+                }
+            })
     }
 
     /// Remove a tag from a version
     pub fn remove_tag(&self, campaign_id: &str, version_id: &str, tag: &str) -> Result<()> {
-        let mut versions = self.versions.write().unwrap();
-        let campaign_versions = versions
-            .get_mut(campaign_id)
-            .ok_or_else(|| VersionError::CampaignNotFound(campaign_id.to_string()))?;
-
-        let version = campaign_versions
-            .iter_mut()
-            .find(|v| v.id == version_id)
-            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
-
-        version.tags.retain(|t| t != tag);
-        Ok(())
+        self.store
+            .write()
+            .unwrap()
+            .with_metadata_mut(campaign_id, version_id, |version| version.tags.retain(|t| t != tag))
     }
 
     /// Mark a version as a milestone
     pub fn mark_as_milestone(&self, campaign_id: &str, version_id: &str) -> Result<()> {
-        let mut versions = self.versions.write().unwrap();
-        let campaign_versions = versions
-            .get_mut(campaign_id)
-            .ok_or_else(|| VersionError::CampaignNotFound(campaign_id.to_string()))?;
-
-        let version = campaign_versions
-            .iter_mut()
-            .find(|v| v.id == version_id)
-            .ok_or_else(|| VersionError::NotFound(version_id.to_string()))?;
-
-        version.version_type = VersionType::Milestone;
-        Ok(())
+        self.store
+            .write()
+            .unwrap()
+            .with_metadata_mut(campaign_id, version_id, |version| {
+                version.version_type = VersionType::Milestone;
+            })
     }
 }
 
@@ -783,4 +1190,109 @@ mod tests {
         let retrieved = manager.get_version("camp-1", &v.id).unwrap();
         assert!(!retrieved.tags.contains(&"important".to_string()));
     }
+
+    fn campaign_with_notes(notes: usize) -> String {
+        let notes: Vec<String> = (0..notes).map(|i| format!("Session {} went well", i)).collect();
+        serde_json::json!({
+            "name": "Long Campaign",
+            "system": "D&D 5e",
+            "description": "A campaign that runs for years and years",
+            "notes": notes,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_versions_between_checkpoints_are_deltas() {
+        let manager = VersionManager::default();
+        let mut ids = Vec::new();
+        for i in 1..=12 {
+            let v = manager
+                .create_version("camp-1", &format!("v{}", i), VersionType::Manual, &campaign_with_notes(i * 5))
+                .unwrap();
+            ids.push(v.id);
+        }
+
+        // Checkpoint at 1 and 11, deltas in between
+        assert!(matches!(manager.get_snapshot_content("camp-1", &ids[0]), Some(SnapshotContent::Full { .. })));
+        assert!(matches!(manager.get_snapshot_content("camp-1", &ids[5]), Some(SnapshotContent::Delta { .. })));
+        assert!(matches!(manager.get_snapshot_content("camp-1", &ids[10]), Some(SnapshotContent::Full { .. })));
+
+        for (i, id) in ids.iter().enumerate() {
+            let version = manager.get_version("camp-1", id).unwrap();
+            assert!(version.verify_integrity());
+            assert_eq!(version.data_snapshot, campaign_with_notes((i + 1) * 5));
+        }
+
+        let stats = manager.storage_stats(Some("camp-1"));
+        assert_eq!(stats.version_count, 12);
+        assert_eq!(stats.full_checkpoints, 2);
+        assert_eq!(stats.deltas, 10);
+        assert_eq!(stats.longest_chain, 9);
+        assert!(stats.stored_bytes < stats.logical_bytes / 2);
+    }
+
+    #[test]
+    fn test_deleting_a_base_keeps_later_versions_intact() {
+        let manager = VersionManager::default();
+        let v1 = manager
+            .create_version("camp-1", "v1", VersionType::Manual, &campaign_with_notes(20))
+            .unwrap();
+        let v2 = manager
+            .create_version("camp-1", "v2", VersionType::Manual, &campaign_with_notes(21))
+            .unwrap();
+        let v3 = manager
+            .create_version("camp-1", "v3", VersionType::Manual, &campaign_with_notes(22))
+            .unwrap();
+
+        manager.delete_version("camp-1", &v2.id).unwrap();
+        assert!(matches!(
+            manager.get_snapshot_content("camp-1", &v3.id),
+            Some(SnapshotContent::Delta { base_version_id, .. }) if base_version_id == v1.id
+        ));
+        assert_eq!(manager.get_version("camp-1", &v3.id).unwrap().data_snapshot, campaign_with_notes(22));
+
+        manager.delete_version("camp-1", &v1.id).unwrap();
+        assert!(matches!(manager.get_snapshot_content("camp-1", &v3.id), Some(SnapshotContent::Full { .. })));
+        assert_eq!(manager.get_version("camp-1", &v3.id).unwrap().data_snapshot, campaign_with_notes(22));
+    }
+
+    #[test]
+    fn test_garbage_collection() {
+        let manager = VersionManager::default();
+        for i in 0..3 {
+            manager
+                .create_version("camp-1", "v", VersionType::Manual, &campaign_with_notes(10 + i))
+                .unwrap();
+        }
+        let kept = manager
+            .create_version("camp-2", "v", VersionType::Manual, &campaign_with_notes(10))
+            .unwrap();
+        assert_eq!(manager.storage_stats(None).garbage_blobs, 0);
+
+        manager.delete_all_versions("camp-1");
+        let stats = manager.storage_stats(None);
+        // camp-2 shares camp-1's first checkpoint, so only the deltas are garbage
+        assert_eq!(stats.garbage_blobs, 2);
+
+        let report = manager.collect_garbage();
+        assert_eq!(report.blobs_removed, 2);
+        assert!(report.bytes_freed > 0);
+        assert_eq!(manager.storage_stats(None).garbage_blobs, 0);
+        assert!(manager.get_version("camp-2", &kept.id).is_some());
+    }
+
+    #[test]
+    fn test_non_json_snapshots_are_stored_whole() {
+        let manager = VersionManager::default();
+        manager
+            .create_version("camp-1", "v1", VersionType::Manual, "plain text")
+            .unwrap();
+        let v2 = manager
+            .create_version("camp-1", "v2", VersionType::Manual, "plain text, edited")
+            .unwrap();
+
+        assert!(matches!(manager.get_snapshot_content("camp-1", &v2.id), Some(SnapshotContent::Full { .. })));
+        assert_eq!(manager.get_version("camp-1", &v2.id).unwrap().data_snapshot, "plain text, edited");
+    }
 }
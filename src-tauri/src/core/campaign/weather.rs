@@ -0,0 +1,683 @@
+//! Weather Module
+//!
+//! Procedural daily weather keyed to the campaign calendar and a location's
+//! climate. Weather fronts last several days and each day builds on the one
+//! before, so conditions drift rather than flip at random. Every report
+//! carries travel conditions, and severe weather can be written to the world
+//! event log.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+use thiserror::Error;
+
+use super::calendar::{CalendarDefinition, CalendarError};
+use super::world_state::{EventImpact, InGameDate, WorldEvent, WorldEventType};
+use crate::core::location_gen::LocationType;
+
+/// World event metadata key holding the location a weather event happened at
+pub const WEATHER_FIELD: &str = "weather_location_id";
+
+/// A previous day further back than this no longer influences the weather
+const MAX_PERSISTENCE_GAP: i64 = 7;
+
+/// Days generated ahead of a date with no history, so fronts are already underway
+const WARMUP_DAYS: i64 = 3;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum WeatherError {
+    #[error(transparent)]
+    Calendar(#[from] CalendarError),
+
+    #[error("Unknown climate zone: {0}")]
+    UnknownClimate(String),
+}
+
+pub type Result<T> = std::result::Result<T, WeatherError>;
+
+// ============================================================================
+// Climate
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClimateZone {
+    Arctic,
+    Temperate,
+    Arid,
+    Tropical,
+    Coastal,
+    Mountain,
+    Swamp,
+}
+
+impl ClimateZone {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "arctic" | "polar" | "tundra" => Some(Self::Arctic),
+            "temperate" => Some(Self::Temperate),
+            "arid" | "desert" => Some(Self::Arid),
+            "tropical" | "jungle" => Some(Self::Tropical),
+            "coastal" | "maritime" => Some(Self::Coastal),
+            "mountain" | "alpine" => Some(Self::Mountain),
+            "swamp" | "marsh" | "wetland" => Some(Self::Swamp),
+            _ => None,
+        }
+    }
+
+    /// Best guess at a location's climate from its type
+    pub fn for_location_type(location_type: &LocationType) -> Self {
+        match location_type {
+            LocationType::Desert => Self::Arid,
+            LocationType::Mountain => Self::Mountain,
+            LocationType::Coast | LocationType::Island => Self::Coastal,
+            LocationType::Swamp => Self::Swamp,
+            _ => Self::Temperate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Autumn,
+}
+
+impl Season {
+    /// Season from how far through its year a date falls; the year starts
+    /// in midwinter
+    pub fn for_date(calendar: &CalendarDefinition, date: &InGameDate) -> Result<Self> {
+        let fraction = calendar.day_of_year(date)? as f64 / calendar.year_length(date.year).max(1) as f64;
+        Ok(match fraction {
+            f if f < 1.0 / 6.0 => Self::Winter,
+            f if f < 5.0 / 12.0 => Self::Spring,
+            f if f < 2.0 / 3.0 => Self::Summer,
+            f if f < 11.0 / 12.0 => Self::Autumn,
+            _ => Self::Winter,
+        })
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Winter => 0,
+            Self::Spring => 1,
+            Self::Summer => 2,
+            Self::Autumn => 3,
+        }
+    }
+}
+
+/// Weather tendencies of a climate, with seasonal values in the order
+/// winter, spring, summer, autumn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClimateProfile {
+    pub zone: ClimateZone,
+    /// Average temperature in °C per season
+    pub seasonal_temperature_c: [i32; 4],
+    /// Day-to-day temperature variation in °C
+    pub temperature_swing_c: i32,
+    /// Chance (0-1) of precipitation per season
+    pub precipitation_chance: [f32; 4],
+    /// Chance (0-1) of a new front arriving on a calm day
+    pub front_chance: f32,
+    /// Chance (0-1) that a storm front turns severe
+    pub severe_chance: f32,
+    /// Chance (0-1) of fog on a dry day
+    pub fog_chance: f32,
+}
+
+impl ClimateProfile {
+    pub fn for_zone(zone: ClimateZone) -> Self {
+        let (temperature, swing, precipitation, front, severe, fog) = match zone {
+            ClimateZone::Arctic => ([-30, -10, 5, -12], 6, [0.3, 0.25, 0.3, 0.35], 0.25, 0.4, 0.05),
+            ClimateZone::Temperate => ([2, 12, 24, 12], 5, [0.35, 0.4, 0.3, 0.4], 0.2, 0.15, 0.1),
+            ClimateZone::Arid => ([12, 25, 38, 24], 6, [0.08, 0.05, 0.02, 0.06], 0.1, 0.3, 0.0),
+            ClimateZone::Tropical => ([24, 27, 30, 27], 3, [0.3, 0.5, 0.7, 0.5], 0.25, 0.25, 0.1),
+            ClimateZone::Coastal => ([6, 13, 21, 14], 3, [0.45, 0.4, 0.3, 0.45], 0.3, 0.2, 0.2),
+            ClimateZone::Mountain => ([-8, 4, 15, 3], 7, [0.4, 0.4, 0.35, 0.4], 0.25, 0.3, 0.15),
+            ClimateZone::Swamp => ([8, 18, 28, 18], 4, [0.4, 0.45, 0.5, 0.45], 0.2, 0.15, 0.3),
+        };
+        Self {
+            zone,
+            seasonal_temperature_c: temperature,
+            temperature_swing_c: swing,
+            precipitation_chance: precipitation,
+            front_chance: front,
+            severe_chance: severe,
+            fog_chance: fog,
+        }
+    }
+}
+
+impl Default for ClimateProfile {
+    fn default() -> Self {
+        Self::for_zone(ClimateZone::Temperate)
+    }
+}
+
+// ============================================================================
+// Weather Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FrontKind {
+    Warm,
+    Cold,
+    Storm,
+    HighPressure,
+}
+
+/// A weather front passing through over several days
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WeatherFront {
+    pub kind: FrontKind,
+    pub days_remaining: u8,
+    pub severe: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WeatherCondition {
+    Clear,
+    PartlyCloudy,
+    Overcast,
+    Fog,
+    Rain,
+    HeavyRain,
+    Thunderstorm,
+    Snow,
+    Blizzard,
+    Sandstorm,
+    Heatwave,
+    Frigid,
+}
+
+impl WeatherCondition {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Clear => "Clear",
+            Self::PartlyCloudy => "Partly cloudy",
+            Self::Overcast => "Overcast",
+            Self::Fog => "Fog",
+            Self::Rain => "Rain",
+            Self::HeavyRain => "Heavy rain",
+            Self::Thunderstorm => "Thunderstorm",
+            Self::Snow => "Snow",
+            Self::Blizzard => "Blizzard",
+            Self::Sandstorm => "Sandstorm",
+            Self::Heatwave => "Heatwave",
+            Self::Frigid => "Bitter cold",
+        }
+    }
+
+    fn is_precipitation(&self) -> bool {
+        matches!(
+            self,
+            Self::Rain | Self::HeavyRain | Self::Thunderstorm | Self::Snow | Self::Blizzard
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum WindLevel {
+    Calm,
+    Breeze,
+    Strong,
+    Gale,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Clear,
+    Reduced,
+    Poor,
+}
+
+/// How the weather affects overland travel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TravelConditions {
+    /// Multiplier on normal travel pace
+    pub speed_multiplier: f32,
+    pub visibility: Visibility,
+    pub hazards: Vec<String>,
+}
+
+/// The weather at a location on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherReport {
+    pub date: InGameDate,
+    pub location_id: String,
+    pub season: Season,
+    pub condition: WeatherCondition,
+    pub temperature_c: i32,
+    pub wind: WindLevel,
+    pub front: Option<WeatherFront>,
+    pub severe: bool,
+    pub travel: TravelConditions,
+    pub summary: String,
+}
+
+impl WeatherReport {
+    /// A world event for severe weather, `None` for ordinary days
+    pub fn to_world_event(&self, campaign_id: &str, location_name: &str) -> Option<WorldEvent> {
+        if !self.severe {
+            return None;
+        }
+        let impact = if self.front.as_ref().is_some_and(|f| f.severe) {
+            EventImpact::Regional
+        } else {
+            EventImpact::Local
+        };
+        let title = format!("{} at {}", self.condition.label(), location_name);
+        let mut event = WorldEvent::new(campaign_id, &title, &self.summary, self.date.clone())
+            .with_type(WorldEventType::Natural)
+            .with_impact(impact)
+            .at_locations(vec![self.location_id.clone()]);
+        event.consequences = self.travel.hazards.clone();
+        event
+            .metadata
+            .insert(WEATHER_FIELD.to_string(), serde_json::json!(self.location_id));
+        Some(event)
+    }
+}
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+/// Generate one day's weather from the climate, season, and the day before
+pub fn generate_day<R: Rng>(
+    climate: &ClimateProfile,
+    season: Season,
+    previous: Option<&WeatherReport>,
+    date: &InGameDate,
+    location_id: &str,
+    rng: &mut R,
+) -> WeatherReport {
+    let season_index = season.index();
+
+    // Fronts persist for several days before a new one can roll in
+    let front = match previous.and_then(|p| p.front.clone()) {
+        Some(front) if front.days_remaining > 1 => Some(WeatherFront {
+            days_remaining: front.days_remaining - 1,
+            ..front
+        }),
+        _ if rng.gen::<f32>() < climate.front_chance => {
+            let kind = [FrontKind::Warm, FrontKind::Cold, FrontKind::Storm, FrontKind::HighPressure][rng.gen_range(0..4)];
+            Some(WeatherFront {
+                kind,
+                days_remaining: rng.gen_range(1..=4),
+                severe: kind == FrontKind::Storm && rng.gen::<f32>() < climate.severe_chance,
+            })
+        }
+        _ => None,
+    };
+    let front_kind = front.as_ref().map(|f| f.kind);
+
+    let (front_temperature, front_precipitation, front_wind) = match front_kind {
+        Some(FrontKind::Warm) => (4, 0.1, 0),
+        Some(FrontKind::Cold) => (-6, 0.2, 15),
+        Some(FrontKind::Storm) => (-2, 0.5, 40),
+        Some(FrontKind::HighPressure) => (2, -0.3, -15),
+        None => (0, 0.0, 0),
+    };
+
+    // Temperature drifts from yesterday toward the seasonal mean
+    let target = climate.seasonal_temperature_c[season_index] + front_temperature;
+    let yesterday = previous.map(|p| p.temperature_c).unwrap_or(target);
+    let swing = climate.temperature_swing_c.max(0);
+    let temperature_c = (yesterday + target) / 2 + rng.gen_range(-swing..=swing);
+
+    let wet_yesterday = previous.is_some_and(|p| p.condition.is_precipitation());
+    let precipitation_chance = (climate.precipitation_chance[season_index]
+        + front_precipitation
+        + if wet_yesterday { 0.15 } else { 0.0 })
+    .clamp(0.0, 1.0);
+    let precipitating = rng.gen::<f32>() < precipitation_chance;
+
+    let severe_front = front.as_ref().is_some_and(|f| f.severe);
+    let wind = match rng.gen_range(0..100) + front_wind {
+        _ if severe_front => WindLevel::Gale,
+        w if w < 40 => WindLevel::Calm,
+        w if w < 75 => WindLevel::Breeze,
+        w if w < 95 => WindLevel::Strong,
+        _ => WindLevel::Gale,
+    };
+
+    let condition = if precipitating {
+        if temperature_c <= 0 {
+            if severe_front || wind == WindLevel::Gale {
+                WeatherCondition::Blizzard
+            } else {
+                WeatherCondition::Snow
+            }
+        } else if front_kind == Some(FrontKind::Storm) {
+            WeatherCondition::Thunderstorm
+        } else if rng.gen::<f32>() < 0.3 {
+            WeatherCondition::HeavyRain
+        } else {
+            WeatherCondition::Rain
+        }
+    } else if climate.zone == ClimateZone::Arid && wind >= WindLevel::Strong {
+        WeatherCondition::Sandstorm
+    } else if temperature_c >= 35 {
+        WeatherCondition::Heatwave
+    } else if temperature_c <= -20 {
+        WeatherCondition::Frigid
+    } else if rng.gen::<f32>() < climate.fog_chance {
+        WeatherCondition::Fog
+    } else if front_kind == Some(FrontKind::HighPressure) {
+        WeatherCondition::Clear
+    } else {
+        [WeatherCondition::Clear, WeatherCondition::PartlyCloudy, WeatherCondition::Overcast][rng.gen_range(0..3)]
+    };
+
+    let severe = matches!(condition, WeatherCondition::Blizzard | WeatherCondition::Sandstorm)
+        || (severe_front && precipitating)
+        || temperature_c >= 40
+        || temperature_c <= -30;
+
+    let travel = travel_conditions(condition, wind, temperature_c);
+    let summary = format!(
+        "{}, {}°C, {} wind",
+        condition.label(),
+        temperature_c,
+        match wind {
+            WindLevel::Calm => "calm",
+            WindLevel::Breeze => "light",
+            WindLevel::Strong => "strong",
+            WindLevel::Gale => "gale-force",
+        }
+    );
+
+    WeatherReport {
+        date: date.clone(),
+        location_id: location_id.to_string(),
+        season,
+        condition,
+        temperature_c,
+        wind,
+        front,
+        severe,
+        travel,
+        summary,
+    }
+}
+
+fn travel_conditions(condition: WeatherCondition, wind: WindLevel, temperature_c: i32) -> TravelConditions {
+    let (mut speed_multiplier, visibility, mut hazards): (f32, Visibility, Vec<&str>) = match condition {
+        WeatherCondition::Clear | WeatherCondition::PartlyCloudy | WeatherCondition::Overcast => {
+            (1.0, Visibility::Clear, vec![])
+        }
+        WeatherCondition::Fog => (0.75, Visibility::Poor, vec!["Easy to get lost off the road"]),
+        WeatherCondition::Rain => (0.9, Visibility::Reduced, vec!["Muddy roads"]),
+        WeatherCondition::HeavyRain => (0.75, Visibility::Reduced, vec!["Muddy roads", "Rivers may flood fords"]),
+        WeatherCondition::Thunderstorm => (0.5, Visibility::Reduced, vec!["Lightning on open ground", "Flash floods"]),
+        WeatherCondition::Snow => (0.75, Visibility::Reduced, vec!["Snow-covered trails"]),
+        WeatherCondition::Blizzard => (0.25, Visibility::Poor, vec!["Exposure to cold", "Whiteout; easy to get lost"]),
+        WeatherCondition::Sandstorm => (0.25, Visibility::Poor, vec!["Blinding sand", "Tracks are erased"]),
+        WeatherCondition::Heatwave => (0.75, Visibility::Clear, vec!["Exhaustion from heat", "Water needs doubled"]),
+        WeatherCondition::Frigid => (0.5, Visibility::Clear, vec!["Exposure to cold"]),
+    };
+    if wind == WindLevel::Gale {
+        speed_multiplier *= 0.75;
+        hazards.push("Flying and sailing are dangerous");
+    }
+    if temperature_c <= -30 && !hazards.contains(&"Exposure to cold") {
+        hazards.push("Exposure to cold");
+    }
+    TravelConditions {
+        speed_multiplier,
+        visibility,
+        hazards: hazards.into_iter().map(String::from).collect(),
+    }
+}
+
+/// Stable seed so a location's weather on a given day is reproducible
+fn day_seed(campaign_id: &str, location_id: &str, day_number: i64) -> u64 {
+    // FNV-1a
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in format!("{}:{}:{}", campaign_id, location_id, day_number).bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// ============================================================================
+// Weather Manager
+// ============================================================================
+
+/// Holds location climates and the weather generated for them
+pub struct WeatherManager {
+    /// Campaign ID -> location ID -> climate
+    climates: RwLock<HashMap<String, HashMap<String, ClimateProfile>>>,
+    /// (campaign ID, location ID) -> day number -> weather
+    history: RwLock<HashMap<(String, String), BTreeMap<i64, WeatherReport>>>,
+}
+
+impl Default for WeatherManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeatherManager {
+    pub fn new() -> Self {
+        Self {
+            climates: RwLock::new(HashMap::new()),
+            history: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // ========================================================================
+    // Climates
+    // ========================================================================
+
+    /// Set a location's climate. Weather already generated for the location
+    /// is discarded so it can be regenerated under the new climate.
+    pub fn set_climate(&self, campaign_id: &str, location_id: &str, climate: ClimateProfile) {
+        self.climates
+            .write()
+            .unwrap()
+            .entry(campaign_id.to_string())
+            .or_default()
+            .insert(location_id.to_string(), climate);
+        self.history
+            .write()
+            .unwrap()
+            .remove(&(campaign_id.to_string(), location_id.to_string()));
+    }
+
+    pub fn get_climate(&self, campaign_id: &str, location_id: &str) -> Option<ClimateProfile> {
+        self.climates
+            .read()
+            .unwrap()
+            .get(campaign_id)
+            .and_then(|c| c.get(location_id).cloned())
+    }
+
+    /// Locations with a climate set, whose weather is tracked as time passes
+    pub fn climate_locations(&self, campaign_id: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .climates
+            .read()
+            .unwrap()
+            .get(campaign_id)
+            .map(|c| c.keys().cloned().collect())
+            .unwrap_or_default();
+        ids.sort();
+        ids
+    }
+
+    pub fn delete_campaign_weather(&self, campaign_id: &str) {
+        self.climates.write().unwrap().remove(campaign_id);
+        self.history.write().unwrap().retain(|(cid, _), _| cid != campaign_id);
+    }
+
+    // ========================================================================
+    // Weather
+    // ========================================================================
+
+    /// Weather at a location on a date, generating (and remembering) any
+    /// days needed to reach it from the last known weather
+    pub fn weather_for(
+        &self,
+        campaign_id: &str,
+        location_id: &str,
+        calendar: &CalendarDefinition,
+        date: &InGameDate,
+        climate: &ClimateProfile,
+    ) -> Result<WeatherReport> {
+        let day = calendar.day_number(date)?;
+        let mut history = self.history.write().unwrap();
+        let days = history
+            .entry((campaign_id.to_string(), location_id.to_string()))
+            .or_default();
+        if let Some(report) = days.get(&day) {
+            return Ok(report.clone());
+        }
+
+        let (mut previous, start) = match days.range(..day).next_back() {
+            Some((known, report)) if day - known <= MAX_PERSISTENCE_GAP => (Some(report.clone()), known + 1),
+            _ => (None, day - WARMUP_DAYS),
+        };
+        for day_number in start..=day {
+            let date = calendar.date_from_day_number(day_number, date);
+            let season = Season::for_date(calendar, &date)?;
+            let mut rng = StdRng::seed_from_u64(day_seed(campaign_id, location_id, day_number));
+            let report = generate_day(climate, season, previous.as_ref(), &date, location_id, &mut rng);
+            days.insert(day_number, report.clone());
+            previous = Some(report);
+        }
+
+        Ok(previous.expect("at least one day is generated"))
+    }
+
+    /// Weather for `days` consecutive days starting at `from`
+    pub fn forecast(
+        &self,
+        campaign_id: &str,
+        location_id: &str,
+        calendar: &CalendarDefinition,
+        from: &InGameDate,
+        days: u32,
+        climate: &ClimateProfile,
+    ) -> Result<Vec<WeatherReport>> {
+        (0..days as i64)
+            .map(|offset| {
+                let date = calendar.add_days(from, offset)?;
+                self.weather_for(campaign_id, location_id, calendar, &date, climate)
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calendar() -> CalendarDefinition {
+        CalendarDefinition::harptos()
+    }
+
+    #[test]
+    fn test_season_follows_calendar() {
+        let calendar = calendar();
+        assert_eq!(Season::for_date(&calendar, &InGameDate::new(1492, 1, 10)).unwrap(), Season::Winter);
+        assert_eq!(Season::for_date(&calendar, &InGameDate::new(1492, 7, 10)).unwrap(), Season::Spring);
+        assert_eq!(Season::for_date(&calendar, &InGameDate::new(1492, 9, 20)).unwrap(), Season::Summer);
+        assert_eq!(Season::for_date(&calendar, &InGameDate::new(1492, 15, 10)).unwrap(), Season::Autumn);
+        assert_eq!(Season::for_date(&calendar, &InGameDate::new(1492, 18, 25)).unwrap(), Season::Winter);
+    }
+
+    #[test]
+    fn test_weather_is_reproducible_and_remembered() {
+        let calendar = calendar();
+        let date = InGameDate::new(1492, 8, 12);
+        let climate = ClimateProfile::default();
+
+        let first = WeatherManager::new()
+            .weather_for("camp-1", "loc-1", &calendar, &date, &climate)
+            .unwrap();
+        let manager = WeatherManager::new();
+        let second = manager.weather_for("camp-1", "loc-1", &calendar, &date, &climate).unwrap();
+        assert_eq!(first.condition, second.condition);
+        assert_eq!(first.temperature_c, second.temperature_c);
+
+        // Warm-up days before the requested date are kept as history
+        let earlier = calendar.add_days(&date, -1).unwrap();
+        let yesterday = manager.weather_for("camp-1", "loc-1", &calendar, &earlier, &climate).unwrap();
+        assert_eq!(yesterday.date, earlier);
+    }
+
+    #[test]
+    fn test_fronts_persist_across_days() {
+        let climate = ClimateProfile::default();
+        let date = InGameDate::new(1492, 8, 12);
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut previous = generate_day(&climate, Season::Summer, None, &date, "loc-1", &mut rng);
+        previous.front = Some(WeatherFront {
+            kind: FrontKind::Storm,
+            days_remaining: 3,
+            severe: true,
+        });
+
+        let next = generate_day(&climate, Season::Summer, Some(&previous), &date, "loc-1", &mut rng);
+        let front = next.front.unwrap();
+        assert_eq!(front.kind, FrontKind::Storm);
+        assert_eq!(front.days_remaining, 2);
+        assert_eq!(next.wind, WindLevel::Gale);
+    }
+
+    #[test]
+    fn test_forecast_and_severe_events() {
+        let calendar = calendar();
+        let manager = WeatherManager::new();
+        let mut climate = ClimateProfile::for_zone(ClimateZone::Arctic);
+        climate.front_chance = 1.0;
+        climate.severe_chance = 1.0;
+        climate.precipitation_chance = [1.0; 4];
+
+        let forecast = manager
+            .forecast("camp-1", "loc-1", &calendar, &InGameDate::new(1492, 1, 5), 5, &climate)
+            .unwrap();
+        assert_eq!(forecast.len(), 5);
+        assert_eq!(forecast[4].date.day, 9);
+
+        let severe = forecast.iter().find(|r| r.severe).expect("severe weather");
+        let event = severe.to_world_event("camp-1", "Icewind Dale").unwrap();
+        assert_eq!(event.event_type, WorldEventType::Natural);
+        assert_eq!(event.location_ids, vec!["loc-1".to_string()]);
+        assert!(event.title.ends_with("at Icewind Dale"));
+    }
+
+    #[test]
+    fn test_travel_conditions() {
+        let clear = travel_conditions(WeatherCondition::Clear, WindLevel::Calm, 20);
+        assert_eq!(clear.speed_multiplier, 1.0);
+        assert!(clear.hazards.is_empty());
+
+        let blizzard = travel_conditions(WeatherCondition::Blizzard, WindLevel::Gale, -35);
+        assert!(blizzard.speed_multiplier < 0.25);
+        assert_eq!(blizzard.visibility, Visibility::Poor);
+        assert!(blizzard.hazards.iter().any(|h| h == "Exposure to cold"));
+        assert_eq!(blizzard.hazards.iter().filter(|h| *h == "Exposure to cold").count(), 1);
+    }
+}
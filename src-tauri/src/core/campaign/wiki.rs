@@ -0,0 +1,808 @@
+//! Campaign Wiki Export
+//!
+//! Renders a campaign's NPCs, locations, factions, and session recaps as an
+//! interlinked set of Markdown pages or a static HTML site. Player-safe mode
+//! leaves out secrets, hidden features, faction clocks, and GM notes so the
+//! result can be handed to players.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{html, Event, Options, Parser};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::factions::Faction;
+use crate::core::campaign_manager::Campaign;
+use crate::core::location_gen::Location;
+use crate::core::npc_gen::{NPCRole, NPC};
+
+/// Tags that keep an NPC or location out of a player-safe wiki
+pub const GM_ONLY_TAGS: &[&str] = &["gm_only", "secret", "hidden"];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum WikiError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unknown wiki format: {0}")]
+    UnknownFormat(String),
+}
+
+pub type Result<T> = std::result::Result<T, WikiError>;
+
+// ============================================================================
+// Wiki Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WikiFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl WikiFormat {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            other => Err(WikiError::UnknownFormat(other.to_string())),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// A session recap to publish
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiRecap {
+    pub session_number: u32,
+    pub title: String,
+    /// Recap body (Markdown)
+    pub content: String,
+    /// Whether the recap note was marked GM-only
+    pub is_private: bool,
+}
+
+/// Everything the wiki is built from
+#[derive(Debug, Clone)]
+pub struct WikiSource {
+    pub campaign: Campaign,
+    pub npcs: Vec<NPC>,
+    pub locations: Vec<Location>,
+    pub factions: Vec<Faction>,
+    pub recaps: Vec<WikiRecap>,
+}
+
+/// One rendered page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPage {
+    /// Path relative to the site root, e.g. `npcs/baron-vane.md`
+    pub path: String,
+    pub title: String,
+    pub content: String,
+}
+
+/// A rendered wiki, ready to be written to disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiSite {
+    pub campaign_id: String,
+    pub format: WikiFormat,
+    pub player_safe: bool,
+    pub pages: Vec<WikiPage>,
+}
+
+impl WikiSite {
+    /// Write every page under `dir`, creating folders as needed
+    pub fn write_to(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::with_capacity(self.pages.len());
+        for page in &self.pages {
+            let path = dir.join(&page.path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, &page.content)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+// ============================================================================
+// Links
+// ============================================================================
+
+/// Turn a name into a file-name slug
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-').to_string();
+    if slug.is_empty() {
+        "page".to_string()
+    } else {
+        slug
+    }
+}
+
+fn escape_link_text(text: &str) -> String {
+    text.replace('[', "\\[").replace(']', "\\]")
+}
+
+struct LinkTarget {
+    name: String,
+    /// Path relative to the site root, without extension
+    path: String,
+}
+
+/// Resolves entity IDs and names to page links
+struct Linker {
+    targets: HashMap<String, LinkTarget>,
+    extension: &'static str,
+}
+
+impl Linker {
+    fn new(extension: &'static str) -> Self {
+        Self {
+            targets: HashMap::new(),
+            extension,
+        }
+    }
+
+    /// Register a page, keeping slugs unique within its folder
+    fn register(&mut self, id: &str, name: &str, folder: &str, used: &mut HashSet<String>) -> String {
+        let base = format!("{}/{}", folder, slugify(name));
+        let mut path = base.clone();
+        let mut n = 2;
+        while !used.insert(path.clone()) {
+            path = format!("{}-{}", base, n);
+            n += 1;
+        }
+        self.targets.insert(
+            id.to_string(),
+            LinkTarget {
+                name: name.to_string(),
+                path: path.clone(),
+            },
+        );
+        path
+    }
+
+    fn file(&self, path: &str) -> String {
+        format!("{}.{}", path, self.extension)
+    }
+
+    /// Link from a page one folder deep
+    fn href(&self, path: &str) -> String {
+        format!("../{}", self.file(path))
+    }
+
+    fn link(&self, id: &str) -> Option<String> {
+        self.targets
+            .get(id)
+            .map(|t| format!("[{}]({})", escape_link_text(&t.name), self.href(&t.path)))
+    }
+
+    /// Link an entity by ID, falling back to plain text
+    fn link_or(&self, id: Option<&str>, fallback: &str) -> String {
+        id.and_then(|id| self.link(id)).unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Link the first whole-word mention of each known name in free text,
+    /// preferring longer names where mentions overlap
+    fn link_mentions(&self, text: &str, skip_id: Option<&str>) -> String {
+        let mut targets: Vec<(&String, &LinkTarget)> = self
+            .targets
+            .iter()
+            .filter(|(id, t)| Some(id.as_str()) != skip_id && t.name.chars().count() > 2)
+            .collect();
+        targets.sort_by_key(|(_, t)| (std::cmp::Reverse(t.name.len()), t.path.clone()));
+
+        let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+        let mut spans: Vec<(usize, usize, &LinkTarget)> = Vec::new();
+        for (_, target) in targets {
+            let found = text.match_indices(target.name.as_str()).find(|(start, m)| {
+                let end = start + m.len();
+                !is_word(text[..*start].chars().next_back())
+                    && !is_word(text[end..].chars().next())
+                    && !spans.iter().any(|(s, e, _)| *start < *e && end > *s)
+            });
+            if let Some((start, m)) = found {
+                spans.push((start, start + m.len(), target));
+            }
+        }
+        spans.sort_by_key(|(start, _, _)| *start);
+
+        let mut linked = String::with_capacity(text.len());
+        let mut last = 0;
+        for (start, end, target) in spans {
+            linked.push_str(&text[last..start]);
+            linked.push_str(&format!(
+                "[{}]({})",
+                escape_link_text(&text[start..end]),
+                self.href(&target.path)
+            ));
+            last = end;
+        }
+        linked.push_str(&text[last..]);
+        linked
+    }
+}
+
+// ============================================================================
+// Page Rendering
+// ============================================================================
+
+fn is_gm_only(tags: &[String]) -> bool {
+    tags.iter().any(|t| GM_ONLY_TAGS.contains(&t.to_lowercase().as_str()))
+}
+
+fn role_label(role: &NPCRole) -> String {
+    match role {
+        NPCRole::QuestGiver => "Quest Giver".to_string(),
+        NPCRole::Custom(role) => role.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn push_list(md: &mut String, heading: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    md.push_str(&format!("\n## {}\n\n", heading));
+    for item in items {
+        md.push_str(&format!("- {}\n", item));
+    }
+}
+
+fn page_header(title: &str, subtitle: &str, linker: &Linker) -> String {
+    let mut md = format!("[← Index]({})\n\n# {}\n", linker.href("index"), title);
+    if !subtitle.is_empty() {
+        md.push_str(&format!("\n*{}*\n", subtitle));
+    }
+    md
+}
+
+fn render_npc(npc: &NPC, factions: &[Faction], linker: &Linker, player_safe: bool) -> String {
+    let mut md = page_header(&npc.name, &role_label(&npc.role), linker);
+
+    let looks = &npc.appearance;
+    let appearance: Vec<String> = [
+        ("Age", &looks.age),
+        ("Build", &looks.build),
+        ("Hair", &looks.hair),
+        ("Eyes", &looks.eyes),
+        ("Clothing", &looks.clothing),
+        ("Demeanor", &looks.demeanor),
+    ]
+    .into_iter()
+    .filter(|(_, value)| !value.trim().is_empty())
+    .map(|(label, value)| format!("**{}:** {}", label, value))
+    .chain(looks.distinguishing_features.iter().cloned())
+    .collect();
+    push_list(&mut md, "Appearance", &appearance);
+    push_list(&mut md, "Personality", &npc.personality.traits);
+
+    let relationships: Vec<String> = npc
+        .relationships
+        .iter()
+        .map(|r| {
+            format!(
+                "{} ({})",
+                linker.link_or(r.target_id.as_deref(), &r.target_name),
+                r.relationship_type
+            )
+        })
+        .collect();
+    push_list(&mut md, "Relationships", &relationships);
+
+    let memberships: Vec<String> = factions
+        .iter()
+        .filter_map(|f| {
+            let member = f.members.iter().find(|m| m.npc_id == npc.id)?;
+            let role = member
+                .role
+                .clone()
+                .unwrap_or_else(|| if member.is_leader { "Leader" } else { "Member" }.to_string());
+            Some(format!("{} ({})", linker.link_or(Some(f.id.as_str()), &f.name), role))
+        })
+        .collect();
+    push_list(&mut md, "Factions", &memberships);
+
+    if !player_safe {
+        push_list(&mut md, "Secrets (GM)", &npc.secrets);
+        let hooks: Vec<String> = npc.hooks.iter().map(|h| h.description.clone()).collect();
+        push_list(&mut md, "Plot Hooks (GM)", &hooks);
+        if !npc.notes.trim().is_empty() {
+            md.push_str(&format!("\n## GM Notes\n\n{}\n", npc.notes.trim()));
+        }
+    }
+    md
+}
+
+fn render_location(location: &Location, factions: &[Faction], linker: &Linker, player_safe: bool) -> String {
+    let mut md = page_header(&location.name, location.location_type.display_name(), linker);
+    if !location.description.trim().is_empty() {
+        md.push_str(&format!(
+            "\n{}\n",
+            linker.link_mentions(location.description.trim(), Some(&location.id))
+        ));
+    }
+    if !location.atmosphere.mood.trim().is_empty() {
+        md.push_str(&format!("\n**Atmosphere:** {}\n", location.atmosphere.mood));
+    }
+
+    let features: Vec<String> = location
+        .notable_features
+        .iter()
+        .filter(|f| !(player_safe && f.hidden))
+        .map(|f| {
+            let hidden = if f.hidden { " *(hidden)*" } else { "" };
+            format!("**{}**{}: {}", f.name, hidden, f.description)
+        })
+        .collect();
+    push_list(&mut md, "Notable Features", &features);
+
+    let inhabitants: Vec<String> = location
+        .inhabitants
+        .iter()
+        .map(|i| format!("**{}** ({}): {}", i.name, i.role, i.description))
+        .collect();
+    push_list(&mut md, "Inhabitants", &inhabitants);
+
+    let connections: Vec<String> = location
+        .connected_locations
+        .iter()
+        .map(|c| {
+            let mut line = linker.link_or(c.target_id.as_deref(), &c.target_name);
+            if let Some(travel_time) = &c.travel_time {
+                line.push_str(&format!(" ({})", travel_time));
+            }
+            line
+        })
+        .collect();
+    push_list(&mut md, "Connections", &connections);
+
+    let controlled_by: Vec<String> = factions
+        .iter()
+        .filter(|f| f.territory.contains(&location.id))
+        .map(|f| linker.link_or(Some(f.id.as_str()), &f.name))
+        .collect();
+    push_list(&mut md, "Controlled By", &controlled_by);
+
+    if !player_safe {
+        let secrets: Vec<String> = location
+            .secrets
+            .iter()
+            .map(|s| s.description.clone())
+            .chain(
+                location
+                    .inhabitants
+                    .iter()
+                    .flat_map(|i| i.secrets.iter().map(move |s| format!("{}: {}", i.name, s))),
+            )
+            .collect();
+        push_list(&mut md, "Secrets (GM)", &secrets);
+        let encounters: Vec<String> = location
+            .encounters
+            .iter()
+            .map(|e| format!("**{}**: {}", e.name, e.description))
+            .collect();
+        push_list(&mut md, "Encounters (GM)", &encounters);
+        if !location.notes.trim().is_empty() {
+            md.push_str(&format!("\n## GM Notes\n\n{}\n", location.notes.trim()));
+        }
+    }
+    md
+}
+
+fn render_faction(faction: &Faction, linker: &Linker, player_safe: bool) -> String {
+    let mut md = page_header(&faction.name, "", linker);
+    if !faction.description.trim().is_empty() {
+        md.push_str(&format!(
+            "\n{}\n",
+            linker.link_mentions(faction.description.trim(), Some(&faction.id))
+        ));
+    }
+
+    // Unachieved goals are the faction's plans, so players only see results
+    let goals: Vec<String> = faction
+        .goals
+        .iter()
+        .filter(|g| g.achieved || !player_safe)
+        .map(|g| {
+            if g.achieved {
+                format!("~~{}~~ (achieved)", g.description)
+            } else {
+                g.description.clone()
+            }
+        })
+        .collect();
+    push_list(&mut md, "Goals", &goals);
+
+    let members: Vec<String> = faction
+        .members
+        .iter()
+        .filter(|m| linker.targets.contains_key(&m.npc_id))
+        .map(|m| {
+            let mut line = linker.link_or(Some(m.npc_id.as_str()), &m.npc_id);
+            if let Some(role) = m.role.as_ref().filter(|r| !r.is_empty()) {
+                line.push_str(&format!(" ({})", role));
+            } else if m.is_leader {
+                line.push_str(" (Leader)");
+            }
+            line
+        })
+        .collect();
+    push_list(&mut md, "Members", &members);
+
+    let territory: Vec<String> = faction
+        .territory
+        .iter()
+        .filter_map(|id| linker.link(id))
+        .collect();
+    push_list(&mut md, "Territory", &territory);
+
+    if !player_safe {
+        md.push_str(&format!("\n**Influence (GM):** {}/100\n", faction.influence));
+        let resources: Vec<String> = faction
+            .resources
+            .iter()
+            .map(|r| format!("{}: {}", r.name, r.amount))
+            .collect();
+        push_list(&mut md, "Resources (GM)", &resources);
+        let clocks: Vec<String> = faction
+            .clocks
+            .iter()
+            .map(|c| format!("{}: {}/{}", c.name, c.filled, c.segments))
+            .collect();
+        push_list(&mut md, "Clocks (GM)", &clocks);
+    }
+    md
+}
+
+fn render_recap(recap: &WikiRecap, linker: &Linker) -> String {
+    let mut md = page_header(&recap.title, &format!("Session {}", recap.session_number), linker);
+    md.push_str(&format!("\n{}\n", linker.link_mentions(recap.content.trim(), None)));
+    md
+}
+
+fn index_section(md: &mut String, heading: &str, entries: &[(String, String)], linker: &Linker) {
+    if entries.is_empty() {
+        return;
+    }
+    md.push_str(&format!("\n## {}\n\n", heading));
+    for (title, path) in entries {
+        md.push_str(&format!("- [{}]({})\n", escape_link_text(title), linker.file(path)));
+    }
+}
+
+// ============================================================================
+// HTML Output
+// ============================================================================
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Render a Markdown page as a standalone HTML document. Raw HTML in the
+/// source is shown as text rather than passed through.
+fn markdown_to_html(title: &str, campaign_name: &str, markdown: &str) -> String {
+    let parser = Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+    let mut body = String::new();
+    html::push_html(&mut body, parser);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{} - {}</title>
+    <style>
+        body {{ font-family: Georgia, 'Times New Roman', serif; line-height: 1.6; color: #222; max-width: 760px; margin: 0 auto; padding: 24px; }}
+        h1 {{ border-bottom: 2px solid #444; padding-bottom: 6px; }}
+        h2 {{ color: #444; margin-top: 28px; }}
+        a {{ color: #7a2e0e; }}
+        li {{ margin-bottom: 4px; }}
+    </style>
+</head>
+<body>
+{}</body>
+</html>
+"#,
+        escape_html(title),
+        escape_html(campaign_name),
+        body
+    )
+}
+
+// ============================================================================
+// Wiki Builder
+// ============================================================================
+
+/// Render the campaign as a set of interlinked pages
+pub fn build_wiki(source: &WikiSource, format: WikiFormat, player_safe: bool) -> WikiSite {
+    let npcs: Vec<&NPC> = source
+        .npcs
+        .iter()
+        .filter(|n| !(player_safe && is_gm_only(&n.tags)))
+        .collect();
+    let locations: Vec<&Location> = source
+        .locations
+        .iter()
+        .filter(|l| !(player_safe && is_gm_only(&l.tags)))
+        .collect();
+    let mut recaps: Vec<&WikiRecap> = source
+        .recaps
+        .iter()
+        .filter(|r| !(player_safe && r.is_private))
+        .collect();
+    recaps.sort_by_key(|r| r.session_number);
+
+    let mut linker = Linker::new(format.extension());
+    let mut used = HashSet::new();
+    let npc_paths: Vec<String> = npcs
+        .iter()
+        .map(|n| linker.register(&n.id, &n.name, "npcs", &mut used))
+        .collect();
+    let location_paths: Vec<String> = locations
+        .iter()
+        .map(|l| linker.register(&l.id, &l.name, "locations", &mut used))
+        .collect();
+    let faction_paths: Vec<String> = source
+        .factions
+        .iter()
+        .map(|f| linker.register(&f.id, &f.name, "factions", &mut used))
+        .collect();
+
+    let mut pages: Vec<WikiPage> = Vec::new();
+    for (npc, path) in npcs.iter().zip(&npc_paths) {
+        pages.push(WikiPage {
+            path: path.clone(),
+            title: npc.name.clone(),
+            content: render_npc(npc, &source.factions, &linker, player_safe),
+        });
+    }
+    for (location, path) in locations.iter().zip(&location_paths) {
+        pages.push(WikiPage {
+            path: path.clone(),
+            title: location.name.clone(),
+            content: render_location(location, &source.factions, &linker, player_safe),
+        });
+    }
+    for (faction, path) in source.factions.iter().zip(&faction_paths) {
+        pages.push(WikiPage {
+            path: path.clone(),
+            title: faction.name.clone(),
+            content: render_faction(faction, &linker, player_safe),
+        });
+    }
+    let mut session_entries = Vec::new();
+    for recap in &recaps {
+        let path = format!("sessions/session-{}", recap.session_number);
+        if !used.insert(path.clone()) {
+            continue;
+        }
+        session_entries.push((format!("Session {}: {}", recap.session_number, recap.title), path.clone()));
+        pages.push(WikiPage {
+            path,
+            title: recap.title.clone(),
+            content: render_recap(recap, &linker),
+        });
+    }
+
+    let campaign = &source.campaign;
+    let mut index = format!("# {}\n\n*{}*\n", campaign.name, campaign.system);
+    if let Some(description) = campaign.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        index.push_str(&format!("\n{}\n", description.trim()));
+    }
+    let entries = |names: Vec<&String>, paths: &[String]| -> Vec<(String, String)> {
+        names.into_iter().cloned().zip(paths.iter().cloned()).collect()
+    };
+    index_section(&mut index, "Sessions", &session_entries, &linker);
+    index_section(&mut index, "NPCs", &entries(npcs.iter().map(|n| &n.name).collect(), &npc_paths), &linker);
+    index_section(
+        &mut index,
+        "Locations",
+        &entries(locations.iter().map(|l| &l.name).collect(), &location_paths),
+        &linker,
+    );
+    index_section(
+        &mut index,
+        "Factions",
+        &entries(source.factions.iter().map(|f| &f.name).collect(), &faction_paths),
+        &linker,
+    );
+    if !player_safe && !campaign.notes.is_empty() {
+        push_list(&mut index, "Campaign Notes (GM)", &campaign.notes);
+    }
+    pages.insert(
+        0,
+        WikiPage {
+            path: "index".to_string(),
+            title: campaign.name.clone(),
+            content: index,
+        },
+    );
+
+    for page in &mut pages {
+        if format == WikiFormat::Html {
+            page.content = markdown_to_html(&page.title, &campaign.name, &page.content);
+        }
+        page.path = linker.file(&page.path);
+    }
+
+    WikiSite {
+        campaign_id: campaign.id.clone(),
+        format,
+        player_safe,
+        pages,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::campaign::factions::{FactionClock, FactionMember};
+
+    fn npc(id: &str, name: &str) -> NPC {
+        let mut npc: NPC = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "role": "Ally",
+            "appearance": {
+                "age": "40", "height": "", "build": "", "hair": "", "eyes": "", "skin": "",
+                "distinguishing_features": [], "clothing": "", "demeanor": ""
+            },
+            "personality": {
+                "traits": [], "ideals": [], "bonds": [], "flaws": [], "mannerisms": [],
+                "speech_patterns": [], "motivations": [], "fears": []
+            },
+            "personality_id": null,
+            "voice": {"pitch": "", "pace": "", "accent": null, "vocabulary": "", "sample_phrases": []},
+            "stats": null,
+            "relationships": [],
+            "secrets": [],
+            "hooks": [],
+            "notes": "",
+            "tags": []
+        }))
+        .unwrap();
+        npc.secrets = vec!["Secretly a vampire".to_string()];
+        npc
+    }
+
+    fn source() -> WikiSource {
+        let campaign: Campaign = serde_json::from_value(serde_json::json!({
+            "id": "campaign-1",
+            "name": "Ashes of Varn",
+            "system": "D&D 5e",
+            "description": "A city in ruin.",
+            "current_date": "",
+            "notes": ["The duke is the villain"],
+            "created_at": "",
+            "updated_at": ""
+        }))
+        .unwrap();
+
+        let mut hidden = npc("npc-3", "The Whisperer");
+        hidden.tags = vec!["gm_only".to_string()];
+        let mut faction = Faction::new("campaign-1", "Ember Guild");
+        faction.id = "faction-1".to_string();
+        faction.members.push(FactionMember {
+            npc_id: "npc-1".to_string(),
+            role: Some("Guildmaster".to_string()),
+            is_leader: true,
+        });
+        faction.clocks.push(FactionClock::new("Burn the docks", 6));
+
+        WikiSource {
+            campaign,
+            npcs: vec![npc("npc-1", "Baron Vane"), npc("npc-2", "Baron Vane"), hidden],
+            locations: vec![],
+            factions: vec![faction],
+            recaps: vec![
+                WikiRecap {
+                    session_number: 2,
+                    title: "Fire at the Docks".to_string(),
+                    content: "The party met Baron Vane and the Ember Guild.".to_string(),
+                    is_private: false,
+                },
+                WikiRecap {
+                    session_number: 3,
+                    title: "GM prep".to_string(),
+                    content: "Spoilers".to_string(),
+                    is_private: true,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Baron Vane"), "baron-vane");
+        assert_eq!(slugify("  The Duke's Keep! "), "the-duke-s-keep");
+        assert_eq!(slugify("???"), "page");
+    }
+
+    #[test]
+    fn test_markdown_pages_are_interlinked() {
+        let site = build_wiki(&source(), WikiFormat::Markdown, false);
+        let paths: Vec<&str> = site.pages.iter().map(|p| p.path.as_str()).collect();
+        assert!(paths.contains(&"index.md"));
+        assert!(paths.contains(&"npcs/baron-vane.md"));
+        assert!(paths.contains(&"npcs/baron-vane-2.md"));
+        assert!(paths.contains(&"factions/ember-guild.md"));
+
+        let npc = site.pages.iter().find(|p| p.path == "npcs/baron-vane.md").unwrap();
+        assert!(npc.content.contains("[Ember Guild](../factions/ember-guild.md) (Guildmaster)"));
+        assert!(npc.content.contains("Secretly a vampire"));
+
+        let recap = site.pages.iter().find(|p| p.path == "sessions/session-2.md").unwrap();
+        assert!(recap.content.contains("[Ember Guild](../factions/ember-guild.md)"));
+        assert!(site.pages[0].content.contains("[Session 2: Fire at the Docks](sessions/session-2.md)"));
+    }
+
+    #[test]
+    fn test_player_safe_hides_gm_content() {
+        let site = build_wiki(&source(), WikiFormat::Markdown, true);
+        let all: String = site.pages.iter().map(|p| p.content.as_str()).collect();
+        assert!(!all.contains("Secretly a vampire"));
+        assert!(!all.contains("The Whisperer"));
+        assert!(!all.contains("Burn the docks"));
+        assert!(!all.contains("The duke is the villain"));
+        assert!(!all.contains("Spoilers"));
+        assert!(site.pages.iter().all(|p| p.path != "sessions/session-3.md"));
+    }
+
+    #[test]
+    fn test_html_output_escapes_raw_html() {
+        let mut source = source();
+        source.recaps[0].content = "Beware <script>alert(1)</script> the Ember Guild".to_string();
+        let site = build_wiki(&source, WikiFormat::Html, true);
+
+        let recap = site.pages.iter().find(|p| p.path == "sessions/session-2.html").unwrap();
+        assert!(recap.content.starts_with("<!DOCTYPE html>"));
+        assert!(recap.content.contains("<a href=\"../factions/ember-guild.html\">Ember Guild</a>"));
+        assert!(!recap.content.contains("<script>"));
+        assert!(WikiFormat::parse("pdf").is_err());
+    }
+
+    #[test]
+    fn test_write_to_creates_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let site = build_wiki(&source(), WikiFormat::Markdown, true);
+        let written = site.write_to(dir.path()).unwrap();
+        assert_eq!(written.len(), site.pages.len());
+        assert!(dir.path().join("npcs/baron-vane.md").exists());
+        assert!(dir.path().join("index.md").exists());
+    }
+}
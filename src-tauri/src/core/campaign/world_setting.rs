@@ -0,0 +1,729 @@
+//! Shared World Setting Module
+//!
+//! A setting is a world several campaigns play in. It owns locations,
+//! factions, deities, and a calendar that its campaigns reference instead of
+//! copying. Locations, factions, and the calendar stay in their usual
+//! managers, stored under the setting's ID where a campaign ID would go;
+//! deities live on the setting itself.
+//!
+//! A campaign can hide or patch any shared entity for itself without
+//! touching the other campaigns, and world events recorded in one campaign
+//! spread to the others according to the setting's propagation rule.
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::campaign::world_state::{EventImpact, InGameDate, WorldEvent, WorldEventType};
+
+/// World event metadata naming the event a propagated copy came from
+pub const PROPAGATED_FROM_FIELD: &str = "propagated_from";
+
+/// Fields an override can't change
+const PROTECTED_FIELDS: &[&str] = &["id", "campaign_id"];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum WorldSettingError {
+    #[error("Setting not found: {0}")]
+    SettingNotFound(String),
+
+    #[error("Setting name cannot be empty")]
+    EmptyName,
+
+    #[error("Campaign {0} already belongs to setting {1}")]
+    AlreadyMember(String, String),
+
+    #[error("Campaign {0} doesn't belong to a setting")]
+    NotMember(String),
+
+    #[error("Deity not found: {0}")]
+    DeityNotFound(String),
+
+    #[error("Invalid override: {0}")]
+    InvalidOverride(String),
+}
+
+pub type Result<T> = std::result::Result<T, WorldSettingError>;
+
+// ============================================================================
+// Setting Types
+// ============================================================================
+
+/// Kind of entity a setting shares with its campaigns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SharedEntityKind {
+    Location,
+    Faction,
+    Deity,
+}
+
+impl SharedEntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Location => "location",
+            Self::Faction => "faction",
+            Self::Deity => "deity",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "location" => Some(Self::Location),
+            "faction" => Some(Self::Faction),
+            "deity" | "god" => Some(Self::Deity),
+            _ => None,
+        }
+    }
+}
+
+/// A god or other power worshipped in the setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deity {
+    pub id: String,
+    pub name: String,
+    /// Epithet, e.g. "the Morninglord"
+    pub title: Option<String>,
+    pub domains: Vec<String>,
+    pub alignment: Option<String>,
+    pub symbol: Option<String>,
+    pub description: String,
+}
+
+impl Deity {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            title: None,
+            domains: Vec::new(),
+            alignment: None,
+            symbol: None,
+            description: String::new(),
+        }
+    }
+
+    /// Builder: set domains
+    pub fn with_domains(mut self, domains: Vec<String>) -> Self {
+        self.domains = domains;
+        self
+    }
+}
+
+/// Which world events spread from one campaign to the rest of the setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropagationRule {
+    /// Smallest impact that spreads
+    pub min_impact: EventImpact,
+    /// Event types that spread; empty means any
+    #[serde(default)]
+    pub event_types: Vec<WorldEventType>,
+    /// Keep events the GM marked as not public to their own campaign
+    pub public_only: bool,
+}
+
+impl Default for PropagationRule {
+    fn default() -> Self {
+        Self {
+            min_impact: EventImpact::Regional,
+            event_types: Vec::new(),
+            public_only: true,
+        }
+    }
+}
+
+impl PropagationRule {
+    pub fn applies_to(&self, event: &WorldEvent) -> bool {
+        event.impact >= self.min_impact
+            && (self.event_types.is_empty() || self.event_types.contains(&event.event_type))
+            && (event.is_public || !self.public_only)
+    }
+}
+
+/// A world shared by several campaigns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldSetting {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub deities: Vec<Deity>,
+    pub propagation: PropagationRule,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorldSetting {
+    pub fn new(name: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            deities: Vec::new(),
+            propagation: PropagationRule::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Builder: set description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+}
+
+/// A campaign's place in a setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingMembership {
+    pub campaign_id: String,
+    pub setting_id: String,
+    /// Spread this campaign's world events to the others
+    pub share_events: bool,
+    /// Take in world events from the others
+    pub receive_events: bool,
+    pub joined_at: DateTime<Utc>,
+}
+
+/// One campaign's change to a shared entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityOverride {
+    pub campaign_id: String,
+    pub kind: SharedEntityKind,
+    pub entity_id: String,
+    /// Leave the entity out of this campaign entirely
+    #[serde(default)]
+    pub hidden: bool,
+    /// Fields to replace, merged into the shared entity; `null` removes a field
+    #[serde(default)]
+    pub patch: Value,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EntityOverride {
+    pub fn new(campaign_id: &str, kind: SharedEntityKind, entity_id: &str) -> Self {
+        Self {
+            campaign_id: campaign_id.to_string(),
+            kind,
+            entity_id: entity_id.to_string(),
+            hidden: false,
+            patch: Value::Null,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Builder: hide the entity
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Builder: set the patch
+    pub fn with_patch(mut self, patch: Value) -> Self {
+        self.patch = patch;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        match &self.patch {
+            Value::Null => Ok(()),
+            Value::Object(fields) => match PROTECTED_FIELDS.iter().find(|f| fields.contains_key(**f)) {
+                Some(field) => Err(WorldSettingError::InvalidOverride(format!("{} can't be overridden", field))),
+                None => Ok(()),
+            },
+            _ => Err(WorldSettingError::InvalidOverride("patch must be an object".to_string())),
+        }
+    }
+}
+
+/// Merge `patch` into `target` the way JSON merge patch does
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(fields) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+    let Value::Object(target) = target else {
+        return;
+    };
+    for (key, value) in fields {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// A copy of `event` for a campaign it spread to
+pub fn propagated_event(event: &WorldEvent, campaign_id: &str, date: Option<InGameDate>) -> WorldEvent {
+    let mut copy = event.clone();
+    copy.id = Uuid::new_v4().to_string();
+    copy.campaign_id = campaign_id.to_string();
+    copy.session_number = None;
+    copy.pc_ids.clear();
+    if let Some(date) = date {
+        copy.in_game_date = date;
+    }
+    copy.metadata.insert(
+        PROPAGATED_FROM_FIELD.to_string(),
+        serde_json::json!({ "campaign_id": event.campaign_id, "event_id": event.id }),
+    );
+    copy
+}
+
+// ============================================================================
+// World Setting Manager
+// ============================================================================
+
+pub struct WorldSettingManager {
+    settings: RwLock<HashMap<String, WorldSetting>>,
+    /// Campaign ID -> membership
+    memberships: RwLock<HashMap<String, SettingMembership>>,
+    /// (campaign ID, kind, entity ID) -> override
+    overrides: RwLock<HashMap<(String, SharedEntityKind, String), EntityOverride>>,
+}
+
+impl Default for WorldSettingManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldSettingManager {
+    pub fn new() -> Self {
+        Self {
+            settings: RwLock::new(HashMap::new()),
+            memberships: RwLock::new(HashMap::new()),
+            overrides: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // ========================================================================
+    // Settings
+    // ========================================================================
+
+    pub fn create_setting(&self, setting: WorldSetting) -> Result<WorldSetting> {
+        if setting.name.trim().is_empty() {
+            return Err(WorldSettingError::EmptyName);
+        }
+        self.settings
+            .write()
+            .unwrap()
+            .insert(setting.id.clone(), setting.clone());
+        Ok(setting)
+    }
+
+    pub fn get_setting(&self, setting_id: &str) -> Option<WorldSetting> {
+        self.settings.read().unwrap().get(setting_id).cloned()
+    }
+
+    pub fn update_setting(&self, mut setting: WorldSetting) -> Result<WorldSetting> {
+        if setting.name.trim().is_empty() {
+            return Err(WorldSettingError::EmptyName);
+        }
+        let mut settings = self.settings.write().unwrap();
+        let existing = settings
+            .get(&setting.id)
+            .ok_or_else(|| WorldSettingError::SettingNotFound(setting.id.clone()))?;
+        setting.created_at = existing.created_at;
+        setting.updated_at = Utc::now();
+        settings.insert(setting.id.clone(), setting.clone());
+        Ok(setting)
+    }
+
+    /// Delete a setting and release its campaigns. Returns the campaigns
+    /// that belonged to it.
+    pub fn delete_setting(&self, setting_id: &str) -> Result<Vec<String>> {
+        self.settings
+            .write()
+            .unwrap()
+            .remove(setting_id)
+            .ok_or_else(|| WorldSettingError::SettingNotFound(setting_id.to_string()))?;
+        let mut released = Vec::new();
+        self.memberships.write().unwrap().retain(|campaign_id, m| {
+            let keep = m.setting_id != setting_id;
+            if !keep {
+                released.push(campaign_id.clone());
+            }
+            keep
+        });
+        self.overrides
+            .write()
+            .unwrap()
+            .retain(|(campaign_id, _, _), _| !released.contains(campaign_id));
+        released.sort();
+        Ok(released)
+    }
+
+    /// All settings by name
+    pub fn list_settings(&self) -> Vec<WorldSetting> {
+        let mut settings: Vec<WorldSetting> = self.settings.read().unwrap().values().cloned().collect();
+        settings.sort_by(|a, b| a.name.cmp(&b.name));
+        settings
+    }
+
+    // ========================================================================
+    // Membership
+    // ========================================================================
+
+    pub fn join(&self, campaign_id: &str, setting_id: &str) -> Result<SettingMembership> {
+        if self.get_setting(setting_id).is_none() {
+            return Err(WorldSettingError::SettingNotFound(setting_id.to_string()));
+        }
+        let mut memberships = self.memberships.write().unwrap();
+        if let Some(existing) = memberships.get(campaign_id) {
+            return Err(WorldSettingError::AlreadyMember(
+                campaign_id.to_string(),
+                existing.setting_id.clone(),
+            ));
+        }
+        let membership = SettingMembership {
+            campaign_id: campaign_id.to_string(),
+            setting_id: setting_id.to_string(),
+            share_events: true,
+            receive_events: true,
+            joined_at: Utc::now(),
+        };
+        memberships.insert(campaign_id.to_string(), membership.clone());
+        Ok(membership)
+    }
+
+    /// Take a campaign out of its setting, dropping its overrides
+    pub fn leave(&self, campaign_id: &str) -> Result<SettingMembership> {
+        let membership = self
+            .memberships
+            .write()
+            .unwrap()
+            .remove(campaign_id)
+            .ok_or_else(|| WorldSettingError::NotMember(campaign_id.to_string()))?;
+        self.overrides
+            .write()
+            .unwrap()
+            .retain(|(id, _, _), _| id != campaign_id);
+        Ok(membership)
+    }
+
+    pub fn set_event_sharing(&self, campaign_id: &str, share: bool, receive: bool) -> Result<SettingMembership> {
+        let mut memberships = self.memberships.write().unwrap();
+        let membership = memberships
+            .get_mut(campaign_id)
+            .ok_or_else(|| WorldSettingError::NotMember(campaign_id.to_string()))?;
+        membership.share_events = share;
+        membership.receive_events = receive;
+        Ok(membership.clone())
+    }
+
+    pub fn membership(&self, campaign_id: &str) -> Option<SettingMembership> {
+        self.memberships.read().unwrap().get(campaign_id).cloned()
+    }
+
+    /// The setting a campaign plays in
+    pub fn setting_for(&self, campaign_id: &str) -> Option<WorldSetting> {
+        self.membership(campaign_id).and_then(|m| self.get_setting(&m.setting_id))
+    }
+
+    /// A setting's campaigns, earliest to join first
+    pub fn members(&self, setting_id: &str) -> Vec<SettingMembership> {
+        let mut members: Vec<SettingMembership> = self
+            .memberships
+            .read()
+            .unwrap()
+            .values()
+            .filter(|m| m.setting_id == setting_id)
+            .cloned()
+            .collect();
+        members.sort_by_key(|m| m.joined_at);
+        members
+    }
+
+    // ========================================================================
+    // Deities
+    // ========================================================================
+
+    /// Add a deity, or replace the one with the same ID
+    pub fn save_deity(&self, setting_id: &str, deity: Deity) -> Result<Deity> {
+        if deity.name.trim().is_empty() {
+            return Err(WorldSettingError::EmptyName);
+        }
+        let mut settings = self.settings.write().unwrap();
+        let setting = settings
+            .get_mut(setting_id)
+            .ok_or_else(|| WorldSettingError::SettingNotFound(setting_id.to_string()))?;
+        match setting.deities.iter_mut().find(|d| d.id == deity.id) {
+            Some(existing) => *existing = deity.clone(),
+            None => setting.deities.push(deity.clone()),
+        }
+        setting.updated_at = Utc::now();
+        Ok(deity)
+    }
+
+    pub fn remove_deity(&self, setting_id: &str, deity_id: &str) -> Result<Deity> {
+        let mut settings = self.settings.write().unwrap();
+        let setting = settings
+            .get_mut(setting_id)
+            .ok_or_else(|| WorldSettingError::SettingNotFound(setting_id.to_string()))?;
+        let index = setting
+            .deities
+            .iter()
+            .position(|d| d.id == deity_id)
+            .ok_or_else(|| WorldSettingError::DeityNotFound(deity_id.to_string()))?;
+        setting.updated_at = Utc::now();
+        Ok(setting.deities.remove(index))
+    }
+
+    /// The deities a campaign sees, with its overrides applied
+    pub fn deities_for(&self, campaign_id: &str) -> Vec<Deity> {
+        let deities = self.setting_for(campaign_id).map(|s| s.deities).unwrap_or_default();
+        let mut deities = self.apply_overrides(campaign_id, SharedEntityKind::Deity, deities, |d| &d.id);
+        deities.sort_by(|a, b| a.name.cmp(&b.name));
+        deities
+    }
+
+    // ========================================================================
+    // Overrides
+    // ========================================================================
+
+    pub fn set_override(&self, entity_override: EntityOverride) -> Result<EntityOverride> {
+        if self.membership(&entity_override.campaign_id).is_none() {
+            return Err(WorldSettingError::NotMember(entity_override.campaign_id.clone()));
+        }
+        entity_override.validate()?;
+        let mut entity_override = entity_override;
+        entity_override.updated_at = Utc::now();
+        self.overrides.write().unwrap().insert(
+            (
+                entity_override.campaign_id.clone(),
+                entity_override.kind,
+                entity_override.entity_id.clone(),
+            ),
+            entity_override.clone(),
+        );
+        Ok(entity_override)
+    }
+
+    pub fn clear_override(&self, campaign_id: &str, kind: SharedEntityKind, entity_id: &str) -> Option<EntityOverride> {
+        self.overrides
+            .write()
+            .unwrap()
+            .remove(&(campaign_id.to_string(), kind, entity_id.to_string()))
+    }
+
+    pub fn list_overrides(&self, campaign_id: &str) -> Vec<EntityOverride> {
+        let mut overrides: Vec<EntityOverride> = self
+            .overrides
+            .read()
+            .unwrap()
+            .values()
+            .filter(|o| o.campaign_id == campaign_id)
+            .cloned()
+            .collect();
+        overrides.sort_by(|a, b| (a.kind.as_str(), &a.entity_id).cmp(&(b.kind.as_str(), &b.entity_id)));
+        overrides
+    }
+
+    /// Apply a campaign's overrides to shared entities: hidden ones are
+    /// dropped and patched ones have their fields replaced. An entity whose
+    /// patch no longer fits its shape is kept as shared.
+    pub fn apply_overrides<T, F>(&self, campaign_id: &str, kind: SharedEntityKind, entities: Vec<T>, id_of: F) -> Vec<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Fn(&T) -> &str,
+    {
+        let overrides = self.overrides.read().unwrap();
+        entities
+            .into_iter()
+            .filter_map(|entity| {
+                let key = (campaign_id.to_string(), kind, id_of(&entity).to_string());
+                let Some(entity_override) = overrides.get(&key) else {
+                    return Some(entity);
+                };
+                if entity_override.hidden {
+                    return None;
+                }
+                if entity_override.patch.is_null() {
+                    return Some(entity);
+                }
+                let patched = serde_json::to_value(&entity).ok().and_then(|mut value| {
+                    merge_patch(&mut value, &entity_override.patch);
+                    serde_json::from_value(value).ok()
+                });
+                if patched.is_none() {
+                    log::warn!("Override for {} {} doesn't fit; using the shared version", kind.as_str(), key.2);
+                }
+                Some(patched.unwrap_or(entity))
+            })
+            .collect()
+    }
+
+    // ========================================================================
+    // Event Propagation
+    // ========================================================================
+
+    /// The setting a world event spreads through, if it spreads at all.
+    /// Events that were themselves propagated don't spread again.
+    pub fn spreading_setting(&self, event: &WorldEvent) -> Option<WorldSetting> {
+        if event.metadata.contains_key(PROPAGATED_FROM_FIELD) {
+            return None;
+        }
+        let source = self.membership(&event.campaign_id).filter(|m| m.share_events)?;
+        self.get_setting(&source.setting_id)
+            .filter(|s| s.propagation.applies_to(event))
+    }
+
+    /// Campaigns a world event recorded in its campaign should spread to
+    pub fn propagation_targets(&self, event: &WorldEvent) -> Vec<String> {
+        let Some(setting) = self.spreading_setting(event) else {
+            return Vec::new();
+        };
+        self.members(&setting.id)
+            .into_iter()
+            .filter(|m| m.receive_events && m.campaign_id != event.campaign_id)
+            .map(|m| m.campaign_id)
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn setting_with(manager: &WorldSettingManager, campaigns: &[&str]) -> WorldSetting {
+        let setting = manager.create_setting(WorldSetting::new("Forgotten Realms")).unwrap();
+        for campaign_id in campaigns {
+            manager.join(campaign_id, &setting.id).unwrap();
+        }
+        setting
+    }
+
+    fn event(campaign_id: &str, impact: EventImpact) -> WorldEvent {
+        WorldEvent::new(campaign_id, "The king is dead", "", InGameDate::new(1492, 3, 1))
+            .with_type(WorldEventType::Political)
+            .with_impact(impact)
+    }
+
+    #[test]
+    fn test_membership() {
+        let manager = WorldSettingManager::new();
+        let setting = setting_with(&manager, &["camp-a", "camp-b"]);
+        let other = manager.create_setting(WorldSetting::new("Eberron")).unwrap();
+
+        assert!(matches!(
+            manager.join("camp-a", &other.id),
+            Err(WorldSettingError::AlreadyMember(_, _))
+        ));
+        assert!(matches!(manager.join("camp-c", "missing"), Err(WorldSettingError::SettingNotFound(_))));
+        assert_eq!(manager.setting_for("camp-b").unwrap().id, setting.id);
+        assert_eq!(manager.members(&setting.id).len(), 2);
+
+        manager.leave("camp-b").unwrap();
+        assert!(manager.setting_for("camp-b").is_none());
+        assert_eq!(manager.delete_setting(&setting.id).unwrap(), vec!["camp-a".to_string()]);
+        assert!(manager.membership("camp-a").is_none());
+    }
+
+    #[test]
+    fn test_overrides_hide_and_patch() {
+        let manager = WorldSettingManager::new();
+        let setting = setting_with(&manager, &["camp-a", "camp-b"]);
+        let lathander = manager
+            .save_deity(&setting.id, Deity::new("Lathander").with_domains(vec!["Life".to_string()]))
+            .unwrap();
+        let bane = manager.save_deity(&setting.id, Deity::new("Bane")).unwrap();
+
+        manager
+            .set_override(
+                EntityOverride::new("camp-a", SharedEntityKind::Deity, &lathander.id)
+                    .with_patch(json!({ "title": "the Fallen Dawn", "domains": ["Death"] })),
+            )
+            .unwrap();
+        manager
+            .set_override(EntityOverride::new("camp-a", SharedEntityKind::Deity, &bane.id).hidden())
+            .unwrap();
+
+        let a = manager.deities_for("camp-a");
+        assert_eq!(a.len(), 1);
+        assert_eq!(a[0].title.as_deref(), Some("the Fallen Dawn"));
+        assert_eq!(a[0].domains, vec!["Death".to_string()]);
+
+        let b = manager.deities_for("camp-b");
+        assert_eq!(b.len(), 2);
+        assert!(b.iter().all(|d| d.title.is_none()));
+
+        manager.clear_override("camp-a", SharedEntityKind::Deity, &bane.id);
+        assert_eq!(manager.deities_for("camp-a").len(), 2);
+    }
+
+    #[test]
+    fn test_override_validation() {
+        let manager = WorldSettingManager::new();
+        setting_with(&manager, &["camp-a"]);
+
+        let protected = EntityOverride::new("camp-a", SharedEntityKind::Location, "loc-1").with_patch(json!({ "id": "x" }));
+        assert!(matches!(manager.set_override(protected), Err(WorldSettingError::InvalidOverride(_))));
+        let scalar = EntityOverride::new("camp-a", SharedEntityKind::Location, "loc-1").with_patch(json!("x"));
+        assert!(matches!(manager.set_override(scalar), Err(WorldSettingError::InvalidOverride(_))));
+        let outsider = EntityOverride::new("camp-z", SharedEntityKind::Location, "loc-1").hidden();
+        assert!(matches!(manager.set_override(outsider), Err(WorldSettingError::NotMember(_))));
+
+        // A patch that doesn't fit keeps the shared entity
+        let deity = Deity::new("Tymora");
+        manager
+            .set_override(
+                EntityOverride::new("camp-a", SharedEntityKind::Deity, &deity.id).with_patch(json!({ "domains": 3 })),
+            )
+            .unwrap();
+        let resolved = manager.apply_overrides("camp-a", SharedEntityKind::Deity, vec![deity], |d| &d.id);
+        assert_eq!(resolved[0].name, "Tymora");
+    }
+
+    #[test]
+    fn test_event_propagation_rules() {
+        let manager = WorldSettingManager::new();
+        let mut setting = setting_with(&manager, &["camp-a", "camp-b", "camp-c"]);
+        manager.set_event_sharing("camp-c", true, false).unwrap();
+
+        assert_eq!(manager.propagation_targets(&event("camp-a", EventImpact::National)), vec!["camp-b"]);
+        assert!(manager.propagation_targets(&event("camp-a", EventImpact::Local)).is_empty());
+
+        let mut secret = event("camp-a", EventImpact::Global);
+        secret.is_public = false;
+        assert!(manager.propagation_targets(&secret).is_empty());
+
+        setting.propagation.event_types = vec![WorldEventType::Natural];
+        manager.update_setting(setting).unwrap();
+        assert!(manager.propagation_targets(&event("camp-a", EventImpact::Global)).is_empty());
+        assert!(manager.propagation_targets(&event("camp-z", EventImpact::Global)).is_empty());
+    }
+
+    #[test]
+    fn test_propagated_event_does_not_spread_again() {
+        let manager = WorldSettingManager::new();
+        setting_with(&manager, &["camp-a", "camp-b"]);
+
+        let original = event("camp-a", EventImpact::Global);
+        let copy = propagated_event(&original, "camp-b", Some(InGameDate::new(1490, 1, 1)));
+
+        assert_ne!(copy.id, original.id);
+        assert_eq!(copy.campaign_id, "camp-b");
+        assert_eq!(copy.in_game_date.year, 1490);
+        assert_eq!(copy.metadata[PROPAGATED_FROM_FIELD]["event_id"], json!(original.id));
+        assert!(manager.propagation_targets(&copy).is_empty());
+    }
+}
@@ -179,7 +179,7 @@ pub enum WorldEventType {
 
 
 /// Impact level of an event
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[derive(Default)]
 pub enum EventImpact {
     /// Affects only individuals
@@ -576,6 +576,49 @@ impl WorldStateManager {
         Ok(())
     }
 
+    /// Copy a campaign's world state to another campaign
+    ///
+    /// Location keys and event location references are rewritten through
+    /// `location_ids`. Events get new ids and are only copied when
+    /// `include_events` is set.
+    pub fn copy_state(
+        &self,
+        from_campaign_id: &str,
+        to_campaign_id: &str,
+        location_ids: &HashMap<String, String>,
+        include_events: bool,
+    ) -> Option<WorldState> {
+        let mut state = self.get_state(from_campaign_id)?;
+        let remap = |id: &String| location_ids.get(id).cloned().unwrap_or_else(|| id.clone());
+
+        state.campaign_id = to_campaign_id.to_string();
+        state.locations = state
+            .locations
+            .into_values()
+            .map(|mut location| {
+                location.location_id = remap(&location.location_id);
+                (location.location_id.clone(), location)
+            })
+            .collect();
+
+        if include_events {
+            for event in &mut state.events {
+                event.id = Uuid::new_v4().to_string();
+                event.campaign_id = to_campaign_id.to_string();
+                event.location_ids = event.location_ids.iter().map(remap).collect();
+            }
+        } else {
+            state.events.clear();
+        }
+        state.updated_at = Utc::now();
+
+        self.states
+            .write()
+            .unwrap()
+            .insert(to_campaign_id.to_string(), state.clone());
+        Some(state)
+    }
+
     /// Delete world state for a campaign
     pub fn delete_state(&self, campaign_id: &str) {
         self.states.write().unwrap().remove(campaign_id);
@@ -732,6 +775,41 @@ impl WorldStateManager {
             .unwrap_or_default()
     }
 
+    /// Move an NPC to a location's notable NPCs, removing them from every
+    /// other location. Location state is created if it isn't tracked yet.
+    pub fn move_npc(
+        &self,
+        campaign_id: &str,
+        npc_id: &str,
+        location_id: &str,
+        location_name: &str,
+        date: &InGameDate,
+    ) -> Result<()> {
+        let mut states = self.states.write().unwrap();
+        let state = states
+            .get_mut(campaign_id)
+            .ok_or_else(|| WorldStateError::CampaignNotFound(campaign_id.to_string()))?;
+
+        for location in state.locations.values_mut() {
+            if location.location_id != location_id && location.notable_npcs.iter().any(|id| id == npc_id) {
+                location.notable_npcs.retain(|id| id != npc_id);
+                location.updated_at = Utc::now();
+            }
+        }
+
+        let location = state
+            .locations
+            .entry(location_id.to_string())
+            .or_insert_with(|| LocationState::new(location_id, location_name));
+        if !location.notable_npcs.iter().any(|id| id == npc_id) {
+            location.notable_npcs.push(npc_id.to_string());
+        }
+        location.last_accurate_date = date.clone();
+        location.updated_at = Utc::now();
+        state.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Update location condition
     pub fn update_location_condition(
         &self,
@@ -988,6 +1066,44 @@ mod tests {
         assert_eq!(retrieved.population, Some(100_000));
     }
 
+    #[test]
+    fn test_copy_state_remaps_locations() {
+        let manager = WorldStateManager::new();
+        manager.initialize("camp-1");
+        manager
+            .set_location_state("camp-1", LocationState::new("loc-1", "Neverwinter"))
+            .unwrap();
+        let event = WorldEvent::new("camp-1", "Fire", "The docks burned", InGameDate::new(1492, 6, 15))
+            .at_locations(vec!["loc-1".to_string()]);
+        manager.add_event("camp-1", event).unwrap();
+
+        let ids = HashMap::from([("loc-1".to_string(), "loc-2".to_string())]);
+        let copy = manager.copy_state("camp-1", "camp-2", &ids, true).unwrap();
+        assert_eq!(copy.campaign_id, "camp-2");
+        assert!(manager.get_location_state("camp-2", "loc-2").is_some());
+        assert_eq!(copy.events[0].location_ids, vec!["loc-2".to_string()]);
+        assert_eq!(manager.list_events("camp-1", None, None)[0].location_ids, vec!["loc-1".to_string()]);
+
+        let fresh = manager.copy_state("camp-1", "camp-3", &ids, false).unwrap();
+        assert!(fresh.events.is_empty());
+    }
+
+    #[test]
+    fn test_move_npc_between_locations() {
+        let manager = WorldStateManager::new();
+        manager.initialize("camp-1");
+        let date = InGameDate::new(1492, 6, 15);
+
+        manager.move_npc("camp-1", "npc-1", "loc-temple", "Temple", &date).unwrap();
+        manager.move_npc("camp-1", "npc-1", "loc-market", "Market", &date).unwrap();
+
+        let temple = manager.get_location_state("camp-1", "loc-temple").unwrap();
+        assert!(temple.notable_npcs.is_empty());
+        let market = manager.get_location_state("camp-1", "loc-market").unwrap();
+        assert_eq!(market.notable_npcs, vec!["npc-1".to_string()]);
+        assert_eq!(market.last_accurate_date, date);
+    }
+
     #[test]
     fn test_custom_fields() {
         let manager = WorldStateManager::new();
@@ -0,0 +1,725 @@
+//! World Update Proposals
+//!
+//! Turns a played session into a reviewable changeset of world-state
+//! changes (an NPC died, a location was destroyed, a relationship shifted).
+//! The model proposes; the GM approves or rejects each change, and only
+//! approved changes are applied to the world state.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::world_state::{
+    EventImpact, InGameDate, InteractionRecord, LocationCondition, LocationState, NpcRelationshipState, WorldEvent,
+    WorldEventType, WorldStateError, WorldStateManager,
+};
+
+/// World event metadata key linking an applied change to its changeset
+pub const CHANGESET_FIELD: &str = "changeset_id";
+
+/// Proposals the model is less sure of than this are dropped
+pub const MIN_CONFIDENCE: f32 = 0.3;
+
+/// System instructions for world update extraction
+pub const WORLD_UPDATE_SYSTEM_PROMPT: &str = "You maintain the world state of a tabletop RPG campaign. \
+Read the session record and list only lasting changes to the world that clearly happened in play. \
+Never speculate or invent outcomes. Respond only with valid JSON.";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ChangesetError {
+    #[error("Changeset not found: {0}")]
+    ChangesetNotFound(String),
+
+    #[error("Change not found: {0}")]
+    ChangeNotFound(String),
+
+    #[error("Changeset {0} has already been applied or discarded")]
+    AlreadyResolved(String),
+
+    #[error(transparent)]
+    WorldState(#[from] WorldStateError),
+}
+
+pub type Result<T> = std::result::Result<T, ChangesetError>;
+
+// ============================================================================
+// Changeset Types
+// ============================================================================
+
+/// A change to the world state
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorldChange {
+    NpcDied {
+        npc_id: Option<String>,
+        npc_name: String,
+    },
+    LocationCondition {
+        location_id: Option<String>,
+        location_name: String,
+        condition: LocationCondition,
+    },
+    RelationshipChanged {
+        npc_id: Option<String>,
+        npc_name: String,
+        target_id: Option<String>,
+        target_name: String,
+        disposition_change: i32,
+    },
+    WorldFact {
+        title: String,
+        description: String,
+    },
+}
+
+impl WorldChange {
+    /// One-line description for review lists and event titles
+    pub fn describe(&self) -> String {
+        match self {
+            Self::NpcDied { npc_name, .. } => format!("{} died", npc_name),
+            Self::LocationCondition {
+                location_name,
+                condition,
+                ..
+            } => format!("{} is now {}", location_name, condition),
+            Self::RelationshipChanged {
+                npc_name,
+                target_name,
+                disposition_change,
+                ..
+            } => format!(
+                "{}'s attitude toward {} {} by {}",
+                npc_name,
+                target_name,
+                if *disposition_change < 0 { "worsened" } else { "improved" },
+                disposition_change.abs()
+            ),
+            Self::WorldFact { title, .. } => title.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeDecision {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A proposed change awaiting the GM's decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposedChange {
+    pub id: String,
+    pub change: WorldChange,
+    /// Why the change follows from the session
+    pub reason: String,
+    /// Quote or paraphrase from the session record
+    pub evidence: String,
+    pub confidence: f32,
+    pub decision: ChangeDecision,
+}
+
+impl ProposedChange {
+    pub fn new(change: WorldChange, reason: &str) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            change,
+            reason: reason.to_string(),
+            evidence: String::new(),
+            confidence: 1.0,
+            decision: ChangeDecision::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangesetStatus {
+    Pending,
+    Applied,
+    Discarded,
+}
+
+impl ChangesetStatus {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "pending" => Some(Self::Pending),
+            "applied" => Some(Self::Applied),
+            "discarded" => Some(Self::Discarded),
+            _ => None,
+        }
+    }
+}
+
+/// World-state changes proposed from one session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldStateChangeset {
+    pub id: String,
+    pub campaign_id: String,
+    pub session_id: String,
+    pub session_number: u32,
+    pub changes: Vec<ProposedChange>,
+    pub status: ChangesetStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Campaign version created when the changeset was applied
+    pub version_id: Option<String>,
+}
+
+impl WorldStateChangeset {
+    pub fn new(campaign_id: &str, session_id: &str, session_number: u32, changes: Vec<ProposedChange>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            campaign_id: campaign_id.to_string(),
+            session_id: session_id.to_string(),
+            session_number,
+            changes,
+            status: ChangesetStatus::Pending,
+            created_at: Utc::now(),
+            resolved_at: None,
+            version_id: None,
+        }
+    }
+}
+
+/// The result of applying a changeset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangesetApplication {
+    pub changeset: WorldStateChangeset,
+    /// World events recorded for the applied changes
+    pub events: Vec<WorldEvent>,
+    /// Changes that could not be applied, with the reason
+    pub failures: Vec<String>,
+}
+
+// ============================================================================
+// Extraction Prompt
+// ============================================================================
+
+/// A campaign entity the model can refer to by name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownEntity {
+    pub id: String,
+    pub name: String,
+}
+
+/// Build the user prompt asking for world changes from a session transcript
+pub fn build_world_update_prompt(transcript: &str, npcs: &[KnownEntity], locations: &[KnownEntity]) -> String {
+    let mut prompt = format!(
+        "List the lasting world-state changes from the following TTRPG session.\n\n{}",
+        transcript
+    );
+
+    let names = |entities: &[KnownEntity]| entities.iter().map(|e| e.name.as_str()).collect::<Vec<_>>().join(", ");
+    if !npcs.is_empty() {
+        prompt.push_str(&format!("\nKNOWN NPCS: {}\n", names(npcs)));
+    }
+    if !locations.is_empty() {
+        prompt.push_str(&format!("KNOWN LOCATIONS: {}\n", names(locations)));
+    }
+
+    prompt.push_str(
+        r#"
+Please respond in JSON format with:
+{
+  "changes": [
+    {"type": "npc_died", "npc": "<name>", "reason": "<why>", "evidence": "<quote>", "confidence": 0.0-1.0},
+    {"type": "location_condition", "location": "<name>", "condition": "damaged|ruined|destroyed|occupied|abandoned|under_siege|cursed|blessed|normal", "reason": "...", "evidence": "...", "confidence": 0.0-1.0},
+    {"type": "relationship_changed", "npc": "<name>", "target": "<name>", "change": -50 to 50, "reason": "...", "evidence": "...", "confidence": 0.0-1.0},
+    {"type": "world_fact", "title": "<short>", "description": "<lasting fact>", "reason": "...", "evidence": "...", "confidence": 0.0-1.0}
+  ]
+}
+Use names exactly as listed above where possible. Return an empty list if nothing lasting changed."#,
+    );
+
+    prompt
+}
+
+/// A change as written by the model
+#[derive(Debug, Deserialize)]
+struct RawChange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    npc: Option<String>,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    location: Option<String>,
+    #[serde(default)]
+    condition: Option<String>,
+    #[serde(default)]
+    change: Option<i32>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    reason: String,
+    #[serde(default)]
+    evidence: String,
+    #[serde(default)]
+    confidence: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawChanges {
+    #[serde(default)]
+    changes: Vec<RawChange>,
+}
+
+fn resolve(name: &str, entities: &[KnownEntity]) -> Option<String> {
+    entities
+        .iter()
+        .find(|e| e.name.eq_ignore_ascii_case(name.trim()))
+        .map(|e| e.id.clone())
+}
+
+/// Parse the model's proposals, matching names to known NPCs and locations.
+/// Malformed and low-confidence entries are dropped.
+pub fn parse_world_update_response(
+    response: &str,
+    npcs: &[KnownEntity],
+    locations: &[KnownEntity],
+) -> Vec<ProposedChange> {
+    let trimmed = response.trim();
+    let Some(raw) = trimmed
+        .find('{')
+        .zip(trimmed.rfind('}'))
+        .filter(|(start, end)| start < end)
+        .and_then(|(start, end)| serde_json::from_str::<RawChanges>(&trimmed[start..=end]).ok())
+    else {
+        return vec![];
+    };
+
+    raw.changes
+        .into_iter()
+        .filter_map(|raw| {
+            let confidence = raw.confidence.unwrap_or(0.5).clamp(0.0, 1.0);
+            if confidence < MIN_CONFIDENCE {
+                return None;
+            }
+            let change = match raw.kind.as_str() {
+                "npc_died" => {
+                    let npc = raw.npc?;
+                    WorldChange::NpcDied {
+                        npc_id: resolve(&npc, npcs),
+                        npc_name: npc,
+                    }
+                }
+                "location_condition" => {
+                    let location = raw.location?;
+                    WorldChange::LocationCondition {
+                        location_id: resolve(&location, locations),
+                        location_name: location,
+                        condition: raw.condition?.parse().ok()?,
+                    }
+                }
+                "relationship_changed" => {
+                    let (npc, target) = (raw.npc?, raw.target?);
+                    WorldChange::RelationshipChanged {
+                        npc_id: resolve(&npc, npcs),
+                        npc_name: npc,
+                        target_id: resolve(&target, npcs),
+                        target_name: target,
+                        disposition_change: raw.change?.clamp(-100, 100),
+                    }
+                }
+                "world_fact" => WorldChange::WorldFact {
+                    title: raw.title?,
+                    description: raw.description.unwrap_or_default(),
+                },
+                _ => return None,
+            };
+            let mut proposed = ProposedChange::new(change, &raw.reason);
+            proposed.evidence = raw.evidence;
+            proposed.confidence = confidence;
+            Some(proposed)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Applying Changes
+// ============================================================================
+
+/// Apply one change to the world state and record it as a world event
+pub fn apply_change(
+    world: &WorldStateManager,
+    campaign_id: &str,
+    changeset_id: &str,
+    proposed: &ProposedChange,
+    date: &InGameDate,
+    session_number: u32,
+) -> std::result::Result<WorldEvent, WorldStateError> {
+    let mut event = WorldEvent::new(campaign_id, &proposed.change.describe(), &proposed.reason, date.clone());
+
+    match &proposed.change {
+        WorldChange::NpcDied { npc_id, .. } => {
+            event = event.with_type(WorldEventType::Personal).with_impact(EventImpact::Personal);
+            if let Some(npc_id) = npc_id {
+                for mut location in world.list_locations(campaign_id) {
+                    if location.notable_npcs.iter().any(|id| id == npc_id) {
+                        location.notable_npcs.retain(|id| id != npc_id);
+                        location.updated_at = Utc::now();
+                        world.set_location_state(campaign_id, location)?;
+                    }
+                }
+                event = event.involving_npcs(vec![npc_id.clone()]);
+            }
+        }
+        WorldChange::LocationCondition {
+            location_id,
+            location_name,
+            condition,
+        } => {
+            if let Some(location_id) = location_id {
+                if world.get_location_state(campaign_id, location_id).is_none() {
+                    world.set_location_state(campaign_id, LocationState::new(location_id, location_name))?;
+                }
+                world.update_location_condition(campaign_id, location_id, condition.clone())?;
+                event = event.at_locations(vec![location_id.clone()]);
+            }
+        }
+        WorldChange::RelationshipChanged {
+            npc_id,
+            target_id,
+            target_name,
+            disposition_change,
+            ..
+        } => {
+            event = event.with_type(WorldEventType::Personal).with_impact(EventImpact::Personal);
+            if let (Some(npc_id), Some(target_id)) = (npc_id, target_id) {
+                let interaction = InteractionRecord {
+                    in_game_date: date.clone(),
+                    description: proposed.reason.clone(),
+                    disposition_change: *disposition_change,
+                    session_number: Some(session_number),
+                };
+                let exists = world.get_npc_relationships(campaign_id, npc_id).iter().any(|r| &r.target_id == target_id);
+                if exists {
+                    world.modify_disposition(campaign_id, npc_id, target_id, *disposition_change, Some(interaction))?;
+                } else {
+                    world.set_npc_relationship(
+                        campaign_id,
+                        NpcRelationshipState {
+                            npc_id: npc_id.clone(),
+                            target_id: target_id.clone(),
+                            target_type: "NPC".to_string(),
+                            disposition: (*disposition_change).clamp(-100, 100),
+                            relationship_type: "acquaintance".to_string(),
+                            familiarity: 10,
+                            recent_interactions: vec![interaction],
+                            notes: format!("Relationship with {} began in session {}", target_name, session_number),
+                        },
+                    )?;
+                }
+                event = event.involving_npcs(vec![npc_id.clone(), target_id.clone()]);
+            }
+        }
+        WorldChange::WorldFact { description, .. } => {
+            if !description.is_empty() {
+                event.description = description.clone();
+            }
+        }
+    }
+
+    event.session_number = Some(session_number);
+    if !proposed.evidence.is_empty() {
+        event.consequences.push(proposed.evidence.clone());
+    }
+    event
+        .metadata
+        .insert(CHANGESET_FIELD.to_string(), serde_json::json!(changeset_id));
+    world.add_event(campaign_id, event)
+}
+
+// ============================================================================
+// Changeset Manager
+// ============================================================================
+
+/// Holds proposed world-state changesets until they are reviewed
+pub struct ChangesetManager {
+    changesets: RwLock<HashMap<String, WorldStateChangeset>>,
+}
+
+impl Default for ChangesetManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChangesetManager {
+    pub fn new() -> Self {
+        Self {
+            changesets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn add_changeset(&self, changeset: WorldStateChangeset) -> WorldStateChangeset {
+        self.changesets
+            .write()
+            .unwrap()
+            .insert(changeset.id.clone(), changeset.clone());
+        changeset
+    }
+
+    pub fn get_changeset(&self, changeset_id: &str) -> Option<WorldStateChangeset> {
+        self.changesets.read().unwrap().get(changeset_id).cloned()
+    }
+
+    /// List a campaign's changesets, newest first
+    pub fn list_changesets(&self, campaign_id: &str, status: Option<ChangesetStatus>) -> Vec<WorldStateChangeset> {
+        let mut changesets: Vec<WorldStateChangeset> = self
+            .changesets
+            .read()
+            .unwrap()
+            .values()
+            .filter(|c| c.campaign_id == campaign_id && status.is_none_or(|s| c.status == s))
+            .cloned()
+            .collect();
+        changesets.sort_by_key(|c| std::cmp::Reverse(c.created_at));
+        changesets
+    }
+
+    fn with_pending<T>(
+        &self,
+        changeset_id: &str,
+        f: impl FnOnce(&mut WorldStateChangeset) -> Result<T>,
+    ) -> Result<T> {
+        let mut changesets = self.changesets.write().unwrap();
+        let changeset = changesets
+            .get_mut(changeset_id)
+            .ok_or_else(|| ChangesetError::ChangesetNotFound(changeset_id.to_string()))?;
+        if changeset.status != ChangesetStatus::Pending {
+            return Err(ChangesetError::AlreadyResolved(changeset_id.to_string()));
+        }
+        f(changeset)
+    }
+
+    /// Approve or reject a single proposed change
+    pub fn decide(&self, changeset_id: &str, change_id: &str, decision: ChangeDecision) -> Result<WorldStateChangeset> {
+        self.with_pending(changeset_id, |changeset| {
+            let change = changeset
+                .changes
+                .iter_mut()
+                .find(|c| c.id == change_id)
+                .ok_or_else(|| ChangesetError::ChangeNotFound(change_id.to_string()))?;
+            change.decision = decision;
+            Ok(changeset.clone())
+        })
+    }
+
+    /// Discard a changeset without applying anything
+    pub fn discard(&self, changeset_id: &str) -> Result<WorldStateChangeset> {
+        self.with_pending(changeset_id, |changeset| {
+            changeset.status = ChangesetStatus::Discarded;
+            changeset.resolved_at = Some(Utc::now());
+            Ok(changeset.clone())
+        })
+    }
+
+    /// Apply the approved changes of a pending changeset. Changes still
+    /// pending are treated as rejected.
+    pub fn apply(
+        &self,
+        changeset_id: &str,
+        world: &WorldStateManager,
+        date: &InGameDate,
+    ) -> Result<ChangesetApplication> {
+        self.with_pending(changeset_id, |changeset| {
+            let mut events = Vec::new();
+            let mut failures = Vec::new();
+            for proposed in changeset
+                .changes
+                .iter()
+                .filter(|c| c.decision == ChangeDecision::Approved)
+            {
+                match apply_change(
+                    world,
+                    &changeset.campaign_id,
+                    &changeset.id,
+                    proposed,
+                    date,
+                    changeset.session_number,
+                ) {
+                    Ok(event) => events.push(event),
+                    Err(e) => failures.push(format!("{}: {}", proposed.change.describe(), e)),
+                }
+            }
+
+            changeset.status = ChangesetStatus::Applied;
+            changeset.resolved_at = Some(Utc::now());
+            Ok(ChangesetApplication {
+                changeset: changeset.clone(),
+                events,
+                failures,
+            })
+        })
+    }
+
+    /// Record the campaign version created for an applied changeset
+    pub fn set_version(&self, changeset_id: &str, version_id: &str) -> Result<()> {
+        let mut changesets = self.changesets.write().unwrap();
+        let changeset = changesets
+            .get_mut(changeset_id)
+            .ok_or_else(|| ChangesetError::ChangesetNotFound(changeset_id.to_string()))?;
+        changeset.version_id = Some(version_id.to_string());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npcs() -> Vec<KnownEntity> {
+        vec![
+            KnownEntity {
+                id: "npc-1".to_string(),
+                name: "Baron Vane".to_string(),
+            },
+            KnownEntity {
+                id: "npc-2".to_string(),
+                name: "Mira".to_string(),
+            },
+        ]
+    }
+
+    fn locations() -> Vec<KnownEntity> {
+        vec![KnownEntity {
+            id: "loc-1".to_string(),
+            name: "Old Mill".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_prompt_lists_known_entities() {
+        let prompt = build_world_update_prompt("SESSION: Session 4\n", &npcs(), &locations());
+        assert!(prompt.contains("SESSION: Session 4"));
+        assert!(prompt.contains("KNOWN NPCS: Baron Vane, Mira"));
+        assert!(prompt.contains("KNOWN LOCATIONS: Old Mill"));
+    }
+
+    #[test]
+    fn test_parse_resolves_names_and_drops_bad_entries() {
+        let response = r#"```json
+{"changes": [
+  {"type": "npc_died", "npc": "baron vane", "reason": "Slain in the duel", "confidence": 0.9},
+  {"type": "location_condition", "location": "Old Mill", "condition": "destroyed", "reason": "Burned down"},
+  {"type": "relationship_changed", "npc": "Mira", "target": "Stranger", "change": -80, "confidence": 0.8},
+  {"type": "npc_died", "npc": "Goblin", "confidence": 0.1},
+  {"type": "location_condition", "location": "Old Mill"},
+  {"type": "weather"}
+]}
+```"#;
+        let changes = parse_world_update_response(response, &npcs(), &locations());
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            changes[0].change,
+            WorldChange::NpcDied {
+                npc_id: Some("npc-1".to_string()),
+                npc_name: "baron vane".to_string(),
+            }
+        );
+        assert!(matches!(
+            &changes[1].change,
+            WorldChange::LocationCondition { location_id: Some(id), condition: LocationCondition::Destroyed, .. } if id == "loc-1"
+        ));
+        assert!(matches!(
+            &changes[2].change,
+            WorldChange::RelationshipChanged { target_id: None, disposition_change: -80, .. }
+        ));
+        assert!(parse_world_update_response("Nothing changed.", &npcs(), &locations()).is_empty());
+    }
+
+    #[test]
+    fn test_apply_only_approved_changes() {
+        let world = WorldStateManager::new();
+        world.initialize("camp-1");
+        world.move_npc("camp-1", "npc-1", "loc-2", "Keep", &InGameDate::default()).unwrap();
+
+        let died = ProposedChange::new(
+            WorldChange::NpcDied {
+                npc_id: Some("npc-1".to_string()),
+                npc_name: "Baron Vane".to_string(),
+            },
+            "Slain in the duel",
+        );
+        let burned = ProposedChange::new(
+            WorldChange::LocationCondition {
+                location_id: Some("loc-1".to_string()),
+                location_name: "Old Mill".to_string(),
+                condition: LocationCondition::Destroyed,
+            },
+            "Burned down",
+        );
+        let manager = ChangesetManager::new();
+        let changeset = manager.add_changeset(WorldStateChangeset::new("camp-1", "s-1", 4, vec![died.clone(), burned]));
+        manager.decide(&changeset.id, &died.id, ChangeDecision::Approved).unwrap();
+
+        let applied = manager.apply(&changeset.id, &world, &InGameDate::default()).unwrap();
+        assert_eq!(applied.events.len(), 1);
+        assert_eq!(applied.events[0].title, "Baron Vane died");
+        assert_eq!(applied.events[0].session_number, Some(4));
+        assert!(world.get_location_state("camp-1", "loc-2").unwrap().notable_npcs.is_empty());
+        assert!(world.get_location_state("camp-1", "loc-1").is_none());
+        assert_eq!(applied.changeset.status, ChangesetStatus::Applied);
+
+        assert!(matches!(
+            manager.apply(&changeset.id, &world, &InGameDate::default()),
+            Err(ChangesetError::AlreadyResolved(_))
+        ));
+    }
+
+    #[test]
+    fn test_relationship_change_creates_or_modifies_relationship() {
+        let world = WorldStateManager::new();
+        world.initialize("camp-1");
+        let mut change = ProposedChange::new(
+            WorldChange::RelationshipChanged {
+                npc_id: Some("npc-1".to_string()),
+                npc_name: "Baron Vane".to_string(),
+                target_id: Some("npc-2".to_string()),
+                target_name: "Mira".to_string(),
+                disposition_change: -20,
+            },
+            "Mira exposed the baron",
+        );
+        change.decision = ChangeDecision::Approved;
+
+        apply_change(&world, "camp-1", "cs-1", &change, &InGameDate::default(), 2).unwrap();
+        apply_change(&world, "camp-1", "cs-1", &change, &InGameDate::default(), 3).unwrap();
+
+        let relationships = world.get_npc_relationships("camp-1", "npc-1");
+        assert_eq!(relationships.len(), 1);
+        assert_eq!(relationships[0].disposition, -40);
+        assert_eq!(relationships[0].recent_interactions.len(), 2);
+    }
+
+    #[test]
+    fn test_discard_and_list() {
+        let manager = ChangesetManager::new();
+        let changeset = manager.add_changeset(WorldStateChangeset::new("camp-1", "s-1", 1, vec![]));
+        manager.add_changeset(WorldStateChangeset::new("camp-2", "s-2", 1, vec![]));
+
+        assert_eq!(manager.list_changesets("camp-1", Some(ChangesetStatus::Pending)).len(), 1);
+        manager.discard(&changeset.id).unwrap();
+        assert!(manager.list_changesets("camp-1", Some(ChangesetStatus::Pending)).is_empty());
+        assert_eq!(manager.list_changesets("camp-1", None).len(), 1);
+        assert!(manager.decide(&changeset.id, "x", ChangeDecision::Approved).is_err());
+    }
+}
@@ -1,15 +1,21 @@
 //! Campaign Manager Module
 //!
 //! Handles TTRPG campaign lifecycle: creation, versioning, rollback, and notes.
+//! Campaigns can optionally be encrypted at rest; encrypted campaigns are
+//! written to the data directory and decrypted transparently on load.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use thiserror::Error;
 
+use crate::core::campaign::encryption::{
+    CampaignKey, CampaignKeys, EncryptedCampaign, EncryptionError, ENCRYPTED_CAMPAIGN_EXTENSION,
+};
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -33,6 +39,21 @@ pub enum CampaignError {
 
     #[error("Maximum snapshots reached for campaign")]
     MaxSnapshotsReached,
+
+    #[error("Campaign is locked: {0}")]
+    Locked(String),
+
+    #[error("Campaign is not encrypted: {0}")]
+    NotEncrypted(String),
+
+    #[error("Campaign is already encrypted: {0}")]
+    AlreadyEncrypted(String),
+
+    #[error("No data directory configured for encrypted storage")]
+    StorageUnavailable,
+
+    #[error("Encryption error: {0}")]
+    Encryption(#[from] EncryptionError),
 }
 
 pub type Result<T> = std::result::Result<T, CampaignError>;
@@ -90,6 +111,12 @@ pub struct CampaignSettings {
     /// Dynamic theme blending weights
     #[serde(default)]
     pub theme_weights: ThemeWeights,
+    /// Table house rules
+    #[serde(default)]
+    pub house_rules: Vec<String>,
+    /// Default name bank culture for generated NPCs (e.g. "norse")
+    #[serde(default)]
+    pub name_culture: Option<String>,
 }
 
 fn default_theme() -> String {
@@ -126,6 +153,14 @@ pub struct SessionNote {
     pub session_number: Option<u32>,
 }
 
+/// An encrypted campaign whose key hasn't been supplied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedCampaign {
+    pub id: String,
+    pub name: String,
+    pub encrypted_at: DateTime<Utc>,
+}
+
 /// Summary of a snapshot for listing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotSummary {
@@ -164,9 +199,12 @@ pub struct CampaignManager {
     campaigns: RwLock<HashMap<String, Campaign>>,
     snapshots: RwLock<HashMap<String, Vec<CampaignSnapshot>>>,
     notes: RwLock<HashMap<String, Vec<SessionNote>>>,
-    /// Data directory for campaign persistence (reserved for future file-based storage)
-    #[allow(dead_code)]
-    data_dir: Option<std::path::PathBuf>,
+    /// Campaign ID -> keys of unlocked encrypted campaigns
+    encrypted: RwLock<HashMap<String, CampaignKeys>>,
+    /// Campaign ID -> encrypted campaigns not loaded into memory
+    locked: RwLock<HashMap<String, LockedCampaign>>,
+    /// Data directory for encrypted campaign files
+    data_dir: Option<PathBuf>,
 }
 
 impl Default for CampaignManager {
@@ -181,6 +219,8 @@ impl CampaignManager {
             campaigns: RwLock::new(HashMap::new()),
             snapshots: RwLock::new(HashMap::new()),
             notes: RwLock::new(HashMap::new()),
+            encrypted: RwLock::new(HashMap::new()),
+            locked: RwLock::new(HashMap::new()),
             data_dir: None,
         }
     }
@@ -191,10 +231,17 @@ impl CampaignManager {
             campaigns: RwLock::new(HashMap::new()),
             snapshots: RwLock::new(HashMap::new()),
             notes: RwLock::new(HashMap::new()),
+            encrypted: RwLock::new(HashMap::new()),
+            locked: RwLock::new(HashMap::new()),
             data_dir: Some(data_dir.as_ref().to_path_buf()),
         }
     }
 
+    /// Set the directory encrypted campaigns are stored in
+    pub fn set_data_dir(&mut self, data_dir: impl AsRef<Path>) {
+        self.data_dir = Some(data_dir.as_ref().to_path_buf());
+    }
+
     // ========================================================================
     // Campaign CRUD
     // ========================================================================
@@ -215,6 +262,8 @@ impl CampaignManager {
                 tags: vec![],
                 theme: "fantasy".to_string(),
                 theme_weights: ThemeWeights::default(),
+                house_rules: vec![],
+                name_culture: None,
             },
         };
 
@@ -233,6 +282,9 @@ impl CampaignManager {
 
     pub fn update_campaign(&self, mut campaign: Campaign, auto_snapshot: bool) -> Result<()> {
         let id = campaign.id.clone();
+        if self.is_locked(&id) {
+            return Err(CampaignError::Locked(id));
+        }
 
         // Auto-snapshot if enabled
         if auto_snapshot {
@@ -248,11 +300,17 @@ impl CampaignManager {
         }
 
         campaign.updated_at = Utc::now().to_rfc3339();
-        self.campaigns.write().unwrap().insert(id, campaign);
+        self.campaigns.write().unwrap().insert(id.clone(), campaign);
+        self.sync_encrypted(&id);
         Ok(())
     }
 
     pub fn delete_campaign(&self, id: &str) -> Result<()> {
+        if self.locked.write().unwrap().remove(id).is_some() {
+            std::fs::remove_file(self.encrypted_path(id)?)?;
+            return Ok(());
+        }
+
         let mut campaigns = self.campaigns.write().unwrap();
         if campaigns.remove(id).is_none() {
             return Err(CampaignError::NotFound(id.to_string()));
@@ -262,6 +320,12 @@ impl CampaignManager {
         self.snapshots.write().unwrap().remove(id);
         self.notes.write().unwrap().remove(id);
 
+        if self.encrypted.write().unwrap().remove(id).is_some() {
+            if let Ok(path) = self.encrypted_path(id) {
+                std::fs::remove_file(path)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -270,7 +334,9 @@ impl CampaignManager {
     // ========================================================================
 
     pub fn create_snapshot(&self, campaign_id: &str, description: &str) -> Result<String> {
-        self.create_snapshot_internal(campaign_id, description, SnapshotType::Manual)
+        let snapshot_id = self.create_snapshot_internal(campaign_id, description, SnapshotType::Manual)?;
+        self.sync_encrypted(campaign_id);
+        Ok(snapshot_id)
     }
 
     fn create_snapshot_internal(
@@ -365,6 +431,7 @@ impl CampaignManager {
 
         self.campaigns.write().unwrap()
             .insert(campaign_id.to_string(), restored);
+        self.sync_encrypted(campaign_id);
 
         Ok(())
     }
@@ -379,6 +446,8 @@ impl CampaignManager {
             .ok_or_else(|| CampaignError::SnapshotNotFound(snapshot_id.to_string()))?;
 
         campaign_snapshots.remove(pos);
+        drop(snapshots);
+        self.sync_encrypted(campaign_id);
         Ok(())
     }
 
@@ -436,6 +505,7 @@ impl CampaignManager {
             .entry(campaign_id.to_string())
             .or_default()
             .push(note.clone());
+        self.sync_encrypted(campaign_id);
         note
     }
 
@@ -462,6 +532,8 @@ impl CampaignManager {
             .ok_or_else(|| CampaignError::NoteNotFound(note.id.clone()))?;
 
         campaign_notes[pos] = note;
+        drop(notes);
+        self.sync_encrypted(campaign_id);
         Ok(())
     }
 
@@ -475,6 +547,8 @@ impl CampaignManager {
             .ok_or_else(|| CampaignError::NoteNotFound(note_id.to_string()))?;
 
         campaign_notes.remove(pos);
+        drop(notes);
+        self.sync_encrypted(campaign_id);
         Ok(())
     }
 
@@ -514,6 +588,45 @@ impl CampaignManager {
             .unwrap_or_default()
     }
 
+    // ========================================================================
+    // Cloning
+    // ========================================================================
+
+    /// Deep-copy a campaign under a new id, optionally with its notes
+    ///
+    /// Snapshots are not copied; the clone starts its own history.
+    pub fn clone_campaign(&self, campaign_id: &str, name: Option<&str>, include_notes: bool) -> Result<Campaign> {
+        let mut campaign = self.get_campaign(campaign_id)
+            .ok_or_else(|| CampaignError::NotFound(campaign_id.to_string()))?;
+
+        campaign.id = Uuid::new_v4().to_string();
+        campaign.name = name
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} (Copy)", campaign.name));
+        campaign.created_at = Utc::now().to_rfc3339();
+        campaign.updated_at = campaign.created_at.clone();
+
+        if include_notes {
+            let notes: Vec<SessionNote> = self.get_notes(campaign_id).into_iter()
+                .map(|mut n| {
+                    n.id = Uuid::new_v4().to_string();
+                    n.campaign_id = campaign.id.clone();
+                    n
+                })
+                .collect();
+            if !notes.is_empty() {
+                self.notes.write().unwrap()
+                    .insert(campaign.id.clone(), notes);
+            }
+        } else {
+            campaign.notes.clear();
+        }
+
+        self.campaigns.write().unwrap()
+            .insert(campaign.id.clone(), campaign.clone());
+        Ok(campaign)
+    }
+
     // ========================================================================
     // Export / Import
     // ========================================================================
@@ -600,6 +713,166 @@ impl CampaignManager {
             .map_err(|e| CampaignError::SerializationError(e.to_string()))?;
         self.import_campaign(export, new_id)
     }
+
+    // ========================================================================
+    // Encryption at Rest
+    // ========================================================================
+
+    fn encrypted_path(&self, campaign_id: &str) -> Result<PathBuf> {
+        let dir = self.data_dir.as_ref().ok_or(CampaignError::StorageUnavailable)?;
+        Ok(dir.join(format!("{}.{}", campaign_id, ENCRYPTED_CAMPAIGN_EXTENSION)))
+    }
+
+    pub fn is_encrypted(&self, campaign_id: &str) -> bool {
+        self.encrypted.read().unwrap().contains_key(campaign_id) || self.is_locked(campaign_id)
+    }
+
+    pub fn is_locked(&self, campaign_id: &str) -> bool {
+        self.locked.read().unwrap().contains_key(campaign_id)
+    }
+
+    pub fn list_locked_campaigns(&self) -> Vec<LockedCampaign> {
+        let mut locked: Vec<LockedCampaign> = self.locked.read().unwrap().values().cloned().collect();
+        locked.sort_by(|a, b| a.name.cmp(&b.name));
+        locked
+    }
+
+    /// The encrypted file stored for a campaign
+    pub fn read_encrypted(&self, campaign_id: &str) -> Result<EncryptedCampaign> {
+        Ok(EncryptedCampaign::read(&self.encrypted_path(campaign_id)?)?)
+    }
+
+    fn save_encrypted(&self, campaign_id: &str, keys: &CampaignKeys) -> Result<EncryptedCampaign> {
+        let path = self.encrypted_path(campaign_id)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let sealed = EncryptedCampaign::seal(&self.export_campaign(campaign_id)?, keys)?;
+        sealed.write(&path)?;
+        Ok(sealed)
+    }
+
+    /// Re-encrypt an encrypted campaign after a change. Failures are logged
+    /// rather than returned since the in-memory copy is already updated.
+    fn sync_encrypted(&self, campaign_id: &str) {
+        let keys = self.encrypted.read().unwrap().get(campaign_id).cloned();
+        if let Some(keys) = keys {
+            if let Err(e) = self.save_encrypted(campaign_id, &keys) {
+                log::warn!("Failed to save encrypted campaign {}: {}", campaign_id, e);
+            }
+        }
+    }
+
+    /// Start storing a campaign encrypted on disk
+    pub fn encrypt_campaign(&self, campaign_id: &str, keys: CampaignKeys) -> Result<()> {
+        if self.is_encrypted(campaign_id) {
+            return Err(CampaignError::AlreadyEncrypted(campaign_id.to_string()));
+        }
+        self.save_encrypted(campaign_id, &keys)?;
+        self.encrypted.write().unwrap().insert(campaign_id.to_string(), keys);
+        Ok(())
+    }
+
+    /// Stop encrypting an unlocked campaign and remove its encrypted file
+    pub fn decrypt_campaign(&self, campaign_id: &str) -> Result<()> {
+        if self.is_locked(campaign_id) {
+            return Err(CampaignError::Locked(campaign_id.to_string()));
+        }
+        if self.encrypted.write().unwrap().remove(campaign_id).is_none() {
+            return Err(CampaignError::NotEncrypted(campaign_id.to_string()));
+        }
+        let path = self.encrypted_path(campaign_id)?;
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Save an encrypted campaign and drop its plaintext from memory
+    pub fn lock_campaign(&self, campaign_id: &str) -> Result<LockedCampaign> {
+        if let Some(locked) = self.locked.read().unwrap().get(campaign_id) {
+            return Ok(locked.clone());
+        }
+        let keys = self.encrypted.read().unwrap().get(campaign_id).cloned()
+            .ok_or_else(|| CampaignError::NotEncrypted(campaign_id.to_string()))?;
+        let sealed = self.save_encrypted(campaign_id, &keys)?;
+
+        self.campaigns.write().unwrap().remove(campaign_id);
+        self.snapshots.write().unwrap().remove(campaign_id);
+        self.notes.write().unwrap().remove(campaign_id);
+        self.encrypted.write().unwrap().remove(campaign_id);
+
+        let locked = LockedCampaign {
+            id: campaign_id.to_string(),
+            name: sealed.name,
+            encrypted_at: sealed.encrypted_at,
+        };
+        self.locked.write().unwrap().insert(campaign_id.to_string(), locked.clone());
+        Ok(locked)
+    }
+
+    /// Decrypt a locked campaign and load it back into memory
+    pub fn unlock_campaign(&self, campaign_id: &str, key: &CampaignKey) -> Result<Campaign> {
+        if !self.is_locked(campaign_id) {
+            return self.get_campaign(campaign_id)
+                .ok_or_else(|| CampaignError::NotFound(campaign_id.to_string()));
+        }
+        let sealed = self.read_encrypted(campaign_id)?;
+        self.load_sealed(&sealed, key.clone())?;
+        self.get_campaign(campaign_id)
+            .ok_or_else(|| CampaignError::NotFound(campaign_id.to_string()))
+    }
+
+    fn load_sealed(&self, sealed: &EncryptedCampaign, key: CampaignKey) -> Result<()> {
+        let export = sealed.open(&key)?;
+        let campaign_id = self.import_campaign(export, false)?;
+        self.encrypted.write().unwrap().insert(campaign_id.clone(), sealed.keys(key));
+        self.locked.write().unwrap().remove(&campaign_id);
+        Ok(())
+    }
+
+    /// Load every encrypted campaign in the data directory. Campaigns
+    /// `key_for` supplies a working key for are decrypted; the rest stay
+    /// locked. Returns how many were decrypted.
+    pub fn load_encrypted_campaigns(&self, key_for: impl Fn(&str) -> Option<CampaignKey>) -> Result<usize> {
+        let Some(dir) = self.data_dir.as_ref().filter(|dir| dir.exists()) else {
+            return Ok(0);
+        };
+        let suffix = format!(".{}", ENCRYPTED_CAMPAIGN_EXTENSION);
+        let mut loaded = 0;
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if !path.to_string_lossy().ends_with(&suffix) {
+                continue;
+            }
+            let sealed = match EncryptedCampaign::read(&path) {
+                Ok(sealed) => sealed,
+                Err(e) => {
+                    log::warn!("Skipping unreadable encrypted campaign {:?}: {}", path, e);
+                    continue;
+                }
+            };
+
+            match key_for(&sealed.campaign_id) {
+                Some(key) => match self.load_sealed(&sealed, key) {
+                    Ok(()) => {
+                        loaded += 1;
+                        continue;
+                    }
+                    Err(e) => log::warn!("Could not decrypt campaign {}: {}", sealed.campaign_id, e),
+                },
+                None => log::info!("No key for encrypted campaign {}, leaving it locked", sealed.campaign_id),
+            }
+            self.locked.write().unwrap().insert(sealed.campaign_id.clone(), LockedCampaign {
+                id: sealed.campaign_id,
+                name: sealed.name,
+                encrypted_at: sealed.encrypted_at,
+            });
+        }
+
+        Ok(loaded)
+    }
 }
 
 // ============================================================================
@@ -719,4 +992,63 @@ mod tests {
         let notes = manager.get_notes(&new_id);
         assert_eq!(notes.len(), 1);
     }
+
+    #[test]
+    fn test_clone_campaign() {
+        let manager = CampaignManager::new();
+        let campaign = manager.create_campaign("Curse of the Crown", "D&D 5e");
+        manager.create_snapshot(&campaign.id, "Snapshot 1").unwrap();
+        let note = manager.add_note(&campaign.id, "Prep: the bridge is out", vec![], None);
+
+        let clone = manager.clone_campaign(&campaign.id, Some("Thursday Group"), true).unwrap();
+        assert_ne!(clone.id, campaign.id);
+        assert_eq!(clone.name, "Thursday Group");
+        assert!(manager.list_snapshots(&clone.id).is_empty());
+
+        let notes = manager.get_notes(&clone.id);
+        assert_eq!(notes.len(), 1);
+        assert_ne!(notes[0].id, note.id);
+        assert_eq!(notes[0].campaign_id, clone.id);
+
+        let bare = manager.clone_campaign(&campaign.id, None, false).unwrap();
+        assert_eq!(bare.name, "Curse of the Crown (Copy)");
+        assert!(manager.get_notes(&bare.id).is_empty());
+    }
+
+    #[test]
+    fn test_encrypted_campaign_lock_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = CampaignManager::with_data_dir(dir.path());
+        let campaign = manager.create_campaign("Masks of Nyarlathotep", "coc");
+        let (keys, _) = CampaignKeys::generate(&campaign.id).unwrap();
+        let key = keys.data_key.clone();
+        manager.encrypt_campaign(&campaign.id, keys).unwrap();
+
+        // Changes after encrypting are written through to disk
+        manager.add_note(&campaign.id, "Jackson's player has a phobia of spiders", vec![], None);
+        let on_disk = std::fs::read_to_string(
+            dir.path().join(format!("{}.{}", campaign.id, ENCRYPTED_CAMPAIGN_EXTENSION)),
+        ).unwrap();
+        assert!(!on_disk.contains("spiders"));
+
+        manager.lock_campaign(&campaign.id).unwrap();
+        assert!(manager.get_campaign(&campaign.id).is_none());
+        assert!(manager.get_notes(&campaign.id).is_empty());
+        assert_eq!(manager.list_locked_campaigns()[0].name, "Masks of Nyarlathotep");
+        assert!(matches!(
+            manager.unlock_campaign(&campaign.id, &CampaignKey::generate()),
+            Err(CampaignError::Encryption(_))
+        ));
+
+        // A fresh manager decrypts transparently when the key is available
+        let reloaded = CampaignManager::with_data_dir(dir.path());
+        assert_eq!(reloaded.load_encrypted_campaigns(|_| Some(key.clone())).unwrap(), 1);
+        assert_eq!(reloaded.get_notes(&campaign.id).len(), 1);
+        assert!(reloaded.is_encrypted(&campaign.id));
+
+        let keyless = CampaignManager::with_data_dir(dir.path());
+        assert_eq!(keyless.load_encrypted_campaigns(|_| None).unwrap(), 0);
+        assert!(keyless.is_locked(&campaign.id));
+        assert_eq!(keyless.unlock_campaign(&campaign.id, &key).unwrap().name, "Masks of Nyarlathotep");
+    }
 }
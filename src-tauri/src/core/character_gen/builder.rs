@@ -0,0 +1,1485 @@
+//! D&D 5e Character Builder
+//!
+//! Builds a D&D 5e character one choice at a time: race and lineage, class,
+//! background, ability scores (point buy, the standard array, or rolled),
+//! skills, spells, and starting equipment. The builder lists the options
+//! open at each step and checks every choice against the rules, so a
+//! character can only be finished once nothing breaks them.
+//!
+//! Races, classes, backgrounds, and the cantrips and 1st-level spells come
+//! from the SRD. Spells from ingested rulebooks are added to the lists, so
+//! higher-level and non-SRD spells can be picked when their books are in
+//! the library.
+
+use std::collections::{BTreeMap, HashMap};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::level_up::LevelGain;
+use super::systems::dnd5e::DnD5eGenerator;
+use super::{
+    random_fantasy_name, AttributeValue, Character, CharacterBackground, CharacterGenError, CharacterTrait,
+    Equipment, EquipmentCategory, GameSystem, Result, SystemGenerator, TraitType,
+};
+use crate::database::TTRPGDocumentRecord;
+
+/// Points to spend under point buy
+pub const POINT_BUY_BUDGET: i32 = 27;
+
+/// The standard array, highest first
+pub const STANDARD_ARRAY: [i32; 6] = [15, 14, 13, 12, 10, 8];
+
+/// Highest character level
+pub(super) const MAX_LEVEL: u32 = 20;
+
+/// Score a class's main ability should reach; also the multiclassing
+/// prerequisite
+const PRIMARY_ABILITY_MINIMUM: i32 = 13;
+
+// ============================================================================
+// Abilities and Skills
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 6] = [
+        Self::Strength,
+        Self::Dexterity,
+        Self::Constitution,
+        Self::Intelligence,
+        Self::Wisdom,
+        Self::Charisma,
+    ];
+
+    /// Name as used in a character's attributes
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Strength => "Strength",
+            Self::Dexterity => "Dexterity",
+            Self::Constitution => "Constitution",
+            Self::Intelligence => "Intelligence",
+            Self::Wisdom => "Wisdom",
+            Self::Charisma => "Charisma",
+        }
+    }
+}
+
+/// Every skill with the ability it uses
+pub(super) const SKILLS: [(&str, Ability); 18] = [
+    ("Acrobatics", Ability::Dexterity),
+    ("Animal Handling", Ability::Wisdom),
+    ("Arcana", Ability::Intelligence),
+    ("Athletics", Ability::Strength),
+    ("Deception", Ability::Charisma),
+    ("History", Ability::Intelligence),
+    ("Insight", Ability::Wisdom),
+    ("Intimidation", Ability::Charisma),
+    ("Investigation", Ability::Intelligence),
+    ("Medicine", Ability::Wisdom),
+    ("Nature", Ability::Intelligence),
+    ("Perception", Ability::Wisdom),
+    ("Performance", Ability::Charisma),
+    ("Persuasion", Ability::Charisma),
+    ("Religion", Ability::Intelligence),
+    ("Sleight of Hand", Ability::Dexterity),
+    ("Stealth", Ability::Dexterity),
+    ("Survival", Ability::Wisdom),
+];
+
+fn all_skills() -> Vec<String> {
+    SKILLS.iter().map(|(name, _)| name.to_string()).collect()
+}
+
+pub(super) fn modifier(score: i32) -> i32 {
+    (score - 10).div_euclid(2)
+}
+
+pub(super) fn proficiency_bonus(level: u32) -> i32 {
+    2 + (level.clamp(1, MAX_LEVEL) as i32 - 1) / 4
+}
+
+pub(super) fn same_name(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b.trim())
+}
+
+pub(super) fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+// ============================================================================
+// Rules Catalog
+// ============================================================================
+
+/// A lineage (subrace) and the bonuses it adds to its race's
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageOption {
+    pub name: String,
+    pub ability_bonuses: BTreeMap<Ability, i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaceOption {
+    pub name: String,
+    pub speed: u32,
+    pub ability_bonuses: BTreeMap<Ability, i32>,
+    /// +1 bonuses the player places, each on a different ability
+    pub free_ability_bonuses: u32,
+    /// Abilities free bonuses can't go to
+    pub excluded_from_free_bonuses: Vec<Ability>,
+    /// Skills the race is proficient in
+    pub skills: Vec<String>,
+    /// Skills of the player's choice, from any
+    pub free_skills: u32,
+    /// One lineage must be chosen when there are any
+    pub lineages: Vec<LineageOption>,
+}
+
+/// How a class learns its spells
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpellList {
+    /// A fixed number known at each level, from 1st
+    Known { by_level: Vec<u32> },
+    /// Prepares the spellcasting modifier plus its level divided by
+    /// `level_divisor`, at least one
+    Prepared { level_divisor: u32 },
+    /// Copies six spells into a spellbook at 1st level and two more each
+    /// level after
+    Spellbook,
+}
+
+/// How far up the spell levels a class's slots reach
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CasterProgression {
+    Full,
+    Half,
+    Pact,
+}
+
+impl CasterProgression {
+    /// Highest spell level castable at a character level
+    pub fn max_spell_level(&self, level: u32) -> u32 {
+        match self {
+            Self::Full => level.div_ceil(2).min(9),
+            Self::Half if level < 2 => 0,
+            Self::Half => level.div_ceil(4).min(5),
+            Self::Pact => level.div_ceil(2).min(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Spellcasting {
+    pub ability: Ability,
+    pub progression: CasterProgression,
+    pub list: SpellList,
+    /// Cantrips known at 1st level; one more is learned at 4th and 10th
+    pub cantrips: u32,
+}
+
+impl Spellcasting {
+    pub fn cantrips_known(&self, level: u32) -> u32 {
+        if self.cantrips == 0 {
+            return 0;
+        }
+        self.cantrips + u32::from(level >= 4) + u32::from(level >= 10)
+    }
+
+    /// Spells to pick at a level, given the spellcasting ability's score
+    pub fn spells_known(&self, level: u32, score: i32) -> u32 {
+        if self.progression.max_spell_level(level) == 0 {
+            return 0;
+        }
+        match &self.list {
+            SpellList::Known { by_level } => by_level.get(level as usize - 1).copied().unwrap_or(0),
+            SpellList::Prepared { level_divisor } => {
+                (modifier(score) + (level / level_divisor) as i32).max(1) as u32
+            }
+            SpellList::Spellbook => 6 + 2 * (level - 1),
+        }
+    }
+
+    /// Spell slots at a level, indexed from 1st-level slots
+    pub fn spell_slots(&self, level: u32) -> Vec<u32> {
+        let level = level.clamp(1, MAX_LEVEL);
+        match self.progression {
+            CasterProgression::Full => FULL_CASTER_SLOTS[level as usize - 1].to_vec(),
+            CasterProgression::Half if level < 2 => vec![],
+            // Half casters have the slots of a full caster of half their level
+            CasterProgression::Half => FULL_CASTER_SLOTS[level.div_ceil(2) as usize - 1].to_vec(),
+            CasterProgression::Pact => {
+                let count = match level {
+                    1 => 1,
+                    2..=10 => 2,
+                    11..=16 => 3,
+                    _ => 4,
+                };
+                let mut slots = vec![0; self.progression.max_spell_level(level) as usize];
+                if let Some(last) = slots.last_mut() {
+                    *last = count;
+                }
+                slots
+            }
+        }
+    }
+}
+
+/// A full caster's spell slots at each level
+const FULL_CASTER_SLOTS: [&[u32]; 20] = [
+    &[2],
+    &[3],
+    &[4, 2],
+    &[4, 3],
+    &[4, 3, 2],
+    &[4, 3, 3],
+    &[4, 3, 3, 1],
+    &[4, 3, 3, 2],
+    &[4, 3, 3, 3, 1],
+    &[4, 3, 3, 3, 2],
+    &[4, 3, 3, 3, 2, 1],
+    &[4, 3, 3, 3, 2, 1],
+    &[4, 3, 3, 3, 2, 1, 1],
+    &[4, 3, 3, 3, 2, 1, 1],
+    &[4, 3, 3, 3, 2, 1, 1, 1],
+    &[4, 3, 3, 3, 2, 1, 1, 1],
+    &[4, 3, 3, 3, 2, 1, 1, 1, 1],
+    &[4, 3, 3, 3, 3, 1, 1, 1, 1],
+    &[4, 3, 3, 3, 3, 2, 1, 1, 1],
+    &[4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassOption {
+    pub name: String,
+    pub hit_die: u32,
+    /// Abilities the class relies on; the first is its main one
+    pub primary_abilities: Vec<Ability>,
+    pub saving_throws: Vec<Ability>,
+    pub skill_count: u32,
+    pub skill_options: Vec<String>,
+    #[serde(default)]
+    pub spellcasting: Option<Spellcasting>,
+    /// Starting gold instead of equipment: this many d4, times the
+    /// multiplier
+    pub gold_dice: u32,
+    pub gold_multiplier: u32,
+}
+
+impl ClassOption {
+    pub fn max_starting_gold(&self) -> u32 {
+        self.gold_dice * 4 * self.gold_multiplier
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackgroundOption {
+    pub name: String,
+    pub skills: Vec<String>,
+}
+
+/// A spell that can be picked, level 0 being a cantrip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellOption {
+    pub name: String,
+    pub level: u32,
+    /// Classes with the spell on their list; empty when the source doesn't
+    /// say, in which case any spellcaster may pick it
+    pub classes: Vec<String>,
+    /// The ingested document the spell came from
+    #[serde(default)]
+    pub document_id: Option<String>,
+}
+
+impl SpellOption {
+    /// Read a spell from an ingested D&D 5e document, if it is one
+    pub fn from_record(record: &TTRPGDocumentRecord) -> Option<Self> {
+        if !record.element_type.eq_ignore_ascii_case("spell")
+            || GameSystem::from_str(&record.game_system) != GameSystem::DnD5e
+        {
+            return None;
+        }
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let level = record.level.map(|l| l as u32).or_else(|| match attributes.get("level")? {
+            serde_json::Value::Number(n) => n.as_u64().map(|n| n as u32),
+            serde_json::Value::String(s) if s.eq_ignore_ascii_case("cantrip") => Some(0),
+            serde_json::Value::String(s) => s.trim_end_matches(|c: char| !c.is_ascii_digit()).parse().ok(),
+            _ => None,
+        })?;
+        let classes = match attributes.get("classes") {
+            Some(serde_json::Value::Array(values)) => {
+                values.iter().filter_map(|v| v.as_str()).map(|c| c.trim().to_string()).collect()
+            }
+            Some(serde_json::Value::String(s)) => s.split(',').map(|c| c.trim().to_string()).collect(),
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            name: record.name.clone(),
+            level: level.min(9),
+            classes,
+            document_id: Some(record.id.clone()),
+        })
+    }
+
+    pub fn available_to(&self, class: &str) -> bool {
+        self.classes.is_empty() || self.classes.iter().any(|c| same_name(c, class))
+    }
+}
+
+/// A feat that can be taken in place of an ability score improvement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatOption {
+    pub name: String,
+    /// A minimum score the character must have, such as Strength 13
+    #[serde(default)]
+    pub prerequisite: Option<(Ability, i32)>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub document_id: Option<String>,
+}
+
+impl FeatOption {
+    /// Read a feat from an ingested D&D 5e document, if it is one. A
+    /// prerequisite reads like "Strength 13" or "Str 13 or higher".
+    pub fn from_record(record: &TTRPGDocumentRecord) -> Option<Self> {
+        if !record.element_type.eq_ignore_ascii_case("feat")
+            || GameSystem::from_str(&record.game_system) != GameSystem::DnD5e
+        {
+            return None;
+        }
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let prerequisite = attributes.get("prerequisite").and_then(|p| p.as_str()).and_then(|text| {
+            let mut words = text.split_whitespace();
+            let prefix: String = words.next()?.to_lowercase().chars().take(3).collect();
+            let ability = Ability::ALL
+                .into_iter()
+                .find(|a| prefix.len() == 3 && a.name().to_lowercase().starts_with(&prefix))?;
+            Some((ability, words.next()?.parse().ok()?))
+        });
+
+        Some(Self {
+            name: record.name.clone(),
+            prerequisite,
+            description: record.content.chars().take(200).collect(),
+            document_id: Some(record.id.clone()),
+        })
+    }
+}
+
+/// Everything the builder chooses from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesCatalog {
+    pub races: Vec<RaceOption>,
+    pub classes: Vec<ClassOption>,
+    pub backgrounds: Vec<BackgroundOption>,
+    pub spells: Vec<SpellOption>,
+    #[serde(default)]
+    pub feats: Vec<FeatOption>,
+}
+
+impl Default for RulesCatalog {
+    fn default() -> Self {
+        Self::srd()
+    }
+}
+
+impl RulesCatalog {
+    /// The SRD's races, classes, backgrounds, and feat, with its cantrips
+    /// and 1st-level spells
+    pub fn srd() -> Self {
+        Self {
+            races: srd_races(),
+            classes: srd_classes(),
+            backgrounds: srd_backgrounds(),
+            spells: SRD_SPELLS
+                .iter()
+                .map(|(name, level, classes)| SpellOption {
+                    name: name.to_string(),
+                    level: *level,
+                    classes: strings(classes),
+                    document_id: None,
+                })
+                .collect(),
+            feats: vec![FeatOption {
+                name: "Grappler".to_string(),
+                prerequisite: Some((Ability::Strength, 13)),
+                description: "Advantage on attack rolls against a creature you are grappling".to_string(),
+                document_id: None,
+            }],
+        }
+    }
+
+    /// Add the spells among ingested documents, skipping any already listed
+    pub fn with_ingested_spells(mut self, records: &[TTRPGDocumentRecord]) -> Self {
+        for spell in records.iter().filter_map(SpellOption::from_record) {
+            if !self.spells.iter().any(|s| same_name(&s.name, &spell.name)) {
+                self.spells.push(spell);
+            }
+        }
+        self.spells.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.name.cmp(&b.name)));
+        self
+    }
+
+    /// Add the feats among ingested documents, skipping any already listed
+    pub fn with_ingested_feats(mut self, records: &[TTRPGDocumentRecord]) -> Self {
+        for feat in records.iter().filter_map(FeatOption::from_record) {
+            if !self.feats.iter().any(|f| same_name(&f.name, &feat.name)) {
+                self.feats.push(feat);
+            }
+        }
+        self.feats.sort_by(|a, b| a.name.cmp(&b.name));
+        self
+    }
+
+    pub fn race(&self, name: &str) -> Option<&RaceOption> {
+        self.races.iter().find(|r| same_name(&r.name, name))
+    }
+
+    pub fn class(&self, name: &str) -> Option<&ClassOption> {
+        self.classes.iter().find(|c| same_name(&c.name, name))
+    }
+
+    pub fn background(&self, name: &str) -> Option<&BackgroundOption> {
+        self.backgrounds.iter().find(|b| same_name(&b.name, name))
+    }
+
+    pub fn spell(&self, name: &str) -> Option<&SpellOption> {
+        self.spells.iter().find(|s| same_name(&s.name, name))
+    }
+
+    pub fn feat(&self, name: &str) -> Option<&FeatOption> {
+        self.feats.iter().find(|f| same_name(&f.name, name))
+    }
+}
+
+fn bonuses(values: &[(Ability, i32)]) -> BTreeMap<Ability, i32> {
+    values.iter().copied().collect()
+}
+
+fn race(name: &str, speed: u32, ability_bonuses: &[(Ability, i32)], lineages: &[(&str, &[(Ability, i32)])]) -> RaceOption {
+    RaceOption {
+        name: name.to_string(),
+        speed,
+        ability_bonuses: bonuses(ability_bonuses),
+        free_ability_bonuses: 0,
+        excluded_from_free_bonuses: vec![],
+        skills: vec![],
+        free_skills: 0,
+        lineages: lineages
+            .iter()
+            .map(|(name, ability_bonuses)| LineageOption { name: name.to_string(), ability_bonuses: bonuses(ability_bonuses) })
+            .collect(),
+    }
+}
+
+fn srd_races() -> Vec<RaceOption> {
+    use Ability::*;
+    vec![
+        race("Dragonborn", 30, &[(Strength, 2), (Charisma, 1)], &[]),
+        race("Dwarf", 25, &[(Constitution, 2)], &[("Hill Dwarf", &[(Wisdom, 1)]), ("Mountain Dwarf", &[(Strength, 2)])]),
+        RaceOption {
+            skills: strings(&["Perception"]),
+            ..race(
+                "Elf",
+                30,
+                &[(Dexterity, 2)],
+                &[("High Elf", &[(Intelligence, 1)]), ("Wood Elf", &[(Wisdom, 1)]), ("Drow", &[(Charisma, 1)])],
+            )
+        },
+        race("Gnome", 25, &[(Intelligence, 2)], &[("Forest Gnome", &[(Dexterity, 1)]), ("Rock Gnome", &[(Constitution, 1)])]),
+        RaceOption {
+            free_ability_bonuses: 2,
+            excluded_from_free_bonuses: vec![Charisma],
+            free_skills: 2,
+            ..race("Half-Elf", 30, &[(Charisma, 2)], &[])
+        },
+        RaceOption {
+            skills: strings(&["Intimidation"]),
+            ..race("Half-Orc", 30, &[(Strength, 2), (Constitution, 1)], &[])
+        },
+        race("Halfling", 25, &[(Dexterity, 2)], &[("Lightfoot Halfling", &[(Charisma, 1)]), ("Stout Halfling", &[(Constitution, 1)])]),
+        race("Human", 30, &Ability::ALL.map(|a| (a, 1)), &[]),
+        race("Tiefling", 30, &[(Charisma, 2), (Intelligence, 1)], &[]),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+fn class(
+    name: &str,
+    hit_die: u32,
+    primary_abilities: &[Ability],
+    saving_throws: [Ability; 2],
+    skill_count: u32,
+    skill_options: &[&str],
+    spellcasting: Option<Spellcasting>,
+    gold: (u32, u32),
+) -> ClassOption {
+    ClassOption {
+        name: name.to_string(),
+        hit_die,
+        primary_abilities: primary_abilities.to_vec(),
+        saving_throws: saving_throws.to_vec(),
+        skill_count,
+        skill_options: if skill_options.is_empty() { all_skills() } else { strings(skill_options) },
+        spellcasting,
+        gold_dice: gold.0,
+        gold_multiplier: gold.1,
+    }
+}
+
+fn caster(ability: Ability, progression: CasterProgression, list: SpellList, cantrips: u32) -> Option<Spellcasting> {
+    Some(Spellcasting { ability, progression, list, cantrips })
+}
+
+fn srd_classes() -> Vec<ClassOption> {
+    use Ability::*;
+    use CasterProgression::*;
+    let known = |by_level: [u32; 20]| SpellList::Known { by_level: by_level.to_vec() };
+    let prepared = SpellList::Prepared { level_divisor: 1 };
+    vec![
+        class(
+            "Barbarian", 12, &[Strength], [Strength, Constitution], 2,
+            &["Animal Handling", "Athletics", "Intimidation", "Nature", "Perception", "Survival"],
+            None, (2, 10),
+        ),
+        class(
+            "Bard", 8, &[Charisma], [Dexterity, Charisma], 3, &[],
+            caster(Charisma, Full, known([4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 15, 16, 18, 19, 19, 20, 22, 22, 22]), 2),
+            (5, 10),
+        ),
+        class(
+            "Cleric", 8, &[Wisdom], [Wisdom, Charisma], 2,
+            &["History", "Insight", "Medicine", "Persuasion", "Religion"],
+            caster(Wisdom, Full, prepared.clone(), 3), (5, 10),
+        ),
+        class(
+            "Druid", 8, &[Wisdom], [Intelligence, Wisdom], 2,
+            &["Arcana", "Animal Handling", "Insight", "Medicine", "Nature", "Perception", "Religion", "Survival"],
+            caster(Wisdom, Full, prepared, 2), (2, 10),
+        ),
+        class(
+            "Fighter", 10, &[Strength, Dexterity], [Strength, Constitution], 2,
+            &["Acrobatics", "Animal Handling", "Athletics", "History", "Insight", "Intimidation", "Perception", "Survival"],
+            None, (5, 10),
+        ),
+        class(
+            "Monk", 8, &[Dexterity, Wisdom], [Strength, Dexterity], 2,
+            &["Acrobatics", "Athletics", "History", "Insight", "Religion", "Stealth"],
+            None, (5, 1),
+        ),
+        class(
+            "Paladin", 10, &[Strength, Charisma], [Wisdom, Charisma], 2,
+            &["Athletics", "Insight", "Intimidation", "Medicine", "Persuasion", "Religion"],
+            caster(Charisma, Half, SpellList::Prepared { level_divisor: 2 }, 0), (5, 10),
+        ),
+        class(
+            "Ranger", 10, &[Dexterity, Wisdom], [Strength, Dexterity], 3,
+            &["Animal Handling", "Athletics", "Insight", "Investigation", "Nature", "Perception", "Stealth", "Survival"],
+            caster(Wisdom, Half, known([0, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11]), 0),
+            (5, 10),
+        ),
+        class(
+            "Rogue", 8, &[Dexterity], [Dexterity, Intelligence], 4,
+            &[
+                "Acrobatics", "Athletics", "Deception", "Insight", "Intimidation", "Investigation", "Perception",
+                "Performance", "Persuasion", "Sleight of Hand", "Stealth",
+            ],
+            None, (4, 10),
+        ),
+        class(
+            "Sorcerer", 6, &[Charisma], [Constitution, Charisma], 2,
+            &["Arcana", "Deception", "Insight", "Intimidation", "Persuasion", "Religion"],
+            caster(Charisma, Full, known([2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 12, 13, 13, 14, 14, 15, 15, 15, 15]), 4),
+            (3, 10),
+        ),
+        class(
+            "Warlock", 8, &[Charisma], [Wisdom, Charisma], 2,
+            &["Arcana", "Deception", "History", "Intimidation", "Investigation", "Nature", "Religion"],
+            caster(Charisma, Pact, known([2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 11, 11, 12, 12, 13, 13, 14, 14, 15, 15]), 2),
+            (4, 10),
+        ),
+        class(
+            "Wizard", 6, &[Intelligence], [Intelligence, Wisdom], 2,
+            &["Arcana", "History", "Insight", "Investigation", "Medicine", "Religion"],
+            caster(Intelligence, Full, SpellList::Spellbook, 3), (4, 10),
+        ),
+    ]
+}
+
+fn srd_backgrounds() -> Vec<BackgroundOption> {
+    [
+        ("Acolyte", ["Insight", "Religion"]),
+        ("Charlatan", ["Deception", "Sleight of Hand"]),
+        ("Criminal", ["Deception", "Stealth"]),
+        ("Entertainer", ["Acrobatics", "Performance"]),
+        ("Folk Hero", ["Animal Handling", "Survival"]),
+        ("Guild Artisan", ["Insight", "Persuasion"]),
+        ("Hermit", ["Medicine", "Religion"]),
+        ("Noble", ["History", "Persuasion"]),
+        ("Outlander", ["Athletics", "Survival"]),
+        ("Sage", ["Arcana", "History"]),
+        ("Sailor", ["Athletics", "Perception"]),
+        ("Soldier", ["Athletics", "Intimidation"]),
+        ("Urchin", ["Sleight of Hand", "Stealth"]),
+    ]
+    .into_iter()
+    .map(|(name, skills)| BackgroundOption { name: name.to_string(), skills: strings(&skills) })
+    .collect()
+}
+
+/// SRD cantrips and 1st-level spells with the classes that have them
+const SRD_SPELLS: &[(&str, u32, &[&str])] = &[
+    ("Acid Splash", 0, &["Sorcerer", "Wizard"]),
+    ("Chill Touch", 0, &["Sorcerer", "Warlock", "Wizard"]),
+    ("Dancing Lights", 0, &["Bard", "Sorcerer", "Wizard"]),
+    ("Druidcraft", 0, &["Druid"]),
+    ("Eldritch Blast", 0, &["Warlock"]),
+    ("Fire Bolt", 0, &["Sorcerer", "Wizard"]),
+    ("Guidance", 0, &["Cleric", "Druid"]),
+    ("Light", 0, &["Bard", "Cleric", "Sorcerer", "Wizard"]),
+    ("Mage Hand", 0, &["Bard", "Sorcerer", "Warlock", "Wizard"]),
+    ("Mending", 0, &["Bard", "Cleric", "Druid", "Sorcerer", "Wizard"]),
+    ("Message", 0, &["Bard", "Sorcerer", "Wizard"]),
+    ("Minor Illusion", 0, &["Bard", "Sorcerer", "Warlock", "Wizard"]),
+    ("Poison Spray", 0, &["Druid", "Sorcerer", "Warlock", "Wizard"]),
+    ("Prestidigitation", 0, &["Bard", "Sorcerer", "Warlock", "Wizard"]),
+    ("Produce Flame", 0, &["Druid"]),
+    ("Ray of Frost", 0, &["Sorcerer", "Wizard"]),
+    ("Resistance", 0, &["Cleric", "Druid"]),
+    ("Sacred Flame", 0, &["Cleric"]),
+    ("Shillelagh", 0, &["Druid"]),
+    ("Shocking Grasp", 0, &["Sorcerer", "Wizard"]),
+    ("Spare the Dying", 0, &["Cleric"]),
+    ("Thaumaturgy", 0, &["Cleric"]),
+    ("True Strike", 0, &["Bard", "Sorcerer", "Warlock", "Wizard"]),
+    ("Vicious Mockery", 0, &["Bard"]),
+    ("Bless", 1, &["Cleric", "Paladin"]),
+    ("Burning Hands", 1, &["Sorcerer", "Wizard"]),
+    ("Charm Person", 1, &["Bard", "Druid", "Sorcerer", "Warlock", "Wizard"]),
+    ("Comprehend Languages", 1, &["Bard", "Sorcerer", "Warlock", "Wizard"]),
+    ("Cure Wounds", 1, &["Bard", "Cleric", "Druid", "Paladin", "Ranger"]),
+    ("Detect Magic", 1, &["Bard", "Cleric", "Druid", "Paladin", "Ranger", "Sorcerer", "Wizard"]),
+    ("Entangle", 1, &["Druid"]),
+    ("Expeditious Retreat", 1, &["Sorcerer", "Warlock", "Wizard"]),
+    ("Faerie Fire", 1, &["Bard", "Druid"]),
+    ("Goodberry", 1, &["Druid", "Ranger"]),
+    ("Guiding Bolt", 1, &["Cleric"]),
+    ("Healing Word", 1, &["Bard", "Cleric", "Druid"]),
+    ("Hellish Rebuke", 1, &["Warlock"]),
+    ("Hideous Laughter", 1, &["Bard", "Wizard"]),
+    ("Hunter's Mark", 1, &["Ranger"]),
+    ("Mage Armor", 1, &["Sorcerer", "Wizard"]),
+    ("Magic Missile", 1, &["Sorcerer", "Wizard"]),
+    ("Protection from Evil and Good", 1, &["Cleric", "Paladin", "Warlock", "Wizard"]),
+    ("Shield", 1, &["Sorcerer", "Wizard"]),
+    ("Shield of Faith", 1, &["Cleric", "Paladin"]),
+    ("Sleep", 1, &["Bard", "Sorcerer", "Wizard"]),
+    ("Thunderwave", 1, &["Bard", "Druid", "Sorcerer", "Wizard"]),
+];
+
+// ============================================================================
+// Build
+// ============================================================================
+
+/// How ability scores were generated, with the score given each ability
+/// before racial bonuses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum AbilityScores {
+    /// Scores from 8 to 15 bought with 27 points
+    PointBuy { scores: BTreeMap<Ability, i32> },
+    /// 15, 14, 13, 12, 10, and 8, one to each ability
+    StandardArray { scores: BTreeMap<Ability, i32> },
+    /// Six rolls of 4d6 dropping the lowest, one to each ability
+    Rolled { rolls: Vec<i32>, scores: BTreeMap<Ability, i32> },
+}
+
+impl AbilityScores {
+    pub fn scores(&self) -> &BTreeMap<Ability, i32> {
+        match self {
+            Self::PointBuy { scores } | Self::StandardArray { scores } | Self::Rolled { scores, .. } => scores,
+        }
+    }
+}
+
+/// Point buy cost of a score, if it can be bought
+pub fn point_buy_cost(score: i32) -> Option<i32> {
+    match score {
+        8..=13 => Some(score - 8),
+        14 => Some(7),
+        15 => Some(9),
+        _ => None,
+    }
+}
+
+/// Six ability scores, each the best three of 4d6
+pub fn roll_ability_scores<R: Rng>(rng: &mut R) -> Vec<i32> {
+    (0..6)
+        .map(|_| {
+            let mut rolls: Vec<i32> = (0..4).map(|_| rng.gen_range(1..=6)).collect();
+            rolls.sort_unstable();
+            rolls[1..].iter().sum()
+        })
+        .collect()
+}
+
+/// Starting gold rolled for a class
+pub fn roll_starting_gold<R: Rng>(class: &ClassOption, rng: &mut R) -> u32 {
+    (0..class.gold_dice).map(|_| rng.gen_range(1..=4)).sum::<u32>() * class.gold_multiplier
+}
+
+/// What the character starts with
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StartingEquipment {
+    /// The class's equipment
+    ClassPackage,
+    /// Starting gold to buy equipment with
+    Gold { gp: u32 },
+}
+
+/// A character part-way through building; every choice is optional until
+/// it's finished
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterBuild {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default = "default_level")]
+    pub level: u32,
+    #[serde(default)]
+    pub race: Option<String>,
+    #[serde(default)]
+    pub lineage: Option<String>,
+    #[serde(default)]
+    pub class: Option<String>,
+    #[serde(default)]
+    pub background: Option<String>,
+    #[serde(default)]
+    pub abilities: Option<AbilityScores>,
+    /// Where the race's free +1 bonuses go
+    #[serde(default)]
+    pub bonus_abilities: Vec<Ability>,
+    /// Class skill picks, plus any the race lets the player choose
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    /// Spells known, prepared, or in the spellbook, by the class's rules
+    #[serde(default)]
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub equipment: Option<StartingEquipment>,
+}
+
+fn default_level() -> u32 {
+    1
+}
+
+impl Default for CharacterBuild {
+    fn default() -> Self {
+        Self {
+            name: None,
+            level: default_level(),
+            race: None,
+            lineage: None,
+            class: None,
+            background: None,
+            abilities: None,
+            bonus_abilities: vec![],
+            skills: vec![],
+            cantrips: vec![],
+            spells: vec![],
+            equipment: None,
+        }
+    }
+}
+
+/// The builder's steps, in order
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildStep {
+    Race,
+    Class,
+    Background,
+    Abilities,
+    Skills,
+    Spells,
+    Equipment,
+    /// Every choice is made and valid
+    Review,
+}
+
+/// A choice that breaks the rules, or only looks unwise
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BuildIssue {
+    pub step: BuildStep,
+    pub message: String,
+    /// Only advice; doesn't stop the character being finished
+    #[serde(default)]
+    pub warning: bool,
+}
+
+impl BuildIssue {
+    fn error(step: BuildStep, message: impl Into<String>) -> Self {
+        Self { step, message: message.into(), warning: false }
+    }
+
+    fn warning(step: BuildStep, message: impl Into<String>) -> Self {
+        Self { step, message: message.into(), warning: true }
+    }
+}
+
+/// What a build can choose from now, with what it has chosen wrongly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildChoices {
+    /// The first step with a choice missing or broken
+    pub next_step: BuildStep,
+    pub races: Vec<RaceOption>,
+    pub classes: Vec<ClassOption>,
+    pub backgrounds: Vec<BackgroundOption>,
+    /// Skills the race and background already give
+    pub fixed_skills: Vec<String>,
+    pub skill_options: Vec<String>,
+    pub skills_to_pick: u32,
+    /// Skills beyond the class list the race lets the player pick
+    pub free_skills: u32,
+    pub free_ability_bonuses: u32,
+    pub cantrip_options: Vec<SpellOption>,
+    pub cantrips_to_pick: u32,
+    pub spell_options: Vec<SpellOption>,
+    pub spells_to_pick: u32,
+    pub class_equipment: Vec<Equipment>,
+    pub max_starting_gold: Option<u32>,
+    pub issues: Vec<BuildIssue>,
+}
+
+/// A finished character with the numbers the sheet needs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuiltCharacter {
+    pub character: Character,
+    pub max_hp: i32,
+    pub proficiency_bonus: i32,
+    pub speed: u32,
+    pub saving_throws: BTreeMap<Ability, i32>,
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+    /// Spell slots, indexed from 1st-level slots
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+    /// Each level gained since the character was built, oldest first
+    #[serde(default)]
+    pub level_history: Vec<LevelGain>,
+}
+
+/// The first step with a blocking issue
+fn next_step(issues: &[BuildIssue]) -> BuildStep {
+    issues.iter().filter(|i| !i.warning).map(|i| i.step).min().unwrap_or(BuildStep::Review)
+}
+
+/// Steps a D&D 5e character through the rules in a catalog
+pub struct CharacterBuilder<'a> {
+    catalog: &'a RulesCatalog,
+}
+
+impl<'a> CharacterBuilder<'a> {
+    pub fn new(catalog: &'a RulesCatalog) -> Self {
+        Self { catalog }
+    }
+
+    /// Options open to a build and the problems with its choices so far
+    pub fn choices(&self, build: &CharacterBuild) -> BuildChoices {
+        let issues = self.validate(build);
+        let class = build.class.as_deref().and_then(|c| self.catalog.class(c));
+        let race = build.race.as_deref().and_then(|r| self.catalog.race(r));
+        let fixed_skills = self.fixed_skills(build);
+        let (cantrip_options, spell_options) = match class {
+            Some(class) => self.spell_options(class, build.level),
+            None => (vec![], vec![]),
+        };
+
+        BuildChoices {
+            next_step: next_step(&issues),
+            races: self.catalog.races.clone(),
+            classes: self.catalog.classes.clone(),
+            backgrounds: self.catalog.backgrounds.clone(),
+            skill_options: class
+                .map(|c| c.skill_options.iter().filter(|s| !fixed_skills.contains(s)).cloned().collect())
+                .unwrap_or_default(),
+            fixed_skills,
+            skills_to_pick: class.map_or(0, |c| c.skill_count) + race.map_or(0, |r| r.free_skills),
+            free_skills: race.map_or(0, |r| r.free_skills),
+            free_ability_bonuses: race.map_or(0, |r| r.free_ability_bonuses),
+            cantrip_options,
+            cantrips_to_pick: self.cantrips_to_pick(build),
+            spell_options,
+            spells_to_pick: self.spells_to_pick(build),
+            class_equipment: class
+                .map(|c| DnD5eGenerator::new().starting_equipment(Some(&c.name)))
+                .unwrap_or_default(),
+            max_starting_gold: class.map(ClassOption::max_starting_gold),
+            issues,
+        }
+    }
+
+    /// Everything wrong with a build, in step order. Missing choices are
+    /// errors too.
+    pub fn validate(&self, build: &CharacterBuild) -> Vec<BuildIssue> {
+        let mut issues = Vec::new();
+        self.check_race(build, &mut issues);
+        self.check_class(build, &mut issues);
+        self.check_background(build, &mut issues);
+        self.check_abilities(build, &mut issues);
+        self.check_skills(build, &mut issues);
+        self.check_spells(build, &mut issues);
+        self.check_equipment(build, &mut issues);
+        issues.sort_by_key(|i| i.step);
+        issues
+    }
+
+    /// Finish a build whose choices are all made and within the rules
+    pub fn finish(&self, build: &CharacterBuild) -> Result<BuiltCharacter> {
+        let errors: Vec<String> = self
+            .validate(build)
+            .into_iter()
+            .filter(|i| !i.warning)
+            .map(|i| i.message)
+            .collect();
+        if !errors.is_empty() {
+            return Err(CharacterGenError::InvalidBuild(errors.join("; ")));
+        }
+        let (Some(race), Some(class), Some(background), Some(_)) = (
+            build.race.as_deref().and_then(|r| self.catalog.race(r)),
+            build.class.as_deref().and_then(|c| self.catalog.class(c)),
+            build.background.as_deref().and_then(|b| self.catalog.background(b)),
+            build.abilities.as_ref(),
+        ) else {
+            return Err(CharacterGenError::InvalidBuild("The build is incomplete".to_string()));
+        };
+
+        let scores = self.final_scores(build);
+        let level = build.level;
+        let proficiency = proficiency_bonus(level);
+        let proficient: Vec<String> = self.fixed_skills(build).into_iter().chain(build.skills.iter().cloned()).collect();
+        let skills: HashMap<String, i32> = SKILLS
+            .iter()
+            .map(|(skill, ability)| {
+                let bonus = if proficient.iter().any(|p| same_name(p, skill)) { proficiency } else { 0 };
+                (skill.to_string(), modifier(scores[ability]) + bonus)
+            })
+            .collect();
+        let saving_throws = Ability::ALL
+            .iter()
+            .map(|ability| {
+                let bonus = if class.saving_throws.contains(ability) { proficiency } else { 0 };
+                (*ability, modifier(scores[ability]) + bonus)
+            })
+            .collect();
+        let con = modifier(scores[&Ability::Constitution]);
+        let max_hp = (class.hit_die as i32 + con + (level as i32 - 1) * (class.hit_die as i32 / 2 + 1 + con)).max(level as i32);
+
+        let generator = DnD5eGenerator::new();
+        let race_name = build.lineage.clone().unwrap_or_else(|| race.name.clone());
+        let mut traits = DnD5eGenerator::get_racial_traits(&race_name);
+        traits.extend(DnD5eGenerator::get_class_traits(&class.name, level));
+        traits.push(CharacterTrait {
+            name: background.name.clone(),
+            trait_type: TraitType::Background,
+            description: format!("Proficient in {}", background.skills.join(" and ")),
+            mechanical_effect: None,
+        });
+        for (name, list) in [("Cantrips", &build.cantrips), ("Spells", &build.spells)] {
+            if !list.is_empty() {
+                traits.push(CharacterTrait {
+                    name: name.to_string(),
+                    trait_type: TraitType::Class,
+                    description: list.join(", "),
+                    mechanical_effect: None,
+                });
+            }
+        }
+        let equipment = match &build.equipment {
+            Some(StartingEquipment::Gold { gp }) => vec![Equipment {
+                name: "Coin Pouch".to_string(),
+                category: EquipmentCategory::Other,
+                description: format!("{} gp to buy equipment with", gp),
+                stats: HashMap::new(),
+            }],
+            _ => generator.starting_equipment(Some(&class.name)),
+        };
+
+        let character = Character {
+            id: Uuid::new_v4().to_string(),
+            name: build.name.clone().filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| random_fantasy_name(&mut rand::thread_rng())),
+            system: GameSystem::DnD5e,
+            concept: format!("{} {}", race_name, class.name),
+            race: Some(race_name),
+            class: Some(class.name.clone()),
+            level,
+            attributes: scores.iter().map(|(a, s)| (a.name().to_string(), AttributeValue::new(*s))).collect(),
+            skills,
+            traits,
+            equipment,
+            background: CharacterBackground {
+                origin: background.name.clone(),
+                occupation: Some(class.name.clone()),
+                ..Default::default()
+            },
+            backstory: None,
+            notes: String::new(),
+            portrait_prompt: None,
+            derived_stats: Vec::new(),
+        };
+
+        Ok(BuiltCharacter {
+            character,
+            max_hp,
+            proficiency_bonus: proficiency,
+            speed: race.speed,
+            saving_throws,
+            cantrips: build.cantrips.clone(),
+            spells: build.spells.clone(),
+            spell_slots: class.spellcasting.as_ref().map(|s| s.spell_slots(level)).unwrap_or_default(),
+            level_history: vec![],
+        })
+    }
+
+    // ------------------------------------------------------------------------
+    // Derived values
+    // ------------------------------------------------------------------------
+
+    /// Skills the race and background give without a choice
+    fn fixed_skills(&self, build: &CharacterBuild) -> Vec<String> {
+        let race = build.race.as_deref().and_then(|r| self.catalog.race(r));
+        let background = build.background.as_deref().and_then(|b| self.catalog.background(b));
+        let mut skills: Vec<String> = race.map(|r| r.skills.clone()).unwrap_or_default();
+        for skill in background.map(|b| b.skills.clone()).unwrap_or_default() {
+            if !skills.contains(&skill) {
+                skills.push(skill);
+            }
+        }
+        skills
+    }
+
+    /// Scores with racial bonuses, or the base scores before they're all set
+    fn final_scores(&self, build: &CharacterBuild) -> BTreeMap<Ability, i32> {
+        let mut scores: BTreeMap<Ability, i32> =
+            Ability::ALL.iter().map(|a| (*a, 10)).collect();
+        if let Some(abilities) = &build.abilities {
+            scores.extend(abilities.scores().iter().map(|(a, s)| (*a, *s)));
+        }
+        if let Some(race) = build.race.as_deref().and_then(|r| self.catalog.race(r)) {
+            let lineage = build
+                .lineage
+                .as_deref()
+                .and_then(|l| race.lineages.iter().find(|o| same_name(&o.name, l)));
+            let fixed = race.ability_bonuses.iter().chain(lineage.iter().flat_map(|l| &l.ability_bonuses));
+            for (ability, bonus) in fixed {
+                *scores.entry(*ability).or_default() += bonus;
+            }
+            for ability in build.bonus_abilities.iter().take(race.free_ability_bonuses as usize) {
+                *scores.entry(*ability).or_default() += 1;
+            }
+        }
+        scores
+    }
+
+    fn cantrips_to_pick(&self, build: &CharacterBuild) -> u32 {
+        build
+            .class
+            .as_deref()
+            .and_then(|c| self.catalog.class(c))
+            .and_then(|c| c.spellcasting.as_ref())
+            .map_or(0, |s| s.cantrips_known(build.level))
+    }
+
+    fn spells_to_pick(&self, build: &CharacterBuild) -> u32 {
+        let scores = self.final_scores(build);
+        build
+            .class
+            .as_deref()
+            .and_then(|c| self.catalog.class(c))
+            .and_then(|c| c.spellcasting.as_ref())
+            .map_or(0, |s| s.spells_known(build.level, scores[&s.ability]))
+    }
+
+    /// Cantrips and leveled spells on a class's list that it can cast
+    fn spell_options(&self, class: &ClassOption, level: u32) -> (Vec<SpellOption>, Vec<SpellOption>) {
+        let Some(casting) = &class.spellcasting else {
+            return (vec![], vec![]);
+        };
+        let max_level = casting.progression.max_spell_level(level);
+        self.catalog
+            .spells
+            .iter()
+            .filter(|s| s.available_to(&class.name) && s.level <= max_level)
+            .cloned()
+            .partition(|s| s.level == 0)
+    }
+
+    // ------------------------------------------------------------------------
+    // Checks
+    // ------------------------------------------------------------------------
+
+    fn check_race(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        let Some(name) = &build.race else {
+            issues.push(BuildIssue::error(BuildStep::Race, "Choose a race"));
+            return;
+        };
+        let Some(race) = self.catalog.race(name) else {
+            issues.push(BuildIssue::error(BuildStep::Race, format!("Unknown race: {}", name)));
+            return;
+        };
+        match &build.lineage {
+            None if !race.lineages.is_empty() => issues.push(BuildIssue::error(
+                BuildStep::Race,
+                format!("Choose a lineage for your {}", race.name.to_lowercase()),
+            )),
+            Some(lineage) if !race.lineages.iter().any(|l| same_name(&l.name, lineage)) => {
+                issues.push(BuildIssue::error(
+                    BuildStep::Race,
+                    format!("{} isn't one of the {} lineages", lineage, race.name),
+                ))
+            }
+            _ => {}
+        }
+
+        let picked = &build.bonus_abilities;
+        if picked.len() != race.free_ability_bonuses as usize {
+            issues.push(BuildIssue::error(
+                BuildStep::Race,
+                format!("Choose {} abilities for your racial bonuses", race.free_ability_bonuses),
+            ));
+        }
+        if picked.iter().enumerate().any(|(i, a)| picked[..i].contains(a)) {
+            issues.push(BuildIssue::error(BuildStep::Race, "Racial bonuses must go to different abilities"));
+        }
+        for ability in picked.iter().filter(|a| race.excluded_from_free_bonuses.contains(a)) {
+            issues.push(BuildIssue::error(
+                BuildStep::Race,
+                format!("A {}'s free bonuses can't go to {}", race.name.to_lowercase(), ability.name()),
+            ));
+        }
+    }
+
+    fn check_class(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        if !(1..=MAX_LEVEL).contains(&build.level) {
+            issues.push(BuildIssue::error(BuildStep::Class, format!("Level must be from 1 to {}", MAX_LEVEL)));
+        }
+        match &build.class {
+            None => issues.push(BuildIssue::error(BuildStep::Class, "Choose a class")),
+            Some(name) if self.catalog.class(name).is_none() => {
+                issues.push(BuildIssue::error(BuildStep::Class, format!("Unknown class: {}", name)))
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn check_background(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        match &build.background {
+            None => issues.push(BuildIssue::error(BuildStep::Background, "Choose a background")),
+            Some(name) if self.catalog.background(name).is_none() => {
+                issues.push(BuildIssue::error(BuildStep::Background, format!("Unknown background: {}", name)))
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn check_abilities(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        let step = BuildStep::Abilities;
+        let Some(abilities) = &build.abilities else {
+            issues.push(BuildIssue::error(step, "Generate ability scores"));
+            return;
+        };
+        let scores = abilities.scores();
+        if let Some(missing) = Ability::ALL.iter().find(|a| !scores.contains_key(a)) {
+            issues.push(BuildIssue::error(step, format!("Assign a score to {}", missing.name())));
+            return;
+        }
+        let mut assigned: Vec<i32> = scores.values().copied().collect();
+        assigned.sort_unstable_by(|a, b| b.cmp(a));
+
+        match abilities {
+            AbilityScores::PointBuy { .. } => {
+                let costs: Option<Vec<i32>> = assigned.iter().map(|s| point_buy_cost(*s)).collect();
+                match costs.map(|c| c.iter().sum::<i32>()) {
+                    None => issues.push(BuildIssue::error(step, "Point buy scores must be from 8 to 15")),
+                    Some(spent) if spent > POINT_BUY_BUDGET => issues.push(BuildIssue::error(
+                        step,
+                        format!("Point buy costs {} points; only {} can be spent", spent, POINT_BUY_BUDGET),
+                    )),
+                    Some(spent) if spent < POINT_BUY_BUDGET => issues.push(BuildIssue::warning(
+                        step,
+                        format!("{} point buy points are unspent", POINT_BUY_BUDGET - spent),
+                    )),
+                    Some(_) => {}
+                }
+            }
+            AbilityScores::StandardArray { .. } => {
+                if assigned != STANDARD_ARRAY {
+                    issues.push(BuildIssue::error(step, "Use each standard array score (15, 14, 13, 12, 10, 8) once"));
+                }
+            }
+            AbilityScores::Rolled { rolls, .. } => {
+                let mut rolled = rolls.clone();
+                rolled.sort_unstable_by(|a, b| b.cmp(a));
+                if rolled.len() != 6 || rolled.iter().any(|r| !(3..=18).contains(r)) {
+                    issues.push(BuildIssue::error(step, "Roll six scores, each from 3 to 18"));
+                } else if assigned != rolled {
+                    issues.push(BuildIssue::error(step, "Assign each rolled score to one ability"));
+                }
+            }
+        }
+
+        if let Some(class) = build.class.as_deref().and_then(|c| self.catalog.class(c)) {
+            let scores = self.final_scores(build);
+            let best = class.primary_abilities.iter().map(|a| scores[a]).max().unwrap_or(10);
+            if best < PRIMARY_ABILITY_MINIMUM {
+                let names: Vec<&str> = class.primary_abilities.iter().map(|a| a.name()).collect();
+                issues.push(BuildIssue::warning(
+                    step,
+                    format!("A {} needs {} of at least {}", class.name.to_lowercase(), names.join(" or "), PRIMARY_ABILITY_MINIMUM),
+                ));
+            }
+        }
+    }
+
+    fn check_skills(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        let step = BuildStep::Skills;
+        let Some(class) = build.class.as_deref().and_then(|c| self.catalog.class(c)) else {
+            return;
+        };
+        let free = build.race.as_deref().and_then(|r| self.catalog.race(r)).map_or(0, |r| r.free_skills);
+        let fixed = self.fixed_skills(build);
+        let wanted = (class.skill_count + free) as usize;
+        if build.skills.len() != wanted {
+            issues.push(BuildIssue::error(step, format!("Choose {} skills", wanted)));
+        }
+
+        let mut off_list = 0;
+        for (i, skill) in build.skills.iter().enumerate() {
+            if !SKILLS.iter().any(|(name, _)| same_name(name, skill)) {
+                issues.push(BuildIssue::error(step, format!("Unknown skill: {}", skill)));
+            } else if fixed.iter().any(|f| same_name(f, skill)) {
+                issues.push(BuildIssue::error(step, format!("{} is already given by your race or background", skill)));
+            } else if build.skills[..i].iter().any(|s| same_name(s, skill)) {
+                issues.push(BuildIssue::error(step, format!("{} is chosen twice", skill)));
+            } else if !class.skill_options.iter().any(|o| same_name(o, skill)) {
+                off_list += 1;
+            }
+        }
+        if off_list > free {
+            issues.push(BuildIssue::error(
+                step,
+                format!("A {} chooses skills from its class list", class.name.to_lowercase()),
+            ));
+        }
+    }
+
+    fn check_spells(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        let step = BuildStep::Spells;
+        let Some(class) = build.class.as_deref().and_then(|c| self.catalog.class(c)) else {
+            return;
+        };
+        if class.spellcasting.is_none() {
+            if !build.cantrips.is_empty() || !build.spells.is_empty() {
+                issues.push(BuildIssue::error(step, format!("{}s don't cast spells", class.name)));
+            }
+            return;
+        }
+        let max_level = class.spellcasting.as_ref().map_or(0, |s| s.progression.max_spell_level(build.level));
+
+        for (list, wanted, cantrips) in [
+            (&build.cantrips, self.cantrips_to_pick(build), true),
+            (&build.spells, self.spells_to_pick(build), false),
+        ] {
+            let kind = if cantrips { "cantrips" } else { "spells" };
+            if list.len() != wanted as usize {
+                issues.push(BuildIssue::error(step, format!("Choose {} {}", wanted, kind)));
+            }
+            for (i, name) in list.iter().enumerate() {
+                let message = match self.catalog.spell(name) {
+                    None => Some(format!("Unknown spell: {}", name)),
+                    Some(_) if list[..i].iter().any(|s| same_name(s, name)) => Some(format!("{} is chosen twice", name)),
+                    Some(spell) if !spell.available_to(&class.name) => {
+                        Some(format!("{} isn't on the {} spell list", spell.name, class.name.to_lowercase()))
+                    }
+                    Some(spell) if cantrips && spell.level != 0 => Some(format!("{} isn't a cantrip", spell.name)),
+                    Some(spell) if !cantrips && spell.level == 0 => Some(format!("{} is a cantrip", spell.name)),
+                    Some(spell) if spell.level > max_level => Some(format!(
+                        "{} is a level {} spell; a level {} {} can cast up to level {}",
+                        spell.name, spell.level, build.level, class.name.to_lowercase(), max_level
+                    )),
+                    Some(_) => None,
+                };
+                issues.extend(message.map(|m| BuildIssue::error(step, m)));
+            }
+        }
+    }
+
+    fn check_equipment(&self, build: &CharacterBuild, issues: &mut Vec<BuildIssue>) {
+        let step = BuildStep::Equipment;
+        match &build.equipment {
+            None => issues.push(BuildIssue::error(step, "Choose the class's equipment or starting gold")),
+            Some(StartingEquipment::Gold { gp }) => {
+                let Some(class) = build.class.as_deref().and_then(|c| self.catalog.class(c)) else {
+                    return;
+                };
+                if *gp > class.max_starting_gold() {
+                    issues.push(BuildIssue::error(
+                        step,
+                        format!("A {} starts with at most {} gp", class.name.to_lowercase(), class.max_starting_gold()),
+                    ));
+                }
+            }
+            Some(StartingEquipment::ClassPackage) => {}
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standard_array(order: [Ability; 6]) -> AbilityScores {
+        AbilityScores::StandardArray { scores: order.into_iter().zip(STANDARD_ARRAY).collect() }
+    }
+
+    fn wizard() -> CharacterBuild {
+        use Ability::*;
+        CharacterBuild {
+            name: Some("Elara".to_string()),
+            race: Some("Elf".to_string()),
+            lineage: Some("High Elf".to_string()),
+            class: Some("Wizard".to_string()),
+            background: Some("Sage".to_string()),
+            abilities: Some(standard_array([Intelligence, Dexterity, Constitution, Wisdom, Charisma, Strength])),
+            skills: strings(&["Investigation", "Medicine"]),
+            cantrips: strings(&["Fire Bolt", "Mage Hand", "Light"]),
+            spells: strings(&["Magic Missile", "Shield", "Mage Armor", "Sleep", "Detect Magic", "Burning Hands"]),
+            equipment: Some(StartingEquipment::ClassPackage),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_steps_and_finish() {
+        let catalog = RulesCatalog::srd();
+        let builder = CharacterBuilder::new(&catalog);
+        assert_eq!(builder.choices(&CharacterBuild::default()).next_step, BuildStep::Race);
+
+        let build = wizard();
+        let choices = builder.choices(&build);
+        assert_eq!(choices.next_step, BuildStep::Review, "{:?}", choices.issues);
+        assert_eq!((choices.cantrips_to_pick, choices.spells_to_pick), (3, 6));
+        // Sage already gives Arcana and History; Elves get Perception
+        assert_eq!(choices.fixed_skills, strings(&["Perception", "Arcana", "History"]));
+        assert!(!choices.skill_options.contains(&"Arcana".to_string()));
+
+        let built = builder.finish(&build).unwrap();
+        assert_eq!(built.character.race.as_deref(), Some("High Elf"));
+        assert_eq!(built.character.attributes["Intelligence"].base, 16);
+        assert_eq!(built.character.attributes["Dexterity"].base, 16);
+        assert_eq!(built.max_hp, 7);
+        assert_eq!(built.character.skills["Arcana"], 5);
+        assert_eq!(built.character.skills["Athletics"], -1);
+        assert_eq!(built.saving_throws[&Ability::Intelligence], 5);
+
+        // Prepared casters prepare their modifier plus level (Wisdom 12)
+        let cleric = CharacterBuild {
+            class: Some("Cleric".to_string()),
+            level: 3,
+            ..build.clone()
+        };
+        assert_eq!(builder.choices(&cleric).spells_to_pick, 1 + 3);
+        assert!(builder.finish(&cleric).is_err());
+    }
+
+    #[test]
+    fn test_rules_are_enforced() {
+        use Ability::*;
+        let catalog = RulesCatalog::srd();
+        let builder = CharacterBuilder::new(&catalog);
+        let messages = |build: &CharacterBuild| -> Vec<String> {
+            builder.validate(build).into_iter().filter(|i| !i.warning).map(|i| i.message).collect()
+        };
+
+        let mut build = wizard();
+        build.lineage = Some("Hill Dwarf".to_string());
+        build.abilities = Some(AbilityScores::PointBuy { scores: Ability::ALL.iter().map(|a| (*a, 15)).collect() });
+        build.skills = strings(&["Arcana", "Stealth"]);
+        build.spells[0] = "Cure Wounds".to_string();
+        build.cantrips[0] = "Shield".to_string();
+        build.equipment = Some(StartingEquipment::Gold { gp: 500 });
+        assert_eq!(
+            messages(&build),
+            vec![
+                "Hill Dwarf isn't one of the Elf lineages",
+                "Point buy costs 54 points; only 27 can be spent",
+                "Arcana is already given by your race or background",
+                "A wizard chooses skills from its class list",
+                "Shield isn't a cantrip",
+                "Cure Wounds isn't on the wizard spell list",
+                "A wizard starts with at most 160 gp",
+            ]
+        );
+
+        // Half-elves place two +1s, never on Charisma, and pick any two skills
+        let mut half_elf = wizard();
+        half_elf.race = Some("Half-Elf".to_string());
+        half_elf.lineage = None;
+        half_elf.bonus_abilities = vec![Charisma, Charisma];
+        let issues = messages(&half_elf);
+        assert!(issues.contains(&"Racial bonuses must go to different abilities".to_string()));
+        assert!(issues.contains(&"Choose 4 skills".to_string()));
+        half_elf.bonus_abilities = vec![Intelligence, Constitution];
+        half_elf.skills.extend(strings(&["Stealth", "Persuasion"]));
+        assert!(messages(&half_elf).is_empty());
+
+        // Ingested spells widen the lists up to the castable level
+        let record = TTRPGDocumentRecord::new(
+            "doc-fireball".to_string(),
+            "phb".to_string(),
+            "Fireball".to_string(),
+            "spell".to_string(),
+            "dnd5e".to_string(),
+            "A bright streak flashes...".to_string(),
+            0.9,
+        )
+        .with_level(3)
+        .with_attributes(serde_json::json!({ "classes": ["Sorcerer", "Wizard"] }));
+        let catalog = RulesCatalog::srd().with_ingested_spells(&[record]);
+        let builder = CharacterBuilder::new(&catalog);
+        let mut build = wizard();
+        build.spells[0] = "Fireball".to_string();
+        assert!(builder.validate(&build).iter().any(|i| i.message.starts_with("Fireball is a level 3 spell")));
+        build.level = 5;
+        build.spells.extend(strings(&["Thunderwave", "Comprehend Languages", "Expeditious Retreat", "Hideous Laughter", "Charm Person", "Chill Touch"]));
+        assert!(builder.choices(&build).spell_options.iter().any(|s| s.name == "Fireball"));
+        assert!(builder.validate(&build).iter().any(|i| i.message == "Chill Touch is a cantrip"));
+
+        let rolls = roll_ability_scores(&mut rand::thread_rng());
+        assert!(rolls.len() == 6 && rolls.iter().all(|r| (3..=18).contains(r)));
+        let scores = [Strength, Dexterity, Constitution, Intelligence, Wisdom, Charisma].into_iter().zip(rolls.iter().copied()).collect();
+        build.abilities = Some(AbilityScores::Rolled { rolls: rolls.clone(), scores });
+        assert!(!builder.validate(&build).iter().any(|i| i.step == BuildStep::Abilities && !i.warning));
+    }
+}
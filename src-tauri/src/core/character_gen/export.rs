@@ -0,0 +1,576 @@
+//! Character Export
+//!
+//! Turns a character into something to take to the table: a printable PDF
+//! sheet, a Foundry VTT Actor to import into a world, or a Roll20 character
+//! with the attributes its sheets read. D&D 5e and Pathfinder 2e map onto
+//! their VTT systems' own fields; other systems export their attributes and
+//! skills as plain values.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+use super::builder::BuiltCharacter;
+use super::sheet_pdf;
+use super::{Character, EquipmentCategory, GameSystem, TraitType};
+
+/// Foundry VTT's default actor portrait
+const FOUNDRY_DEFAULT_IMAGE: &str = "icons/svg/mystery-man.svg";
+
+/// Flag scope Foundry keeps our IDs under
+const FOUNDRY_FLAG_SCOPE: &str = "ttrpg-assistant";
+
+/// Abilities with their Foundry keys, in sheet order
+const ABILITIES: [(&str, &str); 6] = [
+    ("Strength", "str"),
+    ("Dexterity", "dex"),
+    ("Constitution", "con"),
+    ("Intelligence", "int"),
+    ("Wisdom", "wis"),
+    ("Charisma", "cha"),
+];
+
+/// D&D 5e skills with their Foundry keys and abilities
+const DND5E_SKILLS: [(&str, &str, &str); 18] = [
+    ("Acrobatics", "acr", "Dexterity"),
+    ("Animal Handling", "ani", "Wisdom"),
+    ("Arcana", "arc", "Intelligence"),
+    ("Athletics", "ath", "Strength"),
+    ("Deception", "dec", "Charisma"),
+    ("History", "his", "Intelligence"),
+    ("Insight", "ins", "Wisdom"),
+    ("Intimidation", "itm", "Charisma"),
+    ("Investigation", "inv", "Intelligence"),
+    ("Medicine", "med", "Wisdom"),
+    ("Nature", "nat", "Intelligence"),
+    ("Perception", "prc", "Wisdom"),
+    ("Performance", "prf", "Charisma"),
+    ("Persuasion", "per", "Charisma"),
+    ("Religion", "rel", "Intelligence"),
+    ("Sleight of Hand", "slt", "Dexterity"),
+    ("Stealth", "ste", "Dexterity"),
+    ("Survival", "sur", "Wisdom"),
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Unknown export format: {0}")]
+    UnknownFormat(String),
+}
+
+pub type Result<T> = std::result::Result<T, ExportError>;
+
+// ============================================================================
+// Export Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SheetFormat {
+    #[default]
+    Pdf,
+    FoundryVtt,
+    Roll20,
+}
+
+impl SheetFormat {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "pdf" => Ok(Self::Pdf),
+            "foundry" | "foundry_vtt" | "foundryvtt" => Ok(Self::FoundryVtt),
+            "roll20" => Ok(Self::Roll20),
+            other => Err(ExportError::UnknownFormat(other.to_string())),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::FoundryVtt | Self::Roll20 => "json",
+        }
+    }
+}
+
+/// Numbers a sheet shows that a `Character` doesn't carry, such as those
+/// worked out by the character builder
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SheetExtras {
+    #[serde(default)]
+    pub max_hp: Option<i32>,
+    #[serde(default)]
+    pub armor_class: Option<i32>,
+    #[serde(default)]
+    pub speed: Option<u32>,
+    #[serde(default)]
+    pub proficiency_bonus: Option<i32>,
+    /// Saving throw totals by name: "Strength" in D&D 5e, "Fortitude" in
+    /// Pathfinder 2e
+    #[serde(default)]
+    pub saving_throws: BTreeMap<String, i32>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+    /// Spell slots, indexed from 1st-level slots
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+}
+
+impl From<&BuiltCharacter> for SheetExtras {
+    fn from(built: &BuiltCharacter) -> Self {
+        Self {
+            max_hp: Some(built.max_hp),
+            armor_class: None,
+            speed: Some(built.speed),
+            proficiency_bonus: Some(built.proficiency_bonus),
+            saving_throws: built.saving_throws.iter().map(|(a, v)| (a.name().to_string(), *v)).collect(),
+            cantrips: built.cantrips.clone(),
+            spells: built.spells.clone(),
+            spell_slots: built.spell_slots.clone(),
+        }
+    }
+}
+
+/// Export a character in a format, as the bytes of the file to write
+pub fn export_character(character: &Character, extras: &SheetExtras, format: SheetFormat) -> Result<Vec<u8>> {
+    match format {
+        SheetFormat::Pdf => Ok(sheet_pdf::render_sheet(character, extras)),
+        SheetFormat::FoundryVtt => Ok(serde_json::to_vec_pretty(&foundry_actor(character, extras))?),
+        SheetFormat::Roll20 => Ok(serde_json::to_vec_pretty(&roll20_character(character, extras))?),
+    }
+}
+
+// ============================================================================
+// Shared Helpers
+// ============================================================================
+
+pub(super) fn ability_score(character: &Character, ability: &str) -> i32 {
+    character.attributes.get(ability).map_or(10, |a| a.total())
+}
+
+pub(super) fn ability_modifier(character: &Character, ability: &str) -> i32 {
+    (ability_score(character, ability) - 10).div_euclid(2)
+}
+
+/// The proficiency bonus given, or the one for the character's level
+pub(super) fn proficiency(character: &Character, extras: &SheetExtras) -> i32 {
+    extras
+        .proficiency_bonus
+        .unwrap_or_else(|| 2 + (character.level.clamp(1, 20) as i32 - 1) / 4)
+}
+
+/// How many times over a bonus includes proficiency: 0, 1, or 2 for
+/// expertise
+fn proficiency_multiple(total: i32, modifier: i32, proficiency: i32) -> i32 {
+    if proficiency <= 0 {
+        return 0;
+    }
+    ((total - modifier) / proficiency).clamp(0, 2)
+}
+
+fn snake_case(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Backstory and background as HTML paragraphs for a VTT biography
+fn biography_html(character: &Character) -> String {
+    let background = &character.background;
+    let mut paragraphs = Vec::new();
+    if !background.origin.is_empty() {
+        paragraphs.push(format!("<strong>Background:</strong> {}", escape_html(&background.origin)));
+    }
+    if !background.motivation.is_empty() {
+        paragraphs.push(format!("<strong>Motivation:</strong> {}", escape_html(&background.motivation)));
+    }
+    for text in [character.backstory.as_deref(), Some(background.history.as_str())].into_iter().flatten() {
+        paragraphs.extend(text.split("\n\n").filter(|p| !p.trim().is_empty()).map(|p| escape_html(p.trim())));
+    }
+    paragraphs.iter().map(|p| format!("<p>{}</p>", p)).collect()
+}
+
+fn item(name: &str, item_type: &str, system: Value) -> Value {
+    json!({ "name": name, "type": item_type, "system": system })
+}
+
+// ============================================================================
+// Foundry VTT
+// ============================================================================
+
+/// The character as a Foundry VTT Actor for its game system
+pub fn foundry_actor(character: &Character, extras: &SheetExtras) -> Value {
+    let (system, items) = match character.system {
+        GameSystem::DnD5e => foundry_dnd5e(character, extras),
+        GameSystem::Pathfinder2e => foundry_pf2e(character, extras),
+        _ => foundry_generic(character, extras),
+    };
+    json!({
+        "name": character.name,
+        "type": "character",
+        "img": FOUNDRY_DEFAULT_IMAGE,
+        "system": system,
+        "items": items,
+        "flags": { FOUNDRY_FLAG_SCOPE: { "character_id": character.id, "game_system": character.system.id() } },
+    })
+}
+
+fn foundry_dnd5e(character: &Character, extras: &SheetExtras) -> (Value, Vec<Value>) {
+    let proficiency = proficiency(character, extras);
+    let abilities: Map<String, Value> = ABILITIES
+        .iter()
+        .map(|(name, key)| {
+            let modifier = ability_modifier(character, name);
+            let save = extras.saving_throws.get(*name).copied().unwrap_or(modifier);
+            let proficient = proficiency_multiple(save, modifier, proficiency).min(1);
+            (key.to_string(), json!({ "value": ability_score(character, name), "proficient": proficient }))
+        })
+        .collect();
+    let skills: Map<String, Value> = DND5E_SKILLS
+        .iter()
+        .map(|(name, key, ability)| {
+            let modifier = ability_modifier(character, ability);
+            let total = character.skills.get(*name).copied().unwrap_or(modifier);
+            (key.to_string(), json!({ "value": proficiency_multiple(total, modifier, proficiency) }))
+        })
+        .collect();
+    let hp = extras.max_hp.unwrap_or_default();
+    let system = json!({
+        "abilities": abilities,
+        "skills": skills,
+        "attributes": {
+            "hp": { "value": hp, "max": hp },
+            "movement": { "walk": extras.speed.unwrap_or(30), "units": "ft" },
+            "ac": extras.armor_class.map_or(json!({ "calc": "default" }), |ac| json!({ "calc": "flat", "flat": ac })),
+        },
+        "details": {
+            "race": character.race.clone().unwrap_or_default(),
+            "background": character.background.origin,
+            "biography": { "value": biography_html(character) },
+        },
+    });
+
+    let mut items = Vec::new();
+    if let Some(class) = &character.class {
+        items.push(item(class, "class", json!({ "levels": character.level })));
+    }
+    items.extend(feature_items(character, "feat"));
+    items.extend(character.equipment.iter().map(|e| {
+        let item_type = match e.category {
+            EquipmentCategory::Weapon => "weapon",
+            EquipmentCategory::Armor | EquipmentCategory::Magic => "equipment",
+            EquipmentCategory::Tool => "tool",
+            EquipmentCategory::Consumable => "consumable",
+            _ => "loot",
+        };
+        item(&e.name, item_type, json!({ "description": { "value": escape_html(&e.description) } }))
+    }));
+    items.extend(extras.cantrips.iter().map(|s| item(s, "spell", json!({ "level": 0 }))));
+    items.extend(extras.spells.iter().map(|s| item(s, "spell", json!({}))));
+    (system, items)
+}
+
+fn foundry_pf2e(character: &Character, extras: &SheetExtras) -> (Value, Vec<Value>) {
+    let abilities: Map<String, Value> = ABILITIES
+        .iter()
+        .map(|(name, key)| (key.to_string(), json!({ "mod": ability_modifier(character, name) })))
+        .collect();
+    let hp = extras.max_hp.unwrap_or_default();
+    let system = json!({
+        "abilities": abilities,
+        "attributes": {
+            "hp": { "value": hp },
+            "speed": { "value": extras.speed.unwrap_or(25) },
+        },
+        "details": {
+            "level": { "value": character.level },
+            "biography": { "backstory": biography_html(character) },
+        },
+    });
+
+    let mut items = Vec::new();
+    if let Some(ancestry) = &character.race {
+        items.push(item(ancestry, "ancestry", json!({})));
+    }
+    if !character.background.origin.is_empty() {
+        items.push(item(&character.background.origin, "background", json!({})));
+    }
+    if let Some(class) = &character.class {
+        items.push(item(class, "class", json!({})));
+    }
+    items.extend(feature_items(character, "feat"));
+    items.extend(character.equipment.iter().map(|e| {
+        let item_type = match e.category {
+            EquipmentCategory::Weapon => "weapon",
+            EquipmentCategory::Armor => "armor",
+            EquipmentCategory::Consumable => "consumable",
+            _ => "equipment",
+        };
+        item(&e.name, item_type, json!({ "description": { "value": escape_html(&e.description) } }))
+    }));
+    (system, items)
+}
+
+/// Foundry's Simple Worldbuilding system, for games without a system module
+fn foundry_generic(character: &Character, extras: &SheetExtras) -> (Value, Vec<Value>) {
+    let mut attributes = Map::new();
+    let mut stats = Map::new();
+    for (name, value) in sorted(&character.attributes) {
+        stats.insert(snake_case(name), json!({ "value": value.total(), "label": name, "dtype": "Number" }));
+    }
+    let mut skills = Map::new();
+    for (name, value) in sorted(&character.skills) {
+        skills.insert(snake_case(name), json!({ "value": value, "label": name, "dtype": "Number" }));
+    }
+    attributes.insert("attributes".to_string(), Value::Object(stats));
+    attributes.insert("skills".to_string(), Value::Object(skills));
+    if let Some(hp) = extras.max_hp {
+        attributes.insert("health".to_string(), json!({ "value": hp, "max": hp, "label": "Health", "dtype": "Resource" }));
+    }
+
+    let system = json!({
+        "biography": biography_html(character),
+        "attributes": attributes,
+        "groups": {
+            "attributes": { "label": "Attributes" },
+            "skills": { "label": "Skills" },
+        },
+    });
+    let mut items = feature_items(character, "item");
+    items.extend(
+        character
+            .equipment
+            .iter()
+            .map(|e| item(&e.name, "item", json!({ "description": escape_html(&e.description) }))),
+    );
+    (system, items)
+}
+
+fn feature_items(character: &Character, item_type: &str) -> Vec<Value> {
+    character
+        .traits
+        .iter()
+        .filter(|t| !matches!(t.trait_type, TraitType::Personality | TraitType::Flaw | TraitType::Bond | TraitType::Ideal))
+        .map(|t| item(&t.name, item_type, json!({ "description": { "value": escape_html(&t.description) } })))
+        .collect()
+}
+
+fn sorted<V>(map: &std::collections::HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+// ============================================================================
+// Roll20
+// ============================================================================
+
+/// The character as a Roll20 character export, with attributes named as
+/// the system's sheet reads them
+pub fn roll20_character(character: &Character, extras: &SheetExtras) -> Value {
+    let mut attribs = Vec::new();
+    let mut attrib = |name: String, current: Value, max: Value| {
+        attribs.push(json!({ "name": name, "current": current, "max": max, "id": "" }));
+    };
+
+    attrib("level".to_string(), json!(character.level), json!(""));
+    attrib("class".to_string(), json!(character.class.clone().unwrap_or_default()), json!(""));
+    attrib("race".to_string(), json!(character.race.clone().unwrap_or_default()), json!(""));
+    attrib("background".to_string(), json!(character.background.origin), json!(""));
+    if let Some(hp) = extras.max_hp {
+        attrib("hp".to_string(), json!(hp), json!(hp));
+    }
+    if let Some(ac) = extras.armor_class {
+        attrib("ac".to_string(), json!(ac), json!(""));
+    }
+    if let Some(speed) = extras.speed {
+        attrib("speed".to_string(), json!(speed), json!(""));
+    }
+
+    match character.system {
+        GameSystem::DnD5e | GameSystem::Pathfinder2e => {
+            for (name, _) in ABILITIES {
+                let key = name.to_lowercase();
+                attrib(key.clone(), json!(ability_score(character, name)), json!(""));
+                attrib(format!("{}_mod", key), json!(ability_modifier(character, name)), json!(""));
+                if let Some(save) = extras.saving_throws.get(name) {
+                    attrib(format!("{}_save_bonus", key), json!(save), json!(""));
+                }
+            }
+            attrib("pb".to_string(), json!(proficiency(character, extras)), json!(""));
+            for (name, value) in sorted(&character.skills) {
+                attrib(format!("{}_bonus", snake_case(name)), json!(value), json!(""));
+            }
+        }
+        _ => {
+            for (name, value) in sorted(&character.attributes) {
+                attrib(snake_case(name), json!(value.total()), json!(""));
+            }
+            for (name, value) in sorted(&character.skills) {
+                attrib(snake_case(name), json!(value), json!(""));
+            }
+        }
+    }
+
+    // Repeating sections, one row per trait and item
+    for (i, t) in character.traits.iter().enumerate() {
+        let row = format!("repeating_traits_{}", row_id("trait", i));
+        attrib(format!("{}_name", row), json!(t.name), json!(""));
+        attrib(format!("{}_description", row), json!(t.description), json!(""));
+    }
+    for (i, e) in character.equipment.iter().enumerate() {
+        let row = format!("repeating_inventory_{}", row_id("item", i));
+        attrib(format!("{}_itemname", row), json!(e.name), json!(""));
+        attrib(format!("{}_itemcount", row), json!(1), json!(""));
+    }
+    for (i, spell) in extras.cantrips.iter().enumerate() {
+        attrib(format!("repeating_spell-cantrip_{}_spellname", row_id("cantrip", i)), json!(spell), json!(""));
+    }
+    for (i, spell) in extras.spells.iter().enumerate() {
+        attrib(format!("repeating_spell-1_{}_spellname", row_id("spell", i)), json!(spell), json!(""));
+    }
+
+    json!({
+        "schema_version": 2,
+        "type": "character",
+        "character": {
+            "name": character.name,
+            "avatar": "",
+            "bio": biography_html(character),
+            "gmnotes": escape_html(&character.notes),
+            "defaulttoken": "",
+            "tags": "[]",
+            "controlledby": "",
+            "inplayerjournals": "",
+            "attribs": attribs,
+            "abilities": [],
+        },
+    })
+}
+
+/// A stable repeating-section row ID in Roll20's "-" plus 19 characters form
+fn row_id(kind: &str, index: usize) -> String {
+    let prefix: String = kind.chars().take(7).collect();
+    format!("-{}{:0width$}", prefix, index, width = 19 - prefix.len())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::core::character_gen::{
+        AttributeValue, CharacterBackground, CharacterTrait, Equipment,
+    };
+    use std::collections::HashMap;
+
+    pub(crate) fn fighter() -> Character {
+        Character {
+            id: "char-1".to_string(),
+            name: "Bram <the Bold>".to_string(),
+            system: GameSystem::DnD5e,
+            concept: "Dwarf Fighter".to_string(),
+            race: Some("Hill Dwarf".to_string()),
+            class: Some("Fighter".to_string()),
+            level: 5,
+            attributes: ABILITIES
+                .iter()
+                .zip([16, 12, 15, 10, 13, 8])
+                .map(|((name, _), score)| (name.to_string(), AttributeValue::new(score)))
+                .collect(),
+            skills: HashMap::from([
+                ("Athletics".to_string(), 6),
+                ("Perception".to_string(), 4),
+                ("Stealth".to_string(), 1),
+            ]),
+            traits: vec![CharacterTrait {
+                name: "Second Wind".to_string(),
+                trait_type: TraitType::Class,
+                description: "Regain 1d10 + 5 HP".to_string(),
+                mechanical_effect: None,
+            }],
+            equipment: vec![Equipment {
+                name: "Longsword".to_string(),
+                category: EquipmentCategory::Weapon,
+                description: "1d8 slashing".to_string(),
+                stats: HashMap::new(),
+            }],
+            background: CharacterBackground { origin: "Soldier".to_string(), ..Default::default() },
+            backstory: Some("Held the pass at Kelgrim.\n\nNever went home.".to_string()),
+            notes: String::new(),
+            portrait_prompt: None,
+            derived_stats: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_foundry_actor() {
+        let extras = SheetExtras {
+            max_hp: Some(49),
+            saving_throws: BTreeMap::from([("Strength".to_string(), 6), ("Dexterity".to_string(), 1)]),
+            ..Default::default()
+        };
+        let actor = foundry_actor(&fighter(), &extras);
+        assert_eq!(actor["name"], "Bram <the Bold>");
+        assert_eq!(actor["system"]["abilities"]["str"], json!({ "value": 16, "proficient": 1 }));
+        assert_eq!(actor["system"]["abilities"]["dex"]["proficient"], 0);
+        // Athletics +6 is Strength +3 and proficiency +3; Stealth is untrained
+        assert_eq!(actor["system"]["skills"]["ath"]["value"], 1);
+        assert_eq!(actor["system"]["skills"]["ste"]["value"], 0);
+        assert_eq!(actor["system"]["attributes"]["hp"]["max"], 49);
+        assert_eq!(
+            actor["system"]["details"]["biography"]["value"],
+            "<p><strong>Background:</strong> Soldier</p><p>Held the pass at Kelgrim.</p><p>Never went home.</p>"
+        );
+        let items = actor["items"].as_array().unwrap();
+        assert_eq!(items[0], json!({ "name": "Fighter", "type": "class", "system": { "levels": 5 } }));
+        assert!(items.iter().any(|i| i["name"] == "Longsword" && i["type"] == "weapon"));
+
+        let mut investigator = fighter();
+        investigator.system = GameSystem::CallOfCthulhu;
+        let actor = foundry_actor(&investigator, &SheetExtras::default());
+        assert_eq!(actor["system"]["attributes"]["attributes"]["strength"]["value"], 16);
+        assert_eq!(actor["system"]["attributes"]["skills"]["athletics"]["label"], "Athletics");
+    }
+
+    #[test]
+    fn test_roll20_character_and_formats() {
+        let extras = SheetExtras { max_hp: Some(49), cantrips: vec!["Light".to_string()], ..Default::default() };
+        let export = roll20_character(&fighter(), &extras);
+        let attribs = export["character"]["attribs"].as_array().unwrap();
+        let attrib = |name: &str| attribs.iter().find(|a| a["name"] == name).map(|a| a["current"].clone());
+        assert_eq!(attrib("strength"), Some(json!(16)));
+        assert_eq!(attrib("strength_mod"), Some(json!(3)));
+        assert_eq!(attrib("pb"), Some(json!(3)));
+        assert_eq!(attrib("hp"), Some(json!(49)));
+        assert_eq!(attrib("athletics_bonus"), Some(json!(6)));
+        assert_eq!(attrib("repeating_traits_-trait00000000000000_name"), Some(json!("Second Wind")));
+        assert_eq!(attrib("repeating_spell-cantrip_-cantrip000000000000_spellname"), Some(json!("Light")));
+        assert_eq!(row_id("trait", 3).len(), 20);
+
+        assert_eq!(SheetFormat::parse("Foundry").unwrap(), SheetFormat::FoundryVtt);
+        assert!(matches!(SheetFormat::parse("fantasy grounds"), Err(ExportError::UnknownFormat(_))));
+        let bytes = export_character(&fighter(), &extras, SheetFormat::Roll20).unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&bytes).unwrap(), export);
+    }
+}
@@ -0,0 +1,927 @@
+//! Character Import
+//!
+//! Reads characters made in other tools: D&D Beyond's character JSON and
+//! Pathbuilder 2e's JSON export. Each becomes a `Character` with its
+//! features, spells, inventory, and proficiencies, plus the hit points,
+//! armor class, and passive scores the party roster and character sheets
+//! use.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::builder::{modifier, proficiency_bonus, Ability, RulesCatalog, SKILLS};
+use super::export::SheetExtras;
+use super::{
+    AttributeValue, Character, CharacterBackground, CharacterTrait, Equipment, EquipmentCategory, GameSystem,
+    TraitType,
+};
+use crate::core::campaign::party::{InventorySummary, PassiveStats, PlayerCharacter, MAX_LEVEL};
+
+/// Pathbuilder's ability keys, in `Ability::ALL` order
+const PATHBUILDER_ABILITIES: [&str; 6] = ["str", "dex", "con", "int", "wis", "cha"];
+
+/// Pathfinder 2e skills with their abilities
+const PF2E_SKILLS: [(&str, Ability); 16] = [
+    ("Acrobatics", Ability::Dexterity),
+    ("Arcana", Ability::Intelligence),
+    ("Athletics", Ability::Strength),
+    ("Crafting", Ability::Intelligence),
+    ("Deception", Ability::Charisma),
+    ("Diplomacy", Ability::Charisma),
+    ("Intimidation", Ability::Charisma),
+    ("Medicine", Ability::Wisdom),
+    ("Nature", Ability::Wisdom),
+    ("Occultism", Ability::Intelligence),
+    ("Performance", Ability::Charisma),
+    ("Religion", Ability::Wisdom),
+    ("Society", Ability::Intelligence),
+    ("Stealth", Ability::Dexterity),
+    ("Survival", Ability::Wisdom),
+    ("Thievery", Ability::Dexterity),
+];
+
+/// Pathbuilder's weapon and armor proficiency keys with their names
+const PF2E_EQUIPMENT_PROFICIENCIES: [(&str, &str); 8] = [
+    ("simple", "Simple Weapons"),
+    ("martial", "Martial Weapons"),
+    ("advanced", "Advanced Weapons"),
+    ("unarmed", "Unarmed Attacks"),
+    ("unarmored", "Unarmored Defense"),
+    ("light", "Light Armor"),
+    ("medium", "Medium Armor"),
+    ("heavy", "Heavy Armor"),
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("Invalid JSON: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("Unknown import source: {0}")]
+    UnknownSource(String),
+
+    #[error("Not a D&D Beyond or Pathbuilder character export")]
+    Unrecognized,
+
+    #[error("Character export is missing {0}")]
+    MissingField(&'static str),
+}
+
+pub type Result<T> = std::result::Result<T, ImportError>;
+
+// ============================================================================
+// Import Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportSource {
+    DndBeyond,
+    Pathbuilder,
+}
+
+impl ImportSource {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "dndbeyond" | "dnd_beyond" | "ddb" => Ok(Self::DndBeyond),
+            "pathbuilder" | "pathbuilder2e" => Ok(Self::Pathbuilder),
+            other => Err(ImportError::UnknownSource(other.to_string())),
+        }
+    }
+
+    /// Recognize an export by its shape: Pathbuilder wraps the character
+    /// in `build`, D&D Beyond has `stats` and `classes`, sometimes inside
+    /// the character service's `data`
+    pub fn detect(value: &Value) -> Option<Self> {
+        if value.get("build").is_some_and(Value::is_object) {
+            return Some(Self::Pathbuilder);
+        }
+        let character = value.get("data").filter(|d| d.is_object()).unwrap_or(value);
+        (character.get("stats").is_some() && character.get("classes").is_some()).then_some(Self::DndBeyond)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::DndBeyond => "D&D Beyond",
+            Self::Pathbuilder => "Pathbuilder 2e",
+        }
+    }
+}
+
+/// An imported character with the numbers its source worked out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedCharacter {
+    pub source: ImportSource,
+    pub character: Character,
+    /// Hit points, armor class, speed, saves, and spells
+    pub extras: SheetExtras,
+    pub subclass: Option<String>,
+    /// Weapon, armor, and tool proficiencies
+    pub proficiencies: Vec<String>,
+    pub languages: Vec<String>,
+    pub initiative_modifier: i32,
+    pub passives: PassiveStats,
+    pub inventory: InventorySummary,
+}
+
+impl ImportedCharacter {
+    /// The character as a player character on a campaign's party roster
+    pub fn to_player_character(&self, campaign_id: &str) -> PlayerCharacter {
+        let character = &self.character;
+        let class = character.class.as_deref().unwrap_or("Adventurer");
+        let level = character.level.min(MAX_LEVEL as u32) as u8;
+        let mut player = PlayerCharacter::new(campaign_id, &character.name, class, level).with_combat_stats(
+            self.extras.max_hp.unwrap_or(10),
+            self.extras.armor_class.unwrap_or(10),
+            self.initiative_modifier,
+        );
+        player.subclass = self.subclass.clone();
+        player.ancestry = character.race.clone();
+        player.passives = self.passives.clone();
+        player.inventory = self.inventory.clone();
+        player.notes = format!("Imported from {}", self.source.display_name());
+        player
+    }
+}
+
+/// Import a character export, detecting its source unless one is given
+pub fn import_character(data: &str, source: Option<ImportSource>) -> Result<ImportedCharacter> {
+    let value: Value = serde_json::from_str(data)?;
+    let source = match source {
+        Some(source) => source,
+        None => ImportSource::detect(&value).ok_or(ImportError::Unrecognized)?,
+    };
+    match source {
+        ImportSource::DndBeyond => import_dndbeyond(&value),
+        ImportSource::Pathbuilder => import_pathbuilder(&value),
+    }
+}
+
+// ============================================================================
+// JSON Helpers
+// ============================================================================
+
+/// A non-empty string at a JSON pointer
+fn text(value: &Value, pointer: &str) -> Option<String> {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+fn number(value: &Value, pointer: &str) -> Option<i64> {
+    value.pointer(pointer).and_then(Value::as_i64)
+}
+
+/// The array at a JSON pointer, or nothing when it's missing or null
+fn array<'a>(value: &'a Value, pointer: &str) -> &'a [Value] {
+    value.pointer(pointer).and_then(Value::as_array).map_or(&[], Vec::as_slice)
+}
+
+/// Plain text from the HTML D&D Beyond writes descriptions in
+fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&rsquo;", "'")
+        .replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn new_trait(name: String, trait_type: TraitType, description: String) -> CharacterTrait {
+    CharacterTrait { name, trait_type, description, mechanical_effect: None }
+}
+
+/// Wealth in gold pieces from a coin purse
+fn gold_value(coins: &Value) -> i64 {
+    let coin = |key: &str| coins.get(key).and_then(Value::as_i64).unwrap_or(0);
+    coin("pp") * 10 + coin("gp") + coin("ep") / 2 + coin("sp") / 10 + coin("cp") / 100
+}
+
+fn push_unique(list: &mut Vec<String>, item: String) {
+    if !list.contains(&item) {
+        list.push(item);
+    }
+}
+
+fn attributes(scores: &BTreeMap<Ability, i32>) -> std::collections::HashMap<String, AttributeValue> {
+    scores.iter().map(|(ability, score)| (ability.name().to_string(), AttributeValue::new(*score))).collect()
+}
+
+// ============================================================================
+// D&D Beyond
+// ============================================================================
+
+fn import_dndbeyond(root: &Value) -> Result<ImportedCharacter> {
+    let ddb = root.get("data").filter(|d| d.is_object()).unwrap_or(root);
+    let name = text(ddb, "/name").ok_or(ImportError::MissingField("name"))?;
+    let classes = array(ddb, "/classes");
+    let primary = classes
+        .iter()
+        .find(|c| c["isStartingClass"].as_bool() == Some(true))
+        .or_else(|| classes.first())
+        .ok_or(ImportError::MissingField("classes"))?;
+    let class = text(primary, "/definition/name").ok_or(ImportError::MissingField("class name"))?;
+    let level = classes.iter().filter_map(|c| number(c, "/level")).sum::<i64>().clamp(1, 20) as u32;
+    let proficiency = proficiency_bonus(level);
+
+    // Modifiers come grouped by what grants them: race, class, feats, items
+    let modifiers: Vec<&Value> = ddb
+        .get("modifiers")
+        .and_then(Value::as_object)
+        .map(|groups| groups.values().flat_map(|g| g.as_array().into_iter().flatten()).collect())
+        .unwrap_or_default();
+    let granted = |kind: &str, sub: &str| -> Vec<&Value> {
+        modifiers.iter().copied().filter(|m| m["type"] == kind && m["subType"] == sub).collect()
+    };
+    let has = |kind: &str, sub: &str| !granted(kind, sub).is_empty();
+    let total = |kind: &str, sub: &str| granted(kind, sub).iter().filter_map(|m| m["value"].as_i64()).sum::<i64>() as i32;
+
+    let scores: BTreeMap<Ability, i32> = Ability::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, ability)| {
+            let id = i as i64 + 1;
+            let stat = |list: &str| {
+                array(ddb, list).iter().find(|s| s["id"].as_i64() == Some(id)).and_then(|s| s["value"].as_i64())
+            };
+            let sub = format!("{}-score", ability.name().to_lowercase());
+            let score = stat("/overrideStats").map(|s| s as i32).unwrap_or_else(|| {
+                let raised = stat("/stats").unwrap_or(10) as i32 + stat("/bonusStats").unwrap_or(0) as i32 + total("bonus", &sub);
+                // Items like a Headband of Intellect set a score outright
+                granted("set", &sub).iter().filter_map(|m| m["value"].as_i64()).map(|s| s as i32).fold(raised, i32::max)
+            });
+            (*ability, score)
+        })
+        .collect();
+    let ability_mod = |ability: Ability| modifier(scores[&ability]);
+
+    let mut skills = std::collections::HashMap::new();
+    for (skill, ability) in SKILLS {
+        let sub = skill.to_lowercase().replace(' ', "-");
+        let trained = if has("expertise", &sub) {
+            2 * proficiency
+        } else if has("proficiency", &sub) {
+            proficiency
+        } else if has("half-proficiency", &sub) || has("half-proficiency", "ability-checks") {
+            proficiency / 2
+        } else {
+            0
+        };
+        skills.insert(skill.to_string(), ability_mod(ability) + trained + total("bonus", &sub));
+    }
+    let saving_throws: BTreeMap<String, i32> = Ability::ALL
+        .iter()
+        .map(|ability| {
+            let sub = format!("{}-saving-throws", ability.name().to_lowercase());
+            let trained = if has("proficiency", &sub) { proficiency } else { 0 };
+            (ability.name().to_string(), ability_mod(*ability) + trained + total("bonus", &sub))
+        })
+        .collect();
+
+    let max_hp = number(ddb, "/overrideHitPoints").map(|hp| hp as i32).unwrap_or_else(|| {
+        let per_level = ability_mod(Ability::Constitution) + total("bonus", "hit-points-per-level");
+        number(ddb, "/baseHitPoints").unwrap_or(0) as i32
+            + number(ddb, "/bonusHitPoints").unwrap_or(0) as i32
+            + per_level * level as i32
+    });
+    let speed = number(ddb, "/race/weightSpeeds/normal/walk").unwrap_or(30) as u32 + total("bonus", "speed") as u32;
+
+    let inventory = array(ddb, "/inventory");
+    let armor_class = dndbeyond_armor_class(inventory, ability_mod(Ability::Dexterity)) + total("bonus", "armor-class");
+
+    // Weapon, armor, tool, and language proficiencies by name; skills and
+    // saves are already in the totals above
+    let mut proficiencies = Vec::new();
+    let mut languages = Vec::new();
+    for m in &modifiers {
+        let (Some(kind), Some(name)) = (m["type"].as_str(), text(m, "/friendlySubtypeName")) else {
+            continue;
+        };
+        let sub = m["subType"].as_str().unwrap_or_default();
+        let is_skill_or_save =
+            sub.ends_with("-saving-throws") || SKILLS.iter().any(|(skill, _)| skill.to_lowercase().replace(' ', "-") == sub);
+        match kind {
+            "proficiency" | "expertise" if !is_skill_or_save => push_unique(&mut proficiencies, name),
+            "language" => push_unique(&mut languages, name),
+            _ => {}
+        }
+    }
+    proficiencies.sort();
+    languages.sort();
+
+    let mut traits = Vec::new();
+    for racial in array(ddb, "/race/racialTraits") {
+        if racial.pointer("/definition/hideInSheet").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        if let Some(name) = text(racial, "/definition/name") {
+            let description = strip_html(&text(racial, "/definition/description").unwrap_or_default());
+            traits.push(new_trait(name, TraitType::Racial, description));
+        }
+    }
+    for c in classes {
+        let class_level = number(c, "/level").unwrap_or(1);
+        for feature in array(c, "/classFeatures") {
+            if number(feature, "/definition/requiredLevel").unwrap_or(1) > class_level
+                || feature.pointer("/definition/hideInSheet").and_then(Value::as_bool) == Some(true)
+            {
+                continue;
+            }
+            if let Some(name) = text(feature, "/definition/name") {
+                let description = strip_html(&text(feature, "/definition/description").unwrap_or_default());
+                traits.push(new_trait(name, TraitType::Class, description));
+            }
+        }
+    }
+    for feat in array(ddb, "/feats") {
+        if let Some(name) = text(feat, "/definition/name") {
+            let description = strip_html(&text(feat, "/definition/description").unwrap_or_default());
+            traits.push(new_trait(name, TraitType::Feat, description));
+        }
+    }
+    if let Some(feature) = text(ddb, "/background/definition/featureName") {
+        let description = strip_html(&text(ddb, "/background/definition/featureDescription").unwrap_or_default());
+        traits.push(new_trait(feature, TraitType::Background, description));
+    }
+    for (key, label, trait_type) in [
+        ("personalityTraits", "Personality", TraitType::Personality),
+        ("ideals", "Ideal", TraitType::Ideal),
+        ("bonds", "Bond", TraitType::Bond),
+        ("flaws", "Flaw", TraitType::Flaw),
+    ] {
+        if let Some(description) = text(ddb, &format!("/traits/{}", key)) {
+            traits.push(new_trait(label.to_string(), trait_type, description));
+        }
+    }
+
+    let mut equipment = Vec::new();
+    let mut notable_items = Vec::new();
+    let mut attuned_items = Vec::new();
+    for entry in inventory {
+        let Some(item_name) = text(entry, "/definition/name") else {
+            continue;
+        };
+        let magic = entry.pointer("/definition/magic").and_then(Value::as_bool) == Some(true);
+        let category = match entry.pointer("/definition/filterType").and_then(Value::as_str) {
+            Some("Weapon") => EquipmentCategory::Weapon,
+            Some("Armor") => EquipmentCategory::Armor,
+            Some("Potion" | "Scroll") => EquipmentCategory::Consumable,
+            Some("Ring" | "Rod" | "Staff" | "Wand" | "Wondrous item") => EquipmentCategory::Magic,
+            _ if magic => EquipmentCategory::Magic,
+            _ if text(entry, "/definition/type").is_some_and(|t| t.contains("Tool")) => EquipmentCategory::Tool,
+            _ => EquipmentCategory::Other,
+        };
+        let mut stats = std::collections::HashMap::new();
+        if let Some(quantity) = number(entry, "/quantity").filter(|q| *q > 1) {
+            stats.insert("quantity".to_string(), quantity.to_string());
+        }
+        if let Some(damage) = text(entry, "/definition/damage/diceString") {
+            stats.insert("damage".to_string(), damage);
+        }
+        if let Some(ac) = number(entry, "/definition/armorClass") {
+            stats.insert("armor_class".to_string(), ac.to_string());
+        }
+        if entry["equipped"].as_bool() == Some(true) {
+            stats.insert("equipped".to_string(), "yes".to_string());
+        }
+        if entry["isAttuned"].as_bool() == Some(true) {
+            attuned_items.push(item_name.clone());
+        }
+        if magic {
+            notable_items.push(item_name.clone());
+        }
+        let description = text(entry, "/definition/snippet").or_else(|| text(entry, "/definition/description"));
+        equipment.push(Equipment {
+            name: item_name,
+            category,
+            description: strip_html(&description.unwrap_or_default()),
+            stats,
+        });
+    }
+
+    // Spells known or prepared through classes, plus those granted by
+    // race, feats, and items
+    let mut cantrips = Vec::new();
+    let mut spells = Vec::new();
+    let granted_spells = ddb
+        .get("spells")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|sources| sources.values())
+        .flat_map(|list| list.as_array().into_iter().flatten());
+    let class_spells = array(ddb, "/classSpells").iter().flat_map(|c| array(c, "/spells"));
+    for spell in class_spells.chain(granted_spells) {
+        if let Some(spell_name) = text(spell, "/definition/name") {
+            let list = if number(spell, "/definition/level") == Some(0) { &mut cantrips } else { &mut spells };
+            push_unique(list, spell_name);
+        }
+    }
+    // Multiclass slots combine levels across classes; only a single
+    // class's slots can be read off its table
+    let spell_slots = match classes {
+        [_] => RulesCatalog::srd()
+            .class(&class)
+            .and_then(|c| c.spellcasting.as_ref())
+            .map(|s| s.spell_slots(level))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    let race = text(ddb, "/race/fullName");
+    let class_summary = if classes.len() > 1 {
+        classes
+            .iter()
+            .filter_map(|c| Some(format!("{} {}", text(c, "/definition/name")?, number(c, "/level")?)))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    } else {
+        class.clone()
+    };
+    let backstory = text(ddb, "/notes/backstory");
+    let character = Character {
+        id: Uuid::new_v4().to_string(),
+        name,
+        system: GameSystem::DnD5e,
+        concept: race.as_ref().map_or_else(|| class_summary.clone(), |r| format!("{} {}", r, class_summary)),
+        race,
+        class: Some(class),
+        level,
+        attributes: attributes(&scores),
+        skills,
+        traits,
+        equipment,
+        background: CharacterBackground {
+            origin: text(ddb, "/background/definition/name")
+                .or_else(|| text(ddb, "/background/customBackground/name"))
+                .unwrap_or_default(),
+            ..Default::default()
+        },
+        backstory,
+        notes: text(ddb, "/notes/otherNotes").unwrap_or_default(),
+        portrait_prompt: None,
+        derived_stats: Vec::new(),
+    };
+
+    let passive = |skill: &str| 10 + character.skills.get(skill).copied().unwrap_or(0);
+    let passives = PassiveStats {
+        perception: passive("Perception"),
+        insight: passive("Insight"),
+        investigation: passive("Investigation"),
+    };
+    Ok(ImportedCharacter {
+        source: ImportSource::DndBeyond,
+        extras: SheetExtras {
+            max_hp: Some(max_hp),
+            armor_class: Some(armor_class),
+            speed: Some(speed),
+            proficiency_bonus: Some(proficiency),
+            saving_throws,
+            cantrips,
+            spells,
+            spell_slots,
+        },
+        subclass: text(primary, "/subclassDefinition/name"),
+        proficiencies,
+        languages,
+        initiative_modifier: ability_mod(Ability::Dexterity) + total("bonus", "initiative"),
+        passives,
+        inventory: InventorySummary {
+            gold: ddb.get("currencies").map_or(0, gold_value),
+            notable_items,
+            attuned_items,
+        },
+        character,
+    })
+}
+
+/// Armor class from equipped armor and shield: light armor adds all of
+/// Dexterity, medium up to +2, heavy none
+fn dndbeyond_armor_class(inventory: &[Value], dexterity: i32) -> i32 {
+    let equipped: Vec<&Value> = inventory.iter().filter(|i| i["equipped"].as_bool() == Some(true)).collect();
+    let armor_type = |item: &Value| number(item, "/definition/armorTypeId");
+    let armor_value = |item: &Value| number(item, "/definition/armorClass").unwrap_or(0) as i32;
+
+    let body = equipped.iter().find(|i| matches!(armor_type(i), Some(1..=3)));
+    let base = match body {
+        Some(armor) => {
+            armor_value(armor)
+                + match armor_type(armor) {
+                    Some(1) => dexterity,
+                    Some(2) => dexterity.min(2),
+                    _ => 0,
+                }
+        }
+        None => 10 + dexterity,
+    };
+    let shield = equipped.iter().find(|i| armor_type(i) == Some(4)).map_or(0, |s| armor_value(s));
+    base + shield
+}
+
+// ============================================================================
+// Pathbuilder 2e
+// ============================================================================
+
+fn import_pathbuilder(root: &Value) -> Result<ImportedCharacter> {
+    let build = root.get("build").unwrap_or(root);
+    let name = text(build, "/name").ok_or(ImportError::MissingField("name"))?;
+    let class = text(build, "/class").ok_or(ImportError::MissingField("class"))?;
+    let level = number(build, "/level").unwrap_or(1).clamp(1, 20) as u32;
+
+    let scores: BTreeMap<Ability, i32> = Ability::ALL
+        .iter()
+        .zip(PATHBUILDER_ABILITIES)
+        .map(|(ability, key)| (*ability, number(build, &format!("/abilities/{}", key)).unwrap_or(10) as i32))
+        .collect();
+    let ability_mod = |ability: Ability| modifier(scores[&ability]);
+
+    // Proficiency ranks are 0 untrained, then 2, 4, 6, 8 for trained
+    // through legendary; trained ranks add the character's level
+    let rank = |key: &str| number(build, &format!("/proficiencies/{}", key)).unwrap_or(0) as i32;
+    let trained = |rank: i32| if rank > 0 { rank + level as i32 } else { 0 };
+
+    let mut skills: std::collections::HashMap<String, i32> = PF2E_SKILLS
+        .iter()
+        .map(|(skill, ability)| (skill.to_string(), ability_mod(*ability) + trained(rank(&skill.to_lowercase()))))
+        .collect();
+    for lore in array(build, "/lores") {
+        if let (Some(topic), Some(lore_rank)) = (text(lore, "/0"), number(lore, "/1")) {
+            skills.insert(format!("{} Lore", topic), ability_mod(Ability::Intelligence) + trained(lore_rank as i32));
+        }
+    }
+    let perception = ability_mod(Ability::Wisdom) + trained(rank("perception"));
+    skills.insert("Perception".to_string(), perception);
+    let saving_throws: BTreeMap<String, i32> = [
+        ("Fortitude", Ability::Constitution),
+        ("Reflex", Ability::Dexterity),
+        ("Will", Ability::Wisdom),
+    ]
+    .iter()
+    .map(|(save, ability)| (save.to_string(), ability_mod(*ability) + trained(rank(&save.to_lowercase()))))
+    .collect();
+
+    let hp_per_level = number(build, "/attributes/classhp").unwrap_or(8) as i32
+        + number(build, "/attributes/bonushpPerLevel").unwrap_or(0) as i32
+        + ability_mod(Ability::Constitution);
+    let max_hp = number(build, "/attributes/ancestryhp").unwrap_or(0) as i32
+        + number(build, "/attributes/bonushp").unwrap_or(0) as i32
+        + hp_per_level * level as i32;
+    let speed = number(build, "/attributes/speed").unwrap_or(25) + number(build, "/attributes/speedBonus").unwrap_or(0);
+
+    let rank_name = |rank: i32| match rank {
+        2 => "Trained",
+        4 => "Expert",
+        6 => "Master",
+        _ => "Legendary",
+    };
+    let mut proficiencies: Vec<String> = PF2E_EQUIPMENT_PROFICIENCIES
+        .iter()
+        .filter(|(key, _)| rank(key) > 0)
+        .map(|(key, label)| format!("{} in {}", rank_name(rank(key)), label))
+        .collect();
+    if rank("classDC") > 0 {
+        proficiencies.push(format!("{} in Class DC", rank_name(rank("classDC"))));
+    }
+    let languages: Vec<String> =
+        array(build, "/languages").iter().filter_map(Value::as_str).map(String::from).collect();
+
+    let mut traits = Vec::new();
+    if let Some(heritage) = text(build, "/heritage") {
+        traits.push(new_trait(heritage, TraitType::Racial, "Heritage".to_string()));
+    }
+    // Feats are [name, choice, type, level] rows
+    for feat in array(build, "/feats") {
+        let Some(feat_name) = text(feat, "/0") else {
+            continue;
+        };
+        let feat_type = text(feat, "/2").unwrap_or_default();
+        let trait_type = if feat_type.contains("Ancestry") || feat_type.contains("Heritage") {
+            TraitType::Racial
+        } else {
+            TraitType::Feat
+        };
+        let mut description = feat_type;
+        if let Some(choice) = text(feat, "/1") {
+            description = format!("{} ({})", description, choice);
+        }
+        if let Some(feat_level) = number(feat, "/3") {
+            description = format!("{}, level {}", description, feat_level).trim_start_matches(", ").to_string();
+        }
+        traits.push(new_trait(feat_name, trait_type, description));
+    }
+    for special in array(build, "/specials").iter().filter_map(Value::as_str) {
+        if !traits.iter().any(|t| t.name == special) {
+            traits.push(new_trait(special.to_string(), TraitType::Class, String::new()));
+        }
+    }
+
+    let mut equipment = Vec::new();
+    for weapon in array(build, "/weapons") {
+        let Some(weapon_name) = text(weapon, "/display").or_else(|| text(weapon, "/name")) else {
+            continue;
+        };
+        let mut stats = std::collections::HashMap::new();
+        if let Some(attack) = number(weapon, "/attack") {
+            stats.insert("attack".to_string(), format!("{:+}", attack));
+        }
+        if let Some(die) = text(weapon, "/die") {
+            let bonus = number(weapon, "/damageBonus").unwrap_or(0);
+            let damage_type = text(weapon, "/damageType").unwrap_or_default();
+            stats.insert("damage".to_string(), format!("1{}{:+} {}", die, bonus, damage_type).trim_end().to_string());
+        }
+        equipment.push(Equipment { name: weapon_name, category: EquipmentCategory::Weapon, description: String::new(), stats });
+    }
+    for armor in array(build, "/armor") {
+        let Some(armor_name) = text(armor, "/display").or_else(|| text(armor, "/name")) else {
+            continue;
+        };
+        let mut stats = std::collections::HashMap::new();
+        if armor["worn"].as_bool() == Some(true) {
+            stats.insert("equipped".to_string(), "yes".to_string());
+        }
+        equipment.push(Equipment { name: armor_name, category: EquipmentCategory::Armor, description: String::new(), stats });
+    }
+    // Other gear is [name, quantity] rows
+    for item in array(build, "/equipment") {
+        let Some(item_name) = text(item, "/0") else {
+            continue;
+        };
+        let mut stats = std::collections::HashMap::new();
+        if let Some(quantity) = number(item, "/1").filter(|q| *q > 1) {
+            stats.insert("quantity".to_string(), quantity.to_string());
+        }
+        equipment.push(Equipment { name: item_name, category: EquipmentCategory::Other, description: String::new(), stats });
+    }
+
+    let mut cantrips = Vec::new();
+    let mut spells = Vec::new();
+    let mut spell_slots = Vec::new();
+    for caster in array(build, "/spellCasters") {
+        for rank_list in array(caster, "/spells") {
+            let list = if number(rank_list, "/spellLevel") == Some(0) { &mut cantrips } else { &mut spells };
+            for spell in array(rank_list, "/list").iter().filter_map(Value::as_str) {
+                push_unique(list, spell.to_string());
+            }
+        }
+        // Slots per day by rank, cantrips first
+        let per_day: Vec<u32> = array(caster, "/perDay").iter().skip(1).map(|n| n.as_u64().unwrap_or(0) as u32).collect();
+        if spell_slots.is_empty() && per_day.iter().any(|n| *n > 0) {
+            spell_slots = per_day;
+            while spell_slots.last() == Some(&0) {
+                spell_slots.pop();
+            }
+        }
+    }
+
+    let race = text(build, "/ancestry");
+    let character = Character {
+        id: Uuid::new_v4().to_string(),
+        concept: race.as_ref().map_or_else(|| class.clone(), |r| format!("{} {}", r, class)),
+        name,
+        system: GameSystem::Pathfinder2e,
+        race,
+        class: Some(class),
+        level,
+        attributes: attributes(&scores),
+        skills,
+        traits,
+        equipment,
+        background: CharacterBackground {
+            origin: text(build, "/background").unwrap_or_default(),
+            ..Default::default()
+        },
+        backstory: None,
+        notes: String::new(),
+        portrait_prompt: None,
+        derived_stats: Vec::new(),
+    };
+
+    Ok(ImportedCharacter {
+        source: ImportSource::Pathbuilder,
+        character,
+        extras: SheetExtras {
+            max_hp: Some(max_hp),
+            armor_class: number(build, "/acTotal/acTotal").map(|ac| ac as i32),
+            speed: Some(speed.max(0) as u32),
+            proficiency_bonus: None,
+            saving_throws,
+            cantrips,
+            spells,
+            spell_slots,
+        },
+        subclass: None,
+        proficiencies,
+        languages,
+        // Pathfinder rolls Perception for initiative and uses it to notice,
+        // read motives, and search alike
+        initiative_modifier: perception,
+        passives: PassiveStats {
+            perception: 10 + perception,
+            insight: 10 + perception,
+            investigation: 10 + perception,
+        },
+        inventory: InventorySummary {
+            gold: build.get("money").map_or(0, gold_value),
+            notable_items: Vec::new(),
+            attuned_items: Vec::new(),
+        },
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_import_dndbeyond() {
+        let export = json!({
+            "id": 1234,
+            "success": true,
+            "data": {
+                "name": "Sister Wren",
+                "stats": [
+                    {"id": 1, "value": 10}, {"id": 2, "value": 14}, {"id": 3, "value": 13},
+                    {"id": 4, "value": 10}, {"id": 5, "value": 15}, {"id": 6, "value": 12}
+                ],
+                "bonusStats": [{"id": 1, "value": null}],
+                "overrideStats": [],
+                "race": {
+                    "fullName": "Hill Dwarf",
+                    "weightSpeeds": {"normal": {"walk": 25}},
+                    "racialTraits": [
+                        {"definition": {"name": "Darkvision", "description": "<p>You can see in the dark.</p>"}},
+                        {"definition": {"name": "Size", "hideInSheet": true}}
+                    ]
+                },
+                "classes": [{
+                    "level": 3,
+                    "isStartingClass": true,
+                    "definition": {"name": "Cleric"},
+                    "subclassDefinition": {"name": "Life Domain"},
+                    "classFeatures": [
+                        {"definition": {"name": "Channel Divinity", "requiredLevel": 2, "description": ""}},
+                        {"definition": {"name": "Destroy Undead", "requiredLevel": 5}}
+                    ]
+                }],
+                "background": {"definition": {"name": "Acolyte", "featureName": "Shelter of the Faithful"}},
+                "baseHitPoints": 20,
+                "overrideHitPoints": null,
+                "modifiers": {
+                    "race": [
+                        {"type": "bonus", "subType": "constitution-score", "value": 2},
+                        {"type": "bonus", "subType": "wisdom-score", "value": 1},
+                        {"type": "bonus", "subType": "hit-points-per-level", "value": 1},
+                        {"type": "language", "subType": "dwarvish", "friendlySubtypeName": "Dwarvish"}
+                    ],
+                    "class": [
+                        {"type": "proficiency", "subType": "wisdom-saving-throws", "friendlySubtypeName": "Wisdom Saving Throws"},
+                        {"type": "proficiency", "subType": "insight", "friendlySubtypeName": "Insight"},
+                        {"type": "proficiency", "subType": "medium-armor", "friendlySubtypeName": "Medium Armor"}
+                    ],
+                    "item": []
+                },
+                "inventory": [
+                    {"equipped": true, "quantity": 1,
+                     "definition": {"name": "Scale Mail", "filterType": "Armor", "armorTypeId": 2, "armorClass": 14}},
+                    {"equipped": true, "quantity": 1,
+                     "definition": {"name": "Shield", "filterType": "Armor", "armorTypeId": 4, "armorClass": 2}},
+                    {"equipped": false, "quantity": 3, "isAttuned": false,
+                     "definition": {"name": "Potion of Healing", "filterType": "Potion", "magic": true}}
+                ],
+                "currencies": {"cp": 50, "sp": 5, "gp": 12, "ep": 0, "pp": 1},
+                "classSpells": [{"spells": [
+                    {"definition": {"name": "Sacred Flame", "level": 0}},
+                    {"definition": {"name": "Bless", "level": 1}}
+                ]}],
+                "spells": {"race": [], "class": [{"definition": {"name": "Bless", "level": 1}}], "item": null},
+                "traits": {"personalityTraits": "Quotes scripture at length.", "ideals": null},
+                "notes": {"backstory": "Raised in the temple at Dunmar."}
+            }
+        });
+
+        let imported = import_character(&export.to_string(), None).unwrap();
+        assert_eq!(imported.source, ImportSource::DndBeyond);
+        let character = &imported.character;
+        assert_eq!(character.concept, "Hill Dwarf Cleric");
+        assert_eq!(character.level, 3);
+        assert_eq!(character.attributes["Constitution"].base, 15);
+        assert_eq!(character.attributes["Wisdom"].base, 16);
+        assert_eq!(character.skills["Insight"], 5);
+        assert_eq!(character.skills["Stealth"], 2);
+        assert_eq!(imported.extras.saving_throws["Wisdom"], 5);
+        // 20 base, plus Constitution +2 and Dwarven Toughness +1 per level
+        assert_eq!(imported.extras.max_hp, Some(29));
+        // Scale mail 14, Dexterity capped at +2, shield +2
+        assert_eq!(imported.extras.armor_class, Some(18));
+        assert_eq!(imported.extras.speed, Some(25));
+        assert_eq!(imported.extras.cantrips, vec!["Sacred Flame"]);
+        assert_eq!(imported.extras.spells, vec!["Bless"]);
+        assert_eq!(imported.extras.spell_slots, vec![4, 2]);
+        assert_eq!(imported.proficiencies, vec!["Medium Armor"]);
+        assert_eq!(imported.languages, vec!["Dwarvish"]);
+        let trait_names: Vec<&str> = character.traits.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(trait_names, vec!["Darkvision", "Channel Divinity", "Shelter of the Faithful", "Personality"]);
+        assert_eq!(character.traits[0].description, "You can see in the dark.");
+        assert!(matches!(character.equipment[2].category, EquipmentCategory::Consumable));
+        assert_eq!(character.equipment[2].stats["quantity"], "3");
+        assert_eq!(character.backstory.as_deref(), Some("Raised in the temple at Dunmar."));
+
+        let player = imported.to_player_character("campaign-1");
+        assert_eq!(player.class, "Cleric");
+        assert_eq!(player.subclass.as_deref(), Some("Life Domain"));
+        assert_eq!((player.max_hp, player.armor_class, player.initiative_modifier), (29, 18, 2));
+        assert_eq!(player.passives.insight, 15);
+        assert_eq!(player.inventory.gold, 22);
+        assert_eq!(player.inventory.notable_items, vec!["Potion of Healing"]);
+    }
+
+    #[test]
+    fn test_import_pathbuilder() {
+        let export = json!({
+            "success": true,
+            "build": {
+                "name": "Korra Ironfist",
+                "class": "Fighter",
+                "level": 3,
+                "ancestry": "Dwarf",
+                "heritage": "Rock Dwarf",
+                "background": "Warrior",
+                "languages": ["Common", "Dwarven"],
+                "attributes": {"ancestryhp": 10, "classhp": 10, "bonushp": 0, "bonushpPerLevel": 0, "speed": 20, "speedBonus": 0},
+                "abilities": {"str": 18, "dex": 12, "con": 16, "int": 10, "wis": 12, "cha": 8},
+                "proficiencies": {
+                    "classDC": 2, "perception": 4, "fortitude": 4, "reflex": 4, "will": 2,
+                    "heavy": 2, "medium": 2, "light": 2, "unarmored": 2,
+                    "simple": 4, "martial": 4, "advanced": 2, "unarmed": 4,
+                    "athletics": 4, "intimidation": 2
+                },
+                "feats": [
+                    ["Power Attack", null, "Class Feat", 1],
+                    ["Dwarven Weapon Familiarity", null, "Ancestry Feat", 1]
+                ],
+                "specials": ["Darkvision", "Attack of Opportunity"],
+                "lores": [["Warfare", 2]],
+                "equipment": [["Backpack", 1], ["Torch", 5]],
+                "weapons": [{"name": "Warhammer", "display": "+1 Warhammer", "die": "d8", "damageType": "B", "attack": 10, "damageBonus": 4}],
+                "armor": [{"name": "Half Plate", "display": "Half Plate", "worn": true}],
+                "money": {"pp": 0, "gp": 15, "sp": 3, "cp": 2},
+                "spellCasters": [],
+                "acTotal": {"acTotal": 20}
+            }
+        });
+
+        assert!(matches!(import_character("[]", None), Err(ImportError::Unrecognized)));
+        let imported = import_character(&export.to_string(), Some(ImportSource::parse("Pathbuilder").unwrap())).unwrap();
+        let character = &imported.character;
+        assert_eq!(character.system, GameSystem::Pathfinder2e);
+        assert_eq!(character.concept, "Dwarf Fighter");
+        // Expert (4) plus level 3 plus Strength +4
+        assert_eq!(character.skills["Athletics"], 11);
+        assert_eq!(character.skills["Arcana"], 0);
+        assert_eq!(character.skills["Warfare Lore"], 5);
+        assert_eq!(imported.initiative_modifier, 8);
+        assert_eq!(imported.extras.saving_throws["Fortitude"], 10);
+        // 10 ancestry, plus 10 class and Constitution +3 per level
+        assert_eq!(imported.extras.max_hp, Some(49));
+        assert_eq!(imported.extras.armor_class, Some(20));
+        assert!(imported.proficiencies.contains(&"Expert in Martial Weapons".to_string()));
+        assert_eq!(imported.languages, vec!["Common", "Dwarven"]);
+        let feat = character.traits.iter().find(|t| t.name == "Dwarven Weapon Familiarity").unwrap();
+        assert!(matches!(feat.trait_type, TraitType::Racial));
+        assert_eq!(feat.description, "Ancestry Feat, level 1");
+        assert_eq!(character.equipment[0].stats["damage"], "1d8+4 B");
+        assert_eq!(character.equipment[3].stats["quantity"], "5");
+        assert_eq!(imported.inventory.gold, 15);
+    }
+}
@@ -0,0 +1,681 @@
+//! D&D 5e Level-Up Assistant
+//!
+//! Walks a built character through one level at a time. Each level lists
+//! what it brings: hit points, new class features, an ability score
+//! improvement or feat where the class gets one, new cantrips and spells,
+//! and the new spell slots. The player's choices are checked against the
+//! rules before anything changes, and every level gained is kept on the
+//! character so a sheet can show how it grew over the campaign.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::builder::{
+    modifier, proficiency_bonus, same_name, Ability, BuiltCharacter, ClassOption, FeatOption, RulesCatalog,
+    SpellOption, MAX_LEVEL, SKILLS,
+};
+use super::systems::dnd5e::DnD5eGenerator;
+use super::{AttributeValue, CharacterGenError, CharacterTrait, Result, TraitType};
+
+/// Highest an ability score can be raised by an improvement
+const MAX_ABILITY_SCORE: i32 = 20;
+
+/// Points an ability score improvement adds
+const IMPROVEMENT_POINTS: i32 = 2;
+
+/// SRD class features by the level they're gained, beyond those the
+/// character generator already describes
+const CLASS_FEATURES: &[(&str, u32, &str)] = &[
+    ("Barbarian", 2, "Reckless Attack"),
+    ("Barbarian", 2, "Danger Sense"),
+    ("Barbarian", 3, "Primal Path"),
+    ("Barbarian", 5, "Extra Attack"),
+    ("Barbarian", 5, "Fast Movement"),
+    ("Barbarian", 7, "Feral Instinct"),
+    ("Barbarian", 9, "Brutal Critical"),
+    ("Barbarian", 11, "Relentless Rage"),
+    ("Barbarian", 15, "Persistent Rage"),
+    ("Barbarian", 18, "Indomitable Might"),
+    ("Barbarian", 20, "Primal Champion"),
+    ("Bard", 2, "Jack of All Trades"),
+    ("Bard", 2, "Song of Rest"),
+    ("Bard", 3, "Bard College"),
+    ("Bard", 3, "Expertise"),
+    ("Bard", 5, "Font of Inspiration"),
+    ("Bard", 6, "Countercharm"),
+    ("Bard", 10, "Magical Secrets"),
+    ("Bard", 20, "Superior Inspiration"),
+    ("Cleric", 2, "Channel Divinity"),
+    ("Cleric", 5, "Destroy Undead"),
+    ("Cleric", 10, "Divine Intervention"),
+    ("Druid", 2, "Druid Circle"),
+    ("Druid", 18, "Timeless Body"),
+    ("Druid", 18, "Beast Spells"),
+    ("Druid", 20, "Archdruid"),
+    ("Fighter", 3, "Martial Archetype"),
+    ("Fighter", 5, "Extra Attack"),
+    ("Fighter", 9, "Indomitable"),
+    ("Fighter", 11, "Extra Attack (2)"),
+    ("Fighter", 20, "Extra Attack (3)"),
+    ("Monk", 2, "Unarmored Movement"),
+    ("Monk", 3, "Monastic Tradition"),
+    ("Monk", 3, "Deflect Missiles"),
+    ("Monk", 4, "Slow Fall"),
+    ("Monk", 5, "Extra Attack"),
+    ("Monk", 5, "Stunning Strike"),
+    ("Monk", 6, "Ki-Empowered Strikes"),
+    ("Monk", 7, "Evasion"),
+    ("Monk", 7, "Stillness of Mind"),
+    ("Monk", 10, "Purity of Body"),
+    ("Monk", 13, "Tongue of the Sun and Moon"),
+    ("Monk", 14, "Diamond Soul"),
+    ("Monk", 15, "Timeless Body"),
+    ("Monk", 18, "Empty Body"),
+    ("Monk", 20, "Perfect Self"),
+    ("Paladin", 2, "Fighting Style"),
+    ("Paladin", 2, "Spellcasting"),
+    ("Paladin", 3, "Divine Health"),
+    ("Paladin", 3, "Sacred Oath"),
+    ("Paladin", 5, "Extra Attack"),
+    ("Paladin", 6, "Aura of Protection"),
+    ("Paladin", 10, "Aura of Courage"),
+    ("Paladin", 11, "Improved Divine Smite"),
+    ("Paladin", 14, "Cleansing Touch"),
+    ("Ranger", 2, "Fighting Style"),
+    ("Ranger", 2, "Spellcasting"),
+    ("Ranger", 3, "Ranger Archetype"),
+    ("Ranger", 3, "Primeval Awareness"),
+    ("Ranger", 5, "Extra Attack"),
+    ("Ranger", 8, "Land's Stride"),
+    ("Ranger", 10, "Hide in Plain Sight"),
+    ("Ranger", 14, "Vanish"),
+    ("Ranger", 18, "Feral Senses"),
+    ("Ranger", 20, "Foe Slayer"),
+    ("Rogue", 3, "Roguish Archetype"),
+    ("Rogue", 5, "Uncanny Dodge"),
+    ("Rogue", 6, "Expertise"),
+    ("Rogue", 7, "Evasion"),
+    ("Rogue", 11, "Reliable Talent"),
+    ("Rogue", 14, "Blindsense"),
+    ("Rogue", 15, "Slippery Mind"),
+    ("Rogue", 18, "Elusive"),
+    ("Rogue", 20, "Stroke of Luck"),
+    ("Sorcerer", 2, "Font of Magic"),
+    ("Sorcerer", 3, "Metamagic"),
+    ("Sorcerer", 20, "Sorcerous Restoration"),
+    ("Warlock", 2, "Eldritch Invocations"),
+    ("Warlock", 3, "Pact Boon"),
+    ("Warlock", 11, "Mystic Arcanum"),
+    ("Warlock", 20, "Eldritch Master"),
+    ("Wizard", 2, "Arcane Tradition"),
+    ("Wizard", 18, "Spell Mastery"),
+    ("Wizard", 20, "Signature Spells"),
+];
+
+/// Whether a class gains an ability score improvement at a level
+fn improves_abilities(class: &str, level: u32) -> bool {
+    matches!(level, 4 | 8 | 12 | 16 | 19)
+        || (same_name("Fighter", class) && matches!(level, 6 | 14))
+        || (same_name("Rogue", class) && level == 10)
+}
+
+// ============================================================================
+// Choices
+// ============================================================================
+
+/// How the hit points for a level are found
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum HitPointGain {
+    /// The hit die's fixed average, rounded up
+    #[default]
+    Average,
+    /// A roll of the hit die
+    Rolled { roll: i32 },
+}
+
+/// What an ability score improvement level is spent on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AbilityImprovement {
+    /// +2 to one ability, or +1 to two
+    Scores { increases: BTreeMap<Ability, i32> },
+    Feat { name: String },
+}
+
+/// The player's choices for the next level
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LevelUpChoices {
+    #[serde(default)]
+    pub hit_points: HitPointGain,
+    /// Required at levels that grant an improvement, refused otherwise
+    #[serde(default)]
+    pub improvement: Option<AbilityImprovement>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+}
+
+/// What the next level brings and the options to choose from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelUpOptions {
+    pub from_level: u32,
+    pub to_level: u32,
+    pub hit_die: u32,
+    /// Hit points from the die when taking the average
+    pub average_hp: i32,
+    pub constitution_modifier: i32,
+    pub proficiency_bonus: i32,
+    pub features: Vec<CharacterTrait>,
+    pub ability_score_improvement: bool,
+    /// Feats whose prerequisites the character meets
+    pub feats: Vec<FeatOption>,
+    pub cantrips_to_learn: u32,
+    pub cantrip_options: Vec<SpellOption>,
+    /// New spells to learn, or to prepare for prepared casters, before any
+    /// improvement to the spellcasting ability
+    pub spells_to_learn: u32,
+    pub spell_options: Vec<SpellOption>,
+    /// Spell slots at the new level, indexed from 1st-level slots
+    pub spell_slots: Vec<u32>,
+}
+
+/// A level gained, as kept in the character's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelGain {
+    pub from_level: u32,
+    pub to_level: u32,
+    pub hp_gained: i32,
+    #[serde(default)]
+    pub improvement: Option<AbilityImprovement>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub cantrips: Vec<String>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub spell_slots: Vec<u32>,
+    pub leveled_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Level-Up Assistant
+// ============================================================================
+
+/// Levels up built D&D 5e characters against the rules in a catalog
+pub struct LevelUpAssistant<'a> {
+    catalog: &'a RulesCatalog,
+}
+
+impl<'a> LevelUpAssistant<'a> {
+    pub fn new(catalog: &'a RulesCatalog) -> Self {
+        Self { catalog }
+    }
+
+    /// What the character's next level brings
+    pub fn options(&self, built: &BuiltCharacter) -> Result<LevelUpOptions> {
+        let class = self.class(built)?;
+        let from_level = built.character.level;
+        let to_level = next_level(built)?;
+        let scores = scores(built);
+        let casting = class.spellcasting.as_ref();
+        let max_spell_level = casting.map_or(0, |c| c.progression.max_spell_level(to_level));
+        let known = |spell: &&SpellOption| {
+            !built.cantrips.iter().chain(&built.spells).any(|s| same_name(s, &spell.name))
+        };
+        let (cantrip_options, spell_options): (Vec<SpellOption>, Vec<SpellOption>) = match casting {
+            Some(_) => self
+                .catalog
+                .spells
+                .iter()
+                .filter(|s| s.available_to(&class.name) && s.level <= max_spell_level)
+                .filter(known)
+                .cloned()
+                .partition(|s| s.level == 0),
+            None => (vec![], vec![]),
+        };
+        let ability_score_improvement = improves_abilities(&class.name, to_level);
+
+        Ok(LevelUpOptions {
+            from_level,
+            to_level,
+            hit_die: class.hit_die,
+            average_hp: class.hit_die as i32 / 2 + 1,
+            constitution_modifier: modifier(scores[&Ability::Constitution]),
+            proficiency_bonus: proficiency_bonus(to_level),
+            features: new_features(built, &class.name, to_level),
+            ability_score_improvement,
+            feats: if ability_score_improvement {
+                self.catalog
+                    .feats
+                    .iter()
+                    .filter(|f| !has_feat(built, &f.name) && meets_prerequisite(f, &scores))
+                    .cloned()
+                    .collect()
+            } else {
+                vec![]
+            },
+            cantrips_to_learn: cantrips_to_learn(built, class, to_level),
+            cantrip_options,
+            spells_to_learn: spells_to_learn(built, class, to_level, &scores),
+            spell_options,
+            spell_slots: casting.map(|c| c.spell_slots(to_level)).unwrap_or_default(),
+        })
+    }
+
+    /// Everything wrong with the choices for the next level
+    pub fn validate(&self, built: &BuiltCharacter, choices: &LevelUpChoices) -> Vec<String> {
+        let class = match self.class(built) {
+            Ok(class) => class,
+            Err(e) => return vec![e.to_string()],
+        };
+        let to_level = match next_level(built) {
+            Ok(level) => level,
+            Err(e) => return vec![e.to_string()],
+        };
+        let mut errors = Vec::new();
+
+        if let HitPointGain::Rolled { roll } = choices.hit_points {
+            if !(1..=class.hit_die as i32).contains(&roll) {
+                errors.push(format!("A d{} can't roll {}", class.hit_die, roll));
+            }
+        }
+
+        let scores = scores(built);
+        match (&choices.improvement, improves_abilities(&class.name, to_level)) {
+            (None, false) => {}
+            (None, true) => errors.push("Choose an ability score improvement or a feat".to_string()),
+            (Some(_), false) => errors.push(format!("A {} gets no ability score improvement at level {}", class.name.to_lowercase(), to_level)),
+            (Some(AbilityImprovement::Scores { increases }), true) => {
+                if increases.values().sum::<i32>() != IMPROVEMENT_POINTS || increases.values().any(|v| !(1..=2).contains(v)) {
+                    errors.push("An ability score improvement adds 2 points, to one ability or split between two".to_string());
+                }
+                for (ability, increase) in increases {
+                    if scores[ability] + increase > MAX_ABILITY_SCORE {
+                        errors.push(format!("{} can't go above {}", ability.name(), MAX_ABILITY_SCORE));
+                    }
+                }
+            }
+            (Some(AbilityImprovement::Feat { name }), true) => match self.catalog.feat(name) {
+                None => errors.push(format!("Unknown feat: {}", name)),
+                Some(feat) if has_feat(built, &feat.name) => errors.push(format!("{} already has {}", built.character.name, feat.name)),
+                Some(feat) if !meets_prerequisite(feat, &scores) => {
+                    if let Some((ability, minimum)) = feat.prerequisite {
+                        errors.push(format!("{} needs {} {}", feat.name, ability.name(), minimum));
+                    }
+                }
+                Some(_) => {}
+            },
+        }
+
+        let improved = improved_scores(&scores, choices.improvement.as_ref());
+        let max_spell_level = class.spellcasting.as_ref().map_or(0, |c| c.progression.max_spell_level(to_level));
+        for (picked, wanted, cantrips) in [
+            (&choices.cantrips, cantrips_to_learn(built, class, to_level), true),
+            (&choices.spells, spells_to_learn(built, class, to_level, &improved), false),
+        ] {
+            let kind = if cantrips { "cantrips" } else { "spells" };
+            if picked.len() != wanted as usize {
+                errors.push(format!("Choose {} new {}", wanted, kind));
+            }
+            for (i, name) in picked.iter().enumerate() {
+                let message = match self.catalog.spell(name) {
+                    None => Some(format!("Unknown spell: {}", name)),
+                    Some(_) if picked[..i].iter().any(|s| same_name(s, name)) => Some(format!("{} is chosen twice", name)),
+                    Some(spell) if built.cantrips.iter().chain(&built.spells).any(|s| same_name(s, &spell.name)) => {
+                        Some(format!("{} is already known", spell.name))
+                    }
+                    Some(spell) if !spell.available_to(&class.name) => {
+                        Some(format!("{} isn't on the {} spell list", spell.name, class.name.to_lowercase()))
+                    }
+                    Some(spell) if cantrips && spell.level != 0 => Some(format!("{} isn't a cantrip", spell.name)),
+                    Some(spell) if !cantrips && spell.level == 0 => Some(format!("{} is a cantrip", spell.name)),
+                    Some(spell) if spell.level > max_spell_level => Some(format!(
+                        "{} is a level {} spell; a level {} {} can cast up to level {}",
+                        spell.name, spell.level, to_level, class.name.to_lowercase(), max_spell_level
+                    )),
+                    Some(_) => None,
+                };
+                errors.extend(message);
+            }
+        }
+        errors
+    }
+
+    /// Raise the character one level with the given choices, recording the
+    /// gain in its history
+    pub fn level_up(&self, built: &mut BuiltCharacter, choices: &LevelUpChoices) -> Result<LevelGain> {
+        let errors = self.validate(built, choices);
+        if !errors.is_empty() {
+            return Err(CharacterGenError::InvalidLevelUp(errors.join("; ")));
+        }
+        let class = self.class(built)?.clone();
+        let from_level = built.character.level;
+        let to_level = from_level + 1;
+        let old_scores = scores(built);
+        let new_scores = improved_scores(&old_scores, choices.improvement.as_ref());
+
+        // Improvements
+        for (ability, score) in &new_scores {
+            built.character.attributes.insert(ability.name().to_string(), AttributeValue::new(*score));
+        }
+        if let Some(AbilityImprovement::Feat { name }) = &choices.improvement {
+            let feat = self.catalog.feat(name).cloned();
+            built.character.traits.push(CharacterTrait {
+                name: feat.as_ref().map_or(name.clone(), |f| f.name.clone()),
+                trait_type: TraitType::Feat,
+                description: feat.map(|f| f.description).unwrap_or_default(),
+                mechanical_effect: None,
+            });
+        }
+
+        // Hit points; a higher Constitution modifier counts for every level
+        let old_con = modifier(old_scores[&Ability::Constitution]);
+        let new_con = modifier(new_scores[&Ability::Constitution]);
+        let die_hp = match choices.hit_points {
+            HitPointGain::Average => class.hit_die as i32 / 2 + 1,
+            HitPointGain::Rolled { roll } => roll,
+        };
+        let hp_gained = (die_hp + new_con).max(1) + (new_con - old_con) * from_level as i32;
+        built.max_hp += hp_gained;
+
+        // Proficiency-based bonuses keep their multiple of the new bonus
+        let old_proficiency = built.proficiency_bonus;
+        let new_proficiency = proficiency_bonus(to_level);
+        let rescale = |value: i32, ability: &Ability| {
+            let bonus = value - modifier(old_scores[ability]);
+            let bonus = if old_proficiency > 0 && bonus % old_proficiency == 0 {
+                bonus / old_proficiency * new_proficiency
+            } else {
+                bonus
+            };
+            modifier(new_scores[ability]) + bonus
+        };
+        for (skill, ability) in SKILLS {
+            if let Some(value) = built.character.skills.get_mut(skill) {
+                *value = rescale(*value, &ability);
+            }
+        }
+        for (ability, value) in built.saving_throws.iter_mut() {
+            *value = rescale(*value, ability);
+        }
+        built.proficiency_bonus = new_proficiency;
+        built.character.level = to_level;
+
+        // Features, refreshing those that grow with level
+        let features = new_features(built, &class.name, from_level + 1);
+        let feature_names = features.iter().map(|f| f.name.clone()).collect();
+        for current in DnD5eGenerator::get_class_traits(&class.name, to_level) {
+            if let Some(existing) = built.character.traits.iter_mut().find(|t| t.name == current.name) {
+                existing.mechanical_effect = current.mechanical_effect;
+            }
+        }
+        built.character.traits.extend(features);
+
+        // Spells
+        built.cantrips.extend(choices.cantrips.iter().cloned());
+        built.spells.extend(choices.spells.iter().cloned());
+        for (name, list) in [("Cantrips", &built.cantrips), ("Spells", &built.spells)] {
+            if list.is_empty() {
+                continue;
+            }
+            match built.character.traits.iter_mut().find(|t| t.name == name && t.trait_type == TraitType::Class) {
+                Some(existing) => existing.description = list.join(", "),
+                None => built.character.traits.push(CharacterTrait {
+                    name: name.to_string(),
+                    trait_type: TraitType::Class,
+                    description: list.join(", "),
+                    mechanical_effect: None,
+                }),
+            }
+        }
+        built.spell_slots = class.spellcasting.as_ref().map(|c| c.spell_slots(to_level)).unwrap_or_default();
+
+        let gain = LevelGain {
+            from_level,
+            to_level,
+            hp_gained,
+            improvement: choices.improvement.clone(),
+            features: feature_names,
+            cantrips: choices.cantrips.clone(),
+            spells: choices.spells.clone(),
+            spell_slots: built.spell_slots.clone(),
+            leveled_at: Utc::now(),
+        };
+        built.level_history.push(gain.clone());
+        Ok(gain)
+    }
+
+    fn class(&self, built: &BuiltCharacter) -> Result<&'a ClassOption> {
+        let catalog: &'a RulesCatalog = self.catalog;
+        built
+            .character
+            .class
+            .as_deref()
+            .and_then(|c| catalog.class(c))
+            .ok_or_else(|| CharacterGenError::InvalidLevelUp(format!("{} has no D&D 5e class", built.character.name)))
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn next_level(built: &BuiltCharacter) -> Result<u32> {
+    if built.character.level >= MAX_LEVEL {
+        return Err(CharacterGenError::InvalidLevelUp(format!(
+            "{} is already level {}",
+            built.character.name, MAX_LEVEL
+        )));
+    }
+    Ok(built.character.level + 1)
+}
+
+fn scores(built: &BuiltCharacter) -> BTreeMap<Ability, i32> {
+    Ability::ALL
+        .iter()
+        .map(|a| (*a, built.character.attributes.get(a.name()).map_or(10, |v| v.base)))
+        .collect()
+}
+
+fn improved_scores(scores: &BTreeMap<Ability, i32>, improvement: Option<&AbilityImprovement>) -> BTreeMap<Ability, i32> {
+    let mut scores = scores.clone();
+    if let Some(AbilityImprovement::Scores { increases }) = improvement {
+        for (ability, increase) in increases {
+            *scores.entry(*ability).or_insert(10) += increase;
+        }
+    }
+    scores
+}
+
+fn has_feat(built: &BuiltCharacter, name: &str) -> bool {
+    built.character.traits.iter().any(|t| t.trait_type == TraitType::Feat && same_name(&t.name, name))
+}
+
+fn meets_prerequisite(feat: &FeatOption, scores: &BTreeMap<Ability, i32>) -> bool {
+    feat.prerequisite.is_none_or(|(ability, minimum)| scores[&ability] >= minimum)
+}
+
+fn cantrips_to_learn(built: &BuiltCharacter, class: &ClassOption, level: u32) -> u32 {
+    class
+        .spellcasting
+        .as_ref()
+        .map_or(0, |c| c.cantrips_known(level).saturating_sub(built.cantrips.len() as u32))
+}
+
+fn spells_to_learn(built: &BuiltCharacter, class: &ClassOption, level: u32, scores: &BTreeMap<Ability, i32>) -> u32 {
+    class
+        .spellcasting
+        .as_ref()
+        .map_or(0, |c| c.spells_known(level, scores[&c.ability]).saturating_sub(built.spells.len() as u32))
+}
+
+/// Class features gained at a level that the character doesn't have yet
+fn new_features(built: &BuiltCharacter, class: &str, level: u32) -> Vec<CharacterTrait> {
+    let has = |name: &str| built.character.traits.iter().any(|t| t.name == name);
+    let mut features: Vec<CharacterTrait> = DnD5eGenerator::get_class_traits(class, level)
+        .into_iter()
+        .filter(|t| !has(&t.name))
+        .collect();
+    for (_, _, name) in CLASS_FEATURES.iter().filter(|(c, l, _)| same_name(c, class) && *l == level) {
+        if !has(name) && !features.iter().any(|f| &f.name == name) {
+            features.push(CharacterTrait {
+                name: name.to_string(),
+                trait_type: TraitType::Class,
+                description: format!("{} feature gained at level {}", class, level),
+                mechanical_effect: None,
+            });
+        }
+    }
+    features
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::character_gen::builder::{AbilityScores, CharacterBuild, CharacterBuilder, StartingEquipment};
+    use crate::database::TTRPGDocumentRecord;
+
+    fn build(class: &str, level: u32, skills: &[&str], cantrips: &[&str], spells: &[&str]) -> BuiltCharacter {
+        use Ability::*;
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        let order = if class == "Wizard" {
+            [Intelligence, Constitution, Dexterity, Wisdom, Charisma, Strength]
+        } else {
+            [Strength, Constitution, Dexterity, Wisdom, Charisma, Intelligence]
+        };
+        let build = CharacterBuild {
+            name: Some("Rowan".to_string()),
+            level,
+            race: Some("Human".to_string()),
+            class: Some(class.to_string()),
+            background: Some("Sage".to_string()),
+            abilities: Some(AbilityScores::StandardArray { scores: order.into_iter().zip([15, 14, 13, 12, 10, 8]).collect() }),
+            skills: strings(skills),
+            cantrips: strings(cantrips),
+            spells: strings(spells),
+            equipment: Some(StartingEquipment::ClassPackage),
+            ..Default::default()
+        };
+        CharacterBuilder::new(&RulesCatalog::srd()).finish(&build).unwrap()
+    }
+
+    #[test]
+    fn test_improvement_and_hit_points() {
+        let catalog = RulesCatalog::srd();
+        let assistant = LevelUpAssistant::new(&catalog);
+        // Strength 16, Constitution 15, Athletics proficient
+        let mut fighter = build("Fighter", 3, &["Athletics", "Perception"], &[], &[]);
+        assert_eq!((fighter.max_hp, fighter.character.skills["Athletics"]), (28, 5));
+
+        let options = assistant.options(&fighter).unwrap();
+        assert!(options.ability_score_improvement);
+        assert_eq!(options.feats.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), vec!["Grappler"]);
+
+        let mut choices = LevelUpChoices::default();
+        assert_eq!(assistant.validate(&fighter, &choices), vec!["Choose an ability score improvement or a feat"]);
+        choices.improvement = Some(AbilityImprovement::Scores {
+            increases: [(Ability::Strength, 1), (Ability::Constitution, 2)].into_iter().collect(),
+        });
+        assert!(assistant.validate(&fighter, &choices).contains(&"An ability score improvement adds 2 points, to one ability or split between two".to_string()));
+
+        // Constitution 15 -> 16 raises its modifier for all four levels
+        choices.improvement = Some(AbilityImprovement::Scores {
+            increases: [(Ability::Strength, 1), (Ability::Constitution, 1)].into_iter().collect(),
+        });
+        choices.hit_points = HitPointGain::Rolled { roll: 8 };
+        let gain = assistant.level_up(&mut fighter, &choices).unwrap();
+        assert_eq!(gain.hp_gained, 8 + 3 + 3);
+        assert_eq!(fighter.max_hp, 42);
+        assert_eq!(fighter.character.level, 4);
+        assert_eq!(fighter.character.attributes["Strength"].base, 17);
+        assert_eq!(fighter.level_history.len(), 1);
+
+        // Level 5 raises proficiency and brings Extra Attack
+        let gain = assistant.level_up(&mut fighter, &LevelUpChoices::default()).unwrap();
+        assert_eq!(gain.features, vec!["Extra Attack"]);
+        assert_eq!(fighter.proficiency_bonus, 3);
+        assert_eq!(fighter.character.skills["Athletics"], 6);
+        assert_eq!(fighter.saving_throws[&Ability::Strength], 6);
+        assert_eq!(fighter.character.skills["Stealth"], 2);
+
+        // A rolled hit die must be possible, and fighters improve again at 6th
+        let choices = LevelUpChoices { hit_points: HitPointGain::Rolled { roll: 11 }, ..Default::default() };
+        assert_eq!(
+            assistant.validate(&fighter, &choices),
+            vec!["A d10 can't roll 11", "Choose an ability score improvement or a feat"]
+        );
+    }
+
+    #[test]
+    fn test_new_spells_and_slots() {
+        let misty_step = TTRPGDocumentRecord::new(
+            "doc-misty".to_string(),
+            "phb".to_string(),
+            "Misty Step".to_string(),
+            "spell".to_string(),
+            "dnd5e".to_string(),
+            "Briefly surrounded by silvery mist...".to_string(),
+            0.9,
+        )
+        .with_level(2)
+        .with_attributes(serde_json::json!({ "classes": "Sorcerer, Warlock, Wizard" }));
+        let catalog = RulesCatalog::srd().with_ingested_spells(&[misty_step]);
+        let assistant = LevelUpAssistant::new(&catalog);
+        let mut wizard = build(
+            "Wizard",
+            1,
+            &["Investigation", "Medicine"],
+            &["Fire Bolt", "Mage Hand", "Light"],
+            &["Magic Missile", "Shield", "Mage Armor", "Sleep", "Detect Magic", "Burning Hands"],
+        );
+        assert_eq!(wizard.spell_slots, vec![2]);
+
+        let options = assistant.options(&wizard).unwrap();
+        assert_eq!((options.cantrips_to_learn, options.spells_to_learn), (0, 2));
+        assert_eq!(options.features.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), vec!["Arcane Tradition"]);
+        assert!(!options.spell_options.iter().any(|s| s.name == "Misty Step" || s.name == "Shield"));
+
+        let choices = LevelUpChoices {
+            spells: vec!["Misty Step".to_string(), "Shield".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            assistant.validate(&wizard, &choices),
+            vec![
+                "Misty Step is a level 2 spell; a level 2 wizard can cast up to level 1",
+                "Shield is already known",
+            ]
+        );
+        let choices = LevelUpChoices {
+            spells: vec!["Thunderwave".to_string(), "Charm Person".to_string()],
+            ..Default::default()
+        };
+        assistant.level_up(&mut wizard, &choices).unwrap();
+        assert_eq!(wizard.spell_slots, vec![3]);
+
+        let choices = LevelUpChoices {
+            spells: vec!["Misty Step".to_string(), "Sleep".to_string()],
+            ..Default::default()
+        };
+        assert!(assistant.validate(&wizard, &choices).contains(&"Sleep is already known".to_string()));
+        let choices = LevelUpChoices {
+            spells: vec!["Misty Step".to_string(), "Expeditious Retreat".to_string()],
+            ..Default::default()
+        };
+        let gain = assistant.level_up(&mut wizard, &choices).unwrap();
+        assert_eq!(gain.spell_slots, vec![4, 2]);
+        assert_eq!(wizard.spells.len(), 10);
+        let spells_trait = wizard.character.traits.iter().find(|t| t.name == "Spells").unwrap();
+        assert!(spells_trait.description.ends_with("Misty Step, Expeditious Retreat"));
+        assert_eq!(wizard.level_history.iter().map(|g| g.to_level).collect::<Vec<_>>(), vec![2, 3]);
+    }
+}
@@ -0,0 +1,681 @@
+//! Lifepath Generation
+//!
+//! Rolls (or asks the LLM for) the events of a character's life before play:
+//! family, formative events, contacts, and enemies. Unlike a prose backstory,
+//! a lifepath is structured: the people in it can become NPCs related to the
+//! character, and its hooks can seed plot threads.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::core::campaign::relationships::RelationshipType;
+use crate::core::character_gen::{CharacterGenError, Result};
+use crate::core::llm::{ChatMessage, ChatRequest, LLMClient, LLMConfig};
+use crate::core::npc_gen::name_banks::{builtin_bank, generate_from_rules, NameBankRegistry};
+use crate::core::npc_gen::errors::NameGenerationError;
+use crate::core::npc_gen::names::Gender;
+
+// ============================================================================
+// Lifepath Types
+// ============================================================================
+
+/// Who the lifepath is for
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LifepathSubject {
+    pub name: String,
+    pub ancestry: Option<String>,
+    /// Class or occupation
+    pub occupation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifepathOptions {
+    /// Generate with the LLM instead of rolling on the tables
+    #[serde(default)]
+    pub use_ai: bool,
+    #[serde(default = "default_formative_events")]
+    pub formative_events: usize,
+    #[serde(default = "default_contacts")]
+    pub contacts: usize,
+    #[serde(default = "default_enemies")]
+    pub enemies: usize,
+    /// Tone for LLM generation, e.g. "tragic" or "lighthearted"
+    pub tone: Option<String>,
+    /// Campaign setting for LLM generation
+    pub setting: Option<String>,
+    /// Name bank culture for the people in the lifepath
+    pub name_culture: Option<String>,
+}
+
+fn default_formative_events() -> usize {
+    2
+}
+
+fn default_contacts() -> usize {
+    2
+}
+
+fn default_enemies() -> usize {
+    1
+}
+
+impl Default for LifepathOptions {
+    fn default() -> Self {
+        Self {
+            use_ai: false,
+            formative_events: default_formative_events(),
+            contacts: default_contacts(),
+            enemies: default_enemies(),
+            tone: None,
+            setting: None,
+            name_culture: None,
+        }
+    }
+}
+
+/// Which part of the lifepath an event belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifeEventCategory {
+    Family,
+    Formative,
+    Contact,
+    Enemy,
+}
+
+/// How a person from the lifepath is tied to the character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonTie {
+    Family,
+    Friend,
+    Mentor,
+    Romantic,
+    Contact,
+    Rival,
+    Enemy,
+}
+
+impl PersonTie {
+    /// The relationship from the character to this person
+    pub fn relationship_type(&self) -> RelationshipType {
+        match self {
+            Self::Family => RelationshipType::Family,
+            Self::Friend => RelationshipType::Ally,
+            Self::Mentor => RelationshipType::Mentor,
+            Self::Romantic => RelationshipType::Romantic,
+            Self::Contact => RelationshipType::Acquaintance,
+            Self::Rival => RelationshipType::Custom("Rival".to_string()),
+            Self::Enemy => RelationshipType::Enemy,
+        }
+    }
+
+    /// NPC role for the person when they are added to the campaign
+    pub fn npc_role(&self) -> &'static str {
+        match self {
+            Self::Family | Self::Friend | Self::Romantic => "ally",
+            Self::Mentor => "mentor",
+            Self::Contact => "informant",
+            Self::Rival => "rival",
+            Self::Enemy => "enemy",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonStatus {
+    #[default]
+    Alive,
+    Dead,
+    Missing,
+    Estranged,
+    Imprisoned,
+}
+
+/// Someone who mattered in the character's past
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifepathPerson {
+    pub name: String,
+    pub tie: PersonTie,
+    /// Who they are to the character, e.g. "mother" or "old mentor"
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub status: PersonStatus,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeEvent {
+    pub category: LifeEventCategory,
+    /// Age at the time, if known
+    #[serde(default)]
+    pub age: Option<u32>,
+    pub description: String,
+    /// Name of the person the event is about
+    #[serde(default)]
+    pub person: Option<String>,
+}
+
+/// Unfinished business from the character's past, ready to become a plot
+/// thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifepathHook {
+    pub title: String,
+    pub description: String,
+    /// Name of the person the hook involves
+    #[serde(default)]
+    pub person: Option<String>,
+    /// Words in session notes that point at this hook
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FamilyBackground {
+    pub origin: String,
+    pub upbringing: String,
+    #[serde(default)]
+    pub siblings: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lifepath {
+    #[serde(default)]
+    pub subject_name: String,
+    #[serde(default)]
+    pub family: FamilyBackground,
+    /// Events in the order they happened
+    #[serde(default)]
+    pub events: Vec<LifeEvent>,
+    #[serde(default)]
+    pub people: Vec<LifepathPerson>,
+    #[serde(default)]
+    pub hooks: Vec<LifepathHook>,
+}
+
+impl Lifepath {
+    pub fn person(&self, name: &str) -> Option<&LifepathPerson> {
+        self.people.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The lifepath as a few lines of notes
+    pub fn summary(&self) -> String {
+        let mut summary = format!("Lifepath: from {}; {}.", self.family.origin, self.family.upbringing);
+        for event in &self.events {
+            match event.age {
+                Some(age) => summary.push_str(&format!("\n- Age {}: {}", age, event.description)),
+                None => summary.push_str(&format!("\n- {}", event.description)),
+            }
+        }
+        summary
+    }
+
+    /// Add an event, its person, and its hook, filling in the templates
+    fn add(
+        &mut self,
+        category: LifeEventCategory,
+        age: Option<u32>,
+        template: &str,
+        person: Option<LifepathPerson>,
+        hook: Option<HookData>,
+        subject: &str,
+    ) {
+        let person_name = person.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+        let role = person.as_ref().map(|p| p.role.clone()).unwrap_or_default();
+        let fill = |text: &str| {
+            text.replace("{subject}", subject)
+                .replace("{person}", &person_name)
+                .replace("{role}", &role)
+        };
+        self.events.push(LifeEvent {
+            category,
+            age,
+            description: fill(template),
+            person: person.as_ref().map(|p| p.name.clone()),
+        });
+        if let Some((title, description)) = hook {
+            self.hooks.push(LifepathHook {
+                title: fill(title),
+                description: fill(description),
+                person: person.as_ref().map(|p| p.name.clone()),
+                keywords: person.as_ref().map(|p| vec![p.name.clone()]).unwrap_or_default(),
+            });
+        }
+        if let Some(mut person) = person {
+            person.description = fill(&person.description);
+            self.people.push(person);
+        }
+    }
+}
+
+// ============================================================================
+// Lifepath Tables
+// ============================================================================
+
+/// (origin, upbringing)
+const ORIGINS: &[(&str, &str)] = &[
+    ("a farming village", "raised on a farm and up before dawn for chores"),
+    ("a bustling port city", "grew up on the docks among sailors and smugglers"),
+    ("a minor noble house", "raised among tutors, etiquette, and family politics"),
+    ("a traveling caravan", "never lived more than a month in one place"),
+    ("a temple orphanage", "raised by clergy alongside other foundlings"),
+    ("a mining town", "grew up in the shadow of the mine and its dangers"),
+    ("a frontier fort", "raised among soldiers on a dangerous border"),
+    ("a family of craftsfolk", "apprenticed to the family trade from a young age"),
+];
+
+/// A rolled hook: (title, description); `{person}` and `{subject}` are filled in
+type HookData = (&'static str, &'static str);
+
+/// (what happened to a parent, their status, hook)
+const PARENT_FATES: &[(&str, PersonStatus, Option<HookData>)] = &[
+    ("{subject}'s {role}, {person}, is alive and proud of them", PersonStatus::Alive, None),
+    ("{subject}'s {role}, {person}, still keeps the family home and worries constantly", PersonStatus::Alive, None),
+    ("{subject}'s {role}, {person}, died of a fever when {subject} was young", PersonStatus::Dead, None),
+    (
+        "{subject}'s {role}, {person}, vanished one night and was never found",
+        PersonStatus::Missing,
+        Some(("The disappearance of {person}", "{person} vanished without a trace years ago. Someone knows what happened.")),
+    ),
+    (
+        "{subject}'s {role}, {person}, was imprisoned for a crime they may not have committed",
+        PersonStatus::Imprisoned,
+        Some(("Clear {person}'s name", "{person} still sits in a cell for a crime the real culprit got away with.")),
+    ),
+    (
+        "{subject}'s {role}, {person}, disowned them after a bitter argument",
+        PersonStatus::Estranged,
+        Some(("Reconcile with {person}", "{person} has fallen ill and may not have long to make peace with {subject}.")),
+    ),
+];
+
+/// (event, hook)
+const FORMATIVE_EVENTS: &[(&str, Option<HookData>)] = &[
+    ("{subject} survived a fire that destroyed their home", None),
+    (
+        "{subject} found a strange amulet in a riverbed that is always warm to the touch",
+        Some(("The warm amulet", "The amulet {subject} found as a child has begun to glow, and someone else is looking for it.")),
+    ),
+    (
+        "{subject} saw a monster kill a traveler and no one believed them",
+        Some(("What {subject} saw", "The creature {subject} saw as a child has been sighted again.")),
+    ),
+    ("{subject} won a local contest and a moment of fame", None),
+    (
+        "{subject} was falsely accused of theft and fled town",
+        Some(("The old accusation", "The theft {subject} was blamed for is still unsolved, and a bounty is still posted.")),
+    ),
+    ("{subject} spent a winter lost in the wilderness and came back changed", None),
+    ("A wandering sage taught {subject} to read the stars", None),
+    (
+        "{subject} made a bargain with a stranger and still owes them a favor",
+        Some(("The favor owed", "The stranger {subject} bargained with has come to collect.")),
+    ),
+];
+
+/// (role, tie, description, hook)
+const CONTACTS: &[(&str, PersonTie, &str, Option<HookData>)] = &[
+    ("childhood friend", PersonTie::Friend, "Grew up with {subject} and still writes now and then", None),
+    (
+        "old mentor",
+        PersonTie::Mentor,
+        "Taught {subject} most of what they know",
+        Some(("{person}'s last lesson", "{person} has sent for {subject} with a task only a former student can do.")),
+    ),
+    ("fence", PersonTie::Contact, "Buys and sells things for {subject} without asking questions", None),
+    ("guard sergeant", PersonTie::Contact, "Owes {subject} a favor from years back", None),
+    ("scholar", PersonTie::Contact, "Will research anything for a good story", None),
+    (
+        "former lover",
+        PersonTie::Romantic,
+        "Parted from {subject} on uncertain terms",
+        Some(("Unfinished business with {person}", "{person} turns up in trouble and asks {subject} for help.")),
+    ),
+    ("smuggler captain", PersonTie::Contact, "Can get {subject} anywhere, for a price", None),
+];
+
+/// (role, tie, cause, hook)
+const ENEMIES: &[(&str, PersonTie, &str, HookData)] = &[
+    (
+        "rival",
+        PersonTie::Rival,
+        "{subject} humiliated {person} in front of everyone",
+        ("{person}'s grudge", "{person} has never forgiven {subject} and is working to ruin them."),
+    ),
+    (
+        "crime boss",
+        PersonTie::Enemy,
+        "{subject} witnessed one of {person}'s crimes",
+        ("Loose ends", "{person} wants the only witness to their crime silenced."),
+    ),
+    (
+        "former partner",
+        PersonTie::Enemy,
+        "{person} betrayed {subject} and took everything",
+        ("Settling accounts with {person}", "{person} is living well on what they stole from {subject}."),
+    ),
+    (
+        "disgraced knight",
+        PersonTie::Rival,
+        "{subject} exposed {person}'s cowardice",
+        ("{person}'s redemption", "{person} is out to prove themselves by beating {subject} at anything."),
+    ),
+    (
+        "cult priest",
+        PersonTie::Enemy,
+        "{subject} escaped {person}'s sacrifice",
+        ("The cult remembers", "{person}'s cult still wants the one who got away."),
+    ),
+    (
+        "noble heir",
+        PersonTie::Enemy,
+        "{person} blames {subject} for their sibling's death",
+        ("Blood price", "{person} has hired hunters to bring {subject} in."),
+    ),
+];
+
+const FIRST_NAMES: &[&str] = &[
+    "Alda", "Bram", "Corin", "Dessa", "Edric", "Fenna", "Garrick", "Hilde", "Ivo", "Jorah", "Kaela", "Lorne",
+    "Maren", "Nils", "Orla", "Pell", "Quinn", "Rosalind", "Soren", "Tamsin", "Ulric", "Vesna", "Wynn", "Yara",
+];
+
+const SURNAMES: &[&str] = &[
+    "Ashdown", "Blackwood", "Carrow", "Dunmore", "Fairweather", "Greaves", "Holloway", "Kettle", "Marsh", "Redfern",
+    "Stone", "Thorne", "Vance", "Wicks",
+];
+
+// ============================================================================
+// Lifepath Generator
+// ============================================================================
+
+pub struct LifepathGenerator {
+    llm_client: Option<LLMClient>,
+    name_banks: Option<Arc<NameBankRegistry>>,
+}
+
+impl Default for LifepathGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LifepathGenerator {
+    pub fn new() -> Self {
+        Self {
+            llm_client: None,
+            name_banks: None,
+        }
+    }
+
+    pub fn with_llm(mut self, llm_config: LLMConfig) -> Self {
+        self.llm_client = Some(LLMClient::new(llm_config));
+        self
+    }
+
+    /// Name the people in rolled lifepaths from these banks
+    pub fn with_name_banks(mut self, name_banks: Arc<NameBankRegistry>) -> Self {
+        self.name_banks = Some(name_banks);
+        self
+    }
+
+    /// Roll a lifepath on the tables
+    pub fn roll(&self, subject: &LifepathSubject, options: &LifepathOptions, rng: &mut impl Rng) -> Lifepath {
+        let (origin, upbringing) = *ORIGINS.choose(rng).expect("origins");
+        let family_name = subject.name.split_whitespace().skip(1).last().map(str::to_string);
+        let mut lifepath = Lifepath {
+            subject_name: subject.name.clone(),
+            family: FamilyBackground {
+                origin: origin.to_string(),
+                upbringing: upbringing.to_string(),
+                siblings: rng.gen_range(0..=4),
+            },
+            events: vec![],
+            people: vec![],
+            hooks: vec![],
+        };
+
+        // Family: one parent's fate, and a sibling if there are any
+        let (fate, status, hook) = PARENT_FATES.choose(rng).expect("parent fates");
+        let role = if rng.gen_bool(0.5) { "mother" } else { "father" };
+        let parent = self.person(rng, options, family_name.as_deref(), PersonTie::Family, role, *status, "");
+        let age = (*status != PersonStatus::Alive).then(|| rng.gen_range(4..=14));
+        lifepath.add(LifeEventCategory::Family, age, fate, Some(parent), *hook, &subject.name);
+        if lifepath.family.siblings > 0 {
+            let role = if rng.gen_bool(0.5) { "sister" } else { "brother" };
+            let family_name = family_name.as_deref();
+            let sibling = self.person(rng, options, family_name, PersonTie::Family, role, PersonStatus::Alive, "");
+            let description = match lifepath.family.siblings {
+                1 => format!("{{subject}} grew up with one sibling, their {} {{person}}", role),
+                n => format!("{{subject}} grew up with {} siblings, closest to their {} {{person}}", n, role),
+            };
+            lifepath.add(LifeEventCategory::Family, None, &description, Some(sibling), None, &subject.name);
+        }
+
+        let mut ages: Vec<u32> = (0..options.formative_events.min(FORMATIVE_EVENTS.len()))
+            .map(|_| rng.gen_range(8..=18))
+            .collect();
+        ages.sort_unstable();
+        for ((event, hook), age) in FORMATIVE_EVENTS.choose_multiple(rng, ages.len()).zip(ages) {
+            lifepath.add(LifeEventCategory::Formative, Some(age), event, None, *hook, &subject.name);
+        }
+
+        for (role, tie, description, hook) in CONTACTS.choose_multiple(rng, options.contacts) {
+            let contact = self.person(rng, options, None, *tie, role, PersonStatus::Alive, description);
+            let event = format!("{{subject}} met {{person}}, a {}", role);
+            lifepath.add(LifeEventCategory::Contact, None, &event, Some(contact), *hook, &subject.name);
+        }
+
+        for (role, tie, cause, hook) in ENEMIES.choose_multiple(rng, options.enemies) {
+            let enemy = self.person(rng, options, None, *tie, role, PersonStatus::Alive, cause);
+            lifepath.add(LifeEventCategory::Enemy, None, cause, Some(enemy), Some(*hook), &subject.name);
+        }
+
+        lifepath
+    }
+
+    /// Have the LLM write the lifepath, in the same structure as a rolled one
+    pub async fn generate_detailed(&self, subject: &LifepathSubject, options: &LifepathOptions) -> Result<Lifepath> {
+        let llm = self
+            .llm_client
+            .as_ref()
+            .ok_or_else(|| CharacterGenError::LLMError("No LLM configured".to_string()))?;
+
+        let request = ChatRequest {
+            messages: vec![ChatMessage::user(build_prompt(subject, options))],
+            system_prompt: Some(
+                "You are a creative GM assistant who writes character lifepaths. Respond only with JSON."
+                    .to_string(),
+            ),
+            temperature: Some(0.85),
+            max_tokens: Some(1500),
+            provider: None,
+            tools: None,
+            tool_choice: None,
+        };
+        let response = llm
+            .chat(request)
+            .await
+            .map_err(|e| CharacterGenError::LLMError(e.to_string()))?;
+        parse_lifepath(&response.content, subject)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn person(
+        &self,
+        rng: &mut impl Rng,
+        options: &LifepathOptions,
+        family_name: Option<&str>,
+        tie: PersonTie,
+        role: &str,
+        status: PersonStatus,
+        description: &str,
+    ) -> LifepathPerson {
+        let name = self
+            .culture_name(rng, options)
+            .unwrap_or_else(|| {
+                let first = FIRST_NAMES.choose(rng).expect("first names");
+                let last = SURNAMES.choose(rng).expect("surnames");
+                format!("{} {}", first, last)
+            });
+        // Family shares the character's family name
+        let name = match (family_name, name.split_once(' ')) {
+            (Some(family), Some((first, _))) => format!("{} {}", first, family),
+            (Some(family), None) => format!("{} {}", name, family),
+            _ => name,
+        };
+        LifepathPerson {
+            name,
+            tie,
+            role: role.to_string(),
+            status,
+            description: description.to_string(),
+        }
+    }
+
+    fn culture_name(&self, rng: &mut impl Rng, options: &LifepathOptions) -> Option<String> {
+        let culture = options.name_culture.as_deref()?;
+        let gender = if rng.gen_bool(0.5) { Gender::Male } else { Gender::Female };
+        let result = match &self.name_banks {
+            Some(banks) => banks.generate(culture, gender, rng),
+            None => builtin_bank(culture)
+                .ok_or_else(|| NameGenerationError::CultureNotFound {
+                    culture: culture.to_string(),
+                })
+                .and_then(|rules| generate_from_rules(&rules, gender, rng)),
+        };
+        result.ok()
+    }
+}
+
+// ============================================================================
+// LLM Prompt and Parsing
+// ============================================================================
+
+fn build_prompt(subject: &LifepathSubject, options: &LifepathOptions) -> String {
+    let mut prompt = format!(
+        "Write the lifepath of {}, {} {}, before they started adventuring.\n",
+        subject.name,
+        subject.ancestry.as_deref().unwrap_or("a"),
+        subject.occupation.as_deref().unwrap_or("adventurer"),
+    );
+    if let Some(setting) = &options.setting {
+        prompt.push_str(&format!("Setting: {}\n", setting));
+    }
+    if let Some(tone) = &options.tone {
+        prompt.push_str(&format!("Tone: {}\n", tone));
+    }
+    prompt.push_str(&format!(
+        "Include their family, {} formative events, {} contacts, and {} enemies. \
+         Every person mentioned must be listed in \"people\" with a full name. \
+         Hooks are unfinished business a GM could build a session around.\n\n\
+         Respond with JSON:\n\
+         {{\"family\": {{\"origin\": \"...\", \"upbringing\": \"...\", \"siblings\": 0}},\n \
+         \"events\": [{{\"category\": \"family|formative|contact|enemy\", \"age\": 12, \"description\": \"...\", \"person\": \"name or null\"}}],\n \
+         \"people\": [{{\"name\": \"...\", \"tie\": \"family|friend|mentor|romantic|contact|rival|enemy\", \"role\": \"mother\", \
+         \"status\": \"alive|dead|missing|estranged|imprisoned\", \"description\": \"...\"}}],\n \
+         \"hooks\": [{{\"title\": \"...\", \"description\": \"...\", \"person\": \"name or null\", \"keywords\": [\"...\"]}}]}}",
+        options.formative_events, options.contacts, options.enemies
+    ));
+    prompt
+}
+
+/// Parse the LLM's JSON lifepath, dropping references to people it forgot
+/// to list
+pub fn parse_lifepath(content: &str, subject: &LifepathSubject) -> Result<Lifepath> {
+    let start = content.find('{');
+    let end = content.rfind('}');
+    let json = match (start, end) {
+        (Some(start), Some(end)) if start < end => &content[start..=end],
+        _ => return Err(CharacterGenError::BackstoryError("No lifepath JSON in response".to_string())),
+    };
+    let mut lifepath: Lifepath = serde_json::from_str(json)
+        .map_err(|e| CharacterGenError::BackstoryError(format!("Failed to parse lifepath: {}", e)))?;
+    lifepath.subject_name = subject.name.clone();
+
+    let known: Vec<String> = lifepath.people.iter().map(|p| p.name.to_lowercase()).collect();
+    let is_known = |name: &Option<String>| name.as_ref().is_some_and(|n| known.contains(&n.to_lowercase()));
+    for event in &mut lifepath.events {
+        if !is_known(&event.person) {
+            event.person = None;
+        }
+    }
+    for hook in &mut lifepath.hooks {
+        if !is_known(&hook.person) {
+            hook.person = None;
+        }
+        if let Some(person) = &hook.person {
+            if !hook.keywords.contains(person) {
+                hook.keywords.push(person.clone());
+            }
+        }
+    }
+    Ok(lifepath)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn subject() -> LifepathSubject {
+        LifepathSubject {
+            name: "Kestrel Vane".to_string(),
+            ancestry: Some("human".to_string()),
+            occupation: Some("rogue".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rolled_lifepath_links_people_and_hooks() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let options = LifepathOptions {
+            contacts: 3,
+            enemies: 2,
+            ..Default::default()
+        };
+        let lifepath = LifepathGenerator::new().roll(&subject(), &options, &mut rng);
+
+        let family: Vec<_> = lifepath.people.iter().filter(|p| p.tie == PersonTie::Family).collect();
+        assert_eq!(family.len(), if lifepath.family.siblings > 0 { 2 } else { 1 });
+        assert!(family.iter().all(|p| p.name.ends_with(" Vane")));
+        assert_eq!(lifepath.events.iter().filter(|e| e.category == LifeEventCategory::Contact).count(), 3);
+        assert_eq!(lifepath.events.iter().filter(|e| e.category == LifeEventCategory::Enemy).count(), 2);
+        // Every enemy leaves a hook, and every hook's person is in the lifepath
+        assert!(lifepath.hooks.len() >= 2);
+        for hook in &lifepath.hooks {
+            assert!(!hook.title.contains('{') && !hook.description.contains('{'));
+            if let Some(name) = &hook.person {
+                assert!(lifepath.person(name).is_some());
+                assert!(hook.keywords.contains(name));
+            }
+        }
+        assert!(lifepath.summary().contains("Kestrel Vane"));
+    }
+
+    #[test]
+    fn test_parse_llm_lifepath() {
+        let response = r#"Here you go:
+{"family": {"origin": "a river town", "upbringing": "raised by fishers", "siblings": 1},
+ "events": [{"category": "enemy", "age": 17, "description": "Crossed Maro", "person": "Maro Venn"},
+            {"category": "formative", "description": "Met a ghost", "person": "The Ghost"}],
+ "people": [{"name": "Maro Venn", "tie": "enemy", "role": "smuggler", "status": "alive"}],
+ "hooks": [{"title": "Maro's revenge", "description": "Maro is coming", "person": "maro venn"}]}"#;
+        let lifepath = parse_lifepath(response, &subject()).unwrap();
+
+        assert_eq!(lifepath.subject_name, "Kestrel Vane");
+        assert_eq!(lifepath.people[0].tie.relationship_type(), RelationshipType::Enemy);
+        assert_eq!(lifepath.events[1].person, None);
+        assert_eq!(lifepath.hooks[0].keywords, vec!["maro venn".to_string()]);
+        assert!(parse_lifepath("no json here", &subject()).is_err());
+    }
+}
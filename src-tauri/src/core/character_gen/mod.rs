@@ -10,10 +10,17 @@
 //! - Dungeon World (PbtA)
 //! - GURPS (Universal)
 //! - Warhammer Fantasy (Grimdark)
+//! - Savage Worlds (Pulp Action)
 
 pub mod systems;
 pub mod backstory;
+pub mod lifepath;
 pub mod prompts;
+pub mod builder;
+pub mod level_up;
+pub mod export;
+pub mod import;
+pub mod sheet_pdf;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -43,6 +50,12 @@ pub enum CharacterGenError {
 
     #[error("Backstory generation failed: {0}")]
     BackstoryError(String),
+
+    #[error("Invalid character build: {0}")]
+    InvalidBuild(String),
+
+    #[error("Invalid level up: {0}")]
+    InvalidLevelUp(String),
 }
 
 pub type Result<T> = std::result::Result<T, CharacterGenError>;
@@ -62,6 +75,9 @@ pub struct Character {
     pub level: u32,
     pub attributes: HashMap<String, AttributeValue>,
     pub skills: HashMap<String, i32>,
+    /// Figures worked out from attributes and skills, like Sanity or Parry
+    #[serde(default)]
+    pub derived_stats: Vec<DerivedStat>,
     pub traits: Vec<CharacterTrait>,
     pub equipment: Vec<Equipment>,
     pub background: CharacterBackground,
@@ -82,6 +98,7 @@ pub enum GameSystem {
     DungeonWorld,
     GURPS,
     Warhammer,
+    SavageWorlds,
     Custom(String),
 }
 
@@ -98,6 +115,7 @@ impl GameSystem {
             "dw" | "dungeon world" | "dungeonworld" | "pbta" => Self::DungeonWorld,
             "gurps" => Self::GURPS,
             "warhammer" | "wfrp" | "warhammer fantasy" => Self::Warhammer,
+            "savage_worlds" | "savage worlds" | "savageworlds" | "swade" => Self::SavageWorlds,
             other => Self::Custom(other.to_string()),
         }
     }
@@ -114,6 +132,7 @@ impl GameSystem {
             Self::DungeonWorld => "Dungeon World",
             Self::GURPS => "GURPS",
             Self::Warhammer => "Warhammer Fantasy",
+            Self::SavageWorlds => "Savage Worlds",
             Self::Custom(name) => name,
         }
     }
@@ -130,6 +149,7 @@ impl GameSystem {
             Self::DungeonWorld => "dungeon_world",
             Self::GURPS => "gurps",
             Self::Warhammer => "warhammer",
+            Self::SavageWorlds => "savage_worlds",
             Self::Custom(name) => name,
         }
     }
@@ -147,6 +167,7 @@ impl GameSystem {
             Self::DungeonWorld,
             Self::GURPS,
             Self::Warhammer,
+            Self::SavageWorlds,
         ]
     }
 }
@@ -188,6 +209,23 @@ impl AttributeValue {
     }
 }
 
+/// A figure worked out from a character's attributes, such as Hit Points,
+/// Sanity, or Toughness, kept as the system writes it ("+1D4", "6 (1)")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DerivedStat {
+    pub name: String,
+    pub value: String,
+}
+
+impl DerivedStat {
+    pub fn new(name: &str, value: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterTrait {
     pub name: String,
@@ -209,12 +247,13 @@ pub enum TraitType {
     Aspect,     // Fate
     Stunt,      // Fate
     Merit,      // WoD
-    Edge,       // Shadowrun
+    Edge,       // Shadowrun, Savage Worlds
     Cyberware,  // Cyberpunk
     Talent,     // Warhammer
     Move,       // Dungeon World
     Advantage,  // GURPS
     Disadvantage, // GURPS
+    Hindrance,  // Savage Worlds
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -366,6 +405,7 @@ impl SystemInfo {
             GameSystem::DungeonWorld => "Fiction-first fantasy adventure".to_string(),
             GameSystem::GURPS => "Generic Universal RolePlaying System".to_string(),
             GameSystem::Warhammer => "Grimdark fantasy in the Old World".to_string(),
+            GameSystem::SavageWorlds => "Fast, furious pulp action in any setting".to_string(),
             GameSystem::Custom(name) => format!("Custom system: {}", name),
         }
     }
@@ -416,6 +456,7 @@ impl GeneratorRegistry {
         registry.register(Box::new(systems::dungeon_world::DungeonWorldGenerator::new()));
         registry.register(Box::new(systems::gurps::GURPSGenerator::new()));
         registry.register(Box::new(systems::warhammer::WarhammerGenerator::new()));
+        registry.register(Box::new(systems::savage_worlds::SavageWorldsGenerator::new()));
 
         registry
     }
@@ -565,13 +606,14 @@ mod tests {
         assert_eq!(GameSystem::from_str("fate"), GameSystem::FateCore);
         assert_eq!(GameSystem::from_str("gurps"), GameSystem::GURPS);
         assert_eq!(GameSystem::from_str("warhammer"), GameSystem::Warhammer);
+        assert_eq!(GameSystem::from_str("SWADE"), GameSystem::SavageWorlds);
     }
 
     #[test]
     fn test_registry_creation() {
         let registry = GeneratorRegistry::new();
         let systems = registry.list_systems();
-        assert!(systems.len() >= 10); // At least 10 systems registered
+        assert!(systems.len() >= 11); // At least 11 systems registered
     }
 
     #[test]
@@ -263,6 +263,13 @@ impl BackstoryPromptBuilder {
                  Embrace dark humor and the absurdity of survival in a doomed world.\n\
                  Reference appropriate careers, social standing, and the ever-present threat of Chaos.".to_string()
             }
+            GameSystem::SavageWorlds => {
+                "You are a master storyteller specializing in Savage Worlds character backstories.\n\
+                 Write fast, pulpy tales with bold heroes and vivid action, fitted to the campaign's setting.\n\
+                 Explain the character's Hindrances as real flaws with a story behind them.\n\
+                 Show where their Edges came from: training, luck, or a defining moment.\n\
+                 Keep it punchy and leave room for cliffhangers and rivalries.".to_string()
+            }
             GameSystem::Custom(name) => {
                 format!(
                     "You are a master storyteller creating a character backstory for the {} system.\n\
@@ -442,7 +449,7 @@ impl BackstoryTemplates {
                 "What they hope to achieve or escape",
                 "Their relationship with faith and Sigmar",
             ],
-            GameSystem::GURPS | GameSystem::Custom(_) => vec![
+            GameSystem::GURPS | GameSystem::SavageWorlds | GameSystem::Custom(_) => vec![
                 "Origins and early life",
                 "Education and training",
                 "Formative experiences",
@@ -555,6 +562,7 @@ mod tests {
             backstory: None,
             notes: String::new(),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         }
     }
 
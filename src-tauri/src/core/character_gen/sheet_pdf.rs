@@ -0,0 +1,575 @@
+//! Character Sheet PDF
+//!
+//! Renders a character to a printable PDF sheet. D&D 5e and Pathfinder 2e
+//! characters get a first page laid out like their game's own sheet, with
+//! ability boxes, skills, and combat numbers; other systems list their
+//! attributes and skills. Features, equipment, spells, and the backstory
+//! follow, running onto more pages as needed.
+//!
+//! The PDF is written directly with the standard Helvetica fonts every
+//! reader provides, so no font files or PDF libraries are needed.
+
+use super::export::{ability_modifier, ability_score, proficiency, SheetExtras};
+use super::{Character, GameSystem};
+
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 36.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+/// Average Helvetica glyph width as a fraction of the font size
+const GLYPH_WIDTH: f32 = 0.52;
+
+const ABILITIES: [&str; 6] = ["Strength", "Dexterity", "Constitution", "Intelligence", "Wisdom", "Charisma"];
+
+const DND5E_SKILLS: [(&str, &str); 18] = [
+    ("Acrobatics", "Dexterity"),
+    ("Animal Handling", "Wisdom"),
+    ("Arcana", "Intelligence"),
+    ("Athletics", "Strength"),
+    ("Deception", "Charisma"),
+    ("History", "Intelligence"),
+    ("Insight", "Wisdom"),
+    ("Intimidation", "Charisma"),
+    ("Investigation", "Intelligence"),
+    ("Medicine", "Wisdom"),
+    ("Nature", "Intelligence"),
+    ("Perception", "Wisdom"),
+    ("Performance", "Charisma"),
+    ("Persuasion", "Charisma"),
+    ("Religion", "Intelligence"),
+    ("Sleight of Hand", "Dexterity"),
+    ("Stealth", "Dexterity"),
+    ("Survival", "Wisdom"),
+];
+
+/// Render a character sheet, choosing the layout by game system
+pub fn render_sheet(character: &Character, extras: &SheetExtras) -> Vec<u8> {
+    let mut flow = Flow::new();
+    header(&mut flow, character);
+    match character.system {
+        GameSystem::DnD5e => dnd5e_front(&mut flow, character, extras),
+        GameSystem::Pathfinder2e => pf2e_front(&mut flow, character, extras),
+        _ => generic_front(&mut flow, character, extras),
+    }
+    details(&mut flow, character, extras);
+    write_pdf(&flow.pages)
+}
+
+// ============================================================================
+// Sheet Layouts
+// ============================================================================
+
+fn header(flow: &mut Flow, character: &Character) {
+    flow.y -= 20.0;
+    let y = flow.y;
+    flow.page().text(MARGIN, y, 20.0, true, &character.name);
+
+    let mut subtitle = vec![format!("Level {}", character.level)];
+    subtitle.extend(character.race.clone());
+    subtitle.extend(character.class.clone());
+    let mut line = subtitle.join(" ");
+    if !character.background.origin.is_empty() {
+        line.push_str(&format!(" · {}", character.background.origin));
+    }
+    line.push_str(&format!(" · {}", character.system.display_name()));
+    flow.y -= 16.0;
+    let y = flow.y;
+    let page = flow.page();
+    page.text(MARGIN, y, 10.0, false, &line);
+    page.line(MARGIN, y - 6.0, PAGE_WIDTH - MARGIN, y - 6.0);
+    flow.y -= 14.0;
+}
+
+/// Ability boxes down the left, saves and skills in the middle, combat
+/// numbers on the right
+fn dnd5e_front(flow: &mut Flow, character: &Character, extras: &SheetExtras) {
+    let top = flow.y;
+    let proficiency = proficiency(character, extras);
+    let page = flow.page();
+
+    for (i, ability) in ABILITIES.iter().enumerate() {
+        let y = top - (i as f32 + 1.0) * 64.0;
+        page.rect(MARGIN, y, 80.0, 58.0);
+        page.centered(MARGIN + 40.0, y + 46.0, 7.0, true, &ability.to_uppercase());
+        page.centered(MARGIN + 40.0, y + 24.0, 18.0, true, &signed(ability_modifier(character, ability)));
+        page.centered(MARGIN + 40.0, y + 8.0, 10.0, false, &ability_score(character, ability).to_string());
+    }
+
+    let x = MARGIN + 96.0;
+    let mut y = top - 14.0;
+    page.text(x, y, 10.0, true, &format!("{}  Proficiency Bonus", signed(proficiency)));
+    y -= 20.0;
+    page.text(x, y, 9.0, true, "SAVING THROWS");
+    for ability in ABILITIES {
+        y -= 11.0;
+        let modifier = ability_modifier(character, ability);
+        let save = extras.saving_throws.get(ability).copied().unwrap_or(modifier);
+        page.check_line(x, y, save > modifier, &format!("{}  {}", signed(save), ability));
+    }
+    y -= 20.0;
+    page.text(x, y, 9.0, true, "SKILLS");
+    for (skill, ability) in DND5E_SKILLS {
+        y -= 11.0;
+        let modifier = ability_modifier(character, ability);
+        let total = character.skills.get(skill).copied().unwrap_or(modifier);
+        page.check_line(x, y, total > modifier, &format!("{}  {} ({})", signed(total), skill, &ability[..3]));
+    }
+
+    let x = MARGIN + 312.0;
+    let combat = [
+        ("ARMOR CLASS", extras.armor_class.map(|ac| ac.to_string())),
+        ("HIT POINTS", extras.max_hp.map(|hp| hp.to_string())),
+        ("SPEED", extras.speed.map(|s| format!("{} ft", s))),
+    ];
+    stat_boxes(page, x, top - 58.0, &combat);
+    let passive = 10 + character
+        .skills
+        .get("Perception")
+        .copied()
+        .unwrap_or_else(|| ability_modifier(character, "Wisdom"));
+    page.text(x, top - 78.0, 10.0, false, &format!("Passive Perception {}", passive));
+    if !extras.spell_slots.is_empty() {
+        page.text(x, top - 100.0, 9.0, true, "SPELL SLOTS");
+        let slots: Vec<String> = extras
+            .spell_slots
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| **n > 0)
+            .map(|(i, n)| format!("{}: {}", ordinal(i + 1), n))
+            .collect();
+        for (i, line) in wrap(&slots.join("   "), 10.0, 228.0).iter().enumerate() {
+            page.text(x, top - 113.0 - i as f32 * 12.0, 10.0, false, line);
+        }
+    }
+
+    flow.y = top - 6.0 * 64.0 - 8.0;
+}
+
+/// Attribute modifiers across the top, combat numbers, then skills
+fn pf2e_front(flow: &mut Flow, character: &Character, extras: &SheetExtras) {
+    let top = flow.y;
+    let page = flow.page();
+    let width = (CONTENT_WIDTH - 5.0 * 6.0) / 6.0;
+    for (i, ability) in ABILITIES.iter().enumerate() {
+        let x = MARGIN + i as f32 * (width + 6.0);
+        let y = top - 56.0;
+        page.rect(x, y, width, 52.0);
+        page.centered(x + width / 2.0, y + 40.0, 7.0, true, &ability.to_uppercase());
+        page.centered(x + width / 2.0, y + 16.0, 18.0, true, &signed(ability_modifier(character, ability)));
+    }
+    let combat = [
+        ("ARMOR CLASS", extras.armor_class.map(|ac| ac.to_string())),
+        ("HIT POINTS", extras.max_hp.map(|hp| hp.to_string())),
+        ("SPEED", extras.speed.map(|s| format!("{} ft", s))),
+        ("CLASS DC", None),
+    ];
+    stat_boxes(page, MARGIN, top - 112.0, &combat);
+    flow.y = top - 120.0;
+
+    flow.heading("Skills");
+    let skills: Vec<String> = sorted(&character.skills)
+        .into_iter()
+        .map(|(name, value)| format!("{}  {}", signed(*value), name))
+        .collect();
+    flow.columns(&skills, 3, 10.0);
+}
+
+/// Attributes and skills as plain values, for systems without a sheet
+/// layout of their own
+fn generic_front(flow: &mut Flow, character: &Character, extras: &SheetExtras) {
+    flow.heading("Attributes");
+    let attributes: Vec<String> = sorted(&character.attributes)
+        .into_iter()
+        .map(|(name, value)| format!("{}: {}", name, value.total()))
+        .collect();
+    flow.columns(&attributes, 3, 10.0);
+    if let Some(hp) = extras.max_hp {
+        flow.paragraph(&format!("Hit Points: {}", hp), 10.0);
+    }
+    if !character.derived_stats.is_empty() {
+        let derived: Vec<String> = character
+            .derived_stats
+            .iter()
+            .map(|s| format!("{}: {}", s.name, s.value))
+            .collect();
+        flow.columns(&derived, 3, 10.0);
+    }
+    if !character.skills.is_empty() {
+        flow.heading("Skills");
+        let skills: Vec<String> = sorted(&character.skills)
+            .into_iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect();
+        flow.columns(&skills, 3, 10.0);
+    }
+}
+
+/// Features, equipment, spells, and story, shared by every layout
+fn details(flow: &mut Flow, character: &Character, extras: &SheetExtras) {
+    if !character.traits.is_empty() {
+        flow.heading("Features & Traits");
+        for t in &character.traits {
+            flow.labelled(&t.name, &t.description, 10.0);
+        }
+    }
+    if !character.equipment.is_empty() {
+        flow.heading("Equipment");
+        for e in &character.equipment {
+            flow.labelled(&e.name, &e.description, 10.0);
+        }
+    }
+    if !extras.cantrips.is_empty() || !extras.spells.is_empty() {
+        flow.heading("Spells");
+        if !extras.cantrips.is_empty() {
+            flow.labelled("Cantrips", &extras.cantrips.join(", "), 10.0);
+        }
+        if !extras.spells.is_empty() {
+            flow.labelled("Spells", &extras.spells.join(", "), 10.0);
+        }
+    }
+
+    let background = &character.background;
+    let story: Vec<(&str, String)> = [
+        ("Occupation", background.occupation.clone().unwrap_or_default()),
+        ("Motivation", background.motivation.clone()),
+        ("Connections", background.connections.join("; ")),
+    ]
+    .into_iter()
+    .filter(|(_, text)| !text.is_empty())
+    .collect();
+    let backstory = character.backstory.as_deref().unwrap_or(&background.history);
+    if !story.is_empty() || !backstory.is_empty() {
+        flow.heading("Background");
+        for (label, text) in &story {
+            flow.labelled(label, text, 10.0);
+        }
+        for paragraph in backstory.split("\n\n").filter(|p| !p.trim().is_empty()) {
+            flow.y -= 4.0;
+            flow.paragraph(paragraph.trim(), 10.0);
+        }
+    }
+    if !character.notes.is_empty() {
+        flow.heading("Notes");
+        flow.paragraph(&character.notes, 10.0);
+    }
+}
+
+/// A row of labelled boxes; values left out are blank to fill in by hand
+fn stat_boxes(page: &mut Page, x: f32, y: f32, stats: &[(&str, Option<String>)]) {
+    for (i, (label, value)) in stats.iter().enumerate() {
+        let x = x + i as f32 * 78.0;
+        page.rect(x, y, 72.0, 50.0);
+        page.centered(x + 36.0, y + 39.0, 7.0, true, label);
+        if let Some(value) = value {
+            page.centered(x + 36.0, y + 14.0, 16.0, true, value);
+        }
+    }
+}
+
+fn signed(n: i32) -> String {
+    format!("{:+}", n)
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+fn sorted<V>(map: &std::collections::HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+// ============================================================================
+// Page Layout
+// ============================================================================
+
+/// One page's content stream
+#[derive(Default)]
+struct Page {
+    content: Vec<u8>,
+}
+
+impl Page {
+    fn op(&mut self, op: &str) {
+        self.content.extend_from_slice(op.as_bytes());
+    }
+
+    fn text(&mut self, x: f32, y: f32, size: f32, bold: bool, text: &str) {
+        let font = if bold { "F2" } else { "F1" };
+        self.op(&format!("BT /{} {} Tf {:.1} {:.1} Td ", font, size, x, y));
+        self.content.extend(pdf_string(text));
+        self.op(" Tj ET\n");
+    }
+
+    fn centered(&mut self, center_x: f32, y: f32, size: f32, bold: bool, text: &str) {
+        self.text(center_x - text_width(text, size) / 2.0, y, size, bold, text);
+    }
+
+    /// A line of text after a small box, filled when checked
+    fn check_line(&mut self, x: f32, y: f32, checked: bool, text: &str) {
+        let paint = if checked { "f" } else { "S" };
+        self.op(&format!("{:.1} {:.1} 6 6 re {}\n", x, y, paint));
+        self.text(x + 10.0, y, 9.0, false, text);
+    }
+
+    fn rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.op(&format!("{:.1} {:.1} {:.1} {:.1} re S\n", x, y, width, height));
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        self.op(&format!("{:.1} {:.1} m {:.1} {:.1} l S\n", x1, y1, x2, y2));
+    }
+}
+
+/// Text laid out down the page, starting a new page when it fills
+struct Flow {
+    pages: Vec<Page>,
+    /// Baseline of the last line written
+    y: f32,
+}
+
+impl Flow {
+    fn new() -> Self {
+        Self { pages: vec![Page::default()], y: PAGE_HEIGHT - MARGIN }
+    }
+
+    fn page(&mut self) -> &mut Page {
+        self.pages.last_mut().expect("a flow always has a page")
+    }
+
+    /// Start a new page unless `height` more fits on this one
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.pages.push(Page::default());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn heading(&mut self, title: &str) {
+        self.reserve(40.0);
+        self.y -= 22.0;
+        let y = self.y;
+        let page = self.page();
+        page.text(MARGIN, y, 12.0, true, title);
+        page.line(MARGIN, y - 4.0, PAGE_WIDTH - MARGIN, y - 4.0);
+        self.y -= 6.0;
+    }
+
+    fn paragraph(&mut self, text: &str, size: f32) {
+        for line in wrap(text, size, CONTENT_WIDTH) {
+            self.reserve(size + 3.0);
+            self.y -= size + 3.0;
+            let y = self.y;
+            self.page().text(MARGIN, y, size, false, &line);
+        }
+    }
+
+    /// A bold label followed by text wrapping under it
+    fn labelled(&mut self, label: &str, text: &str, size: f32) {
+        let label = if text.is_empty() { label.to_string() } else { format!("{}: ", label) };
+        let indent = text_width(&label, size);
+        let mut lines = wrap(text, size, CONTENT_WIDTH - indent).into_iter();
+        self.reserve(size + 3.0);
+        self.y -= size + 3.0;
+        let y = self.y;
+        let page = self.page();
+        page.text(MARGIN, y, size, true, &label);
+        if let Some(first) = lines.next() {
+            page.text(MARGIN + indent, y, size, false, &first);
+        }
+        let rest: Vec<String> = lines.collect();
+        if !rest.is_empty() {
+            self.paragraph(&rest.join(" "), size);
+        }
+    }
+
+    /// Short entries across `count` columns, filled row by row
+    fn columns(&mut self, entries: &[String], count: usize, size: f32) {
+        let width = CONTENT_WIDTH / count as f32;
+        let max_chars = (width / (size * GLYPH_WIDTH)) as usize;
+        for row in entries.chunks(count) {
+            self.reserve(size + 3.0);
+            self.y -= size + 3.0;
+            let y = self.y;
+            for (i, entry) in row.iter().enumerate() {
+                let entry: String = entry.chars().take(max_chars).collect();
+                self.page().text(MARGIN + i as f32 * width, y, size, false, &entry);
+            }
+        }
+    }
+}
+
+fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * GLYPH_WIDTH
+}
+
+/// Word-wrap text to lines no wider than `width`, breaking words that
+/// don't fit on a line of their own
+fn wrap(text: &str, size: f32, width: f32) -> Vec<String> {
+    let max_chars = ((width / (size * GLYPH_WIDTH)) as usize).max(1);
+    let mut lines = Vec::new();
+    for source_line in text.lines() {
+        let mut line = String::new();
+        for word in source_line.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            while word.len() > max_chars {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                lines.push(word.drain(..max_chars).collect());
+            }
+            let word: String = word.into_iter().collect();
+            if word.is_empty() {
+                continue;
+            }
+            if line.is_empty() {
+                line = word;
+            } else if line.chars().count() + 1 + word.chars().count() <= max_chars {
+                line.push(' ');
+                line.push_str(&word);
+            } else {
+                lines.push(std::mem::replace(&mut line, word));
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// ============================================================================
+// PDF Writer
+// ============================================================================
+
+/// A literal string in the fonts' WinAnsi encoding, with characters it
+/// lacks replaced by '?'
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut bytes = vec![b'('];
+    for c in text.chars() {
+        let byte = match c {
+            '\\' | '(' | ')' => {
+                bytes.push(b'\\');
+                c as u8
+            }
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201C}' => 0x93,
+            '\u{201D}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{2026}' => 0x85,
+            ' '..='~' | '\u{A0}'..='\u{FF}' => c as u8,
+            _ => b'?',
+        };
+        bytes.push(byte);
+    }
+    bytes.push(b')');
+    bytes
+}
+
+/// Assemble pages into a PDF file: catalog, page tree, the two fonts, then
+/// a page and content stream per page, indexed by the cross-reference table
+fn write_pdf(pages: &[Page]) -> Vec<u8> {
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 5 + 2 * i).collect();
+    let kids: Vec<String> = page_ids.iter().map(|id| format!("{} 0 R", id)).collect();
+
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()).into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec(),
+    ];
+    for (page, id) in pages.iter().zip(&page_ids) {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                id + 1
+            )
+            .into_bytes(),
+        );
+        let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+        stream.extend_from_slice(&page.content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref = pdf.len();
+    pdf.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", objects.len() + 1, xref).as_bytes(),
+    );
+    pdf
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::character_gen::export::tests::fighter;
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn test_render_sheet_structure() {
+        let pdf = render_sheet(&fighter(), &SheetExtras { max_hp: Some(49), ..Default::default() });
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(contains(&pdf, b"/Count 1"));
+        assert!(contains(&pdf, b"(Bram <the Bold>) Tj"));
+        assert!(contains(&pdf, b"(+6  Athletics \\(Str\\)) Tj"));
+        assert!(contains(&pdf, b"(49) Tj"));
+
+        // startxref points at the cross-reference table, whose entries
+        // point at their objects
+        let text = String::from_utf8_lossy(&pdf);
+        let startxref: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(pdf[startxref..].starts_with(b"xref\n0 7\n"));
+        let first_entry = &pdf[startxref + 29..startxref + 39];
+        let offset: usize = std::str::from_utf8(first_entry).unwrap().parse().unwrap();
+        assert!(pdf[offset..].starts_with(b"1 0 obj"));
+    }
+
+    #[test]
+    fn test_long_sheets_paginate_and_text_is_encoded() {
+        let mut character = fighter();
+        character.system = GameSystem::Pathfinder2e;
+        character.backstory = Some(vec!["A long road, café to café — (again).\n\n"; 120].concat());
+        let pdf = render_sheet(&character, &SheetExtras::default());
+        assert!(!contains(&pdf, b"/Count 1 >>"));
+        assert!(contains(&pdf, b"caf\xE9 to caf\xE9 \x97 \\(again\\)."));
+
+        assert_eq!(wrap("one two three four", 10.0, 50.0), vec!["one two", "three", "four"]);
+        assert_eq!(wrap("abcdefghijkl", 10.0, 28.0), vec!["abcde", "fghij", "kl"]);
+        assert_eq!(pdf_string("ア(b)"), b"(?\\(b\\))".to_vec());
+    }
+}
@@ -1,16 +1,104 @@
 //! Call of Cthulhu Character Generator
 //!
-//! Generates investigators for Call of Cthulhu 7th Edition.
+//! Generates investigators for Call of Cthulhu 7th Edition: rolled
+//! characteristics, an occupation with its skill points and Credit Rating
+//! range, personal interest points, and the derived figures (Hit Points,
+//! Sanity, Magic Points, Move Rate, Damage Bonus, and Build).
 
 use crate::core::character_gen::{
     SystemGenerator, Character, GameSystem, GenerationOptions,
     AttributeValue, CharacterTrait, TraitType, Equipment, EquipmentCategory,
-    CharacterBackground, Result, random_1920s_name,
+    CharacterBackground, CharacterGenError, DerivedStat, Result, random_1920s_name,
 };
+use rand::seq::SliceRandom;
 use rand::Rng;
 use uuid::Uuid;
 use std::collections::HashMap;
 
+/// Highest a skill is raised to at creation
+const SKILL_CAP: i32 = 80;
+
+/// Skills no points may be spent on at creation
+const UNTRAINABLE: [&str; 2] = ["Cthulhu Mythos", "Credit Rating"];
+
+// ============================================================================
+// Occupations
+// ============================================================================
+
+/// An investigator occupation
+pub struct Occupation {
+    pub name: &'static str,
+    /// Characteristics that may each count twice alongside EDU × 2 for
+    /// occupation skill points, the best one counting; none means EDU × 4
+    pub point_characteristics: &'static [&'static str],
+    /// Lowest and highest starting Credit Rating
+    pub credit_rating: (i32, i32),
+    /// The eight occupation skills
+    pub skills: [&'static str; 8],
+}
+
+const fn occupation(
+    name: &'static str,
+    point_characteristics: &'static [&'static str],
+    credit_rating: (i32, i32),
+    skills: [&'static str; 8],
+) -> Occupation {
+    Occupation { name, point_characteristics, credit_rating, skills }
+}
+
+/// Occupations, simplified from the Keeper Rulebook's list
+pub const OCCUPATIONS: [Occupation; 20] = [
+    occupation("Antiquarian", &[], (30, 70), ["Appraise", "Art/Craft", "History", "Library Use", "Language (Other)", "Charm", "Spot Hidden", "Accounting"]),
+    occupation("Archaeologist", &[], (10, 40), ["Appraise", "Archaeology", "History", "Language (Other)", "Library Use", "Spot Hidden", "Mechanical Repair", "Navigate"]),
+    occupation("Artist", &["DEX", "POW"], (9, 50), ["Art/Craft", "History", "Natural World", "Language (Other)", "Psychology", "Spot Hidden", "Charm", "Fast Talk"]),
+    occupation("Author", &[], (9, 30), ["Art/Craft", "History", "Library Use", "Natural World", "Occult", "Language (Other)", "Psychology", "Persuade"]),
+    occupation("Clergy", &[], (9, 60), ["Accounting", "History", "Library Use", "Listen", "Language (Other)", "Psychology", "Persuade", "Charm"]),
+    occupation("Dilettante", &["APP"], (50, 99), ["Art/Craft", "Firearms (Rifle)", "Language (Other)", "Ride", "Charm", "Fast Talk", "Persuade", "Spot Hidden"]),
+    occupation("Doctor", &[], (30, 80), ["First Aid", "Medicine", "Psychology", "Science", "Language (Other)", "Persuade", "Spot Hidden", "Psychoanalysis"]),
+    occupation("Engineer", &[], (30, 60), ["Art/Craft", "Electrical Repair", "Library Use", "Mechanical Repair", "Operate Heavy Machinery", "Science", "Navigate", "Drive Auto"]),
+    occupation("Entertainer", &["APP"], (9, 70), ["Art/Craft", "Disguise", "Charm", "Fast Talk", "Listen", "Psychology", "Persuade", "Sleight of Hand"]),
+    occupation("Journalist", &[], (9, 30), ["Photography", "History", "Library Use", "Fast Talk", "Psychology", "Persuade", "Spot Hidden", "Listen"]),
+    occupation("Lawyer", &[], (30, 80), ["Accounting", "Law", "Library Use", "Charm", "Persuade", "Psychology", "Intimidate", "Fast Talk"]),
+    occupation("Librarian", &[], (9, 35), ["Accounting", "Library Use", "Language (Other)", "History", "Occult", "Psychology", "Spot Hidden", "Persuade"]),
+    occupation("Military Officer", &["DEX", "STR"], (20, 70), ["Accounting", "Firearms (Handgun)", "Navigate", "Persuade", "Intimidate", "Psychology", "Survival", "First Aid"]),
+    occupation("Nurse", &[], (9, 30), ["First Aid", "Listen", "Medicine", "Psychology", "Science", "Spot Hidden", "Charm", "Persuade"]),
+    occupation("Parapsychologist", &[], (9, 30), ["Anthropology", "Art/Craft", "History", "Library Use", "Occult", "Language (Other)", "Psychology", "Photography"]),
+    occupation("Pilot", &["DEX"], (20, 70), ["Electrical Repair", "Mechanical Repair", "Navigate", "Operate Heavy Machinery", "Pilot", "Science", "Spot Hidden", "Listen"]),
+    occupation("Police Detective", &["DEX", "STR"], (20, 50), ["Disguise", "Firearms (Handgun)", "Law", "Listen", "Psychology", "Spot Hidden", "Persuade", "Intimidate"]),
+    occupation("Private Investigator", &["DEX", "STR"], (9, 30), ["Photography", "Disguise", "Law", "Library Use", "Psychology", "Spot Hidden", "Fast Talk", "Locksmith"]),
+    occupation("Professor", &[], (20, 70), ["Library Use", "Language (Other)", "Psychology", "Science", "History", "Anthropology", "Persuade", "Archaeology"]),
+    occupation("Scientist", &[], (9, 50), ["Science", "Library Use", "Language (Other)", "Spot Hidden", "Electrical Repair", "Mechanical Repair", "Persuade", "Anthropology"]),
+];
+
+/// An occupation by name, ignoring case
+pub fn find_occupation(name: &str) -> Option<&'static Occupation> {
+    OCCUPATIONS.iter().find(|o| o.name.eq_ignore_ascii_case(name.trim()))
+}
+
+impl Occupation {
+    /// Occupation skill points for these characteristics
+    pub fn skill_points(&self, attrs: &HashMap<String, AttributeValue>) -> i32 {
+        let value = |name: &str| attrs.get(name).map(|a| a.base).unwrap_or(50);
+        match self.point_characteristics.iter().map(|c| value(c)).max() {
+            Some(best) => value("EDU") * 2 + best * 2,
+            None => value("EDU") * 4,
+        }
+    }
+
+    /// The skill point formula as the rulebook writes it
+    pub fn formula(&self) -> String {
+        match self.point_characteristics {
+            [] => "EDU × 4".to_string(),
+            [one] => format!("EDU × 2 + {} × 2", one),
+            many => format!("EDU × 2 + ({}) × 2", many.join(" or ")),
+        }
+    }
+}
+
+// ============================================================================
+// Generator
+// ============================================================================
+
 pub struct CallOfCthulhuGenerator;
 
 impl CallOfCthulhuGenerator {
@@ -40,7 +128,9 @@ impl CallOfCthulhuGenerator {
         attrs
     }
 
-    fn get_skills() -> HashMap<String, i32> {
+    /// Every skill at its base value. Dodge (half DEX) and Language (Own)
+    /// (EDU) depend on the investigator and start at 0 here.
+    pub fn base_skills() -> HashMap<String, i32> {
         let skills = [
             ("Accounting", 5), ("Anthropology", 1), ("Appraise", 5),
             ("Archaeology", 1), ("Art/Craft", 5), ("Charm", 15),
@@ -49,7 +139,8 @@ impl CallOfCthulhuGenerator {
             ("Electrical Repair", 10), ("Fast Talk", 5), ("Fighting (Brawl)", 25),
             ("Firearms (Handgun)", 20), ("Firearms (Rifle)", 25),
             ("First Aid", 30), ("History", 5), ("Intimidate", 15),
-            ("Jump", 20), ("Law", 5), ("Library Use", 20),
+            ("Jump", 20), ("Language (Other)", 1), ("Language (Own)", 0),
+            ("Law", 5), ("Library Use", 20),
             ("Listen", 20), ("Locksmith", 1), ("Mechanical Repair", 10),
             ("Medicine", 1), ("Natural World", 10), ("Navigate", 10),
             ("Occult", 5), ("Operate Heavy Machinery", 1), ("Persuade", 10),
@@ -63,15 +154,95 @@ impl CallOfCthulhuGenerator {
         skills.iter().map(|(k, v)| (k.to_string(), *v)).collect()
     }
 
+    /// Skills with occupation and personal interest points spent
+    fn allocate_skills(
+        attrs: &HashMap<String, AttributeValue>,
+        occupation: Option<&Occupation>,
+        rng: &mut impl Rng,
+    ) -> HashMap<String, i32> {
+        let value = |name: &str| attrs.get(name).map(|a| a.base).unwrap_or(50);
+        let mut skills = Self::base_skills();
+        skills.insert("Dodge".to_string(), value("DEX") / 2);
+        skills.insert("Language (Own)".to_string(), value("EDU"));
+
+        // Without a known occupation, points go to eight skills picked at random
+        let (occupation_skills, mut points, credit_rating): (Vec<String>, i32, (i32, i32)) = match occupation {
+            Some(o) => (o.skills.iter().map(|s| s.to_string()).collect(), o.skill_points(attrs), o.credit_rating),
+            None => (Self::pick_trainable(&skills, 8, rng), value("EDU") * 4, (9, 30)),
+        };
+
+        // Credit Rating is bought with occupation points first
+        let credit = rng.gen_range(credit_rating.0..=credit_rating.1).min(points);
+        skills.insert("Credit Rating".to_string(), credit);
+        points -= credit;
+        Self::spend(&mut skills, &occupation_skills, points, rng);
+
+        // Personal interests go to a handful of any other skills
+        let interests = Self::pick_trainable(&skills, 4, rng);
+        Self::spend(&mut skills, &interests, value("INT") * 2, rng);
+        skills
+    }
+
+    /// Skills points can be spent on, `count` of them at random
+    fn pick_trainable(skills: &HashMap<String, i32>, count: usize, rng: &mut impl Rng) -> Vec<String> {
+        let mut names: Vec<&String> = skills.keys().filter(|s| !UNTRAINABLE.contains(&s.as_str())).collect();
+        names.sort_unstable();
+        names.choose_multiple(rng, count).map(|s| s.to_string()).collect()
+    }
+
+    /// Spread points over skills in steps of 5, none past the cap
+    fn spend(skills: &mut HashMap<String, i32>, names: &[String], mut points: i32, rng: &mut impl Rng) {
+        while points > 0 {
+            let open: Vec<&String> = names
+                .iter()
+                .filter(|n| skills.get(*n).is_some_and(|v| *v < SKILL_CAP))
+                .collect();
+            let Some(name) = open.choose(rng) else {
+                break;
+            };
+            let value = skills.get_mut(*name).expect("open skills exist");
+            let step = rng.gen_range(1..=4) * 5;
+            let raise = step.min(points).min(SKILL_CAP - *value);
+            *value += raise;
+            points -= raise;
+        }
+    }
+
+    /// Hit Points, Sanity, Magic Points, Move Rate, Damage Bonus, and Build
+    fn derived_stats(attrs: &HashMap<String, AttributeValue>, skills: &HashMap<String, i32>) -> Vec<DerivedStat> {
+        let value = |name: &str| attrs.get(name).map(|a| a.base).unwrap_or(50);
+        let (str_, dex, siz) = (value("STR"), value("DEX"), value("SIZ"));
+        let mythos = skills.get("Cthulhu Mythos").copied().unwrap_or(0);
+
+        let move_rate = if dex < siz && str_ < siz {
+            7
+        } else if dex > siz && str_ > siz {
+            9
+        } else {
+            8
+        };
+        let (damage_bonus, build) = match str_ + siz {
+            ..=64 => ("-2", -2),
+            65..=84 => ("-1", -1),
+            85..=124 => ("None", 0),
+            125..=164 => ("+1D4", 1),
+            165..=204 => ("+1D6", 2),
+            _ => ("+2D6", 3),
+        };
+
+        vec![
+            DerivedStat::new("HP", (value("CON") + siz) / 10),
+            DerivedStat::new("Sanity", value("POW")),
+            DerivedStat::new("Max Sanity", 99 - mythos),
+            DerivedStat::new("Magic Points", value("POW") / 5),
+            DerivedStat::new("Move Rate", move_rate),
+            DerivedStat::new("Damage Bonus", damage_bonus),
+            DerivedStat::new("Build", build),
+        ]
+    }
+
     fn random_occupation(rng: &mut impl Rng) -> String {
-        let occupations = [
-            "Antiquarian", "Author", "Dilettante", "Doctor",
-            "Journalist", "Librarian", "Police Detective", "Professor",
-            "Private Investigator", "Parapsychologist", "Nurse",
-            "Clergy", "Archaeologist", "Artist", "Lawyer",
-            "Military Officer", "Pilot", "Engineer", "Scientist",
-        ];
-        occupations[rng.gen_range(0..occupations.len())].to_string()
+        OCCUPATIONS[rng.gen_range(0..OCCUPATIONS.len())].name.to_string()
     }
 }
 
@@ -93,10 +264,13 @@ impl SystemGenerator for CallOfCthulhuGenerator {
             .unwrap_or_else(|| random_1920s_name(&mut rng));
 
         let attributes = Self::roll_characteristics(&mut rng);
-        let skills = Self::get_skills();
 
         let occupation = options.class.clone()
             .unwrap_or_else(|| Self::random_occupation(&mut rng));
+        let known = find_occupation(&occupation);
+        let occupation = known.map_or(occupation, |o| o.name.to_string());
+        let skills = Self::allocate_skills(&attributes, known, &mut rng);
+        let derived_stats = Self::derived_stats(&attributes, &skills);
 
         let traits = vec![
             CharacterTrait {
@@ -108,8 +282,20 @@ impl SystemGenerator for CallOfCthulhuGenerator {
             CharacterTrait {
                 name: occupation.clone(),
                 trait_type: TraitType::Class,
-                description: format!("Professional {} with relevant skills", occupation),
-                mechanical_effect: Some("Occupation skill points based on EDU".to_string()),
+                description: match known {
+                    Some(o) => format!("Professional {}; occupation skills: {}", occupation, o.skills.join(", ")),
+                    None => format!("Professional {} with relevant skills", occupation),
+                },
+                mechanical_effect: Some(match known {
+                    Some(o) => format!(
+                        "Occupation skill points: {} ({}); Credit Rating {}–{}",
+                        o.formula(),
+                        o.skill_points(&attributes),
+                        o.credit_rating.0,
+                        o.credit_rating.1
+                    ),
+                    None => "Occupation skill points based on EDU".to_string(),
+                }),
             },
         ];
 
@@ -119,12 +305,6 @@ impl SystemGenerator for CallOfCthulhuGenerator {
             vec![]
         };
 
-        // Calculate derived stats
-        let hp = (attributes.get("CON").map(|a| a.base).unwrap_or(50)
-            + attributes.get("SIZ").map(|a| a.base).unwrap_or(50)) / 10;
-        let san = attributes.get("POW").map(|a| a.base).unwrap_or(50);
-        let mp = attributes.get("POW").map(|a| a.base).unwrap_or(50) / 5;
-
         let background = CharacterBackground {
             origin: "United States".to_string(),
             occupation: Some(occupation.clone()),
@@ -134,6 +314,13 @@ impl SystemGenerator for CallOfCthulhuGenerator {
             history: String::new(),
         };
 
+        let notes = derived_stats
+            .iter()
+            .filter(|s| s.name != "Max Sanity")
+            .map(|s| format!("{}: {}", s.name, s.value))
+            .collect::<Vec<_>>()
+            .join("\n");
+
         Ok(Character {
             id: Uuid::new_v4().to_string(),
             name,
@@ -148,8 +335,9 @@ impl SystemGenerator for CallOfCthulhuGenerator {
             equipment,
             background,
             backstory: None,
-            notes: format!("HP: {}\nSanity: {}\nMagic Points: {}", hp, san, mp),
+            notes,
             portrait_prompt: None,
+            derived_stats,
         })
     }
 
@@ -159,28 +347,7 @@ impl SystemGenerator for CallOfCthulhuGenerator {
     }
 
     fn available_classes(&self) -> Vec<String> {
-        vec![
-            "Antiquarian".to_string(),
-            "Archaeologist".to_string(),
-            "Artist".to_string(),
-            "Author".to_string(),
-            "Clergy".to_string(),
-            "Dilettante".to_string(),
-            "Doctor".to_string(),
-            "Engineer".to_string(),
-            "Entertainer".to_string(),
-            "Journalist".to_string(),
-            "Lawyer".to_string(),
-            "Librarian".to_string(),
-            "Military Officer".to_string(),
-            "Nurse".to_string(),
-            "Parapsychologist".to_string(),
-            "Pilot".to_string(),
-            "Police Detective".to_string(),
-            "Private Investigator".to_string(),
-            "Professor".to_string(),
-            "Scientist".to_string(),
-        ]
+        OCCUPATIONS.iter().map(|o| o.name.to_string()).collect()
     }
 
     fn available_backgrounds(&self) -> Vec<String> {
@@ -287,4 +454,23 @@ impl SystemGenerator for CallOfCthulhuGenerator {
 
         equipment
     }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(class) = &options.class {
+            if find_occupation(class).is_none() {
+                return Err(CharacterGenError::InvalidOption(format!(
+                    "Unknown Call of Cthulhu occupation: {}",
+                    class
+                )));
+            }
+        }
+        if let Some(race) = &options.race {
+            if !race.eq_ignore_ascii_case("human") {
+                return Err(CharacterGenError::InvalidOption(
+                    "Call of Cthulhu investigators are human".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
 }
@@ -142,6 +142,7 @@ impl SystemGenerator for CyberpunkGenerator {
             backstory: None,
             notes: "Humanity: 40\nEurodollars: 2550".to_string(),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -75,7 +75,7 @@ impl DnD5eGenerator {
         traits
     }
 
-    fn get_racial_traits(race: &str) -> Vec<CharacterTrait> {
+    pub(crate) fn get_racial_traits(race: &str) -> Vec<CharacterTrait> {
         match race.to_lowercase().as_str() {
             "human" => vec![
                 CharacterTrait {
@@ -244,7 +244,7 @@ impl DnD5eGenerator {
         }
     }
 
-    fn get_class_traits(class: &str, level: u32) -> Vec<CharacterTrait> {
+    pub(crate) fn get_class_traits(class: &str, level: u32) -> Vec<CharacterTrait> {
         let mut traits = vec![];
 
         match class.to_lowercase().as_str() {
@@ -542,6 +542,7 @@ impl SystemGenerator for DnD5eGenerator {
             backstory: None,
             notes: String::new(),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -304,6 +304,7 @@ impl SystemGenerator for DungeonWorldGenerator {
             backstory: None,
             notes: format!("HP: {}\nArmor: 0\nLoad: 9\nXP: 0/{}", hp, level + 7),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
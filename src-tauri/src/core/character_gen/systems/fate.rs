@@ -207,6 +207,7 @@ impl SystemGenerator for FateCoreGenerator {
             backstory: None,
             notes: "Fate Points: 3\nRefresh: 3\nStress: [1][2][3]".to_string(),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
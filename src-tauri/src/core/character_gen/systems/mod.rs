@@ -12,6 +12,7 @@ pub mod wod;
 pub mod dungeon_world;
 pub mod gurps;
 pub mod warhammer;
+pub mod savage_worlds;
 
 // Re-exports for convenience
 pub use dnd5e::DnD5eGenerator;
@@ -24,3 +25,4 @@ pub use wod::WorldOfDarknessGenerator;
 pub use dungeon_world::DungeonWorldGenerator;
 pub use gurps::GURPSGenerator;
 pub use warhammer::WarhammerGenerator;
+pub use savage_worlds::SavageWorldsGenerator;
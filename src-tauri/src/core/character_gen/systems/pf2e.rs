@@ -420,6 +420,7 @@ impl SystemGenerator for Pathfinder2eGenerator {
             backstory: None,
             notes: String::new(),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -0,0 +1,531 @@
+//! Savage Worlds Character Generator
+//!
+//! Generates Novice characters for Savage Worlds Adventure Edition:
+//! attributes and skills as die types, an ancestry, Hindrances and the
+//! Edges and raises they pay for, and the derived Pace, Parry, and
+//! Toughness. Archetypes stand in for classes and decide where points go.
+
+use crate::core::character_gen::{
+    SystemGenerator, Character, GameSystem, GenerationOptions,
+    AttributeValue, CharacterTrait, TraitType, Equipment, EquipmentCategory,
+    CharacterBackground, CharacterGenError, DerivedStat, Result, random_fantasy_name,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use uuid::Uuid;
+use std::collections::HashMap;
+
+/// Attribute points at creation, each raising one attribute a die type
+const ATTRIBUTE_POINTS: i32 = 5;
+
+/// Skill points at creation
+const SKILL_POINTS: i32 = 12;
+
+/// Every character starts with these skills at d4
+const CORE_SKILLS: [&str; 5] = ["Athletics", "Common Knowledge", "Notice", "Persuasion", "Stealth"];
+
+/// Skills and the attribute each is linked to
+const SKILLS: [(&str, &str); 28] = [
+    ("Athletics", "Agility"), ("Battle", "Smarts"), ("Boating", "Agility"),
+    ("Common Knowledge", "Smarts"), ("Driving", "Agility"), ("Electronics", "Smarts"),
+    ("Faith", "Spirit"), ("Fighting", "Agility"), ("Gambling", "Smarts"),
+    ("Hacking", "Smarts"), ("Healing", "Smarts"), ("Intimidation", "Spirit"),
+    ("Language", "Smarts"), ("Notice", "Smarts"), ("Occult", "Smarts"),
+    ("Performance", "Spirit"), ("Persuasion", "Spirit"), ("Piloting", "Agility"),
+    ("Repair", "Smarts"), ("Research", "Smarts"), ("Riding", "Agility"),
+    ("Science", "Smarts"), ("Shooting", "Agility"), ("Spellcasting", "Smarts"),
+    ("Stealth", "Agility"), ("Survival", "Smarts"), ("Taunt", "Smarts"),
+    ("Thievery", "Agility"),
+];
+
+fn linked_attribute(skill: &str) -> &'static str {
+    SKILLS.iter().find(|(s, _)| *s == skill).map_or("Smarts", |(_, a)| a)
+}
+
+/// A die type as written, like "d8"
+fn die(sides: i32) -> String {
+    format!("d{}", sides)
+}
+
+// ============================================================================
+// Archetypes
+// ============================================================================
+
+/// A character concept that decides where points go
+struct Archetype {
+    name: &'static str,
+    /// Attributes in order of importance
+    attributes: [&'static str; 3],
+    /// Skills in order of importance
+    skills: &'static [&'static str],
+    /// Edges the archetype reaches for first
+    edges: &'static [&'static str],
+}
+
+const ARCHETYPES: [Archetype; 8] = [
+    Archetype { name: "Soldier", attributes: ["Agility", "Vigor", "Strength"], skills: &["Fighting", "Shooting", "Athletics", "Notice", "Battle", "Intimidation"], edges: &["Brawny", "Trademark Weapon", "Nerves of Steel"] },
+    Archetype { name: "Brawler", attributes: ["Strength", "Vigor", "Agility"], skills: &["Fighting", "Athletics", "Intimidation", "Notice", "Taunt"], edges: &["Brawler", "Brawny", "Nerves of Steel"] },
+    Archetype { name: "Investigator", attributes: ["Smarts", "Spirit", "Agility"], skills: &["Research", "Notice", "Persuasion", "Shooting", "Occult", "Stealth"], edges: &["Investigator", "Alertness", "Linguist"] },
+    Archetype { name: "Scoundrel", attributes: ["Agility", "Smarts", "Spirit"], skills: &["Thievery", "Stealth", "Fighting", "Notice", "Gambling", "Taunt"], edges: &["Thief", "Quick", "Luck"] },
+    Archetype { name: "Mage", attributes: ["Smarts", "Spirit", "Vigor"], skills: &["Spellcasting", "Occult", "Notice", "Research", "Language"], edges: &["Arcane Background (Magic)", "Linguist", "Alertness"] },
+    Archetype { name: "Priest", attributes: ["Spirit", "Smarts", "Vigor"], skills: &["Faith", "Healing", "Persuasion", "Notice", "Fighting"], edges: &["Arcane Background (Miracles)", "Healer", "Brave"] },
+    Archetype { name: "Face", attributes: ["Spirit", "Smarts", "Agility"], skills: &["Persuasion", "Performance", "Notice", "Taunt", "Gambling", "Shooting"], edges: &["Charismatic", "Attractive", "Rich"] },
+    Archetype { name: "Explorer", attributes: ["Agility", "Smarts", "Vigor"], skills: &["Survival", "Notice", "Shooting", "Athletics", "Riding", "Fighting"], edges: &["Woodsman", "Fleet-Footed", "Alertness"] },
+];
+
+fn find_archetype(name: &str) -> Option<&'static Archetype> {
+    ARCHETYPES.iter().find(|a| a.name.eq_ignore_ascii_case(name.trim()))
+}
+
+// ============================================================================
+// Ancestries
+// ============================================================================
+
+/// A playable ancestry and what it changes
+struct Ancestry {
+    name: &'static str,
+    /// Attributes that start a die type higher
+    attribute_bonus: Option<&'static str>,
+    pace: i32,
+    size: i32,
+    /// Humans pick a free Novice Edge
+    free_edge: bool,
+    /// An Edge every member has
+    granted_edge: Option<&'static str>,
+    traits: &'static [(&'static str, &'static str)],
+}
+
+const ANCESTRIES: [Ancestry; 4] = [
+    Ancestry { name: "Human", attribute_bonus: None, pace: 6, size: 0, free_edge: true, granted_edge: None, traits: &[("Adaptable", "Begins play with a free Novice Edge")] },
+    Ancestry { name: "Dwarf", attribute_bonus: Some("Vigor"), pace: 5, size: 0, free_edge: false, granted_edge: None, traits: &[("Low Light Vision", "Ignores penalties for Dim and Dark Illumination"), ("Reduced Pace", "Pace is 5 and the running die is a d4"), ("Tough", "Vigor starts at d6")] },
+    Ancestry { name: "Elf", attribute_bonus: Some("Agility"), pace: 6, size: 0, free_edge: false, granted_edge: None, traits: &[("Agile", "Agility starts at d6"), ("All Thumbs", "−2 to use mechanical or electronic devices"), ("Low Light Vision", "Ignores penalties for Dim and Dark Illumination")] },
+    Ancestry { name: "Half-Folk", attribute_bonus: Some("Spirit"), pace: 5, size: -1, free_edge: false, granted_edge: Some("Luck"), traits: &[("Reduced Pace", "Pace is 5 and the running die is a d4"), ("Size −1", "Toughness is 1 lower"), ("Spirited", "Spirit starts at d6")] },
+];
+
+fn find_ancestry(name: &str) -> Option<&'static Ancestry> {
+    ANCESTRIES.iter().find(|a| a.name.eq_ignore_ascii_case(name.trim()))
+}
+
+// ============================================================================
+// Edges & Hindrances
+// ============================================================================
+
+/// A Novice Edge and what it takes
+struct Edge {
+    name: &'static str,
+    /// Attributes or skills and the die each needs
+    requirements: &'static [(&'static str, i32)],
+    effect: &'static str,
+}
+
+const EDGES: [Edge; 20] = [
+    Edge { name: "Alertness", requirements: &[], effect: "+2 to Notice rolls" },
+    Edge { name: "Ambidextrous", requirements: &[("Agility", 8)], effect: "Ignores the off-hand penalty" },
+    Edge { name: "Arcane Background (Magic)", requirements: &[], effect: "Casts spells with Spellcasting; 3 powers and 10 Power Points" },
+    Edge { name: "Arcane Background (Miracles)", requirements: &[], effect: "Works miracles with Faith; 3 powers and 10 Power Points" },
+    Edge { name: "Attractive", requirements: &[("Vigor", 6)], effect: "+1 to Performance and Persuasion rolls" },
+    Edge { name: "Brave", requirements: &[("Spirit", 6)], effect: "+2 to Fear checks and −2 to rolls on the Fear Table" },
+    Edge { name: "Brawler", requirements: &[("Strength", 8), ("Vigor", 8)], effect: "Toughness +1; unarmed damage is Str+d4" },
+    Edge { name: "Brawny", requirements: &[("Strength", 6), ("Vigor", 6)], effect: "Size +1, so Toughness +1; carries more" },
+    Edge { name: "Charismatic", requirements: &[("Spirit", 8)], effect: "One free reroll on Persuasion" },
+    Edge { name: "Fleet-Footed", requirements: &[("Agility", 6)], effect: "Pace +2 and the running die is a d8" },
+    Edge { name: "Healer", requirements: &[("Spirit", 8)], effect: "+2 to Healing rolls" },
+    Edge { name: "Investigator", requirements: &[("Smarts", 8), ("Research", 8)], effect: "+2 to Research and to Notice when searching" },
+    Edge { name: "Linguist", requirements: &[("Smarts", 6)], effect: "Knows several languages at d4" },
+    Edge { name: "Luck", requirements: &[], effect: "+1 Benny each session" },
+    Edge { name: "Nerves of Steel", requirements: &[("Vigor", 8)], effect: "Ignores one point of Wound penalties" },
+    Edge { name: "Quick", requirements: &[("Agility", 8)], effect: "Redraws action cards of Five or lower" },
+    Edge { name: "Rich", requirements: &[], effect: "Three times the starting funds" },
+    Edge { name: "Thief", requirements: &[("Agility", 8), ("Stealth", 6), ("Thievery", 6)], effect: "+1 to Thievery, climbing, and Stealth in urban areas" },
+    Edge { name: "Trademark Weapon", requirements: &[("Fighting", 8)], effect: "+1 to attack and Parry with one particular weapon" },
+    Edge { name: "Woodsman", requirements: &[("Spirit", 6), ("Survival", 8)], effect: "+2 to Survival and to Stealth in the wilds" },
+];
+
+/// A Hindrance: name, whether it is Major, and its effect
+const HINDRANCES: [(&str, bool, &str); 16] = [
+    ("Arrogant", true, "Must humiliate opponents and challenge the most powerful foe"),
+    ("Cautious", false, "Plans extensively and avoids rash action"),
+    ("Code of Honor", true, "Keeps their word and acts like a gentleman"),
+    ("Curious", true, "Wants to know about everything"),
+    ("Enemy", false, "Someone occasionally hunts them"),
+    ("Greedy", false, "Obsessed with wealth and possessions"),
+    ("Habit", false, "Has an irritating or socially unacceptable habit"),
+    ("Heroic", true, "Always helps those in need"),
+    ("Loyal", false, "Never leaves a friend behind"),
+    ("Outsider", false, "−2 to Persuasion with most of society"),
+    ("Overconfident", true, "Believes they can do anything"),
+    ("Poverty", false, "Half starting funds; loses half their money each week"),
+    ("Quirk", false, "Has a minor but persistent foible"),
+    ("Stubborn", false, "Wants their way and never admits a mistake"),
+    ("Vow", true, "Bound by a serious oath"),
+    ("Wanted", true, "A major crime hangs over them"),
+];
+
+fn find_edge(name: &str) -> Option<&'static Edge> {
+    EDGES.iter().find(|e| e.name.eq_ignore_ascii_case(name.trim()))
+}
+
+// ============================================================================
+// Generator
+// ============================================================================
+
+pub struct SavageWorldsGenerator;
+
+impl SavageWorldsGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Attributes from d4, the ancestry's bonus, and points spent in the
+    /// archetype's order; hindrance points buy one more raise
+    fn build_attributes(ancestry: &Ancestry, archetype: &Archetype) -> HashMap<String, i32> {
+        let mut dice: HashMap<String, i32> = ["Agility", "Smarts", "Spirit", "Strength", "Vigor"]
+            .iter()
+            .map(|a| (a.to_string(), 4))
+            .collect();
+        if let Some(bonus) = ancestry.attribute_bonus {
+            dice.insert(bonus.to_string(), 6);
+        }
+        let [primary, secondary, tertiary] = archetype.attributes;
+        let order = [primary, secondary, primary, tertiary, secondary, primary];
+        for attribute in order.iter().take(ATTRIBUTE_POINTS as usize + 1) {
+            let value = dice.get_mut(*attribute).expect("every attribute exists");
+            if *value < 12 {
+                *value += 2;
+            }
+        }
+        dice
+    }
+
+    /// Core skills at d4 and skill points spent in the archetype's order,
+    /// one point a die type up to the linked attribute and two beyond
+    fn build_skills(attributes: &HashMap<String, i32>, archetype: &Archetype) -> HashMap<String, i32> {
+        let mut skills: HashMap<String, i32> = CORE_SKILLS.iter().map(|s| (s.to_string(), 4)).collect();
+        let mut points = SKILL_POINTS;
+
+        let cost = |skills: &HashMap<String, i32>, skill: &str| -> Option<i32> {
+            match skills.get(skill) {
+                None => Some(1),
+                Some(12) => None,
+                Some(current) => Some(if current + 2 > attributes[linked_attribute(skill)] { 2 } else { 1 }),
+            }
+        };
+
+        // Round-robin raises that cost a single point, then spend any
+        // remainder on the archetype's first skill
+        loop {
+            let mut raised = false;
+            for skill in archetype.skills {
+                if points > 0 && cost(&skills, skill) == Some(1) {
+                    *skills.entry(skill.to_string()).or_insert(2) += 2;
+                    points -= 1;
+                    raised = true;
+                }
+            }
+            if !raised {
+                break;
+            }
+        }
+        for skill in archetype.skills {
+            while let Some(price) = cost(&skills, skill).filter(|p| *p <= points) {
+                *skills.entry(skill.to_string()).or_insert(2) += 2;
+                points -= price;
+            }
+        }
+        skills
+    }
+
+    /// Whether a character meets an Edge's requirements
+    fn qualifies(edge: &Edge, attributes: &HashMap<String, i32>, skills: &HashMap<String, i32>) -> bool {
+        edge.requirements.iter().all(|(trait_name, needed)| {
+            attributes.get(*trait_name).or_else(|| skills.get(*trait_name)).is_some_and(|d| d >= needed)
+        })
+    }
+
+    /// Pick Edges: the archetype's favorites first, then any that qualify
+    fn choose_edges(
+        count: usize,
+        taken: &[&'static str],
+        archetype: &Archetype,
+        attributes: &HashMap<String, i32>,
+        skills: &HashMap<String, i32>,
+        rng: &mut impl Rng,
+    ) -> Vec<&'static Edge> {
+        let mut chosen: Vec<&'static Edge> = Vec::new();
+        let open = |edge: &Edge, chosen: &[&Edge]| {
+            !taken.contains(&edge.name) && !chosen.iter().any(|c| c.name == edge.name) && Self::qualifies(edge, attributes, skills)
+        };
+        for name in archetype.edges {
+            if chosen.len() < count {
+                if let Some(edge) = find_edge(name).filter(|e| open(e, &chosen)) {
+                    chosen.push(edge);
+                }
+            }
+        }
+        while chosen.len() < count {
+            let options: Vec<&'static Edge> = EDGES
+                .iter()
+                .filter(|e| !e.name.starts_with("Arcane Background") && open(e, &chosen))
+                .collect();
+            match options.choose(rng) {
+                Some(edge) => chosen.push(edge),
+                None => break,
+            }
+        }
+        chosen
+    }
+
+    /// One Major and two Minor Hindrances, for the full four points
+    fn choose_hindrances(rng: &mut impl Rng) -> Vec<(&'static str, bool, &'static str)> {
+        let majors: Vec<_> = HINDRANCES.iter().filter(|h| h.1).collect();
+        let minors: Vec<_> = HINDRANCES.iter().filter(|h| !h.1).collect();
+        let mut chosen = vec![**majors.choose(rng).expect("majors exist")];
+        chosen.extend(minors.choose_multiple(rng, 2).map(|h| **h));
+        chosen
+    }
+
+    /// Pace, Parry, Toughness, Size, and Bennies
+    fn derived_stats(
+        ancestry: &Ancestry,
+        attributes: &HashMap<String, i32>,
+        skills: &HashMap<String, i32>,
+        edges: &[&str],
+    ) -> Vec<DerivedStat> {
+        let has = |edge: &str| edges.contains(&edge);
+        let size = ancestry.size + i32::from(has("Brawny"));
+        let pace = ancestry.pace + if has("Fleet-Footed") { 2 } else { 0 };
+        let running_die = if has("Fleet-Footed") {
+            8
+        } else if ancestry.pace < 6 {
+            4
+        } else {
+            6
+        };
+        let parry = 2 + skills.get("Fighting").map_or(0, |d| d / 2);
+        let toughness = 2 + attributes["Vigor"] / 2 + size + i32::from(has("Brawler"));
+        let bennies = 3 + i32::from(has("Luck"));
+
+        vec![
+            DerivedStat::new("Pace", pace),
+            DerivedStat::new("Running Die", die(running_die)),
+            DerivedStat::new("Parry", parry),
+            DerivedStat::new("Toughness", toughness),
+            DerivedStat::new("Size", size),
+            DerivedStat::new("Bennies", bennies),
+        ]
+    }
+}
+
+impl Default for SavageWorldsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemGenerator for SavageWorldsGenerator {
+    fn system(&self) -> GameSystem {
+        GameSystem::SavageWorlds
+    }
+
+    fn generate(&self, options: &GenerationOptions) -> Result<Character> {
+        let mut rng = rand::thread_rng();
+
+        let name = options.name.clone()
+            .unwrap_or_else(|| random_fantasy_name(&mut rng));
+        let ancestry = match options.race.as_deref() {
+            Some(race) => find_ancestry(race)
+                .ok_or_else(|| CharacterGenError::InvalidOption(format!("Unknown Savage Worlds ancestry: {}", race)))?,
+            None => ANCESTRIES.choose(&mut rng).expect("ancestries exist"),
+        };
+        let archetype = match options.class.as_deref() {
+            Some(class) => find_archetype(class)
+                .ok_or_else(|| CharacterGenError::InvalidOption(format!("Unknown Savage Worlds archetype: {}", class)))?,
+            None => ARCHETYPES.choose(&mut rng).expect("archetypes exist"),
+        };
+
+        let dice = Self::build_attributes(ancestry, archetype);
+        let skills = Self::build_skills(&dice, archetype);
+
+        // Hindrance points pay for one attribute raise (in the attributes
+        // above) and one Edge; Humans get a free Edge on top
+        let hindrances = Self::choose_hindrances(&mut rng);
+        let granted: Vec<&'static str> = ancestry.granted_edge.into_iter().collect();
+        let edge_count = 1 + usize::from(ancestry.free_edge);
+        let edges = Self::choose_edges(edge_count, &granted, archetype, &dice, &skills, &mut rng);
+        let edge_names: Vec<&str> = granted.iter().copied().chain(edges.iter().map(|e| e.name)).collect();
+        let derived_stats = Self::derived_stats(ancestry, &dice, &skills, &edge_names);
+
+        let mut traits: Vec<CharacterTrait> = ancestry
+            .traits
+            .iter()
+            .map(|(name, effect)| CharacterTrait {
+                name: name.to_string(),
+                trait_type: TraitType::Racial,
+                description: format!("{} ancestry", ancestry.name),
+                mechanical_effect: Some(effect.to_string()),
+            })
+            .collect();
+        for name in &edge_names {
+            let edge = find_edge(name).expect("chosen edges exist");
+            traits.push(CharacterTrait {
+                name: edge.name.to_string(),
+                trait_type: TraitType::Edge,
+                description: "Novice Edge".to_string(),
+                mechanical_effect: Some(edge.effect.to_string()),
+            });
+        }
+        for (name, major, effect) in &hindrances {
+            traits.push(CharacterTrait {
+                name: name.to_string(),
+                trait_type: TraitType::Hindrance,
+                description: if *major { "Major Hindrance" } else { "Minor Hindrance" }.to_string(),
+                mechanical_effect: Some(effect.to_string()),
+            });
+        }
+
+        let equipment = if options.include_equipment {
+            self.starting_equipment(Some(archetype.name))
+        } else {
+            vec![]
+        };
+
+        let background = CharacterBackground {
+            origin: ancestry.name.to_string(),
+            occupation: Some(archetype.name.to_string()),
+            motivation: "Seeking fortune and glory".to_string(),
+            connections: vec![],
+            secrets: vec![],
+            history: String::new(),
+        };
+
+        let notes = derived_stats
+            .iter()
+            .map(|s| format!("{}: {}", s.name, s.value))
+            .chain(std::iter::once("Rank: Novice".to_string()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Character {
+            id: Uuid::new_v4().to_string(),
+            name,
+            system: GameSystem::SavageWorlds,
+            concept: options.concept.clone().unwrap_or_else(|| format!("{} {}", ancestry.name, archetype.name)),
+            race: Some(ancestry.name.to_string()),
+            class: Some(archetype.name.to_string()),
+            level: 1, // Novice; Savage Worlds advances by rank
+            attributes: dice.into_iter().map(|(k, v)| (k, AttributeValue::new_raw(v))).collect(),
+            skills,
+            traits,
+            equipment,
+            background,
+            backstory: None,
+            notes,
+            portrait_prompt: None,
+            derived_stats,
+        })
+    }
+
+    fn available_races(&self) -> Vec<String> {
+        ANCESTRIES.iter().map(|a| a.name.to_string()).collect()
+    }
+
+    fn available_classes(&self) -> Vec<String> {
+        ARCHETYPES.iter().map(|a| a.name.to_string()).collect()
+    }
+
+    fn available_backgrounds(&self) -> Vec<String> {
+        vec![
+            "Adventurer".to_string(),
+            "Drifter".to_string(),
+            "Noble".to_string(),
+            "Soldier of Fortune".to_string(),
+            "Scholar".to_string(),
+            "Outlaw".to_string(),
+        ]
+    }
+
+    fn attribute_names(&self) -> Vec<String> {
+        vec![
+            "Agility".to_string(),
+            "Smarts".to_string(),
+            "Spirit".to_string(),
+            "Strength".to_string(),
+            "Vigor".to_string(),
+        ]
+    }
+
+    fn starting_equipment(&self, archetype: Option<&str>) -> Vec<Equipment> {
+        let weapon = |name: &str, damage: &str, notes: &str| Equipment {
+            name: name.to_string(),
+            category: EquipmentCategory::Weapon,
+            description: notes.to_string(),
+            stats: [("Damage".to_string(), damage.to_string())].into(),
+        };
+        let armor = |name: &str, value: &str| Equipment {
+            name: name.to_string(),
+            category: EquipmentCategory::Armor,
+            description: "Worn armor".to_string(),
+            stats: [("Armor".to_string(), value.to_string())].into(),
+        };
+        let mut equipment = vec![Equipment {
+            name: "Backpack".to_string(),
+            category: EquipmentCategory::Tool,
+            description: "Bedroll, rations, rope, and a waterskin".to_string(),
+            stats: HashMap::new(),
+        }];
+
+        match archetype.map(|s| s.to_lowercase()).as_deref() {
+            Some("soldier") => {
+                equipment.push(weapon("Long Sword", "Str+d8", "A soldier's blade"));
+                equipment.push(weapon("Crossbow", "2d6", "Range 15/30/60, AP 2"));
+                equipment.push(armor("Chain Hauberk", "+3"));
+            }
+            Some("brawler") => {
+                equipment.push(weapon("Brass Knuckles", "Str+d4", "Counts as an unarmed attack"));
+                equipment.push(armor("Leather Jacket", "+1"));
+            }
+            Some("scoundrel") => {
+                equipment.push(weapon("Dagger", "Str+d4", "Can be thrown 3/6/12"));
+                equipment.push(Equipment {
+                    name: "Lockpicks".to_string(),
+                    category: EquipmentCategory::Tool,
+                    description: "Avoids the −2 penalty for picking locks without tools".to_string(),
+                    stats: HashMap::new(),
+                });
+            }
+            Some("mage") | Some("priest") => {
+                equipment.push(weapon("Staff", "Str+d4", "Parry +1, Reach 1, two hands"));
+                equipment.push(Equipment {
+                    name: if archetype == Some("Priest") { "Holy Symbol" } else { "Spellbook" }.to_string(),
+                    category: EquipmentCategory::Magic,
+                    description: "Focus for arcane powers".to_string(),
+                    stats: HashMap::new(),
+                });
+            }
+            Some("explorer") => {
+                equipment.push(weapon("Bow", "2d6", "Range 12/24/48"));
+                equipment.push(weapon("Hand Axe", "Str+d6", "Can be thrown 3/6/12"));
+                equipment.push(armor("Leather Armor", "+2"));
+            }
+            _ => {
+                equipment.push(weapon("Short Sword", "Str+d6", "A light blade"));
+            }
+        }
+
+        equipment
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(race) = &options.race {
+            if find_ancestry(race).is_none() {
+                return Err(CharacterGenError::InvalidOption(format!("Unknown Savage Worlds ancestry: {}", race)));
+            }
+        }
+        if let Some(class) = &options.class {
+            if find_archetype(class).is_none() {
+                return Err(CharacterGenError::InvalidOption(format!("Unknown Savage Worlds archetype: {}", class)));
+            }
+        }
+        Ok(())
+    }
+}
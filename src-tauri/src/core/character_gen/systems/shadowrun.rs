@@ -230,6 +230,7 @@ impl SystemGenerator for ShadowrunGenerator {
             backstory: None,
             notes: format!("Essence: {}\nNuyen: 6000", essence),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -274,6 +274,7 @@ impl SystemGenerator for WarhammerGenerator {
             backstory: None,
             notes: format!("Wounds: {}\nCareer Rank: {}\nFate: 2\nResilience: 1", wounds, rank),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -255,6 +255,7 @@ impl SystemGenerator for WorldOfDarknessGenerator {
             backstory: None,
             notes: format!("Health: {}\nWillpower: {}\nVirtue: {}\nVice: {}", health, willpower, virtue, vice),
             portrait_prompt: None,
+            derived_stats: Vec::new(),
         })
     }
 
@@ -444,3 +444,170 @@ pub const URBAN_LOOT_ITEMS: &[&str] = &[
     "Trade goods",
     "Contraband",
 ];
+
+// ============================================================================
+// Settlement Data
+// ============================================================================
+
+/// Government tuple: (form, ruler title, description)
+pub type GovernmentData = (&'static str, &'static str, &'static str);
+
+pub const GOVERNMENT_FORMS: &[GovernmentData] = &[
+    ("Hereditary Lordship", "Lord", "A noble family has ruled here for generations and expects it to stay that way"),
+    ("Elected Council", "Speaker of the Council", "Prominent citizens sit on a council that argues over every tax and road repair"),
+    ("Merchant Oligarchy", "Guildmaster", "The wealthiest trading houses decide the laws, mostly in their own favor"),
+    ("Theocracy", "High Priest", "The temple's word is law, and its clergy double as judges"),
+    ("Military Governorship", "Marshal", "A garrison commander keeps order under martial law"),
+    ("Elder Moot", "Eldest", "The oldest heads of household meet to settle disputes by custom"),
+    ("Puppet Mayoralty", "Mayor", "An elected mayor holds the office while someone else holds the strings"),
+];
+
+/// District tuple: (name, location type, description)
+pub type DistrictData = (&'static str, &'static str, &'static str);
+
+pub const DISTRICTS: &[DistrictData] = &[
+    ("Market Square", "market", "Stalls and shopfronts crowd around a busy square"),
+    ("Temple Ward", "temple", "Shrines and chapels line quiet streets that smell of incense"),
+    ("The Warrens", "slum", "A maze of leaning tenements where the watch rarely goes"),
+    ("High Town", "manor", "Walled gardens and townhouses of the wealthy"),
+    ("Artisans' Row", "guild", "Workshops ring with hammers, looms, and haggling"),
+    ("Castle Hill", "castle", "The seat of power looks down on the rest of town"),
+    ("Harbor Ward", "coast", "Warehouses, piers, and sailors' dives along the water"),
+    ("Garrison Quarter", "stronghold", "Barracks and drill yards of the local soldiery"),
+    ("Scholars' Quarter", "tower", "Libraries, an observatory, and arguing students"),
+];
+
+/// Notable figure tuple: (title, occupation, NPC role)
+pub type FigureData = (&'static str, &'static str, &'static str);
+
+pub const SETTLEMENT_FIGURES: &[FigureData] = &[
+    ("Captain of the Watch", "guard captain", "authority"),
+    ("Guild Treasurer", "merchant", "merchant"),
+    ("Temple Elder", "priest", "mentor"),
+    ("Fence", "fence", "informant"),
+    ("Healer", "healer", "ally"),
+    ("Crime Boss", "thief", "rival"),
+    ("Master Smith", "blacksmith", "merchant"),
+    ("Town Crier", "herald", "informant"),
+    ("Retired Adventurer", "veteran", "questgiver"),
+];
+
+/// Rumor templates; `{npc}`, `{district}`, and `{settlement}` are filled in
+pub const RUMOR_TEMPLATES: &[&str] = &[
+    "{npc} has been seen meeting hooded strangers in {district} after dark",
+    "Something has been stealing livestock from the farms outside {settlement}",
+    "{npc} owes a great deal of money to the wrong people",
+    "There is an old smugglers' tunnel under {district}",
+    "The well water in {district} has tasted of copper for a week",
+    "{npc} knows where the old lord's treasure was buried",
+    "A caravan bound for {settlement} vanished on the road last month",
+    "The ruler of {settlement} is secretly ill and hiding it",
+];
+
+// ============================================================================
+// Tavern Data
+// ============================================================================
+
+/// Menu tuple: (name, price in copper pieces at a modest establishment)
+pub type MenuData = (&'static str, u32);
+
+pub const TAVERN_FOOD: &[MenuData] = &[
+    ("Bowl of pottage", 3),
+    ("Bread and cheese", 4),
+    ("Mutton stew", 10),
+    ("Roast chicken", 20),
+    ("Fried river fish", 12),
+    ("Meat pie", 8),
+    ("Honeyed oatcakes", 5),
+    ("Venison with mushrooms", 40),
+];
+
+pub const TAVERN_DRINKS: &[MenuData] = &[
+    ("Small beer", 2),
+    ("Mug of ale", 4),
+    ("Dark stout", 6),
+    ("Cider", 4),
+    ("Mead", 10),
+    ("House wine (pitcher)", 20),
+    ("Dwarven spirits", 30),
+    ("Elven wine (bottle)", 200),
+];
+
+pub const TAVERN_LODGING: &[MenuData] = &[
+    ("Space by the hearth", 5),
+    ("Bed in the common room", 20),
+    ("Private room", 80),
+];
+
+/// Patron tuple: (description, occupation, NPC role)
+pub type PatronData = (&'static str, &'static str, &'static str);
+
+pub const TAVERN_PATRONS: &[PatronData] = &[
+    ("A weathered caravan master nursing a mug by the fire", "caravan master", "questgiver"),
+    ("A nervous scholar scribbling in a ledger", "scholar", "informant"),
+    ("A loud mercenary boasting about old battles", "mercenary", "neutral"),
+    ("A hooded figure watching the door", "spy", "informant"),
+    ("A farmer drowning their worries", "farmer", "bystander"),
+    ("A traveling bard tuning a lute", "bard", "ally"),
+    ("A card sharp running a game in the corner", "gambler", "rival"),
+    ("A tired priest on pilgrimage", "priest", "mentor"),
+    ("A well-dressed merchant who pays for every round", "merchant", "merchant"),
+];
+
+/// Hook templates tied to a patron; `{npc}` and `{tavern}` are filled in
+pub const TAVERN_HOOKS: &[&str] = &[
+    "{npc} is hiring guards for a trip through bandit country",
+    "{npc} lost a signet ring in a card game and wants it back quietly",
+    "{npc} swears the cellar of {tavern} has a door that wasn't there last week",
+    "{npc} needs someone to deliver a sealed letter, no questions asked",
+    "{npc} has a map to a ruin and no one brave enough to follow it",
+    "{npc} is being hunted and begs for help before the night is out",
+];
+
+// ============================================================================
+// Dungeon Data
+// ============================================================================
+
+/// Room tuple: (name, description)
+pub type RoomData = (&'static str, &'static str);
+
+pub const DUNGEON_ROOMS: &[RoomData] = &[
+    ("Guard Room", "Overturned tables and rusted weapon racks"),
+    ("Collapsed Hall", "Half the ceiling lies across the floor in broken slabs"),
+    ("Flooded Cistern", "Knee-deep black water hides the floor"),
+    ("Shrine", "A defaced altar to a forgotten god"),
+    ("Barracks", "Rows of rotted bunks and footlockers"),
+    ("Storeroom", "Smashed crates and barrels, some still sealed"),
+    ("Prison Cells", "Iron cages with doors hanging open"),
+    ("Library", "Mold-eaten shelves and scattered scrolls"),
+    ("Fungus Grotto", "Glowing mushrooms carpet a natural cave"),
+    ("Crypt", "Stone sarcophagi line the walls"),
+    ("Workshop", "Benches covered in strange tools and half-built devices"),
+    ("Chasm Bridge", "A narrow bridge spans a drop into darkness"),
+    ("Kitchen", "A cold hearth and the bones of old meals"),
+    ("Trophy Hall", "Mounted heads of beasts, some of them unfamiliar"),
+];
+
+pub const DUNGEON_BOSS_ENCOUNTERS: &[EncounterData] = &[
+    (
+        "Master of the Depths",
+        "The dungeon's ruler waits with its most loyal servants",
+        "Entering the innermost chamber",
+        &["Dungeon hoard", "Ruler's regalia"],
+        false,
+    ),
+    (
+        "Awakened Guardian",
+        "An ancient construct rises to defend what it was built to protect",
+        "Touching the sealed vault",
+        &["Vault contents", "Guardian core"],
+        false,
+    ),
+    (
+        "Brood Mother",
+        "A monstrous creature guards its nest and young",
+        "Disturbing the nest",
+        &["Rare monster parts", "Swallowed valuables"],
+        false,
+    ),
+];
@@ -16,6 +16,8 @@
 mod data;
 mod types;
 
+pub mod sites;
+
 pub use types::*;
 
 use crate::core::llm::{ChatMessage, ChatRequest, LLMClient, LLMConfig, MessageRole};
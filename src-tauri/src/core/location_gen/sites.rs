@@ -0,0 +1,876 @@
+//! Site Generators
+//!
+//! Procedural settlements, taverns, and dungeons built as linked entities
+//! rather than prose. Districts, rooms, and taverns are `Location`s wired to
+//! each other through their connections, the people living there are full
+//! NPCs placed in those locations, and every result can list the
+//! relationships that tie it into the campaign's entity graph.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use super::data;
+use super::{
+    ConnectionType, Difficulty, Disposition, Encounter, Inhabitant, Location, LocationConnection,
+    LocationGenerationOptions, LocationGenerator, LocationType, LootPotential, TreasureLevel,
+};
+use crate::core::campaign::relationships::{EntityRelationship, EntityType, RelationshipType};
+use crate::core::campaign::shops::SettlementSize;
+use crate::core::npc_gen::{NPCGenerationOptions, NPCGenerator, PlotHook, PlotHookType, Urgency, NPC};
+
+// ============================================================================
+// Shared Types
+// ============================================================================
+
+/// Settings shared by all site generators
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SiteOptions {
+    /// Site name (default: generated)
+    pub name: Option<String>,
+    pub campaign_id: Option<String>,
+    /// Game system for the generated NPCs
+    pub system: Option<String>,
+    pub theme: Option<String>,
+    /// Name bank culture for the generated NPCs
+    pub name_culture: Option<String>,
+}
+
+/// A generated NPC and the location they can be found at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteNpc {
+    pub npc: NPC,
+    /// What they are at this site, e.g. "Captain of the Watch"
+    pub title: String,
+    pub location_id: String,
+}
+
+// ============================================================================
+// Settlement Types
+// ============================================================================
+
+/// Who runs a settlement and how
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Government {
+    pub form: String,
+    pub ruler_title: String,
+    pub description: String,
+    /// NPC ID of the ruler, one of the settlement's notable NPCs
+    pub ruler_id: String,
+}
+
+/// Gossip the party can pick up, pointing at the NPC or district it is about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rumor {
+    pub text: String,
+    pub is_true: bool,
+    pub about_npc_id: Option<String>,
+    pub about_location_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedSettlement {
+    pub settlement: Location,
+    pub size: SettlementSize,
+    pub government: Government,
+    pub districts: Vec<Location>,
+    /// The ruler first, then the other notable figures
+    pub notable_npcs: Vec<SiteNpc>,
+    pub rumors: Vec<Rumor>,
+}
+
+impl GeneratedSettlement {
+    /// The settlement and its districts
+    pub fn locations(&self) -> Vec<&Location> {
+        std::iter::once(&self.settlement).chain(&self.districts).collect()
+    }
+
+    /// Districts are part of the settlement, NPCs are located in their
+    /// district, and the ruler controls the settlement
+    pub fn relationships(&self, campaign_id: &str) -> Vec<EntityRelationship> {
+        let mut relationships: Vec<_> = self
+            .districts
+            .iter()
+            .map(|d| location_link(campaign_id, d, &self.settlement, RelationshipType::PartOf))
+            .collect();
+        for site_npc in &self.notable_npcs {
+            if let Some(location) = self.locations().into_iter().find(|l| l.id == site_npc.location_id) {
+                relationships.push(npc_link(campaign_id, site_npc, location, RelationshipType::LocatedAt));
+            }
+            if site_npc.npc.id == self.government.ruler_id {
+                relationships.push(
+                    npc_link(campaign_id, site_npc, &self.settlement, RelationshipType::Controls)
+                        .with_description(&self.government.form),
+                );
+            }
+        }
+        relationships
+    }
+}
+
+// ============================================================================
+// Tavern Types
+// ============================================================================
+
+/// How fancy a tavern is; scales its prices
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TavernQuality {
+    Squalid,
+    Poor,
+    #[default]
+    Modest,
+    Comfortable,
+    Wealthy,
+}
+
+impl TavernQuality {
+    /// Menu prices in percent of a modest establishment's
+    pub fn price_percent(&self) -> u32 {
+        match self {
+            Self::Squalid => 50,
+            Self::Poor => 75,
+            Self::Modest => 100,
+            Self::Comfortable => 200,
+            Self::Wealthy => 400,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MenuCategory {
+    Food,
+    Drink,
+    Lodging,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MenuItem {
+    pub name: String,
+    pub category: MenuCategory,
+    pub price_cp: u32,
+    /// Price in coins, e.g. "1 sp 2 cp"
+    pub price: String,
+}
+
+/// An adventure hook offered by one of the patrons
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TavernHook {
+    pub text: String,
+    pub npc_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedTavern {
+    pub tavern: Location,
+    pub quality: TavernQuality,
+    pub keeper: SiteNpc,
+    pub menu: Vec<MenuItem>,
+    pub patrons: Vec<SiteNpc>,
+    pub hooks: Vec<TavernHook>,
+}
+
+impl GeneratedTavern {
+    /// The keeper followed by the patrons
+    pub fn npcs(&self) -> Vec<&SiteNpc> {
+        std::iter::once(&self.keeper).chain(&self.patrons).collect()
+    }
+
+    /// The keeper owns the tavern; everyone is located there
+    pub fn relationships(&self, campaign_id: &str) -> Vec<EntityRelationship> {
+        let mut relationships = vec![npc_link(campaign_id, &self.keeper, &self.tavern, RelationshipType::Owns)];
+        relationships.extend(
+            self.npcs()
+                .into_iter()
+                .map(|p| npc_link(campaign_id, p, &self.tavern, RelationshipType::LocatedAt)),
+        );
+        relationships
+    }
+}
+
+// ============================================================================
+// Dungeon Types
+// ============================================================================
+
+/// An edge in the dungeon's room graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DungeonPassage {
+    pub from_id: String,
+    pub to_id: String,
+    pub connection_type: ConnectionType,
+    /// Secret passages are never needed to reach a room
+    pub hidden: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedDungeon {
+    pub dungeon: Location,
+    /// Rooms in key order; the first is the entrance
+    pub rooms: Vec<Location>,
+    pub passages: Vec<DungeonPassage>,
+    /// The deepest room, holding the boss encounter and the hoard
+    pub boss_room_id: String,
+}
+
+impl GeneratedDungeon {
+    /// The dungeon and its rooms
+    pub fn locations(&self) -> Vec<&Location> {
+        std::iter::once(&self.dungeon).chain(&self.rooms).collect()
+    }
+
+    /// Rooms are part of the dungeon and connected along the passages;
+    /// secret passages are unknown to the party
+    pub fn relationships(&self, campaign_id: &str) -> Vec<EntityRelationship> {
+        let mut relationships: Vec<_> = self
+            .rooms
+            .iter()
+            .map(|r| location_link(campaign_id, r, &self.dungeon, RelationshipType::PartOf))
+            .collect();
+        for passage in &self.passages {
+            let room = |id: &str| self.rooms.iter().find(|r| r.id == id);
+            if let (Some(from), Some(to)) = (room(&passage.from_id), room(&passage.to_id)) {
+                let link = location_link(campaign_id, from, to, RelationshipType::ConnectedTo)
+                    .with_description(&passage.connection_type.to_string());
+                relationships.push(if passage.hidden { link.as_secret() } else { link });
+            }
+        }
+        relationships
+    }
+}
+
+// ============================================================================
+// Site Generator
+// ============================================================================
+
+pub struct SiteGenerator {
+    locations: LocationGenerator,
+    npcs: NPCGenerator,
+}
+
+impl Default for SiteGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SiteGenerator {
+    pub fn new() -> Self {
+        Self {
+            locations: LocationGenerator::new(),
+            npcs: NPCGenerator::new(),
+        }
+    }
+
+    /// Generate the site's people with this NPC generator, e.g. one with
+    /// name banks loaded
+    pub fn with_npc_generator(mut self, npcs: NPCGenerator) -> Self {
+        self.npcs = npcs;
+        self
+    }
+
+    /// Generate a settlement with its government, districts, notable NPCs,
+    /// and rumors
+    pub fn settlement(&self, options: &SiteOptions, size: SettlementSize, rng: &mut impl Rng) -> GeneratedSettlement {
+        let (location_type, district_range, figure_count, rumor_count) = settlement_layout(size);
+        let mut settlement = self.base_location(options, location_type, true, None);
+
+        let district_count = rng.gen_range(district_range.0..=district_range.1);
+        let mut districts: Vec<Location> = data::DISTRICTS
+            .choose_multiple(rng, district_count)
+            .map(|(name, kind, description)| {
+                let mut district =
+                    site_location(name, LocationType::from_str(kind), description, options.campaign_id.clone());
+                district.atmosphere = settlement.atmosphere.clone();
+                district.tags = vec!["district".to_string(), settlement.name.clone()];
+                district
+            })
+            .collect();
+        for district in &mut districts {
+            connect(&mut settlement, district, ConnectionType::Road);
+        }
+        for i in 1..districts.len() {
+            let (before, after) = districts.split_at_mut(i);
+            connect(&mut before[i - 1], &mut after[0], ConnectionType::Path);
+        }
+
+        // The ruler sits in the seat of power if the settlement has one
+        let (form, ruler_title, description) = *data::GOVERNMENT_FORMS.choose(rng).expect("government forms");
+        let seat = districts
+            .iter()
+            .position(|d| matches!(d.location_type, LocationType::Castle | LocationType::Manor))
+            .unwrap_or(0);
+        let ruler = self.person(
+            options,
+            &format!("{} of {}", ruler_title, settlement.name),
+            &ruler_title.to_lowercase(),
+            "authority",
+            &districts[seat],
+        );
+        let government = Government {
+            form: form.to_string(),
+            ruler_title: ruler_title.to_string(),
+            description: description.to_string(),
+            ruler_id: ruler.npc.id.clone(),
+        };
+
+        let mut notable_npcs = vec![ruler];
+        for (title, occupation, role) in data::SETTLEMENT_FIGURES.choose_multiple(rng, figure_count) {
+            let district = districts.choose(rng).expect("at least one district");
+            notable_npcs.push(self.person(options, title, occupation, role, district));
+        }
+        for site_npc in &notable_npcs {
+            if let Some(district) = districts.iter_mut().find(|d| d.id == site_npc.location_id) {
+                district.inhabitants.push(inhabitant(site_npc, Disposition::Varies, vec![]));
+            }
+        }
+
+        let rumors: Vec<Rumor> = data::RUMOR_TEMPLATES
+            .choose_multiple(rng, rumor_count)
+            .map(|template| {
+                let npc = notable_npcs.choose(rng).expect("at least the ruler");
+                let district = districts.choose(rng).expect("at least one district");
+                Rumor {
+                    text: template
+                        .replace("{npc}", &npc.npc.name)
+                        .replace("{district}", &district.name)
+                        .replace("{settlement}", &settlement.name),
+                    is_true: rng.gen_bool(0.6),
+                    about_npc_id: template.contains("{npc}").then(|| npc.npc.id.clone()),
+                    about_location_id: template.contains("{district}").then(|| district.id.clone()),
+                }
+            })
+            .collect();
+
+        settlement.notes = settlement_notes(&government, &notable_npcs[0], &rumors);
+        GeneratedSettlement {
+            settlement,
+            size,
+            government,
+            districts,
+            notable_npcs,
+            rumors,
+        }
+    }
+
+    /// Generate a tavern with its keeper, menu, patrons, and adventure hooks
+    pub fn tavern(&self, options: &SiteOptions, quality: TavernQuality, rng: &mut impl Rng) -> GeneratedTavern {
+        let mut tavern = self.base_location(options, "tavern", false, None);
+
+        let mut menu = Vec::new();
+        for (items, category) in [(data::TAVERN_FOOD, MenuCategory::Food), (data::TAVERN_DRINKS, MenuCategory::Drink)] {
+            let count = rng.gen_range(3..=5);
+            menu.extend(items.choose_multiple(rng, count).map(|item| menu_item(item, category, quality)));
+        }
+        let lodging = match quality {
+            TavernQuality::Squalid | TavernQuality::Poor => &data::TAVERN_LODGING[..2],
+            _ => data::TAVERN_LODGING,
+        };
+        menu.extend(lodging.iter().map(|item| menu_item(item, MenuCategory::Lodging, quality)));
+
+        let keeper = self.person(options, "Proprietor", "innkeeper", "merchant", &tavern);
+        let patron_count = rng.gen_range(3..=5);
+        let patron_types: Vec<&data::PatronData> = data::TAVERN_PATRONS.choose_multiple(rng, patron_count).collect();
+        let mut patrons: Vec<SiteNpc> = patron_types
+            .iter()
+            .map(|(_, occupation, role)| self.person(options, &capitalize(occupation), occupation, role, &tavern))
+            .collect();
+
+        let hook_count = rng.gen_range(1..=3).min(patrons.len());
+        let mut hooks = Vec::new();
+        for (patron, template) in patrons.iter_mut().zip(data::TAVERN_HOOKS.choose_multiple(rng, hook_count)) {
+            let text = template.replace("{npc}", &patron.npc.name).replace("{tavern}", &tavern.name);
+            patron.npc.hooks.push(PlotHook {
+                description: text.clone(),
+                hook_type: PlotHookType::Quest,
+                urgency: Urgency::Medium,
+                reward_hint: None,
+            });
+            hooks.push(TavernHook {
+                text,
+                npc_id: patron.npc.id.clone(),
+            });
+        }
+
+        tavern.inhabitants.push(inhabitant(
+            &keeper,
+            Disposition::Friendly,
+            vec!["Food and drink".to_string(), "Lodging".to_string(), "Local gossip".to_string()],
+        ));
+        for (patron, (description, _, _)) in patrons.iter().zip(patron_types) {
+            let mut patron_inhabitant = inhabitant(patron, Disposition::Neutral, vec![]);
+            patron_inhabitant.description = description.to_string();
+            tavern.inhabitants.push(patron_inhabitant);
+        }
+        tavern.notes = tavern_notes(&menu, &hooks);
+
+        GeneratedTavern {
+            tavern,
+            quality,
+            keeper,
+            menu,
+            patrons,
+            hooks,
+        }
+    }
+
+    /// Generate a dungeon as a graph of rooms with encounters and treasure.
+    /// Every room can be reached from the entrance without secret passages.
+    pub fn dungeon(
+        &self,
+        options: &SiteOptions,
+        room_count: usize,
+        danger: Difficulty,
+        rng: &mut impl Rng,
+    ) -> GeneratedDungeon {
+        let room_count = room_count.clamp(3, 30);
+        let mut dungeon = self.base_location(options, "dungeon", false, Some(danger.clone()));
+
+        let mut rooms: Vec<Location> = (0..room_count)
+            .map(|i| {
+                let (name, description) = if i == 0 {
+                    ("Entrance", "Worn steps lead down from the surface")
+                } else {
+                    *data::DUNGEON_ROOMS.choose(rng).expect("dungeon rooms")
+                };
+                let mut room = site_location(
+                    &format!("Room {}: {}", i + 1, name),
+                    LocationType::Custom("Dungeon Room".to_string()),
+                    description,
+                    options.campaign_id.clone(),
+                );
+                room.atmosphere = dungeon.atmosphere.clone();
+                room.tags = vec!["room".to_string(), dungeon.name.clone()];
+                room
+            })
+            .collect();
+
+        // A tree keeps every room reachable; linking mostly to recent rooms
+        // makes it deep rather than a hub, and a few extra passages add loops
+        let mut edges = Vec::new();
+        for i in 1..room_count {
+            let j = rng.gen_range(i.saturating_sub(3)..i);
+            let connection_type = match rng.gen_range(0..100) {
+                0..=59 => ConnectionType::Door,
+                60..=74 => ConnectionType::Stairs,
+                _ => ConnectionType::Path,
+            };
+            edges.push((j, i, connection_type));
+        }
+        for _ in 0..room_count / 4 {
+            let a = rng.gen_range(0..room_count);
+            let b = rng.gen_range(0..room_count);
+            let linked = edges.iter().any(|(x, y, _)| (*x, *y) == (a, b) || (*x, *y) == (b, a));
+            if a != b && !linked {
+                let connection_type = if rng.gen_bool(0.5) { ConnectionType::Secret } else { ConnectionType::Door };
+                edges.push((a.min(b), a.max(b), connection_type));
+            }
+        }
+
+        let depths = room_depths(room_count, &edges);
+        let boss_room = (0..room_count).max_by_key(|&i| (depths[i], std::cmp::Reverse(i))).unwrap_or(0);
+
+        let mut passages = Vec::new();
+        for (a, b, connection_type) in edges {
+            let hidden = matches!(connection_type, ConnectionType::Secret);
+            let (before, after) = rooms.split_at_mut(b);
+            connect(&mut before[a], &mut after[0], connection_type.clone());
+            if hidden {
+                before[a].loot_potential.get_or_insert_with(empty_loot).hidden_caches += 1;
+            }
+            passages.push(DungeonPassage {
+                from_id: before[a].id.clone(),
+                to_id: after[0].id.clone(),
+                connection_type,
+                hidden,
+            });
+        }
+        connect(&mut dungeon, &mut rooms[0], ConnectionType::Stairs);
+
+        for (i, room) in rooms.iter_mut().enumerate() {
+            if i == boss_room {
+                let boss = data::DUNGEON_BOSS_ENCOUNTERS.choose(rng).expect("boss encounters");
+                room.encounters.push(encounter(boss, harder(&danger)));
+                let loot = room.loot_potential.get_or_insert_with(empty_loot);
+                loot.treasure_level = TreasureLevel::Hoard;
+                loot.notable_items = pick_loot(rng, 3);
+            } else if i > 0 {
+                if rng.gen_bool(0.4) {
+                    let found = data::DUNGEON_ENCOUNTERS.choose(rng).expect("dungeon encounters");
+                    room.encounters.push(encounter(found, danger.clone()));
+                }
+                if rng.gen_bool(0.3) {
+                    let count = rng.gen_range(0..=1);
+                    let loot = room.loot_potential.get_or_insert_with(empty_loot);
+                    loot.treasure_level = match depths[i] {
+                        0..=1 => TreasureLevel::Poor,
+                        2..=3 => TreasureLevel::Modest,
+                        _ => TreasureLevel::Average,
+                    };
+                    loot.notable_items = pick_loot(rng, count);
+                }
+            }
+        }
+
+        dungeon.loot_potential = Some(LootPotential {
+            treasure_level: TreasureLevel::Rich,
+            notable_items: rooms
+                .iter()
+                .filter_map(|r| r.loot_potential.as_ref())
+                .flat_map(|l| l.notable_items.clone())
+                .collect(),
+            hidden_caches: rooms.iter().filter_map(|r| r.loot_potential.as_ref()).map(|l| l.hidden_caches).sum(),
+        });
+
+        GeneratedDungeon {
+            boss_room_id: rooms[boss_room].id.clone(),
+            dungeon,
+            rooms,
+            passages,
+        }
+    }
+
+    // ========================================================================
+    // Helpers
+    // ========================================================================
+
+    /// The site itself, from the location templates, with people left to
+    /// the site generator
+    fn base_location(
+        &self,
+        options: &SiteOptions,
+        location_type: &str,
+        include_encounters: bool,
+        danger_level: Option<Difficulty>,
+    ) -> Location {
+        self.locations.generate_quick(&LocationGenerationOptions {
+            location_type: Some(location_type.to_string()),
+            name: options.name.clone(),
+            theme: options.theme.clone(),
+            campaign_id: options.campaign_id.clone(),
+            danger_level,
+            include_inhabitants: false,
+            include_secrets: true,
+            include_encounters,
+            include_loot: false,
+            ..Default::default()
+        })
+    }
+
+    fn person(&self, options: &SiteOptions, title: &str, occupation: &str, role: &str, at: &Location) -> SiteNpc {
+        let npc = self.npcs.generate_quick(&NPCGenerationOptions {
+            system: options.system.clone(),
+            role: Some(role.to_string()),
+            occupation: Some(occupation.to_string()),
+            location: Some(at.name.clone()),
+            name_culture: options.name_culture.clone(),
+            include_secrets: true,
+            ..Default::default()
+        });
+        SiteNpc {
+            npc,
+            title: title.to_string(),
+            location_id: at.id.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// (location type, district range, notable figures besides the ruler, rumors)
+fn settlement_layout(size: SettlementSize) -> (&'static str, (usize, usize), usize, usize) {
+    match size {
+        SettlementSize::Hamlet => ("village", (1, 1), 1, 2),
+        SettlementSize::Village => ("village", (1, 2), 2, 3),
+        SettlementSize::Town => ("town", (2, 4), 3, 4),
+        SettlementSize::City => ("city", (4, 6), 5, 5),
+        SettlementSize::Metropolis => ("city", (6, 9), 7, 6),
+    }
+}
+
+/// A bare location for a generated district or room
+fn site_location(name: &str, location_type: LocationType, description: &str, campaign_id: Option<String>) -> Location {
+    let now = chrono::Utc::now();
+    Location {
+        id: uuid::Uuid::new_v4().to_string(),
+        campaign_id,
+        name: name.to_string(),
+        location_type,
+        description: description.to_string(),
+        atmosphere: Default::default(),
+        notable_features: vec![],
+        inhabitants: vec![],
+        secrets: vec![],
+        encounters: vec![],
+        connected_locations: vec![],
+        loot_potential: None,
+        map_reference: None,
+        tags: vec![],
+        notes: String::new(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// Connect two locations both ways
+fn connect(a: &mut Location, b: &mut Location, connection_type: ConnectionType) {
+    a.connected_locations.push(connection_to(b, connection_type.clone()));
+    b.connected_locations.push(connection_to(a, connection_type));
+}
+
+fn connection_to(target: &Location, connection_type: ConnectionType) -> LocationConnection {
+    LocationConnection {
+        target_id: Some(target.id.clone()),
+        target_name: target.name.clone(),
+        connection_type,
+        description: None,
+        travel_time: None,
+        hazards: vec![],
+    }
+}
+
+fn inhabitant(site_npc: &SiteNpc, disposition: Disposition, services: Vec<String>) -> Inhabitant {
+    Inhabitant {
+        name: site_npc.npc.name.clone(),
+        role: site_npc.title.clone(),
+        description: site_npc.npc.appearance.demeanor.clone(),
+        disposition,
+        secrets: site_npc.npc.secrets.clone(),
+        services,
+    }
+}
+
+fn location_link(campaign_id: &str, from: &Location, to: &Location, kind: RelationshipType) -> EntityRelationship {
+    EntityRelationship::new(
+        campaign_id,
+        &from.id,
+        EntityType::Location,
+        &from.name,
+        &to.id,
+        EntityType::Location,
+        &to.name,
+        kind,
+    )
+}
+
+fn npc_link(campaign_id: &str, from: &SiteNpc, to: &Location, kind: RelationshipType) -> EntityRelationship {
+    EntityRelationship::new(
+        campaign_id,
+        &from.npc.id,
+        EntityType::NPC,
+        &from.npc.name,
+        &to.id,
+        EntityType::Location,
+        &to.name,
+        kind,
+    )
+}
+
+fn settlement_notes(government: &Government, ruler: &SiteNpc, rumors: &[Rumor]) -> String {
+    let mut notes = format!(
+        "Government: {}, led by {} {}. {}.\n\nRumors:",
+        government.form, government.ruler_title, ruler.npc.name, government.description
+    );
+    for rumor in rumors {
+        notes.push_str(&format!("\n- {} ({})", rumor.text, if rumor.is_true { "true" } else { "false" }));
+    }
+    notes
+}
+
+fn menu_item(item: &data::MenuData, category: MenuCategory, quality: TavernQuality) -> MenuItem {
+    let price_cp = (item.1 * quality.price_percent() / 100).max(1);
+    MenuItem {
+        name: item.0.to_string(),
+        category,
+        price_cp,
+        price: format_coins(price_cp),
+    }
+}
+
+/// Copper pieces as gold, silver, and copper, e.g. "1 gp 2 sp"
+fn format_coins(cp: u32) -> String {
+    let parts: Vec<String> = [(cp / 100, "gp"), (cp / 10 % 10, "sp"), (cp % 10, "cp")]
+        .iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, coin)| format!("{} {}", amount, coin))
+        .collect();
+    if parts.is_empty() {
+        "free".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn tavern_notes(menu: &[MenuItem], hooks: &[TavernHook]) -> String {
+    let mut notes = "Menu:".to_string();
+    for item in menu {
+        notes.push_str(&format!("\n- {}: {}", item.name, item.price));
+    }
+    notes.push_str("\n\nHooks:");
+    for hook in hooks {
+        notes.push_str(&format!("\n- {}", hook.text));
+    }
+    notes
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Steps from the entrance to each room, ignoring secret passages
+fn room_depths(room_count: usize, edges: &[(usize, usize, ConnectionType)]) -> Vec<usize> {
+    let mut depths = vec![usize::MAX; room_count];
+    let mut queue = VecDeque::from([0]);
+    depths[0] = 0;
+    while let Some(room) = queue.pop_front() {
+        for (a, b, connection_type) in edges {
+            if matches!(connection_type, ConnectionType::Secret) {
+                continue;
+            }
+            let next = if *a == room { *b } else if *b == room { *a } else { continue };
+            if depths[next] == usize::MAX {
+                depths[next] = depths[room] + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    depths
+}
+
+fn encounter((name, description, trigger, rewards, optional): &data::EncounterData, difficulty: Difficulty) -> Encounter {
+    Encounter {
+        name: name.to_string(),
+        description: description.to_string(),
+        trigger: trigger.to_string(),
+        difficulty,
+        rewards: rewards.iter().map(|s| s.to_string()).collect(),
+        optional: *optional,
+    }
+}
+
+fn harder(danger: &Difficulty) -> Difficulty {
+    match danger {
+        Difficulty::Easy => Difficulty::Medium,
+        Difficulty::Medium => Difficulty::Hard,
+        Difficulty::Hard => Difficulty::VeryHard,
+        Difficulty::VeryHard | Difficulty::NearlyImpossible => Difficulty::NearlyImpossible,
+    }
+}
+
+fn empty_loot() -> LootPotential {
+    LootPotential {
+        treasure_level: TreasureLevel::None,
+        notable_items: vec![],
+        hidden_caches: 0,
+    }
+}
+
+fn pick_loot(rng: &mut impl Rng, count: usize) -> Vec<String> {
+    data::DUNGEON_LOOT_ITEMS.choose_multiple(rng, count).map(|s| s.to_string()).collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn options() -> SiteOptions {
+        SiteOptions {
+            campaign_id: Some("camp-1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settlement_links_districts_and_people() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let site = SiteGenerator::new().settlement(&options(), SettlementSize::City, &mut rng);
+
+        assert_eq!(site.settlement.location_type, LocationType::City);
+        assert!((4..=6).contains(&site.districts.len()));
+        assert_eq!(site.notable_npcs[0].npc.id, site.government.ruler_id);
+        assert_eq!(site.rumors.len(), 5);
+        for district in &site.districts {
+            assert!(district
+                .connected_locations
+                .iter()
+                .any(|c| c.target_id.as_deref() == Some(site.settlement.id.as_str())));
+        }
+
+        let relationships = site.relationships("camp-1");
+        let part_of = relationships.iter().filter(|r| r.relationship_type == RelationshipType::PartOf).count();
+        assert_eq!(part_of, site.districts.len());
+        assert!(relationships.iter().any(|r| r.relationship_type == RelationshipType::Controls
+            && r.source_id == site.government.ruler_id
+            && r.target_id == site.settlement.id));
+        let located = relationships.iter().filter(|r| r.relationship_type == RelationshipType::LocatedAt).count();
+        assert_eq!(located, site.notable_npcs.len());
+    }
+
+    #[test]
+    fn test_tavern_menu_patrons_and_hooks() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let site = SiteGenerator::new().tavern(&options(), TavernQuality::Wealthy, &mut rng);
+
+        assert_eq!(site.tavern.location_type, LocationType::Tavern);
+        assert!(site.menu.iter().any(|m| m.category == MenuCategory::Food));
+        assert!(site.menu.iter().any(|m| m.category == MenuCategory::Drink));
+        assert_eq!(site.menu.iter().filter(|m| m.category == MenuCategory::Lodging).count(), 3);
+        if let Some(ale) = site.menu.iter().find(|m| m.name == "Mug of ale") {
+            assert_eq!(ale.price, "1 sp 6 cp");
+        }
+
+        assert_eq!(site.tavern.inhabitants.len(), site.patrons.len() + 1);
+        assert!(!site.hooks.is_empty());
+        for hook in &site.hooks {
+            let patron = site.patrons.iter().find(|p| p.npc.id == hook.npc_id).expect("hook from a patron");
+            assert!(hook.text.contains(&patron.npc.name));
+            assert_eq!(patron.npc.hooks.len(), 1);
+        }
+        assert_eq!(site.relationships("camp-1").len(), site.patrons.len() + 2);
+        assert_eq!(format_coins(125), "1 gp 2 sp 5 cp");
+    }
+
+    #[test]
+    fn test_dungeon_rooms_reachable_without_secrets() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let site = SiteGenerator::new().dungeon(&options(), 12, Difficulty::Medium, &mut rng);
+
+        assert_eq!(site.rooms.len(), 12);
+        assert!(site.rooms[0].name.ends_with("Entrance"));
+        let mut reached = vec![site.rooms[0].id.clone()];
+        let mut i = 0;
+        while i < reached.len() {
+            for passage in site.passages.iter().filter(|p| !p.hidden) {
+                for (from, to) in [(&passage.from_id, &passage.to_id), (&passage.to_id, &passage.from_id)] {
+                    if *from == reached[i] && !reached.contains(to) {
+                        reached.push(to.clone());
+                    }
+                }
+            }
+            i += 1;
+        }
+        assert_eq!(reached.len(), site.rooms.len());
+
+        let boss_room = site.rooms.iter().find(|r| r.id == site.boss_room_id).expect("boss room");
+        assert_eq!(boss_room.encounters.len(), 1);
+        assert!(matches!(boss_room.loot_potential.as_ref().map(|l| &l.treasure_level), Some(TreasureLevel::Hoard)));
+        assert!(site.passages.len() >= site.rooms.len() - 1);
+        assert_eq!(
+            site.relationships("camp-1").len(),
+            site.rooms.len() + site.passages.len()
+        );
+    }
+}
@@ -7,6 +7,7 @@ use chrono::Utc;
 use std::collections::HashMap;
 use std::sync::RwLock;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::core::location_gen::{
     Location, LocationType, LocationConnection, Inhabitant, Secret,
@@ -320,6 +321,38 @@ impl LocationManager {
             .collect()
     }
 
+    /// Copy a campaign's locations to another campaign with new ids
+    ///
+    /// Connections between copied locations are re-pointed at the copies.
+    /// Returns a map from original location id to copied location id.
+    pub fn copy_campaign_locations(
+        &self,
+        from_campaign_id: &str,
+        to_campaign_id: &str,
+    ) -> Result<HashMap<String, String>> {
+        let originals = self.list_locations_for_campaign(from_campaign_id);
+        let id_map: HashMap<String, String> = originals
+            .iter()
+            .map(|l| (l.id.clone(), Uuid::new_v4().to_string()))
+            .collect();
+
+        let now = Utc::now();
+        for mut location in originals {
+            location.id = id_map[&location.id].clone();
+            location.campaign_id = Some(to_campaign_id.to_string());
+            for connection in &mut location.connected_locations {
+                if let Some(new_id) = connection.target_id.as_ref().and_then(|id| id_map.get(id)) {
+                    connection.target_id = Some(new_id.clone());
+                }
+            }
+            location.created_at = now;
+            location.updated_at = now;
+            self.save_location(location)?;
+        }
+
+        Ok(id_map)
+    }
+
     /// Get all locations (no campaign filter)
     pub fn list_all(&self) -> Vec<Location> {
         let locations = match self.locations.read() {
@@ -384,6 +417,39 @@ mod tests {
         assert_eq!(retrieved.location_type, LocationType::Tavern);
     }
 
+    #[test]
+    fn test_copy_campaign_locations() {
+        let manager = LocationManager::new();
+        let generator = LocationGenerator::new();
+        let options = LocationGenerationOptions {
+            campaign_id: Some("campaign-1".to_string()),
+            ..Default::default()
+        };
+
+        let tavern = generator.generate_quick(&options);
+        let mut road = generator.generate_quick(&options);
+        road.connected_locations.push(LocationConnection {
+            target_id: Some(tavern.id.clone()),
+            target_name: tavern.name.clone(),
+            connection_type: crate::core::location_gen::ConnectionType::Road,
+            description: None,
+            travel_time: None,
+            hazards: vec![],
+        });
+        let road_id = road.id.clone();
+        manager.save_location(tavern.clone()).unwrap();
+        manager.save_location(road).unwrap();
+
+        let id_map = manager.copy_campaign_locations("campaign-1", "campaign-2").unwrap();
+        assert_eq!(manager.count_for_campaign("campaign-2"), 2);
+
+        let copied_road = manager.get_location(&id_map[&road_id]).unwrap();
+        assert_eq!(
+            copied_road.connected_locations[0].target_id.as_deref(),
+            Some(id_map[&tavern.id].as_str())
+        );
+    }
+
     #[test]
     fn test_list_by_campaign() {
         let manager = LocationManager::new();
@@ -18,6 +18,7 @@
 //! embedded `meilisearch_lib`. All operations are now synchronous and use
 //! the `MeilisearchLib` API directly.
 
+use crate::core::search::index_tuning::IndexTuning;
 use crate::core::search::{LibraryDocumentMetadata, SearchError, INDEX_LIBRARY_METADATA};
 use crate::ingestion::claude_extractor::ClaudeDocumentExtractor;
 use crate::ingestion::extraction_settings::TextExtractionProvider;
@@ -26,6 +27,7 @@ use chrono::Utc;
 use meilisearch_lib::{FilterableAttributesRule, MeilisearchLib, SearchQuery, Settings, Setting};
 use std::collections::BTreeSet;
 use std::path::Path;
+use std::sync::RwLock;
 use std::time::Duration;
 
 // Re-export types that external code may need (preserving backward compatibility)
@@ -44,11 +46,13 @@ pub use crate::ingestion::pipeline_models::{
 
 pub struct MeilisearchPipeline {
     config: PipelineConfig,
+    /// Relevancy settings given to chunk indexes as they are created
+    index_tuning: RwLock<IndexTuning>,
 }
 
 impl MeilisearchPipeline {
     pub fn new(config: PipelineConfig) -> Self {
-        Self { config }
+        Self { config, index_tuning: RwLock::new(IndexTuning::default()) }
     }
 
     pub fn with_defaults() -> Self {
@@ -60,6 +64,15 @@ impl MeilisearchPipeline {
         &self.config
     }
 
+    /// Relevancy settings for chunk indexes created from now on
+    pub fn index_tuning(&self) -> IndexTuning {
+        self.index_tuning.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set_index_tuning(&self, tuning: IndexTuning) {
+        *self.index_tuning.write().unwrap_or_else(|e| e.into_inner()) = tuning;
+    }
+
     // ========================================================================
     // Two-Phase Pipeline: Extract → Raw → Chunk
     // ========================================================================
@@ -116,7 +129,7 @@ impl MeilisearchPipeline {
         })?;
 
         log::info!("Creating chunks index '{}' (if not exists)...", chunks_index);
-        ensure_chunks_index(meili, &chunks_index).map_err(|e| {
+        ensure_chunks_index(meili, &chunks_index, &self.index_tuning()).map_err(|e| {
             SearchError::ConfigError(format!(
                 "Failed to create chunks index '{}': {}. Aborting before extraction.",
                 chunks_index, e
@@ -596,7 +609,7 @@ impl MeilisearchPipeline {
             SearchError::ConfigError(format!("Failed to create raw index '{}': {}", raw_index, e))
         })?;
 
-        ensure_chunks_index(meili, &chunks_index).map_err(|e| {
+        ensure_chunks_index(meili, &chunks_index, &self.index_tuning()).map_err(|e| {
             SearchError::ConfigError(format!(
                 "Failed to create chunks index '{}': {}",
                 chunks_index, e
@@ -823,7 +836,7 @@ impl MeilisearchPipeline {
         log::info!("Chunking from '{}' to '{}'", raw_index, chunks_index);
 
         // Ensure chunks index exists with proper settings
-        ensure_chunks_index(meili, &chunks_index).map_err(|e| {
+        ensure_chunks_index(meili, &chunks_index, &self.index_tuning()).map_err(|e| {
             SearchError::ConfigError(format!("Failed to create chunks index: {}", e))
         })?;
 
@@ -1096,8 +1109,9 @@ fn ensure_raw_index(meili: &MeilisearchLib, uid: &str) -> Result<(), SearchError
 /// Ensure a chunks index exists with proper settings for search.
 ///
 /// Creates the index if it doesn't exist and configures searchable and
-/// filterable attributes for optimal search performance.
-fn ensure_chunks_index(meili: &MeilisearchLib, uid: &str) -> Result<(), SearchError> {
+/// filterable attributes for optimal search performance, on top of the
+/// user's typo tolerance and ranking settings.
+fn ensure_chunks_index(meili: &MeilisearchLib, uid: &str, tuning: &IndexTuning) -> Result<(), SearchError> {
     // Create index if it doesn't exist
     if !meili
         .index_exists(uid)
@@ -1125,7 +1139,9 @@ fn ensure_chunks_index(meili: &MeilisearchLib, uid: &str) -> Result<(), SearchEr
         searchable_attributes: Setting::Set(vec!["content".to_string()]).into(),
         filterable_attributes: Setting::Set(filterable),
         sortable_attributes: Setting::Set(sortable),
-        ..Default::default()
+        ..tuning
+            .meili_settings()
+            .map_err(|e| SearchError::ConfigError(e.to_string()))?
     };
 
     let task = meili
@@ -48,6 +48,7 @@ pub mod search_analytics;
 pub mod name_gen;
 pub mod voice_queue;
 pub mod transcription;
+pub mod speech_input;
 
 // TASK-022, TASK-023, TASK-024: Analytics and Security modules
 pub mod usage;
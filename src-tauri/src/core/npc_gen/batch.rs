@@ -0,0 +1,678 @@
+//! NPC Batch Generation
+//!
+//! Rosters of related NPCs generated in one go: a thieves' guild, a watch
+//! company, a cult. A roster has a leader, lieutenants who answer to the
+//! leader, and rank-and-file members who each answer to a lieutenant. All
+//! members share the group's cant and accent, and the roster lists the ties
+//! between them so they can be added to the relationship graph and to the
+//! faction they belong to.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{NPCGenerationOptions, NPCGenerator, NPCRelationship, NPC};
+use crate::core::campaign::factions::{Faction, FactionMember};
+use crate::core::campaign::relationships::{EntityRelationship, EntityType, RelationshipType};
+use crate::core::location_gen::Location;
+
+/// Largest roster generated in one batch
+pub const MAX_BATCH_SIZE: usize = 30;
+
+// ============================================================================
+// Options
+// ============================================================================
+
+/// The kind of group a roster is generated for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterKind {
+    ThievesGuild,
+    CityWatch,
+    Cult,
+    MerchantHouse,
+    MercenaryCompany,
+    NobleHousehold,
+    #[default]
+    Generic,
+}
+
+/// Settings for a roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcBatchOptions {
+    /// Number of NPCs, leader included (1 to 30)
+    #[serde(default = "default_count")]
+    pub count: usize,
+    #[serde(default)]
+    pub kind: RosterKind,
+    /// Group name (default: generated from the kind)
+    #[serde(default)]
+    pub group_name: Option<String>,
+    #[serde(default)]
+    pub system: Option<String>,
+    #[serde(default)]
+    pub name_culture: Option<String>,
+    /// Name of the place the group operates from
+    #[serde(default)]
+    pub location: Option<String>,
+    /// Dialect ID every member speaks with (default: the kind's accent)
+    #[serde(default)]
+    pub dialect_id: Option<String>,
+    /// Vocabulary bank ID every member draws phrases from
+    #[serde(default)]
+    pub vocabulary_bank_id: Option<String>,
+}
+
+fn default_count() -> usize {
+    6
+}
+
+impl Default for NpcBatchOptions {
+    fn default() -> Self {
+        Self {
+            count: default_count(),
+            kind: RosterKind::default(),
+            group_name: None,
+            system: None,
+            name_culture: None,
+            location: None,
+            dialect_id: None,
+            vocabulary_bank_id: None,
+        }
+    }
+}
+
+// ============================================================================
+// Roster Types
+// ============================================================================
+
+/// Place in the group's hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterRank {
+    Leader,
+    Lieutenant,
+    Member,
+}
+
+/// A generated NPC and their place in the roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterMember {
+    pub npc: NPC,
+    pub rank: RosterRank,
+    /// Title within the group, e.g. "Guildmaster"
+    pub title: String,
+    /// NPC ID of the member they answer to
+    pub reports_to: Option<String>,
+}
+
+/// The way of speaking shared by the whole group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedVoice {
+    pub accent: String,
+    pub dialect_id: Option<String>,
+    pub vocabulary_bank_id: Option<String>,
+    /// Description of the group's jargon
+    pub vocabulary: String,
+    /// Cant and catchphrases members use
+    pub phrases: Vec<String>,
+}
+
+/// A relationship between two members of the roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterTie {
+    pub from_id: String,
+    pub to_id: String,
+    pub relationship_type: RelationshipType,
+    pub description: String,
+    /// Known only to the source member
+    pub secret: bool,
+}
+
+/// A generated group of related NPCs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcBatch {
+    pub group_name: String,
+    pub kind: RosterKind,
+    pub members: Vec<RosterMember>,
+    pub voice: SharedVoice,
+    pub ties: Vec<RosterTie>,
+}
+
+impl NpcBatch {
+    /// The member at the top of the hierarchy
+    pub fn leader(&self) -> &RosterMember {
+        &self.members[0]
+    }
+
+    pub fn npcs(&self) -> impl Iterator<Item = &NPC> {
+        self.members.iter().map(|m| &m.npc)
+    }
+
+    /// Faction membership entries for every member, the leader marked as such
+    pub fn faction_members(&self) -> Vec<FactionMember> {
+        self.members
+            .iter()
+            .map(|m| FactionMember {
+                npc_id: m.npc.id.clone(),
+                role: Some(m.title.clone()),
+                is_leader: m.rank == RosterRank::Leader,
+            })
+            .collect()
+    }
+
+    /// Relationships for the entity graph: the ties between members, each
+    /// member's place in the faction, and where they can be found
+    pub fn relationships(
+        &self,
+        campaign_id: &str,
+        faction: Option<&Faction>,
+        location: Option<&Location>,
+    ) -> Vec<EntityRelationship> {
+        let mut relationships: Vec<EntityRelationship> = self
+            .ties
+            .iter()
+            .filter_map(|tie| {
+                let from = self.member(&tie.from_id)?;
+                let to = self.member(&tie.to_id)?;
+                let relationship = npc_link(campaign_id, &from.npc, &to.npc, tie.relationship_type.clone())
+                    .with_description(&tie.description);
+                Some(if tie.secret { relationship.as_secret() } else { relationship })
+            })
+            .collect();
+
+        for member in &self.members {
+            if let Some(faction) = faction {
+                let kind = match member.rank {
+                    RosterRank::Leader => RelationshipType::LeaderOf,
+                    _ => RelationshipType::MemberOf,
+                };
+                relationships.push(
+                    EntityRelationship::new(
+                        campaign_id,
+                        &member.npc.id,
+                        EntityType::NPC,
+                        &member.npc.name,
+                        &faction.id,
+                        EntityType::Faction,
+                        &faction.name,
+                        kind,
+                    )
+                    .with_description(&member.title),
+                );
+            }
+            if let Some(location) = location {
+                relationships.push(EntityRelationship::new(
+                    campaign_id,
+                    &member.npc.id,
+                    EntityType::NPC,
+                    &member.npc.name,
+                    &location.id,
+                    EntityType::Location,
+                    &location.name,
+                    RelationshipType::LocatedAt,
+                ));
+            }
+        }
+        relationships
+    }
+
+    fn member(&self, npc_id: &str) -> Option<&RosterMember> {
+        self.members.iter().find(|m| m.npc.id == npc_id)
+    }
+}
+
+// ============================================================================
+// Roster Tables
+// ============================================================================
+
+/// (title, occupation, NPC role)
+type RankData = (&'static str, &'static str, &'static str);
+
+struct RosterTemplate {
+    names: &'static [&'static str],
+    leader: RankData,
+    lieutenant: RankData,
+    members: &'static [RankData],
+    accent: &'static str,
+    vocabulary: &'static str,
+    phrases: &'static [&'static str],
+}
+
+fn template(kind: RosterKind) -> &'static RosterTemplate {
+    match kind {
+        RosterKind::ThievesGuild => &THIEVES_GUILD,
+        RosterKind::CityWatch => &CITY_WATCH,
+        RosterKind::Cult => &CULT,
+        RosterKind::MerchantHouse => &MERCHANT_HOUSE,
+        RosterKind::MercenaryCompany => &MERCENARY_COMPANY,
+        RosterKind::NobleHousehold => &NOBLE_HOUSEHOLD,
+        RosterKind::Generic => &GENERIC,
+    }
+}
+
+static THIEVES_GUILD: RosterTemplate = RosterTemplate {
+    names: &["The Quiet Hands", "The Gutter Court", "The Lantern Cutters", "The Night Market Crew"],
+    leader: ("Guildmaster", "crime boss", "boss"),
+    lieutenant: ("Crew Boss", "thief", "rival"),
+    members: &[
+        ("Cutpurse", "pickpocket", "minion"),
+        ("Fence", "fence", "merchant"),
+        ("Lookout", "street urchin", "informant"),
+        ("Second-Story Man", "burglar", "minion"),
+        ("Enforcer", "thug", "enemy"),
+    ],
+    accent: "clipped street cant",
+    vocabulary: "thieves' cant: marks, fences, and jobs",
+    phrases: &[
+        "Mind the mark, not the purse.",
+        "That's hot goods, friend. Costs extra.",
+        "Quiet as the Hands.",
+        "The watch is sleeping on the east side tonight.",
+        "No names on the job.",
+    ],
+};
+
+static CITY_WATCH: RosterTemplate = RosterTemplate {
+    names: &["The Night Watch", "The Gate Wardens", "The Lamplight Company", "The Harbor Watch"],
+    leader: ("Captain of the Watch", "guard captain", "authority"),
+    lieutenant: ("Sergeant", "guard sergeant", "authority"),
+    members: &[
+        ("Watchman", "guard", "neutral"),
+        ("Gate Warden", "guard", "neutral"),
+        ("Recruit", "guard", "bystander"),
+        ("Watch Clerk", "clerk", "informant"),
+    ],
+    accent: "drilled, formal barracks speech",
+    vocabulary: "watch jargon: shifts, posts, and the duty roster",
+    phrases: &[
+        "State your business.",
+        "Move along, nothing to see.",
+        "By order of the Captain.",
+        "Third bell, change of watch.",
+    ],
+};
+
+static CULT: RosterTemplate = RosterTemplate {
+    names: &["The Veiled Choir", "The Children of the Ember", "The Hollow Communion", "The Last Tide"],
+    leader: ("High Priest", "cult leader", "boss"),
+    lieutenant: ("Acolyte of the Inner Circle", "zealot", "enemy"),
+    members: &[
+        ("Initiate", "cultist", "minion"),
+        ("Recruiter", "street preacher", "informant"),
+        ("Keeper of Relics", "scholar", "minion"),
+        ("Devotee", "cultist", "minion"),
+    ],
+    accent: "hushed, liturgical cadence",
+    vocabulary: "ritual language: the Awakening, the faithful, and the unclean",
+    phrases: &[
+        "The veil grows thin.",
+        "All will be made whole.",
+        "You are not yet ready to see.",
+        "Blessed are those who listen.",
+    ],
+};
+
+static MERCHANT_HOUSE: RosterTemplate = RosterTemplate {
+    names: &["House Veldt", "The Saltroad Company", "The Gilded Scale", "The Amberline Consortium"],
+    leader: ("Head of the House", "merchant prince", "authority"),
+    lieutenant: ("Factor", "trader", "merchant"),
+    members: &[
+        ("Clerk", "clerk", "neutral"),
+        ("Caravan Master", "caravan master", "merchant"),
+        ("Buyer", "trader", "merchant"),
+        ("Guard", "mercenary", "neutral"),
+    ],
+    accent: "polished, mercantile courtesy",
+    vocabulary: "ledger talk: margins, consignments, and letters of credit",
+    phrases: &[
+        "Everything has a price.",
+        "Let's put it in writing.",
+        "The House remembers its friends.",
+        "Margins are thin this season.",
+    ],
+};
+
+static MERCENARY_COMPANY: RosterTemplate = RosterTemplate {
+    names: &["The Iron Wolves", "The Red Company", "The Broken Spears", "The Free Lances"],
+    leader: ("Commander", "mercenary captain", "boss"),
+    lieutenant: ("Lieutenant", "veteran soldier", "rival"),
+    members: &[
+        ("Sellsword", "mercenary", "minion"),
+        ("Scout", "scout", "informant"),
+        ("Quartermaster", "quartermaster", "merchant"),
+        ("Crossbowman", "mercenary", "minion"),
+    ],
+    accent: "rough camp slang",
+    vocabulary: "soldier's slang: contracts, pay, and the line",
+    phrases: &[
+        "Coin first, then steel.",
+        "Hold the line!",
+        "The contract says nothing about that.",
+        "We've seen worse.",
+    ],
+};
+
+static NOBLE_HOUSEHOLD: RosterTemplate = RosterTemplate {
+    names: &["House Ashford", "House Marrowind", "House Caldera", "House Thorne"],
+    leader: ("Lord of the House", "noble", "authority"),
+    lieutenant: ("Steward", "steward", "neutral"),
+    members: &[
+        ("Heir", "noble", "rival"),
+        ("Servant", "servant", "bystander"),
+        ("Household Guard", "guard", "neutral"),
+        ("Courtier", "courtier", "informant"),
+    ],
+    accent: "refined courtly diction",
+    vocabulary: "courtly speech: titles, favors, and precedence",
+    phrases: &[
+        "As my lord wishes.",
+        "The House does not forget a slight.",
+        "One must observe the proprieties.",
+        "An audience may be arranged.",
+    ],
+};
+
+static GENERIC: RosterTemplate = RosterTemplate {
+    names: &["The Company", "The Circle", "The Fellowship", "The Band"],
+    leader: ("Leader", "leader", "authority"),
+    lieutenant: ("Second", "veteran", "ally"),
+    members: &[
+        ("Member", "commoner", "neutral"),
+        ("Member", "laborer", "neutral"),
+        ("Member", "artisan", "neutral"),
+    ],
+    accent: "local speech",
+    vocabulary: "the group's shorthand and in-jokes",
+    phrases: &["We look after our own.", "You'll want to talk to the boss.", "Not my place to say."],
+};
+
+// ============================================================================
+// Batch Generator
+// ============================================================================
+
+pub struct NpcBatchGenerator {
+    npcs: NPCGenerator,
+}
+
+impl Default for NpcBatchGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NpcBatchGenerator {
+    pub fn new() -> Self {
+        Self { npcs: NPCGenerator::new() }
+    }
+
+    /// Generate the roster's members with this NPC generator, e.g. one with
+    /// name banks loaded
+    pub fn with_npc_generator(mut self, npcs: NPCGenerator) -> Self {
+        self.npcs = npcs;
+        self
+    }
+
+    /// Generate a roster: one leader, a lieutenant for every four or so
+    /// members, and members split between the lieutenants
+    pub fn generate(&self, options: &NpcBatchOptions, rng: &mut impl Rng) -> NpcBatch {
+        let count = options.count.clamp(1, MAX_BATCH_SIZE);
+        let template = template(options.kind);
+        let group_name = options
+            .group_name
+            .clone()
+            .unwrap_or_else(|| template.names.choose(rng).copied().unwrap_or("The Company").to_string());
+        let voice = SharedVoice {
+            accent: options.dialect_id.clone().unwrap_or_else(|| template.accent.to_string()),
+            dialect_id: options.dialect_id.clone(),
+            vocabulary_bank_id: options.vocabulary_bank_id.clone(),
+            vocabulary: template.vocabulary.to_string(),
+            phrases: template.phrases.iter().map(|p| p.to_string()).collect(),
+        };
+
+        let lieutenant_count = if count <= 2 { count - 1 } else { (count + 2) / 4 };
+        let mut members = vec![self.member(options, &voice, &group_name, RosterRank::Leader, template.leader, None, rng)];
+        let leader_id = members[0].npc.id.clone();
+        for _ in 0..lieutenant_count {
+            let lieutenant = self.member(
+                options,
+                &voice,
+                &group_name,
+                RosterRank::Lieutenant,
+                template.lieutenant,
+                Some(&leader_id),
+                rng,
+            );
+            members.push(lieutenant);
+        }
+        for i in 0..count - 1 - lieutenant_count {
+            let superior = members[1 + i % lieutenant_count].npc.id.clone();
+            let rank_data = *template.members.choose(rng).unwrap_or(&template.lieutenant);
+            members.push(self.member(options, &voice, &group_name, RosterRank::Member, rank_data, Some(&superior), rng));
+        }
+
+        let ties = roster_ties(&members, rng);
+        for tie in &ties {
+            let target_name = members.iter().find(|m| m.npc.id == tie.to_id).map(|m| m.npc.name.clone());
+            if let Some(source) = members.iter_mut().find(|m| m.npc.id == tie.from_id) {
+                source.npc.relationships.push(NPCRelationship {
+                    target_id: Some(tie.to_id.clone()),
+                    target_name: target_name.unwrap_or_default(),
+                    relationship_type: relationship_label(&tie.relationship_type),
+                    disposition: disposition(&tie.relationship_type),
+                    notes: tie.description.clone(),
+                });
+            }
+        }
+
+        NpcBatch {
+            group_name,
+            kind: options.kind,
+            members,
+            voice,
+            ties,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn member(
+        &self,
+        options: &NpcBatchOptions,
+        voice: &SharedVoice,
+        group_name: &str,
+        rank: RosterRank,
+        (title, occupation, role): RankData,
+        reports_to: Option<&str>,
+        rng: &mut impl Rng,
+    ) -> RosterMember {
+        let mut npc = self.npcs.generate_quick(&NPCGenerationOptions {
+            system: options.system.clone(),
+            role: Some(role.to_string()),
+            occupation: Some(occupation.to_string()),
+            location: options.location.clone(),
+            name_culture: options.name_culture.clone(),
+            include_secrets: rank != RosterRank::Member,
+            include_hooks: rank == RosterRank::Leader,
+            ..Default::default()
+        });
+        npc.voice.accent = Some(voice.accent.clone());
+        npc.voice.vocabulary = voice.vocabulary.clone();
+        npc.voice.sample_phrases = voice.phrases.choose_multiple(rng, 2).cloned().collect();
+        npc.notes = format!("{} of {}.", title, group_name);
+        npc.tags.push(group_name.to_lowercase());
+
+        RosterMember {
+            npc,
+            rank,
+            title: title.to_string(),
+            reports_to: reports_to.map(|id| id.to_string()),
+        }
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Chain of command, a rivalry between lieutenants, crews that look out for
+/// each other, and sometimes a member secretly working against the leader
+fn roster_ties(members: &[RosterMember], rng: &mut impl Rng) -> Vec<RosterTie> {
+    let mut ties: Vec<RosterTie> = members
+        .iter()
+        .filter_map(|m| {
+            let superior = m.reports_to.as_ref()?;
+            Some(tie(&m.npc.id, superior, RelationshipType::Employee, "Answers to", false))
+        })
+        .collect();
+
+    let lieutenants: Vec<&RosterMember> = members.iter().filter(|m| m.rank == RosterRank::Lieutenant).collect();
+    if let [a, b, ..] = lieutenants.choose_multiple(rng, 2).collect::<Vec<_>>()[..] {
+        ties.push(tie(
+            &a.npc.id,
+            &b.npc.id,
+            RelationshipType::Custom("Rival".to_string()),
+            "Competing for the leader's favor",
+            false,
+        ));
+    }
+
+    for lieutenant in &lieutenants {
+        let crew: Vec<&RosterMember> = members
+            .iter()
+            .filter(|m| m.reports_to.as_deref() == Some(lieutenant.npc.id.as_str()))
+            .collect();
+        if let [a, b, ..] = crew[..] {
+            ties.push(tie(&a.npc.id, &b.npc.id, RelationshipType::Ally, "Work the same crew", false));
+        }
+    }
+
+    let rank_and_file: Vec<&RosterMember> = members.iter().filter(|m| m.rank == RosterRank::Member).collect();
+    if rank_and_file.len() >= 4 && rng.gen_bool(0.5) {
+        if let Some(mole) = rank_and_file.choose(rng) {
+            ties.push(tie(
+                &mole.npc.id,
+                &members[0].npc.id,
+                RelationshipType::Enemy,
+                "Secretly informing on the group",
+                true,
+            ));
+        }
+    }
+    ties
+}
+
+fn tie(from_id: &str, to_id: &str, relationship_type: RelationshipType, description: &str, secret: bool) -> RosterTie {
+    RosterTie {
+        from_id: from_id.to_string(),
+        to_id: to_id.to_string(),
+        relationship_type,
+        description: description.to_string(),
+        secret,
+    }
+}
+
+fn npc_link(campaign_id: &str, from: &NPC, to: &NPC, kind: RelationshipType) -> EntityRelationship {
+    EntityRelationship::new(campaign_id, &from.id, EntityType::NPC, &from.name, &to.id, EntityType::NPC, &to.name, kind)
+}
+
+fn relationship_label(kind: &RelationshipType) -> String {
+    match kind {
+        RelationshipType::Custom(label) => label.to_lowercase(),
+        other => format!("{:?}", other).to_lowercase(),
+    }
+}
+
+fn disposition(kind: &RelationshipType) -> i32 {
+    match kind {
+        RelationshipType::Ally => 60,
+        RelationshipType::Employee => 30,
+        RelationshipType::Enemy => -60,
+        RelationshipType::Custom(_) => -30,
+        _ => 0,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn roster(count: usize, seed: u64) -> NpcBatch {
+        let options = NpcBatchOptions {
+            count,
+            kind: RosterKind::ThievesGuild,
+            ..Default::default()
+        };
+        NpcBatchGenerator::new().generate(&options, &mut StdRng::seed_from_u64(seed))
+    }
+
+    #[test]
+    fn test_roster_hierarchy() {
+        let batch = roster(9, 3);
+        assert_eq!(batch.members.len(), 9);
+        assert_eq!(batch.leader().rank, RosterRank::Leader);
+        assert!(batch.leader().reports_to.is_none());
+
+        let lieutenants = batch.members.iter().filter(|m| m.rank == RosterRank::Lieutenant).count();
+        assert_eq!(lieutenants, 2);
+        for member in batch.members.iter().skip(1) {
+            let superior = member.reports_to.as_ref().expect("everyone but the leader reports to someone");
+            let superior = batch.members.iter().find(|m| &m.npc.id == superior).unwrap();
+            match member.rank {
+                RosterRank::Lieutenant => assert_eq!(superior.rank, RosterRank::Leader),
+                _ => assert_eq!(superior.rank, RosterRank::Lieutenant),
+            }
+        }
+
+        assert_eq!(roster(1, 3).members.len(), 1);
+        assert_eq!(roster(100, 3).members.len(), MAX_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_roster_shares_voice() {
+        let options = NpcBatchOptions {
+            count: 5,
+            kind: RosterKind::Cult,
+            group_name: Some("The Pale Choir".to_string()),
+            dialect_id: Some("northern".to_string()),
+            ..Default::default()
+        };
+        let batch = NpcBatchGenerator::new().generate(&options, &mut StdRng::seed_from_u64(11));
+
+        assert_eq!(batch.group_name, "The Pale Choir");
+        for member in &batch.members {
+            assert_eq!(member.npc.voice.accent.as_deref(), Some("northern"));
+            assert_eq!(member.npc.voice.vocabulary, batch.voice.vocabulary);
+            assert!(member.npc.voice.sample_phrases.iter().all(|p| batch.voice.phrases.contains(p)));
+            assert!(member.npc.tags.contains(&"the pale choir".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_roster_relationships_and_faction() {
+        let batch = roster(6, 5);
+        let faction = Faction::new("camp-1", &batch.group_name);
+        let relationships = batch.relationships("camp-1", Some(&faction), None);
+
+        let leads = relationships
+            .iter()
+            .filter(|r| r.relationship_type == RelationshipType::LeaderOf)
+            .count();
+        let joins = relationships
+            .iter()
+            .filter(|r| r.relationship_type == RelationshipType::MemberOf)
+            .count();
+        assert_eq!(leads, 1);
+        assert_eq!(joins, 5);
+        assert!(relationships.iter().all(|r| r.source_id != r.target_id));
+
+        let members = batch.faction_members();
+        assert_eq!(members.iter().filter(|m| m.is_leader).count(), 1);
+        assert_eq!(members[0].npc_id, batch.leader().npc.id);
+    }
+}
@@ -5,8 +5,14 @@
 
 use crate::core::character_gen::{Character, GenerationOptions, CharacterGenerator};
 use crate::core::llm::{LLMClient, LLMConfig, ChatMessage, ChatRequest, MessageRole};
+use crate::ingestion::ttrpg::StatBlockData;
+use super::name_banks::{builtin_bank, generate_from_rules, NameBankRegistry};
+use super::names::Gender;
+use super::speech_quirks::SpeechQuirks;
+use super::stat_block::{build_stat_block, StatBlockTarget};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use rand::Rng;
 use uuid::Uuid;
 use thiserror::Error;
@@ -43,6 +49,9 @@ pub struct NPC {
     pub personality_id: Option<String>,
     pub voice: VoiceDescription,
     pub stats: Option<Character>,
+    /// Combat stat block, present when one was requested at generation time
+    #[serde(default)]
+    pub stat_block: Option<StatBlockData>,
     pub relationships: Vec<NPCRelationship>,
     pub secrets: Vec<String>,
     pub hooks: Vec<PlotHook>,
@@ -119,6 +128,10 @@ pub struct VoiceDescription {
     pub accent: Option<String>,
     pub vocabulary: String,
     pub sample_phrases: Vec<String>,
+    /// Catchphrases, accent respellings, and word choices, shared by the
+    /// NPC's conversation prompt and text-to-speech
+    #[serde(default)]
+    pub quirks: Option<SpeechQuirks>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -174,6 +187,12 @@ pub struct NPCGenerationOptions {
     pub personality_depth: PersonalityDepth,
     pub include_hooks: bool,
     pub include_secrets: bool,
+    /// Build a combat stat block tuned to this CR (5e) or creature level (PF2e)
+    #[serde(default)]
+    pub stat_block: Option<StatBlockTarget>,
+    /// Name bank culture ID (e.g. "norse"); falls back to the campaign default
+    #[serde(default)]
+    pub name_culture: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -191,6 +210,7 @@ pub enum PersonalityDepth {
 
 pub struct NPCGenerator {
     llm_client: Option<LLMClient>,
+    name_banks: Option<Arc<NameBankRegistry>>,
 }
 
 impl Default for NPCGenerator {
@@ -201,20 +221,29 @@ impl Default for NPCGenerator {
 
 impl NPCGenerator {
     pub fn new() -> Self {
-        Self { llm_client: None }
+        Self { llm_client: None, name_banks: None }
     }
 
     pub fn with_llm(llm_config: LLMConfig) -> Self {
         Self {
             llm_client: Some(LLMClient::new(llm_config)),
+            name_banks: None,
         }
     }
 
+    /// Draw names from these banks, including custom and trained ones,
+    /// instead of only the built-in banks
+    pub fn with_name_banks(mut self, name_banks: Arc<NameBankRegistry>) -> Self {
+        self.name_banks = Some(name_banks);
+        self
+    }
+
     /// Generate a quick NPC without LLM
     pub fn generate_quick(&self, options: &NPCGenerationOptions) -> NPC {
         let mut rng = rand::thread_rng();
 
         let name = options.name.clone()
+            .or_else(|| self.culture_name(&mut rng, options))
             .unwrap_or_else(|| self.random_name(&mut rng, options.race.as_deref()));
 
         let role = options.role.as_deref()
@@ -252,6 +281,8 @@ impl NPCGenerator {
             vec![]
         };
 
+        let stat_block = self.generate_stat_block(&name, &role, options);
+
         NPC {
             id: Uuid::new_v4().to_string(),
             name,
@@ -261,6 +292,7 @@ impl NPCGenerator {
             personality_id: None,
             voice,
             stats,
+            stat_block,
             relationships: vec![],
             secrets,
             hooks,
@@ -308,6 +340,10 @@ impl NPCGenerator {
             prompt.push_str(&format!("Game System: {}\n", system));
         }
 
+        if let Some(name) = options.name.clone().or_else(|| self.culture_name(&mut rand::thread_rng(), options)) {
+            prompt.push_str(&format!("Name: {}\n", name));
+        }
+
         if let Some(role) = &options.role {
             prompt.push_str(&format!("Role: {}\n", role));
         }
@@ -392,15 +428,19 @@ Respond with a JSON object containing:
             .map(NPCRole::from_str)
             .unwrap_or(NPCRole::Neutral);
 
+        let name = parsed["name"].as_str().unwrap_or("Unknown").to_string();
+        let stat_block = self.generate_stat_block(&name, &role, options);
+
         Ok(NPC {
             id: Uuid::new_v4().to_string(),
-            name: parsed["name"].as_str().unwrap_or("Unknown").to_string(),
+            name,
             role,
             appearance: self.parse_appearance(&parsed["appearance"]),
             personality: self.parse_personality(&parsed["personality"]),
             personality_id: None,
             voice: self.parse_voice(&parsed["voice"]),
             stats: None,
+            stat_block,
             relationships: vec![],
             secrets: parsed["secrets"].as_array()
                 .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
@@ -411,6 +451,23 @@ Respond with a JSON object containing:
         })
     }
 
+    fn generate_stat_block(
+        &self,
+        name: &str,
+        role: &NPCRole,
+        options: &NPCGenerationOptions,
+    ) -> Option<StatBlockData> {
+        options.stat_block.map(|target| {
+            build_stat_block(
+                name,
+                options.race.as_deref(),
+                options.occupation.as_deref(),
+                role,
+                target,
+            )
+        })
+    }
+
     fn parse_appearance(&self, value: &serde_json::Value) -> AppearanceDescription {
         AppearanceDescription {
             age: value["age"].as_str().unwrap_or("Adult").to_string(),
@@ -455,6 +512,7 @@ Respond with a JSON object containing:
             sample_phrases: value["sample_phrases"].as_array()
                 .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
                 .unwrap_or_default(),
+            quirks: None,
         }
     }
 
@@ -490,6 +548,24 @@ Respond with a JSON object containing:
     // Random Generation Helpers
     // ========================================================================
 
+    /// A name from the requested culture's bank, if one was requested and
+    /// can produce a name
+    fn culture_name(&self, rng: &mut impl Rng, options: &NPCGenerationOptions) -> Option<String> {
+        let culture = options.name_culture.as_deref()?;
+        let gender = if rng.gen_bool(0.5) { Gender::Male } else { Gender::Female };
+        let result = match &self.name_banks {
+            Some(banks) => banks.generate(culture, gender, rng),
+            None => builtin_bank(culture)
+                .ok_or_else(|| super::errors::NameGenerationError::CultureNotFound {
+                    culture: culture.to_string(),
+                })
+                .and_then(|rules| generate_from_rules(&rules, gender, rng)),
+        };
+        result
+            .map_err(|e| log::warn!("Falling back to default names: {}", e))
+            .ok()
+    }
+
     fn random_name(&self, rng: &mut impl Rng, race: Option<&str>) -> String {
         let first_names = match race {
             Some("Elf") | Some("elf") => vec![
@@ -691,6 +767,7 @@ Respond with a JSON object containing:
             accent: accents[rng.gen_range(0..accents.len())].map(String::from),
             vocabulary: vocabularies[rng.gen_range(0..vocabularies.len())].to_string(),
             sample_phrases: vec![sample_phrases[rng.gen_range(0..sample_phrases.len())].to_string()],
+            quirks: None,
         }
     }
 
@@ -901,6 +978,42 @@ mod tests {
         assert!(!npc.name.is_empty());
     }
 
+    #[test]
+    fn test_quick_generation_with_stat_block() {
+        let generator = NPCGenerator::new();
+        let options = NPCGenerationOptions {
+            name: Some("Captain Vell".to_string()),
+            role: Some("boss".to_string()),
+            occupation: Some("mercenary".to_string()),
+            stat_block: Some(StatBlockTarget::ChallengeRating(5.0)),
+            ..Default::default()
+        };
+
+        let npc = generator.generate_quick(&options);
+        let stat_block = npc.stat_block.expect("stat block requested");
+        assert_eq!(stat_block.name, "Captain Vell");
+        assert_eq!(stat_block.challenge_rating.and_then(|cr| cr.xp), Some(1800));
+        assert!(generator.generate_quick(&NPCGenerationOptions::default()).stat_block.is_none());
+    }
+
+    #[test]
+    fn test_quick_generation_with_name_culture() {
+        let generator = NPCGenerator::new();
+        let options = NPCGenerationOptions {
+            name_culture: Some("arabic_inspired".to_string()),
+            ..Default::default()
+        };
+
+        let npc = generator.generate_quick(&options);
+        assert!(npc.name.contains(" al-"), "{}", npc.name);
+
+        let unknown = NPCGenerationOptions {
+            name_culture: Some("no_such_culture".to_string()),
+            ..Default::default()
+        };
+        assert!(!generator.generate_quick(&unknown).name.is_empty());
+    }
+
     #[test]
     fn test_npc_store() {
         let store = NPCStore::new();
@@ -0,0 +1,287 @@
+//! NPC Conversation Memory
+//!
+//! Turns finished stretches of an NPC conversation into lasting memories - a
+//! short summary plus notable facts - and picks the memories worth reminding
+//! the NPC of before its next reply.
+//!
+//! Storage lives in the database (`npc_memories`); this module only builds
+//! prompts, parses the LLM's answer, and ranks memories against a message.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::database::{ConversationMessage, NpcMemoryRecord};
+
+/// Unsummarized messages that trigger folding the conversation into memory
+pub const SUMMARIZE_AFTER_MESSAGES: usize = 16;
+
+/// Most recent messages always sent verbatim, even once summarized
+pub const RECENT_MESSAGES: usize = 6;
+
+/// Memories injected into a reply prompt
+pub const MEMORIES_PER_PROMPT: usize = 8;
+
+/// Default importance for a memory with none given
+pub const DEFAULT_IMPORTANCE: i32 = 3;
+
+// ============================================================================
+// Memory Kinds
+// ============================================================================
+
+/// What a memory records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryKind {
+    /// Summary of a stretch of conversation
+    Summary,
+    /// A single notable fact: a promise, a name, a debt, a grudge
+    Fact,
+}
+
+impl MemoryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Summary => "summary",
+            Self::Fact => "fact",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "summary" => Self::Summary,
+            _ => Self::Fact,
+        }
+    }
+}
+
+// ============================================================================
+// Summarization
+// ============================================================================
+
+/// What the LLM extracted from a stretch of conversation
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct MemoryExtraction {
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub facts: Vec<ExtractedFact>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExtractedFact {
+    pub fact: String,
+    #[serde(default = "default_importance")]
+    pub importance: i32,
+}
+
+fn default_importance() -> i32 {
+    DEFAULT_IMPORTANCE
+}
+
+/// Messages that have not yet been folded into memory, once there are
+/// enough of them to be worth summarizing
+pub fn pending_messages(
+    messages: &[ConversationMessage],
+    summarized_count: usize,
+    force: bool,
+) -> Option<&[ConversationMessage]> {
+    let pending = messages.get(summarized_count..).unwrap_or_default();
+    let threshold = if force { 1 } else { SUMMARIZE_AFTER_MESSAGES };
+    (pending.len() >= threshold).then_some(pending)
+}
+
+/// Messages to send the LLM verbatim: everything not yet summarized, and
+/// never fewer than the last [`RECENT_MESSAGES`]
+pub fn context_window(messages: &[ConversationMessage], summarized_count: usize) -> &[ConversationMessage] {
+    let start = summarized_count.min(messages.len().saturating_sub(RECENT_MESSAGES));
+    &messages[start..]
+}
+
+/// Prompt asking the LLM to summarize a stretch of conversation from the
+/// NPC's point of view
+pub fn summarization_prompt(npc_name: &str, messages: &[ConversationMessage]) -> String {
+    let mut prompt = format!(
+        "Below is part of a conversation between the players and {npc_name}, an NPC in a tabletop \
+         roleplaying game. Write what {npc_name} would remember of it.\n\n"
+    );
+    prompt.push_str("### CONVERSATION BEGIN ###\n");
+    for message in messages {
+        let speaker = if message.role == "user" { "Players" } else { npc_name };
+        prompt.push_str(&format!("{}: {}\n", speaker, message.content));
+    }
+    prompt.push_str("### CONVERSATION END ###\n\n");
+    prompt.push_str(
+        r#"Respond with a JSON object:
+{
+  "summary": "two or three sentences, from the NPC's point of view",
+  "facts": [{"fact": "one notable fact: a name, promise, debt, secret shared, or change in attitude", "importance": 1-5}]
+}
+List at most five facts. Skip small talk."#,
+    );
+    prompt
+}
+
+/// Parse the LLM's summarization answer, tolerating prose around the JSON
+pub fn parse_extraction(response: &str) -> Option<MemoryExtraction> {
+    let start = response.find('{')?;
+    let end = response.rfind('}')?;
+    let mut extraction: MemoryExtraction = serde_json::from_str(response.get(start..=end)?).ok()?;
+    extraction.summary = extraction.summary.trim().to_string();
+    extraction.facts.retain(|f| !f.fact.trim().is_empty());
+    for fact in &mut extraction.facts {
+        fact.fact = fact.fact.trim().to_string();
+        fact.importance = fact.importance.clamp(1, 5);
+    }
+    (!extraction.summary.is_empty() || !extraction.facts.is_empty()).then_some(extraction)
+}
+
+// ============================================================================
+// Retrieval
+// ============================================================================
+
+/// Lowercased words worth matching on
+fn keywords(text: &str) -> HashSet<String> {
+    const STOP_WORDS: &[&str] = &[
+        "the", "and", "you", "your", "are", "was", "were", "for", "with", "that", "this", "have",
+        "has", "had", "what", "about", "from", "they", "them", "their", "there", "will", "would",
+        "can", "could", "not", "but", "our", "who", "how", "why", "when", "where",
+    ];
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 2)
+        .map(str::to_lowercase)
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Pick the memories most worth reminding the NPC of before replying to
+/// `query`. Memories sharing words with the message rank first, then by
+/// importance, then by recency. The latest summary is always kept so the
+/// NPC knows where the conversation left off.
+pub fn select_relevant<'a>(
+    memories: &'a [NpcMemoryRecord],
+    query: &str,
+    limit: usize,
+) -> Vec<&'a NpcMemoryRecord> {
+    let query_words = keywords(query);
+    let latest_summary = memories
+        .iter()
+        .filter(|m| MemoryKind::parse(&m.kind) == MemoryKind::Summary)
+        .max_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    let mut ranked: Vec<(usize, &NpcMemoryRecord)> = memories.iter().enumerate().collect();
+    ranked.sort_by_key(|(index, memory)| {
+        let overlap = keywords(&memory.content).intersection(&query_words).count() as i32;
+        std::cmp::Reverse((overlap * 10 + memory.importance, *index))
+    });
+
+    let mut selected: Vec<&NpcMemoryRecord> = Vec::new();
+    if let Some(summary) = latest_summary {
+        selected.push(summary);
+    }
+    for (_, memory) in ranked {
+        if selected.len() >= limit {
+            break;
+        }
+        if !selected.iter().any(|m| m.id == memory.id) {
+            selected.push(memory);
+        }
+    }
+    selected.truncate(limit);
+    selected.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    selected
+}
+
+/// System prompt section reminding the NPC of earlier conversations
+pub fn memory_prompt_section(memories: &[&NpcMemoryRecord]) -> Option<String> {
+    if memories.is_empty() {
+        return None;
+    }
+    let mut section = String::from("### MEMORIES BEGIN ###\n");
+    section.push_str("What you remember from earlier conversations with the players:\n");
+    for memory in memories {
+        section.push_str(&format!("- {}\n", memory.content));
+    }
+    section.push_str("### MEMORIES END ###\n");
+    Some(section)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(kind: MemoryKind, content: &str, importance: i32, created_at: &str) -> NpcMemoryRecord {
+        let mut record = NpcMemoryRecord::new(
+            "npc-1".to_string(),
+            "camp-1".to_string(),
+            kind.as_str(),
+            content.to_string(),
+            importance,
+        );
+        record.created_at = created_at.to_string();
+        record
+    }
+
+    fn message(role: &str, content: &str) -> ConversationMessage {
+        ConversationMessage {
+            id: uuid::Uuid::new_v4().to_string(),
+            role: role.to_string(),
+            content: content.to_string(),
+            parent_message_id: None,
+            created_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_select_relevant_prefers_matching_memories() {
+        let memories = vec![
+            memory(MemoryKind::Summary, "The party asked for work", 3, "2024-01-01"),
+            memory(MemoryKind::Fact, "Mira owes the guild forty gold", 2, "2024-01-02"),
+            memory(MemoryKind::Fact, "The mayor is secretly a vampire", 5, "2024-01-03"),
+            memory(MemoryKind::Summary, "The party returned from the mill", 3, "2024-01-04"),
+            memory(MemoryKind::Fact, "Likes apples", 1, "2024-01-05"),
+        ];
+
+        let selected = select_relevant(&memories, "Does Mira still owe you gold?", 3);
+        let contents: Vec<&str> = selected.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Mira owes the guild forty gold",
+                "The mayor is secretly a vampire",
+                "The party returned from the mill",
+            ]
+        );
+
+        let section = memory_prompt_section(&selected).unwrap();
+        assert!(section.contains("- Mira owes the guild forty gold\n"));
+        assert!(memory_prompt_section(&[]).is_none());
+    }
+
+    #[test]
+    fn test_summarization_window_and_parsing() {
+        let messages: Vec<ConversationMessage> = (0..20)
+            .map(|i| message(if i % 2 == 0 { "user" } else { "assistant" }, &format!("line {}", i)))
+            .collect();
+
+        assert_eq!(pending_messages(&messages, 0, false).map(<[_]>::len), Some(20));
+        assert!(pending_messages(&messages, 10, false).is_none());
+        assert_eq!(pending_messages(&messages, 10, true).map(<[_]>::len), Some(10));
+        assert_eq!(context_window(&messages, 20).len(), RECENT_MESSAGES);
+        assert_eq!(context_window(&messages, 8).len(), 12);
+        assert!(summarization_prompt("Mira", &messages[..2]).contains("Players: line 0\nMira: line 1\n"));
+
+        let response = r#"Sure! {"summary": " They asked about the mill. ",
+            "facts": [{"fact": "Promised to return the ring", "importance": 9}, {"fact": " "}]}"#;
+        let extraction = parse_extraction(response).unwrap();
+        assert_eq!(extraction.summary, "They asked about the mill.");
+        assert_eq!(extraction.facts.len(), 1);
+        assert_eq!(extraction.facts[0].importance, 5);
+        assert!(parse_extraction("no json here").is_none());
+        assert!(parse_extraction(r#"{"summary": "", "facts": []}"#).is_none());
+    }
+}
@@ -16,6 +16,12 @@
 //! - [`names`]: Cultural naming rules and name component models
 //! - [`dialects`]: Dialect transformation engine and rules
 //! - [`generator`]: Core NPC generation logic (legacy, being extended)
+//! - [`stat_block`]: Combat stat blocks built from DMG / PF2e creature-building math
+//! - [`memory`]: Conversation summarization and memory retrieval for NPC chat
+//! - [`name_banks`]: Built-in, custom, and Markov-trained culture name banks
+//! - [`batch`]: Rosters of related NPCs with a shared voice and hierarchy
+//! - [`speech_quirks`]: Per-NPC catchphrases, accent respellings, and word choices
+//! - [`reskin`]: Re-theming stat blocks with their numbers unchanged
 //!
 //! # Example
 //!
@@ -81,6 +87,24 @@ pub mod dialects;
 /// Core NPC generator (legacy implementation).
 mod generator;
 
+/// Combat stat blocks tuned to a challenge rating or creature level.
+pub mod stat_block;
+
+/// Conversation summaries and facts an NPC remembers between chats.
+pub mod memory;
+
+/// Culture name banks and name generation from naming rules.
+pub mod name_banks;
+
+/// Rosters of related NPCs generated together for a faction or location.
+pub mod batch;
+
+/// Per-NPC speech quirks shared by conversation prompts and text-to-speech.
+pub mod speech_quirks;
+
+/// Re-themed stat blocks: new names, damage types, and flavor, same math.
+pub mod reskin;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
@@ -117,6 +141,12 @@ pub use names::{
     NamePattern, NameStructure, PhoneticRule as NamePhoneticRule,
 };
 
+// Name bank types
+pub use name_banks::{
+    builtin_bank, builtin_banks, generate_from_rules, MarkovNameModel, NameBankKind,
+    NameBankRegistry, NameBankSummary, TrainedNameBank,
+};
+
 // Dialect types
 pub use dialects::{
     clear_pattern_cache, DialectDefinition, DialectTransformResult, DialectTransformer,
@@ -131,6 +161,18 @@ pub use generator::{
     PlotHookType, Urgency, VoiceDescription,
 };
 
+// Batch generation
+pub use batch::{NpcBatch, NpcBatchGenerator, NpcBatchOptions, RosterKind, RosterMember, RosterRank};
+
+// Speech quirks
+pub use speech_quirks::{Respelling, SpeechQuirks};
+
+// Stat block generation
+pub use stat_block::{build_stat_block, CombatStyle, StatBlockTarget};
+
+// Stat block reskinning
+pub use reskin::{reskin_stat_block, ReskinTheme};
+
 // ============================================================================
 // Integration Types
 // ============================================================================
@@ -0,0 +1,1140 @@
+//! Culture Name Banks
+//!
+//! Turns [`CulturalNamingRules`] into actual names and keeps the registry of
+//! banks a campaign can draw from:
+//!
+//! - **Built-in banks**: Norse, Arabic-inspired, three Elvish variants, and
+//!   sci-fi, each with its own name structure
+//! - **Custom banks**: naming-rule YAML files dropped into the names directory
+//! - **Trained banks**: character-level Markov chains trained on a list of
+//!   names the user supplies, producing new names with the same flavor
+//!
+//! # Example
+//!
+//! ```ignore
+//! let registry = NameBankRegistry::new();
+//! let mut rng = rand::thread_rng();
+//! let name = registry.generate("norse", Gender::Female, &mut rng)?;
+//! // e.g. "Sigrid Haraldsdottir"
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::RwLock;
+
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::errors::NameGenerationError;
+use super::names::{
+    ComponentType, CulturalNamingRules, Gender, GenderRules, NameComponent, NameStructure,
+};
+
+/// Fewest names a Markov bank can be trained on
+pub const MIN_TRAINING_NAMES: usize = 5;
+
+/// Attempts at a name that fits the culture's length limits
+const MAX_ATTEMPTS: usize = 20;
+
+/// Markers for the start and end of a name in Markov chain keys
+const START: char = '\u{2}';
+const END: char = '\u{3}';
+
+/// Subdirectory of the names directory holding trained banks
+const TRAINED_DIR: &str = "trained";
+
+type NameResult<T> = std::result::Result<T, NameGenerationError>;
+
+// ============================================================================
+// Generation from Naming Rules
+// ============================================================================
+
+/// Generate a name following a culture's naming rules
+pub fn generate_from_rules(
+    rules: &CulturalNamingRules,
+    gender: Gender,
+    rng: &mut impl Rng,
+) -> NameResult<String> {
+    let mut name = String::new();
+    for _ in 0..MAX_ATTEMPTS {
+        name = compose(rules, gender, rng)?;
+        let len = name.chars().count();
+        if rules.min_length.is_none_or(|min| len >= min)
+            && rules.max_length.is_none_or(|max| len <= max)
+        {
+            break;
+        }
+    }
+    Ok(name)
+}
+
+fn compose(rules: &CulturalNamingRules, gender: Gender, rng: &mut impl Rng) -> NameResult<String> {
+    Ok(match rules.random_structure(rng) {
+        NameStructure::GivenFamily => {
+            format!("{} {}", given(rules, gender, rng)?, family(rules, rng)?)
+        }
+        NameStructure::FamilyGiven => {
+            format!("{} {}", family(rules, rng)?, given(rules, gender, rng)?)
+        }
+        NameStructure::GivenEpithet => format!(
+            "{} {}",
+            given(rules, gender, rng)?,
+            pick(rules, ComponentType::Epithet, Some(gender), rng)?
+        ),
+        NameStructure::ClanDescriptor => format!(
+            "{} {}",
+            given(rules, gender, rng)?,
+            pick(rules, ComponentType::Clan, None, rng).or_else(|_| pick(
+                rules,
+                ComponentType::Epithet,
+                Some(gender),
+                rng
+            ))?
+        ),
+        NameStructure::PrefixRootSuffix => synthesize(rules, rng)?,
+        NameStructure::Patronymic => format!(
+            "{} {}",
+            given(rules, gender, rng)?,
+            lineage(rules, Gender::Male, gender, rng)?
+        ),
+        NameStructure::Matronymic => format!(
+            "{} {}",
+            given(rules, gender, rng)?,
+            lineage(rules, Gender::Female, gender, rng)?
+        ),
+        NameStructure::SingleName => given(rules, gender, rng)?,
+        NameStructure::TitleBased => format!(
+            "{} {}",
+            pick(rules, ComponentType::Title, Some(gender), rng)?,
+            family(rules, rng).or_else(|_| pick(
+                rules,
+                ComponentType::Epithet,
+                Some(gender),
+                rng
+            ))?
+        ),
+    })
+}
+
+/// A weighted random component of one type
+fn pick(
+    rules: &CulturalNamingRules,
+    component_type: ComponentType,
+    gender: Option<Gender>,
+    rng: &mut impl Rng,
+) -> NameResult<String> {
+    rules
+        .components
+        .select_random(component_type, gender.as_ref(), rng)
+        .map(|c| c.text.clone())
+        .ok_or_else(|| NameGenerationError::ComponentNotAvailable {
+            culture: rules.culture_id.clone(),
+            component_type: format!("{:?}", component_type).to_lowercase(),
+        })
+}
+
+/// A given name from the bank, or built from syllables when the culture has
+/// no whole given names
+fn given(rules: &CulturalNamingRules, gender: Gender, rng: &mut impl Rng) -> NameResult<String> {
+    if rules.components.has_type(ComponentType::Given) {
+        pick(rules, ComponentType::Given, Some(gender), rng)
+    } else {
+        synthesize(rules, rng)
+    }
+}
+
+fn family(rules: &CulturalNamingRules, rng: &mut impl Rng) -> NameResult<String> {
+    pick(rules, ComponentType::Family, None, rng)
+        .or_else(|_| pick(rules, ComponentType::Clan, None, rng))
+}
+
+/// Prefix, an optional root, and a suffix, respecting phonetic rules
+fn synthesize(rules: &CulturalNamingRules, rng: &mut impl Rng) -> NameResult<String> {
+    let components = &rules.components;
+    let missing = |component_type: &str| NameGenerationError::ComponentNotAvailable {
+        culture: rules.culture_id.clone(),
+        component_type: component_type.to_string(),
+    };
+    let mut parts: Vec<&NameComponent> = Vec::new();
+    for _ in 0..MAX_ATTEMPTS {
+        parts.clear();
+        parts.push(
+            components
+                .select_random(ComponentType::Prefix, None, rng)
+                .ok_or_else(|| missing("prefix"))?,
+        );
+        if rng.gen_bool(0.5) {
+            parts.extend(components.select_random(ComponentType::Root, None, rng));
+        }
+        parts.extend(components.select_random(ComponentType::Suffix, None, rng));
+        if parts
+            .windows(2)
+            .all(|pair| rules.is_compatible(pair[0], pair[1]))
+        {
+            break;
+        }
+    }
+    Ok(capitalize(
+        &parts.iter().map(|c| c.text.as_str()).collect::<String>(),
+    ))
+}
+
+/// "Haraldsdottir" from a parent's given name and the culture's gendered
+/// suffix
+fn lineage(
+    rules: &CulturalNamingRules,
+    parent_gender: Gender,
+    gender: Gender,
+    rng: &mut impl Rng,
+) -> NameResult<String> {
+    let parent = given(rules, parent_gender, rng)?;
+    let fallback = match gender {
+        Gender::Female => "daughter",
+        _ => "son",
+    };
+    let suffix = rules.gender_rules.get_suffix(&gender).unwrap_or(fallback);
+    if parent.ends_with('s') && suffix.starts_with('s') {
+        Ok(format!("{}{}", parent, &suffix[1..]))
+    } else {
+        Ok(format!("{}{}", parent, suffix))
+    }
+}
+
+/// Uppercase the first letter and any letter after a space, hyphen, or
+/// apostrophe
+fn capitalize(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = true;
+    for c in name.chars() {
+        if upper {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
+        }
+        upper = matches!(c, ' ' | '-' | '\'');
+    }
+    out
+}
+
+// ============================================================================
+// Markov Chain Names
+// ============================================================================
+
+/// Character-level Markov chain over a list of names
+#[derive(Debug, Clone)]
+pub struct MarkovNameModel {
+    order: usize,
+    transitions: HashMap<String, Vec<(char, u32)>>,
+    min_length: usize,
+    max_length: usize,
+    training: HashSet<String>,
+}
+
+impl MarkovNameModel {
+    /// Train a model where each letter depends on the `order` letters before
+    /// it (clamped to 1-4). Higher orders stay closer to the source names.
+    pub fn train(names: &[String], order: usize) -> NameResult<Self> {
+        let order = order.clamp(1, 4);
+        let names: Vec<String> = names
+            .iter()
+            .map(|n| n.trim().to_lowercase())
+            .filter(|n| !n.is_empty())
+            .collect();
+        if names.len() < MIN_TRAINING_NAMES {
+            return Err(NameGenerationError::ConstraintUnsatisfiable {
+                culture: "markov".to_string(),
+                reason: format!(
+                    "need at least {} names to train on, got {}",
+                    MIN_TRAINING_NAMES,
+                    names.len()
+                ),
+            });
+        }
+
+        let mut counts: HashMap<String, HashMap<char, u32>> = HashMap::new();
+        for name in &names {
+            let padded: Vec<char> = std::iter::repeat_n(START, order)
+                .chain(name.chars())
+                .chain(std::iter::once(END))
+                .collect();
+            for window in padded.windows(order + 1) {
+                let key: String = window[..order].iter().collect();
+                *counts
+                    .entry(key)
+                    .or_default()
+                    .entry(window[order])
+                    .or_insert(0) += 1;
+            }
+        }
+        let transitions = counts
+            .into_iter()
+            .map(|(key, next)| {
+                let mut next: Vec<(char, u32)> = next.into_iter().collect();
+                next.sort();
+                (key, next)
+            })
+            .collect();
+
+        let lengths = names.iter().map(|n| n.chars().count());
+        Ok(Self {
+            order,
+            transitions,
+            min_length: lengths.clone().min().unwrap_or(1).max(2),
+            max_length: lengths.max().unwrap_or(12),
+            training: names.into_iter().collect(),
+        })
+    }
+
+    /// A new name in the style of the training list. Prefers names not in
+    /// the list, but falls back to one of them for tiny or rigid lists.
+    pub fn generate(&self, rng: &mut impl Rng) -> Option<String> {
+        let mut fallback = None;
+        for _ in 0..MAX_ATTEMPTS * 5 {
+            let Some(name) = self.walk(rng) else {
+                continue;
+            };
+            if !self.training.contains(&name) {
+                return Some(capitalize(&name));
+            }
+            fallback.get_or_insert(name);
+        }
+        fallback.map(|name| capitalize(&name))
+    }
+
+    fn walk(&self, rng: &mut impl Rng) -> Option<String> {
+        let mut key: Vec<char> = vec![START; self.order];
+        let mut name = String::new();
+        loop {
+            let options = self.transitions.get(&key.iter().collect::<String>())?;
+            let total: u32 = options.iter().map(|(_, count)| count).sum();
+            let mut choice = rng.gen_range(0..total);
+            let next = options
+                .iter()
+                .find(|(_, count)| {
+                    if choice < *count {
+                        true
+                    } else {
+                        choice -= count;
+                        false
+                    }
+                })
+                .map(|(c, _)| *c)?;
+            if next == END {
+                break;
+            }
+            name.push(next);
+            if name.chars().count() > self.max_length {
+                return None;
+            }
+            key.remove(0);
+            key.push(next);
+        }
+        (name.chars().count() >= self.min_length).then_some(name)
+    }
+}
+
+/// A bank trained from names the user supplied, saved so it can be
+/// retrained on load
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainedNameBank {
+    pub culture_id: String,
+    #[serde(default)]
+    pub culture_name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Letters of context per step (1-4)
+    #[serde(default = "default_order")]
+    pub order: usize,
+    /// Given names to learn from
+    pub names: Vec<String>,
+    /// Family names, learned from as well when there are enough, otherwise
+    /// picked as-is
+    #[serde(default)]
+    pub family_names: Vec<String>,
+}
+
+fn default_order() -> usize {
+    3
+}
+
+struct TrainedModels {
+    bank: TrainedNameBank,
+    given: MarkovNameModel,
+    family: Option<MarkovNameModel>,
+}
+
+impl TrainedModels {
+    fn new(bank: TrainedNameBank) -> NameResult<Self> {
+        let given = MarkovNameModel::train(&bank.names, bank.order).map_err(|_| {
+            NameGenerationError::ConstraintUnsatisfiable {
+                culture: bank.culture_id.clone(),
+                reason: format!(
+                    "need at least {} given names to train on",
+                    MIN_TRAINING_NAMES
+                ),
+            }
+        })?;
+        let family = MarkovNameModel::train(&bank.family_names, bank.order).ok();
+        Ok(Self {
+            bank,
+            given,
+            family,
+        })
+    }
+
+    fn generate(&self, rng: &mut impl Rng) -> NameResult<String> {
+        let given = self.given.generate(rng).ok_or_else(|| {
+            NameGenerationError::ConstraintUnsatisfiable {
+                culture: self.bank.culture_id.clone(),
+                reason: "training names produced no usable chain".to_string(),
+            }
+        })?;
+        let family = match &self.family {
+            Some(model) => model.generate(rng),
+            None => self
+                .bank
+                .family_names
+                .choose(rng)
+                .map(|f| f.trim().to_string()),
+        };
+        Ok(match family {
+            Some(family) if !family.is_empty() => format!("{} {}", given, family),
+            _ => given,
+        })
+    }
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Where a name bank came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NameBankKind {
+    Builtin,
+    Custom,
+    Trained,
+}
+
+/// A name bank as listed to the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameBankSummary {
+    pub culture_id: String,
+    pub culture_name: String,
+    pub description: String,
+    pub kind: NameBankKind,
+    pub tags: Vec<String>,
+}
+
+enum NameBank {
+    Rules(Box<CulturalNamingRules>, NameBankKind),
+    Trained(Box<TrainedModels>),
+}
+
+impl NameBank {
+    fn summary(&self) -> NameBankSummary {
+        match self {
+            Self::Rules(rules, kind) => NameBankSummary {
+                culture_id: rules.culture_id.clone(),
+                culture_name: rules.culture_name.clone(),
+                description: rules.description.clone(),
+                kind: *kind,
+                tags: rules.tags.clone(),
+            },
+            Self::Trained(models) => NameBankSummary {
+                culture_id: models.bank.culture_id.clone(),
+                culture_name: models.bank.culture_name.clone(),
+                description: models.bank.description.clone(),
+                kind: NameBankKind::Trained,
+                tags: vec!["trained".to_string()],
+            },
+        }
+    }
+}
+
+/// All name banks available for generation, keyed by culture ID
+pub struct NameBankRegistry {
+    banks: RwLock<HashMap<String, NameBank>>,
+}
+
+impl Default for NameBankRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NameBankRegistry {
+    /// A registry holding the built-in banks
+    pub fn new() -> Self {
+        let banks = builtin_banks()
+            .into_iter()
+            .map(|rules| {
+                (
+                    rules.culture_id.clone(),
+                    NameBank::Rules(Box::new(rules), NameBankKind::Builtin),
+                )
+            })
+            .collect();
+        Self {
+            banks: RwLock::new(banks),
+        }
+    }
+
+    /// Load custom rule files (`*.yaml`) and trained banks (`trained/*.yaml`)
+    /// from the names directory. Files that fail to parse are skipped.
+    /// Returns how many banks were loaded.
+    pub fn load_dir(&self, dir: &Path) -> usize {
+        let mut loaded = 0;
+        for path in yaml_files(dir) {
+            match std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    serde_yaml_ng::from_str::<CulturalNamingRules>(&s).map_err(|e| e.to_string())
+                }) {
+                Ok(rules) => match self.add_rules(rules) {
+                    Ok(()) => loaded += 1,
+                    Err(e) => log::warn!("Skipping naming rules {}: {}", path.display(), e),
+                },
+                Err(e) => log::warn!("Failed to load naming rules {}: {}", path.display(), e),
+            }
+        }
+        for path in yaml_files(&dir.join(TRAINED_DIR)) {
+            match std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    serde_yaml_ng::from_str::<TrainedNameBank>(&s).map_err(|e| e.to_string())
+                }) {
+                Ok(bank) => match self.add_trained(bank) {
+                    Ok(_) => loaded += 1,
+                    Err(e) => log::warn!("Skipping trained name bank {}: {}", path.display(), e),
+                },
+                Err(e) => log::warn!("Failed to load trained name bank {}: {}", path.display(), e),
+            }
+        }
+        loaded
+    }
+
+    /// Add or replace a custom bank from naming rules
+    pub fn add_rules(&self, rules: CulturalNamingRules) -> NameResult<()> {
+        rules.validate()?;
+        self.banks.write().unwrap().insert(
+            rules.culture_id.clone(),
+            NameBank::Rules(Box::new(rules), NameBankKind::Custom),
+        );
+        Ok(())
+    }
+
+    /// Train and add or replace a Markov bank
+    pub fn add_trained(&self, bank: TrainedNameBank) -> NameResult<NameBankSummary> {
+        if bank.culture_id.is_empty() {
+            return Err(NameGenerationError::InvalidPattern {
+                culture: bank.culture_id,
+                pattern: "culture_id".to_string(),
+                reason: "Culture ID cannot be empty".to_string(),
+            });
+        }
+        let entry = NameBank::Trained(Box::new(TrainedModels::new(bank)?));
+        let summary = entry.summary();
+        self.banks
+            .write()
+            .unwrap()
+            .insert(summary.culture_id.clone(), entry);
+        Ok(summary)
+    }
+
+    /// Remove a custom or trained bank. Built-in banks stay.
+    pub fn remove(&self, culture_id: &str) -> NameResult<NameBankKind> {
+        let mut banks = self.banks.write().unwrap();
+        match banks.get(culture_id).map(|b| b.summary().kind) {
+            None => Err(NameGenerationError::CultureNotFound {
+                culture: culture_id.to_string(),
+            }),
+            Some(NameBankKind::Builtin) => Err(NameGenerationError::InvalidPattern {
+                culture: culture_id.to_string(),
+                pattern: "culture_id".to_string(),
+                reason: "Built-in name banks cannot be removed".to_string(),
+            }),
+            Some(kind) => {
+                banks.remove(culture_id);
+                Ok(kind)
+            }
+        }
+    }
+
+    pub fn contains(&self, culture_id: &str) -> bool {
+        self.banks.read().unwrap().contains_key(culture_id)
+    }
+
+    /// Naming rules of a custom (non-built-in) bank
+    pub fn custom_rules(&self, culture_id: &str) -> Option<CulturalNamingRules> {
+        match self.banks.read().unwrap().get(culture_id) {
+            Some(NameBank::Rules(rules, NameBankKind::Custom)) => Some(rules.as_ref().clone()),
+            _ => None,
+        }
+    }
+
+    /// Names a trained bank was trained from
+    pub fn trained_bank(&self, culture_id: &str) -> Option<TrainedNameBank> {
+        match self.banks.read().unwrap().get(culture_id) {
+            Some(NameBank::Trained(models)) => Some(models.bank.clone()),
+            _ => None,
+        }
+    }
+
+    /// All banks, sorted by culture name
+    pub fn list(&self) -> Vec<NameBankSummary> {
+        let mut summaries: Vec<NameBankSummary> = self
+            .banks
+            .read()
+            .unwrap()
+            .values()
+            .map(NameBank::summary)
+            .collect();
+        summaries.sort_by(|a, b| a.culture_name.cmp(&b.culture_name));
+        summaries
+    }
+
+    /// Generate a name from a culture's bank
+    pub fn generate(
+        &self,
+        culture_id: &str,
+        gender: Gender,
+        rng: &mut impl Rng,
+    ) -> NameResult<String> {
+        match self.banks.read().unwrap().get(culture_id) {
+            Some(NameBank::Rules(rules, _)) => generate_from_rules(rules, gender, rng),
+            Some(NameBank::Trained(models)) => models.generate(rng),
+            None => Err(NameGenerationError::CultureNotFound {
+                culture: culture_id.to_string(),
+            }),
+        }
+    }
+}
+
+/// Path a trained bank is saved to under the names directory
+pub fn trained_bank_path(names_dir: &Path, culture_id: &str) -> std::path::PathBuf {
+    names_dir
+        .join(TRAINED_DIR)
+        .join(format!("{}.yaml", culture_id))
+}
+
+fn yaml_files(dir: &Path) -> Vec<std::path::PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.is_file() && matches!(p.extension().and_then(|e| e.to_str()), Some("yaml" | "yml"))
+        })
+        .collect()
+}
+
+// ============================================================================
+// Built-in Banks
+// ============================================================================
+
+/// Culture IDs of the built-in banks
+pub const BUILTIN_CULTURES: &[&str] = &[
+    "norse",
+    "arabic_inspired",
+    "high_elf",
+    "wood_elf",
+    "dark_elf",
+    "sci_fi",
+];
+
+fn add_all(
+    rules: &mut CulturalNamingRules,
+    component_type: ComponentType,
+    gender: Gender,
+    texts: &[&str],
+) {
+    for text in texts {
+        rules
+            .components
+            .add(NameComponent::new(*text, component_type).with_gender(gender));
+    }
+}
+
+fn bank(
+    id: &str,
+    name: &str,
+    description: &str,
+    structure: NameStructure,
+    tags: &[&str],
+) -> CulturalNamingRules {
+    let mut rules = CulturalNamingRules::new(id)
+        .with_name(name)
+        .with_structure(structure);
+    rules.description = description.to_string();
+    rules.tags = tags.iter().map(|t| t.to_string()).collect();
+    rules
+}
+
+/// The name banks that ship with the app
+pub fn builtin_banks() -> Vec<CulturalNamingRules> {
+    vec![
+        norse(),
+        arabic_inspired(),
+        high_elf(),
+        wood_elf(),
+        dark_elf(),
+        sci_fi(),
+    ]
+}
+
+/// A single built-in bank by culture ID
+pub fn builtin_bank(culture_id: &str) -> Option<CulturalNamingRules> {
+    builtin_banks()
+        .into_iter()
+        .find(|rules| rules.culture_id == culture_id)
+}
+
+fn norse() -> CulturalNamingRules {
+    let mut rules = bank(
+        "norse",
+        "Norse",
+        "Old Norse given names with patronymics, matronymics, or a byname",
+        NameStructure::Patronymic,
+        &["historical", "nordic", "fantasy"],
+    )
+    .add_alternative_structure(NameStructure::Matronymic)
+    .add_alternative_structure(NameStructure::GivenEpithet);
+    rules.gender_rules = GenderRules::new().with_gendered_suffixes("sson", "sdottir");
+    rules.gender_rules.neutral_suffix = Some("sbarn".to_string());
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Male,
+        &[
+            "Arne", "Bjorn", "Eirik", "Gunnar", "Halfdan", "Harald", "Ingvar", "Ivar", "Knut",
+            "Leif", "Olaf", "Ragnar", "Sigurd", "Sten", "Thorvald", "Ulf", "Vidar", "Hakon", "Orm",
+            "Torstein",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Female,
+        &[
+            "Astrid", "Asa", "Bergljot", "Freydis", "Gudrun", "Gunnhild", "Helga", "Hild",
+            "Ingrid", "Ragnhild", "Sigrid", "Solveig", "Thora", "Thyra", "Ylva", "Aslaug",
+            "Estrid", "Ragna", "Tove", "Vigdis",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Epithet,
+        Gender::Neutral,
+        &[
+            "the Red",
+            "the Tall",
+            "the Unready",
+            "Ironside",
+            "Forkbeard",
+            "the Boneless",
+            "Bluetooth",
+            "the Far-Traveled",
+            "Snake-Eye",
+            "the Black",
+            "the Peacemaker",
+            "Skull-Splitter",
+            "the Silent",
+        ],
+    );
+    rules
+}
+
+fn arabic_inspired() -> CulturalNamingRules {
+    let mut rules = bank(
+        "arabic_inspired",
+        "Arabic-Inspired",
+        "Given names with a nisba family name, or a title for notables",
+        NameStructure::GivenFamily,
+        &["desert", "fantasy"],
+    )
+    .add_alternative_structure(NameStructure::TitleBased);
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Male,
+        &[
+            "Amir", "Bashir", "Faris", "Hakim", "Idris", "Jamal", "Khalid", "Malik", "Nasir",
+            "Omar", "Qasim", "Rashid", "Samir", "Tariq", "Yusuf", "Zayd", "Harun", "Karim",
+            "Mahir", "Walid",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Female,
+        &[
+            "Amira", "Dalia", "Farah", "Hana", "Jamila", "Layla", "Leena", "Mariam", "Nadia",
+            "Noor", "Rania", "Salma", "Samira", "Yasmin", "Zahra", "Zaynab", "Aisha", "Huda",
+            "Lubna", "Safiya",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Family,
+        Gender::Neutral,
+        &[
+            "al-Basri",
+            "al-Dimashqi",
+            "al-Farsi",
+            "al-Hakim",
+            "al-Jazari",
+            "al-Khatib",
+            "al-Masri",
+            "al-Qasimi",
+            "al-Rashid",
+            "al-Sayegh",
+            "al-Tamimi",
+            "al-Zahir",
+            "al-Haddad",
+            "al-Najjar",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Title,
+        Gender::Male,
+        &["Emir", "Sheikh", "Vizier", "Qadi", "Sayyid"],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Title,
+        Gender::Female,
+        &["Emira", "Sheikha", "Sayyida"],
+    );
+    rules
+}
+
+fn high_elf() -> CulturalNamingRules {
+    let mut rules = bank(
+        "high_elf",
+        "High Elvish",
+        "Flowing synthetic names of an old courtly tongue, with house names",
+        NameStructure::PrefixRootSuffix,
+        &["elvish", "fantasy"],
+    )
+    .add_alternative_structure(NameStructure::GivenFamily);
+    rules.max_length = Some(24);
+    add_all(
+        &mut rules,
+        ComponentType::Prefix,
+        Gender::Neutral,
+        &[
+            "Ael", "Cael", "Elar", "Fael", "Gal", "Ithil", "Lael", "Mir", "Nai", "Quel", "Sael",
+            "Thal", "Vael",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Root,
+        Gender::Neutral,
+        &["an", "en", "ia", "il", "ir", "or", "ya", "ri"],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Suffix,
+        Gender::Neutral,
+        &[
+            "dor", "driel", "las", "lion", "mir", "nor", "ras", "thien", "wen", "wyn", "riel",
+            "ion",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Family,
+        Gender::Neutral,
+        &[
+            "Aelorien",
+            "Caelithar",
+            "Elanthas",
+            "Ilvareth",
+            "Miritheon",
+            "Naeloris",
+            "Quelvanar",
+            "Silvanthir",
+            "Thalorien",
+            "Vaelistra",
+        ],
+    );
+    rules
+}
+
+fn wood_elf() -> CulturalNamingRules {
+    let mut rules = bank(
+        "wood_elf",
+        "Wood Elvish",
+        "Short forest names followed by a nature byname",
+        NameStructure::GivenEpithet,
+        &["elvish", "fantasy", "wilderness"],
+    )
+    .add_alternative_structure(NameStructure::SingleName);
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Male,
+        &[
+            "Aerun", "Beiro", "Elrin", "Faen", "Hadrin", "Ivel", "Lorin", "Riel", "Soveth",
+            "Taeral",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Female,
+        &[
+            "Adrie", "Bryn", "Caelia", "Enna", "Ilsa", "Lia", "Meriel", "Nessa", "Shava", "Tessiel",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Epithet,
+        Gender::Neutral,
+        &[
+            "Leafwhisper",
+            "Mossfoot",
+            "Thornheart",
+            "Brightbough",
+            "Fernshadow",
+            "Oakenveil",
+            "of the Greenwood",
+            "Swiftstream",
+            "Ashbark",
+            "Windsong",
+            "Dewglimmer",
+            "Hollowbranch",
+        ],
+    );
+    rules
+}
+
+fn dark_elf() -> CulturalNamingRules {
+    let mut rules = bank(
+        "dark_elf",
+        "Dark Elvish",
+        "Sharp, apostrophe-broken names with a noble house",
+        NameStructure::GivenFamily,
+        &["elvish", "fantasy", "underdark"],
+    )
+    .add_alternative_structure(NameStructure::TitleBased);
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Male,
+        &[
+            "Dren'thal",
+            "Ilzar",
+            "Kel'ryn",
+            "Mazzok",
+            "Nalfein",
+            "Ryld",
+            "Szorak",
+            "Vel'drin",
+            "Xundar",
+            "Zaknar",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Female,
+        &[
+            "Ilvaera", "Jhaelith", "Kyr'nae", "Lirdra", "Malvessa", "Nyx'ara", "Quave", "Shi'lara",
+            "Viconia", "Zesstra",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Family,
+        Gender::Neutral,
+        &[
+            "Auvrath",
+            "Duskryn",
+            "Helviiryn",
+            "Kilsek",
+            "Mizzrym",
+            "Szornath",
+            "Vrinn",
+            "Xorlarrin",
+            "Zauvir",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Title,
+        Gender::Male,
+        &["Patron", "Weaponmaster"],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Title,
+        Gender::Female,
+        &["Matron", "High Priestess"],
+    );
+    rules
+}
+
+fn sci_fi() -> CulturalNamingRules {
+    let mut rules = bank(
+        "sci_fi",
+        "Sci-Fi",
+        "Clipped spacefaring names with colony family names or call signs",
+        NameStructure::GivenFamily,
+        &["science fiction", "space"],
+    )
+    .add_alternative_structure(NameStructure::SingleName)
+    .add_alternative_structure(NameStructure::GivenEpithet);
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Male,
+        &[
+            "Axon", "Cade", "Dax", "Jarek", "Kasimir", "Orrin", "Rook", "Soren", "Teo", "Vance",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Female,
+        &[
+            "Anya", "Cyra", "Ilse", "Juno", "Kaia", "Lyra", "Mira", "Nova", "Rhea", "Vesna",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Given,
+        Gender::Neutral,
+        &["Ash", "Echo", "Kit", "Quill", "Sol", "Zephyr"],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Family,
+        Gender::Neutral,
+        &[
+            "Achebe-Lin",
+            "Castellanos",
+            "Halvorsen",
+            "Ikeda",
+            "Kovacs",
+            "Mbeki",
+            "Okafor",
+            "Petrov-Reyes",
+            "Tanaka",
+            "Vasquez",
+            "Zhao",
+            "Nakamura-Singh",
+        ],
+    );
+    add_all(
+        &mut rules,
+        ComponentType::Epithet,
+        Gender::Neutral,
+        &[
+            "\"Ghost\"",
+            "\"Static\"",
+            "\"Redline\"",
+            "\"Vacuum\"",
+            "\"Patch\"",
+            "\"Drift\"",
+            "\"Blackbox\"",
+        ],
+    );
+    rules
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_builtin_banks_generate_names() {
+        let registry = NameBankRegistry::new();
+        let mut rng = StdRng::seed_from_u64(11);
+        assert_eq!(registry.list().len(), BUILTIN_CULTURES.len());
+
+        for culture in BUILTIN_CULTURES {
+            let rules = builtin_bank(culture).unwrap();
+            assert!(rules.validate().is_ok(), "{} fails validation", culture);
+            for _ in 0..25 {
+                let name = registry
+                    .generate(culture, Gender::Female, &mut rng)
+                    .unwrap();
+                assert!(
+                    !name.trim().is_empty(),
+                    "{} generated an empty name",
+                    culture
+                );
+            }
+        }
+
+        let mut norse = builtin_bank("norse").unwrap();
+        norse.alternative_structures.clear();
+        let name = generate_from_rules(&norse, Gender::Female, &mut rng).unwrap();
+        assert!(
+            name.ends_with("dottir") && !name.contains("ssdottir"),
+            "{}",
+            name
+        );
+        assert!(matches!(
+            registry.generate("klingon", Gender::Any, &mut rng),
+            Err(NameGenerationError::CultureNotFound { .. })
+        ));
+        assert!(registry.remove("norse").is_err());
+    }
+
+    #[test]
+    fn test_markov_bank_learns_style() {
+        let names: Vec<String> = [
+            "Thalindra",
+            "Thalorin",
+            "Thalvessa",
+            "Melindra",
+            "Melorin",
+            "Calindra",
+            "Calvessa",
+            "Orindra",
+        ]
+        .iter()
+        .map(|n| n.to_string())
+        .collect();
+        assert!(MarkovNameModel::train(&names[..3], 2).is_err());
+
+        let model = MarkovNameModel::train(&names, 2).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let name = model.generate(&mut rng).unwrap();
+            assert!(name.chars().next().unwrap().is_uppercase(), "{}", name);
+            assert!((4..=9).contains(&name.chars().count()), "{}", name);
+        }
+
+        let registry = NameBankRegistry::new();
+        let summary = registry
+            .add_trained(TrainedNameBank {
+                culture_id: "sea_elves".to_string(),
+                culture_name: "Sea Elves".to_string(),
+                description: String::new(),
+                order: 2,
+                names,
+                family_names: vec!["Wavecrest".to_string()],
+            })
+            .unwrap();
+        assert_eq!(summary.kind, NameBankKind::Trained);
+        let name = registry
+            .generate("sea_elves", Gender::Any, &mut rng)
+            .unwrap();
+        assert!(name.ends_with(" Wavecrest"), "{}", name);
+        assert_eq!(registry.remove("sea_elves").unwrap(), NameBankKind::Trained);
+        assert!(!registry.contains("sea_elves"));
+    }
+}
@@ -0,0 +1,407 @@
+//! Stat Block Reskinning
+//!
+//! Re-themes an existing stat block, e.g. "make this bandit captain a
+//! frost-themed cultist": a new name and creature type, element-flavored
+//! attack names, new damage types, and a line of flavor. Every number
+//! carries over unchanged (armor class, hit points, attack bonuses, damage
+//! dice, saves, and challenge rating), so the reskinned creature is exactly
+//! as dangerous as the original.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ingestion::ttrpg::{Feature, StatBlockData};
+
+/// Damage types a stat block can deal
+const DAMAGE_TYPES: [&str; 13] = [
+    "acid", "bludgeoning", "cold", "fire", "force", "lightning", "necrotic", "piercing", "poison", "psychic", "radiant",
+    "slashing", "thunder",
+];
+
+/// Damage types that come from weapons rather than an element
+const PHYSICAL_TYPES: [&str; 3] = ["bludgeoning", "piercing", "slashing"];
+
+/// Creature types a theme can name
+const CREATURE_TYPES: [&str; 14] = [
+    "aberration", "beast", "celestial", "construct", "dragon", "elemental", "fey", "fiend", "giant", "humanoid",
+    "monstrosity", "ooze", "plant", "undead",
+];
+
+/// Words in a theme that describe it rather than name the creature
+const FILLER_WORDS: [&str; 10] = ["a", "an", "the", "themed", "style", "styled", "flavored", "version", "of", "with"];
+
+// ============================================================================
+// Elements
+// ============================================================================
+
+/// A damage element a theme can call for
+struct Element {
+    damage_type: &'static str,
+    adjective: &'static str,
+    keywords: &'static [&'static str],
+    flavor: &'static str,
+}
+
+const ELEMENTS: [Element; 10] = [
+    Element {
+        damage_type: "cold",
+        adjective: "Frost",
+        keywords: &["frost", "ice", "icy", "cold", "winter", "snow", "rime", "glacial", "frozen"],
+        flavor: "Frost rimes its gear, and its breath hangs in the air as mist.",
+    },
+    Element {
+        damage_type: "fire",
+        adjective: "Flame",
+        keywords: &["fire", "flame", "ember", "burning", "infernal", "ash", "magma", "lava"],
+        flavor: "Embers drift from it, and the air around it shimmers with heat.",
+    },
+    Element {
+        damage_type: "lightning",
+        adjective: "Storm",
+        keywords: &["storm", "lightning", "shock", "spark", "tempest"],
+        flavor: "Sparks crawl over its skin, and its hair stands on end.",
+    },
+    Element {
+        damage_type: "thunder",
+        adjective: "Thunder",
+        keywords: &["thunder", "sonic", "booming", "echo"],
+        flavor: "Its footfalls boom, and its voice rattles loose stones.",
+    },
+    Element {
+        damage_type: "acid",
+        adjective: "Acid",
+        keywords: &["acid", "corrosive", "caustic", "ooze", "slime"],
+        flavor: "Its gear is pitted and dripping, and it smells of bile.",
+    },
+    Element {
+        damage_type: "poison",
+        adjective: "Venom",
+        keywords: &["poison", "venom", "venomous", "toxic", "plague", "blight", "swamp"],
+        flavor: "A sickly green sheen coats its weapons, and flies follow it.",
+    },
+    Element {
+        damage_type: "necrotic",
+        adjective: "Grave",
+        keywords: &["shadow", "necrotic", "grave", "death", "undead", "rot", "blood", "bone", "ghost"],
+        flavor: "Shadows cling to it, and plants wither where it stands.",
+    },
+    Element {
+        damage_type: "radiant",
+        adjective: "Radiant",
+        keywords: &["radiant", "holy", "sun", "celestial", "divine", "light", "dawn"],
+        flavor: "A soft light rises from it, and its eyes shine like polished gold.",
+    },
+    Element {
+        damage_type: "psychic",
+        adjective: "Mind",
+        keywords: &["psychic", "mind", "dream", "psionic", "madness", "nightmare"],
+        flavor: "Whispers follow it, and onlookers catch half-remembered dreams.",
+    },
+    Element {
+        damage_type: "force",
+        adjective: "Arcane",
+        keywords: &["arcane", "force", "eldritch", "astral", "runic"],
+        flavor: "Glowing runes drift around it and trace its movements.",
+    },
+];
+
+fn element_for_type(damage_type: &str) -> Option<&'static Element> {
+    ELEMENTS.iter().find(|e| e.damage_type.eq_ignore_ascii_case(damage_type))
+}
+
+// ============================================================================
+// Themes
+// ============================================================================
+
+/// What to change when reskinning a stat block
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReskinTheme {
+    /// The creature's new name
+    pub name: Option<String>,
+    /// Damage type the creature deals instead of its own ("cold")
+    pub damage_type: Option<String>,
+    /// New creature type ("undead"), replacing the old one
+    pub creature_type: Option<String>,
+    /// Word put in front of its attack names ("Frost")
+    pub adjective: Option<String>,
+    /// A line describing the new look, added as a trait
+    pub flavor: Option<String>,
+}
+
+impl ReskinTheme {
+    /// Read a theme from a description like "frost-themed cultist" or
+    /// "make this bandit captain a frost-themed cultist". The element comes
+    /// from words like "frost" or "infernal", the creature type from words
+    /// like "undead", and the name from the rest of the description.
+    pub fn parse(description: &str) -> Self {
+        let lower = format!(" {} ", description.trim().to_lowercase());
+        // "make this X a Y" describes Y
+        let theme = [" into ", " as ", " a ", " an "]
+            .iter()
+            .filter_map(|marker| lower.rfind(marker).map(|i| i + marker.len()))
+            .max()
+            .map_or(lower.as_str(), |start| &lower[start..]);
+        let words: Vec<&str> = theme
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let element = words.iter().find_map(|w| ELEMENTS.iter().find(|e| e.keywords.contains(w)));
+        let creature_type = words.iter().find(|w| CREATURE_TYPES.contains(w)).map(|w| w.to_string());
+        let name_words: Vec<String> = words
+            .iter()
+            .filter(|w| !FILLER_WORDS.contains(w))
+            .map(|w| title_case(w))
+            .collect();
+
+        Self {
+            name: (!name_words.is_empty()).then(|| name_words.join(" ")),
+            damage_type: element.map(|e| e.damage_type.to_string()),
+            creature_type,
+            adjective: element.map(|e| e.adjective.to_string()),
+            flavor: element.map(|e| e.flavor.to_string()),
+        }
+    }
+
+    /// Use this damage type, with its element's adjective and flavor unless
+    /// they are already set
+    pub fn with_damage_type(mut self, damage_type: &str) -> Self {
+        let damage_type = damage_type.trim().to_lowercase();
+        if let Some(element) = element_for_type(&damage_type) {
+            self.adjective.get_or_insert_with(|| element.adjective.to_string());
+            self.flavor.get_or_insert_with(|| element.flavor.to_string());
+        }
+        self.damage_type = Some(damage_type);
+        self
+    }
+}
+
+// ============================================================================
+// Reskinning
+// ============================================================================
+
+/// A copy of the stat block in the theme's skin, with its numbers unchanged
+pub fn reskin_stat_block(block: &StatBlockData, theme: &ReskinTheme) -> StatBlockData {
+    let mut reskinned = block.clone();
+    let new_name = theme.name.clone().unwrap_or_else(|| block.name.clone());
+    reskinned.name = new_name.clone();
+    if let Some(creature_type) = &theme.creature_type {
+        reskinned.creature_type = Some(creature_type.clone());
+    }
+
+    // Every phrase to swap in descriptions, longest first so a creature
+    // named after its weapon is renamed whole
+    let mut renames: Vec<(String, String)> = Vec::new();
+    if new_name != block.name {
+        renames.push((block.name.clone(), new_name.clone()));
+        renames.push((block.name.to_lowercase(), new_name.to_lowercase()));
+    }
+
+    let features = || {
+        block
+            .traits
+            .iter()
+            .chain(&block.actions)
+            .chain(&block.bonus_actions)
+            .chain(&block.reactions)
+            .chain(&block.legendary_actions)
+    };
+    let dealt: Vec<&str> = match &theme.damage_type {
+        Some(new_type) => DAMAGE_TYPES
+            .iter()
+            .copied()
+            .filter(|t| *t != new_type.as_str() && features().any(|f| deals(f, t)))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    if let Some(new_type) = &theme.damage_type {
+        for old_type in &dealt {
+            renames.push((old_type.to_string(), new_type.clone()));
+            // Element names in feature names, like "Fire Breath"
+            let adjective = theme.adjective.clone().unwrap_or_else(|| title_case(new_type));
+            renames.push((title_case(old_type), adjective));
+        }
+    }
+    if let Some(adjective) = &theme.adjective {
+        for feature in features().filter(|f| f.attack_bonus.is_some()) {
+            let already_themed = feature.name.split_whitespace().any(|w| w == adjective)
+                || dealt.iter().any(|t| feature.name.split_whitespace().any(|w| w == title_case(t)));
+            if !already_themed {
+                renames.push((feature.name.clone(), format!("{} {}", adjective, feature.name)));
+                renames.push((feature.name.to_lowercase(), format!("{} {}", adjective, feature.name).to_lowercase()));
+            }
+        }
+    }
+    renames.sort_by_key(|(from, _)| std::cmp::Reverse(from.len()));
+    renames.dedup_by(|a, b| a.0 == b.0);
+
+    for list in [
+        &mut reskinned.traits,
+        &mut reskinned.actions,
+        &mut reskinned.bonus_actions,
+        &mut reskinned.reactions,
+        &mut reskinned.legendary_actions,
+    ] {
+        for feature in list.iter_mut() {
+            feature.name = replace_phrases(&feature.name, &renames);
+            feature.description = replace_phrases(&feature.description, &renames);
+        }
+    }
+
+    if let Some(new_type) = &theme.damage_type {
+        // Defenses follow the element: a fire creature made frost trades its
+        // fire immunity for cold immunity, and its cold weakness for fire
+        let elemental: Vec<&str> = dealt.iter().copied().filter(|t| !PHYSICAL_TYPES.contains(t)).collect();
+        let swap = |entry: &String| -> String {
+            let lower = entry.trim().to_lowercase();
+            if elemental.contains(&lower.as_str()) {
+                new_type.clone()
+            } else if lower == *new_type && elemental.len() == 1 {
+                elemental[0].to_string()
+            } else {
+                entry.clone()
+            }
+        };
+        for defenses in [
+            &mut reskinned.damage_resistances,
+            &mut reskinned.damage_immunities,
+            &mut reskinned.damage_vulnerabilities,
+        ] {
+            let mut swapped: Vec<String> = Vec::new();
+            for entry in defenses.iter().map(swap) {
+                if !swapped.contains(&entry) {
+                    swapped.push(entry);
+                }
+            }
+            *defenses = swapped;
+        }
+    }
+
+    if let Some(flavor) = &theme.flavor {
+        reskinned.traits.insert(0, Feature::new("Appearance".to_string(), flavor.clone()));
+    }
+    reskinned
+}
+
+/// Whether a feature deals damage of a type
+fn deals(feature: &Feature, damage_type: &str) -> bool {
+    let description = feature.description.to_lowercase();
+    find_word(&description, damage_type).is_some() && (feature.damage.is_some() || description.contains("damage"))
+}
+
+/// Replace whole-word occurrences of each phrase, in order
+fn replace_phrases(text: &str, renames: &[(String, String)]) -> String {
+    // Placeholders keep a replacement from being replaced again
+    let mut result = text.to_string();
+    for (i, (from, _)) in renames.iter().enumerate() {
+        while let Some(start) = find_word(&result, from) {
+            result.replace_range(start..start + from.len(), &format!("\u{1}{}\u{2}", i));
+        }
+    }
+    for (i, (_, to)) in renames.iter().enumerate() {
+        result = result.replace(&format!("\u{1}{}\u{2}", i), to);
+    }
+    result
+}
+
+/// Byte offset of the first whole-word occurrence of `word`
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    if word.is_empty() {
+        return None;
+    }
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'';
+    text.match_indices(word).map(|(i, _)| i).find(|&i| {
+        let before = text[..i].chars().next_back().is_none_or(|c| !is_word_char(c));
+        let after = text[i + word.len()..].chars().next().is_none_or(|c| !is_word_char(c));
+        before && after
+    })
+}
+
+fn title_case(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::npc_gen::{build_stat_block, NPCRole, StatBlockTarget};
+
+    #[test]
+    fn test_parse_theme() {
+        let theme = ReskinTheme::parse("make this bandit captain a frost-themed cultist");
+        assert_eq!(theme.name.as_deref(), Some("Frost Cultist"));
+        assert_eq!(theme.damage_type.as_deref(), Some("cold"));
+        assert_eq!(theme.adjective.as_deref(), Some("Frost"));
+        assert!(theme.creature_type.is_none());
+
+        let theme = ReskinTheme::parse("burning undead knight of the crown");
+        assert_eq!(theme.damage_type.as_deref(), Some("fire"));
+        assert_eq!(theme.creature_type.as_deref(), Some("undead"));
+        assert_eq!(theme.name.as_deref(), Some("Burning Undead Knight Crown"));
+
+        // "light" is a whole word, not the start of "lightning"
+        assert_eq!(ReskinTheme::parse("lightning witch").damage_type.as_deref(), Some("lightning"));
+        assert!(ReskinTheme::parse("pirate").flavor.is_none());
+    }
+
+    #[test]
+    fn test_reskin_keeps_the_math() {
+        let block = build_stat_block(
+            "Bandit Captain",
+            Some("Human"),
+            Some("Mercenary captain"),
+            &NPCRole::Enemy,
+            StatBlockTarget::ChallengeRating(5.0),
+        );
+        let theme = ReskinTheme::parse("a frost-themed cultist");
+        let reskinned = reskin_stat_block(&block, &theme);
+
+        assert_eq!(reskinned.name, "Frost Cultist");
+        assert_eq!(reskinned.armor_class.as_ref().unwrap().value, block.armor_class.as_ref().unwrap().value);
+        assert_eq!(reskinned.hit_points.as_ref().unwrap().average, block.hit_points.as_ref().unwrap().average);
+        assert_eq!(reskinned.saving_throws, block.saving_throws);
+
+        let attack = reskinned.actions.iter().find(|a| a.attack_bonus.is_some()).unwrap();
+        assert_eq!(attack.name, "Frost Greataxe");
+        assert_eq!(attack.attack_bonus, Some(6));
+        assert_eq!(attack.damage.as_deref(), Some("2d12+3"));
+        assert!(attack.description.contains("Hit: 16 (2d12 + 3) cold damage"), "{}", attack.description);
+        assert_eq!(reskinned.actions[0].description, "The Frost Cultist makes two Frost Greataxe attacks.");
+        assert_eq!(reskinned.traits[0].name, "Appearance");
+        // The original is untouched
+        assert_eq!(block.actions[1].name, "Greataxe");
+    }
+
+    #[test]
+    fn test_reskin_swaps_elemental_defenses() {
+        let mut breath = Feature::new(
+            "Fire Breath (Recharge 5–6)".to_string(),
+            "Each creature in a 15-foot cone takes 21 (6d6) fire damage.".to_string(),
+        );
+        breath.damage = Some("6d6".to_string());
+        let block = StatBlockData {
+            name: "Hell Hound".to_string(),
+            creature_type: Some("fiend".to_string()),
+            damage_immunities: vec!["fire".to_string()],
+            damage_vulnerabilities: vec!["cold".to_string()],
+            actions: vec![breath],
+            ..Default::default()
+        };
+
+        let theme = ReskinTheme { name: Some("Rime Hound".to_string()), ..Default::default() }.with_damage_type("Cold");
+        let reskinned = reskin_stat_block(&block, &theme);
+        assert_eq!(reskinned.actions[0].name, "Frost Breath (Recharge 5–6)");
+        assert!(reskinned.actions[0].description.ends_with("21 (6d6) cold damage."));
+        assert_eq!(reskinned.damage_immunities, vec!["cold"]);
+        assert_eq!(reskinned.damage_vulnerabilities, vec!["fire"]);
+        assert_eq!(reskinned.creature_type.as_deref(), Some("fiend"));
+    }
+}
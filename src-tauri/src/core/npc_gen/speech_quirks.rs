@@ -0,0 +1,397 @@
+//! NPC Speech Quirks
+//!
+//! Per-NPC speech habits built on a dialect: catchphrases, accent
+//! respellings, and vocabulary constraints. The same quirks go into the
+//! NPC's conversation prompt and are turned back into speakable words before
+//! text-to-speech, so the NPC's written and spoken dialogue match.
+//!
+//! Quirks are generated from a seed derived from the NPC's ID, so
+//! regenerating them for the same NPC and dialect gives the same result.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+use super::dialects::{DialectDefinition, Intensity};
+use super::generator::{NPCRole, NPC};
+use super::vocabulary::Formality;
+
+/// Everyday words tried against a dialect's phonetic rules to find respellings
+const COMMON_WORDS: &[&str] = &[
+    "the", "this", "that", "them", "there", "here", "going", "nothing", "something", "you", "your", "yes",
+    "old", "and", "of", "to", "with", "what", "have", "about", "hello", "friend", "little", "myself",
+    "because", "before", "over", "for", "think", "thing", "told", "hold", "around", "help",
+];
+
+/// Modern words that break a fantasy voice, avoided by everyone
+const ANACHRONISMS: &[&str] = &["okay", "cool", "awesome"];
+
+const FORMAL_AVOIDED: &[&str] = &["yeah", "gonna", "wanna", "kinda", "stuff", "guys"];
+const FORMAL_FAVORED: &[&str] = &["indeed", "certainly", "perhaps"];
+const CASUAL_AVOIDED: &[&str] = &["henceforth", "whereupon", "notwithstanding"];
+const HOSTILE_AVOIDED: &[&str] = &["please", "sorry", "kindly"];
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A word the NPC writes differently, and how to say it aloud
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Respelling {
+    /// The word in standard spelling, e.g. "here"
+    pub standard: String,
+    /// How the NPC's dialogue writes it, e.g. "'ere"
+    pub written: String,
+    /// What text-to-speech should read for the written form
+    pub spoken: String,
+}
+
+impl Respelling {
+    /// Respelling whose spoken form is read off the written one. Dropped
+    /// letters marked by apostrophes are read without them; a stub too short
+    /// to pronounce falls back to the standard word.
+    pub fn new(standard: &str, written: &str) -> Self {
+        let stripped = written.trim_matches('\'');
+        let pronounceable = stripped.chars().any(|c| "aeiouyAEIOUY".contains(c)) && stripped.len() > 1;
+        Self {
+            standard: standard.to_lowercase(),
+            written: written.to_string(),
+            spoken: if pronounceable { stripped.to_string() } else { standard.to_lowercase() },
+        }
+    }
+}
+
+/// An NPC's speech habits
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeechQuirks {
+    /// Dialect the quirks were built from
+    #[serde(default)]
+    pub dialect_id: Option<String>,
+    #[serde(default)]
+    pub intensity: Intensity,
+    #[serde(default)]
+    pub formality: Formality,
+    /// Phrases the NPC comes back to
+    #[serde(default)]
+    pub catchphrases: Vec<String>,
+    #[serde(default)]
+    pub respellings: Vec<Respelling>,
+    /// Words the NPC reaches for
+    #[serde(default)]
+    pub favored_words: Vec<String>,
+    /// Words the NPC never uses
+    #[serde(default)]
+    pub avoided_words: Vec<String>,
+}
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+impl SpeechQuirks {
+    /// Build quirks for an NPC from a dialect, the NPC's own sample phrases,
+    /// and their vocabulary. `intensity` defaults to the dialect's.
+    pub fn generate(npc: &NPC, dialect: Option<&DialectDefinition>, intensity: Option<Intensity>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        npc.id.hash(&mut hasher);
+        let mut rng = StdRng::seed_from_u64(hasher.finish());
+
+        let intensity = intensity.or(dialect.map(|d| d.default_intensity)).unwrap_or_default();
+        let formality = formality_of(npc);
+        let mut quirks = Self {
+            dialect_id: dialect.map(|d| d.id.clone()),
+            intensity,
+            formality,
+            ..Self::default()
+        };
+
+        if let Some(dialect) = dialect {
+            let mut respellings = dialect_respellings(dialect, &mut rng);
+            respellings.shuffle(&mut rng);
+            respellings.truncate(match intensity {
+                Intensity::Light => 3,
+                Intensity::Moderate => 6,
+                Intensity::Heavy => 10,
+            });
+            respellings.sort_by(|a, b| a.standard.cmp(&b.standard));
+            quirks.respellings = respellings;
+        }
+
+        // The NPC's own phrases first, then the dialect's
+        let mut own: Vec<String> = npc.voice.sample_phrases.iter().filter(|p| !p.trim().is_empty()).cloned().collect();
+        own.shuffle(&mut rng);
+        let mut borrowed: Vec<String> = dialect
+            .map(|d| {
+                d.interjections
+                    .iter()
+                    .chain(d.proverbs.iter())
+                    .cloned()
+                    .chain(d.exclamation_templates.iter().map(|t| t.expand(&mut rng)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        borrowed.shuffle(&mut rng);
+        let count = if intensity == Intensity::Light { 2 } else { 3 };
+        quirks.catchphrases = own
+            .into_iter()
+            .take(2)
+            .chain(borrowed)
+            .take(count)
+            .map(|p| quirks.write(p.trim()))
+            .collect();
+
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+        let (favored, avoided) = match formality {
+            Formality::Formal => (words(FORMAL_FAVORED), words(FORMAL_AVOIDED)),
+            Formality::Casual => (Vec::new(), words(CASUAL_AVOIDED)),
+            Formality::Hostile => (Vec::new(), words(HOSTILE_AVOIDED)),
+        };
+        quirks.favored_words = favored;
+        quirks.avoided_words = avoided.into_iter().chain(words(ANACHRONISMS)).collect();
+        quirks
+    }
+}
+
+/// Formality read off the NPC's vocabulary description and role
+fn formality_of(npc: &NPC) -> Formality {
+    let vocabulary = npc.voice.vocabulary.to_lowercase();
+    let has = |words: &[&str]| words.iter().any(|w| vocabulary.contains(w));
+    if has(&["formal", "educated", "scholarly", "archaic", "noble", "refined", "eloquent"]) {
+        Formality::Formal
+    } else if has(&["crude", "coarse", "hostile", "vulgar", "threatening"]) {
+        Formality::Hostile
+    } else if npc.role == NPCRole::Authority {
+        Formality::Formal
+    } else {
+        Formality::Casual
+    }
+}
+
+/// Single-word respellings a dialect produces: its phonetic rules run over
+/// everyday words, plus its one-word vocabulary swaps
+fn dialect_respellings(dialect: &DialectDefinition, rng: &mut impl Rng) -> Vec<Respelling> {
+    let patterns: Vec<_> = dialect
+        .phonetic_rules
+        .iter()
+        .filter_map(|rule| rule.build_pattern().ok().map(|re| (re, rule.to.clone())))
+        .collect();
+
+    let mut found: BTreeMap<String, Respelling> = BTreeMap::new();
+    for word in COMMON_WORDS {
+        let written = patterns
+            .iter()
+            .fold(word.to_string(), |text, (re, to)| re.replace_all(&text, to.as_str()).into_owned());
+        if written != *word && is_word(&written) {
+            found.insert(word.to_string(), Respelling::new(word, &written));
+        }
+    }
+
+    let mut swaps: Vec<(&String, &Vec<String>)> = dialect.vocabulary_replacements.iter().collect();
+    swaps.sort_by(|a, b| a.0.cmp(b.0));
+    for (word, alternatives) in swaps {
+        let Some(alternative) = alternatives.choose(rng) else {
+            continue;
+        };
+        if is_word(word) && is_word(alternative) && !word.eq_ignore_ascii_case(alternative) {
+            found.insert(word.to_lowercase(), Respelling::new(word, alternative));
+        }
+    }
+    found.into_values().collect()
+}
+
+/// One word: letters and apostrophes only
+fn is_word(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_alphabetic() || c == '\'') && text.chars().any(|c| c.is_alphabetic())
+}
+
+// ============================================================================
+// Applying Quirks
+// ============================================================================
+
+impl SpeechQuirks {
+    /// Rewrite standard spellings into the NPC's written accent
+    pub fn write(&self, text: &str) -> String {
+        let table: HashMap<String, &str> = self
+            .respellings
+            .iter()
+            .map(|r| (r.standard.to_lowercase(), r.written.as_str()))
+            .collect();
+        replace_words(text, &table)
+    }
+
+    /// Turn the NPC's written accent into words text-to-speech reads
+    /// naturally
+    pub fn speak(&self, text: &str) -> String {
+        let table: HashMap<String, &str> = self
+            .respellings
+            .iter()
+            .filter(|r| r.written != r.spoken)
+            .map(|r| (r.written.to_lowercase(), r.spoken.as_str()))
+            .collect();
+        replace_words(text, &table)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.catchphrases.is_empty()
+            && self.respellings.is_empty()
+            && self.favored_words.is_empty()
+            && self.avoided_words.is_empty()
+    }
+
+    /// System prompt section describing how the NPC talks
+    pub fn prompt_section(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let quoted = |items: &[String]| items.iter().map(|i| format!("\"{}\"", i)).collect::<Vec<_>>().join(", ");
+
+        let mut section = String::from("### SPEECH BEGIN ###\n");
+        section.push_str(&format!("Your tone is {}.\n", self.formality.as_str()));
+        if !self.catchphrases.is_empty() {
+            section.push_str(&format!(
+                "Now and then use your catchphrases: {}.\n",
+                quoted(&self.catchphrases)
+            ));
+        }
+        if !self.respellings.is_empty() {
+            let spellings: Vec<String> =
+                self.respellings.iter().map(|r| format!("\"{}\" as \"{}\"", r.standard, r.written)).collect();
+            section.push_str(&format!("Always write {}.\n", spellings.join(", ")));
+        }
+        if !self.favored_words.is_empty() {
+            section.push_str(&format!("Favor words like {}.\n", quoted(&self.favored_words)));
+        }
+        if !self.avoided_words.is_empty() {
+            section.push_str(&format!("Never say {}.\n", quoted(&self.avoided_words)));
+        }
+        section.push_str("### SPEECH END ###\n");
+        Some(section)
+    }
+}
+
+/// Replace whole words, matched case-insensitively. Apostrophes count as
+/// part of a word, so "'ere" and "goin'" are matched whole. A replacement
+/// for a capitalized word is capitalized.
+fn replace_words(text: &str, table: &HashMap<String, &str>) -> String {
+    if table.is_empty() {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, result: &mut String| {
+        if word.is_empty() {
+            return;
+        }
+        // Quotes around a word aren't part of it unless the table says so
+        let core = if table.contains_key(&word.to_lowercase()) { word.as_str() } else { word.trim_matches('\'') };
+        match table.get(&core.to_lowercase()) {
+            Some(replacement) => {
+                let start = word.find(core).unwrap_or(0);
+                result.push_str(&word[..start]);
+                result.push_str(&match_case(core, replacement));
+                result.push_str(&word[start + core.len()..]);
+            }
+            None => result.push_str(word),
+        }
+        word.clear();
+    };
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '\'' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut word, &mut result);
+    result
+}
+
+fn match_case(original: &str, replacement: &str) -> String {
+    let capitalized = original.chars().find(|c| c.is_alphabetic()).is_some_and(|c| c.is_uppercase());
+    if !capitalized {
+        return replacement.to_string();
+    }
+    let mut done = false;
+    replacement
+        .chars()
+        .map(|c| {
+            if !done && c.is_alphabetic() {
+                done = true;
+                c.to_uppercase().next().unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::npc_gen::dialects::PhoneticRule;
+    use crate::core::npc_gen::{NPCGenerationOptions, NPCGenerator};
+
+    fn cockney() -> DialectDefinition {
+        let mut dialect = DialectDefinition::new("cockney")
+            .add_phonetic_rule(PhoneticRule::new("h-drop", "h", "'").at_word_start())
+            .add_phonetic_rule(PhoneticRule::new("g-drop", "ing", "in'").at_word_end());
+        dialect.vocabulary_replacements.insert("friend".to_string(), vec!["mate".to_string()]);
+        dialect.interjections = vec!["Blimey!".to_string(), "Cor!".to_string()];
+        dialect
+    }
+
+    fn npc() -> NPC {
+        NPCGenerator::new().generate_quick(&NPCGenerationOptions::default())
+    }
+
+    #[test]
+    fn test_quirks_are_consistent_per_npc() {
+        let npc = npc();
+        let dialect = cockney();
+        let first = SpeechQuirks::generate(&npc, Some(&dialect), Some(Intensity::Heavy));
+        let again = SpeechQuirks::generate(&npc, Some(&dialect), Some(Intensity::Heavy));
+        assert_eq!(first, again);
+        assert_eq!(first.dialect_id.as_deref(), Some("cockney"));
+        assert!(first.respellings.iter().any(|r| r.standard == "here" && r.written == "'ere"));
+        assert!(first.respellings.iter().any(|r| r.standard == "friend" && r.written == "mate"));
+        assert!(first.avoided_words.contains(&"okay".to_string()));
+
+        let light = SpeechQuirks::generate(&npc, Some(&dialect), Some(Intensity::Light));
+        assert!(light.respellings.len() <= 3);
+        assert!(light.catchphrases.len() <= 2);
+    }
+
+    #[test]
+    fn test_written_and_spoken_forms_match() {
+        let quirks = SpeechQuirks {
+            respellings: vec![
+                Respelling::new("here", "'ere"),
+                Respelling::new("going", "goin'"),
+                Respelling::new("the", "th'"),
+            ],
+            ..SpeechQuirks::default()
+        };
+        let written = quirks.write("Here we are, going to the 'docks'.");
+        assert_eq!(written, "'Ere we are, goin' to th' 'docks'.");
+        assert_eq!(quirks.speak(&written), "Ere we are, goin to the 'docks'.");
+    }
+
+    #[test]
+    fn test_prompt_section() {
+        assert!(SpeechQuirks::default().prompt_section().is_none());
+        let quirks = SpeechQuirks::generate(&npc(), Some(&cockney()), None);
+        let section = quirks.prompt_section().unwrap();
+        assert!(section.starts_with("### SPEECH BEGIN ###"));
+        assert!(section.contains("Never say"));
+        assert!(section.contains("\"here\" as \"'ere\"") || !quirks.respellings.iter().any(|r| r.standard == "here"));
+    }
+}
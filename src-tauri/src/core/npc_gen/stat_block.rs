@@ -0,0 +1,597 @@
+//! NPC Stat Blocks
+//!
+//! Builds a combat stat block for a generated NPC, tuned to a target
+//! strength: a D&D 5e challenge rating, using the Dungeon Master's Guide
+//! monster statistics by CR, or a Pathfinder 2e creature level, using the
+//! Gamemastery Guide's creature-building tables. The NPC's occupation picks
+//! how it fights; bosses get legendary actions. The result is a
+//! `StatBlockData`, the same shape parsed from rulebooks, so it can be added
+//! to combat directly.
+
+use serde::{Deserialize, Serialize};
+
+use super::generator::NPCRole;
+use crate::core::campaign::encounter_builder::cr_to_xp;
+use crate::ingestion::ttrpg::stat_block::{ArmorClass, ChallengeRating, HitPoints};
+use crate::ingestion::ttrpg::{Feature, StatBlockData};
+
+/// Lowest challenge rating a boss gets legendary actions at
+const LEGENDARY_MIN_CR: f32 = 5.0;
+
+/// Lowest creature level a Pathfinder boss gets a reaction at
+const PF2E_REACTION_MIN_LEVEL: i32 = 3;
+
+// ============================================================================
+// Targets
+// ============================================================================
+
+/// How strong a stat block should be
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatBlockTarget {
+    /// D&D 5e challenge rating, 0 to 30 (1/8 is 0.125)
+    ChallengeRating(f32),
+    /// Pathfinder 2e creature level, -1 to 24
+    CreatureLevel(i32),
+}
+
+/// How an NPC fights
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombatStyle {
+    /// Heavy melee weapon, Strength, armor
+    Brute,
+    /// Light weapons and a bow, Dexterity, slips away
+    Skirmisher,
+    /// Attack spells and a save effect
+    Caster,
+}
+
+impl CombatStyle {
+    /// The style an occupation suggests; anything unrecognized fights as a
+    /// brute
+    pub fn for_occupation(occupation: Option<&str>) -> Self {
+        let occupation = occupation.unwrap_or_default().to_lowercase();
+        let any = |words: &[&str]| words.iter().any(|w| occupation.contains(w));
+        if any(&["mage", "wizard", "sorcer", "warlock", "witch", "priest", "cleric", "acolyte", "druid", "shaman", "cultist", "sage", "scholar"]) {
+            Self::Caster
+        } else if any(&["scout", "thief", "rogue", "assassin", "archer", "hunter", "ranger", "spy", "bandit", "smuggler", "pickpocket"]) {
+            Self::Skirmisher
+        } else {
+            Self::Brute
+        }
+    }
+
+    fn weapon(&self) -> Weapon {
+        match self {
+            Self::Brute => Weapon { name: "Greataxe", die: 12, damage_type: "slashing", reach: "5 ft.", attack: "Melee Weapon Attack" },
+            Self::Skirmisher => Weapon { name: "Shortsword", die: 6, damage_type: "piercing", reach: "5 ft.", attack: "Melee Weapon Attack" },
+            Self::Caster => Weapon { name: "Arcane Bolt", die: 10, damage_type: "force", reach: "120 ft.", attack: "Ranged Spell Attack" },
+        }
+    }
+}
+
+struct Weapon {
+    name: &'static str,
+    die: i32,
+    damage_type: &'static str,
+    reach: &'static str,
+    attack: &'static str,
+}
+
+/// What the stat block is built for
+struct Subject<'a> {
+    name: &'a str,
+    race: Option<&'a str>,
+    style: CombatStyle,
+    boss: bool,
+}
+
+/// Build a stat block for an NPC at a target strength
+pub fn build_stat_block(
+    name: &str,
+    race: Option<&str>,
+    occupation: Option<&str>,
+    role: &NPCRole,
+    target: StatBlockTarget,
+) -> StatBlockData {
+    let subject = Subject { name, race, style: CombatStyle::for_occupation(occupation), boss: *role == NPCRole::Boss };
+    match target {
+        StatBlockTarget::ChallengeRating(cr) => dnd5e_stat_block(&subject, cr),
+        StatBlockTarget::CreatureLevel(level) => pf2e_stat_block(&subject, level),
+    }
+}
+
+// ============================================================================
+// D&D 5e
+// ============================================================================
+
+/// A row of the DMG's Monster Statistics by Challenge Rating table
+struct CrRow {
+    cr: f32,
+    proficiency: i32,
+    armor_class: i32,
+    hp: (i32, i32),
+    attack_bonus: i32,
+    damage: (i32, i32),
+    save_dc: i32,
+}
+
+const fn row(
+    cr: f32,
+    proficiency: i32,
+    armor_class: i32,
+    hp: (i32, i32),
+    attack_bonus: i32,
+    damage: (i32, i32),
+    save_dc: i32,
+) -> CrRow {
+    CrRow { cr, proficiency, armor_class, hp, attack_bonus, damage, save_dc }
+}
+
+const CR_TABLE: [CrRow; 34] = [
+    row(0.0, 2, 13, (1, 6), 3, (0, 1), 13),
+    row(0.125, 2, 13, (7, 35), 3, (2, 3), 13),
+    row(0.25, 2, 13, (36, 49), 3, (4, 5), 13),
+    row(0.5, 2, 13, (50, 70), 3, (6, 8), 13),
+    row(1.0, 2, 13, (71, 85), 3, (9, 14), 13),
+    row(2.0, 2, 13, (86, 100), 3, (15, 20), 13),
+    row(3.0, 2, 13, (101, 115), 4, (21, 26), 13),
+    row(4.0, 2, 14, (116, 130), 5, (27, 32), 14),
+    row(5.0, 3, 15, (131, 145), 6, (33, 38), 15),
+    row(6.0, 3, 15, (146, 160), 6, (39, 44), 15),
+    row(7.0, 3, 15, (161, 175), 6, (45, 50), 15),
+    row(8.0, 3, 16, (176, 190), 7, (51, 56), 16),
+    row(9.0, 4, 16, (191, 205), 7, (57, 62), 16),
+    row(10.0, 4, 17, (206, 220), 7, (63, 68), 16),
+    row(11.0, 4, 17, (221, 235), 8, (69, 74), 17),
+    row(12.0, 4, 17, (236, 250), 8, (75, 80), 17),
+    row(13.0, 5, 18, (251, 265), 8, (81, 86), 18),
+    row(14.0, 5, 18, (266, 280), 8, (87, 92), 18),
+    row(15.0, 5, 18, (281, 295), 8, (93, 98), 18),
+    row(16.0, 5, 18, (296, 310), 9, (99, 104), 18),
+    row(17.0, 6, 19, (311, 325), 10, (105, 110), 19),
+    row(18.0, 6, 19, (326, 340), 10, (111, 116), 19),
+    row(19.0, 6, 19, (341, 355), 10, (117, 122), 19),
+    row(20.0, 6, 19, (356, 400), 10, (123, 140), 19),
+    row(21.0, 7, 19, (401, 445), 11, (141, 158), 20),
+    row(22.0, 7, 19, (446, 490), 11, (159, 176), 20),
+    row(23.0, 7, 19, (491, 535), 11, (177, 194), 20),
+    row(24.0, 7, 19, (536, 580), 12, (195, 212), 21),
+    row(25.0, 8, 19, (581, 625), 12, (213, 230), 21),
+    row(26.0, 8, 19, (626, 670), 12, (231, 248), 21),
+    row(27.0, 8, 19, (671, 715), 13, (249, 266), 22),
+    row(28.0, 8, 19, (716, 760), 13, (267, 284), 22),
+    row(29.0, 9, 19, (761, 805), 13, (285, 302), 22),
+    row(30.0, 9, 19, (806, 850), 14, (303, 320), 23),
+];
+
+/// The table row for a challenge rating, rounding down between rows
+fn cr_row(cr: f32) -> &'static CrRow {
+    CR_TABLE.iter().rev().find(|row| row.cr <= cr).unwrap_or(&CR_TABLE[0])
+}
+
+fn dnd5e_stat_block(subject: &Subject, cr: f32) -> StatBlockData {
+    let stats = cr_row(cr.clamp(0.0, 30.0));
+    let style = subject.style;
+    let weapon = style.weapon();
+
+    // The attack bonus is proficiency plus the attacking ability, which
+    // sets that ability; Constitution grows with hit points
+    let primary = (stats.attack_bonus - stats.proficiency).clamp(0, 5);
+    let constitution = (1 + stats.cr as i32 / 4).min(5);
+    let [strength, dexterity, intelligence, wisdom, charisma] = match style {
+        CombatStyle::Brute => [primary, 1, -1, 0, 0],
+        CombatStyle::Skirmisher => [0, primary, 0, 1, 0],
+        CombatStyle::Caster => [-1, 1, primary, 1, 0],
+    };
+    let score = |modifier: i32| 10 + 2 * modifier;
+
+    let hp = hit_points((stats.hp.0 + stats.hp.1) / 2, 8, constitution);
+    let armor_type = match style {
+        CombatStyle::Brute => match stats.armor_class {
+            ac if ac >= 18 => "plate",
+            ac if ac >= 16 => "chain mail",
+            _ => "scale mail",
+        },
+        CombatStyle::Skirmisher => "studded leather",
+        CombatStyle::Caster => "mage armor",
+    };
+
+    // Damage per round splits across the round's attacks
+    let attacks = match stats.cr {
+        cr if cr < 3.0 => 1,
+        cr if cr < 11.0 => 2,
+        cr if cr < 17.0 => 3,
+        _ => 4,
+    };
+    let per_attack = ((stats.damage.0 + stats.damage.1) / 2 / attacks).max(1);
+    let (average, damage) = damage_dice(per_attack, weapon.die, primary);
+
+    let mut attack = Feature::new(
+        weapon.name.to_string(),
+        format!(
+            "{}: +{} to hit, {} {}, one target. Hit: {} ({}) {} damage.",
+            weapon.attack,
+            stats.attack_bonus,
+            if weapon.reach.starts_with('5') { "reach" } else { "range" },
+            weapon.reach,
+            average,
+            damage.replace('+', " + ").replace('-', " - "),
+            weapon.damage_type
+        ),
+    );
+    attack.attack_bonus = Some(stats.attack_bonus);
+    attack.damage = Some(damage);
+    attack.reach = Some(weapon.reach.to_string());
+
+    let mut block = StatBlockData {
+        name: subject.name.to_string(),
+        size: Some("Medium".to_string()),
+        creature_type: Some(match subject.race {
+            Some(race) => format!("humanoid ({})", race.to_lowercase()),
+            None => "humanoid".to_string(),
+        }),
+        armor_class: Some(ArmorClass { value: stats.armor_class, armor_type: Some(armor_type.to_string()) }),
+        hit_points: Some(hp),
+        challenge_rating: Some(ChallengeRating { value: cr, xp: cr_to_xp(cr).map(|xp| xp as i32) }),
+        languages: vec!["Common".to_string()],
+        ..Default::default()
+    };
+    block.speed.walk = Some(30);
+    let scores = &mut block.ability_scores;
+    scores.strength = Some(score(strength));
+    scores.dexterity = Some(score(dexterity));
+    scores.constitution = Some(score(constitution));
+    scores.intelligence = Some(score(intelligence));
+    scores.wisdom = Some(score(wisdom));
+    scores.charisma = Some(score(charisma));
+
+    let (save, skill) = match style {
+        CombatStyle::Brute => ("Str", "Athletics"),
+        CombatStyle::Skirmisher => ("Dex", "Stealth"),
+        CombatStyle::Caster => ("Int", "Arcana"),
+    };
+    block.saving_throws.insert(save.to_string(), primary + stats.proficiency);
+    block.saving_throws.insert("Con".to_string(), constitution + stats.proficiency);
+    block.skills.insert(skill.to_string(), primary + stats.proficiency);
+    block.skills.insert("Perception".to_string(), wisdom + stats.proficiency);
+    block.senses.push(format!("passive Perception {}", 10 + wisdom + stats.proficiency));
+
+    if attacks > 1 {
+        block.actions.push(Feature::new(
+            "Multiattack".to_string(),
+            format!("The {} makes {} {} attacks.", subject.name, number_word(attacks), weapon.name),
+        ));
+    }
+    block.actions.push(attack);
+    match style {
+        CombatStyle::Brute => block.bonus_actions.push(Feature::new(
+            "Aggressive".to_string(),
+            format!("The {} moves up to its speed toward a hostile creature it can see.", subject.name),
+        )),
+        CombatStyle::Skirmisher => block.bonus_actions.push(Feature::new(
+            "Cunning Action".to_string(),
+            format!("The {} takes the Dash, Disengage, or Hide action.", subject.name),
+        )),
+        CombatStyle::Caster => block.actions.push(Feature::new(
+            "Binding Word (Recharge 5–6)".to_string(),
+            format!(
+                "One creature within 60 feet must succeed on a DC {} Wisdom saving throw or be restrained until the end of the {}'s next turn.",
+                stats.save_dc, subject.name
+            ),
+        )),
+    }
+
+    if subject.boss && stats.cr >= LEGENDARY_MIN_CR {
+        block.traits.push(Feature::new(
+            "Legendary Resistance (3/Day)".to_string(),
+            format!("If the {} fails a saving throw, it can choose to succeed instead.", subject.name),
+        ));
+        let mut strike = Feature::new("Attack".to_string(), format!("The {} makes one {} attack.", subject.name, weapon.name));
+        strike.cost = Some(1);
+        let mut reposition = Feature::new("Move".to_string(), format!("The {} moves up to its speed without provoking opportunity attacks.", subject.name));
+        reposition.cost = Some(1);
+        block.legendary_actions = vec![strike, reposition];
+    }
+    block
+}
+
+// ============================================================================
+// Pathfinder 2e
+// ============================================================================
+
+/// A row of the Gamemastery Guide's creature-building tables, moderate
+/// column unless noted
+struct LevelRow {
+    level: i32,
+    /// High attribute modifier, for the creature's best attribute
+    high_attribute: i32,
+    attribute: i32,
+    perception: i32,
+    armor_class: i32,
+    save: i32,
+    hp: i32,
+    strike_attack: i32,
+    strike_damage: i32,
+    spell_dc: i32,
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn level_row(
+    level: i32,
+    high_attribute: i32,
+    attribute: i32,
+    perception: i32,
+    armor_class: i32,
+    hp: i32,
+    strike_attack: i32,
+    strike_damage: i32,
+    spell_dc: i32,
+) -> LevelRow {
+    LevelRow {
+        level,
+        high_attribute,
+        attribute,
+        perception,
+        armor_class,
+        save: perception,
+        hp,
+        strike_attack,
+        strike_damage,
+        spell_dc,
+    }
+}
+
+const LEVEL_TABLE: [LevelRow; 26] = [
+    level_row(-1, 3, 2, 5, 14, 8, 6, 3, 13),
+    level_row(0, 3, 2, 6, 15, 15, 6, 4, 13),
+    level_row(1, 4, 3, 7, 15, 20, 7, 6, 14),
+    level_row(2, 4, 3, 8, 17, 30, 9, 8, 15),
+    level_row(3, 4, 3, 10, 18, 45, 10, 10, 17),
+    level_row(4, 5, 3, 11, 20, 60, 12, 12, 18),
+    level_row(5, 5, 4, 12, 21, 75, 13, 13, 19),
+    level_row(6, 5, 4, 14, 23, 95, 15, 15, 21),
+    level_row(7, 6, 4, 15, 24, 115, 16, 16, 22),
+    level_row(8, 6, 4, 16, 26, 135, 18, 18, 23),
+    level_row(9, 6, 4, 18, 27, 155, 19, 19, 25),
+    level_row(10, 7, 5, 19, 29, 175, 21, 21, 26),
+    level_row(11, 7, 5, 20, 30, 195, 22, 23, 27),
+    level_row(12, 7, 5, 22, 32, 215, 24, 24, 29),
+    level_row(13, 8, 5, 23, 33, 235, 25, 26, 30),
+    level_row(14, 8, 5, 24, 35, 255, 27, 27, 31),
+    level_row(15, 8, 6, 26, 36, 275, 28, 29, 33),
+    level_row(16, 9, 6, 27, 38, 295, 30, 30, 34),
+    level_row(17, 9, 6, 28, 39, 315, 31, 32, 35),
+    level_row(18, 9, 6, 30, 41, 335, 33, 33, 37),
+    level_row(19, 10, 6, 31, 42, 355, 34, 35, 38),
+    level_row(20, 10, 7, 32, 44, 375, 36, 36, 39),
+    level_row(21, 10, 7, 34, 45, 400, 37, 38, 41),
+    level_row(22, 10, 8, 35, 47, 430, 39, 39, 42),
+    level_row(23, 10, 8, 36, 48, 460, 40, 41, 43),
+    level_row(24, 12, 9, 38, 50, 500, 42, 42, 45),
+];
+
+fn pf2e_stat_block(subject: &Subject, level: i32) -> StatBlockData {
+    let level = level.clamp(-1, 24);
+    let stats = &LEVEL_TABLE[(level + 1) as usize];
+    let style = subject.style;
+    let weapon = style.weapon();
+
+    let (high, moderate) = (stats.high_attribute, stats.attribute);
+    let [strength, dexterity, constitution, intelligence, wisdom, charisma] = match style {
+        CombatStyle::Brute => [high, 1, moderate, -1, 1, 0],
+        CombatStyle::Skirmisher => [1, high, 1, 0, moderate, 0],
+        CombatStyle::Caster => [-1, 1, 1, high, moderate, 1],
+    };
+    // Stat blocks hold scores; Pathfinder lists modifiers, which a score
+    // of 10 + 2 × modifier reproduces
+    let score = |modifier: i32| 10 + 2 * modifier;
+
+    // Strikes grow from one die to four as striking runes would add them
+    let dice = match level {
+        l if l < 4 => 1,
+        l if l < 12 => 2,
+        l if l < 20 => 3,
+        _ => 4,
+    };
+    let die_average = (weapon.die + 1) as f32 / 2.0;
+    let bonus = (stats.strike_damage as f32 - dice as f32 * die_average).round().max(0.0) as i32;
+    let damage = format!("{}d{}+{}", dice, weapon.die, bonus);
+
+    let (attack_name, trait_list) = match style {
+        CombatStyle::Brute => ("Melee", "sweep"),
+        CombatStyle::Skirmisher => ("Melee", "agile, finesse"),
+        CombatStyle::Caster => ("Ranged", "arcane, range 120 feet"),
+    };
+    let mut strike = Feature::new(
+        weapon.name.to_string(),
+        format!("{} [one-action] {} +{} ({}), Damage {} {}", attack_name, weapon.name.to_lowercase(), stats.strike_attack, trait_list, damage.replace('+', " + "), weapon.damage_type),
+    );
+    strike.attack_bonus = Some(stats.strike_attack);
+    strike.damage = Some(damage);
+    strike.reach = Some(if style == CombatStyle::Caster { "120 ft.".to_string() } else { "5 ft.".to_string() });
+
+    let mut block = StatBlockData {
+        name: subject.name.to_string(),
+        size: Some("Medium".to_string()),
+        creature_type: Some(match subject.race {
+            Some(race) => format!("humanoid ({})", race.to_lowercase()),
+            None => "humanoid".to_string(),
+        }),
+        armor_class: Some(ArmorClass { value: stats.armor_class, armor_type: None }),
+        hit_points: Some(HitPoints { average: stats.hp, formula: None }),
+        // Pathfinder creatures have a level rather than a challenge rating
+        challenge_rating: Some(ChallengeRating { value: stats.level as f32, xp: None }),
+        languages: vec!["Common".to_string()],
+        ..Default::default()
+    };
+    block.speed.walk = Some(25);
+    let scores = &mut block.ability_scores;
+    scores.strength = Some(score(strength));
+    scores.dexterity = Some(score(dexterity));
+    scores.constitution = Some(score(constitution));
+    scores.intelligence = Some(score(intelligence));
+    scores.wisdom = Some(score(wisdom));
+    scores.charisma = Some(score(charisma));
+
+    // One save is strong and one weak, by two either way
+    let (strong, weak) = match style {
+        CombatStyle::Brute => ("Fortitude", "Will"),
+        CombatStyle::Skirmisher => ("Reflex", "Fortitude"),
+        CombatStyle::Caster => ("Will", "Reflex"),
+    };
+    for save in ["Fortitude", "Reflex", "Will"] {
+        let adjustment = if save == strong { 2 } else if save == weak { -2 } else { 0 };
+        block.saving_throws.insert(save.to_string(), stats.save + adjustment);
+    }
+    let skill = match style {
+        CombatStyle::Brute => "Athletics",
+        CombatStyle::Skirmisher => "Stealth",
+        CombatStyle::Caster => "Arcana",
+    };
+    block.skills.insert(skill.to_string(), stats.perception);
+    block.skills.insert("Perception".to_string(), stats.perception);
+    block.senses.push(format!("Perception +{}", stats.perception));
+
+    block.actions.push(strike);
+    match style {
+        CombatStyle::Brute => block.traits.push(Feature::new(
+            "Brutal Blow".to_string(),
+            "On a critical hit with a melee Strike, the target is knocked prone.".to_string(),
+        )),
+        CombatStyle::Skirmisher => block.traits.push(Feature::new(
+            "Sneak Attack".to_string(),
+            format!("The {} deals an extra {}d6 precision damage to off-guard creatures.", subject.name, (level + 5) / 6 + 1),
+        )),
+        CombatStyle::Caster => block.actions.push(Feature::new(
+            "Arcane Spells".to_string(),
+            format!(
+                "DC {}, attack +{}; the {} casts spells of up to rank {}.",
+                stats.spell_dc,
+                stats.spell_dc - 8,
+                subject.name,
+                ((level + 1) / 2).clamp(1, 10)
+            ),
+        )),
+    }
+    if subject.boss && level >= PF2E_REACTION_MIN_LEVEL {
+        block.reactions.push(Feature::new(
+            "Reactive Strike".to_string(),
+            format!("The {} Strikes a creature within reach that uses a manipulate or move action.", subject.name),
+        ));
+    }
+    block
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Hit dice that average close to `target` hit points
+fn hit_points(target: i32, die: i32, constitution: i32) -> HitPoints {
+    let per_die = (die + 1) as f32 / 2.0 + constitution as f32;
+    let count = ((target as f32 / per_die).round() as i32).max(1);
+    let average = (count as f32 * (die + 1) as f32 / 2.0).floor() as i32 + count * constitution;
+    let formula = match count * constitution {
+        0 => format!("{}d{}", count, die),
+        bonus if bonus > 0 => format!("{}d{} + {}", count, die, bonus),
+        bonus => format!("{}d{} - {}", count, die, -bonus),
+    };
+    HitPoints { average: average.max(1), formula: Some(formula) }
+}
+
+/// Damage dice of one size averaging close to `target` with a modifier,
+/// as the average and a formula like "2d6+3"
+fn damage_dice(target: i32, die: i32, modifier: i32) -> (i32, String) {
+    let die_average = (die + 1) as f32 / 2.0;
+    let count = (((target - modifier) as f32 / die_average).round() as i32).max(1);
+    let average = ((count as f32 * die_average).floor() as i32 + modifier).max(1);
+    let formula = match modifier {
+        0 => format!("{}d{}", count, die),
+        m if m > 0 => format!("{}d{}+{}", count, die, m),
+        m => format!("{}d{}{}", count, die, m),
+    };
+    (average, formula)
+}
+
+fn number_word(n: i32) -> String {
+    match n {
+        2 => "two".to_string(),
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        n => n.to_string(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::stat_blocks::CombatantStats;
+
+    #[test]
+    fn test_dnd5e_stat_block_matches_cr() {
+        let block = build_stat_block(
+            "Captain Voss",
+            Some("Human"),
+            Some("Mercenary captain"),
+            &NPCRole::Boss,
+            StatBlockTarget::ChallengeRating(5.0),
+        );
+        assert_eq!(block.armor_class.as_ref().unwrap().value, 15);
+        let hp = block.hit_points.as_ref().unwrap();
+        // The CR 5 range is 131–145 hit points
+        assert!((131..=145).contains(&hp.average), "{}", hp.average);
+        assert_eq!(hp.formula.as_deref(), Some("21d8 + 42"));
+        assert_eq!(block.ability_scores.strength, Some(16));
+        assert_eq!(block.saving_throws["Str"], 6);
+        assert_eq!(block.challenge_rating.as_ref().unwrap().xp, Some(1800));
+
+        // Two greataxe attacks of 2d12+3 each, near CR 5's 33–38 a round
+        let attack = block.actions.iter().find(|a| a.name == "Greataxe").unwrap();
+        assert_eq!(attack.attack_bonus, Some(6));
+        assert_eq!(attack.damage.as_deref(), Some("2d12+3"));
+        assert!(attack.description.contains("Hit: 16 (2d12 + 3) slashing damage"), "{}", attack.description);
+        assert_eq!(block.actions[0].name, "Multiattack");
+        assert_eq!(block.legendary_actions.len(), 2);
+
+        let stats = CombatantStats::from_stat_block(&block);
+        assert_eq!(stats.attacks().count(), 1);
+        assert_eq!(stats.challenge_rating, Some(5.0));
+
+        let minion = build_stat_block("Cutpurse", None, Some("pickpocket"), &NPCRole::Minion, StatBlockTarget::ChallengeRating(0.125));
+        assert_eq!(minion.skills["Stealth"], 3);
+        assert_eq!(minion.actions[0].name, "Shortsword");
+        assert!(minion.legendary_actions.is_empty());
+        assert_eq!(minion.challenge_rating.as_ref().unwrap().xp, Some(25));
+    }
+
+    #[test]
+    fn test_pf2e_stat_block_matches_level() {
+        let block = build_stat_block(
+            "Sister Maren",
+            Some("Elf"),
+            Some("Temple priest"),
+            &NPCRole::Enemy,
+            StatBlockTarget::CreatureLevel(5),
+        );
+        assert_eq!(block.armor_class.as_ref().unwrap().value, 21);
+        assert_eq!(block.hit_points.as_ref().unwrap().average, 75);
+        assert_eq!(block.ability_scores.intelligence, Some(20));
+        assert_eq!(block.saving_throws["Will"], 14);
+        assert_eq!(block.saving_throws["Reflex"], 10);
+        let strike = &block.actions[0];
+        assert_eq!(strike.attack_bonus, Some(13));
+        assert_eq!(strike.damage.as_deref(), Some("2d10+2"));
+        assert!(block.actions[1].description.starts_with("DC 19, attack +11"));
+        assert!(block.reactions.is_empty());
+
+        assert_eq!(CombatStyle::for_occupation(Some("Blacksmith")), CombatStyle::Brute);
+        let lowest = build_stat_block("Rat Catcher", None, None, &NPCRole::Bystander, StatBlockTarget::CreatureLevel(-3));
+        assert_eq!(lowest.challenge_rating.as_ref().unwrap().value, -1.0);
+    }
+}
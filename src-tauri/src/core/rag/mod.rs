@@ -11,6 +11,7 @@
 //! - `TtrpgIndex`: Enum of known TTRPG content indexes
 //! - `LlmProvider`: Enum of supported LLM providers (Anthropic, OpenAI, Ollama, etc.)
 //! - TTRPG-specific templates for rules, fiction, and chunk formatting
+//! - `rules_qa`: Prompt, citation checks, and refusals for cited rules answers
 //!
 //! # Usage
 //!
@@ -45,6 +46,7 @@
 
 mod config;
 mod provider;
+pub mod rules_qa;
 mod templates;
 
 // Core configuration types
@@ -59,6 +61,9 @@ pub use config::{
 // Provider types
 pub use provider::{parse_provider, LlmProvider};
 
+// Rules Q&A
+pub use rules_qa::{RulesAnswer, RulesAnswerStatus, RulesFootnote, RulesPassage};
+
 // Template constants
 pub use templates::{
     CAMPAIGN_CONTEXT_TEMPLATE, CHUNK_TEMPLATE, FICTION_TEMPLATE, NPC_TEMPLATE, RULES_TEMPLATE,
@@ -0,0 +1,356 @@
+//! Rules Q&A
+//!
+//! Answers rules questions from rulebook passages only. The model must cite
+//! the passages it used by chunk ID; citations are checked against what was
+//! retrieved and turned into numbered footnotes. Weak retrieval, an
+//! unsure model, or an answer without valid citations becomes a refusal
+//! instead of a guess.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::search::{LibraryDocumentMetadata, INDEX_RULES};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Passages given to the model when no limit is requested
+pub const DEFAULT_RULES_PASSAGES: usize = 6;
+
+/// Below this best ranking score the rulebooks likely don't cover the question
+pub const MIN_RETRIEVAL_SCORE: f32 = 0.3;
+
+/// Answers the model is less sure of than this are refused
+pub const MIN_ANSWER_CONFIDENCE: f32 = 0.5;
+
+/// Library content types that hold rules
+const RULEBOOK_CONTENT_TYPES: [&str; 3] = ["core_rulebook", "rulebook", "supplement"];
+
+/// System instructions for rules answers
+pub const RULES_QA_SYSTEM_PROMPT: &str = "You are a rules reference for a tabletop RPG. \
+Answer only from the rulebook passages you are given, never from memory. \
+Every claim must cite the passage it comes from by its ID in square brackets, like [phb-142-3]. \
+If the passages don't answer the question, say so instead of guessing.";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A retrieved rulebook passage the answer may cite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesPassage {
+    pub chunk_id: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub content: String,
+}
+
+/// Whether the question was answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesAnswerStatus {
+    Answered,
+    Refused,
+}
+
+/// A cited passage, numbered as it appears in the answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesFootnote {
+    /// The `[n]` marker used in the answer text
+    pub number: usize,
+    pub chunk_id: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    /// Supporting text quoted by the model, if any
+    pub quote: Option<String>,
+}
+
+/// A rules answer with footnotes, or a refusal with its reason
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesAnswer {
+    pub question: String,
+    pub game_system: String,
+    pub status: RulesAnswerStatus,
+    /// Answer text with `[n]` footnote markers; empty when refused
+    pub answer: String,
+    pub footnotes: Vec<RulesFootnote>,
+    /// The model's confidence in the answer, 0.0-1.0
+    pub confidence: f32,
+    /// Best ranking score among the retrieved passages, 0.0-1.0
+    pub retrieval_score: f32,
+    pub refusal_reason: Option<String>,
+}
+
+impl RulesAnswer {
+    pub fn refused(question: &str, game_system: &str, reason: impl Into<String>, retrieval_score: f32) -> Self {
+        Self {
+            question: question.to_string(),
+            game_system: game_system.to_string(),
+            status: RulesAnswerStatus::Refused,
+            answer: String::new(),
+            footnotes: Vec::new(),
+            confidence: 0.0,
+            retrieval_score,
+            refusal_reason: Some(reason.into()),
+        }
+    }
+}
+
+// ============================================================================
+// Retrieval Scope
+// ============================================================================
+
+/// Whether a library document holds rules content
+pub fn is_rulebook(doc: &LibraryDocumentMetadata) -> bool {
+    doc.content_index == INDEX_RULES
+        || doc
+            .content_type
+            .as_deref()
+            .is_some_and(|t| RULEBOOK_CONTENT_TYPES.contains(&t.to_lowercase().as_str()))
+}
+
+/// Why retrieval can't support an answer, if it can't
+pub fn retrieval_refusal(passages: &[RulesPassage], retrieval_score: f32) -> Option<String> {
+    if passages.is_empty() {
+        Some("No rulebook passages matched the question".to_string())
+    } else if retrieval_score < MIN_RETRIEVAL_SCORE {
+        Some("The rulebooks don't appear to cover this question".to_string())
+    } else {
+        None
+    }
+}
+
+// ============================================================================
+// Prompt and Parsing
+// ============================================================================
+
+/// Build the user prompt with the question and labelled passages
+pub fn build_rules_prompt(question: &str, game_system: &str, passages: &[RulesPassage]) -> String {
+    let mut prompt = format!("GAME SYSTEM: {}\nQUESTION: {}\n\nPASSAGES:\n", game_system, question);
+    for passage in passages {
+        let page = passage.page_number.map(|p| format!(", p. {}", p)).unwrap_or_default();
+        prompt.push_str(&format!(
+            "[{}] ({}{})\n{}\n\n",
+            passage.chunk_id,
+            passage.source,
+            page,
+            passage.content.trim()
+        ));
+    }
+
+    prompt.push_str(
+        r#"Please respond in JSON format with:
+{
+  "answerable": true|false,
+  "answer": "<answer citing passages inline, e.g. 'Grappled creatures have speed 0 [id].'>",
+  "citations": [{"chunk_id": "<passage ID>", "quote": "<short supporting quote>"}],
+  "confidence": 0.0-1.0,
+  "reason": "<why it can't be answered, when answerable is false>"
+}
+Cite only the passage IDs listed above."#,
+    );
+
+    prompt
+}
+
+/// An answer as written by the model
+#[derive(Debug, Deserialize)]
+struct RawAnswer {
+    #[serde(default = "default_answerable")]
+    answerable: bool,
+    #[serde(default)]
+    answer: String,
+    #[serde(default)]
+    citations: Vec<RawCitation>,
+    #[serde(default)]
+    confidence: Option<f32>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+fn default_answerable() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct RawCitation {
+    chunk_id: String,
+    #[serde(default)]
+    quote: Option<String>,
+}
+
+/// Parse the model's answer and check its citations against the passages.
+///
+/// Inline `[chunk_id]` markers become `[n]` footnote markers numbered by
+/// first appearance; markers for unknown IDs are removed. Refuses when the
+/// model couldn't answer, was unsure, or cited nothing that was retrieved.
+pub fn parse_rules_answer(
+    response: &str,
+    question: &str,
+    game_system: &str,
+    passages: &[RulesPassage],
+    retrieval_score: f32,
+) -> RulesAnswer {
+    let refuse = |reason: String| RulesAnswer::refused(question, game_system, reason, retrieval_score);
+
+    let trimmed = response.trim();
+    let Some(raw) = trimmed
+        .find('{')
+        .zip(trimmed.rfind('}'))
+        .filter(|(start, end)| start < end)
+        .and_then(|(start, end)| serde_json::from_str::<RawAnswer>(&trimmed[start..=end]).ok())
+    else {
+        return refuse("The answer could not be read".to_string());
+    };
+
+    if !raw.answerable || raw.answer.trim().is_empty() {
+        return refuse(
+            raw.reason
+                .filter(|r| !r.trim().is_empty())
+                .unwrap_or_else(|| "The rulebook passages don't answer this question".to_string()),
+        );
+    }
+    let confidence = raw.confidence.unwrap_or(0.0).clamp(0.0, 1.0);
+    if confidence < MIN_ANSWER_CONFIDENCE {
+        return refuse("Not confident enough in an answer from these passages".to_string());
+    }
+
+    let find = |id: &str| passages.iter().find(|p| p.chunk_id == id.trim());
+    let mut footnotes: Vec<RulesFootnote> = Vec::new();
+    let mut note = |passage: &RulesPassage, quote: Option<String>| -> usize {
+        if let Some(existing) = footnotes.iter_mut().find(|f| f.chunk_id == passage.chunk_id) {
+            if existing.quote.is_none() {
+                existing.quote = quote;
+            }
+            return existing.number;
+        }
+        let number = footnotes.len() + 1;
+        footnotes.push(RulesFootnote {
+            number,
+            chunk_id: passage.chunk_id.clone(),
+            source: passage.source.clone(),
+            page_number: passage.page_number,
+            quote,
+        });
+        number
+    };
+
+    // Rewrite inline markers, numbering footnotes as they appear
+    let mut answer = String::new();
+    let mut rest = raw.answer.trim();
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']').map(|c| open + c) else {
+            break;
+        };
+        answer.push_str(&rest[..open]);
+        let marker = &rest[open + 1..close];
+        let ids: Vec<&str> = marker.split(',').map(str::trim).collect();
+        let cited: Vec<&RulesPassage> = ids.iter().filter_map(|id| find(id)).collect();
+        if !cited.is_empty() {
+            for passage in cited {
+                let number = note(passage, None);
+                answer.push_str(&format!("[{}]", number));
+            }
+        } else if marker.contains(char::is_whitespace) {
+            // Bracketed prose rather than a citation
+            answer.push_str(&rest[open..=close]);
+        } else {
+            // An unknown passage ID: drop the marker and its leading space
+            answer.truncate(answer.trim_end().len());
+        }
+        rest = &rest[close + 1..];
+    }
+    answer.push_str(rest);
+
+    // Citations listed without an inline marker still back the answer
+    for citation in raw.citations {
+        if let Some(passage) = find(&citation.chunk_id) {
+            note(passage, citation.quote.filter(|q| !q.trim().is_empty()));
+        }
+    }
+
+    if footnotes.is_empty() {
+        return refuse("The answer did not cite any retrieved rulebook passage".to_string());
+    }
+
+    RulesAnswer {
+        question: question.to_string(),
+        game_system: game_system.to_string(),
+        status: RulesAnswerStatus::Answered,
+        answer,
+        footnotes,
+        confidence,
+        retrieval_score,
+        refusal_reason: None,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passages() -> Vec<RulesPassage> {
+        vec![
+            RulesPassage {
+                chunk_id: "phb-290-1".to_string(),
+                source: "Player's Handbook".to_string(),
+                page_number: Some(290),
+                content: "A grappled creature's speed becomes 0.".to_string(),
+            },
+            RulesPassage {
+                chunk_id: "phb-195-4".to_string(),
+                source: "Player's Handbook".to_string(),
+                page_number: Some(195),
+                content: "You can use the Attack action to make a special melee attack, a grapple.".to_string(),
+            },
+        ]
+    }
+
+    fn parse(response: &str) -> RulesAnswer {
+        parse_rules_answer(response, "How does grappling work?", "D&D 5th Edition", &passages(), 0.8)
+    }
+
+    #[test]
+    fn test_answer_markers_become_footnotes() {
+        let answer = parse(
+            r#"{"answerable": true,
+                "answer": "Grappling replaces an attack [phb-195-4]. The target's speed becomes 0 [phb-290-1] [made-up-7].",
+                "citations": [{"chunk_id": "phb-290-1", "quote": "speed becomes 0"}],
+                "confidence": 0.9}"#,
+        );
+
+        assert_eq!(answer.status, RulesAnswerStatus::Answered);
+        assert_eq!(answer.answer, "Grappling replaces an attack [1]. The target's speed becomes 0 [2].");
+        assert_eq!(answer.footnotes.len(), 2);
+        assert_eq!(answer.footnotes[0].chunk_id, "phb-195-4");
+        assert_eq!(answer.footnotes[1].page_number, Some(290));
+        assert_eq!(answer.footnotes[1].quote.as_deref(), Some("speed becomes 0"));
+        assert!(build_rules_prompt("q", "D&D 5th Edition", &passages()).contains("[phb-290-1] (Player's Handbook, p. 290)"));
+    }
+
+    #[test]
+    fn test_refuses_unsupported_answers() {
+        let uncited = parse(r#"{"answerable": true, "answer": "Roll a d20 [nowhere-1].", "confidence": 0.9}"#);
+        assert_eq!(uncited.status, RulesAnswerStatus::Refused);
+        assert!(uncited.footnotes.is_empty());
+
+        let unsure = parse(r#"{"answerable": true, "answer": "Maybe [phb-290-1].", "confidence": 0.2}"#);
+        assert_eq!(unsure.status, RulesAnswerStatus::Refused);
+
+        let declined = parse(r#"{"answerable": false, "answer": "", "reason": "Passages cover grappling, not shoving"}"#);
+        assert_eq!(declined.refusal_reason.as_deref(), Some("Passages cover grappling, not shoving"));
+
+        assert_eq!(parse("I think you roll Athletics.").status, RulesAnswerStatus::Refused);
+    }
+
+    #[test]
+    fn test_retrieval_refusal() {
+        assert!(retrieval_refusal(&[], 0.9).is_some());
+        assert!(retrieval_refusal(&passages(), 0.1).is_some());
+        assert!(retrieval_refusal(&passages(), 0.6).is_none());
+    }
+}
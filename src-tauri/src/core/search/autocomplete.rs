@@ -0,0 +1,289 @@
+//! Search Autocomplete
+//!
+//! A prefix index over corpus terms, entity names (NPCs, locations, spells,
+//! monsters), and past searches for as-you-type completions. Lookups are a
+//! binary search into sorted keys, so they stay well under a millisecond
+//! even with tens of thousands of entries.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Completions returned when no limit is requested
+pub const DEFAULT_SUGGESTION_LIMIT: usize = 8;
+
+/// Most corpus words and phrases kept, most frequent first
+pub const MAX_CORPUS_TERMS: usize = 20_000;
+
+/// Shortest corpus word worth suggesting
+const MIN_TERM_CHARS: usize = 3;
+
+/// Score multiplier for matching the start of an entry rather than a later word
+const LEADING_MATCH_BOOST: f32 = 1.5;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Where a suggestion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionKind {
+    History,
+    Npc,
+    Location,
+    Spell,
+    Monster,
+    Term,
+}
+
+impl SuggestionKind {
+    /// Base weight; things the user searched or named rank above raw terms
+    fn weight(&self) -> f32 {
+        match self {
+            SuggestionKind::History => 3.0,
+            SuggestionKind::Npc | SuggestionKind::Location => 2.5,
+            SuggestionKind::Spell | SuggestionKind::Monster => 2.0,
+            SuggestionKind::Term => 1.0,
+        }
+    }
+}
+
+/// A ranked completion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub text: String,
+    pub kind: SuggestionKind,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    text: String,
+    kind: SuggestionKind,
+    /// Frequency or search count
+    count: u64,
+}
+
+// ============================================================================
+// Suggestion Index
+// ============================================================================
+
+/// Prefix index of completions
+#[derive(Debug, Default)]
+pub struct SuggestionIndex {
+    entries: Vec<Entry>,
+    /// Normalized key, entry index, and whether the key starts the entry
+    keys: Vec<(String, usize, bool)>,
+}
+
+impl SuggestionIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add a completion. Names and past searches also match from any later
+    /// word ("dragon" finds "Red Dragon"); corpus terms only from the start.
+    pub fn add(&mut self, text: &str, kind: SuggestionKind, count: u64) {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let normalized = normalize(&text);
+        if normalized.is_empty() {
+            return;
+        }
+
+        let index = self.entries.len();
+        self.entries.push(Entry { text, kind, count });
+        self.keys.push((normalized.clone(), index, true));
+        if kind != SuggestionKind::Term {
+            for (position, _) in normalized.match_indices(' ') {
+                self.keys.push((normalized[position + 1..].to_string(), index, false));
+            }
+        }
+    }
+
+    /// Add corpus words or phrases from a SymSpell frequency dictionary
+    /// ("term count" per line), keeping the most frequent `max_terms`.
+    pub fn add_dictionary(&mut self, contents: &str, max_terms: usize) {
+        let mut terms: Vec<(&str, u64)> = contents
+            .lines()
+            .filter_map(|line| {
+                let (term, count) = line.trim().rsplit_once(' ')?;
+                Some((term, count.parse().ok()?))
+            })
+            .filter(|(term, _)| term.chars().count() >= MIN_TERM_CHARS && !term.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        terms.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        for (term, count) in terms.into_iter().take(max_terms) {
+            self.add(term, SuggestionKind::Term, count);
+        }
+    }
+
+    /// Read a dictionary file if it exists
+    pub fn add_dictionary_file(&mut self, path: &Path, max_terms: usize) -> std::io::Result<()> {
+        if path.exists() {
+            self.add_dictionary(&std::fs::read_to_string(path)?, max_terms);
+        }
+        Ok(())
+    }
+
+    /// Sort keys for lookup; call once after adding entries
+    pub fn finish(&mut self) {
+        self.keys.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    /// Ranked completions for what has been typed so far.
+    ///
+    /// Matches the whole input. When the input has several words, the last
+    /// word is also completed from corpus terms after the earlier words,
+    /// ranked below whole-input matches.
+    pub fn suggest(&self, partial: &str, limit: usize) -> Vec<Suggestion> {
+        let prefix = normalize(partial);
+        if prefix.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut best: HashMap<String, Suggestion> = HashMap::new();
+        let mut offer = |suggestion: Suggestion| {
+            let key = suggestion.text.to_lowercase();
+            match best.get(&key) {
+                Some(existing) if existing.score >= suggestion.score => {}
+                _ => {
+                    best.insert(key, suggestion);
+                }
+            }
+        };
+
+        for (entry, leading) in self.matching(&prefix) {
+            if normalize(&entry.text) == prefix {
+                continue;
+            }
+            offer(Suggestion {
+                text: entry.text.clone(),
+                kind: entry.kind,
+                score: score(entry, leading),
+            });
+        }
+
+        // Complete the last word of a multi-word input from corpus terms
+        let typed = partial.trim_end();
+        if let (Some((_, last)), Some(split)) = (prefix.rsplit_once(' '), typed.rfind(char::is_whitespace)) {
+            let kept = typed[..split].trim_end();
+            for (entry, leading) in self.matching(last) {
+                if entry.kind == SuggestionKind::Term && leading && normalize(&entry.text) != last {
+                    offer(Suggestion {
+                        text: format!("{} {}", kept, entry.text),
+                        kind: SuggestionKind::Term,
+                        // Below whole-input matches of the same strength
+                        score: score(entry, leading) * 0.5,
+                    });
+                }
+            }
+        }
+
+        let mut suggestions: Vec<Suggestion> = best.into_values().collect();
+        suggestions.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.text.len().cmp(&b.text.len()))
+                .then_with(|| a.text.cmp(&b.text))
+        });
+        suggestions.truncate(limit);
+        suggestions
+    }
+
+    /// Entries with a key starting with `prefix`, and whether that key
+    /// starts the entry
+    fn matching<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a Entry, bool)> + 'a {
+        let start = self.keys.partition_point(|(key, _, _)| key.as_str() < prefix);
+        self.keys[start..]
+            .iter()
+            .take_while(move |(key, _, _)| key.starts_with(prefix))
+            .map(|(_, index, leading)| (&self.entries[*index], *leading))
+    }
+}
+
+/// Lowercase words separated by single spaces, punctuation dropped
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Kind weight, nudged by frequency so a common term can't outrank a name
+fn score(entry: &Entry, leading: bool) -> f32 {
+    let frequency = 1.0 + ((1.0 + entry.count as f32).ln() / 10.0).min(1.0);
+    let position = if leading { LEADING_MATCH_BOOST } else { 1.0 };
+    entry.kind.weight() * frequency * position
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SuggestionIndex {
+        let mut index = SuggestionIndex::new();
+        index.add("Fireball", SuggestionKind::Spell, 1);
+        index.add("Fire Bolt", SuggestionKind::Spell, 1);
+        index.add("Red Dragon", SuggestionKind::Monster, 1);
+        index.add("Captain Fiora", SuggestionKind::Npc, 1);
+        index.add("fire resistance", SuggestionKind::History, 4);
+        index.add_dictionary("fire 900\nfiend 300\narcher 120\nat 5000\n12 40\n", MAX_CORPUS_TERMS);
+        index.finish();
+        index
+    }
+
+    #[test]
+    fn test_ranks_history_and_entities_above_terms() {
+        let suggestions = index().suggest("fi", 10);
+        let texts: Vec<&str> = suggestions.iter().map(|s| s.text.as_str()).collect();
+
+        assert_eq!(texts[0], "fire resistance");
+        assert!(texts.contains(&"Fireball"));
+        assert!(texts.contains(&"fiend"));
+        assert!(texts.contains(&"Captain Fiora"));
+        let term = texts.iter().position(|t| *t == "fiend").unwrap();
+        let spell = texts.iter().position(|t| *t == "Fire Bolt").unwrap();
+        assert!(spell < term);
+    }
+
+    #[test]
+    fn test_matches_later_words_of_names() {
+        let suggestions = index().suggest("drag", 5);
+        assert_eq!(suggestions[0].text, "Red Dragon");
+        assert_eq!(suggestions[0].kind, SuggestionKind::Monster);
+
+        // Typing the whole entry offers nothing new
+        assert!(index().suggest("Fireball", 5).iter().all(|s| s.text != "Fireball"));
+        assert!(index().suggest("", 5).is_empty());
+    }
+
+    #[test]
+    fn test_completes_last_word_from_terms() {
+        let suggestions = index().suggest("goblin arc", 5);
+        assert_eq!(suggestions[0].text, "goblin archer");
+        assert_eq!(suggestions[0].kind, SuggestionKind::Term);
+
+        // Short words and numbers aren't corpus terms
+        assert!(index().suggest("a", 10).iter().all(|s| s.text != "at"));
+        assert!(index().suggest("1", 10).is_empty());
+    }
+}
@@ -0,0 +1,395 @@
+//! Bookmarks
+//!
+//! Pinned search results for quick reference at the table. A bookmark keeps
+//! a copy of the passage it points at, so the in-session sidebar can show it
+//! without searching again. Bookmarks can belong to a campaign, to one of
+//! its sessions, or to neither.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Longest label accepted, in characters
+pub const MAX_LABEL_CHARS: usize = 80;
+
+/// Longest excerpt in the quick reference, in characters
+pub const QUICK_REFERENCE_EXCERPT_CHARS: usize = 240;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum BookmarkError {
+    #[error("Bookmark not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid bookmark: {0}")]
+    Invalid(String),
+
+    #[error("Failed to read or write bookmarks: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse bookmarks: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, BookmarkError>;
+
+// ============================================================================
+// Bookmarks
+// ============================================================================
+
+/// A search result or chunk to pin
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NewBookmark {
+    /// Index the result came from
+    pub index: String,
+    /// Document ID within the index, when known
+    pub document_id: Option<String>,
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub label: Option<String>,
+    pub campaign_id: Option<String>,
+    pub session_id: Option<String>,
+}
+
+/// A pinned passage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: String,
+    pub index: String,
+    pub document_id: Option<String>,
+    /// The passage as it was when pinned
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    pub label: Option<String>,
+    pub campaign_id: Option<String>,
+    /// Set for bookmarks kept for one session; campaign-wide otherwise
+    pub session_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Bookmark {
+    pub fn new(new: NewBookmark) -> Result<Self> {
+        let content = new.content.trim().to_string();
+        if content.is_empty() {
+            return Err(BookmarkError::Invalid("nothing to bookmark".to_string()));
+        }
+        if new.session_id.is_some() && new.campaign_id.is_none() {
+            return Err(BookmarkError::Invalid("a session bookmark needs its campaign".to_string()));
+        }
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            index: new.index.trim().to_string(),
+            document_id: new.document_id.filter(|id| !id.trim().is_empty()),
+            content,
+            source: new.source.trim().to_string(),
+            page_number: new.page_number,
+            label: normalize_label(new.label)?,
+            campaign_id: new.campaign_id,
+            session_id: new.session_id,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Whether this points at the same passage as `other`
+    fn same_target(&self, other: &Bookmark) -> bool {
+        self.index == other.index
+            && match (&self.document_id, &other.document_id) {
+                (Some(a), Some(b)) => a == b,
+                _ => self.content == other.content,
+            }
+    }
+
+    /// The label, or the source and page
+    pub fn title(&self) -> String {
+        match (&self.label, self.page_number) {
+            (Some(label), _) => label.clone(),
+            (None, Some(page)) => format!("{} p. {}", self.source, page),
+            (None, None) => self.source.clone(),
+        }
+    }
+}
+
+/// Trimmed label, `None` when blank
+fn normalize_label(label: Option<String>) -> Result<Option<String>> {
+    let label = label.map(|l| l.split_whitespace().collect::<Vec<_>>().join(" ")).filter(|l| !l.is_empty());
+    if label.as_ref().is_some_and(|l| l.chars().count() > MAX_LABEL_CHARS) {
+        return Err(BookmarkError::Invalid(format!("labels are at most {} characters", MAX_LABEL_CHARS)));
+    }
+    Ok(label)
+}
+
+/// Bookmarks sharing a campaign and session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkGroup {
+    pub campaign_id: Option<String>,
+    pub session_id: Option<String>,
+    pub bookmarks: Vec<Bookmark>,
+}
+
+/// A bookmark as shown in the in-session sidebar
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickReferenceItem {
+    pub bookmark_id: String,
+    pub title: String,
+    /// Start of the passage, cut at a word
+    pub excerpt: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    /// Pinned for this session rather than the whole campaign
+    pub session_only: bool,
+}
+
+impl From<&Bookmark> for QuickReferenceItem {
+    fn from(bookmark: &Bookmark) -> Self {
+        Self {
+            bookmark_id: bookmark.id.clone(),
+            title: bookmark.title(),
+            excerpt: excerpt(&bookmark.content, QUICK_REFERENCE_EXCERPT_CHARS),
+            source: bookmark.source.clone(),
+            page_number: bookmark.page_number,
+            session_only: bookmark.session_id.is_some(),
+        }
+    }
+}
+
+/// The first `max_chars` of `text` with whitespace collapsed, cut back to a
+/// word boundary when it has to be shortened
+fn excerpt(text: &str, max_chars: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space > 0 => &cut[..space],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+}
+
+// ============================================================================
+// Bookmark Library
+// ============================================================================
+
+/// All bookmarks, persisted as one JSON file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookmarkLibrary {
+    bookmarks: Vec<Bookmark>,
+}
+
+impl BookmarkLibrary {
+    /// Load from a JSON file; a missing file yields an empty library
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|b| b.id == id)
+    }
+
+    /// Add a bookmark. Pinning a passage already pinned in the same
+    /// campaign and session returns the existing bookmark, relabelled when
+    /// a label is given.
+    pub fn create(&mut self, bookmark: Bookmark) -> Bookmark {
+        if let Some(existing) = self.bookmarks.iter_mut().find(|b| {
+            b.campaign_id == bookmark.campaign_id && b.session_id == bookmark.session_id && b.same_target(&bookmark)
+        }) {
+            if bookmark.label.is_some() {
+                existing.label = bookmark.label;
+            }
+            return existing.clone();
+        }
+        self.bookmarks.push(bookmark.clone());
+        bookmark
+    }
+
+    /// Replace a bookmark's label and its campaign and session
+    pub fn update(
+        &mut self,
+        id: &str,
+        label: Option<String>,
+        campaign_id: Option<String>,
+        session_id: Option<String>,
+    ) -> Result<Bookmark> {
+        if session_id.is_some() && campaign_id.is_none() {
+            return Err(BookmarkError::Invalid("a session bookmark needs its campaign".to_string()));
+        }
+        let label = normalize_label(label)?;
+        let bookmark = self
+            .bookmarks
+            .iter_mut()
+            .find(|b| b.id == id)
+            .ok_or_else(|| BookmarkError::NotFound(id.to_string()))?;
+        bookmark.label = label;
+        bookmark.campaign_id = campaign_id;
+        bookmark.session_id = session_id;
+        Ok(bookmark.clone())
+    }
+
+    pub fn delete(&mut self, id: &str) -> Result<Bookmark> {
+        let index = self
+            .bookmarks
+            .iter()
+            .position(|b| b.id == id)
+            .ok_or_else(|| BookmarkError::NotFound(id.to_string()))?;
+        Ok(self.bookmarks.remove(index))
+    }
+
+    /// Bookmarks grouped by campaign, then session, oldest first in each
+    /// group. Unassigned bookmarks come first, and a campaign's
+    /// campaign-wide group before its session groups. With `campaign_id`,
+    /// only that campaign's groups.
+    pub fn grouped(&self, campaign_id: Option<&str>) -> Vec<BookmarkGroup> {
+        let mut groups: BTreeMap<(Option<String>, Option<String>), Vec<Bookmark>> = BTreeMap::new();
+        for bookmark in self
+            .bookmarks
+            .iter()
+            .filter(|b| campaign_id.is_none() || b.campaign_id.as_deref() == campaign_id)
+        {
+            groups
+                .entry((bookmark.campaign_id.clone(), bookmark.session_id.clone()))
+                .or_default()
+                .push(bookmark.clone());
+        }
+        groups
+            .into_iter()
+            .map(|((campaign_id, session_id), mut bookmarks)| {
+                bookmarks.sort_by_key(|b| b.created_at);
+                BookmarkGroup { campaign_id, session_id, bookmarks }
+            })
+            .collect()
+    }
+
+    /// The sidebar for a session: its own bookmarks, then the campaign-wide
+    /// ones, oldest first in each
+    pub fn quick_reference(&self, campaign_id: &str, session_id: &str) -> Vec<QuickReferenceItem> {
+        let mut items: Vec<&Bookmark> = self
+            .bookmarks
+            .iter()
+            .filter(|b| b.campaign_id.as_deref() == Some(campaign_id))
+            .filter(|b| b.session_id.is_none() || b.session_id.as_deref() == Some(session_id))
+            .collect();
+        items.sort_by_key(|b| (b.session_id.is_none(), b.created_at));
+        items.into_iter().map(QuickReferenceItem::from).collect()
+    }
+
+    /// Remove a deleted campaign's bookmarks
+    pub fn delete_campaign_bookmarks(&mut self, campaign_id: &str) {
+        self.bookmarks.retain(|b| b.campaign_id.as_deref() != Some(campaign_id));
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pin(content: &str, campaign_id: Option<&str>, session_id: Option<&str>) -> Bookmark {
+        Bookmark::new(NewBookmark {
+            index: "rules".to_string(),
+            content: content.to_string(),
+            source: "PHB".to_string(),
+            page_number: Some(251),
+            campaign_id: campaign_id.map(str::to_string),
+            session_id: session_id.map(str::to_string),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_bookmark_is_validated() {
+        assert!(Bookmark::new(NewBookmark { content: "  ".to_string(), ..Default::default() }).is_err());
+        assert!(Bookmark::new(NewBookmark {
+            content: "Grapple".to_string(),
+            session_id: Some("s1".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Bookmark::new(NewBookmark {
+            content: "Grapple".to_string(),
+            label: Some("x".repeat(MAX_LABEL_CHARS + 1)),
+            ..Default::default()
+        })
+        .is_err());
+
+        let bookmark = Bookmark::new(NewBookmark {
+            content: " Flanking grants advantage ".to_string(),
+            source: "DMG".to_string(),
+            label: Some("   ".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(bookmark.content, "Flanking grants advantage");
+        assert_eq!(bookmark.label, None);
+        assert_eq!(bookmark.title(), "DMG");
+    }
+
+    #[test]
+    fn test_create_dedupes_and_groups() {
+        let mut library = BookmarkLibrary::default();
+        let first = library.create(pin("Grappling rules", Some("c1"), None));
+        let mut again = pin("Grappling rules", Some("c1"), None);
+        again.label = Some("Grapple".to_string());
+        let again = library.create(again);
+        assert_eq!(again.id, first.id);
+        assert_eq!(again.label.as_deref(), Some("Grapple"));
+
+        library.create(pin("Grappling rules", Some("c1"), Some("s1")));
+        library.create(pin("Cover rules", None, None));
+        library.create(pin("Lair actions", Some("c2"), None));
+
+        let groups = library.grouped(None);
+        let keys: Vec<_> = groups.iter().map(|g| (g.campaign_id.as_deref(), g.session_id.as_deref())).collect();
+        assert_eq!(keys, vec![(None, None), (Some("c1"), None), (Some("c1"), Some("s1")), (Some("c2"), None)]);
+        assert_eq!(library.grouped(Some("c1")).len(), 2);
+
+        library.delete_campaign_bookmarks("c1");
+        assert_eq!(library.grouped(None).len(), 2);
+    }
+
+    #[test]
+    fn test_quick_reference_puts_session_pins_first() {
+        let mut library = BookmarkLibrary::default();
+        library.create(pin("Campaign-wide house rule on crits", Some("c1"), None));
+        library.create(pin("Other session's pin", Some("c1"), Some("s0")));
+        let long = format!("Opportunity attacks. {}", "You can make one when a creature leaves your reach. ".repeat(10));
+        library.create(pin(&long, Some("c1"), Some("s1")));
+
+        let items = library.quick_reference("c1", "s1");
+
+        assert_eq!(items.len(), 2);
+        assert!(items[0].session_only);
+        assert_eq!(items[0].title, "PHB p. 251");
+        assert!(items[0].excerpt.ends_with('…'));
+        assert!(items[0].excerpt.chars().count() <= QUICK_REFERENCE_EXCERPT_CHARS + 1);
+        assert_eq!(items[1].excerpt, "Campaign-wide house rule on crits");
+    }
+}
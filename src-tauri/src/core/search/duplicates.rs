@@ -0,0 +1,319 @@
+//! Duplicate Content Detection
+//!
+//! Finds near-duplicate campaign notes and ingested chunks by comparing
+//! their embeddings. Items whose similarity passes the threshold are grouped
+//! into clusters, each with the item to keep and a suggestion: delete the
+//! others when they say the same thing, or merge them when they overlap.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Cosine similarity at which two items count as near-duplicates
+pub const DEFAULT_DUPLICATE_THRESHOLD: f32 = 0.92;
+
+/// Cosine similarity at which a duplicate adds nothing and can be deleted
+pub const DELETE_THRESHOLD: f32 = 0.98;
+
+/// Longest preview in a report, in characters
+pub const PREVIEW_CHARS: usize = 160;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum DuplicateError {
+    #[error("Similarity threshold must be between 0.5 and 1.0, got {0}")]
+    InvalidThreshold(f32),
+}
+
+pub type Result<T> = std::result::Result<T, DuplicateError>;
+
+// ============================================================================
+// Duplicate Types
+// ============================================================================
+
+/// What a scanned item is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateKind {
+    /// A campaign note
+    Note,
+    /// An ingested document chunk
+    Chunk,
+}
+
+/// An item to scan, with its embedding
+#[derive(Debug, Clone)]
+pub struct DuplicateCandidate {
+    pub id: String,
+    pub kind: DuplicateKind,
+    pub content: String,
+    /// Source document for chunks
+    pub source: Option<String>,
+    pub page_number: Option<i32>,
+    pub embedding: Vec<f32>,
+}
+
+/// A clustered item, as reported
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateMember {
+    pub id: String,
+    pub kind: DuplicateKind,
+    pub preview: String,
+    pub source: Option<String>,
+    pub page_number: Option<i32>,
+    /// Cosine similarity to the item kept (1.0 for the item itself)
+    pub similarity: f32,
+}
+
+/// What to do with a cluster's duplicates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateSuggestion {
+    /// They repeat the kept item; delete them
+    Delete,
+    /// They overlap the kept item; merge what they add into it
+    Merge,
+}
+
+/// Near-duplicates of one item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateCluster {
+    pub kind: DuplicateKind,
+    /// The longest item, which the others would be merged into
+    pub keep: DuplicateMember,
+    /// Most similar first
+    pub duplicates: Vec<DuplicateMember>,
+    pub suggestion: DuplicateSuggestion,
+}
+
+/// Result of a duplicate scan
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateReport {
+    /// Largest clusters first
+    pub clusters: Vec<DuplicateCluster>,
+    pub notes_scanned: usize,
+    pub chunks_scanned: usize,
+    /// Items that couldn't be compared, e.g. chunks not yet embedded
+    pub skipped: usize,
+    pub threshold: f32,
+}
+
+impl DuplicateReport {
+    /// Items the suggestions would remove
+    pub fn duplicate_count(&self) -> usize {
+        self.clusters.iter().map(|c| c.duplicates.len()).sum()
+    }
+}
+
+// ============================================================================
+// Detection
+// ============================================================================
+
+/// Cosine similarity of two embeddings; 0.0 when their lengths differ or
+/// either is zero
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Check a user-supplied threshold, defaulting to [`DEFAULT_DUPLICATE_THRESHOLD`]
+pub fn check_threshold(threshold: Option<f32>) -> Result<f32> {
+    let threshold = threshold.unwrap_or(DEFAULT_DUPLICATE_THRESHOLD);
+    if (0.5..=1.0).contains(&threshold) {
+        Ok(threshold)
+    } else {
+        Err(DuplicateError::InvalidThreshold(threshold))
+    }
+}
+
+/// Group candidates whose embeddings are at least `threshold` similar.
+///
+/// Items are only compared with items of the same kind. Similarity is
+/// transitive within a cluster, so a chain of close items forms one.
+pub fn find_duplicate_clusters(candidates: &[DuplicateCandidate], threshold: f32) -> Vec<DuplicateCluster> {
+    let mut parent: Vec<usize> = (0..candidates.len()).collect();
+    for i in 0..candidates.len() {
+        for j in (i + 1)..candidates.len() {
+            let (a, b) = (&candidates[i], &candidates[j]);
+            if a.kind == b.kind && cosine_similarity(&a.embedding, &b.embedding) >= threshold {
+                let (root_a, root_b) = (find_root(&mut parent, i), find_root(&mut parent, j));
+                if root_a != root_b {
+                    parent[root_b] = root_a;
+                }
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of_root = HashMap::new();
+    for i in 0..candidates.len() {
+        let root = find_root(&mut parent, i);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(i);
+    }
+
+    let mut clusters: Vec<DuplicateCluster> = groups
+        .into_iter()
+        .filter(|members| members.len() > 1)
+        .map(|members| build_cluster(candidates, &members))
+        .collect();
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.duplicates.len()));
+    clusters
+}
+
+fn find_root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// Keep the longest member, earliest on ties
+fn build_cluster(candidates: &[DuplicateCandidate], members: &[usize]) -> DuplicateCluster {
+    let keep = members
+        .iter()
+        .copied()
+        .max_by(|&a, &b| {
+            candidates[a]
+                .content
+                .trim()
+                .len()
+                .cmp(&candidates[b].content.trim().len())
+                .then(b.cmp(&a))
+        })
+        .unwrap_or(members[0]);
+    let kept = &candidates[keep];
+
+    let mut duplicates: Vec<DuplicateMember> = members
+        .iter()
+        .filter(|&&i| i != keep)
+        .map(|&i| member(&candidates[i], cosine_similarity(&kept.embedding, &candidates[i].embedding)))
+        .collect();
+    duplicates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+
+    let repeats = duplicates.iter().all(|d| d.similarity >= DELETE_THRESHOLD)
+        || members
+            .iter()
+            .all(|&i| normalize(&candidates[i].content) == normalize(&kept.content));
+    DuplicateCluster {
+        kind: kept.kind,
+        keep: member(kept, 1.0),
+        duplicates,
+        suggestion: if repeats { DuplicateSuggestion::Delete } else { DuplicateSuggestion::Merge },
+    }
+}
+
+fn member(candidate: &DuplicateCandidate, similarity: f32) -> DuplicateMember {
+    DuplicateMember {
+        id: candidate.id.clone(),
+        kind: candidate.kind,
+        preview: preview(&candidate.content),
+        source: candidate.source.clone(),
+        page_number: candidate.page_number,
+        similarity,
+    }
+}
+
+/// The content on one line, cut to [`PREVIEW_CHARS`]
+fn preview(content: &str) -> String {
+    let text = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
+
+fn normalize(content: &str) -> String {
+    content
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, kind: DuplicateKind, content: &str, embedding: &[f32]) -> DuplicateCandidate {
+        DuplicateCandidate {
+            id: id.to_string(),
+            kind,
+            content: content.to_string(),
+            source: None,
+            page_number: None,
+            embedding: embedding.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0, 0.0, 0.0]), 0.0);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        assert!(check_threshold(None).is_ok());
+        assert!(check_threshold(Some(0.2)).is_err());
+    }
+
+    #[test]
+    fn test_clusters_near_duplicates_of_the_same_kind() {
+        let candidates = vec![
+            candidate("a", DuplicateKind::Note, "The duke hired the thieves.", &[1.0, 0.0, 0.0]),
+            candidate("b", DuplicateKind::Note, "The duke hired the thieves guild to steal it.", &[0.95, 0.2, 0.0]),
+            candidate("c", DuplicateKind::Note, "Goblins raid the mill.", &[0.0, 1.0, 0.0]),
+            candidate("d", DuplicateKind::Chunk, "The duke hired the thieves.", &[1.0, 0.0, 0.0]),
+        ];
+        let clusters = find_duplicate_clusters(&candidates, 0.9);
+
+        assert_eq!(clusters.len(), 1);
+        let cluster = &clusters[0];
+        assert_eq!(cluster.kind, DuplicateKind::Note);
+        assert_eq!(cluster.keep.id, "b");
+        assert_eq!(cluster.duplicates.len(), 1);
+        assert_eq!(cluster.duplicates[0].id, "a");
+        assert_eq!(cluster.suggestion, DuplicateSuggestion::Merge);
+    }
+
+    #[test]
+    fn test_identical_items_are_suggested_for_deletion() {
+        let candidates = vec![
+            candidate("x", DuplicateKind::Chunk, "Fireball  deals 8d6.", &[0.3, 0.4, 0.5]),
+            candidate("y", DuplicateKind::Chunk, "fireball deals 8d6.", &[0.3, 0.41, 0.5]),
+            candidate("z", DuplicateKind::Chunk, "Fireball deals 8d6.", &[0.31, 0.4, 0.5]),
+        ];
+        let clusters = find_duplicate_clusters(&candidates, 0.95);
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].keep.id, "x");
+        assert_eq!(clusters[0].duplicates.len(), 2);
+        assert_eq!(clusters[0].suggestion, DuplicateSuggestion::Delete);
+        assert_eq!(clusters[0].keep.preview, "Fireball deals 8d6.");
+    }
+}
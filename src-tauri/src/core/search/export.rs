@@ -0,0 +1,219 @@
+//! Search Result Export
+//!
+//! Turns search results picked during prep into the text of a campaign note
+//! or handout: each passage quoted with a numbered citation, followed by the
+//! list of sources they came from.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Most results one export may hold
+pub const MAX_EXPORT_RESULTS: usize = 50;
+
+/// Tag added to every exported note and handout
+pub const EXPORT_TAG: &str = "research";
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("No search results selected")]
+    NothingSelected,
+
+    #[error("At most {MAX_EXPORT_RESULTS} search results can be exported at once")]
+    TooManyResults,
+
+    #[error("Export title cannot be empty")]
+    EmptyTitle,
+}
+
+pub type Result<T> = std::result::Result<T, ExportError>;
+
+// ============================================================================
+// Export Types
+// ============================================================================
+
+/// What the selected results become
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportTarget {
+    /// A campaign note, for the GM
+    Note,
+    /// A text handout, hidden until revealed to players
+    Handout,
+}
+
+/// A search result picked for export
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportedResult {
+    pub content: String,
+    pub source: String,
+    pub page_number: Option<u32>,
+    /// Heading shown above the passage instead of the source
+    pub label: Option<String>,
+}
+
+impl ExportedResult {
+    /// "Source, p. N", or the source alone
+    pub fn citation(&self) -> String {
+        let source = match self.source.trim() {
+            "" => "Unknown source",
+            source => source,
+        };
+        match self.page_number {
+            Some(page) => format!("{}, p. {}", source, page),
+            None => source.to_string(),
+        }
+    }
+}
+
+/// Formatted export, ready to save as a note or handout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportDocument {
+    pub title: String,
+    /// Markdown: the quoted passages, then the sources
+    pub body: String,
+    /// One per distinct source and page, in order of first use
+    pub citations: Vec<String>,
+}
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+/// Format `results` under `title`. Blank results are dropped; passages from
+/// the same source and page share one citation number.
+pub fn format_export(title: &str, results: &[ExportedResult]) -> Result<ExportDocument> {
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.is_empty() {
+        return Err(ExportError::EmptyTitle);
+    }
+    if results.len() > MAX_EXPORT_RESULTS {
+        return Err(ExportError::TooManyResults);
+    }
+
+    let mut citations: Vec<String> = Vec::new();
+    let mut sections = Vec::new();
+    for result in results {
+        let content = result.content.trim();
+        if content.is_empty() {
+            continue;
+        }
+        let citation = result.citation();
+        let number = match citations.iter().position(|c| *c == citation) {
+            Some(i) => i + 1,
+            None => {
+                citations.push(citation.clone());
+                citations.len()
+            }
+        };
+        let heading = result
+            .label
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .unwrap_or(&citation);
+        let quote: Vec<String> = content
+            .lines()
+            .map(|line| match line.trim_end() {
+                "" => ">".to_string(),
+                line => format!("> {}", line),
+            })
+            .collect();
+        sections.push(format!("### {} [{}]\n\n{}", heading, number, quote.join("\n")));
+    }
+    if sections.is_empty() {
+        return Err(ExportError::NothingSelected);
+    }
+
+    let sources: Vec<String> = citations
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("{}. {}", i + 1, c))
+        .collect();
+    let body = format!(
+        "## {}\n\n{}\n\n**Sources**\n\n{}\n",
+        title,
+        sections.join("\n\n"),
+        sources.join("\n")
+    );
+    Ok(ExportDocument { title, body, citations })
+}
+
+/// The export tag plus `tags`, trimmed and without duplicates
+pub fn export_tags(tags: Vec<String>) -> Vec<String> {
+    let mut merged = vec![EXPORT_TAG.to_string()];
+    for tag in tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !merged.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            merged.push(tag.to_string());
+        }
+    }
+    merged
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(content: &str, source: &str, page: Option<u32>) -> ExportedResult {
+        ExportedResult {
+            content: content.to_string(),
+            source: source.to_string(),
+            page_number: page,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_passages_are_quoted_with_shared_citations() {
+        let results = vec![
+            result("Fireball deals 8d6 fire damage.", "PHB", Some(241)),
+            result("Goblins are small.\n\nThey hate dwarves.", "MM", Some(166)),
+            result("A bright streak flashes.", "PHB", Some(241)),
+        ];
+        let doc = format_export("  Ambush   prep ", &results).unwrap();
+
+        assert_eq!(doc.title, "Ambush prep");
+        assert_eq!(doc.citations, vec!["PHB, p. 241", "MM, p. 166"]);
+        assert!(doc.body.starts_with("## Ambush prep\n"));
+        assert!(doc.body.contains("### PHB, p. 241 [1]\n\n> Fireball deals 8d6 fire damage."));
+        assert!(doc.body.contains("> Goblins are small.\n>\n> They hate dwarves."));
+        assert_eq!(doc.body.matches("[1]").count(), 2);
+        assert!(doc.body.ends_with("**Sources**\n\n1. PHB, p. 241\n2. MM, p. 166\n"));
+    }
+
+    #[test]
+    fn test_labels_and_missing_sources() {
+        let mut labelled = result("Roll on the table.", "", None);
+        labelled.label = Some("Wild Magic".to_string());
+        let doc = format_export("Notes", &[labelled, result("   ", "DMG", Some(1))]).unwrap();
+
+        assert!(doc.body.contains("### Wild Magic [1]"));
+        assert_eq!(doc.citations, vec!["Unknown source"]);
+    }
+
+    #[test]
+    fn test_rejects_empty_exports() {
+        assert!(matches!(format_export("Prep", &[]), Err(ExportError::NothingSelected)));
+        assert!(matches!(
+            format_export("Prep", &[result(" ", "PHB", None)]),
+            Err(ExportError::NothingSelected)
+        ));
+        assert!(matches!(format_export(" ", &[result("x", "PHB", None)]), Err(ExportError::EmptyTitle)));
+        let many = vec![result("x", "PHB", None); MAX_EXPORT_RESULTS + 1];
+        assert!(matches!(format_export("Prep", &many), Err(ExportError::TooManyResults)));
+        assert_eq!(export_tags(vec![" Research ".into(), "goblins".into(), "".into()]), vec!["research", "goblins"]);
+    }
+}
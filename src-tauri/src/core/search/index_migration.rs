@@ -0,0 +1,558 @@
+//! Index Migrations
+//!
+//! Moves a search index to a new schema version without taking it offline.
+//! The new index is built next to the live one under a staging name, filled
+//! with the live index's documents, checked, and then swapped with the live
+//! index in one step, so searches see either the old index or the complete
+//! new one. The old index, now under the staging name, is deleted last.
+//!
+//! Versions applied are kept in a JSON file. An index already on disk with no
+//! recorded version is taken to be on version 1, the version every index
+//! definition starts at; bumping a definition's version is what triggers a
+//! migration. Statuses reuse the campaign data migration's
+//! [`MigrationStatus`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+pub use crate::core::campaign::migration::MigrationStatus;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Version of an index with no recorded version
+pub const BASELINE_INDEX_VERSION: u32 = 1;
+
+/// Documents copied per batch
+pub const MIGRATION_BATCH_SIZE: usize = 1000;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum IndexMigrationError {
+    #[error("Index operation failed: {0}")]
+    Backend(String),
+
+    #[error("Index '{index}' has {found} documents after copying, expected {expected}")]
+    Verification { index: String, expected: u64, found: u64 },
+
+    #[error("Failed to read or write index versions: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse index versions: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, IndexMigrationError>;
+
+// ============================================================================
+// Index Definitions
+// ============================================================================
+
+/// An index as the current app version defines it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexDefinition {
+    pub uid: String,
+    pub primary_key: Option<String>,
+    /// Bumped whenever the settings or primary key change
+    pub version: u32,
+    /// Meilisearch settings, in Meilisearch's JSON format
+    pub settings: serde_json::Value,
+}
+
+impl IndexDefinition {
+    /// Where the new version is built before the swap
+    pub fn staging_uid(&self) -> String {
+        format!("{}__v{}", self.uid, self.version)
+    }
+}
+
+/// The schema version each index was last built with, persisted as one
+/// JSON file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexVersions {
+    versions: BTreeMap<String, u32>,
+}
+
+impl IndexVersions {
+    /// Load from a JSON file; a missing file records no versions
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// The recorded version, or the baseline for an index never recorded
+    pub fn get(&self, uid: &str) -> u32 {
+        self.versions.get(uid).copied().unwrap_or(BASELINE_INDEX_VERSION)
+    }
+
+    pub fn set(&mut self, uid: &str, version: u32) {
+        self.versions.insert(uid.to_string(), version);
+    }
+}
+
+// ============================================================================
+// Migration Status
+// ============================================================================
+
+/// Where a migration has got to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationPhase {
+    /// Waiting its turn
+    Pending,
+    /// Creating the staging index with the new settings
+    Building,
+    /// Copying documents into the staging index
+    Copying,
+    /// Swapping the staging and live indexes
+    Swapping,
+    /// Deleting the old index
+    CleaningUp,
+    Done,
+}
+
+/// One index's move to a new schema version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexMigration {
+    pub id: String,
+    pub index: String,
+    pub staging_index: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub phase: MigrationPhase,
+    pub status: MigrationStatus,
+    pub documents_total: u64,
+    pub documents_copied: u64,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+}
+
+impl IndexMigration {
+    pub fn new(definition: &IndexDefinition, from_version: u32) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            index: definition.uid.clone(),
+            staging_index: definition.staging_uid(),
+            from_version,
+            to_version: definition.version,
+            phase: MigrationPhase::Pending,
+            status: MigrationStatus::InProgress,
+            documents_total: 0,
+            documents_copied: 0,
+            started_at: Utc::now(),
+            completed_at: None,
+            error: None,
+        }
+    }
+
+    /// Share of the documents copied, 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        match self.phase {
+            MigrationPhase::Pending | MigrationPhase::Building => 0.0,
+            MigrationPhase::Copying => self.documents_copied as f32 / self.documents_total.max(1) as f32,
+            _ => 1.0,
+        }
+    }
+
+    fn finish(&mut self, status: MigrationStatus, error: Option<String>) {
+        self.status = status;
+        self.error = error;
+        self.completed_at = Some(Utc::now());
+    }
+}
+
+/// Every migration this run, for status reporting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexMigrationReport {
+    pub migrations: Vec<IndexMigration>,
+    /// Indexes that didn't exist and were created at their current version
+    pub created: Vec<String>,
+}
+
+impl IndexMigrationReport {
+    /// Whether any migration is still under way
+    pub fn in_progress(&self) -> bool {
+        self.migrations.iter().any(|m| m.status == MigrationStatus::InProgress)
+    }
+
+    /// One line for the log
+    pub fn summary(&self) -> String {
+        let count = |status: MigrationStatus| self.migrations.iter().filter(|m| m.status == status).count();
+        format!(
+            "Index migrations: {} completed, {} rolled back, {} created",
+            count(MigrationStatus::Completed),
+            count(MigrationStatus::RolledBack),
+            self.created.len()
+        )
+    }
+}
+
+// ============================================================================
+// Backend
+// ============================================================================
+
+/// The index operations a migration needs. Each call returns once the
+/// operation has finished.
+pub trait IndexMigrationBackend {
+    fn index_exists(&self, uid: &str) -> std::result::Result<bool, String>;
+    fn create_index(&self, uid: &str, primary_key: Option<String>) -> std::result::Result<(), String>;
+    fn update_settings(&self, uid: &str, settings: &serde_json::Value) -> std::result::Result<(), String>;
+    fn document_count(&self, uid: &str) -> std::result::Result<u64, String>;
+    /// A page of documents and the index's document count
+    fn read_documents(
+        &self,
+        uid: &str,
+        offset: usize,
+        limit: usize,
+    ) -> std::result::Result<(u64, Vec<serde_json::Value>), String>;
+    fn add_documents(
+        &self,
+        uid: &str,
+        documents: Vec<serde_json::Value>,
+        primary_key: Option<String>,
+    ) -> std::result::Result<(), String>;
+    /// Exchange two indexes' contents in one step
+    fn swap_indexes(&self, a: &str, b: &str) -> std::result::Result<(), String>;
+    fn delete_index(&self, uid: &str) -> std::result::Result<(), String>;
+}
+
+// ============================================================================
+// Migration
+// ============================================================================
+
+/// Bring each index up to its definition's version: create missing ones,
+/// migrate stale ones, and record the versions applied. `on_progress` sees
+/// the report after every step. A failed migration leaves its live index
+/// untouched and the rest still run.
+pub fn run_index_migrations(
+    backend: &impl IndexMigrationBackend,
+    definitions: &[IndexDefinition],
+    versions: &mut IndexVersions,
+    mut on_progress: impl FnMut(&IndexMigrationReport),
+) -> IndexMigrationReport {
+    let mut report = IndexMigrationReport::default();
+    let mut pending = Vec::new();
+    for definition in definitions {
+        match backend.index_exists(&definition.uid) {
+            Ok(false) => match create_fresh(backend, definition) {
+                Ok(()) => {
+                    versions.set(&definition.uid, definition.version);
+                    report.created.push(definition.uid.clone());
+                }
+                Err(e) => log::warn!("Failed to create index '{}': {}", definition.uid, e),
+            },
+            Ok(true) if versions.get(&definition.uid) < definition.version => {
+                report.migrations.push(IndexMigration::new(definition, versions.get(&definition.uid)));
+                pending.push(definition);
+            }
+            Ok(true) => {}
+            Err(e) => log::warn!("Failed to check index '{}': {}", definition.uid, e),
+        }
+    }
+    on_progress(&report);
+
+    for (position, definition) in pending.into_iter().enumerate() {
+        let result = migrate_index(backend, definition, &mut report, position, &mut on_progress);
+        let migration = &mut report.migrations[position];
+        match result {
+            Ok(()) => {
+                versions.set(&definition.uid, definition.version);
+                migration.phase = MigrationPhase::Done;
+                migration.finish(MigrationStatus::Completed, None);
+            }
+            Err(e) => {
+                log::error!("Migration of index '{}' failed: {}", definition.uid, e);
+                if let Err(cleanup) = backend.delete_index(&definition.staging_uid()) {
+                    log::warn!("Failed to remove staging index '{}': {}", definition.staging_uid(), cleanup);
+                }
+                migration.finish(MigrationStatus::RolledBack, Some(e.to_string()));
+            }
+        }
+        on_progress(&report);
+    }
+    report
+}
+
+/// Create an index at its current version
+fn create_fresh(backend: &impl IndexMigrationBackend, definition: &IndexDefinition) -> Result<()> {
+    backend
+        .create_index(&definition.uid, definition.primary_key.clone())
+        .map_err(IndexMigrationError::Backend)?;
+    backend
+        .update_settings(&definition.uid, &definition.settings)
+        .map_err(IndexMigrationError::Backend)
+}
+
+/// Build the staging index, fill it, swap it in, and delete the old index.
+///
+/// Documents written to the live index while copying are picked up by one
+/// more full pass when its count has changed; additions are upserts, so
+/// copying a document twice is harmless. Nothing touches the live index
+/// before the swap.
+fn migrate_index(
+    backend: &impl IndexMigrationBackend,
+    definition: &IndexDefinition,
+    report: &mut IndexMigrationReport,
+    position: usize,
+    on_progress: &mut impl FnMut(&IndexMigrationReport),
+) -> Result<()> {
+    let (uid, staging) = (definition.uid.as_str(), definition.staging_uid());
+    let mut step = |report: &mut IndexMigrationReport, update: &dyn Fn(&mut IndexMigration)| {
+        update(&mut report.migrations[position]);
+        on_progress(report);
+    };
+    let backend_err = IndexMigrationError::Backend;
+
+    step(report, &|m| m.phase = MigrationPhase::Building);
+    // A staging index left by an interrupted migration may be incomplete
+    if backend.index_exists(&staging).map_err(backend_err)? {
+        backend.delete_index(&staging).map_err(backend_err)?;
+    }
+    create_fresh(backend, &IndexDefinition { uid: staging.clone(), ..definition.clone() })?;
+
+    step(report, &|m| m.phase = MigrationPhase::Copying);
+    let mut copied = copy_documents(backend, definition, &staging, report, &mut step)?;
+    let live_count = backend.document_count(uid).map_err(backend_err)?;
+    if live_count != copied {
+        log::info!("Index '{}' changed while copying, copying again", uid);
+        copied = copy_documents(backend, definition, &staging, report, &mut step)?;
+    }
+    let staged = backend.document_count(&staging).map_err(backend_err)?;
+    if staged != copied {
+        return Err(IndexMigrationError::Verification { index: staging, expected: copied, found: staged });
+    }
+
+    step(report, &|m| m.phase = MigrationPhase::Swapping);
+    backend.swap_indexes(uid, &staging).map_err(backend_err)?;
+
+    step(report, &|m| m.phase = MigrationPhase::CleaningUp);
+    // The swap succeeded, so a leftover old index only wastes space
+    if let Err(e) = backend.delete_index(&staging) {
+        log::warn!("Failed to delete old index now named '{}': {}", staging, e);
+    }
+    Ok(())
+}
+
+/// Copy every document of the live index into the staging index, returning
+/// how many were copied
+fn copy_documents(
+    backend: &impl IndexMigrationBackend,
+    definition: &IndexDefinition,
+    staging: &str,
+    report: &mut IndexMigrationReport,
+    step: &mut impl FnMut(&mut IndexMigrationReport, &dyn Fn(&mut IndexMigration)),
+) -> Result<u64> {
+    let mut offset = 0;
+    loop {
+        let (total, page) = backend
+            .read_documents(&definition.uid, offset, MIGRATION_BATCH_SIZE)
+            .map_err(IndexMigrationError::Backend)?;
+        let page_len = page.len();
+        if page_len > 0 {
+            backend
+                .add_documents(staging, page, definition.primary_key.clone())
+                .map_err(IndexMigrationError::Backend)?;
+        }
+        offset += page_len;
+        let copied = offset as u64;
+        step(report, &|m| {
+            m.documents_total = total;
+            m.documents_copied = copied;
+        });
+        if page_len < MIGRATION_BATCH_SIZE || copied >= total {
+            return Ok(copied);
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// Indexes held in memory, with an optional failure on one operation
+    #[derive(Default)]
+    struct MemoryBackend {
+        indexes: RefCell<HashMap<String, (serde_json::Value, Vec<serde_json::Value>)>>,
+        fail_on: Option<&'static str>,
+    }
+
+    impl MemoryBackend {
+        fn with_index(uid: &str, documents: usize) -> Self {
+            let backend = Self::default();
+            let documents = (0..documents).map(|i| serde_json::json!({ "id": i.to_string() })).collect();
+            backend.indexes.borrow_mut().insert(uid.to_string(), (serde_json::json!({ "v": 1 }), documents));
+            backend
+        }
+
+        fn fail(&self, operation: &str) -> std::result::Result<(), String> {
+            match self.fail_on {
+                Some(failing) if failing == operation => Err(format!("{} failed", operation)),
+                _ => Ok(()),
+            }
+        }
+
+        fn settings(&self, uid: &str) -> serde_json::Value {
+            self.indexes.borrow()[uid].0.clone()
+        }
+    }
+
+    impl IndexMigrationBackend for MemoryBackend {
+        fn index_exists(&self, uid: &str) -> std::result::Result<bool, String> {
+            Ok(self.indexes.borrow().contains_key(uid))
+        }
+        fn create_index(&self, uid: &str, _: Option<String>) -> std::result::Result<(), String> {
+            self.indexes.borrow_mut().insert(uid.to_string(), (serde_json::Value::Null, Vec::new()));
+            Ok(())
+        }
+        fn update_settings(&self, uid: &str, settings: &serde_json::Value) -> std::result::Result<(), String> {
+            self.indexes.borrow_mut().get_mut(uid).ok_or("no index")?.0 = settings.clone();
+            Ok(())
+        }
+        fn document_count(&self, uid: &str) -> std::result::Result<u64, String> {
+            Ok(self.indexes.borrow().get(uid).ok_or("no index")?.1.len() as u64)
+        }
+        fn read_documents(
+            &self,
+            uid: &str,
+            offset: usize,
+            limit: usize,
+        ) -> std::result::Result<(u64, Vec<serde_json::Value>), String> {
+            let indexes = self.indexes.borrow();
+            let documents = &indexes.get(uid).ok_or("no index")?.1;
+            Ok((documents.len() as u64, documents.iter().skip(offset).take(limit).cloned().collect()))
+        }
+        fn add_documents(
+            &self,
+            uid: &str,
+            documents: Vec<serde_json::Value>,
+            _: Option<String>,
+        ) -> std::result::Result<(), String> {
+            self.fail("add_documents")?;
+            self.indexes.borrow_mut().get_mut(uid).ok_or("no index")?.1.extend(documents);
+            Ok(())
+        }
+        fn swap_indexes(&self, a: &str, b: &str) -> std::result::Result<(), String> {
+            let mut indexes = self.indexes.borrow_mut();
+            let first = indexes.remove(a).ok_or("no index")?;
+            let second = indexes.remove(b).ok_or("no index")?;
+            indexes.insert(a.to_string(), second);
+            indexes.insert(b.to_string(), first);
+            Ok(())
+        }
+        fn delete_index(&self, uid: &str) -> std::result::Result<(), String> {
+            self.indexes.borrow_mut().remove(uid);
+            Ok(())
+        }
+    }
+
+    fn definition(uid: &str, version: u32) -> IndexDefinition {
+        IndexDefinition {
+            uid: uid.to_string(),
+            primary_key: Some("id".to_string()),
+            version,
+            settings: serde_json::json!({ "v": version }),
+        }
+    }
+
+    #[test]
+    fn test_stale_index_is_rebuilt_and_swapped_in() {
+        let backend = MemoryBackend::with_index("plots", MIGRATION_BATCH_SIZE + 5);
+        let mut versions = IndexVersions::default();
+        let mut phases = Vec::new();
+
+        let report = run_index_migrations(&backend, &[definition("plots", 2)], &mut versions, |r| {
+            if let Some(m) = r.migrations.first() {
+                if phases.last() != Some(&m.phase) {
+                    phases.push(m.phase);
+                }
+            }
+        });
+
+        let migration = &report.migrations[0];
+        assert_eq!(migration.status, MigrationStatus::Completed);
+        assert_eq!((migration.from_version, migration.to_version), (1, 2));
+        assert_eq!(migration.documents_copied, (MIGRATION_BATCH_SIZE + 5) as u64);
+        assert_eq!(
+            phases,
+            vec![
+                MigrationPhase::Pending,
+                MigrationPhase::Building,
+                MigrationPhase::Copying,
+                MigrationPhase::Swapping,
+                MigrationPhase::CleaningUp,
+                MigrationPhase::Done,
+            ]
+        );
+        assert_eq!(backend.settings("plots"), serde_json::json!({ "v": 2 }));
+        assert_eq!(backend.document_count("plots").unwrap(), (MIGRATION_BATCH_SIZE + 5) as u64);
+        assert!(!backend.index_exists("plots__v2").unwrap());
+        assert_eq!(versions.get("plots"), 2);
+        assert!(!report.in_progress());
+    }
+
+    #[test]
+    fn test_failed_copy_leaves_live_index_untouched() {
+        let mut backend = MemoryBackend::with_index("plots", 3);
+        backend.fail_on = Some("add_documents");
+        let mut versions = IndexVersions::default();
+
+        let report = run_index_migrations(&backend, &[definition("plots", 2)], &mut versions, |_| {});
+
+        let migration = &report.migrations[0];
+        assert_eq!(migration.status, MigrationStatus::RolledBack);
+        assert!(migration.error.as_deref().unwrap().contains("add_documents failed"));
+        assert_eq!(backend.settings("plots"), serde_json::json!({ "v": 1 }));
+        assert_eq!(backend.document_count("plots").unwrap(), 3);
+        assert!(!backend.index_exists("plots__v2").unwrap());
+        assert_eq!(versions.get("plots"), 1);
+    }
+
+    #[test]
+    fn test_missing_and_current_indexes_are_not_migrated() {
+        let backend = MemoryBackend::with_index("arcs", 2);
+        let mut versions = IndexVersions::default();
+
+        let report = run_index_migrations(
+            &backend,
+            &[definition("arcs", BASELINE_INDEX_VERSION), definition("plots", 3)],
+            &mut versions,
+            |_| {},
+        );
+
+        assert!(report.migrations.is_empty());
+        assert_eq!(report.created, vec!["plots"]);
+        assert_eq!(versions.get("plots"), 3);
+        assert_eq!(backend.settings("plots"), serde_json::json!({ "v": 3 }));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("index_versions.json");
+        versions.save(&path).unwrap();
+        assert_eq!(IndexVersions::load(&path).unwrap(), versions);
+        assert_eq!(IndexVersions::load(&dir.path().join("missing.json")).unwrap().get("x"), 1);
+    }
+}
@@ -0,0 +1,321 @@
+//! Index Tuning
+//!
+//! User-editable Meilisearch relevancy settings for the library indexes:
+//! typo tolerance, ranking rules, stop words, and the distinct attribute.
+//! Fantasy names ("Tiamat", "Mordenkainen", "Drizzt") are easy to misspell
+//! and rarely have near neighbours in the vocabulary, so the defaults allow
+//! typos on shorter words than Meilisearch does.
+
+use meilisearch_lib::{Settings, Unchecked};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Shortest word allowed one typo; Meilisearch's own default is 5
+pub const DEFAULT_ONE_TYPO_MIN_CHARS: u8 = 4;
+
+/// Shortest word allowed two typos; Meilisearch's own default is 9
+pub const DEFAULT_TWO_TYPOS_MIN_CHARS: u8 = 8;
+
+/// Most stop words accepted
+const MAX_STOP_WORDS: usize = 200;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum IndexTuningError {
+    #[error("Invalid index tuning: {0}")]
+    Invalid(String),
+
+    #[error("Failed to build index settings: {0}")]
+    Settings(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, IndexTuningError>;
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// When queries may match words with typos
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TypoTolerance {
+    pub enabled: bool,
+    /// Shortest word, in characters, that may match with one typo
+    pub one_typo_min_chars: u8,
+    /// Shortest word that may match with two typos
+    pub two_typos_min_chars: u8,
+    /// Words that must match exactly
+    pub disable_on_words: Vec<String>,
+    /// Attributes whose values must match exactly
+    pub disable_on_attributes: Vec<String>,
+}
+
+impl Default for TypoTolerance {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            one_typo_min_chars: DEFAULT_ONE_TYPO_MIN_CHARS,
+            two_typos_min_chars: DEFAULT_TWO_TYPOS_MIN_CHARS,
+            disable_on_words: Vec::new(),
+            disable_on_attributes: Vec::new(),
+        }
+    }
+}
+
+/// A Meilisearch ranking rule, applied in list order
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum RankingRule {
+    /// More query words matched first
+    Words,
+    /// Fewer typos first
+    Typo,
+    /// Query words closer together first
+    Proximity,
+    /// Matches in more important attributes first
+    Attribute,
+    /// The query's sort parameter
+    Sort,
+    /// Exact word matches first
+    Exactness,
+    /// Lower values of a numeric attribute first
+    Asc { attribute: String },
+    /// Higher values first
+    Desc { attribute: String },
+}
+
+impl RankingRule {
+    /// The rule as Meilisearch writes it ("words", "release_year:desc")
+    pub fn as_meili(&self) -> String {
+        match self {
+            RankingRule::Words => "words".to_string(),
+            RankingRule::Typo => "typo".to_string(),
+            RankingRule::Proximity => "proximity".to_string(),
+            RankingRule::Attribute => "attribute".to_string(),
+            RankingRule::Sort => "sort".to_string(),
+            RankingRule::Exactness => "exactness".to_string(),
+            RankingRule::Asc { attribute } => format!("{}:asc", attribute),
+            RankingRule::Desc { attribute } => format!("{}:desc", attribute),
+        }
+    }
+
+    /// Meilisearch's default order
+    pub fn defaults() -> Vec<RankingRule> {
+        vec![
+            RankingRule::Words,
+            RankingRule::Typo,
+            RankingRule::Proximity,
+            RankingRule::Attribute,
+            RankingRule::Sort,
+            RankingRule::Exactness,
+        ]
+    }
+}
+
+/// Relevancy settings applied to every library index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexTuning {
+    pub typo_tolerance: TypoTolerance,
+    pub ranking_rules: Vec<RankingRule>,
+    /// Words ignored in queries
+    pub stop_words: Vec<String>,
+    /// Attribute whose value appears at most once in results
+    pub distinct_attribute: Option<String>,
+}
+
+impl Default for IndexTuning {
+    fn default() -> Self {
+        Self {
+            typo_tolerance: TypoTolerance::default(),
+            ranking_rules: RankingRule::defaults(),
+            stop_words: Vec::new(),
+            distinct_attribute: None,
+        }
+    }
+}
+
+impl IndexTuning {
+    /// The tuning with words and attribute names trimmed and de-duplicated,
+    /// or why it can't be applied
+    pub fn validated(&self) -> Result<Self> {
+        let typo = &self.typo_tolerance;
+        if typo.one_typo_min_chars == 0 {
+            return Err(IndexTuningError::Invalid("one-typo word length must be at least 1".to_string()));
+        }
+        if typo.two_typos_min_chars < typo.one_typo_min_chars {
+            return Err(IndexTuningError::Invalid(
+                "two-typo word length can't be below the one-typo length".to_string(),
+            ));
+        }
+        if self.ranking_rules.is_empty() {
+            return Err(IndexTuningError::Invalid("at least one ranking rule is required".to_string()));
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut ranking_rules = Vec::new();
+        for rule in &self.ranking_rules {
+            let rule = match rule {
+                RankingRule::Asc { attribute } => RankingRule::Asc { attribute: attribute_name(attribute)? },
+                RankingRule::Desc { attribute } => RankingRule::Desc { attribute: attribute_name(attribute)? },
+                rule => rule.clone(),
+            };
+            if !seen.insert(rule.as_meili()) {
+                return Err(IndexTuningError::Invalid(format!("ranking rule '{}' is listed twice", rule.as_meili())));
+            }
+            ranking_rules.push(rule);
+        }
+
+        let stop_words = normalized_words(&self.stop_words);
+        if stop_words.len() > MAX_STOP_WORDS {
+            return Err(IndexTuningError::Invalid(format!("at most {} stop words are allowed", MAX_STOP_WORDS)));
+        }
+
+        Ok(Self {
+            typo_tolerance: TypoTolerance {
+                enabled: typo.enabled,
+                one_typo_min_chars: typo.one_typo_min_chars,
+                two_typos_min_chars: typo.two_typos_min_chars,
+                disable_on_words: normalized_words(&typo.disable_on_words),
+                disable_on_attributes: typo
+                    .disable_on_attributes
+                    .iter()
+                    .map(|a| attribute_name(a))
+                    .collect::<Result<BTreeSet<_>>>()?
+                    .into_iter()
+                    .collect(),
+            },
+            ranking_rules,
+            stop_words,
+            distinct_attribute: match self.distinct_attribute.as_deref().map(str::trim) {
+                None | Some("") => None,
+                Some(attribute) => Some(attribute_name(attribute)?),
+            },
+        })
+    }
+
+    /// The tuning in Meilisearch's settings format. An unset distinct
+    /// attribute is sent as `null`, which clears one set before.
+    pub fn settings_json(&self) -> serde_json::Value {
+        let typo = &self.typo_tolerance;
+        json!({
+            "typoTolerance": {
+                "enabled": typo.enabled,
+                "minWordSizeForTypos": {
+                    "oneTypo": typo.one_typo_min_chars,
+                    "twoTypos": typo.two_typos_min_chars,
+                },
+                "disableOnWords": typo.disable_on_words,
+                "disableOnAttributes": typo.disable_on_attributes,
+            },
+            "rankingRules": self.ranking_rules.iter().map(RankingRule::as_meili).collect::<Vec<_>>(),
+            "stopWords": self.stop_words,
+            "distinctAttribute": self.distinct_attribute,
+        })
+    }
+
+    /// Meilisearch settings carrying only the tuned fields
+    pub fn meili_settings(&self) -> Result<Settings<Unchecked>> {
+        Ok(serde_json::from_value(self.settings_json())?)
+    }
+}
+
+/// Lowercase, trimmed, distinct, sorted words
+fn normalized_words(words: &[String]) -> Vec<String> {
+    words
+        .iter()
+        .map(|w| w.trim().to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// A document attribute name, which may be a dotted path
+fn attribute_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.');
+    if valid {
+        Ok(name.to_string())
+    } else {
+        Err(IndexTuningError::Invalid(format!("'{}' is not a valid attribute name", name)))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_loosen_typos_and_keep_meili_rules() {
+        let tuning: IndexTuning = serde_json::from_str("{}").unwrap();
+        assert_eq!(tuning, IndexTuning::default());
+
+        let json = tuning.settings_json();
+        assert_eq!(json["typoTolerance"]["minWordSizeForTypos"]["oneTypo"], 4);
+        assert_eq!(json["typoTolerance"]["minWordSizeForTypos"]["twoTypos"], 8);
+        assert_eq!(json["rankingRules"][0], "words");
+        assert_eq!(json["rankingRules"].as_array().unwrap().len(), 6);
+        assert!(json["distinctAttribute"].is_null());
+    }
+
+    #[test]
+    fn test_validated_normalizes_and_renders_custom_rules() {
+        let tuning = IndexTuning {
+            ranking_rules: vec![
+                RankingRule::Words,
+                RankingRule::Desc { attribute: " page_number ".to_string() },
+            ],
+            stop_words: vec!["The".to_string(), "the".to_string(), " of ".to_string(), " ".to_string()],
+            distinct_attribute: Some(" source ".to_string()),
+            ..Default::default()
+        };
+
+        let valid = tuning.validated().unwrap();
+
+        assert_eq!(valid.stop_words, vec!["of", "the"]);
+        assert_eq!(valid.distinct_attribute.as_deref(), Some("source"));
+        let json = valid.settings_json();
+        assert_eq!(json["rankingRules"][1], "page_number:desc");
+        assert_eq!(json["distinctAttribute"], "source");
+
+        let rule: RankingRule = serde_json::from_str(r#"{"rule": "asc", "attribute": "level"}"#).unwrap();
+        assert_eq!(rule.as_meili(), "level:asc");
+    }
+
+    #[test]
+    fn test_validated_rejects_bad_settings() {
+        let mut tuning = IndexTuning::default();
+        tuning.typo_tolerance.two_typos_min_chars = 3;
+        assert!(tuning.validated().is_err());
+
+        let duplicate = IndexTuning {
+            ranking_rules: vec![RankingRule::Typo, RankingRule::Typo],
+            ..Default::default()
+        };
+        assert!(duplicate.validated().is_err());
+
+        let bad_attribute = IndexTuning {
+            distinct_attribute: Some("source; drop".to_string()),
+            ..Default::default()
+        };
+        assert!(bad_attribute.validated().is_err());
+        assert!(IndexTuning { ranking_rules: Vec::new(), ..Default::default() }.validated().is_err());
+    }
+}
@@ -0,0 +1,544 @@
+//! Quick Lookup
+//!
+//! Name lookups for spells and monsters: "what does Counterspell do?" or
+//! "pull up the owlbear". Exact and near-exact names from the ingested
+//! documents win over everything else; misspellings fall back to edit
+//! distance, and entries that were never extracted fall back to search
+//! hits parsed the same way. Results are parsed entries, not chunk text.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::database::TTRPGDocumentRecord;
+use crate::ingestion::ttrpg::stat_block::{ArmorClass, ChallengeRating, HitPoints};
+use crate::ingestion::ttrpg::{levenshtein_distance, StatBlockData, StatBlockParser};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Other close matches returned alongside the best one
+pub const MAX_ALTERNATIVES: usize = 5;
+
+/// Most edits a fuzzy name match may need
+const MAX_FUZZY_EDITS: usize = 3;
+
+/// Element types holding spells
+const SPELL_ELEMENT_TYPES: &[&str] = &["spell"];
+
+/// Element types holding creature stat blocks
+const MONSTER_ELEMENT_TYPES: &[&str] = &["monster", "creature", "stat_block", "npc"];
+
+// ============================================================================
+// Lookup Kinds
+// ============================================================================
+
+/// What is being looked up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LookupKind {
+    Spell,
+    Monster,
+}
+
+impl LookupKind {
+    /// Element types in the ingested documents that hold this kind
+    pub fn element_types(&self) -> &'static [&'static str] {
+        match self {
+            LookupKind::Spell => SPELL_ELEMENT_TYPES,
+            LookupKind::Monster => MONSTER_ELEMENT_TYPES,
+        }
+    }
+
+    /// Whether a record is of this kind
+    pub fn holds(&self, record: &TTRPGDocumentRecord) -> bool {
+        self.element_types().contains(&record.element_type.to_lowercase().as_str())
+    }
+
+    /// Whether a search hit's text reads like an entry of this kind
+    pub fn looks_like(&self, text: &str) -> bool {
+        let lower = text.to_lowercase();
+        match self {
+            LookupKind::Spell => lower.contains("casting time") && (lower.contains("range") || lower.contains("duration")),
+            LookupKind::Monster => lower.contains("armor class") && lower.contains("hit points"),
+        }
+    }
+}
+
+/// How closely a match's name agrees with the query, best first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchQuality {
+    /// Same name, character for character
+    Exact,
+    /// Same name ignoring case, punctuation and a leading "the"
+    Normalized,
+    /// The name starts with the query
+    Prefix,
+    /// The name contains the query
+    Partial,
+    /// The name is a few edits away from the query
+    Fuzzy,
+    /// Found by full-text search rather than by name
+    Search,
+}
+
+// ============================================================================
+// Name Matching
+// ============================================================================
+
+/// Lowercase words with punctuation and a leading "the" dropped, so
+/// "The Owlbear" and "owlbear" compare equal
+pub fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .to_lowercase()
+        .chars()
+        .filter(|c| *c != '\'' && *c != '’')
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() { c } else { ' ' })
+        .collect();
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    match words.split_first() {
+        Some((&"the", rest)) if !rest.is_empty() => rest.join(" "),
+        _ => words.join(" "),
+    }
+}
+
+/// How well `name` matches `query`, and the edit distance between them;
+/// `None` when it doesn't match at all
+pub fn match_quality(query: &str, name: &str) -> Option<(MatchQuality, usize)> {
+    let query_norm = normalize_name(query);
+    let name_norm = normalize_name(name);
+    if query_norm.is_empty() || name_norm.is_empty() {
+        return None;
+    }
+    let distance = levenshtein_distance(&query_norm, &name_norm);
+    let quality = if query.trim() == name.trim() {
+        MatchQuality::Exact
+    } else if query_norm == name_norm {
+        MatchQuality::Normalized
+    } else if name_norm.starts_with(&query_norm) {
+        MatchQuality::Prefix
+    } else if name_norm.contains(&query_norm) {
+        MatchQuality::Partial
+    } else if distance <= fuzzy_edits(&query_norm) {
+        MatchQuality::Fuzzy
+    } else {
+        return None;
+    };
+    Some((quality, distance))
+}
+
+/// Edits allowed for a fuzzy match: one per four characters, at least one
+fn fuzzy_edits(query: &str) -> usize {
+    (query.chars().count() / 4).clamp(1, MAX_FUZZY_EDITS)
+}
+
+/// Records of `kind` matching `query`, best first: by match quality, then
+/// fewest edits, then extraction confidence
+pub fn rank_by_name<'a>(
+    query: &str,
+    kind: LookupKind,
+    records: &'a [TTRPGDocumentRecord],
+) -> Vec<(MatchQuality, &'a TTRPGDocumentRecord)> {
+    let mut matches: Vec<(MatchQuality, usize, &TTRPGDocumentRecord)> = records
+        .iter()
+        .filter(|r| kind.holds(r))
+        .filter_map(|r| match_quality(query, &r.name).map(|(quality, distance)| (quality, distance, r)))
+        .collect();
+    matches.sort_by(|a, b| {
+        (a.0, a.1)
+            .cmp(&(b.0, b.1))
+            .then(b.2.confidence.partial_cmp(&a.2.confidence).unwrap_or(std::cmp::Ordering::Equal))
+            .then_with(|| a.2.name.cmp(&b.2.name))
+    });
+    matches.into_iter().map(|(quality, _, record)| (quality, record)).collect()
+}
+
+/// The part of a search hit that starts at a heading naming `query`, with
+/// that heading; `None` when no line of the hit is the entry's name
+pub fn find_entry_in_text<'a>(query: &str, text: &'a str) -> Option<(&'a str, &'a str)> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let heading = line.trim();
+        let names_entry = heading.chars().count() <= 60
+            && matches!(
+                match_quality(query, heading),
+                Some((MatchQuality::Exact | MatchQuality::Normalized | MatchQuality::Fuzzy, _))
+            );
+        if names_entry {
+            return Some((heading, &text[offset..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+// ============================================================================
+// Lookup Results
+// ============================================================================
+
+/// Another entry that matched the name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LookupCandidate {
+    pub document_id: String,
+    pub name: String,
+    pub quality: MatchQuality,
+}
+
+/// The best entry for a name, parsed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookupResult<T> {
+    pub entry: T,
+    pub quality: MatchQuality,
+    /// The ingested entry; `None` for search fallbacks
+    pub document_id: Option<String>,
+    /// The rulebook, or the search hit's source
+    pub source: String,
+    pub page_number: Option<i32>,
+    pub game_system: Option<String>,
+    /// Next-best name matches, for "did you mean"
+    pub alternatives: Vec<LookupCandidate>,
+}
+
+impl<T> LookupResult<T> {
+    /// The best-ranked record, parsed with `parse`, with the rest as
+    /// alternatives
+    pub fn from_ranked(
+        ranked: &[(MatchQuality, &TTRPGDocumentRecord)],
+        parse: impl Fn(&TTRPGDocumentRecord) -> T,
+    ) -> Option<Self> {
+        let (quality, record) = ranked.first()?;
+        Some(Self {
+            entry: parse(record),
+            quality: *quality,
+            document_id: Some(record.id.clone()),
+            source: record.source_document_id.clone(),
+            page_number: record.page_number,
+            game_system: Some(record.game_system.clone()),
+            alternatives: ranked
+                .iter()
+                .skip(1)
+                .filter(|(_, r)| r.name != record.name || r.source_document_id != record.source_document_id)
+                .take(MAX_ALTERNATIVES)
+                .map(|(quality, r)| LookupCandidate {
+                    document_id: r.id.clone(),
+                    name: r.name.clone(),
+                    quality: *quality,
+                })
+                .collect(),
+        })
+    }
+
+    /// An entry parsed from a search hit
+    pub fn from_search(entry: T, source: String, page_number: Option<i32>) -> Self {
+        Self {
+            entry,
+            quality: MatchQuality::Search,
+            document_id: None,
+            source,
+            page_number,
+            game_system: None,
+            alternatives: Vec::new(),
+        }
+    }
+}
+
+// ============================================================================
+// Spells
+// ============================================================================
+
+/// A spell entry, parsed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpellEntry {
+    pub name: String,
+    /// 0 for cantrips
+    pub level: Option<u8>,
+    pub school: Option<String>,
+    pub casting_time: Option<String>,
+    pub range: Option<String>,
+    pub components: Option<String>,
+    pub duration: Option<String>,
+    pub ritual: bool,
+    pub concentration: bool,
+    pub classes: Vec<String>,
+    pub description: String,
+    pub at_higher_levels: Option<String>,
+}
+
+fn spell_header_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)^(?:(\d+)(?:st|nd|rd|th)[- ]level\s+(\w+)|(\w+)\s+cantrip)(\s*\(ritual\))?").unwrap()
+    })
+}
+
+fn spell_field_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)^(casting time|range|components|duration|classes)\s*:\s*(.+)$").unwrap()
+    })
+}
+
+fn higher_levels_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"(?i)^at higher levels\s*[.:]\s*").unwrap())
+}
+
+impl SpellEntry {
+    /// Parse a spell from its text: level and school line, the labelled
+    /// fields, the description, then "At Higher Levels"
+    pub fn parse(name: &str, text: &str) -> Self {
+        let mut spell = Self { name: name.trim().to_string(), ..Default::default() };
+        let mut description = Vec::new();
+        let mut higher_levels: Option<Vec<&str>> = None;
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some(higher) = higher_levels.as_mut() {
+                higher.push(line);
+                continue;
+            }
+            if spell.level.is_none() && description.is_empty() {
+                if let Some(caps) = spell_header_pattern().captures(line) {
+                    spell.level = Some(caps.get(1).and_then(|l| l.as_str().parse().ok()).unwrap_or(0));
+                    spell.school = caps.get(2).or_else(|| caps.get(3)).map(|s| capitalize(s.as_str()));
+                    spell.ritual = caps.get(4).is_some();
+                    continue;
+                }
+            }
+            if let Some(caps) = spell_field_pattern().captures(line) {
+                let value = caps[2].trim().to_string();
+                match caps[1].to_lowercase().as_str() {
+                    "casting time" => spell.casting_time = Some(value),
+                    "range" => spell.range = Some(value),
+                    "components" => spell.components = Some(value),
+                    "duration" => spell.duration = Some(value),
+                    _ => spell.classes = split_list(&value),
+                }
+                continue;
+            }
+            if let Some(found) = higher_levels_pattern().find(line) {
+                higher_levels = Some(vec![&line[found.end()..]]);
+                continue;
+            }
+            if spell.name.is_empty() && description.is_empty() {
+                spell.name = line.to_string();
+                continue;
+            }
+            if line.eq_ignore_ascii_case(&spell.name) && description.is_empty() {
+                continue;
+            }
+            description.push(line);
+        }
+
+        spell.description = description.join(" ");
+        spell.at_higher_levels = higher_levels.map(|h| h.join(" ").trim().to_string()).filter(|h| !h.is_empty());
+        spell.concentration = spell.duration.as_deref().is_some_and(|d| d.to_lowercase().contains("concentration"));
+        spell.ritual |= spell.casting_time.as_deref().is_some_and(|t| t.to_lowercase().contains("ritual"));
+        spell
+    }
+
+    /// Parse an ingested spell, preferring its extracted attributes over
+    /// what the text says
+    pub fn from_record(record: &TTRPGDocumentRecord) -> Self {
+        let mut spell = Self::parse(&record.name, &record.content);
+        let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+        let text = |key: &str| {
+            attributes
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+
+        if let Some(level) = record.level {
+            spell.level = Some(level.clamp(0, 9) as u8);
+        } else if let Some(level) = attributes.get("level") {
+            spell.level = match level {
+                serde_json::Value::Number(n) => n.as_u64().map(|n| n.min(9) as u8),
+                serde_json::Value::String(s) if s.eq_ignore_ascii_case("cantrip") => Some(0),
+                serde_json::Value::String(s) => s.trim_end_matches(|c: char| !c.is_ascii_digit()).parse().ok(),
+                _ => None,
+            }
+            .or(spell.level);
+        }
+        spell.school = text("school").map(|s| capitalize(&s)).or(spell.school);
+        spell.casting_time = text("casting_time").or(spell.casting_time);
+        spell.range = text("range").or(spell.range);
+        spell.components = text("components").or(spell.components);
+        spell.duration = text("duration").or(spell.duration);
+        spell.at_higher_levels = text("at_higher_levels").or(spell.at_higher_levels);
+        match attributes.get("classes") {
+            Some(serde_json::Value::Array(values)) => {
+                spell.classes = values.iter().filter_map(|v| v.as_str()).map(str::to_string).collect()
+            }
+            Some(serde_json::Value::String(s)) => spell.classes = split_list(s),
+            _ => {}
+        }
+        if let Some(ritual) = attributes.get("ritual").and_then(|v| v.as_bool()) {
+            spell.ritual = ritual;
+        }
+        spell.concentration = attributes
+            .get("concentration")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| spell.duration.as_deref().is_some_and(|d| d.to_lowercase().contains("concentration")));
+        spell
+    }
+}
+
+/// "Sorcerer, Warlock, Wizard" as a list
+fn split_list(value: &str) -> Vec<String> {
+    value.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+// ============================================================================
+// Monsters
+// ============================================================================
+
+/// Parse a monster's stat block from its text
+pub fn parse_stat_block(name: &str, text: &str) -> StatBlockData {
+    let mut stat_block = StatBlockParser::new().parse(text).unwrap_or_default();
+    if !name.trim().is_empty() {
+        stat_block.name = name.trim().to_string();
+    }
+    stat_block
+}
+
+/// Parse an ingested monster, preferring its extracted attributes and
+/// challenge rating over what the text says
+pub fn stat_block_from_record(record: &TTRPGDocumentRecord) -> StatBlockData {
+    let mut stat_block = parse_stat_block(&record.name, &record.content);
+    let attributes = record.attributes().unwrap_or(serde_json::Value::Null);
+    let number = |value: Option<&serde_json::Value>, field: &str| {
+        value.and_then(|v| v.as_i64().or_else(|| v.get(field)?.as_i64())).map(|n| n as i32)
+    };
+    let text = |key: &str| attributes.get(key).and_then(|v| v.as_str()).map(|s| s.trim().to_lowercase());
+
+    if let Some(value) = number(attributes.get("armor_class").or_else(|| attributes.get("ac")), "value") {
+        let armor_type = stat_block.armor_class.take().and_then(|ac| ac.armor_type);
+        stat_block.armor_class = Some(ArmorClass { value, armor_type });
+    }
+    if let Some(average) = number(attributes.get("hit_points").or_else(|| attributes.get("hp")), "average") {
+        let formula = stat_block.hit_points.take().and_then(|hp| hp.formula);
+        stat_block.hit_points = Some(HitPoints { average, formula });
+    }
+    if let Some(cr) = record.challenge_rating {
+        let xp = stat_block.challenge_rating.take().and_then(|c| c.xp);
+        stat_block.challenge_rating = Some(ChallengeRating { value: cr as f32, xp });
+    }
+    stat_block.creature_type = text("creature_type").or_else(|| text("type")).or(stat_block.creature_type);
+    stat_block.size = text("size").or(stat_block.size);
+    stat_block.alignment = text("alignment").or(stat_block.alignment);
+    stat_block
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, element_type: &str, content: &str) -> TTRPGDocumentRecord {
+        TTRPGDocumentRecord::new(
+            format!("{}-{}", element_type, name.to_lowercase().replace(' ', "-")),
+            "phb".to_string(),
+            name.to_string(),
+            element_type.to_string(),
+            "dnd5e".to_string(),
+            content.to_string(),
+            0.9,
+        )
+    }
+
+    #[test]
+    fn test_rank_by_name_prefers_exact_then_near_exact() {
+        let records = vec![
+            record("Fire Bolt", "spell", ""),
+            record("Fireball", "spell", ""),
+            record("Fireball", "spell", "").with_cr(0.0),
+            record("Delayed Blast Fireball", "spell", ""),
+            record("Fireball Golem", "monster", ""),
+        ];
+
+        let ranked = rank_by_name("Fireball", LookupKind::Spell, &records);
+        let names: Vec<_> = ranked.iter().map(|(q, r)| (*q, r.name.as_str())).collect();
+        assert_eq!(
+            names,
+            vec![
+                (MatchQuality::Exact, "Fireball"),
+                (MatchQuality::Exact, "Fireball"),
+                (MatchQuality::Partial, "Delayed Blast Fireball"),
+            ]
+        );
+
+        assert_eq!(match_quality("the owlbear", "Owlbear").unwrap().0, MatchQuality::Normalized);
+        assert_eq!(match_quality("tashas hideous laughter", "Tasha's Hideous Laughter").unwrap().0, MatchQuality::Normalized);
+        assert_eq!(match_quality("Fireblal", "Fireball").unwrap().0, MatchQuality::Fuzzy);
+        assert!(match_quality("Wish", "Fireball").is_none());
+
+        let result = LookupResult::from_ranked(&ranked, |r| r.name.clone()).unwrap();
+        assert_eq!(result.entry, "Fireball");
+        assert_eq!(result.alternatives.len(), 1, "duplicate entries from the same book are not alternatives");
+    }
+
+    #[test]
+    fn test_spell_parse_reads_fields_and_higher_levels() {
+        let text = "Fireball\n3rd-level evocation\nCasting Time: 1 action\nRange: 150 feet\n\
+            Components: V, S, M (a tiny ball of bat guano and sulfur)\nDuration: Instantaneous\n\
+            A bright streak flashes from your pointing finger.\nEach creature takes 8d6 fire damage.\n\
+            At Higher Levels. The damage increases by 1d6 for each slot level above 3rd.";
+
+        let spell = SpellEntry::parse("Fireball", text);
+
+        assert_eq!(spell.level, Some(3));
+        assert_eq!(spell.school.as_deref(), Some("Evocation"));
+        assert_eq!(spell.range.as_deref(), Some("150 feet"));
+        assert!(spell.description.starts_with("A bright streak"));
+        assert!(spell.description.ends_with("fire damage."));
+        assert!(spell.at_higher_levels.unwrap().starts_with("The damage increases"));
+        assert!(!spell.concentration);
+
+        let mut bless = record("Bless", "spell", "Enchantment cantrip\nDuration: Concentration, up to 1 minute");
+        bless.attributes_json = Some(r#"{"level": "1st", "classes": "Cleric, Paladin"}"#.to_string());
+        let bless = SpellEntry::from_record(&bless);
+        assert_eq!(bless.level, Some(1));
+        assert_eq!(bless.school.as_deref(), Some("Enchantment"));
+        assert_eq!(bless.classes, vec!["Cleric", "Paladin"]);
+        assert!(bless.concentration);
+    }
+
+    #[test]
+    fn test_stat_block_from_record_prefers_attributes() {
+        let text = "Owlbear\nLarge monstrosity, unaligned\nArmor Class 13 (natural armor)\n\
+            Hit Points 59 (7d10 + 21)\nSpeed 40 ft.\nChallenge 3 (700 XP)";
+        let mut owlbear = record("Owlbear", "monster", text).with_cr(3.0);
+        owlbear.attributes_json = Some(r#"{"armor_class": {"value": 14}}"#.to_string());
+
+        let stat_block = stat_block_from_record(&owlbear);
+
+        assert_eq!(stat_block.name, "Owlbear");
+        let ac = stat_block.armor_class.unwrap();
+        assert_eq!(ac.value, 14);
+        assert_eq!(ac.armor_type.as_deref(), Some("natural armor"));
+        assert_eq!(stat_block.hit_points.unwrap().average, 59);
+        assert_eq!(stat_block.challenge_rating.unwrap().value, 3.0);
+        assert!(LookupKind::Monster.looks_like(text));
+        assert!(!LookupKind::Spell.looks_like(text));
+
+        let page = format!("Orc\nMedium humanoid\nArmor Class 13\n{}", text);
+        let (heading, entry) = find_entry_in_text("owl bear", &page).unwrap();
+        assert_eq!(heading, "Owlbear");
+        assert_eq!(parse_stat_block(heading, entry).hit_points.unwrap().average, 59);
+        assert!(find_entry_in_text("Beholder", &page).is_none());
+    }
+}
@@ -0,0 +1,281 @@
+//! Index Maintenance
+//!
+//! Types and helpers behind listing, inspecting, rebuilding, and optimizing
+//! the search indexes: the Meilisearch indexes and the SurrealDB tables that
+//! carry vector indexes.
+//!
+//! A Meilisearch rebuild exports the index's documents and settings to a
+//! snapshot file before dropping the index, then recreates and refills it.
+//! If the rebuild is interrupted after the drop, the next rebuild of that
+//! index restores from the snapshot instead of exporting again, so no
+//! documents are lost.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Documents read per page when exporting an index
+pub const EXPORT_PAGE_SIZE: usize = 1000;
+
+/// Documents added per task when refilling an index
+pub const IMPORT_BATCH_SIZE: usize = 500;
+
+/// Share of a rebuild's progress spent exporting, then recreating; the
+/// rest is importing
+const EXPORT_SHARE: f32 = 0.3;
+const RECREATE_SHARE: f32 = 0.05;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum MaintenanceError {
+    #[error("Failed to read or write rebuild snapshot: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse rebuild snapshot: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, MaintenanceError>;
+
+// ============================================================================
+// Index Info
+// ============================================================================
+
+/// Where an index lives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexBackend {
+    /// An embedded Meilisearch index
+    Meilisearch,
+    /// A SurrealDB table with a vector index
+    VectorTable,
+}
+
+/// An index as listed for maintenance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSummary {
+    pub name: String,
+    pub backend: IndexBackend,
+    pub documents: u64,
+    /// An interrupted rebuild left a snapshot; rebuilding again restores it
+    pub pending_rebuild: bool,
+}
+
+/// Detailed figures for one index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexStats {
+    pub name: String,
+    pub backend: IndexBackend,
+    pub documents: u64,
+    /// Rows with an embedding (vector tables)
+    pub embedded_documents: Option<u64>,
+    /// Meilisearch primary key
+    pub primary_key: Option<String>,
+    /// Indexes defined on a vector table
+    pub table_indexes: Vec<String>,
+    pub pending_rebuild: bool,
+}
+
+// ============================================================================
+// Progress
+// ============================================================================
+
+/// A maintenance operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceOperation {
+    Rebuild,
+    Optimize,
+}
+
+/// Step of a maintenance operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStage {
+    /// Reading documents and settings into the snapshot
+    Exporting,
+    /// Dropping and recreating the index
+    Recreating,
+    /// Adding the documents back
+    Importing,
+    /// Rebuilding a vector table's indexes
+    Reindexing,
+    Completed,
+    Failed,
+}
+
+impl MaintenanceStage {
+    /// Overall progress, 0.0 to 1.0, at `fraction` of the way through this
+    /// stage
+    pub fn progress(&self, fraction: f32) -> f32 {
+        let fraction = fraction.clamp(0.0, 1.0);
+        match self {
+            MaintenanceStage::Exporting => EXPORT_SHARE * fraction,
+            MaintenanceStage::Recreating => EXPORT_SHARE + RECREATE_SHARE * fraction,
+            MaintenanceStage::Importing => {
+                EXPORT_SHARE + RECREATE_SHARE + (1.0 - EXPORT_SHARE - RECREATE_SHARE) * fraction
+            }
+            MaintenanceStage::Reindexing => fraction,
+            MaintenanceStage::Completed => 1.0,
+            MaintenanceStage::Failed => 0.0,
+        }
+    }
+}
+
+/// Progress event emitted during a rebuild or optimize
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexProgress {
+    pub index: String,
+    pub backend: IndexBackend,
+    pub operation: MaintenanceOperation,
+    pub stage: MaintenanceStage,
+    /// 0.0 to 1.0
+    pub progress: f32,
+    pub message: String,
+}
+
+/// Outcome of a rebuild or optimize
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceResult {
+    pub index: String,
+    pub backend: IndexBackend,
+    pub operation: MaintenanceOperation,
+    /// Documents in the index afterwards
+    pub documents: u64,
+    pub duration_ms: u64,
+}
+
+// ============================================================================
+// Rebuild Snapshot
+// ============================================================================
+
+/// A Meilisearch index's documents and settings, saved before it is dropped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebuildSnapshot {
+    pub index: String,
+    pub primary_key: Option<String>,
+    /// The index settings as Meilisearch serializes them
+    pub settings: serde_json::Value,
+    pub documents: Vec<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RebuildSnapshot {
+    pub fn new(
+        index: &str,
+        primary_key: Option<String>,
+        settings: serde_json::Value,
+        documents: Vec<serde_json::Value>,
+    ) -> Self {
+        Self {
+            index: index.to_string(),
+            primary_key,
+            settings,
+            documents,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Snapshot file for `index` in `dir`
+    pub fn path(dir: &Path, index: &str) -> PathBuf {
+        let name: String = index
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        dir.join(format!("{}.json", name))
+    }
+
+    /// The snapshot at `path`, if an earlier rebuild left one
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(&std::fs::read_to_string(path)?)?))
+    }
+
+    /// Write the snapshot, through a temporary file so a crash never leaves
+    /// a partial one
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Remove the snapshot once the index is refilled
+    pub fn remove(path: &Path) -> Result<()> {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_round_trip_and_remove() {
+        let dir = TempDir::new().unwrap();
+        let path = RebuildSnapshot::path(dir.path(), "rules");
+        assert!(RebuildSnapshot::load(&path).unwrap().is_none());
+
+        let snapshot = RebuildSnapshot::new(
+            "rules",
+            Some("id".to_string()),
+            json!({"filterableAttributes": ["source"]}),
+            vec![json!({"id": "a", "content": "Grapple"}), json!({"id": "b", "content": "Shove"})],
+        );
+        snapshot.save(&path).unwrap();
+
+        let loaded = RebuildSnapshot::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.primary_key.as_deref(), Some("id"));
+        assert_eq!(loaded.documents.len(), 2);
+        assert_eq!(loaded.settings["filterableAttributes"][0], "source");
+
+        RebuildSnapshot::remove(&path).unwrap();
+        assert!(RebuildSnapshot::load(&path).unwrap().is_none());
+        // Removing twice is fine
+        RebuildSnapshot::remove(&path).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_path_is_a_plain_file_name() {
+        let dir = Path::new("/data/index_rebuilds");
+        assert_eq!(RebuildSnapshot::path(dir, "ttrpg_npcs"), dir.join("ttrpg_npcs.json"));
+        assert_eq!(RebuildSnapshot::path(dir, "../etc/x"), dir.join("___etc_x.json"));
+    }
+
+    #[test]
+    fn test_stage_progress_is_monotonic() {
+        let steps = [
+            MaintenanceStage::Exporting.progress(0.0),
+            MaintenanceStage::Exporting.progress(1.0),
+            MaintenanceStage::Recreating.progress(0.5),
+            MaintenanceStage::Importing.progress(0.0),
+            MaintenanceStage::Importing.progress(0.5),
+            MaintenanceStage::Importing.progress(2.0),
+        ];
+        assert!(steps.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(steps[5], 1.0);
+        assert_eq!(MaintenanceStage::Completed.progress(0.0), 1.0);
+    }
+}
@@ -22,6 +22,10 @@
 //! - `hybrid`: Hybrid search engine combining keyword and semantic search
 //! - `synonyms`: TTRPG synonym dictionary for query expansion
 //! - `query`: Unified query enhancement with correction, expansion, and suggestions
+//! - `snippets`: Match-highlighted excerpts showing why a result matched
+//! - `autocomplete`: Prefix index of terms, entity names, and past searches
+//! - `rerank`: Optional cross-encoder re-ranking of the top fused results
+//! - `scope`: Library, campaign, and session search scopes
 //!
 //! # Usage Example
 //!
@@ -57,12 +61,25 @@ mod ttrpg;
 // Hybrid Search Engine Modules (existing)
 // ============================================================================
 
+pub mod autocomplete;
+pub mod bookmarks;
+pub mod duplicates;
 pub mod embeddings;
+pub mod export;
 pub mod fusion;
 pub mod hybrid;
+pub mod index_migration;
+pub mod index_tuning;
+pub mod lookup;
+pub mod maintenance;
 pub mod providers;
 pub mod query;
+pub mod rerank;
+pub mod scope;
+pub mod snippets;
 pub mod synonyms;
+pub mod term_sets;
+pub mod vector_tuning;
 
 // ============================================================================
 // Re-exports: Core Search Client
@@ -104,20 +121,47 @@ pub use library::{LibraryRepository, LibraryRepositoryImpl};
 // Re-exports: Hybrid Search Engine (existing)
 // ============================================================================
 
+pub use autocomplete::{Suggestion, SuggestionIndex, SuggestionKind};
+pub use bookmarks::{Bookmark, BookmarkError, BookmarkGroup, BookmarkLibrary, NewBookmark, QuickReferenceItem};
+pub use duplicates::{
+    DuplicateCluster, DuplicateError, DuplicateKind, DuplicateMember, DuplicateReport, DuplicateSuggestion,
+};
 pub use embeddings::{EmbeddingCache, EmbeddingConfig, EmbeddingError, EmbeddingProvider};
+pub use export::{format_export, ExportDocument, ExportError, ExportTarget, ExportedResult};
 pub use fusion::{FusedSearchResult, FusionStrategy, RRFConfig, RRFEngine};
 pub use hybrid::{
     HybridConfig, HybridSearchEngine, HybridSearchError, HybridSearchOptions,
     HybridSearchResponse, HybridSearchResult,
 };
+pub use index_migration::{
+    IndexDefinition, IndexMigration, IndexMigrationBackend, IndexMigrationError, IndexMigrationReport,
+    IndexVersions, MigrationPhase,
+};
+pub use index_tuning::{IndexTuning, IndexTuningError, RankingRule, TypoTolerance};
+pub use lookup::{LookupKind, LookupResult, MatchQuality, SpellEntry};
+pub use maintenance::{
+    IndexBackend, IndexProgress, IndexStats, IndexSummary, MaintenanceOperation, MaintenanceResult,
+    MaintenanceStage,
+};
 pub use providers::{create_provider, OllamaEmbeddings, OpenAIEmbeddings};
 pub use query::{
     enhance_query, get_query_hints, get_query_suggestions, CorrectionDetails, EnhancedQuery,
     ExpansionDetails, HintType, QueryEnhancer, SearchHint, TermExpansion, WordCorrection,
 };
+pub use rerank::{
+    CrossEncoder, RerankError, RerankModel, RerankSettings, Reranker, DEFAULT_RERANK_CANDIDATES,
+};
+pub use scope::{SearchScope, ScopedItem, ScopedKind};
+pub use snippets::{
+    extract_snippets, query_terms, snippet_length, Snippet, SnippetPart, DEFAULT_SNIPPET_LENGTH,
+};
 pub use synonyms::{
     ClarificationPrompt, DiceNotation, ExpansionInfo, QueryExpansionResult, TTRPGSynonyms,
 };
+pub use term_sets::{TermSet, TermSetError, TermSetKind, TermSetLibrary, TermSetScope};
+pub use vector_tuning::{
+    VectorBenchmarkReport, VectorIndexKind, VectorPrecision, VectorTuning, VectorTuningError,
+};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,286 @@
+//! Search Re-ranking
+//!
+//! An optional second stage after RRF fusion: a small local cross-encoder
+//! reads the query and each of the top fused candidates together and scores
+//! their relevance directly. This is slower than fusion, so it only runs over
+//! the first few dozen candidates, but it fixes cases where keyword and
+//! vector ranks agree on the wrong passage, as with closely worded rules.
+//!
+//! The ONNX model runs through fastembed and needs the `rerank` feature;
+//! without it, loading a cross-encoder fails and search keeps the fused order.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Fused candidates re-scored when none is configured
+pub const DEFAULT_RERANK_CANDIDATES: usize = 50;
+
+/// Fewest and most candidates accepted
+pub const RERANK_CANDIDATE_RANGE: (usize, usize) = (10, 200);
+
+/// Passages scored per model call
+#[cfg(feature = "rerank")]
+const RERANK_BATCH_SIZE: usize = 16;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum RerankError {
+    #[error("Re-ranking is not available in this build (enable the `rerank` feature)")]
+    Unavailable,
+
+    #[error("Failed to load re-ranking model: {0}")]
+    Model(String),
+
+    #[error("Re-ranking failed: {0}")]
+    Inference(String),
+}
+
+pub type Result<T> = std::result::Result<T, RerankError>;
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// Cross-encoder used for re-ranking
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RerankModel {
+    /// Jina v1 Turbo, English, about 38M parameters
+    #[default]
+    JinaTurbo,
+    /// BGE base, English and Chinese
+    BgeBase,
+    /// Jina v2 base, multilingual
+    JinaMultilingual,
+}
+
+impl RerankModel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RerankModel::JinaTurbo => "Jina Turbo (small, English)",
+            RerankModel::BgeBase => "BGE Base",
+            RerankModel::JinaMultilingual => "Jina v2 (multilingual)",
+        }
+    }
+
+    #[cfg(feature = "rerank")]
+    fn fastembed_model(&self) -> fastembed::RerankerModel {
+        match self {
+            RerankModel::JinaTurbo => fastembed::RerankerModel::JINARerankerV1TurboEn,
+            RerankModel::BgeBase => fastembed::RerankerModel::BGERerankerBase,
+            RerankModel::JinaMultilingual => fastembed::RerankerModel::JINARerankerV2BaseMultiligual,
+        }
+    }
+}
+
+/// Re-ranking options, part of the persisted search settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RerankSettings {
+    /// Re-score fused results with the cross-encoder
+    pub enabled: bool,
+    pub model: RerankModel,
+    /// Top fused candidates re-scored before the result limit applies
+    pub candidates: usize,
+}
+
+impl Default for RerankSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: RerankModel::default(),
+            candidates: DEFAULT_RERANK_CANDIDATES,
+        }
+    }
+}
+
+impl RerankSettings {
+    /// Candidates to re-score, within the accepted range and at least `limit`
+    pub fn candidate_count(&self, limit: usize) -> usize {
+        let (min, max) = RERANK_CANDIDATE_RANGE;
+        self.candidates.clamp(min, max).max(limit)
+    }
+}
+
+// ============================================================================
+// Re-ranking
+// ============================================================================
+
+/// Scores how well each passage answers a query; higher is more relevant
+pub trait Reranker: Send + Sync {
+    /// One score per passage, in passage order
+    fn score(&self, query: &str, passages: &[&str]) -> Result<Vec<f32>>;
+}
+
+/// Score passages, checking that one score came back for each
+pub fn score_passages(reranker: &dyn Reranker, query: &str, passages: &[&str]) -> Result<Vec<f32>> {
+    if passages.is_empty() {
+        return Ok(Vec::new());
+    }
+    let scores = reranker.score(query, passages)?;
+    if scores.len() != passages.len() {
+        return Err(RerankError::Inference(format!(
+            "expected {} scores, got {}",
+            passages.len(),
+            scores.len()
+        )));
+    }
+    Ok(scores)
+}
+
+/// Re-order the first `scores.len()` items by score, best first.
+///
+/// Items past the scored ones keep their order after them, and ties keep
+/// the fused order. Returns the scores in the new order.
+pub fn reorder_by_scores<T>(items: &mut Vec<T>, scores: &[f32]) -> Vec<f32> {
+    let head = scores.len().min(items.len());
+    let mut order: Vec<usize> = (0..head).collect();
+    order.sort_by(|a, b| {
+        scores[*b]
+            .partial_cmp(&scores[*a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let tail = items.split_off(head);
+    let mut slots: Vec<Option<T>> = std::mem::take(items).into_iter().map(Some).collect();
+    for &i in &order {
+        if let Some(item) = slots[i].take() {
+            items.push(item);
+        }
+    }
+    items.extend(tail);
+
+    order.into_iter().map(|i| scores[i]).collect()
+}
+
+// ============================================================================
+// Cross-Encoder
+// ============================================================================
+
+/// A local ONNX cross-encoder
+pub struct CrossEncoder {
+    model: RerankModel,
+    #[cfg(feature = "rerank")]
+    inner: std::sync::Mutex<fastembed::TextRerank>,
+}
+
+impl CrossEncoder {
+    /// Load a model, downloading it into `cache_dir` on first use
+    #[cfg(feature = "rerank")]
+    pub fn load(model: RerankModel, cache_dir: &Path) -> Result<Self> {
+        let options = fastembed::RerankInitOptions::new(model.fastembed_model())
+            .with_cache_dir(cache_dir.to_path_buf())
+            .with_show_download_progress(false);
+        let inner = fastembed::TextRerank::try_new(options).map_err(|e| RerankError::Model(e.to_string()))?;
+        Ok(Self { model, inner: std::sync::Mutex::new(inner) })
+    }
+
+    /// Loading always fails without the `rerank` feature
+    #[cfg(not(feature = "rerank"))]
+    pub fn load(_model: RerankModel, _cache_dir: &Path) -> Result<Self> {
+        Err(RerankError::Unavailable)
+    }
+
+    pub fn model(&self) -> RerankModel {
+        self.model
+    }
+
+    /// Whether this build can run a cross-encoder
+    pub fn is_supported() -> bool {
+        cfg!(feature = "rerank")
+    }
+}
+
+impl Reranker for CrossEncoder {
+    #[cfg(feature = "rerank")]
+    fn score(&self, query: &str, passages: &[&str]) -> Result<Vec<f32>> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let ranked = inner
+            .rerank(query, passages.to_vec(), false, Some(RERANK_BATCH_SIZE))
+            .map_err(|e| RerankError::Inference(e.to_string()))?;
+
+        let mut scores = vec![f32::NEG_INFINITY; passages.len()];
+        for result in ranked {
+            if let Some(score) = scores.get_mut(result.index) {
+                *score = result.score;
+            }
+        }
+        Ok(scores)
+    }
+
+    #[cfg(not(feature = "rerank"))]
+    fn score(&self, _query: &str, _passages: &[&str]) -> Result<Vec<f32>> {
+        Err(RerankError::Unavailable)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scores passages by how many query words they contain
+    struct WordOverlap;
+
+    impl Reranker for WordOverlap {
+        fn score(&self, query: &str, passages: &[&str]) -> Result<Vec<f32>> {
+            Ok(passages
+                .iter()
+                .map(|p| query.split_whitespace().filter(|w| p.contains(w)).count() as f32)
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_reorders_head_and_keeps_tail() {
+        let mut items = vec!["cover rules", "grapple escape DC", "grapple", "flanking", "grapple escape"];
+        let scores = score_passages(&WordOverlap, "grapple escape DC", &items[..4]).unwrap();
+
+        let sorted = reorder_by_scores(&mut items, &scores);
+
+        assert_eq!(items, vec!["grapple escape DC", "grapple", "cover rules", "flanking", "grapple escape"]);
+        assert_eq!(sorted, vec![3.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_mismatched_scores_are_an_error() {
+        struct Short;
+        impl Reranker for Short {
+            fn score(&self, _query: &str, _passages: &[&str]) -> Result<Vec<f32>> {
+                Ok(vec![1.0])
+            }
+        }
+
+        assert!(score_passages(&Short, "q", &["a", "b"]).is_err());
+        assert!(score_passages(&Short, "q", &[]).unwrap().is_empty());
+
+        // Extra scores beyond the items are ignored
+        let mut items = vec!["a"];
+        assert_eq!(reorder_by_scores(&mut items, &[0.5, 2.0]), vec![0.5]);
+        assert_eq!(items, vec!["a"]);
+    }
+
+    #[test]
+    fn test_settings_defaults_and_candidate_count() {
+        let settings: RerankSettings = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.model, RerankModel::JinaTurbo);
+        assert_eq!(settings.candidate_count(10), DEFAULT_RERANK_CANDIDATES);
+
+        let few = RerankSettings { candidates: 2, ..Default::default() };
+        assert_eq!(few.candidate_count(5), RERANK_CANDIDATE_RANGE.0);
+        assert_eq!(few.candidate_count(30), 30);
+        assert_eq!(CrossEncoder::is_supported(), cfg!(feature = "rerank"));
+    }
+}
@@ -0,0 +1,278 @@
+//! Search Scopes
+//!
+//! What a search covers: the shared library, one campaign's own content, or
+//! one session's. Campaign content lives partly in the search indexes (chat
+//! and campaign documents, filtered by campaign or session) and partly in
+//! the campaign stores (notes, NPCs, locations, handouts), which are matched
+//! here and merged with the index hits by Reciprocal Rank Fusion.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::config::{INDEX_CHAT, INDEX_DOCUMENTS, INDEX_FICTION, INDEX_RULES};
+use super::snippets::query_terms;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Indexes holding shared library content
+pub const LIBRARY_INDEXES: [&str; 3] = [INDEX_RULES, INDEX_FICTION, INDEX_DOCUMENTS];
+
+/// Indexes holding content tied to a campaign or session
+pub const CAMPAIGN_INDEXES: [&str; 2] = [INDEX_CHAT, INDEX_DOCUMENTS];
+
+/// RRF constant for merging index hits with campaign content
+const SCOPE_RRF_K: f32 = 60.0;
+
+/// Weight of a query term found in an item's title over one in its body
+const TITLE_MATCH_WEIGHT: f32 = 2.0;
+
+// ============================================================================
+// Scope
+// ============================================================================
+
+/// What a search covers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum SearchScope {
+    /// Rulebooks, fiction, and uploaded documents
+    #[default]
+    Library,
+    /// One campaign's notes, NPCs, locations, handouts, chat, and documents
+    Campaign {
+        campaign_id: String,
+        #[serde(default)]
+        include_library: bool,
+    },
+    /// One session's notes, handouts, and chat
+    Session {
+        session_id: String,
+        #[serde(default)]
+        include_library: bool,
+    },
+}
+
+impl SearchScope {
+    pub fn includes_library(&self) -> bool {
+        match self {
+            SearchScope::Library => true,
+            SearchScope::Campaign { include_library, .. } | SearchScope::Session { include_library, .. } => {
+                *include_library
+            }
+        }
+    }
+
+    /// Whether campaign stores are searched
+    pub fn includes_campaign_content(&self) -> bool {
+        !matches!(self, SearchScope::Library)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match (self, self.includes_library()) {
+            (SearchScope::Library, _) => "Library",
+            (SearchScope::Campaign { .. }, false) => "Campaign",
+            (SearchScope::Campaign { .. }, true) => "Campaign + Library",
+            (SearchScope::Session { .. }, false) => "Session",
+            (SearchScope::Session { .. }, true) => "Session + Library",
+        }
+    }
+}
+
+// ============================================================================
+// Campaign Content
+// ============================================================================
+
+/// Kind of campaign content a scoped hit came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopedKind {
+    CampaignNote,
+    SessionNote,
+    Npc,
+    Location,
+    Handout,
+}
+
+impl ScopedKind {
+    /// Source type reported on results
+    pub fn source_type(&self) -> &'static str {
+        match self {
+            ScopedKind::CampaignNote => "campaign_note",
+            ScopedKind::SessionNote => "session_note",
+            ScopedKind::Npc => "npc",
+            ScopedKind::Location => "location",
+            ScopedKind::Handout => "handout",
+        }
+    }
+}
+
+/// A searchable piece of campaign content
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopedItem {
+    pub kind: ScopedKind,
+    pub id: String,
+    pub title: String,
+    pub body: String,
+}
+
+impl ScopedItem {
+    pub fn new(kind: ScopedKind, id: &str, title: &str, parts: &[&str]) -> Self {
+        let body = parts
+            .iter()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Self { kind, id: id.to_string(), title: title.trim().to_string(), body }
+    }
+}
+
+/// Campaign content matching the query, best first.
+///
+/// Each query term counts once, more in the title than the body, and the
+/// score is the share of the best possible; the whole query appearing as a
+/// phrase adds a bonus. Items matching no term are dropped.
+pub fn rank_scoped_items(query: &str, items: Vec<ScopedItem>) -> Vec<(ScopedItem, f32)> {
+    let mut terms = query_terms(query);
+    if terms.is_empty() {
+        terms = query.split_whitespace().map(str::to_lowercase).collect();
+    }
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let phrase = query.trim().to_lowercase();
+    let best = terms.len() as f32 * TITLE_MATCH_WEIGHT;
+
+    let mut ranked: Vec<(ScopedItem, f32)> = items
+        .into_iter()
+        .filter_map(|item| {
+            let title = item.title.to_lowercase();
+            let body = item.body.to_lowercase();
+            let matched: f32 = terms
+                .iter()
+                .map(|term| {
+                    if has_word_prefix(&title, term) {
+                        TITLE_MATCH_WEIGHT
+                    } else if has_word_prefix(&body, term) {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+                .sum();
+            if matched == 0.0 {
+                return None;
+            }
+            let bonus = if terms.len() > 1 && (title.contains(&phrase) || body.contains(&phrase)) {
+                0.5
+            } else {
+                0.0
+            };
+            Some((item, matched / best + bonus))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.title.cmp(&b.0.title))
+    });
+    ranked
+}
+
+/// Whether `term` starts a word in `text`
+fn has_word_prefix(text: &str, term: &str) -> bool {
+    text.match_indices(term).any(|(i, _)| {
+        text[..i].chars().next_back().is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+// ============================================================================
+// Merging
+// ============================================================================
+
+/// Merge ranked lists of keys with Reciprocal Rank Fusion.
+///
+/// A key found in several lists sums its contributions. Returns each key
+/// with its fused score, best first; ties keep first-seen order.
+pub fn merge_ranked(lists: &[Vec<String>]) -> Vec<(String, f32)> {
+    let mut order: Vec<String> = Vec::new();
+    let mut scores: HashMap<String, f32> = HashMap::new();
+    for list in lists {
+        for (rank, key) in list.iter().enumerate() {
+            let score = 1.0 / (SCOPE_RRF_K + rank as f32 + 1.0);
+            match scores.get_mut(key) {
+                Some(total) => *total += score,
+                None => {
+                    scores.insert(key.clone(), score);
+                    order.push(key.clone());
+                }
+            }
+        }
+    }
+
+    let mut merged: Vec<(String, f32)> = order
+        .into_iter()
+        .map(|key| {
+            let score = scores[&key];
+            (key, score)
+        })
+        .collect();
+    merged.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    merged
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_serialization_and_library() {
+        let scope: SearchScope =
+            serde_json::from_str(r#"{"scope": "campaign", "campaign_id": "c1", "include_library": true}"#).unwrap();
+        assert_eq!(scope, SearchScope::Campaign { campaign_id: "c1".to_string(), include_library: true });
+        assert!(scope.includes_library());
+        assert_eq!(scope.label(), "Campaign + Library");
+
+        let session: SearchScope = serde_json::from_str(r#"{"scope": "session", "session_id": "s1"}"#).unwrap();
+        assert!(!session.includes_library());
+        assert!(session.includes_campaign_content());
+        assert!(!SearchScope::default().includes_campaign_content());
+    }
+
+    #[test]
+    fn test_ranks_title_matches_first() {
+        let items = vec![
+            ScopedItem::new(ScopedKind::CampaignNote, "n1", "Session 3 recap", &["The party met Mayor Vell at the docks."]),
+            ScopedItem::new(ScopedKind::Npc, "npc1", "Mayor Vell", &["Corrupt, owes the thieves' guild."]),
+            ScopedItem::new(ScopedKind::Location, "loc1", "The Docks", &["Smugglers unload at night."]),
+            ScopedItem::new(ScopedKind::Handout, "h1", "Map", &["Shows the swamp."]),
+        ];
+
+        let ranked = rank_scoped_items("mayor vell", items);
+        let ids: Vec<&str> = ranked.iter().map(|(item, _)| item.id.as_str()).collect();
+
+        assert_eq!(ids, vec!["npc1", "n1"]);
+        assert!(ranked[0].1 > ranked[1].1);
+        // Terms match at word starts only
+        let dock = vec![ScopedItem::new(ScopedKind::Location, "l", "Paddock", &[])];
+        assert!(rank_scoped_items("dock", dock).is_empty());
+    }
+
+    #[test]
+    fn test_merge_ranked_interleaves_and_sums() {
+        let library = vec!["rules:1".to_string(), "rules:2".to_string()];
+        let campaign = vec!["npc:a".to_string(), "rules:2".to_string()];
+
+        let merged = merge_ranked(&[library, campaign]);
+        let keys: Vec<&str> = merged.iter().map(|(k, _)| k.as_str()).collect();
+
+        assert_eq!(keys, vec!["rules:2", "rules:1", "npc:a"]);
+        assert_eq!(merged.len(), 3);
+    }
+}
@@ -0,0 +1,256 @@
+//! Search Result Snippets
+//!
+//! Extracts match-highlighted excerpts from search hits so a result can show
+//! why it matched. Works on any hit content, keyword or vector, since the
+//! terms are found in the text rather than taken from the engine.
+
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Snippet length in characters when none is requested
+pub const DEFAULT_SNIPPET_LENGTH: usize = 200;
+
+/// Shortest and longest snippet lengths accepted
+pub const SNIPPET_LENGTH_RANGE: (usize, usize) = (40, 1000);
+
+/// Most snippets extracted from one hit
+pub const MAX_SNIPPETS: usize = 3;
+
+/// Shortest query word treated as a term
+const MIN_TERM_CHARS: usize = 3;
+
+/// How far context is trimmed back to reach a word boundary
+const BOUNDARY_SLACK: usize = 15;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// One piece of the matched span
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnippetPart {
+    pub text: String,
+    /// Whether this piece is a matched query term
+    pub highlighted: bool,
+}
+
+/// An excerpt around one or more matched terms
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snippet {
+    /// Text before the first match, starting with "…" when cut
+    pub pre_context: String,
+    /// From the first match to the last, with matched terms as their own parts
+    pub parts: Vec<SnippetPart>,
+    /// Text after the last match, ending with "…" when cut
+    pub post_context: String,
+}
+
+impl Snippet {
+    /// The snippet as plain text
+    pub fn text(&self) -> String {
+        let mut text = self.pre_context.clone();
+        for part in &self.parts {
+            text.push_str(&part.text);
+        }
+        text.push_str(&self.post_context);
+        text
+    }
+
+    /// The matched terms as they appear in the content
+    pub fn matched_terms(&self) -> Vec<&str> {
+        self.parts.iter().filter(|p| p.highlighted).map(|p| p.text.as_str()).collect()
+    }
+}
+
+// ============================================================================
+// Extraction
+// ============================================================================
+
+/// Lowercased query words worth highlighting, without duplicates
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric()) {
+        let word = word.to_lowercase();
+        if word.chars().count() >= MIN_TERM_CHARS && !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
+/// Clamp a requested snippet length, defaulting when absent
+pub fn snippet_length(requested: Option<usize>) -> usize {
+    let (min, max) = SNIPPET_LENGTH_RANGE;
+    requested.unwrap_or(DEFAULT_SNIPPET_LENGTH).clamp(min, max)
+}
+
+/// Extract up to [`MAX_SNIPPETS`] snippets of about `length` characters.
+///
+/// Terms match case-insensitively at the start of a word, so "grapple"
+/// highlights "Grappled" but "ice" doesn't highlight "dice". Nearby matches
+/// share a snippet. Returns nothing when no term appears.
+pub fn extract_snippets(content: &str, terms: &[String], length: usize) -> Vec<Snippet> {
+    let chars: Vec<char> = content.chars().collect();
+    // One lowercase char per char keeps indices aligned with `chars`
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let matches = find_matches(&lower, terms);
+    let mut snippets = Vec::new();
+    let mut next = 0;
+
+    while next < matches.len() && snippets.len() < MAX_SNIPPETS {
+        let (span_start, first_end) = matches[next];
+        let mut span_end = first_end;
+        let mut last = next;
+        while last + 1 < matches.len() && matches[last + 1].1 - span_start <= length {
+            last += 1;
+            span_end = matches[last].1;
+        }
+
+        let spare = length.saturating_sub(span_end - span_start);
+        let mut from = span_start.saturating_sub(spare / 2);
+        let mut to = (span_end + spare - (span_start - from)).min(chars.len());
+        // Use any context the end of the content left unspent
+        from = from.min(span_start.saturating_sub(length.saturating_sub(to - span_start)));
+
+        from = snap_forward(&chars, from, span_start);
+        to = snap_backward(&chars, to, span_end);
+
+        let mut pre_context: String = chars[from..span_start].iter().collect();
+        if from > 0 {
+            pre_context = format!("…{}", pre_context.trim_start());
+        }
+        let mut post_context: String = chars[span_end..to].iter().collect();
+        if to < chars.len() {
+            post_context = format!("{}…", post_context.trim_end());
+        }
+
+        let mut parts = Vec::new();
+        let mut cursor = span_start;
+        for &(start, end) in &matches[next..=last] {
+            if start > cursor {
+                parts.push(SnippetPart { text: chars[cursor..start].iter().collect(), highlighted: false });
+            }
+            parts.push(SnippetPart { text: chars[start..end].iter().collect(), highlighted: true });
+            cursor = end;
+        }
+
+        snippets.push(Snippet { pre_context, parts, post_context });
+
+        // Matches already shown in this snippet's context start the next one
+        next = last + 1;
+        while next < matches.len() && matches[next].0 < to {
+            next += 1;
+        }
+    }
+
+    snippets
+}
+
+/// Char ranges where a term starts a word, sorted and without overlaps
+fn find_matches(lower: &[char], terms: &[String]) -> Vec<(usize, usize)> {
+    let mut matches: Vec<(usize, usize)> = Vec::new();
+    for term in terms {
+        let term: Vec<char> = term.chars().collect();
+        if term.is_empty() || term.len() > lower.len() {
+            continue;
+        }
+        for start in 0..=lower.len() - term.len() {
+            let at_word_start = start == 0 || !lower[start - 1].is_alphanumeric();
+            if at_word_start && lower[start..start + term.len()] == term[..] {
+                matches.push((start, start + term.len()));
+            }
+        }
+    }
+    matches.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in matches {
+        match merged.last_mut() {
+            Some(last) if start < last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Move a context start forward to just after whitespace, if one is close
+fn snap_forward(chars: &[char], from: usize, limit: usize) -> usize {
+    if from == 0 {
+        return 0;
+    }
+    (from..limit.min(from + BOUNDARY_SLACK))
+        .find(|&i| chars[i - 1].is_whitespace())
+        .unwrap_or(from)
+}
+
+/// Move a context end back to whitespace, if one is close
+fn snap_backward(chars: &[char], to: usize, limit: usize) -> usize {
+    if to >= chars.len() {
+        return chars.len();
+    }
+    (limit.max(to.saturating_sub(BOUNDARY_SLACK))..=to)
+        .rev()
+        .find(|&i| chars[i].is_whitespace())
+        .unwrap_or(to)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlights_term_with_context() {
+        let filler = "The rules for movement are covered in chapter nine of this book. ".repeat(4);
+        let content = format!("{}A creature that is Grappled has a speed of 0. {}", filler, filler);
+
+        let snippets = extract_snippets(&content, &terms(&["grapple"]), 120);
+
+        assert_eq!(snippets.len(), 1);
+        let snippet = &snippets[0];
+        assert_eq!(snippet.matched_terms(), vec!["Grapple"]);
+        assert!(snippet.pre_context.starts_with('…'));
+        assert!(snippet.post_context.ends_with('…'));
+        assert!(snippet.text().contains("Grappled has a speed of 0"));
+        assert!(snippet.text().chars().count() <= 125);
+    }
+
+    #[test]
+    fn test_matches_only_at_word_start() {
+        let snippets = extract_snippets("Roll dice. Ice storms hurt.", &terms(&["ice"]), 200);
+
+        assert_eq!(snippets.len(), 1);
+        assert_eq!(snippets[0].matched_terms(), vec!["Ice"]);
+        assert_eq!(snippets[0].pre_context, "Roll dice. ");
+        assert!(extract_snippets("Roll dice.", &terms(&["ice"]), 200).is_empty());
+    }
+
+    #[test]
+    fn test_nearby_matches_share_a_snippet_and_distant_ones_split() {
+        let gap = "word ".repeat(100);
+        let content = format!("fire and acid damage {} fire again", gap);
+
+        let snippets = extract_snippets(&content, &terms(&["fire", "acid"]), 60);
+
+        assert_eq!(snippets.len(), 2);
+        assert_eq!(snippets[0].matched_terms(), vec!["fire", "acid"]);
+        assert_eq!(snippets[1].matched_terms(), vec!["fire"]);
+        assert_eq!(query_terms("Fire, fire and ACID!"), terms(&["fire", "and", "acid"]));
+        assert_eq!(snippet_length(Some(5)), SNIPPET_LENGTH_RANGE.0);
+        assert_eq!(snippet_length(None), DEFAULT_SNIPPET_LENGTH);
+    }
+}
@@ -0,0 +1,388 @@
+//! Custom Synonym and Antonym Sets
+//!
+//! User-defined term sets for a game system or a single campaign. Synonym
+//! sets become Meilisearch index synonyms, so a keyword search for any term
+//! in a set also matches the others. Antonym sets extend the built-in pairs
+//! the result ranker uses to penalize results carrying the opposite of a
+//! queried attribute.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use thiserror::Error;
+
+use crate::core::ttrpg_search::AntonymMapper;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Most terms on one side of a set
+pub const MAX_TERMS_PER_SET: usize = 32;
+
+/// Longest term accepted, in characters
+const MAX_TERM_CHARS: usize = 64;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum TermSetError {
+    #[error("Term set not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid term set: {0}")]
+    Invalid(String),
+
+    #[error("Failed to read or write term sets: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse term sets: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, TermSetError>;
+
+// ============================================================================
+// Term Sets
+// ============================================================================
+
+/// What the terms in a set are to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermSetKind {
+    /// Every term means the same as every other
+    Synonym,
+    /// Every term is the opposite of every one of its opposites
+    Antonym,
+}
+
+/// Where a set applies
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum TermSetScope {
+    /// Searches in campaigns of a game system, by system ID ("dnd5e")
+    GameSystem { game_system: String },
+    /// Searches in one campaign
+    Campaign { campaign_id: String },
+}
+
+impl TermSetScope {
+    /// Whether the set applies to a search in `game_system` and `campaign_id`
+    pub fn applies_to(&self, game_system: Option<&str>, campaign_id: Option<&str>) -> bool {
+        match self {
+            TermSetScope::GameSystem { game_system: system } => game_system == Some(system.as_str()),
+            TermSetScope::Campaign { campaign_id: id } => campaign_id == Some(id.as_str()),
+        }
+    }
+}
+
+/// A user-defined synonym or antonym set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermSet {
+    pub id: String,
+    pub kind: TermSetKind,
+    pub scope: TermSetScope,
+    /// Lowercase and distinct
+    pub terms: Vec<String>,
+    /// For an antonym set, the terms opposed to `terms`; empty for synonyms
+    #[serde(default)]
+    pub opposites: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TermSet {
+    /// A synonym set of `terms`, or an antonym set opposing `terms` to
+    /// `opposites`
+    pub fn new(kind: TermSetKind, scope: TermSetScope, terms: &[String], opposites: &[String]) -> Result<Self> {
+        let (terms, opposites) = validate_terms(kind, terms, opposites)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            scope,
+            terms,
+            opposites,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Related term pairs: every two terms of a synonym set, or each term
+    /// with each opposite of an antonym set
+    fn pairs(&self) -> Vec<(&str, &str)> {
+        match self.kind {
+            TermSetKind::Synonym => self
+                .terms
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| self.terms[i + 1..].iter().map(move |b| (a.as_str(), b.as_str())))
+                .collect(),
+            TermSetKind::Antonym => self
+                .terms
+                .iter()
+                .flat_map(|a| self.opposites.iter().map(move |b| (a.as_str(), b.as_str())))
+                .collect(),
+        }
+    }
+}
+
+/// Normalize both sides of a set and check it makes sense for its kind
+fn validate_terms(kind: TermSetKind, terms: &[String], opposites: &[String]) -> Result<(Vec<String>, Vec<String>)> {
+    let terms = normalize_terms(terms)?;
+    let opposites = normalize_terms(opposites)?;
+    match kind {
+        TermSetKind::Synonym if terms.len() < 2 => {
+            Err(TermSetError::Invalid("a synonym set needs at least two different terms".to_string()))
+        }
+        TermSetKind::Synonym if !opposites.is_empty() => {
+            Err(TermSetError::Invalid("a synonym set has no opposites".to_string()))
+        }
+        TermSetKind::Antonym if terms.is_empty() || opposites.is_empty() => {
+            Err(TermSetError::Invalid("an antonym set needs terms and their opposites".to_string()))
+        }
+        TermSetKind::Antonym if terms.iter().any(|t| opposites.contains(t)) => {
+            Err(TermSetError::Invalid("a term can't be its own opposite".to_string()))
+        }
+        _ => Ok((terms, opposites)),
+    }
+}
+
+/// Trim, lowercase, and de-duplicate terms, rejecting long terms and
+/// oversized sides
+fn normalize_terms(terms: &[String]) -> Result<Vec<String>> {
+    let mut seen = BTreeSet::new();
+    let mut normalized = Vec::new();
+    for term in terms {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        if term.is_empty() {
+            continue;
+        }
+        if term.chars().count() > MAX_TERM_CHARS {
+            return Err(TermSetError::Invalid(format!(
+                "'{}' is longer than {} characters",
+                term, MAX_TERM_CHARS
+            )));
+        }
+        if seen.insert(term.clone()) {
+            normalized.push(term);
+        }
+    }
+    if normalized.len() > MAX_TERMS_PER_SET {
+        return Err(TermSetError::Invalid(format!("a set can have at most {} terms", MAX_TERMS_PER_SET)));
+    }
+    Ok(normalized)
+}
+
+// ============================================================================
+// Term Set Library
+// ============================================================================
+
+/// All custom term sets, persisted as one JSON file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TermSetLibrary {
+    sets: Vec<TermSet>,
+}
+
+impl TermSetLibrary {
+    /// Load from a JSON file; a missing file yields an empty library
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Sets of `kind` (any when `None`), limited to those for a game system
+    /// or campaign when given, ordered by scope and creation
+    pub fn list(
+        &self,
+        kind: Option<TermSetKind>,
+        game_system: Option<&str>,
+        campaign_id: Option<&str>,
+    ) -> Vec<TermSet> {
+        let scoped = game_system.is_some() || campaign_id.is_some();
+        let mut sets: Vec<TermSet> = self
+            .sets
+            .iter()
+            .filter(|set| kind.is_none_or(|k| set.kind == k))
+            .filter(|set| !scoped || set.scope.applies_to(game_system, campaign_id))
+            .cloned()
+            .collect();
+        sets.sort_by(|a, b| {
+            let scope_key = |set: &TermSet| matches!(set.scope, TermSetScope::Campaign { .. });
+            scope_key(a).cmp(&scope_key(b)).then_with(|| a.created_at.cmp(&b.created_at))
+        });
+        sets
+    }
+
+    pub fn get(&self, id: &str) -> Option<&TermSet> {
+        self.sets.iter().find(|set| set.id == id)
+    }
+
+    pub fn create(&mut self, set: TermSet) -> TermSet {
+        self.sets.push(set.clone());
+        set
+    }
+
+    /// Replace a set's terms and opposites and, when given, its scope
+    pub fn update(
+        &mut self,
+        id: &str,
+        terms: &[String],
+        opposites: &[String],
+        scope: Option<TermSetScope>,
+    ) -> Result<TermSet> {
+        let set = self
+            .sets
+            .iter_mut()
+            .find(|set| set.id == id)
+            .ok_or_else(|| TermSetError::NotFound(id.to_string()))?;
+        let (terms, opposites) = validate_terms(set.kind, terms, opposites)?;
+        set.terms = terms;
+        set.opposites = opposites;
+        if let Some(scope) = scope {
+            set.scope = scope;
+        }
+        set.updated_at = Utc::now();
+        Ok(set.clone())
+    }
+
+    pub fn delete(&mut self, id: &str) -> Result<TermSet> {
+        let index = self
+            .sets
+            .iter()
+            .position(|set| set.id == id)
+            .ok_or_else(|| TermSetError::NotFound(id.to_string()))?;
+        Ok(self.sets.remove(index))
+    }
+
+    /// Synonyms for Meilisearch: each term mapped to every other term it
+    /// shares a set with.
+    ///
+    /// Meilisearch synonyms are index-wide, so sets from every scope are
+    /// merged; a synonym only widens what keyword search matches.
+    pub fn index_synonyms(&self) -> BTreeMap<String, Vec<String>> {
+        let mut synonyms: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for set in self.sets.iter().filter(|set| set.kind == TermSetKind::Synonym) {
+            for (a, b) in set.pairs() {
+                synonyms.entry(a.to_string()).or_default().insert(b.to_string());
+                synonyms.entry(b.to_string()).or_default().insert(a.to_string());
+            }
+        }
+        synonyms
+            .into_iter()
+            .map(|(term, others)| (term, others.into_iter().collect()))
+            .collect()
+    }
+
+    /// The built-in antonym pairs plus the antonym sets for a search in
+    /// `game_system` and `campaign_id`
+    pub fn antonym_mapper(&self, game_system: Option<&str>, campaign_id: Option<&str>) -> AntonymMapper {
+        let mut mapper = AntonymMapper::new();
+        for set in self
+            .sets
+            .iter()
+            .filter(|set| set.kind == TermSetKind::Antonym && set.scope.applies_to(game_system, campaign_id))
+        {
+            for (a, b) in set.pairs() {
+                mapper.add_pair(a, b);
+            }
+        }
+        mapper
+    }
+
+    /// Remove a deleted campaign's sets
+    pub fn delete_campaign_sets(&mut self, campaign_id: &str) {
+        self.sets
+            .retain(|set| !matches!(&set.scope, TermSetScope::Campaign { campaign_id: id } if id == campaign_id));
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    fn system(id: &str) -> TermSetScope {
+        TermSetScope::GameSystem { game_system: id.to_string() }
+    }
+
+    fn campaign(id: &str) -> TermSetScope {
+        TermSetScope::Campaign { campaign_id: id.to_string() }
+    }
+
+    #[test]
+    fn test_terms_are_normalized_and_validated() {
+        let set = TermSet::new(TermSetKind::Synonym, system("dnd5e"), &terms(&[" Hit  Points ", "HP", "hp", ""]), &[])
+            .unwrap();
+        assert_eq!(set.terms, vec!["hit points", "hp"]);
+
+        assert!(TermSet::new(TermSetKind::Synonym, system("dnd5e"), &terms(&["HP", "hp"]), &[]).is_err());
+        assert!(TermSet::new(TermSetKind::Antonym, system("dnd5e"), &terms(&["holy"]), &[]).is_err());
+        assert!(TermSet::new(TermSetKind::Antonym, system("dnd5e"), &terms(&["holy"]), &terms(&["Holy"])).is_err());
+        let long = "x".repeat(MAX_TERM_CHARS + 1);
+        assert!(TermSet::new(TermSetKind::Antonym, system("dnd5e"), &terms(&["a"]), &terms(&[&long])).is_err());
+
+        let scope: TermSetScope = serde_json::from_str(r#"{"scope": "campaign", "campaign_id": "c1"}"#).unwrap();
+        assert_eq!(scope, campaign("c1"));
+    }
+
+    #[test]
+    fn test_index_synonyms_merge_sets() {
+        let mut library = TermSetLibrary::default();
+        library.create(TermSet::new(TermSetKind::Synonym, system("dnd5e"), &terms(&["hp", "hit points"]), &[]).unwrap());
+        library.create(TermSet::new(TermSetKind::Synonym, campaign("c1"), &terms(&["hp", "health", "vigor"]), &[]).unwrap());
+        library.create(TermSet::new(TermSetKind::Antonym, system("dnd5e"), &terms(&["holy"]), &terms(&["unholy"])).unwrap());
+
+        let synonyms = library.index_synonyms();
+
+        assert_eq!(synonyms["hp"], vec!["health", "hit points", "vigor"]);
+        assert_eq!(synonyms["vigor"], vec!["health", "hp"]);
+        assert!(!synonyms.contains_key("holy"));
+    }
+
+    #[test]
+    fn test_antonym_mapper_uses_applicable_sets() {
+        let mut library = TermSetLibrary::default();
+        library.create(TermSet::new(TermSetKind::Antonym, system("dnd5e"), &terms(&["holy"]), &terms(&["unholy"])).unwrap());
+        let corrupt = library.create(
+            TermSet::new(TermSetKind::Antonym, campaign("c1"), &terms(&["sunlit"]), &terms(&["blighted", "corrupted"]))
+                .unwrap(),
+        );
+
+        let mapper = library.antonym_mapper(Some("dnd5e"), Some("c1"));
+        assert!(mapper.are_antonyms("holy", "unholy"));
+        assert!(mapper.are_antonyms("corrupted", "sunlit"));
+        assert!(!mapper.are_antonyms("blighted", "corrupted"));
+        // Built-in pairs stay
+        assert!(mapper.are_antonyms("fire", "cold"));
+
+        let other = library.antonym_mapper(Some("pf2e"), Some("c2"));
+        assert!(!other.are_antonyms("holy", "unholy"));
+        assert_eq!(library.list(Some(TermSetKind::Antonym), Some("pf2e"), Some("c1")).len(), 1);
+
+        library.delete_campaign_sets("c1");
+        assert!(library.get(&corrupt.id).is_none());
+        assert!(library.delete(&corrupt.id).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! Vector Index Tuning
+//!
+//! User-editable settings for the SurrealDB vector indexes: the index
+//! structure, how vectors are stored, and the HNSW graph and search
+//! parameters, plus the arithmetic for benchmarking them against exact
+//! search. SurrealDB offers HNSW and M-tree indexes but no IVF or product
+//! quantization; storing vectors as 32-bit floats is the quantization it
+//! supports, halving an index's memory.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use thiserror::Error;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// HNSW neighbours per node, as in the schema
+pub const DEFAULT_HNSW_M: u32 = 12;
+
+/// HNSW candidate list size while building, as in the schema
+pub const DEFAULT_HNSW_EFC: u32 = 150;
+
+/// HNSW candidate list size while searching
+pub const DEFAULT_SEARCH_EF: u32 = 100;
+
+/// Entries per M-tree node
+pub const DEFAULT_MTREE_CAPACITY: u32 = 40;
+
+/// Search candidate list sizes tried by a benchmark, besides the current one
+pub const BENCHMARK_EF_VALUES: [u32; 6] = [10, 20, 40, 80, 160, 320];
+
+/// Recall a benchmark recommendation must reach
+pub const TARGET_RECALL: f32 = 0.95;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum VectorTuningError {
+    #[error("Invalid vector index tuning: {0}")]
+    Invalid(String),
+
+    #[error("Not a vector index definition: {0}")]
+    NotVectorIndex(String),
+}
+
+pub type Result<T> = std::result::Result<T, VectorTuningError>;
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// How nearest neighbours are indexed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorIndexKind {
+    /// Approximate graph search; fast on large libraries
+    Hnsw,
+    /// Exact metric tree; slower to query, smaller to build
+    Mtree,
+}
+
+/// How each vector component is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorPrecision {
+    /// 64-bit floats, SurrealDB's default
+    F64,
+    /// 32-bit floats: half the memory, no measurable recall loss for
+    /// embeddings
+    F32,
+}
+
+impl VectorPrecision {
+    fn as_surql(self) -> &'static str {
+        match self {
+            Self::F64 => "F64",
+            Self::F32 => "F32",
+        }
+    }
+}
+
+/// Vector index settings applied to every vector table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VectorTuning {
+    pub kind: VectorIndexKind,
+    pub precision: VectorPrecision,
+    /// HNSW: neighbours kept per node; more raises recall and memory
+    pub m: u32,
+    /// HNSW: candidates considered while building; more raises recall and
+    /// build time
+    pub efc: u32,
+    /// HNSW: candidates considered per search; more raises recall and
+    /// latency
+    pub search_ef: u32,
+    /// M-tree: entries per node
+    pub mtree_capacity: u32,
+}
+
+impl Default for VectorTuning {
+    fn default() -> Self {
+        Self {
+            kind: VectorIndexKind::Hnsw,
+            precision: VectorPrecision::F64,
+            m: DEFAULT_HNSW_M,
+            efc: DEFAULT_HNSW_EFC,
+            search_ef: DEFAULT_SEARCH_EF,
+            mtree_capacity: DEFAULT_MTREE_CAPACITY,
+        }
+    }
+}
+
+impl VectorTuning {
+    /// The tuning, or why it can't be applied
+    pub fn validated(&self) -> Result<Self> {
+        if !(2..=128).contains(&self.m) {
+            return Err(VectorTuningError::Invalid("HNSW M must be between 2 and 128".to_string()));
+        }
+        if !(self.m..=2000).contains(&self.efc) {
+            return Err(VectorTuningError::Invalid(
+                "HNSW build EF must be at least M and at most 2000".to_string(),
+            ));
+        }
+        if !(1..=2000).contains(&self.search_ef) {
+            return Err(VectorTuningError::Invalid("HNSW search EF must be between 1 and 2000".to_string()));
+        }
+        if !(2..=1000).contains(&self.mtree_capacity) {
+            return Err(VectorTuningError::Invalid("M-tree capacity must be between 2 and 1000".to_string()));
+        }
+        Ok(self.clone())
+    }
+
+    /// Candidate list size for searches, or `None` when the index takes none
+    pub fn search_ef(&self) -> Option<u32> {
+        match self.kind {
+            VectorIndexKind::Hnsw => Some(self.search_ef),
+            VectorIndexKind::Mtree => None,
+        }
+    }
+
+    /// Whether applying this in place of `other` means rebuilding the indexes
+    pub fn needs_reindex(&self, other: &Self) -> bool {
+        self.kind != other.kind
+            || self.precision != other.precision
+            || match self.kind {
+                VectorIndexKind::Hnsw => self.m != other.m || self.efc != other.efc,
+                VectorIndexKind::Mtree => self.mtree_capacity != other.mtree_capacity,
+            }
+    }
+
+    /// A schema `DEFINE INDEX` statement for a vector index, rewritten with
+    /// this tuning. The field, dimension, and distance are kept.
+    pub fn index_statement(&self, statement: &str) -> Result<String> {
+        let words: Vec<&str> = statement.trim().trim_end_matches(';').split_whitespace().collect();
+        let not_vector = || VectorTuningError::NotVectorIndex(statement.to_string());
+        let kind_at = words.iter().position(|w| matches!(*w, "HNSW" | "MTREE")).ok_or_else(not_vector)?;
+        let value_after = |keyword: &str| {
+            words[kind_at..]
+                .windows(2)
+                .find(|pair| pair[0] == keyword)
+                .map(|pair| pair[1])
+        };
+        let dimension = value_after("DIMENSION")
+            .and_then(|d| d.parse::<u32>().ok())
+            .ok_or_else(not_vector)?;
+        let distance = value_after("DIST").unwrap_or("COSINE");
+
+        let head = words[..kind_at].join(" ");
+        let precision = self.precision.as_surql();
+        Ok(match self.kind {
+            VectorIndexKind::Hnsw => format!(
+                "{} HNSW DIMENSION {} DIST {} TYPE {} EFC {} M {}",
+                head, dimension, distance, precision, self.efc, self.m
+            ),
+            VectorIndexKind::Mtree => format!(
+                "{} MTREE DIMENSION {} DIST {} TYPE {} CAPACITY {}",
+                head, dimension, distance, precision, self.mtree_capacity
+            ),
+        })
+    }
+}
+
+// ============================================================================
+// Benchmarking
+// ============================================================================
+
+/// Recall and latency of searches with one candidate list size
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EfBenchmark {
+    /// Search candidate list size; `None` for the index's own default
+    pub search_ef: Option<u32>,
+    /// Share of the exact nearest neighbours found, averaged over queries
+    pub recall: f32,
+    pub mean_latency_ms: f64,
+    pub p95_latency_ms: f64,
+}
+
+/// Result of benchmarking vector search on the library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorBenchmarkReport {
+    pub table: String,
+    /// Embedded rows in the table
+    pub vectors: u64,
+    /// Queries run per setting
+    pub queries: usize,
+    /// Neighbours compared per query
+    pub k: usize,
+    pub tuning: VectorTuning,
+    /// Exact (brute-force) search, the baseline
+    pub exact_mean_latency_ms: f64,
+    /// Smallest setting first
+    pub results: Vec<EfBenchmark>,
+    /// Smallest candidate list reaching [`TARGET_RECALL`], if any did
+    pub recommended_search_ef: Option<u32>,
+}
+
+/// Share of `exact` that `approximate` found; 1.0 when `exact` is empty
+pub fn recall(exact: &[String], approximate: &[String]) -> f32 {
+    if exact.is_empty() {
+        return 1.0;
+    }
+    let found: HashSet<&String> = approximate.iter().collect();
+    exact.iter().filter(|id| found.contains(id)).count() as f32 / exact.len() as f32
+}
+
+/// Mean and 95th percentile of latencies, in milliseconds
+pub fn latency_summary(latencies_ms: &[f64]) -> (f64, f64) {
+    if latencies_ms.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mut sorted = latencies_ms.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+    let p95_at = ((sorted.len() as f64 * 0.95).ceil() as usize).clamp(1, sorted.len()) - 1;
+    (mean, sorted[p95_at])
+}
+
+/// The smallest candidate list size whose recall reaches [`TARGET_RECALL`]
+pub fn recommend_search_ef(results: &[EfBenchmark]) -> Option<u32> {
+    results
+        .iter()
+        .filter(|r| r.recall >= TARGET_RECALL)
+        .filter_map(|r| r.search_ef)
+        .min()
+}
+
+/// Candidate list sizes to benchmark: the standard ones and `current`,
+/// ascending
+pub fn benchmark_ef_values(current: Option<u32>) -> Vec<u32> {
+    let mut values: Vec<u32> = BENCHMARK_EF_VALUES.iter().copied().chain(current).collect();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA_INDEX: &str = "DEFINE INDEX IF NOT EXISTS chunk_embedding ON chunk FIELDS embedding HNSW DIMENSION 768 DIST COSINE EFC 150 M 12";
+
+    #[test]
+    fn test_index_statement_rewrites_vector_clause() {
+        let tuning = VectorTuning { precision: VectorPrecision::F32, m: 16, efc: 200, ..Default::default() };
+        assert_eq!(
+            tuning.index_statement(SCHEMA_INDEX).unwrap(),
+            "DEFINE INDEX IF NOT EXISTS chunk_embedding ON chunk FIELDS embedding HNSW DIMENSION 768 DIST COSINE TYPE F32 EFC 200 M 16"
+        );
+
+        let mtree = VectorTuning { kind: VectorIndexKind::Mtree, ..Default::default() };
+        let statement = mtree.index_statement(&format!("{};", SCHEMA_INDEX)).unwrap();
+        assert!(statement.ends_with("MTREE DIMENSION 768 DIST COSINE TYPE F64 CAPACITY 40"));
+        assert_eq!(mtree.search_ef(), None);
+
+        assert!(matches!(
+            tuning.index_statement("DEFINE INDEX IF NOT EXISTS chunk_page ON chunk FIELDS page_number"),
+            Err(VectorTuningError::NotVectorIndex(_))
+        ));
+    }
+
+    #[test]
+    fn test_validation_and_reindex_detection() {
+        let default = VectorTuning::default();
+        assert!(default.validated().is_ok());
+        assert!(VectorTuning { m: 1, ..Default::default() }.validated().is_err());
+        assert!(VectorTuning { efc: 4, ..Default::default() }.validated().is_err());
+        assert!(VectorTuning { search_ef: 0, ..Default::default() }.validated().is_err());
+
+        assert!(!VectorTuning { search_ef: 40, ..Default::default() }.needs_reindex(&default));
+        assert!(!VectorTuning { mtree_capacity: 80, ..Default::default() }.needs_reindex(&default));
+        assert!(VectorTuning { m: 16, ..Default::default() }.needs_reindex(&default));
+        assert!(VectorTuning { precision: VectorPrecision::F32, ..Default::default() }.needs_reindex(&default));
+    }
+
+    #[test]
+    fn test_benchmark_arithmetic() {
+        let ids = |s: &[&str]| s.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(recall(&ids(&["a", "b", "c", "d"]), &ids(&["a", "c", "x"])), 0.5);
+        assert_eq!(recall(&[], &ids(&["a"])), 1.0);
+
+        let (mean, p95) = latency_summary(&[1.0, 2.0, 3.0, 4.0, 10.0]);
+        assert_eq!(mean, 4.0);
+        assert_eq!(p95, 10.0);
+        assert_eq!(latency_summary(&[]), (0.0, 0.0));
+
+        assert_eq!(benchmark_ef_values(Some(100)), vec![10, 20, 40, 80, 100, 160, 320]);
+        assert_eq!(benchmark_ef_values(Some(40)).len(), BENCHMARK_EF_VALUES.len());
+
+        let result = |ef, recall| EfBenchmark { search_ef: Some(ef), recall, mean_latency_ms: 1.0, p95_latency_ms: 1.0 };
+        assert_eq!(recommend_search_ef(&[result(10, 0.7), result(40, 0.96), result(80, 0.99)]), Some(40));
+        assert_eq!(recommend_search_ef(&[result(10, 0.7)]), None);
+    }
+}
@@ -213,6 +213,9 @@ pub struct AnalyticsSummary {
     pub period_start: DateTime<Utc>,
     /// Period end
     pub period_end: DateTime<Utc>,
+    /// Volume, zero results, click-through, and latency over the period
+    #[serde(default)]
+    pub trends: SearchTrends,
 }
 
 impl From<DbSearchAnalyticsSummary> for AnalyticsSummary {
@@ -233,6 +236,7 @@ impl From<DbSearchAnalyticsSummary> for AnalyticsSummary {
             period_end: DateTime::parse_from_rfc3339(&db.period_end)
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now()),
+            trends: SearchTrends::default(),
         }
     }
 }
@@ -264,6 +268,225 @@ impl From<PopularQueryRecord> for PopularQuery {
     }
 }
 
+// ============================================================================
+// Time-Bucketed Aggregates
+// ============================================================================
+
+/// Upper bounds (ms) of the latency histogram buckets; slower searches go
+/// in one more, open-ended bucket
+pub const LATENCY_BUCKET_BOUNDS_MS: [u64; 7] = [25, 50, 100, 250, 500, 1000, 2500];
+
+/// Most time buckets in one summary; finer requests are widened to fit
+const MAX_TIME_BUCKETS: i64 = 500;
+
+/// Most zero-result queries listed as missing content
+const MAX_MISSING_QUERIES: usize = 20;
+
+/// Search latencies counted into fixed buckets
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Upper bound of each bucket but the last (ms)
+    pub bounds_ms: Vec<u64>,
+    /// Searches per bucket, one more than there are bounds
+    pub counts: Vec<u32>,
+    /// Median latency (ms)
+    pub p50_ms: u64,
+    /// 95th percentile latency (ms)
+    pub p95_ms: u64,
+}
+
+impl LatencyHistogram {
+    pub fn from_latencies(latencies: &[u64]) -> Self {
+        let mut counts = vec![0u32; LATENCY_BUCKET_BOUNDS_MS.len() + 1];
+        for &ms in latencies {
+            let bucket = LATENCY_BUCKET_BOUNDS_MS.partition_point(|&bound| bound < ms);
+            counts[bucket] += 1;
+        }
+        let mut sorted = latencies.to_vec();
+        sorted.sort_unstable();
+        Self {
+            bounds_ms: LATENCY_BUCKET_BOUNDS_MS.to_vec(),
+            counts,
+            p50_ms: percentile(&sorted, 0.50),
+            p95_ms: percentile(&sorted, 0.95),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values, 0 when empty
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Searches in one time bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsBucket {
+    /// Bucket start
+    pub start: DateTime<Utc>,
+    /// Searches run
+    pub searches: u32,
+    /// Searches that found nothing
+    pub zero_result_searches: u32,
+    /// Searches with a clicked result
+    pub clicked_searches: u32,
+    /// Clicked searches over searches
+    pub click_through_rate: f64,
+    /// Average latency (ms)
+    pub avg_latency_ms: f64,
+    /// 95th percentile latency (ms)
+    pub p95_latency_ms: u64,
+}
+
+/// A query that keeps finding nothing, likely content the library lacks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingContentQuery {
+    pub query: String,
+    /// Zero-result searches for it in the period
+    pub count: u32,
+    pub last_searched: DateTime<Utc>,
+}
+
+/// One search, as the aggregates see it
+#[derive(Debug, Clone)]
+pub struct SearchSample {
+    pub timestamp: DateTime<Utc>,
+    pub query: String,
+    pub result_count: usize,
+    pub clicked: bool,
+    pub latency_ms: u64,
+}
+
+impl From<&SearchRecord> for SearchSample {
+    fn from(record: &SearchRecord) -> Self {
+        Self {
+            timestamp: record.timestamp,
+            query: record.query.clone(),
+            result_count: record.result_count,
+            clicked: record.clicked,
+            latency_ms: record.execution_time_ms,
+        }
+    }
+}
+
+impl SearchSample {
+    /// Sample from a stored record, `None` if its timestamp doesn't parse
+    pub fn from_db_record(record: &SearchAnalyticsRecord) -> Option<Self> {
+        let timestamp = DateTime::parse_from_rfc3339(&record.created_at).ok()?.with_timezone(&Utc);
+        Some(Self {
+            timestamp,
+            query: record.query.clone(),
+            result_count: record.results_count.max(0) as usize,
+            clicked: record.selected_result_index.is_some(),
+            latency_ms: record.response_time_ms.max(0) as u64,
+        })
+    }
+}
+
+/// Search volume, zero results, click-through, and latency over time
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchTrends {
+    /// Width of each bucket (minutes)
+    pub bucket_minutes: i64,
+    /// Buckets covering the period in order, empty ones included
+    pub buckets: Vec<AnalyticsBucket>,
+    /// Latency over the whole period
+    pub latency_histogram: LatencyHistogram,
+    /// Zero-result queries, most searched first
+    pub missing_content: Vec<MissingContentQuery>,
+}
+
+/// Bucket width for a period when none is asked for: hourly up to two days,
+/// daily beyond
+pub fn default_bucket_minutes(hours: i64) -> i64 {
+    if hours <= 48 { 60 } else { 24 * 60 }
+}
+
+impl SearchTrends {
+    /// Aggregate the samples between `start` and `end`.
+    ///
+    /// Buckets align to multiples of their width since the epoch, so the
+    /// same hour is the same bucket from one call to the next.
+    pub fn from_samples(
+        samples: &[SearchSample],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        bucket_minutes: i64,
+    ) -> Self {
+        let span_minutes = (end - start).num_minutes().max(1);
+        let bucket_minutes = bucket_minutes
+            .max(1)
+            .max((span_minutes + MAX_TIME_BUCKETS - 1) / MAX_TIME_BUCKETS);
+        let width = bucket_minutes * 60;
+        let first = start.timestamp().div_euclid(width) * width;
+        let count = ((end.timestamp() - first) / width + 1).max(1) as usize;
+
+        let mut latencies: Vec<Vec<u64>> = vec![Vec::new(); count];
+        let mut buckets: Vec<AnalyticsBucket> = (0..count)
+            .map(|i| AnalyticsBucket {
+                start: DateTime::from_timestamp(first + i as i64 * width, 0).unwrap_or(start),
+                searches: 0,
+                zero_result_searches: 0,
+                clicked_searches: 0,
+                click_through_rate: 0.0,
+                avg_latency_ms: 0.0,
+                p95_latency_ms: 0,
+            })
+            .collect();
+        let mut missing: HashMap<String, MissingContentQuery> = HashMap::new();
+
+        for sample in samples.iter().filter(|s| s.timestamp >= start && s.timestamp <= end) {
+            let index = ((sample.timestamp.timestamp() - first) / width) as usize;
+            let Some(bucket) = buckets.get_mut(index) else { continue };
+            bucket.searches += 1;
+            if sample.clicked {
+                bucket.clicked_searches += 1;
+            }
+            latencies[index].push(sample.latency_ms);
+
+            if sample.result_count == 0 {
+                bucket.zero_result_searches += 1;
+                let normalized = sample.query.to_lowercase().trim().to_string();
+                let entry = missing.entry(normalized.clone()).or_insert(MissingContentQuery {
+                    query: normalized,
+                    count: 0,
+                    last_searched: sample.timestamp,
+                });
+                entry.count += 1;
+                entry.last_searched = entry.last_searched.max(sample.timestamp);
+            }
+        }
+
+        for (bucket, bucket_latencies) in buckets.iter_mut().zip(latencies.iter_mut()) {
+            if bucket.searches == 0 {
+                continue;
+            }
+            bucket.click_through_rate = bucket.clicked_searches as f64 / bucket.searches as f64;
+            bucket.avg_latency_ms =
+                bucket_latencies.iter().sum::<u64>() as f64 / bucket.searches as f64;
+            bucket_latencies.sort_unstable();
+            bucket.p95_latency_ms = percentile(bucket_latencies, 0.95);
+        }
+
+        let all_latencies: Vec<u64> = latencies.into_iter().flatten().collect();
+        let mut missing_content: Vec<MissingContentQuery> = missing.into_values().collect();
+        missing_content.sort_by(|a, b| {
+            b.count.cmp(&a.count).then_with(|| b.last_searched.cmp(&a.last_searched))
+        });
+        missing_content.truncate(MAX_MISSING_QUERIES);
+
+        Self {
+            bucket_minutes,
+            buckets,
+            latency_histogram: LatencyHistogram::from_latencies(&all_latencies),
+            missing_content,
+        }
+    }
+}
+
 // ============================================================================
 // Database-Backed Search Analytics
 // ============================================================================
@@ -310,9 +533,29 @@ impl DbSearchAnalytics {
 
     /// Get analytics summary for a time period
     pub async fn get_summary(&self, hours: i64) -> Result<AnalyticsSummary, String> {
-        self.db.get_search_analytics_summary(hours).await
+        self.get_summary_with_buckets(hours, None).await
+    }
+
+    /// Get analytics summary with trends in buckets of `bucket_minutes`
+    /// (default: hourly up to two days, daily beyond)
+    pub async fn get_summary_with_buckets(
+        &self,
+        hours: i64,
+        bucket_minutes: Option<i64>,
+    ) -> Result<AnalyticsSummary, String> {
+        let mut summary: AnalyticsSummary = self.db.get_search_analytics_summary(hours).await
             .map(|s| s.into())
-            .map_err(|e| format!("Failed to get analytics summary: {}", e))
+            .map_err(|e| format!("Failed to get analytics summary: {}", e))?;
+        let records = self.db.get_search_analytics(hours).await
+            .map_err(|e| format!("Failed to get search records: {}", e))?;
+        let samples: Vec<SearchSample> = records.iter().filter_map(SearchSample::from_db_record).collect();
+        summary.trends = SearchTrends::from_samples(
+            &samples,
+            summary.period_start,
+            summary.period_end,
+            bucket_minutes.unwrap_or_else(|| default_bucket_minutes(hours)),
+        );
+        Ok(summary)
     }
 
     /// Get popular queries with detailed stats
@@ -542,7 +785,14 @@ impl SearchAnalytics {
 
     /// Get summary for a time period
     pub fn get_summary(&self, hours: i64) -> AnalyticsSummary {
-        let cutoff = Utc::now() - Duration::hours(hours);
+        self.get_summary_with_buckets(hours, None)
+    }
+
+    /// Get summary with trends in buckets of `bucket_minutes` (default:
+    /// hourly up to two days, daily beyond)
+    pub fn get_summary_with_buckets(&self, hours: i64, bucket_minutes: Option<i64>) -> AnalyticsSummary {
+        let now = Utc::now();
+        let cutoff = now - Duration::hours(hours);
         let records = self.records.read().unwrap();
 
         let relevant: Vec<&SearchRecord> = records
@@ -598,6 +848,14 @@ impl SearchAnalytics {
             *by_search_type.entry(record.search_type.clone()).or_default() += 1;
         }
 
+        let samples: Vec<SearchSample> = relevant.iter().map(|r| SearchSample::from(*r)).collect();
+        let trends = SearchTrends::from_samples(
+            &samples,
+            cutoff,
+            now,
+            bucket_minutes.unwrap_or_else(|| default_bucket_minutes(hours)),
+        );
+
         AnalyticsSummary {
             total_searches,
             zero_result_searches,
@@ -609,7 +867,8 @@ impl SearchAnalytics {
             cache_stats: self.get_cache_stats(),
             by_search_type,
             period_start: cutoff,
-            period_end: Utc::now(),
+            period_end: now,
+            trends,
         }
     }
 
@@ -886,4 +1145,74 @@ mod tests {
         // CTR = 3/13 = ~0.23
         assert!(detailed[0].click_through_rate > 0.2);
     }
+
+    fn sample(minutes: i64, query: &str, result_count: usize, clicked: bool, latency_ms: u64) -> SearchSample {
+        let start = DateTime::parse_from_rfc3339("2026-03-01T10:00:00Z").unwrap().with_timezone(&Utc);
+        SearchSample {
+            timestamp: start + Duration::minutes(minutes),
+            query: query.to_string(),
+            result_count,
+            clicked,
+            latency_ms,
+        }
+    }
+
+    #[test]
+    fn test_trends_bucket_volume_and_click_through() {
+        let start = DateTime::parse_from_rfc3339("2026-03-01T10:00:00Z").unwrap().with_timezone(&Utc);
+        let samples = vec![
+            sample(5, "grapple", 4, true, 40),
+            sample(20, "grapple", 4, false, 60),
+            sample(70, "owlbear lair", 0, false, 120),
+            sample(95, "Owlbear Lair ", 0, false, 90),
+            sample(100, "flanking", 2, true, 30),
+            // Outside the period
+            sample(300, "late", 0, false, 10),
+        ];
+
+        let trends = SearchTrends::from_samples(&samples, start, start + Duration::hours(3), 60);
+
+        assert_eq!(trends.buckets.len(), 4);
+        assert_eq!(trends.buckets[0].searches, 2);
+        assert!((trends.buckets[0].click_through_rate - 0.5).abs() < 1e-9);
+        assert!((trends.buckets[0].avg_latency_ms - 50.0).abs() < 1e-9);
+        assert_eq!(trends.buckets[1].searches, 3);
+        assert_eq!(trends.buckets[1].zero_result_searches, 2);
+        assert_eq!(trends.buckets[2].searches, 0);
+        assert_eq!(trends.missing_content.len(), 1);
+        assert_eq!(trends.missing_content[0].query, "owlbear lair");
+        assert_eq!(trends.missing_content[0].count, 2);
+    }
+
+    #[test]
+    fn test_latency_histogram_and_bucket_limit() {
+        let histogram = LatencyHistogram::from_latencies(&[10, 25, 26, 80, 400, 3000]);
+        assert_eq!(histogram.counts.len(), LATENCY_BUCKET_BOUNDS_MS.len() + 1);
+        assert_eq!(histogram.counts[0], 2);
+        assert_eq!(histogram.counts[1], 1);
+        assert_eq!(histogram.counts[LATENCY_BUCKET_BOUNDS_MS.len()], 1);
+        assert_eq!(histogram.p50_ms, 26);
+        assert_eq!(histogram.p95_ms, 3000);
+        assert_eq!(LatencyHistogram::from_latencies(&[]).p95_ms, 0);
+
+        // A month in one-minute buckets is widened
+        let start = Utc::now() - Duration::days(30);
+        let trends = SearchTrends::from_samples(&[], start, Utc::now(), 1);
+        assert!(trends.buckets.len() <= MAX_TIME_BUCKETS as usize + 1);
+        assert!(trends.bucket_minutes > 1);
+    }
+
+    #[test]
+    fn test_summary_includes_trends() {
+        let analytics = SearchAnalytics::new();
+        analytics.record(make_record("ghoul paralysis", 0, false));
+        analytics.record(make_record("opportunity attack", 3, true));
+
+        let summary = analytics.get_summary_with_buckets(24, Some(30));
+        assert_eq!(summary.trends.bucket_minutes, 30);
+        let searches: u32 = summary.trends.buckets.iter().map(|b| b.searches).sum();
+        assert_eq!(searches, 2);
+        assert_eq!(summary.trends.missing_content[0].query, "ghoul paralysis");
+        assert_eq!(summary.trends.latency_histogram.p50_ms, 50);
+    }
 }
@@ -0,0 +1,277 @@
+//! Limited Abilities Module
+//!
+//! Stat block abilities that can't be used every turn: recharge abilities
+//! like a dragon's breath, which come back on a d6 roll at the start of the
+//! creature's turn, triggers limited to once per turn or round, and X/Day
+//! abilities. Limits are read from the ability as printed, such as
+//! "Fire Breath (Recharge 5–6)" or "Legendary Resistance (3/Day)".
+
+use rand::Rng;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use thiserror::Error;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum AbilityError {
+    #[error("{combatant} has no tracked ability named {ability}")]
+    UnknownAbility { combatant: String, ability: String },
+
+    #[error("{0} isn't available")]
+    Unavailable(String),
+
+    #[error("Can't read a usage limit from: {0}")]
+    InvalidLimit(String),
+}
+
+pub type Result<T> = std::result::Result<T, AbilityError>;
+
+// ============================================================================
+// Usage Limits
+// ============================================================================
+
+/// How often a limited ability can be used
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UsageLimit {
+    /// Comes back on a d6 roll of `min` or higher at the start of each turn
+    Recharge { min: u32 },
+    /// Comes back after a short or long rest
+    Rest,
+    PerDay { uses: u32 },
+    PerTurn { uses: u32 },
+    PerRound { uses: u32 },
+}
+
+fn limit_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)\brecharge\s+([1-6])(?:\s*[-–—]\s*6)?\b|\brecharges?\s+after\s+a\s+(?:short\s+or\s+)?long\s+rest|\b(\d+)\s*/\s*(day|turn|round)\b")
+            .expect("limit pattern is valid")
+    })
+}
+
+fn trigger_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)\bonce\s+(?:per\s+(turn|round)|on\s+each\s+of\s+its\s+turns)\b").expect("trigger pattern is valid")
+    })
+}
+
+impl UsageLimit {
+    /// Read a limit such as "Recharge 5–6", "3/Day", or "1/Turn"
+    pub fn parse(text: &str) -> Option<Self> {
+        let caps = limit_pattern().captures(text)?;
+        if let Some(min) = caps.get(1) {
+            return Some(Self::Recharge { min: min.as_str().parse().ok()? });
+        }
+        let Some(uses) = caps.get(2) else {
+            return Some(Self::Rest);
+        };
+        let uses = uses.as_str().parse().ok()?;
+        match caps[3].to_lowercase().as_str() {
+            "day" => Some(Self::PerDay { uses }),
+            "turn" => Some(Self::PerTurn { uses }),
+            _ => Some(Self::PerRound { uses }),
+        }
+    }
+
+    /// Read a trigger limit from a description, such as Sneak Attack's
+    /// "Once per turn, the rogue can..."
+    pub fn parse_trigger(description: &str) -> Option<Self> {
+        let caps = trigger_pattern().captures(description)?;
+        match caps.get(1).map(|m| m.as_str().to_lowercase()) {
+            Some(unit) if unit == "round" => Some(Self::PerRound { uses: 1 }),
+            _ => Some(Self::PerTurn { uses: 1 }),
+        }
+    }
+
+    pub fn max_uses(&self) -> u32 {
+        match self {
+            Self::Recharge { .. } | Self::Rest => 1,
+            Self::PerDay { uses } | Self::PerTurn { uses } | Self::PerRound { uses } => *uses,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Recharge { min: 6 } => "Recharge 6".to_string(),
+            Self::Recharge { min } => format!("Recharge {}–6", min),
+            Self::Rest => "Recharges after a Short or Long Rest".to_string(),
+            Self::PerDay { uses } => format!("{}/Day", uses),
+            Self::PerTurn { uses } => format!("{}/Turn", uses),
+            Self::PerRound { uses } => format!("{}/Round", uses),
+        }
+    }
+}
+
+// ============================================================================
+// Tracked Abilities
+// ============================================================================
+
+/// A limited ability and the uses it has left
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackedAbility {
+    pub name: String,
+    pub limit: UsageLimit,
+    pub uses_left: u32,
+    /// The latest recharge roll, while the ability is spent
+    #[serde(default)]
+    pub last_roll: Option<u32>,
+}
+
+impl TrackedAbility {
+    pub fn new(name: impl Into<String>, limit: UsageLimit) -> Self {
+        Self {
+            name: name.into(),
+            limit,
+            uses_left: limit.max_uses(),
+            last_roll: None,
+        }
+    }
+
+    /// Track a stat block feature with a printed limit, named without it
+    pub fn from_feature(name: &str, description: &str) -> Option<Self> {
+        if let Some(limit) = UsageLimit::parse(name) {
+            let base = name.split('(').next().unwrap_or(name).trim();
+            return Some(Self::new(if base.is_empty() { name } else { base }, limit));
+        }
+        UsageLimit::parse_trigger(description).map(|limit| Self::new(name.trim(), limit))
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.uses_left > 0
+    }
+
+    /// Spend a use, returning how many are left
+    pub fn use_once(&mut self) -> Result<u32> {
+        if !self.is_available() {
+            return Err(AbilityError::Unavailable(self.name.clone()));
+        }
+        self.uses_left -= 1;
+        Ok(self.uses_left)
+    }
+
+    /// Give back every use
+    pub fn restore(&mut self) {
+        self.uses_left = self.limit.max_uses();
+        self.last_roll = None;
+    }
+
+    /// Roll to recharge a spent recharge ability
+    pub fn roll_recharge<R: Rng>(&mut self, rng: &mut R) -> Option<u32> {
+        let UsageLimit::Recharge { min } = self.limit else {
+            return None;
+        };
+        if self.is_available() {
+            return None;
+        }
+        let roll = rng.gen_range(1..=6);
+        if roll >= min {
+            self.restore();
+        } else {
+            self.last_roll = Some(roll);
+        }
+        Some(roll)
+    }
+
+    /// What to call out at the start of the creature's turn
+    pub fn alert(&self) -> Option<String> {
+        match self.limit {
+            UsageLimit::Recharge { .. } if self.is_available() => Some(format!("{} available!", self.name)),
+            UsageLimit::PerTurn { .. } | UsageLimit::PerRound { .. } if self.is_available() => {
+                Some(format!("{} ready ({})", self.name, self.limit.label()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A recharge roll made at the start of a creature's turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RechargeRoll {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub ability: String,
+    pub roll: u32,
+    pub recharged: bool,
+}
+
+impl RechargeRoll {
+    /// Combat log line for the roll
+    pub fn describe(&self) -> String {
+        if self.recharged {
+            format!("{}'s {} recharges (rolled {})", self.combatant_name, self.ability, self.roll)
+        } else {
+            format!("{}'s {} doesn't recharge (rolled {})", self.combatant_name, self.ability, self.roll)
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_parsing_limits() {
+        assert_eq!(UsageLimit::parse("Fire Breath (Recharge 5–6)"), Some(UsageLimit::Recharge { min: 5 }));
+        assert_eq!(UsageLimit::parse("Lightning Breath (Recharge 6)"), Some(UsageLimit::Recharge { min: 6 }));
+        assert_eq!(UsageLimit::parse("Legendary Resistance (3/Day)"), Some(UsageLimit::PerDay { uses: 3 }));
+        assert_eq!(UsageLimit::parse("Parry (1/Round)"), Some(UsageLimit::PerRound { uses: 1 }));
+        assert_eq!(
+            UsageLimit::parse("Shapechanger (Recharges after a Short or Long Rest)"),
+            Some(UsageLimit::Rest)
+        );
+        assert_eq!(UsageLimit::parse("Multiattack"), None);
+        assert_eq!(
+            UsageLimit::parse_trigger("Once per turn, the assassin can deal an extra 4d6 damage"),
+            Some(UsageLimit::PerTurn { uses: 1 })
+        );
+        assert_eq!(UsageLimit::Recharge { min: 5 }.label(), "Recharge 5–6");
+    }
+
+    #[test]
+    fn test_features_become_tracked_abilities() {
+        let breath = TrackedAbility::from_feature("Fire Breath (Recharge 5-6)", "The dragon exhales fire").unwrap();
+        assert_eq!(breath.name, "Fire Breath");
+        assert_eq!(breath.alert().as_deref(), Some("Fire Breath available!"));
+
+        let sneak = TrackedAbility::from_feature("Sneak Attack", "Once on each of its turns, the spy can...").unwrap();
+        assert_eq!(sneak.limit, UsageLimit::PerTurn { uses: 1 });
+        assert!(TrackedAbility::from_feature("Bite", "Melee Weapon Attack").is_none());
+    }
+
+    #[test]
+    fn test_using_and_recharging() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut breath = TrackedAbility::new("Fire Breath", UsageLimit::Recharge { min: 5 });
+        assert_eq!(breath.roll_recharge(&mut rng), None);
+        assert_eq!(breath.use_once().unwrap(), 0);
+        assert!(matches!(breath.use_once(), Err(AbilityError::Unavailable(_))));
+        assert_eq!(breath.alert(), None);
+
+        let mut rolls = Vec::new();
+        while !breath.is_available() {
+            let roll = breath.roll_recharge(&mut rng).unwrap();
+            assert_eq!(breath.is_available(), roll >= 5);
+            rolls.push(roll);
+        }
+        assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+        assert_eq!(breath.last_roll, None);
+
+        let mut resistance = TrackedAbility::new("Legendary Resistance", UsageLimit::PerDay { uses: 3 });
+        resistance.use_once().unwrap();
+        assert_eq!(resistance.roll_recharge(&mut rng), None);
+        assert_eq!(resistance.uses_left, 2);
+    }
+}
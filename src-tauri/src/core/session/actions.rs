@@ -0,0 +1,158 @@
+//! Special Actions Module
+//!
+//! Tracks the actions that happen outside a combatant's own turn: legendary
+//! action pools that refill at the start of the creature's turn, lair actions
+//! on initiative count 20, and whether each combatant still has their
+//! reaction this round.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Initiative count lair actions happen on, losing initiative ties
+pub const LAIR_INITIATIVE: i32 = 20;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ActionError {
+    #[error("{0} has no legendary actions")]
+    NoLegendaryActions(String),
+
+    #[error("{name} has {remaining} legendary actions left, not enough for one costing {cost}")]
+    Exhausted { name: String, remaining: u32, cost: u32 },
+
+    #[error("{0} has already used their reaction this round")]
+    ReactionUsed(String),
+}
+
+// ============================================================================
+// Action Pools
+// ============================================================================
+
+/// A pool of actions spent between turns and refilled each round
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionPool {
+    pub max: u32,
+    pub remaining: u32,
+}
+
+impl ActionPool {
+    pub fn new(max: u32) -> Self {
+        Self { max, remaining: max }
+    }
+
+    /// Spend from the pool, returning what is left
+    pub fn spend(&mut self, cost: u32) -> Option<u32> {
+        self.remaining = self.remaining.checked_sub(cost)?;
+        Some(self.remaining)
+    }
+
+    pub fn reset(&mut self) {
+        self.remaining = self.max;
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// What is left after spending a legendary action or reaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionSpend {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub remaining: u32,
+    pub max: u32,
+    /// Set when nothing is left to spend until the pool refills
+    pub warning: Option<String>,
+}
+
+impl ActionSpend {
+    pub fn new(combatant_id: &str, combatant_name: &str, pool: ActionPool, what: &str) -> Self {
+        Self {
+            combatant_id: combatant_id.to_string(),
+            combatant_name: combatant_name.to_string(),
+            remaining: pool.remaining,
+            max: pool.max,
+            warning: pool
+                .is_exhausted()
+                .then(|| format!("{} has no {} left this round", combatant_name, what)),
+        }
+    }
+}
+
+// ============================================================================
+// Lair Actions
+// ============================================================================
+
+/// A creature whose lair acts on an initiative count of its own
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LairActions {
+    pub combatant_id: String,
+    #[serde(default = "default_lair_initiative")]
+    pub initiative: i32,
+    /// Round the lair last acted in
+    #[serde(default)]
+    pub last_round: Option<u32>,
+}
+
+fn default_lair_initiative() -> i32 {
+    LAIR_INITIATIVE
+}
+
+impl LairActions {
+    pub fn new(combatant_id: impl Into<String>) -> Self {
+        Self {
+            combatant_id: combatant_id.into(),
+            initiative: LAIR_INITIATIVE,
+            last_round: None,
+        }
+    }
+
+    /// Whether the lair acts before a turn at `initiative` in `round`.
+    /// The lair loses ties, so it goes once the order drops below its count.
+    pub fn triggers_before(&self, round: u32, initiative: i32) -> bool {
+        initiative < self.initiative && self.last_round.is_none_or(|r| r < round)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_pool() {
+        let mut pool = ActionPool::new(3);
+        assert_eq!(pool.spend(2), Some(1));
+        assert_eq!(pool.spend(2), None);
+        assert_eq!(pool.remaining, 1);
+        assert_eq!(pool.spend(1), Some(0));
+        assert!(pool.is_exhausted());
+        pool.reset();
+        assert_eq!(pool.remaining, 3);
+    }
+
+    #[test]
+    fn test_spend_warning() {
+        let pool = ActionPool { max: 3, remaining: 0 };
+        let spend = ActionSpend::new("d1", "Dragon", pool, "legendary actions");
+        assert_eq!(spend.warning.as_deref(), Some("Dragon has no legendary actions left this round"));
+        assert!(ActionSpend::new("d1", "Dragon", ActionPool::new(3), "legendary actions").warning.is_none());
+    }
+
+    #[test]
+    fn test_lair_triggers_once_per_round_losing_ties() {
+        let mut lair = LairActions::new("dragon");
+        assert!(!lair.triggers_before(1, 20));
+        assert!(lair.triggers_before(1, 19));
+        lair.last_round = Some(1);
+        assert!(!lair.triggers_before(1, 5));
+        assert!(lair.triggers_before(2, 5));
+    }
+}
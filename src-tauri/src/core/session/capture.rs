@@ -0,0 +1,261 @@
+//! Timeline Capture Module
+//!
+//! Builds timeline events for things that happen elsewhere in the app so the
+//! session timeline fills itself in: fights starting and ending, NPC
+//! replies, world events, quest status changes, and dice rolls. Each
+//! category can be switched off on its own.
+
+use serde::{Deserialize, Serialize};
+
+use super::combat_report::CombatReport;
+use super::player_view::GM_ONLY_TAG;
+use super::timeline::{EventSeverity, TimelineEvent, TimelineEventType};
+use crate::core::campaign::quests::{Quest, QuestStatus};
+use crate::core::campaign::world_state::{EventImpact, WorldEvent};
+
+/// Tag on every captured event, so they can be told from ones added by hand
+pub const AUTO_CAPTURE_TAG: &str = "auto";
+
+/// Longest NPC line quoted in a dialogue event, in characters
+const MAX_EXCERPT_CHARS: usize = 160;
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// Something that writes to the timeline on its own
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureCategory {
+    Combat,
+    NpcConversations,
+    WorldEvents,
+    Quests,
+    DiceRolls,
+}
+
+/// Which categories are captured; all are on by default
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CaptureSettings {
+    #[serde(default = "default_true")]
+    pub combat: bool,
+    #[serde(default = "default_true")]
+    pub npc_conversations: bool,
+    #[serde(default = "default_true")]
+    pub world_events: bool,
+    #[serde(default = "default_true")]
+    pub quests: bool,
+    #[serde(default = "default_true")]
+    pub dice_rolls: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for CaptureSettings {
+    fn default() -> Self {
+        Self {
+            combat: true,
+            npc_conversations: true,
+            world_events: true,
+            quests: true,
+            dice_rolls: true,
+        }
+    }
+}
+
+impl CaptureSettings {
+    pub fn is_enabled(&self, category: CaptureCategory) -> bool {
+        match category {
+            CaptureCategory::Combat => self.combat,
+            CaptureCategory::NpcConversations => self.npc_conversations,
+            CaptureCategory::WorldEvents => self.world_events,
+            CaptureCategory::Quests => self.quests,
+            CaptureCategory::DiceRolls => self.dice_rolls,
+        }
+    }
+
+    pub fn set(&mut self, category: CaptureCategory, enabled: bool) {
+        let flag = match category {
+            CaptureCategory::Combat => &mut self.combat,
+            CaptureCategory::NpcConversations => &mut self.npc_conversations,
+            CaptureCategory::WorldEvents => &mut self.world_events,
+            CaptureCategory::Quests => &mut self.quests,
+            CaptureCategory::DiceRolls => &mut self.dice_rolls,
+        };
+        *flag = enabled;
+    }
+}
+
+// ============================================================================
+// Event Builders
+// ============================================================================
+
+fn captured(event: TimelineEvent, category: &str) -> TimelineEvent {
+    event.with_tags([AUTO_CAPTURE_TAG, category])
+}
+
+fn excerpt(text: &str) -> String {
+    let text = text.trim();
+    if text.chars().count() <= MAX_EXCERPT_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(MAX_EXCERPT_CHARS).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// A finished fight, naming everyone who took part
+pub fn combat_ended_event(session_id: &str, report: &CombatReport) -> TimelineEvent {
+    let defeated: Vec<&str> = report.combatants.iter().filter(|c| c.defeated).map(|c| c.name.as_str()).collect();
+    let mut description = format!("Combat ended after {} rounds", report.rounds);
+    if !defeated.is_empty() {
+        description.push_str(&format!("; defeated: {}", defeated.join(", ")));
+    }
+    let event = TimelineEvent::new(session_id, TimelineEventType::CombatEnd, "Combat Concluded", description)
+        .with_severity(EventSeverity::Notable)
+        .with_meta("rounds", report.rounds)
+        .with_meta("combat_id", &report.combat_id)
+        .with_meta("actual_difficulty", &report.actual_difficulty);
+    let event = report.combatants.iter().fold(event, |event, c| {
+        let role = if c.defeated { "defeated" } else { "combatant" };
+        event.with_entity_role("combatant", &c.combatant_id, &c.name, role)
+    });
+    captured(event, "combat")
+}
+
+/// Something an NPC said in conversation
+pub fn npc_dialogue_event(session_id: &str, npc_id: &str, npc_name: &str, line: &str) -> TimelineEvent {
+    let event = TimelineEvent::new(
+        session_id,
+        TimelineEventType::NPCDialogue,
+        format!("{} speaks", npc_name),
+        format!("{}: \"{}\"", npc_name, excerpt(line)),
+    )
+    .with_entity_role("npc", npc_id, npc_name, "speaker");
+    captured(event, "npc")
+}
+
+/// A world event recorded during the session; secret ones are kept off
+/// the players' screen
+pub fn world_event_event(session_id: &str, world_event: &WorldEvent) -> TimelineEvent {
+    let severity = match world_event.impact {
+        EventImpact::Personal | EventImpact::Local => EventSeverity::Info,
+        EventImpact::Regional => EventSeverity::Notable,
+        EventImpact::National => EventSeverity::Important,
+        EventImpact::Global | EventImpact::Cosmic => EventSeverity::Critical,
+    };
+    let event = TimelineEvent::new(
+        session_id,
+        TimelineEventType::Custom("world_event".to_string()),
+        &world_event.title,
+        &world_event.description,
+    )
+    .with_severity(severity)
+    .with_entity("world_event", &world_event.id, &world_event.title)
+    .with_meta("event_type", &world_event.event_type)
+    .with_meta("impact", &world_event.impact)
+    .with_meta("in_game_date", &world_event.in_game_date);
+    let event = world_event
+        .npc_ids
+        .iter()
+        .fold(event, |event, id| event.with_entity_role("npc", id, id, "involved"));
+    let event = world_event
+        .location_ids
+        .iter()
+        .fold(event, |event, id| event.with_entity_role("location", id, id, "location"));
+    let event = if world_event.is_public { event } else { event.with_tags([GM_ONLY_TAG]) };
+    captured(event, "world")
+}
+
+/// A quest moving from one status to another
+pub fn quest_status_event(session_id: &str, quest: &Quest, previous: QuestStatus) -> TimelineEvent {
+    let severity = if quest.status.is_resolved() { EventSeverity::Important } else { EventSeverity::Notable };
+    let title = match quest.status {
+        QuestStatus::Available => format!("Quest available: {}", quest.title),
+        QuestStatus::Active => format!("Quest started: {}", quest.title),
+        QuestStatus::Completed => format!("Quest completed: {}", quest.title),
+        QuestStatus::Failed => format!("Quest failed: {}", quest.title),
+        QuestStatus::Abandoned => format!("Quest abandoned: {}", quest.title),
+    };
+    let mut event = TimelineEvent::new(
+        session_id,
+        TimelineEventType::Custom("quest_update".to_string()),
+        title,
+        format!("{} went from {} to {}", quest.title, previous.as_str(), quest.status.as_str()),
+    )
+    .with_severity(severity)
+    .with_entity_role("quest", &quest.id, &quest.title, "subject")
+    .with_meta("previous_status", previous.as_str())
+    .with_meta("status", quest.status.as_str());
+    if let Some(giver) = &quest.giver_npc_id {
+        event = event.with_entity_role("npc", giver, giver, "giver");
+    }
+    captured(event, "quest")
+}
+
+/// A dice roll; natural 20s and 1s on a d20 stand out
+pub fn dice_roll_event(
+    session_id: &str,
+    notation: &str,
+    total: i32,
+    context: Option<&str>,
+    critical: Option<bool>,
+) -> TimelineEvent {
+    let (title, severity) = match critical {
+        Some(true) => (format!("Natural 20 on {}", notation), EventSeverity::Notable),
+        Some(false) => (format!("Natural 1 on {}", notation), EventSeverity::Notable),
+        None => (format!("Rolled {}", notation), EventSeverity::Trace),
+    };
+    let description = match context {
+        Some(context) => format!("{}: {} = {}", context, notation, total),
+        None => format!("{} = {}", notation, total),
+    };
+    let event = TimelineEvent::new(session_id, TimelineEventType::PlayerRoll, title, description)
+        .with_severity(severity)
+        .with_meta("notation", notation)
+        .with_meta("total", total);
+    captured(event, "dice")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_toggles() {
+        let mut settings = CaptureSettings::default();
+        assert!(settings.is_enabled(CaptureCategory::DiceRolls));
+        settings.set(CaptureCategory::DiceRolls, false);
+        assert!(!settings.is_enabled(CaptureCategory::DiceRolls));
+        assert!(settings.is_enabled(CaptureCategory::Quests));
+
+        let parsed: CaptureSettings = serde_json::from_str(r#"{"npc_conversations": false}"#).unwrap();
+        assert!(!parsed.npc_conversations);
+        assert!(parsed.combat && parsed.world_events);
+    }
+
+    #[test]
+    fn test_built_events() {
+        let line = "a".repeat(200);
+        let dialogue = npc_dialogue_event("s1", "npc-1", "Mira", &line);
+        assert_eq!(dialogue.entity_refs[0].entity_id, "npc-1");
+        assert!(dialogue.description.ends_with("…\""));
+        assert_eq!(dialogue.tags, vec![AUTO_CAPTURE_TAG, "npc"]);
+
+        let mut quest = Quest::new("c1", "Find the Lost Crown");
+        quest.status = QuestStatus::Completed;
+        let update = quest_status_event("s1", &quest, QuestStatus::Active);
+        assert_eq!(update.title, "Quest completed: Find the Lost Crown");
+        assert_eq!(update.severity, EventSeverity::Important);
+        assert_eq!(update.description, "Find the Lost Crown went from active to completed");
+
+        let crit = dice_roll_event("s1", "1d20", 20, Some("Attack"), Some(true));
+        assert_eq!((crit.title.as_str(), crit.severity), ("Natural 20 on 1d20", EventSeverity::Notable));
+        assert_eq!(dice_roll_event("s1", "2d6", 7, None, None).description, "2d6 = 7");
+    }
+}
@@ -0,0 +1,360 @@
+//! Session Clock Module
+//!
+//! Keeps real time at the table. Each session gets a clock when it starts;
+//! time spent paused doesn't count. The clock reminds the GM to call a break
+//! after a stretch of play and, when a session plan is attached, nudges them
+//! when a scene runs well past its estimate.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::plan_types::SessionPlan;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// When the clock speaks up
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClockConfig {
+    #[serde(default = "default_true")]
+    pub break_reminders: bool,
+    /// Minutes of play between break reminders
+    #[serde(default = "default_break_interval")]
+    pub break_interval_minutes: u32,
+    #[serde(default)]
+    pub pacing_nudges: bool,
+    /// Minutes a scene can run past its estimate before a nudge
+    #[serde(default = "default_nudge_threshold")]
+    pub nudge_threshold_minutes: u32,
+    /// Also show notices as system notifications
+    #[serde(default = "default_true")]
+    pub system_notifications: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_break_interval() -> u32 {
+    90
+}
+
+fn default_nudge_threshold() -> u32 {
+    15
+}
+
+impl Default for ClockConfig {
+    fn default() -> Self {
+        Self {
+            break_reminders: true,
+            break_interval_minutes: default_break_interval(),
+            pacing_nudges: false,
+            nudge_threshold_minutes: default_nudge_threshold(),
+            system_notifications: true,
+        }
+    }
+}
+
+/// A planned scene and how long it was expected to take
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SceneEstimate {
+    pub name: String,
+    pub estimated_minutes: u32,
+}
+
+impl SceneEstimate {
+    /// The plan's pacing beats, in order
+    pub fn from_plan(plan: &SessionPlan) -> Vec<Self> {
+        let mut beats: Vec<_> = plan.pacing_beats.iter().collect();
+        beats.sort_by_key(|b| b.order);
+        beats
+            .into_iter()
+            .map(|b| Self { name: b.name.clone(), estimated_minutes: b.estimated_duration })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Notices
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockNoticeKind {
+    Break,
+    Pacing,
+}
+
+/// A reminder or nudge for the GM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockNotice {
+    pub session_id: String,
+    pub kind: ClockNoticeKind,
+    pub title: String,
+    pub message: String,
+    /// Show it as a system notification too
+    pub notify: bool,
+}
+
+/// Format minutes as "1h 35m" or "40m"
+pub fn format_minutes(minutes: i64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+// ============================================================================
+// Clock
+// ============================================================================
+
+/// Where a session's clock stands
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockStatus {
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    /// Minutes played, not counting pauses
+    pub elapsed_minutes: i64,
+    pub paused: bool,
+    /// Minutes until the next break reminder, when reminders are on
+    pub next_break_in_minutes: Option<i64>,
+    pub current_scene: Option<String>,
+    /// Minutes the plan expected to have passed by the end of this scene
+    pub planned_minutes: Option<i64>,
+    /// Minutes over (positive) or under (negative) the plan so far
+    pub drift_minutes: Option<i64>,
+    pub config: ClockConfig,
+}
+
+/// Real time played in one session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionClock {
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Seconds spent paused, not counting a pause in progress
+    pub paused_seconds: i64,
+    pub config: ClockConfig,
+    pub scenes: Vec<SceneEstimate>,
+    pub scene_index: usize,
+    /// Elapsed seconds at which the next break reminder is due
+    next_break_at: i64,
+    /// Scene already nudged about, so each overrun is raised once
+    nudged_scene: Option<usize>,
+}
+
+impl SessionClock {
+    pub fn new(session_id: impl Into<String>, started_at: DateTime<Utc>) -> Self {
+        let config = ClockConfig::default();
+        Self {
+            session_id: session_id.into(),
+            started_at,
+            paused_at: None,
+            paused_seconds: 0,
+            next_break_at: i64::from(config.break_interval_minutes) * 60,
+            config,
+            scenes: Vec::new(),
+            scene_index: 0,
+            nudged_scene: None,
+        }
+    }
+
+    /// Seconds played up to `now`, not counting pauses
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let until = self.paused_at.unwrap_or(now);
+        ((until - self.started_at).num_seconds() - self.paused_seconds).max(0)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(now);
+        }
+    }
+
+    /// Resume after a pause, which counts as a break
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_seconds += (now - paused_at).num_seconds().max(0);
+            self.next_break_at = self.elapsed_seconds(now) + self.break_interval_seconds();
+        }
+    }
+
+    pub fn configure(&mut self, config: ClockConfig, now: DateTime<Utc>) {
+        self.config = config;
+        self.next_break_at = self.elapsed_seconds(now) + self.break_interval_seconds();
+    }
+
+    /// Follow a plan's scenes from the first one
+    pub fn set_scenes(&mut self, scenes: Vec<SceneEstimate>) {
+        self.scenes = scenes;
+        self.scene_index = 0;
+        self.nudged_scene = None;
+    }
+
+    /// Mark the table as having reached a scene
+    pub fn set_scene(&mut self, index: usize) {
+        self.scene_index = index.min(self.scenes.len().saturating_sub(1));
+    }
+
+    fn break_interval_seconds(&self) -> i64 {
+        i64::from(self.config.break_interval_minutes.max(1)) * 60
+    }
+
+    /// Minutes planned up to the end of the current scene
+    fn planned_minutes(&self) -> Option<i64> {
+        if self.scenes.is_empty() {
+            return None;
+        }
+        Some(self.scenes.iter().take(self.scene_index + 1).map(|s| i64::from(s.estimated_minutes)).sum())
+    }
+
+    pub fn status(&self, now: DateTime<Utc>) -> ClockStatus {
+        let elapsed = self.elapsed_seconds(now);
+        let planned_minutes = self.planned_minutes();
+        ClockStatus {
+            session_id: self.session_id.clone(),
+            started_at: self.started_at,
+            elapsed_minutes: elapsed / 60,
+            paused: self.is_paused(),
+            next_break_in_minutes: self
+                .config
+                .break_reminders
+                .then(|| ((self.next_break_at - elapsed).max(0) + 59) / 60),
+            current_scene: self.scenes.get(self.scene_index).map(|s| s.name.clone()),
+            planned_minutes,
+            drift_minutes: planned_minutes.map(|planned| elapsed / 60 - planned),
+            config: self.config.clone(),
+        }
+    }
+
+    /// Reminders and nudges that have come due, each raised once
+    pub fn check(&mut self, now: DateTime<Utc>) -> Vec<ClockNotice> {
+        let mut notices = Vec::new();
+        if self.is_paused() {
+            return notices;
+        }
+        let elapsed = self.elapsed_seconds(now);
+
+        if self.config.break_reminders && elapsed >= self.next_break_at {
+            notices.push(self.notice(
+                ClockNoticeKind::Break,
+                "Time for a break?",
+                format!("You've been playing for {}.", format_minutes(elapsed / 60)),
+            ));
+            self.next_break_at = elapsed + self.break_interval_seconds();
+        }
+
+        if let (true, Some(planned)) = (self.config.pacing_nudges, self.planned_minutes()) {
+            let over = elapsed / 60 - planned;
+            if over >= i64::from(self.config.nudge_threshold_minutes) && self.nudged_scene != Some(self.scene_index) {
+                let scene = self.scenes[self.scene_index].name.clone();
+                let remaining = self.scenes.len() - self.scene_index - 1;
+                let message = match remaining {
+                    0 => format!("\"{}\" is running {} over the plan.", scene, format_minutes(over)),
+                    n => format!(
+                        "\"{}\" is running {} over the plan, with {} scene{} to go.",
+                        scene,
+                        format_minutes(over),
+                        n,
+                        if n == 1 { "" } else { "s" }
+                    ),
+                };
+                notices.push(self.notice(ClockNoticeKind::Pacing, "Behind schedule", message));
+                self.nudged_scene = Some(self.scene_index);
+            }
+        }
+
+        notices
+    }
+
+    fn notice(&self, kind: ClockNoticeKind, title: &str, message: String) -> ClockNotice {
+        ClockNotice {
+            session_id: self.session_id.clone(),
+            kind,
+            title: title.to_string(),
+            message,
+            notify: self.config.system_notifications,
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn at(start: DateTime<Utc>, minutes: i64) -> DateTime<Utc> {
+        start + Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn test_pauses_dont_count() {
+        let start = Utc::now();
+        let mut clock = SessionClock::new("s1", start);
+        clock.pause(at(start, 30));
+        assert_eq!(clock.elapsed_seconds(at(start, 50)), 30 * 60);
+        clock.resume(at(start, 45));
+        let status = clock.status(at(start, 60));
+        assert_eq!(status.elapsed_minutes, 45);
+        assert!(!status.paused);
+        assert_eq!(format_minutes(95), "1h 35m");
+        assert_eq!(format_minutes(40), "40m");
+    }
+
+    #[test]
+    fn test_break_reminders() {
+        let start = Utc::now();
+        let mut clock = SessionClock::new("s1", start);
+        clock.configure(ClockConfig { break_interval_minutes: 60, ..Default::default() }, start);
+        assert!(clock.check(at(start, 59)).is_empty());
+
+        let notices = clock.check(at(start, 61));
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].kind, ClockNoticeKind::Break);
+        assert_eq!(notices[0].message, "You've been playing for 1h 1m.");
+        assert!(clock.check(at(start, 90)).is_empty());
+        assert_eq!(clock.check(at(start, 121)).len(), 1);
+
+        // A pause is a break, so the next reminder is a full interval later
+        clock.pause(at(start, 130));
+        assert!(clock.check(at(start, 200)).is_empty());
+        clock.resume(at(start, 140));
+        assert!(clock.check(at(start, 190)).is_empty());
+        assert_eq!(clock.check(at(start, 201)).len(), 1);
+    }
+
+    #[test]
+    fn test_pacing_nudges() {
+        let start = Utc::now();
+        let mut clock = SessionClock::new("s1", start);
+        clock.configure(ClockConfig { break_reminders: false, pacing_nudges: true, ..Default::default() }, start);
+        clock.set_scenes(vec![
+            SceneEstimate { name: "Opening Hook".to_string(), estimated_minutes: 20 },
+            SceneEstimate { name: "Ambush".to_string(), estimated_minutes: 40 },
+            SceneEstimate { name: "Finale".to_string(), estimated_minutes: 60 },
+        ]);
+        assert!(clock.check(at(start, 30)).is_empty());
+
+        let notices = clock.check(at(start, 36));
+        assert_eq!(notices[0].kind, ClockNoticeKind::Pacing);
+        assert_eq!(notices[0].message, "\"Opening Hook\" is running 16m over the plan, with 2 scenes to go.");
+        assert!(clock.check(at(start, 50)).is_empty());
+
+        clock.set_scene(1);
+        let status = clock.status(at(start, 50));
+        assert_eq!((status.planned_minutes, status.drift_minutes), (Some(60), Some(-10)));
+        assert_eq!(status.current_scene.as_deref(), Some("Ambush"));
+        assert!(clock.check(at(start, 70)).is_empty());
+        assert_eq!(clock.check(at(start, 75)).len(), 1);
+    }
+}
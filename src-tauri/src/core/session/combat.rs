@@ -5,10 +5,28 @@
 //! HP tracking, and combat event logging.
 
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::conditions::ConditionTracker;
+use super::abilities::{AbilityError, RechargeRoll, TrackedAbility, UsageLimit};
+use super::actions::{ActionError, ActionPool, ActionSpend, LairActions};
+use super::combat_report::CombatTally;
+use super::damage::{Damage, DamageBreakdown, DamageDefenses};
+use super::economy::{
+    Activity, CombatMode, EconomyError, TurnActions, TurnSpend, MAX_HERO_POINTS, STARTING_HERO_POINTS,
+};
+use super::conditions::{AdvancedCondition, ConditionDuration, ConditionTemplates, ConditionTracker};
+use super::death::{DeathRules, DeathTrack, DeathUpdate, LifeState};
+use super::groups::{GroupDamageResult, GroupError, GroupTargets, MinionGroup};
+use super::initiative::{InitiativeRoll, InitiativeRules};
+use super::morale::{MoraleCheck, MoraleError, MoraleGroup, MoraleRules, MoraleTrigger};
+use super::plan_types::EncounterDifficulty;
+use super::positioning::{check_range, AttackRange, Battlefield, Movement, Position, PositionError, RangeCheck};
+use super::stat_blocks::CombatantStats;
+use super::turn_order::{initiative_at, triggered_by, ReadiedAction, ReadiedReminder, TurnOrderError};
 
 // ============================================================================
 // Combat Types
@@ -44,6 +62,9 @@ pub enum CombatEventType {
     Reaction,
     Death,
     Stabilized,
+    TurnChange,
+    Undo,
+    Redo,
     Other,
 }
 
@@ -78,8 +99,49 @@ pub struct Combatant {
     /// Condition immunities (e.g., "Frightened", "Poisoned")
     #[serde(default)]
     pub condition_immunities: Vec<String>,
+    /// Damage resistances, immunities, and vulnerabilities
+    #[serde(default)]
+    pub damage_defenses: DamageDefenses,
     pub is_active: bool,
     pub notes: String,
+    /// Hidden roll-off used when tie-breakers leave initiative tied
+    #[serde(default)]
+    pub tie_roll: u32,
+    /// Loses their turn in the first round
+    #[serde(default)]
+    pub surprised: bool,
+    /// Death saves, dying value, and whether the combatant is dead
+    #[serde(default)]
+    pub death: DeathTrack,
+    /// Legendary actions taken at the end of other combatants' turns,
+    /// refilled at the start of this combatant's turn
+    #[serde(default)]
+    pub legendary_actions: Option<ActionPool>,
+    /// Reaction spent since the start of their last turn
+    #[serde(default)]
+    pub reaction_used: bool,
+    /// Actions left this turn under the three-action economy
+    #[serde(default)]
+    pub turn_actions: Option<TurnActions>,
+    /// Recharge, per-turn, per-round, and X/Day abilities
+    #[serde(default)]
+    pub abilities: Vec<TrackedAbility>,
+    /// Where the combatant stands, when the combat tracks positions
+    #[serde(default)]
+    pub position: Option<Position>,
+    /// Saves, attacks, and traits from the stat block the combatant came from
+    #[serde(default)]
+    pub stats: Option<CombatantStats>,
+    /// Members, when this entry is a group of identical creatures whose
+    /// hit points add up to the combatant's
+    #[serde(default)]
+    pub group: Option<MinionGroup>,
+    /// Stepped out of the order until they come back in later
+    #[serde(default)]
+    pub delaying: bool,
+    /// An action held for a trigger, until the start of their next turn
+    #[serde(default)]
+    pub readied: Option<ReadiedAction>,
 }
 
 impl Combatant {
@@ -97,8 +159,21 @@ impl Combatant {
             armor_class: None,
             condition_tracker: ConditionTracker::new(),
             condition_immunities: vec![],
+            damage_defenses: DamageDefenses::default(),
             is_active: true,
             notes: String::new(),
+            tie_roll: 0,
+            surprised: false,
+            death: DeathTrack::default(),
+            legendary_actions: None,
+            reaction_used: false,
+            turn_actions: None,
+            abilities: vec![],
+            position: None,
+            stats: None,
+            group: None,
+            delaying: false,
+            readied: None,
         }
     }
 
@@ -139,7 +214,10 @@ impl Combatant {
     /// Cannot exceed max HP
     /// Returns the new current HP value
     pub fn heal(&mut self, amount: i32) -> i32 {
-        if let (Some(current), Some(max)) = (self.current_hp, self.max_hp) {
+        if let Some(group) = &mut self.group {
+            group.heal(amount);
+            self.current_hp = Some(group.total_hp());
+        } else if let (Some(current), Some(max)) = (self.current_hp, self.max_hp) {
             self.current_hp = Some((current + amount).min(max));
         }
         self.current_hp.unwrap_or(0)
@@ -152,6 +230,80 @@ impl Combatant {
         self.temp_hp = Some(current_temp.max(amount));
     }
 
+    /// Spend legendary actions, warning when the pool runs dry
+    pub fn spend_legendary_action(&mut self, cost: u32) -> Result<ActionSpend, ActionError> {
+        let pool = self
+            .legendary_actions
+            .as_mut()
+            .ok_or_else(|| ActionError::NoLegendaryActions(self.name.clone()))?;
+        if pool.spend(cost).is_none() {
+            return Err(ActionError::Exhausted { name: self.name.clone(), remaining: pool.remaining, cost });
+        }
+        Ok(ActionSpend::new(&self.id, &self.name, *pool, "legendary actions"))
+    }
+
+    /// Use this round's reaction
+    pub fn use_reaction(&mut self) -> Result<ActionSpend, ActionError> {
+        if self.reaction_used {
+            return Err(ActionError::ReactionUsed(self.name.clone()));
+        }
+        self.reaction_used = true;
+        Ok(ActionSpend::new(&self.id, &self.name, ActionPool { max: 1, remaining: 0 }, "reaction"))
+    }
+
+    /// Spend actions this turn under the three-action economy
+    pub fn spend_actions(&mut self, activity: &Activity) -> Result<TurnSpend, EconomyError> {
+        let turn = self.turn_actions.as_mut().ok_or(EconomyError::NotThreeAction)?;
+        TurnSpend::spend(&self.id, &self.name, turn, activity)
+    }
+
+    /// Refill legendary actions, the reaction, and three-action turn actions,
+    /// as at the start of their turn
+    pub fn refresh_actions(&mut self) {
+        if let Some(pool) = self.legendary_actions.as_mut() {
+            pool.reset();
+        }
+        if let Some(turn) = self.turn_actions.as_mut() {
+            turn.reset();
+        }
+        self.reaction_used = false;
+    }
+
+    /// Roll recharge for spent recharge abilities and bring back per-turn
+    /// triggers, as at the start of their turn
+    pub fn start_turn_abilities<R: Rng>(&mut self, rng: &mut R) -> Vec<RechargeRoll> {
+        let mut rolls = Vec::new();
+        for ability in &mut self.abilities {
+            if matches!(ability.limit, UsageLimit::PerTurn { .. }) {
+                ability.restore();
+            }
+            if let Some(roll) = ability.roll_recharge(rng) {
+                rolls.push(RechargeRoll {
+                    combatant_id: self.id.clone(),
+                    combatant_name: self.name.clone(),
+                    ability: ability.name.clone(),
+                    roll,
+                    recharged: ability.is_available(),
+                });
+            }
+        }
+        rolls
+    }
+
+    /// Limited abilities worth calling out on their turn, such as
+    /// "Fire Breath available!"
+    pub fn ability_alerts(&self) -> Vec<String> {
+        self.abilities.iter().filter_map(TrackedAbility::alert).collect()
+    }
+
+    fn tracked_ability_mut(&mut self, ability: &str) -> Result<&mut TrackedAbility, AbilityError> {
+        let combatant = self.name.clone();
+        self.abilities
+            .iter_mut()
+            .find(|a| a.name.eq_ignore_ascii_case(ability))
+            .ok_or_else(|| AbilityError::UnknownAbility { combatant, ability: ability.to_string() })
+    }
+
     /// Check if this combatant is immune to a condition
     pub fn is_immune_to(&self, condition_name: &str) -> bool {
         self.condition_immunities
@@ -187,14 +339,118 @@ pub struct CombatState {
     pub started_at: DateTime<Utc>,
     pub status: CombatStatus,
     pub events: Vec<CombatEvent>,
+    #[serde(default)]
+    pub initiative_rules: InitiativeRules,
+    /// In-game seconds a round lasts, for minute and hour durations
+    #[serde(default = "default_seconds_per_round")]
+    pub seconds_per_round: u32,
+    #[serde(default)]
+    pub death_rules: DeathRules,
+    #[serde(default)]
+    pub lair_actions: Option<LairActions>,
+    #[serde(default)]
+    pub mode: CombatMode,
+    /// Hero points by player name, carried between fights in a session
+    #[serde(default)]
+    pub hero_points: HashMap<String, u32>,
+    /// Zones or a grid, when the combat tracks positions
+    #[serde(default)]
+    pub battlefield: Option<Battlefield>,
+    /// Running totals for the combat report
+    #[serde(default)]
+    pub tally: CombatTally,
+    /// How hard the encounter was rated before the fight
+    #[serde(default)]
+    pub estimated_difficulty: Option<EncounterDifficulty>,
+    #[serde(default)]
+    pub morale_rules: MoraleRules,
+    /// Enemy groups whose morale is tracked
+    #[serde(default)]
+    pub morale_groups: Vec<MoraleGroup>,
+}
+
+fn default_seconds_per_round() -> u32 {
+    6
+}
+
+/// Why a condition ended on its own
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiryReason {
+    /// Its duration ran out
+    Duration,
+    /// It lasted until the end of its source's turn
+    SourceTurnEnded,
+    /// The concentration sustaining it ended
+    ConcentrationEnded,
+    /// It was the concentration itself, broken by damage or incapacitation
+    ConcentrationBroken,
+}
+
+/// A condition that ended without being removed by hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionExpiry {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub condition_id: String,
+    pub condition_name: String,
+    pub reason: ExpiryReason,
+    /// The condition was a concentration that sustained other effects
+    pub concentration: bool,
+}
+
+/// Something to call out about a combatant's condition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionReminder {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub condition_id: String,
+    pub condition_name: String,
+    pub message: String,
+}
+
+/// Hit points after damage or healing, with any change on the death track
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthUpdate {
+    pub hp: i32,
+    pub death: Option<DeathUpdate>,
 }
 
-/// Result of advancing a turn, containing the new current combatant
-/// and any conditions that expired during the transition
+/// Result of advancing a turn, containing the new current combatant,
+/// any conditions that expired during the transition, and reminders for
+/// the new combatant's conditions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TurnResult {
     pub current_combatant: Option<Combatant>,
     pub new_round: bool,
-    pub expired_conditions: Vec<(String, String)>, // (combatant_name, condition_name)
+    pub expired_conditions: Vec<ConditionExpiry>,
+    pub reminders: Vec<ConditionReminder>,
+    /// The lair owner's ID when lair actions happen before this turn
+    pub lair_action: Option<String>,
+    /// Recharge rolls the new combatant made for spent abilities
+    #[serde(default)]
+    pub recharges: Vec<RechargeRoll>,
+    /// Readied actions whose trigger is the new combatant's turn
+    #[serde(default)]
+    pub readied: Vec<ReadiedReminder>,
+}
+
+/// The combatant whose turn it is, with the limited abilities they have
+/// ready, such as "Fire Breath available!"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentCombatant {
+    #[serde(flatten)]
+    pub combatant: Combatant,
+    pub ability_alerts: Vec<String>,
+}
+
+impl From<Combatant> for CurrentCombatant {
+    fn from(combatant: Combatant) -> Self {
+        Self {
+            ability_alerts: combatant.ability_alerts(),
+            combatant,
+        }
+    }
 }
 
 impl CombatState {
@@ -208,23 +464,101 @@ impl CombatState {
             started_at: Utc::now(),
             status: CombatStatus::Active,
             events: vec![],
+            initiative_rules: InitiativeRules::default(),
+            seconds_per_round: default_seconds_per_round(),
+            death_rules: DeathRules::default(),
+            lair_actions: None,
+            mode: CombatMode::default(),
+            hero_points: HashMap::new(),
+            battlefield: None,
+            tally: CombatTally::default(),
+            estimated_difficulty: None,
+            morale_rules: MoraleRules::default(),
+            morale_groups: vec![],
         }
     }
 
     /// Sort combatants by initiative (highest first)
-    /// Uses initiative modifier as tiebreaker
+    /// Ties are broken by the combat's initiative rules
     pub fn sort_initiative(&mut self) {
-        self.combatants.sort_by(|a, b| {
-            b.initiative
-                .cmp(&a.initiative)
-                .then_with(|| b.initiative_modifier.cmp(&a.initiative_modifier))
-        });
+        let rules = &self.initiative_rules;
+        self.combatants.sort_by(|a, b| rules.compare(a, b));
     }
 
-    /// Add a combatant and re-sort initiative
-    pub fn add_combatant(&mut self, combatant: Combatant) {
-        self.combatants.push(combatant);
+    /// Re-sort while keeping the turn with the current combatant
+    fn resort_keeping_turn(&mut self) {
+        let current_id = self.current_combatant().map(|c| c.id.clone());
         self.sort_initiative();
+        if let Some(pos) = current_id.and_then(|id| self.combatants.iter().position(|c| c.id == id)) {
+            self.current_turn = pos;
+        }
+    }
+
+    /// Replace the initiative rules and re-sort
+    pub fn set_initiative_rules(&mut self, rules: InitiativeRules) {
+        self.initiative_rules = rules;
+        self.resort_keeping_turn();
+    }
+
+    /// Roll initiative for one combatant with the combat's rules
+    pub fn roll_initiative<R: Rng>(&mut self, combatant_id: &str, rng: &mut R) -> Option<InitiativeRoll> {
+        let combatant = self.combatants.iter_mut().find(|c| c.id == combatant_id)?;
+        let roll = self.initiative_rules.roll(combatant.initiative_modifier, rng);
+        roll.apply(combatant);
+        self.resort_keeping_turn();
+        Some(roll)
+    }
+
+    /// Re-roll initiative for every combatant. The turn order restarts from
+    /// the top, so this is meant for between rounds.
+    pub fn reroll_initiative<R: Rng>(&mut self, rng: &mut R) -> Vec<(String, InitiativeRoll)> {
+        let mut rolls = Vec::with_capacity(self.combatants.len());
+        for combatant in &mut self.combatants {
+            let roll = self.initiative_rules.roll(combatant.initiative_modifier, rng);
+            roll.apply(combatant);
+            rolls.push((combatant.id.clone(), roll));
+        }
+        self.sort_initiative();
+        self.current_turn = self.first_ready_turn();
+        self.log_event("Initiative", CombatEventType::Other, "Initiative re-rolled");
+        rolls
+    }
+
+    /// Mark combatants as surprised. Surprised combatants lose their turn in
+    /// the first round; if the current combatant is surprised, the turn moves
+    /// to the first combatant who can act.
+    pub fn set_surprised(&mut self, combatant_ids: &[String]) {
+        for combatant in &mut self.combatants {
+            combatant.surprised = combatant_ids.contains(&combatant.id);
+        }
+        if self.round == 1 && !self.can_act(self.current_turn) {
+            self.current_turn = self.first_ready_turn();
+        }
+    }
+
+    /// Whether the combatant at a turn index takes their turn this round
+    fn can_act(&self, turn: usize) -> bool {
+        self.combatants
+            .get(turn)
+            .is_some_and(|c| c.is_active && !c.death.is_dead() && !(c.surprised && self.round == 1))
+    }
+
+    /// First turn index whose combatant can act, or 0 if none can
+    fn first_ready_turn(&self) -> usize {
+        (0..self.combatants.len()).find(|&i| self.can_act(i)).unwrap_or(0)
+    }
+
+    /// Add a combatant at their place in initiative, leaving the order of
+    /// the others as it is
+    pub fn add_combatant(&mut self, mut combatant: Combatant) {
+        self.prepare_for_mode(&mut combatant);
+        let rules = &self.initiative_rules;
+        let position = self
+            .combatants
+            .iter()
+            .position(|c| rules.compare(&combatant, c) == Ordering::Less)
+            .unwrap_or(self.combatants.len());
+        self.combatants.insert(position, combatant);
     }
 
     /// Remove a combatant by ID
@@ -270,37 +604,34 @@ impl CombatState {
     /// Advance to the next turn
     /// Handles end-of-turn condition ticking, round advancement,
     /// and start-of-turn condition ticking
-    /// Returns the new current combatant and any expired conditions
+    /// Returns the new current combatant, any expired conditions, and
+    /// reminders for the new combatant's conditions
     pub fn next_turn(&mut self) -> TurnResult {
         if self.combatants.is_empty() {
-            return TurnResult {
-                current_combatant: None,
-                new_round: false,
-                expired_conditions: vec![],
-            };
+            return TurnResult::default();
         }
 
-        let mut expired_conditions = Vec::new();
-
-        // Tick conditions at END of current combatant's turn
-        if let Some(current) = self.combatants.get_mut(self.current_turn) {
-            let expired = current.condition_tracker.tick_end_of_turn(true);
-            for condition in expired {
-                self.events.push(CombatEvent {
-                    round: self.round,
-                    turn: self.current_turn,
-                    timestamp: Utc::now(),
-                    actor: current.name.clone(),
-                    event_type: CombatEventType::ConditionRemoved,
-                    description: format!("{} condition expired on {}", condition.name, current.name),
-                });
-                expired_conditions.push((current.name.clone(), condition.name));
+        let mut expired = Vec::new();
+
+        // Tick conditions at END of current combatant's turn, including
+        // conditions this combatant applied "until the end of its next turn"
+        let ending = self.current_turn;
+        if let Some(current) = self.combatants.get_mut(ending) {
+            let ended = current.condition_tracker.tick_end_of_turn(true);
+            expired.extend(self.record_expired(ending, ended, ExpiryReason::Duration, ""));
+            let source_id = self.combatants[ending].id.clone();
+            for idx in 0..self.combatants.len() {
+                let ended = self.combatants[idx].condition_tracker.expire_source_turn(&source_id);
+                expired.extend(self.record_expired(idx, ended, ExpiryReason::SourceTurnEnded, ""));
             }
         }
 
         // Move to next active combatant
         let start = self.current_turn;
         let mut new_round = false;
+        let mut current_combatant = None;
+        let mut lair_action = None;
+        let mut recharges = Vec::new();
 
         loop {
             self.current_turn = (self.current_turn + 1) % self.combatants.len();
@@ -309,61 +640,840 @@ impl CombatState {
             if self.current_turn == 0 {
                 self.round += 1;
                 new_round = true;
-
-                // Tick round-based conditions for all combatants
                 for combatant in &mut self.combatants {
-                    let expired = combatant.condition_tracker.tick_round();
-                    for condition in expired {
-                        self.events.push(CombatEvent {
-                            round: self.round,
-                            turn: 0,
-                            timestamp: Utc::now(),
-                            actor: combatant.name.clone(),
-                            event_type: CombatEventType::ConditionRemoved,
-                            description: format!(
-                                "{} condition expired on {} (round end)",
-                                condition.name, combatant.name
-                            ),
-                        });
-                        expired_conditions.push((combatant.name.clone(), condition.name));
+                    combatant.surprised = false;
+                    for ability in &mut combatant.abilities {
+                        if matches!(ability.limit, UsageLimit::PerRound { .. }) {
+                            ability.restore();
+                        }
                     }
                 }
+
+                // Tick round-based and minute/hour conditions for all combatants
+                for idx in 0..self.combatants.len() {
+                    let tracker = &mut self.combatants[idx].condition_tracker;
+                    let mut ended = tracker.tick_round();
+                    ended.extend(tracker.tick_seconds(self.seconds_per_round));
+                    expired.extend(self.record_expired(idx, ended, ExpiryReason::Duration, " (round end)"));
+                }
+            }
+
+            // Surprised combatants lose their first-round turn
+            let skipped = &self.combatants[self.current_turn];
+            if skipped.is_active && !self.can_act(self.current_turn) {
+                let name = skipped.name.clone();
+                self.log_event(&name, CombatEventType::Other, format!("{} is surprised and loses their turn", name));
             }
 
             // Tick start-of-turn conditions for the new current combatant
-            if self.combatants[self.current_turn].is_active {
-                let combatant = &mut self.combatants[self.current_turn];
-                let expired = combatant.condition_tracker.tick_start_of_turn(true);
-                for condition in expired {
-                    self.events.push(CombatEvent {
-                        round: self.round,
-                        turn: self.current_turn,
-                        timestamp: Utc::now(),
-                        actor: combatant.name.clone(),
-                        event_type: CombatEventType::ConditionRemoved,
-                        description: format!(
-                            "{} condition expired on {} (start of turn)",
-                            condition.name, combatant.name
-                        ),
-                    });
-                    expired_conditions.push((combatant.name.clone(), condition.name));
+            if self.can_act(self.current_turn) {
+                let idx = self.current_turn;
+                self.combatants[idx].delaying = false;
+                if let Some(lapsed) = self.combatants[idx].readied.take() {
+                    let name = self.combatants[idx].name.clone();
+                    self.log_event(&name, CombatEventType::Other, format!("{}'s readied {} lapses", name, lapsed.action));
                 }
-                return TurnResult {
-                    current_combatant: Some(self.combatants[self.current_turn].clone()),
-                    new_round,
-                    expired_conditions,
-                };
+                lair_action = self.trigger_lair(idx);
+                self.combatants[idx].refresh_actions();
+                recharges = self.combatants[idx].start_turn_abilities(&mut rand::thread_rng());
+                for roll in &recharges {
+                    self.log_event(&roll.combatant_name, CombatEventType::Other, roll.describe());
+                }
+                let ended = self.combatants[idx].condition_tracker.tick_start_of_turn(true);
+                expired.extend(self.record_expired(idx, ended, ExpiryReason::Duration, " (start of turn)"));
+                current_combatant = Some(idx);
+                break;
             }
 
             // Full loop without finding active combatant
             if self.current_turn == start {
-                return TurnResult {
-                    current_combatant: None,
-                    new_round,
-                    expired_conditions,
+                break;
+            }
+        }
+
+        // Effects sustained by concentration that just ended go with it
+        let concentration_ids: Vec<String> = expired
+            .iter()
+            .filter(|e| e.concentration)
+            .map(|e| e.condition_id.clone())
+            .collect();
+        expired.extend(self.release_concentration(&concentration_ids));
+
+        let reminders = current_combatant.map(|idx| self.reminders_for(idx)).unwrap_or_default();
+        let readied = current_combatant
+            .map(|idx| triggered_by(&self.combatants, &self.combatants[idx].id))
+            .unwrap_or_default();
+        if let Some(idx) = current_combatant {
+            let name = self.combatants[idx].name.clone();
+            self.log_event(&name, CombatEventType::TurnChange, format!("Round {}: {}'s turn", self.round, name));
+        }
+        for reminder in &readied {
+            self.log_event(&reminder.combatant_name, CombatEventType::Reaction, reminder.describe());
+        }
+        TurnResult {
+            current_combatant: current_combatant.map(|idx| self.combatants[idx].clone()),
+            new_round,
+            expired_conditions: expired,
+            reminders,
+            lair_action,
+            recharges,
+            readied,
+        }
+    }
+
+    /// Run lair actions if the turn at `idx` is the first below the lair's
+    /// initiative count this round. Returns the lair owner's ID.
+    fn trigger_lair(&mut self, idx: usize) -> Option<String> {
+        let round = self.round;
+        let initiative = self.combatants[idx].initiative;
+        let lair = self.lair_actions.as_mut()?;
+        if !lair.triggers_before(round, initiative) {
+            return None;
+        }
+        lair.last_round = Some(round);
+        let (owner_id, count) = (lair.combatant_id.clone(), lair.initiative);
+        let owner = self.get_combatant(&owner_id).filter(|c| c.is_active && !c.death.is_dead())?;
+        let name = owner.name.clone();
+        self.log_event(&name, CombatEventType::Action, format!("{}'s lair acts on initiative {}", name, count));
+        Some(owner_id)
+    }
+
+    /// Log removed conditions and describe them as expiries
+    fn record_expired(
+        &mut self,
+        idx: usize,
+        conditions: Vec<AdvancedCondition>,
+        reason: ExpiryReason,
+        suffix: &str,
+    ) -> Vec<ConditionExpiry> {
+        let (combatant_id, combatant_name) = {
+            let c = &self.combatants[idx];
+            (c.id.clone(), c.name.clone())
+        };
+        conditions
+            .into_iter()
+            .map(|condition| {
+                let description = match reason {
+                    ExpiryReason::ConcentrationEnded => {
+                        format!("{} ended on {} as concentration ended", condition.name, combatant_name)
+                    }
+                    ExpiryReason::ConcentrationBroken => format!("{} loses concentration", combatant_name),
+                    _ => format!("{} condition expired on {}{}", condition.name, combatant_name, suffix),
                 };
+                self.events.push(CombatEvent {
+                    round: self.round,
+                    turn: idx,
+                    timestamp: Utc::now(),
+                    actor: combatant_name.clone(),
+                    event_type: CombatEventType::ConditionRemoved,
+                    description,
+                });
+                ConditionExpiry {
+                    combatant_id: combatant_id.clone(),
+                    combatant_name: combatant_name.clone(),
+                    condition_id: condition.id.clone(),
+                    concentration: condition.is_concentration(),
+                    condition_name: condition.name,
+                    reason,
+                }
+            })
+            .collect()
+    }
+
+    /// Turn reminders for a combatant's conditions
+    fn reminders_for(&self, idx: usize) -> Vec<ConditionReminder> {
+        let combatant = &self.combatants[idx];
+        combatant
+            .condition_tracker
+            .conditions()
+            .iter()
+            .flat_map(|condition| {
+                condition.turn_reminders().into_iter().map(|message| ConditionReminder {
+                    combatant_id: combatant.id.clone(),
+                    combatant_name: combatant.name.clone(),
+                    condition_id: condition.id.clone(),
+                    condition_name: condition.name.clone(),
+                    message,
+                })
+            })
+            .collect()
+    }
+
+    // ========================================================================
+    // Hit Points and Dying
+    // ========================================================================
+
+    /// Damage a combatant and apply the death rules if they drop to 0 HP
+    pub fn damage(&mut self, combatant_id: &str, amount: i32, critical: bool) -> Option<HealthUpdate> {
+        self.take_damage(combatant_id, &Damage::new(amount).critical(critical))
+            .map(|breakdown| breakdown.health_update())
+    }
+
+    /// Apply a hit of damage after the combatant's damage defenses, with
+    /// temporary HP soaking it up first, and the death rules if they drop to
+    /// 0 HP
+    pub fn take_damage(&mut self, combatant_id: &str, damage: &Damage) -> Option<DamageBreakdown> {
+        if self.get_combatant(combatant_id)?.group.is_some() {
+            return self
+                .damage_group(combatant_id, damage, &GroupTargets::default())
+                .and_then(|result| result.ok())
+                .map(|result| result.breakdown);
+        }
+        let rules = self.death_rules;
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let adjustment = damage
+            .damage_type
+            .as_deref()
+            .map(|t| combatant.damage_defenses.adjustment(t, damage.magical))
+            .unwrap_or_default();
+        let effective = adjustment.apply(damage.amount.max(0));
+        let hp_before = combatant.current_hp.unwrap_or(0);
+        let absorbed = combatant.temp_hp.unwrap_or(0).clamp(0, effective);
+        let hp_after = combatant.apply_damage(effective);
+        let death = rules.on_damage(combatant, hp_before, effective - absorbed, damage.critical);
+        let name = combatant.name.clone();
+
+        let breakdown = DamageBreakdown {
+            raw: damage.amount,
+            damage_type: damage.damage_type.clone(),
+            adjustment,
+            effective,
+            absorbed,
+            hp_before,
+            hp_after,
+            temp_hp_after: combatant.temp_hp.unwrap_or(0),
+            death,
+        };
+        let source = damage
+            .source_id
+            .as_deref()
+            .and_then(|id| self.get_combatant(id))
+            .map(|c| (c.id.clone(), c.name.clone()));
+        let source = source.as_ref().map(|(id, name)| (id.as_str(), name.as_str()));
+        self.tally.record_damage((combatant_id, &name), source, &breakdown);
+        self.log_event(&name, CombatEventType::Damage, breakdown.describe(&name));
+        self.log_death_update(&name, breakdown.death.as_ref());
+        Some(breakdown)
+    }
+
+    /// Heal a combatant, bringing them back from dying. The dead stay dead.
+    pub fn heal(&mut self, combatant_id: &str, amount: i32) -> Option<HealthUpdate> {
+        let rules = self.death_rules;
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        if combatant.death.is_dead() {
+            return Some(HealthUpdate { hp: combatant.current_hp.unwrap_or(0), death: None });
+        }
+        let hp_before = combatant.current_hp.unwrap_or(0);
+        let hp = combatant.heal(amount);
+        let death = rules.on_healed(combatant);
+        let name = combatant.name.clone();
+        self.tally.record_healing(combatant_id, &name, hp - hp_before);
+
+        self.log_event(&name, CombatEventType::Healing, format!("{} heals {} HP", name, amount));
+        self.log_death_update(&name, death.as_ref());
+        Some(HealthUpdate { hp, death })
+    }
+
+    /// Resolve a death save or recovery check from a natural d20 roll.
+    /// Returns `None` when the combatant is missing or not dying.
+    pub fn roll_death_save(&mut self, combatant_id: &str, roll: i32) -> Option<DeathUpdate> {
+        let rules = self.death_rules;
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let update = rules.roll_save(combatant, roll)?;
+        let name = combatant.name.clone();
+        self.log_death_update(&name, Some(&update));
+        Some(update)
+    }
+
+    /// Stabilize a dying combatant
+    pub fn stabilize(&mut self, combatant_id: &str) -> Option<DeathUpdate> {
+        let rules = self.death_rules;
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let update = rules.stabilize(combatant)?;
+        let name = combatant.name.clone();
+        self.log_death_update(&name, Some(&update));
+        Some(update)
+    }
+
+    fn log_death_update(&mut self, name: &str, update: Option<&DeathUpdate>) {
+        if let Some(update) = update {
+            let event_type = match update.track.state {
+                LifeState::Dead => CombatEventType::Death,
+                LifeState::Stable | LifeState::Conscious => CombatEventType::Stabilized,
+                LifeState::Dying => CombatEventType::Other,
+            };
+            self.log_event(name, event_type, update.message.clone());
+        }
+    }
+
+    // ========================================================================
+    // Delaying, Readying, and Reordering
+    // ========================================================================
+
+    /// Move a combatant to `position` in the order (0 is first), taking the
+    /// initiative of the combatant they now go before. The turn stays with
+    /// whoever has it. Returns the new initiative.
+    pub fn move_in_order(&mut self, combatant_id: &str, position: usize) -> Option<Result<i32, TurnOrderError>> {
+        let from = self.combatants.iter().position(|c| c.id == combatant_id)?;
+        if position >= self.combatants.len() {
+            return Some(Err(TurnOrderError::InvalidPosition(position)));
+        }
+        let initiative = self.place(from, position);
+        let name = self.combatants[position].name.clone();
+        self.log_event(
+            "GM",
+            CombatEventType::Other,
+            format!("{} moved to place {} in the initiative order ({})", name, position + 1, initiative),
+        );
+        Some(Ok(initiative))
+    }
+
+    /// Move the combatant at `from` to `to`, keeping the turn with the
+    /// current combatant, and give them the initiative of their new place
+    fn place(&mut self, from: usize, to: usize) -> i32 {
+        let current_id = self.current_combatant().map(|c| c.id.clone());
+        let combatant = self.combatants.remove(from);
+        self.combatants.insert(to, combatant);
+        let initiative = initiative_at(&self.combatants, to).unwrap_or(self.combatants[to].initiative);
+        self.combatants[to].initiative = initiative;
+        if let Some(pos) = current_id.and_then(|id| self.combatants.iter().position(|c| c.id == id)) {
+            self.current_turn = pos;
+        }
+        initiative
+    }
+
+    /// The current combatant delays, and the turn passes on. They come back
+    /// with `resume_delayed_turn`; if they haven't by the time their place
+    /// in the order comes round again, they act there as usual.
+    pub fn delay_turn(&mut self, combatant_id: &str) -> Option<Result<TurnResult, TurnOrderError>> {
+        let combatant = self.get_combatant(combatant_id)?;
+        let name = combatant.name.clone();
+        if self.current_combatant().map(|c| c.id.as_str()) != Some(combatant_id) {
+            return Some(Err(TurnOrderError::NotTheirTurn(name)));
+        }
+        self.get_combatant_mut(combatant_id)?.delaying = true;
+        self.log_event(&name, CombatEventType::Other, format!("{} delays their turn", name));
+        Some(Ok(self.next_turn()))
+    }
+
+    /// Bring a delaying combatant back into the order right after the
+    /// current combatant, at their initiative, and start their turn
+    pub fn resume_delayed_turn(&mut self, combatant_id: &str) -> Option<Result<TurnResult, TurnOrderError>> {
+        let from = self.combatants.iter().position(|c| c.id == combatant_id)?;
+        let name = self.combatants[from].name.clone();
+        if !self.combatants[from].delaying {
+            return Some(Err(TurnOrderError::NotDelaying(name)));
+        }
+        if from == self.current_turn {
+            return Some(Err(TurnOrderError::AlreadyTheirTurn(name)));
+        }
+        let to = if from < self.current_turn { self.current_turn } else { self.current_turn + 1 };
+        let initiative = self.place(from, to);
+        self.log_event(
+            &name,
+            CombatEventType::Other,
+            format!("{} stops delaying and acts at initiative {}", name, initiative),
+        );
+        Some(Ok(self.next_turn()))
+    }
+
+    /// Hold an action until a trigger, replacing any already readied. With
+    /// `trigger_combatant_id`, it's brought up when that combatant's turn
+    /// starts.
+    pub fn ready_action(
+        &mut self,
+        combatant_id: &str,
+        action: impl Into<String>,
+        trigger: impl Into<String>,
+        trigger_combatant_id: Option<String>,
+    ) -> Option<ReadiedAction> {
+        let round = self.round;
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let readied = ReadiedAction {
+            action: action.into(),
+            trigger: trigger.into(),
+            trigger_combatant_id,
+            round,
+        };
+        combatant.readied = Some(readied.clone());
+        let description = ReadiedReminder::from_combatant(combatant)?.describe();
+        let name = combatant.name.clone();
+        self.log_event(&name, CombatEventType::Action, description);
+        Some(readied)
+    }
+
+    /// Take a readied action, spending the combatant's reaction
+    pub fn take_readied_action(&mut self, combatant_id: &str) -> Option<Result<ReadiedAction, TurnOrderError>> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let name = combatant.name.clone();
+        let Some(readied) = combatant.readied.take() else {
+            return Some(Err(TurnOrderError::NothingReadied(name)));
+        };
+        combatant.reaction_used = true;
+        self.log_event(&name, CombatEventType::Reaction, format!("{} takes their readied action: {}", name, readied.action));
+        Some(Ok(readied))
+    }
+
+    // ========================================================================
+    // Minion Groups
+    // ========================================================================
+
+    /// Hit members of a group, each hit adjusted for the group's damage
+    /// defenses. Members who drop leave the group; when none are left
+    /// standing the group is dead. Each member dropped counts as a kill.
+    pub fn damage_group(
+        &mut self,
+        combatant_id: &str,
+        damage: &Damage,
+        targets: &GroupTargets,
+    ) -> Option<Result<GroupDamageResult, GroupError>> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let name = combatant.name.clone();
+        let Some(group) = combatant.group.as_mut() else {
+            return Some(Err(GroupError::NotAGroup(name)));
+        };
+        let adjustment = damage
+            .damage_type
+            .as_deref()
+            .map(|t| combatant.damage_defenses.adjustment(t, damage.magical))
+            .unwrap_or_default();
+        let effective = adjustment.apply(damage.amount.max(0));
+        let hp_before = group.total_hp();
+        let hits = match group.take_hits(effective, targets) {
+            Ok(hits) => hits,
+            Err(e) => return Some(Err(e)),
+        };
+        let (standing, size, status) = (group.standing(), group.size(), group.describe());
+        combatant.current_hp = Some(group.total_hp());
+
+        let death = (standing == 0 && !combatant.death.is_dead()).then(|| {
+            let previous = combatant.death.state;
+            combatant.death.state = LifeState::Dead;
+            DeathUpdate {
+                previous,
+                track: combatant.death.clone(),
+                message: format!("{} are wiped out", name),
             }
+        });
+        let breakdown = DamageBreakdown {
+            raw: damage.amount,
+            damage_type: damage.damage_type.clone(),
+            adjustment,
+            effective: effective * hits.hits as i32,
+            absorbed: 0,
+            hp_before,
+            hp_after: hp_before - hits.hp_lost,
+            temp_hp_after: 0,
+            death,
+        };
+
+        let source = damage
+            .source_id
+            .as_deref()
+            .and_then(|id| self.get_combatant(id))
+            .map(|c| (c.id.clone(), c.name.clone()));
+        let source = source.as_ref().map(|(id, name)| (id.as_str(), name.as_str()));
+        self.tally.record_group_damage((combatant_id, &name), source, breakdown.effective, hits.dropped.len() as u32);
+        let mut description = breakdown.describe(&name);
+        if !hits.dropped.is_empty() {
+            description.push_str(&format!("; {} drop, {}", hits.dropped.len(), status));
+        }
+        self.log_event(&name, CombatEventType::Damage, description);
+        self.log_death_update(&name, breakdown.death.as_ref());
+
+        Some(Ok(GroupDamageResult {
+            combatant_id: combatant_id.to_string(),
+            combatant_name: name,
+            hits,
+            standing,
+            size,
+            breakdown,
+        }))
+    }
+
+    // ========================================================================
+    // Morale
+    // ========================================================================
+
+    pub fn add_morale_group(&mut self, group: MoraleGroup) {
+        self.morale_groups.push(group);
+    }
+
+    pub fn remove_morale_group(&mut self, group_id: &str) -> Option<MoraleGroup> {
+        let index = self.morale_groups.iter().position(|g| g.id == group_id)?;
+        Some(self.morale_groups.remove(index))
+    }
+
+    /// Roll morale for every group with a trigger that has just happened,
+    /// once per group however many happened together
+    pub fn check_morale<R: Rng>(&mut self, rng: &mut R) -> Vec<MoraleCheck> {
+        let rules = self.morale_rules;
+        let mut groups = std::mem::take(&mut self.morale_groups);
+        let checks: Vec<MoraleCheck> = groups
+            .iter_mut()
+            .filter_map(|group| {
+                let pending = group.pending(&self.combatants);
+                let check = group.check(*pending.first()?, &rules, rng);
+                group.fired.extend(pending.into_iter().skip(1));
+                Some(check)
+            })
+            .collect();
+        self.morale_groups = groups;
+        for check in &checks {
+            self.log_event(&check.group_name, CombatEventType::Other, check.suggestion.clone());
+        }
+        checks
+    }
+
+    /// Roll morale for a group on the GM's call
+    pub fn call_morale_check<R: Rng>(&mut self, group_id: &str, rng: &mut R) -> Result<MoraleCheck, MoraleError> {
+        let rules = self.morale_rules;
+        let group = self
+            .morale_groups
+            .iter_mut()
+            .find(|g| g.id == group_id)
+            .ok_or_else(|| MoraleError::GroupNotFound(group_id.to_string()))?;
+        if group.broken {
+            return Err(MoraleError::AlreadyBroken(group.name.clone()));
+        }
+        let check = group.check(MoraleTrigger::Called, &rules, rng);
+        self.log_event(&check.group_name, CombatEventType::Other, check.suggestion.clone());
+        Ok(check)
+    }
+
+    // ========================================================================
+    // Action Economy and Hero Points
+    // ========================================================================
+
+    /// Switch action economies, giving every combatant turn actions under the
+    /// three-action economy and taking them away otherwise
+    pub fn set_mode(&mut self, mode: CombatMode) {
+        self.mode = mode;
+        let mut combatants = std::mem::take(&mut self.combatants);
+        for combatant in &mut combatants {
+            combatant.turn_actions = None;
+            self.prepare_for_mode(combatant);
+        }
+        self.combatants = combatants;
+    }
+
+    /// Turn actions and starting hero points for a combatant joining a
+    /// three-action combat
+    fn prepare_for_mode(&mut self, combatant: &mut Combatant) {
+        if !self.mode.is_three_action() {
+            return;
+        }
+        combatant.turn_actions.get_or_insert_with(TurnActions::new);
+        if combatant.combatant_type == CombatantType::Player {
+            self.hero_points.entry(combatant.name.clone()).or_insert(STARTING_HERO_POINTS);
+        }
+    }
+
+    /// Spend a combatant's actions on an activity, tracking attacks for the
+    /// multiple attack penalty
+    pub fn spend_actions(&mut self, combatant_id: &str, activity: &Activity) -> Option<Result<TurnSpend, EconomyError>> {
+        let result = self.get_combatant_mut(combatant_id)?.spend_actions(activity);
+        if let Ok(spend) = &result {
+            let event_type = if activity.attack { CombatEventType::Attack } else { CombatEventType::Action };
+            let name = spend.combatant_name.clone();
+            self.log_event(&name, event_type, spend.describe(activity));
+        }
+        Some(result)
+    }
+
+    /// Gain or lose actions this turn, as from quickened, slowed, or stunned
+    pub fn adjust_actions(&mut self, combatant_id: &str, delta: i32) -> Option<Result<TurnActions, EconomyError>> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let Some(turn) = combatant.turn_actions.as_mut() else {
+            return Some(Err(EconomyError::NotThreeAction));
+        };
+        turn.adjust(delta);
+        Some(Ok(*turn))
+    }
+
+    /// Hero points a combatant holds
+    pub fn hero_points(&self, combatant_id: &str) -> Option<u32> {
+        let combatant = self.get_combatant(combatant_id)?;
+        Some(self.hero_points.get(&combatant.name).copied().unwrap_or(0))
+    }
+
+    /// Give a combatant hero points, up to the most they can hold, returning
+    /// their new total
+    pub fn award_hero_points(&mut self, combatant_id: &str, amount: u32) -> Option<Result<u32, EconomyError>> {
+        if !self.mode.is_three_action() {
+            return Some(Err(EconomyError::NotThreeAction));
         }
+        let name = self.get_combatant(combatant_id)?.name.clone();
+        let points = self.hero_points.entry(name.clone()).or_insert(0);
+        *points = (*points + amount).min(MAX_HERO_POINTS);
+        let total = *points;
+        self.log_event(&name, CombatEventType::Other, format!("{} gains a hero point ({} held)", name, total));
+        Some(Ok(total))
+    }
+
+    /// Spend a hero point to reroll a check, returning what is left
+    pub fn spend_hero_point(&mut self, combatant_id: &str) -> Option<Result<u32, EconomyError>> {
+        let name = self.get_combatant(combatant_id)?.name.clone();
+        let Some(points) = self.hero_points.get_mut(&name).filter(|p| **p > 0) else {
+            return Some(Err(EconomyError::NoHeroPoints(name)));
+        };
+        *points -= 1;
+        let left = *points;
+        self.log_event(&name, CombatEventType::Other, format!("{} spends a hero point to reroll", name));
+        Some(Ok(left))
+    }
+
+    /// Spend every hero point a dying combatant holds to stabilize them at
+    /// 0 HP
+    pub fn heroic_recovery(&mut self, combatant_id: &str) -> Option<Result<DeathUpdate, EconomyError>> {
+        let rules = self.death_rules;
+        let name = self.get_combatant(combatant_id)?.name.clone();
+        if self.hero_points.get(&name).copied().unwrap_or(0) == 0 {
+            return Some(Err(EconomyError::NoHeroPoints(name)));
+        }
+        let Some(update) = rules.heroic_recovery(self.get_combatant_mut(combatant_id)?) else {
+            return Some(Err(EconomyError::NotDying(name)));
+        };
+        self.hero_points.insert(name.clone(), 0);
+        self.log_death_update(&name, Some(&update));
+        Some(Ok(update))
+    }
+
+    // ========================================================================
+    // Limited Abilities
+    // ========================================================================
+
+    /// Use a limited ability, failing when it's spent
+    pub fn use_ability(&mut self, combatant_id: &str, ability: &str) -> Option<Result<TrackedAbility, AbilityError>> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        let name = combatant.name.clone();
+        let result = combatant.tracked_ability_mut(ability).and_then(|tracked| {
+            tracked.use_once()?;
+            Ok(tracked.clone())
+        });
+        if let Ok(tracked) = &result {
+            self.log_event(&name, CombatEventType::Action, format!("{} uses {}", name, tracked.name));
+        }
+        Some(result)
+    }
+
+    /// Give a limited ability back every use, as when the GM rules it
+    /// recharged
+    pub fn restore_ability(&mut self, combatant_id: &str, ability: &str) -> Option<Result<TrackedAbility, AbilityError>> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        Some(combatant.tracked_ability_mut(ability).map(|tracked| {
+            tracked.restore();
+            tracked.clone()
+        }))
+    }
+
+    /// Track a limited ability the stat block didn't mark, replacing any of
+    /// the same name
+    pub fn track_ability(&mut self, combatant_id: &str, ability: TrackedAbility) -> Option<()> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        combatant.abilities.retain(|a| !a.name.eq_ignore_ascii_case(&ability.name));
+        combatant.abilities.push(ability);
+        Some(())
+    }
+
+    // ========================================================================
+    // Positioning
+    // ========================================================================
+
+    /// Track positions on a battlefield, or stop with `None`. Positions that
+    /// don't fit the new battlefield are cleared.
+    pub fn set_battlefield(&mut self, battlefield: Option<Battlefield>) {
+        for combatant in &mut self.combatants {
+            let fits = match (&battlefield, &combatant.position) {
+                (Some(field), Some(position)) => field.validate(position).is_ok(),
+                _ => false,
+            };
+            if !fits {
+                combatant.position = None;
+            }
+        }
+        self.battlefield = battlefield;
+    }
+
+    /// Move a combatant, logging the distance covered. Grid squares hold one
+    /// combatant at a time; zones hold any number.
+    pub fn move_combatant(&mut self, combatant_id: &str, to: Position) -> Option<Result<Movement, PositionError>> {
+        let Some(battlefield) = self.battlefield.clone() else {
+            return Some(Err(PositionError::NoBattlefield));
+        };
+        let combatant = self.get_combatant(combatant_id)?;
+        if let Err(e) = battlefield.validate(&to) {
+            return Some(Err(e));
+        }
+        if let Position::Square { x, y } = to {
+            let occupant = self
+                .combatants
+                .iter()
+                .find(|c| c.id != combatant_id && c.is_active && c.position.as_ref() == Some(&to));
+            if let Some(occupant) = occupant {
+                return Some(Err(PositionError::Occupied { x, y, name: occupant.name.clone() }));
+            }
+        }
+        let movement = Movement {
+            combatant_id: combatant.id.clone(),
+            combatant_name: combatant.name.clone(),
+            distance_feet: combatant.position.as_ref().and_then(|from| battlefield.distance_feet(from, &to)),
+            from: combatant.position.clone(),
+            to: to.clone(),
+        };
+        self.get_combatant_mut(combatant_id)?.position = Some(to);
+        self.log_event(&movement.combatant_name, CombatEventType::Movement, movement.describe(&battlefield));
+        Some(Ok(movement))
+    }
+
+    /// Whether an attack from one combatant reaches another
+    pub fn check_range(
+        &self,
+        attacker_id: &str,
+        target_id: &str,
+        range: AttackRange,
+    ) -> Option<Result<RangeCheck, PositionError>> {
+        let attacker = self.get_combatant(attacker_id)?;
+        let target = self.get_combatant(target_id)?;
+        let Some(battlefield) = &self.battlefield else {
+            return Some(Err(PositionError::NoBattlefield));
+        };
+        Some(check_range(battlefield, attacker, target, range))
+    }
+
+    // ========================================================================
+    // Legendary Actions, Lair Actions, and Reactions
+    // ========================================================================
+
+    /// Give a combatant a pool of legendary actions, or none with `max` 0
+    pub fn set_legendary_actions(&mut self, combatant_id: &str, max: u32) -> Option<()> {
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        combatant.legendary_actions = (max > 0).then(|| ActionPool::new(max));
+        Some(())
+    }
+
+    /// Spend legendary actions between turns
+    pub fn spend_legendary_action(
+        &mut self,
+        combatant_id: &str,
+        cost: u32,
+        action: Option<&str>,
+    ) -> Option<Result<ActionSpend, ActionError>> {
+        let result = self.get_combatant_mut(combatant_id)?.spend_legendary_action(cost);
+        if let Ok(spend) = &result {
+            let description = match action {
+                Some(action) => format!("{} uses a legendary action: {}", spend.combatant_name, action),
+                None => format!("{} uses a legendary action", spend.combatant_name),
+            };
+            let name = spend.combatant_name.clone();
+            self.log_event(&name, CombatEventType::Action, description);
+        }
+        Some(result)
+    }
+
+    /// Spend a combatant's reaction
+    pub fn use_reaction(
+        &mut self,
+        combatant_id: &str,
+        reaction: Option<&str>,
+    ) -> Option<Result<ActionSpend, ActionError>> {
+        let result = self.get_combatant_mut(combatant_id)?.use_reaction();
+        if let Ok(spend) = &result {
+            let description = match reaction {
+                Some(reaction) => format!("{} reacts: {}", spend.combatant_name, reaction),
+                None => format!("{} uses their reaction", spend.combatant_name),
+            };
+            let name = spend.combatant_name.clone();
+            self.log_event(&name, CombatEventType::Reaction, description);
+        }
+        Some(result)
+    }
+
+    /// Refill a combatant's legendary actions and reaction early
+    pub fn refresh_actions(&mut self, combatant_id: &str) -> Option<()> {
+        self.get_combatant_mut(combatant_id)?.refresh_actions();
+        Some(())
+    }
+
+    /// Set or clear the creature whose lair acts on initiative 20
+    pub fn set_lair_actions(&mut self, lair: Option<LairActions>) -> Option<()> {
+        if let Some(lair) = &lair {
+            self.get_combatant(&lair.combatant_id)?;
+        }
+        self.lair_actions = lair;
+        Some(())
+    }
+
+    // ========================================================================
+    // Concentration
+    // ========================================================================
+
+    /// End every condition sustained by the given concentration conditions
+    pub fn release_concentration(&mut self, concentration_ids: &[String]) -> Vec<ConditionExpiry> {
+        if concentration_ids.is_empty() {
+            return Vec::new();
+        }
+        let mut expired = Vec::new();
+        for idx in 0..self.combatants.len() {
+            let ended = self.combatants[idx].condition_tracker.remove_sustained_by(concentration_ids);
+            expired.extend(self.record_expired(idx, ended, ExpiryReason::ConcentrationEnded, ""));
+        }
+        expired
+    }
+
+    /// Break a combatant's concentration, ending the effects it sustains
+    pub fn break_concentration(&mut self, combatant_id: &str) -> Vec<ConditionExpiry> {
+        let Some(idx) = self.combatants.iter().position(|c| c.id == combatant_id) else {
+            return Vec::new();
+        };
+        let dropped = self.combatants[idx].condition_tracker.remove_concentration();
+        let mut expired = self.record_expired(idx, dropped, ExpiryReason::ConcentrationBroken, "");
+        let ids: Vec<String> = expired.iter().map(|e| e.condition_id.clone()).collect();
+        expired.extend(self.release_concentration(&ids));
+        expired
+    }
+
+    /// Start concentrating on an effect, first breaking any existing
+    /// concentration. Returns the new concentration condition and whatever
+    /// ended with the old one.
+    pub fn start_concentration(
+        &mut self,
+        combatant_id: &str,
+        effect: &str,
+        duration: ConditionDuration,
+    ) -> Option<(AdvancedCondition, Vec<ConditionExpiry>)> {
+        self.get_combatant(combatant_id)?;
+        let expired = self.break_concentration(combatant_id);
+        let condition = ConditionTemplates::concentrating_on(effect, duration);
+        let combatant = self.get_combatant_mut(combatant_id)?;
+        combatant.condition_tracker.add_condition(condition.clone()).ok()?;
+        let name = combatant.name.clone();
+        self.log_event(&name, CombatEventType::ConditionApplied, format!("{} concentrates on {}", name, effect));
+        Some((condition, expired))
+    }
+
+    /// A reminder for the concentration check a combatant must make after
+    /// taking damage, if they are concentrating
+    pub fn concentration_check(&self, combatant_id: &str, damage: i32) -> Option<ConditionReminder> {
+        let combatant = self.get_combatant(combatant_id)?;
+        let concentration = combatant.condition_tracker.concentration()?;
+        let effect = concentration
+            .metadata
+            .get("effect")
+            .and_then(|v| v.as_str())
+            .unwrap_or("their spell");
+        Some(ConditionReminder {
+            combatant_id: combatant.id.clone(),
+            combatant_name: combatant.name.clone(),
+            condition_id: concentration.id.clone(),
+            condition_name: concentration.name.clone(),
+            message: format!(
+                "{} must make a DC {} Constitution save to keep concentrating on {}",
+                combatant.name,
+                (damage / 2).max(10),
+                effect
+            ),
+        })
     }
 
     /// Go back to the previous turn
@@ -388,6 +1498,8 @@ impl CombatState {
             }
 
             if self.combatants[self.current_turn].is_active {
+                let name = self.combatants[self.current_turn].name.clone();
+                self.log_event(&name, CombatEventType::TurnChange, format!("Back to round {}: {}'s turn", self.round, name));
                 return Some(self.combatants[self.current_turn].clone());
             }
 
@@ -397,6 +1509,23 @@ impl CombatState {
         }
     }
 
+    /// A copy of the combat for undo, leaving out the log
+    pub fn snapshot(&mut self) -> CombatState {
+        let events = std::mem::take(&mut self.events);
+        let snapshot = self.clone();
+        self.events = events;
+        snapshot
+    }
+
+    /// Go back to a snapshot, keeping the log. Returns the combat as it was,
+    /// without its log, so the change can be redone.
+    pub fn restore(&mut self, snapshot: CombatState) -> CombatState {
+        let events = std::mem::take(&mut self.events);
+        let replaced = std::mem::replace(self, snapshot);
+        self.events = events;
+        replaced
+    }
+
     /// Log a combat event
     pub fn log_event(&mut self, actor: impl Into<String>, event_type: CombatEventType, description: impl Into<String>) {
         self.events.push(CombatEvent {
@@ -438,6 +1567,7 @@ impl Default for CombatState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::session::economy::ACTIONS_PER_TURN;
 
     #[test]
     fn test_combatant_damage() {
@@ -521,4 +1651,244 @@ mod tests {
         assert_eq!(combat.current_turn, 0);
         assert_eq!(combat.current_combatant().unwrap().name, "Goblin");
     }
+
+    #[test]
+    fn test_surprised_combatants_skip_first_round() {
+        let mut combat = CombatState::new();
+        let goblin = Combatant::new("Goblin", 18, CombatantType::Monster);
+        let goblin_id = goblin.id.clone();
+        combat.add_combatant(goblin);
+        combat.add_combatant(Combatant::new("Fighter", 15, CombatantType::Player));
+        combat.add_combatant(Combatant::new("Wizard", 12, CombatantType::Player));
+
+        combat.set_surprised(&[goblin_id]);
+        assert_eq!(combat.current_combatant().unwrap().name, "Fighter");
+
+        assert_eq!(combat.next_turn().current_combatant.unwrap().name, "Wizard");
+
+        // Round 2: the goblin is no longer surprised
+        let result = combat.next_turn();
+        assert!(result.new_round);
+        assert_eq!(result.current_combatant.unwrap().name, "Goblin");
+        assert!(combat.combatants.iter().all(|c| !c.surprised));
+    }
+
+    #[test]
+    fn test_condition_expiry_and_concentration() {
+        use crate::core::session::conditions::AdvancedCondition;
+
+        let mut combat = CombatState::new();
+        let wizard = Combatant::new("Wizard", 18, CombatantType::Player);
+        let wizard_id = wizard.id.clone();
+        let ogre = Combatant::new("Ogre", 10, CombatantType::Monster);
+        let ogre_id = ogre.id.clone();
+        combat.add_combatant(wizard);
+        combat.add_combatant(ogre);
+
+        let (concentration, _) = combat
+            .start_concentration(&wizard_id, "Hold Person", ConditionDuration::Minutes(1))
+            .unwrap();
+        let mut held = AdvancedCondition::new("Held", "", ConditionDuration::Rounds(10)).sustained_by(&concentration.id);
+        held.source_id = Some(wizard_id.clone());
+        let mut marked = AdvancedCondition::new("Marked", "", ConditionDuration::EndOfSourceTurn);
+        marked.source_id = Some(wizard_id.clone());
+        let ogre_tracker = &mut combat.get_combatant_mut(&ogre_id).unwrap().condition_tracker;
+        ogre_tracker.add_condition(held).unwrap();
+        ogre_tracker.add_condition(marked).unwrap();
+
+        // The wizard's turn ends, and the mark they placed with it
+        let result = combat.next_turn();
+        assert_eq!(result.expired_conditions.len(), 1);
+        assert_eq!(result.expired_conditions[0].condition_name, "Marked");
+        assert_eq!(result.expired_conditions[0].reason, ExpiryReason::SourceTurnEnded);
+
+        let check = combat.concentration_check(&wizard_id, 24).unwrap();
+        assert!(check.message.contains("DC 12"));
+
+        // Breaking concentration drops the effect it sustains
+        let ended = combat.break_concentration(&wizard_id);
+        let reasons: Vec<_> = ended.iter().map(|e| (e.condition_name.as_str(), e.reason)).collect();
+        assert_eq!(
+            reasons,
+            vec![("Concentrating", ExpiryReason::ConcentrationBroken), ("Held", ExpiryReason::ConcentrationEnded)]
+        );
+        assert!(combat.get_combatant(&ogre_id).unwrap().condition_tracker.conditions().is_empty());
+        assert!(combat.concentration_check(&wizard_id, 5).is_none());
+    }
+
+    #[test]
+    fn test_dead_combatants_lose_their_turns() {
+        let mut combat = CombatState::new();
+        let mut goblin = Combatant::new("Goblin", 18, CombatantType::Monster);
+        goblin.max_hp = Some(7);
+        goblin.current_hp = Some(7);
+        let goblin_id = goblin.id.clone();
+        let mut fighter = Combatant::new("Fighter", 15, CombatantType::Player);
+        fighter.max_hp = Some(12);
+        fighter.current_hp = Some(12);
+        let fighter_id = fighter.id.clone();
+        combat.add_combatant(goblin);
+        combat.add_combatant(fighter);
+
+        let update = combat.damage(&goblin_id, 9, false).unwrap();
+        assert!(update.death.unwrap().died());
+        assert!(matches!(combat.events.last().unwrap().event_type, CombatEventType::Death));
+
+        // The dying fighter still takes turns to make death saves
+        combat.damage(&fighter_id, 12, false).unwrap();
+        assert_eq!(combat.next_turn().current_combatant.unwrap().name, "Fighter");
+        assert_eq!(combat.next_turn().current_combatant.unwrap().name, "Fighter");
+
+        // Healing brings them back, but not the goblin
+        assert!(combat.heal(&fighter_id, 5).unwrap().death.is_some());
+        assert_eq!(combat.heal(&goblin_id, 5).unwrap().hp, 0);
+        assert!(combat.roll_death_save(&fighter_id, 15).is_none());
+    }
+
+    #[test]
+    fn test_legendary_lair_and_reactions() {
+        use crate::core::session::actions::LairActions;
+
+        let mut combat = CombatState::new();
+        let dragon = Combatant::new("Dragon", 22, CombatantType::Monster);
+        let dragon_id = dragon.id.clone();
+        let rogue = Combatant::new("Rogue", 20, CombatantType::Player);
+        let rogue_id = rogue.id.clone();
+        combat.add_combatant(dragon);
+        combat.add_combatant(rogue);
+        combat.add_combatant(Combatant::new("Cleric", 8, CombatantType::Player));
+        combat.set_legendary_actions(&dragon_id, 3).unwrap();
+        combat.set_lair_actions(Some(LairActions::new(&dragon_id))).unwrap();
+
+        // The lair loses the tie with the rogue and acts before the cleric
+        assert!(combat.next_turn().lair_action.is_none());
+        combat.spend_legendary_action(&dragon_id, 2, Some("Wing Attack")).unwrap().unwrap();
+        let spend = combat.spend_legendary_action(&dragon_id, 1, None).unwrap().unwrap();
+        assert!(spend.warning.is_some());
+        assert!(combat.spend_legendary_action(&dragon_id, 1, None).unwrap().is_err());
+        assert_eq!(combat.next_turn().lair_action, Some(dragon_id.clone()));
+
+        combat.use_reaction(&rogue_id, Some("Uncanny Dodge")).unwrap().unwrap();
+        assert!(combat.use_reaction(&rogue_id, None).unwrap().is_err());
+
+        // The dragon's turn refills its pool; the lair waits for the next drop below 20
+        assert!(combat.next_turn().lair_action.is_none());
+        assert_eq!(combat.get_combatant(&dragon_id).unwrap().legendary_actions.unwrap().remaining, 3);
+        combat.next_turn();
+        assert!(!combat.get_combatant(&rogue_id).unwrap().reaction_used);
+        assert!(combat.next_turn().lair_action.is_some());
+    }
+
+    #[test]
+    fn test_three_action_economy_and_hero_points() {
+
+        let mut combat = CombatState::new();
+        combat.death_rules = DeathRules::Dying { max_dying: 4 };
+        let mut fighter = Combatant::new("Fighter", 20, CombatantType::Player);
+        fighter.current_hp = Some(10);
+        fighter.max_hp = Some(10);
+        let fighter_id = fighter.id.clone();
+        let goblin = Combatant::new("Goblin", 10, CombatantType::Monster);
+        let goblin_id = goblin.id.clone();
+        combat.add_combatant(fighter);
+        combat.add_combatant(goblin);
+        assert!(combat.get_combatant(&fighter_id).unwrap().turn_actions.is_none());
+        assert!(combat.spend_actions(&fighter_id, &Activity::attack(false)).unwrap().is_err());
+
+        combat.set_mode(CombatMode::ThreeAction);
+        assert_eq!(combat.hero_points(&fighter_id), Some(STARTING_HERO_POINTS));
+        assert_eq!(combat.hero_points(&goblin_id), Some(0));
+
+        combat.spend_actions(&fighter_id, &Activity::attack(false)).unwrap().unwrap();
+        let spend = combat.spend_actions(&fighter_id, &Activity::attack(false)).unwrap().unwrap();
+        assert_eq!((spend.attack_penalty, spend.remaining), (Some(-5), 1));
+        assert!(matches!(combat.events.last().unwrap().event_type, CombatEventType::Attack));
+
+        // The next turn around refills actions and clears the penalty
+        combat.next_turn();
+        combat.next_turn();
+        let turn = combat.get_combatant(&fighter_id).unwrap().turn_actions.unwrap();
+        assert_eq!((turn.remaining, turn.attacks), (ACTIONS_PER_TURN, 0));
+
+        combat.damage(&fighter_id, 10, false);
+        assert!(matches!(combat.spend_hero_point(&goblin_id), Some(Err(EconomyError::NoHeroPoints(_)))));
+        assert_eq!(combat.award_hero_points(&fighter_id, 5).unwrap().unwrap(), MAX_HERO_POINTS);
+        let update = combat.heroic_recovery(&fighter_id).unwrap().unwrap();
+        assert_eq!(update.track.state, LifeState::Stable);
+        assert_eq!(combat.hero_points(&fighter_id), Some(0));
+    }
+
+    #[test]
+    fn test_moving_on_a_grid() {
+        use crate::core::session::positioning::Zone;
+
+        let mut combat = CombatState::new();
+        let fighter = Combatant::new("Fighter", 20, CombatantType::Player);
+        let fighter_id = fighter.id.clone();
+        let goblin = Combatant::new("Goblin", 10, CombatantType::Monster);
+        let goblin_id = goblin.id.clone();
+        combat.add_combatant(fighter);
+        combat.add_combatant(goblin);
+        assert!(matches!(
+            combat.move_combatant(&fighter_id, Position::square(0, 0)),
+            Some(Err(PositionError::NoBattlefield))
+        ));
+
+        combat.set_battlefield(Some(Battlefield::grid(10, 10)));
+        let placed = combat.move_combatant(&fighter_id, Position::square(0, 0)).unwrap().unwrap();
+        assert_eq!(placed.distance_feet, None);
+        combat.move_combatant(&goblin_id, Position::square(4, 0)).unwrap().unwrap();
+        assert!(matches!(
+            combat.move_combatant(&fighter_id, Position::square(4, 0)),
+            Some(Err(PositionError::Occupied { .. }))
+        ));
+
+        let moved = combat.move_combatant(&fighter_id, Position::square(3, 1)).unwrap().unwrap();
+        assert_eq!(moved.distance_feet, Some(15));
+        let logged = combat.events.last().unwrap();
+        assert!(matches!(logged.event_type, CombatEventType::Movement));
+        assert_eq!(logged.description, "Fighter moves 15 ft to (3, 1)");
+        assert!(combat.check_range(&fighter_id, &goblin_id, AttackRange::reach(5)).unwrap().unwrap().in_range());
+
+        // Switching to zones clears grid positions
+        combat.set_battlefield(Some(Battlefield::zones(vec![Zone::new("hall", "Great Hall")])));
+        assert!(combat.combatants.iter().all(|c| c.position.is_none()));
+    }
+
+    #[test]
+    fn test_recharge_and_per_round_abilities() {
+        use crate::core::session::abilities::UsageLimit;
+
+        let mut combat = CombatState::new();
+        let mut dragon = Combatant::new("Dragon", 20, CombatantType::Monster);
+        dragon.abilities = vec![
+            TrackedAbility::new("Fire Breath", UsageLimit::Recharge { min: 5 }),
+            TrackedAbility::new("Tail Swipe", UsageLimit::PerRound { uses: 1 }),
+        ];
+        let dragon_id = dragon.id.clone();
+        combat.add_combatant(dragon);
+        combat.add_combatant(Combatant::new("Fighter", 10, CombatantType::Player));
+        assert_eq!(
+            CurrentCombatant::from(combat.current_combatant().cloned().unwrap()).ability_alerts,
+            vec!["Fire Breath available!".to_string(), "Tail Swipe ready (1/Round)".to_string()]
+        );
+
+        combat.use_ability(&dragon_id, "fire breath").unwrap().unwrap();
+        combat.use_ability(&dragon_id, "Tail Swipe").unwrap().unwrap();
+        assert!(matches!(combat.use_ability(&dragon_id, "Fire Breath"), Some(Err(AbilityError::Unavailable(_)))));
+        assert!(matches!(combat.use_ability(&dragon_id, "Bite"), Some(Err(AbilityError::UnknownAbility { .. }))));
+        assert!(combat.get_combatant(&dragon_id).unwrap().ability_alerts().is_empty());
+
+        // The dragon's next turn rolls recharge and the new round restores the swipe
+        assert!(combat.next_turn().recharges.is_empty());
+        let result = combat.next_turn();
+        assert_eq!(result.recharges.len(), 1);
+        let dragon = combat.get_combatant(&dragon_id).unwrap();
+        assert_eq!(dragon.abilities[0].is_available(), result.recharges[0].recharged);
+        assert!(dragon.abilities[1].is_available());
+        assert_eq!(combat.events.iter().filter(|e| e.description.contains("recharge")).count(), 1);
+
+        combat.restore_ability(&dragon_id, "Fire Breath").unwrap().unwrap();
+        assert!(combat.get_combatant(&dragon_id).unwrap().abilities[0].is_available());
+    }
 }
@@ -0,0 +1,305 @@
+//! Combat Report Module
+//!
+//! Tallies a fight as it happens (damage dealt and taken, healing, kills,
+//! and conditions applied) and turns the tally into a report when combat
+//! ends. The report compares how hard the fight was expected to be with how
+//! hard it turned out for the party, to help calibrate later encounters.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::combat::{CombatState, CombatantType};
+use super::damage::DamageBreakdown;
+use super::plan_types::EncounterDifficulty;
+
+/// Conditions listed in a report, most used first
+pub const TOP_CONDITIONS: usize = 5;
+
+// ============================================================================
+// Tally
+// ============================================================================
+
+/// Running totals for one combatant
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CombatantTally {
+    pub name: String,
+    pub damage_dealt: i32,
+    pub damage_taken: i32,
+    pub healing_received: i32,
+    /// Combatants this one dropped to 0 HP
+    pub kills: u32,
+    /// Times this combatant dropped to 0 HP, or members of its group dropped
+    pub times_dropped: u32,
+}
+
+/// Running totals for a fight, kept on the combat so undo rewinds them too
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CombatTally {
+    /// Totals by combatant ID
+    pub combatants: HashMap<String, CombatantTally>,
+    /// Times each condition was applied, by name
+    pub conditions: HashMap<String, u32>,
+}
+
+impl CombatTally {
+    fn entry(&mut self, combatant_id: &str, name: &str) -> &mut CombatantTally {
+        self.combatants
+            .entry(combatant_id.to_string())
+            .or_insert_with(|| CombatantTally { name: name.to_string(), ..Default::default() })
+    }
+
+    /// Count a hit against its target and, when known, its source
+    pub fn record_damage(&mut self, target: (&str, &str), source: Option<(&str, &str)>, breakdown: &DamageBreakdown) {
+        let dropped = breakdown.hp_before > 0 && breakdown.hp_after == 0;
+        let taken = self.entry(target.0, target.1);
+        taken.damage_taken += breakdown.effective;
+        if dropped {
+            taken.times_dropped += 1;
+        }
+        if let Some((source_id, source_name)) = source {
+            let dealt = self.entry(source_id, source_name);
+            dealt.damage_dealt += breakdown.effective;
+            if dropped {
+                dealt.kills += 1;
+            }
+        }
+    }
+
+    /// Count hits on a minion group, where each member dropped is a kill
+    pub fn record_group_damage(&mut self, target: (&str, &str), source: Option<(&str, &str)>, damage: i32, dropped: u32) {
+        let taken = self.entry(target.0, target.1);
+        taken.damage_taken += damage;
+        taken.times_dropped += dropped;
+        if let Some((source_id, source_name)) = source {
+            let dealt = self.entry(source_id, source_name);
+            dealt.damage_dealt += damage;
+            dealt.kills += dropped;
+        }
+    }
+
+    pub fn record_healing(&mut self, combatant_id: &str, name: &str, amount: i32) {
+        self.entry(combatant_id, name).healing_received += amount.max(0);
+    }
+
+    pub fn record_condition(&mut self, condition: &str) {
+        *self.conditions.entry(condition.to_lowercase()).or_insert(0) += 1;
+    }
+}
+
+// ============================================================================
+// Difficulty
+// ============================================================================
+
+fn difficulty_rank(difficulty: &EncounterDifficulty) -> u8 {
+    match difficulty {
+        EncounterDifficulty::Trivial => 0,
+        EncounterDifficulty::Easy => 1,
+        EncounterDifficulty::Medium => 2,
+        EncounterDifficulty::Hard => 3,
+        EncounterDifficulty::Deadly | EncounterDifficulty::Boss => 4,
+    }
+}
+
+/// How hard a fight was for the party, from the share of their hit points
+/// it cost. A player dropping makes it at least Hard; a player dying makes
+/// it Deadly.
+pub fn actual_difficulty(party_hp_share: f32, player_dropped: bool, player_died: bool) -> EncounterDifficulty {
+    let by_hp = match party_hp_share {
+        s if s < 0.1 => EncounterDifficulty::Trivial,
+        s if s < 0.25 => EncounterDifficulty::Easy,
+        s if s < 0.5 => EncounterDifficulty::Medium,
+        s if s < 0.75 => EncounterDifficulty::Hard,
+        _ => EncounterDifficulty::Deadly,
+    };
+    if player_died {
+        EncounterDifficulty::Deadly
+    } else if player_dropped && difficulty_rank(&by_hp) < difficulty_rank(&EncounterDifficulty::Hard) {
+        EncounterDifficulty::Hard
+    } else {
+        by_hp
+    }
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// One combatant's part in a fight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatantReport {
+    pub combatant_id: String,
+    pub name: String,
+    pub combatant_type: CombatantType,
+    pub damage_dealt: i32,
+    pub damage_taken: i32,
+    pub healing_received: i32,
+    pub kills: u32,
+    pub times_dropped: u32,
+    pub hp_remaining: Option<i32>,
+    pub max_hp: Option<i32>,
+    /// At 0 HP or dead when the fight ended
+    pub defeated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConditionCount {
+    pub condition: String,
+    pub count: u32,
+}
+
+/// What happened in a fight, stored with the session once combat ends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatReport {
+    pub combat_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub rounds: u32,
+    pub combatants: Vec<CombatantReport>,
+    pub total_damage: i32,
+    pub total_healing: i32,
+    pub total_kills: u32,
+    pub top_conditions: Vec<ConditionCount>,
+    /// Share of the party's hit points the fight cost
+    pub party_hp_share: f32,
+    /// Difficulty the encounter was rated at before the fight
+    pub estimated_difficulty: Option<EncounterDifficulty>,
+    pub actual_difficulty: EncounterDifficulty,
+    /// How the estimate held up, such as "Played harder than estimated
+    /// (Medium, played Hard)"
+    pub calibration: Option<String>,
+}
+
+impl CombatReport {
+    pub fn from_combat(combat: &CombatState) -> Self {
+        let tally = &combat.tally;
+        let combatants: Vec<CombatantReport> = combat
+            .combatants
+            .iter()
+            .map(|c| {
+                let totals = tally.combatants.get(&c.id).cloned().unwrap_or_default();
+                CombatantReport {
+                    combatant_id: c.id.clone(),
+                    name: c.name.clone(),
+                    combatant_type: c.combatant_type.clone(),
+                    damage_dealt: totals.damage_dealt,
+                    damage_taken: totals.damage_taken,
+                    healing_received: totals.healing_received,
+                    kills: totals.kills,
+                    times_dropped: totals.times_dropped,
+                    hp_remaining: c.current_hp,
+                    max_hp: c.max_hp,
+                    defeated: c.death.is_dead() || c.current_hp == Some(0),
+                }
+            })
+            .collect();
+
+        let party: Vec<_> = combat
+            .combatants
+            .iter()
+            .filter(|c| c.combatant_type == CombatantType::Player)
+            .collect();
+        let party_max_hp: i32 = party.iter().filter_map(|c| c.max_hp).sum();
+        let party_taken: i32 = party
+            .iter()
+            .filter_map(|c| tally.combatants.get(&c.id))
+            .map(|t| t.damage_taken)
+            .sum();
+        let party_hp_share = if party_max_hp > 0 { party_taken as f32 / party_max_hp as f32 } else { 0.0 };
+        let player_dropped = party.iter().any(|c| tally.combatants.get(&c.id).is_some_and(|t| t.times_dropped > 0));
+        let player_died = party.iter().any(|c| c.death.is_dead());
+        let actual = actual_difficulty(party_hp_share, player_dropped, player_died);
+
+        let mut top_conditions: Vec<ConditionCount> = tally
+            .conditions
+            .iter()
+            .map(|(condition, count)| ConditionCount { condition: condition.clone(), count: *count })
+            .collect();
+        top_conditions.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.condition.cmp(&b.condition)));
+        top_conditions.truncate(TOP_CONDITIONS);
+
+        let calibration = combat.estimated_difficulty.as_ref().map(|estimated| {
+            let (estimated_rank, actual_rank) = (difficulty_rank(estimated), difficulty_rank(&actual));
+            let verdict = match estimated_rank.cmp(&actual_rank) {
+                std::cmp::Ordering::Less => "Played harder than estimated",
+                std::cmp::Ordering::Greater => "Played easier than estimated",
+                std::cmp::Ordering::Equal => "Played as estimated",
+            };
+            format!("{} ({}, played {})", verdict, estimated.display_name(), actual.display_name())
+        });
+
+        Self {
+            combat_id: combat.id.clone(),
+            started_at: combat.started_at,
+            ended_at: Utc::now(),
+            rounds: combat.round,
+            total_damage: tally.combatants.values().map(|t| t.damage_taken).sum(),
+            total_healing: tally.combatants.values().map(|t| t.healing_received).sum(),
+            total_kills: tally.combatants.values().map(|t| t.times_dropped).sum(),
+            combatants,
+            top_conditions,
+            party_hp_share,
+            estimated_difficulty: combat.estimated_difficulty.clone(),
+            actual_difficulty: actual,
+            calibration,
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::Combatant;
+    use crate::core::session::damage::Damage;
+
+    fn fighter(name: &str, hp: i32, combatant_type: CombatantType) -> Combatant {
+        let mut combatant = Combatant::new(name, 10, combatant_type);
+        combatant.current_hp = Some(hp);
+        combatant.max_hp = Some(hp);
+        combatant
+    }
+
+    #[test]
+    fn test_actual_difficulty() {
+        assert_eq!(actual_difficulty(0.05, false, false), EncounterDifficulty::Trivial);
+        assert_eq!(actual_difficulty(0.3, false, false), EncounterDifficulty::Medium);
+        assert_eq!(actual_difficulty(0.3, true, false), EncounterDifficulty::Hard);
+        assert_eq!(actual_difficulty(0.6, true, false), EncounterDifficulty::Hard);
+        assert_eq!(actual_difficulty(0.1, false, true), EncounterDifficulty::Deadly);
+        assert_eq!(actual_difficulty(0.9, false, false), EncounterDifficulty::Deadly);
+    }
+
+    #[test]
+    fn test_report_from_tally() {
+        let mut combat = CombatState::new();
+        let paladin = fighter("Paladin", 40, CombatantType::Player);
+        let goblin = fighter("Goblin", 7, CombatantType::Monster);
+        let (paladin_id, goblin_id) = (paladin.id.clone(), goblin.id.clone());
+        combat.add_combatant(paladin);
+        combat.add_combatant(goblin);
+        combat.estimated_difficulty = Some(EncounterDifficulty::Trivial);
+
+        combat.take_damage(&paladin_id, &Damage::new(12).by(&goblin_id));
+        combat.heal(&paladin_id, 5);
+        combat.take_damage(&goblin_id, &Damage::new(9).by(&paladin_id));
+        combat.tally.record_condition("Prone");
+        combat.tally.record_condition("prone");
+        combat.tally.record_condition("Frightened");
+
+        let report = CombatReport::from_combat(&combat);
+        let paladin = report.combatants.iter().find(|c| c.name == "Paladin").unwrap();
+        assert_eq!((paladin.damage_dealt, paladin.damage_taken, paladin.healing_received), (9, 12, 5));
+        assert_eq!(paladin.kills, 1);
+        let goblin = report.combatants.iter().find(|c| c.name == "Goblin").unwrap();
+        assert!(goblin.defeated);
+        assert_eq!((report.total_damage, report.total_healing, report.total_kills), (21, 5, 1));
+        assert_eq!(report.top_conditions[0], ConditionCount { condition: "prone".to_string(), count: 2 });
+        assert!((report.party_hp_share - 0.3).abs() < f32::EPSILON);
+        assert_eq!(report.actual_difficulty, EncounterDifficulty::Medium);
+        assert_eq!(report.calibration.as_deref(), Some("Played harder than estimated (Trivial, played Medium)"));
+    }
+}
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::collections::HashMap;
 
+/// Tag marking a combatant's concentration condition
+pub const CONCENTRATION_TAG: &str = "concentration";
+
 // ============================================================================
 // Duration Types
 // ============================================================================
@@ -271,6 +274,13 @@ pub struct AdvancedCondition {
     pub save_attempts: u32,
     /// Additional metadata
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Concentration condition that sustains this one; it ends when
+    /// concentration does
+    #[serde(default)]
+    pub concentration_id: Option<String>,
+    /// Combat time not yet counted against a minute or hour duration
+    #[serde(default)]
+    pub pending_seconds: u32,
 }
 
 impl AdvancedCondition {
@@ -299,6 +309,8 @@ impl AdvancedCondition {
             notes: String::new(),
             save_attempts: 0,
             metadata: HashMap::new(),
+            concentration_id: None,
+            pending_seconds: 0,
         }
     }
 
@@ -345,6 +357,17 @@ impl AdvancedCondition {
         self
     }
 
+    /// Builder: end this condition when a concentration condition ends
+    pub fn sustained_by(mut self, concentration_id: impl Into<String>) -> Self {
+        self.concentration_id = Some(concentration_id.into());
+        self
+    }
+
+    /// Whether this is a combatant's concentration
+    pub fn is_concentration(&self) -> bool {
+        self.tags.iter().any(|t| t == CONCENTRATION_TAG)
+    }
+
     /// Tick the condition at end of turn
     /// Returns true if the condition should be removed
     pub fn tick_end_of_turn(&mut self, is_own_turn: bool) -> bool {
@@ -417,6 +440,62 @@ impl AdvancedCondition {
         }
     }
 
+    /// Tick minute and hour durations for combat time passing (in seconds)
+    /// Returns true if the condition should be removed
+    pub fn tick_seconds(&mut self, seconds: u32) -> bool {
+        let unit = match &self.duration {
+            ConditionDuration::Minutes(_) => 60,
+            ConditionDuration::Hours(_) => 3600,
+            _ => return false,
+        };
+        self.pending_seconds += seconds;
+        while self.pending_seconds >= unit {
+            self.pending_seconds -= unit;
+            if let Some(ref mut remaining) = self.remaining {
+                if *remaining <= 1 {
+                    return true;
+                }
+                *remaining -= 1;
+            }
+        }
+        false
+    }
+
+    /// Things to call out when the affected creature's turn starts: saves,
+    /// recurring damage or healing, and the condition ending this turn
+    pub fn turn_reminders(&self) -> Vec<String> {
+        let mut reminders = Vec::new();
+        if let ConditionDuration::UntilSave { save_type, dc, timing } = &self.duration {
+            let when = match timing {
+                SaveTiming::StartOfTurn => "now",
+                SaveTiming::EndOfTurn => "at the end of the turn",
+                SaveTiming::OnDamage => "when damaged",
+                SaveTiming::OnAction => "as an action",
+            };
+            reminders.push(format!("DC {} {} save {} to end {}", dc, save_type, when, self.name));
+        }
+        for effect in &self.effects {
+            match effect {
+                ConditionEffect::RecurringDamage { dice, damage_type, timing } => {
+                    reminders.push(format!("{}: take {} {} damage ({:?})", self.name, dice, damage_type, timing));
+                }
+                ConditionEffect::RecurringHealing { dice, timing } => {
+                    reminders.push(format!("{}: regain {} HP ({:?})", self.name, dice, timing));
+                }
+                _ => {}
+            }
+        }
+        let ends_this_turn = match &self.duration {
+            ConditionDuration::EndOfNextTurn => true,
+            ConditionDuration::Turns(_) => self.remaining == Some(1),
+            _ => false,
+        };
+        if ends_this_turn {
+            reminders.push(format!("{} ends at the end of this turn", self.name));
+        }
+        reminders
+    }
+
     /// Attempt a saving throw against this condition
     /// Returns true if the save succeeded (condition should be removed if UntilSave)
     pub fn attempt_save(&mut self, roll: i32) -> bool {
@@ -609,6 +688,60 @@ impl ConditionTracker {
         expired
     }
 
+    /// Tick minute and hour durations for combat time passing
+    /// Returns list of expired conditions
+    pub fn tick_seconds(&mut self, seconds: u32) -> Vec<AdvancedCondition> {
+        let mut expired = Vec::new();
+        self.conditions.retain_mut(|c| {
+            if c.tick_seconds(seconds) {
+                expired.push(c.clone());
+                false
+            } else {
+                true
+            }
+        });
+        expired
+    }
+
+    /// Remove conditions lasting until the end of a source's turn
+    pub fn expire_source_turn(&mut self, source_id: &str) -> Vec<AdvancedCondition> {
+        self.remove_where(|c| {
+            c.duration == ConditionDuration::EndOfSourceTurn && c.source_id.as_deref() == Some(source_id)
+        })
+    }
+
+    /// Remove conditions sustained by any of the given concentration conditions
+    pub fn remove_sustained_by(&mut self, concentration_ids: &[String]) -> Vec<AdvancedCondition> {
+        self.remove_where(|c| {
+            c.concentration_id
+                .as_ref()
+                .is_some_and(|id| concentration_ids.contains(id))
+        })
+    }
+
+    /// Remove the concentration condition, if any
+    pub fn remove_concentration(&mut self) -> Vec<AdvancedCondition> {
+        self.remove_where(|c| c.is_concentration())
+    }
+
+    fn remove_where(&mut self, predicate: impl Fn(&AdvancedCondition) -> bool) -> Vec<AdvancedCondition> {
+        let mut removed = Vec::new();
+        self.conditions.retain(|c| {
+            if predicate(c) {
+                removed.push(c.clone());
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    /// The active concentration condition
+    pub fn concentration(&self) -> Option<&AdvancedCondition> {
+        self.conditions.iter().find(|c| c.is_concentration())
+    }
+
     /// Get all active conditions
     pub fn conditions(&self) -> &[AdvancedCondition] {
         &self.conditions
@@ -857,7 +990,23 @@ impl ConditionTemplates {
         .with_effect(ConditionEffect::Custom { description: "CON save (DC 10 or half damage) on taking damage".to_string() })
         .with_icon("target")
         .with_color("#3b82f6")
-        .with_tags(["magical", "concentration"])
+        .with_tags(["magical", CONCENTRATION_TAG])
+    }
+
+    /// Concentration on a named effect, lasting at most `duration`
+    pub fn concentrating_on(effect: &str, duration: ConditionDuration) -> AdvancedCondition {
+        let mut condition = Self::concentrating();
+        condition.description = format!("Concentrating on {}. CON save on damage or lose concentration.", effect);
+        condition.remaining = match &duration {
+            ConditionDuration::Turns(n)
+            | ConditionDuration::Rounds(n)
+            | ConditionDuration::Minutes(n)
+            | ConditionDuration::Hours(n) => Some(*n),
+            _ => None,
+        };
+        condition.duration = duration;
+        condition.metadata.insert("effect".to_string(), serde_json::json!(effect));
+        condition.with_stacking(StackingRule::Latest)
     }
 
     pub fn exhaustion(level: u32) -> AdvancedCondition {
@@ -1010,4 +1159,52 @@ mod tests {
         let result = tracker.add_condition(exhaustion2);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_minute_durations_tick_with_combat_time() {
+        let mut condition = AdvancedCondition::new("Blessed", "", ConditionDuration::Minutes(1));
+        for _ in 0..9 {
+            assert!(!condition.tick_seconds(6));
+        }
+        assert_eq!(condition.remaining, Some(1));
+        assert!(condition.tick_seconds(6)); // ten rounds make a minute
+
+        let mut hour = AdvancedCondition::new("Warded", "", ConditionDuration::Hours(2));
+        assert!(!hour.tick_seconds(3600));
+        assert_eq!(hour.remaining, Some(1));
+        assert!(!hour.tick_seconds(3599));
+        assert!(hour.tick_seconds(1));
+
+        let mut rounds = AdvancedCondition::new("Slowed", "", ConditionDuration::Rounds(1));
+        assert!(!rounds.tick_seconds(600));
+    }
+
+    #[test]
+    fn test_concentration_and_reminders() {
+        let mut tracker = ConditionTracker::new();
+        let concentration = ConditionTemplates::concentrating_on("Bless", ConditionDuration::Minutes(1));
+        assert_eq!(concentration.remaining, Some(1));
+        let concentration_id = concentration.id.clone();
+        tracker.add_condition(concentration).unwrap();
+        tracker
+            .add_condition(AdvancedCondition::new("Blessed", "", ConditionDuration::Minutes(1)).sustained_by(&concentration_id))
+            .unwrap();
+        tracker.add_condition(ConditionTemplates::prone()).unwrap();
+
+        assert_eq!(tracker.concentration().unwrap().id, concentration_id);
+        let dropped = tracker.remove_concentration();
+        assert_eq!(dropped.len(), 1);
+        let ended = tracker.remove_sustained_by(&[concentration_id]);
+        assert_eq!(ended[0].name, "Blessed");
+        assert_eq!(tracker.conditions().len(), 1);
+
+        let held = AdvancedCondition::new(
+            "Held",
+            "",
+            ConditionDuration::UntilSave { save_type: "WIS".to_string(), dc: 13, timing: SaveTiming::EndOfTurn },
+        );
+        assert_eq!(held.turn_reminders(), vec!["DC 13 WIS save at the end of the turn to end Held".to_string()]);
+        let dazed = AdvancedCondition::new("Dazed", "", ConditionDuration::EndOfNextTurn);
+        assert_eq!(dazed.turn_reminders().len(), 1);
+    }
 }
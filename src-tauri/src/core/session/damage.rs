@@ -0,0 +1,425 @@
+//! Damage Module
+//!
+//! Typed damage and the defenses that change it. A combatant's resistances,
+//! immunities, and vulnerabilities adjust each hit by its damage type before
+//! temporary hit points soak it up, and every hit reports a breakdown of how
+//! the rolled damage became lost hit points.
+//!
+//! Area damage, as from a fireball or a dragon's breath, is rolled once,
+//! halved for each target that made its save, then adjusted per target.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+use super::combat::{CombatState, HealthUpdate};
+use super::death::DeathUpdate;
+use crate::core::campaign::dice::{DiceNotation, DiceRoller};
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum DamageError {
+    #[error("Invalid damage expression: {0}")]
+    InvalidExpression(String),
+
+    #[error("No targets given for area damage")]
+    NoTargets,
+}
+
+pub type Result<T> = std::result::Result<T, DamageError>;
+
+// ============================================================================
+// Damage Rolls
+// ============================================================================
+
+/// Roll a damage expression: dice notation such as `8d6` or `2d10 + 4`, or
+/// a flat number
+pub fn roll_damage<R: Rng>(expression: &str, rng: &mut R) -> Result<i32> {
+    let expression = expression.replace(' ', "");
+    if let Ok(flat) = expression.parse::<i32>() {
+        return if flat < 0 { Err(DamageError::InvalidExpression(expression)) } else { Ok(flat) };
+    }
+    let dice = DiceNotation::parse(&expression).map_err(|_| DamageError::InvalidExpression(expression))?;
+    Ok(DiceRoller::new().roll_with_rng(&dice, rng).total.max(0))
+}
+
+// ============================================================================
+// Damage Defenses
+// ============================================================================
+
+/// Damage types a combatant resists, ignores, or is vulnerable to, written
+/// as on a stat block (e.g., "fire", "bludgeoning, piercing, and slashing
+/// from nonmagical attacks")
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DamageDefenses {
+    #[serde(default)]
+    pub resistances: Vec<String>,
+    #[serde(default)]
+    pub immunities: Vec<String>,
+    #[serde(default)]
+    pub vulnerabilities: Vec<String>,
+}
+
+impl DamageDefenses {
+    pub fn is_empty(&self) -> bool {
+        self.resistances.is_empty() && self.immunities.is_empty() && self.vulnerabilities.is_empty()
+    }
+
+    /// How these defenses change damage of one type. Entries limited to
+    /// nonmagical attacks don't apply to magical damage.
+    pub fn adjustment(&self, damage_type: &str, magical: bool) -> DamageAdjustment {
+        let matches = |entries: &[String]| entries.iter().any(|e| entry_covers(e, damage_type, magical));
+        DamageAdjustment {
+            immune: matches(&self.immunities),
+            resistant: matches(&self.resistances),
+            vulnerable: matches(&self.vulnerabilities),
+        }
+    }
+}
+
+fn entry_covers(entry: &str, damage_type: &str, magical: bool) -> bool {
+    let entry = entry.to_lowercase();
+    let damage_type = damage_type.trim().to_lowercase();
+    if magical && entry.contains("nonmagical") {
+        return false;
+    }
+    entry
+        .split(|c: char| !c.is_alphabetic())
+        .any(|word| word == damage_type)
+}
+
+/// Which defenses applied to a hit
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DamageAdjustment {
+    pub immune: bool,
+    pub resistant: bool,
+    pub vulnerable: bool,
+}
+
+impl DamageAdjustment {
+    /// Immunity takes everything; resistance halves, rounding down, before
+    /// vulnerability doubles
+    pub fn apply(&self, amount: i32) -> i32 {
+        if self.immune {
+            return 0;
+        }
+        let amount = if self.resistant { amount / 2 } else { amount };
+        if self.vulnerable { amount * 2 } else { amount }
+    }
+
+    /// Short note for the combat log, or None when nothing applied
+    pub fn label(&self) -> Option<&'static str> {
+        match (self.immune, self.resistant, self.vulnerable) {
+            (true, _, _) => Some("immune"),
+            (false, true, true) => Some("resisted and vulnerable"),
+            (false, true, false) => Some("resisted"),
+            (false, false, true) => Some("vulnerable"),
+            (false, false, false) => None,
+        }
+    }
+}
+
+// ============================================================================
+// Typed Damage
+// ============================================================================
+
+/// One hit of damage before the target's defenses
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Damage {
+    pub amount: i32,
+    #[serde(default)]
+    pub damage_type: Option<String>,
+    /// Magical damage gets past resistances to nonmagical attacks
+    #[serde(default)]
+    pub magical: bool,
+    #[serde(default)]
+    pub critical: bool,
+    /// The combatant dealing the damage, credited in the combat report
+    #[serde(default)]
+    pub source_id: Option<String>,
+}
+
+impl Damage {
+    pub fn new(amount: i32) -> Self {
+        Self {
+            amount,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_type(mut self, damage_type: impl Into<String>) -> Self {
+        self.damage_type = Some(damage_type.into());
+        self
+    }
+
+    pub fn magical(mut self, magical: bool) -> Self {
+        self.magical = magical;
+        self
+    }
+
+    pub fn critical(mut self, critical: bool) -> Self {
+        self.critical = critical;
+        self
+    }
+
+    pub fn by(mut self, source_id: impl Into<String>) -> Self {
+        self.source_id = Some(source_id.into());
+        self
+    }
+}
+
+/// How a hit of damage became lost hit points
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DamageBreakdown {
+    /// Damage dealt before defenses
+    pub raw: i32,
+    pub damage_type: Option<String>,
+    pub adjustment: DamageAdjustment,
+    /// Damage after immunity, resistance, and vulnerability
+    pub effective: i32,
+    /// Part of the effective damage soaked up by temporary hit points
+    pub absorbed: i32,
+    pub hp_before: i32,
+    pub hp_after: i32,
+    pub temp_hp_after: i32,
+    pub death: Option<DeathUpdate>,
+}
+
+impl DamageBreakdown {
+    /// Hit points lost after temporary hit points
+    pub fn hp_lost(&self) -> i32 {
+        self.hp_before - self.hp_after
+    }
+
+    pub fn health_update(&self) -> HealthUpdate {
+        HealthUpdate {
+            hp: self.hp_after,
+            death: self.death.clone(),
+        }
+    }
+
+    /// Combat log line for the hit
+    pub fn describe(&self, name: &str) -> String {
+        let damage_type = self.damage_type.as_deref().map(|t| format!(" {}", t)).unwrap_or_default();
+        let mut description = format!("{} takes {}{} damage", name, self.effective, damage_type);
+        if let Some(label) = self.adjustment.label() {
+            description.push_str(&format!(" ({}, {} dealt)", label, self.raw));
+        }
+        if self.absorbed > 0 {
+            description.push_str(&format!(", {} absorbed by temporary HP", self.absorbed));
+        }
+        description
+    }
+}
+
+// ============================================================================
+// Area Damage
+// ============================================================================
+
+/// Damage dealt to every target of an area effect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AreaDamage {
+    /// Dice notation or a flat amount
+    pub expression: String,
+    pub damage_type: Option<String>,
+    /// Magical damage gets past resistances to nonmagical attacks
+    #[serde(default)]
+    pub magical: bool,
+    /// The combatant whose effect it is, credited in the combat report
+    #[serde(default)]
+    pub source_id: Option<String>,
+}
+
+impl AreaDamage {
+    pub fn new(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            damage_type: None,
+            magical: false,
+            source_id: None,
+        }
+    }
+
+    pub fn with_type(mut self, damage_type: impl Into<String>) -> Self {
+        self.damage_type = Some(damage_type.into());
+        self
+    }
+
+    pub fn magical(mut self, magical: bool) -> Self {
+        self.magical = magical;
+        self
+    }
+
+    /// The hit one target takes from the rolled amount
+    fn for_target(&self, rolled: i32, saved: bool) -> Damage {
+        Damage {
+            amount: if saved { rolled / 2 } else { rolled },
+            damage_type: self.damage_type.clone(),
+            magical: self.magical,
+            critical: false,
+            source_id: self.source_id.clone(),
+        }
+    }
+}
+
+/// What one target took from area damage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetDamage {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub saved: bool,
+    pub breakdown: DamageBreakdown,
+}
+
+/// The outcome of area damage across all its targets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AreaDamageResult {
+    pub rolled: i32,
+    pub damage_type: Option<String>,
+    pub targets: Vec<TargetDamage>,
+}
+
+/// Apply rolled area damage to each target, halving it for those whose save
+/// succeeded. Targets missing from the combat are skipped.
+pub fn apply_area_damage(
+    combat: &mut CombatState,
+    targets: &[String],
+    damage: &AreaDamage,
+    rolled: i32,
+    saves: &HashMap<String, bool>,
+) -> AreaDamageResult {
+    let targets = targets
+        .iter()
+        .filter_map(|id| {
+            let combatant_name = combat.get_combatant(id)?.name.clone();
+            let saved = saves.get(id).copied().unwrap_or(false);
+            let breakdown = combat.take_damage(id, &damage.for_target(rolled, saved))?;
+            Some(TargetDamage {
+                combatant_id: id.clone(),
+                combatant_name,
+                saved,
+                breakdown,
+            })
+        })
+        .collect();
+
+    AreaDamageResult {
+        rolled,
+        damage_type: damage.damage_type.clone(),
+        targets,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::{Combatant, CombatantType};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn defenses(resistances: &[&str], immunities: &[&str], vulnerabilities: &[&str]) -> DamageDefenses {
+        let list = |entries: &[&str]| entries.iter().map(|e| e.to_string()).collect();
+        DamageDefenses {
+            resistances: list(resistances),
+            immunities: list(immunities),
+            vulnerabilities: list(vulnerabilities),
+        }
+    }
+
+    fn combatant(name: &str, hp: i32, defenses: DamageDefenses) -> Combatant {
+        let mut combatant = Combatant::new(name, 10, CombatantType::Monster);
+        combatant.current_hp = Some(hp);
+        combatant.max_hp = Some(hp);
+        combatant.damage_defenses = defenses;
+        combatant
+    }
+
+    #[test]
+    fn test_roll_damage() {
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(roll_damage("28", &mut rng).unwrap(), 28);
+        for _ in 0..20 {
+            let rolled = roll_damage("8d6", &mut rng).unwrap();
+            assert!((8..=48).contains(&rolled));
+        }
+        assert!((6..=24).contains(&roll_damage("2d10 + 4", &mut rng).unwrap()));
+        assert!(matches!(roll_damage("fire", &mut rng), Err(DamageError::InvalidExpression(_))));
+        assert!(roll_damage("-3", &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_damage_adjustments() {
+        let skeleton = defenses(&[], &["poison"], &["bludgeoning"]);
+        assert_eq!(skeleton.adjustment("Poison", false).apply(20), 0);
+        assert_eq!(skeleton.adjustment("bludgeoning", false).apply(7), 14);
+        assert_eq!(skeleton.adjustment("fire", false).apply(7), 7);
+
+        let golem = defenses(&["bludgeoning, piercing, and slashing from nonmagical attacks", "fire"], &[], &[]);
+        assert_eq!(golem.adjustment("slashing", false).apply(9), 4);
+        assert_eq!(golem.adjustment("slashing", true).apply(9), 9);
+        assert_eq!(golem.adjustment("fire", true).apply(9), 4);
+
+        let both = DamageAdjustment { immune: false, resistant: true, vulnerable: true };
+        assert_eq!(both.apply(9), 8);
+        assert_eq!(both.label(), Some("resisted and vulnerable"));
+    }
+
+    #[test]
+    fn test_breakdown_with_temp_hp() {
+        let mut combat = CombatState::new();
+        let mut knight = combatant("Knight", 30, defenses(&["fire"], &[], &[]));
+        knight.temp_hp = Some(4);
+        let id = knight.id.clone();
+        combat.add_combatant(knight);
+
+        let breakdown = combat.take_damage(&id, &Damage::new(21).with_type("Fire")).unwrap();
+        assert_eq!((breakdown.raw, breakdown.effective, breakdown.absorbed), (21, 10, 4));
+        assert_eq!((breakdown.hp_before, breakdown.hp_after, breakdown.temp_hp_after), (30, 24, 0));
+        assert_eq!(breakdown.hp_lost(), 6);
+        assert_eq!(
+            combat.events.last().unwrap().description,
+            "Knight takes 10 Fire damage (resisted, 21 dealt), 4 absorbed by temporary HP"
+        );
+
+        // Untyped damage ignores defenses
+        let breakdown = combat.take_damage(&id, &Damage::new(5)).unwrap();
+        assert_eq!((breakdown.effective, breakdown.hp_after), (5, 19));
+        assert_eq!(combat.events.last().unwrap().description, "Knight takes 5 damage");
+    }
+
+    #[test]
+    fn test_area_damage_saves_and_resistances() {
+        let mut combat = CombatState::new();
+        let mut ids = Vec::new();
+        for combatant in [
+            combatant("Goblin", 30, DamageDefenses::default()),
+            combatant("Fire Elemental", 30, defenses(&[], &["fire"], &[])),
+            combatant("Knight", 30, defenses(&["fire"], &[], &[])),
+        ] {
+            ids.push(combatant.id.clone());
+            combat.add_combatant(combatant);
+        }
+        ids.push("missing".to_string());
+        let saves = HashMap::from([(ids[2].clone(), true)]);
+
+        let fireball = AreaDamage::new("8d6").with_type("fire").magical(true);
+        let result = apply_area_damage(&mut combat, &ids, &fireball, 25, &saves);
+        let damage: Vec<_> = result
+            .targets
+            .iter()
+            .map(|t| (t.combatant_name.as_str(), t.breakdown.effective))
+            .collect();
+        assert_eq!(damage, vec![("Goblin", 25), ("Fire Elemental", 0), ("Knight", 6)]);
+        assert!(result.targets[2].saved && result.targets[2].breakdown.adjustment.resistant);
+        assert_eq!(combat.get_combatant(&ids[0]).unwrap().current_hp, Some(5));
+        assert_eq!(combat.get_combatant(&ids[2]).unwrap().current_hp, Some(24));
+    }
+}
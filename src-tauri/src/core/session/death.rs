@@ -0,0 +1,432 @@
+//! Death and Dying Module
+//!
+//! Per-system rules for what happens at 0 HP: D&D 5e death saving throws,
+//! Pathfinder 2e dying and wounded values, instant death from massive damage,
+//! and stabilization. Monsters and NPCs die outright at 0 HP; the dying rules
+//! apply to players and allies.
+
+use serde::{Deserialize, Serialize};
+
+use super::combat::{Combatant, CombatantType};
+use crate::core::character_gen::GameSystem;
+
+/// Death save successes that stabilize, and failures that kill (D&D 5e)
+const DEATH_SAVES_NEEDED: u32 = 3;
+
+/// Death save DC (D&D 5e)
+const DEATH_SAVE_DC: i32 = 10;
+
+// ============================================================================
+// Death Rules
+// ============================================================================
+
+/// How a game system handles combatants reduced to 0 HP
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeathRules {
+    /// D&D 5e: three death save successes stabilize, three failures kill.
+    /// Damage left over after 0 HP that equals max HP kills outright.
+    #[default]
+    DeathSaves,
+    /// Pathfinder 2e: reaching 0 HP gives dying 1 + wounded (2 + wounded on
+    /// a critical hit), death comes at `max_dying` minus doomed. A single
+    /// hit of twice max HP kills outright.
+    Dying { max_dying: u32 },
+    /// No dying track; the combatant goes down at 0 HP and the GM decides
+    Manual,
+}
+
+impl DeathRules {
+    /// The rules a game system uses, falling back to death saves for d20
+    /// systems and GM rulings for everything else
+    pub fn for_system(system: &str) -> Self {
+        match GameSystem::from_str(system) {
+            GameSystem::Pathfinder2e => Self::Dying { max_dying: 4 },
+            GameSystem::DnD5e | GameSystem::Custom(_) => Self::DeathSaves,
+            _ => Self::Manual,
+        }
+    }
+
+    /// Apply the rules after a combatant took `damage` to their hit points
+    /// (after temp HP), having had `hp_before`
+    pub fn on_damage(&self, combatant: &mut Combatant, hp_before: i32, damage: i32, critical: bool) -> Option<DeathUpdate> {
+        let max_hp = combatant.max_hp?;
+        if damage <= 0 || combatant.current_hp? > 0 || combatant.death.state == LifeState::Dead {
+            return None;
+        }
+        let previous = combatant.death.state;
+        let name = combatant.name.clone();
+        let track = &mut combatant.death;
+
+        let massive = match self {
+            Self::DeathSaves => damage - hp_before >= max_hp,
+            Self::Dying { .. } => damage >= max_hp * 2,
+            Self::Manual => false,
+        };
+        if massive {
+            track.state = LifeState::Dead;
+            return Some(DeathUpdate::new(previous, track, format!("{} is killed outright by massive damage", name)));
+        }
+        if !tracks_dying(&combatant.combatant_type) {
+            track.state = LifeState::Dead;
+            return Some(DeathUpdate::new(previous, track, format!("{} dies", name)));
+        }
+
+        let message = match (self, previous) {
+            (Self::DeathSaves, LifeState::Conscious) => {
+                track.reset_saves();
+                track.state = LifeState::Dying;
+                format!("{} falls unconscious and is dying", name)
+            }
+            (Self::DeathSaves, _) => {
+                track.state = LifeState::Dying;
+                track.failures += if critical { 2 } else { 1 };
+                if track.failures >= DEATH_SAVES_NEEDED {
+                    track.state = LifeState::Dead;
+                    format!("{} takes damage while dying and dies", name)
+                } else {
+                    format!("{} takes damage while dying ({} failed death saves)", name, track.failures)
+                }
+            }
+            (Self::Dying { max_dying }, _) => {
+                let gained = if critical { 2 } else { 1 };
+                track.dying = if previous == LifeState::Dying {
+                    track.dying + gained
+                } else {
+                    gained + track.wounded
+                };
+                track.state = LifeState::Dying;
+                if track.dying >= death_threshold(*max_dying, track.doomed) {
+                    track.state = LifeState::Dead;
+                    format!("{} reaches dying {} and dies", name, track.dying)
+                } else {
+                    format!("{} is dying {}", name, track.dying)
+                }
+            }
+            (Self::Manual, _) => {
+                track.state = LifeState::Dying;
+                format!("{} is down", name)
+            }
+        };
+        Some(DeathUpdate::new(previous, track, message))
+    }
+
+    /// Resolve a death saving throw (D&D 5e) or recovery check (PF2e) from
+    /// the natural d20 roll. Returns `None` when the combatant is not dying
+    /// or the rules have no saves.
+    pub fn roll_save(&self, combatant: &mut Combatant, roll: i32) -> Option<DeathUpdate> {
+        if combatant.death.state != LifeState::Dying {
+            return None;
+        }
+        let previous = combatant.death.state;
+        let name = combatant.name.clone();
+
+        let message = match self {
+            Self::DeathSaves if roll >= 20 => {
+                combatant.current_hp = Some(1);
+                combatant.death.reset_saves();
+                combatant.death.state = LifeState::Conscious;
+                format!("{} rolls a natural 20 and regains 1 HP", name)
+            }
+            Self::DeathSaves => {
+                let track = &mut combatant.death;
+                if roll >= DEATH_SAVE_DC {
+                    track.successes += 1;
+                } else {
+                    track.failures += if roll <= 1 { 2 } else { 1 };
+                }
+                if track.failures >= DEATH_SAVES_NEEDED {
+                    track.state = LifeState::Dead;
+                    format!("{} fails their third death save and dies", name)
+                } else if track.successes >= DEATH_SAVES_NEEDED {
+                    track.reset_saves();
+                    track.state = LifeState::Stable;
+                    format!("{} is stable", name)
+                } else {
+                    format!("{} death saves: {} successes, {} failures", name, track.successes, track.failures)
+                }
+            }
+            Self::Dying { max_dying } => {
+                let track = &mut combatant.death;
+                let dc = 10 + track.dying as i32;
+                let change: i32 = if roll >= 20 || roll >= dc + 10 {
+                    -2
+                } else if roll >= dc {
+                    -1
+                } else if roll <= 1 || roll <= dc - 10 {
+                    2
+                } else {
+                    1
+                };
+                track.dying = (track.dying as i32 + change).max(0) as u32;
+                if track.dying == 0 {
+                    track.wounded += 1;
+                    track.state = LifeState::Stable;
+                    format!("{} recovers from dying (wounded {})", name, track.wounded)
+                } else if track.dying >= death_threshold(*max_dying, track.doomed) {
+                    track.state = LifeState::Dead;
+                    format!("{} reaches dying {} and dies", name, track.dying)
+                } else {
+                    format!("{} is dying {}", name, track.dying)
+                }
+            }
+            Self::Manual => return None,
+        };
+        Some(DeathUpdate::new(previous, &combatant.death, message))
+    }
+
+    /// Stabilize a dying combatant, e.g. with a Medicine check or
+    /// Spare the Dying
+    pub fn stabilize(&self, combatant: &mut Combatant) -> Option<DeathUpdate> {
+        let track = &mut combatant.death;
+        if track.state != LifeState::Dying {
+            return None;
+        }
+        let previous = track.state;
+        track.reset_saves();
+        if let Self::Dying { .. } = self {
+            track.dying = 0;
+            track.wounded += 1;
+        }
+        track.state = LifeState::Stable;
+        Some(DeathUpdate::new(previous, track, format!("{} is stabilized", combatant.name)))
+    }
+
+    /// Pathfinder 2e heroic recovery: spending every hero point ends dying
+    /// and stabilizes the combatant at 0 HP without raising wounded
+    pub fn heroic_recovery(&self, combatant: &mut Combatant) -> Option<DeathUpdate> {
+        let track = &mut combatant.death;
+        if track.state != LifeState::Dying {
+            return None;
+        }
+        let previous = track.state;
+        track.reset_saves();
+        track.dying = 0;
+        track.state = LifeState::Stable;
+        Some(DeathUpdate::new(previous, track, format!("{} spends their hero points to avoid death", combatant.name)))
+    }
+
+    /// Bring a combatant back to consciousness after healing lifted them
+    /// above 0 HP
+    pub fn on_healed(&self, combatant: &mut Combatant) -> Option<DeathUpdate> {
+        let track = &mut combatant.death;
+        if !matches!(track.state, LifeState::Dying | LifeState::Stable) || combatant.current_hp.unwrap_or(0) <= 0 {
+            return None;
+        }
+        let previous = track.state;
+        track.reset_saves();
+        if let (Self::Dying { .. }, LifeState::Dying) = (self, previous) {
+            track.dying = 0;
+            track.wounded += 1;
+        }
+        track.state = LifeState::Conscious;
+        Some(DeathUpdate::new(previous, track, format!("{} regains consciousness", combatant.name)))
+    }
+
+    /// What a dying combatant must roll at the start of their turn
+    pub fn turn_reminder(&self, combatant: &Combatant) -> Option<String> {
+        if combatant.death.state != LifeState::Dying {
+            return None;
+        }
+        match self {
+            Self::DeathSaves => Some(format!("{} makes a death saving throw", combatant.name)),
+            Self::Dying { .. } => Some(format!(
+                "{} makes a DC {} recovery check",
+                combatant.name,
+                10 + combatant.death.dying
+            )),
+            Self::Manual => None,
+        }
+    }
+}
+
+/// Whether a combatant uses the dying rules rather than dying at 0 HP
+fn tracks_dying(combatant_type: &CombatantType) -> bool {
+    matches!(combatant_type, CombatantType::Player | CombatantType::Ally)
+}
+
+fn death_threshold(max_dying: u32, doomed: u32) -> u32 {
+    max_dying.saturating_sub(doomed).max(1)
+}
+
+// ============================================================================
+// Death Tracking
+// ============================================================================
+
+/// Whether a combatant is up, dying, or dead
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LifeState {
+    #[default]
+    Conscious,
+    /// At 0 HP and making death saves or recovery checks
+    Dying,
+    /// At 0 HP but no longer dying
+    Stable,
+    Dead,
+}
+
+/// A combatant's place on the death track
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeathTrack {
+    pub state: LifeState,
+    /// Death save successes (D&D 5e)
+    pub successes: u32,
+    /// Death save failures (D&D 5e)
+    pub failures: u32,
+    /// Dying value (PF2e)
+    pub dying: u32,
+    /// Wounded value, added to dying when next knocked out (PF2e)
+    pub wounded: u32,
+    /// Doomed value, lowering the dying value that kills (PF2e)
+    pub doomed: u32,
+}
+
+impl DeathTrack {
+    fn reset_saves(&mut self) {
+        self.successes = 0;
+        self.failures = 0;
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.state == LifeState::Dead
+    }
+}
+
+/// A change on a combatant's death track
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeathUpdate {
+    pub previous: LifeState,
+    pub track: DeathTrack,
+    pub message: String,
+}
+
+impl DeathUpdate {
+    fn new(previous: LifeState, track: &DeathTrack, message: String) -> Self {
+        Self { previous, track: track.clone(), message }
+    }
+
+    /// The combatant died with this change
+    pub fn died(&self) -> bool {
+        self.track.is_dead() && self.previous != LifeState::Dead
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hero(max_hp: i32) -> Combatant {
+        let mut c = Combatant::new("Hero", 10, CombatantType::Player);
+        c.max_hp = Some(max_hp);
+        c.current_hp = Some(max_hp);
+        c
+    }
+
+    /// Take damage the way the combat engine does
+    fn hit(rules: DeathRules, c: &mut Combatant, damage: i32, critical: bool) -> Option<DeathUpdate> {
+        let before = c.current_hp.unwrap();
+        c.apply_damage(damage);
+        rules.on_damage(c, before, damage, critical)
+    }
+
+    #[test]
+    fn test_death_saves() {
+        let rules = DeathRules::DeathSaves;
+        let mut c = hero(20);
+        let update = hit(rules, &mut c, 25, false).unwrap();
+        assert_eq!((update.previous, c.death.state), (LifeState::Conscious, LifeState::Dying));
+
+        rules.roll_save(&mut c, 12).unwrap();
+        rules.roll_save(&mut c, 5).unwrap();
+        assert_eq!((c.death.successes, c.death.failures), (1, 1));
+        rules.roll_save(&mut c, 15).unwrap();
+        rules.roll_save(&mut c, 10).unwrap();
+        assert_eq!(c.death.state, LifeState::Stable);
+        assert!(rules.roll_save(&mut c, 10).is_none());
+
+        // A natural 1 counts twice, and damage while dying adds a failure
+        let mut c = hero(20);
+        hit(rules, &mut c, 20, false);
+        rules.roll_save(&mut c, 1).unwrap();
+        let update = hit(rules, &mut c, 3, false).unwrap();
+        assert!(update.died());
+    }
+
+    #[test]
+    fn test_natural_twenty_and_healing() {
+        let rules = DeathRules::DeathSaves;
+        let mut c = hero(20);
+        hit(rules, &mut c, 20, false);
+        rules.roll_save(&mut c, 20).unwrap();
+        assert_eq!((c.death.state, c.current_hp), (LifeState::Conscious, Some(1)));
+
+        hit(rules, &mut c, 1, false);
+        rules.roll_save(&mut c, 3).unwrap();
+        c.heal(5);
+        let update = rules.on_healed(&mut c).unwrap();
+        assert_eq!(update.previous, LifeState::Dying);
+        assert_eq!((c.death.state, c.death.failures), (LifeState::Conscious, 0));
+    }
+
+    #[test]
+    fn test_massive_damage_and_monsters() {
+        let mut c = hero(20);
+        assert!(hit(DeathRules::DeathSaves, &mut c, 40, false).unwrap().died());
+
+        let mut c = hero(20);
+        assert!(!hit(DeathRules::Dying { max_dying: 4 }, &mut c, 39, false).unwrap().died());
+        let mut c = hero(20);
+        assert!(hit(DeathRules::Dying { max_dying: 4 }, &mut c, 40, false).unwrap().died());
+
+        let mut goblin = Combatant::new("Goblin", 12, CombatantType::Monster);
+        goblin.max_hp = Some(7);
+        goblin.current_hp = Some(7);
+        assert!(hit(DeathRules::DeathSaves, &mut goblin, 7, false).unwrap().died());
+    }
+
+    #[test]
+    fn test_pf2e_dying_and_wounded() {
+        let rules = DeathRules::Dying { max_dying: 4 };
+        let mut c = hero(30);
+        hit(rules, &mut c, 30, true);
+        assert_eq!((c.death.state, c.death.dying), (LifeState::Dying, 2));
+        assert_eq!(rules.turn_reminder(&c).unwrap(), "Hero makes a DC 12 recovery check");
+
+        // Success against DC 12 lowers dying; at 0 the hero is stable and wounded
+        rules.roll_save(&mut c, 13).unwrap();
+        rules.roll_save(&mut c, 20).unwrap();
+        assert_eq!((c.death.state, c.death.dying, c.death.wounded), (LifeState::Stable, 0, 1));
+
+        // Wounded adds to dying the next time they drop; doomed lowers the threshold
+        c.death.doomed = 1;
+        hit(rules, &mut c, 5, false);
+        assert_eq!(c.death.dying, 2);
+        let update = rules.roll_save(&mut c, 2).unwrap();
+        assert!(update.died());
+    }
+
+    #[test]
+    fn test_stabilize_and_rules_for_system() {
+        let rules = DeathRules::Dying { max_dying: 4 };
+        let mut c = hero(10);
+        hit(rules, &mut c, 10, false);
+        rules.stabilize(&mut c).unwrap();
+        assert_eq!((c.death.state, c.death.dying, c.death.wounded), (LifeState::Stable, 0, 1));
+        assert!(rules.stabilize(&mut c).is_none());
+
+        // Heroic recovery leaves wounded where it was
+        let mut c = hero(10);
+        hit(rules, &mut c, 10, false);
+        rules.heroic_recovery(&mut c).unwrap();
+        assert_eq!((c.death.state, c.death.dying, c.death.wounded), (LifeState::Stable, 0, 0));
+
+        assert_eq!(DeathRules::for_system("dnd5e"), DeathRules::DeathSaves);
+        assert_eq!(DeathRules::for_system("pf2e"), DeathRules::Dying { max_dying: 4 });
+        assert_eq!(DeathRules::for_system("coc"), DeathRules::Manual);
+    }
+}
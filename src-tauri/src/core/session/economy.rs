@@ -0,0 +1,289 @@
+//! Action Economy Module
+//!
+//! How a game system paces a turn. Pathfinder 2e combats run on the
+//! three-action economy: each turn brings three actions and the reaction
+//! back, every attack after the first in a turn takes a growing multiple
+//! attack penalty, and players hold hero points to reroll a check or cheat
+//! death. Other systems keep the standard turn, where only reactions and
+//! legendary actions are tracked.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::character_gen::GameSystem;
+
+/// Actions each turn starts with under the three-action economy
+pub const ACTIONS_PER_TURN: u32 = 3;
+
+/// Most hero points a player can hold
+pub const MAX_HERO_POINTS: u32 = 3;
+
+/// Hero points each player starts a session with
+pub const STARTING_HERO_POINTS: u32 = 1;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum EconomyError {
+    #[error("This combat doesn't use the three-action economy")]
+    NotThreeAction,
+
+    #[error("{name} has {remaining} actions left, not enough for one costing {cost}")]
+    NotEnoughActions { name: String, remaining: u32, cost: u32 },
+
+    #[error("{0} has no hero points")]
+    NoHeroPoints(String),
+
+    #[error("{0} is not dying")]
+    NotDying(String),
+}
+
+pub type Result<T> = std::result::Result<T, EconomyError>;
+
+// ============================================================================
+// Combat Mode
+// ============================================================================
+
+/// Which action economy a combat tracks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CombatMode {
+    /// Action, bonus action, and reaction; only reactions are tracked
+    #[default]
+    Standard,
+    /// Pathfinder 2e: three actions and a reaction per turn, the multiple
+    /// attack penalty, and hero points
+    ThreeAction,
+}
+
+impl CombatMode {
+    pub fn for_system(system: &str) -> Self {
+        match GameSystem::from_str(system) {
+            GameSystem::Pathfinder2e => Self::ThreeAction,
+            _ => Self::Standard,
+        }
+    }
+
+    pub fn is_three_action(&self) -> bool {
+        *self == Self::ThreeAction
+    }
+}
+
+// ============================================================================
+// Multiple Attack Penalty
+// ============================================================================
+
+/// Penalty on an attack after `prior_attacks` others this turn: -5 then -10,
+/// or -4 then -8 with an agile weapon
+pub fn multiple_attack_penalty(prior_attacks: u32, agile: bool) -> i32 {
+    match (prior_attacks, agile) {
+        (0, _) => 0,
+        (1, false) => -5,
+        (1, true) => -4,
+        (_, false) => -10,
+        (_, true) => -8,
+    }
+}
+
+// ============================================================================
+// Turn Actions
+// ============================================================================
+
+/// A combatant's actions this turn under the three-action economy
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TurnActions {
+    pub max: u32,
+    pub remaining: u32,
+    /// Attacks made this turn, for the multiple attack penalty
+    pub attacks: u32,
+}
+
+impl Default for TurnActions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TurnActions {
+    pub fn new() -> Self {
+        Self {
+            max: ACTIONS_PER_TURN,
+            remaining: ACTIONS_PER_TURN,
+            attacks: 0,
+        }
+    }
+
+    /// Start a new turn with full actions and no attacks made
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Gain or lose actions this turn, as from quickened, slowed, or stunned
+    pub fn adjust(&mut self, delta: i32) {
+        self.remaining = self.remaining.saturating_add_signed(delta);
+        self.max = self.max.max(self.remaining);
+    }
+
+    /// The penalty the next attack this turn takes
+    pub fn attack_penalty(&self, agile: bool) -> i32 {
+        multiple_attack_penalty(self.attacks, agile)
+    }
+}
+
+/// Something a combatant spends actions on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    /// Actions the activity costs; free actions cost 0
+    #[serde(default = "default_activity_cost")]
+    pub cost: u32,
+    /// The activity makes an attack roll and counts toward the penalty
+    #[serde(default)]
+    pub attack: bool,
+    #[serde(default)]
+    pub agile: bool,
+    /// What the combatant does, for the combat log
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+fn default_activity_cost() -> u32 {
+    1
+}
+
+impl Activity {
+    pub fn actions(cost: u32) -> Self {
+        Self {
+            cost,
+            attack: false,
+            agile: false,
+            name: None,
+        }
+    }
+
+    /// A single-action attack such as a Strike
+    pub fn attack(agile: bool) -> Self {
+        Self {
+            attack: true,
+            agile,
+            ..Self::actions(1)
+        }
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+/// What is left after spending actions on an activity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnSpend {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub remaining: u32,
+    pub max: u32,
+    /// Penalty the attack was made with, when the activity was an attack
+    pub attack_penalty: Option<i32>,
+    /// Penalty the next non-agile attack this turn takes
+    pub next_attack_penalty: i32,
+    /// Set when no actions are left this turn
+    pub warning: Option<String>,
+}
+
+impl TurnSpend {
+    /// Spend an activity's actions, failing when too few are left
+    pub fn spend(combatant_id: &str, name: &str, turn: &mut TurnActions, activity: &Activity) -> Result<Self> {
+        if activity.cost > turn.remaining {
+            return Err(EconomyError::NotEnoughActions {
+                name: name.to_string(),
+                remaining: turn.remaining,
+                cost: activity.cost,
+            });
+        }
+        turn.remaining -= activity.cost;
+        let attack_penalty = activity.attack.then(|| turn.attack_penalty(activity.agile));
+        if activity.attack {
+            turn.attacks += 1;
+        }
+        Ok(Self {
+            combatant_id: combatant_id.to_string(),
+            combatant_name: name.to_string(),
+            remaining: turn.remaining,
+            max: turn.max,
+            attack_penalty,
+            next_attack_penalty: turn.attack_penalty(false),
+            warning: (turn.remaining == 0).then(|| format!("{} has no actions left this turn", name)),
+        })
+    }
+
+    /// Combat log line for the spend
+    pub fn describe(&self, activity: &Activity) -> String {
+        let what = match (&activity.name, activity.attack) {
+            (Some(name), _) => name.clone(),
+            (None, true) => "an attack".to_string(),
+            (None, false) => format!("{} action{}", activity.cost, if activity.cost == 1 { "" } else { "s" }),
+        };
+        let penalty = match self.attack_penalty {
+            Some(penalty) if penalty != 0 => format!(" at {} MAP", penalty),
+            _ => String::new(),
+        };
+        format!("{} uses {}{} ({} of {} actions left)", self.combatant_name, what, penalty, self.remaining, self.max)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_for_system_and_map() {
+        assert_eq!(CombatMode::for_system("pf2e"), CombatMode::ThreeAction);
+        assert_eq!(CombatMode::for_system("dnd5e"), CombatMode::Standard);
+
+        assert_eq!(
+            (0..4).map(|n| multiple_attack_penalty(n, false)).collect::<Vec<_>>(),
+            vec![0, -5, -10, -10]
+        );
+        assert_eq!(multiple_attack_penalty(1, true), -4);
+        assert_eq!(multiple_attack_penalty(2, true), -8);
+    }
+
+    #[test]
+    fn test_spending_actions_and_attacks() {
+        let mut turn = TurnActions::new();
+        let first = TurnSpend::spend("f1", "Valeros", &mut turn, &Activity::attack(false)).unwrap();
+        assert_eq!((first.attack_penalty, first.next_attack_penalty, first.remaining), (Some(0), -5, 2));
+
+        let dagger = Activity::attack(true).named("Dagger Strike");
+        let second = TurnSpend::spend("f1", "Valeros", &mut turn, &dagger).unwrap();
+        assert_eq!(second.attack_penalty, Some(-4));
+        assert_eq!(second.describe(&dagger), "Valeros uses Dagger Strike at -4 MAP (1 of 3 actions left)");
+
+        let err = TurnSpend::spend("f1", "Valeros", &mut turn, &Activity::actions(2)).unwrap_err();
+        assert!(matches!(err, EconomyError::NotEnoughActions { remaining: 1, cost: 2, .. }));
+
+        let last = TurnSpend::spend("f1", "Valeros", &mut turn, &Activity::actions(1)).unwrap();
+        assert_eq!(last.warning.as_deref(), Some("Valeros has no actions left this turn"));
+        assert_eq!(last.next_attack_penalty, -10);
+
+        turn.reset();
+        assert_eq!(turn, TurnActions::new());
+    }
+
+    #[test]
+    fn test_adjusting_actions() {
+        let mut turn = TurnActions::new();
+        turn.adjust(-1);
+        assert_eq!((turn.remaining, turn.max), (2, 3));
+        turn.adjust(2);
+        assert_eq!((turn.remaining, turn.max), (4, 4));
+        turn.adjust(-10);
+        assert_eq!(turn.remaining, 0);
+    }
+}
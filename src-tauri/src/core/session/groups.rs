@@ -0,0 +1,279 @@
+//! Minion Groups Module
+//!
+//! Large numbers of identical creatures, such as a dozen skeletons, fought as
+//! one initiative entry. A group's hit points are either pooled, so damage
+//! spills from one member into the next, or tracked for each member, so a
+//! hit only ever fells the member it lands on. Members drop out as their
+//! share of the hit points runs out, and the group is wiped out when the
+//! last one falls.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::combat::Combatant;
+use super::damage::DamageBreakdown;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum GroupError {
+    #[error("{0} isn't a group")]
+    NotAGroup(String),
+
+    #[error("A group needs at least one member with hit points")]
+    Empty,
+
+    #[error("No member {0} in the group")]
+    UnknownMember(u32),
+
+    #[error("Member {0} is already down")]
+    MemberDown(u32),
+}
+
+pub type Result<T> = std::result::Result<T, GroupError>;
+
+// ============================================================================
+// Group
+// ============================================================================
+
+/// How a group's hit points are kept
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupHpMode {
+    /// One pool; members drop from the back as it shrinks
+    #[default]
+    Pooled,
+    /// Each member has their own hit points
+    PerMember,
+}
+
+/// One creature in a group, numbered from 1
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GroupMember {
+    pub number: u32,
+    pub current_hp: i32,
+    pub down: bool,
+}
+
+/// Which members a hit lands on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupTargets {
+    /// The first members still standing
+    Count(u32),
+    /// Members by number
+    Members(Vec<u32>),
+}
+
+impl Default for GroupTargets {
+    fn default() -> Self {
+        Self::Count(1)
+    }
+}
+
+/// Hit points lost and members felled by hits on a group
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GroupHits {
+    pub hits: u32,
+    pub hp_lost: i32,
+    /// Numbers of the members that dropped
+    pub dropped: Vec<u32>,
+}
+
+/// The outcome of hits on a group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDamageResult {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub hits: GroupHits,
+    pub standing: usize,
+    pub size: usize,
+    pub breakdown: DamageBreakdown,
+}
+
+/// Identical creatures sharing one initiative entry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MinionGroup {
+    pub hp_mode: GroupHpMode,
+    /// Hit points each member starts with
+    pub member_max_hp: i32,
+    pub members: Vec<GroupMember>,
+}
+
+impl MinionGroup {
+    pub fn new(size: u32, member_max_hp: i32, hp_mode: GroupHpMode) -> Result<Self> {
+        if size == 0 || member_max_hp <= 0 {
+            return Err(GroupError::Empty);
+        }
+        Ok(Self {
+            hp_mode,
+            member_max_hp,
+            members: (1..=size)
+                .map(|number| GroupMember { number, current_hp: member_max_hp, down: false })
+                .collect(),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Members still fighting
+    pub fn standing(&self) -> usize {
+        self.members.iter().filter(|m| !m.down).count()
+    }
+
+    pub fn total_hp(&self) -> i32 {
+        self.members.iter().map(|m| m.current_hp).sum()
+    }
+
+    pub fn max_hp(&self) -> i32 {
+        self.member_max_hp * self.size() as i32
+    }
+
+    /// "9 of 12 standing"
+    pub fn describe(&self) -> String {
+        format!("{} of {} standing", self.standing(), self.size())
+    }
+
+    /// Apply `amount` damage to each targeted member. Pooled groups lose the
+    /// total from the pool, spilling over into further members; otherwise
+    /// damage beyond a member's hit points is lost.
+    pub fn take_hits(&mut self, amount: i32, targets: &GroupTargets) -> Result<GroupHits> {
+        let amount = amount.max(0);
+        let targeted = self.targeted(targets)?;
+        let hits = targeted.len() as u32;
+        let hp_before = self.total_hp();
+        let down_before: Vec<u32> = self.members.iter().filter(|m| m.down).map(|m| m.number).collect();
+
+        match self.hp_mode {
+            GroupHpMode::Pooled => self.spread_pool(hp_before - amount * hits as i32),
+            GroupHpMode::PerMember => {
+                for number in &targeted {
+                    if let Some(member) = self.members.iter_mut().find(|m| m.number == *number) {
+                        member.current_hp = (member.current_hp - amount).max(0);
+                    }
+                }
+            }
+        }
+        for member in &mut self.members {
+            member.down = member.down || member.current_hp == 0;
+        }
+
+        Ok(GroupHits {
+            hits,
+            hp_lost: hp_before - self.total_hp(),
+            dropped: self
+                .members
+                .iter()
+                .filter(|m| m.down && !down_before.contains(&m.number))
+                .map(|m| m.number)
+                .collect(),
+        })
+    }
+
+    /// Heal the standing members in order, each up to full. Members who
+    /// dropped stay down. Returns the hit points restored.
+    pub fn heal(&mut self, amount: i32) -> i32 {
+        let mut left = amount.max(0);
+        for member in self.members.iter_mut().filter(|m| !m.down) {
+            let healed = left.min(self.member_max_hp - member.current_hp);
+            member.current_hp += healed;
+            left -= healed;
+        }
+        amount.max(0) - left
+    }
+
+    /// Standing members to hit, by number
+    fn targeted(&self, targets: &GroupTargets) -> Result<Vec<u32>> {
+        match targets {
+            GroupTargets::Count(count) => Ok(self
+                .members
+                .iter()
+                .filter(|m| !m.down)
+                .take(*count as usize)
+                .map(|m| m.number)
+                .collect()),
+            GroupTargets::Members(numbers) => {
+                for number in numbers {
+                    match self.members.iter().find(|m| m.number == *number) {
+                        None => return Err(GroupError::UnknownMember(*number)),
+                        Some(m) if m.down => return Err(GroupError::MemberDown(*number)),
+                        Some(_) => {}
+                    }
+                }
+                let mut numbers = numbers.clone();
+                numbers.sort_unstable();
+                numbers.dedup();
+                Ok(numbers)
+            }
+        }
+    }
+
+    /// Share a pool of hit points out over the standing members, filling
+    /// each in turn, so the last ones run out first
+    fn spread_pool(&mut self, pool: i32) {
+        let mut left = pool.max(0);
+        for member in self.members.iter_mut().filter(|m| !m.down) {
+            member.current_hp = left.min(self.member_max_hp);
+            left -= member.current_hp;
+        }
+    }
+}
+
+impl Combatant {
+    /// Turn this combatant into a group of `size` like it, each with its
+    /// maximum hit points
+    pub fn into_group(mut self, size: u32, hp_mode: GroupHpMode) -> Result<Self> {
+        let group = MinionGroup::new(size, self.max_hp.unwrap_or(0), hp_mode)?;
+        self.current_hp = Some(group.total_hp());
+        self.max_hp = Some(group.max_hp());
+        self.temp_hp = None;
+        self.group = Some(group);
+        Ok(self)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pooled_damage_spills_over() {
+        let mut group = MinionGroup::new(5, 10, GroupHpMode::Pooled).unwrap();
+        let hits = group.take_hits(25, &GroupTargets::Count(1)).unwrap();
+        assert_eq!((hits.hp_lost, hits.dropped.clone()), (25, vec![4, 5]));
+        assert_eq!(group.describe(), "3 of 5 standing");
+        assert_eq!(group.members[2].current_hp, 5);
+
+        // Dropped members don't come back
+        assert_eq!(group.heal(30), 5);
+        assert_eq!((group.total_hp(), group.standing()), (30, 3));
+
+        let hits = group.take_hits(8, &GroupTargets::Count(4)).unwrap();
+        assert_eq!((hits.hits, hits.hp_lost), (3, 24));
+        assert_eq!(group.standing(), 1);
+    }
+
+    #[test]
+    fn test_per_member_damage() {
+        let mut group = MinionGroup::new(4, 7, GroupHpMode::PerMember).unwrap();
+        let hits = group.take_hits(20, &GroupTargets::Count(1)).unwrap();
+        assert_eq!((hits.hp_lost, hits.dropped), (7, vec![1]));
+
+        let hits = group.take_hits(5, &GroupTargets::Members(vec![2, 4])).unwrap();
+        assert_eq!((hits.hits, hits.hp_lost), (2, 10));
+        assert!(hits.dropped.is_empty());
+        assert_eq!(group.total_hp(), 2 + 7 + 2);
+
+        assert!(matches!(group.take_hits(5, &GroupTargets::Members(vec![1])), Err(GroupError::MemberDown(1))));
+        assert!(matches!(group.take_hits(5, &GroupTargets::Members(vec![9])), Err(GroupError::UnknownMember(9))));
+        assert!(matches!(MinionGroup::new(0, 7, GroupHpMode::Pooled), Err(GroupError::Empty)));
+    }
+}
@@ -0,0 +1,232 @@
+//! Combat History Module
+//!
+//! Undo and redo for combat actions. Each damage, heal, condition change,
+//! move, or turn change records the combat as it stood beforehand, so undoing
+//! restores that snapshot. The combat log is never rewound: undos and redos
+//! are logged like any other action, and the whole log can be exported to
+//! the session timeline.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::combat::{CombatEvent, CombatEventType, CombatState};
+use super::timeline::{EventSeverity, TimelineEvent, TimelineEventType};
+
+/// Combat actions kept for undo before the oldest are dropped
+pub const MAX_HISTORY: usize = 100;
+
+/// Tag on timeline events exported from a combat log
+pub const COMBAT_LOG_TAG: &str = "combat_log";
+
+// ============================================================================
+// History
+// ============================================================================
+
+/// A combat action and the combat as it stood on the other side of it
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub description: String,
+    pub recorded_at: DateTime<Utc>,
+    state: Box<CombatState>,
+}
+
+/// Undo and redo stacks for one combat
+#[derive(Debug, Clone, Default)]
+pub struct CombatHistory {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    /// Log entries already exported to the timeline
+    exported: usize,
+}
+
+impl CombatHistory {
+    /// Record an action along with the combat from before it. A new action
+    /// clears anything waiting to be redone.
+    pub fn record(&mut self, description: impl Into<String>, before: CombatState) {
+        self.redo.clear();
+        self.push_undo(description.into(), before);
+    }
+
+    fn push_undo(&mut self, description: String, state: CombatState) {
+        if self.undo.len() >= MAX_HISTORY {
+            self.undo.remove(0);
+        }
+        self.undo.push(HistoryEntry {
+            description,
+            recorded_at: Utc::now(),
+            state: Box::new(state),
+        });
+    }
+
+    /// Undo the latest action on `combat`, returning its description
+    pub fn undo(&mut self, combat: &mut CombatState) -> Option<String> {
+        let entry = self.undo.pop()?;
+        let after = combat.restore(*entry.state);
+        combat.log_event("GM", CombatEventType::Undo, format!("Undid: {}", entry.description));
+        self.redo.push(HistoryEntry {
+            description: entry.description.clone(),
+            recorded_at: Utc::now(),
+            state: Box::new(after),
+        });
+        Some(entry.description)
+    }
+
+    /// Redo the latest undone action on `combat`, returning its description
+    pub fn redo(&mut self, combat: &mut CombatState) -> Option<String> {
+        let entry = self.redo.pop()?;
+        let before = combat.restore(*entry.state);
+        combat.log_event("GM", CombatEventType::Redo, format!("Redid: {}", entry.description));
+        self.push_undo(entry.description.clone(), before);
+        Some(entry.description)
+    }
+
+    /// Log entries not yet exported to the timeline, marking them exported
+    pub fn take_unexported<'a>(&mut self, events: &'a [CombatEvent]) -> &'a [CombatEvent] {
+        let start = self.exported.min(events.len());
+        self.exported = events.len();
+        &events[start..]
+    }
+
+    pub fn status(&self) -> HistoryStatus {
+        HistoryStatus {
+            undo: self.undo.last().map(|e| e.description.clone()),
+            redo: self.redo.last().map(|e| e.description.clone()),
+            undo_depth: self.undo.len(),
+            redo_depth: self.redo.len(),
+        }
+    }
+}
+
+/// What undo and redo would do next
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryStatus {
+    /// Action an undo would reverse
+    pub undo: Option<String>,
+    /// Action a redo would repeat
+    pub redo: Option<String>,
+    pub undo_depth: usize,
+    pub redo_depth: usize,
+}
+
+/// The outcome of an undo or redo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryStep {
+    /// The action undone or redone
+    pub description: String,
+    pub combat: CombatState,
+    pub status: HistoryStatus,
+}
+
+// ============================================================================
+// Timeline Export
+// ============================================================================
+
+/// A combat log entry as a session timeline event
+pub fn timeline_event(session_id: &str, event: &CombatEvent) -> TimelineEvent {
+    let (event_type, severity) = match event.event_type {
+        CombatEventType::Damage | CombatEventType::Attack => (TimelineEventType::CombatDamage, EventSeverity::Trace),
+        CombatEventType::Healing | CombatEventType::Stabilized => {
+            (TimelineEventType::CombatHealing, EventSeverity::Trace)
+        }
+        CombatEventType::Death => (TimelineEventType::CombatDeath, EventSeverity::Notable),
+        CombatEventType::ConditionApplied => (TimelineEventType::ConditionApplied, EventSeverity::Trace),
+        CombatEventType::ConditionRemoved => (TimelineEventType::ConditionRemoved, EventSeverity::Trace),
+        CombatEventType::TurnChange => (TimelineEventType::CombatTurnStart, EventSeverity::Trace),
+        _ => (TimelineEventType::Custom("combat_action".to_string()), EventSeverity::Trace),
+    };
+    let mut timeline_event = TimelineEvent::new(session_id, event_type, event.actor.clone(), event.description.clone())
+        .with_severity(severity)
+        .with_meta("round", event.round)
+        .with_meta("turn", event.turn)
+        .with_tags([COMBAT_LOG_TAG]);
+    timeline_event.timestamp = event.timestamp;
+    timeline_event
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::{Combatant, CombatantType};
+
+    fn combat() -> (CombatState, String) {
+        let mut combat = CombatState::new();
+        let mut goblin = Combatant::new("Goblin", 12, CombatantType::Monster);
+        goblin.current_hp = Some(7);
+        goblin.max_hp = Some(7);
+        let id = goblin.id.clone();
+        combat.add_combatant(goblin);
+        (combat, id)
+    }
+
+    fn hp(combat: &CombatState, id: &str) -> Option<i32> {
+        combat.get_combatant(id).and_then(|c| c.current_hp)
+    }
+
+    #[test]
+    fn test_undo_and_redo_keep_the_log() {
+        let (mut combat, id) = combat();
+        let mut history = CombatHistory::default();
+
+        history.record("Damage Goblin", combat.snapshot());
+        combat.damage(&id, 5, false);
+        assert_eq!(hp(&combat, &id), Some(2));
+        let logged = combat.events.len();
+
+        assert_eq!(history.undo(&mut combat).as_deref(), Some("Damage Goblin"));
+        assert_eq!(hp(&combat, &id), Some(7));
+        assert_eq!(combat.events.len(), logged + 1);
+        assert!(matches!(combat.events.last().unwrap().event_type, CombatEventType::Undo));
+        assert_eq!(history.status().redo.as_deref(), Some("Damage Goblin"));
+
+        assert_eq!(history.redo(&mut combat).as_deref(), Some("Damage Goblin"));
+        assert_eq!(hp(&combat, &id), Some(2));
+        assert_eq!(history.status(), HistoryStatus {
+            undo: Some("Damage Goblin".to_string()),
+            redo: None,
+            undo_depth: 1,
+            redo_depth: 0,
+        });
+        assert!(history.redo(&mut combat).is_none());
+    }
+
+    #[test]
+    fn test_new_action_clears_redo_and_history_is_capped() {
+        let (mut combat, id) = combat();
+        let mut history = CombatHistory::default();
+        history.record("Damage Goblin", combat.snapshot());
+        combat.damage(&id, 1, false);
+        history.undo(&mut combat);
+
+        history.record("Heal Goblin", combat.snapshot());
+        assert_eq!(history.status().redo_depth, 0);
+
+        for i in 0..MAX_HISTORY + 5 {
+            history.record(format!("Turn {}", i), combat.snapshot());
+        }
+        let status = history.status();
+        assert_eq!(status.undo_depth, MAX_HISTORY);
+        assert_eq!(status.undo.as_deref(), Some(format!("Turn {}", MAX_HISTORY + 4).as_str()));
+    }
+
+    #[test]
+    fn test_timeline_export() {
+        let (mut combat, id) = combat();
+        combat.damage(&id, 3, false);
+        let event = timeline_event("s1", combat.events.last().unwrap());
+        assert_eq!(event.event_type, TimelineEventType::CombatDamage);
+        assert_eq!(event.title, "Goblin");
+        assert_eq!(event.timestamp, combat.events.last().unwrap().timestamp);
+        assert_eq!(event.tags, vec![COMBAT_LOG_TAG.to_string()]);
+        assert_eq!(event.metadata.get("round"), Some(&serde_json::json!(1)));
+
+        let mut history = CombatHistory::default();
+        assert_eq!(history.take_unexported(&combat.events).len(), combat.events.len());
+        assert!(history.take_unexported(&combat.events).is_empty());
+        combat.heal(&id, 1);
+        assert_eq!(history.take_unexported(&combat.events).len(), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Initiative Rules Module
+//!
+//! Per-system initiative dice and tie-break rules. Rolls initiative for
+//! combatants from their initiative modifier and orders tied combatants the
+//! way each game system resolves ties.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use super::combat::{Combatant, CombatantType};
+use crate::core::campaign::dice::{DiceNotation, DiceRoller, DiceType};
+use crate::core::character_gen::GameSystem;
+
+/// Sides of the hidden die rolled to settle ties nothing else breaks
+const ROLL_OFF_SIDES: u32 = 1000;
+
+// ============================================================================
+// Tie Breaking
+// ============================================================================
+
+/// A rule for ordering combatants with the same initiative, applied in turn
+/// until one decides
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreaker {
+    /// Higher initiative modifier goes first
+    Modifier,
+    /// Monsters and NPCs go before players and allies
+    NonPlayersFirst,
+    /// Players and allies go before monsters and NPCs
+    PlayersFirst,
+    /// A hidden roll-off made when initiative was rolled
+    RollOff,
+}
+
+impl TieBreaker {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "modifier" | "dexterity" | "dex" => Some(Self::Modifier),
+            "non_players_first" | "enemies_first" | "monsters_first" => Some(Self::NonPlayersFirst),
+            "players_first" => Some(Self::PlayersFirst),
+            "roll_off" | "random" => Some(Self::RollOff),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Modifier => "modifier",
+            Self::NonPlayersFirst => "non_players_first",
+            Self::PlayersFirst => "players_first",
+            Self::RollOff => "roll_off",
+        }
+    }
+
+    /// `Less` when `a` acts before `b`
+    fn compare(&self, a: &Combatant, b: &Combatant) -> Ordering {
+        match self {
+            Self::Modifier => b.initiative_modifier.cmp(&a.initiative_modifier),
+            Self::NonPlayersFirst => is_party(a).cmp(&is_party(b)),
+            Self::PlayersFirst => is_party(b).cmp(&is_party(a)),
+            Self::RollOff => b.tie_roll.cmp(&a.tie_roll),
+        }
+    }
+}
+
+fn is_party(combatant: &Combatant) -> bool {
+    matches!(combatant.combatant_type, CombatantType::Player | CombatantType::Ally)
+}
+
+// ============================================================================
+// Initiative Rules
+// ============================================================================
+
+/// How a combat rolls initiative and breaks ties
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InitiativeRules {
+    /// Dice added to the modifier; `None` when initiative is the modifier
+    /// alone (e.g. Call of Cthulhu acting in DEX order)
+    pub dice: Option<DiceNotation>,
+    pub tie_breakers: Vec<TieBreaker>,
+}
+
+impl Default for InitiativeRules {
+    fn default() -> Self {
+        Self {
+            dice: Some(Self::die(DiceType::D20)),
+            tie_breakers: vec![TieBreaker::Modifier, TieBreaker::RollOff],
+        }
+    }
+}
+
+impl InitiativeRules {
+    fn die(dice_type: DiceType) -> DiceNotation {
+        DiceNotation::new(1, dice_type, 0).expect("a single die is valid notation")
+    }
+
+    /// The rules a game system uses, falling back to d20 + modifier with the
+    /// higher modifier winning ties
+    pub fn for_system(system: &str) -> Self {
+        let defaults = Self::default();
+        match GameSystem::from_str(system) {
+            // Ties go to the adversary (Player Core, "Initiative")
+            GameSystem::Pathfinder2e => Self {
+                tie_breakers: vec![TieBreaker::NonPlayersFirst, TieBreaker::Modifier, TieBreaker::RollOff],
+                ..defaults
+            },
+            GameSystem::CallOfCthulhu | GameSystem::Warhammer => Self {
+                dice: None,
+                ..defaults
+            },
+            GameSystem::Cyberpunk => Self {
+                dice: Some(Self::die(DiceType::D10)),
+                ..defaults
+            },
+            GameSystem::Shadowrun => Self {
+                dice: Some(Self::die(DiceType::D6)),
+                ..defaults
+            },
+            _ => defaults,
+        }
+    }
+
+    /// Roll initiative for a modifier
+    pub fn roll<R: Rng>(&self, modifier: i32, rng: &mut R) -> InitiativeRoll {
+        let natural = self
+            .dice
+            .as_ref()
+            .map(|dice| DiceRoller::new().roll_with_rng(dice, rng).total);
+        InitiativeRoll {
+            dice: self.dice.as_ref().map(|d| d.original.clone()),
+            natural,
+            modifier,
+            total: natural.unwrap_or(0) + modifier,
+            tie_roll: rng.gen_range(1..=ROLL_OFF_SIDES),
+        }
+    }
+
+    /// `Less` when `a` acts before `b`, by initiative then tie-breakers
+    pub fn compare(&self, a: &Combatant, b: &Combatant) -> Ordering {
+        self.tie_breakers
+            .iter()
+            .fold(b.initiative.cmp(&a.initiative), |order, rule| order.then_with(|| rule.compare(a, b)))
+    }
+}
+
+/// An initiative roll and its parts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitiativeRoll {
+    pub dice: Option<String>,
+    /// What the dice showed, when any were rolled
+    pub natural: Option<i32>,
+    pub modifier: i32,
+    pub total: i32,
+    /// Hidden roll-off for ties
+    pub tie_roll: u32,
+}
+
+impl InitiativeRoll {
+    /// Record the roll on a combatant
+    pub fn apply(&self, combatant: &mut Combatant) {
+        combatant.initiative = self.total;
+        combatant.initiative_modifier = self.modifier;
+        combatant.tie_roll = self.tie_roll;
+    }
+}
+
+/// Initiative modifier for an ability score, using the d20 formula
+pub fn ability_modifier(score: i32) -> i32 {
+    (score - 10).div_euclid(2)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn combatant(name: &str, initiative: i32, modifier: i32, combatant_type: CombatantType) -> Combatant {
+        let mut c = Combatant::new(name, initiative, combatant_type);
+        c.initiative_modifier = modifier;
+        c
+    }
+
+    #[test]
+    fn test_rules_for_system() {
+        let dnd = InitiativeRules::for_system("dnd5e");
+        assert_eq!(dnd.dice.as_ref().unwrap().sides(), 20);
+        assert_eq!(dnd.tie_breakers[0], TieBreaker::Modifier);
+
+        let pf2e = InitiativeRules::for_system("pf2e");
+        assert_eq!(pf2e.tie_breakers[0], TieBreaker::NonPlayersFirst);
+
+        assert!(InitiativeRules::for_system("Call of Cthulhu").dice.is_none());
+        assert_eq!(InitiativeRules::for_system("cyberpunk red").dice.unwrap().sides(), 10);
+        assert_eq!(InitiativeRules::for_system("homebrew"), InitiativeRules::default());
+    }
+
+    #[test]
+    fn test_roll_adds_modifier() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rules = InitiativeRules::default();
+        for _ in 0..50 {
+            let roll = rules.roll(3, &mut rng);
+            let natural = roll.natural.unwrap();
+            assert!((1..=20).contains(&natural));
+            assert_eq!(roll.total, natural + 3);
+            assert!((1..=ROLL_OFF_SIDES).contains(&roll.tie_roll));
+        }
+
+        let static_rules = InitiativeRules::for_system("coc");
+        let roll = static_rules.roll(65, &mut rng);
+        assert_eq!((roll.natural, roll.total), (None, 65));
+    }
+
+    #[test]
+    fn test_tie_breakers() {
+        let fighter = combatant("Fighter", 15, 2, CombatantType::Player);
+        let goblin = combatant("Goblin", 15, 2, CombatantType::Monster);
+        let rogue = combatant("Rogue", 15, 4, CombatantType::Player);
+
+        let dnd = InitiativeRules::for_system("dnd5e");
+        assert_eq!(dnd.compare(&rogue, &goblin), Ordering::Less);
+
+        let pf2e = InitiativeRules::for_system("pf2e");
+        assert_eq!(pf2e.compare(&goblin, &rogue), Ordering::Less);
+
+        let players_first = InitiativeRules {
+            tie_breakers: vec![TieBreaker::PlayersFirst],
+            ..InitiativeRules::default()
+        };
+        assert_eq!(players_first.compare(&fighter, &goblin), Ordering::Less);
+
+        // Higher initiative always wins, whatever the tie-breakers say
+        let quick_goblin = combatant("Goblin", 16, -1, CombatantType::Monster);
+        assert_eq!(dnd.compare(&quick_goblin, &rogue), Ordering::Less);
+    }
+
+    #[test]
+    fn test_roll_off_settles_remaining_ties() {
+        let rules = InitiativeRules::default();
+        let mut a = combatant("A", 12, 1, CombatantType::Monster);
+        let mut b = combatant("B", 12, 1, CombatantType::Monster);
+        assert_eq!(rules.compare(&a, &b), Ordering::Equal);
+
+        a.tie_roll = 10;
+        b.tie_roll = 900;
+        assert_eq!(rules.compare(&b, &a), Ordering::Less);
+
+        let roll = InitiativeRoll { dice: None, natural: None, modifier: 5, total: 14, tie_roll: 42 };
+        roll.apply(&mut a);
+        assert_eq!((a.initiative, a.initiative_modifier, a.tie_roll), (14, 5, 42));
+    }
+
+    #[test]
+    fn test_parsing_and_modifiers() {
+        assert_eq!(TieBreaker::parse("Enemies First"), Some(TieBreaker::NonPlayersFirst));
+        assert_eq!(TieBreaker::parse("roll-off"), Some(TieBreaker::RollOff));
+        assert!(TieBreaker::parse("coin toss").is_none());
+        assert_eq!(TieBreaker::parse(TieBreaker::PlayersFirst.as_str()), Some(TieBreaker::PlayersFirst));
+
+        assert_eq!(ability_modifier(10), 0);
+        assert_eq!(ability_modifier(15), 2);
+        assert_eq!(ability_modifier(9), -1);
+        assert_eq!(ability_modifier(1), -5);
+    }
+}
@@ -0,0 +1,280 @@
+//! Combat Journal Module
+//!
+//! Combat lives in memory, so a crash mid-fight would lose it. The journal
+//! keeps an append-only file per session on disk: the session as it was
+//! when combat started, then the whole combat state after every change.
+//! The last readable entry is the fight as it stood when the app went down.
+//! A journal is deleted when its combat ends, and rewritten down to its
+//! latest entry once it grows large.
+//!
+//! Layout under the journal root:
+//!
+//! ```text
+//! <root>/<session_id>.jsonl
+//! ```
+
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::combat::{CombatState, CombatStatus};
+use crate::core::session_manager::GameSession;
+
+/// Journal size past which it is rewritten down to its latest entry
+const COMPACT_BYTES: u64 = 4 * 1024 * 1024;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("Combat journal I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Combat journal serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, JournalError>;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// One line of a journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum JournalRecord {
+    /// The session the combat belongs to, written when combat starts
+    Session { session: GameSession },
+    /// The combat after a change
+    Combat { saved_at: DateTime<Utc>, combat: CombatState },
+}
+
+/// A session with its combat as last journaled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveredCombat {
+    /// The session, with `combat` set to the recovered fight
+    pub session: GameSession,
+    pub saved_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Journal
+// ============================================================================
+
+/// Append-only per-session journal of combat state
+#[derive(Debug, Clone)]
+pub struct CombatJournal {
+    root: PathBuf,
+}
+
+impl CombatJournal {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Journal file for a session
+    pub fn journal_path(&self, session_id: &str) -> PathBuf {
+        let safe: String = session_id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.root.join(format!("{}.jsonl", safe))
+    }
+
+    /// Start a session's journal for a new fight, replacing any earlier one
+    pub fn begin(&self, session: &GameSession) -> Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        let path = self.journal_path(&session.id);
+        let mut file = std::fs::File::create(&path)?;
+        write_record(&mut file, &JournalRecord::Session { session: session.clone() })?;
+        if let Some(combat) = &session.combat {
+            write_record(&mut file, &combat_record(combat))?;
+        }
+        Ok(())
+    }
+
+    /// Record the combat after a change. Does nothing for a session whose
+    /// journal was never begun.
+    pub fn append(&self, session_id: &str, combat: &CombatState) -> Result<()> {
+        let path = self.journal_path(session_id);
+        if !path.exists() {
+            return Ok(());
+        }
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path)?;
+        write_record(&mut file, &combat_record(combat))?;
+
+        if file.metadata()?.len() > COMPACT_BYTES {
+            self.compact(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Delete a session's journal once its combat is over
+    pub fn finish(&self, session_id: &str) -> Result<()> {
+        let path = self.journal_path(session_id);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// A session's combat as last journaled, if its journal holds a fight
+    /// still in progress
+    ///
+    /// Unreadable lines (e.g., a write cut short by a crash) are skipped.
+    pub fn load(&self, session_id: &str) -> Result<Option<RecoveredCombat>> {
+        let path = self.journal_path(session_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        load_path(&path)
+    }
+
+    /// Every combat still in progress in the journal root
+    pub fn load_all(&self) -> Result<Vec<RecoveredCombat>> {
+        if !self.root.exists() {
+            return Ok(vec![]);
+        }
+        let mut recovered = Vec::new();
+        for entry in std::fs::read_dir(&self.root)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "jsonl") {
+                recovered.extend(load_path(&path)?);
+            }
+        }
+        recovered.sort_by_key(|r| r.saved_at);
+        Ok(recovered)
+    }
+
+    /// Rewrite a journal as its session and latest combat, through a
+    /// temporary file so a crash partway leaves the old journal whole
+    fn compact(&self, path: &Path) -> Result<()> {
+        let Some(recovered) = load_path(path)? else {
+            return Ok(());
+        };
+        let temp = path.with_extension("jsonl.tmp");
+        {
+            let mut file = std::fs::File::create(&temp)?;
+            let mut session = recovered.session;
+            let combat = session.combat.take();
+            write_record(&mut file, &JournalRecord::Session { session })?;
+            if let Some(combat) = combat {
+                write_record(&mut file, &JournalRecord::Combat { saved_at: recovered.saved_at, combat })?;
+            }
+            file.sync_all()?;
+        }
+        std::fs::rename(temp, path)?;
+        Ok(())
+    }
+}
+
+fn combat_record(combat: &CombatState) -> JournalRecord {
+    JournalRecord::Combat { saved_at: Utc::now(), combat: combat.clone() }
+}
+
+fn write_record(file: &mut std::fs::File, record: &JournalRecord) -> Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    file.write_all(&line)?;
+    Ok(())
+}
+
+fn load_path(path: &Path) -> Result<Option<RecoveredCombat>> {
+    let reader = BufReader::new(std::fs::File::open(path)?);
+    let mut session = None;
+    let mut latest = None;
+    for line in reader.lines() {
+        match serde_json::from_str(&line?) {
+            Ok(JournalRecord::Session { session: s }) => session = Some(s),
+            Ok(JournalRecord::Combat { saved_at, combat }) => latest = Some((saved_at, combat)),
+            Err(_) => continue,
+        }
+    }
+
+    let Some(mut session) = session else {
+        return Ok(None);
+    };
+    let saved_at = match latest {
+        Some((saved_at, combat)) => {
+            session.combat = Some(combat);
+            saved_at
+        }
+        None => session.started_at,
+    };
+    let in_progress = session.combat.as_ref().is_some_and(|c| c.status != CombatStatus::Ended);
+    Ok(in_progress.then_some(RecoveredCombat { session, saved_at }))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::{Combatant, CombatantType};
+    use crate::core::session_manager::SessionManager;
+
+    fn session_in_combat() -> GameSession {
+        let manager = SessionManager::new();
+        let session = manager.start_session("campaign", 1);
+        manager.start_combat(&session.id).unwrap();
+        manager.get_session(&session.id).unwrap()
+    }
+
+    #[test]
+    fn test_latest_entry_is_recovered() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = CombatJournal::new(dir.path().to_path_buf());
+        let mut session = session_in_combat();
+        journal.begin(&session).unwrap();
+
+        let combat = session.combat.as_mut().unwrap();
+        combat.add_combatant(Combatant::new("Goblin", 12, CombatantType::Monster));
+        journal.append(&session.id, combat).unwrap();
+        combat.add_combatant(Combatant::new("Aria", 15, CombatantType::Player));
+        journal.append(&session.id, combat).unwrap();
+
+        // A write cut short by a crash
+        let path = journal.journal_path(&session.id);
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"kind\":\"combat\",\"saved_at\":").unwrap();
+
+        let recovered = journal.load(&session.id).unwrap().unwrap();
+        assert_eq!(recovered.session.id, session.id);
+        assert_eq!(recovered.session.combat.unwrap().combatants.len(), 2);
+        assert_eq!(journal.load_all().unwrap().len(), 1);
+
+        journal.compact(&path).unwrap();
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines, 2);
+        assert_eq!(journal.load(&session.id).unwrap().unwrap().session.combat.unwrap().combatants.len(), 2);
+    }
+
+    #[test]
+    fn test_finished_combat_is_not_recovered() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = CombatJournal::new(dir.path().to_path_buf());
+        let mut session = session_in_combat();
+
+        // Nothing is journaled before combat begins
+        journal.append(&session.id, session.combat.as_ref().unwrap()).unwrap();
+        assert!(journal.load(&session.id).unwrap().is_none());
+
+        journal.begin(&session).unwrap();
+        let combat = session.combat.as_mut().unwrap();
+        combat.end();
+        journal.append(&session.id, combat).unwrap();
+        assert!(journal.load(&session.id).unwrap().is_none());
+
+        journal.finish(&session.id).unwrap();
+        assert!(!journal.journal_path(&session.id).exists());
+        assert!(journal.load_all().unwrap().is_empty());
+    }
+}
@@ -1,14 +1,41 @@
 //! Session Module
 //!
-//! Submodules for session management including timeline tracking,
-//! advanced conditions, combat state, session notes with AI categorization,
-//! and session planning with pacing templates.
+//! Submodules for session management including timeline tracking with
+//! automatic capture from other subsystems, advanced conditions, combat
+//! state, initiative rules, death and dying, legendary and lair actions, the
+//! PF2e three-action economy with hero points, recharge and limited-use
+//! abilities, combatants from stat blocks, minion groups sharing one
+//! initiative, morale checks for enemy groups, typed damage with
+//! resistances and area effects, zone and grid positioning, combat undo and
+//! redo, an on-disk combat journal for crash recovery, post-fight combat
+//! reports, a player-facing view with GM-only details removed, session
+//! notes with AI categorization, session planning with pacing templates, a
+//! session clock with break reminders and pacing nudges, and LLM-written
+//! recaps.
 
 pub mod timeline;
+pub mod capture;
 pub mod conditions;
 pub mod combat;
+pub mod initiative;
+pub mod turn_order;
+pub mod death;
+pub mod actions;
+pub mod abilities;
+pub mod economy;
+pub mod stat_blocks;
+pub mod damage;
+pub mod groups;
+pub mod morale;
+pub mod positioning;
+pub mod history;
+pub mod journal;
+pub mod combat_report;
+pub mod player_view;
 pub mod notes;
 pub mod plan_types;
+pub mod clock;
+pub mod recap;
 
 // Re-exports for convenience
 pub use timeline::{
@@ -16,6 +43,11 @@ pub use timeline::{
     SessionTimeline, TimelineSummary, CombatSummary, KeyMoment,
 };
 
+pub use capture::{
+    CaptureCategory, CaptureSettings, combat_ended_event, dice_roll_event, npc_dialogue_event,
+    quest_status_event, world_event_event, AUTO_CAPTURE_TAG,
+};
+
 pub use conditions::{
     ConditionDuration, SaveTiming, ConditionEffect, StackingRule,
     AdvancedCondition, ConditionTracker, ConditionTemplates,
@@ -35,7 +67,81 @@ pub use plan_types::{
     pacing_templates,
 };
 
+pub use clock::{
+    ClockConfig, ClockNotice, ClockNoticeKind, ClockStatus, SceneEstimate, SessionClock, format_minutes,
+};
+
+pub use recap::{
+    ChatExcerpt, RecapInputs, GeneratedRecap,
+    build_recap_prompt, parse_recap_response, is_recap_note, session_transcript,
+};
+
 pub use combat::{
     CombatState, CombatStatus, Combatant, CombatantType,
     CombatEvent, CombatEventType, TurnResult,
+    ConditionExpiry, ConditionReminder, CurrentCombatant, ExpiryReason, HealthUpdate,
+};
+
+pub use initiative::{
+    InitiativeRules, InitiativeRoll, TieBreaker, ability_modifier,
+};
+
+pub use turn_order::{
+    ReadiedAction, ReadiedReminder, TurnOrderError, initiative_at, triggered_by,
+};
+
+pub use death::{
+    DeathRules, DeathTrack, DeathUpdate, LifeState,
+};
+
+pub use actions::{
+    ActionError, ActionPool, ActionSpend, LairActions, LAIR_INITIATIVE,
+};
+
+pub use abilities::{
+    AbilityError, RechargeRoll, TrackedAbility, UsageLimit,
+};
+
+pub use economy::{
+    Activity, CombatMode, EconomyError, TurnActions, TurnSpend, multiple_attack_penalty,
+    ACTIONS_PER_TURN, MAX_HERO_POINTS, STARTING_HERO_POINTS,
+};
+
+pub use stat_blocks::{
+    CombatantStats, CombatantAction, HpMethod, combatants_from_stat_block, numbered_names, stat_block_hp,
+};
+
+pub use damage::{
+    AreaDamage, AreaDamageResult, Damage, DamageAdjustment, DamageBreakdown, DamageDefenses, DamageError,
+    TargetDamage, apply_area_damage, roll_damage,
+};
+
+pub use groups::{
+    GroupDamageResult, GroupError, GroupHits, GroupHpMode, GroupMember, GroupTargets, MinionGroup,
+};
+
+pub use morale::{
+    MoraleCheck, MoraleError, MoraleGroup, MoraleRules, MoraleTrigger,
+};
+
+pub use positioning::{
+    AttackRange, Battlefield, DiagonalRule, Movement, Position, PositionError, RangeBand, RangeCheck,
+    Token, TokenLayout, Zone, check_range, DEFAULT_SQUARE_FEET, DEFAULT_ZONE_FEET,
+};
+
+pub use combat_report::{
+    CombatReport, CombatTally, CombatantReport, CombatantTally, ConditionCount, actual_difficulty, TOP_CONDITIONS,
+};
+
+pub use player_view::{
+    HealthStatus, PlayerCombatView, PlayerCombatant, PlayerTimelineEvent, PlayerViewSnapshot, is_public,
+    public_timeline, GM_ONLY_TAG, PLAYER_TIMELINE_LIMIT,
+};
+
+pub use history::{
+    CombatHistory, HistoryEntry, HistoryStatus, HistoryStep, timeline_event, COMBAT_LOG_TAG, MAX_HISTORY,
+};
+
+pub use journal::{
+    CombatJournal, JournalError, RecoveredCombat,
 };
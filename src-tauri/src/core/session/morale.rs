@@ -0,0 +1,336 @@
+//! Morale Module
+//!
+//! Optional morale for groups of enemies. A morale group names the
+//! combatants who fight together, their leader, and the events that shake
+//! them: the leader falling, or half the group going down. Each event
+//! prompts one morale roll under the game system's rules, and the result is
+//! a suggestion for the GM ("The goblins break and flee"), never a change to
+//! the fight on its own.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::combat::Combatant;
+use crate::core::character_gen::GameSystem;
+
+/// Morale save DC (D&D 5e optional morale rule)
+const MORALE_SAVE_DC: i32 = 10;
+
+/// Morale score of an average creature under the 2d6 rules
+const AVERAGE_MORALE: i32 = 7;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum MoraleError {
+    #[error("Morale group not found: {0}")]
+    GroupNotFound(String),
+
+    #[error("A morale group needs at least one member")]
+    NoMembers,
+
+    #[error("{0} has already broken")]
+    AlreadyBroken(String),
+}
+
+pub type Result<T> = std::result::Result<T, MoraleError>;
+
+// ============================================================================
+// Morale Rules
+// ============================================================================
+
+/// How a game system rolls morale
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MoraleRules {
+    /// Classic and OSR games: 2d6 over the morale score (2-12) breaks the
+    /// group. A score of 12 never breaks; 2 never fights on.
+    #[default]
+    TwoD6,
+    /// D&D 5e and Pathfinder: a d20 save plus the morale score as a bonus;
+    /// failing against `dc` breaks the group
+    Save { dc: i32 },
+    /// No roll; the GM is told to decide
+    Manual,
+}
+
+impl MoraleRules {
+    /// The rules a game system uses, falling back to the 2d6 roll for
+    /// custom systems and GM rulings for systems without morale
+    pub fn for_system(system: &str) -> Self {
+        match GameSystem::from_str(system) {
+            GameSystem::DnD5e | GameSystem::Pathfinder2e => Self::Save { dc: MORALE_SAVE_DC },
+            GameSystem::Custom(_) => Self::TwoD6,
+            _ => Self::Manual,
+        }
+    }
+
+    /// The morale score a group gets when none is given
+    pub fn default_morale(&self) -> i32 {
+        match self {
+            Self::TwoD6 => AVERAGE_MORALE,
+            Self::Save { .. } | Self::Manual => 0,
+        }
+    }
+
+    /// Roll morale for a score, returning the roll, what it was against,
+    /// and whether the group holds; all `None` under manual rules
+    fn roll<R: Rng>(&self, morale: i32, rng: &mut R) -> (Option<i32>, Option<i32>, Option<bool>) {
+        match self {
+            Self::TwoD6 => {
+                let roll = rng.gen_range(1..=6) + rng.gen_range(1..=6);
+                let holds = morale >= 12 || (morale > 2 && roll <= morale);
+                (Some(roll), Some(morale), Some(holds))
+            }
+            Self::Save { dc } => {
+                let roll = rng.gen_range(1..=20) + morale;
+                (Some(roll), Some(*dc), Some(roll >= *dc))
+            }
+            Self::Manual => (None, None, None),
+        }
+    }
+}
+
+// ============================================================================
+// Morale Groups
+// ============================================================================
+
+/// What calls for a morale check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MoraleTrigger {
+    /// The group's leader is down or dead
+    LeaderKilled,
+    /// Half the group or more is down
+    HalfDown,
+    /// The GM asked for a check
+    Called,
+}
+
+impl MoraleTrigger {
+    fn reason(&self) -> &'static str {
+        match self {
+            Self::LeaderKilled => "their leader has fallen",
+            Self::HalfDown => "half of them are down",
+            Self::Called => "morale check",
+        }
+    }
+}
+
+fn default_triggers() -> Vec<MoraleTrigger> {
+    vec![MoraleTrigger::LeaderKilled, MoraleTrigger::HalfDown]
+}
+
+/// Combatants who keep or lose their nerve together
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoraleGroup {
+    pub id: String,
+    /// Plural name used in suggestions, such as "goblins"
+    pub name: String,
+    pub member_ids: Vec<String>,
+    #[serde(default)]
+    pub leader_id: Option<String>,
+    /// Morale score under 2d6 rules, or the save bonus under save rules
+    pub morale: i32,
+    /// Events that call for a check; each fires once
+    #[serde(default = "default_triggers")]
+    pub triggers: Vec<MoraleTrigger>,
+    #[serde(default)]
+    pub fired: Vec<MoraleTrigger>,
+    /// The group failed a check; no more are rolled
+    #[serde(default)]
+    pub broken: bool,
+}
+
+impl MoraleGroup {
+    pub fn new(name: impl Into<String>, member_ids: Vec<String>, morale: i32) -> Result<Self> {
+        if member_ids.is_empty() {
+            return Err(MoraleError::NoMembers);
+        }
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.into(),
+            member_ids,
+            leader_id: None,
+            morale,
+            triggers: default_triggers(),
+            fired: vec![],
+            broken: false,
+        })
+    }
+
+    pub fn with_leader(mut self, leader_id: impl Into<String>) -> Self {
+        self.leader_id = Some(leader_id.into());
+        self
+    }
+
+    pub fn with_triggers(mut self, triggers: Vec<MoraleTrigger>) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Creatures in the group and how many are down. Minion groups count
+    /// each member; combatants no longer in the fight count as down.
+    pub fn casualties(&self, combatants: &[Combatant]) -> (usize, usize) {
+        self.member_ids.iter().fold((0, 0), |(down, total), id| {
+            match combatants.iter().find(|c| &c.id == id) {
+                Some(Combatant { group: Some(group), .. }) => {
+                    (down + group.size() - group.standing(), total + group.size())
+                }
+                Some(combatant) if !is_down(combatant) => (down, total + 1),
+                _ => (down + 1, total + 1),
+            }
+        })
+    }
+
+    /// Triggers that have happened but not yet been checked
+    pub fn pending(&self, combatants: &[Combatant]) -> Vec<MoraleTrigger> {
+        if self.broken {
+            return vec![];
+        }
+        let (down, total) = self.casualties(combatants);
+        self.triggers
+            .iter()
+            .copied()
+            .filter(|t| !self.fired.contains(t))
+            .filter(|t| match t {
+                MoraleTrigger::LeaderKilled => self
+                    .leader_id
+                    .as_ref()
+                    .is_some_and(|id| combatants.iter().find(|c| &c.id == id).is_none_or(is_down)),
+                MoraleTrigger::HalfDown => total > 0 && down * 2 >= total,
+                MoraleTrigger::Called => false,
+            })
+            .collect()
+    }
+
+    /// Roll morale for a trigger, marking it fired and the group broken on
+    /// a failure
+    pub fn check<R: Rng>(&mut self, trigger: MoraleTrigger, rules: &MoraleRules, rng: &mut R) -> MoraleCheck {
+        let (roll, target, holds) = rules.roll(self.morale, rng);
+        if !self.fired.contains(&trigger) {
+            self.fired.push(trigger);
+        }
+        self.broken = holds == Some(false);
+        MoraleCheck {
+            group_id: self.id.clone(),
+            group_name: self.name.clone(),
+            trigger,
+            roll,
+            target,
+            holds,
+            suggestion: suggestion(&self.name, trigger, roll, target, holds),
+        }
+    }
+}
+
+fn is_down(combatant: &Combatant) -> bool {
+    !combatant.is_active || combatant.death.is_dead() || combatant.current_hp.is_some_and(|hp| hp <= 0)
+}
+
+// ============================================================================
+// Morale Checks
+// ============================================================================
+
+/// A morale roll and what it suggests to the GM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoraleCheck {
+    pub group_id: String,
+    pub group_name: String,
+    pub trigger: MoraleTrigger,
+    pub roll: Option<i32>,
+    /// The morale score or save DC rolled against
+    pub target: Option<i32>,
+    /// Whether the group holds; `None` leaves it to the GM
+    pub holds: Option<bool>,
+    /// "The goblins break and flee (their leader has fallen; rolled 9 against 7)"
+    pub suggestion: String,
+}
+
+fn suggestion(
+    name: &str,
+    trigger: MoraleTrigger,
+    roll: Option<i32>,
+    target: Option<i32>,
+    holds: Option<bool>,
+) -> String {
+    let outcome = match holds {
+        Some(true) => format!("The {} hold their ground", name),
+        Some(false) => format!("The {} break and flee", name),
+        None => format!("The {} may break; decide whether they flee", name),
+    };
+    match roll.zip(target) {
+        Some((roll, target)) => format!("{} ({}; rolled {} against {})", outcome, trigger.reason(), roll, target),
+        None => format!("{} ({})", outcome, trigger.reason()),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::CombatantType;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn goblin(name: &str, hp: i32) -> Combatant {
+        let mut combatant = Combatant::new(name, 10, CombatantType::Monster);
+        combatant.current_hp = Some(hp);
+        combatant.max_hp = Some(7);
+        combatant
+    }
+
+    #[test]
+    fn test_triggers() {
+        let mut combatants = vec![goblin("Boss", 7), goblin("Goblin 1", 7), goblin("Goblin 2", 7), goblin("Goblin 3", 7)];
+        let ids: Vec<String> = combatants.iter().map(|c| c.id.clone()).collect();
+        let mut group = MoraleGroup::new("goblins", ids.clone(), 12).unwrap().with_leader(&ids[0]);
+        assert!(group.pending(&combatants).is_empty());
+
+        combatants[1].current_hp = Some(0);
+        assert_eq!(group.casualties(&combatants), (1, 4));
+        assert!(group.pending(&combatants).is_empty());
+
+        combatants[0].current_hp = Some(0);
+        assert_eq!(group.pending(&combatants), vec![MoraleTrigger::LeaderKilled, MoraleTrigger::HalfDown]);
+
+        // A score of 12 never breaks, and each trigger is only checked once
+        let mut rng = StdRng::seed_from_u64(7);
+        let check = group.check(MoraleTrigger::LeaderKilled, &MoraleRules::TwoD6, &mut rng);
+        assert_eq!(check.holds, Some(true));
+        assert!(check.suggestion.starts_with("The goblins hold their ground (their leader has fallen; rolled"));
+        assert_eq!(group.pending(&combatants), vec![MoraleTrigger::HalfDown]);
+
+        assert!(matches!(MoraleGroup::new("goblins", vec![], 7), Err(MoraleError::NoMembers)));
+    }
+
+    #[test]
+    fn test_rules() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let (roll, target, holds) = MoraleRules::TwoD6.roll(2, &mut rng);
+            assert!((2..=12).contains(&roll.unwrap()));
+            assert_eq!((target, holds), (Some(2), Some(false)));
+
+            let (roll, _, holds) = MoraleRules::Save { dc: 10 }.roll(3, &mut rng);
+            assert_eq!(holds, Some(roll.unwrap() >= 10));
+        }
+        assert_eq!(MoraleRules::Manual.roll(7, &mut rng), (None, None, None));
+
+        assert_eq!(MoraleRules::for_system("D&D 5e"), MoraleRules::Save { dc: 10 });
+        assert_eq!(MoraleRules::for_system("Call of Cthulhu"), MoraleRules::Manual);
+        assert_eq!(MoraleRules::TwoD6.default_morale(), 7);
+
+        let mut group = MoraleGroup::new("bandits", vec!["b1".to_string()], 0).unwrap();
+        let check = group.check(MoraleTrigger::Called, &MoraleRules::Manual, &mut rng);
+        assert_eq!(check.suggestion, "The bandits may break; decide whether they flee (morale check)");
+        assert!(!group.broken);
+    }
+}
@@ -0,0 +1,228 @@
+//! Player View Module
+//!
+//! What the players' screen shows: the initiative order, the token layout,
+//! and the session's public timeline. GM-only details are left out: exact
+//! hit points of anything that isn't a player character, armor class, stat
+//! blocks, notes, and timeline events that are minor or marked GM-only.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::combat::{CombatState, CombatStatus, Combatant, CombatantType};
+use super::positioning::TokenLayout;
+use super::timeline::{EventSeverity, TimelineEvent, TimelineEventType};
+
+/// Tag that keeps a timeline event off the players' screen
+pub const GM_ONLY_TAG: &str = "gm_only";
+
+/// Most recent public timeline events sent to the player view
+pub const PLAYER_TIMELINE_LIMIT: usize = 20;
+
+// ============================================================================
+// Combat
+// ============================================================================
+
+/// How hurt a creature looks, without its numbers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Unhurt,
+    Wounded,
+    Bloodied,
+    Down,
+    Dead,
+    Unknown,
+}
+
+impl HealthStatus {
+    pub fn of(combatant: &Combatant) -> Self {
+        if combatant.death.is_dead() {
+            return Self::Dead;
+        }
+        match (combatant.current_hp, combatant.max_hp) {
+            (Some(current), _) if current <= 0 => Self::Down,
+            (Some(current), Some(max)) if max > 0 && current >= max => Self::Unhurt,
+            (Some(current), Some(max)) if max > 0 && current * 2 <= max => Self::Bloodied,
+            (Some(_), Some(_)) => Self::Wounded,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// A combatant as the players see them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerCombatant {
+    pub id: String,
+    pub name: String,
+    pub combatant_type: CombatantType,
+    pub initiative: i32,
+    pub is_current: bool,
+    pub health: HealthStatus,
+    /// Exact hit points, shown for player characters only
+    pub current_hp: Option<i32>,
+    pub max_hp: Option<i32>,
+    pub temp_hp: Option<i32>,
+    pub conditions: Vec<String>,
+}
+
+impl PlayerCombatant {
+    fn from_combatant(combatant: &Combatant, is_current: bool) -> Self {
+        let is_player = combatant.combatant_type == CombatantType::Player;
+        Self {
+            id: combatant.id.clone(),
+            name: combatant.name.clone(),
+            combatant_type: combatant.combatant_type.clone(),
+            initiative: combatant.initiative,
+            is_current,
+            health: HealthStatus::of(combatant),
+            current_hp: combatant.current_hp.filter(|_| is_player),
+            max_hp: combatant.max_hp.filter(|_| is_player),
+            temp_hp: combatant.temp_hp.filter(|hp| is_player && *hp > 0),
+            conditions: combatant.condition_tracker.conditions().iter().map(|c| c.name.clone()).collect(),
+        }
+    }
+}
+
+/// The initiative tracker as the players see it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerCombatView {
+    pub round: u32,
+    pub current: Option<String>,
+    /// Combatants still in the fight, in initiative order
+    pub combatants: Vec<PlayerCombatant>,
+    pub layout: Option<TokenLayout>,
+}
+
+impl PlayerCombatView {
+    /// The players' view of a running fight; none once it has ended
+    pub fn from_combat(combat: &CombatState) -> Option<Self> {
+        if combat.status == CombatStatus::Ended {
+            return None;
+        }
+        let current = combat.current_combatant().map(|c| c.id.clone());
+        Some(Self {
+            round: combat.round,
+            current: combat.current_combatant().map(|c| c.name.clone()),
+            combatants: combat
+                .combatants
+                .iter()
+                .filter(|c| c.is_active)
+                .map(|c| PlayerCombatant::from_combatant(c, current.as_ref() == Some(&c.id)))
+                .collect(),
+            layout: TokenLayout::from_combat(combat),
+        })
+    }
+}
+
+// ============================================================================
+// Timeline
+// ============================================================================
+
+/// A timeline event the players can see
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerTimelineEvent {
+    pub id: String,
+    pub event_type: TimelineEventType,
+    pub timestamp: DateTime<Utc>,
+    pub title: String,
+    pub description: String,
+}
+
+/// Whether players can see an event: notable or more, not marked GM-only,
+/// and not about the GM's notes
+pub fn is_public(event: &TimelineEvent) -> bool {
+    event.severity >= EventSeverity::Notable
+        && !event.tags.iter().any(|t| t == GM_ONLY_TAG)
+        && !matches!(
+            event.event_type,
+            TimelineEventType::NoteAdded
+                | TimelineEventType::NoteEdited
+                | TimelineEventType::NoteDeleted
+                | TimelineEventType::NPCMood
+        )
+}
+
+/// The latest public events, newest first
+pub fn public_timeline(events: &[TimelineEvent]) -> Vec<PlayerTimelineEvent> {
+    events
+        .iter()
+        .rev()
+        .filter(|e| is_public(e))
+        .take(PLAYER_TIMELINE_LIMIT)
+        .map(|e| PlayerTimelineEvent {
+            id: e.id.clone(),
+            event_type: e.event_type.clone(),
+            timestamp: e.timestamp,
+            title: e.title.clone(),
+            description: e.description.clone(),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Snapshot
+// ============================================================================
+
+/// Everything on the players' screen for one session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerViewSnapshot {
+    pub session_id: String,
+    pub campaign_id: String,
+    pub combat: Option<PlayerCombatView>,
+    pub timeline: Vec<PlayerTimelineEvent>,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combatant(name: &str, combatant_type: CombatantType, current: i32, max: i32) -> Combatant {
+        let mut combatant = Combatant::new(name, 10, combatant_type);
+        combatant.current_hp = Some(current);
+        combatant.max_hp = Some(max);
+        combatant.armor_class = Some(15);
+        combatant
+    }
+
+    #[test]
+    fn test_combat_view_hides_monster_hp() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Fighter", CombatantType::Player, 20, 30));
+        combat.add_combatant(combatant("Ogre", CombatantType::Monster, 25, 59));
+        let mut scout = combatant("Scout", CombatantType::Monster, 11, 11);
+        scout.is_active = false;
+        combat.add_combatant(scout);
+
+        let view = PlayerCombatView::from_combat(&combat).unwrap();
+        assert_eq!(view.combatants.len(), 2);
+        let fighter = view.combatants.iter().find(|c| c.name == "Fighter").unwrap();
+        assert_eq!((fighter.current_hp, fighter.max_hp), (Some(20), Some(30)));
+        let ogre = view.combatants.iter().find(|c| c.name == "Ogre").unwrap();
+        assert_eq!((ogre.current_hp, ogre.health), (None, HealthStatus::Bloodied));
+
+        combat.end();
+        assert!(PlayerCombatView::from_combat(&combat).is_none());
+    }
+
+    #[test]
+    fn test_public_timeline() {
+        let events = vec![
+            TimelineEvent::new("s1", TimelineEventType::CombatStart, "Combat Initiated", "")
+                .with_severity(EventSeverity::Notable),
+            TimelineEvent::new("s1", TimelineEventType::PlayerRoll, "Rolled 2d6", ""),
+            TimelineEvent::new("s1", TimelineEventType::Custom("world_event".to_string()), "Cult meets", "")
+                .with_severity(EventSeverity::Important)
+                .with_tags([GM_ONLY_TAG]),
+            TimelineEvent::new("s1", TimelineEventType::NoteAdded, "Secret door", "")
+                .with_severity(EventSeverity::Important),
+            TimelineEvent::new("s1", TimelineEventType::CombatEnd, "Combat Concluded", "")
+                .with_severity(EventSeverity::Notable),
+        ];
+        let titles: Vec<_> = public_timeline(&events).into_iter().map(|e| e.title).collect();
+        assert_eq!(titles, vec!["Combat Concluded", "Combat Initiated"]);
+    }
+}
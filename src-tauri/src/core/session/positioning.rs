@@ -0,0 +1,444 @@
+//! Positioning Module
+//!
+//! Optional positions for combatants, either in abstract zones joined to
+//! their neighbours or on a square grid. Moves are checked against the
+//! battlefield and logged, attacks are checked for range, and the token
+//! layout is exposed for map views and the player window.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use thiserror::Error;
+
+use super::combat::{CombatState, Combatant, CombatantType};
+
+/// Feet a grid square covers unless the battlefield says otherwise
+pub const DEFAULT_SQUARE_FEET: u32 = 5;
+
+/// Rough feet between neighbouring zones, for range checks
+pub const DEFAULT_ZONE_FEET: u32 = 30;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum PositionError {
+    #[error("This combat doesn't track positions")]
+    NoBattlefield,
+
+    #[error("Unknown zone: {0}")]
+    UnknownZone(String),
+
+    #[error("Square ({x}, {y}) is off the grid")]
+    OutOfBounds { x: i32, y: i32 },
+
+    #[error("Square ({x}, {y}) is occupied by {name}")]
+    Occupied { x: i32, y: i32, name: String },
+
+    #[error("{0} is a square but the battlefield uses zones, or the other way around")]
+    WrongKind(String),
+
+    #[error("{0} has no position")]
+    Unplaced(String),
+}
+
+pub type Result<T> = std::result::Result<T, PositionError>;
+
+// ============================================================================
+// Battlefield
+// ============================================================================
+
+/// How diagonal grid moves are counted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagonalRule {
+    /// Every diagonal costs one square (D&D 5e)
+    #[default]
+    Uniform,
+    /// Diagonals alternate between one and two squares (PF2e)
+    Alternating,
+}
+
+/// An abstract area of the battlefield
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Zone {
+    pub id: String,
+    pub name: String,
+    /// Zones reachable in one move
+    #[serde(default)]
+    pub adjacent: Vec<String>,
+}
+
+impl Zone {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            adjacent: Vec::new(),
+        }
+    }
+
+    pub fn adjacent_to(mut self, zones: &[&str]) -> Self {
+        self.adjacent.extend(zones.iter().map(|z| z.to_string()));
+        self
+    }
+}
+
+/// Where combatants can stand
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Battlefield {
+    Zones {
+        zones: Vec<Zone>,
+        #[serde(default = "default_zone_feet")]
+        zone_feet: u32,
+    },
+    Grid {
+        width: u32,
+        height: u32,
+        #[serde(default = "default_square_feet")]
+        square_feet: u32,
+        #[serde(default)]
+        diagonals: DiagonalRule,
+    },
+}
+
+fn default_zone_feet() -> u32 {
+    DEFAULT_ZONE_FEET
+}
+
+fn default_square_feet() -> u32 {
+    DEFAULT_SQUARE_FEET
+}
+
+impl Battlefield {
+    pub fn zones(zones: Vec<Zone>) -> Self {
+        Self::Zones { zones, zone_feet: DEFAULT_ZONE_FEET }
+    }
+
+    pub fn grid(width: u32, height: u32) -> Self {
+        Self::Grid {
+            width,
+            height,
+            square_feet: DEFAULT_SQUARE_FEET,
+            diagonals: DiagonalRule::default(),
+        }
+    }
+
+    /// Check a position exists on this battlefield
+    pub fn validate(&self, position: &Position) -> Result<()> {
+        match (self, position) {
+            (Self::Zones { zones, .. }, Position::Zone { zone }) => {
+                if zones.iter().any(|z| &z.id == zone) {
+                    Ok(())
+                } else {
+                    Err(PositionError::UnknownZone(zone.clone()))
+                }
+            }
+            (Self::Grid { width, height, .. }, Position::Square { x, y }) => {
+                if (0..*width as i32).contains(x) && (0..*height as i32).contains(y) {
+                    Ok(())
+                } else {
+                    Err(PositionError::OutOfBounds { x: *x, y: *y })
+                }
+            }
+            _ => Err(PositionError::WrongKind(position.label(self))),
+        }
+    }
+
+    /// Feet between two positions, or None when no path joins them
+    pub fn distance_feet(&self, from: &Position, to: &Position) -> Option<u32> {
+        match (self, from, to) {
+            (Self::Zones { zones, zone_feet }, Position::Zone { zone: a }, Position::Zone { zone: b }) => {
+                zone_steps(zones, a, b).map(|steps| steps * zone_feet)
+            }
+            (
+                Self::Grid { square_feet, diagonals, .. },
+                Position::Square { x: x1, y: y1 },
+                Position::Square { x: x2, y: y2 },
+            ) => {
+                let dx = x1.abs_diff(*x2);
+                let dy = y1.abs_diff(*y2);
+                let squares = match diagonals {
+                    DiagonalRule::Uniform => dx.max(dy),
+                    DiagonalRule::Alternating => dx.max(dy) + dx.min(dy) / 2,
+                };
+                Some(squares * square_feet)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Moves between two zones through their neighbours
+fn zone_steps(zones: &[Zone], from: &str, to: &str) -> Option<u32> {
+    let mut seen = HashSet::from([from]);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((current, steps)) = queue.pop_front() {
+        if current == to {
+            return Some(steps);
+        }
+        // Zones are joined both ways, whichever side lists the other
+        let neighbours = zones.iter().filter_map(|z| {
+            if z.id == current {
+                Some(z.adjacent.iter().map(String::as_str).collect::<Vec<_>>())
+            } else if z.adjacent.iter().any(|a| a == current) {
+                Some(vec![z.id.as_str()])
+            } else {
+                None
+            }
+        });
+        for next in neighbours.flatten() {
+            if seen.insert(next) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Where a combatant stands
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Position {
+    Zone { zone: String },
+    Square { x: i32, y: i32 },
+}
+
+impl Position {
+    pub fn zone(zone: impl Into<String>) -> Self {
+        Self::Zone { zone: zone.into() }
+    }
+
+    pub fn square(x: i32, y: i32) -> Self {
+        Self::Square { x, y }
+    }
+
+    /// The zone's name or the square's coordinates
+    pub fn label(&self, battlefield: &Battlefield) -> String {
+        match (self, battlefield) {
+            (Self::Zone { zone }, Battlefield::Zones { zones, .. }) => zones
+                .iter()
+                .find(|z| &z.id == zone)
+                .map(|z| z.name.clone())
+                .unwrap_or_else(|| zone.clone()),
+            (Self::Zone { zone }, _) => zone.clone(),
+            (Self::Square { x, y }, _) => format!("({}, {})", x, y),
+        }
+    }
+}
+
+// ============================================================================
+// Movement and Range
+// ============================================================================
+
+/// A combatant's move across the battlefield
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Movement {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    pub from: Option<Position>,
+    pub to: Position,
+    /// Feet covered, when the combatant had a position to move from
+    pub distance_feet: Option<u32>,
+}
+
+impl Movement {
+    /// Combat log line for the move
+    pub fn describe(&self, battlefield: &Battlefield) -> String {
+        let to = self.to.label(battlefield);
+        match (&self.from, self.distance_feet) {
+            (None, _) => format!("{} is placed at {}", self.combatant_name, to),
+            (Some(_), Some(feet)) => format!("{} moves {} ft to {}", self.combatant_name, feet, to),
+            (Some(_), None) => format!("{} moves to {}", self.combatant_name, to),
+        }
+    }
+}
+
+/// An attack's normal range and the long range it can reach at a penalty
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttackRange {
+    pub normal_feet: u32,
+    #[serde(default)]
+    pub long_feet: Option<u32>,
+}
+
+impl AttackRange {
+    /// A melee attack with the given reach
+    pub fn reach(feet: u32) -> Self {
+        Self { normal_feet: feet, long_feet: None }
+    }
+
+    pub fn ranged(normal_feet: u32, long_feet: u32) -> Self {
+        Self { normal_feet, long_feet: Some(long_feet) }
+    }
+}
+
+/// Whether a target is within an attack's range
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RangeBand {
+    Normal,
+    /// Within long range, at disadvantage or a range penalty
+    Long,
+    OutOfRange,
+}
+
+/// The outcome of a range check between two combatants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RangeCheck {
+    pub attacker_id: String,
+    pub target_id: String,
+    pub distance_feet: Option<u32>,
+    pub band: RangeBand,
+}
+
+impl RangeCheck {
+    pub fn in_range(&self) -> bool {
+        self.band != RangeBand::OutOfRange
+    }
+}
+
+/// How far apart two positioned combatants are and whether an attack reaches
+pub fn check_range(
+    battlefield: &Battlefield,
+    attacker: &Combatant,
+    target: &Combatant,
+    range: AttackRange,
+) -> Result<RangeCheck> {
+    let from = attacker.position.as_ref().ok_or_else(|| PositionError::Unplaced(attacker.name.clone()))?;
+    let to = target.position.as_ref().ok_or_else(|| PositionError::Unplaced(target.name.clone()))?;
+    let distance_feet = battlefield.distance_feet(from, to);
+    let band = match distance_feet {
+        Some(feet) if feet <= range.normal_feet => RangeBand::Normal,
+        Some(feet) if range.long_feet.is_some_and(|long| feet <= long) => RangeBand::Long,
+        _ => RangeBand::OutOfRange,
+    };
+    Ok(RangeCheck {
+        attacker_id: attacker.id.clone(),
+        target_id: target.id.clone(),
+        distance_feet,
+        band,
+    })
+}
+
+// ============================================================================
+// Token Layout
+// ============================================================================
+
+/// A combatant's token for a map view, without hit points or other GM-only
+/// details so it can be shown to players
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    pub combatant_id: String,
+    pub name: String,
+    pub combatant_type: CombatantType,
+    pub position: Option<Position>,
+    pub is_current: bool,
+    /// At 0 HP or dead
+    pub is_down: bool,
+}
+
+/// Everything a map view needs to draw the battlefield
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenLayout {
+    pub battlefield: Battlefield,
+    pub round: u32,
+    pub tokens: Vec<Token>,
+}
+
+impl TokenLayout {
+    pub fn from_combat(combat: &CombatState) -> Option<Self> {
+        let battlefield = combat.battlefield.clone()?;
+        let current = combat.current_combatant().map(|c| c.id.clone());
+        let tokens = combat
+            .combatants
+            .iter()
+            .filter(|c| c.is_active)
+            .map(|c| Token {
+                combatant_id: c.id.clone(),
+                name: c.name.clone(),
+                combatant_type: c.combatant_type.clone(),
+                position: c.position.clone(),
+                is_current: current.as_ref() == Some(&c.id),
+                is_down: c.death.is_dead() || c.current_hp == Some(0),
+            })
+            .collect();
+        Some(Self {
+            battlefield,
+            round: combat.round,
+            tokens,
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placed(name: &str, position: Position) -> Combatant {
+        let mut combatant = Combatant::new(name, 10, CombatantType::Monster);
+        combatant.position = Some(position);
+        combatant
+    }
+
+    #[test]
+    fn test_grid_distances() {
+        let grid = Battlefield::grid(20, 20);
+        let (a, b) = (Position::square(0, 0), Position::square(3, 2));
+        assert_eq!(grid.distance_feet(&a, &b), Some(15));
+
+        let pf2e = Battlefield::Grid {
+            width: 20,
+            height: 20,
+            square_feet: 5,
+            diagonals: DiagonalRule::Alternating,
+        };
+        assert_eq!(pf2e.distance_feet(&a, &b), Some(20));
+        assert_eq!(pf2e.distance_feet(&a, &Position::square(3, 3)), Some(20));
+
+        assert!(grid.validate(&Position::square(19, 0)).is_ok());
+        assert!(matches!(grid.validate(&Position::square(20, 0)), Err(PositionError::OutOfBounds { .. })));
+        assert!(matches!(grid.validate(&Position::zone("hall")), Err(PositionError::WrongKind(_))));
+    }
+
+    #[test]
+    fn test_zone_distances() {
+        let zones = Battlefield::zones(vec![
+            Zone::new("hall", "Great Hall").adjacent_to(&["stairs"]),
+            Zone::new("stairs", "Stairs").adjacent_to(&["balcony"]),
+            Zone::new("balcony", "Balcony"),
+            Zone::new("vault", "Sealed Vault"),
+        ]);
+        let hall = Position::zone("hall");
+        assert_eq!(zones.distance_feet(&hall, &hall), Some(0));
+        assert_eq!(zones.distance_feet(&Position::zone("balcony"), &hall), Some(60));
+        assert_eq!(zones.distance_feet(&hall, &Position::zone("vault")), None);
+        assert!(matches!(zones.validate(&Position::zone("roof")), Err(PositionError::UnknownZone(_))));
+        assert_eq!(Position::zone("balcony").label(&zones), "Balcony");
+    }
+
+    #[test]
+    fn test_range_checks() {
+        let grid = Battlefield::grid(30, 30);
+        let archer = placed("Archer", Position::square(0, 0));
+        let near = placed("Goblin", Position::square(1, 1));
+        let far = placed("Orc", Position::square(25, 0));
+
+        let check = |target: &Combatant, range| check_range(&grid, &archer, target, range).unwrap().band;
+        assert_eq!(check(&near, AttackRange::reach(5)), RangeBand::Normal);
+        assert_eq!(check(&far, AttackRange::reach(5)), RangeBand::OutOfRange);
+        assert_eq!(check(&far, AttackRange::ranged(80, 320)), RangeBand::Long);
+        assert_eq!(check(&far, AttackRange::ranged(150, 600)), RangeBand::Normal);
+
+        let unplaced = Combatant::new("Ghost", 10, CombatantType::Monster);
+        assert!(matches!(
+            check_range(&grid, &archer, &unplaced, AttackRange::reach(5)),
+            Err(PositionError::Unplaced(_))
+        ));
+    }
+}
@@ -0,0 +1,335 @@
+//! Session Recap Module
+//!
+//! Builds the summarization prompt for an LLM-written session recap from the
+//! session's timeline, combat log, chat excerpts, and notes, and turns the
+//! model's answer into a session note with a "previously on…" intro.
+
+use serde::{Deserialize, Serialize};
+
+use super::combat::CombatEvent;
+use super::notes::{NoteCategory, SessionNote};
+use super::timeline::{EventSeverity, TimelineEvent};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Tag placed on generated recap notes
+pub const RECAP_NOTE_TAG: &str = "recap";
+
+/// Note metadata key holding the spoken intro text
+pub const PREVIOUSLY_ON_FIELD: &str = "previously_on";
+
+/// Most recent chat lines included in the prompt
+pub const MAX_CHAT_EXCERPTS: usize = 40;
+
+/// Most recent combat log entries included in the prompt
+pub const MAX_COMBAT_EVENTS: usize = 60;
+
+/// Longest single chat line or note body passed to the model
+const MAX_EXCERPT_CHARS: usize = 400;
+
+/// System instructions for recap generation
+pub const RECAP_SYSTEM_PROMPT: &str = "You are the narrator of a tabletop RPG campaign, \
+writing the recap of a session that has just been played. Stay faithful to what happened; \
+never invent outcomes. Respond only with valid JSON.";
+
+// ============================================================================
+// Recap Inputs
+// ============================================================================
+
+/// A line of table talk or narration from the session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatExcerpt {
+    pub speaker: String,
+    pub text: String,
+}
+
+/// Everything about a session that goes into its recap
+#[derive(Debug, Clone, Default)]
+pub struct RecapInputs {
+    pub session_number: u32,
+    pub session_title: Option<String>,
+    pub timeline: Vec<TimelineEvent>,
+    pub combat_log: Vec<CombatEvent>,
+    pub chat: Vec<ChatExcerpt>,
+    pub notes: Vec<SessionNote>,
+}
+
+impl RecapInputs {
+    pub fn new(session_number: u32, session_title: Option<String>) -> Self {
+        Self {
+            session_number,
+            session_title,
+            ..Default::default()
+        }
+    }
+
+    /// Builder: add timeline events
+    pub fn with_timeline(mut self, events: Vec<TimelineEvent>) -> Self {
+        self.timeline = events;
+        self
+    }
+
+    /// Builder: add the combat log
+    pub fn with_combat_log(mut self, events: Vec<CombatEvent>) -> Self {
+        self.combat_log = events;
+        self
+    }
+
+    /// Builder: add chat excerpts, oldest first
+    pub fn with_chat(mut self, chat: Vec<ChatExcerpt>) -> Self {
+        self.chat = chat;
+        self
+    }
+
+    /// Builder: add session notes; earlier recaps are left out
+    pub fn with_notes(mut self, notes: Vec<SessionNote>) -> Self {
+        self.notes = notes.into_iter().filter(|n| !is_recap_note(n)).collect();
+        self
+    }
+
+    /// Whether there is anything to summarize
+    pub fn is_empty(&self) -> bool {
+        self.significant_events().next().is_none()
+            && self.combat_log.is_empty()
+            && self.chat.is_empty()
+            && self.notes.is_empty()
+    }
+
+    /// Timeline events worth mentioning (background tracking is skipped)
+    fn significant_events(&self) -> impl Iterator<Item = &TimelineEvent> {
+        self.timeline.iter().filter(|e| e.severity > EventSeverity::Trace)
+    }
+
+    fn heading(&self) -> String {
+        match &self.session_title {
+            Some(title) => format!("Session {}: {}", self.session_number, title),
+            None => format!("Session {}", self.session_number),
+        }
+    }
+}
+
+/// Whether a note is a previously generated recap
+pub fn is_recap_note(note: &SessionNote) -> bool {
+    note.tags.iter().any(|t| t == RECAP_NOTE_TAG)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(max_chars) {
+        Some((idx, _)) => format!("{}…", &text[..idx]),
+        None => text.to_string(),
+    }
+}
+
+/// Render the session's timeline, combat log, chat, and notes as prompt text
+pub fn session_transcript(inputs: &RecapInputs) -> String {
+    let mut prompt = format!("SESSION: {}\n", inputs.heading());
+
+    let events: Vec<_> = inputs.significant_events().collect();
+    if !events.is_empty() {
+        prompt.push_str("\nTIMELINE:\n");
+        for event in events {
+            prompt.push_str(&format!("- [{}] {}", event.timestamp.format("%H:%M"), event.title));
+            if !event.description.is_empty() {
+                prompt.push_str(&format!(": {}", truncate(&event.description, MAX_EXCERPT_CHARS)));
+            }
+            prompt.push('\n');
+        }
+    }
+
+    if !inputs.combat_log.is_empty() {
+        prompt.push_str("\nCOMBAT LOG:\n");
+        let skip = inputs.combat_log.len().saturating_sub(MAX_COMBAT_EVENTS);
+        for event in inputs.combat_log.iter().skip(skip) {
+            prompt.push_str(&format!(
+                "- Round {}: {} ({:?}) {}\n",
+                event.round, event.actor, event.event_type, event.description
+            ));
+        }
+    }
+
+    if !inputs.chat.is_empty() {
+        prompt.push_str("\nTABLE EXCERPTS:\n");
+        let skip = inputs.chat.len().saturating_sub(MAX_CHAT_EXCERPTS);
+        for line in inputs.chat.iter().skip(skip) {
+            prompt.push_str(&format!("{}: {}\n", line.speaker, truncate(&line.text, MAX_EXCERPT_CHARS)));
+        }
+    }
+
+    if !inputs.notes.is_empty() {
+        prompt.push_str("\nGM NOTES:\n");
+        for note in &inputs.notes {
+            prompt.push_str(&format!("## {}\n{}\n", note.title, truncate(&note.content, MAX_EXCERPT_CHARS)));
+        }
+    }
+
+    prompt
+}
+
+/// Build the user prompt asking for a recap of the session
+pub fn build_recap_prompt(inputs: &RecapInputs) -> String {
+    let mut prompt = format!("Summarize the following TTRPG session.\n\n{}", session_transcript(inputs));
+    prompt.push_str(
+        r#"
+Please respond in JSON format with:
+{
+  "summary": "<2-4 paragraph recap in past tense>",
+  "highlights": ["<key moment>", "..."],
+  "previously_on": "<3-5 sentences spoken aloud at the start of next session, beginning with 'Previously on'>"
+}"#,
+    );
+
+    prompt
+}
+
+// ============================================================================
+// Generated Recap
+// ============================================================================
+
+/// A recap as written by the model
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeneratedRecap {
+    pub summary: String,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+    #[serde(default)]
+    pub previously_on: String,
+}
+
+impl GeneratedRecap {
+    /// Session note holding this recap
+    pub fn to_note(&self, session_id: &str, campaign_id: &str, inputs: &RecapInputs) -> SessionNote {
+        let mut content = self.summary.trim().to_string();
+        if !self.highlights.is_empty() {
+            content.push_str("\n\n### Highlights\n");
+            for highlight in &self.highlights {
+                content.push_str(&format!("- {}\n", highlight));
+            }
+        }
+
+        let mut note = SessionNote::new(session_id, campaign_id, format!("{} Recap", inputs.heading()), content)
+            .with_category(NoteCategory::Plot)
+            .with_tags([RECAP_NOTE_TAG])
+            .pinned();
+        note.author = "system".to_string();
+        if !self.previously_on.is_empty() {
+            note.ai_summary = Some(self.previously_on.clone());
+            note.metadata.insert(
+                PREVIOUSLY_ON_FIELD.to_string(),
+                serde_json::Value::String(self.previously_on.clone()),
+            );
+        }
+        note
+    }
+}
+
+/// Parse the model's recap, accepting fenced JSON and falling back to
+/// treating a plain-text answer as the summary
+pub fn parse_recap_response(response: &str) -> GeneratedRecap {
+    let trimmed = response.trim();
+    let json = trimmed
+        .find('{')
+        .zip(trimmed.rfind('}'))
+        .filter(|(start, end)| start < end)
+        .map(|(start, end)| &trimmed[start..=end]);
+
+    match json.and_then(|j| serde_json::from_str::<GeneratedRecap>(j).ok()) {
+        Some(recap) if !recap.summary.trim().is_empty() => recap,
+        _ => GeneratedRecap {
+            summary: trimmed.to_string(),
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::CombatEventType;
+    use crate::core::session::timeline::TimelineEventType;
+    use chrono::Utc;
+
+    fn sample_inputs() -> RecapInputs {
+        RecapInputs::new(3, Some("The Sunken Vault".to_string()))
+            .with_timeline(vec![
+                TimelineEvent::new("s-1", TimelineEventType::CombatTurnStart, "Turn", "Goblin's turn")
+                    .with_severity(EventSeverity::Trace),
+                TimelineEvent::new("s-1", TimelineEventType::LocationChange, "Vault Door", "The party breaks the seal")
+                    .with_severity(EventSeverity::Notable),
+            ])
+            .with_combat_log(vec![CombatEvent {
+                round: 2,
+                turn: 0,
+                timestamp: Utc::now(),
+                actor: "Mira".to_string(),
+                event_type: CombatEventType::Attack,
+                description: "strikes the warden".to_string(),
+            }])
+            .with_chat(vec![ChatExcerpt {
+                speaker: "Warden".to_string(),
+                text: "None shall pass the vault.".to_string(),
+            }])
+    }
+
+    #[test]
+    fn test_prompt_includes_all_sources() {
+        let inputs = sample_inputs().with_notes(vec![SessionNote::new("s-1", "c-1", "Loot", "A silver key")]);
+        let prompt = build_recap_prompt(&inputs);
+
+        assert!(prompt.contains("Session 3: The Sunken Vault"));
+        assert!(prompt.contains("The party breaks the seal"));
+        assert!(!prompt.contains("Goblin's turn"));
+        assert!(prompt.contains("Round 2: Mira"));
+        assert!(prompt.contains("Warden: None shall pass"));
+        assert!(prompt.contains("A silver key"));
+    }
+
+    #[test]
+    fn test_previous_recaps_are_not_summarized() {
+        let old = GeneratedRecap {
+            summary: "Old recap".to_string(),
+            ..Default::default()
+        }
+        .to_note("s-1", "c-1", &sample_inputs());
+        let inputs = RecapInputs::new(1, None).with_notes(vec![old]);
+
+        assert!(inputs.notes.is_empty());
+        assert!(inputs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_fenced_response() {
+        let response = "```json\n{\"summary\": \"They opened the vault.\", \"highlights\": [\"Seal broken\"], \
+                        \"previously_on\": \"Previously on...\"}\n```";
+        let recap = parse_recap_response(response);
+
+        assert_eq!(recap.summary, "They opened the vault.");
+        assert_eq!(recap.highlights, vec!["Seal broken"]);
+        assert_eq!(recap.previously_on, "Previously on...");
+    }
+
+    #[test]
+    fn test_parse_plain_text_falls_back_to_summary() {
+        let recap = parse_recap_response("The party rested at the inn.");
+        assert_eq!(recap.summary, "The party rested at the inn.");
+        assert!(recap.previously_on.is_empty());
+    }
+
+    #[test]
+    fn test_recap_note() {
+        let recap = GeneratedRecap {
+            summary: "They opened the vault.".to_string(),
+            highlights: vec!["Seal broken".to_string()],
+            previously_on: "Previously on...".to_string(),
+        };
+        let note = recap.to_note("s-1", "c-1", &sample_inputs());
+
+        assert!(is_recap_note(&note));
+        assert_eq!(note.title, "Session 3: The Sunken Vault Recap");
+        assert_eq!(note.category, NoteCategory::Plot);
+        assert!(note.content.contains("- Seal broken"));
+        assert_eq!(note.ai_summary.as_deref(), Some("Previously on..."));
+    }
+}
@@ -0,0 +1,319 @@
+//! Stat Block Combatants Module
+//!
+//! Turns parsed creature stat blocks into combat-ready combatants: hit points
+//! taken as the average or rolled from the formula, armor class, initiative
+//! from dexterity, damage resistances and immunities, and the saves,
+//! attacks, and traits kept on the combatant for reference during play.
+//! Recharge, per-turn, per-round, and X/Day abilities are tracked for use.
+//! Several copies get numbered names that carry on from creatures of the
+//! same name already in the fight.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::abilities::TrackedAbility;
+use super::actions::ActionPool;
+use super::combat::{Combatant, CombatantType};
+use super::damage::DamageDefenses;
+use super::initiative::{ability_modifier, InitiativeRules};
+use crate::core::campaign::dice::{DiceNotation, DiceRoller};
+use crate::ingestion::ttrpg::{Feature, StatBlockData};
+
+/// Legendary actions a creature gets each round when its stat block has any
+const DEFAULT_LEGENDARY_ACTIONS: u32 = 3;
+
+// ============================================================================
+// Hit Points
+// ============================================================================
+
+/// How a stat block's hit points become a combatant's
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HpMethod {
+    /// The printed average
+    #[default]
+    Average,
+    /// Rolled from the hit dice formula, falling back to the average
+    Rolled,
+}
+
+/// Hit points for one creature from a stat block
+pub fn stat_block_hp<R: Rng>(stat_block: &StatBlockData, method: HpMethod, rng: &mut R) -> Option<i32> {
+    let hp = stat_block.hit_points.as_ref()?;
+    let rolled = match method {
+        HpMethod::Average => None,
+        HpMethod::Rolled => hp
+            .formula
+            .as_deref()
+            .and_then(|f| DiceNotation::parse(&f.replace(' ', "")).ok())
+            .map(|dice| DiceRoller::new().roll_with_rng(&dice, rng).total),
+    };
+    Some(rolled.unwrap_or(hp.average).max(1))
+}
+
+// ============================================================================
+// Combatant Stats
+// ============================================================================
+
+/// An attack or other action from a stat block
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CombatantAction {
+    pub name: String,
+    pub description: String,
+    pub attack_bonus: Option<i32>,
+    pub damage: Option<String>,
+    pub reach: Option<String>,
+    /// Legendary action cost
+    pub cost: Option<i32>,
+}
+
+impl From<&Feature> for CombatantAction {
+    fn from(feature: &Feature) -> Self {
+        Self {
+            name: feature.name.clone(),
+            description: feature.description.clone(),
+            attack_bonus: feature.attack_bonus,
+            damage: feature.damage.clone(),
+            reach: feature.reach.clone(),
+            cost: feature.cost,
+        }
+    }
+}
+
+/// Stat block details a combatant carries into the fight
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CombatantStats {
+    pub challenge_rating: Option<f32>,
+    /// Saving throw bonuses by ability, covering every ability with a score
+    pub saving_throws: HashMap<String, i32>,
+    pub traits: Vec<CombatantAction>,
+    pub actions: Vec<CombatantAction>,
+    pub bonus_actions: Vec<CombatantAction>,
+    pub reactions: Vec<CombatantAction>,
+    pub legendary_actions: Vec<CombatantAction>,
+}
+
+impl CombatantStats {
+    pub fn from_stat_block(stat_block: &StatBlockData) -> Self {
+        let scores = &stat_block.ability_scores;
+        let mut saving_throws: HashMap<String, i32> = [
+            ("strength", scores.strength),
+            ("dexterity", scores.dexterity),
+            ("constitution", scores.constitution),
+            ("intelligence", scores.intelligence),
+            ("wisdom", scores.wisdom),
+            ("charisma", scores.charisma),
+        ]
+        .into_iter()
+        .filter_map(|(ability, score)| Some((ability.to_string(), ability_modifier(score?))))
+        .collect();
+        // Proficient saves override the plain ability modifier
+        for (ability, bonus) in &stat_block.saving_throws {
+            saving_throws.insert(full_ability_name(ability), *bonus);
+        }
+        let features = |list: &[Feature]| list.iter().map(CombatantAction::from).collect();
+
+        Self {
+            challenge_rating: stat_block.challenge_rating.as_ref().map(|cr| cr.value),
+            saving_throws,
+            traits: features(&stat_block.traits),
+            actions: features(&stat_block.actions),
+            bonus_actions: features(&stat_block.bonus_actions),
+            reactions: features(&stat_block.reactions),
+            legendary_actions: features(&stat_block.legendary_actions),
+        }
+    }
+
+    /// Actions with an attack bonus
+    pub fn attacks(&self) -> impl Iterator<Item = &CombatantAction> {
+        self.actions.iter().filter(|a| a.attack_bonus.is_some())
+    }
+
+    /// Traits and actions with limited uses, such as "Fire Breath
+    /// (Recharge 5–6)" or a once-per-turn trigger
+    pub fn limited_abilities(&self) -> Vec<TrackedAbility> {
+        self.traits
+            .iter()
+            .chain(&self.actions)
+            .chain(&self.bonus_actions)
+            .chain(&self.reactions)
+            .filter_map(|a| TrackedAbility::from_feature(&a.name, &a.description))
+            .collect()
+    }
+}
+
+fn full_ability_name(ability: &str) -> String {
+    match ability.trim().to_lowercase().as_str() {
+        "str" => "strength".to_string(),
+        "dex" => "dexterity".to_string(),
+        "con" => "constitution".to_string(),
+        "int" => "intelligence".to_string(),
+        "wis" => "wisdom".to_string(),
+        "cha" => "charisma".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// Building Combatants
+// ============================================================================
+
+/// Names for `count` copies of a creature. A single creature keeps its name
+/// unless the fight already has one by that name; otherwise copies are
+/// numbered after the highest number already taken.
+pub fn numbered_names(name: &str, count: u32, existing: &[Combatant]) -> Vec<String> {
+    let taken = existing
+        .iter()
+        .filter_map(|c| match c.name.strip_prefix(name) {
+            Some("") => Some(1),
+            Some(rest) => rest.strip_prefix(' ')?.parse::<u32>().ok(),
+            None => None,
+        })
+        .max();
+    match (count, taken) {
+        (1, None) => vec![name.to_string()],
+        (_, taken) => {
+            let start = taken.unwrap_or(0) + 1;
+            (start..start + count).map(|n| format!("{} {}", name, n)).collect()
+        }
+    }
+}
+
+/// Combatants for `count` copies of a stat block, each with their own hit
+/// points and initiative roll
+pub fn combatants_from_stat_block<R: Rng>(
+    stat_block: &StatBlockData,
+    count: u32,
+    combatant_type: CombatantType,
+    hp_method: HpMethod,
+    rules: &InitiativeRules,
+    existing: &[Combatant],
+    rng: &mut R,
+) -> Vec<Combatant> {
+    let stats = CombatantStats::from_stat_block(stat_block);
+    let defenses = DamageDefenses {
+        resistances: stat_block.damage_resistances.clone(),
+        immunities: stat_block.damage_immunities.clone(),
+        vulnerabilities: stat_block.damage_vulnerabilities.clone(),
+    };
+    let abilities = stats.limited_abilities();
+    let modifier = stat_block.ability_scores.dexterity.map(ability_modifier).unwrap_or(0);
+
+    numbered_names(&stat_block.name, count.max(1), existing)
+        .into_iter()
+        .map(|name| {
+            let mut combatant = Combatant::new(name, 0, combatant_type.clone());
+            rules.roll(modifier, rng).apply(&mut combatant);
+            let hp = stat_block_hp(stat_block, hp_method, rng);
+            combatant.current_hp = hp;
+            combatant.max_hp = hp;
+            combatant.armor_class = stat_block.armor_class.as_ref().map(|ac| ac.value);
+            combatant.condition_immunities = stat_block.condition_immunities.clone();
+            combatant.damage_defenses = defenses.clone();
+            combatant.abilities = abilities.clone();
+            if !stats.legendary_actions.is_empty() {
+                combatant.legendary_actions = Some(ActionPool::new(DEFAULT_LEGENDARY_ACTIONS));
+            }
+            combatant.stats = Some(stats.clone());
+            combatant
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingestion::ttrpg::stat_block::{ArmorClass, HitPoints};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn goblin() -> StatBlockData {
+        let mut scimitar = Feature::new("Scimitar".to_string(), "Melee Weapon Attack".to_string());
+        scimitar.attack_bonus = Some(4);
+        scimitar.damage = Some("1d6+2".to_string());
+        let mut goblin = StatBlockData {
+            name: "Goblin".to_string(),
+            armor_class: Some(ArmorClass { value: 15, armor_type: Some("leather armor, shield".to_string()) }),
+            hit_points: Some(HitPoints { average: 7, formula: Some("2d6".to_string()) }),
+            actions: vec![scimitar, Feature::new("Nimble Escape".to_string(), "Disengage or Hide".to_string())],
+            traits: vec![Feature::new("Cunning Strike (1/Turn)".to_string(), "Extra 1d6 damage".to_string())],
+            condition_immunities: vec!["charmed".to_string()],
+            damage_vulnerabilities: vec!["radiant".to_string()],
+            ..Default::default()
+        };
+        goblin.ability_scores.dexterity = Some(14);
+        goblin.ability_scores.wisdom = Some(8);
+        goblin.saving_throws.insert("Wis".to_string(), 1);
+        goblin
+    }
+
+    #[test]
+    fn test_hit_points() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let goblin = goblin();
+        assert_eq!(stat_block_hp(&goblin, HpMethod::Average, &mut rng), Some(7));
+        for _ in 0..20 {
+            let hp = stat_block_hp(&goblin, HpMethod::Rolled, &mut rng).unwrap();
+            assert!((2..=12).contains(&hp));
+        }
+
+        let mut ogre = goblin.clone();
+        ogre.hit_points = Some(HitPoints { average: 59, formula: Some("7d10 + 21".to_string()) });
+        let hp = stat_block_hp(&ogre, HpMethod::Rolled, &mut rng).unwrap();
+        assert!((28..=91).contains(&hp));
+
+        ogre.hit_points = Some(HitPoints { average: 59, formula: None });
+        assert_eq!(stat_block_hp(&ogre, HpMethod::Rolled, &mut rng), Some(59));
+    }
+
+    #[test]
+    fn test_numbered_names() {
+        assert_eq!(numbered_names("Goblin", 1, &[]), vec!["Goblin"]);
+        assert_eq!(numbered_names("Goblin", 3, &[]), vec!["Goblin 1", "Goblin 2", "Goblin 3"]);
+
+        let existing = vec![
+            Combatant::new("Goblin 1", 10, CombatantType::Monster),
+            Combatant::new("Goblin 2", 10, CombatantType::Monster),
+            Combatant::new("Goblin Boss", 10, CombatantType::Monster),
+        ];
+        assert_eq!(numbered_names("Goblin", 2, &existing), vec!["Goblin 3", "Goblin 4"]);
+
+        let lone = vec![Combatant::new("Ogre", 8, CombatantType::Monster)];
+        assert_eq!(numbered_names("Ogre", 1, &lone), vec!["Ogre 2"]);
+    }
+
+    #[test]
+    fn test_combatants_from_stat_block() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let combatants = combatants_from_stat_block(
+            &goblin(),
+            4,
+            CombatantType::Monster,
+            HpMethod::Average,
+            &InitiativeRules::default(),
+            &[],
+            &mut rng,
+        );
+        assert_eq!(combatants.len(), 4);
+        assert_eq!(combatants[3].name, "Goblin 4");
+
+        let goblin = &combatants[0];
+        assert_eq!((goblin.current_hp, goblin.max_hp, goblin.armor_class), (Some(7), Some(7), Some(15)));
+        assert_eq!(goblin.initiative_modifier, 2);
+        assert_eq!(goblin.condition_immunities, vec!["charmed".to_string()]);
+        assert_eq!(goblin.damage_defenses.vulnerabilities, vec!["radiant".to_string()]);
+        assert!(goblin.legendary_actions.is_none());
+        assert_eq!(goblin.abilities.len(), 1);
+        assert_eq!(goblin.abilities[0].name, "Cunning Strike");
+
+        let stats = goblin.stats.as_ref().unwrap();
+        assert_eq!(stats.saving_throws.get("dexterity"), Some(&2));
+        assert_eq!(stats.saving_throws.get("wisdom"), Some(&1));
+        assert_eq!(stats.attacks().map(|a| a.name.as_str()).collect::<Vec<_>>(), vec!["Scimitar"]);
+    }
+}
@@ -0,0 +1,160 @@
+//! Turn Order Module
+//!
+//! Changes to the initiative order made during a fight. A combatant can
+//! delay, stepping out of the order and coming back later in the round at a
+//! new initiative, or ready an action with a stated trigger, which is
+//! brought up when the combatant named in the trigger starts their turn.
+//! The GM can also move anyone to a new place in the order by hand.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::combat::Combatant;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum TurnOrderError {
+    #[error("It isn't {0}'s turn")]
+    NotTheirTurn(String),
+
+    #[error("{0} isn't delaying")]
+    NotDelaying(String),
+
+    #[error("{0} has no readied action")]
+    NothingReadied(String),
+
+    #[error("It's already {0}'s turn")]
+    AlreadyTheirTurn(String),
+
+    #[error("No place {0} in the initiative order")]
+    InvalidPosition(usize),
+}
+
+pub type Result<T> = std::result::Result<T, TurnOrderError>;
+
+// ============================================================================
+// Readied Actions
+// ============================================================================
+
+/// An action held until something happens; it lapses at the start of the
+/// combatant's next turn
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReadiedAction {
+    /// What the combatant will do, such as "attack with longsword"
+    pub action: String,
+    /// What sets it off, such as "the ogre comes through the door"
+    pub trigger: String,
+    /// A combatant whose turn brings the trigger up
+    #[serde(default)]
+    pub trigger_combatant_id: Option<String>,
+    /// Round it was readied in
+    pub round: u32,
+}
+
+/// A readied action to bring up, with who readied it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadiedReminder {
+    pub combatant_id: String,
+    pub combatant_name: String,
+    #[serde(flatten)]
+    pub readied: ReadiedAction,
+}
+
+impl ReadiedReminder {
+    pub fn from_combatant(combatant: &Combatant) -> Option<Self> {
+        Some(Self {
+            combatant_id: combatant.id.clone(),
+            combatant_name: combatant.name.clone(),
+            readied: combatant.readied.clone()?,
+        })
+    }
+
+    /// "Aria is ready to attack with longsword when the ogre comes through
+    /// the door"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} is ready to {} when {}",
+            self.combatant_name, self.readied.action, self.readied.trigger
+        )
+    }
+}
+
+/// Readied actions set off by `combatant_id`'s turn
+pub fn triggered_by(combatants: &[Combatant], combatant_id: &str) -> Vec<ReadiedReminder> {
+    combatants
+        .iter()
+        .filter(|c| c.is_active && !c.death.is_dead())
+        .filter(|c| {
+            c.readied
+                .as_ref()
+                .and_then(|r| r.trigger_combatant_id.as_deref())
+                .is_some_and(|id| id == combatant_id)
+        })
+        .filter_map(ReadiedReminder::from_combatant)
+        .collect()
+}
+
+// ============================================================================
+// Reordering
+// ============================================================================
+
+/// Initiative for a combatant placed at `position`: that of the combatant it
+/// now goes before, or of the one before it when it goes last
+pub fn initiative_at(order: &[Combatant], position: usize) -> Option<i32> {
+    order
+        .get(position + 1)
+        .or_else(|| position.checked_sub(1).and_then(|i| order.get(i)))
+        .map(|c| c.initiative)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::combat::CombatantType;
+
+    fn readied(on: Option<&str>) -> ReadiedAction {
+        ReadiedAction {
+            action: "attack with longsword".to_string(),
+            trigger: "the ogre comes through the door".to_string(),
+            trigger_combatant_id: on.map(str::to_string),
+            round: 1,
+        }
+    }
+
+    #[test]
+    fn test_triggered_by() {
+        let ogre = Combatant::new("Ogre", 8, CombatantType::Monster);
+        let mut aria = Combatant::new("Aria", 15, CombatantType::Player);
+        aria.readied = Some(readied(Some(&ogre.id)));
+        let mut bram = Combatant::new("Bram", 12, CombatantType::Player);
+        bram.readied = Some(readied(None));
+        let combatants = vec![aria, bram, ogre.clone()];
+
+        let reminders = triggered_by(&combatants, &ogre.id);
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(
+            reminders[0].describe(),
+            "Aria is ready to attack with longsword when the ogre comes through the door"
+        );
+        assert!(triggered_by(&combatants, &combatants[0].id).is_empty());
+    }
+
+    #[test]
+    fn test_initiative_at() {
+        let order: Vec<Combatant> = [18, 12, 5]
+            .into_iter()
+            .map(|init| Combatant::new("C", init, CombatantType::Monster))
+            .collect();
+        assert_eq!(initiative_at(&order, 0), Some(12));
+        assert_eq!(initiative_at(&order, 1), Some(5));
+        assert_eq!(initiative_at(&order, 2), Some(12));
+        assert_eq!(initiative_at(&order[..1], 0), None);
+    }
+}
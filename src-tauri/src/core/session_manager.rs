@@ -11,6 +11,7 @@
 //! - Custom condition builder support
 
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::RwLock;
@@ -31,13 +32,60 @@ use super::session::notes::{
     EntityType as NoteEntityType, NoteCategory, NotesManager, SessionNote,
 };
 
+// Combat history imports
+use super::session::history::{timeline_event, CombatHistory};
+
+// Stat block imports
+use super::session::stat_blocks::combatants_from_stat_block;
+use crate::ingestion::ttrpg::StatBlockData;
+
+// Area damage imports
+use super::session::damage::{apply_area_damage, roll_damage};
+
+// Session clock imports
+use super::session::clock::SessionClock;
+
+// Timeline capture imports
+use super::session::capture::combat_ended_event;
+
+// Player view imports
+use super::session::player_view::{public_timeline, PlayerCombatView};
+
+// Combat journal for crash recovery
+use super::session::journal::CombatJournal;
+
 // ============================================================================
 // Re-exports for backward compatibility
 // ============================================================================
 
 pub use super::session::combat::{
     CombatEvent, CombatEventType, CombatState, CombatStatus, Combatant, CombatantType,
+    ConditionExpiry, ConditionReminder, CurrentCombatant, ExpiryReason, HealthUpdate, TurnResult,
+};
+pub use super::session::abilities::{AbilityError, RechargeRoll, TrackedAbility, UsageLimit};
+pub use super::session::actions::{ActionError, ActionPool, ActionSpend, LairActions};
+pub use super::session::death::{DeathRules, DeathTrack, DeathUpdate, LifeState};
+pub use super::session::initiative::{InitiativeRoll, InitiativeRules, TieBreaker};
+pub use super::session::turn_order::{ReadiedAction, ReadiedReminder, TurnOrderError};
+pub use super::session::stat_blocks::{CombatantStats, HpMethod};
+pub use super::session::history::{HistoryStatus, HistoryStep};
+pub use super::session::combat_report::{CombatReport, CombatTally, CombatantReport, ConditionCount};
+pub use super::session::plan_types::{EncounterDifficulty, SessionPlan};
+pub use super::session::capture::{CaptureCategory, CaptureSettings};
+pub use super::session::player_view::{HealthStatus, PlayerViewSnapshot};
+pub use super::session::clock::{ClockConfig, ClockNotice, ClockNoticeKind, ClockStatus, SceneEstimate};
+pub use super::session::economy::{Activity, CombatMode, EconomyError, TurnActions, TurnSpend};
+pub use super::session::positioning::{
+    AttackRange, Battlefield, Movement, Position, PositionError, RangeBand, RangeCheck, TokenLayout,
 };
+pub use super::session::damage::{
+    AreaDamage, AreaDamageResult, Damage, DamageAdjustment, DamageBreakdown, DamageDefenses, DamageError, TargetDamage,
+};
+pub use super::session::groups::{
+    GroupDamageResult, GroupError, GroupHits, GroupHpMode, GroupMember, GroupTargets, MinionGroup,
+};
+pub use super::session::morale::{MoraleCheck, MoraleError, MoraleGroup, MoraleRules, MoraleTrigger};
+pub use super::session::journal::{JournalError, RecoveredCombat};
 
 // ============================================================================
 // Error Types
@@ -59,6 +107,42 @@ pub enum SessionError {
 
     #[error("Invalid initiative order")]
     InvalidInitiativeOrder,
+
+    #[error("Combatant is not dying: {0}")]
+    NotDying(String),
+
+    #[error(transparent)]
+    Action(#[from] ActionError),
+
+    #[error(transparent)]
+    Ability(#[from] AbilityError),
+
+    #[error(transparent)]
+    Damage(#[from] DamageError),
+
+    #[error(transparent)]
+    Economy(#[from] EconomyError),
+
+    #[error(transparent)]
+    Position(#[from] PositionError),
+
+    #[error(transparent)]
+    Group(#[from] GroupError),
+
+    #[error(transparent)]
+    Morale(#[from] MoraleError),
+
+    #[error(transparent)]
+    TurnOrder(#[from] TurnOrderError),
+
+    #[error(transparent)]
+    Journal(#[from] JournalError),
+
+    #[error("Nothing to undo")]
+    NothingToUndo,
+
+    #[error("Nothing to redo")]
+    NothingToRedo,
 }
 
 pub type Result<T> = std::result::Result<T, SessionError>;
@@ -80,6 +164,9 @@ pub struct GameSession {
     pub active_scene: Option<String>,
     pub title: Option<String>,
     pub order_index: i32,
+    /// Reports from the session's finished fights, oldest first
+    #[serde(default)]
+    pub combat_reports: Vec<CombatReport>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -140,6 +227,14 @@ pub struct SessionManager {
     timelines: RwLock<HashMap<String, SessionTimeline>>,
     // TASK-017: Notes manager
     notes_manager: RwLock<NotesManager>,
+    // Undo/redo history per session's combat
+    combat_history: RwLock<HashMap<String, CombatHistory>>,
+    // Real-time clock per running session
+    clocks: RwLock<HashMap<String, SessionClock>>,
+    // Which subsystems write to timelines on their own
+    capture: RwLock<CaptureSettings>,
+    // On-disk journal of running fights, when one is set
+    journal: Option<CombatJournal>,
 }
 
 impl Default for SessionManager {
@@ -155,9 +250,18 @@ impl SessionManager {
             campaign_sessions: RwLock::new(HashMap::new()),
             timelines: RwLock::new(HashMap::new()),
             notes_manager: RwLock::new(NotesManager::new()),
+            combat_history: RwLock::new(HashMap::new()),
+            clocks: RwLock::new(HashMap::new()),
+            capture: RwLock::new(CaptureSettings::default()),
+            journal: None,
         }
     }
 
+    /// Journal every change to a running fight so it survives a crash
+    pub fn set_combat_journal(&mut self, journal: CombatJournal) {
+        self.journal = Some(journal);
+    }
+
     // ========================================================================
     // Private Helpers - Reduce Lock Boilerplate
     // ========================================================================
@@ -184,7 +288,87 @@ impl SessionManager {
             .get_mut(session_id)
             .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
         let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        Ok(f(combat))
+        let result = f(combat);
+        self.journal_combat(session_id, combat);
+        Ok(result)
+    }
+
+    /// Write a changed combat to the journal, dropping the journal once the
+    /// fight is over. Failures are logged rather than failing the change.
+    fn journal_combat(&self, session_id: &str, combat: &CombatState) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        let written = match combat.status {
+            CombatStatus::Ended => journal.finish(session_id),
+            _ => journal.append(session_id, combat),
+        };
+        if let Err(e) = written {
+            log::warn!("Failed to journal combat for session {}: {}", session_id, e);
+        }
+    }
+
+    /// Run a combat change that can be undone. `describe` names the action
+    /// from the combat before the change; nothing is recorded when `f`
+    /// returns `None`.
+    fn with_recorded_combat<D, F, R>(&self, session_id: &str, describe: D, f: F) -> Result<Option<R>>
+    where
+        D: FnOnce(&CombatState) -> String,
+        F: FnOnce(&mut CombatState) -> Option<R>,
+    {
+        let (result, description, before) = self.with_combat_mut(session_id, |combat| {
+            let description = describe(combat);
+            let before = combat.snapshot();
+            (f(combat), description, before)
+        })?;
+        if result.is_some() {
+            self.combat_history
+                .write()
+                .unwrap()
+                .entry(session_id.to_string())
+                .or_default()
+                .record(description, before);
+        }
+        Ok(result)
+    }
+
+    /// Run a combat change on a combatant that can fail, recording it for
+    /// undo only when it succeeds
+    fn with_recorded_attempt<D, F, R, E>(&self, session_id: &str, combatant_id: &str, describe: D, f: F) -> Result<R>
+    where
+        D: FnOnce(&CombatState) -> String,
+        F: FnOnce(&mut CombatState) -> Option<std::result::Result<R, E>>,
+        SessionError: From<E>,
+    {
+        let mut error = None;
+        self.with_recorded_combat(session_id, describe, |combat| match f(combat)? {
+            Ok(result) => Some(result),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        })?
+        .ok_or_else(|| match error {
+            Some(e) => e.into(),
+            None => SessionError::CombatantNotFound(combatant_id.to_string()),
+        })
+    }
+
+    /// A combatant's name for action descriptions
+    fn combatant_name(combat: &CombatState, combatant_id: &str) -> String {
+        combat
+            .get_combatant(combatant_id)
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| combatant_id.to_string())
+    }
+
+    fn has_combatant(&self, session_id: &str, combatant_id: &str) -> bool {
+        self.sessions
+            .read()
+            .unwrap()
+            .get(session_id)
+            .and_then(|s| s.combat.as_ref())
+            .is_some_and(|c| c.get_combatant(combatant_id).is_some())
     }
 
     /// Find a combatant index in the combat state
@@ -209,6 +393,7 @@ impl SessionManager {
             active_scene: None,
             title: None,
             order_index: 0,
+            combat_reports: vec![],
         };
 
         // Store session
@@ -217,6 +402,11 @@ impl SessionManager {
             .unwrap()
             .insert(session.id.clone(), session.clone());
 
+        self.clocks
+            .write()
+            .unwrap()
+            .insert(session.id.clone(), SessionClock::new(&session.id, session.started_at));
+
         // Link to campaign
         self.campaign_sessions
             .write()
@@ -273,6 +463,7 @@ impl SessionManager {
             active_scene: None,
             title,
             order_index: session_number as i32,
+            combat_reports: vec![],
         };
 
         self.sessions
@@ -289,8 +480,69 @@ impl SessionManager {
         session
     }
 
+    /// Copy a campaign's sessions to another campaign as planned sessions
+    ///
+    /// Titles, numbering, and order carry over with new ids; play logs and
+    /// combat state do not, so the copies are ready to run with a new group.
+    pub fn copy_sessions(&self, from_campaign_id: &str, to_campaign_id: &str) -> Vec<GameSession> {
+        let session_ids = self
+            .campaign_sessions
+            .read()
+            .unwrap()
+            .get(from_campaign_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut copies: Vec<GameSession> = {
+            let sessions = self.sessions.read().unwrap();
+            session_ids
+                .iter()
+                .filter_map(|id| sessions.get(id))
+                .map(|s| GameSession {
+                    id: Uuid::new_v4().to_string(),
+                    campaign_id: to_campaign_id.to_string(),
+                    session_number: s.session_number,
+                    started_at: Utc::now(),
+                    ended_at: None,
+                    status: SessionStatus::Planned,
+                    combat: None,
+                    notes: vec![],
+                    active_scene: None,
+                    title: s.title.clone(),
+                    order_index: s.order_index,
+                    combat_reports: vec![],
+                })
+                .collect()
+        };
+        copies.sort_by_key(|s| (s.order_index, s.session_number));
+
+        let mut sessions = self.sessions.write().unwrap();
+        let mut campaign_sessions = self.campaign_sessions.write().unwrap();
+        let linked = campaign_sessions.entry(to_campaign_id.to_string()).or_default();
+        for copy in &copies {
+            sessions.insert(copy.id.clone(), copy.clone());
+            linked.push(copy.id.clone());
+        }
+
+        copies
+    }
+
+    /// Restore a session exactly as given (e.g. from a campaign archive)
+    pub fn import_session(&self, session: GameSession) {
+        self.campaign_sessions
+            .write()
+            .unwrap()
+            .entry(session.campaign_id.clone())
+            .or_default()
+            .push(session.id.clone());
+        self.sessions
+            .write()
+            .unwrap()
+            .insert(session.id.clone(), session);
+    }
+
     pub fn start_planned_session(&self, session_id: &str) -> Result<GameSession> {
-        self.with_session_mut(session_id, |session| {
+        let session = self.with_session_mut(session_id, |session| {
             session.status = SessionStatus::Active;
             session.started_at = Utc::now();
             session.notes.push(SessionLogEntry {
@@ -301,7 +553,12 @@ impl SessionManager {
                 actor: None,
             });
             session.clone()
-        })
+        })?;
+        self.clocks
+            .write()
+            .unwrap()
+            .insert(session.id.clone(), SessionClock::new(&session.id, session.started_at));
+        Ok(session)
     }
 
     pub fn get_session(&self, session_id: &str) -> Option<GameSession> {
@@ -367,13 +624,21 @@ impl SessionManager {
     pub fn pause_session(&self, session_id: &str) -> Result<()> {
         self.with_session_mut(session_id, |session| {
             session.status = SessionStatus::Paused;
-        })
+        })?;
+        if let Some(clock) = self.clocks.write().unwrap().get_mut(session_id) {
+            clock.pause(Utc::now());
+        }
+        Ok(())
     }
 
     pub fn resume_session(&self, session_id: &str) -> Result<()> {
         self.with_session_mut(session_id, |session| {
             session.status = SessionStatus::Active;
-        })
+        })?;
+        if let Some(clock) = self.clocks.write().unwrap().get_mut(session_id) {
+            clock.resume(Utc::now());
+        }
+        Ok(())
     }
 
     pub fn end_session(&self, session_id: &str) -> Result<SessionSummary> {
@@ -406,6 +671,7 @@ impl SessionManager {
 
             (summary, session.session_number, session.id.clone())
         };
+        self.clocks.write().unwrap().remove(session_id);
 
         // TASK-014: Log session end event to timeline
         let _ = self.log_session_event(
@@ -427,6 +693,88 @@ impl SessionManager {
         })
     }
 
+    // ========================================================================
+    // Session Clock
+    // ========================================================================
+
+    /// Run a closure against a running session's clock
+    fn with_clock_mut<F, R>(&self, session_id: &str, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut SessionClock) -> R,
+    {
+        let mut clocks = self.clocks.write().unwrap();
+        let clock = clocks
+            .get_mut(session_id)
+            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
+        Ok(f(clock))
+    }
+
+    pub fn session_clock(&self, session_id: &str) -> Result<ClockStatus> {
+        self.with_clock_mut(session_id, |clock| clock.status(Utc::now()))
+    }
+
+    pub fn configure_session_clock(&self, session_id: &str, config: ClockConfig) -> Result<ClockStatus> {
+        self.with_clock_mut(session_id, |clock| {
+            let now = Utc::now();
+            clock.configure(config, now);
+            clock.status(now)
+        })
+    }
+
+    /// Pace the session against a plan's scenes, starting from the first
+    pub fn set_clock_plan(&self, session_id: &str, scenes: Vec<SceneEstimate>) -> Result<ClockStatus> {
+        self.with_clock_mut(session_id, |clock| {
+            clock.set_scenes(scenes);
+            clock.status(Utc::now())
+        })
+    }
+
+    /// Mark the table as having reached a planned scene
+    pub fn set_clock_scene(&self, session_id: &str, scene_index: usize) -> Result<ClockStatus> {
+        self.with_clock_mut(session_id, |clock| {
+            clock.set_scene(scene_index);
+            clock.status(Utc::now())
+        })
+    }
+
+    /// Break reminders and pacing nudges due across running sessions
+    pub fn check_session_clocks(&self, now: DateTime<Utc>) -> Vec<ClockNotice> {
+        self.clocks
+            .write()
+            .unwrap()
+            .values_mut()
+            .flat_map(|clock| clock.check(now))
+            .collect()
+    }
+
+    // ========================================================================
+    // Player View
+    // ========================================================================
+
+    /// What the players' screen shows for a session
+    pub fn player_view(&self, session_id: &str) -> Result<PlayerViewSnapshot> {
+        let (campaign_id, combat) = {
+            let sessions = self.sessions.read().unwrap();
+            let session = sessions
+                .get(session_id)
+                .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
+            (session.campaign_id.clone(), session.combat.as_ref().and_then(PlayerCombatView::from_combat))
+        };
+        let timeline = self
+            .timelines
+            .read()
+            .unwrap()
+            .get(session_id)
+            .map(|t| public_timeline(t.events()))
+            .unwrap_or_default();
+        Ok(PlayerViewSnapshot {
+            session_id: session_id.to_string(),
+            campaign_id,
+            combat,
+            timeline,
+        })
+    }
+
     // ========================================================================
     // Session Logging
     // ========================================================================
@@ -479,40 +827,62 @@ impl SessionManager {
                 return Err(SessionError::CombatAlreadyActive);
             }
 
-            let combat = CombatState::new();
+            // Hero points last the whole session, not just one fight
+            let mut combat = CombatState::new();
+            if let Some(previous) = &session.combat {
+                combat.hero_points = previous.hero_points.clone();
+            }
             session.combat = Some(combat.clone());
+            if let Some(journal) = &self.journal {
+                if let Err(e) = journal.begin(session) {
+                    log::warn!("Failed to start combat journal for session {}: {}", session_id, e);
+                }
+            }
             combat
         };
+        self.combat_history.write().unwrap().remove(session_id);
 
         // TASK-014: Log combat start event to timeline
-        let _ = self.log_combat_timeline_event(
-            session_id,
-            TimelineEventType::CombatStart,
-            "Combat Initiated",
-            "Roll for initiative!",
-            EventSeverity::Notable,
-        );
+        self.capture_event(session_id, CaptureCategory::Combat, |session_id| {
+            TimelineEvent::new(session_id, TimelineEventType::CombatStart, "Combat Initiated", "Roll for initiative!")
+                .with_severity(EventSeverity::Notable)
+        });
 
         Ok(combat)
     }
 
-    pub fn end_combat(&self, session_id: &str) -> Result<()> {
-        let rounds = self.with_combat_mut(session_id, |combat| {
-            let rounds = combat.round;
+    /// End the combat, storing its report with the session
+    pub fn end_combat(&self, session_id: &str) -> Result<CombatReport> {
+        let report = self.with_combat_mut(session_id, |combat| {
             combat.end();
-            rounds
+            CombatReport::from_combat(combat)
         })?;
+        if let Some(session) = self.sessions.write().unwrap().get_mut(session_id) {
+            session.combat_reports.push(report.clone());
+        }
 
         // TASK-014: Log combat end event to timeline
-        let _ = self.log_combat_timeline_event(
-            session_id,
-            TimelineEventType::CombatEnd,
-            "Combat Concluded",
-            &format!("Combat ended after {} rounds", rounds),
-            EventSeverity::Notable,
-        );
+        self.capture_event(session_id, CaptureCategory::Combat, |session_id| combat_ended_event(session_id, &report));
 
-        Ok(())
+        Ok(report)
+    }
+
+    /// Rate how hard the current fight is expected to be, for its report
+    pub fn set_estimated_difficulty(&self, session_id: &str, difficulty: Option<EncounterDifficulty>) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.estimated_difficulty = difficulty)
+    }
+
+    /// A finished fight's report, or the latest one without `combat_id`
+    pub fn get_combat_report(&self, session_id: &str, combat_id: Option<&str>) -> Result<Option<CombatReport>> {
+        let sessions = self.sessions.read().unwrap();
+        let session = sessions
+            .get(session_id)
+            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
+        let report = match combat_id {
+            Some(id) => session.combat_reports.iter().find(|r| r.combat_id == id),
+            None => session.combat_reports.last(),
+        };
+        Ok(report.cloned())
     }
 
     pub fn get_combat(&self, session_id: &str) -> Option<CombatState> {
@@ -545,14 +915,35 @@ impl SessionManager {
         Ok(combatant)
     }
 
-    pub fn remove_combatant(&self, session_id: &str, combatant_id: &str) -> Result<()> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
+    /// Add copies of a stat block's creature, numbered after any of the same
+    /// name already fighting, with initiative rolled by the combat's rules
+    pub fn add_stat_block_combatants(
+        &self,
+        session_id: &str,
+        stat_block: &StatBlockData,
+        count: u32,
+        combatant_type: CombatantType,
+        hp_method: HpMethod,
+    ) -> Result<Vec<Combatant>> {
+        self.with_combat_mut(session_id, |combat| {
+            let added = combatants_from_stat_block(
+                stat_block,
+                count,
+                combatant_type,
+                hp_method,
+                &combat.initiative_rules,
+                &combat.combatants,
+                &mut rand::thread_rng(),
+            );
+            for combatant in &added {
+                combat.add_combatant(combatant.clone());
+            }
+            added
+        })
+    }
 
-        combat.remove_combatant(combatant_id)
+    pub fn remove_combatant(&self, session_id: &str, combatant_id: &str) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.remove_combatant(combatant_id))?
             .map(|_| ())
             .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
     }
@@ -578,15 +969,108 @@ impl SessionManager {
         })
     }
 
-    pub fn next_turn(&self, session_id: &str) -> Result<Option<Combatant>> {
+    pub fn set_initiative_rules(&self, session_id: &str, rules: InitiativeRules) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.set_initiative_rules(rules))
+    }
+
+    /// Roll initiative for a combatant with the combat's rules
+    pub fn roll_initiative(&self, session_id: &str, combatant_id: &str) -> Result<InitiativeRoll> {
+        self.with_combat_mut(session_id, |combat| combat.roll_initiative(combatant_id, &mut rand::thread_rng()))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Re-roll initiative for every combatant, returning the new order
+    pub fn reroll_initiative(&self, session_id: &str) -> Result<CombatState> {
+        self.with_combat_mut(session_id, |combat| {
+            combat.reroll_initiative(&mut rand::thread_rng());
+            combat.clone()
+        })
+    }
+
+    pub fn set_surprised(&self, session_id: &str, combatant_ids: &[String]) -> Result<CombatState> {
         self.with_combat_mut(session_id, |combat| {
-            let result = combat.next_turn();
-            result.current_combatant
+            combat.set_surprised(combatant_ids);
+            combat.clone()
         })
     }
 
+    pub fn next_turn(&self, session_id: &str) -> Result<Option<Combatant>> {
+        self.advance_turn(session_id).map(|result| result.current_combatant)
+    }
+
+    /// Advance to the next turn, returning the conditions that expired and
+    /// reminders for the new combatant
+    pub fn advance_turn(&self, session_id: &str) -> Result<TurnResult> {
+        self.with_recorded_combat(session_id, |_| "Next turn".to_string(), |combat| Some(combat.next_turn()))
+            .map(Option::unwrap_or_default)
+    }
+
     pub fn previous_turn(&self, session_id: &str) -> Result<Option<Combatant>> {
-        self.with_combat_mut(session_id, |combat| combat.previous_turn())
+        self.with_recorded_combat(session_id, |_| "Previous turn".to_string(), |combat| Some(combat.previous_turn()))
+            .map(Option::flatten)
+    }
+
+    /// Move a combatant to a new place in the initiative order (0 is
+    /// first), returning their new initiative
+    pub fn move_in_initiative(&self, session_id: &str, combatant_id: &str, position: usize) -> Result<i32> {
+        self.with_recorded_attempt(
+            session_id,
+            combatant_id,
+            |combat| format!("Move {} in initiative", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.move_in_order(combatant_id, position),
+        )
+    }
+
+    /// The current combatant delays, passing the turn on
+    pub fn delay_turn(&self, session_id: &str, combatant_id: &str) -> Result<TurnResult> {
+        self.with_recorded_attempt(
+            session_id,
+            combatant_id,
+            |combat| format!("{} delays", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.delay_turn(combatant_id),
+        )
+    }
+
+    /// A delaying combatant comes back into the order after the current
+    /// combatant and takes their turn
+    pub fn resume_delayed_turn(&self, session_id: &str, combatant_id: &str) -> Result<TurnResult> {
+        self.with_recorded_attempt(
+            session_id,
+            combatant_id,
+            |combat| format!("{} stops delaying", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.resume_delayed_turn(combatant_id),
+        )
+    }
+
+    /// Ready an action for a trigger, brought up at the start of
+    /// `trigger_combatant_id`'s turn when one is given
+    pub fn ready_action(
+        &self,
+        session_id: &str,
+        combatant_id: &str,
+        action: &str,
+        trigger: &str,
+        trigger_combatant_id: Option<String>,
+    ) -> Result<ReadiedAction> {
+        if let Some(id) = trigger_combatant_id.as_deref().filter(|id| !self.has_combatant(session_id, id)) {
+            return Err(SessionError::CombatantNotFound(id.to_string()));
+        }
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("{} readies an action", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.ready_action(combatant_id, action, trigger, trigger_combatant_id),
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Take a combatant's readied action, spending their reaction
+    pub fn take_readied_action(&self, session_id: &str, combatant_id: &str) -> Result<ReadiedAction> {
+        self.with_recorded_attempt(
+            session_id,
+            combatant_id,
+            |combat| format!("{} takes their readied action", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.take_readied_action(combatant_id),
+        )
     }
 
     pub fn get_current_combatant(&self, session_id: &str) -> Option<Combatant> {
@@ -599,107 +1083,544 @@ impl SessionManager {
     }
 
     // ========================================================================
-    // HP Tracking (Delegates to Combatant methods)
+    // Combat History
     // ========================================================================
 
-    pub fn damage_combatant(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<i32> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
-
-        let combatant = &mut combat.combatants[idx];
-        let new_hp = combatant.apply_damage(amount);
-        let name = combatant.name.clone();
+    /// Undo the latest damage, heal, condition, or turn change, restoring
+    /// the combat as it was before it. The combat log keeps every entry.
+    pub fn undo_combat_action(&self, session_id: &str) -> Result<HistoryStep> {
+        let mut histories = self.combat_history.write().unwrap();
+        let history = histories.entry(session_id.to_string()).or_default();
+        let (description, combat) = self
+            .with_combat_mut(session_id, |combat| history.undo(combat).map(|d| (d, combat.clone())))?
+            .ok_or(SessionError::NothingToUndo)?;
+        Ok(HistoryStep { description, combat, status: history.status() })
+    }
+
+    /// Redo the latest undone combat action
+    pub fn redo_combat_action(&self, session_id: &str) -> Result<HistoryStep> {
+        let mut histories = self.combat_history.write().unwrap();
+        let history = histories.entry(session_id.to_string()).or_default();
+        let (description, combat) = self
+            .with_combat_mut(session_id, |combat| history.redo(combat).map(|d| (d, combat.clone())))?
+            .ok_or(SessionError::NothingToRedo)?;
+        Ok(HistoryStep { description, combat, status: history.status() })
+    }
+
+    /// What undo and redo would do next
+    pub fn combat_history_status(&self, session_id: &str) -> HistoryStatus {
+        self.combat_history
+            .read()
+            .unwrap()
+            .get(session_id)
+            .map(CombatHistory::status)
+            .unwrap_or_default()
+    }
 
-        combat.log_event(&name, CombatEventType::Damage, format!("{} takes {} damage", name, amount));
-        Ok(new_hp)
+    /// Copy the combat log into the session timeline, skipping entries an
+    /// earlier export already copied. Returns how many were exported.
+    pub fn export_combat_log(&self, session_id: &str) -> Result<usize> {
+        let combat = self.get_combat(session_id).ok_or(SessionError::NoCombatActive)?;
+        let events: Vec<TimelineEvent> = {
+            let mut histories = self.combat_history.write().unwrap();
+            let history = histories.entry(session_id.to_string()).or_default();
+            history
+                .take_unexported(&combat.events)
+                .iter()
+                .map(|event| timeline_event(session_id, event))
+                .collect()
+        };
+        let count = events.len();
+        for event in events {
+            self.add_timeline_event(session_id, event)?;
+        }
+        Ok(count)
     }
 
-    pub fn heal_combatant(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<i32> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+    // ========================================================================
+    // Combat Recovery
+    // ========================================================================
 
-        let combatant = &mut combat.combatants[idx];
-        let new_hp = combatant.heal(amount);
-        let name = combatant.name.clone();
+    /// Bring back every fight a crash left in the journal, along with its
+    /// session. Returns the ids of the sessions whose combat was restored.
+    pub fn recover_journaled_combats(&self) -> Result<Vec<String>> {
+        let Some(journal) = &self.journal else {
+            return Ok(vec![]);
+        };
+        let mut restored = Vec::new();
+        for recovered in journal.load_all()? {
+            let session_id = recovered.session.id.clone();
+            if self.restore_combat(recovered) {
+                restored.push(session_id);
+            }
+        }
+        Ok(restored)
+    }
 
-        combat.log_event(&name, CombatEventType::Healing, format!("{} heals {} HP", name, amount));
-        Ok(new_hp)
+    /// Restore a session's fight from the journal, such as when the session
+    /// is reopened after a crash. A fight already running is kept as it is.
+    /// Returns `None` when the journal holds no fight for the session.
+    pub fn recover_combat(&self, session_id: &str) -> Result<Option<CombatState>> {
+        let Some(journal) = &self.journal else {
+            return Ok(None);
+        };
+        let Some(recovered) = journal.load(session_id)? else {
+            return Ok(None);
+        };
+        self.restore_combat(recovered);
+        Ok(self.get_combat(session_id))
     }
 
-    pub fn add_temp_hp(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<()> {
+    /// Put a journaled fight back into its session, importing the session
+    /// if it isn't loaded. Returns whether anything was restored.
+    fn restore_combat(&self, recovered: RecoveredCombat) -> bool {
         let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
-
-        combat.combatants[idx].add_temp_hp(amount);
-        Ok(())
+        match sessions.get_mut(&recovered.session.id) {
+            Some(session) if session.combat.as_ref().is_some_and(|c| c.status != CombatStatus::Ended) => false,
+            Some(session) => {
+                session.combat = recovered.session.combat;
+                true
+            }
+            None => {
+                drop(sessions);
+                self.import_session(recovered.session);
+                true
+            }
+        }
     }
 
     // ========================================================================
-    // Advanced Conditions (TASK-015)
+    // Legendary Actions, Lair Actions, and Reactions
     // ========================================================================
 
-    /// Add an advanced condition with full duration/stacking support
-    pub fn add_advanced_condition(
+    pub fn set_legendary_actions(&self, session_id: &str, combatant_id: &str, max: u32) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.set_legendary_actions(combatant_id, max))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Spend legendary actions; fails when the pool cannot cover the cost
+    pub fn spend_legendary_action(
         &self,
         session_id: &str,
         combatant_id: &str,
-        condition: AdvancedCondition,
-    ) -> Result<()> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        cost: u32,
+        action: Option<&str>,
+    ) -> Result<ActionSpend> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.spend_legendary_action(combatant_id, cost, action))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
 
-        let combatant = &mut combat.combatants[idx];
+    /// Spend a combatant's reaction; fails when it is already used
+    pub fn use_reaction(&self, session_id: &str, combatant_id: &str, reaction: Option<&str>) -> Result<ActionSpend> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.use_reaction(combatant_id, reaction))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
 
-        // Check for immunity
-        if combatant.is_immune_to(&condition.name) {
-            let name = combatant.name.clone();
-            let cond_name = condition.name.clone();
-            combat.log_event(
-                &name,
-                CombatEventType::ConditionApplied,
-                format!("{} is immune to {}", name, cond_name),
-            );
-            return Ok(());
+    /// Refill a combatant's legendary actions and reaction
+    pub fn refresh_actions(&self, session_id: &str, combatant_id: &str) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.refresh_actions(combatant_id))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    pub fn set_lair_actions(&self, session_id: &str, lair: Option<LairActions>) -> Result<()> {
+        let combatant_id = lair.as_ref().map(|l| l.combatant_id.clone()).unwrap_or_default();
+        self.with_combat_mut(session_id, |combat| combat.set_lair_actions(lair))?
+            .ok_or(SessionError::CombatantNotFound(combatant_id))
+    }
+
+    // ========================================================================
+    // Action Economy and Hero Points
+    // ========================================================================
+
+    /// Track the three-action economy or the standard turn
+    pub fn set_combat_mode(&self, session_id: &str, mode: CombatMode) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.set_mode(mode))
+    }
+
+    /// Spend a combatant's actions this turn; fails when too few are left
+    pub fn spend_actions(&self, session_id: &str, combatant_id: &str, activity: &Activity) -> Result<TurnSpend> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.spend_actions(combatant_id, activity))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
+
+    /// Gain or lose actions this turn
+    pub fn adjust_turn_actions(&self, session_id: &str, combatant_id: &str, delta: i32) -> Result<TurnActions> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.adjust_actions(combatant_id, delta))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
+
+    /// Give a combatant hero points, returning their new total
+    pub fn award_hero_points(&self, session_id: &str, combatant_id: &str, amount: u32) -> Result<u32> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.award_hero_points(combatant_id, amount))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
+
+    /// Spend a hero point to reroll, returning what is left
+    pub fn spend_hero_point(&self, session_id: &str, combatant_id: &str) -> Result<u32> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.spend_hero_point(combatant_id))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
+
+    /// Spend all of a dying combatant's hero points to stabilize them
+    pub fn heroic_recovery(&self, session_id: &str, combatant_id: &str) -> Result<DeathUpdate> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.heroic_recovery(combatant_id))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
+
+    // ========================================================================
+    // Limited Abilities
+    // ========================================================================
+
+    /// Use a recharge, per-turn, per-round, or X/Day ability. Uses can be
+    /// undone like damage.
+    pub fn use_ability(&self, session_id: &str, combatant_id: &str, ability: &str) -> Result<TrackedAbility> {
+        let mut error = None;
+        let used = self.with_recorded_combat(
+            session_id,
+            |combat| format!("{} uses {}", Self::combatant_name(combat, combatant_id), ability),
+            |combat| combat.use_ability(combatant_id, ability)?.map_err(|e| error = Some(e)).ok(),
+        )?;
+        if let Some(e) = error {
+            return Err(e.into());
         }
+        used.ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
 
-        let condition_name = condition.name.clone();
-        let combatant_name = combatant.name.clone();
+    /// Bring a limited ability back without rolling
+    pub fn restore_ability(&self, session_id: &str, combatant_id: &str, ability: &str) -> Result<TrackedAbility> {
+        Ok(self
+            .with_combat_mut(session_id, |combat| combat.restore_ability(combatant_id, ability))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))??)
+    }
 
-        match combatant.condition_tracker.add_condition(condition) {
-            Ok(()) => {
-                combat.log_event(
-                    combatant_name,
-                    CombatEventType::ConditionApplied,
-                    format!("Gained condition: {}", condition_name),
-                );
-            }
-            Err(msg) => {
-                combat.log_event(
-                    combatant_name,
-                    CombatEventType::Other,
-                    format!("Condition not applied: {}", msg),
-                );
+    /// Track a limited ability on a combatant
+    pub fn track_ability(&self, session_id: &str, combatant_id: &str, ability: TrackedAbility) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.track_ability(combatant_id, ability))?
+            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    // ========================================================================
+    // Positioning
+    // ========================================================================
+
+    /// Track positions in zones or on a grid, or stop with `None`
+    pub fn set_battlefield(&self, session_id: &str, battlefield: Option<Battlefield>) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.set_battlefield(battlefield))
+    }
+
+    /// Move a combatant to a zone or square. Moves can be undone so a later
+    /// undo doesn't quietly put the token back.
+    pub fn move_combatant(&self, session_id: &str, combatant_id: &str, to: Position) -> Result<Movement> {
+        let mut error = None;
+        let movement = self.with_recorded_combat(
+            session_id,
+            |combat| format!("Move {}", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.move_combatant(combatant_id, to)?.map_err(|e| error = Some(e)).ok(),
+        )?;
+        if let Some(e) = error {
+            return Err(e.into());
+        }
+        movement.ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Whether an attack from one combatant reaches another
+    pub fn check_attack_range(
+        &self,
+        session_id: &str,
+        attacker_id: &str,
+        target_id: &str,
+        range: AttackRange,
+    ) -> Result<RangeCheck> {
+        let combat = self.get_combat(session_id).ok_or(SessionError::NoCombatActive)?;
+        let missing = if combat.get_combatant(attacker_id).is_none() { attacker_id } else { target_id };
+        Ok(combat
+            .check_range(attacker_id, target_id, range)
+            .ok_or_else(|| SessionError::CombatantNotFound(missing.to_string()))??)
+    }
+
+    /// Token positions for a map view, or `None` when positions aren't tracked
+    pub fn token_layout(&self, session_id: &str) -> Result<Option<TokenLayout>> {
+        let combat = self.get_combat(session_id).ok_or(SessionError::NoCombatActive)?;
+        Ok(TokenLayout::from_combat(&combat))
+    }
+
+    // ========================================================================
+    // Minion Groups
+    // ========================================================================
+
+    /// Add a group of `size` creatures like `combatant`, each with its
+    /// maximum hit points, acting together on its initiative
+    pub fn add_combatant_group(
+        &self,
+        session_id: &str,
+        combatant: Combatant,
+        size: u32,
+        hp_mode: GroupHpMode,
+    ) -> Result<Combatant> {
+        let group = combatant.into_group(size, hp_mode)?;
+        self.add_combatant(session_id, group.clone())?;
+        Ok(group)
+    }
+
+    /// Add a group of a stat block's creature, with one initiative roll
+    pub fn add_stat_block_group(
+        &self,
+        session_id: &str,
+        stat_block: &StatBlockData,
+        size: u32,
+        combatant_type: CombatantType,
+        hp_method: HpMethod,
+        hp_mode: GroupHpMode,
+    ) -> Result<Combatant> {
+        self.with_combat_mut(session_id, |combat| {
+            let mut created = combatants_from_stat_block(
+                stat_block,
+                1,
+                combatant_type,
+                hp_method,
+                &combat.initiative_rules,
+                &combat.combatants,
+                &mut rand::thread_rng(),
+            );
+            let group = created.remove(0).into_group(size, hp_mode)?;
+            combat.add_combatant(group.clone());
+            Ok(group)
+        })?
+    }
+
+    /// Hit members of a group, dropping those whose hit points run out.
+    /// Undone as a single action.
+    pub fn damage_group(
+        &self,
+        session_id: &str,
+        combatant_id: &str,
+        damage: &Damage,
+        targets: &GroupTargets,
+    ) -> Result<GroupDamageResult> {
+        let damage_type = damage.damage_type.as_deref().map(|t| format!(" {}", t)).unwrap_or_default();
+        self.with_recorded_attempt(
+            session_id,
+            combatant_id,
+            |combat| format!("{}{} damage to {}", damage.amount, damage_type, Self::combatant_name(combat, combatant_id)),
+            |combat| combat.damage_group(combatant_id, damage, targets),
+        )
+    }
+
+    // ========================================================================
+    // Morale
+    // ========================================================================
+
+    pub fn set_morale_rules(&self, session_id: &str, rules: MoraleRules) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.morale_rules = rules)
+    }
+
+    /// Track morale for a group of combatants, all of whom must be in the
+    /// fight
+    pub fn add_morale_group(&self, session_id: &str, group: MoraleGroup) -> Result<MoraleGroup> {
+        self.with_combat_mut(session_id, |combat| {
+            let missing = group
+                .member_ids
+                .iter()
+                .chain(&group.leader_id)
+                .find(|id| combat.get_combatant(id).is_none());
+            if let Some(id) = missing {
+                return Err(SessionError::CombatantNotFound(id.clone()));
             }
+            combat.add_morale_group(group.clone());
+            Ok(group)
+        })?
+    }
+
+    pub fn remove_morale_group(&self, session_id: &str, group_id: &str) -> Result<MoraleGroup> {
+        self.with_combat_mut(session_id, |combat| combat.remove_morale_group(group_id))?
+            .ok_or_else(|| MoraleError::GroupNotFound(group_id.to_string()).into())
+    }
+
+    /// Roll morale for groups shaken by what just happened: a leader
+    /// falling or half the group going down
+    pub fn check_morale(&self, session_id: &str) -> Result<Vec<MoraleCheck>> {
+        self.with_combat_mut(session_id, |combat| combat.check_morale(&mut rand::thread_rng()))
+    }
+
+    /// Roll morale for a group when the GM asks for it
+    pub fn call_morale_check(&self, session_id: &str, group_id: &str) -> Result<MoraleCheck> {
+        Ok(self.with_combat_mut(session_id, |combat| combat.call_morale_check(group_id, &mut rand::thread_rng()))??)
+    }
+
+    // ========================================================================
+    // HP Tracking (Delegates to Combatant methods)
+    // ========================================================================
+
+    pub fn damage_combatant(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<i32> {
+        self.apply_damage(session_id, combatant_id, amount, false).map(|update| update.hp)
+    }
+
+    /// Damage a combatant, applying the combat's death rules if they drop
+    /// to 0 HP. Critical hits count double against dying combatants.
+    pub fn apply_damage(&self, session_id: &str, combatant_id: &str, amount: i32, critical: bool) -> Result<HealthUpdate> {
+        self.take_damage(session_id, combatant_id, &Damage::new(amount).critical(critical))
+            .map(|breakdown| breakdown.health_update())
+    }
+
+    /// Deal a hit of typed damage, adjusted for the combatant's resistances,
+    /// immunities, and vulnerabilities, returning how it was applied
+    pub fn take_damage(&self, session_id: &str, combatant_id: &str, damage: &Damage) -> Result<DamageBreakdown> {
+        let damage_type = damage.damage_type.as_deref().map(|t| format!(" {}", t)).unwrap_or_default();
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("{}{} damage to {}", damage.amount, damage_type, Self::combatant_name(combat, combatant_id)),
+            |combat| combat.take_damage(combatant_id, damage),
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Replace a combatant's damage resistances, immunities, and
+    /// vulnerabilities
+    pub fn set_damage_defenses(&self, session_id: &str, combatant_id: &str, defenses: DamageDefenses) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| {
+            combat.get_combatant_mut(combatant_id).map(|c| c.damage_defenses = defenses)
+        })?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    /// Roll area damage once and apply it to every target, halved for those
+    /// whose save succeeded and adjusted for each target's resistances,
+    /// immunities, and vulnerabilities. Undone as a single action.
+    pub fn apply_damage_to_many(
+        &self,
+        session_id: &str,
+        combatant_ids: &[String],
+        damage: &AreaDamage,
+        saves: &HashMap<String, bool>,
+    ) -> Result<AreaDamageResult> {
+        if combatant_ids.is_empty() {
+            return Err(DamageError::NoTargets.into());
+        }
+        let rolled = roll_damage(&damage.expression, &mut rand::thread_rng())?;
+        let damage_type = damage.damage_type.as_deref().map(|t| format!(" {}", t)).unwrap_or_default();
+
+        // Nothing is applied unless every target is in the fight
+        let mut missing = None;
+        self.with_recorded_combat(
+            session_id,
+            |_| format!("{}{} damage to {} targets", rolled, damage_type, combatant_ids.len()),
+            |combat| {
+                missing = combatant_ids.iter().find(|id| combat.get_combatant(id).is_none()).cloned();
+                missing.is_none().then(|| apply_area_damage(combat, combatant_ids, damage, rolled, saves))
+            },
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(missing.unwrap_or_default()))
+    }
+
+    pub fn heal_combatant(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<i32> {
+        self.apply_healing(session_id, combatant_id, amount).map(|update| update.hp)
+    }
+
+    /// Heal a combatant, bringing them back from dying
+    pub fn apply_healing(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<HealthUpdate> {
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("Heal {} for {}", Self::combatant_name(combat, combatant_id), amount),
+            |combat| combat.heal(combatant_id, amount),
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    pub fn set_death_rules(&self, session_id: &str, rules: DeathRules) -> Result<()> {
+        self.with_combat_mut(session_id, |combat| combat.death_rules = rules)
+    }
+
+    /// Resolve a death save or recovery check, rolling the d20 when no
+    /// roll is given
+    pub fn roll_death_save(&self, session_id: &str, combatant_id: &str, roll: Option<i32>) -> Result<DeathUpdate> {
+        let roll = roll.unwrap_or_else(|| rand::thread_rng().gen_range(1..=20));
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("Death save for {}", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.roll_death_save(combatant_id, roll),
+        )?
+        .ok_or_else(|| SessionError::NotDying(combatant_id.to_string()))
+    }
+
+    /// Stabilize a dying combatant
+    pub fn stabilize_combatant(&self, session_id: &str, combatant_id: &str) -> Result<DeathUpdate> {
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("Stabilize {}", Self::combatant_name(combat, combatant_id)),
+            |combat| combat.stabilize(combatant_id),
+        )?
+        .ok_or_else(|| SessionError::NotDying(combatant_id.to_string()))
+    }
+
+    pub fn add_temp_hp(&self, session_id: &str, combatant_id: &str, amount: i32) -> Result<()> {
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("{} temporary HP for {}", amount, Self::combatant_name(combat, combatant_id)),
+            |combat| combat.get_combatant_mut(combatant_id).map(|c| c.add_temp_hp(amount)),
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
+
+    // ========================================================================
+    // Advanced Conditions (TASK-015)
+    // ========================================================================
+
+    /// Add an advanced condition with full duration/stacking support
+    pub fn add_advanced_condition(
+        &self,
+        session_id: &str,
+        combatant_id: &str,
+        condition: AdvancedCondition,
+    ) -> Result<()> {
+        let condition_name = condition.name.clone();
+        let added = self.with_recorded_combat(
+            session_id,
+            |combat| format!("{} on {}", condition_name, Self::combatant_name(combat, combatant_id)),
+            |combat| {
+                let idx = Self::find_combatant_index(combat, combatant_id)?;
+                let combatant = &mut combat.combatants[idx];
+                let combatant_name = combatant.name.clone();
+
+                // Check for immunity
+                if combatant.is_immune_to(&condition.name) {
+                    combat.log_event(
+                        &combatant_name,
+                        CombatEventType::ConditionApplied,
+                        format!("{} is immune to {}", combatant_name, condition_name),
+                    );
+                    return None;
+                }
+
+                match combatant.condition_tracker.add_condition(condition) {
+                    Ok(()) => {
+                        combat.tally.record_condition(&condition_name);
+                        combat.log_event(
+                            combatant_name,
+                            CombatEventType::ConditionApplied,
+                            format!("Gained condition: {}", condition_name),
+                        );
+                        Some(())
+                    }
+                    Err(msg) => {
+                        combat.log_event(
+                            combatant_name,
+                            CombatEventType::Other,
+                            format!("Condition not applied: {}", msg),
+                        );
+                        None
+                    }
+                }
+            },
+        )?;
+        if added.is_none() && !self.has_combatant(session_id, combatant_id) {
+            return Err(SessionError::CombatantNotFound(combatant_id.to_string()));
         }
         Ok(())
     }
@@ -753,27 +1674,36 @@ impl SessionManager {
         combatant_id: &str,
         condition_id: &str,
     ) -> Result<Option<AdvancedCondition>> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
-
-        let combatant = &mut combat.combatants[idx];
-        if let Some(removed) = combatant.condition_tracker.remove_condition(condition_id) {
-            let name = combatant.name.clone();
-            let cond_name = removed.name.clone();
-            combat.log_event(
-                &name,
-                CombatEventType::ConditionRemoved,
-                format!("{} loses condition: {}", name, cond_name),
-            );
-            Ok(Some(removed))
-        } else {
-            Ok(None)
+        let removed = self.with_recorded_combat(
+            session_id,
+            |combat| {
+                let condition = combat
+                    .get_combatant(combatant_id)
+                    .and_then(|c| c.condition_tracker.conditions().iter().find(|c| c.id == condition_id))
+                    .map(|c| c.name.clone())
+                    .unwrap_or_else(|| condition_id.to_string());
+                format!("Remove {} from {}", condition, Self::combatant_name(combat, combatant_id))
+            },
+            |combat| {
+                let idx = Self::find_combatant_index(combat, combatant_id)?;
+                let combatant = &mut combat.combatants[idx];
+                let removed = combatant.condition_tracker.remove_condition(condition_id)?;
+                let name = combatant.name.clone();
+                combat.log_event(
+                    &name,
+                    CombatEventType::ConditionRemoved,
+                    format!("{} loses condition: {}", name, removed.name),
+                );
+                if removed.is_concentration() {
+                    combat.release_concentration(std::slice::from_ref(&removed.id));
+                }
+                Some(removed)
+            },
+        )?;
+        if removed.is_none() && !self.has_combatant(session_id, combatant_id) {
+            return Err(SessionError::CombatantNotFound(combatant_id.to_string()));
         }
+        Ok(removed)
     }
 
     /// Remove all advanced conditions with a given name
@@ -783,26 +1713,68 @@ impl SessionManager {
         combatant_id: &str,
         condition_name: &str,
     ) -> Result<Vec<AdvancedCondition>> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        let removed = self.with_recorded_combat(
+            session_id,
+            |combat| format!("Remove {} from {}", condition_name, Self::combatant_name(combat, combatant_id)),
+            |combat| {
+                let idx = Self::find_combatant_index(combat, combatant_id)?;
+                let combatant = &mut combat.combatants[idx];
+                let removed = combatant.condition_tracker.remove_by_name(condition_name);
+                let name = combatant.name.clone();
+
+                for condition in &removed {
+                    combat.log_event(
+                        &name,
+                        CombatEventType::ConditionRemoved,
+                        format!("{} loses condition: {}", name, condition.name),
+                    );
+                }
+                let concentration_ids: Vec<String> = removed
+                    .iter()
+                    .filter(|c| c.is_concentration())
+                    .map(|c| c.id.clone())
+                    .collect();
+                combat.release_concentration(&concentration_ids);
+                Some(removed).filter(|r| !r.is_empty())
+            },
+        )?;
+        if removed.is_none() && !self.has_combatant(session_id, combatant_id) {
+            return Err(SessionError::CombatantNotFound(combatant_id.to_string()));
+        }
+        Ok(removed.unwrap_or_default())
+    }
 
-        let combatant = &mut combat.combatants[idx];
-        let removed = combatant.condition_tracker.remove_by_name(condition_name);
-        let name = combatant.name.clone();
+    /// Start a combatant concentrating on an effect, breaking any earlier
+    /// concentration. Returns the concentration condition and the conditions
+    /// that ended with the old one.
+    pub fn start_concentration(
+        &self,
+        session_id: &str,
+        combatant_id: &str,
+        effect: &str,
+        duration: AdvancedConditionDuration,
+    ) -> Result<(AdvancedCondition, Vec<ConditionExpiry>)> {
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("{} concentrates on {}", Self::combatant_name(combat, combatant_id), effect),
+            |combat| combat.start_concentration(combatant_id, effect, duration),
+        )?
+        .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))
+    }
 
-        for condition in &removed {
-            combat.log_event(
-                &name,
-                CombatEventType::ConditionRemoved,
-                format!("{} loses condition: {}", name, condition.name),
-            );
-        }
-        Ok(removed)
+    /// Break a combatant's concentration and end the effects it sustains
+    pub fn break_concentration(&self, session_id: &str, combatant_id: &str) -> Result<Vec<ConditionExpiry>> {
+        self.with_recorded_combat(
+            session_id,
+            |combat| format!("Break {}'s concentration", Self::combatant_name(combat, combatant_id)),
+            |combat| Some(combat.break_concentration(combatant_id)),
+        )
+        .map(Option::unwrap_or_default)
+    }
+
+    /// The concentration check a combatant must make after taking damage
+    pub fn concentration_check(&self, session_id: &str, combatant_id: &str, damage: i32) -> Option<ConditionReminder> {
+        self.get_combat(session_id)?.concentration_check(combatant_id, damage)
     }
 
     /// Get all advanced conditions for a combatant
@@ -830,16 +1802,13 @@ impl SessionManager {
         combatant_id: &str,
         condition_name: &str,
     ) -> Result<()> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        self.with_combat_mut(session_id, |combat| {
+            let idx = Self::find_combatant_index(combat, combatant_id)
+                .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
 
-        combat.combatants[idx].add_immunity(condition_name);
-        Ok(())
+            combat.combatants[idx].add_immunity(condition_name);
+            Ok(())
+        })?
     }
 
     /// Remove a condition immunity from a combatant
@@ -849,16 +1818,13 @@ impl SessionManager {
         combatant_id: &str,
         condition_name: &str,
     ) -> Result<()> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        self.with_combat_mut(session_id, |combat| {
+            let idx = Self::find_combatant_index(combat, combatant_id)
+                .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
 
-        combat.combatants[idx].remove_immunity(condition_name);
-        Ok(())
+            combat.combatants[idx].remove_immunity(condition_name);
+            Ok(())
+        })?
     }
 
     /// Attempt a saving throw against a condition
@@ -869,38 +1835,35 @@ impl SessionManager {
         condition_id: &str,
         roll: i32,
     ) -> Result<bool> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
-
-        let combatant = &mut combat.combatants[idx];
-        if let Some(cond) = combatant.condition_tracker.get_mut(condition_id) {
-            let success = cond.attempt_save(roll);
-            let name = combatant.name.clone();
-            let cond_name = cond.name.clone();
-
-            if success {
-                combatant.condition_tracker.remove_condition(condition_id);
-                combat.log_event(
-                    &name,
-                    CombatEventType::ConditionRemoved,
-                    format!("{} saved against {} (roll: {})", name, cond_name, roll),
-                );
+        self.with_combat_mut(session_id, |combat| {
+            let idx = Self::find_combatant_index(combat, combatant_id)
+                .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+
+            let combatant = &mut combat.combatants[idx];
+            if let Some(cond) = combatant.condition_tracker.get_mut(condition_id) {
+                let success = cond.attempt_save(roll);
+                let name = combatant.name.clone();
+                let cond_name = cond.name.clone();
+
+                if success {
+                    combatant.condition_tracker.remove_condition(condition_id);
+                    combat.log_event(
+                        &name,
+                        CombatEventType::ConditionRemoved,
+                        format!("{} saved against {} (roll: {})", name, cond_name, roll),
+                    );
+                } else {
+                    combat.log_event(
+                        &name,
+                        CombatEventType::Other,
+                        format!("{} failed save against {} (roll: {})", name, cond_name, roll),
+                    );
+                }
+                Ok(success)
             } else {
-                combat.log_event(
-                    &name,
-                    CombatEventType::Other,
-                    format!("{} failed save against {} (roll: {})", name, cond_name, roll),
-                );
+                Ok(false)
             }
-            Ok(success)
-        } else {
-            Ok(false)
-        }
+        })?
     }
 
     /// Tick conditions at end of turn for a specific combatant
@@ -910,28 +1873,25 @@ impl SessionManager {
         session_id: &str,
         combatant_id: &str,
     ) -> Result<Vec<String>> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        self.with_combat_mut(session_id, |combat| {
+            let idx = Self::find_combatant_index(combat, combatant_id)
+                .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
 
-        let combatant = &mut combat.combatants[idx];
-        let expired = combatant.condition_tracker.tick_end_of_turn(true);
-        let expired_names: Vec<String> = expired.iter().map(|c| c.name.clone()).collect();
+            let combatant = &mut combat.combatants[idx];
+            let expired = combatant.condition_tracker.tick_end_of_turn(true);
+            let expired_names: Vec<String> = expired.iter().map(|c| c.name.clone()).collect();
 
-        let name = combatant.name.clone();
-        for cond in &expired {
-            combat.log_event(
-                &name,
-                CombatEventType::ConditionRemoved,
-                format!("{} expired on {}", cond.name, name),
-            );
-        }
+            let name = combatant.name.clone();
+            for cond in &expired {
+                combat.log_event(
+                    &name,
+                    CombatEventType::ConditionRemoved,
+                    format!("{} expired on {}", cond.name, name),
+                );
+            }
 
-        Ok(expired_names)
+            Ok(expired_names)
+        })?
     }
 
     /// Tick conditions at start of turn for a specific combatant
@@ -941,28 +1901,25 @@ impl SessionManager {
         session_id: &str,
         combatant_id: &str,
     ) -> Result<Vec<String>> {
-        let mut sessions = self.sessions.write().unwrap();
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| SessionError::SessionNotFound(session_id.to_string()))?;
-        let combat = session.combat.as_mut().ok_or(SessionError::NoCombatActive)?;
-        let idx = Self::find_combatant_index(combat, combatant_id)
-            .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
+        self.with_combat_mut(session_id, |combat| {
+            let idx = Self::find_combatant_index(combat, combatant_id)
+                .ok_or_else(|| SessionError::CombatantNotFound(combatant_id.to_string()))?;
 
-        let combatant = &mut combat.combatants[idx];
-        let expired = combatant.condition_tracker.tick_start_of_turn(true);
-        let expired_names: Vec<String> = expired.iter().map(|c| c.name.clone()).collect();
+            let combatant = &mut combat.combatants[idx];
+            let expired = combatant.condition_tracker.tick_start_of_turn(true);
+            let expired_names: Vec<String> = expired.iter().map(|c| c.name.clone()).collect();
 
-        let name = combatant.name.clone();
-        for cond in &expired {
-            combat.log_event(
-                &name,
-                CombatEventType::ConditionRemoved,
-                format!("{} expired on {} (start of turn)", cond.name, name),
-            );
-        }
+            let name = combatant.name.clone();
+            for cond in &expired {
+                combat.log_event(
+                    &name,
+                    CombatEventType::ConditionRemoved,
+                    format!("{} expired on {} (start of turn)", cond.name, name),
+                );
+            }
 
-        Ok(expired_names)
+            Ok(expired_names)
+        })?
     }
 
     /// Get list of available condition templates
@@ -1242,6 +2199,48 @@ impl SessionManager {
         self.add_timeline_event(session_id, event)
     }
 
+    // ========================================================================
+    // Timeline Capture
+    // ========================================================================
+
+    /// Which subsystems write to the timeline on their own
+    pub fn capture_settings(&self) -> CaptureSettings {
+        self.capture.read().unwrap().clone()
+    }
+
+    pub fn set_capture_settings(&self, settings: CaptureSettings) {
+        *self.capture.write().unwrap() = settings;
+    }
+
+    pub fn set_capture_category(&self, category: CaptureCategory, enabled: bool) -> CaptureSettings {
+        let mut settings = self.capture.write().unwrap();
+        settings.set(category, enabled);
+        settings.clone()
+    }
+
+    /// Add an automatically captured event to a session's timeline, unless
+    /// its category is switched off
+    pub fn capture_event<F>(&self, session_id: &str, category: CaptureCategory, build: F) -> Option<TimelineEvent>
+    where
+        F: FnOnce(&str) -> TimelineEvent,
+    {
+        if !self.capture.read().unwrap().is_enabled(category) {
+            return None;
+        }
+        let event = build(session_id);
+        self.add_timeline_event(session_id, event.clone()).ok()?;
+        Some(event)
+    }
+
+    /// Capture an event in the campaign's active session, if one is running
+    pub fn capture_campaign_event<F>(&self, campaign_id: &str, category: CaptureCategory, build: F) -> Option<TimelineEvent>
+    where
+        F: FnOnce(&str) -> TimelineEvent,
+    {
+        let session = self.get_active_session(campaign_id)?;
+        self.capture_event(&session.id, category, build)
+    }
+
     /// Create a timeline for a new session (called automatically on session start)
     fn ensure_timeline_exists(&self, session_id: &str) {
         let mut timelines = self.timelines.write().unwrap();
@@ -1289,6 +2288,21 @@ mod tests {
         assert!(summary.ended_at.is_some());
     }
 
+    #[test]
+    fn test_copy_sessions_as_planned() {
+        let manager = SessionManager::new();
+        let played = manager.start_session("campaign-1", 1);
+        manager.end_session(&played.id).unwrap();
+        manager.create_planned_session("campaign-1", Some("The Bridge".to_string()));
+
+        let copies = manager.copy_sessions("campaign-1", "campaign-2");
+        assert_eq!(copies.len(), 2);
+        assert!(copies.iter().all(|s| s.status == SessionStatus::Planned && s.notes.is_empty()));
+        assert!(copies.iter().all(|s| s.id != played.id));
+        assert_eq!(manager.list_sessions("campaign-2").len(), 2);
+        assert_eq!(manager.list_sessions("campaign-1").len(), 2);
+    }
+
     #[test]
     fn test_combat_initiative() {
         let manager = SessionManager::new();
@@ -0,0 +1,374 @@
+//! Voice Intent Parsing
+//!
+//! Turns a transcript into a recognized game action. Recognition is
+//! deliberately conservative: anything that doesn't clearly match a combat
+//! or dice command becomes `VoiceIntent::Chat` so nothing is applied by
+//! accident.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::session::conditions::ConditionTemplates;
+
+// ============================================================================
+// Intent Types
+// ============================================================================
+
+/// A game action recognized from speech
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VoiceIntent {
+    Damage { target: String, amount: i32 },
+    Heal { target: String, amount: i32 },
+    AddCondition { target: String, condition: String },
+    RemoveCondition { target: String, condition: String },
+    NextTurn,
+    PreviousTurn,
+    RollDice { expression: String },
+    /// Not a recognized action; pass through to chat
+    Chat { text: String },
+}
+
+impl VoiceIntent {
+    /// True if the intent acts on the combat tracker
+    pub fn is_combat(&self) -> bool {
+        !matches!(self, Self::Chat { .. } | Self::RollDice { .. })
+    }
+}
+
+// ============================================================================
+// Patterns
+// ============================================================================
+
+/// Digits, a single number word, a compound like "twenty-one", or "N hundred"
+const NUMBER: &str = r"(?P<amount>\d+|(?:twenty|thirty|forty|fifty|sixty|seventy|eighty|ninety)[\s-](?:one|two|three|four|five|six|seven|eight|nine)|[a-z]+(?:\s+hundred)?)";
+
+static DAMAGE_TAKES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(?P<target>.+?)\s+(?:takes|took|take|suffers|suffered)\s+{}\s+(?:points?\s+of\s+)?(?:\w+\s+)?damage$",
+        NUMBER
+    ))
+    .expect("valid regex")
+});
+
+static DAMAGE_DEAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(?:deal|do|apply)\s+{}\s+(?:points?\s+of\s+)?(?:\w+\s+)?damage\s+to\s+(?P<target>.+)$",
+        NUMBER
+    ))
+    .expect("valid regex")
+});
+
+static DAMAGE_HIT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(?:hit|damage)\s+(?P<target>.+?)\s+for\s+{}(?:\s+(?:points?|damage|hp))?$",
+        NUMBER
+    ))
+    .expect("valid regex")
+});
+
+static HEAL_VERB_FIRST: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^heal\s+(?P<target>.+?)\s+(?:for|by)\s+{}(?:\s+(?:points?|hit points|hp))?$",
+        NUMBER
+    ))
+    .expect("valid regex")
+});
+
+static HEAL_SUBJECT_FIRST: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(?P<target>.+?)\s+(?:heals|healed|regains|regained|recovers|gains|gained)\s+(?:for\s+)?{}(?:\s+(?:points?|hit points|hp|health))?$",
+        NUMBER
+    ))
+    .expect("valid regex")
+});
+
+static CONDITION_REMOVE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<target>.+?)\s+is\s+no\s+longer\s+(?P<condition>[a-z ]+)$").expect("valid regex")
+});
+
+static CONDITION_ADD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<target>.+?)\s+is\s+(?:now\s+)?(?P<condition>[a-z ]+)$").expect("valid regex")
+});
+
+static DICE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^roll\s+(?:an?\s+)?(?P<count>\d+|[a-z]+)?\s*d\s*(?P<sides>\d+|[a-z]+)(?:\s*(?P<sign>\+|-|plus|minus)\s*(?P<modifier>\d+|[a-z]+))?$",
+    )
+    .expect("valid regex")
+});
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Parse a transcript into a game intent
+pub fn parse_intent(transcript: &str) -> VoiceIntent {
+    let normalized = normalize(transcript);
+    parse_normalized(&normalized).unwrap_or_else(|| VoiceIntent::Chat {
+        text: transcript.trim().to_string(),
+    })
+}
+
+fn parse_normalized(text: &str) -> Option<VoiceIntent> {
+    if text.is_empty() {
+        return None;
+    }
+
+    match text {
+        "next turn" | "next" | "end turn" | "end of turn" | "next combatant" => {
+            return Some(VoiceIntent::NextTurn)
+        }
+        "previous turn" | "go back" | "back one turn" | "undo turn" => {
+            return Some(VoiceIntent::PreviousTurn)
+        }
+        _ => {}
+    }
+
+    for re in [&*DAMAGE_TAKES, &*DAMAGE_DEAL, &*DAMAGE_HIT] {
+        if let Some(caps) = re.captures(text) {
+            let amount = parse_spoken_number(&caps["amount"])?;
+            return Some(VoiceIntent::Damage {
+                target: clean_target(&caps["target"])?,
+                amount,
+            });
+        }
+    }
+
+    for re in [&*HEAL_VERB_FIRST, &*HEAL_SUBJECT_FIRST] {
+        if let Some(caps) = re.captures(text) {
+            let amount = parse_spoken_number(&caps["amount"])?;
+            return Some(VoiceIntent::Heal {
+                target: clean_target(&caps["target"])?,
+                amount,
+            });
+        }
+    }
+
+    if let Some(caps) = CONDITION_REMOVE.captures(text) {
+        if let Some(condition) = match_condition(&caps["condition"]) {
+            return Some(VoiceIntent::RemoveCondition {
+                target: clean_target(&caps["target"])?,
+                condition,
+            });
+        }
+    }
+
+    if let Some(caps) = CONDITION_ADD.captures(text) {
+        if let Some(condition) = match_condition(&caps["condition"]) {
+            return Some(VoiceIntent::AddCondition {
+                target: clean_target(&caps["target"])?,
+                condition,
+            });
+        }
+    }
+
+    if let Some(caps) = DICE.captures(text) {
+        let count = match caps.name("count") {
+            Some(m) => parse_spoken_number(m.as_str())?,
+            None => 1,
+        };
+        let sides = match &caps["sides"] {
+            "percent" | "percentile" => 100,
+            s => parse_spoken_number(s)?,
+        };
+        let mut expression = format!("{}d{}", count, sides);
+        if let (Some(sign), Some(modifier)) = (caps.name("sign"), caps.name("modifier")) {
+            let value = parse_spoken_number(modifier.as_str())?;
+            let op = if matches!(sign.as_str(), "-" | "minus") { '-' } else { '+' };
+            expression.push(op);
+            expression.push_str(&value.to_string());
+        }
+        return Some(VoiceIntent::RollDice { expression });
+    }
+
+    None
+}
+
+/// Lowercase, strip punctuation (keeping +/-), and collapse whitespace
+fn normalize(text: &str) -> String {
+    let cleaned: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '+' || c == '-' || c == '\'' { c } else { ' ' })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Strip leading articles from a target name
+fn clean_target(target: &str) -> Option<String> {
+    let trimmed = target
+        .trim()
+        .trim_start_matches("the ")
+        .trim_start_matches("a ")
+        .trim_start_matches("an ")
+        .trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Match a spoken condition against the known condition templates
+fn match_condition(spoken: &str) -> Option<String> {
+    let spoken = spoken.trim();
+    ConditionTemplates::list_names()
+        .into_iter()
+        .find(|name| name.eq_ignore_ascii_case(spoken))
+        .map(String::from)
+}
+
+/// Parse a number written as digits or English words ("seven", "twenty-three")
+pub fn parse_spoken_number(text: &str) -> Option<i32> {
+    let text = text.trim();
+    if let Ok(n) = text.parse::<i32>() {
+        return Some(n);
+    }
+
+    let mut total = 0;
+    let mut matched = false;
+    for word in text.split(|c: char| c == ' ' || c == '-').filter(|w| !w.is_empty()) {
+        let value = match word {
+            "zero" => 0,
+            "one" | "a" | "an" => 1,
+            "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "six" => 6,
+            "seven" => 7,
+            "eight" => 8,
+            "nine" => 9,
+            "ten" => 10,
+            "eleven" => 11,
+            "twelve" => 12,
+            "thirteen" => 13,
+            "fourteen" => 14,
+            "fifteen" => 15,
+            "sixteen" => 16,
+            "seventeen" => 17,
+            "eighteen" => 18,
+            "nineteen" => 19,
+            "twenty" => 20,
+            "thirty" => 30,
+            "forty" => 40,
+            "fifty" => 50,
+            "sixty" => 60,
+            "seventy" => 70,
+            "eighty" => 80,
+            "ninety" => 90,
+            "hundred" => {
+                total = total.max(1) * 100;
+                matched = true;
+                continue;
+            }
+            _ => return None,
+        };
+        total += value;
+        matched = true;
+    }
+    matched.then_some(total)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage_takes() {
+        assert_eq!(
+            parse_intent("Goblin takes 7 damage."),
+            VoiceIntent::Damage { target: "goblin".to_string(), amount: 7 }
+        );
+        assert_eq!(
+            parse_intent("The orc chief takes twelve points of fire damage"),
+            VoiceIntent::Damage { target: "orc chief".to_string(), amount: 12 }
+        );
+    }
+
+    #[test]
+    fn test_damage_deal_and_hit() {
+        assert_eq!(
+            parse_intent("deal 5 damage to the ogre"),
+            VoiceIntent::Damage { target: "ogre".to_string(), amount: 5 }
+        );
+        assert_eq!(
+            parse_intent("hit goblin 2 for twenty-one"),
+            VoiceIntent::Damage { target: "goblin 2".to_string(), amount: 21 }
+        );
+    }
+
+    #[test]
+    fn test_heal() {
+        assert_eq!(
+            parse_intent("Heal Thorin for 8"),
+            VoiceIntent::Heal { target: "thorin".to_string(), amount: 8 }
+        );
+        assert_eq!(
+            parse_intent("Mira regains ten hit points"),
+            VoiceIntent::Heal { target: "mira".to_string(), amount: 10 }
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        assert_eq!(
+            parse_intent("The goblin is prone"),
+            VoiceIntent::AddCondition { target: "goblin".to_string(), condition: "Prone".to_string() }
+        );
+        assert_eq!(
+            parse_intent("Thorin is no longer poisoned"),
+            VoiceIntent::RemoveCondition { target: "thorin".to_string(), condition: "Poisoned".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_unknown_condition_is_chat() {
+        assert!(matches!(parse_intent("The king is angry"), VoiceIntent::Chat { .. }));
+    }
+
+    #[test]
+    fn test_turn_control() {
+        assert_eq!(parse_intent("Next turn."), VoiceIntent::NextTurn);
+        assert_eq!(parse_intent("go back"), VoiceIntent::PreviousTurn);
+    }
+
+    #[test]
+    fn test_dice() {
+        assert_eq!(
+            parse_intent("roll 2d6 plus 3"),
+            VoiceIntent::RollDice { expression: "2d6+3".to_string() }
+        );
+        assert_eq!(
+            parse_intent("Roll a d20"),
+            VoiceIntent::RollDice { expression: "1d20".to_string() }
+        );
+        assert_eq!(
+            parse_intent("roll three d eight minus one"),
+            VoiceIntent::RollDice { expression: "3d8-1".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_chat_passthrough_preserves_text() {
+        assert_eq!(
+            parse_intent("  What does the innkeeper say?  "),
+            VoiceIntent::Chat { text: "What does the innkeeper say?".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_parse_spoken_number() {
+        assert_eq!(parse_spoken_number("42"), Some(42));
+        assert_eq!(parse_spoken_number("seven"), Some(7));
+        assert_eq!(parse_spoken_number("thirty four"), Some(34));
+        assert_eq!(parse_spoken_number("one hundred"), Some(100));
+        assert_eq!(parse_spoken_number("lots"), None);
+    }
+}
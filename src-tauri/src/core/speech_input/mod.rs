@@ -0,0 +1,44 @@
+//! Speech Input Module
+//!
+//! Push-to-talk speech-to-text for hands-free table control. Audio is captured
+//! from the selected input device, transcribed (local whisper.cpp preferred,
+//! cloud Whisper as fallback), and parsed into game intents such as
+//! "goblin takes 7 damage" or "next turn". Anything that isn't a recognized
+//! game action is routed to chat.
+
+pub mod intent;
+pub mod recorder;
+
+use thiserror::Error;
+
+pub use intent::{parse_intent, parse_spoken_number, VoiceIntent};
+pub use recorder::{
+    list_input_devices, InputDeviceInfo, PushToTalkRecorder, Recording, WHISPER_SAMPLE_RATE,
+};
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum SpeechInputError {
+    #[error("No input device available: {0}")]
+    NoDevice(String),
+
+    #[error("Failed to open input stream: {0}")]
+    StreamError(String),
+
+    #[error("Unsupported sample format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Already recording")]
+    AlreadyRecording,
+
+    #[error("Not recording")]
+    NotRecording,
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, SpeechInputError>;
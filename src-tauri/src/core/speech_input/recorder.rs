@@ -0,0 +1,419 @@
+//! Push-to-Talk Recorder
+//!
+//! Captures microphone audio on a dedicated thread (cpal streams are not
+//! `Send`) between `start` and `stop`, downmixing to mono. The finished
+//! recording can be encoded as 16 kHz 16-bit WAV for Whisper.
+
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+use rodio::cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use rodio::cpal::{self, SampleFormat};
+use serde::{Deserialize, Serialize};
+
+use super::{Result, SpeechInputError};
+
+/// Sample rate expected by Whisper models
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
+/// Hard cap on a single push-to-talk capture
+const MAX_RECORDING_SECS: u32 = 120;
+
+/// RMS level below which a recording is treated as silence
+const SILENCE_RMS_THRESHOLD: f32 = 0.005;
+
+// ============================================================================
+// Device Enumeration
+// ============================================================================
+
+/// An audio input device available for recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+}
+
+/// List the input devices on the default audio host
+pub fn list_input_devices() -> Result<Vec<InputDeviceInfo>> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+
+    let devices = host
+        .input_devices()
+        .map_err(|e| SpeechInputError::NoDevice(e.to_string()))?;
+
+    Ok(devices
+        .filter_map(|device| {
+            let name = device.name().ok()?;
+            let config = device.default_input_config().ok();
+            Some(InputDeviceInfo {
+                is_default: default_name.as_deref() == Some(name.as_str()),
+                sample_rate: config.as_ref().map(|c| c.sample_rate().0),
+                channels: config.as_ref().map(|c| c.channels()),
+                name,
+            })
+        })
+        .collect())
+}
+
+fn find_input_device(name: Option<&str>) -> Result<cpal::Device> {
+    let host = cpal::default_host();
+    match name {
+        Some(wanted) => host
+            .input_devices()
+            .map_err(|e| SpeechInputError::NoDevice(e.to_string()))?
+            .find(|d| d.name().map(|n| n == wanted).unwrap_or(false))
+            .ok_or_else(|| SpeechInputError::NoDevice(wanted.to_string())),
+        None => host
+            .default_input_device()
+            .ok_or_else(|| SpeechInputError::NoDevice("no default input device".to_string())),
+    }
+}
+
+// ============================================================================
+// Recording
+// ============================================================================
+
+/// A finished mono recording
+#[derive(Debug, Clone)]
+pub struct Recording {
+    /// Mono samples in the range [-1.0, 1.0]
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+    pub device_name: String,
+}
+
+impl Recording {
+    pub fn duration_secs(&self) -> f32 {
+        if self.sample_rate == 0 {
+            return 0.0;
+        }
+        self.samples.len() as f32 / self.sample_rate as f32
+    }
+
+    /// Root-mean-square level of the recording
+    pub fn rms(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let sum: f32 = self.samples.iter().map(|s| s * s).sum();
+        (sum / self.samples.len() as f32).sqrt()
+    }
+
+    /// True if the recording contains no meaningful audio
+    pub fn is_silent(&self) -> bool {
+        self.rms() < SILENCE_RMS_THRESHOLD
+    }
+
+    /// Encode as 16-bit mono WAV at the given sample rate
+    pub fn to_wav(&self, target_rate: u32) -> Vec<u8> {
+        let samples = resample_linear(&self.samples, self.sample_rate, target_rate);
+        encode_wav_pcm16(&samples, target_rate)
+    }
+}
+
+/// Linear-interpolation resampler (adequate for speech recognition input)
+pub fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() || from_rate == 0 || to_rate == 0 {
+        return samples.to_vec();
+    }
+
+    let ratio = from_rate as f64 / to_rate as f64;
+    let out_len = ((samples.len() as f64) / ratio).floor() as usize;
+    (0..out_len)
+        .map(|i| {
+            let pos = i as f64 * ratio;
+            let idx = pos.floor() as usize;
+            let frac = (pos - idx as f64) as f32;
+            let a = samples[idx.min(samples.len() - 1)];
+            let b = samples[(idx + 1).min(samples.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+/// Encode mono f32 samples as a 16-bit PCM WAV file
+pub fn encode_wav_pcm16(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut out = Vec::with_capacity(44 + data_len as usize);
+
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    out.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    out.extend_from_slice(&1u16.to_le_bytes()); // mono
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+    out.extend_from_slice(&2u16.to_le_bytes()); // block align
+    out.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+
+    for s in samples {
+        let v = (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
+
+// ============================================================================
+// Push-to-Talk Recorder
+// ============================================================================
+
+struct ActiveCapture {
+    stop_tx: mpsc::Sender<()>,
+    handle: JoinHandle<()>,
+    buffer: Arc<Mutex<Vec<f32>>>,
+    sample_rate: u32,
+    device_name: String,
+    started_at: Instant,
+}
+
+/// Records microphone audio while the push-to-talk key is held
+#[derive(Default)]
+pub struct PushToTalkRecorder {
+    active: Option<ActiveCapture>,
+}
+
+impl PushToTalkRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Seconds elapsed since recording started
+    pub fn elapsed_secs(&self) -> Option<f32> {
+        self.active.as_ref().map(|a| a.started_at.elapsed().as_secs_f32())
+    }
+
+    /// Start capturing from the named device (or the system default)
+    pub fn start(&mut self, device_name: Option<&str>) -> Result<()> {
+        if self.active.is_some() {
+            return Err(SpeechInputError::AlreadyRecording);
+        }
+
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let (ready_tx, ready_rx) = mpsc::channel::<Result<(u32, String)>>();
+
+        let thread_buffer = buffer.clone();
+        let wanted = device_name.map(String::from);
+        let handle = std::thread::Builder::new()
+            .name("ptt-capture".to_string())
+            .spawn(move || {
+                let stream = match open_capture(wanted.as_deref(), thread_buffer) {
+                    Ok((stream, rate, name)) => {
+                        let _ = ready_tx.send(Ok((rate, name)));
+                        stream
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+                // Blocks until stop() is called or the recorder is dropped
+                let _ = stop_rx.recv();
+                drop(stream);
+            })?;
+
+        let (sample_rate, device_name) = match ready_rx.recv() {
+            Ok(Ok(info)) => info,
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(e);
+            }
+            Err(_) => {
+                let _ = handle.join();
+                return Err(SpeechInputError::StreamError(
+                    "capture thread exited unexpectedly".to_string(),
+                ));
+            }
+        };
+
+        log::info!("Push-to-talk recording started on '{}' at {} Hz", device_name, sample_rate);
+
+        self.active = Some(ActiveCapture {
+            stop_tx,
+            handle,
+            buffer,
+            sample_rate,
+            device_name,
+            started_at: Instant::now(),
+        });
+        Ok(())
+    }
+
+    /// Stop capturing and return the recording
+    pub fn stop(&mut self) -> Result<Recording> {
+        let active = self.active.take().ok_or(SpeechInputError::NotRecording)?;
+        let _ = active.stop_tx.send(());
+        let _ = active.handle.join();
+
+        let samples = std::mem::take(&mut *active.buffer.lock().unwrap_or_else(|e| e.into_inner()));
+        log::info!(
+            "Push-to-talk recording stopped after {:.1}s ({} samples)",
+            active.started_at.elapsed().as_secs_f32(),
+            samples.len()
+        );
+
+        Ok(Recording {
+            samples,
+            sample_rate: active.sample_rate,
+            device_name: active.device_name,
+        })
+    }
+
+    /// Stop capturing and discard the audio
+    pub fn cancel(&mut self) {
+        if let Some(active) = self.active.take() {
+            let _ = active.stop_tx.send(());
+            let _ = active.handle.join();
+        }
+    }
+}
+
+/// Open and start an input stream that appends mono samples to `buffer`
+fn open_capture(
+    device_name: Option<&str>,
+    buffer: Arc<Mutex<Vec<f32>>>,
+) -> Result<(cpal::Stream, u32, String)> {
+    let device = find_input_device(device_name)?;
+    let name = device.name().unwrap_or_else(|_| "unknown".to_string());
+    let config = device
+        .default_input_config()
+        .map_err(|e| SpeechInputError::StreamError(e.to_string()))?;
+
+    let sample_rate = config.sample_rate().0;
+    let channels = config.channels() as usize;
+    let max_samples = (sample_rate * MAX_RECORDING_SECS) as usize;
+    let stream_config: cpal::StreamConfig = config.config();
+    let err_fn = |e: cpal::StreamError| log::warn!("Input stream error: {}", e);
+
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => device.build_input_stream(
+            &stream_config,
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                push_frames(&buffer, data, channels, max_samples, |s| s)
+            },
+            err_fn,
+            None,
+        ),
+        SampleFormat::I16 => device.build_input_stream(
+            &stream_config,
+            move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                push_frames(&buffer, data, channels, max_samples, |s| s as f32 / i16::MAX as f32)
+            },
+            err_fn,
+            None,
+        ),
+        SampleFormat::U16 => device.build_input_stream(
+            &stream_config,
+            move |data: &[u16], _: &cpal::InputCallbackInfo| {
+                push_frames(&buffer, data, channels, max_samples, |s| {
+                    (s as f32 - 32768.0) / 32768.0
+                })
+            },
+            err_fn,
+            None,
+        ),
+        other => return Err(SpeechInputError::UnsupportedFormat(format!("{:?}", other))),
+    }
+    .map_err(|e| SpeechInputError::StreamError(e.to_string()))?;
+
+    stream
+        .play()
+        .map_err(|e| SpeechInputError::StreamError(e.to_string()))?;
+
+    Ok((stream, sample_rate, name))
+}
+
+/// Downmix interleaved frames to mono and append to the shared buffer
+fn push_frames<T: Copy>(
+    buffer: &Mutex<Vec<f32>>,
+    data: &[T],
+    channels: usize,
+    max_samples: usize,
+    convert: impl Fn(T) -> f32,
+) {
+    let Ok(mut buf) = buffer.lock() else {
+        return;
+    };
+    for frame in data.chunks(channels.max(1)) {
+        if buf.len() >= max_samples {
+            return;
+        }
+        let sum: f32 = frame.iter().map(|s| convert(*s)).sum();
+        buf.push(sum / frame.len() as f32);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resample_halves_length() {
+        let samples: Vec<f32> = (0..32_000).map(|i| (i as f32 / 32_000.0)).collect();
+        let out = resample_linear(&samples, 32_000, 16_000);
+        assert_eq!(out.len(), 16_000);
+        assert!((out[8_000] - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_resample_same_rate_is_identity() {
+        let samples = vec![0.1, -0.2, 0.3];
+        assert_eq!(resample_linear(&samples, 16_000, 16_000), samples);
+    }
+
+    #[test]
+    fn test_encode_wav_header() {
+        let wav = encode_wav_pcm16(&[0.0, 1.0, -1.0], 16_000);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 16_000);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 6);
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+    }
+
+    #[test]
+    fn test_push_frames_downmixes() {
+        let buffer = Mutex::new(Vec::new());
+        push_frames(&buffer, &[1.0f32, 0.0, 0.5, 0.5], 2, 100, |s| s);
+        assert_eq!(*buffer.lock().unwrap(), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_push_frames_respects_cap() {
+        let buffer = Mutex::new(Vec::new());
+        push_frames(&buffer, &[0.1f32; 10], 1, 4, |s| s);
+        assert_eq!(buffer.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_silence_detection() {
+        let silent = Recording { samples: vec![0.0; 100], sample_rate: 16_000, device_name: String::new() };
+        assert!(silent.is_silent());
+        let loud = Recording { samples: vec![0.5; 100], sample_rate: 16_000, device_name: String::new() };
+        assert!(!loud.is_silent());
+    }
+
+    #[test]
+    fn test_stop_without_start_errors() {
+        let mut recorder = PushToTalkRecorder::new();
+        assert!(matches!(recorder.stop(), Err(SpeechInputError::NotRecording)));
+    }
+}
@@ -0,0 +1,584 @@
+//! Vector store backup and restore.
+//!
+//! Moves an ingested library to another machine without re-embedding it.
+//! A backup is a zip file with a `manifest.json` and one `<table>.jsonl`
+//! file per table: the library items, then each vector table with its
+//! embeddings. The manifest records row counts, checksums, and the
+//! embedding-model fingerprint of the stored vectors.
+//!
+//! Restoring checks the fingerprint first: vectors whose dimensions don't
+//! match the vector index can't be searched, and vectors from another model
+//! shouldn't be mixed with the ones already stored.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use surrealdb::engine::local::Db;
+use surrealdb::Surreal;
+use thiserror::Error;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::error::StorageError;
+use super::schema::{field_definitions, index_definitions, vector_tables, FieldDefinition};
+use super::stats::{vector_table_stats, EmbeddingModelStats};
+
+/// Current backup schema version
+pub const BACKUP_SCHEMA_VERSION: u32 = 1;
+
+/// Table holding the library items chunks belong to
+pub const METADATA_TABLE: &str = "library_item";
+
+/// Rows read or written per query
+pub const BACKUP_BATCH_SIZE: usize = 500;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum BackupError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("Unsupported backup schema version {0} (this build reads up to {BACKUP_SCHEMA_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Backup table '{0}' failed verification")]
+    ChecksumMismatch(String),
+
+    #[error("Backup vectors from '{model}' have {dimensions} dimensions but the vector index takes {index}")]
+    DimensionMismatch { model: String, dimensions: u64, index: u64 },
+
+    #[error("Backup embeddings are from {backup}, but the library already holds embeddings from {existing}; replace the library to restore")]
+    ModelMismatch { backup: String, existing: String },
+
+    #[error("Invalid backup: {0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, BackupError>;
+
+// ============================================================================
+// Backup Types
+// ============================================================================
+
+/// Backup metadata stored in `manifest.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorBackupManifest {
+    pub schema_version: u32,
+    /// App version that wrote the backup
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    /// In restore order, library items first
+    pub tables: Vec<BackupTable>,
+    pub fingerprint: EmbeddingFingerprint,
+}
+
+/// One table in a backup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupTable {
+    pub name: String,
+    /// Path inside the archive, one JSON row per line
+    pub file: String,
+    pub rows: u64,
+    /// Hex-encoded SHA-256 of the file contents
+    pub sha256: String,
+}
+
+/// The embedding models behind a set of stored vectors
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingFingerprint {
+    /// Largest group first
+    pub models: Vec<EmbeddingModelStats>,
+    /// Dimensions the vector index was defined with
+    pub index_dimensions: Option<u64>,
+    /// Hex-encoded SHA-256 of the model names and dimensions; equal
+    /// fingerprints come from the same models
+    pub hash: String,
+}
+
+impl EmbeddingFingerprint {
+    pub fn new(models: Vec<EmbeddingModelStats>, index_dimensions: Option<u64>) -> Self {
+        let mut keys = model_keys(&models);
+        keys.sort();
+        keys.dedup();
+        let hash = hex::encode(Sha256::digest(keys.join("\n").as_bytes()));
+        Self { models, index_dimensions, hash }
+    }
+
+    /// "model (N dimensions)" for each model, as shown in errors
+    pub fn describe(&self) -> String {
+        if self.models.is_empty() {
+            return "no embeddings".to_string();
+        }
+        self.models
+            .iter()
+            .map(|m| format!("{} ({} dimensions)", m.model.as_deref().unwrap_or("an unknown model"), m.dimensions))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+fn model_keys(models: &[EmbeddingModelStats]) -> Vec<String> {
+    models
+        .iter()
+        .map(|m| format!("{}:{}", m.model.as_deref().unwrap_or(""), m.dimensions))
+        .collect()
+}
+
+/// How a restore treats what is already stored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreMode {
+    /// Add the backup's rows, overwriting rows with the same ID
+    #[default]
+    Merge,
+    /// Delete the stored library items and vectors first
+    Replace,
+}
+
+/// Rows restored into one table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestoredTable {
+    pub name: String,
+    pub rows: u64,
+}
+
+/// Result of a restore
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestoreReport {
+    pub tables: Vec<RestoredTable>,
+    pub mode: RestoreMode,
+    pub fingerprint: EmbeddingFingerprint,
+    pub exported_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Fingerprint Checks
+// ============================================================================
+
+/// Dimensions of the first vector index in the schema
+pub fn index_dimensions() -> Option<u64> {
+    index_definitions().into_iter().find(|i| i.vector).and_then(|i| i.dimension())
+}
+
+/// Check that a backup's vectors can be restored next to `existing` ones.
+///
+/// Every backup model must match the index dimensions. When merging, the
+/// stored vectors must come from the same models as the backup's.
+pub fn check_fingerprint(
+    backup: &EmbeddingFingerprint,
+    index_dimensions: Option<u64>,
+    existing: &[EmbeddingModelStats],
+    mode: RestoreMode,
+) -> Result<()> {
+    if let Some(index) = index_dimensions {
+        if let Some(model) = backup.models.iter().find(|m| m.dimensions != index) {
+            return Err(BackupError::DimensionMismatch {
+                model: model.model.clone().unwrap_or_else(|| "an unknown model".to_string()),
+                dimensions: model.dimensions,
+                index,
+            });
+        }
+    }
+    if mode == RestoreMode::Merge && !existing.is_empty() && !backup.models.is_empty() {
+        let existing_keys = model_keys(existing);
+        if model_keys(&backup.models).iter().any(|k| !existing_keys.contains(k)) {
+            return Err(BackupError::ModelMismatch {
+                backup: backup.describe(),
+                existing: EmbeddingFingerprint::new(existing.to_vec(), None).describe(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Fingerprint of the vectors in the store
+pub async fn store_fingerprint(db: &Surreal<Db>) -> Result<EmbeddingFingerprint> {
+    let mut models: Vec<EmbeddingModelStats> = Vec::new();
+    for table in vector_tables() {
+        for model in vector_table_stats(db, &table).await?.embedding_models {
+            match models.iter_mut().find(|m| m.model == model.model && m.dimensions == model.dimensions) {
+                Some(known) => known.rows += model.rows,
+                None => models.push(model),
+            }
+        }
+    }
+    models.sort_by_key(|m| std::cmp::Reverse(m.rows));
+    Ok(EmbeddingFingerprint::new(models, index_dimensions()))
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Tables a backup holds, in restore order
+pub fn backup_tables() -> Vec<String> {
+    let mut tables = vec![METADATA_TABLE.to_string()];
+    tables.extend(vector_tables());
+    tables
+}
+
+/// Query reading one page of `table`, with record IDs and links as plain IDs
+fn export_query(table: &str, fields: &[FieldDefinition], start: usize) -> String {
+    let links: String = fields
+        .iter()
+        .filter(|f| f.kind.contains("record<"))
+        .map(|f| {
+            if f.kind.starts_with("option<") {
+                format!(", (IF {name} != NONE THEN meta::id({name}) END) AS {name}", name = f.name)
+            } else {
+                format!(", meta::id({name}) AS {name}", name = f.name)
+            }
+        })
+        .collect();
+    format!(
+        "SELECT *, meta::id(id) AS id{} FROM {} ORDER BY id LIMIT {} START {}",
+        links, table, BACKUP_BATCH_SIZE, start
+    )
+}
+
+/// Write the library items and vector tables to a backup at `dest`.
+///
+/// The archive is written next to `dest` and moved into place once
+/// complete, so an interrupted export never leaves a partial backup.
+pub async fn export_vector_backup(db: &Surreal<Db>, dest: &Path) -> Result<VectorBackupManifest> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let partial = dest.with_extension("partial");
+    let mut zip = ZipWriter::new(File::create(&partial)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut manifest = VectorBackupManifest {
+        schema_version: BACKUP_SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        tables: Vec::new(),
+        fingerprint: store_fingerprint(db).await?,
+    };
+
+    for table in backup_tables() {
+        let fields = field_definitions(&table);
+        let file = format!("{}.jsonl", table);
+        zip.start_file(file.as_str(), options)?;
+        let mut hasher = Sha256::new();
+        let mut rows = 0u64;
+        loop {
+            let page: Vec<Value> = db
+                .query(export_query(&table, &fields, rows as usize))
+                .await
+                .and_then(|mut r| r.take(0))
+                .map_err(|e| StorageError::Query(format!("Failed to read '{}': {}", table, e)))?;
+            for row in &page {
+                let mut line = serde_json::to_vec(row)?;
+                line.push(b'\n');
+                hasher.update(&line);
+                zip.write_all(&line)?;
+            }
+            rows += page.len() as u64;
+            if page.len() < BACKUP_BATCH_SIZE {
+                break;
+            }
+        }
+        manifest.tables.push(BackupTable {
+            name: table,
+            file,
+            rows,
+            sha256: hex::encode(hasher.finalize()),
+        });
+    }
+
+    zip.start_file(MANIFEST_FILE, options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+    zip.finish()?;
+    std::fs::rename(&partial, dest)?;
+
+    Ok(manifest)
+}
+
+// ============================================================================
+// Reading
+// ============================================================================
+
+/// Read a backup's manifest without checking its tables
+pub fn read_backup_manifest(path: &Path) -> Result<VectorBackupManifest> {
+    let mut zip = ZipArchive::new(File::open(path)?)?;
+    read_manifest(&mut zip)
+}
+
+/// Check that every table in a backup matches its row count and checksum
+pub fn verify_vector_backup(path: &Path) -> Result<VectorBackupManifest> {
+    let mut zip = ZipArchive::new(File::open(path)?)?;
+    let manifest = read_manifest(&mut zip)?;
+    for table in &manifest.tables {
+        let mut hasher = Sha256::new();
+        let mut rows = 0u64;
+        let mut reader = BufReader::new(zip.by_name(&table.file)?);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            hasher.update(&line);
+            rows += 1;
+            line.clear();
+        }
+        if rows != table.rows || hex::encode(hasher.finalize()) != table.sha256 {
+            return Err(BackupError::ChecksumMismatch(table.name.clone()));
+        }
+    }
+    Ok(manifest)
+}
+
+fn read_manifest<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Result<VectorBackupManifest> {
+    let manifest: VectorBackupManifest = serde_json::from_reader(zip.by_name(MANIFEST_FILE)?)?;
+    if manifest.schema_version == 0 || manifest.schema_version > BACKUP_SCHEMA_VERSION {
+        return Err(BackupError::UnsupportedVersion(manifest.schema_version));
+    }
+    let known = backup_tables();
+    if let Some(table) = manifest.tables.iter().find(|t| !known.contains(&t.name)) {
+        return Err(BackupError::Invalid(format!("unknown table '{}'", table.name)));
+    }
+    Ok(manifest)
+}
+
+/// Send the rows of one backup table in batches, then any read error
+fn read_table_rows(path: &Path, file: &str, batches: tokio::sync::mpsc::Sender<Result<Vec<Value>>>) {
+    if let Err(e) = send_table_rows(path, file, &batches) {
+        let _ = batches.blocking_send(Err(e));
+    }
+}
+
+/// Stops early, without error, once the receiver is gone
+fn send_table_rows(path: &Path, file: &str, batches: &tokio::sync::mpsc::Sender<Result<Vec<Value>>>) -> Result<()> {
+    let mut zip = ZipArchive::new(File::open(path)?)?;
+    let reader = BufReader::new(zip.by_name(file)?);
+    let mut batch = Vec::with_capacity(BACKUP_BATCH_SIZE);
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        batch.push(serde_json::from_str(&line)?);
+        if batch.len() == BACKUP_BATCH_SIZE && batches.blocking_send(Ok(std::mem::take(&mut batch))).is_err() {
+            return Ok(());
+        }
+    }
+    if !batch.is_empty() {
+        let _ = batches.blocking_send(Ok(batch));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Restore
+// ============================================================================
+
+/// Statement upserting each row of `$rows` into `table`. Plain IDs become
+/// record links again, and missing fields take their schema defaults.
+fn restore_statement(table: &str, fields: &[FieldDefinition]) -> String {
+    let assignments: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            let optional = field.kind.starts_with("option<");
+            let value = match &field.default {
+                Some(default) if !optional => format!("($row.{} ?? {})", field.name, default),
+                _ => format!("$row.{}", field.name),
+            };
+            let expression = match field.kind.split_once("record<") {
+                Some((_, linked)) => {
+                    let linked = linked.trim_end_matches('>');
+                    if optional {
+                        format!("IF {v} != NONE THEN type::thing('{t}', {v}) END", v = value, t = linked)
+                    } else {
+                        format!("type::thing('{}', {})", linked, value)
+                    }
+                }
+                None if field.kind == "datetime" => format!("<datetime>{}", value),
+                None if field.kind == "option<datetime>" => {
+                    format!("IF {v} != NONE THEN <datetime>{v} END", v = value)
+                }
+                None => value,
+            };
+            format!("{} = {}", field.name, expression)
+        })
+        .collect();
+    format!(
+        "FOR $row IN $rows {{ UPSERT type::thing('{}', $row.id) SET {}; }};",
+        table,
+        assignments.join(", ")
+    )
+}
+
+/// Drop null fields, which SurrealDB would reject for optional fields
+fn strip_nulls(row: Value) -> Value {
+    match row {
+        Value::Object(map) => Value::Object(map.into_iter().filter(|(_, v)| !v.is_null()).collect::<Map<_, _>>()),
+        other => other,
+    }
+}
+
+/// Restore a verified backup into the store.
+///
+/// Checks the backup's embedding fingerprint against the vector index and
+/// the vectors already stored, then upserts each table in batches. With
+/// [`RestoreMode::Replace`] the stored library items and vectors are
+/// deleted first.
+pub async fn restore_vector_backup(db: &Surreal<Db>, path: &Path, mode: RestoreMode) -> Result<RestoreReport> {
+    let manifest = {
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || verify_vector_backup(&path))
+            .await
+            .map_err(|e| BackupError::Invalid(format!("verification task failed: {}", e)))??
+    };
+    let existing = store_fingerprint(db).await?;
+    check_fingerprint(&manifest.fingerprint, index_dimensions(), &existing.models, mode)?;
+
+    if mode == RestoreMode::Replace {
+        for table in backup_tables().iter().rev() {
+            db.query(format!("DELETE {} RETURN NONE;", table))
+                .await
+                .and_then(|r| r.check())
+                .map_err(|e| StorageError::Query(format!("Failed to clear '{}': {}", table, e)))?;
+        }
+    }
+
+    let mut report = RestoreReport {
+        tables: Vec::new(),
+        mode,
+        fingerprint: manifest.fingerprint.clone(),
+        exported_at: manifest.exported_at,
+    };
+    for table in &manifest.tables {
+        let statement = restore_statement(&table.name, &field_definitions(&table.name));
+        let (sender, mut batches) = tokio::sync::mpsc::channel(2);
+        let (source, file) = (path.to_path_buf(), table.file.clone());
+        let reader = tokio::task::spawn_blocking(move || read_table_rows(&source, &file, sender));
+
+        let mut rows = 0u64;
+        while let Some(batch) = batches.recv().await {
+            let batch: Vec<Value> = batch?.into_iter().map(strip_nulls).collect();
+            let count = batch.len() as u64;
+            db.query(statement.as_str())
+                .bind(("rows", batch))
+                .await
+                .and_then(|r| r.check())
+                .map_err(|e| StorageError::Query(format!("Failed to restore '{}': {}", table.name, e)))?;
+            rows += count;
+        }
+        reader
+            .await
+            .map_err(|e| BackupError::Invalid(format!("reader task failed: {}", e)))?;
+        report.tables.push(RestoredTable { name: table.name.clone(), rows });
+    }
+
+    Ok(report)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage::ingestion::{ingest_chunks, ChunkData};
+    use crate::core::storage::{vector_search, SurrealStorage};
+    use tempfile::TempDir;
+
+    fn model(name: &str, dimensions: u64) -> EmbeddingModelStats {
+        EmbeddingModelStats { model: Some(name.to_string()), dimensions, rows: 10 }
+    }
+
+    #[test]
+    fn test_check_fingerprint() {
+        let backup = EmbeddingFingerprint::new(vec![model("nomic-embed-text", 768)], Some(768));
+        assert!(check_fingerprint(&backup, Some(768), &[], RestoreMode::Merge).is_ok());
+        assert!(check_fingerprint(&backup, Some(768), &[model("nomic-embed-text", 768)], RestoreMode::Merge).is_ok());
+        assert!(matches!(
+            check_fingerprint(&backup, Some(1536), &[], RestoreMode::Replace),
+            Err(BackupError::DimensionMismatch { dimensions: 768, index: 1536, .. })
+        ));
+        let other = [model("mxbai-embed-large", 768)];
+        assert!(matches!(
+            check_fingerprint(&backup, Some(768), &other, RestoreMode::Merge),
+            Err(BackupError::ModelMismatch { .. })
+        ));
+        assert!(check_fingerprint(&backup, Some(768), &other, RestoreMode::Replace).is_ok());
+
+        let same = EmbeddingFingerprint::new(vec![model("nomic-embed-text", 768)], None);
+        assert_eq!(same.hash, backup.hash);
+        assert_ne!(EmbeddingFingerprint::new(other.to_vec(), None).hash, backup.hash);
+    }
+
+    #[test]
+    fn test_restore_statement_relinks_records() {
+        let statement = restore_statement("chunk", &field_definitions("chunk"));
+        assert!(statement.starts_with("FOR $row IN $rows { UPSERT type::thing('chunk', $row.id) SET content = $row.content"));
+        assert!(statement.contains(
+            "library_item = IF $row.library_item != NONE THEN type::thing('library_item', $row.library_item) END"
+        ));
+        assert!(statement.contains("created_at = <datetime>($row.created_at ?? time::now())"));
+        assert!(statement.contains("namespace = ($row.namespace ?? \"library\")"));
+        assert!(export_query("chunk", &field_definitions("chunk"), 0).contains("THEN meta::id(library_item) END) AS library_item"));
+        assert_eq!(strip_nulls(serde_json::json!({"a": 1, "b": null})), serde_json::json!({"a": 1}));
+    }
+
+    #[tokio::test]
+    async fn test_backup_round_trip() {
+        let source_dir = TempDir::new().unwrap();
+        let source = SurrealStorage::new(source_dir.path().join("db")).await.unwrap();
+        source
+            .db()
+            .query("CREATE type::thing('library_item', 'phb') SET slug = 'phb', title = 'Player Handbook', status = 'ready'")
+            .await
+            .unwrap();
+        let chunks = (0..3)
+            .map(|i| ChunkData {
+                content: format!("Fireball rule {}", i),
+                content_type: "rules".to_string(),
+                page_number: Some(241),
+                embedding: Some((0..768).map(|d| ((d + i) % 7) as f32 / 7.0).collect()),
+                embedding_model: Some("nomic-embed-text".to_string()),
+                ..Default::default()
+            })
+            .collect();
+        ingest_chunks(source.db(), "phb", chunks).await.unwrap();
+
+        let archive = source_dir.path().join("library.zip");
+        let manifest = export_vector_backup(source.db(), &archive).await.unwrap();
+        assert_eq!(manifest.tables.iter().map(|t| t.rows).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(manifest.fingerprint.models, vec![EmbeddingModelStats {
+            model: Some("nomic-embed-text".to_string()),
+            dimensions: 768,
+            rows: 3
+        }]);
+        assert_eq!(verify_vector_backup(&archive).unwrap(), manifest);
+
+        let target_dir = TempDir::new().unwrap();
+        let target = SurrealStorage::new(target_dir.path().to_path_buf()).await.unwrap();
+        let report = restore_vector_backup(target.db(), &archive, RestoreMode::Merge).await.unwrap();
+        assert_eq!(report.tables[1], RestoredTable { name: "chunk".to_string(), rows: 3 });
+
+        let query: Vec<f32> = (0..768).map(|d| (d % 7) as f32 / 7.0).collect();
+        let results = vector_search(target.db(), query, 3, None).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].content, "Fireball rule 0");
+        assert_eq!(store_fingerprint(target.db()).await.unwrap().hash, manifest.fingerprint.hash);
+    }
+}
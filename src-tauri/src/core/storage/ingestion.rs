@@ -40,6 +40,7 @@ use surrealdb::engine::local::Db;
 use surrealdb::Surreal;
 
 use super::error::StorageError;
+use super::namespace::LIBRARY_NAMESPACE;
 
 /// Document chunk data for ingestion.
 ///
@@ -111,6 +112,10 @@ pub struct ChunkData {
     /// Arbitrary metadata as JSON.
     #[serde(default)]
     pub metadata: Option<serde_json::Value>,
+
+    /// Vector namespace (e.g., "library", "campaign:{id}"); library if unset.
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// Ingest document chunks into SurrealDB.
@@ -193,7 +198,8 @@ pub async fn ingest_chunks(
                 semantic_keywords: $keywords,
                 embedding: $embedding,
                 embedding_model: $embedding_model,
-                metadata: $metadata
+                metadata: $metadata,
+                namespace: $namespace
             };
         "#;
 
@@ -215,6 +221,7 @@ pub async fn ingest_chunks(
             .bind(("embedding", chunk.embedding))
             .bind(("embedding_model", chunk.embedding_model))
             .bind(("metadata", chunk.metadata))
+            .bind(("namespace", chunk.namespace.unwrap_or_else(|| LIBRARY_NAMESPACE.to_string())))
             .await;
 
         if let Err(e) = result {
@@ -643,6 +650,7 @@ mod tests {
             embedding: Some(vec![0.1; 768]),
             embedding_model: Some("nomic-embed-text-v1.5".to_string()),
             metadata: Some(serde_json::json!({"source": "phb"})),
+            namespace: Some("campaign:c-1".to_string()),
         }];
 
         let result = ingest_chunks(&db, "full-doc", chunks).await;
@@ -21,6 +21,9 @@
 //! - `ingestion` - Document ingestion and chunking
 //! - `migration` - SQLite/Meilisearch to SurrealDB migration utilities
 //! - `models` - Data models for storage operations
+//! - `stats` - Vector store statistics and orphaned chunk cleanup
+//! - `backup` - Portable vector store backups
+//! - `namespace` - Vector namespaces for library, campaign, note, and NPC chunks
 
 pub mod surrealdb;
 pub mod error;
@@ -30,6 +33,9 @@ pub mod rag;
 pub mod ingestion;
 pub mod migration;
 pub mod models;
+pub mod stats;
+pub mod backup;
+pub mod namespace;
 
 pub use error::StorageError;
 pub use surrealdb::SurrealStorage;
@@ -51,6 +57,13 @@ pub use search::{
     SearchFilter,
     PreprocessedSearchResult,
     vector_search,
+    vector_search_with_ef,
+    exact_vector_search,
+    set_vector_search_ef,
+    sample_chunk_embeddings,
+    find_similar_chunks,
+    list_embedded_chunks,
+    EmbeddedChunk,
     fulltext_search,
     fulltext_search_with_highlights,
     hybrid_search,
@@ -73,6 +86,24 @@ pub use models::{
     LibraryItem, LibraryItemBuilder, LibraryItemWithCount,
 };
 
+// Vector store statistics and cleanup
+pub use stats::{
+    cleanup_orphaned_chunks, count_orphaned_chunks, directory_size, vector_table_stats,
+    EmbeddingModelStats, OrphanCleanupReport, VectorStoreStats, VectorTableStats,
+};
+
+// Vector store backup and restore
+pub use backup::{
+    export_vector_backup, read_backup_manifest, restore_vector_backup, BackupError, EmbeddingFingerprint,
+    RestoreMode, RestoreReport, VectorBackupManifest,
+};
+
+// Vector namespaces
+pub use namespace::{
+    delete_campaign_vectors, delete_namespace_chunks, namespace_condition, namespace_stats, upsert_namespace_chunks,
+    NamespaceStats, VectorNamespace, LIBRARY_NAMESPACE,
+};
+
 // RAG pipeline types and functions (Task 4.1, 4.2)
 pub use rag::{
     RagConfig, RagSource, RagResponse, RagContext, FormattedContext,
@@ -0,0 +1,335 @@
+//! Vector namespaces.
+//!
+//! Chunks are grouped into namespaces so a search can be scoped to the
+//! collections it needs:
+//!
+//! - `library` - ingested rulebooks and sourcebooks (the default)
+//! - `campaign:{id}` - documents ingested for one campaign
+//! - `notes` - campaign notes, each carrying `metadata.campaign_id`
+//! - `npcs` - campaign NPCs, each carrying `metadata.campaign_id`
+//!
+//! Chunks written before namespaces existed have none and count as library
+//! chunks. Deleting a campaign removes its namespace and its note and NPC
+//! vectors.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use surrealdb::engine::local::Db;
+use surrealdb::Surreal;
+
+use super::error::StorageError;
+use super::ingestion::ChunkData;
+use super::search::surql_string;
+
+/// Namespace of chunks that don't name one
+pub const LIBRARY_NAMESPACE: &str = "library";
+
+const CAMPAIGN_PREFIX: &str = "campaign:";
+const NOTES_NAMESPACE: &str = "notes";
+const NPCS_NAMESPACE: &str = "npcs";
+
+// ============================================================================
+// Namespace Types
+// ============================================================================
+
+/// A collection of chunks searched together
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum VectorNamespace {
+    /// Ingested library documents
+    Library,
+    /// Documents ingested for one campaign
+    Campaign(String),
+    /// Campaign notes
+    Notes,
+    /// Campaign NPCs
+    Npcs,
+}
+
+impl VectorNamespace {
+    pub fn campaign(campaign_id: impl Into<String>) -> Self {
+        VectorNamespace::Campaign(campaign_id.into())
+    }
+
+    /// Whether chunks in this namespace link a library item
+    pub fn has_library_items(&self) -> bool {
+        matches!(self, VectorNamespace::Library | VectorNamespace::Campaign(_))
+    }
+}
+
+impl fmt::Display for VectorNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorNamespace::Library => f.write_str(LIBRARY_NAMESPACE),
+            VectorNamespace::Campaign(id) => write!(f, "{}{}", CAMPAIGN_PREFIX, id),
+            VectorNamespace::Notes => f.write_str(NOTES_NAMESPACE),
+            VectorNamespace::Npcs => f.write_str(NPCS_NAMESPACE),
+        }
+    }
+}
+
+impl FromStr for VectorNamespace {
+    type Err = StorageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            LIBRARY_NAMESPACE => Ok(VectorNamespace::Library),
+            NOTES_NAMESPACE => Ok(VectorNamespace::Notes),
+            NPCS_NAMESPACE => Ok(VectorNamespace::Npcs),
+            other => match other.strip_prefix(CAMPAIGN_PREFIX).map(str::trim) {
+                Some(id) if !id.is_empty() => Ok(VectorNamespace::Campaign(id.to_string())),
+                _ => Err(StorageError::Config(format!(
+                    "Unknown vector namespace '{}'. Use library, campaign:<id>, notes, or npcs",
+                    other
+                ))),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for VectorNamespace {
+    type Error = StorageError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<VectorNamespace> for String {
+    fn from(namespace: VectorNamespace) -> Self {
+        namespace.to_string()
+    }
+}
+
+/// Chunk count of one namespace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamespaceStats {
+    pub namespace: String,
+    pub rows: u64,
+    pub embedded_rows: u64,
+}
+
+// ============================================================================
+// Namespace Operations
+// ============================================================================
+
+/// Upsert chunks that belong to no library item, such as campaign notes
+/// and NPCs, keyed by the ID of what they were made from. Writing the same
+/// key again replaces its chunk.
+///
+/// # Arguments
+///
+/// * `db` - SurrealDB connection
+/// * `namespace` - Target namespace; must not be one holding library items
+/// * `chunks` - (key, chunk) pairs
+///
+/// # Returns
+///
+/// Number of chunks written.
+pub async fn upsert_namespace_chunks(
+    db: &Surreal<Db>,
+    namespace: &VectorNamespace,
+    chunks: Vec<(String, ChunkData)>,
+) -> Result<usize, StorageError> {
+    if namespace.has_library_items() {
+        return Err(StorageError::Config(format!(
+            "Chunks in the '{}' namespace are ingested with their library item",
+            namespace
+        )));
+    }
+
+    let count = chunks.len();
+    for (key, mut chunk) in chunks {
+        chunk.namespace = Some(namespace.to_string());
+        db.query("UPSERT type::thing('chunk', $id) CONTENT $chunk RETURN NONE")
+            .bind(("id", format!("{}-{}", namespace, key)))
+            .bind(("chunk", chunk))
+            .await
+            .and_then(|r| r.check())
+            .map_err(|e| StorageError::Query(format!("Failed to write '{}' chunk {}: {}", namespace, key, e)))?;
+    }
+    Ok(count)
+}
+
+/// Delete every chunk in a namespace of notes or NPCs. Returns the number
+/// deleted. Library documents are deleted with their library item instead.
+pub async fn delete_namespace_chunks(db: &Surreal<Db>, namespace: &VectorNamespace) -> Result<u64, StorageError> {
+    if namespace.has_library_items() {
+        return Err(StorageError::Config(format!(
+            "Chunks in the '{}' namespace are deleted with their library item",
+            namespace
+        )));
+    }
+    delete_chunks_where(db, &namespace_condition(std::slice::from_ref(namespace))).await
+}
+
+/// Delete the vectors of a deleted campaign: the documents ingested into
+/// its namespace, with their library items, and its notes and NPCs.
+/// Returns the number of chunks deleted.
+pub async fn delete_campaign_vectors(db: &Surreal<Db>, campaign_id: &str) -> Result<u64, StorageError> {
+    let namespace = surql_string(&VectorNamespace::campaign(campaign_id).to_string());
+    db.query(format!(
+        "DELETE library_item WHERE id IN (SELECT VALUE library_item FROM chunk WHERE namespace = {}) RETURN NONE",
+        namespace
+    ))
+    .await
+    .and_then(|r| r.check())
+    .map_err(|e| StorageError::Query(format!("Failed to delete campaign documents: {}", e)))?;
+
+    let condition = format!(
+        "namespace = {} OR (namespace IN [{}, {}] AND metadata.campaign_id = {})",
+        namespace,
+        surql_string(NOTES_NAMESPACE),
+        surql_string(NPCS_NAMESPACE),
+        surql_string(campaign_id)
+    );
+    delete_chunks_where(db, &condition).await
+}
+
+async fn delete_chunks_where(db: &Surreal<Db>, condition: &str) -> Result<u64, StorageError> {
+    let rows: Vec<serde_json::Value> = db
+        .query(format!("SELECT count() AS total FROM chunk WHERE {} GROUP ALL", condition))
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to count chunks: {}", e)))?;
+    let total = rows
+        .first()
+        .and_then(|row| row.get("total"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    if total > 0 {
+        db.query(format!("DELETE chunk WHERE {} RETURN NONE", condition))
+            .await
+            .and_then(|r| r.check())
+            .map_err(|e| StorageError::Query(format!("Failed to delete chunks: {}", e)))?;
+    }
+    Ok(total)
+}
+
+/// Chunk counts per namespace, largest first
+pub async fn namespace_stats(db: &Surreal<Db>) -> Result<Vec<NamespaceStats>, StorageError> {
+    let stats: Vec<NamespaceStats> = db
+        .query(format!(
+            "SELECT (namespace ?? {}) AS namespace, count() AS rows, count(embedding != NONE) AS embedded_rows \
+             FROM chunk GROUP BY namespace",
+            surql_string(LIBRARY_NAMESPACE)
+        ))
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to count namespaces: {}", e)))?;
+    // Chunks without a namespace group apart from those named "library"
+    let mut merged: Vec<NamespaceStats> = Vec::new();
+    for row in stats {
+        match merged.iter_mut().find(|m| m.namespace == row.namespace) {
+            Some(known) => {
+                known.rows += row.rows;
+                known.embedded_rows += row.embedded_rows;
+            }
+            None => merged.push(row),
+        }
+    }
+    merged.sort_by_key(|s| std::cmp::Reverse(s.rows));
+    Ok(merged)
+}
+
+/// SurrealQL condition matching chunks in any of `namespaces`; chunks
+/// without one match the library namespace
+pub fn namespace_condition(namespaces: &[VectorNamespace]) -> String {
+    let names: Vec<String> = namespaces.iter().map(|n| surql_string(&n.to_string())).collect();
+    if namespaces.contains(&VectorNamespace::Library) {
+        format!("(namespace IN [{}] OR namespace = NONE)", names.join(", "))
+    } else {
+        format!("namespace IN [{}]", names.join(", "))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage::ingestion::ingest_chunks;
+    use crate::core::storage::{vector_search, SearchFilter, SurrealStorage};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_namespace_round_trip() {
+        for name in ["library", "campaign:c-1", "notes", "npcs"] {
+            assert_eq!(name.parse::<VectorNamespace>().unwrap().to_string(), name);
+        }
+        assert_eq!("campaign: c-1".parse::<VectorNamespace>().unwrap(), VectorNamespace::campaign("c-1"));
+        assert!("campaign:".parse::<VectorNamespace>().is_err());
+        assert!("rules".parse::<VectorNamespace>().is_err());
+
+        let parsed: Vec<VectorNamespace> = serde_json::from_str(r#"["library", "campaign:c-1"]"#).unwrap();
+        assert_eq!(parsed, vec![VectorNamespace::Library, VectorNamespace::campaign("c-1")]);
+        assert_eq!(serde_json::to_string(&VectorNamespace::Npcs).unwrap(), r#""npcs""#);
+
+        assert_eq!(
+            namespace_condition(&[VectorNamespace::Notes, VectorNamespace::campaign("it's")]),
+            r"namespace IN ['notes', 'campaign:it\'s']"
+        );
+        assert_eq!(
+            namespace_condition(&[VectorNamespace::Library]),
+            "(namespace IN ['library'] OR namespace = NONE)"
+        );
+    }
+
+    fn chunk(content: &str, campaign_id: &str, axis: usize) -> ChunkData {
+        let mut embedding = vec![0.0; 768];
+        embedding[axis] = 1.0;
+        ChunkData {
+            content: content.to_string(),
+            content_type: "notes".to_string(),
+            embedding: Some(embedding),
+            metadata: Some(serde_json::json!({ "campaign_id": campaign_id })),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_search_and_campaign_deletion() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = SurrealStorage::new(temp_dir.path().to_path_buf()).await.unwrap();
+        let db = storage.db();
+
+        db.query("CREATE type::thing('library_item', 'phb') SET slug = 'phb', title = 'PHB'")
+            .await
+            .unwrap();
+        ingest_chunks(db, "phb", vec![chunk("Fireball", "", 0)]).await.unwrap();
+        upsert_namespace_chunks(
+            db,
+            &VectorNamespace::Notes,
+            vec![("n-1".to_string(), chunk("The duke lies", "c-1", 0)), ("n-2".to_string(), chunk("Other", "c-2", 0))],
+        )
+        .await
+        .unwrap();
+        upsert_namespace_chunks(db, &VectorNamespace::Npcs, vec![("npc-1".to_string(), chunk("Duke Varn", "c-1", 0))])
+            .await
+            .unwrap();
+        assert!(upsert_namespace_chunks(db, &VectorNamespace::Library, vec![]).await.is_err());
+
+        let mut query = vec![0.0; 768];
+        query[0] = 1.0;
+        let filter = SearchFilter::new().namespaces(vec![VectorNamespace::Notes, VectorNamespace::Npcs]);
+        let results = vector_search(db, query.clone(), 10, filter.to_surql().as_deref()).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.content != "Fireball"));
+
+        let filter = SearchFilter::new().namespaces(vec![VectorNamespace::Library]);
+        let results = vector_search(db, query.clone(), 10, filter.to_surql().as_deref()).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, "phb");
+
+        assert_eq!(delete_campaign_vectors(db, "c-1").await.unwrap(), 2);
+        let stats = namespace_stats(db).await.unwrap();
+        let rows = |name: &str| stats.iter().find(|s| s.namespace == name).map(|s| s.rows);
+        assert_eq!(rows("library"), Some(1));
+        assert_eq!(rows("notes"), Some(1));
+        assert_eq!(rows("npcs"), None);
+    }
+}
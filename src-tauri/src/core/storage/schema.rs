@@ -182,7 +182,9 @@ DEFINE INDEX IF NOT EXISTS library_game ON library_item FIELDS game_system_id;
 
 DEFINE TABLE IF NOT EXISTS chunk SCHEMAFULL;
 DEFINE FIELD IF NOT EXISTS content ON chunk TYPE string;
-DEFINE FIELD IF NOT EXISTS library_item ON chunk TYPE record<library_item>;
+-- Only library chunks link a library item; OVERWRITE relaxes stores created
+-- before campaign notes and NPCs had vectors of their own
+DEFINE FIELD OVERWRITE library_item ON chunk TYPE option<record<library_item>>;
 DEFINE FIELD IF NOT EXISTS content_type ON chunk TYPE string;
 DEFINE FIELD IF NOT EXISTS page_number ON chunk TYPE option<int>;
 DEFINE FIELD IF NOT EXISTS page_start ON chunk TYPE option<int>;
@@ -197,6 +199,8 @@ DEFINE FIELD IF NOT EXISTS embedding ON chunk TYPE option<array<float>>;
 DEFINE FIELD IF NOT EXISTS embedding_model ON chunk TYPE option<string>;
 DEFINE FIELD IF NOT EXISTS created_at ON chunk TYPE datetime DEFAULT time::now();
 DEFINE FIELD IF NOT EXISTS metadata ON chunk TYPE option<object>;
+-- Vector namespace: library, campaign:{id}, notes, or npcs (none = library)
+DEFINE FIELD IF NOT EXISTS namespace ON chunk TYPE string DEFAULT "library";
 
 -- Full-text index on content with BM25 and highlights (FR-3.2)
 DEFINE INDEX IF NOT EXISTS chunk_content ON chunk FIELDS content SEARCH ANALYZER ttrpg_analyzer BM25 HIGHLIGHTS;
@@ -209,6 +213,7 @@ DEFINE INDEX IF NOT EXISTS chunk_embedding ON chunk FIELDS embedding HNSW DIMENS
 DEFINE INDEX IF NOT EXISTS chunk_library ON chunk FIELDS library_item;
 DEFINE INDEX IF NOT EXISTS chunk_type ON chunk FIELDS content_type;
 DEFINE INDEX IF NOT EXISTS chunk_page ON chunk FIELDS page_number;
+DEFINE INDEX IF NOT EXISTS chunk_namespace ON chunk FIELDS namespace;
 
 -- ============================================================================
 -- CHUNK RELATIONS (for cross-references) - Task 1.2.4, FR-5.1
@@ -251,6 +256,110 @@ DEFINE INDEX IF NOT EXISTS location_parent ON location FIELDS parent_location;
 /// Schema version for migration tracking
 pub const SCHEMA_VERSION: u32 = 1;
 
+/// An index defined in the schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDefinition {
+    pub name: String,
+    pub table: String,
+    /// The full `DEFINE INDEX` statement
+    pub statement: String,
+    /// Whether it is a vector (HNSW) index
+    pub vector: bool,
+}
+
+impl IndexDefinition {
+    /// Vector dimensions a vector index accepts
+    pub fn dimension(&self) -> Option<u64> {
+        let mut words = self.statement.split_whitespace();
+        words.find(|w| *w == "DIMENSION")?;
+        words.next()?.parse().ok()
+    }
+}
+
+/// A field defined in the schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDefinition {
+    pub name: String,
+    pub table: String,
+    /// Declared type, e.g. `option<record<library_item>>`
+    pub kind: String,
+    /// Default value expression, e.g. `time::now()`
+    pub default: Option<String>,
+}
+
+/// Fields the current schema defines on `table`, in schema order
+pub fn field_definitions(table: &str) -> Vec<FieldDefinition> {
+    SCHEMA_V1
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| {
+            let rest = line
+                .strip_prefix("DEFINE FIELD IF NOT EXISTS ")
+                .or_else(|| line.strip_prefix("DEFINE FIELD OVERWRITE "))?;
+            let mut words = rest.split_whitespace();
+            let name = words.next()?;
+            if words.next()? != "ON" {
+                return None;
+            }
+            let on = match words.next()? {
+                "TABLE" => words.next()?,
+                on => on,
+            };
+            if on != table || words.next()? != "TYPE" {
+                return None;
+            }
+            let kind = words.next()?.trim_end_matches(';').to_string();
+            let default = match words.next() {
+                Some("DEFAULT") => Some(words.collect::<Vec<_>>().join(" ").trim_end_matches(';').to_string()),
+                _ => None,
+            };
+            Some(FieldDefinition {
+                name: name.to_string(),
+                table: on.to_string(),
+                kind,
+                default,
+            })
+        })
+        .collect()
+}
+
+/// Every index defined in the current schema, in schema order
+pub fn index_definitions() -> Vec<IndexDefinition> {
+    SCHEMA_V1
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| {
+            let rest = line.strip_prefix("DEFINE INDEX IF NOT EXISTS ")?;
+            let mut words = rest.split_whitespace();
+            let name = words.next()?;
+            if words.next()? != "ON" {
+                return None;
+            }
+            let table = match words.next()? {
+                "TABLE" => words.next()?,
+                table => table,
+            };
+            Some(IndexDefinition {
+                name: name.to_string(),
+                table: table.to_string(),
+                statement: line.trim_end_matches(';').to_string(),
+                vector: line.contains(" HNSW "),
+            })
+        })
+        .collect()
+}
+
+/// Tables with a vector index, which hold embeddings for semantic search
+pub fn vector_tables() -> Vec<String> {
+    let mut tables: Vec<String> = Vec::new();
+    for index in index_definitions().into_iter().filter(|i| i.vector) {
+        if !tables.contains(&index.table) {
+            tables.push(index.table);
+        }
+    }
+    tables
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,7 +423,7 @@ mod tests {
         // FR-5.2, FR-6.1: Record link fields for relations
         assert!(SCHEMA_V1.contains("TYPE record<campaign>"));
         assert!(SCHEMA_V1.contains("TYPE record<npc>"));
-        assert!(SCHEMA_V1.contains("TYPE record<library_item>"));
+        assert!(SCHEMA_V1.contains("TYPE option<record<library_item>>"));
         assert!(SCHEMA_V1.contains("TYPE record<chunk>"));
     }
 
@@ -331,4 +440,36 @@ mod tests {
     fn test_schema_version_constant() {
         assert_eq!(SCHEMA_VERSION, 1);
     }
+
+    #[test]
+    fn test_index_definitions_and_vector_tables() {
+        let chunk: Vec<IndexDefinition> = index_definitions().into_iter().filter(|i| i.table == "chunk").collect();
+        let names: Vec<&str> = chunk.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["chunk_content", "chunk_embedding", "chunk_library", "chunk_type", "chunk_page", "chunk_namespace"]
+        );
+
+        let embedding = &chunk[1];
+        assert!(embedding.vector);
+        assert!(embedding.statement.starts_with("DEFINE INDEX IF NOT EXISTS chunk_embedding ON chunk"));
+        assert!(!embedding.statement.ends_with(';'));
+        assert_eq!(vector_tables(), vec!["chunk"]);
+        assert_eq!(embedding.dimension(), Some(768));
+    }
+
+    #[test]
+    fn test_field_definitions() {
+        let fields = field_definitions("chunk");
+        let kind = |name: &str| fields.iter().find(|f| f.name == name).map(|f| f.kind.as_str());
+        assert_eq!(kind("content"), Some("string"));
+        assert_eq!(kind("library_item"), Some("option<record<library_item>>"));
+        assert_eq!(kind("embedding"), Some("option<array<float>>"));
+        assert_eq!(kind("created_at"), Some("datetime"));
+        let namespace = fields.iter().find(|f| f.name == "namespace").unwrap();
+        assert_eq!(namespace.default.as_deref(), Some("\"library\""));
+        assert_eq!(fields.iter().find(|f| f.name == "content").unwrap().default, None);
+        assert!(fields.iter().all(|f| f.table == "chunk"));
+        assert!(field_definitions("no_such_table").is_empty());
+    }
 }
@@ -30,14 +30,19 @@
 //! ```
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 use serde::{Deserialize, Serialize};
 use surrealdb::engine::local::Db;
 use surrealdb::Surreal;
 
 use super::error::StorageError;
+use super::namespace::{namespace_condition, VectorNamespace};
 use crate::core::preprocess::{Correction, ProcessedQuery, QueryPipeline};
 
+/// HNSW search candidate list size for [`vector_search`]; 0 for the index's own
+static VECTOR_SEARCH_EF: AtomicU32 = AtomicU32::new(100);
+
 // ============================================================================
 // TYPES
 // ============================================================================
@@ -211,6 +216,18 @@ pub struct SearchFilter {
     pub page_min: Option<i32>,
     /// Filter by maximum page number
     pub page_max: Option<i32>,
+    /// Filter to any of these content types
+    pub content_types: Vec<String>,
+    /// Filter to any of these library item slugs
+    pub library_items: Vec<String>,
+    /// Filter by the source document's game system ID (e.g. "dnd5e")
+    pub game_system: Option<String>,
+    /// Filter by semantic chunk type (stat_block, spell, table, narrative)
+    pub element_type: Option<String>,
+    /// Filter by the campaign recorded in chunk metadata
+    pub campaign_id: Option<String>,
+    /// Filter to any of these vector namespaces
+    pub namespaces: Vec<VectorNamespace>,
 }
 
 impl SearchFilter {
@@ -231,6 +248,42 @@ impl SearchFilter {
         self
     }
 
+    /// Filter to any of several content types.
+    pub fn content_types(mut self, content_types: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.content_types = content_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Filter to any of several library item slugs.
+    pub fn library_items(mut self, slugs: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.library_items = slugs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Filter by the source document's game system ID.
+    pub fn game_system(mut self, game_system: impl Into<String>) -> Self {
+        self.game_system = Some(game_system.into());
+        self
+    }
+
+    /// Filter by semantic chunk type.
+    pub fn element_type(mut self, element_type: impl Into<String>) -> Self {
+        self.element_type = Some(element_type.into());
+        self
+    }
+
+    /// Filter to chunks belonging to a campaign.
+    pub fn campaign(mut self, campaign_id: impl Into<String>) -> Self {
+        self.campaign_id = Some(campaign_id.into());
+        self
+    }
+
+    /// Filter to any of several vector namespaces.
+    pub fn namespaces(mut self, namespaces: impl IntoIterator<Item = VectorNamespace>) -> Self {
+        self.namespaces = namespaces.into_iter().collect();
+        self
+    }
+
     /// Filter by page range.
     pub fn page_range(mut self, min: Option<i32>, max: Option<i32>) -> Self {
         self.page_min = min;
@@ -252,6 +305,33 @@ impl SearchFilter {
             conditions.push(format!("library_item = library_item:{}", li));
         }
 
+        if !self.content_types.is_empty() {
+            let types: Vec<String> = self.content_types.iter().map(|ct| format!("'{}'", ct)).collect();
+            conditions.push(format!("content_type IN [{}]", types.join(", ")));
+        }
+
+        if !self.library_items.is_empty() {
+            let items: Vec<String> = self.library_items.iter().map(|li| format!("library_item:{}", li)).collect();
+            conditions.push(format!("library_item IN [{}]", items.join(", ")));
+        }
+
+        // Followed through the record link, so the filter stays in the query
+        if let Some(ref gs) = self.game_system {
+            conditions.push(format!("library_item.game_system_id = {}", surql_string(gs)));
+        }
+
+        if let Some(ref et) = self.element_type {
+            conditions.push(format!("chunk_type = {}", surql_string(et)));
+        }
+
+        if let Some(ref campaign_id) = self.campaign_id {
+            conditions.push(format!("metadata.campaign_id = {}", surql_string(campaign_id)));
+        }
+
+        if !self.namespaces.is_empty() {
+            conditions.push(namespace_condition(&self.namespaces));
+        }
+
         if let Some(min) = self.page_min {
             conditions.push(format!("page_number >= {}", min));
         }
@@ -268,6 +348,11 @@ impl SearchFilter {
     }
 }
 
+/// Quote a value as a SurrealQL string literal.
+pub(super) fn surql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 // ============================================================================
 // VECTOR SEARCH (Task 2.1.1, Task 2.1.2)
 // ============================================================================
@@ -324,6 +409,58 @@ pub async fn vector_search(
     embedding: Vec<f32>,
     limit: usize,
     filters: Option<&str>,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let operator = match VECTOR_SEARCH_EF.load(Ordering::Relaxed) {
+        0 => format!("<|{}|>", limit),
+        ef => format!("<|{},{}|>", limit, ef),
+    };
+    knn_search(db, embedding, &operator, filters).await
+}
+
+/// Vector search with a given HNSW search candidate list size.
+///
+/// Like [`vector_search`], but `ef` overrides the configured size; `None`
+/// leaves it to the index. Used to benchmark search settings.
+pub async fn vector_search_with_ef(
+    db: &Surreal<Db>,
+    embedding: Vec<f32>,
+    limit: usize,
+    ef: Option<u32>,
+    filters: Option<&str>,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let operator = match ef {
+        Some(ef) => format!("<|{},{}|>", limit, ef),
+        None => format!("<|{}|>", limit),
+    };
+    knn_search(db, embedding, &operator, filters).await
+}
+
+/// Exact (brute-force) nearest neighbours by COSINE distance.
+///
+/// Compares the query with every embedding instead of using the vector
+/// index, so the results are the true nearest neighbours. Slow on large
+/// libraries; used as the baseline when benchmarking the index.
+pub async fn exact_vector_search(
+    db: &Surreal<Db>,
+    embedding: Vec<f32>,
+    limit: usize,
+    filters: Option<&str>,
+) -> Result<Vec<SearchResult>, StorageError> {
+    knn_search(db, embedding, &format!("<|{},COSINE|>", limit), filters).await
+}
+
+/// Set the HNSW search candidate list size used by [`vector_search`];
+/// `None` leaves it to the index, as M-tree indexes need.
+pub fn set_vector_search_ef(ef: Option<u32>) {
+    VECTOR_SEARCH_EF.store(ef.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Run a KNN query with the given `<|...|>` operator
+async fn knn_search(
+    db: &Surreal<Db>,
+    embedding: Vec<f32>,
+    operator: &str,
+    filters: Option<&str>,
 ) -> Result<Vec<SearchResult>, StorageError> {
     // Build filter clause - note: SurrealDB KNN syntax requires filters BEFORE the KNN operator
     // Example: WHERE flag = true AND embedding <|K,EFC|> $vec
@@ -331,29 +468,28 @@ pub async fn vector_search(
         .map(|f| format!("{} AND", f))
         .unwrap_or_default();
 
-    // Note: The KNN operator <|K,EFC|> finds K nearest neighbors using HNSW.
+    // Note: The KNN operator <|K,EF|> finds K nearest neighbors using HNSW.
     // - K: number of results to return
-    // - EFC: search quality factor (higher = better quality, slower; default ~100)
+    // - EF: search quality factor (higher = better quality, slower)
+    // <|K,COSINE|> instead compares every row (brute force).
     // The distance metric (COSINE) is specified when defining the HNSW index.
     // Distance is returned via vector::distance::knn() function.
     // Results are automatically ordered by distance (ascending).
-    let efc = 100; // Search quality factor for HNSW
     let query = format!(
         r#"
         SELECT
             meta::id(id) as id,
             content,
-            library_item.slug as source,
+            (library_item.slug ?? namespace) as source,
             page_number,
             section_path,
             content_type,
             vector::distance::knn() as score
         FROM chunk
-        WHERE {filter_clause} embedding <|{limit},{efc}|> $embedding
+        WHERE {filter_clause} embedding {operator} $embedding
         ORDER BY score ASC;
     "#,
-        limit = limit,
-        efc = efc,
+        operator = operator,
         filter_clause = filter_clause
     );
 
@@ -370,6 +506,149 @@ pub async fn vector_search(
     Ok(results)
 }
 
+// ============================================================================
+// SIMILAR CONTENT
+// ============================================================================
+
+/// Find the chunks nearest to an existing chunk ("more like this").
+///
+/// Uses the chunk's stored embedding as the KNN query, so no embedding
+/// provider is needed. The chunk itself is left out of the results.
+///
+/// # Arguments
+///
+/// * `db` - SurrealDB database reference
+/// * `chunk_id` - ID of the source chunk (without the `chunk:` prefix)
+/// * `limit` - Maximum number of results to return
+/// * `filters` - Optional WHERE clause conditions, as for `vector_search()`
+///
+/// # Returns
+///
+/// Vector of `SearchResult` ordered by ascending COSINE distance.
+///
+/// # Errors
+///
+/// Returns `StorageError::NotFound` if there is no such chunk, and
+/// `StorageError::Embedding` if it hasn't been embedded yet.
+pub async fn find_similar_chunks(
+    db: &Surreal<Db>,
+    chunk_id: &str,
+    limit: usize,
+    filters: Option<&str>,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let mut response = db
+        .query("SELECT VALUE embedding FROM type::thing('chunk', $id)")
+        .bind(("id", chunk_id.to_string()))
+        .await
+        .map_err(|e| StorageError::Query(format!("Failed to read chunk embedding: {}", e)))?;
+
+    let embeddings: Vec<Option<Vec<f32>>> = response
+        .take(0)
+        .map_err(|e| StorageError::Query(format!("Failed to extract chunk embedding: {}", e)))?;
+
+    let embedding = embeddings
+        .into_iter()
+        .next()
+        .ok_or_else(|| StorageError::NotFound(format!("chunk:{}", chunk_id)))?
+        .ok_or_else(|| StorageError::Embedding(format!("chunk:{} has no embedding", chunk_id)))?;
+
+    // One extra, since the chunk is its own nearest neighbor
+    let mut results = vector_search(db, embedding, limit + 1, filters).await?;
+    results.retain(|r| r.id != chunk_id);
+    results.truncate(limit);
+
+    Ok(results)
+}
+
+/// A chunk with its stored embedding, for comparing chunks with each other
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddedChunk {
+    /// Chunk ID without the table prefix
+    pub id: String,
+    pub content: String,
+    /// Source document slug (from library_item)
+    #[serde(default)]
+    pub source: String,
+    pub page_number: Option<i32>,
+    #[serde(default)]
+    pub content_type: String,
+    /// `None` until the chunk has been embedded
+    pub embedding: Option<Vec<f32>>,
+}
+
+/// List chunks with their stored embeddings.
+///
+/// # Arguments
+///
+/// * `db` - SurrealDB database reference
+/// * `limit` - Maximum number of chunks to return
+/// * `filters` - Optional WHERE clause conditions, as for `vector_search()`
+///
+/// # Returns
+///
+/// Chunks in record ID order, including ones not yet embedded.
+pub async fn list_embedded_chunks(
+    db: &Surreal<Db>,
+    limit: usize,
+    filters: Option<&str>,
+) -> Result<Vec<EmbeddedChunk>, StorageError> {
+    let where_clause = filters.map(|f| format!("WHERE {}", f)).unwrap_or_default();
+    let query = format!(
+        r#"
+        SELECT
+            meta::id(id) as id,
+            content,
+            (library_item.slug ?? namespace) as source,
+            page_number,
+            content_type,
+            embedding
+        FROM chunk
+        {where_clause}
+        ORDER BY id
+        LIMIT {limit};
+    "#,
+        where_clause = where_clause,
+        limit = limit
+    );
+
+    let mut response = db
+        .query(&query)
+        .await
+        .map_err(|e| StorageError::Query(format!("Failed to list chunk embeddings: {}", e)))?;
+
+    response
+        .take(0)
+        .map_err(|e| StorageError::Query(format!("Failed to extract chunk embeddings: {}", e)))
+}
+
+/// Pick up to `count` embedded chunks at random, as benchmark queries.
+///
+/// # Returns
+///
+/// Chunk IDs (without the table prefix) with their embeddings.
+pub async fn sample_chunk_embeddings(
+    db: &Surreal<Db>,
+    count: usize,
+) -> Result<Vec<(String, Vec<f32>)>, StorageError> {
+    #[derive(Deserialize)]
+    struct Sample {
+        id: String,
+        embedding: Vec<f32>,
+    }
+
+    let query = format!(
+        "SELECT meta::id(id) AS id, embedding FROM chunk WHERE embedding != NONE ORDER BY rand() LIMIT {};",
+        count
+    );
+    let samples: Vec<Sample> = db
+        .query(&query)
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to sample chunk embeddings: {}", e)))?;
+
+    Ok(samples.into_iter().map(|s| (s.id, s.embedding)).collect())
+}
+
 // ============================================================================
 // FULL-TEXT SEARCH (Task 2.2.1, Task 2.2.2)
 // ============================================================================
@@ -492,7 +771,7 @@ pub async fn fulltext_search_with_highlights(
         SELECT
             meta::id(id) as id,
             content,
-            library_item.slug as source,
+            (library_item.slug ?? namespace) as source,
             page_number,
             section_path,
             content_type,
@@ -811,7 +1090,7 @@ async fn fulltext_search_expanded(
         SELECT
             meta::id(id) as id,
             content,
-            library_item.slug as source,
+            (library_item.slug ?? namespace) as source,
             page_number,
             section_path,
             content_type,
@@ -1064,6 +1343,90 @@ mod tests {
     // Task 2.1.1: vector_search() unit tests
     // ========================================================================
 
+    #[tokio::test]
+    async fn test_find_similar_chunks_excludes_source_chunk() {
+        let (_dir, db) = setup_test_db().await;
+        insert_library_item(&db, "mm-2024", "Monster Manual 2024").await;
+
+        insert_chunk(&db, "Red dragons lair in volcanoes", "mm-2024", "rules", Some(10), make_embedding(0.0)).await;
+        insert_chunk(&db, "Brass dragons lair in deserts", "mm-2024", "rules", Some(11), make_embedding(0.1)).await;
+        insert_chunk(&db, "Goblins ambush travellers", "mm-2024", "fiction", Some(90), make_embedding(2.0)).await;
+
+        let mut response = db
+            .query("SELECT VALUE meta::id(id) FROM chunk WHERE content CONTAINS 'Red dragons'")
+            .await
+            .expect("Failed to look up chunk");
+        let ids: Vec<String> = response.take(0).expect("Failed to read chunk id");
+
+        let results = find_similar_chunks(&db, &ids[0], 2, None)
+            .await
+            .expect("Similar search failed");
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.id != ids[0]));
+        assert!(results[0].content.contains("Brass dragons"));
+
+        let filter = SearchFilter::new().content_types(["rules"]).to_surql();
+        let results = find_similar_chunks(&db, &ids[0], 5, filter.as_deref())
+            .await
+            .expect("Filtered similar search failed");
+        assert_eq!(results.len(), 1);
+
+        let missing = find_similar_chunks(&db, "missing", 5, None).await;
+        assert!(matches!(missing, Err(StorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_embedded_chunks() {
+        let (_dir, db) = setup_test_db().await;
+        insert_library_item(&db, "mm-2024", "Monster Manual 2024").await;
+
+        insert_chunk(&db, "Red dragons lair in volcanoes", "mm-2024", "rules", Some(10), make_embedding(0.0)).await;
+        insert_chunk(&db, "Goblins ambush travellers", "mm-2024", "fiction", Some(90), make_embedding(2.0)).await;
+
+        let chunks = list_embedded_chunks(&db, 10, None).await.expect("Listing chunks failed");
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.embedding.as_ref().is_some_and(|e| e.len() == 768)));
+        assert!(chunks.iter().all(|c| c.source == "mm-2024"));
+
+        let filter = SearchFilter::new().content_type("fiction").to_surql();
+        let chunks = list_embedded_chunks(&db, 10, filter.as_deref()).await.expect("Filtered listing failed");
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].content.contains("Goblins"));
+
+        let chunks = list_embedded_chunks(&db, 1, None).await.expect("Limited listing failed");
+        assert_eq!(chunks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_exact_and_tuned_vector_search_agree() {
+        let (_dir, db) = setup_test_db().await;
+        insert_library_item(&db, "mm-2024", "Monster Manual 2024").await;
+
+        for seed in 0..5 {
+            let content = format!("Monster entry {}", seed);
+            insert_chunk(&db, &content, "mm-2024", "rules", Some(seed), make_embedding(seed as f32)).await;
+        }
+
+        let exact = exact_vector_search(&db, make_embedding(0.0), 3, None)
+            .await
+            .expect("Exact search failed");
+        let tuned = vector_search_with_ef(&db, make_embedding(0.0), 3, Some(40), None)
+            .await
+            .expect("Tuned search failed");
+
+        assert_eq!(exact.len(), 3);
+        assert!(exact[0].content.contains("entry 0"));
+        assert_eq!(
+            exact.iter().map(|r| &r.id).collect::<Vec<_>>(),
+            tuned.iter().map(|r| &r.id).collect::<Vec<_>>()
+        );
+
+        let samples = sample_chunk_embeddings(&db, 2).await.expect("Sampling failed");
+        assert_eq!(samples.len(), 2);
+        assert!(samples.iter().all(|(_, e)| e.len() == 768));
+    }
+
     #[tokio::test]
     async fn test_vector_search_returns_results_ordered_by_distance() {
         let (_dir, db) = setup_test_db().await;
@@ -1395,6 +1758,30 @@ mod tests {
         assert!(surql.contains(" AND "));
     }
 
+    #[test]
+    fn test_search_filter_multiple_values() {
+        let filter = SearchFilter::new()
+            .content_types(["rules", "homebrew"])
+            .library_items(vec!["phb-2024".to_string(), "mm-2024".to_string()]);
+
+        let surql = filter.to_surql().expect("Should have filter");
+        assert!(surql.contains("content_type IN ['rules', 'homebrew']"));
+        assert!(surql.contains("library_item IN [library_item:phb-2024, library_item:mm-2024]"));
+    }
+
+    #[test]
+    fn test_search_filter_metadata() {
+        let filter = SearchFilter::new()
+            .game_system("dnd5e")
+            .element_type("stat_block")
+            .campaign("it's-mine");
+
+        let surql = filter.to_surql().expect("Should have filter");
+        assert!(surql.contains("library_item.game_system_id = 'dnd5e'"));
+        assert!(surql.contains("chunk_type = 'stat_block'"));
+        assert!(surql.contains(r"metadata.campaign_id = 'it\'s-mine'"));
+    }
+
     #[test]
     fn test_search_filter_library_item() {
         let filter = SearchFilter::new().library_item("phb-2024");
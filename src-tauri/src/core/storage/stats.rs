@@ -0,0 +1,295 @@
+//! Vector store statistics and cleanup.
+//!
+//! Row counts, embedding models, and size estimates for the tables that
+//! carry vector indexes, plus cleanup of chunks whose library item is gone.
+//! Chunks are normally deleted with their library item, but an interrupted
+//! deletion or a migration can leave them behind, still matching searches.
+//! Chunks outside the library namespace have no library item and are never
+//! orphans.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use surrealdb::engine::local::Db;
+use surrealdb::Surreal;
+
+use super::error::StorageError;
+
+/// Bytes per stored embedding value; SurrealDB keeps floats as 64-bit
+const BYTES_PER_DIMENSION: u64 = 8;
+
+/// Most orphaned library item IDs listed in a cleanup report
+const MAX_LISTED_ORPHAN_SOURCES: usize = 100;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Embedded rows of a table produced by one model at one size
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingModelStats {
+    /// Model recorded at ingestion, if any
+    pub model: Option<String>,
+    pub dimensions: u64,
+    pub rows: u64,
+}
+
+/// Figures for one vector table
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VectorTableStats {
+    pub table: String,
+    pub rows: u64,
+    pub embedded_rows: u64,
+    /// Rows whose library item no longer exists
+    pub orphaned_rows: u64,
+    /// Text plus raw vector bytes; index and storage overhead is not included
+    pub estimated_bytes: u64,
+    /// Largest group first
+    pub embedding_models: Vec<EmbeddingModelStats>,
+}
+
+/// Figures for the whole vector store
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VectorStoreStats {
+    /// RocksDB data directory
+    pub path: String,
+    /// Size of the data directory, covering every table and index
+    pub disk_bytes: u64,
+    pub tables: Vec<VectorTableStats>,
+}
+
+/// Result of an orphan cleanup pass
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrphanCleanupReport {
+    /// Chunks found (and deleted, unless a dry run)
+    pub orphaned_chunks: u64,
+    /// Chunk references left pointing at missing chunks
+    pub dangling_references: u64,
+    /// Missing library items the chunks belonged to
+    pub missing_library_items: Vec<String>,
+    pub dry_run: bool,
+}
+
+// ============================================================================
+// Statistics
+// ============================================================================
+
+/// Row counts, embedding models, and estimated size of a vector table.
+///
+/// `table` must be a schema table name; it is inlined into the query.
+pub async fn vector_table_stats(db: &Surreal<Db>, table: &str) -> Result<VectorTableStats, StorageError> {
+    let totals: Vec<serde_json::Value> = db
+        .query(format!(
+            "SELECT count() AS total, count(embedding != NONE) AS embedded, \
+             math::sum(string::len(content)) AS content_bytes FROM {} GROUP ALL",
+            table
+        ))
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to count '{}': {}", table, e)))?;
+    let total = |field: &str| {
+        totals
+            .first()
+            .and_then(|row| row.get(field))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+
+    #[derive(Deserialize)]
+    struct ModelRow {
+        model: Option<String>,
+        dimensions: Option<u64>,
+        rows: u64,
+    }
+
+    let models: Vec<ModelRow> = db
+        .query(format!(
+            "SELECT embedding_model AS model, array::len(embedding) AS dimensions, count() AS rows \
+             FROM {} WHERE embedding != NONE GROUP BY model, dimensions",
+            table
+        ))
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to group embeddings of '{}': {}", table, e)))?;
+    let mut embedding_models: Vec<EmbeddingModelStats> = models
+        .into_iter()
+        .map(|m| EmbeddingModelStats {
+            model: m.model,
+            dimensions: m.dimensions.unwrap_or(0),
+            rows: m.rows,
+        })
+        .collect();
+    embedding_models.sort_by_key(|m| std::cmp::Reverse(m.rows));
+
+    let vector_bytes: u64 = embedding_models
+        .iter()
+        .map(|m| m.rows * m.dimensions * BYTES_PER_DIMENSION)
+        .sum();
+
+    Ok(VectorTableStats {
+        table: table.to_string(),
+        rows: total("total"),
+        embedded_rows: total("embedded"),
+        orphaned_rows: if table == "chunk" { count_orphaned_chunks(db).await? } else { 0 },
+        estimated_bytes: total("content_bytes") + vector_bytes,
+        embedding_models,
+    })
+}
+
+/// Total size of the files under `path`, in bytes
+pub fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+// ============================================================================
+// Orphan Cleanup
+// ============================================================================
+
+/// Number of chunks whose library item no longer exists
+pub async fn count_orphaned_chunks(db: &Surreal<Db>) -> Result<u64, StorageError> {
+    let rows: Vec<serde_json::Value> = db
+        .query("SELECT count() AS orphaned FROM chunk WHERE library_item != NONE AND library_item.id = NONE GROUP ALL")
+        .await
+        .and_then(|mut r| r.take(0))
+        .map_err(|e| StorageError::Query(format!("Failed to count orphaned chunks: {}", e)))?;
+    Ok(rows
+        .first()
+        .and_then(|row| row.get("orphaned"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0))
+}
+
+/// Find chunks whose library item no longer exists and, unless `dry_run`,
+/// delete them along with chunk references to missing chunks.
+pub async fn cleanup_orphaned_chunks(db: &Surreal<Db>, dry_run: bool) -> Result<OrphanCleanupReport, StorageError> {
+    let mut response = db
+        .query(
+            "SELECT count() AS orphaned FROM chunk WHERE library_item != NONE AND library_item.id = NONE GROUP ALL; \
+             SELECT meta::id(library_item) AS item FROM chunk WHERE library_item != NONE AND library_item.id = NONE GROUP BY item; \
+             SELECT count() AS dangling FROM chunk_reference WHERE in.id = NONE OR out.id = NONE GROUP ALL;",
+        )
+        .await
+        .map_err(|e| StorageError::Query(format!("Failed to find orphaned chunks: {}", e)))?;
+
+    let count = |rows: Vec<serde_json::Value>, field: &str| {
+        rows.first()
+            .and_then(|row| row.get(field))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+    let orphaned: Vec<serde_json::Value> = response.take(0).map_err(|e| StorageError::Query(e.to_string()))?;
+    let sources: Vec<serde_json::Value> = response.take(1).map_err(|e| StorageError::Query(e.to_string()))?;
+    let dangling: Vec<serde_json::Value> = response.take(2).map_err(|e| StorageError::Query(e.to_string()))?;
+
+    let report = OrphanCleanupReport {
+        orphaned_chunks: count(orphaned, "orphaned"),
+        dangling_references: count(dangling, "dangling"),
+        missing_library_items: sources
+            .into_iter()
+            .filter_map(|row| match row.get("item") {
+                Some(serde_json::Value::String(id)) => Some(id.clone()),
+                Some(other) => Some(other.to_string()),
+                None => None,
+            })
+            .take(MAX_LISTED_ORPHAN_SOURCES)
+            .collect(),
+        dry_run,
+    };
+
+    if !dry_run && (report.orphaned_chunks > 0 || report.dangling_references > 0) {
+        db.query(
+            "DELETE chunk WHERE library_item != NONE AND library_item.id = NONE RETURN NONE; \
+             DELETE chunk_reference WHERE in.id = NONE OR out.id = NONE RETURN NONE;",
+        )
+        .await
+        .and_then(|r| r.check())
+        .map_err(|e| StorageError::Query(format!("Failed to delete orphaned chunks: {}", e)))?;
+    }
+
+    Ok(report)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage::ingestion::{ingest_chunks, ChunkData};
+    use crate::core::storage::SurrealStorage;
+    use tempfile::TempDir;
+
+    async fn setup_test_db() -> (SurrealStorage, TempDir) {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let storage = SurrealStorage::new(temp_dir.path().to_path_buf())
+            .await
+            .expect("Failed to create storage");
+        (storage, temp_dir)
+    }
+
+    async fn ingest(db: &Surreal<Db>, id: &str, embedded: usize, plain: usize) {
+        db.query("CREATE type::thing('library_item', $id) SET slug = $id, title = $id")
+            .bind(("id", id.to_string()))
+            .await
+            .expect("Failed to create library item");
+        let mut chunks: Vec<ChunkData> = (0..embedded)
+            .map(|i| ChunkData {
+                content: format!("chunk {}", i),
+                content_type: "rules".to_string(),
+                embedding: Some(vec![0.1; 768]),
+                embedding_model: Some("nomic-embed-text".to_string()),
+                ..Default::default()
+            })
+            .collect();
+        chunks.extend((0..plain).map(|_| ChunkData {
+            content: "plain".to_string(),
+            content_type: "rules".to_string(),
+            ..Default::default()
+        }));
+        ingest_chunks(db, id, chunks).await.expect("Failed to ingest chunks");
+    }
+
+    #[tokio::test]
+    async fn test_vector_table_stats() {
+        let (storage, _temp) = setup_test_db().await;
+        ingest(storage.db(), "phb", 3, 1).await;
+
+        let stats = vector_table_stats(storage.db(), "chunk").await.unwrap();
+        assert_eq!(stats.rows, 4);
+        assert_eq!(stats.embedded_rows, 3);
+        assert_eq!(stats.orphaned_rows, 0);
+        assert_eq!(
+            stats.embedding_models,
+            vec![EmbeddingModelStats { model: Some("nomic-embed-text".to_string()), dimensions: 768, rows: 3 }]
+        );
+        assert!(stats.estimated_bytes >= 3 * 768 * BYTES_PER_DIMENSION);
+        assert!(directory_size(storage.path()) > 0);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_orphaned_chunks() {
+        let (storage, _temp) = setup_test_db().await;
+        let db = storage.db();
+        ingest(db, "kept", 2, 0).await;
+        ingest(db, "gone", 2, 1).await;
+        // Delete the item without its chunks, as an interrupted deletion would
+        db.query("DELETE type::thing('library_item', 'gone')").await.unwrap();
+
+        let preview = cleanup_orphaned_chunks(db, true).await.unwrap();
+        assert_eq!(preview.orphaned_chunks, 3);
+        assert_eq!(preview.missing_library_items, vec!["gone".to_string()]);
+        assert_eq!(count_orphaned_chunks(db).await.unwrap(), 3);
+
+        let report = cleanup_orphaned_chunks(db, false).await.unwrap();
+        assert_eq!(report.orphaned_chunks, 3);
+        assert!(!report.dry_run);
+        assert_eq!(count_orphaned_chunks(db).await.unwrap(), 0);
+        assert_eq!(vector_table_stats(db, "chunk").await.unwrap().rows, 2);
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides a thread-safe wrapper around SurrealDB with RocksDB persistence,
 //! handling initialization, schema application, and shared access across async tasks.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use surrealdb::engine::local::{Db, RocksDb};
@@ -67,6 +67,8 @@ pub struct SurrealStorage {
     db: Arc<Surreal<Db>>,
     /// Configuration settings
     config: Arc<RwLock<StorageConfig>>,
+    /// RocksDB data directory
+    path: PathBuf,
 }
 
 impl SurrealStorage {
@@ -111,6 +113,7 @@ impl SurrealStorage {
         let storage = Self {
             db: Arc::new(db),
             config: Arc::new(RwLock::new(StorageConfig::default())),
+            path: db_path.clone(),
         };
 
         // Apply schema
@@ -160,6 +163,7 @@ impl SurrealStorage {
         let storage = Self {
             db: Arc::new(db),
             config: Arc::new(RwLock::new(config.clone())),
+            path: db_path.clone(),
         };
 
         // Apply schema
@@ -239,6 +243,11 @@ impl SurrealStorage {
         Arc::clone(&self.db)
     }
 
+    /// RocksDB data directory the database was opened at.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     /// Get current configuration.
     ///
     /// Returns a clone of the current storage configuration.
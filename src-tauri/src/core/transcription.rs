@@ -1,12 +1,13 @@
 //! Transcription Module
 //!
 //! Multi-provider transcription abstraction supporting audio-to-text conversion
-//! via various backends (OpenAI Whisper, future providers).
+//! via various backends (OpenAI Whisper, Groq, local whisper.cpp).
 
 use async_trait::async_trait;
 use reqwest::multipart;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -70,7 +71,9 @@ pub enum TranscriptionProviderType {
     #[default]
     OpenAI,
     Groq,
-    // Future: Local (Whisper.cpp), AssemblyAI, Deepgram, etc.
+    /// Local whisper.cpp CLI (offline)
+    Local,
+    // Future: AssemblyAI, Deepgram, etc.
 }
 
 impl std::str::FromStr for TranscriptionProviderType {
@@ -80,6 +83,7 @@ impl std::str::FromStr for TranscriptionProviderType {
         match s.to_lowercase().as_str() {
             "openai" | "whisper" => Ok(Self::OpenAI),
             "groq" => Ok(Self::Groq),
+            "local" | "whisper.cpp" | "whisper_cpp" => Ok(Self::Local),
             _ => Err(format!("Unknown transcription provider: {}", s)),
         }
     }
@@ -90,6 +94,7 @@ impl std::fmt::Display for TranscriptionProviderType {
         match self {
             Self::OpenAI => write!(f, "openai"),
             Self::Groq => write!(f, "groq"),
+            Self::Local => write!(f, "local"),
         }
     }
 }
@@ -319,6 +324,148 @@ impl TranscriptionProvider for GroqTranscriptionProvider {
     }
 }
 
+// ============================================================================
+// Local Whisper Provider (whisper.cpp CLI)
+// ============================================================================
+
+/// Offline transcription using the whisper.cpp command-line tool.
+///
+/// Expects 16 kHz mono WAV input, which is what the push-to-talk recorder
+/// produces.
+pub struct LocalWhisperTranscriptionProvider {
+    executable: Option<String>,
+    model_path: PathBuf,
+    language: Option<String>,
+}
+
+impl LocalWhisperTranscriptionProvider {
+    /// Executable names shipped by whisper.cpp over time
+    const EXECUTABLES: &'static [&'static str] = &["whisper-cli", "whisper-cpp", "whisper"];
+
+    /// Create a provider using the default model location
+    pub fn new() -> Self {
+        Self::with_model(Self::default_model_path())
+    }
+
+    /// Create a provider using a specific ggml model file
+    pub fn with_model(model_path: impl Into<PathBuf>) -> Self {
+        let executable = Self::EXECUTABLES
+            .iter()
+            .find(|exe| which::which(exe).is_ok())
+            .map(|exe| exe.to_string());
+
+        if executable.is_none() {
+            log::debug!("whisper.cpp executable not found; local transcription unavailable");
+        }
+
+        Self {
+            executable,
+            model_path: model_path.into(),
+            language: None,
+        }
+    }
+
+    /// Set a language hint (e.g., "en")
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Default model location: `<data dir>/ttrpg-assistant/stt/whisper/ggml-base.en.bin`
+    pub fn default_model_path() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("ttrpg-assistant/stt/whisper/ggml-base.en.bin")
+    }
+}
+
+impl Default for LocalWhisperTranscriptionProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for LocalWhisperTranscriptionProvider {
+    fn id(&self) -> &'static str {
+        "local"
+    }
+
+    fn name(&self) -> &'static str {
+        "Local Whisper"
+    }
+
+    fn is_available(&self) -> bool {
+        self.executable.is_some() && self.model_path.exists()
+    }
+
+    async fn transcribe(&self, audio_path: &Path) -> Result<TranscriptionResult> {
+        let exe = self.executable.as_ref().ok_or_else(|| {
+            TranscriptionError::ProviderNotAvailable("whisper.cpp executable not found".to_string())
+        })?;
+
+        if !self.model_path.exists() {
+            return Err(TranscriptionError::ConfigError(format!(
+                "Whisper model not found: {}",
+                self.model_path.display()
+            )));
+        }
+        if !audio_path.exists() {
+            return Err(TranscriptionError::InvalidAudioFile(
+                audio_path.display().to_string(),
+            ));
+        }
+
+        let mut cmd = tokio::process::Command::new(exe);
+        cmd.arg("-m")
+            .arg(&self.model_path)
+            .arg("-f")
+            .arg(audio_path)
+            .arg("--no-timestamps")
+            .arg("--no-prints");
+        if let Some(lang) = &self.language {
+            cmd.arg("-l").arg(lang);
+        }
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        let started = std::time::Instant::now();
+        let output = tokio::time::timeout(DEFAULT_TRANSCRIPTION_TIMEOUT, cmd.output())
+            .await
+            .map_err(|_| {
+                TranscriptionError::ApiError(format!(
+                    "Local Whisper timed out after {:?}",
+                    DEFAULT_TRANSCRIPTION_TIMEOUT
+                ))
+            })??;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(TranscriptionError::ApiError(format!(
+                "whisper.cpp exited with {:?}: {}",
+                output.status.code(),
+                stderr.trim()
+            )));
+        }
+
+        Ok(TranscriptionResult {
+            text: clean_whisper_output(&String::from_utf8_lossy(&output.stdout)),
+            language: self.language.clone(),
+            duration_seconds: Some(started.elapsed().as_secs_f64()),
+            provider: self.name().to_string(),
+        })
+    }
+}
+
+/// Join whisper.cpp output lines and drop non-speech markers like `[BLANK_AUDIO]`
+fn clean_whisper_output(raw: &str) -> String {
+    raw.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter(|line| !(line.starts_with('[') && line.ends_with(']')))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 // ============================================================================
 // Transcription Manager
 // ============================================================================
@@ -450,6 +597,12 @@ impl TranscriptionManagerBuilder {
         self
     }
 
+    /// Add local whisper.cpp provider
+    pub fn with_local(mut self, provider: LocalWhisperTranscriptionProvider) -> Self {
+        self.manager.add_provider(Arc::new(provider));
+        self
+    }
+
     /// Set default provider
     pub fn default_provider(mut self, provider_type: TranscriptionProviderType) -> Self {
         self.manager.set_default(provider_type);
@@ -518,6 +671,10 @@ mod tests {
             "groq".parse::<TranscriptionProviderType>().unwrap(),
             TranscriptionProviderType::Groq
         );
+        assert_eq!(
+            "whisper.cpp".parse::<TranscriptionProviderType>().unwrap(),
+            TranscriptionProviderType::Local
+        );
         assert!("unknown".parse::<TranscriptionProviderType>().is_err());
     }
 
@@ -525,6 +682,19 @@ mod tests {
     fn test_provider_type_display() {
         assert_eq!(TranscriptionProviderType::OpenAI.to_string(), "openai");
         assert_eq!(TranscriptionProviderType::Groq.to_string(), "groq");
+        assert_eq!(TranscriptionProviderType::Local.to_string(), "local");
+    }
+
+    #[test]
+    fn test_clean_whisper_output() {
+        let raw = "\n [BLANK_AUDIO]\n Goblin takes seven damage.\n  Next turn.\n";
+        assert_eq!(clean_whisper_output(raw), "Goblin takes seven damage. Next turn.");
+    }
+
+    #[test]
+    fn test_local_provider_unavailable_without_model() {
+        let provider = LocalWhisperTranscriptionProvider::with_model("/nonexistent/ggml-model.bin");
+        assert!(!provider.is_available());
     }
 
     #[test]
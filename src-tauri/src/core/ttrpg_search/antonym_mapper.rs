@@ -58,17 +58,15 @@ impl AntonymMapper {
         mapper
     }
 
-    /// Add a bidirectional antonym pair
+    /// Add a bidirectional antonym pair; a pair already present is skipped
     pub fn add_pair(&mut self, a: &str, b: &str) {
-        self.antonyms
-            .entry(a.to_lowercase())
-            .or_default()
-            .push(b.to_lowercase());
-
-        self.antonyms
-            .entry(b.to_lowercase())
-            .or_default()
-            .push(a.to_lowercase());
+        let (a, b) = (a.to_lowercase(), b.to_lowercase());
+        for (from, to) in [(&a, &b), (&b, &a)] {
+            let antonyms = self.antonyms.entry(from.clone()).or_default();
+            if !antonyms.contains(to) {
+                antonyms.push(to.clone());
+            }
+        }
     }
 
     /// Get antonyms for an attribute
@@ -2,6 +2,9 @@
 //!
 //! Builds Meilisearch filter strings from QueryConstraints.
 
+use std::collections::HashMap;
+
+use super::facets::{cr_range_bounds, FacetField};
 use super::QueryConstraints;
 use crate::ingestion::ttrpg::{GameVocabulary, DnD5eVocabulary};
 
@@ -18,6 +21,7 @@ impl AttributeFilter {
     /// # Example output
     /// ```text
     /// (damage_types = "fire" OR damage_types = "radiant") AND NOT creature_types = "undead" AND challenge_rating >= 1 AND challenge_rating <= 5
+    /// armor_class > 17 AND (dice_expressions = "8d6" OR dice_expressions = "1d20+5")
     /// ```
     pub fn build_filter_string(constraints: &QueryConstraints) -> String {
         let mut filters = Vec::new();
@@ -79,6 +83,21 @@ impl AttributeFilter {
             }
         }
 
+        // Build numeric comparison filters ("armor_class > 17")
+        for numeric in &constraints.numeric_constraints {
+            filters.push(format!("{} {} {}", numeric.field, numeric.comparison.operator(), numeric.value));
+        }
+
+        // Build dice filter; any of the query's dice
+        let dice: Vec<String> = constraints
+            .dice_expressions
+            .iter()
+            .map(|d| format!("dice_expressions = \"{}\"", d.notation()))
+            .collect();
+        if !dice.is_empty() {
+            filters.push(Self::combine_or(&dice));
+        }
+
         filters.join(" AND ")
     }
 
@@ -110,6 +129,53 @@ impl AttributeFilter {
         format!("source = \"{}\"", Self::escape_value(source))
     }
 
+    /// Build a filter for one facet value, or None if the value can't be a
+    /// value of that facet
+    pub fn build_facet_value_filter(field: FacetField, value: &str) -> Option<String> {
+        let equals = |name: &str| format!("{} = \"{}\"", name, Self::escape_value(value));
+        match field {
+            FacetField::GameSystem => Some(Self::build_game_system_filter(value)),
+            FacetField::Document => Some(Self::build_source_filter(value)),
+            FacetField::ElementType => Some(equals("element_type")),
+            FacetField::SourceBook => Some(equals("book_title")),
+            FacetField::ChallengeRating => {
+                let (min, next) = cr_range_bounds(value)?;
+                Some(match next {
+                    Some(next) => format!("challenge_rating >= {} AND challenge_rating < {}", min, next),
+                    None => format!("challenge_rating >= {}", min),
+                })
+            }
+            FacetField::SpellLevel => {
+                let level: u32 = value.parse().ok()?;
+                Some(format!("element_type = \"spell\" AND level = {}", level))
+            }
+        }
+    }
+
+    /// Build a filter from selected facet values, keyed by facet key.
+    /// Values of one facet are ORed; facets are ANDed. Unknown facets and
+    /// values are skipped.
+    pub fn build_facet_filter(selected: &HashMap<String, Vec<String>>) -> String {
+        let mut facets: Vec<(FacetField, &Vec<String>)> = selected
+            .iter()
+            .filter_map(|(key, values)| FacetField::from_key(key).map(|f| (f, values)))
+            .collect();
+        facets.sort_by_key(|(field, _)| FacetField::ALL.iter().position(|f| f == field));
+
+        let parts: Vec<String> = facets
+            .into_iter()
+            .map(|(field, values)| {
+                let options: Vec<String> = values
+                    .iter()
+                    .filter_map(|v| Self::build_facet_value_filter(field, v))
+                    .map(|f| if f.contains(" AND ") { format!("({})", f) } else { f })
+                    .collect();
+                Self::combine_or(&options)
+            })
+            .collect();
+        Self::combine_and(&parts)
+    }
+
     /// Combine multiple filter strings with AND
     pub fn combine_and(filters: &[String]) -> String {
         filters
@@ -283,6 +349,29 @@ mod tests {
         assert!(filter.contains("level = 3"));
     }
 
+    #[test]
+    fn test_numeric_and_dice_filters() {
+        use super::super::{Comparison, DiceExpression, NumericConstraint};
+
+        let constraints = QueryConstraints {
+            numeric_constraints: vec![
+                NumericConstraint { field: "armor_class".to_string(), comparison: Comparison::Greater, value: 17.0 },
+                NumericConstraint { field: "challenge_rating".to_string(), comparison: Comparison::LessOrEqual, value: 0.5 },
+            ],
+            dice_expressions: vec![
+                DiceExpression { count: 8, sides: 6, modifier: 0 },
+                DiceExpression { count: 1, sides: 20, modifier: -1 },
+            ],
+            ..Default::default()
+        };
+
+        let filter = AttributeFilter::build_filter_string(&constraints);
+        assert_eq!(
+            filter,
+            "armor_class > 17 AND challenge_rating <= 0.5 AND (dice_expressions = \"8d6\" OR dice_expressions = \"1d20-1\")"
+        );
+    }
+
     #[test]
     fn test_element_type_filter() {
         let filter = AttributeFilter::build_element_type_filter(&["stat_block", "spell"]);
@@ -300,6 +389,28 @@ mod tests {
         assert!(combined.contains(" AND "));
     }
 
+    #[test]
+    fn test_build_facet_filter() {
+        let selected: HashMap<String, Vec<String>> = [
+            ("challenge_rating".to_string(), vec!["2-4".to_string(), "17+".to_string()]),
+            ("game_system".to_string(), vec!["dnd5e".to_string()]),
+            ("unknown".to_string(), vec!["x".to_string()]),
+        ]
+        .into();
+
+        let filter = AttributeFilter::build_facet_filter(&selected);
+
+        assert_eq!(
+            filter,
+            "game_system = \"dnd5e\" AND ((challenge_rating >= 2 AND challenge_rating < 5) OR challenge_rating >= 17)"
+        );
+        assert_eq!(
+            AttributeFilter::build_facet_value_filter(FacetField::SpellLevel, "3").as_deref(),
+            Some("element_type = \"spell\" AND level = 3")
+        );
+        assert!(AttributeFilter::build_facet_value_filter(FacetField::ChallengeRating, "1-99").is_none());
+    }
+
     #[test]
     fn test_escape_value() {
         let value = "\"quoted\" and \\slashed";
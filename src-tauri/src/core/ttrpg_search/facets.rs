@@ -0,0 +1,290 @@
+//! Search Facets Module
+//!
+//! Counts facet values (game system, document, element type, CR range, spell
+//! level, source book) over a set of search hits, so filter checkboxes can
+//! show counts without extra queries. Selected values become Meilisearch
+//! filters through `AttributeFilter::build_facet_filter`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// ============================================================================
+// Facet Fields
+// ============================================================================
+
+/// Challenge rating ranges by encounter tier, as (key, lowest CR). Each
+/// range runs up to the next one's lowest CR.
+pub const CR_RANGES: [(&str, f32); 5] = [
+    ("0-1", 0.0),
+    ("2-4", 2.0),
+    ("5-10", 5.0),
+    ("11-16", 11.0),
+    ("17+", 17.0),
+];
+
+/// A field search results can be narrowed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetField {
+    GameSystem,
+    Document,
+    ElementType,
+    ChallengeRating,
+    SpellLevel,
+    SourceBook,
+}
+
+impl FacetField {
+    /// Every facet, in display order
+    pub const ALL: [FacetField; 6] = [
+        FacetField::GameSystem,
+        FacetField::SourceBook,
+        FacetField::Document,
+        FacetField::ElementType,
+        FacetField::ChallengeRating,
+        FacetField::SpellLevel,
+    ];
+
+    /// Key used in requests and responses
+    pub fn key(&self) -> &'static str {
+        match self {
+            FacetField::GameSystem => "game_system",
+            FacetField::Document => "document",
+            FacetField::ElementType => "element_type",
+            FacetField::ChallengeRating => "challenge_rating",
+            FacetField::SpellLevel => "spell_level",
+            FacetField::SourceBook => "source_book",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FacetField::GameSystem => "Game System",
+            FacetField::Document => "Document",
+            FacetField::ElementType => "Element Type",
+            FacetField::ChallengeRating => "Challenge Rating",
+            FacetField::SpellLevel => "Spell Level",
+            FacetField::SourceBook => "Source Book",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.key() == key)
+    }
+
+    /// The facet values of one document, read through `get`
+    pub fn values_from<'a>(
+        get: impl Fn(&str) -> Option<&'a serde_json::Value>,
+    ) -> Vec<(FacetField, String)> {
+        let text = |field: &str| {
+            get(field)
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+        let number = |field: &str| get(field).and_then(|v| v.as_f64());
+
+        let mut values = Vec::new();
+        if let Some(system) = text("game_system") {
+            values.push((FacetField::GameSystem, system));
+        }
+        if let Some(source) = text("source") {
+            values.push((FacetField::Document, source));
+        }
+        if let Some(book) = text("book_title") {
+            values.push((FacetField::SourceBook, book));
+        }
+        let element_type = text("element_type");
+        if let Some(ref element) = element_type {
+            values.push((FacetField::ElementType, element.clone()));
+        }
+        if let Some(range) = number("challenge_rating").and_then(|cr| cr_range_key(cr as f32)) {
+            values.push((FacetField::ChallengeRating, range.to_string()));
+        }
+        let spell_level = number("spell_level")
+            .or_else(|| number("level").filter(|_| element_type.as_deref() == Some("spell")));
+        if let Some(level) = spell_level {
+            values.push((FacetField::SpellLevel, (level as u32).to_string()));
+        }
+        values
+    }
+}
+
+/// The CR range a challenge rating falls in
+pub fn cr_range_key(cr: f32) -> Option<&'static str> {
+    CR_RANGES
+        .iter()
+        .rev()
+        .find(|(_, min)| cr >= *min)
+        .map(|(key, _)| *key)
+}
+
+/// Lowest CR of a range and the lowest CR past it, if any
+pub fn cr_range_bounds(key: &str) -> Option<(f32, Option<f32>)> {
+    let position = CR_RANGES.iter().position(|(k, _)| *k == key)?;
+    Some((CR_RANGES[position].1, CR_RANGES.get(position + 1).map(|(_, min)| *min)))
+}
+
+/// Whether a hit's facet values satisfy a selection keyed by facet key:
+/// one of the selected values for every selected facet. Unknown facets and
+/// empty selections are ignored.
+pub fn matches_selection(values: &[(FacetField, String)], selected: &HashMap<String, Vec<String>>) -> bool {
+    selected.iter().all(|(key, wanted)| match FacetField::from_key(key) {
+        Some(field) if !wanted.is_empty() => values.iter().any(|(f, v)| *f == field && wanted.contains(v)),
+        _ => true,
+    })
+}
+
+// ============================================================================
+// Facet Counts
+// ============================================================================
+
+/// One value of a facet and how many hits have it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacetValue {
+    pub value: String,
+    pub count: usize,
+}
+
+/// A facet's values with counts, ready for filter checkboxes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Facet {
+    pub field: FacetField,
+    pub label: String,
+    pub values: Vec<FacetValue>,
+}
+
+/// Tallies facet values across hits
+#[derive(Debug, Default)]
+pub struct FacetCounter {
+    counts: HashMap<FacetField, HashMap<String, usize>>,
+}
+
+impl FacetCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one hit's facet values
+    pub fn add(&mut self, values: &[(FacetField, String)]) {
+        for (field, value) in values {
+            *self.counts.entry(*field).or_default().entry(value.clone()).or_insert(0) += 1;
+        }
+    }
+
+    /// Add counts already tallied elsewhere, e.g. by another search
+    pub fn add_facets(&mut self, facets: &[Facet]) {
+        for facet in facets {
+            let counts = self.counts.entry(facet.field).or_default();
+            for value in &facet.values {
+                *counts.entry(value.value.clone()).or_insert(0) += value.count;
+            }
+        }
+    }
+
+    /// Facets that have values, in display order. CR ranges and spell levels
+    /// keep their natural order; other values go most common first.
+    pub fn facets(&self) -> Vec<Facet> {
+        FacetField::ALL
+            .iter()
+            .filter_map(|field| {
+                let counts = self.counts.get(field)?;
+                let mut values: Vec<FacetValue> = counts
+                    .iter()
+                    .map(|(value, count)| FacetValue { value: value.clone(), count: *count })
+                    .collect();
+                match field {
+                    FacetField::ChallengeRating => values.sort_by_key(|v| {
+                        CR_RANGES.iter().position(|(key, _)| *key == v.value)
+                    }),
+                    FacetField::SpellLevel => values.sort_by_key(|v| v.value.parse::<u32>().ok()),
+                    _ => values.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value))),
+                }
+                Some(Facet { field: *field, label: field.label().to_string(), values })
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn values(doc: &serde_json::Value) -> Vec<(FacetField, String)> {
+        FacetField::values_from(|field| doc.get(field))
+    }
+
+    #[test]
+    fn test_values_from_document() {
+        let doc = json!({
+            "game_system": "dnd5e",
+            "source": "phb.pdf",
+            "book_title": "Player's Handbook",
+            "element_type": "spell",
+            "level": 3,
+        });
+
+        let found = values(&doc);
+
+        assert!(found.contains(&(FacetField::GameSystem, "dnd5e".to_string())));
+        assert!(found.contains(&(FacetField::SourceBook, "Player's Handbook".to_string())));
+        assert!(found.contains(&(FacetField::SpellLevel, "3".to_string())));
+        assert!(!found.iter().any(|(f, _)| *f == FacetField::ChallengeRating));
+
+        // Level only counts as a spell level on spells
+        let class_feature = json!({ "element_type": "class_feature", "level": 3 });
+        assert!(!values(&class_feature).iter().any(|(f, _)| *f == FacetField::SpellLevel));
+    }
+
+    #[test]
+    fn test_cr_ranges() {
+        assert_eq!(cr_range_key(0.25), Some("0-1"));
+        assert_eq!(cr_range_key(1.5), Some("0-1"));
+        assert_eq!(cr_range_key(4.0), Some("2-4"));
+        assert_eq!(cr_range_key(13.0), Some("11-16"));
+        assert_eq!(cr_range_key(30.0), Some("17+"));
+        assert_eq!(cr_range_key(-1.0), None);
+        assert_eq!(cr_range_bounds("5-10"), Some((5.0, Some(11.0))));
+        assert_eq!(cr_range_bounds("17+"), Some((17.0, None)));
+    }
+
+    #[test]
+    fn test_matches_selection() {
+        let found = values(&json!({ "game_system": "dnd5e", "challenge_rating": 3 }));
+        let select = |key: &str, wanted: &[&str]| -> HashMap<String, Vec<String>> {
+            [(key.to_string(), wanted.iter().map(|w| w.to_string()).collect())].into()
+        };
+
+        assert!(matches_selection(&found, &select("game_system", &["pf2e", "dnd5e"])));
+        assert!(matches_selection(&found, &select("challenge_rating", &["2-4"])));
+        assert!(!matches_selection(&found, &select("spell_level", &["1"])));
+        assert!(matches_selection(&found, &select("unknown", &["x"])));
+    }
+
+    #[test]
+    fn test_counter_orders_values() {
+        let mut counter = FacetCounter::new();
+        for doc in [
+            json!({ "game_system": "pf2e", "challenge_rating": 12 }),
+            json!({ "game_system": "dnd5e", "challenge_rating": 3 }),
+            json!({ "game_system": "dnd5e", "challenge_rating": 0.5 }),
+        ] {
+            counter.add(&values(&doc));
+        }
+
+        let facets = counter.facets();
+        let systems = facets.iter().find(|f| f.field == FacetField::GameSystem).unwrap();
+        assert_eq!(systems.values[0], FacetValue { value: "dnd5e".to_string(), count: 2 });
+
+        let cr = facets.iter().find(|f| f.field == FacetField::ChallengeRating).unwrap();
+        let order: Vec<_> = cr.values.iter().map(|v| v.value.as_str()).collect();
+        assert_eq!(order, vec!["0-1", "2-4", "11-16"]);
+        assert!(!facets.iter().any(|f| f.field == FacetField::Document));
+    }
+}
@@ -7,6 +7,7 @@
 //! - Reciprocal Rank Fusion (RRF) result ranking
 //! - Background indexing queue with retry logic
 //! - Meilisearch filter string building
+//! - Facet counts for drill-down filters
 
 pub mod query_parser;
 pub mod query_expansion;
@@ -14,14 +15,18 @@ pub mod antonym_mapper;
 pub mod result_ranker;
 pub mod index_queue;
 pub mod attribute_filter;
+pub mod facets;
 pub mod ttrpg_constants;
 
-pub use query_parser::{QueryParser, QueryConstraints, RequiredAttribute};
+pub use query_parser::{
+    Comparison, DiceExpression, NumericConstraint, QueryConstraints, QueryParser, RequiredAttribute,
+};
 pub use query_expansion::QueryExpander;
 pub use antonym_mapper::AntonymMapper;
 pub use result_ranker::{ResultRanker, RankingConfig, ScoreBreakdown, RankedResult, SearchCandidate};
 pub use index_queue::{IndexQueue, PendingDocument};
 pub use attribute_filter::AttributeFilter;
+pub use facets::{matches_selection, Facet, FacetCounter, FacetField, FacetValue};
 pub use ttrpg_constants::{
     TTRPGGenre, CharacterClass, CharacterRace, CharacterTrait, TraitCategory,
     CharacterBackground, CharacterMotivation, NPCRole, WeaponType, ItemType,
@@ -1,6 +1,8 @@
 //! Query Parser Module
 //!
-//! Parses user queries to extract constraints, negations, and named entities.
+//! Parses user queries to extract constraints, negations, and named entities,
+//! including dice expressions ("8d6") and numeric comparisons ("AC > 17",
+//! "at 5th level") that become attribute filters.
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -24,6 +26,76 @@ pub struct RequiredAttribute {
     pub required: bool,
 }
 
+/// How a numeric attribute compares to a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Comparison {
+    /// The Meilisearch filter operator
+    pub fn operator(&self) -> &'static str {
+        match self {
+            Comparison::Equal => "=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+        }
+    }
+
+    /// The comparison for an operator or phrase (">=", "at least", "over")
+    fn from_phrase(phrase: &str) -> Option<Self> {
+        let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        Some(match phrase.as_str() {
+            "=" | "==" => Comparison::Equal,
+            ">" | "over" | "above" | "more than" | "greater than" | "higher than" => Comparison::Greater,
+            ">=" | "=>" | "at least" | "+" => Comparison::GreaterOrEqual,
+            "<" | "under" | "below" | "less than" | "fewer than" | "lower than" => Comparison::Less,
+            "<=" | "=<" | "at most" | "up to" => Comparison::LessOrEqual,
+            _ if phrase.starts_with("or ") => match &phrase[3..] {
+                "higher" | "more" | "greater" | "better" | "above" => Comparison::GreaterOrEqual,
+                "lower" | "less" | "fewer" | "below" => Comparison::LessOrEqual,
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// A numeric attribute compared to a value ("AC > 17")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumericConstraint {
+    /// Filterable attribute (armor_class, hit_points, challenge_rating, ...)
+    pub field: String,
+    pub comparison: Comparison,
+    pub value: f32,
+}
+
+/// A dice expression in a query ("8d6", "1d20+5")
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiceExpression {
+    pub count: u32,
+    pub sides: u32,
+    pub modifier: i32,
+}
+
+impl DiceExpression {
+    /// Standard notation, as stored in `dice_expressions` ("2d6", "1d20+5")
+    pub fn notation(&self) -> String {
+        match self.modifier {
+            0 => format!("{}d{}", self.count, self.sides),
+            m if m > 0 => format!("{}d{}+{}", self.count, self.sides, m),
+            m => format!("{}d{}{}", self.count, self.sides, m),
+        }
+    }
+}
+
 /// Parsed query constraints
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct QueryConstraints {
@@ -43,6 +115,12 @@ pub struct QueryConstraints {
     pub level_range: Option<(u32, u32)>,
     /// Exact match entities (quoted strings)
     pub exact_match_entities: Vec<String>,
+    /// Numeric comparisons ("AC > 17", "at 5th level")
+    #[serde(default)]
+    pub numeric_constraints: Vec<NumericConstraint>,
+    /// Dice expressions ("8d6")
+    #[serde(default)]
+    pub dice_expressions: Vec<DiceExpression>,
 }
 
 // ============================================================================
@@ -56,6 +134,9 @@ pub struct QueryParser {
     negation_pattern: Regex,
     cr_pattern: Regex,
     level_pattern: Regex,
+    ordinal_level_pattern: Regex,
+    comparison_pattern: Regex,
+    dice_pattern: Regex,
     quoted_pattern: Regex,
 }
 
@@ -85,6 +166,19 @@ impl QueryParser {
             level_pattern: Regex::new(
                 r"(?i)\blevel\s*(\d+)\s*(?:to|-|–)\s*(\d+)|\blevel\s*(\d+)"
             ).unwrap(),
+            ordinal_level_pattern: Regex::new(
+                r"(?i)\b(at\s+)?(\d+)(?:st|nd|rd|th)[\s-]*level\b"
+            ).unwrap(),
+            comparison_pattern: Regex::new(concat!(
+                r"(?i)\b(ac|armou?r class|hp|hit points|speed|cr|challenge rating|level)\s*",
+                r"(>=|<=|=>|=<|==|>|<|=|at least|at most|up to|more than|greater than|higher than|",
+                r"less than|fewer than|lower than|over|above|under|below)?\s*",
+                r"(\d+(?:/\d+|\.\d+)?)",
+                r"(\s*\+|\s+or\s+(?:higher|more|greater|better|above|lower|less|fewer|below)\b)?",
+            )).unwrap(),
+            dice_pattern: Regex::new(
+                r"(?i)\b(\d{0,3})d(\d{1,3})(?:\s*([+-])\s*(\d{1,3}))?\b"
+            ).unwrap(),
             quoted_pattern: Regex::new(r#""([^"]+)""#).unwrap(),
         }
     }
@@ -110,6 +204,25 @@ impl QueryParser {
         // Extract level range
         constraints.level_range = self.extract_level_range(query);
 
+        // Extract comparisons and ordinal levels ("AC > 17", "at 5th level")
+        let (numeric, ordinal_level) = self.extract_numeric_constraints(query);
+        constraints.numeric_constraints = numeric;
+        if constraints.level_range.is_none() {
+            constraints.level_range = ordinal_level.map(|lvl| (lvl, lvl));
+        }
+        // "cr 10 or higher" also reads as "cr 10"; the comparison wins
+        let compares = |field: &str| constraints.numeric_constraints.iter().any(|c| c.field == field);
+        let (compares_cr, compares_level) = (compares("challenge_rating"), compares("level"));
+        if compares_cr && constraints.cr_range.is_some_and(|(min, max)| min == max) {
+            constraints.cr_range = None;
+        }
+        if compares_level && constraints.level_range.is_some_and(|(min, max)| min == max) {
+            constraints.level_range = None;
+        }
+
+        // Extract dice expressions
+        constraints.dice_expressions = self.extract_dice(query);
+
         // Extract required attributes from vocabulary
         constraints.required_attributes = self.extract_attributes(query);
 
@@ -187,6 +300,82 @@ impl QueryParser {
         None
     }
 
+    /// Extract numeric comparisons, plus the level of a "5th-level" phrase.
+    ///
+    /// "at 5th level" means cast or reached at that level, so it becomes
+    /// `level <= 5` rather than a level of exactly 5. A bare "cr 5" or
+    /// "level 3" is left to the range extractors.
+    fn extract_numeric_constraints(&self, query: &str) -> (Vec<NumericConstraint>, Option<u32>) {
+        let mut numeric = Vec::new();
+        let mut ordinal_level = None;
+
+        for caps in self.ordinal_level_pattern.captures_iter(query) {
+            let Some(level) = caps.get(2).and_then(|m| m.as_str().parse::<u32>().ok()) else {
+                continue;
+            };
+            if caps.get(1).is_some() {
+                numeric.push(NumericConstraint {
+                    field: "level".to_string(),
+                    comparison: Comparison::LessOrEqual,
+                    value: level as f32,
+                });
+            } else if ordinal_level.is_none() {
+                ordinal_level = Some(level);
+            }
+        }
+
+        for caps in self.comparison_pattern.captures_iter(query) {
+            let field = match caps[1].split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase().as_str() {
+                "ac" | "armor class" | "armour class" => "armor_class",
+                "hp" | "hit points" => "hit_points",
+                "speed" => "speed",
+                "cr" | "challenge rating" => "challenge_rating",
+                _ => "level",
+            };
+            let phrase = caps.get(2).or(caps.get(4)).map(|m| m.as_str().trim());
+            let comparison = match phrase {
+                Some(phrase) => match Comparison::from_phrase(phrase) {
+                    Some(comparison) => comparison,
+                    None => continue,
+                },
+                // "cr 5" and "level 3" are ranges, handled separately
+                None if matches!(field, "challenge_rating" | "level") => continue,
+                None => Comparison::Equal,
+            };
+            if let Some(value) = Self::parse_cr(&caps[3]) {
+                numeric.push(NumericConstraint { field: field.to_string(), comparison, value });
+            }
+        }
+
+        (numeric, ordinal_level)
+    }
+
+    /// Extract dice expressions ("8d6", "d20", "1d8 + 3")
+    fn extract_dice(&self, query: &str) -> Vec<DiceExpression> {
+        let mut dice: Vec<DiceExpression> = Vec::new();
+        for caps in self.dice_pattern.captures_iter(query) {
+            let count = match caps[1].parse::<u32>() {
+                Ok(count) => count,
+                Err(_) if caps[1].is_empty() => 1,
+                Err(_) => continue,
+            };
+            let Ok(sides) = caps[2].parse::<u32>() else { continue };
+            if count == 0 || sides < 2 {
+                continue;
+            }
+            let modifier = match (caps.get(3), caps.get(4).and_then(|m| m.as_str().parse::<i32>().ok())) {
+                (Some(sign), Some(n)) if sign.as_str() == "-" => -n,
+                (Some(_), Some(n)) => n,
+                _ => 0,
+            };
+            let expression = DiceExpression { count, sides, modifier };
+            if !dice.contains(&expression) {
+                dice.push(expression);
+            }
+        }
+        dice
+    }
+
     /// Extract TTRPG attributes from query
     fn extract_attributes(&self, query: &str) -> Vec<RequiredAttribute> {
         let query_lower = query.to_lowercase();
@@ -269,6 +458,11 @@ impl QueryParser {
         // Remove level patterns
         result = self.level_pattern.replace_all(&result, "").to_string();
 
+        // Remove ordinal levels and comparisons; dice stay, as they are
+        // worth matching as keywords too
+        result = self.ordinal_level_pattern.replace_all(&result, "").to_string();
+        result = self.comparison_pattern.replace_all(&result, "").to_string();
+
         // Remove quotes
         result = self.quoted_pattern.replace_all(&result, "$1").to_string();
 
@@ -340,6 +534,56 @@ mod tests {
         assert_eq!(result.level_range, Some((9, 9)));
     }
 
+    #[test]
+    fn test_parse_numeric_comparisons() {
+        let parser = QueryParser::new();
+
+        let result = parser.parse("monsters with AC > 17 and hp at least 100");
+        assert_eq!(
+            result.numeric_constraints,
+            vec![
+                NumericConstraint { field: "armor_class".to_string(), comparison: Comparison::Greater, value: 17.0 },
+                NumericConstraint {
+                    field: "hit_points".to_string(),
+                    comparison: Comparison::GreaterOrEqual,
+                    value: 100.0,
+                },
+            ]
+        );
+        assert_eq!(result.semantic_query, "monsters with and");
+
+        let result = parser.parse("dragons cr 10 or higher with AC 19");
+        assert_eq!(result.cr_range, None);
+        assert!(result.numeric_constraints.iter().any(|c| c.field == "challenge_rating"
+            && c.comparison == Comparison::GreaterOrEqual
+            && c.value == 10.0));
+        assert!(result.numeric_constraints.iter().any(|c| c.field == "armor_class"
+            && c.comparison == Comparison::Equal));
+
+        // Bare "level 3" stays a range only
+        let result = parser.parse("level 3 spells");
+        assert!(result.numeric_constraints.is_empty());
+    }
+
+    #[test]
+    fn test_parse_ordinal_levels_and_dice() {
+        let parser = QueryParser::new();
+
+        let result = parser.parse("fireball damage at 5th level");
+        assert_eq!(
+            result.numeric_constraints,
+            vec![NumericConstraint { field: "level".to_string(), comparison: Comparison::LessOrEqual, value: 5.0 }]
+        );
+        assert_eq!(result.level_range, None);
+        assert_eq!(result.semantic_query, "fireball damage");
+
+        let result = parser.parse("3rd-level evocation spells dealing 8d6 or d20 + 5");
+        assert_eq!(result.level_range, Some((3, 3)));
+        let notations: Vec<String> = result.dice_expressions.iter().map(DiceExpression::notation).collect();
+        assert_eq!(notations, vec!["8d6", "1d20+5"]);
+        assert!(result.semantic_query.contains("8d6"));
+    }
+
     #[test]
     fn test_parse_quoted_strings() {
         let parser = QueryParser::new();
@@ -410,6 +410,12 @@ impl UsageTracker {
         records.iter().rev().take(limit).cloned().collect()
     }
 
+    /// Get records made at or after a point in time, oldest first
+    pub fn get_records_since(&self, since: DateTime<Utc>) -> Vec<UsageRecord> {
+        let records = self.records.read().unwrap();
+        records.iter().filter(|r| r.timestamp >= since).cloned().collect()
+    }
+
     // Helper to aggregate stats
     fn aggregate_stats(
         &self,
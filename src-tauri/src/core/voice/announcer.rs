@@ -0,0 +1,400 @@
+//! Combat Announcer
+//!
+//! Turns combat state changes (turn start, damage, conditions, death) into
+//! short spoken announcements. Each kind of event has a list of phrasing
+//! templates that users can replace; alternatives are rotated so repeated
+//! events don't sound identical.
+//!
+//! Templates use `{placeholder}` syntax:
+//!
+//! | Placeholder   | Meaning                              |
+//! |---------------|--------------------------------------|
+//! | `{name}`      | Combatant the event is about         |
+//! | `{round}`     | Current combat round                 |
+//! | `{amount}`    | Damage or healing dealt              |
+//! | `{hp}`        | Hit points after the event           |
+//! | `{max_hp}`    | Maximum hit points                   |
+//! | `{condition}` | Condition applied or removed         |
+//!
+//! A template that needs a value the event doesn't carry (e.g., `{hp}` for a
+//! monster without tracked hit points) is skipped in favour of the next one.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::types::{Result, VoiceError};
+
+static PLACEHOLDER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{(\w+)\}").expect("Invalid placeholder regex"));
+
+/// Placeholders templates may use
+pub const PLACEHOLDERS: &[&str] = &["name", "round", "amount", "hp", "max_hp", "condition"];
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Kinds of announcement, each with its own templates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnouncementKind {
+    CombatStart,
+    CombatEnd,
+    /// First turn of a new round
+    RoundStart,
+    TurnStart,
+    Damage,
+    /// Damage that drops a combatant to half hit points or below
+    Bloodied,
+    Healing,
+    ConditionApplied,
+    ConditionRemoved,
+    Death,
+}
+
+impl AnnouncementKind {
+    pub const ALL: [AnnouncementKind; 10] = [
+        AnnouncementKind::CombatStart,
+        AnnouncementKind::CombatEnd,
+        AnnouncementKind::RoundStart,
+        AnnouncementKind::TurnStart,
+        AnnouncementKind::Damage,
+        AnnouncementKind::Bloodied,
+        AnnouncementKind::Healing,
+        AnnouncementKind::ConditionApplied,
+        AnnouncementKind::ConditionRemoved,
+        AnnouncementKind::Death,
+    ];
+
+    /// Built-in phrasings
+    pub fn default_templates(self) -> &'static [&'static str] {
+        match self {
+            Self::CombatStart => &["Roll for initiative!", "Steel yourselves. Combat begins."],
+            Self::CombatEnd => &["The battle is over after {round} rounds.", "Combat ends."],
+            Self::RoundStart => &["Round {round}. {name}, you're up.", "Round {round} begins with {name}."],
+            Self::TurnStart => &["{name}, you're up.", "{name}'s turn.", "Over to {name}."],
+            Self::Damage => &["{name} takes {amount} damage.", "{amount} damage to {name}."],
+            Self::Bloodied => &["{name} is bloodied, down to {hp} hit points!", "{name} is badly hurt!"],
+            Self::Healing => &["{name} recovers {amount} hit points.", "{name} is healed for {amount}."],
+            Self::ConditionApplied => &["{name} is {condition}.", "{name} is now {condition}."],
+            Self::ConditionRemoved => &["{name} is no longer {condition}."],
+            Self::Death => &["{name} falls!", "{name} is down!"],
+        }
+    }
+}
+
+/// A combat state change to announce
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnnouncerEvent {
+    CombatStart,
+    CombatEnd {
+        rounds: u32,
+    },
+    TurnStart {
+        name: String,
+        round: u32,
+        /// The turn wrapped around to a new round
+        #[serde(default)]
+        new_round: bool,
+    },
+    Damage {
+        name: String,
+        amount: i32,
+        hp: Option<i32>,
+        max_hp: Option<i32>,
+    },
+    Healing {
+        name: String,
+        amount: i32,
+        hp: Option<i32>,
+    },
+    ConditionApplied {
+        name: String,
+        condition: String,
+    },
+    ConditionRemoved {
+        name: String,
+        condition: String,
+    },
+    Death {
+        name: String,
+    },
+}
+
+impl AnnouncerEvent {
+    pub fn kind(&self) -> AnnouncementKind {
+        match self {
+            Self::CombatStart => AnnouncementKind::CombatStart,
+            Self::CombatEnd { .. } => AnnouncementKind::CombatEnd,
+            Self::TurnStart { new_round: true, .. } => AnnouncementKind::RoundStart,
+            Self::TurnStart { .. } => AnnouncementKind::TurnStart,
+            Self::Damage { amount, hp: Some(hp), max_hp: Some(max), .. }
+                if *hp > 0 && *hp * 2 <= *max && (*hp + *amount) * 2 > *max =>
+            {
+                AnnouncementKind::Bloodied
+            }
+            Self::Damage { .. } => AnnouncementKind::Damage,
+            Self::Healing { .. } => AnnouncementKind::Healing,
+            Self::ConditionApplied { .. } => AnnouncementKind::ConditionApplied,
+            Self::ConditionRemoved { .. } => AnnouncementKind::ConditionRemoved,
+            Self::Death { .. } => AnnouncementKind::Death,
+        }
+    }
+
+    /// Values available to templates
+    pub fn values(&self) -> HashMap<&'static str, String> {
+        let mut values = HashMap::new();
+        match self {
+            Self::CombatStart => {}
+            Self::CombatEnd { rounds } => {
+                values.insert("round", rounds.to_string());
+            }
+            Self::TurnStart { name, round, .. } => {
+                values.insert("name", name.clone());
+                values.insert("round", round.to_string());
+            }
+            Self::Damage { name, amount, hp, max_hp } => {
+                values.insert("name", name.clone());
+                values.insert("amount", amount.to_string());
+                if let Some(hp) = hp {
+                    values.insert("hp", hp.to_string());
+                }
+                if let Some(max_hp) = max_hp {
+                    values.insert("max_hp", max_hp.to_string());
+                }
+            }
+            Self::Healing { name, amount, hp } => {
+                values.insert("name", name.clone());
+                values.insert("amount", amount.to_string());
+                if let Some(hp) = hp {
+                    values.insert("hp", hp.to_string());
+                }
+            }
+            Self::ConditionApplied { name, condition } | Self::ConditionRemoved { name, condition } => {
+                values.insert("name", name.clone());
+                values.insert("condition", condition.to_lowercase());
+            }
+            Self::Death { name } => {
+                values.insert("name", name.clone());
+            }
+        }
+        values
+    }
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Combat announcer settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnouncerConfig {
+    /// Announcements are opt-in
+    pub enabled: bool,
+    /// Voice for announcements (`None` = the default voice)
+    pub voice_id: Option<String>,
+    /// Event kinds that are not announced
+    pub muted: HashSet<AnnouncementKind>,
+    /// Replacement phrasings per event kind
+    pub templates: HashMap<AnnouncementKind, Vec<String>>,
+}
+
+impl AnnouncerConfig {
+    /// Load from a JSON file; a missing file yields the defaults
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| VoiceError::IoError(std::io::Error::other(e)))
+    }
+
+    /// Save to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| VoiceError::IoError(std::io::Error::other(e)))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Check that every custom template only uses known placeholders
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        for (kind, templates) in &self.templates {
+            for template in templates {
+                if let Some(unknown) = PLACEHOLDER_RE
+                    .captures_iter(template)
+                    .map(|c| c[1].to_string())
+                    .find(|p| !PLACEHOLDERS.contains(&p.as_str()))
+                {
+                    return Err(format!(
+                        "Unknown placeholder {{{}}} in {:?} template \"{}\"",
+                        unknown, kind, template
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Templates for an event kind: the user's, or the built-in ones
+    pub fn templates_for(&self, kind: AnnouncementKind) -> Vec<&str> {
+        match self.templates.get(&kind).filter(|t| t.iter().any(|s| !s.trim().is_empty())) {
+            Some(custom) => custom.iter().map(String::as_str).filter(|s| !s.trim().is_empty()).collect(),
+            None => kind.default_templates().to_vec(),
+        }
+    }
+}
+
+// ============================================================================
+// Announcer
+// ============================================================================
+
+/// Fill a template's placeholders; `None` if a needed value is missing
+pub fn render_template(template: &str, values: &HashMap<&'static str, String>) -> Option<String> {
+    let mut missing = false;
+    let rendered = PLACEHOLDER_RE.replace_all(template, |caps: &regex::Captures| {
+        match values.get(&caps[1]) {
+            Some(value) => value.clone(),
+            None => {
+                missing = true;
+                String::new()
+            }
+        }
+    });
+    (!missing).then(|| rendered.trim().to_string())
+}
+
+/// Renders announcements for combat events
+#[derive(Debug, Default)]
+pub struct CombatAnnouncer {
+    config: AnnouncerConfig,
+    /// Rotates through alternative phrasings
+    counter: AtomicUsize,
+}
+
+impl CombatAnnouncer {
+    pub fn new(config: AnnouncerConfig) -> Self {
+        Self { config, counter: AtomicUsize::new(0) }
+    }
+
+    pub fn config(&self) -> &AnnouncerConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: AnnouncerConfig) {
+        self.config = config;
+    }
+
+    /// Text to speak for an event, or `None` if it shouldn't be announced
+    pub fn announce(&self, event: &AnnouncerEvent) -> Option<String> {
+        if !self.config.enabled {
+            return None;
+        }
+        self.render(event)
+    }
+
+    /// Text for an event regardless of whether the announcer is enabled
+    pub fn render(&self, event: &AnnouncerEvent) -> Option<String> {
+        let kind = event.kind();
+        if self.config.muted.contains(&kind) {
+            return None;
+        }
+
+        let templates = self.config.templates_for(kind);
+        if templates.is_empty() {
+            return None;
+        }
+        let values = event.values();
+        let start = self.counter.fetch_add(1, Ordering::Relaxed);
+        (0..templates.len())
+            .map(|offset| templates[(start + offset) % templates.len()])
+            .find_map(|template| render_template(template, &values))
+            .filter(|text| !text.is_empty())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AnnouncerConfig {
+        AnnouncerConfig { enabled: true, ..Default::default() }
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let announcer = CombatAnnouncer::default();
+        assert_eq!(announcer.announce(&AnnouncerEvent::CombatStart), None);
+        assert!(announcer.render(&AnnouncerEvent::CombatStart).is_some());
+    }
+
+    #[test]
+    fn test_event_kinds() {
+        let damage = |amount, hp| AnnouncerEvent::Damage {
+            name: "Goblin".into(),
+            amount,
+            hp: Some(hp),
+            max_hp: Some(20),
+        };
+        assert_eq!(damage(5, 12).kind(), AnnouncementKind::Damage);
+        assert_eq!(damage(5, 10).kind(), AnnouncementKind::Bloodied);
+        assert_eq!(damage(2, 8).kind(), AnnouncementKind::Damage);
+
+        let turn = AnnouncerEvent::TurnStart { name: "Aria".into(), round: 2, new_round: true };
+        assert_eq!(turn.kind(), AnnouncementKind::RoundStart);
+    }
+
+    #[test]
+    fn test_custom_templates_rotate_and_skip_missing_values() {
+        let mut config = enabled();
+        config.templates.insert(
+            AnnouncementKind::Damage,
+            vec!["{name} is at {hp}.".into(), "Ouch, {name}!".into()],
+        );
+        let announcer = CombatAnnouncer::new(config);
+        let event = AnnouncerEvent::Damage { name: "Ogre".into(), amount: 3, hp: Some(40), max_hp: Some(59) };
+
+        assert_eq!(announcer.announce(&event).as_deref(), Some("Ogre is at 40."));
+        assert_eq!(announcer.announce(&event).as_deref(), Some("Ouch, Ogre!"));
+
+        let untracked = AnnouncerEvent::Damage { name: "Ogre".into(), amount: 3, hp: None, max_hp: None };
+        for _ in 0..3 {
+            assert_eq!(announcer.announce(&untracked).as_deref(), Some("Ouch, Ogre!"));
+        }
+    }
+
+    #[test]
+    fn test_muted_kinds() {
+        let mut config = enabled();
+        config.muted.insert(AnnouncementKind::TurnStart);
+        let announcer = CombatAnnouncer::new(config);
+
+        let turn = AnnouncerEvent::TurnStart { name: "Aria".into(), round: 1, new_round: false };
+        assert_eq!(announcer.announce(&turn), None);
+        let death = AnnouncerEvent::Death { name: "Aria".into() };
+        assert!(announcer.announce(&death).unwrap().contains("Aria"));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_placeholders() {
+        let mut config = enabled();
+        config.templates.insert(AnnouncementKind::Death, vec!["{name} dies to {weapon}".into()]);
+        assert!(config.validate().unwrap_err().contains("{weapon}"));
+
+        config.templates.insert(AnnouncementKind::Death, vec!["{name} dies".into()]);
+        assert!(config.validate().is_ok());
+    }
+}
@@ -2,6 +2,11 @@
 //!
 //! Caches synthesized audio with intelligent LRU eviction.
 //! Provides disk storage with size tracking and cache statistics.
+//!
+//! Files are content-addressed: the cache key is a hash of the text, voice,
+//! provider, settings and format, so identical requests reuse the same audio.
+//! Entry metadata is kept in an index file so LRU order, tags and the
+//! provider/voice of each clip survive restarts.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -25,6 +30,12 @@ const DEFAULT_MAX_SIZE_BYTES: u64 = 500 * 1024 * 1024;
 /// Minimum free space to maintain after eviction: 10 MB
 const MIN_FREE_SPACE_BYTES: u64 = 10 * 1024 * 1024;
 
+/// Index file holding entry metadata, stored alongside the audio files
+const INDEX_FILE_NAME: &str = "cache_index.json";
+
+/// Length of the hex digest in content-addressed keys
+const KEY_HASH_LEN: usize = 32;
+
 // ============================================================================
 // Cache Types
 // ============================================================================
@@ -48,6 +59,12 @@ pub struct CacheEntry {
     pub tags: Vec<String>,
     /// Voice profile ID used to generate this audio
     pub profile_id: Option<String>,
+    /// Provider that synthesized this audio (e.g., "openai", "piper")
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Provider-specific voice ID used to synthesize this audio
+    #[serde(default)]
+    pub voice_id: Option<String>,
     /// Duration of the audio in milliseconds
     pub duration_ms: Option<u64>,
     /// Output format
@@ -67,11 +84,20 @@ impl CacheEntry {
             access_count: 1,
             tags: Vec::new(),
             profile_id: None,
+            provider: None,
+            voice_id: None,
             duration_ms: None,
             format,
         }
     }
 
+    /// Record which provider and voice produced this entry
+    pub fn with_source(mut self, provider: &str, voice_id: &str) -> Self {
+        self.provider = Some(provider.to_string());
+        self.voice_id = Some(voice_id.to_string());
+        self
+    }
+
     /// Record access to this entry
     pub fn record_access(&mut self) {
         self.last_accessed = Utc::now();
@@ -148,6 +174,12 @@ pub struct CacheStats {
     pub oldest_entry_age_secs: i64,
     /// Average entry size in bytes
     pub avg_entry_size_bytes: u64,
+    /// Bytes used per provider ("unknown" for entries without metadata)
+    #[serde(default)]
+    pub bytes_by_provider: HashMap<String, u64>,
+    /// Bytes used per voice, keyed as "provider:voice_id"
+    #[serde(default)]
+    pub bytes_by_voice: HashMap<String, u64>,
 }
 
 impl CacheStats {
@@ -183,6 +215,9 @@ pub struct CacheKeyParams {
 
 impl CacheKeyParams {
     /// Create new cache key parameters
+    ///
+    /// Whitespace in the text is normalized so that requests differing only
+    /// in spacing or line breaks share one cache entry.
     pub fn new(
         text: &str,
         provider: VoiceProviderType,
@@ -191,7 +226,7 @@ impl CacheKeyParams {
         format: OutputFormat,
     ) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.split_whitespace().collect::<Vec<_>>().join(" "),
             provider,
             voice_id: voice_id.to_string(),
             settings_hash: hash_settings_sha256(settings),
@@ -218,7 +253,7 @@ impl CacheKeyParams {
         hasher.update(format!("{:?}", self.format).as_bytes());
 
         let result = hasher.finalize();
-        // Use first 16 bytes (32 hex chars) for a shorter but still unique key
+        // Use first 16 bytes (KEY_HASH_LEN hex chars) for a shorter but still unique key
         format!("{}.{}", hex::encode(&result[..16]), self.format.extension())
     }
 
@@ -288,6 +323,8 @@ pub struct AudioCache {
     cache_dir: PathBuf,
     /// Cache configuration
     config: CacheConfig,
+    /// Maximum cache size in bytes (adjustable at runtime)
+    max_size: AtomicU64,
     /// Current cache size in bytes
     current_size: AtomicU64,
     /// Cache entries indexed by key
@@ -306,6 +343,7 @@ impl AudioCache {
 
         let cache = Self {
             cache_dir,
+            max_size: AtomicU64::new(config.max_size_bytes),
             config,
             current_size: AtomicU64::new(0),
             entries: RwLock::new(HashMap::new()),
@@ -337,7 +375,25 @@ impl AudioCache {
 
     /// Get the maximum cache size in bytes
     pub fn max_size(&self) -> u64 {
-        self.config.max_size_bytes
+        self.max_size.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum cache size, evicting least recently used entries
+    /// until the cache fits
+    ///
+    /// Returns the number of entries evicted.
+    pub async fn set_max_size(&self, max_size_bytes: u64) -> CacheResult<usize> {
+        self.max_size.store(max_size_bytes, Ordering::Relaxed);
+
+        let current = self.current_size();
+        if current <= max_size_bytes {
+            return Ok(0);
+        }
+
+        // An explicit limit change ignores the minimum-age grace period
+        let evicted = self.evict_lru(current - max_size_bytes, 0).await;
+        self.save_index().await?;
+        Ok(evicted)
     }
 
     /// Check if the cache contains an entry for the given key
@@ -347,17 +403,25 @@ impl AudioCache {
 
     /// Get a cache entry by key
     pub async fn get(&self, key: &str) -> Option<PathBuf> {
-        let mut entries = self.entries.write().await;
+        let path = {
+            let mut entries = self.entries.write().await;
 
-        if let Some(entry) = entries.get_mut(key) {
-            // Update access stats
-            entry.record_access();
-            self.hits.fetch_add(1, Ordering::Relaxed);
-            Some(entry.path.clone())
-        } else {
-            self.misses.fetch_add(1, Ordering::Relaxed);
-            None
+            if let Some(entry) = entries.get_mut(key) {
+                // Update access stats
+                entry.record_access();
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                entry.path.clone()
+            } else {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+        };
+
+        // Persist the new access time so LRU order survives restarts
+        if let Err(e) = self.save_index().await {
+            log::debug!("Failed to save voice cache index: {}", e);
         }
+        Some(path)
     }
 
     /// Get or synthesize audio
@@ -371,6 +435,43 @@ impl AudioCache {
         tags: &[String],
         synthesize: F,
     ) -> CacheResult<PathBuf>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CacheResult<Vec<u8>>>,
+    {
+        self.lookup_or_synthesize(key, format, tags, None, synthesize)
+            .await
+            .map(|(path, _)| path)
+    }
+
+    /// Get or synthesize audio, recording the provider and voice that produced it
+    ///
+    /// Returns the audio path and whether it was served from the cache.
+    pub async fn get_or_synthesize_for<F, Fut>(
+        &self,
+        key: &str,
+        format: OutputFormat,
+        tags: &[String],
+        provider: &str,
+        voice_id: &str,
+        synthesize: F,
+    ) -> CacheResult<(PathBuf, bool)>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CacheResult<Vec<u8>>>,
+    {
+        self.lookup_or_synthesize(key, format, tags, Some((provider, voice_id)), synthesize)
+            .await
+    }
+
+    async fn lookup_or_synthesize<F, Fut>(
+        &self,
+        key: &str,
+        format: OutputFormat,
+        tags: &[String],
+        source: Option<(&str, &str)>,
+        synthesize: F,
+    ) -> CacheResult<(PathBuf, bool)>
     where
         F: FnOnce() -> Fut,
         Fut: Future<Output = CacheResult<Vec<u8>>>,
@@ -378,7 +479,7 @@ impl AudioCache {
         // Check cache first
         if let Some(path) = self.get(key).await {
             if path.exists() {
-                return Ok(path);
+                return Ok((path, true));
             }
             // Path doesn't exist, remove stale entry
             self.remove(key).await?;
@@ -392,25 +493,22 @@ impl AudioCache {
         self.ensure_space(size).await?;
 
         // Write to disk
-        let file_path = self.cache_dir.join(format!("{}.{}", key, format.extension()));
+        let file_path = self.file_path(key, &format);
         fs::write(&file_path, &audio_data).await?;
 
         // Create entry
         let mut entry = CacheEntry::new(key.to_string(), file_path.clone(), size, format);
+        if let Some((provider, voice_id)) = source {
+            entry = entry.with_source(provider, voice_id);
+        }
         for tag in tags {
             entry.add_tag(tag);
         }
 
-        // Store entry
-        {
-            let mut entries = self.entries.write().await;
-            entries.insert(key.to_string(), entry);
-        }
-
-        // Update size
-        self.current_size.fetch_add(size, Ordering::Relaxed);
+        self.insert_entry(entry).await;
+        self.save_index().await?;
 
-        Ok(file_path)
+        Ok((file_path, false))
     }
 
     /// Store audio data in the cache
@@ -427,7 +525,7 @@ impl AudioCache {
         self.ensure_space(size).await?;
 
         // Write to disk
-        let file_path = self.cache_dir.join(format!("{}.{}", key, format.extension()));
+        let file_path = self.file_path(key, &format);
         fs::write(&file_path, data).await?;
 
         // Create entry
@@ -436,33 +534,50 @@ impl AudioCache {
             entry.add_tag(tag);
         }
 
-        // Store entry
-        {
-            let mut entries = self.entries.write().await;
-            // If key already exists, remove old size first
-            if let Some(old_entry) = entries.remove(key) {
-                self.current_size.fetch_sub(old_entry.size, Ordering::Relaxed);
-            }
-            entries.insert(key.to_string(), entry);
+        self.insert_entry(entry).await;
+        self.save_index().await?;
+
+        Ok(file_path)
+    }
+
+    /// Path for a cache file
+    ///
+    /// Content-addressed keys already carry the format extension; other keys
+    /// get it appended.
+    fn file_path(&self, key: &str, format: &OutputFormat) -> PathBuf {
+        let extension = format!(".{}", format.extension());
+        if key.ends_with(&extension) {
+            self.cache_dir.join(key)
+        } else {
+            self.cache_dir.join(format!("{}{}", key, extension))
         }
+    }
 
-        // Update size
+    /// Store an entry, replacing (and un-counting) any previous entry for the key
+    async fn insert_entry(&self, entry: CacheEntry) {
+        let size = entry.size;
+        let mut entries = self.entries.write().await;
+        if let Some(old_entry) = entries.insert(entry.key.clone(), entry) {
+            self.current_size.fetch_sub(old_entry.size, Ordering::Relaxed);
+        }
         self.current_size.fetch_add(size, Ordering::Relaxed);
-
-        Ok(file_path)
     }
 
     /// Remove an entry from the cache
     pub async fn remove(&self, key: &str) -> CacheResult<()> {
-        let mut entries = self.entries.write().await;
+        let removed = {
+            let mut entries = self.entries.write().await;
+            entries.remove(key)
+        };
 
-        if let Some(entry) = entries.remove(key) {
+        if let Some(entry) = removed {
             // Delete file
             if entry.path.exists() {
                 fs::remove_file(&entry.path).await?;
             }
             // Update size
             self.current_size.fetch_sub(entry.size, Ordering::Relaxed);
+            self.save_index().await?;
         }
 
         Ok(())
@@ -487,19 +602,49 @@ impl AudioCache {
         Ok(count)
     }
 
+    /// Clear entries produced by a provider and/or voice
+    ///
+    /// `None` matches anything, so passing neither clears the whole cache.
+    pub async fn clear_matching(
+        &self,
+        provider: Option<&str>,
+        voice_id: Option<&str>,
+    ) -> CacheResult<usize> {
+        let entries_to_remove: Vec<String> = {
+            let entries = self.entries.read().await;
+            entries
+                .iter()
+                .filter(|(_, e)| {
+                    provider.is_none_or(|p| e.provider.as_deref() == Some(p))
+                        && voice_id.is_none_or(|v| e.voice_id.as_deref() == Some(v))
+                })
+                .map(|(k, _)| k.clone())
+                .collect()
+        };
+
+        let count = entries_to_remove.len();
+        for key in entries_to_remove {
+            self.remove(&key).await?;
+        }
+
+        Ok(count)
+    }
+
     /// Clear all cache entries
     pub async fn clear(&self) -> CacheResult<()> {
-        let mut entries = self.entries.write().await;
+        {
+            let mut entries = self.entries.write().await;
 
-        for (_, entry) in entries.drain() {
-            if entry.path.exists() {
-                let _ = fs::remove_file(&entry.path).await;
+            for (_, entry) in entries.drain() {
+                if entry.path.exists() {
+                    let _ = fs::remove_file(&entry.path).await;
+                }
             }
-        }
 
-        self.current_size.store(0, Ordering::Relaxed);
+            self.current_size.store(0, Ordering::Relaxed);
+        }
 
-        Ok(())
+        self.save_index().await
     }
 
     /// Ensure there's enough space for new data
@@ -509,7 +654,7 @@ impl AudioCache {
         }
 
         let current = self.current_size.load(Ordering::Relaxed);
-        let max = self.config.max_size_bytes;
+        let max = self.max_size();
 
         if current + bytes_needed + MIN_FREE_SPACE_BYTES <= max {
             return Ok(());
@@ -517,17 +662,27 @@ impl AudioCache {
 
         // Need to evict
         let bytes_to_free = (current + bytes_needed + MIN_FREE_SPACE_BYTES).saturating_sub(max);
-        self.evict_lru(bytes_to_free).await
+        let before = self.current_size();
+        self.evict_lru(bytes_to_free, self.config.min_age_for_eviction_secs).await;
+
+        if before - self.current_size() < bytes_to_free {
+            return Err(CacheError::CacheFull);
+        }
+
+        Ok(())
     }
 
     /// Evict entries using LRU policy until enough space is freed
-    async fn evict_lru(&self, bytes_needed: u64) -> CacheResult<()> {
+    ///
+    /// Entries idle for less than `min_idle_secs` are kept. Returns the number
+    /// of entries evicted.
+    async fn evict_lru(&self, bytes_needed: u64, min_idle_secs: i64) -> usize {
         let mut entries = self.entries.write().await;
 
         // Sort entries by last_accessed (oldest first)
         let mut sorted_entries: Vec<(String, DateTime<Utc>, u64)> = entries
             .iter()
-            .filter(|(_, e)| e.idle_seconds() >= self.config.min_age_for_eviction_secs)
+            .filter(|(_, e)| e.idle_seconds() >= min_idle_secs)
             .map(|(k, e)| (k.clone(), e.last_accessed, e.size))
             .collect();
 
@@ -546,6 +701,7 @@ impl AudioCache {
         }
 
         // Remove entries
+        let evicted = evicted_keys.len();
         for key in evicted_keys {
             if let Some(entry) = entries.remove(&key) {
                 if entry.path.exists() {
@@ -556,15 +712,40 @@ impl AudioCache {
             }
         }
 
-        if freed < bytes_needed {
-            return Err(CacheError::CacheFull);
-        }
+        evicted
+    }
 
+    /// Write entry metadata to the index file
+    async fn save_index(&self) -> CacheResult<()> {
+        let json = {
+            let entries = self.entries.read().await;
+            let list: Vec<&CacheEntry> = entries.values().collect();
+            serde_json::to_vec(&list).map_err(|e| CacheError::SerializationError(e.to_string()))?
+        };
+        fs::write(self.cache_dir.join(INDEX_FILE_NAME), json).await?;
         Ok(())
     }
 
+    /// Read entry metadata saved by `save_index`, keyed by file path
+    async fn load_index(&self) -> HashMap<PathBuf, CacheEntry> {
+        let Ok(data) = fs::read(self.cache_dir.join(INDEX_FILE_NAME)).await else {
+            return HashMap::new();
+        };
+        match serde_json::from_slice::<Vec<CacheEntry>>(&data) {
+            Ok(list) => list.into_iter().map(|e| (e.path.clone(), e)).collect(),
+            Err(e) => {
+                log::warn!("Ignoring unreadable voice cache index: {}", e);
+                HashMap::new()
+            }
+        }
+    }
+
     /// Rebuild the cache index from disk
+    ///
+    /// Metadata from the index file is kept for files that still exist; files
+    /// missing from the index are added with fresh metadata.
     async fn rebuild_index(&self) -> CacheResult<()> {
+        let mut indexed = self.load_index().await;
         let mut entries = self.entries.write().await;
         entries.clear();
 
@@ -574,17 +755,21 @@ impl AudioCache {
         while let Some(dir_entry) = read_dir.next_entry().await? {
             let path = dir_entry.path();
 
-            if !path.is_file() {
+            if !path.is_file() || path.file_name().is_some_and(|n| n == INDEX_FILE_NAME) {
                 continue;
             }
 
             if let Ok(metadata) = fs::metadata(&path).await {
                 let size = metadata.len();
-                let file_name = path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("")
-                    .to_string();
+
+                if let Some(mut entry) = indexed.remove(&path) {
+                    entry.size = size;
+                    total_size += size;
+                    entries.insert(entry.key.clone(), entry);
+                    continue;
+                }
+
+                let file_name = key_from_file_name(&path);
 
                 if file_name.is_empty() {
                     continue;
@@ -623,11 +808,13 @@ impl AudioCache {
             evictions: self.evictions.load(Ordering::Relaxed),
             entry_count: entries.len(),
             current_size_bytes: self.current_size.load(Ordering::Relaxed),
-            max_size_bytes: self.config.max_size_bytes,
+            max_size_bytes: self.max_size(),
             entries_by_format: HashMap::new(),
             hit_rate: 0.0,
             oldest_entry_age_secs: 0,
             avg_entry_size_bytes: 0,
+            bytes_by_provider: HashMap::new(),
+            bytes_by_voice: HashMap::new(),
         };
 
         stats.calculate_hit_rate();
@@ -641,6 +828,12 @@ impl AudioCache {
             if age > stats.oldest_entry_age_secs {
                 stats.oldest_entry_age_secs = age;
             }
+
+            let provider = entry.provider.as_deref().unwrap_or("unknown");
+            *stats.bytes_by_provider.entry(provider.to_string()).or_insert(0) += entry.size;
+            if let Some(voice_id) = &entry.voice_id {
+                *stats.bytes_by_voice.entry(format!("{}:{}", provider, voice_id)).or_insert(0) += entry.size;
+            }
         }
 
         // Calculate average size
@@ -697,6 +890,22 @@ impl AudioCache {
     }
 }
 
+/// Recover a cache key from a file name when the index has no entry for it
+///
+/// Content-addressed files are named after their key (`<hash>.<ext>`); older
+/// or custom-keyed files are `<key>.<ext>`.
+fn key_from_file_name(path: &Path) -> String {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let is_content_addressed =
+        stem.len() == KEY_HASH_LEN && stem.chars().all(|c| c.is_ascii_hexdigit());
+
+    if is_content_addressed {
+        path.file_name().and_then(|s| s.to_str()).unwrap_or("").to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -837,4 +1046,89 @@ mod tests {
 
         assert!(!cache.contains("to-remove").await);
     }
+
+    #[tokio::test]
+    async fn test_identical_requests_share_key_and_file() {
+        let (cache, _temp) = create_test_cache().await;
+        let settings = VoiceSettings::default();
+
+        let key = CacheKeyParams::new("Roll  for\ninitiative", VoiceProviderType::OpenAI, "alloy", &settings, OutputFormat::Mp3).to_key();
+        let same = CacheKeyParams::new("Roll for initiative", VoiceProviderType::OpenAI, "alloy", &settings, OutputFormat::Mp3).to_key();
+        assert_eq!(key, same);
+
+        let (path, cached) = cache
+            .get_or_synthesize_for(&key, OutputFormat::Mp3, &[], "openai", "alloy", || async { Ok(vec![1u8; 64]) })
+            .await
+            .unwrap();
+        assert!(!cached);
+        assert_eq!(path.file_name().unwrap().to_str().unwrap(), key);
+
+        let (again, cached) = cache
+            .get_or_synthesize_for(&same, OutputFormat::Mp3, &[], "openai", "alloy", || async { Ok(vec![2u8; 64]) })
+            .await
+            .unwrap();
+        assert!(cached);
+        assert_eq!(again, path);
+        assert_eq!(std::fs::read(&again).unwrap(), vec![1u8; 64]);
+    }
+
+    #[tokio::test]
+    async fn test_metadata_survives_reload() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let cache = AudioCache::with_defaults(temp_dir.path().to_path_buf()).await.unwrap();
+            cache
+                .get_or_synthesize_for("line-1", OutputFormat::Mp3, &["npc:gm".to_string()], "piper", "en_US-amy", || async {
+                    Ok(vec![0u8; 128])
+                })
+                .await
+                .unwrap();
+        }
+
+        let cache = AudioCache::with_defaults(temp_dir.path().to_path_buf()).await.unwrap();
+        assert_eq!(cache.len().await, 1);
+        let entry = &cache.list_entries().await[0];
+        assert_eq!(entry.key, "line-1");
+        assert_eq!(entry.provider.as_deref(), Some("piper"));
+        assert_eq!(entry.voice_id.as_deref(), Some("en_US-amy"));
+        assert!(entry.has_tag("npc:gm"));
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.bytes_by_provider.get("piper"), Some(&128));
+        assert_eq!(stats.bytes_by_voice.get("piper:en_US-amy"), Some(&128));
+    }
+
+    #[tokio::test]
+    async fn test_set_max_size_evicts_least_recently_used() {
+        let (cache, _temp) = create_test_cache().await;
+
+        for key in ["oldest", "newer", "newest"] {
+            cache.put(key, &[0u8; 100], OutputFormat::Mp3, &[]).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        // Touch the oldest entry so it becomes the most recently used
+        cache.get("oldest").await;
+
+        let evicted = cache.set_max_size(200).await.unwrap();
+        assert_eq!(evicted, 1);
+        assert_eq!(cache.max_size(), 200);
+        assert!(cache.contains("oldest").await);
+        assert!(!cache.contains("newer").await);
+        assert_eq!(cache.current_size(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_clear_matching_by_provider_and_voice() {
+        let (cache, _temp) = create_test_cache().await;
+        let synth = || async { Ok(vec![0u8; 10]) };
+
+        cache.get_or_synthesize_for("a", OutputFormat::Mp3, &[], "openai", "alloy", synth).await.unwrap();
+        cache.get_or_synthesize_for("b", OutputFormat::Mp3, &[], "openai", "nova", synth).await.unwrap();
+        cache.get_or_synthesize_for("c", OutputFormat::Mp3, &[], "piper", "amy", synth).await.unwrap();
+
+        assert_eq!(cache.clear_matching(Some("openai"), Some("nova")).await.unwrap(), 1);
+        assert_eq!(cache.clear_matching(Some("openai"), None).await.unwrap(), 1);
+        assert_eq!(cache.len().await, 1);
+        assert!(cache.contains("c").await);
+    }
 }
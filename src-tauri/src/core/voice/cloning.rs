@@ -0,0 +1,461 @@
+//! Voice Cloning
+//!
+//! Guided cloning flow for providers that can imitate a voice from reference
+//! audio:
+//!
+//! - **XTTS-v2**: the best reference sample is copied into the clone store and
+//!   its path is used as the voice ID (sent as `speaker_wav` at synthesis time).
+//! - **ElevenLabs**: samples are uploaded as an instant voice clone and the
+//!   returned voice ID is used.
+//!
+//! Samples are decoded and checked for length, sample rate, silence and
+//! clipping before anything is sent to a provider.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::profiles::{ProfileMetadata, VoiceProfile};
+use super::types::{Result, VoiceError, VoiceProviderType};
+
+/// Clone registry file inside the store root
+const CLONES_FILE_NAME: &str = "clones.json";
+
+/// RMS below this is treated as silence
+const SILENCE_RMS: f32 = 0.01;
+
+/// Samples at or above this magnitude count as clipped
+const CLIP_LEVEL: f32 = 0.999;
+
+/// Warn when more than this fraction of samples is clipped
+const MAX_CLIPPED_RATIO: f32 = 0.01;
+
+// ============================================================================
+// Sample Analysis
+// ============================================================================
+
+/// Measurements of a reference audio sample
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampleAnalysis {
+    pub path: PathBuf,
+    pub duration_secs: f32,
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Peak magnitude (0.0 - 1.0)
+    pub peak: f32,
+    /// Root-mean-square level (0.0 - 1.0)
+    pub rms: f32,
+    /// Fraction of samples at full scale
+    pub clipped_ratio: f32,
+}
+
+impl SampleAnalysis {
+    /// Measure interleaved samples in the -1.0..=1.0 range
+    pub fn from_samples(
+        path: PathBuf,
+        samples: impl Iterator<Item = f32>,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Self {
+        let mut count: u64 = 0;
+        let mut peak: f32 = 0.0;
+        let mut sum_squares: f64 = 0.0;
+        let mut clipped: u64 = 0;
+
+        for sample in samples {
+            let magnitude = sample.abs();
+            peak = peak.max(magnitude);
+            sum_squares += (sample as f64) * (sample as f64);
+            if magnitude >= CLIP_LEVEL {
+                clipped += 1;
+            }
+            count += 1;
+        }
+
+        let frames = count as f32 / channels.max(1) as f32;
+        Self {
+            path,
+            duration_secs: if sample_rate > 0 { frames / sample_rate as f32 } else { 0.0 },
+            sample_rate,
+            channels,
+            peak,
+            rms: if count > 0 { (sum_squares / count as f64).sqrt() as f32 } else { 0.0 },
+            clipped_ratio: if count > 0 { clipped as f32 / count as f32 } else { 0.0 },
+        }
+    }
+
+    /// Decode an audio file (WAV, MP3, OGG, FLAC) and measure it
+    pub fn from_file(path: &Path) -> Result<Self> {
+        use rodio::Source;
+
+        let file = File::open(path)?;
+        let decoder = rodio::Decoder::new(BufReader::new(file))
+            .map_err(|e| VoiceError::ApiError(format!("Could not decode {}: {}", path.display(), e)))?;
+        let sample_rate = decoder.sample_rate();
+        let channels = decoder.channels();
+
+        Ok(Self::from_samples(
+            path.to_path_buf(),
+            decoder.map(|s| s as f32 / 32768.0),
+            sample_rate,
+            channels,
+        ))
+    }
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// What a provider needs from reference audio
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneRequirements {
+    /// Shortest usable single sample
+    pub min_sample_secs: f32,
+    /// Minimum total reference audio
+    pub min_total_secs: f32,
+    /// Amount of audio that gives the best results
+    pub recommended_total_secs: f32,
+    pub max_samples: usize,
+    pub min_sample_rate: u32,
+}
+
+impl CloneRequirements {
+    /// Requirements for a provider, or `None` if it does not support cloning
+    pub fn for_provider(provider: &VoiceProviderType) -> Option<Self> {
+        match provider {
+            VoiceProviderType::XttsV2 => Some(Self {
+                min_sample_secs: 3.0,
+                min_total_secs: 3.0,
+                recommended_total_secs: 6.0,
+                max_samples: 5,
+                min_sample_rate: 16_000,
+            }),
+            VoiceProviderType::ElevenLabs => Some(Self {
+                min_sample_secs: 5.0,
+                min_total_secs: 30.0,
+                recommended_total_secs: 60.0,
+                max_samples: 25,
+                min_sample_rate: 16_000,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Result of checking reference samples
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneValidation {
+    /// Whether the samples can be used
+    pub valid: bool,
+    /// Problems that block cloning
+    pub errors: Vec<String>,
+    /// Problems that may reduce quality
+    pub warnings: Vec<String>,
+    pub total_duration_secs: f32,
+    pub samples: Vec<SampleAnalysis>,
+}
+
+/// Check analyzed samples against a provider's requirements
+pub fn validate_samples(requirements: &CloneRequirements, samples: Vec<SampleAnalysis>) -> CloneValidation {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    if samples.is_empty() {
+        errors.push("At least one reference sample is required".to_string());
+    }
+    if samples.len() > requirements.max_samples {
+        errors.push(format!("At most {} samples can be used", requirements.max_samples));
+    }
+
+    let mut total = 0.0;
+    for sample in &samples {
+        let name = sample
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| sample.path.display().to_string());
+
+        if sample.rms < SILENCE_RMS {
+            errors.push(format!("{} is silent or nearly silent", name));
+            continue;
+        }
+        if sample.duration_secs < requirements.min_sample_secs {
+            errors.push(format!(
+                "{} is {:.1}s; samples must be at least {:.0}s",
+                name, sample.duration_secs, requirements.min_sample_secs
+            ));
+            continue;
+        }
+        if sample.sample_rate < requirements.min_sample_rate {
+            warnings.push(format!(
+                "{} is {} Hz; {} Hz or higher sounds better",
+                name, sample.sample_rate, requirements.min_sample_rate
+            ));
+        }
+        if sample.clipped_ratio > MAX_CLIPPED_RATIO {
+            warnings.push(format!("{} is clipping; record at a lower gain", name));
+        }
+        total += sample.duration_secs;
+    }
+
+    if samples.is_empty() {
+        // Already reported above
+    } else if total < requirements.min_total_secs {
+        errors.push(format!(
+            "{:.1}s of usable audio; at least {:.0}s is required",
+            total, requirements.min_total_secs
+        ));
+    } else if total < requirements.recommended_total_secs {
+        warnings.push(format!(
+            "{:.0}s or more of audio gives the best results",
+            requirements.recommended_total_secs
+        ));
+    }
+
+    CloneValidation {
+        valid: errors.is_empty(),
+        errors,
+        warnings,
+        total_duration_secs: total,
+        samples,
+    }
+}
+
+/// Pick the sample to use when a provider takes a single reference
+///
+/// Prefers the longest sample that is not clipping.
+pub fn best_reference(samples: &[SampleAnalysis]) -> Option<&SampleAnalysis> {
+    samples
+        .iter()
+        .filter(|s| s.rms >= SILENCE_RMS)
+        .max_by(|a, b| {
+            let a_clean = a.clipped_ratio <= MAX_CLIPPED_RATIO;
+            let b_clean = b.clipped_ratio <= MAX_CLIPPED_RATIO;
+            a_clean
+                .cmp(&b_clean)
+                .then(a.duration_secs.total_cmp(&b.duration_secs))
+        })
+}
+
+// ============================================================================
+// Clone Store
+// ============================================================================
+
+/// A cloned voice created through the cloning flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClonedVoice {
+    pub id: String,
+    pub name: String,
+    pub provider: VoiceProviderType,
+    /// Voice ID to synthesize with (a reference file path for XTTS)
+    pub provider_voice_id: String,
+    /// Voice profile registered for this clone
+    pub profile_id: Option<String>,
+    pub description: Option<String>,
+    /// Metadata for the registered voice profile
+    #[serde(default)]
+    pub metadata: ProfileMetadata,
+    /// Reference audio kept locally
+    pub reference_files: Vec<PathBuf>,
+    pub total_duration_secs: f32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ClonedVoice {
+    /// Voice profile for this clone, reusing its registered profile ID
+    pub fn to_profile(&self) -> VoiceProfile {
+        let mut metadata = self.metadata.clone();
+        if metadata.description.is_none() {
+            metadata.description = self.description.clone();
+        }
+        if !metadata.tags.iter().any(|t| t == "cloned") {
+            metadata.tags.push("cloned".to_string());
+        }
+
+        let mut profile = VoiceProfile::new(&self.name, self.provider.clone(), &self.provider_voice_id)
+            .with_metadata(metadata);
+        if let Some(id) = &self.profile_id {
+            profile.id = id.clone();
+        }
+        profile.created_at = self.created_at;
+        profile
+    }
+}
+
+/// On-disk registry of cloned voices and their reference audio
+#[derive(Debug, Clone)]
+pub struct ClonedVoiceStore {
+    root: PathBuf,
+}
+
+impl ClonedVoiceStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Directory holding one clone's reference audio
+    pub fn clone_dir(&self, clone_id: &str) -> PathBuf {
+        self.root.join(clone_id)
+    }
+
+    pub fn list(&self) -> Result<Vec<ClonedVoice>> {
+        let path = self.root.join(CLONES_FILE_NAME);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = std::fs::read(&path)?;
+        serde_json::from_slice(&data).map_err(|e| VoiceError::IoError(std::io::Error::other(e)))
+    }
+
+    fn save_all(&self, clones: &[ClonedVoice]) -> Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        let json = serde_json::to_vec_pretty(clones)
+            .map_err(|e| VoiceError::IoError(std::io::Error::other(e)))?;
+        std::fs::write(self.root.join(CLONES_FILE_NAME), json)?;
+        Ok(())
+    }
+
+    pub fn get(&self, clone_id: &str) -> Result<Option<ClonedVoice>> {
+        Ok(self.list()?.into_iter().find(|c| c.id == clone_id))
+    }
+
+    /// Copy reference samples into the clone's directory
+    pub fn import_samples(&self, clone_id: &str, samples: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        let dir = self.clone_dir(clone_id);
+        std::fs::create_dir_all(&dir)?;
+
+        samples
+            .iter()
+            .enumerate()
+            .map(|(i, source)| {
+                let extension = source.extension().and_then(|e| e.to_str()).unwrap_or("wav");
+                let dest = dir.join(format!("sample_{}.{}", i + 1, extension));
+                std::fs::copy(source, &dest)?;
+                Ok(dest)
+            })
+            .collect()
+    }
+
+    /// Add or replace a clone record
+    pub fn upsert(&self, clone: ClonedVoice) -> Result<()> {
+        let mut clones = self.list()?;
+        clones.retain(|c| c.id != clone.id);
+        clones.push(clone);
+        self.save_all(&clones)
+    }
+
+    /// Remove a clone record and its reference audio
+    pub fn remove(&self, clone_id: &str) -> Result<Option<ClonedVoice>> {
+        let mut clones = self.list()?;
+        let Some(index) = clones.iter().position(|c| c.id == clone_id) else {
+            return Ok(None);
+        };
+        let removed = clones.remove(index);
+        self.save_all(&clones)?;
+
+        let dir = self.clone_dir(clone_id);
+        if dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        Ok(Some(removed))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tone(path: &str, secs: f32, amplitude: f32) -> SampleAnalysis {
+        let rate = 22_050;
+        let samples = (0..(secs * rate as f32) as usize)
+            .map(move |i| amplitude * (i as f32 * 0.05).sin());
+        SampleAnalysis::from_samples(PathBuf::from(path), samples, rate, 1)
+    }
+
+    #[test]
+    fn test_analysis_measures_duration_and_level() {
+        let analysis = tone("a.wav", 2.0, 0.5);
+        assert!((analysis.duration_secs - 2.0).abs() < 0.01);
+        assert!(analysis.peak <= 0.5 && analysis.peak > 0.49);
+        assert!(analysis.rms > 0.3 && analysis.rms < 0.4);
+        assert_eq!(analysis.clipped_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_validation_rejects_short_and_silent_samples() {
+        let xtts = CloneRequirements::for_provider(&VoiceProviderType::XttsV2).unwrap();
+
+        let result = validate_samples(&xtts, vec![tone("short.wav", 1.0, 0.5), tone("quiet.wav", 8.0, 0.001)]);
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 3);
+
+        let result = validate_samples(&xtts, vec![tone("good.wav", 7.0, 0.5)]);
+        assert!(result.valid, "{:?}", result.errors);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validation_warns_on_clipping_and_short_total() {
+        let elevenlabs = CloneRequirements::for_provider(&VoiceProviderType::ElevenLabs).unwrap();
+        let samples: Vec<_> = (0..4).map(|i| tone(&format!("{}.wav", i), 10.0, 1.5)).collect();
+
+        let result = validate_samples(&elevenlabs, samples);
+        assert!(result.valid);
+        assert_eq!(result.warnings.len(), 5);
+        assert!(CloneRequirements::for_provider(&VoiceProviderType::OpenAI).is_none());
+    }
+
+    #[test]
+    fn test_best_reference_prefers_clean_then_longest() {
+        let samples = vec![tone("clipped.wav", 12.0, 1.5), tone("clean.wav", 6.0, 0.5), tone("clean-long.wav", 9.0, 0.5)];
+        assert_eq!(best_reference(&samples).unwrap().path, PathBuf::from("clean-long.wav"));
+    }
+
+    #[test]
+    fn test_store_import_upsert_and_remove() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("me.wav");
+        std::fs::write(&source, b"RIFF").unwrap();
+        let store = ClonedVoiceStore::new(temp.path().join("clones"));
+
+        let files = store.import_samples("c1", &[source]).unwrap();
+        assert!(files[0].exists());
+
+        store
+            .upsert(ClonedVoice {
+                id: "c1".to_string(),
+                name: "Narrator".to_string(),
+                provider: VoiceProviderType::XttsV2,
+                provider_voice_id: files[0].display().to_string(),
+                profile_id: Some("p1".to_string()),
+                description: Some("Deep and slow".to_string()),
+                metadata: ProfileMetadata::default(),
+                reference_files: files.clone(),
+                total_duration_secs: 6.0,
+                created_at: Utc::now(),
+            })
+            .unwrap();
+        let clones = store.list().unwrap();
+        assert_eq!(clones.len(), 1);
+        let profile = clones[0].to_profile();
+        assert_eq!(profile.id, "p1");
+        assert_eq!(profile.voice_id, files[0].display().to_string());
+        assert_eq!(profile.metadata.description.as_deref(), Some("Deep and slow"));
+        assert!(profile.metadata.tags.contains(&"cloned".to_string()));
+
+        let removed = store.remove("c1").unwrap().unwrap();
+        assert_eq!(removed.name, "Narrator");
+        assert!(!files[0].exists());
+        assert!(store.list().unwrap().is_empty());
+        assert!(store.remove("c1").unwrap().is_none());
+    }
+}
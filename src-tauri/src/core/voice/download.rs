@@ -1,12 +1,24 @@
 use std::path::{Path, PathBuf};
-use reqwest::Client;
+use std::time::Duration;
+use md5::{Digest, Md5};
+use reqwest::{header, Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tokio::io::AsyncWriteExt;
-use tracing::{debug, info};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{debug, info, warn};
 
 const PIPER_HF_BASE: &str = "https://huggingface.co/rhasspy/piper-voices/resolve/main";
 const PIPER_VOICES_JSON: &str = "https://huggingface.co/rhasspy/piper-voices/raw/main/voices.json";
+const PIPER_SAMPLES_BASE: &str = "https://rhasspy.github.io/piper-samples/samples";
+
+/// Cached copy of voices.json inside the models directory
+const CATALOG_FILE_NAME: &str = "voices_catalog.json";
+
+/// How long the cached catalog is used before refetching
+const CATALOG_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Suffix for partially downloaded files (kept so downloads can resume)
+const PARTIAL_SUFFIX: &str = ".part";
 
 #[derive(Error, Debug)]
 pub enum DownloadError {
@@ -24,6 +36,13 @@ pub enum DownloadError {
 
     #[error("Download canceled")]
     Canceled,
+
+    #[error("Checksum mismatch for {file}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 pub type DownloadResult<T> = std::result::Result<T, DownloadError>;
@@ -38,6 +57,12 @@ pub struct AvailablePiperVoice {
     pub num_speakers: u32,
     pub sample_rate: u32,
     pub files: PiperVoiceFiles,
+    /// Speaker names of multi-speaker voices, ordered by speaker ID
+    #[serde(default)]
+    pub speakers: Vec<String>,
+    /// Whether the model and config are already in the models directory
+    #[serde(default)]
+    pub downloaded: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +85,48 @@ pub struct PiperVoiceFiles {
 pub struct PiperFileInfo {
     pub size_bytes: u64,
     pub md5_digest: String,
+    /// Path within the Hugging Face repository
+    #[serde(default)]
+    pub path: String,
+}
+
+/// Filter for browsing the Piper voice catalog
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PiperVoiceFilter {
+    /// Language code, family, or English name (e.g. "en_GB", "en", "German")
+    pub language: Option<String>,
+    /// "x_low", "low", "medium", or "high"
+    pub quality: Option<String>,
+    pub min_speakers: Option<u32>,
+    pub max_speakers: Option<u32>,
+    /// Substring of the voice key or name
+    pub query: Option<String>,
+    /// Only voices that are (or aren't) downloaded
+    pub downloaded: Option<bool>,
+}
+
+impl PiperVoiceFilter {
+    pub fn matches(&self, voice: &AvailablePiperVoice) -> bool {
+        let language_ok = self.language.as_deref().is_none_or(|lang| {
+            let lang = lang.to_lowercase();
+            [&voice.language.code, &voice.language.family, &voice.language.name_english]
+                .iter()
+                .any(|field| field.to_lowercase() == lang)
+        });
+        let quality_ok = self
+            .quality
+            .as_deref()
+            .is_none_or(|q| voice.quality.eq_ignore_ascii_case(q));
+        let speakers_ok = self.min_speakers.is_none_or(|min| voice.num_speakers >= min)
+            && self.max_speakers.is_none_or(|max| voice.num_speakers <= max);
+        let query_ok = self.query.as_deref().is_none_or(|q| {
+            let q = q.to_lowercase();
+            voice.key.to_lowercase().contains(&q) || voice.name.to_lowercase().contains(&q)
+        });
+        let downloaded_ok = self.downloaded.is_none_or(|d| voice.downloaded == d);
+
+        language_ok && quality_ok && speakers_ok && query_ok && downloaded_ok
+    }
 }
 
 /// Download progress callback
@@ -139,6 +206,56 @@ impl VoiceDownloader {
 
     /// List all available Piper voices from Hugging Face
     pub async fn list_available_voices(&self) -> DownloadResult<Vec<AvailablePiperVoice>> {
+        let json = self.fetch_catalog_json().await?;
+        let voices = self.parse_catalog(&json);
+        info!(count = voices.len(), "Found available Piper voices");
+        Ok(voices)
+    }
+
+    /// The full voice catalog, cached on disk for a day
+    ///
+    /// `refresh` forces a refetch. If Hugging Face can't be reached, a stale
+    /// cached copy is used when there is one.
+    pub async fn catalog(&self, refresh: bool) -> DownloadResult<Vec<AvailablePiperVoice>> {
+        let cache_path = self.catalog_cache_path();
+        let cache_age = tokio::fs::metadata(&cache_path)
+            .await
+            .ok()
+            .and_then(|m| m.modified().ok())
+            .and_then(|modified| modified.elapsed().ok());
+
+        if !refresh && cache_age.is_some_and(|age| age < CATALOG_MAX_AGE) {
+            if let Some(json) = self.read_cached_catalog().await {
+                return Ok(self.parse_catalog(&json));
+            }
+        }
+
+        match self.fetch_catalog_json().await {
+            Ok(json) => {
+                tokio::fs::create_dir_all(&self.models_dir).await?;
+                tokio::fs::write(&cache_path, json.to_string()).await?;
+                Ok(self.parse_catalog(&json))
+            }
+            Err(e) => match self.read_cached_catalog().await {
+                Some(json) => {
+                    warn!(error = %e, "Using cached Piper voice catalog");
+                    Ok(self.parse_catalog(&json))
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    fn catalog_cache_path(&self) -> PathBuf {
+        self.models_dir.join(CATALOG_FILE_NAME)
+    }
+
+    async fn read_cached_catalog(&self) -> Option<serde_json::Value> {
+        let data = tokio::fs::read(self.catalog_cache_path()).await.ok()?;
+        serde_json::from_slice(&data).ok()
+    }
+
+    async fn fetch_catalog_json(&self) -> DownloadResult<serde_json::Value> {
         info!("Fetching available Piper voices from Hugging Face");
 
         let response = self.client.get(PIPER_VOICES_JSON).send().await?;
@@ -149,21 +266,25 @@ impl VoiceDownloader {
             ));
         }
 
-        let json: serde_json::Value = response.json().await?;
-
-        let mut voices = Vec::new();
+        Ok(response.json().await?)
+    }
 
-        // Parse the voices.json structure
-        if let Some(obj) = json.as_object() {
-            for (key, value) in obj {
-                if let Ok(voice) = self.parse_voice_entry(key, value) {
-                    voices.push(voice);
-                }
-            }
+    /// Parse voices.json, sorted by key and marked with download state
+    fn parse_catalog(&self, json: &serde_json::Value) -> Vec<AvailablePiperVoice> {
+        let mut voices: Vec<AvailablePiperVoice> = json
+            .as_object()
+            .map(|obj| {
+                obj.iter()
+                    .filter_map(|(key, value)| self.parse_voice_entry(key, value).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for voice in &mut voices {
+            voice.downloaded = self.is_voice_downloaded(&voice.key);
         }
-
-        info!(count = voices.len(), "Found available Piper voices");
-        Ok(voices)
+        voices.sort_by(|a, b| a.key.cmp(&b.key));
+        voices
     }
 
     fn parse_voice_entry(&self, key: &str, value: &serde_json::Value) -> DownloadResult<AvailablePiperVoice> {
@@ -174,15 +295,37 @@ impl VoiceDownloader {
             .and_then(|f| f.as_object())
             .ok_or_else(|| DownloadError::Parse("Missing files".to_string()))?;
 
-        // Get the first available quality variant
-        let (quality, file_info) = files.iter().next()
-            .ok_or_else(|| DownloadError::Parse("No quality variants".to_string()))?;
-
-        let model_file = file_info.get("model")
+        // Files are keyed by their path in the repository
+        let file_info = |suffix: &str| {
+            files.iter()
+                .find(|(path, _)| path.ends_with(suffix))
+                .map(|(path, info)| PiperFileInfo {
+                    size_bytes: info.get("size_bytes").and_then(|s| s.as_u64()).unwrap_or(0),
+                    md5_digest: info.get("md5_digest").and_then(|m| m.as_str()).unwrap_or("").to_string(),
+                    path: path.clone(),
+                })
+        };
+        let model = file_info(".onnx")
             .ok_or_else(|| DownloadError::Parse("Missing model file info".to_string()))?;
-        let config_file = file_info.get("config")
+        let config = file_info(".onnx.json")
             .ok_or_else(|| DownloadError::Parse("Missing config file info".to_string()))?;
 
+        let quality = value.get("quality")
+            .and_then(|q| q.as_str())
+            .map(String::from)
+            .or_else(|| ParsedVoiceKey::parse(key, None).ok().map(|p| p.quality))
+            .unwrap_or_else(|| "medium".to_string());
+
+        let mut speakers: Vec<(String, u64)> = value.get("speaker_id_map")
+            .and_then(|m| m.as_object())
+            .map(|map| {
+                map.iter()
+                    .map(|(name, id)| (name.clone(), id.as_u64().unwrap_or(0)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        speakers.sort_by_key(|(_, id)| *id);
+
         Ok(AvailablePiperVoice {
             key: key.to_string(),
             name: value.get("name")
@@ -197,23 +340,22 @@ impl VoiceDownloader {
                 name_english: language.get("name_english").and_then(|n| n.as_str()).unwrap_or("").to_string(),
                 country_english: language.get("country_english").and_then(|c| c.as_str()).unwrap_or("").to_string(),
             },
-            quality: quality.clone(),
+            quality,
             num_speakers: value.get("num_speakers").and_then(|n| n.as_u64()).unwrap_or(1) as u32,
-            sample_rate: file_info.get("sample_rate").and_then(|s| s.as_u64()).unwrap_or(22050) as u32,
-            files: PiperVoiceFiles {
-                model: PiperFileInfo {
-                    size_bytes: model_file.get("size_bytes").and_then(|s| s.as_u64()).unwrap_or(0),
-                    md5_digest: model_file.get("md5_digest").and_then(|m| m.as_str()).unwrap_or("").to_string(),
-                },
-                config: PiperFileInfo {
-                    size_bytes: config_file.get("size_bytes").and_then(|s| s.as_u64()).unwrap_or(0),
-                    md5_digest: config_file.get("md5_digest").and_then(|m| m.as_str()).unwrap_or("").to_string(),
-                },
-            },
+            sample_rate: value.get("audio")
+                .and_then(|a| a.get("sample_rate"))
+                .and_then(|s| s.as_u64())
+                .unwrap_or(22050) as u32,
+            files: PiperVoiceFiles { model, config },
+            speakers: speakers.into_iter().map(|(name, _)| name).collect(),
+            downloaded: false,
         })
     }
 
     /// Download a Piper voice by key (e.g., "en_US-lessac-medium")
+    ///
+    /// Interrupted downloads resume where they left off. When the catalog is
+    /// available, files are verified against its MD5 digests.
     pub async fn download_voice(
         &self,
         voice_key: &str,
@@ -227,71 +369,151 @@ impl VoiceDownloader {
 
         // Parse voice key using helper struct
         let parsed = ParsedVoiceKey::parse(voice_key, quality)?;
+        let full_key = parsed.model_filename().trim_end_matches(".onnx").to_string();
+
+        // Look up checksums and exact paths; downloads still work without them
+        let entry = match self.catalog(false).await {
+            Ok(catalog) => Some(
+                catalog.into_iter()
+                    .find(|v| v.key == full_key)
+                    .ok_or_else(|| DownloadError::VoiceNotFound(full_key.clone()))?,
+            ),
+            Err(e) => {
+                warn!(error = %e, "Piper voice catalog unavailable; skipping checksum verification");
+                None
+            }
+        };
 
-        // Construct URLs using parsed components
-        let model_url = format!(
-            "{}/{}/{}",
-            PIPER_HF_BASE,
-            parsed.hf_base_path(),
-            parsed.model_filename()
-        );
-        let config_url = format!(
-            "{}/{}/{}",
-            PIPER_HF_BASE,
-            parsed.hf_base_path(),
-            parsed.config_filename()
-        );
+        let url_for = |info: Option<&PiperFileInfo>, filename: String| match info {
+            Some(info) if !info.path.is_empty() => format!("{}/{}", PIPER_HF_BASE, info.path),
+            _ => format!("{}/{}/{}", PIPER_HF_BASE, parsed.hf_base_path(), filename),
+        };
+        let model_info = entry.as_ref().map(|e| &e.files.model);
+        let config_info = entry.as_ref().map(|e| &e.files.config);
+        let model_url = url_for(model_info, parsed.model_filename());
+        let config_url = url_for(config_info, parsed.config_filename());
 
         let model_path = self.models_dir.join(parsed.model_filename());
         let config_path = self.models_dir.join(parsed.config_filename());
 
         // Download model file
         debug!(url = %model_url, "Downloading model file");
-        self.download_file(&model_url, &model_path, progress.as_ref()).await?;
+        self.download_file(&model_url, &model_path, model_info, progress.as_ref()).await?;
 
         // Download config file
         debug!(url = %config_url, "Downloading config file");
-        self.download_file(&config_url, &config_path, None).await?;
+        self.download_file(&config_url, &config_path, config_info, None).await?;
 
         info!(path = ?model_path, "Voice download complete");
         Ok(model_path)
     }
 
+    /// Download `url` to `dest` via a `.part` file, resuming a previous attempt
     async fn download_file(
         &self,
         url: &str,
         dest: &Path,
+        expected: Option<&PiperFileInfo>,
         progress: Option<&ProgressCallback>,
     ) -> DownloadResult<()> {
-        let response = self.client.get(url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(DownloadError::Network(
-                response.error_for_status().unwrap_err()
-            ));
-        }
+        let part_path = partial_path(dest);
+        let mut offset = tokio::fs::metadata(&part_path).await.map(|m| m.len()).unwrap_or(0);
+        let expected_size = expected.map(|e| e.size_bytes).filter(|size| *size > 0);
+
+        // A complete .part file only needs verifying
+        if expected_size.is_none_or(|size| offset < size) {
+            let mut request = self.client.get(url);
+            if offset > 0 {
+                debug!(offset, "Resuming download");
+                request = request.header(header::RANGE, format!("bytes={}-", offset));
+            }
+            let response = request.send().await?;
 
-        let total_size = response.content_length().unwrap_or(0);
-        let mut downloaded: u64 = 0;
+            if response.status() != StatusCode::RANGE_NOT_SATISFIABLE {
+                if !response.status().is_success() {
+                    return Err(DownloadError::Network(
+                        response.error_for_status().unwrap_err()
+                    ));
+                }
 
-        let mut file = tokio::fs::File::create(dest).await?;
-        let mut stream = response.bytes_stream();
+                // Servers that ignore the range send the whole file again
+                if response.status() != StatusCode::PARTIAL_CONTENT {
+                    offset = 0;
+                }
+                let total_size = offset + response.content_length().unwrap_or(0);
+                let mut downloaded = offset;
+
+                let mut file = tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .append(offset > 0)
+                    .truncate(offset == 0)
+                    .open(&part_path)
+                    .await?;
+                let mut stream = response.bytes_stream();
+
+                use futures_util::StreamExt;
+                while let Some(chunk) = stream.next().await {
+                    let chunk = chunk?;
+                    file.write_all(&chunk).await?;
+                    downloaded += chunk.len() as u64;
+
+                    if let Some(ref cb) = progress {
+                        cb(downloaded, total_size);
+                    }
+                }
 
-        use futures_util::StreamExt;
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            downloaded += chunk.len() as u64;
+                file.flush().await?;
+            }
+        }
 
-            if let Some(ref cb) = progress {
-                cb(downloaded, total_size);
+        if let Some(expected) = expected.filter(|e| !e.md5_digest.is_empty()) {
+            let actual = file_md5(&part_path).await?;
+            if !actual.eq_ignore_ascii_case(&expected.md5_digest) {
+                // Start over next time rather than resuming a corrupt file
+                let _ = tokio::fs::remove_file(&part_path).await;
+                return Err(DownloadError::ChecksumMismatch {
+                    file: dest.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default(),
+                    expected: expected.md5_digest.clone(),
+                    actual,
+                });
             }
         }
 
-        file.flush().await?;
+        tokio::fs::rename(&part_path, dest).await?;
         Ok(())
     }
 
+    /// URL of a voice's published audio sample
+    pub fn sample_url(voice_key: &str, speaker_id: u32) -> DownloadResult<String> {
+        let parsed = ParsedVoiceKey::parse(voice_key, None)?;
+        Ok(format!("{}/{}/speaker_{}.mp3", PIPER_SAMPLES_BASE, parsed.hf_base_path(), speaker_id))
+    }
+
+    /// Fetch (and cache) a voice's audio sample so it can be previewed before download
+    pub async fn fetch_sample(&self, voice_key: &str, speaker_id: u32) -> DownloadResult<PathBuf> {
+        let url = Self::sample_url(voice_key, speaker_id)?;
+        let dir = self.models_dir.join("samples");
+        let path = dir.join(format!("{}-speaker_{}.mp3", voice_key, speaker_id));
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let response = self.client.get(&url).send().await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(DownloadError::VoiceNotFound(format!("No sample for {}", voice_key)));
+        }
+        if !response.status().is_success() {
+            return Err(DownloadError::Network(
+                response.error_for_status().unwrap_err()
+            ));
+        }
+
+        tokio::fs::create_dir_all(&dir).await?;
+        tokio::fs::write(&path, response.bytes().await?).await?;
+        Ok(path)
+    }
+
     /// Check if a voice is already downloaded
     pub fn is_voice_downloaded(&self, voice_key: &str) -> bool {
         match ParsedVoiceKey::parse(voice_key, None) {
@@ -328,6 +550,28 @@ impl VoiceDownloader {
     }
 }
 
+/// Where a file is downloaded before it is verified and moved into place
+fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.as_os_str().to_owned();
+    name.push(PARTIAL_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Hex MD5 digest of a file
+async fn file_md5(path: &Path) -> DownloadResult<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Md5::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
 /// Popular pre-defined Piper voices for quick access
 pub fn popular_piper_voices() -> Vec<(&'static str, &'static str, &'static str)> {
     vec![
@@ -537,6 +781,130 @@ mod tests {
         }
     }
 
+    // =========================================================================
+    // Unit Tests: Voice catalog
+    // =========================================================================
+
+    mod catalog {
+        use super::*;
+
+        fn sample_catalog() -> serde_json::Value {
+            serde_json::json!({
+                "en_US-libritts-high": {
+                    "key": "en_US-libritts-high",
+                    "name": "libritts",
+                    "language": {
+                        "code": "en_US", "family": "en", "region": "US",
+                        "name_native": "English", "name_english": "English",
+                        "country_english": "United States"
+                    },
+                    "quality": "high",
+                    "num_speakers": 3,
+                    "speaker_id_map": { "p2": 2, "p0": 0, "p1": 1 },
+                    "files": {
+                        "en/en_US/libritts/high/MODEL_CARD": { "size_bytes": 10, "md5_digest": "aa" },
+                        "en/en_US/libritts/high/en_US-libritts-high.onnx.json": { "size_bytes": 20, "md5_digest": "bb" },
+                        "en/en_US/libritts/high/en_US-libritts-high.onnx": { "size_bytes": 30, "md5_digest": "cc" }
+                    }
+                },
+                "de_DE-thorsten-medium": {
+                    "name": "thorsten",
+                    "language": {
+                        "code": "de_DE", "family": "de", "region": "DE",
+                        "name_native": "Deutsch", "name_english": "German",
+                        "country_english": "Germany"
+                    },
+                    "quality": "medium",
+                    "num_speakers": 1,
+                    "files": {
+                        "de/de_DE/thorsten/medium/de_DE-thorsten-medium.onnx": { "size_bytes": 40, "md5_digest": "dd" },
+                        "de/de_DE/thorsten/medium/de_DE-thorsten-medium.onnx.json": { "size_bytes": 50, "md5_digest": "ee" }
+                    }
+                },
+                "broken": { "name": "no files" }
+            })
+        }
+
+        #[test]
+        fn parse_catalog_reads_files_by_path_and_speakers() {
+            let temp_dir = TempDir::new().unwrap();
+            let downloader = VoiceDownloader::new(temp_dir.path().to_path_buf());
+            std::fs::write(temp_dir.path().join("de_DE-thorsten-medium.onnx"), b"m").unwrap();
+            std::fs::write(temp_dir.path().join("de_DE-thorsten-medium.onnx.json"), b"{}").unwrap();
+
+            let voices = downloader.parse_catalog(&sample_catalog());
+            assert_eq!(voices.len(), 2);
+
+            let thorsten = &voices[0];
+            assert_eq!(thorsten.key, "de_DE-thorsten-medium");
+            assert!(thorsten.downloaded);
+
+            let libritts = &voices[1];
+            assert_eq!(libritts.quality, "high");
+            assert_eq!(libritts.files.model.path, "en/en_US/libritts/high/en_US-libritts-high.onnx");
+            assert_eq!(libritts.files.model.md5_digest, "cc");
+            assert_eq!(libritts.files.config.md5_digest, "bb");
+            assert_eq!(libritts.speakers, vec!["p0", "p1", "p2"]);
+            assert!(!libritts.downloaded);
+        }
+
+        #[test]
+        fn filter_matches_language_quality_and_speakers() {
+            let downloader = VoiceDownloader::new(PathBuf::from("/nonexistent"));
+            let voices = downloader.parse_catalog(&sample_catalog());
+            let keys = |filter: PiperVoiceFilter| -> Vec<String> {
+                voices.iter().filter(|v| filter.matches(v)).map(|v| v.key.clone()).collect()
+            };
+
+            let german = PiperVoiceFilter { language: Some("german".to_string()), ..Default::default() };
+            assert_eq!(keys(german), vec!["de_DE-thorsten-medium"]);
+
+            let multi_speaker = PiperVoiceFilter { min_speakers: Some(2), ..Default::default() };
+            assert_eq!(keys(multi_speaker), vec!["en_US-libritts-high"]);
+
+            let high_english = PiperVoiceFilter {
+                language: Some("en".to_string()),
+                quality: Some("HIGH".to_string()),
+                query: Some("libri".to_string()),
+                ..Default::default()
+            };
+            assert_eq!(keys(high_english), vec!["en_US-libritts-high"]);
+            assert!(keys(PiperVoiceFilter { quality: Some("x_low".to_string()), ..Default::default() }).is_empty());
+        }
+
+        #[tokio::test]
+        async fn catalog_uses_fresh_cache_without_network() {
+            let temp_dir = TempDir::new().unwrap();
+            let downloader = VoiceDownloader::new(temp_dir.path().to_path_buf());
+            std::fs::write(
+                temp_dir.path().join(CATALOG_FILE_NAME),
+                sample_catalog().to_string(),
+            ).unwrap();
+
+            let voices = downloader.catalog(false).await.unwrap();
+            assert_eq!(voices.len(), 2);
+        }
+
+        #[tokio::test]
+        async fn file_md5_and_partial_path() {
+            let temp_dir = TempDir::new().unwrap();
+            let path = temp_dir.path().join("voice.onnx");
+            std::fs::write(&path, b"hello").unwrap();
+
+            assert_eq!(file_md5(&path).await.unwrap(), "5d41402abc4b2a76b9719d911017c592");
+            assert_eq!(partial_path(&path), temp_dir.path().join("voice.onnx.part"));
+        }
+
+        #[test]
+        fn sample_url_uses_voice_path() {
+            assert_eq!(
+                VoiceDownloader::sample_url("en_GB-alba-medium", 0).unwrap(),
+                "https://rhasspy.github.io/piper-samples/samples/en/en_GB/alba/medium/speaker_0.mp3"
+            );
+            assert!(VoiceDownloader::sample_url("invalid", 0).is_err());
+        }
+    }
+
     // =========================================================================
     // Integration Tests: VoiceDownloader
     // =========================================================================
@@ -0,0 +1,384 @@
+//! Provider Fallback Chain
+//!
+//! When the provider a voice belongs to fails in a way another provider can
+//! cover (quota exhausted, rate limited, service unreachable), synthesis moves
+//! down a configured chain of providers instead of failing. The voice is
+//! mapped onto the fallback provider by an explicit mapping, the nearest
+//! matching voice profile, or the provider's default voice.
+//!
+//! A provider that failed is skipped for a cool-down period, so a session
+//! doesn't pay the failed round trip on every line. Degradation and recovery
+//! are reported as events so the UI can show which provider is speaking.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::npc_voice::{score_profile, NpcVoiceHints};
+use super::profiles::VoiceProfile;
+use super::types::{VoiceError, VoiceProviderType};
+
+/// Event emitted when a line is spoken by a fallback provider
+pub const VOICE_FALLBACK_EVENT: &str = "voice:fallback";
+
+/// Event emitted when a degraded provider starts working again (payload: provider ID)
+pub const VOICE_RECOVERED_EVENT: &str = "voice:fallback-recovered";
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_chain() -> Vec<String> {
+    vec!["piper".to_string()]
+}
+
+fn default_cooldown_secs() -> u64 {
+    300
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Fallback chain configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FallbackConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Provider IDs tried in order after the voice's own provider fails
+    #[serde(default = "default_chain")]
+    pub chain: Vec<String>,
+    /// Explicit voice mappings, e.g. "elevenlabs:21m00Tcm4TlvDq8ikWAM" -> "piper:en_US-amy-medium"
+    #[serde(default)]
+    pub voice_map: HashMap<String, String>,
+    /// Voice used on a fallback provider when nothing closer matches (provider ID -> voice ID)
+    #[serde(default)]
+    pub default_voices: HashMap<String, String>,
+    /// How long a failed provider is skipped before it is tried again
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+}
+
+impl Default for FallbackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            chain: default_chain(),
+            voice_map: HashMap::new(),
+            default_voices: HashMap::new(),
+            cooldown_secs: default_cooldown_secs(),
+        }
+    }
+}
+
+/// Per-request fallback policy
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackPolicy {
+    /// Use the configured chain
+    #[default]
+    Default,
+    /// Fail rather than speak with another provider (e.g., a cloned voice)
+    Never,
+    /// Try only these providers, in order
+    Providers(Vec<String>),
+}
+
+impl FallbackPolicy {
+    /// Providers to try after `primary` fails, in order
+    pub fn chain(&self, config: &FallbackConfig, primary: &str) -> Vec<String> {
+        let candidates = match self {
+            Self::Never => return Vec::new(),
+            Self::Default if !config.enabled => return Vec::new(),
+            Self::Default => &config.chain,
+            Self::Providers(providers) => providers,
+        };
+
+        let mut chain: Vec<String> = Vec::new();
+        for provider in candidates {
+            if provider != primary && !chain.contains(provider) {
+                chain.push(provider.clone());
+            }
+        }
+        chain
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// A line was spoken by a fallback provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceFallbackEvent {
+    /// Provider the voice belongs to
+    pub requested_provider: String,
+    pub requested_voice_id: String,
+    /// Provider and voice that actually spoke
+    pub provider: String,
+    pub voice_id: String,
+    /// Why the requested provider was skipped
+    pub reason: String,
+}
+
+/// A provider that failed recently and is being skipped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderDegradation {
+    pub provider: String,
+    pub reason: String,
+    pub failed_at: DateTime<Utc>,
+    /// Seconds until the provider is tried again
+    pub retry_in_secs: u64,
+}
+
+/// Whether another provider could succeed where this error occurred
+///
+/// An unknown voice ID is a configuration mistake rather than an outage,
+/// so it is reported instead of papered over.
+pub fn should_fall_back(error: &VoiceError) -> bool {
+    !matches!(error, VoiceError::InvalidVoiceId(_))
+}
+
+// ============================================================================
+// Degradation Tracking
+// ============================================================================
+
+struct Degradation {
+    reason: String,
+    failed_at: DateTime<Utc>,
+    retry_at: Instant,
+}
+
+/// Tracks providers that failed and when to try them again
+#[derive(Default)]
+pub struct FallbackTracker {
+    degraded: Mutex<HashMap<String, Degradation>>,
+}
+
+impl FallbackTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a failure; the provider is skipped for `cooldown`
+    pub fn mark_failed(&self, provider: &str, reason: &str, cooldown: Duration) {
+        if let Ok(mut degraded) = self.degraded.lock() {
+            degraded.insert(
+                provider.to_string(),
+                Degradation {
+                    reason: reason.to_string(),
+                    failed_at: Utc::now(),
+                    retry_at: Instant::now() + cooldown,
+                },
+            );
+        }
+    }
+
+    /// Forget a failure; returns true if the provider was degraded
+    pub fn mark_recovered(&self, provider: &str) -> bool {
+        self.degraded
+            .lock()
+            .map(|mut degraded| degraded.remove(provider).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Why the provider is being skipped, if it is still cooling down
+    pub fn cooling_down(&self, provider: &str) -> Option<String> {
+        let degraded = self.degraded.lock().ok()?;
+        degraded
+            .get(provider)
+            .filter(|d| d.retry_at > Instant::now())
+            .map(|d| d.reason.clone())
+    }
+
+    /// Providers that failed recently
+    pub fn status(&self) -> Vec<ProviderDegradation> {
+        let Ok(degraded) = self.degraded.lock() else {
+            return Vec::new();
+        };
+        let now = Instant::now();
+        let mut status: Vec<ProviderDegradation> = degraded
+            .iter()
+            .map(|(provider, d)| ProviderDegradation {
+                provider: provider.clone(),
+                reason: d.reason.clone(),
+                failed_at: d.failed_at,
+                retry_in_secs: d.retry_at.saturating_duration_since(now).as_secs(),
+            })
+            .collect();
+        status.sort_by(|a, b| a.provider.cmp(&b.provider));
+        status
+    }
+}
+
+// ============================================================================
+// Voice Mapping
+// ============================================================================
+
+/// Provider ID used for routing and voice ID prefixes
+pub fn provider_key(provider: &VoiceProviderType) -> Option<&'static str> {
+    match provider {
+        VoiceProviderType::ElevenLabs => Some("elevenlabs"),
+        VoiceProviderType::FishAudio => Some("fish_audio"),
+        VoiceProviderType::OpenAI => Some("openai"),
+        VoiceProviderType::Piper => Some("piper"),
+        VoiceProviderType::Ollama => Some("ollama"),
+        VoiceProviderType::Chatterbox => Some("chatterbox"),
+        VoiceProviderType::GptSoVits => Some("gpt_sovits"),
+        VoiceProviderType::XttsV2 => Some("xtts_v2"),
+        VoiceProviderType::FishSpeech => Some("fish_speech"),
+        VoiceProviderType::Dia => Some("dia"),
+        VoiceProviderType::Coqui => Some("coqui"),
+        VoiceProviderType::System | VoiceProviderType::Disabled => None,
+    }
+}
+
+/// Strip a "<provider>:" prefix from a voice ID
+fn strip_provider<'a>(voice_id: &'a str, provider: &str) -> &'a str {
+    voice_id
+        .strip_prefix(provider)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .unwrap_or(voice_id)
+}
+
+/// Provider IDs a mapped voice may be prefixed with
+const PROVIDER_IDS: &[&str] = &[
+    "elevenlabs", "fish_audio", "openai", "piper", "ollama", "chatterbox",
+    "gpt_sovits", "xtts_v2", "fish_speech", "dia", "coqui",
+];
+
+/// Look up an explicit mapping of `from_provider:from_voice` onto `to_provider`
+///
+/// Keys may omit the provider; an unprefixed target applies to any fallback provider.
+pub fn mapped_voice(config: &FallbackConfig, from_provider: &str, from_voice: &str, to_provider: &str) -> Option<String> {
+    let target = config
+        .voice_map
+        .get(&format!("{}:{}", from_provider, from_voice))
+        .or_else(|| config.voice_map.get(from_voice))?;
+
+    match target.split_once(':') {
+        Some((provider, voice)) if provider == to_provider => Some(voice.to_string()),
+        Some((provider, _)) if PROVIDER_IDS.contains(&provider) => None,
+        _ => Some(target.clone()),
+    }
+}
+
+/// Find the profile on `to_provider` that sounds most like the profile
+/// behind `from_provider:from_voice`
+///
+/// Profiles are compared on gender, age, and personality traits. Ties go to
+/// the lowest profile ID so the mapping is stable across lines.
+pub fn nearest_profile_voice(
+    profiles: &[&VoiceProfile],
+    from_provider: &str,
+    from_voice: &str,
+    to_provider: &str,
+) -> Option<String> {
+    let source = profiles.iter().find(|p| {
+        provider_key(&p.provider) == Some(from_provider) && strip_provider(&p.voice_id, from_provider) == from_voice
+    })?;
+
+    let hints = NpcVoiceHints {
+        age_range: Some(source.metadata.age_range.clone()),
+        gender: Some(source.metadata.gender.clone()),
+        traits: source
+            .metadata
+            .personality_traits
+            .iter()
+            .map(|t| t.to_lowercase())
+            .collect(),
+    };
+
+    profiles
+        .iter()
+        .filter(|p| provider_key(&p.provider) == Some(to_provider))
+        .map(|p| (score_profile(&hints, p), *p))
+        .max_by(|(a, pa), (b, pb)| a.cmp(b).then_with(|| pb.id.cmp(&pa.id)))
+        .map(|(_, p)| strip_provider(&p.voice_id, to_provider).to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::profiles::{AgeRange, Gender, ProfileMetadata};
+
+    fn profile(id: &str, provider: VoiceProviderType, voice: &str, age: AgeRange, gender: Gender) -> VoiceProfile {
+        VoiceProfile::preset(id, id, provider, voice, ProfileMetadata::new(age, gender))
+    }
+
+    #[test]
+    fn test_policy_chain() {
+        let config = FallbackConfig {
+            chain: vec!["openai".into(), "piper".into(), "openai".into()],
+            ..Default::default()
+        };
+
+        assert_eq!(FallbackPolicy::Default.chain(&config, "elevenlabs"), ["openai", "piper"]);
+        assert_eq!(FallbackPolicy::Default.chain(&config, "openai"), ["piper"]);
+        assert!(FallbackPolicy::Never.chain(&config, "elevenlabs").is_empty());
+        assert_eq!(
+            FallbackPolicy::Providers(vec!["coqui".into()]).chain(&config, "elevenlabs"),
+            ["coqui"]
+        );
+
+        let disabled = FallbackConfig { enabled: false, ..config };
+        assert!(FallbackPolicy::Default.chain(&disabled, "elevenlabs").is_empty());
+    }
+
+    #[test]
+    fn test_nearest_profile_voice() {
+        let narrator = profile("a-narrator", VoiceProviderType::ElevenLabs, "old-wizard", AgeRange::Elderly, Gender::Male);
+        let amy = profile("b-amy", VoiceProviderType::Piper, "en_US-amy-medium", AgeRange::Adult, Gender::Female);
+        let alan = profile("c-alan", VoiceProviderType::Piper, "piper:en_GB-alan-medium", AgeRange::MiddleAged, Gender::Male);
+        let profiles = vec![&narrator, &amy, &alan];
+
+        assert_eq!(
+            nearest_profile_voice(&profiles, "elevenlabs", "old-wizard", "piper").as_deref(),
+            Some("en_GB-alan-medium")
+        );
+        assert_eq!(nearest_profile_voice(&profiles, "elevenlabs", "unknown", "piper"), None);
+        assert_eq!(nearest_profile_voice(&profiles, "elevenlabs", "old-wizard", "coqui"), None);
+    }
+
+    #[test]
+    fn test_mapped_voice() {
+        let mut config = FallbackConfig::default();
+        config.voice_map.insert("elevenlabs:abc".into(), "piper:en_US-amy-medium".into());
+        config.voice_map.insert("xyz".into(), "en_US-ryan-high".into());
+
+        assert_eq!(mapped_voice(&config, "elevenlabs", "abc", "piper").as_deref(), Some("en_US-amy-medium"));
+        assert_eq!(mapped_voice(&config, "elevenlabs", "abc", "openai"), None);
+        assert_eq!(mapped_voice(&config, "elevenlabs", "xyz", "piper").as_deref(), Some("en_US-ryan-high"));
+        assert_eq!(mapped_voice(&config, "elevenlabs", "other", "piper"), None);
+    }
+
+    #[test]
+    fn test_tracker_cooldown() {
+        let tracker = FallbackTracker::new();
+        tracker.mark_failed("elevenlabs", "Quota exceeded", Duration::from_secs(60));
+        tracker.mark_failed("openai", "Rate limit exceeded", Duration::ZERO);
+
+        assert_eq!(tracker.cooling_down("elevenlabs").as_deref(), Some("Quota exceeded"));
+        assert_eq!(tracker.cooling_down("openai"), None);
+        assert_eq!(tracker.status().len(), 2);
+
+        assert!(tracker.mark_recovered("elevenlabs"));
+        assert!(!tracker.mark_recovered("elevenlabs"));
+        assert_eq!(tracker.cooling_down("elevenlabs"), None);
+    }
+
+    #[test]
+    fn test_should_fall_back() {
+        assert!(should_fall_back(&VoiceError::QuotaExceeded));
+        assert!(should_fall_back(&VoiceError::RateLimitExceeded));
+        assert!(should_fall_back(&VoiceError::ApiError("503".into())));
+        assert!(!should_fall_back(&VoiceError::InvalidVoiceId("nope".into())));
+    }
+}
@@ -6,11 +6,13 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
 use tokio::sync::RwLock;
 
 use crate::core::voice::types::{
     Result, SynthesisRequest, SynthesisResult, VoiceConfig, VoiceProviderType,
-    VoiceError, Voice,
+    VoiceError, Voice, VoiceSettings, VoicePriority, OutputFormat,
 };
 use crate::core::voice::providers::{
     VoiceProvider, elevenlabs::ElevenLabsProvider, fish_audio::FishAudioProvider,
@@ -18,9 +20,16 @@ use crate::core::voice::providers::{
     ChatterboxProvider, GptSoVitsProvider, XttsV2Provider, FishSpeechProvider, DiaProvider, CoquiProvider,
 };
 use crate::core::voice::cache::{AudioCache, CacheKeyParams, CacheConfig, CacheStats, CacheError, CacheEntry};
+use crate::core::voice::normalize::TextNormalizer;
+use crate::core::voice::fallback::{
+    mapped_voice, nearest_profile_voice, provider_key, should_fall_back, FallbackPolicy,
+    FallbackTracker, ProviderDegradation, VoiceFallbackEvent, VOICE_FALLBACK_EVENT, VOICE_RECOVERED_EVENT,
+};
+use crate::core::voice::profiles::VoiceProfileManager;
+use crate::core::voice::session_audio::{SessionAudioLog, SessionUtterance};
+use crate::core::voice::streaming::{split_sentences, AudioChunk, AudioChunkSender, DEFAULT_MAX_SEGMENT_CHARS};
+use crate::core::audio::{AudioMixer, MixerChannel, MixerTrack};
 
-use rodio::{Decoder, OutputStream, Sink};
-use std::io::Cursor;
 
 /// Known provider prefixes for voice IDs
 const PROVIDER_PREFIXES: &[(&str, &str)] = &[
@@ -30,6 +39,7 @@ const PROVIDER_PREFIXES: &[(&str, &str)] = &[
     ("openai:", "openai"),
     ("ollama:", "ollama"),
     ("fish_audio:", "fish_audio"),
+    ("xtts_v2:", "xtts_v2"),
 ];
 
 /// Result of parsing a voice ID with optional provider prefix
@@ -60,12 +70,28 @@ pub struct VoiceManager {
     config: VoiceConfig,
     providers: HashMap<String, Box<dyn VoiceProvider>>,
     cache_dir: PathBuf,
+    /// Expands dice notation and abbreviations before synthesis
+    normalizer: TextNormalizer,
     /// The audio cache instance (lazily initialized)
     cache: RwLock<Option<Arc<AudioCache>>>,
     /// Cache configuration
     cache_config: CacheConfig,
     pub queue: Vec<crate::core::voice::types::QueuedVoice>,
     pub is_playing: bool,
+    /// Queue-wide pause: the current utterance is paused and nothing new starts
+    pub paused: bool,
+    /// Most recently spoken utterance, for replay
+    last_played: Option<crate::core::voice::types::QueuedVoice>,
+    /// Track of the utterance currently playing
+    current_track: Option<MixerTrack>,
+    /// Log of lines spoken per game session
+    session_log: Option<SessionAudioLog>,
+    /// Providers that failed recently and are skipped by the fallback chain
+    fallback: FallbackTracker,
+    /// Voice profiles used to map voices onto fallback providers
+    profiles: Option<Arc<RwLock<VoiceProfileManager>>>,
+    /// Used to report fallback events to the UI
+    app_handle: Option<AppHandle>,
 }
 
 impl VoiceManager {
@@ -119,15 +145,29 @@ impl VoiceManager {
         providers.insert("piper".to_string(), Box::new(PiperProvider::new(piper_config)));
 
         let cache_dir = config.cache_dir.clone().unwrap_or_else(|| PathBuf::from("./voice_cache"));
+        let mut cache_config = CacheConfig::default();
+        if let Some(max_mb) = config.cache_max_size_mb {
+            cache_config.max_size_bytes = max_mb * 1024 * 1024;
+        }
+
+        let normalizer = TextNormalizer::new(&config.text_normalization);
 
         Self {
             config,
             providers,
             cache_dir,
+            normalizer,
             cache: RwLock::new(None),
-            cache_config: CacheConfig::default(),
+            cache_config,
             queue: Vec::new(),
             is_playing: false,
+            paused: false,
+            last_played: None,
+            current_track: None,
+            session_log: None,
+            fallback: FallbackTracker::new(),
+            profiles: None,
+            app_handle: None,
         }
     }
 
@@ -138,11 +178,21 @@ impl VoiceManager {
         manager
     }
 
+    /// Rewrite text the way it will be spoken (dice notation, abbreviations)
+    pub fn normalize_text(&self, text: &str) -> String {
+        self.normalizer.normalize(text)
+    }
+
     /// Get the current voice configuration
     pub fn get_config(&self) -> &VoiceConfig {
         &self.config
     }
 
+    /// Directory where synthesized audio is cached
+    pub fn cache_dir(&self) -> &std::path::Path {
+        &self.cache_dir
+    }
+
     /// Get or initialize the audio cache
     async fn get_cache(&self) -> Result<Arc<AudioCache>> {
         // Check if cache already exists
@@ -210,14 +260,62 @@ impl VoiceManager {
         Ok(cache.list_entries().await)
     }
 
+    /// Clear cache entries produced by a provider and/or voice
+    pub async fn clear_cache_matching(&self, provider: Option<&str>, voice_id: Option<&str>) -> Result<usize> {
+        let cache = self.get_cache().await?;
+        cache.clear_matching(provider, voice_id).await.map_err(|e| VoiceError::IoError(std::io::Error::other(
+            format!("Failed to clear cache: {}", e)
+        )))
+    }
+
+    /// Change the cache size limit, evicting least recently used audio to fit
+    ///
+    /// Returns the number of entries evicted.
+    pub async fn set_cache_max_size(&mut self, max_size_mb: u64) -> Result<usize> {
+        let max_size_bytes = max_size_mb * 1024 * 1024;
+        self.config.cache_max_size_mb = Some(max_size_mb);
+        self.cache_config.max_size_bytes = max_size_bytes;
+
+        let cache = self.get_cache().await?;
+        cache.set_max_size(max_size_bytes).await.map_err(|e| VoiceError::IoError(std::io::Error::other(
+            format!("Failed to resize cache: {}", e)
+        )))
+    }
+
     /// Add an item to the voice queue
     pub fn add_to_queue(&mut self, text: String, voice_id: String) -> crate::core::voice::types::QueuedVoice {
+        self.add_to_queue_with_settings(text, voice_id, None)
+    }
+
+    /// Add an item to the voice queue with explicit voice settings (e.g., from a voice profile)
+    pub fn add_to_queue_with_settings(
+        &mut self,
+        text: String,
+        voice_id: String,
+        settings: Option<VoiceSettings>,
+    ) -> crate::core::voice::types::QueuedVoice {
+        self.add_to_queue_with_priority(text, voice_id, settings, VoicePriority::Normal)
+    }
+
+    /// Add an item to the voice queue at the given playback priority
+    pub fn add_to_queue_with_priority(
+        &mut self,
+        text: String,
+        voice_id: String,
+        settings: Option<VoiceSettings>,
+        priority: VoicePriority,
+    ) -> crate::core::voice::types::QueuedVoice {
         let item = crate::core::voice::types::QueuedVoice {
             id: uuid::Uuid::new_v4().to_string(),
             text,
             voice_id,
+            settings,
+            priority,
             status: crate::core::voice::types::VoiceStatus::Pending,
             created_at: chrono::Utc::now().to_rfc3339(),
+            audio_path: None,
+            session_id: None,
+            fallback: FallbackPolicy::Default,
         };
         self.queue.push(item.clone());
         item
@@ -240,11 +338,153 @@ impl VoiceManager {
         }
     }
 
-    /// Get the next pending item in the queue
+    /// Get the next pending item: highest priority first, then oldest
     pub fn get_next_pending(&self) -> Option<crate::core::voice::types::QueuedVoice> {
         self.queue.iter()
-            .find(|item| matches!(item.status, crate::core::voice::types::VoiceStatus::Pending))
-            .cloned()
+            .enumerate()
+            .filter(|(_, item)| matches!(item.status, crate::core::voice::types::VoiceStatus::Pending))
+            .max_by_key(|(index, item)| (item.priority, std::cmp::Reverse(*index)))
+            .map(|(_, item)| item.clone())
+    }
+
+    /// Record where an item's synthesized audio was written
+    pub fn set_audio_path(&mut self, id: &str, path: String) {
+        if let Some(item) = self.queue.iter_mut().find(|i| i.id == id) {
+            item.audio_path = Some(path);
+        }
+    }
+
+    /// Attribute a queued item to a game session
+    pub fn set_session_id(&mut self, id: &str, session_id: String) {
+        if let Some(item) = self.queue.iter_mut().find(|i| i.id == id) {
+            item.session_id = Some(session_id);
+        }
+    }
+
+    /// Set how a queued item may fall back to other providers
+    pub fn set_fallback_policy(&mut self, id: &str, policy: FallbackPolicy) {
+        if let Some(item) = self.queue.iter_mut().find(|i| i.id == id) {
+            item.fallback = policy;
+        }
+    }
+
+    /// Set where lines spoken in a session are logged
+    pub fn set_session_log(&mut self, log: Option<SessionAudioLog>) {
+        self.session_log = log;
+    }
+
+    /// Log of lines spoken per game session, if configured
+    pub fn session_log(&self) -> Option<&SessionAudioLog> {
+        self.session_log.as_ref()
+    }
+
+    /// Set the voice profiles used to map voices onto fallback providers
+    pub fn set_voice_profiles(&mut self, profiles: Option<Arc<RwLock<VoiceProfileManager>>>) {
+        self.profiles = profiles;
+    }
+
+    pub fn voice_profiles(&self) -> Option<&Arc<RwLock<VoiceProfileManager>>> {
+        self.profiles.as_ref()
+    }
+
+    /// Set the app handle used to emit fallback events
+    pub fn set_app_handle(&mut self, app_handle: Option<AppHandle>) {
+        self.app_handle = app_handle;
+    }
+
+    pub fn app_handle(&self) -> Option<&AppHandle> {
+        self.app_handle.as_ref()
+    }
+
+    /// Providers currently skipped by the fallback chain
+    pub fn degraded_providers(&self) -> Vec<ProviderDegradation> {
+        self.fallback.status()
+    }
+
+    /// The item currently playing, if any
+    pub fn playing_item(&self) -> Option<&crate::core::voice::types::QueuedVoice> {
+        self.queue.iter()
+            .find(|item| matches!(item.status, crate::core::voice::types::VoiceStatus::Playing))
+    }
+
+    /// Mark an item as playing on `track`
+    ///
+    /// If the queue is paused, the track is paused straight away.
+    pub fn begin_playback(&mut self, id: &str, track: MixerTrack) {
+        if self.paused {
+            track.pause();
+        }
+        self.update_status(id, crate::core::voice::types::VoiceStatus::Playing);
+        self.is_playing = true;
+        self.current_track = Some(track);
+    }
+
+    /// Record the end of an item's playback
+    ///
+    /// Items that were interrupted keep their `Interrupted` status. Anything
+    /// that actually played becomes the replay target.
+    pub fn finish_playback(&mut self, id: &str, error: Option<String>) {
+        use crate::core::voice::types::VoiceStatus;
+
+        self.current_track = None;
+        self.is_playing = false;
+        if let Some(item) = self.queue.iter_mut().find(|i| i.id == id) {
+            if item.status == VoiceStatus::Playing {
+                item.status = match error {
+                    Some(e) => VoiceStatus::Failed(e),
+                    None => VoiceStatus::Completed,
+                };
+            }
+            if item.audio_path.is_some() && !matches!(item.status, VoiceStatus::Failed(_)) {
+                self.last_played = Some(item.clone());
+
+                if let (Some(session_log), Some(session_id), Some(audio_path)) =
+                    (&self.session_log, &item.session_id, &item.audio_path)
+                {
+                    let utterance = SessionUtterance {
+                        queue_id: item.id.clone(),
+                        text: item.text.clone(),
+                        voice_id: item.voice_id.clone(),
+                        audio_path: PathBuf::from(audio_path),
+                        spoken_at: chrono::Utc::now(),
+                    };
+                    if let Err(e) = session_log.record(session_id, &utterance) {
+                        log::warn!("Failed to log session utterance: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stop the utterance that is playing; returns the interrupted item
+    pub fn interrupt_current(&mut self) -> Option<crate::core::voice::types::QueuedVoice> {
+        let id = self.playing_item()?.id.clone();
+        self.update_status(&id, crate::core::voice::types::VoiceStatus::Interrupted);
+        if let Some(track) = self.current_track.take() {
+            track.stop();
+        }
+        self.queue.iter().find(|i| i.id == id).cloned()
+    }
+
+    /// Pause the whole queue, including the utterance that is playing
+    pub fn pause_queue(&mut self) {
+        self.paused = true;
+        if let Some(track) = &self.current_track {
+            track.pause();
+        }
+    }
+
+    /// Resume the queue after `pause_queue`
+    pub fn resume_queue(&mut self) {
+        self.paused = false;
+        if let Some(track) = &self.current_track {
+            track.resume();
+        }
+    }
+
+    /// The most recently spoken utterance
+    pub fn last_played(&self) -> Option<&crate::core::voice::types::QueuedVoice> {
+        self.last_played.as_ref()
     }
 
     /// Synthesize audio with caching support
@@ -260,22 +500,135 @@ impl VoiceManager {
     /// Tags can be used to group cache entries (e.g., by session_id, npc_id, campaign_id)
     /// for bulk operations like clearing all audio for a specific session.
     pub async fn synthesize_with_tags(&self, request: SynthesisRequest, tags: &[String]) -> Result<SynthesisResult> {
-        // Determine provider from voice_id prefix or fallback to active provider config
-        let provider_id = match parse_prefixed_voice_id(&request.voice_id) {
-            Some(parsed) => parsed.provider_id,
-            None => match self.get_active_provider_id() {
-                Ok(id) => id,
-                Err(VoiceError::NotConfigured(message)) if message.contains("disabled") => {
-                    return Err(VoiceError::NotConfigured(format!(
-                        "{} (voice_id='{}' may have an unrecognized prefix)",
-                        message,
-                        request.voice_id
-                    )));
+        self.synthesize_with_policy(request, tags, &FallbackPolicy::Default).await
+    }
+
+    /// Synthesize audio, falling back to other providers as `policy` allows
+    ///
+    /// If the voice's provider fails with an error another provider could
+    /// avoid (quota, rate limit, outage), each provider in the chain is tried
+    /// with the closest matching voice. A failed provider is skipped for the
+    /// configured cool-down, and `voice:fallback` / `voice:fallback-recovered`
+    /// events report the degradation.
+    pub async fn synthesize_with_policy(
+        &self,
+        mut request: SynthesisRequest,
+        tags: &[String],
+        policy: &FallbackPolicy,
+    ) -> Result<SynthesisResult> {
+        // Normalize before keying the cache so the key matches what is spoken
+        request.text = self.normalizer.normalize(&request.text);
+
+        let (primary, primary_voice) = self.resolve_provider_id(&request.voice_id)?;
+        let chain = policy.chain(&self.config.fallback, &primary);
+        let cooldown = Duration::from_secs(self.config.fallback.cooldown_secs);
+
+        // Skip the primary while it cools down, unless there is nothing to fall back to
+        let mut reason = if chain.is_empty() { None } else { self.fallback.cooling_down(&primary) };
+        let mut primary_error = None;
+
+        if reason.is_none() {
+            match self.synthesize_on(&primary, &primary_voice, &request, tags).await {
+                Ok(result) => {
+                    if self.fallback.mark_recovered(&primary) {
+                        log::info!("Voice provider '{}' recovered", &primary);
+                        self.emit(VOICE_RECOVERED_EVENT, &primary);
+                    }
+                    return Ok(result);
+                }
+                Err(e) if !chain.is_empty() && should_fall_back(&e) => {
+                    log::warn!("Voice provider '{}' failed, falling back: {}", &primary, e);
+                    self.fallback.mark_failed(&primary, &e.to_string(), cooldown);
+                    reason = Some(e.to_string());
+                    primary_error = Some(e);
                 }
                 Err(e) => return Err(e),
-            },
-        };
+            }
+        }
+
+        for provider in &chain {
+            if !self.providers.contains_key(provider) || self.fallback.cooling_down(provider).is_some() {
+                continue;
+            }
+            let Some(voice_id) = self.fallback_voice(&primary, &primary_voice, provider).await else {
+                log::warn!("No voice to fall back to on '{}' for '{}'", provider, &request.voice_id);
+                continue;
+            };
+
+            match self.synthesize_on(provider, &voice_id, &request, tags).await {
+                Ok(result) => {
+                    self.fallback.mark_recovered(provider);
+                    self.emit(VOICE_FALLBACK_EVENT, VoiceFallbackEvent {
+                        requested_provider: primary.clone(),
+                        requested_voice_id: primary_voice.clone(),
+                        provider: provider.clone(),
+                        voice_id,
+                        reason: reason.clone().unwrap_or_default(),
+                    });
+                    return Ok(result);
+                }
+                Err(e) => {
+                    log::warn!("Fallback voice provider '{}' failed: {}", provider, e);
+                    if should_fall_back(&e) {
+                        self.fallback.mark_failed(provider, &e.to_string(), cooldown);
+                    }
+                }
+            }
+        }
+
+        Err(primary_error.unwrap_or_else(|| VoiceError::NotConfigured(format!(
+            "Voice provider '{}' is unavailable ({}) and no fallback provider succeeded",
+            primary,
+            reason.unwrap_or_default()
+        ))))
+    }
+
+    /// Pick the voice to use on a fallback provider
+    ///
+    /// Explicit mappings win, then the nearest matching voice profile, then
+    /// the provider's configured default, then its first available voice.
+    async fn fallback_voice(&self, from_provider: &str, from_voice: &str, to_provider: &str) -> Option<String> {
+        let config = &self.config.fallback;
+        if let Some(voice) = mapped_voice(config, from_provider, from_voice, to_provider) {
+            return Some(voice);
+        }
+
+        if let Some(profiles) = &self.profiles {
+            let profiles = profiles.read().await;
+            let candidates = profiles.list_all();
+            if let Some(voice) = nearest_profile_voice(&candidates, from_provider, from_voice, to_provider) {
+                return Some(voice);
+            }
+        }
+
+        if let Some(voice) = config.default_voices.get(to_provider) {
+            return Some(voice.clone());
+        }
+        if provider_key(&self.config.provider) == Some(to_provider) {
+            if let Some(voice) = &self.config.default_voice_id {
+                return Some(voice.strip_prefix(&format!("{}:", to_provider)).unwrap_or(voice).to_string());
+            }
+        }
+
+        let provider = self.providers.get(to_provider)?;
+        let voices = provider.list_voices().await.ok()?;
+        voices.into_iter().next().map(|v| v.id)
+    }
 
+    fn emit<S: serde::Serialize + Clone>(&self, event: &str, payload: S) {
+        if let Some(handle) = &self.app_handle {
+            let _ = handle.emit(event, payload);
+        }
+    }
+
+    /// Synthesize with one provider, through the cache
+    async fn synthesize_on(
+        &self,
+        provider_id: &str,
+        provider_voice_id: &str,
+        request: &SynthesisRequest,
+        tags: &[String],
+    ) -> Result<SynthesisResult> {
         let provider = self.providers.get(provider_id)
             .ok_or_else(|| VoiceError::NotConfigured(format!("Provider {} not configured", provider_id)))?;
 
@@ -293,10 +646,12 @@ impl VoiceManager {
              )));
         }
 
+        // Key on the provider's own voice ID so "openai:alloy" and "alloy" (with
+        // OpenAI active) share cached audio
         let cache_key_params = CacheKeyParams::new(
             &request.text,
             provider_type_enum,
-            &request.voice_id,
+            provider_voice_id,
             &settings,
             request.output_format.clone(),
         );
@@ -307,40 +662,112 @@ impl VoiceManager {
 
         // Use get_or_synthesize for atomic check-and-store
         let tags_vec: Vec<String> = tags.to_vec();
-        let request_clone = request.clone();
+        // Providers receive the voice ID without the routing prefix
+        let mut request_clone = request.clone();
+        request_clone.voice_id = provider_voice_id.to_string();
+        // Keep the provider's own error so the fallback chain can classify it
+        let provider_error = std::sync::Mutex::new(None);
 
-        let result_path = cache.get_or_synthesize(
+        let result_path = cache.get_or_synthesize_for(
             &cache_key,
             request.output_format.clone(),
             &tags_vec,
+            provider_id,
+            provider_voice_id,
             || async {
                 // This closure is only called if the key is not in cache
-                let audio_data = provider.synthesize(&request_clone).await
-                    .map_err(|e| CacheError::IoError(std::io::Error::other(
-                        format!("Synthesis failed: {}", e)
-                    )))?;
-                Ok(audio_data)
+                match provider.synthesize(&request_clone).await {
+                    Ok(audio_data) => Ok(audio_data),
+                    Err(e) => {
+                        let error = CacheError::IoError(std::io::Error::other(format!("Synthesis failed: {}", e)));
+                        if let Ok(mut slot) = provider_error.lock() {
+                            *slot = Some(e);
+                        }
+                        Err(error)
+                    }
+                }
             }
         ).await;
 
         match result_path {
-            Ok(path) => {
-                // Determine if this was a cache hit by checking if the path already existed
-                // The cache updates access time on get, so we can check stats
-                let stats = cache.stats().await;
-                let cached = stats.hits > 0;
-
+            Ok((path, cached)) => {
                 Ok(SynthesisResult {
                     audio_path: path,
                     duration_ms: None,
-                    format: request.output_format,
+                    format: request.output_format.clone(),
                     cached,
                 })
             }
-            Err(e) => Err(VoiceError::IoError(std::io::Error::other(
-                format!("Cache operation failed: {}", e)
-            )))
+            Err(e) => match provider_error.into_inner().ok().flatten() {
+                Some(provider_error) => Err(provider_error),
+                None => Err(VoiceError::IoError(std::io::Error::other(
+                    format!("Cache operation failed: {}", e)
+                ))),
+            },
+        }
+    }
+
+    /// Resolve the provider for a voice ID.
+    ///
+    /// Returns the provider ID (from the voice ID prefix, or the active provider)
+    /// and the voice ID with any routing prefix stripped.
+    fn resolve_provider_id(&self, voice_id: &str) -> Result<(String, String)> {
+        match parse_prefixed_voice_id(voice_id) {
+            Some(parsed) => Ok((parsed.provider_id.to_string(), parsed.voice_id.to_string())),
+            None => match self.get_active_provider_id() {
+                Ok(id) => Ok((id.to_string(), voice_id.to_string())),
+                Err(VoiceError::NotConfigured(message)) if message.contains("disabled") => {
+                    Err(VoiceError::NotConfigured(format!(
+                        "{} (voice_id='{}' may have an unrecognized prefix)",
+                        message,
+                        voice_id
+                    )))
+                }
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Synthesize and stream audio chunks into `tx` as they become available.
+    ///
+    /// Providers with native streaming deliver PCM while generating. Other
+    /// providers are driven sentence-by-sentence through the cache, so the
+    /// first sentence can play while later ones are still being synthesized.
+    ///
+    /// Returns true if the provider streamed natively.
+    pub async fn synthesize_streaming(&self, mut request: SynthesisRequest, tx: &AudioChunkSender) -> Result<bool> {
+        // Normalize first so abbreviations like "ft." don't split sentences
+        request.text = self.normalizer.normalize(&request.text);
+
+        let (provider_id, provider_voice_id) = self.resolve_provider_id(&request.voice_id)?;
+
+        // A degraded provider goes through the sentence path, which falls back
+        let native = self.providers.get(&provider_id)
+            .filter(|p| p.supports_streaming() && self.fallback.cooling_down(&provider_id).is_none());
+
+        if let Some(provider) = native {
+            let mut provider_request = request.clone();
+            provider_request.voice_id = provider_voice_id;
+            provider.synthesize_stream(&provider_request, tx).await?;
+            return Ok(true);
+        }
+
+        for segment in split_sentences(&request.text, DEFAULT_MAX_SEGMENT_CHARS) {
+            let segment_request = SynthesisRequest {
+                text: segment,
+                voice_id: request.voice_id.clone(),
+                settings: request.settings.clone(),
+                output_format: OutputFormat::Wav,
+            };
+            let result = self.synthesize(segment_request).await?;
+            let audio = tokio::fs::read(&result.audio_path).await?;
+            if tx.send(AudioChunk::Encoded(audio)).await.is_err() {
+                // Player stopped; no point synthesizing the rest
+                break;
+            }
         }
+
+        Ok(false)
     }
 
     /// Get the provider ID string based on the legacy configuration
@@ -380,19 +807,12 @@ impl VoiceManager {
         }
     }
 
-    /// Play audio data through the system audio output
-    pub fn play_audio(&self, audio_data: Vec<u8>) -> Result<()> {
-        let (_stream, stream_handle) = OutputStream::try_default()
+    /// Play audio data on the mixer's voice channel, blocking until it finishes
+    pub fn play_audio(&self, mixer: &AudioMixer, audio_data: Vec<u8>) -> Result<()> {
+        let track = mixer
+            .play_bytes(MixerChannel::Voice, audio_data, 1.0)
             .map_err(|e| VoiceError::IoError(std::io::Error::other(e.to_string())))?;
-        let sink = Sink::try_new(&stream_handle)
-            .map_err(|e| VoiceError::IoError(std::io::Error::other(e.to_string())))?;
-
-        let cursor = Cursor::new(audio_data);
-        let source = Decoder::new(cursor)
-            .map_err(|e| VoiceError::IoError(std::io::Error::other(e.to_string())))?;
-
-        sink.append(source);
-        sink.sleep_until_end();
+        track.sleep_until_end();
         Ok(())
     }
 
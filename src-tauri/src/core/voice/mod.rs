@@ -8,6 +8,14 @@ pub mod cache;
 pub mod queue;
 pub mod download;
 pub mod install;
+pub mod npc_voice;
+pub mod streaming;
+pub mod read_aloud;
+pub mod cloning;
+pub mod session_audio;
+pub mod normalize;
+pub mod fallback;
+pub mod announcer;
 
 pub use types::*;
 pub use manager::VoiceManager;
@@ -21,6 +29,44 @@ pub use profiles::{
 };
 pub use presets::{get_dm_presets, get_presets_by_tag, get_preset_by_id};
 
+// Re-export streaming playback
+pub use streaming::{
+    AudioChunk, AudioChunkSender, PcmChunker, StreamingPlayer, StreamPlaybackSummary,
+    split_sentences,
+};
+
+// Re-export NPC voice assignment
+pub use npc_voice::{
+    NpcVoiceHints, auto_assign_profile, apply_pronunciations, qualified_voice_id,
+};
+
+// Re-export session read-aloud pre-generation
+pub use read_aloud::{
+    ReadAloudPassage, ReadAloudRequest, ReadAloudStatus, ReadAloudStore, SessionReadAloud,
+};
+
+// Re-export TTS text normalization
+pub use normalize::{TextNormalizationConfig, TextNormalizer};
+
+// Re-export provider fallback chain
+pub use fallback::{
+    FallbackConfig, FallbackPolicy, ProviderDegradation, VoiceFallbackEvent,
+    VOICE_FALLBACK_EVENT, VOICE_RECOVERED_EVENT,
+};
+
+// Re-export combat announcer
+pub use announcer::{AnnouncementKind, AnnouncerConfig, AnnouncerEvent, CombatAnnouncer};
+
+// Re-export session audio log and recap export
+pub use session_audio::{
+    SessionAudioLog, SessionExportOptions, SessionExportSummary, SessionUtterance,
+};
+
+// Re-export voice cloning
+pub use cloning::{
+    ClonedVoice, ClonedVoiceStore, CloneRequirements, CloneValidation, SampleAnalysis,
+};
+
 // Re-export cache system (TASK-005)
 pub use cache::{
     AudioCache, CacheEntry, CacheConfig, CacheStats,
@@ -38,7 +84,7 @@ pub use queue::{
 // Re-export download system
 pub use download::{
     VoiceDownloader, AvailablePiperVoice, PiperLanguage, PiperVoiceFiles, PiperFileInfo,
-    DownloadError, DownloadResult, ProgressCallback, popular_piper_voices,
+    PiperVoiceFilter, DownloadError, DownloadResult, ProgressCallback, popular_piper_voices,
 };
 
 // Re-export install system
@@ -0,0 +1,386 @@
+//! TTS Text Normalization
+//!
+//! Game text reads badly when spoken verbatim: "1d8+3", "AC 15" and
+//! "HP 22/30" come out as letter soup. Before synthesis, dice notation,
+//! modifiers, and stat abbreviations are expanded into speakable words.
+//!
+//! Abbreviations depend on the game system ("INT" is Intelligence in D&D but
+//! Intuition in Shadowrun), so the table is chosen from the configured system
+//! and can be extended or overridden by the user.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::core::character_gen::GameSystem;
+
+/// Dice notation: "1d8+3", "d20", "2d6 - 1", "d%"
+static DICE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(\d*)[dD](\d+\b|%)(?:\s*([+\-−])\s*(\d+)\b)?").expect("valid dice regex")
+});
+
+/// A standalone signed modifier: "+5", "(-1)", "−2"
+static MODIFIER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(^|[\s(\[])([+\-−])(\d+)\b").expect("valid modifier regex"));
+
+/// Current/maximum pairs after hit points: "HP 22/30"
+static HP_FRACTION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(HP|hp)\s*:?\s*(\d+)\s*/\s*(\d+)\b").expect("valid hit point regex")
+});
+
+/// Uses per period: "3/day", "1/long rest"
+static PER_PERIOD_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(\d+)\s*/\s*(day|long rest|short rest|turn|round|encounter)\b")
+        .expect("valid per-period regex")
+});
+
+static MULTI_SPACE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[ \t]{2,}").expect("valid whitespace regex"));
+
+fn default_enabled() -> bool {
+    true
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// How text is prepared for speech
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TextNormalizationConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Game system whose abbreviations are expanded (e.g. "dnd5e", "coc")
+    #[serde(default)]
+    pub game_system: Option<String>,
+    /// Extra or overriding abbreviations, matched as case-sensitive whole words
+    #[serde(default)]
+    pub abbreviations: HashMap<String, String>,
+}
+
+impl Default for TextNormalizationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            game_system: None,
+            abbreviations: HashMap::new(),
+        }
+    }
+}
+
+// ============================================================================
+// Abbreviation Tables
+// ============================================================================
+
+/// Abbreviations shared by most systems
+const COMMON_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("HP", "hit points"),
+    ("hp", "hit points"),
+    ("XP", "experience points"),
+    ("GM", "game master"),
+    ("NPC", "N P C"),
+    ("NPCs", "N P Cs"),
+    ("ft.", "feet"),
+    ("ft", "feet"),
+    ("lb.", "pounds"),
+    ("lbs.", "pounds"),
+    ("lbs", "pounds"),
+    ("lb", "pounds"),
+    ("mi.", "miles"),
+];
+
+const DND5E_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("AC", "armor class"),
+    ("DC", "difficulty class"),
+    ("STR", "strength"),
+    ("DEX", "dexterity"),
+    ("CON", "constitution"),
+    ("INT", "intelligence"),
+    ("WIS", "wisdom"),
+    ("CHA", "charisma"),
+    ("Str", "strength"),
+    ("Dex", "dexterity"),
+    ("Con", "constitution"),
+    ("Int", "intelligence"),
+    ("Wis", "wisdom"),
+    ("Cha", "charisma"),
+    ("CR", "challenge rating"),
+    ("PB", "proficiency bonus"),
+    ("DM", "dungeon master"),
+    ("gp", "gold pieces"),
+    ("sp", "silver pieces"),
+    ("cp", "copper pieces"),
+    ("ep", "electrum pieces"),
+    ("pp", "platinum pieces"),
+];
+
+const PF2E_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("AC", "armor class"),
+    ("DC", "difficulty class"),
+    ("STR", "strength"),
+    ("DEX", "dexterity"),
+    ("CON", "constitution"),
+    ("INT", "intelligence"),
+    ("WIS", "wisdom"),
+    ("CHA", "charisma"),
+    ("Str", "strength"),
+    ("Dex", "dexterity"),
+    ("Con", "constitution"),
+    ("Int", "intelligence"),
+    ("Wis", "wisdom"),
+    ("Cha", "charisma"),
+    ("Fort", "Fortitude"),
+    ("Ref", "Reflex"),
+    ("gp", "gold pieces"),
+    ("sp", "silver pieces"),
+    ("cp", "copper pieces"),
+    ("pp", "platinum pieces"),
+];
+
+const COC_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("SAN", "sanity"),
+    ("STR", "strength"),
+    ("CON", "constitution"),
+    ("SIZ", "size"),
+    ("DEX", "dexterity"),
+    ("APP", "appearance"),
+    ("INT", "intelligence"),
+    ("POW", "power"),
+    ("EDU", "education"),
+    ("MP", "magic points"),
+];
+
+const CYBERPUNK_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("INT", "intelligence"),
+    ("REF", "reflexes"),
+    ("DEX", "dexterity"),
+    ("TECH", "tech"),
+    ("COOL", "cool"),
+    ("WILL", "willpower"),
+    ("MOVE", "move"),
+    ("BODY", "body"),
+    ("EMP", "empathy"),
+    ("SP", "stopping power"),
+    ("DV", "difficulty value"),
+    ("eb", "eurobucks"),
+];
+
+const SHADOWRUN_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("BOD", "body"),
+    ("AGI", "agility"),
+    ("REA", "reaction"),
+    ("STR", "strength"),
+    ("WIL", "willpower"),
+    ("LOG", "logic"),
+    ("INT", "intuition"),
+    ("CHA", "charisma"),
+    ("EDG", "edge"),
+    ("ESS", "essence"),
+    ("MAG", "magic"),
+    ("RES", "resonance"),
+];
+
+/// Built-in abbreviations for a game system (system-specific entries win)
+pub fn system_abbreviations(game_system: Option<&str>) -> HashMap<String, String> {
+    let specific: &[(&str, &str)] = match game_system.map(GameSystem::from_str) {
+        Some(GameSystem::DnD5e) => DND5E_ABBREVIATIONS,
+        Some(GameSystem::Pathfinder2e) => PF2E_ABBREVIATIONS,
+        Some(GameSystem::CallOfCthulhu) => COC_ABBREVIATIONS,
+        Some(GameSystem::Cyberpunk) => CYBERPUNK_ABBREVIATIONS,
+        Some(GameSystem::Shadowrun) => SHADOWRUN_ABBREVIATIONS,
+        _ => &[],
+    };
+
+    COMMON_ABBREVIATIONS
+        .iter()
+        .chain(specific)
+        .map(|(abbr, spoken)| (abbr.to_string(), spoken.to_string()))
+        .collect()
+}
+
+// ============================================================================
+// Normalizer
+// ============================================================================
+
+/// Rewrites text into something a TTS engine reads naturally
+#[derive(Debug, Clone)]
+pub struct TextNormalizer {
+    enabled: bool,
+    /// (pattern, replacement), longest abbreviation first
+    abbreviations: Vec<(Regex, String)>,
+}
+
+impl Default for TextNormalizer {
+    fn default() -> Self {
+        Self::new(&TextNormalizationConfig::default())
+    }
+}
+
+impl TextNormalizer {
+    pub fn new(config: &TextNormalizationConfig) -> Self {
+        let mut table = system_abbreviations(config.game_system.as_deref());
+        table.extend(
+            config
+                .abbreviations
+                .iter()
+                .filter(|(abbr, _)| !abbr.trim().is_empty())
+                .map(|(abbr, spoken)| (abbr.clone(), spoken.clone())),
+        );
+
+        let mut entries: Vec<(String, String)> = table.into_iter().collect();
+        entries.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+
+        let abbreviations = entries
+            .into_iter()
+            .filter_map(|(abbr, spoken)| {
+                // A trailing "." is part of the abbreviation, so only bound word endings
+                let end = if abbr.ends_with(|c: char| c.is_alphanumeric()) { r"\b" } else { "" };
+                let pattern = format!(r"\b{}{}", regex::escape(&abbr), end);
+                Regex::new(&pattern).ok().map(|re| (re, spoken))
+            })
+            .collect();
+
+        Self { enabled: config.enabled, abbreviations }
+    }
+
+    /// Expand dice notation, modifiers, and abbreviations into words
+    pub fn normalize(&self, text: &str) -> String {
+        if !self.enabled {
+            return text.to_string();
+        }
+
+        let mut result = DICE_RE.replace_all(text, expand_dice).into_owned();
+        result = HP_FRACTION_RE
+            .replace_all(&result, "hit points $2 out of $3")
+            .into_owned();
+        result = PER_PERIOD_RE.replace_all(&result, "$1 per $2").into_owned();
+        result = MODIFIER_RE
+            .replace_all(&result, |caps: &Captures| {
+                format!("{}{} {}", &caps[1], sign_word(&caps[2]), &caps[3])
+            })
+            .into_owned();
+
+        for (re, spoken) in &self.abbreviations {
+            result = re.replace_all(&result, regex::NoExpand(spoken)).into_owned();
+        }
+
+        MULTI_SPACE_RE.replace_all(&result, " ").into_owned()
+    }
+}
+
+fn sign_word(sign: &str) -> &'static str {
+    if sign == "+" {
+        "plus"
+    } else {
+        "minus"
+    }
+}
+
+/// "2d6+1" -> "two d six plus 1"
+fn expand_dice(caps: &Captures) -> String {
+    let sides = match &caps[2] {
+        "%" => "percent".to_string(),
+        n => number_words(n),
+    };
+    let mut spoken = match &caps[1] {
+        "" => format!("d {}", sides),
+        count => format!("{} d {}", number_words(count), sides),
+    };
+    if let (Some(sign), Some(bonus)) = (caps.get(3), caps.get(4)) {
+        spoken.push_str(&format!(" {} {}", sign_word(sign.as_str()), bonus.as_str()));
+    }
+    spoken
+}
+
+/// Spell out small numbers (dice counts and sides); larger ones stay as digits
+fn number_words(digits: &str) -> String {
+    const ONES: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    match digits.parse::<usize>() {
+        Ok(n) if n < 20 => ONES[n].to_string(),
+        Ok(n) if n < 100 && n % 10 == 0 => TENS[n / 10].to_string(),
+        Ok(n) if n < 100 => format!("{} {}", TENS[n / 10], ONES[n % 10]),
+        Ok(100) => "one hundred".to_string(),
+        _ => digits.to_string(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalizer(system: Option<&str>) -> TextNormalizer {
+        TextNormalizer::new(&TextNormalizationConfig {
+            game_system: system.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_dice_and_modifiers() {
+        let n = normalizer(Some("dnd5e"));
+        assert_eq!(
+            n.normalize("The ogre hits for 2d6+4 damage. Roll a d20 (+5)."),
+            "The ogre hits for two d six plus 4 damage. Roll a d twenty (plus 5)."
+        );
+        assert_eq!(n.normalize("Roll d% then 1d100 - 2"), "Roll d percent then one d one hundred minus 2");
+        // Ranges and hyphenated words are left alone
+        assert_eq!(n.normalize("5-10 goblins, a half-orc"), "5-10 goblins, a half-orc");
+    }
+
+    #[test]
+    fn test_stats_and_hit_points() {
+        let n = normalizer(Some("dnd5e"));
+        assert_eq!(
+            n.normalize("AC 15, HP 22/30, DC 13 WIS save, 3/day, 30 ft. away"),
+            "armor class 15, hit points 22 out of 30, difficulty class 13 wisdom save, 3 per day, 30 feet away"
+        );
+        // Abbreviations only match whole words
+        assert_eq!(n.normalize("ACTION and CONTACT"), "ACTION and CONTACT");
+    }
+
+    #[test]
+    fn test_abbreviations_depend_on_system() {
+        assert_eq!(normalizer(Some("dnd5e")).normalize("INT 14"), "intelligence 14");
+        assert_eq!(normalizer(Some("shadowrun")).normalize("INT 4"), "intuition 4");
+        assert_eq!(normalizer(Some("coc")).normalize("Lose 1d4 SAN"), "Lose one d four sanity");
+        // Without a system only shared abbreviations apply
+        assert_eq!(normalizer(None).normalize("AC 12, 5 XP"), "AC 12, 5 experience points");
+    }
+
+    #[test]
+    fn test_user_overrides_and_disable() {
+        let mut abbreviations = HashMap::new();
+        abbreviations.insert("AC".to_string(), "armour class".to_string());
+        abbreviations.insert("BBEG".to_string(), "big bad".to_string());
+        let n = TextNormalizer::new(&TextNormalizationConfig {
+            game_system: Some("5e".to_string()),
+            abbreviations,
+            ..Default::default()
+        });
+        assert_eq!(n.normalize("The BBEG has AC 18"), "The big bad has armour class 18");
+
+        let off = TextNormalizer::new(&TextNormalizationConfig { enabled: false, ..Default::default() });
+        assert_eq!(off.normalize("1d8+3"), "1d8+3");
+    }
+
+    #[test]
+    fn test_normalize_is_idempotent() {
+        let n = normalizer(Some("pf2e"));
+        let once = n.normalize("Strike: +9 to hit, 1d8+4 piercing, DC 20 Fort, HP 45/60");
+        assert_eq!(n.normalize(&once), once);
+    }
+}
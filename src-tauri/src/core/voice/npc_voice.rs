@@ -0,0 +1,318 @@
+//! NPC Voice Assignment
+//!
+//! Resolves a consistent voice profile for each NPC. When an NPC has no
+//! linked profile, one is chosen automatically by matching the NPC's apparent
+//! age, gender, and personality traits against the available profiles.
+//! Pronunciation overrides are applied to dialogue before synthesis.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::profiles::{AgeRange, Gender, VoiceProfile};
+use super::types::VoiceProviderType;
+use crate::core::npc_gen::NPC;
+
+// ============================================================================
+// NPC Voice Hints
+// ============================================================================
+
+/// Voice-relevant characteristics extracted from an NPC
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NpcVoiceHints {
+    /// Apparent age range
+    pub age_range: Option<AgeRange>,
+    /// Apparent gender (if it can be inferred)
+    pub gender: Option<Gender>,
+    /// Lowercased personality traits and demeanor words
+    pub traits: Vec<String>,
+}
+
+impl NpcVoiceHints {
+    /// Extract voice hints from an NPC's appearance, tags, and personality
+    pub fn from_npc(npc: &NPC) -> Self {
+        let mut traits: Vec<String> = npc
+            .personality
+            .traits
+            .iter()
+            .chain(npc.personality.mannerisms.iter())
+            .map(|t| t.to_lowercase())
+            .collect();
+
+        if !npc.appearance.demeanor.is_empty() {
+            traits.push(npc.appearance.demeanor.to_lowercase());
+        }
+        if !npc.voice.pitch.is_empty() {
+            traits.push(npc.voice.pitch.to_lowercase());
+        }
+
+        Self {
+            age_range: parse_age_range(&npc.appearance.age),
+            gender: infer_gender(&npc.tags),
+            traits,
+        }
+    }
+}
+
+/// Parse a free-form age description ("Elderly", "Young adult", "42") into an age range
+pub fn parse_age_range(age: &str) -> Option<AgeRange> {
+    let lower = age.trim().to_lowercase();
+    if lower.is_empty() {
+        return None;
+    }
+
+    if let Ok(years) = lower.split_whitespace().next().unwrap_or("").parse::<u32>() {
+        return Some(match years {
+            0..=12 => AgeRange::Child,
+            13..=25 => AgeRange::YoungAdult,
+            26..=45 => AgeRange::Adult,
+            46..=65 => AgeRange::MiddleAged,
+            _ => AgeRange::Elderly,
+        });
+    }
+
+    if lower.contains("child") || lower.contains("kid") {
+        Some(AgeRange::Child)
+    } else if lower.contains("young") || lower.contains("teen") || lower.contains("youth") {
+        Some(AgeRange::YoungAdult)
+    } else if lower.contains("middle") {
+        Some(AgeRange::MiddleAged)
+    } else if lower.contains("elder") || lower.contains("old") || lower.contains("ancient") || lower.contains("venerable") {
+        Some(AgeRange::Elderly)
+    } else if lower.contains("adult") {
+        Some(AgeRange::Adult)
+    } else {
+        None
+    }
+}
+
+/// Infer gender from NPC tags (e.g., "male", "female", "she/her")
+fn infer_gender(tags: &[String]) -> Option<Gender> {
+    tags.iter().find_map(|tag| match tag.to_lowercase().as_str() {
+        "male" | "man" | "he/him" => Some(Gender::Male),
+        "female" | "woman" | "she/her" => Some(Gender::Female),
+        "nonbinary" | "non-binary" | "they/them" => Some(Gender::NonBinary),
+        _ => None,
+    })
+}
+
+// ============================================================================
+// Automatic Assignment
+// ============================================================================
+
+/// Ordinal position of an age range, used for adjacency scoring
+fn age_ordinal(age: &AgeRange) -> i32 {
+    match age {
+        AgeRange::Child => 0,
+        AgeRange::YoungAdult => 1,
+        AgeRange::Adult => 2,
+        AgeRange::MiddleAged => 3,
+        AgeRange::Elderly => 4,
+    }
+}
+
+/// Score how well a profile matches the NPC hints (higher is better)
+pub fn score_profile(hints: &NpcVoiceHints, profile: &VoiceProfile) -> i32 {
+    let mut score = 0;
+
+    if let Some(gender) = &hints.gender {
+        if &profile.metadata.gender == gender {
+            score += 4;
+        } else if profile.metadata.gender == Gender::Neutral {
+            score += 1;
+        } else {
+            score -= 4;
+        }
+    }
+
+    if let Some(age) = &hints.age_range {
+        let distance = (age_ordinal(age) - age_ordinal(&profile.metadata.age_range)).abs();
+        score += match distance {
+            0 => 3,
+            1 => 1,
+            _ => -distance,
+        };
+    }
+
+    for npc_trait in &hints.traits {
+        if profile
+            .metadata
+            .personality_traits
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(npc_trait))
+        {
+            score += 2;
+        }
+    }
+
+    score
+}
+
+/// Choose the best profile for an NPC from a set of candidates.
+///
+/// Profiles already used by many NPCs are penalised so a cast of characters
+/// gets varied voices. Ties are broken deterministically by the NPC ID, so the
+/// same NPC always lands on the same voice for the same candidate set.
+pub fn auto_assign_profile<'a>(
+    npc_id: &str,
+    hints: &NpcVoiceHints,
+    candidates: &[&'a VoiceProfile],
+    usage: &HashMap<String, usize>,
+) -> Option<&'a VoiceProfile> {
+    let scored: Vec<(i32, &'a VoiceProfile)> = candidates
+        .iter()
+        .map(|p| {
+            let used = usage.get(&p.id).copied().unwrap_or(0) as i32;
+            (score_profile(hints, p) - used, *p)
+        })
+        .collect();
+
+    let best = scored.iter().map(|(s, _)| *s).max()?;
+    let mut top: Vec<&'a VoiceProfile> = scored
+        .into_iter()
+        .filter(|(s, _)| *s == best)
+        .map(|(_, p)| p)
+        .collect();
+    top.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut hasher = DefaultHasher::new();
+    npc_id.hash(&mut hasher);
+    let index = (hasher.finish() as usize) % top.len();
+    top.get(index).copied()
+}
+
+/// Build the voice ID for a profile, prefixed with its provider when the
+/// VoiceManager can route on the prefix (e.g., "openai:onyx").
+pub fn qualified_voice_id(profile: &VoiceProfile) -> String {
+    let prefix = match profile.provider {
+        VoiceProviderType::Piper => Some("piper"),
+        VoiceProviderType::Coqui => Some("coqui"),
+        VoiceProviderType::ElevenLabs => Some("elevenlabs"),
+        VoiceProviderType::OpenAI => Some("openai"),
+        VoiceProviderType::Ollama => Some("ollama"),
+        VoiceProviderType::FishAudio => Some("fish_audio"),
+        VoiceProviderType::XttsV2 => Some("xtts_v2"),
+        _ => None,
+    };
+
+    match prefix {
+        Some(p) if !profile.voice_id.starts_with(&format!("{}:", p)) => {
+            format!("{}:{}", p, profile.voice_id)
+        }
+        _ => profile.voice_id.clone(),
+    }
+}
+
+// ============================================================================
+// Pronunciation Overrides
+// ============================================================================
+
+/// Apply pronunciation overrides to text as whole-word, case-insensitive replacements.
+///
+/// Longer words are replaced first so multi-word names win over their parts.
+pub fn apply_pronunciations(text: &str, overrides: &HashMap<String, String>) -> String {
+    let mut words: Vec<(&String, &String)> = overrides.iter().filter(|(w, _)| !w.trim().is_empty()).collect();
+    words.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+
+    let mut result = text.to_string();
+    for (word, spoken) in words {
+        let pattern = format!(r"(?i)\b{}\b", regex::escape(word));
+        if let Ok(re) = Regex::new(&pattern) {
+            result = re.replace_all(&result, regex::NoExpand(spoken)).into_owned();
+        }
+    }
+    result
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::profiles::ProfileMetadata;
+
+    fn profile(id: &str, age: AgeRange, gender: Gender, traits: &[&str]) -> VoiceProfile {
+        VoiceProfile::preset(
+            id,
+            id,
+            VoiceProviderType::OpenAI,
+            "alloy",
+            ProfileMetadata::new(age, gender).with_traits(traits),
+        )
+    }
+
+    #[test]
+    fn test_parse_age_range() {
+        assert_eq!(parse_age_range("Elderly"), Some(AgeRange::Elderly));
+        assert_eq!(parse_age_range("Young adult"), Some(AgeRange::YoungAdult));
+        assert_eq!(parse_age_range("Middle-aged"), Some(AgeRange::MiddleAged));
+        assert_eq!(parse_age_range("52 years"), Some(AgeRange::MiddleAged));
+        assert_eq!(parse_age_range(""), None);
+    }
+
+    #[test]
+    fn test_auto_assign_prefers_matching_profile() {
+        let old_man = profile("old-man", AgeRange::Elderly, Gender::Male, &["wise"]);
+        let young_woman = profile("young-woman", AgeRange::YoungAdult, Gender::Female, &["cheerful"]);
+        let candidates = vec![&old_man, &young_woman];
+
+        let hints = NpcVoiceHints {
+            age_range: Some(AgeRange::Elderly),
+            gender: Some(Gender::Male),
+            traits: vec!["wise".to_string()],
+        };
+
+        let chosen = auto_assign_profile("npc-1", &hints, &candidates, &HashMap::new()).unwrap();
+        assert_eq!(chosen.id, "old-man");
+    }
+
+    #[test]
+    fn test_auto_assign_is_deterministic() {
+        let a = profile("a", AgeRange::Adult, Gender::Neutral, &[]);
+        let b = profile("b", AgeRange::Adult, Gender::Neutral, &[]);
+        let candidates = vec![&a, &b];
+        let hints = NpcVoiceHints::default();
+
+        let first = auto_assign_profile("npc-42", &hints, &candidates, &HashMap::new()).unwrap();
+        let second = auto_assign_profile("npc-42", &hints, &candidates, &HashMap::new()).unwrap();
+        assert_eq!(first.id, second.id);
+    }
+
+    #[test]
+    fn test_auto_assign_spreads_usage() {
+        let a = profile("a", AgeRange::Adult, Gender::Male, &[]);
+        let b = profile("b", AgeRange::Adult, Gender::Male, &[]);
+        let candidates = vec![&a, &b];
+        let hints = NpcVoiceHints {
+            gender: Some(Gender::Male),
+            ..Default::default()
+        };
+
+        let mut usage = HashMap::new();
+        usage.insert("a".to_string(), 3);
+        let chosen = auto_assign_profile("npc-1", &hints, &candidates, &usage).unwrap();
+        assert_eq!(chosen.id, "b");
+    }
+
+    #[test]
+    fn test_qualified_voice_id() {
+        let p = profile("x", AgeRange::Adult, Gender::Male, &[]);
+        assert_eq!(qualified_voice_id(&p), "openai:alloy");
+    }
+
+    #[test]
+    fn test_apply_pronunciations() {
+        let mut overrides = HashMap::new();
+        overrides.insert("Xanathar".to_string(), "ZAN-uh-thar".to_string());
+        overrides.insert("Waterdeep".to_string(), "Water deep".to_string());
+
+        let text = "xanathar rules below Waterdeep. Xanatharian is untouched.";
+        let result = apply_pronunciations(text, &overrides);
+        assert_eq!(result, "ZAN-uh-thar rules below Water deep. Xanatharian is untouched.");
+    }
+}
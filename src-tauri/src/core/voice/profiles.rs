@@ -78,6 +78,9 @@ pub struct ProfileMetadata {
     pub description: Option<String>,
     /// Tags for categorization
     pub tags: Vec<String>,
+    /// Pronunciation overrides applied before synthesis (word -> respelling)
+    #[serde(default)]
+    pub pronunciations: HashMap<String, String>,
 }
 
 impl ProfileMetadata {
@@ -90,6 +93,7 @@ impl ProfileMetadata {
             linked_npc_ids: Vec::new(),
             description: None,
             tags: Vec::new(),
+            pronunciations: HashMap::new(),
         }
     }
 
@@ -116,6 +120,12 @@ impl ProfileMetadata {
         self.tags.push(tag.to_string());
         self
     }
+
+    /// Add a pronunciation override (e.g., "Xanathar" -> "ZAN-uh-thar")
+    pub fn with_pronunciation(mut self, word: &str, spoken_as: &str) -> Self {
+        self.pronunciations.insert(word.to_string(), spoken_as.to_string());
+        self
+    }
 }
 
 /// A complete voice profile configuration
@@ -406,6 +416,15 @@ impl VoiceProfileManager {
         self.npc_to_profile.get(npc_id)
     }
 
+    /// Count how many NPCs are linked to each profile
+    pub fn usage_counts(&self) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for profile_id in self.npc_to_profile.values() {
+            *counts.entry(profile_id.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
     /// Search profiles by name or traits
     pub fn search(&self, query: &str) -> Vec<&VoiceProfile> {
         let query_lower = query.to_lowercase();
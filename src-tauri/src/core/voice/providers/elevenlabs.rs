@@ -3,6 +3,7 @@ use reqwest::Client;
 use serde_json::json;
 use crate::core::voice::types::{Result, SynthesisRequest, Voice, UsageInfo, VoiceError, ElevenLabsConfig};
 use crate::core::voice::providers::VoiceProvider;
+use crate::core::voice::streaming::{forward_pcm_response, AudioChunkSender, STREAM_PCM_SAMPLE_RATE};
 
 pub struct ElevenLabsProvider {
     client: Client,
@@ -16,25 +17,12 @@ impl ElevenLabsProvider {
             config,
         }
     }
-}
-
-#[async_trait]
-impl VoiceProvider for ElevenLabsProvider {
-    fn id(&self) -> &'static str {
-        "elevenlabs"
-    }
-
-    async fn synthesize(&self, request: &SynthesisRequest) -> Result<Vec<u8>> {
-        let url = format!(
-            "https://api.elevenlabs.io/v1/text-to-speech/{}",
-            request.voice_id
-        );
 
+    fn request_body(&self, request: &SynthesisRequest) -> serde_json::Value {
         let settings = request.settings.clone().unwrap_or_default();
-
         let model_id = self.config.model_id.as_deref().unwrap_or("eleven_monolingual_v1");
 
-        let body = json!({
+        json!({
             "text": request.text,
             "model_id": model_id,
             "voice_settings": {
@@ -43,17 +31,10 @@ impl VoiceProvider for ElevenLabsProvider {
                 "style": settings.style,
                 "use_speaker_boost": settings.use_speaker_boost
             }
-        });
-
-        let response = self.client
-            .post(&url)
-            .header("xi-api-key", &self.config.api_key)
-            .header("Content-Type", "application/json")
-            .header("Accept", request.output_format.mime_type())
-            .json(&body)
-            .send()
-            .await?;
+        })
+    }
 
+    async fn check_response(response: reqwest::Response) -> Result<reqwest::Response> {
         if response.status() == 429 {
             return Err(VoiceError::RateLimitExceeded);
         }
@@ -64,14 +45,117 @@ impl VoiceProvider for ElevenLabsProvider {
 
         if !response.status().is_success() {
             let error_text = response.text().await.unwrap_or_default();
+            if error_text.contains("quota_exceeded") {
+                return Err(VoiceError::QuotaExceeded);
+            }
             return Err(VoiceError::ApiError(format!(
                 "ElevenLabs API error: {}", error_text
             )));
         }
 
+        Ok(response)
+    }
+
+    /// Create an instant voice clone from reference samples; returns the new voice ID
+    pub async fn create_instant_clone(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        samples: &[std::path::PathBuf],
+    ) -> Result<String> {
+        let mut form = reqwest::multipart::Form::new().text("name", name.to_string());
+        if let Some(description) = description {
+            form = form.text("description", description.to_string());
+        }
+
+        for path in samples {
+            let bytes = tokio::fs::read(path).await?;
+            let file_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "sample.wav".to_string());
+            form = form.part("files", reqwest::multipart::Part::bytes(bytes).file_name(file_name));
+        }
+
+        let response = self.client
+            .post("https://api.elevenlabs.io/v1/voices/add")
+            .header("xi-api-key", &self.config.api_key)
+            .multipart(form)
+            .send()
+            .await?;
+
+        let response = Self::check_response(response).await?;
+        let data: serde_json::Value = response.json().await?;
+
+        data["voice_id"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| VoiceError::ApiError("ElevenLabs did not return a voice ID".to_string()))
+    }
+
+    /// Delete a voice (e.g., a cloned voice) from the account
+    pub async fn delete_voice(&self, voice_id: &str) -> Result<()> {
+        let response = self.client
+            .delete(format!("https://api.elevenlabs.io/v1/voices/{}", voice_id))
+            .header("xi-api-key", &self.config.api_key)
+            .send()
+            .await?;
+
+        Self::check_response(response).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VoiceProvider for ElevenLabsProvider {
+    fn id(&self) -> &'static str {
+        "elevenlabs"
+    }
+
+    async fn synthesize(&self, request: &SynthesisRequest) -> Result<Vec<u8>> {
+        let url = format!(
+            "https://api.elevenlabs.io/v1/text-to-speech/{}",
+            request.voice_id
+        );
+
+        let body = self.request_body(request);
+
+        let response = self.client
+            .post(&url)
+            .header("xi-api-key", &self.config.api_key)
+            .header("Content-Type", "application/json")
+            .header("Accept", request.output_format.mime_type())
+            .json(&body)
+            .send()
+            .await?;
+
+        let response = Self::check_response(response).await?;
+
         Ok(response.bytes().await?.to_vec())
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn synthesize_stream(&self, request: &SynthesisRequest, tx: &AudioChunkSender) -> Result<()> {
+        let url = format!(
+            "https://api.elevenlabs.io/v1/text-to-speech/{}/stream?output_format=pcm_{}",
+            request.voice_id, STREAM_PCM_SAMPLE_RATE
+        );
+
+        let response = self.client
+            .post(&url)
+            .header("xi-api-key", &self.config.api_key)
+            .header("Content-Type", "application/json")
+            .json(&self.request_body(request))
+            .send()
+            .await?;
+
+        let response = Self::check_response(response).await?;
+        forward_pcm_response(response, STREAM_PCM_SAMPLE_RATE, tx).await
+    }
+
     async fn list_voices(&self) -> Result<Vec<Voice>> {
         let response = self.client
             .get("https://api.elevenlabs.io/v1/voices")
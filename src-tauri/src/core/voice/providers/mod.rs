@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use super::types::{Result, SynthesisRequest, Voice, UsageInfo};
+use super::types::{Result, SynthesisRequest, Voice, UsageInfo, VoiceError};
+use super::streaming::AudioChunkSender;
 
 // Cloud providers
 pub mod elevenlabs;
@@ -37,4 +38,17 @@ pub trait VoiceProvider: Send + Sync {
 
     /// Check usage quotas
     async fn check_usage(&self) -> Result<UsageInfo>;
+
+    /// Whether this provider can stream audio natively while it is generated
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    /// Stream synthesized audio into `tx` as it is generated.
+    ///
+    /// Only called when `supports_streaming()` returns true; other providers
+    /// are streamed sentence-by-sentence by the VoiceManager.
+    async fn synthesize_stream(&self, _request: &SynthesisRequest, _tx: &AudioChunkSender) -> Result<()> {
+        Err(VoiceError::NotConfigured(format!("Provider {} does not support streaming", self.id())))
+    }
 }
@@ -3,6 +3,7 @@ use reqwest::Client;
 use serde_json::json;
 use crate::core::voice::types::{Result, SynthesisRequest, Voice, UsageInfo, VoiceError, OpenAIVoiceConfig};
 use crate::core::voice::providers::VoiceProvider;
+use crate::core::voice::streaming::{forward_pcm_response, AudioChunkSender, STREAM_PCM_SAMPLE_RATE};
 
 pub struct OpenAIVoiceProvider {
     client: Client,
@@ -16,6 +17,53 @@ impl OpenAIVoiceProvider {
             config,
         }
     }
+
+    /// Send a speech request with the given response format and validate the status
+    async fn send_speech_request(&self, request: &SynthesisRequest, response_format: &str) -> Result<reqwest::Response> {
+        let url = "https://api.openai.com/v1/audio/speech";
+
+        // Use voice_id from request, or fall back to config
+        let voice = if request.voice_id == "default" {
+            &self.config.voice
+        } else {
+            &request.voice_id
+        };
+
+        let body = json!({
+            "model": self.config.model,
+            "input": request.text,
+            "voice": voice,
+            "response_format": response_format
+        });
+
+        let response = self.client
+            .post(url)
+            .header("Authorization", format!("Bearer {}", self.config.api_key))
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send()
+            .await?;
+
+        if response.status() == 429 {
+            return Err(VoiceError::RateLimitExceeded);
+        }
+
+        if response.status() == 401 {
+            return Err(VoiceError::ApiError("Invalid API key".to_string()));
+        }
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            if error_text.contains("insufficient_quota") {
+                return Err(VoiceError::QuotaExceeded);
+            }
+            return Err(VoiceError::ApiError(format!(
+                "OpenAI TTS API error: {}", error_text
+            )));
+        }
+
+        Ok(response)
+    }
 }
 
 /// OpenAI TTS voice info
@@ -49,15 +97,6 @@ impl VoiceProvider for OpenAIVoiceProvider {
     }
 
     async fn synthesize(&self, request: &SynthesisRequest) -> Result<Vec<u8>> {
-        let url = "https://api.openai.com/v1/audio/speech";
-
-        // Use voice_id from request, or fall back to config
-        let voice = if request.voice_id == "default" {
-            &self.config.voice
-        } else {
-            &request.voice_id
-        };
-
         let response_format = match request.output_format {
             crate::core::voice::types::OutputFormat::Mp3 => "mp3",
             crate::core::voice::types::OutputFormat::Wav => "wav",
@@ -65,37 +104,18 @@ impl VoiceProvider for OpenAIVoiceProvider {
             crate::core::voice::types::OutputFormat::Pcm => "pcm",
         };
 
-        let body = json!({
-            "model": self.config.model,
-            "input": request.text,
-            "voice": voice,
-            "response_format": response_format
-        });
-
-        let response = self.client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", self.config.api_key))
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
-
-        if response.status() == 429 {
-            return Err(VoiceError::RateLimitExceeded);
-        }
-
-        if response.status() == 401 {
-            return Err(VoiceError::ApiError("Invalid API key".to_string()));
-        }
+        let response = self.send_speech_request(request, response_format).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(VoiceError::ApiError(format!(
-                "OpenAI TTS API error: {}", error_text
-            )));
-        }
+    fn supports_streaming(&self) -> bool {
+        true
+    }
 
-        Ok(response.bytes().await?.to_vec())
+    async fn synthesize_stream(&self, request: &SynthesisRequest, tx: &AudioChunkSender) -> Result<()> {
+        // "pcm" is raw 24kHz s16le mono, delivered with chunked transfer encoding
+        let response = self.send_speech_request(request, "pcm").await?;
+        forward_pcm_response(response, STREAM_PCM_SAMPLE_RATE, tx).await
     }
 
     async fn list_voices(&self) -> Result<Vec<Voice>> {
@@ -55,8 +55,14 @@ impl VoiceProvider for XttsV2Provider {
         // Try the TTS API endpoint
         let url = format!("{}/api/tts", self.config.base_url);
 
+        // Cloned voices use their reference file as the voice ID; otherwise
+        // fall back to the configured speaker WAV
+        let speaker_wav = Some(request.voice_id.clone())
+            .filter(|id| std::path::Path::new(id).is_file())
+            .or_else(|| self.config.speaker_wav.clone());
+
         // If we have a speaker WAV, use multipart
-        if let Some(ref speaker_path) = self.config.speaker_wav {
+        if let Some(ref speaker_path) = speaker_wav {
             let audio_bytes = tokio::fs::read(speaker_path).await?;
 
             let form = multipart::Form::new()
@@ -0,0 +1,315 @@
+//! Session Read-Aloud Pre-Generation
+//!
+//! Read-aloud passages (boxed text from an adventure outline) can be
+//! synthesized ahead of a session. Generated audio is copied out of the voice
+//! cache into a per-session directory so it survives cache eviction and plays
+//! back instantly without a network connection.
+//!
+//! Layout under the store root:
+//!
+//! ```text
+//! <root>/<session_id>/manifest.json
+//! <root>/<session_id>/<passage_id>.mp3
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::types::{Result, VoiceError};
+
+/// Manifest file inside each session directory
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Generation state of a passage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadAloudStatus {
+    #[default]
+    Pending,
+    Ready,
+    Failed(String),
+}
+
+/// A passage to pre-generate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadAloudRequest {
+    /// Optional label, e.g. "Chapter 2: The Sunken Crypt"
+    pub title: Option<String>,
+    pub text: String,
+    /// Voice to read with; falls back to the batch default
+    pub voice_id: Option<String>,
+}
+
+/// A passage stored against a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadAloudPassage {
+    pub id: String,
+    pub title: Option<String>,
+    pub text: String,
+    pub voice_id: String,
+    pub status: ReadAloudStatus,
+    /// Session-local copy of the audio (set once ready)
+    pub audio_path: Option<PathBuf>,
+    pub generated_at: Option<DateTime<Utc>>,
+}
+
+impl ReadAloudPassage {
+    /// Whether the stored audio can be played right now
+    pub fn is_playable(&self) -> bool {
+        self.status == ReadAloudStatus::Ready
+            && self.audio_path.as_ref().is_some_and(|p| p.exists())
+    }
+}
+
+/// All read-aloud passages for one session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionReadAloud {
+    pub session_id: String,
+    pub passages: Vec<ReadAloudPassage>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SessionReadAloud {
+    pub fn new(session_id: &str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            passages: Vec::new(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Add passages, returning the IDs of those that need synthesis
+    ///
+    /// A passage with the same text and voice as an existing one is not added
+    /// again; it is only re-queued if its audio is missing or failed.
+    pub fn add_passages(&mut self, requests: Vec<ReadAloudRequest>, default_voice_id: &str) -> Vec<String> {
+        let mut to_generate = Vec::new();
+
+        for request in requests {
+            let text = request.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let voice_id = request.voice_id.unwrap_or_else(|| default_voice_id.to_string());
+
+            if let Some(existing) = self
+                .passages
+                .iter_mut()
+                .find(|p| p.text == text && p.voice_id == voice_id)
+            {
+                if request.title.is_some() {
+                    existing.title = request.title;
+                }
+                if !existing.is_playable() && !to_generate.contains(&existing.id) {
+                    existing.status = ReadAloudStatus::Pending;
+                    to_generate.push(existing.id.clone());
+                }
+                continue;
+            }
+
+            let passage = ReadAloudPassage {
+                id: Uuid::new_v4().to_string(),
+                title: request.title,
+                text: text.to_string(),
+                voice_id,
+                status: ReadAloudStatus::Pending,
+                audio_path: None,
+                generated_at: None,
+            };
+            to_generate.push(passage.id.clone());
+            self.passages.push(passage);
+        }
+
+        self.updated_at = Utc::now();
+        to_generate
+    }
+
+    pub fn get(&self, passage_id: &str) -> Option<&ReadAloudPassage> {
+        self.passages.iter().find(|p| p.id == passage_id)
+    }
+
+    /// Record generated audio for a passage
+    pub fn mark_ready(&mut self, passage_id: &str, audio_path: PathBuf) {
+        if let Some(p) = self.passages.iter_mut().find(|p| p.id == passage_id) {
+            p.status = ReadAloudStatus::Ready;
+            p.audio_path = Some(audio_path);
+            p.generated_at = Some(Utc::now());
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Record a synthesis failure for a passage
+    pub fn mark_failed(&mut self, passage_id: &str, error: String) {
+        if let Some(p) = self.passages.iter_mut().find(|p| p.id == passage_id) {
+            p.status = ReadAloudStatus::Failed(error);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Remove a passage; returns it so its audio can be deleted
+    pub fn remove(&mut self, passage_id: &str) -> Option<ReadAloudPassage> {
+        let index = self.passages.iter().position(|p| p.id == passage_id)?;
+        self.updated_at = Utc::now();
+        Some(self.passages.remove(index))
+    }
+
+    /// Number of passages that are ready to play
+    pub fn ready_count(&self) -> usize {
+        self.passages.iter().filter(|p| p.status == ReadAloudStatus::Ready).count()
+    }
+}
+
+// ============================================================================
+// Store
+// ============================================================================
+
+/// On-disk store of pre-generated session audio
+#[derive(Debug, Clone)]
+pub struct ReadAloudStore {
+    root: PathBuf,
+}
+
+impl ReadAloudStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Directory holding a session's manifest and audio
+    pub fn session_dir(&self, session_id: &str) -> PathBuf {
+        let safe: String = session_id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.root.join(safe)
+    }
+
+    /// Where a passage's audio is stored
+    pub fn audio_path(&self, session_id: &str, passage_id: &str, extension: &str) -> PathBuf {
+        self.session_dir(session_id).join(format!("{}.{}", passage_id, extension))
+    }
+
+    /// Load a session's passages (empty if nothing was generated yet)
+    pub fn load(&self, session_id: &str) -> Result<SessionReadAloud> {
+        let path = self.session_dir(session_id).join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(SessionReadAloud::new(session_id));
+        }
+        let data = std::fs::read(&path)?;
+        serde_json::from_slice(&data).map_err(|e| VoiceError::IoError(std::io::Error::other(e)))
+    }
+
+    /// Save a session's passages
+    pub fn save(&self, manifest: &SessionReadAloud) -> Result<()> {
+        let dir = self.session_dir(&manifest.session_id);
+        std::fs::create_dir_all(&dir)?;
+        let json = serde_json::to_vec_pretty(manifest)
+            .map_err(|e| VoiceError::IoError(std::io::Error::other(e)))?;
+        std::fs::write(dir.join(MANIFEST_FILE_NAME), json)?;
+        Ok(())
+    }
+
+    /// Copy synthesized audio into the session directory
+    pub fn store_audio(&self, session_id: &str, passage_id: &str, source: &Path) -> Result<PathBuf> {
+        let extension = source.extension().and_then(|e| e.to_str()).unwrap_or("mp3");
+        let dest = self.audio_path(session_id, passage_id, extension);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(source, &dest)?;
+        Ok(dest)
+    }
+
+    /// Delete all stored audio for a session
+    pub fn delete(&self, session_id: &str) -> Result<()> {
+        let dir = self.session_dir(session_id);
+        if dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn request(text: &str, voice: Option<&str>) -> ReadAloudRequest {
+        ReadAloudRequest {
+            title: None,
+            text: text.to_string(),
+            voice_id: voice.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_add_passages_dedupes_and_uses_default_voice() {
+        let mut session = SessionReadAloud::new("s1");
+        let ids = session.add_passages(
+            vec![
+                request("The door creaks open.", None),
+                request("The door creaks open.", None),
+                request("The door creaks open.", Some("openai:onyx")),
+                request("   ", None),
+            ],
+            "piper:amy",
+        );
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(session.passages.len(), 2);
+        assert_eq!(session.passages[0].voice_id, "piper:amy");
+        assert_eq!(session.passages[1].voice_id, "openai:onyx");
+    }
+
+    #[test]
+    fn test_ready_passages_are_not_regenerated() {
+        let temp = TempDir::new().unwrap();
+        let audio = temp.path().join("clip.mp3");
+        std::fs::write(&audio, b"audio").unwrap();
+
+        let mut session = SessionReadAloud::new("s1");
+        let ids = session.add_passages(vec![request("Rain falls.", None)], "v");
+        session.mark_ready(&ids[0], audio);
+
+        assert!(session.add_passages(vec![request("Rain falls.", None)], "v").is_empty());
+        assert_eq!(session.ready_count(), 1);
+
+        session.mark_failed(&ids[0], "offline".to_string());
+        assert_eq!(session.add_passages(vec![request("Rain falls.", None)], "v"), ids);
+    }
+
+    #[test]
+    fn test_store_round_trip_and_delete() {
+        let temp = TempDir::new().unwrap();
+        let store = ReadAloudStore::new(temp.path().join("read_aloud"));
+
+        let source = temp.path().join("cached.mp3");
+        std::fs::write(&source, b"audio").unwrap();
+
+        let mut session = store.load("session/1").unwrap();
+        assert!(session.passages.is_empty());
+        let ids = session.add_passages(vec![request("Torches gutter.", None)], "v");
+        let stored = store.store_audio("session/1", &ids[0], &source).unwrap();
+        session.mark_ready(&ids[0], stored.clone());
+        store.save(&session).unwrap();
+
+        assert!(stored.starts_with(store.session_dir("session/1")));
+        let loaded = store.load("session/1").unwrap();
+        assert!(loaded.get(&ids[0]).unwrap().is_playable());
+
+        store.delete("session/1").unwrap();
+        assert!(!stored.exists());
+        assert!(store.load("session/1").unwrap().passages.is_empty());
+    }
+}
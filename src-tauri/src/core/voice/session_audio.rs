@@ -0,0 +1,320 @@
+//! Session Audio Log and Export
+//!
+//! Utterances spoken during a session (NPC lines, narration, read-aloud
+//! passages) are logged per session with the path of their synthesized
+//! audio. The log can be stitched into a single MP3/OGG "radio drama" recap,
+//! with a pause between lines and an optional looping music bed underneath.
+//!
+//! Encoding is done with `ffmpeg`, which must be on the `PATH`.
+//!
+//! Layout under the log root:
+//!
+//! ```text
+//! <root>/<session_id>.jsonl
+//! ```
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::types::{OutputFormat, Result, VoiceError};
+
+/// Sample rate every clip is resampled to before concatenation
+const EXPORT_SAMPLE_RATE: u32 = 44_100;
+
+fn default_gap_ms() -> u64 {
+    800
+}
+
+fn default_music_volume() -> f32 {
+    0.15
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A line spoken during a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionUtterance {
+    /// Voice queue item that spoke the line
+    pub queue_id: String,
+    pub text: String,
+    pub voice_id: String,
+    /// Synthesized audio (usually in the voice cache)
+    pub audio_path: PathBuf,
+    pub spoken_at: DateTime<Utc>,
+}
+
+/// Options for stitching a session recap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionExportOptions {
+    /// `mp3` or `ogg`
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Silence between utterances
+    #[serde(default = "default_gap_ms")]
+    pub gap_ms: u64,
+    /// Background music looped under the narration
+    #[serde(default)]
+    pub music_path: Option<PathBuf>,
+    /// Music bed volume (0.0 - 1.0)
+    #[serde(default = "default_music_volume")]
+    pub music_volume: f32,
+}
+
+impl Default for SessionExportOptions {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::Mp3,
+            gap_ms: default_gap_ms(),
+            music_path: None,
+            music_volume: default_music_volume(),
+        }
+    }
+}
+
+/// Result of a session export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionExportSummary {
+    pub session_id: String,
+    pub output_path: PathBuf,
+    pub format: OutputFormat,
+    /// Utterances included in the recap
+    pub utterance_count: usize,
+    /// Utterances whose audio was no longer available
+    pub skipped_count: usize,
+}
+
+// ============================================================================
+// Log
+// ============================================================================
+
+/// Append-only per-session log of spoken utterances
+#[derive(Debug, Clone)]
+pub struct SessionAudioLog {
+    root: PathBuf,
+}
+
+impl SessionAudioLog {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Log file for a session
+    pub fn log_path(&self, session_id: &str) -> PathBuf {
+        let safe: String = session_id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.root.join(format!("{}.jsonl", safe))
+    }
+
+    /// Append an utterance to a session's log
+    pub fn record(&self, session_id: &str, utterance: &SessionUtterance) -> Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        let mut line = serde_json::to_vec(utterance)
+            .map_err(|e| VoiceError::IoError(std::io::Error::other(e)))?;
+        line.push(b'\n');
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.log_path(session_id))?;
+        file.write_all(&line)?;
+        Ok(())
+    }
+
+    /// Utterances spoken in a session, oldest first
+    ///
+    /// Unreadable lines (e.g., a write cut short by a crash) are skipped.
+    pub fn load(&self, session_id: &str) -> Result<Vec<SessionUtterance>> {
+        let path = self.log_path(session_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let data = std::fs::read_to_string(&path)?;
+        let mut utterances: Vec<SessionUtterance> = data
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        utterances.sort_by_key(|u| u.spoken_at);
+        Ok(utterances)
+    }
+
+    /// Forget everything logged for a session (the audio itself is untouched)
+    pub fn clear(&self, session_id: &str) -> Result<()> {
+        let path = self.log_path(session_id);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Build the `ffmpeg` arguments that stitch `clips` into `output`
+pub fn export_args(clips: &[PathBuf], options: &SessionExportOptions, output: &Path) -> Result<Vec<String>> {
+    let codec = match options.format {
+        OutputFormat::Mp3 => ["-c:a", "libmp3lame", "-q:a", "4"],
+        OutputFormat::Ogg => ["-c:a", "libvorbis", "-q:a", "5"],
+        _ => return Err(VoiceError::UnsupportedFormat),
+    };
+    if clips.is_empty() {
+        return Err(VoiceError::IoError(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "No audio clips to stitch",
+        )));
+    }
+
+    let mut args: Vec<String> = vec!["-y".into(), "-hide_banner".into(), "-loglevel".into(), "error".into()];
+    for clip in clips {
+        args.push("-i".into());
+        args.push(clip.display().to_string());
+    }
+    if let Some(music) = &options.music_path {
+        args.extend(["-stream_loop".into(), "-1".into(), "-i".into(), music.display().to_string()]);
+    }
+
+    let normalize = format!("aresample={},aformat=sample_fmts=fltp:channel_layouts=stereo", EXPORT_SAMPLE_RATE);
+    let gap_secs = options.gap_ms as f64 / 1000.0;
+    let mut filter = String::new();
+    for i in 0..clips.len() {
+        let pad = if i + 1 < clips.len() && options.gap_ms > 0 {
+            format!(",apad=pad_dur={:.3}", gap_secs)
+        } else {
+            String::new()
+        };
+        filter.push_str(&format!("[{i}:a]{normalize}{pad}[c{i}];"));
+    }
+    for i in 0..clips.len() {
+        filter.push_str(&format!("[c{i}]"));
+    }
+    filter.push_str(&format!("concat=n={}:v=0:a=1[voice]", clips.len()));
+
+    let out_label = if options.music_path.is_some() {
+        let volume = options.music_volume.clamp(0.0, 1.0);
+        filter.push_str(&format!(
+            ";[{}:a]{normalize},volume={volume:.3}[bed];[voice][bed]amix=inputs=2:duration=first:dropout_transition=0:normalize=0[out]",
+            clips.len()
+        ));
+        "[out]"
+    } else {
+        "[voice]"
+    };
+
+    args.extend(["-filter_complex".into(), filter, "-map".into(), out_label.into()]);
+    args.extend(codec.iter().map(|s| s.to_string()));
+    args.push(output.display().to_string());
+    Ok(args)
+}
+
+/// Stitch `clips` into a single audio file at `output` using `ffmpeg`
+pub async fn stitch_audio(clips: &[PathBuf], options: &SessionExportOptions, output: &Path) -> Result<()> {
+    let args = export_args(clips, options, output)?;
+    let ffmpeg = which::which("ffmpeg").map_err(|_| {
+        VoiceError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "ffmpeg is required for session audio export",
+        ))
+    })?;
+
+    if let Some(parent) = output.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    let result = tokio::process::Command::new(ffmpeg).args(&args).output().await?;
+    if !result.status.success() {
+        return Err(VoiceError::IoError(std::io::Error::other(format!(
+            "ffmpeg failed: {}",
+            String::from_utf8_lossy(&result.stderr).trim()
+        ))));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn utterance(text: &str, spoken_at: DateTime<Utc>) -> SessionUtterance {
+        SessionUtterance {
+            queue_id: uuid::Uuid::new_v4().to_string(),
+            text: text.to_string(),
+            voice_id: "piper:amy".to_string(),
+            audio_path: PathBuf::from(format!("/cache/{}.mp3", text)),
+            spoken_at,
+        }
+    }
+
+    #[test]
+    fn test_log_appends_and_loads_in_order() {
+        let temp = TempDir::new().unwrap();
+        let log = SessionAudioLog::new(temp.path().join("session_audio"));
+        let now = Utc::now();
+
+        log.record("s/1", &utterance("second", now)).unwrap();
+        log.record("s/1", &utterance("first", now - chrono::Duration::seconds(5))).unwrap();
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(log.log_path("s/1"))
+            .unwrap()
+            .write_all(b"{\"truncated\n")
+            .unwrap();
+
+        let loaded = log.load("s/1").unwrap();
+        assert_eq!(loaded.iter().map(|u| u.text.as_str()).collect::<Vec<_>>(), ["first", "second"]);
+        assert!(log.load("other").unwrap().is_empty());
+
+        log.clear("s/1").unwrap();
+        assert!(log.load("s/1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_args_pad_all_but_last_clip() {
+        let clips = vec![PathBuf::from("a.mp3"), PathBuf::from("b.wav")];
+        let args = export_args(&clips, &SessionExportOptions::default(), Path::new("out.mp3")).unwrap();
+        let filter = &args[args.iter().position(|a| a == "-filter_complex").unwrap() + 1];
+
+        assert!(filter.contains("[0:a]aresample=44100,aformat=sample_fmts=fltp:channel_layouts=stereo,apad=pad_dur=0.800[c0]"));
+        assert!(filter.contains("[1:a]aresample=44100,aformat=sample_fmts=fltp:channel_layouts=stereo[c1]"));
+        assert!(filter.ends_with("[c0][c1]concat=n=2:v=0:a=1[voice]"));
+        assert!(args.contains(&"libmp3lame".to_string()));
+        assert_eq!(args.last().unwrap(), "out.mp3");
+    }
+
+    #[test]
+    fn test_export_args_music_bed_and_format() {
+        let clips = vec![PathBuf::from("a.mp3")];
+        let options = SessionExportOptions {
+            format: OutputFormat::Ogg,
+            music_path: Some(PathBuf::from("tavern.ogg")),
+            music_volume: 2.0,
+            ..Default::default()
+        };
+        let args = export_args(&clips, &options, Path::new("out.ogg")).unwrap();
+
+        let loop_at = args.iter().position(|a| a == "-stream_loop").unwrap();
+        assert_eq!(args[loop_at + 3], "tavern.ogg");
+        let filter = &args[args.iter().position(|a| a == "-filter_complex").unwrap() + 1];
+        assert!(filter.contains("[1:a]aresample=44100,aformat=sample_fmts=fltp:channel_layouts=stereo,volume=1.000[bed]"));
+        assert!(args.contains(&"[out]".to_string()));
+        assert!(args.contains(&"libvorbis".to_string()));
+
+        let wav = SessionExportOptions { format: OutputFormat::Wav, ..Default::default() };
+        assert!(export_args(&clips, &wav, Path::new("out.wav")).is_err());
+        assert!(export_args(&[], &SessionExportOptions::default(), Path::new("out.mp3")).is_err());
+    }
+}
@@ -0,0 +1,396 @@
+//! Streaming TTS Playback
+//!
+//! Synthesize-while-speaking support. Providers with native streaming
+//! (ElevenLabs, OpenAI) deliver raw PCM as it is generated; other providers
+//! are driven sentence-by-sentence. Chunks are fed to an incremental player
+//! so speech starts as soon as the first chunk arrives instead of after the
+//! whole passage has been synthesized.
+
+use std::io::Cursor;
+use std::sync::mpsc as std_mpsc;
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+use rodio::buffer::SamplesBuffer;
+use rodio::Decoder;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use super::types::{Result, VoiceError};
+use crate::core::audio::{AudioMixer, MixerChannel};
+
+/// Sample rate of the raw PCM returned by ElevenLabs (`pcm_24000`) and OpenAI (`pcm`)
+pub const STREAM_PCM_SAMPLE_RATE: u32 = 24_000;
+
+/// Default number of samples buffered before a PCM chunk is emitted (~200ms at 24kHz)
+const DEFAULT_PCM_CHUNK_SAMPLES: usize = 4_800;
+
+/// Default maximum characters per sentence segment for sentence-by-sentence synthesis
+pub const DEFAULT_MAX_SEGMENT_CHARS: usize = 240;
+
+// ============================================================================
+// Audio Chunks
+// ============================================================================
+
+/// A unit of audio delivered by a streaming synthesis
+#[derive(Debug, Clone)]
+pub enum AudioChunk {
+    /// Raw signed 16-bit little-endian PCM samples
+    Pcm {
+        samples: Vec<i16>,
+        sample_rate: u32,
+        channels: u16,
+    },
+    /// A complete, independently decodable audio file (WAV/MP3/OGG)
+    Encoded(Vec<u8>),
+}
+
+/// Sender half used by providers to push audio chunks
+pub type AudioChunkSender = mpsc::Sender<AudioChunk>;
+
+/// Summary of a completed streaming playback
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StreamPlaybackSummary {
+    pub stream_id: String,
+    /// Number of chunks played
+    pub chunks: usize,
+    /// Milliseconds from request to first audible chunk
+    pub first_audio_ms: Option<u64>,
+    /// Total milliseconds from request to end of playback
+    pub total_ms: u64,
+    /// Whether the provider streamed natively (false = sentence-by-sentence)
+    pub native_streaming: bool,
+}
+
+// ============================================================================
+// PCM Chunking
+// ============================================================================
+
+/// Converts an arbitrary byte stream of s16le PCM into sample chunks.
+///
+/// Network reads do not respect sample boundaries, so an odd trailing byte is
+/// carried over to the next push.
+#[derive(Debug)]
+pub struct PcmChunker {
+    sample_rate: u32,
+    chunk_samples: usize,
+    pending_byte: Option<u8>,
+    buffer: Vec<i16>,
+}
+
+impl PcmChunker {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            chunk_samples: DEFAULT_PCM_CHUNK_SAMPLES,
+            pending_byte: None,
+            buffer: Vec::with_capacity(DEFAULT_PCM_CHUNK_SAMPLES),
+        }
+    }
+
+    /// Set the number of samples per emitted chunk
+    pub fn with_chunk_samples(mut self, samples: usize) -> Self {
+        self.chunk_samples = samples.max(1);
+        self
+    }
+
+    /// Push raw bytes, returning any complete chunks
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<AudioChunk> {
+        let mut data = bytes;
+        if let Some(low) = self.pending_byte.take() {
+            if let Some((&high, rest)) = data.split_first() {
+                self.buffer.push(i16::from_le_bytes([low, high]));
+                data = rest;
+            } else {
+                self.pending_byte = Some(low);
+                return Vec::new();
+            }
+        }
+
+        let mut pairs = data.chunks_exact(2);
+        for pair in &mut pairs {
+            self.buffer.push(i16::from_le_bytes([pair[0], pair[1]]));
+        }
+        if let [last] = pairs.remainder() {
+            self.pending_byte = Some(*last);
+        }
+
+        let mut chunks = Vec::new();
+        while self.buffer.len() >= self.chunk_samples {
+            let rest = self.buffer.split_off(self.chunk_samples);
+            let samples = std::mem::replace(&mut self.buffer, rest);
+            chunks.push(self.make_chunk(samples));
+        }
+        chunks
+    }
+
+    /// Flush any buffered samples as a final chunk
+    pub fn finish(&mut self) -> Option<AudioChunk> {
+        self.pending_byte = None;
+        if self.buffer.is_empty() {
+            None
+        } else {
+            let samples = std::mem::take(&mut self.buffer);
+            Some(self.make_chunk(samples))
+        }
+    }
+
+    fn make_chunk(&self, samples: Vec<i16>) -> AudioChunk {
+        AudioChunk::Pcm {
+            samples,
+            sample_rate: self.sample_rate,
+            channels: 1,
+        }
+    }
+}
+
+/// Forward a streaming HTTP response body of s16le PCM into the chunk sender
+pub async fn forward_pcm_response(
+    response: reqwest::Response,
+    sample_rate: u32,
+    tx: &AudioChunkSender,
+) -> Result<()> {
+    use futures_util::StreamExt;
+
+    let mut chunker = PcmChunker::new(sample_rate);
+    let mut body = response.bytes_stream();
+
+    while let Some(bytes) = body.next().await {
+        let bytes = bytes?;
+        for chunk in chunker.push(&bytes) {
+            if tx.send(chunk).await.is_err() {
+                // Player went away (e.g., playback stopped) - stop reading
+                return Ok(());
+            }
+        }
+    }
+
+    if let Some(chunk) = chunker.finish() {
+        let _ = tx.send(chunk).await;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Sentence Segmentation
+// ============================================================================
+
+/// Split text into sentence-sized segments for incremental synthesis.
+///
+/// Sentences are split on terminal punctuation followed by whitespace. Short
+/// sentences are merged up to `max_chars`; over-long sentences are split on
+/// the last comma or space before the limit.
+pub fn split_sentences(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(16);
+    let mut sentences: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let chars: Vec<char> = text.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        current.push(c);
+        let at_boundary = matches!(c, '.' | '!' | '?' | ';' | '\n')
+            && chars.get(i + 1).is_none_or(|next| next.is_whitespace());
+        if at_boundary {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            current.clear();
+        }
+    }
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        sentences.push(trimmed.to_string());
+    }
+
+    // Break up over-long sentences
+    let mut bounded: Vec<String> = Vec::new();
+    for sentence in sentences {
+        let mut rest = sentence.as_str();
+        while rest.chars().count() > max_chars {
+            let limit = rest.char_indices().nth(max_chars).map(|(i, _)| i).unwrap_or(rest.len());
+            let head = &rest[..limit];
+            let split_at = head.rfind(", ").map(|i| i + 1)
+                .or_else(|| head.rfind(' '))
+                .filter(|&i| i > 0)
+                .unwrap_or(limit);
+            bounded.push(rest[..split_at].trim().to_string());
+            rest = rest[split_at..].trim_start();
+        }
+        if !rest.is_empty() {
+            bounded.push(rest.to_string());
+        }
+    }
+
+    // Merge short neighbours so each synthesis call carries enough context
+    let mut merged: Vec<String> = Vec::new();
+    for segment in bounded {
+        match merged.last_mut() {
+            Some(last) if last.chars().count() + segment.chars().count() < max_chars / 2 => {
+                last.push(' ');
+                last.push_str(&segment);
+            }
+            _ => merged.push(segment),
+        }
+    }
+    merged
+}
+
+// ============================================================================
+// Incremental Player
+// ============================================================================
+
+enum PlayerMessage {
+    Chunk(AudioChunk),
+    Finish,
+}
+
+/// Plays audio chunks as they arrive on the mixer's voice channel.
+///
+/// Decoding and queueing happen on a dedicated player thread; callers
+/// communicate through a channel.
+pub struct StreamingPlayer {
+    tx: std_mpsc::Sender<PlayerMessage>,
+    handle: Option<JoinHandle<std::result::Result<usize, String>>>,
+    started: Instant,
+    first_audio: std::sync::Arc<std::sync::OnceLock<u64>>,
+}
+
+impl StreamingPlayer {
+    /// Start the player thread, playing through the mixer's voice channel
+    pub fn start(mixer: &AudioMixer) -> Self {
+        let mixer = mixer.clone();
+        let (tx, rx) = std_mpsc::channel::<PlayerMessage>();
+        let started = Instant::now();
+        let first_audio = std::sync::Arc::new(std::sync::OnceLock::new());
+        let first_audio_thread = first_audio.clone();
+
+        let handle = std::thread::spawn(move || {
+            let track = mixer.track(MixerChannel::Voice, 1.0).map_err(|e| e.to_string())?;
+            let mut played = 0usize;
+
+            while let Ok(message) = rx.recv() {
+                match message {
+                    PlayerMessage::Chunk(AudioChunk::Pcm { samples, sample_rate, channels }) => {
+                        track.append(SamplesBuffer::new(channels, sample_rate, samples));
+                    }
+                    PlayerMessage::Chunk(AudioChunk::Encoded(bytes)) => {
+                        match Decoder::new(Cursor::new(bytes)) {
+                            Ok(source) => track.append(source),
+                            Err(e) => {
+                                log::warn!("Skipping undecodable audio chunk: {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    PlayerMessage::Finish => break,
+                }
+                played += 1;
+                let _ = first_audio_thread.set(started.elapsed().as_millis() as u64);
+            }
+
+            track.sleep_until_end();
+            Ok(played)
+        });
+
+        Self {
+            tx,
+            handle: Some(handle),
+            started,
+            first_audio,
+        }
+    }
+
+    /// Queue a chunk for playback
+    pub fn push(&self, chunk: AudioChunk) -> Result<()> {
+        self.tx
+            .send(PlayerMessage::Chunk(chunk))
+            .map_err(|_| VoiceError::IoError(std::io::Error::other("Audio player thread has stopped")))
+    }
+
+    /// Milliseconds from player start to the first queued chunk, if any
+    pub fn first_audio_ms(&self) -> Option<u64> {
+        self.first_audio.get().copied()
+    }
+
+    /// Signal end of input and block until playback completes.
+    ///
+    /// Returns the number of chunks played. Call from a blocking context.
+    pub fn finish_blocking(mut self) -> Result<usize> {
+        let _ = self.tx.send(PlayerMessage::Finish);
+        let handle = self.handle.take()
+            .ok_or_else(|| VoiceError::IoError(std::io::Error::other("Player already finished")))?;
+        handle
+            .join()
+            .map_err(|_| VoiceError::IoError(std::io::Error::other("Audio player thread panicked")))?
+            .map_err(|e| VoiceError::IoError(std::io::Error::other(e)))
+    }
+
+    /// Milliseconds since the player was started
+    pub fn elapsed_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcm_chunker_handles_odd_boundaries() {
+        let mut chunker = PcmChunker::new(STREAM_PCM_SAMPLE_RATE).with_chunk_samples(2);
+        // 1 = 0x0001, 2 = 0x0002, 3 = 0x0003 in little-endian, split awkwardly
+        assert!(chunker.push(&[0x01]).is_empty());
+        let chunks = chunker.push(&[0x00, 0x02, 0x00, 0x03]);
+        assert_eq!(chunks.len(), 1);
+        match &chunks[0] {
+            AudioChunk::Pcm { samples, sample_rate, channels } => {
+                assert_eq!(samples, &vec![1, 2]);
+                assert_eq!(*sample_rate, STREAM_PCM_SAMPLE_RATE);
+                assert_eq!(*channels, 1);
+            }
+            _ => panic!("expected PCM chunk"),
+        }
+        assert!(chunker.push(&[0x00]).is_empty());
+        match chunker.finish() {
+            Some(AudioChunk::Pcm { samples, .. }) => assert_eq!(samples, vec![3]),
+            _ => panic!("expected trailing PCM chunk"),
+        }
+        assert!(chunker.finish().is_none());
+    }
+
+    #[test]
+    fn test_split_sentences_basic() {
+        let text = "The door creaks open. A cold wind blows! Who goes there?";
+        let segments = split_sentences(text, 30);
+        assert_eq!(segments, vec![
+            "The door creaks open.",
+            "A cold wind blows!",
+            "Who goes there?",
+        ]);
+    }
+
+    #[test]
+    fn test_split_sentences_ignores_decimal_points() {
+        let segments = split_sentences("It costs 2.5 gold. Pay up.", 200);
+        assert_eq!(segments, vec!["It costs 2.5 gold. Pay up."]);
+    }
+
+    #[test]
+    fn test_split_sentences_breaks_long_sentences() {
+        let text = "word ".repeat(100);
+        let segments = split_sentences(&text, 50);
+        assert!(segments.len() > 1);
+        assert!(segments.iter().all(|s| s.chars().count() <= 50));
+        assert_eq!(segments.join(" ").split_whitespace().count(), 100);
+    }
+
+    #[test]
+    fn test_split_sentences_empty() {
+        assert!(split_sentences("   ", 100).is_empty());
+    }
+}
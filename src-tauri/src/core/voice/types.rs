@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use thiserror::Error;
 
+use super::fallback::{FallbackConfig, FallbackPolicy};
+use super::normalize::TextNormalizationConfig;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -43,6 +46,9 @@ pub type Result<T> = std::result::Result<T, VoiceError>;
 pub struct VoiceConfig {
     pub provider: VoiceProviderType,
     pub cache_dir: Option<PathBuf>,
+    /// Voice cache size limit in megabytes (least recently used audio is evicted)
+    #[serde(default)]
+    pub cache_max_size_mb: Option<u64>,
     pub default_voice_id: Option<String>,
     // Cloud providers
     pub elevenlabs: Option<ElevenLabsConfig>,
@@ -57,6 +63,12 @@ pub struct VoiceConfig {
     pub fish_speech: Option<FishSpeechConfig>,
     pub dia: Option<DiaConfig>,
     pub coqui: Option<CoquiConfig>,
+    /// Expansion of dice notation and game abbreviations before synthesis
+    #[serde(default)]
+    pub text_normalization: TextNormalizationConfig,
+    /// Providers to fall back to when the voice's provider fails
+    #[serde(default)]
+    pub fallback: FallbackConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -366,6 +378,7 @@ impl Default for VoiceConfig {
         Self {
             provider: VoiceProviderType::Disabled,
             cache_dir: None,
+            cache_max_size_mb: None,
             default_voice_id: None,
             elevenlabs: None,
             fish_audio: None,
@@ -378,6 +391,8 @@ impl Default for VoiceConfig {
             fish_speech: None,
             dia: None,
             coqui: None,
+            text_normalization: TextNormalizationConfig::default(),
+            fallback: FallbackConfig::default(),
         }
     }
 }
@@ -473,16 +488,55 @@ pub enum VoiceStatus {
     Processing,
     Playing,
     Completed,
+    /// Cut off by `interrupt_voice` or a higher-priority utterance
+    Interrupted,
     Failed(String),
 }
 
+/// Playback priority for the voice queue
+///
+/// Higher priorities are spoken first; within a level the queue is FIFO.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoicePriority {
+    /// Flavor text and ambient narration
+    Flavor,
+    /// Dialogue and general narration
+    #[default]
+    Normal,
+    /// Combat announcements; cut off flavor text that is playing
+    Combat,
+    /// Cuts off anything that is playing
+    Urgent,
+}
+
+impl VoicePriority {
+    /// Whether an utterance at this priority should interrupt one that is playing
+    pub fn preempts(self, playing: VoicePriority) -> bool {
+        self > playing && (playing == VoicePriority::Flavor || self == VoicePriority::Urgent)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueuedVoice {
     pub id: String,
     pub text: String,
     pub voice_id: String,
+    #[serde(default)]
+    pub settings: Option<VoiceSettings>,
+    #[serde(default)]
+    pub priority: VoicePriority,
     pub status: VoiceStatus,
     pub created_at: String,
+    /// Synthesized audio in the voice cache, once available
+    #[serde(default)]
+    pub audio_path: Option<String>,
+    /// Game session the line belongs to; spoken lines are logged for recaps
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Whether the line may be spoken by a fallback provider
+    #[serde(default)]
+    pub fallback: FallbackPolicy,
 }
 
 // ============================================================================
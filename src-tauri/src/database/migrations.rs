@@ -7,7 +7,7 @@ use sqlx::Row;
 use tracing::{info, warn};
 
 /// Current database schema version
-const SCHEMA_VERSION: i32 = 27;
+const SCHEMA_VERSION: i32 = 28;
 
 /// Run all pending migrations
 pub async fn run_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
@@ -84,6 +84,7 @@ async fn run_migration(pool: &SqlitePool, version: i32) -> Result<(), sqlx::Erro
         25 => ("quick_reference_cards", MIGRATION_V25),
         26 => ("random_tables", MIGRATION_V26),
         27 => ("session_recaps", MIGRATION_V27),
+        28 => ("npc_memories", MIGRATION_V28),
         _ => {
             warn!("Unknown migration version: {}", version);
             return Ok(());
@@ -1228,3 +1229,26 @@ CREATE TABLE IF NOT EXISTS pc_knowledge_filters (
 CREATE INDEX IF NOT EXISTS idx_pc_knowledge_recap ON pc_knowledge_filters(recap_id);
 CREATE INDEX IF NOT EXISTS idx_pc_knowledge_character ON pc_knowledge_filters(character_id);
 "#;
+
+/// Migration v28: NPC memories
+/// Conversation summaries and notable facts an NPC remembers between chats.
+const MIGRATION_V28: &str = r#"
+CREATE TABLE IF NOT EXISTS npc_memories (
+    id TEXT PRIMARY KEY,
+    npc_id TEXT NOT NULL,
+    campaign_id TEXT NOT NULL,
+    kind TEXT NOT NULL DEFAULT 'fact',
+    content TEXT NOT NULL,
+    importance INTEGER NOT NULL DEFAULT 3,
+    source_message_id TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (npc_id) REFERENCES npcs(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_npc_memories_npc ON npc_memories(npc_id);
+CREATE INDEX IF NOT EXISTS idx_npc_memories_campaign ON npc_memories(campaign_id);
+
+-- How many conversation messages have already been folded into memories
+ALTER TABLE npc_conversations ADD COLUMN summarized_count INTEGER NOT NULL DEFAULT 0;
+"#;
@@ -405,6 +405,9 @@ pub struct NpcConversation {
     pub last_message_at: String,
     pub created_at: String,
     pub updated_at: String,
+    /// Number of leading messages already folded into the NPC's memories
+    #[serde(default)]
+    pub summarized_count: u32,
 }
 
 /// Message within an NPC conversation
@@ -429,6 +432,45 @@ impl NpcConversation {
             last_message_at: now.clone(),
             created_at: now.clone(),
             updated_at: now,
+            summarized_count: 0,
+        }
+    }
+}
+
+// ============================================================================
+// NPC Memory Records
+// ============================================================================
+
+/// Something an NPC remembers: a conversation summary or a notable fact
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NpcMemoryRecord {
+    pub id: String,
+    pub npc_id: String,
+    pub campaign_id: String,
+    /// "summary" or "fact"
+    pub kind: String,
+    pub content: String,
+    /// 1 (trivia) to 5 (life-changing)
+    pub importance: i32,
+    /// Last conversation message the memory was drawn from
+    pub source_message_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl NpcMemoryRecord {
+    pub fn new(npc_id: String, campaign_id: String, kind: &str, content: String, importance: i32) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            npc_id,
+            campaign_id,
+            kind: kind.to_string(),
+            content,
+            importance: importance.clamp(1, 5),
+            source_message_id: None,
+            created_at: now.clone(),
+            updated_at: now,
         }
     }
 }
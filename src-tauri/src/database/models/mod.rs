@@ -40,6 +40,7 @@ pub use core::{
     EntityType,
     LocationRecord,
     NpcConversation,
+    NpcMemoryRecord,
     PersonalityRecord,
     SessionEventRecord,
     SessionNoteRecord,
@@ -1,9 +1,9 @@
 //! NPC database operations
 //!
 //! This module provides CRUD operations for NPCs, NPC conversations,
-//! and personality records.
+//! NPC memories, and personality records.
 
-use super::models::{NpcRecord, NpcConversation, PersonalityRecord};
+use super::models::{NpcRecord, NpcConversation, NpcMemoryRecord, PersonalityRecord};
 use super::Database;
 
 /// Extension trait for NPC-related database operations
@@ -19,6 +19,13 @@ pub trait NpcOps {
     fn list_npc_conversations(&self, campaign_id: &str) -> impl std::future::Future<Output = Result<Vec<NpcConversation>, sqlx::Error>> + Send;
     fn save_npc_conversation(&self, conversation: &NpcConversation) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
 
+    // NPC Memories
+    fn save_npc_memory(&self, memory: &NpcMemoryRecord) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_npc_memory(&self, id: &str) -> impl std::future::Future<Output = Result<Option<NpcMemoryRecord>, sqlx::Error>> + Send;
+    fn list_npc_memories(&self, npc_id: &str) -> impl std::future::Future<Output = Result<Vec<NpcMemoryRecord>, sqlx::Error>> + Send;
+    fn delete_npc_memory(&self, id: &str) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn clear_npc_memories(&self, npc_id: &str) -> impl std::future::Future<Output = Result<u64, sqlx::Error>> + Send;
+
     // Personalities
     fn save_personality(&self, record: &PersonalityRecord) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
     fn get_personality(&self, id: &str) -> impl std::future::Future<Output = Result<Option<PersonalityRecord>, sqlx::Error>> + Send;
@@ -118,8 +125,9 @@ impl NpcOps for Database {
         sqlx::query(
             r#"
             INSERT OR REPLACE INTO npc_conversations
-            (id, npc_id, campaign_id, messages_json, unread_count, last_message_at, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            (id, npc_id, campaign_id, messages_json, unread_count, last_message_at, created_at, updated_at,
+             summarized_count)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&conversation.id)
@@ -130,11 +138,72 @@ impl NpcOps for Database {
         .bind(&conversation.last_message_at)
         .bind(&conversation.created_at)
         .bind(&conversation.updated_at)
+        .bind(conversation.summarized_count)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // NPC Memory Operations
+    // =========================================================================
+
+    async fn save_npc_memory(&self, memory: &NpcMemoryRecord) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO npc_memories
+            (id, npc_id, campaign_id, kind, content, importance, source_message_id, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&memory.id)
+        .bind(&memory.npc_id)
+        .bind(&memory.campaign_id)
+        .bind(&memory.kind)
+        .bind(&memory.content)
+        .bind(memory.importance)
+        .bind(&memory.source_message_id)
+        .bind(&memory.created_at)
+        .bind(&memory.updated_at)
         .execute(self.pool())
         .await?;
         Ok(())
     }
 
+    async fn get_npc_memory(&self, id: &str) -> Result<Option<NpcMemoryRecord>, sqlx::Error> {
+        sqlx::query_as::<_, NpcMemoryRecord>(
+            "SELECT * FROM npc_memories WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(self.pool())
+        .await
+    }
+
+    async fn list_npc_memories(&self, npc_id: &str) -> Result<Vec<NpcMemoryRecord>, sqlx::Error> {
+        sqlx::query_as::<_, NpcMemoryRecord>(
+            "SELECT * FROM npc_memories WHERE npc_id = ? ORDER BY created_at"
+        )
+        .bind(npc_id)
+        .fetch_all(self.pool())
+        .await
+    }
+
+    async fn delete_npc_memory(&self, id: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM npc_memories WHERE id = ?")
+            .bind(id)
+            .execute(self.pool())
+            .await?;
+        Ok(())
+    }
+
+    async fn clear_npc_memories(&self, npc_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM npc_memories WHERE npc_id = ?")
+            .bind(npc_id)
+            .execute(self.pool())
+            .await?;
+        Ok(result.rows_affected())
+    }
+
     // =========================================================================
     // Personality Operations
     // =========================================================================
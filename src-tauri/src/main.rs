@@ -80,6 +80,28 @@ fn main() {
                 dictionary_rebuild_service
             ) = commands::AppState::init_defaults(embedded_search.clone_inner());
 
+            // Encrypted campaigns live on disk; decrypt those whose key is in the keyring
+            let mut cm = cm;
+            cm.set_data_dir(app_dir.join("campaigns"));
+            match cm.load_encrypted_campaigns(|id| commands::keyring_campaign_key(&creds, id)) {
+                Ok(count) => log::info!("Loaded {} encrypted campaigns", count),
+                Err(e) => log::warn!("Failed to load encrypted campaigns: {}", e),
+            }
+
+            // Journal running fights to disk and bring back any a crash
+            // interrupted
+            let mut sm = sm;
+            sm.set_combat_journal(ttrpg_assistant::core::session::CombatJournal::new(
+                app_dir.join("combat_journal"),
+            ));
+            match sm.recover_journaled_combats() {
+                Ok(recovered) if !recovered.is_empty() => {
+                    log::info!("Recovered combat in {} sessions", recovered.len())
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Failed to recover journaled combat: {}", e),
+            }
+
             // Load persisted voice config or use default
             let voice_manager = if let Some(voice_config) = commands::load_voice_config_disk(app.handle()) {
                 log::info!("Loading voice config from disk: provider={:?}", voice_config.provider);
@@ -90,6 +112,15 @@ fn main() {
                 vm
             };
 
+            // Log lines spoken in game sessions for audio recaps, and report
+            // provider fallbacks to the UI
+            if let Ok(mut manager) = voice_manager.try_write() {
+                manager.set_session_log(Some(ttrpg_assistant::core::voice::SessionAudioLog::new(
+                    app_dir.join("session_audio"),
+                )));
+                manager.set_app_handle(Some(app.handle().clone()));
+            }
+
             app.manage(commands::AppState {
                 llm_client: std::sync::RwLock::new(None),
                 llm_config: std::sync::RwLock::new(commands::load_llm_config_disk(app.handle())),
@@ -222,11 +253,89 @@ fn main() {
             // TASK-022, TASK-023, TASK-024: Initialize analytics state wrappers
             app.manage(commands::UsageTrackerState::default());
             app.manage(commands::SearchAnalyticsState::default());
+            app.manage(commands::SuggestionState::default());
+            let search_settings = commands::SearchSettingsState::load(app.handle());
+            app.state::<commands::AppState>()
+                .ingestion_pipeline
+                .set_index_tuning(search_settings.settings().index_tuning);
+            app.manage(search_settings);
+            app.manage(commands::TermSetState::load(app.handle()));
+            app.manage(commands::BookmarkState::load(app.handle()));
+            app.manage(commands::IndexMaintenanceState::default());
+            app.manage(commands::IndexMigrationState::default());
+            commands::start_index_migrations(app.handle());
             app.manage(commands::AuditLoggerState::default());
 
             // TASK-025: Initialize synthesis queue state
             app.manage(commands::SynthesisQueueState::default());
 
+            // Campaign quest and faction tracking
+            app.manage(commands::QuestState::default());
+            app.manage(commands::FactionState::default());
+            app.manage(commands::CalendarState::default());
+            app.manage(commands::WeatherState::default());
+            app.manage(commands::PartyState::default());
+            app.manage(commands::WorldUpdateState::default());
+            app.manage(commands::PlotThreadState::default());
+            app.manage(commands::HandoutState::default());
+            app.manage(commands::TreasuryState::default());
+            app.manage(commands::TravelState::default());
+            app.manage(commands::HouseRuleState::default());
+            app.manage(commands::ProgressionState::default());
+            app.manage(commands::WorldSettingState::default());
+            app.manage(commands::PresenceState::default());
+            app.manage(commands::EncounterState::default());
+            app.manage(commands::ShopState::default());
+            app.manage(commands::InventoryState::default());
+            app.manage(commands::SpellcastingState::default());
+            app.manage(commands::PlayerWindowState::default());
+            app.manage(commands::NameBankState::default());
+
+            // Scheduled campaign backups
+            let backup_config = commands::load_backup_config_disk(app.handle()).unwrap_or_default();
+            app.manage(commands::BackupState::new(backup_config));
+            commands::start_backup_scheduler(app.handle().clone());
+
+            // Break reminders and pacing nudges for running sessions
+            commands::start_session_clock_ticker(app.handle().clone());
+
+            // Keeps the player display window in step with its session
+            commands::start_player_view_relay(app.handle().clone());
+
+            // Voice profiles (NPC voice assignment); cloned voices are restored
+            // from disk and registered as profiles
+            let voice_clones = commands::VoiceCloneState::new(commands::voice_clones_dir(app.handle()));
+            let voice_profiles = commands::VoiceProfileState::with_profiles(voice_clones.profiles());
+            // Profiles also map voices onto fallback providers
+            if let Ok(mut manager) = app.state::<commands::AppState>().voice_manager.try_write() {
+                manager.set_voice_profiles(Some(voice_profiles.manager.clone()));
+            }
+            app.manage(voice_profiles);
+            app.manage(voice_clones);
+
+            // Push-to-talk speech input
+            app.manage(commands::SpeechInputState::default());
+
+            // Pre-generated session read-aloud audio
+            app.manage(commands::ReadAloudState::new(commands::read_aloud_dir(app.handle())));
+
+            // Shared audio mixer (voice, music, ambience, sfx channels)
+            let mixer_settings = commands::load_mixer_settings_disk(app.handle()).unwrap_or_default();
+            let mixer = ttrpg_assistant::core::audio::AudioMixer::new(mixer_settings);
+            app.manage(commands::MixerState::new(mixer.clone()));
+
+            // Ambient soundscapes
+            app.manage(commands::SoundscapeState::new(mixer.clone()));
+
+            // Keyword/event triggered sound effects
+            let sfx_config = commands::load_sfx_triggers_disk(app.handle()).unwrap_or_default();
+            app.manage(commands::SfxTriggerState::new(sfx_config, mixer.clone()));
+
+            // Spoken combat announcements (opt-in)
+            let announcer_config = commands::load_announcer_config_disk(app.handle()).unwrap_or_default();
+            let voice_manager = app.state::<commands::AppState>().voice_manager.clone();
+            app.manage(commands::CombatAnnouncerState::new(announcer_config, voice_manager, mixer));
+
             Ok(())
         })
         // Native features (DragDrop, Dialogs)
@@ -262,12 +371,171 @@ fn main() {
             commands::set_campaign_theme,
             commands::get_theme_preset,
 
+            // Campaign Encryption Commands
+            commands::enable_campaign_encryption,
+            commands::disable_campaign_encryption,
+            commands::lock_campaign,
+            commands::unlock_campaign,
+            commands::export_recovery_phrase,
+            commands::list_locked_campaigns,
+
+            // Campaign Backup Commands
+            commands::get_backup_config,
+            commands::set_backup_config,
+            commands::run_backup_now,
+            commands::list_backups,
+            commands::verify_backup,
+            commands::restore_backup,
+
+            // Encounter Builder Commands
+            commands::get_encounter_budget,
+            commands::evaluate_encounter_difficulty,
+            commands::find_encounter_candidates,
+            commands::save_encounter,
+            commands::get_encounter,
+            commands::list_encounters,
+            commands::delete_encounter,
+
+            // Shop Commands
+            commands::generate_shop,
+            commands::get_shop,
+            commands::list_location_shops,
+            commands::list_campaign_shops,
+            commands::update_shop,
+            commands::delete_shop,
+            commands::reroll_shop_inventory,
+            commands::buy_from_shop,
+            commands::restock_shop,
+            commands::restock_campaign_shops,
+
+            // Inventory Commands
+            commands::get_inventory,
+            commands::list_campaign_inventories,
+            commands::add_inventory_item,
+            commands::update_inventory_item,
+            commands::remove_inventory_item,
+            commands::stow_inventory_item,
+            commands::set_item_attunement,
+            commands::set_inventory_strength,
+            commands::get_encumbrance,
+            commands::transfer_inventory_item,
+            commands::loot_combatant,
+            commands::sell_to_shop,
+
+            // Spellcasting Commands
+            commands::get_spellbook,
+            commands::list_campaign_spellbooks,
+            commands::set_spell_slots,
+            commands::sync_spell_slots_from_class,
+            commands::learn_spell,
+            commands::forget_spell,
+            commands::prepare_spell,
+            commands::cast_spell,
+            commands::end_concentration,
+            commands::take_long_rest,
+            commands::take_short_rest,
+            commands::lookup_known_spell,
+
             // Campaign Snapshots
             commands::create_snapshot,
             commands::list_snapshots,
             commands::restore_snapshot,
             commands::export_campaign,
             commands::import_campaign,
+            commands::export_campaign_archive,
+            commands::import_campaign_archive,
+            commands::export_campaign_wiki,
+
+            // Campaign Template Commands
+            commands::list_campaign_templates,
+            commands::save_campaign_template,
+            commands::delete_campaign_template,
+            commands::create_campaign_from_template,
+            commands::clone_campaign,
+
+            // Quest Commands
+            commands::create_quest,
+            commands::get_quest,
+            commands::update_quest,
+            commands::delete_quest,
+            commands::list_quests,
+            commands::search_quests,
+            commands::get_linked_quests,
+            commands::set_quest_status,
+            commands::add_quest_objective,
+            commands::remove_quest_objective,
+            commands::set_quest_objective_status,
+            commands::suggest_quest_updates,
+            commands::apply_quest_suggestion,
+
+            // Faction Commands
+            commands::create_faction,
+            commands::get_faction,
+            commands::update_faction,
+            commands::delete_faction,
+            commands::list_factions,
+            commands::get_npc_factions,
+            commands::adjust_faction_influence,
+            commands::add_faction_clock,
+            commands::advance_faction_clock,
+            commands::remove_faction_clock,
+            commands::relate_faction,
+            commands::get_faction_web,
+
+            // Party Roster Commands
+            commands::create_player_character,
+            commands::get_player_character,
+            commands::update_player_character,
+            commands::delete_player_character,
+            commands::list_player_characters,
+            commands::level_up_player_character,
+            commands::get_party_overview,
+            commands::calculate_party_encounter_difficulty,
+
+            // Progression Commands
+            commands::award_xp,
+            commands::get_progression,
+            commands::set_progression_mode,
+            commands::list_progression_awards,
+
+            // Plot Thread Commands
+            commands::create_plot_thread,
+            commands::get_plot_thread,
+            commands::update_plot_thread,
+            commands::delete_plot_thread,
+            commands::list_plot_threads,
+            commands::get_entity_plot_threads,
+            commands::set_plot_thread_status,
+            commands::touch_plot_thread,
+            commands::analyze_plot_threads,
+
+            // Handout Commands
+            commands::create_handout,
+            commands::get_handout,
+            commands::update_handout,
+            commands::delete_handout,
+            commands::list_handouts,
+            commands::reveal_handout,
+            commands::hide_handout,
+            commands::get_revealed_handouts,
+
+            // Treasury Commands
+            commands::get_party_treasury,
+            commands::record_treasury_transaction,
+            commands::list_treasury_transactions,
+            commands::parse_loot_text,
+            commands::distribute_loot,
+            commands::get_currency_system,
+            commands::convert_currency,
+            commands::get_spending_report,
+
+            // House Rule Commands
+            commands::create_house_rule,
+            commands::get_house_rule,
+            commands::update_house_rule,
+            commands::delete_house_rule,
+            commands::list_house_rules,
+            commands::search_house_rules,
 
             // Campaign Notes Commands
             commands::add_campaign_note,
@@ -311,8 +579,26 @@ fn main() {
             commands::list_sessions,
             commands::create_planned_session,
             commands::start_planned_session,
+            commands::pause_session,
+            commands::resume_session,
             commands::end_session,
 
+            // Session Clock Commands
+            commands::get_session_clock,
+            commands::configure_session_clock,
+            commands::set_session_clock_plan,
+            commands::set_session_clock_scene,
+
+            // Player Window Commands
+            commands::list_displays,
+            commands::open_player_window,
+            commands::close_player_window,
+            commands::get_player_view,
+            commands::get_player_handout_image,
+
+            // Session Recap Commands
+            commands::session::recap::generate_session_recap,
+
             // Global Chat Session Commands (Persistent LLM Chat History)
             commands::get_or_create_chat_session,
             commands::get_active_chat_session,
@@ -330,20 +616,44 @@ fn main() {
             commands::get_session_timeline,
             commands::get_timeline_summary,
             commands::get_timeline_events_by_type,
+            commands::get_timeline_capture,
+            commands::set_timeline_capture,
+            commands::set_timeline_capture_category,
 
             // Combat Commands
             commands::start_combat,
             commands::end_combat,
+            commands::get_combat_report,
             commands::get_combat,
+            commands::recover_combat,
             commands::add_combatant,
+            commands::add_combatant_from_stat_block,
+            commands::add_npc_to_combat,
+            commands::add_combatant_group,
+            commands::add_group_from_stat_block,
             commands::remove_combatant,
             commands::next_turn,
             commands::get_current_combatant,
             commands::damage_combatant,
+            commands::apply_damage_to_many,
+            commands::damage_group,
+            commands::set_combatant_damage_defenses,
             commands::heal_combatant,
             commands::add_condition,
             commands::remove_condition,
 
+            // Initiative Commands
+            commands::roll_initiative,
+            commands::reroll_all_initiative,
+            commands::get_initiative_rules,
+            commands::set_initiative_rules,
+            commands::set_surprised_combatants,
+            commands::move_in_initiative,
+            commands::delay_turn,
+            commands::resume_delayed_turn,
+            commands::ready_action,
+            commands::take_readied_action,
+
             // Advanced Condition Commands (TASK-015)
             commands::add_condition_advanced,
             commands::remove_condition_by_id,
@@ -352,9 +662,75 @@ fn main() {
             commands::tick_conditions_start_of_turn,
             commands::list_condition_templates,
 
+            // Concentration Commands
+            commands::start_concentration,
+            commands::break_concentration,
+
+            // Death and Dying Commands
+            commands::roll_death_save,
+            commands::stabilize_combatant,
+            commands::get_death_rules,
+            commands::set_death_rules,
+
+            // Morale Commands
+            commands::add_morale_group,
+            commands::remove_morale_group,
+            commands::set_morale_rules,
+            commands::call_morale_check,
+
+            // Legendary, Lair, Reaction, and Limited Ability Commands
+            commands::set_legendary_actions,
+            commands::spend_legendary_action,
+            commands::reset_combatant_actions,
+            commands::use_reaction,
+            commands::set_lair_actions,
+            commands::use_combatant_ability,
+            commands::restore_combatant_ability,
+            commands::track_combatant_ability,
+
+            // Action Economy Commands
+            commands::get_combat_mode,
+            commands::set_combat_mode,
+            commands::spend_actions,
+            commands::adjust_turn_actions,
+            commands::award_hero_points,
+            commands::spend_hero_point,
+            commands::heroic_recovery,
+
+            // Positioning Commands
+            commands::set_battlefield,
+            commands::get_token_layout,
+            commands::move_combatant,
+            commands::check_attack_range,
+
+            // Combat History Commands
+            commands::get_combat_log,
+            commands::undo_combat_action,
+            commands::redo_combat_action,
+            commands::get_combat_history,
+            commands::export_combat_log,
+
+            // Combat Announcer Commands
+            commands::get_combat_announcer_config,
+            commands::save_combat_announcer_config,
+            commands::preview_combat_announcement,
+
             // Character Generation Commands (TASK-018)
             commands::generate_character,
             commands::generate_character_advanced,
+            commands::get_character_builder_choices,
+            commands::finish_character_build,
+            commands::roll_ability_scores,
+            commands::roll_starting_gold,
+            commands::save_built_character,
+            commands::get_built_character,
+            commands::get_level_up_options,
+            commands::validate_level_up,
+            commands::level_up_character,
+            commands::export_character_sheet,
+            commands::export_built_character_sheet,
+            commands::import_character,
+            commands::import_character_to_party,
             commands::get_supported_systems,
             commands::list_system_info,
 
@@ -362,11 +738,19 @@ fn main() {
             backstory_commands::generate_backstory,
             backstory_commands::edit_backstory,
 
+            // Lifepath Commands
+            commands::generate_lifepath,
+
             // Location Generation Commands (TASK-020)
             commands::generate_location_quick,
             commands::generate_location,
             commands::list_location_types,
 
+            // Site Generation Commands
+            commands::generate_settlement,
+            commands::generate_tavern,
+            commands::generate_dungeon,
+
             // Personality Application Commands (TASK-021)
             commands::set_active_personality,
             commands::get_active_personality,
@@ -394,6 +778,8 @@ fn main() {
 
             // NPC Commands
             commands::generate_npc,
+            commands::generate_npc_batch,
+            commands::reskin_stat_block,
             commands::get_npc,
             commands::list_npcs,
             commands::update_npc,
@@ -408,6 +794,22 @@ fn main() {
             commands::list_npc_summaries,
             commands::reply_as_npc,
             commands::stream_npc_chat,
+            commands::list_npc_memories,
+            commands::add_npc_memory,
+            commands::update_npc_memory,
+            commands::forget_npc_memory,
+            commands::forget_all_npc_memories,
+            commands::summarize_npc_conversation,
+            commands::get_npc_dispositions,
+            commands::log_npc_interaction,
+            commands::list_name_banks,
+            commands::generate_names,
+            commands::train_name_bank,
+            commands::delete_name_bank,
+            commands::set_campaign_name_culture,
+            commands::generate_npc_speech_quirks,
+            commands::set_npc_speech_quirks,
+            commands::preview_npc_speech,
 
             // Document Ingestion & Search (Meilisearch)
             commands::ingest_document,
@@ -420,8 +822,38 @@ fn main() {
             commands::clear_and_reingest_document,
             commands::ingest_pdf,
             commands::search,
+            commands::scoped_search,
+            commands::list_term_sets,
+            commands::create_term_set,
+            commands::update_term_set,
+            commands::delete_term_set,
+            commands::sync_search_synonyms,
             commands::check_meilisearch_health,
             commands::reindex_library,
+            commands::list_indexes,
+            commands::get_index_stats,
+            commands::rebuild_index,
+            commands::optimize_index,
+            commands::get_index_migration_status,
+            commands::migrate_indexes,
+            commands::benchmark_vector_search,
+            commands::get_vector_store_stats,
+            commands::list_vector_namespaces,
+            commands::compact_vector_store,
+            commands::cleanup_orphan_vectors,
+            commands::export_vector_store,
+            commands::inspect_vector_backup,
+            commands::restore_vector_store,
+            commands::find_similar,
+            commands::find_duplicate_content,
+            commands::list_bookmarks,
+            commands::create_bookmark,
+            commands::update_bookmark,
+            commands::delete_bookmark,
+            commands::get_session_quick_reference,
+            commands::lookup_spell,
+            commands::lookup_monster,
+            commands::export_search_results,
             commands::get_vector_store_status,
             commands::configure_meilisearch_embedder,
             commands::setup_ollama_embeddings,
@@ -442,6 +874,10 @@ fn main() {
             commands::list_downloadable_piper_voices,
             commands::get_popular_piper_voices,
             commands::download_piper_voice,
+            commands::list_piper_voice_catalog,
+            commands::preview_piper_voice,
+            commands::preview_tts_text,
+            commands::get_voice_fallback_status,
             commands::list_openai_voices,
             commands::list_openai_tts_models,
             commands::list_elevenlabs_voices,
@@ -449,9 +885,20 @@ fn main() {
             commands::queue_voice,
             commands::get_voice_queue,
             commands::cancel_voice,
+            commands::interrupt_voice,
+            commands::pause_voice_queue,
+            commands::resume_voice_queue,
+            commands::replay_last_voice,
             commands::play_tts,
+            commands::play_tts_streaming,
             commands::list_all_voices,
 
+            // NPC Voice Commands
+            commands::create_voice_profile,
+            commands::assign_npc_voice,
+            commands::speak_as_npc,
+            commands::set_voice_pronunciation,
+
             // Audio Cache Commands (TASK-005)
             commands::get_audio_cache_stats,
             commands::get_audio_cache_size,
@@ -459,11 +906,58 @@ fn main() {
             commands::clear_audio_cache_by_tag,
             commands::prune_audio_cache,
             commands::list_audio_cache_entries,
+            commands::get_voice_cache_stats,
+            commands::clear_voice_cache,
+            commands::set_voice_cache_max_size,
+
+            // Read-Aloud Pre-Generation Commands
+            commands::pregenerate_read_aloud,
+            commands::get_session_read_aloud,
+            commands::play_read_aloud,
+            commands::remove_read_aloud_passage,
+            commands::delete_session_read_aloud,
+
+            // Voice Cloning Commands
+            commands::get_voice_clone_requirements,
+            commands::validate_voice_clone_samples,
+            commands::create_voice_clone,
+            commands::list_voice_clones,
+            commands::delete_voice_clone,
+
+            // Session Audio Export Commands
+            commands::export_session_audio,
 
             // Audio Commands
             commands::get_audio_volumes,
             commands::get_sfx_categories,
 
+            // Mixer Commands
+            commands::get_mixer_state,
+            commands::set_channel_volume,
+            commands::set_channel_muted,
+            commands::set_master_volume,
+            commands::set_ducking,
+            commands::list_audio_output_devices,
+            commands::set_channel_output_device,
+
+            // Soundscape Commands
+            commands::play_soundscape,
+            commands::crossfade_soundscape,
+            commands::stop_soundscape,
+            commands::set_soundscape_layer_volume,
+            commands::set_soundscape_volume,
+            commands::get_soundscape_status,
+            commands::list_soundscape_scenes,
+            commands::save_soundscape_preset,
+            commands::delete_soundscape_preset,
+
+            // Sound Effect Trigger Commands
+            commands::get_sfx_triggers,
+            commands::save_sfx_triggers,
+            commands::play_sfx,
+            commands::trigger_sfx_event,
+            commands::scan_text_for_sfx,
+
             // Credential Commands
             commands::save_api_key,
             commands::get_api_key,
@@ -475,9 +969,16 @@ fn main() {
             commands::get_system_info,
             commands::reorder_session,
             commands::get_campaign_stats,
+            commands::get_campaign_dashboard,
             commands::generate_campaign_cover,
             commands::transcribe_audio,
 
+            // Push-to-Talk Commands
+            commands::list_audio_input_devices,
+            commands::start_push_to_talk,
+            commands::stop_push_to_talk,
+            commands::cancel_push_to_talk,
+
             // Campaign Versioning Commands (TASK-006)
             commands::create_campaign_version,
             commands::list_campaign_versions,
@@ -487,6 +988,8 @@ fn main() {
             commands::delete_campaign_version,
             commands::add_version_tag,
             commands::mark_version_milestone,
+            commands::get_snapshot_storage_stats,
+            commands::collect_snapshot_garbage,
 
             // World State Commands (TASK-007)
             commands::get_world_state,
@@ -507,6 +1010,70 @@ fn main() {
             commands::set_calendar_config,
             commands::get_calendar_config,
 
+            // Fantasy Calendar Commands
+            commands::list_builtin_calendars,
+            commands::set_campaign_calendar,
+            commands::get_campaign_calendar,
+            commands::describe_in_game_date,
+            commands::add_days_to_date,
+            commands::days_between_dates,
+            commands::add_recurring_event,
+            commands::update_recurring_event,
+            commands::remove_recurring_event,
+            commands::list_recurring_events,
+            commands::add_npc_schedule_entry,
+            commands::remove_npc_schedule_entry,
+            commands::list_npc_schedule,
+            commands::advance_time,
+
+            // NPC Presence Commands
+            commands::where_is_npc,
+            commands::who_is_at_location,
+            commands::set_npc_presence,
+            commands::remove_npc_presence,
+            commands::list_npc_presence,
+
+            // Weather Commands
+            commands::set_location_climate,
+            commands::get_location_climate,
+            commands::get_weather,
+            commands::get_forecast,
+
+            // Travel Commands
+            commands::set_route_segment,
+            commands::list_route_segments,
+            commands::delete_route_segment,
+            commands::plan_travel,
+            commands::travel_to_location,
+
+            // Shared Setting Commands
+            commands::create_world_setting,
+            commands::get_world_setting,
+            commands::update_world_setting,
+            commands::delete_world_setting,
+            commands::list_world_settings,
+            commands::set_setting_propagation,
+            commands::join_world_setting,
+            commands::leave_world_setting,
+            commands::set_setting_event_sharing,
+            commands::list_setting_campaigns,
+            commands::share_with_setting,
+            commands::save_setting_deity,
+            commands::remove_setting_deity,
+            commands::set_setting_calendar,
+            commands::get_campaign_world,
+            commands::set_setting_override,
+            commands::clear_setting_override,
+            commands::list_setting_overrides,
+
+            // World Update Commands
+            commands::propose_world_updates,
+            commands::list_world_changesets,
+            commands::get_world_changeset,
+            commands::decide_world_change,
+            commands::discard_world_changeset,
+            commands::apply_world_changeset,
+
             // Entity Relationship Commands (TASK-009)
             commands::create_entity_relationship,
             commands::get_entity_relationship,
@@ -536,6 +1103,10 @@ fn main() {
             commands::get_click_distribution,
             commands::record_search_selection,
 
+            // Search Autocomplete
+            commands::get_search_suggestions,
+            commands::rebuild_suggestion_index,
+
             // TASK-024: Security Audit Commands
             commands::get_audit_logs,
             commands::query_audit_logs,
@@ -579,6 +1150,16 @@ fn main() {
             commands::get_extraction_presets,
             commands::check_ocr_availability,
 
+            // Search Settings Commands
+            commands::get_search_settings,
+            commands::save_search_settings,
+            commands::get_index_tuning,
+            commands::update_index_tuning,
+            commands::reset_index_tuning,
+            commands::get_vector_tuning,
+            commands::update_vector_tuning,
+            commands::reset_vector_tuning,
+
             // Claude OAuth Commands
             commands::oauth::claude::claude_get_status,
             commands::oauth::claude::claude_start_oauth,
@@ -661,6 +1242,8 @@ fn main() {
             commands::archetype::setting_packs::deactivate_setting_pack,
             commands::archetype::setting_packs::get_active_setting_pack,
             commands::archetype::setting_packs::get_setting_pack_versions,
+            commands::archetype::packs::import_archetype_pack,
+            commands::archetype::packs::export_archetype_pack,
 
             // Archetype Resolution Commands (TASK-ARCH-063)
             commands::archetype::resolution::resolve_archetype,
@@ -696,6 +1279,7 @@ fn main() {
             commands::clear_rag_config,
             commands::rag_query,
             commands::rag_query_stream,
+            commands::ask_rules,
 
             // Query Preprocessing Commands (REQ-QP-003)
             commands::search_with_preprocessing,
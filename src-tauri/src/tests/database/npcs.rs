@@ -1,9 +1,10 @@
 //! NPC Database Tests
 //!
-//! Tests for NPC CRUD operations, conversations, and personalities.
+//! Tests for NPC CRUD operations, conversations, memories, and personalities.
 
 use crate::database::{
-    CampaignOps, CampaignRecord, NpcOps, NpcRecord, NpcConversation, PersonalityRecord,
+    CampaignOps, CampaignRecord, NpcOps, NpcRecord, NpcConversation, NpcMemoryRecord,
+    PersonalityRecord,
 };
 use crate::tests::common::create_test_db;
 
@@ -343,6 +344,100 @@ async fn test_npc_conversation_update() {
     assert_eq!(retrieved.unread_count, 1);
 }
 
+// =============================================================================
+// NPC Memory Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_npc_memories() {
+    let (db, _temp) = create_test_db().await;
+
+    let campaign = CampaignRecord::new(
+        "camp-mem".to_string(),
+        "Memory Test".to_string(),
+        "D&D 5e".to_string(),
+    );
+    db.create_campaign(&campaign)
+        .await
+        .expect("Failed to create campaign");
+
+    let npc = NpcRecord {
+        id: "npc-mem".to_string(),
+        campaign_id: Some("camp-mem".to_string()),
+        name: "Forgetful Innkeeper".to_string(),
+        role: "Innkeeper".to_string(),
+        personality_id: None,
+        personality_json: "{}".to_string(),
+        data_json: None,
+        stats_json: None,
+        notes: None,
+        location_id: None,
+        voice_profile_id: None,
+        quest_hooks: None,
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    db.save_npc(&npc).await.expect("Failed to save NPC");
+
+    let mut conversation = NpcConversation::new(
+        "conv-mem".to_string(),
+        "npc-mem".to_string(),
+        "camp-mem".to_string(),
+    );
+    conversation.summarized_count = 4;
+    db.save_npc_conversation(&conversation)
+        .await
+        .expect("Failed to save conversation");
+    let retrieved = db
+        .get_npc_conversation("npc-mem")
+        .await
+        .expect("Failed to get conversation")
+        .expect("Conversation not found");
+    assert_eq!(retrieved.summarized_count, 4);
+
+    let debt = NpcMemoryRecord::new(
+        "npc-mem".to_string(),
+        "camp-mem".to_string(),
+        "fact",
+        "The party owes 5 gold for the broken table".to_string(),
+        4,
+    );
+    let summary = NpcMemoryRecord::new(
+        "npc-mem".to_string(),
+        "camp-mem".to_string(),
+        "summary",
+        "The party asked about the old mill".to_string(),
+        9,
+    );
+    assert_eq!(summary.importance, 5);
+    db.save_npc_memory(&debt).await.expect("Failed to save memory");
+    db.save_npc_memory(&summary).await.expect("Failed to save memory");
+
+    let mut edited = debt.clone();
+    edited.content = "The party paid for the broken table".to_string();
+    db.save_npc_memory(&edited).await.expect("Failed to edit memory");
+    let fetched = db
+        .get_npc_memory(&debt.id)
+        .await
+        .expect("Failed to get memory")
+        .expect("Memory not found");
+    assert_eq!(fetched.content, "The party paid for the broken table");
+
+    db.delete_npc_memory(&summary.id)
+        .await
+        .expect("Failed to delete memory");
+    let memories = db
+        .list_npc_memories("npc-mem")
+        .await
+        .expect("Failed to list memories");
+    assert_eq!(memories.len(), 1);
+
+    let cleared = db
+        .clear_npc_memories("npc-mem")
+        .await
+        .expect("Failed to clear memories");
+    assert_eq!(cleared, 1);
+}
+
 // =============================================================================
 // Personality Tests
 // =============================================================================
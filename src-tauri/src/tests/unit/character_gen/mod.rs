@@ -27,6 +27,12 @@
 //! - Derived statistics (HP, Sanity, Magic Points)
 //! - Backstory generation elements
 //!
+//! ### Savage Worlds (`savage_worlds_tests`)
+//! - Attributes and skills as die types
+//! - Ancestry bonuses
+//! - Edges and Hindrances
+//! - Derived statistics (Pace, Parry, Toughness)
+//!
 //! ## Running Tests
 //!
 //! ```bash
@@ -41,8 +47,12 @@
 //!
 //! # Run Call of Cthulhu tests only
 //! cargo test tests::unit::character_gen::coc_tests
+//!
+//! # Run Savage Worlds tests only
+//! cargo test tests::unit::character_gen::savage_worlds_tests
 //! ```
 
 mod dnd5e_tests;
 mod pf2e_tests;
 mod coc_tests;
+mod savage_worlds_tests;
@@ -0,0 +1,192 @@
+//! Savage Worlds Character Generator Unit Tests
+//!
+//! Tests for Savage Worlds Adventure Edition character generation including:
+//! - Attributes and skills as die types
+//! - Ancestry bonuses
+//! - Edges and Hindrances
+//! - Derived statistics (Pace, Parry, Toughness)
+
+use crate::core::character_gen::{
+    systems::savage_worlds::SavageWorldsGenerator,
+    GameSystem, GenerationOptions, SystemGenerator, TraitType,
+};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn create_test_generator() -> SavageWorldsGenerator {
+    SavageWorldsGenerator::new()
+}
+
+fn create_default_options() -> GenerationOptions {
+    GenerationOptions {
+        system: Some("savage_worlds".to_string()),
+        ..Default::default()
+    }
+}
+
+fn derived(character: &crate::core::character_gen::Character, name: &str) -> i32 {
+    character.derived_stats.iter()
+        .find(|s| s.name == name)
+        .unwrap_or_else(|| panic!("Missing derived stat: {}", name))
+        .value
+        .parse()
+        .unwrap()
+}
+
+const DIE_TYPES: [i32; 5] = [4, 6, 8, 10, 12];
+
+// ============================================================================
+// Character Creation Tests
+// ============================================================================
+
+#[cfg(test)]
+mod character_creation {
+    use super::*;
+
+    #[test]
+    fn test_create_character_with_defaults() {
+        let generator = create_test_generator();
+        let character = generator.generate(&create_default_options()).unwrap();
+
+        assert_eq!(character.system, GameSystem::SavageWorlds);
+        assert_eq!(character.level, 1);
+        assert!(character.race.is_some());
+        assert!(character.class.is_some());
+        assert_eq!(character.attributes.len(), 5);
+    }
+
+    #[test]
+    fn test_attributes_and_skills_are_die_types() {
+        let generator = create_test_generator();
+
+        for _ in 0..20 {
+            let character = generator.generate(&create_default_options()).unwrap();
+            for (name, attr) in &character.attributes {
+                assert!(DIE_TYPES.contains(&attr.base), "{} is d{}", name, attr.base);
+            }
+            for (name, value) in &character.skills {
+                assert!(DIE_TYPES.contains(value), "{} is d{}", name, value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_core_skills_present() {
+        let generator = create_test_generator();
+        let character = generator.generate(&create_default_options()).unwrap();
+
+        for skill in ["Athletics", "Common Knowledge", "Notice", "Persuasion", "Stealth"] {
+            assert!(character.skills.contains_key(skill), "Missing core skill: {}", skill);
+        }
+    }
+
+    #[test]
+    fn test_ancestry_bonus_applied() {
+        let generator = create_test_generator();
+        let options = GenerationOptions {
+            race: Some("Dwarf".to_string()),
+            ..create_default_options()
+        };
+        let character = generator.generate(&options).unwrap();
+
+        assert!(character.attributes.get("Vigor").unwrap().base >= 6);
+        assert_eq!(derived(&character, "Pace"), 5);
+    }
+}
+
+// ============================================================================
+// Edge & Hindrance Tests
+// ============================================================================
+
+#[cfg(test)]
+mod edges_and_hindrances {
+    use super::*;
+
+    #[test]
+    fn test_one_major_two_minor_hindrances() {
+        let generator = create_test_generator();
+        let character = generator.generate(&create_default_options()).unwrap();
+
+        let hindrances: Vec<_> = character.traits.iter()
+            .filter(|t| t.trait_type == TraitType::Hindrance)
+            .collect();
+        assert_eq!(hindrances.len(), 3);
+        assert_eq!(hindrances.iter().filter(|t| t.description == "Major Hindrance").count(), 1);
+    }
+
+    #[test]
+    fn test_humans_get_extra_edge() {
+        let generator = create_test_generator();
+        let edges = |race: &str| {
+            let options = GenerationOptions {
+                race: Some(race.to_string()),
+                class: Some("Soldier".to_string()),
+                ..create_default_options()
+            };
+            generator.generate(&options).unwrap().traits.iter()
+                .filter(|t| t.trait_type == TraitType::Edge)
+                .count()
+        };
+
+        assert_eq!(edges("Human"), 2);
+        assert_eq!(edges("Elf"), 1);
+    }
+
+    #[test]
+    fn test_arcane_archetypes_get_arcane_background() {
+        let generator = create_test_generator();
+        let options = GenerationOptions {
+            class: Some("Mage".to_string()),
+            ..create_default_options()
+        };
+        let character = generator.generate(&options).unwrap();
+
+        assert!(character.traits.iter().any(|t| t.name == "Arcane Background (Magic)"));
+        assert!(character.skills.contains_key("Spellcasting"));
+    }
+}
+
+// ============================================================================
+// Derived Stats & Validation Tests
+// ============================================================================
+
+#[cfg(test)]
+mod derived_and_validation {
+    use super::*;
+
+    #[test]
+    fn test_parry_and_toughness() {
+        let generator = create_test_generator();
+
+        for _ in 0..10 {
+            let character = generator.generate(&create_default_options()).unwrap();
+            let fighting = character.skills.get("Fighting").copied().unwrap_or(0);
+            let vigor = character.attributes.get("Vigor").unwrap().base;
+
+            assert_eq!(derived(&character, "Parry"), 2 + fighting / 2);
+            assert!(derived(&character, "Toughness") >= 2 + vigor / 2 - 1);
+            assert!(character.notes.contains("Toughness:"));
+        }
+    }
+
+    #[test]
+    fn test_rejects_unknown_archetype_and_ancestry() {
+        let generator = create_test_generator();
+
+        let bad_class = GenerationOptions {
+            class: Some("Wizard".to_string()),
+            ..create_default_options()
+        };
+        assert!(generator.validate_options(&bad_class).is_err());
+
+        let bad_race = GenerationOptions {
+            race: Some("Tiefling".to_string()),
+            ..create_default_options()
+        };
+        assert!(generator.validate_options(&bad_race).is_err());
+
+        assert!(generator.validate_options(&create_default_options()).is_ok());
+    }
+}
@@ -18,10 +18,14 @@
 //! - Session snapshot creation/restoration
 
 
+use std::collections::HashMap;
+
 use crate::core::session_manager::{
-    CombatEventType, CombatState, CombatStatus, Combatant, CombatantType,
-    GameSession, LogEntryType, SessionError, SessionManager,
-    SessionStatus,
+    AbilityError, AreaDamage, Battlefield, CaptureCategory, ClockConfig, ClockNoticeKind, CombatEventType, CombatMode, CombatState, CombatStatus,
+    Combatant, CombatantType, CurrentCombatant, Damage, DamageDefenses, EconomyError, GameSession,
+    EncounterDifficulty, GroupHpMode, GroupTargets, HealthStatus, LogEntryType, MoraleError, MoraleGroup, MoraleRules,
+    MoraleTrigger, Position, TurnOrderError, PositionError, SessionError, SessionManager, SessionStatus,
+    SceneEstimate, TrackedAbility,
 };
 
 use crate::core::session::conditions::{
@@ -31,6 +35,7 @@ use crate::core::session::conditions::{
 use crate::core::session::timeline::{
     EventSeverity, TimelineEvent, TimelineEventType,
 };
+use crate::core::session::journal::CombatJournal;
 use crate::core::session::notes::{
     EntityType as NoteEntityType, NoteCategory, SessionNote,
 };
@@ -846,6 +851,38 @@ mod hp_modification_tests {
         assert_eq!(remaining, 0);
     }
 
+    #[test]
+    fn test_typed_damage_breakdown() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+
+        let mut combatant = create_combatant_with_hp("Troll", 15, 40, 40, Some(5));
+        combatant.damage_defenses.vulnerabilities = vec!["acid".to_string()];
+        combatant.damage_defenses.immunities = vec!["poison".to_string()];
+        let combatant_id = combatant.id.clone();
+        manager.add_combatant(&session.id, combatant).unwrap();
+
+        let breakdown = manager
+            .take_damage(&session.id, &combatant_id, &Damage::new(8).with_type("acid"))
+            .unwrap();
+        assert!(breakdown.adjustment.vulnerable);
+        assert_eq!((breakdown.effective, breakdown.absorbed, breakdown.hp_after), (16, 5, 29));
+
+        let breakdown = manager
+            .take_damage(&session.id, &combatant_id, &Damage::new(12).with_type("poison"))
+            .unwrap();
+        assert_eq!((breakdown.effective, breakdown.hp_lost()), (0, 0));
+
+        manager
+            .set_damage_defenses(&session.id, &combatant_id, DamageDefenses::default())
+            .unwrap();
+        let breakdown = manager
+            .take_damage(&session.id, &combatant_id, &Damage::new(12).with_type("poison"))
+            .unwrap();
+        assert_eq!(breakdown.hp_after, 17);
+    }
+
     #[test]
     fn test_heal_combatant() {
         let manager = create_test_manager();
@@ -1417,6 +1454,38 @@ mod combat_death_tests {
         assert_eq!(remaining, 0);
     }
 
+    #[test]
+    fn test_area_damage_to_many() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+
+        let goblin = create_monster("Goblin", 15, 10);
+        let mut ogre = create_monster("Ogre", 8, 59);
+        ogre.damage_defenses.resistances = vec!["fire".to_string()];
+        let ids = vec![goblin.id.clone(), ogre.id.clone()];
+        manager.add_combatant(&session.id, goblin).unwrap();
+        manager.add_combatant(&session.id, ogre).unwrap();
+
+        // A missing target stops the whole effect
+        let fire = AreaDamage::new("20").with_type("fire");
+        let with_missing = vec![ids[0].clone(), "missing".to_string()];
+        let result = manager.apply_damage_to_many(&session.id, &with_missing, &fire, &HashMap::new());
+        assert!(matches!(result, Err(SessionError::CombatantNotFound(id)) if id == "missing"));
+        assert!(manager.get_combat_log(&session.id).is_empty());
+
+        let saves = HashMap::from([(ids[1].clone(), true)]);
+        let result = manager.apply_damage_to_many(&session.id, &ids, &fire, &saves).unwrap();
+        assert_eq!(result.rolled, 20);
+        assert_eq!(result.targets.iter().map(|t| t.breakdown.effective).collect::<Vec<_>>(), vec![20, 5]);
+        assert_eq!(result.targets[0].breakdown.hp_after, 0);
+        assert_eq!(result.targets[1].breakdown.hp_after, 54);
+
+        let step = manager.undo_combat_action(&session.id).unwrap();
+        assert_eq!(step.description, "20 fire damage to 2 targets");
+        assert!(step.combat.combatants.iter().all(|c| c.current_hp == c.max_hp));
+    }
+
     #[test]
     fn test_remove_dead_combatant() {
         let manager = create_test_manager();
@@ -2036,6 +2105,439 @@ mod combat_event_logging_tests {
         assert_eq!(action_event.round, 2);
     }
 
+    #[test]
+    fn test_undo_redo_combat_actions() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        manager.add_combatant_quick(&session.id, "Goblin", 12, CombatantType::Monster).unwrap();
+
+        manager.damage_combatant(&session.id, &fighter_id, 10).unwrap();
+        manager.add_condition_by_name(&session.id, &fighter_id, "Poisoned", None, None, None).unwrap();
+        manager.next_turn(&session.id).unwrap();
+        assert_eq!(manager.combat_history_status(&session.id).undo.as_deref(), Some("Next turn"));
+
+        let step = manager.undo_combat_action(&session.id).unwrap();
+        assert_eq!(step.combat.current_turn, 0);
+        manager.undo_combat_action(&session.id).unwrap();
+        let step = manager.undo_combat_action(&session.id).unwrap();
+        assert_eq!(step.description, "10 damage to Fighter");
+        let fighter = step.combat.get_combatant(&fighter_id).unwrap();
+        assert_eq!(fighter.current_hp, Some(30));
+        assert!(fighter.condition_tracker.conditions().is_empty());
+        assert!(matches!(manager.undo_combat_action(&session.id), Err(SessionError::NothingToUndo)));
+
+        let step = manager.redo_combat_action(&session.id).unwrap();
+        assert_eq!(step.combat.get_combatant(&fighter_id).unwrap().current_hp, Some(20));
+        assert_eq!(step.status.redo_depth, 2);
+
+        // The log keeps the undone actions alongside the undos and redos
+        let events = manager.get_combat_log(&session.id);
+        assert_eq!(events.iter().filter(|e| matches!(e.event_type, CombatEventType::Damage)).count(), 1);
+        assert_eq!(events.iter().filter(|e| matches!(e.event_type, CombatEventType::Undo)).count(), 3);
+
+        // A new action drops what was waiting to be redone
+        manager.heal_combatant(&session.id, &fighter_id, 5).unwrap();
+        assert!(matches!(manager.redo_combat_action(&session.id), Err(SessionError::NothingToRedo)));
+    }
+
+    #[test]
+    fn test_hero_points_carry_between_combats() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+        manager.set_combat_mode(&session.id, CombatMode::ThreeAction).unwrap();
+
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        assert_eq!(manager.spend_hero_point(&session.id, &fighter_id).unwrap(), 0);
+        assert!(matches!(
+            manager.spend_hero_point(&session.id, &fighter_id),
+            Err(SessionError::Economy(EconomyError::NoHeroPoints(_)))
+        ));
+        manager.end_combat(&session.id).unwrap();
+
+        // The spent point doesn't come back in the next fight
+        manager.start_combat(&session.id).unwrap();
+        manager.set_combat_mode(&session.id, CombatMode::ThreeAction).unwrap();
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        assert_eq!(manager.get_combat(&session.id).unwrap().hero_points(&fighter_id), Some(0));
+        assert_eq!(manager.award_hero_points(&session.id, &fighter_id, 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_positions_and_token_layout() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        assert!(manager.token_layout(&session.id).unwrap().is_none());
+
+        manager.set_battlefield(&session.id, Some(Battlefield::grid(12, 8))).unwrap();
+        manager.move_combatant(&session.id, &fighter_id, Position::square(2, 3)).unwrap();
+        assert!(matches!(
+            manager.move_combatant(&session.id, &fighter_id, Position::square(12, 0)),
+            Err(SessionError::Position(PositionError::OutOfBounds { x: 12, y: 0 }))
+        ));
+
+        let layout = manager.token_layout(&session.id).unwrap().unwrap();
+        assert_eq!(layout.tokens.len(), 1);
+        assert_eq!(layout.tokens[0].position, Some(Position::square(2, 3)));
+        assert!(layout.tokens[0].is_current);
+
+        manager.move_combatant(&session.id, &fighter_id, Position::square(5, 3)).unwrap();
+        manager.undo_combat_action(&session.id).unwrap();
+        let fighter = manager.get_combat(&session.id).unwrap().get_combatant(&fighter_id).cloned().unwrap();
+        assert_eq!(fighter.position, Some(Position::square(2, 3)));
+    }
+
+    #[test]
+    fn test_limited_ability_use_can_be_undone() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+        let dragon = create_combatant_with_hp("Dragon", 20, 200, 200, None);
+        let dragon_id = dragon.id.clone();
+        manager.add_combatant(&session.id, dragon).unwrap();
+        let breath = TrackedAbility::from_feature("Fire Breath (Recharge 5-6)", "").unwrap();
+        manager.track_ability(&session.id, &dragon_id, breath).unwrap();
+
+        assert_eq!(manager.use_ability(&session.id, &dragon_id, "Fire Breath").unwrap().uses_left, 0);
+        assert!(matches!(
+            manager.use_ability(&session.id, &dragon_id, "Fire Breath"),
+            Err(SessionError::Ability(AbilityError::Unavailable(_)))
+        ));
+        assert_eq!(manager.combat_history_status(&session.id).undo.as_deref(), Some("Dragon uses Fire Breath"));
+
+        manager.undo_combat_action(&session.id).unwrap();
+        let current = CurrentCombatant::from(manager.get_current_combatant(&session.id).unwrap());
+        assert_eq!(current.ability_alerts, vec!["Fire Breath available!".to_string()]);
+    }
+
+    #[test]
+    fn test_combat_report_stored_with_session() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        assert!(manager.get_combat_report(&session.id, None).unwrap().is_none());
+        manager.start_combat(&session.id).unwrap();
+        manager.set_estimated_difficulty(&session.id, Some(EncounterDifficulty::Hard)).unwrap();
+
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        let ogre = create_monster("Ogre", 8, 20);
+        let ogre_id = ogre.id.clone();
+        manager.add_combatant(&session.id, ogre).unwrap();
+
+        manager.take_damage(&session.id, &fighter_id, &Damage::new(6).by(&ogre_id)).unwrap();
+        manager.take_damage(&session.id, &ogre_id, &Damage::new(20).by(&fighter_id)).unwrap();
+        manager.add_condition_by_name(&session.id, &ogre_id, "Prone", None, None, None).unwrap();
+        // Undone damage doesn't count
+        manager.apply_damage(&session.id, &fighter_id, 10, false).unwrap();
+        manager.undo_combat_action(&session.id).unwrap();
+
+        let report = manager.end_combat(&session.id).unwrap();
+        assert_eq!(report.total_damage, 26);
+        assert_eq!(report.total_kills, 1);
+        assert_eq!(report.top_conditions[0].condition, "prone");
+        assert_eq!(report.actual_difficulty, EncounterDifficulty::Easy);
+        assert_eq!(
+            report.calibration.as_deref(),
+            Some("Played easier than estimated (Hard, played Easy)")
+        );
+
+        let stored = manager.get_combat_report(&session.id, Some(&report.combat_id)).unwrap().unwrap();
+        assert_eq!(stored.combatants.iter().find(|c| c.name == "Fighter").unwrap().kills, 1);
+        assert!(manager.get_combat_report(&session.id, Some("other")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_session_clock_reminders() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+        let status = manager.session_clock(&session.id).unwrap();
+        assert_eq!(status.elapsed_minutes, 0);
+        assert_eq!(status.next_break_in_minutes, Some(90));
+
+        manager
+            .configure_session_clock(
+                &session.id,
+                ClockConfig { break_interval_minutes: 60, pacing_nudges: true, ..Default::default() },
+            )
+            .unwrap();
+        manager
+            .set_clock_plan(
+                &session.id,
+                vec![SceneEstimate { name: "Opening Hook".to_string(), estimated_minutes: 30 }],
+            )
+            .unwrap();
+
+        let later = chrono::Utc::now() + chrono::Duration::minutes(61);
+        let kinds: Vec<_> = manager.check_session_clocks(later).into_iter().map(|n| n.kind).collect();
+        assert_eq!(kinds, vec![ClockNoticeKind::Break, ClockNoticeKind::Pacing]);
+        assert!(manager.check_session_clocks(later).is_empty());
+
+        manager.pause_session(&session.id).unwrap();
+        assert!(manager.session_clock(&session.id).unwrap().paused);
+
+        manager.end_session(&session.id).unwrap();
+        assert!(matches!(manager.session_clock(&session.id), Err(SessionError::SessionNotFound(_))));
+    }
+
+    #[test]
+    fn test_timeline_capture_toggles() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+
+        manager.start_combat(&session.id).unwrap();
+        manager.add_combatant(&session.id, create_monster("Goblin", 12, 7)).unwrap();
+        manager.end_combat(&session.id).unwrap();
+        let ended = manager.get_timeline_events_by_type(&session.id, &TimelineEventType::CombatEnd);
+        assert_eq!(ended.len(), 1);
+        assert!(ended[0].tags.iter().any(|t| t == "auto"));
+        assert_eq!(ended[0].entity_refs[0].name, "Goblin");
+
+        manager.set_capture_category(CaptureCategory::Combat, false);
+        manager.start_combat(&session.id).unwrap();
+        manager.end_combat(&session.id).unwrap();
+        assert_eq!(manager.get_timeline_events_by_type(&session.id, &TimelineEventType::CombatStart).len(), 1);
+
+        let captured = manager.capture_campaign_event("campaign-1", CaptureCategory::DiceRolls, |session_id| {
+            crate::core::session::capture::dice_roll_event(session_id, "1d20", 20, None, Some(true))
+        });
+        assert_eq!(captured.map(|e| e.session_id), Some(session.id.clone()));
+        assert!(manager
+            .capture_campaign_event("campaign-2", CaptureCategory::DiceRolls, |session_id| {
+                crate::core::session::capture::dice_roll_event(session_id, "1d20", 4, None, None)
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn test_player_view_hides_gm_details() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+        manager.start_combat(&session.id).unwrap();
+        manager.add_combatant(&session.id, create_combatant_with_hp("Fighter", 15, 22, 30, None)).unwrap();
+        let ogre = create_monster("Ogre", 8, 59);
+        let ogre_id = ogre.id.clone();
+        manager.add_combatant(&session.id, ogre).unwrap();
+        manager.damage_combatant(&session.id, &ogre_id, 40).unwrap();
+
+        manager
+            .add_timeline_event(
+                &session.id,
+                TimelineEvent::new(&session.id, TimelineEventType::Custom("world_event".to_string()), "Cult gathers", "")
+                    .with_severity(EventSeverity::Important)
+                    .with_tags(["gm_only"]),
+            )
+            .unwrap();
+
+        let view = manager.player_view(&session.id).unwrap();
+        let combat = view.combat.unwrap();
+        let fighter = combat.combatants.iter().find(|c| c.name == "Fighter").unwrap();
+        assert_eq!(fighter.current_hp, Some(22));
+        let ogre = combat.combatants.iter().find(|c| c.name == "Ogre").unwrap();
+        assert_eq!((ogre.current_hp, ogre.health), (None, HealthStatus::Bloodied));
+        assert_eq!(view.timeline[0].title, "Combat Initiated");
+        assert!(view.timeline.iter().all(|e| e.title != "Cult gathers"));
+
+        manager.end_combat(&session.id).unwrap();
+        assert!(manager.player_view(&session.id).unwrap().combat.is_none());
+        assert!(manager.player_view("missing").is_err());
+    }
+
+    #[test]
+    fn test_minion_group_damage() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+        manager.start_combat(&session.id).unwrap();
+        let fighter = create_combatant_with_hp("Fighter", 18, 30, 30, None);
+        let fighter_id = fighter.id.clone();
+        manager.add_combatant(&session.id, fighter).unwrap();
+        let skeletons = manager
+            .add_combatant_group(&session.id, create_monster("Skeletons", 10, 13), 12, GroupHpMode::Pooled)
+            .unwrap();
+        assert_eq!((skeletons.current_hp, skeletons.max_hp), (Some(156), Some(156)));
+
+        let damage = Damage { source_id: Some(fighter_id.clone()), ..Damage::new(30) };
+        let result = manager
+            .damage_group(&session.id, &skeletons.id, &damage, &GroupTargets::Count(1))
+            .unwrap();
+        assert_eq!((result.hits.dropped.len(), result.standing), (2, 10));
+        assert_eq!(result.breakdown.hp_after, 126);
+
+        // Plain damage hits the group too; undo rewinds the whole volley
+        manager.damage_combatant(&session.id, &skeletons.id, 500).unwrap();
+        let group = manager.get_combat(&session.id).unwrap().get_combatant(&skeletons.id).unwrap().clone();
+        assert!(group.death.is_dead());
+        manager.undo_combat_action(&session.id).unwrap();
+        let group = manager.get_combat(&session.id).unwrap().get_combatant(&skeletons.id).unwrap().clone();
+        assert_eq!(group.group.unwrap().standing(), 10);
+
+        assert!(matches!(
+            manager.damage_group(&session.id, &fighter_id, &Damage::new(5), &GroupTargets::Count(1)),
+            Err(SessionError::Group(_))
+        ));
+
+        let report = manager.end_combat(&session.id).unwrap();
+        let fighter = report.combatants.iter().find(|c| c.name == "Fighter").unwrap();
+        assert_eq!(fighter.kills, 2);
+    }
+
+    #[test]
+    fn test_morale_checks() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+        manager.start_combat(&session.id).unwrap();
+        manager.set_morale_rules(&session.id, MoraleRules::TwoD6).unwrap();
+        let goblins: Vec<Combatant> = ["Boss", "Goblin 1", "Goblin 2", "Goblin 3"]
+            .into_iter()
+            .map(|name| create_monster(name, 12, 7))
+            .collect();
+        let ids: Vec<String> = goblins.iter().map(|g| g.id.clone()).collect();
+        for goblin in goblins {
+            manager.add_combatant(&session.id, goblin).unwrap();
+        }
+
+        let unknown = MoraleGroup::new("goblins", vec!["nobody".to_string()], 7).unwrap();
+        assert!(matches!(manager.add_morale_group(&session.id, unknown), Err(SessionError::CombatantNotFound(_))));
+        let group = MoraleGroup::new("goblins", ids.clone(), 12).unwrap().with_leader(&ids[0]);
+        let group = manager.add_morale_group(&session.id, group).unwrap();
+
+        manager.damage_combatant(&session.id, &ids[1], 10).unwrap();
+        assert!(manager.check_morale(&session.id).unwrap().is_empty());
+
+        // The leader falling brings half the group down too; one roll covers both
+        manager.damage_combatant(&session.id, &ids[0], 10).unwrap();
+        let checks = manager.check_morale(&session.id).unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!((checks[0].trigger, checks[0].holds), (MoraleTrigger::LeaderKilled, Some(true)));
+        assert!(checks[0].suggestion.starts_with("The goblins hold their ground"));
+        assert!(manager.check_morale(&session.id).unwrap().is_empty());
+        assert!(manager.get_combat_log(&session.id).iter().any(|e| e.description == checks[0].suggestion));
+
+        let called = manager.call_morale_check(&session.id, &group.id).unwrap();
+        assert_eq!(called.trigger, MoraleTrigger::Called);
+        manager.remove_morale_group(&session.id, &group.id).unwrap();
+        assert!(matches!(
+            manager.call_morale_check(&session.id, &group.id),
+            Err(SessionError::Morale(MoraleError::GroupNotFound(_)))
+        ));
+    }
+
+    #[test]
+    fn test_delay_ready_and_reorder() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-1", 1);
+        manager.start_combat(&session.id).unwrap();
+        let fighter = manager.add_combatant_quick(&session.id, "Fighter", 20, CombatantType::Player).unwrap();
+        let rogue = manager.add_combatant_quick(&session.id, "Rogue", 15, CombatantType::Player).unwrap();
+        manager.add_combatant(&session.id, create_monster("Goblin", 10, 7)).unwrap();
+        let orc = create_monster("Orc", 5, 15);
+        manager.add_combatant(&session.id, orc.clone()).unwrap();
+
+        assert!(matches!(
+            manager.delay_turn(&session.id, &rogue.id),
+            Err(SessionError::TurnOrder(TurnOrderError::NotTheirTurn(_)))
+        ));
+        let result = manager.delay_turn(&session.id, &fighter.id).unwrap();
+        assert_eq!(result.current_combatant.unwrap().name, "Rogue");
+
+        // The fighter comes back after the goblin, taking the orc's initiative
+        manager.next_turn(&session.id).unwrap();
+        let result = manager.resume_delayed_turn(&session.id, &fighter.id).unwrap();
+        let back = result.current_combatant.unwrap();
+        assert_eq!((back.name.as_str(), back.initiative, back.delaying), ("Fighter", 5, false));
+        let names = |manager: &SessionManager| -> Vec<String> {
+            manager.get_combat(&session.id).unwrap().combatants.iter().map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(names(&manager), vec!["Rogue", "Goblin", "Fighter", "Orc"]);
+
+        // A readied action comes up on its trigger's turn
+        manager.ready_action(&session.id, &rogue.id, "shoot", "the orc charges", Some(orc.id.clone())).unwrap();
+        let result = manager.advance_turn(&session.id).unwrap();
+        assert_eq!(result.readied.len(), 1);
+        assert_eq!(result.readied[0].describe(), "Rogue is ready to shoot when the orc charges");
+        manager.take_readied_action(&session.id, &rogue.id).unwrap();
+        assert!(matches!(
+            manager.take_readied_action(&session.id, &rogue.id),
+            Err(SessionError::TurnOrder(TurnOrderError::NothingReadied(_)))
+        ));
+
+        // Moving by hand is logged and undoable; reinforcements keep the order
+        assert_eq!(manager.move_in_initiative(&session.id, &orc.id, 0).unwrap(), 15);
+        assert_eq!(manager.get_combat(&session.id).unwrap().current_combatant().unwrap().name, "Orc");
+        assert!(manager.get_combat_log(&session.id).iter().any(|e| e.description.contains("Orc moved to place 1")));
+        manager.add_combatant_quick(&session.id, "Wolf", 12, CombatantType::Monster).unwrap();
+        assert_eq!(names(&manager), vec!["Orc", "Rogue", "Wolf", "Goblin", "Fighter"]);
+        assert!(matches!(
+            manager.move_in_initiative(&session.id, &orc.id, 9),
+            Err(SessionError::TurnOrder(TurnOrderError::InvalidPosition(9)))
+        ));
+    }
+
+    #[test]
+    fn test_combat_recovered_from_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = create_test_manager();
+        manager.set_combat_journal(CombatJournal::new(dir.path().to_path_buf()));
+        let session = manager.start_session("campaign-1", 1);
+        manager.start_combat(&session.id).unwrap();
+        let goblin = create_monster("Goblin", 12, 7);
+        manager.add_combatant(&session.id, goblin.clone()).unwrap();
+        manager.add_combatant_quick(&session.id, "Aria", 15, CombatantType::Player).unwrap();
+        manager.next_turn(&session.id).unwrap();
+        manager.damage_combatant(&session.id, &goblin.id, 4).unwrap();
+
+        // The app goes down mid-fight and comes back up
+        let mut restarted = create_test_manager();
+        restarted.set_combat_journal(CombatJournal::new(dir.path().to_path_buf()));
+        assert_eq!(restarted.recover_journaled_combats().unwrap(), vec![session.id.clone()]);
+        assert_eq!(restarted.list_sessions("campaign-1").len(), 1);
+        let combat = restarted.get_combat(&session.id).unwrap();
+        assert_eq!(combat.combatants.len(), 2);
+        assert_eq!(combat.current_combatant().unwrap().name, "Goblin");
+        assert_eq!(combat.get_combatant(&goblin.id).unwrap().current_hp, Some(3));
+
+        // A running fight isn't replaced, and an ended one is gone
+        restarted.damage_combatant(&session.id, &goblin.id, 1).unwrap();
+        let recovered = restarted.recover_combat(&session.id).unwrap().unwrap();
+        assert_eq!(recovered.get_combatant(&goblin.id).unwrap().current_hp, Some(2));
+        restarted.end_combat(&session.id).unwrap();
+        assert!(restarted.recover_combat(&session.id).unwrap().is_none());
+        assert!(manager.recover_journaled_combats().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_combat_log_to_timeline() {
+        let manager = create_test_manager();
+        let session = manager.start_session("campaign-001", 1);
+        manager.start_combat(&session.id).unwrap();
+        manager.add_combatant_quick(&session.id, "Fighter", 18, CombatantType::Player).unwrap();
+        manager.next_turn(&session.id).unwrap();
+
+        let logged = manager.get_combat_log(&session.id).len();
+        assert_eq!(manager.export_combat_log(&session.id).unwrap(), logged);
+        assert_eq!(manager.export_combat_log(&session.id).unwrap(), 0);
+
+        let exported = manager
+            .get_timeline_events(&session.id)
+            .into_iter()
+            .filter(|e| e.tags.iter().any(|t| t == "combat_log"))
+            .count();
+        assert_eq!(exported, logged);
+    }
+
     #[test]
     fn test_get_empty_combat_log() {
         let manager = create_test_manager();
@@ -1237,7 +1237,7 @@ mod tests {
 
     mod voice_manager_integration_tests {
         use super::*;
-        use crate::core::voice::types::{VoiceConfig, VoiceProviderType, QueuedVoice, VoiceStatus};
+        use crate::core::voice::types::{VoiceConfig, VoiceProviderType, QueuedVoice, VoicePriority, VoiceStatus};
         use crate::core::voice::manager::VoiceManager;
 
         fn create_disabled_config() -> VoiceConfig {
@@ -1302,6 +1302,71 @@ mod tests {
             assert_eq!(next.text, "First");
         }
 
+        #[test]
+        fn test_voice_manager_priority_order() {
+            let config = create_disabled_config();
+            let mut manager = VoiceManager::new(config);
+
+            manager.add_to_queue_with_priority("Flavor".to_string(), "voice-1".to_string(), None, VoicePriority::Flavor);
+            manager.add_to_queue("Dialogue".to_string(), "voice-1".to_string());
+            manager.add_to_queue_with_priority("Goblin hits".to_string(), "voice-1".to_string(), None, VoicePriority::Combat);
+            manager.add_to_queue_with_priority("Orc misses".to_string(), "voice-1".to_string(), None, VoicePriority::Combat);
+
+            // Highest priority first, FIFO within a level
+            let next = manager.get_next_pending().unwrap();
+            assert_eq!(next.text, "Goblin hits");
+            manager.update_status(&next.id, VoiceStatus::Completed);
+            assert_eq!(manager.get_next_pending().unwrap().text, "Orc misses");
+        }
+
+        #[test]
+        fn test_voice_priority_preemption() {
+            assert!(VoicePriority::Combat.preempts(VoicePriority::Flavor));
+            assert!(VoicePriority::Urgent.preempts(VoicePriority::Combat));
+            assert!(!VoicePriority::Combat.preempts(VoicePriority::Normal));
+            assert!(!VoicePriority::Flavor.preempts(VoicePriority::Flavor));
+            assert_eq!(VoicePriority::default(), VoicePriority::Normal);
+        }
+
+        #[test]
+        fn test_voice_manager_interrupt_and_pause() {
+            let config = create_disabled_config();
+            let mut manager = VoiceManager::new(config);
+
+            assert!(manager.interrupt_current().is_none());
+
+            let item = manager.add_to_queue("Test".to_string(), "voice-1".to_string());
+            manager.update_status(&item.id, VoiceStatus::Playing);
+            let interrupted = manager.interrupt_current().unwrap();
+            assert_eq!(interrupted.id, item.id);
+            assert_eq!(interrupted.status, VoiceStatus::Interrupted);
+
+            // Interrupted items keep their status and are not replay targets without audio
+            manager.finish_playback(&item.id, None);
+            assert_eq!(manager.get_queue()[0].status, VoiceStatus::Interrupted);
+            assert!(manager.last_played().is_none());
+
+            manager.pause_queue();
+            assert!(manager.paused);
+            manager.resume_queue();
+            assert!(!manager.paused);
+        }
+
+        #[test]
+        fn test_voice_manager_last_played_for_replay() {
+            let config = create_disabled_config();
+            let mut manager = VoiceManager::new(config);
+
+            let item = manager.add_to_queue("Welcome, travelers".to_string(), "voice-1".to_string());
+            manager.set_audio_path(&item.id, "/cache/welcome.mp3".to_string());
+            manager.update_status(&item.id, VoiceStatus::Playing);
+            manager.finish_playback(&item.id, None);
+
+            let last = manager.last_played().unwrap();
+            assert_eq!(last.status, VoiceStatus::Completed);
+            assert_eq!(last.audio_path.as_deref(), Some("/cache/welcome.mp3"));
+        }
+
         #[test]
         fn test_voice_manager_queue_status_workflow() {
             let config = create_disabled_config();